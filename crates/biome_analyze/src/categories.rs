@@ -58,7 +58,7 @@ impl ActionCategory {
     ///
     /// ```
     /// use std::borrow::Cow;
-    /// use biome_analyze::{ActionCategory, RefactorKind};
+    /// use biome_analyze::{ActionCategory, RefactorKind, SourceActionKind};
     ///
     /// assert!(ActionCategory::QuickFix(Cow::from("quickfix")).matches("quickfix"));
     ///
@@ -67,6 +67,11 @@ impl ActionCategory {
     ///
     /// assert!(ActionCategory::Refactor(RefactorKind::Extract).matches("refactor"));
     /// assert!(ActionCategory::Refactor(RefactorKind::Extract).matches("refactor.extract"));
+    ///
+    /// let category = ActionCategory::Source(SourceActionKind::Action(Cow::from("useSortedKeys")));
+    /// assert!(category.matches("source.action"));
+    /// assert!(category.matches("source.action.useSortedKeys.biome"));
+    /// assert!(!category.matches("source.fixAll"));
     /// ```
     pub fn matches(&self, filter: &str) -> bool {
         self.to_str().starts_with(filter)
@@ -104,6 +109,9 @@ impl ActionCategory {
             ActionCategory::Source(SourceActionKind::OrganizeImports) => {
                 Cow::Borrowed("source.organizeImports.biome")
             }
+            ActionCategory::Source(SourceActionKind::Action(name)) => {
+                Cow::Owned(format!("source.action.{name}.biome"))
+            }
             ActionCategory::Source(SourceActionKind::Other(tag)) => {
                 Cow::Owned(format!("source.{tag}.biome"))
             }
@@ -172,6 +180,11 @@ pub enum SourceActionKind {
     FixAll,
     /// Base kind for an organize imports source action: `source.organizeImports`.
     OrganizeImports,
+    /// Kind of the source action provided by the assist rule with the given name,
+    /// such as `source.action.useSortedKeys`.
+    ///
+    /// Each assist rule has its own kind, so that editors can pick the ones that run on save.
+    Action(Cow<'static, str>),
     /// This action is using a source action kind not covered by any of the
     /// previous variants
     Other(Cow<'static, str>),
@@ -182,6 +182,10 @@ pub enum RuleError {
     ReplacedRootWithNonRootError {
         rule_name: Option<(Cow<'static, str>, Cow<'static, str>)>,
     },
+    /// The fixes of the rules with the specified names undo each other, so applying them never ends.
+    ConflictingRuleFixesError {
+        rules: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    },
 }
 
 impl Diagnostic for RuleError {}
@@ -203,6 +207,13 @@ impl std::fmt::Display for RuleError {
                     "a code action replaced the root of the file with a non-root node."
                 )
             }
+            RuleError::ConflictingRuleFixesError { rules } => {
+                std::write!(
+                    fmt,
+                    "the fixes of the rules {} undo each other, so the fixes of the file were not applied.",
+                    ConflictingRules(rules)
+                )
+            }
         }
     }
 }
@@ -224,8 +235,30 @@ impl biome_console::fmt::Display for RuleError {
                     "a code action replaced the root of the file with a non-root node."
                 )
             }
+            RuleError::ConflictingRuleFixesError { rules } => {
+                std::write!(
+                    fmt,
+                    "the fixes of the rules {} undo each other, so the fixes of the file were not applied.",
+                    ConflictingRules(rules)
+                )
+            }
         }
     }
 }
 
 impl std::error::Error for RuleError {}
+
+/// Prints the names of the rules whose fixes conflict, separated by commas
+struct ConflictingRules<'a>(&'a [(Cow<'static, str>, Cow<'static, str>)]);
+
+impl std::fmt::Display for ConflictingRules<'_> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (index, (group, rule)) in self.0.iter().enumerate() {
+            if index > 0 {
+                fmt.write_str(", ")?;
+            }
+            std::write!(fmt, "'{group}/{rule}'")?;
+        }
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ use std::cmp::Ordering;
 use std::collections::{BTreeMap, BinaryHeap};
 use std::fmt::{Debug, Display, Formatter};
 use std::ops;
+use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::trace;
 
 mod categories;
@@ -12,6 +13,7 @@ pub mod context;
 mod diagnostics;
 mod matcher;
 pub mod options;
+pub mod profiling;
 mod query;
 mod registry;
 mod rule;
@@ -72,8 +74,9 @@ pub struct Analyzer<'analyzer, L: Language, Matcher, Break, Diag> {
     query_matcher: Matcher,
     /// Language-specific suppression comment parsing function
     parse_suppression_comment: SuppressionParser<Diag>,
-    /// Language-specific suppression comment emitter
-    suppression_action: Box<dyn SuppressionAction<Language = L>>,
+    /// Language-specific suppression comment emitter, when the suppression comments
+    /// of the language can be emitted
+    suppression_action: Option<Box<dyn SuppressionAction<Language = L>>>,
     /// Handles analyzer signals emitted by individual rules
     emit_signal: SignalHandler<'analyzer, L, Break>,
 }
@@ -97,7 +100,7 @@ where
         metadata: &'analyzer MetadataRegistry,
         query_matcher: Matcher,
         parse_suppression_comment: SuppressionParser<Diag>,
-        suppression_action: Box<dyn SuppressionAction<Language = L>>,
+        suppression_action: Option<Box<dyn SuppressionAction<Language = L>>>,
         emit_signal: SignalHandler<'analyzer, L, Break>,
     ) -> Self {
         Self {
@@ -131,6 +134,7 @@ where
 
         let mut line_index = 0;
         let mut line_suppressions = Vec::new();
+        let current_date = current_date();
 
         for (index, (phase, mut visitors)) in phases.into_iter().enumerate() {
             let runner = PhaseRunner {
@@ -146,8 +150,9 @@ where
                 root: &ctx.root,
                 services: &ctx.services,
                 range: ctx.range,
-                suppression_action: suppression_action.as_ref(),
+                suppression_action: suppression_action.as_deref(),
                 options: ctx.options,
+                current_date: &current_date,
             };
 
             // The first phase being run will inspect the tokens and parse the
@@ -210,8 +215,8 @@ struct PhaseRunner<'analyzer, 'phase, L: Language, Matcher, Break, Diag> {
     signal_queue: BinaryHeap<SignalEntry<'phase, L>>,
     /// Language-specific suppression comment parsing function
     parse_suppression_comment: SuppressionParser<Diag>,
-    /// Language-specific suppression comment emitter
-    suppression_action: &'phase dyn SuppressionAction<Language = L>,
+    /// Language-specific suppression comment emitter, if any
+    suppression_action: Option<&'phase dyn SuppressionAction<Language = L>>,
     /// Line index at the current position of the traversal
     line_index: &'phase mut usize,
     /// Track active suppression comments per-line, ordered by line index
@@ -226,6 +231,8 @@ struct PhaseRunner<'analyzer, 'phase, L: Language, Matcher, Break, Diag> {
     range: Option<TextRange>,
     /// Analyzer options
     options: &'phase AnalyzerOptions,
+    /// The date of the analysis run, with the format `YYYY-MM-DD`
+    current_date: &'phase str,
 }
 
 /// Single entry for a suppression comment in the `line_suppressions` buffer
@@ -460,8 +467,8 @@ where
         let mut has_legacy = false;
 
         for result in (self.parse_suppression_comment)(text) {
-            let kind = match result {
-                Ok(kind) => kind,
+            let suppression = match result {
+                Ok(suppression) => suppression,
                 Err(diag) => {
                     // Emit the suppression parser diagnostic
                     let signal = DiagnosticSignal::new(move || {
@@ -475,74 +482,86 @@ where
                 }
             };
 
-            if matches!(kind, SuppressionKind::Deprecated) {
-                let signal = DiagnosticSignal::new(move || {
-                    SuppressionDiagnostic::new(
-                        category!("suppressions/deprecatedSuppressionComment"),
-                        range,
-                        "// rome-ignore is deprecated, use // biome-ignore instead",
-                    )
-                    .with_tags(DiagnosticTags::DEPRECATED_CODE)
-                    .with_severity(Severity::Information)
-                })
-                .with_action(move || create_suppression_comment_action(token));
-
-                (self.emit_signal)(&signal)?;
+            if range_match(self.range, range) {
+                self.check_explanation(suppression.reason, suppression.until, range)?;
             }
 
-            let (rule, instance) = match kind {
-                SuppressionKind::Everything => (None, None),
-                SuppressionKind::Rule(rule) => (Some(rule), None),
-                SuppressionKind::RuleInstance(rule, instance) => (Some(rule), Some(instance)),
-                SuppressionKind::MaybeLegacy(rule) => (Some(rule), None),
-                SuppressionKind::Deprecated => (None, None),
-            };
+            for kind in suppression.kinds {
+                if matches!(kind, SuppressionKind::Deprecated) {
+                    let signal = DiagnosticSignal::new(move || {
+                        SuppressionDiagnostic::new(
+                            category!("suppressions/deprecatedSuppressionComment"),
+                            range,
+                            "// rome-ignore is deprecated, use // biome-ignore instead",
+                        )
+                        .with_tags(DiagnosticTags::DEPRECATED_CODE)
+                        .with_severity(Severity::Information)
+                    })
+                    .with_action(move || create_suppression_comment_action(token));
 
-            if let Some(rule) = rule {
-                let group_rule = rule.split_once('/');
+                    (self.emit_signal)(&signal)?;
+                }
 
-                let key = match group_rule {
-                    None => self.metadata.find_group(rule).map(RuleFilter::from),
-                    Some((group, rule)) => {
-                        self.metadata.find_rule(group, rule).map(RuleFilter::from)
-                    }
+                let (rule, instance) = match kind {
+                    SuppressionKind::Everything => (None, None),
+                    SuppressionKind::Rule(rule) => (Some(rule), None),
+                    SuppressionKind::RuleInstance(rule, instance) => (Some(rule), Some(instance)),
+                    SuppressionKind::MaybeLegacy(rule) => (Some(rule), None),
+                    SuppressionKind::Deprecated => (None, None),
                 };
 
-                match (key, instance) {
-                    (Some(key), Some(value)) => suppressed_instances.push((key, value.to_owned())),
-                    (Some(key), None) => {
-                        suppressed_rules.push(key);
-                        has_legacy |= matches!(kind, SuppressionKind::MaybeLegacy(_));
-                    }
-                    _ if range_match(self.range, range) => {
-                        // Emit a warning for the unknown rule
-                        let signal = DiagnosticSignal::new(move || match group_rule {
-                            Some((group, rule)) => SuppressionDiagnostic::new(
-                                category!("suppressions/unknownRule"),
-                                range,
-                                format_args!(
-                                    "Unknown lint rule {group}/{rule} in suppression comment"
+                if let Some(rule) = rule {
+                    let group_rule = rule.split_once('/');
+
+                    let key = match group_rule {
+                        None => self.metadata.find_group(rule).map(RuleFilter::from),
+                        Some((group, rule)) => {
+                            self.metadata.find_rule(group, rule).map(RuleFilter::from)
+                        }
+                    };
+
+                    match (key, instance) {
+                        (Some(key), Some(value)) => {
+                            suppressed_instances.push((key, value.to_owned()))
+                        }
+                        (Some(key), None) => {
+                            suppressed_rules.push(key);
+                            has_legacy |= matches!(kind, SuppressionKind::MaybeLegacy(_));
+                        }
+                        _ if range_match(self.range, range) => {
+                            // Emit a warning for the unknown rule
+                            let signal = DiagnosticSignal::new(move || match group_rule {
+                                Some((group, rule)) => SuppressionDiagnostic::new(
+                                    category!("suppressions/unknownRule"),
+                                    range,
+                                    format_args!(
+                                        "Unknown lint rule {group}/{rule} in suppression comment"
+                                    ),
                                 ),
-                            ),
 
-                            None => SuppressionDiagnostic::new(
-                                category!("suppressions/unknownGroup"),
-                                range,
-                                format_args!(
-                                    "Unknown lint rule group {rule} in suppression comment"
+                                None => SuppressionDiagnostic::new(
+                                    category!("suppressions/unknownGroup"),
+                                    range,
+                                    format_args!(
+                                        "Unknown lint rule group {rule} in suppression comment"
+                                    ),
                                 ),
-                            ),
-                        });
+                            });
 
-                        (self.emit_signal)(&signal)?;
+                            (self.emit_signal)(&signal)?;
+                        }
+                        _ => {}
                     }
-                    _ => {}
+                } else {
+                    suppressed_rules.clear();
+                    suppress_all = true;
+                    // If this if a "suppress all lints" comment, no need to
+                    // parse anything else
+                    break;
                 }
-            } else {
-                suppressed_rules.clear();
-                suppress_all = true;
-                // If this if a "suppress all lints" comment, no need to
-                // parse anything else
+            }
+
+            if suppress_all {
                 break;
             }
         }
@@ -608,6 +627,46 @@ where
         ControlFlow::Continue(())
     }
 
+    /// Emits a diagnostic for the suppressions without explanation when the configuration
+    /// requires one, and for the suppressions whose date has passed
+    fn check_explanation(
+        &mut self,
+        reason: &str,
+        until: Option<&str>,
+        range: TextRange,
+    ) -> ControlFlow<Break> {
+        if reason.is_empty() && self.options.configuration.require_suppression_reason {
+            let signal = DiagnosticSignal::new(move || {
+                SuppressionDiagnostic::new(
+                    category!("suppressions/missingReason"),
+                    range,
+                    "Suppression comment has no explanation. Explain why the code is suppressed after the colon.",
+                )
+                .with_severity(Severity::Error)
+            });
+
+            (self.emit_signal)(&signal)?;
+        }
+
+        // The dates have the format `YYYY-MM-DD`, so they can be compared as strings
+        if let Some(until) = until.filter(|until| *until < self.current_date) {
+            let signal = DiagnosticSignal::new(move || {
+                SuppressionDiagnostic::new(
+                    category!("suppressions/expired"),
+                    range,
+                    format_args!(
+                        "Suppression comment expired on {until}. Fix the suppressed code, or update the date of the suppression."
+                    ),
+                )
+                .with_severity(Severity::Error)
+            });
+
+            (self.emit_signal)(&signal)?;
+        }
+
+        ControlFlow::Continue(())
+    }
+
     /// Check a piece of source text (token or trivia) for line breaks and
     /// increment the line index accordingly, extending the range of the
     /// current suppression as required
@@ -691,11 +750,38 @@ fn range_match(filter: Option<TextRange>, range: TextRange) -> bool {
     filter.map_or(true, |filter| filter.intersect(range).is_some())
 }
 
+/// Returns the current date in UTC, with the format `YYYY-MM-DD`
+fn current_date() -> String {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() / 86_400) as i64;
+
+    // Converts the days since the Unix epoch to a date of the Gregorian calendar,
+    // see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
 /// Signature for a suppression comment parser function
 ///
 /// This function receives the text content of a comment and returns a list of
 /// lint suppressions as an optional lint rule (if the lint rule is `None` the
-/// comment is interpreted as suppressing all lints)
+/// comment is interpreted as suppressing all lints). The examples list the
+/// [AnalyzerSuppression::kinds] of the suppressions of the comment.
 ///
 /// # Examples
 ///
@@ -706,7 +792,17 @@ fn range_match(filter: Option<TextRange>, range: TextRange) -> bool {
 /// - `// biome-ignore lint/style/useWhile lint/nursery/noUnreachable` -> `vec![Rule("style/useWhile"), Rule("nursery/noUnreachable")]`
 /// - `// biome-ignore lint(style/useWhile)` -> `vec![MaybeLegacy("style/useWhile")]`
 /// - `// biome-ignore lint(style/useWhile) lint(nursery/noUnreachable)` -> `vec![MaybeLegacy("style/useWhile"), MaybeLegacy("nursery/noUnreachable")]`
-type SuppressionParser<D> = fn(&str) -> Vec<Result<SuppressionKind, D>>;
+type SuppressionParser<D> = fn(&str) -> Vec<Result<AnalyzerSuppression, D>>;
+
+/// A suppression parsed from a comment, eg. `// biome-ignore lint/style/useWhile until: 2025-06-30: explanation`
+pub struct AnalyzerSuppression<'a> {
+    /// What the suppression disables
+    pub kinds: Vec<SuppressionKind<'a>>,
+    /// The explanation of the suppression, empty when the comment has none
+    pub reason: &'a str,
+    /// The date until which the suppression applies, with the format `YYYY-MM-DD`
+    pub until: Option<&'a str>,
+}
 
 /// This enum is used to categorize what is disabled by a suppression comment and with what syntax
 pub enum SuppressionKind<'a> {
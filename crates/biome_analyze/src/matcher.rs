@@ -25,7 +25,7 @@ pub struct MatchQueryParams<'phase, 'query, L: Language> {
     pub query: Query,
     pub services: &'phase ServiceBag,
     pub signal_queue: &'query mut BinaryHeap<SignalEntry<'phase, L>>,
-    pub suppression_action: &'phase dyn SuppressionAction<Language = L>,
+    pub suppression_action: Option<&'phase dyn SuppressionAction<Language = L>>,
     pub options: &'phase AnalyzerOptions,
 }
 
@@ -204,7 +204,7 @@ mod tests {
         ControlFlow, MetadataRegistry, Never, Phases, QueryMatcher, RuleKey, ServiceBag,
         SignalEntry, SuppressionAction, SyntaxVisitor,
     };
-    use crate::{AnalyzerOptions, SuppressionKind};
+    use crate::{AnalyzerOptions, AnalyzerSuppression, SuppressionKind};
     use biome_diagnostics::{category, DiagnosticExt};
     use biome_diagnostics::{Diagnostic, Severity};
     use biome_rowan::{
@@ -351,13 +351,18 @@ mod tests {
 
         fn parse_suppression_comment(
             comment: &'_ str,
-        ) -> Vec<Result<SuppressionKind<'_>, Infallible>> {
-            comment
+        ) -> Vec<Result<AnalyzerSuppression<'_>, Infallible>> {
+            let kinds = comment
                 .trim_start_matches("//")
                 .split(' ')
                 .map(SuppressionKind::Rule)
-                .map(Ok)
-                .collect()
+                .collect();
+
+            vec![Ok(AnalyzerSuppression {
+                kinds,
+                reason: "",
+                until: None,
+            })]
         }
 
         let mut metadata = MetadataRegistry::default();
@@ -390,7 +395,7 @@ mod tests {
             &metadata,
             SuppressionMatcher,
             parse_suppression_comment,
-            Box::new(TestAction),
+            Some(Box::new(TestAction)),
             &mut emit_signal,
         );
 
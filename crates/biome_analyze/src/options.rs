@@ -67,6 +67,9 @@ pub struct AnalyzerConfiguration {
 
     /// Indicates the type of runtime or transformation used for interpreting JSX.
     pub jsx_runtime: Option<JsxRuntime>,
+
+    /// Whether the suppression comments must have an explanation
+    pub require_suppression_reason: bool,
 }
 
 /// A set of information useful to the analyzer infrastructure
@@ -0,0 +1,86 @@
+//! Measures the time spent by the rules and by the phases of the processing of the files,
+//! aggregated across all the files processed by the current process.
+//!
+//! The measures are only taken once [enable] is called, so that the other runs don't pay
+//! for the clock reads.
+
+use crate::RuleKey;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+static IS_ENABLED: AtomicBool = AtomicBool::new(false);
+static RULES: Mutex<BTreeMap<RuleKey, Duration>> = Mutex::new(BTreeMap::new());
+static PHASES: Mutex<BTreeMap<&'static str, Duration>> = Mutex::new(BTreeMap::new());
+
+/// The time spent by the rules and the phases since profiling was enabled
+#[derive(Debug, Default, Clone)]
+pub struct Profile {
+    /// The time spent running each rule, sorted by descending time
+    pub rules: Vec<(RuleKey, Duration)>,
+    /// The time spent in each phase, for example `parse`, sorted by descending time
+    pub phases: Vec<(&'static str, Duration)>,
+}
+
+/// Starts measuring the time spent by the rules and the phases
+pub fn enable() {
+    IS_ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether the time spent by the rules and the phases is measured
+pub fn is_enabled() -> bool {
+    IS_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Adds the time spent by the rules while analyzing a file
+pub(crate) fn record_rules(rules: impl IntoIterator<Item = (RuleKey, Duration)>) {
+    let mut recorded = RULES.lock().unwrap();
+    for (rule, elapsed) in rules {
+        *recorded.entry(rule).or_default() += elapsed;
+    }
+}
+
+/// Runs `f`, adding the time it took to `phase` when profiling is enabled
+pub fn measure<T>(phase: &'static str, f: impl FnOnce() -> T) -> T {
+    if !is_enabled() {
+        return f();
+    }
+
+    let start = Instant::now();
+    let result = f();
+    *PHASES.lock().unwrap().entry(phase).or_default() += start.elapsed();
+    result
+}
+
+/// Returns the time spent by the rules and the phases, and resets the measures
+pub fn take_profile() -> Profile {
+    let mut rules: Vec<_> = std::mem::take(&mut *RULES.lock().unwrap())
+        .into_iter()
+        .collect();
+    rules.sort_by(|(_, a), (_, b)| b.cmp(a));
+    let mut phases: Vec<_> = std::mem::take(&mut *PHASES.lock().unwrap())
+        .into_iter()
+        .collect();
+    phases.sort_by(|(_, a), (_, b)| b.cmp(a));
+    Profile { rules, phases }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aggregates_the_measures() {
+        enable();
+        let rule = RuleKey::new("nursery", "noSlowRule");
+        record_rules([(rule, Duration::from_millis(2))]);
+        record_rules([(rule, Duration::from_millis(3))]);
+        assert_eq!(measure("parse", || 1 + 1), 2);
+
+        let profile = take_profile();
+        assert!(profile.rules.contains(&(rule, Duration::from_millis(5))));
+        assert!(profile.phases.iter().any(|(phase, _)| *phase == "parse"));
+        assert!(take_profile().phases.is_empty());
+    }
+}
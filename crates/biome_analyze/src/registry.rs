@@ -13,6 +13,7 @@ use std::{
     any::TypeId,
     borrow,
     collections::{BTreeMap, BTreeSet},
+    time::{Duration, Instant},
 };
 
 /// Defines all the phases that the [RuleRegistry] supports.
@@ -214,7 +215,7 @@ impl<L: Language + Default + 'static> RegistryVisitor<L> for RuleRegistryBuilder
             }
         }
 
-        phase.rule_states.push(RuleState::default());
+        phase.rule_states.push(RuleState::new(RuleKey::rule::<R>()));
 
         <R::Query as Queryable>::build_visitor(&mut self.visitors, self.root);
     }
@@ -279,8 +280,30 @@ impl<L: Language + 'static> QueryMatcher<L> for RuleRegistry<L> {
         // Run all the rules registered to this QueryMatch
         for rule in rules {
             let state = &mut phase.rule_states[rule.state_index];
-            // TODO: #3394 track error in the signal queue
-            let _ = (rule.run)(&mut params, state);
+            if crate::profiling::is_enabled() {
+                let start = Instant::now();
+                // TODO: #3394 track error in the signal queue
+                let _ = (rule.run)(&mut params, state);
+                state.elapsed += start.elapsed();
+            } else {
+                // TODO: #3394 track error in the signal queue
+                let _ = (rule.run)(&mut params, state);
+            }
+        }
+    }
+}
+
+impl<L: Language> Drop for RuleRegistry<L> {
+    fn drop(&mut self) {
+        // The time spent by the rules is flushed once per file, to keep the contention low
+        if crate::profiling::is_enabled() {
+            crate::profiling::record_rules(
+                self.phase_rules
+                    .iter()
+                    .flat_map(|phase| &phase.rule_states)
+                    .filter(|state| !state.elapsed.is_zero())
+                    .map(|state| (state.rule, state.elapsed)),
+            );
         }
     }
 }
@@ -353,9 +376,21 @@ pub struct RegistryRule<L: Language> {
 }
 
 /// Internal state for a given rule
-#[derive(Default)]
 struct RuleState<L: Language> {
+    rule: RuleKey,
     suppressions: RuleSuppressions<L>,
+    /// The time spent running the rule, only measured when profiling is enabled
+    elapsed: Duration,
+}
+
+impl<L: Language> RuleState<L> {
+    fn new(rule: RuleKey) -> Self {
+        Self {
+            rule,
+            suppressions: RuleSuppressions::default(),
+            elapsed: Duration::ZERO,
+        }
+    }
 }
 
 /// Set of nodes this rule has suppressed from matching its query
@@ -376,7 +376,7 @@ impl RuleMetadata {
                 ActionCategory::QuickFix(Cow::Owned(format!("{}.{}", group, self.name)))
             }
             RuleCategory::Action => {
-                ActionCategory::Source(SourceActionKind::Other(Cow::Borrowed(self.name)))
+                ActionCategory::Source(SourceActionKind::Action(Cow::Borrowed(self.name)))
             }
             RuleCategory::Syntax | RuleCategory::Transformation => unimplemented!(""),
         }
@@ -554,7 +554,7 @@ macro_rules! declare_source_rule {
         /// This macro returns the corresponding [ActionCategory] to use inside the [RuleAction]
         #[allow(unused_macros)]
         macro_rules! rule_action_category {
-            () => { ActionCategory::Source(SourceActionKind::Other(Cow::Borrowed(concat!($language, ".", $name) )))  };
+            () => { ActionCategory::Source(SourceActionKind::Action(Cow::Borrowed($name)))  };
         }
     };
 }
@@ -309,7 +309,7 @@ pub(crate) struct RuleSignal<'phase, R: Rule> {
     state: R::State,
     services: &'phase ServiceBag,
     /// An optional action to suppress the rule.
-    suppression_action: &'phase dyn SuppressionAction<Language = RuleLanguage<R>>,
+    suppression_action: Option<&'phase dyn SuppressionAction<Language = RuleLanguage<R>>>,
     /// A list of strings that are considered "globals" inside the analyzer
     options: &'phase AnalyzerOptions,
 }
@@ -323,8 +323,8 @@ where
         query_result: <<R as Rule>::Query as Queryable>::Output,
         state: R::State,
         services: &'phase ServiceBag,
-        suppression_action: &'phase dyn SuppressionAction<
-            Language = <<R as Rule>::Query as Queryable>::Language,
+        suppression_action: Option<
+            &'phase dyn SuppressionAction<Language = <<R as Rule>::Query as Queryable>::Language>,
         >,
         options: &'phase AnalyzerOptions,
     ) -> Self {
@@ -365,17 +365,12 @@ where
     fn actions(&self) -> AnalyzerActionIter<RuleLanguage<R>> {
         let globals = self.options.globals();
 
-        let configured_applicability = if let Some(fix_kind) = self.options.rule_fix_kind::<R>() {
-            match fix_kind {
-                crate::FixKind::None => {
-                    // The action is disabled
-                    return AnalyzerActionIter::new(vec![]);
-                }
-                crate::FixKind::Safe => Some(Applicability::Always),
-                crate::FixKind::Unsafe => Some(Applicability::MaybeIncorrect),
-            }
-        } else {
-            None
+        // The fix of the rule can be disabled, the suppression action is still emitted
+        let (fix_disabled, configured_applicability) = match self.options.rule_fix_kind::<R>() {
+            Some(crate::FixKind::None) => (true, None),
+            Some(crate::FixKind::Safe) => (false, Some(Applicability::Always)),
+            Some(crate::FixKind::Unsafe) => (false, Some(Applicability::MaybeIncorrect)),
+            None => (false, None),
         };
         let options = self.options.rule_options::<R>().unwrap_or_default();
         let ctx = RuleContext::new(
@@ -391,7 +386,12 @@ where
         .ok();
         if let Some(ctx) = ctx {
             let mut actions = Vec::new();
-            if let Some(action) = R::action(&ctx, &self.state) {
+            let action = if fix_disabled {
+                None
+            } else {
+                R::action(&ctx, &self.state)
+            };
+            if let Some(action) = action {
                 actions.push(AnalyzerAction {
                     rule_name: Some((<R::Group as RuleGroup>::NAME, R::METADATA.name)),
                     applicability: configured_applicability.unwrap_or(action.applicability()),
@@ -400,11 +400,13 @@ where
                     message: action.message,
                 });
             };
-            if let Some(text_range) = R::text_range(&ctx, &self.state) {
+            if let (Some(text_range), Some(suppression_action)) =
+                (R::text_range(&ctx, &self.state), self.suppression_action)
+            {
                 if let Some(suppression_action) = R::suppress(
                     &ctx,
                     &text_range,
-                    self.suppression_action,
+                    suppression_action,
                     self.options.suppression_reason.as_deref(),
                 ) {
                     let action = AnalyzerAction {
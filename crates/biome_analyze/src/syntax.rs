@@ -176,7 +176,7 @@ mod tests {
             &metadata,
             &mut matcher,
             |_| -> Vec<Result<_, Infallible>> { unreachable!() },
-            Box::new(TestAction),
+            Some(Box::new(TestAction)),
             &mut emit_signal,
         );
 
@@ -12,7 +12,7 @@ pub struct VisitorContext<'phase, 'query, L: Language> {
     pub phase: Phases,
     pub root: &'phase LanguageRoot<L>,
     pub services: &'phase ServiceBag,
-    pub suppression_action: &'phase dyn SuppressionAction<Language = L>,
+    pub suppression_action: Option<&'phase dyn SuppressionAction<Language = L>>,
     pub range: Option<TextRange>,
     pub(crate) query_matcher: &'query mut dyn QueryMatcher<L>,
     pub(crate) signal_queue: &'query mut BinaryHeap<SignalEntry<'phase, L>>,
@@ -182,6 +182,20 @@ impl AriaRole {
         self.inherited_abstract_roles()
             .contains(&AriaAbstractRole::Composite)
     }
+
+    /// Returns `true` if the given role inherits of `AriaAbstractRole::Landmark`.
+    ///
+    /// This corresponds to a region of the page that users navigate to (banner, main, navigation, ...).
+    ///
+    /// ```
+    /// use biome_aria_metadata::AriaRole;
+    /// assert!(AriaRole::Main.is_landmark());
+    /// assert!(!AriaRole::Button.is_landmark());
+    /// ```
+    pub fn is_landmark(self) -> bool {
+        self.inherited_abstract_roles()
+            .contains(&AriaAbstractRole::Landmark)
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
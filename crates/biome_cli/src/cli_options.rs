@@ -1,5 +1,6 @@
 use crate::logging::LoggingKind;
 use crate::LoggingLevel;
+use biome_configuration::analyzer::{RuleGroup, RuleSelector};
 use biome_configuration::ConfigurationPathHint;
 use biome_diagnostics::Severity;
 use bpaf::Bpaf;
@@ -27,6 +28,11 @@ pub struct CliOptions {
     #[bpaf(long("config-path"), argument("PATH"), optional)]
     pub config_path: Option<String>,
 
+    /// Override a key of the configuration, with the syntax `<key>=<value>`. The key is the path of the option, separated by dots, and the value is JSON or a string.
+    /// Example: `--config-override=linter.rules.style.noDefaultExport=off`. It can be passed multiple times, and it takes precedence over the variable `BIOME_CONFIG_OVERRIDE`. The other options of the command take precedence over the overrides.
+    #[bpaf(long("config-override"), argument("KEY=VALUE"), many)]
+    pub config_override: Vec<String>,
+
     /// Cap the amount of diagnostics displayed. When `none` is provided, the limit is lifted.
     #[bpaf(
         long("max-diagnostics"),
@@ -48,10 +54,20 @@ pub struct CliOptions {
     #[bpaf(long("error-on-warnings"), switch)]
     pub error_on_warnings: bool,
 
+    /// Tell Biome to exit with an error code if the diagnostics emit more warnings than the given number.
+    #[bpaf(long("max-warnings"), argument("NUMBER"), optional)]
+    pub max_warnings: Option<u32>,
+
+    /// Print the time spent by each phase and by each rule once the files are processed, to find the rules that slow down the command.
+    #[bpaf(long("profile-rules"), switch)]
+    pub profile_rules: bool,
+
     /// Allows to change how diagnostics and summary are reported.
     #[bpaf(
         long("reporter"),
-        argument("json|json-pretty|github|junit|summary|gitlab"),
+        argument::<String>("json|json-pretty|github|junit|summary|gitlab|checkstyle|diff"),
+        complete(complete_reporter),
+        parse(|value: String| value.parse::<CliReporter>()),
         fallback(CliReporter::default())
     )]
     pub reporter: CliReporter,
@@ -86,6 +102,19 @@ pub struct CliOptions {
     pub diagnostic_level: Severity,
 }
 
+/// Options to skip the files that didn't change since the previous runs
+#[derive(Debug, Clone, Default, Bpaf)]
+pub struct CacheOptions {
+    /// Skips the files that didn't change since a previous run that didn't report anything about them.
+    /// The cache is discarded when the configuration, the arguments, or the version of Biome change.
+    #[bpaf(long("cache"), switch)]
+    pub cache: bool,
+
+    /// The path of the file where the cache is stored. Defaults to `.biomecache`.
+    #[bpaf(long("cache-location"), argument("PATH"))]
+    pub cache_location: Option<PathBuf>,
+}
+
 impl CliOptions {
     /// Computes the [ConfigurationPathHint] based on the options passed by the user
     pub(crate) fn as_configuration_path_hint(&self) -> ConfigurationPathHint {
@@ -94,6 +123,16 @@ impl CliOptions {
             Some(path) => ConfigurationPathHint::FromUser(PathBuf::from(path)),
         }
     }
+
+    /// The number of warnings above which the command exits with an error code, when it's set
+    /// by the arguments. `--error-on-warnings` doesn't allow any warning.
+    pub(crate) fn as_max_warnings(&self) -> Option<u32> {
+        if self.error_on_warnings {
+            Some(0)
+        } else {
+            self.max_warnings
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -133,14 +172,41 @@ pub enum CliReporter {
     Summary,
     /// Reports linter diagnostics using the [GitLab Code Quality report](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool).
     GitLab,
+    /// Reports diagnostics in the [Checkstyle](https://checkstyle.org/) XML format
+    Checkstyle,
+    /// Prints the pending changes of the files as a unified diff, instead of writing them
+    Diff,
 }
 
 impl CliReporter {
+    /// The names of the reporters accepted by `--reporter`
+    const NAMES: &'static [&'static str] = &[
+        "json",
+        "json-pretty",
+        "github",
+        "junit",
+        "summary",
+        "gitlab",
+        "checkstyle",
+        "diff",
+    ];
+
     pub(crate) const fn is_default(&self) -> bool {
         matches!(self, Self::Default)
     }
 }
 
+/// Completes the name of a reporter in the shell
+// `bpaf` passes the value typed so far as a `&String`
+#[allow(clippy::ptr_arg)]
+fn complete_reporter(input: &String) -> Vec<(&'static str, Option<&'static str>)> {
+    CliReporter::NAMES
+        .iter()
+        .filter(|name| name.starts_with(input.as_str()))
+        .map(|name| (*name, None))
+        .collect()
+}
+
 impl FromStr for CliReporter {
     type Err = String;
 
@@ -152,6 +218,8 @@ impl FromStr for CliReporter {
             "github" => Ok(Self::GitHub),
             "junit" => Ok(Self::Junit),
             "gitlab" => Ok(Self::GitLab),
+            "checkstyle" => Ok(Self::Checkstyle),
+            "diff" => Ok(Self::Diff),
             _ => Err(format!(
                 "value {s:?} is not valid for the --reporter argument"
             )),
@@ -169,6 +237,8 @@ impl Display for CliReporter {
             CliReporter::GitHub => f.write_str("github"),
             CliReporter::Junit => f.write_str("junit"),
             CliReporter::GitLab => f.write_str("gitlab"),
+            CliReporter::Checkstyle => f.write_str("checkstyle"),
+            CliReporter::Diff => f.write_str("diff"),
         }
     }
 }
@@ -240,3 +310,52 @@ impl From<MaxDiagnostics> for u32 {
         }
     }
 }
+
+/// A list of rules or groups of rules, separated by commas
+#[derive(Debug, Clone, Default)]
+pub struct RuleSelectors(pub Vec<RuleSelector>);
+
+impl FromStr for RuleSelectors {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|selector| {
+                RuleSelector::from_str(selector.trim())
+                    .map_err(|error| format!("{selector:?}: {error}"))
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// Completes the last rule or group of rules of a list separated by commas in the shell.
+///
+/// The rules are completed once their group is typed, because there are hundreds of them.
+// `bpaf` passes the value typed so far as a `&String`
+#[allow(clippy::ptr_arg)]
+pub(crate) fn complete_rule_selectors(input: &String) -> Vec<(String, Option<String>)> {
+    let (previous, current) = match input.rsplit_once(',') {
+        Some((previous, current)) => (&input[..previous.len() + 1], current),
+        None => ("", input.as_str()),
+    };
+    match current.split_once('/') {
+        Some((group_name, rule_prefix)) => {
+            let Ok(group) = RuleGroup::from_str(group_name) else {
+                return Vec::new();
+            };
+            group
+                .rule_names()
+                .iter()
+                .filter(|rule_name| rule_name.starts_with(rule_prefix))
+                .map(|rule_name| (format!("{previous}{group_name}/{rule_name}"), None))
+                .collect()
+        }
+        None => RuleGroup::ALL
+            .iter()
+            .map(|group| group.as_str())
+            .filter(|group_name| group_name.starts_with(current))
+            .map(|group_name| (format!("{previous}{group_name}"), None))
+            .collect(),
+    }
+}
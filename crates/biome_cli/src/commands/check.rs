@@ -1,6 +1,7 @@
 use super::{determine_fix_file_mode, FixFileModeOptions, LoadEditorConfig};
-use crate::cli_options::CliOptions;
+use crate::cli_options::{CacheOptions, CliOptions};
 use crate::commands::{get_files_to_process_with_cli_options, CommandRunner};
+use crate::execute::module_graph::ModuleGraphExport;
 use crate::{CliDiagnostic, Execution, TraversalMode};
 use biome_configuration::analyzer::assists::PartialAssistsConfiguration;
 use biome_configuration::{
@@ -12,6 +13,7 @@ use biome_deserialize::Merge;
 use biome_fs::FileSystem;
 use biome_service::{configuration::LoadedConfiguration, DynRef, Workspace, WorkspaceError};
 use std::ffi::OsString;
+use std::path::PathBuf;
 
 pub(crate) struct CheckCommandPayload {
     pub(crate) apply: bool,
@@ -22,6 +24,7 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) configuration: Option<PartialConfiguration>,
     pub(crate) paths: Vec<OsString>,
     pub(crate) stdin_file_path: Option<String>,
+    pub(crate) stdin_files: bool,
     pub(crate) formatter_enabled: Option<bool>,
     pub(crate) linter_enabled: Option<bool>,
     pub(crate) organize_imports_enabled: Option<bool>,
@@ -29,6 +32,11 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) watch: bool,
+    pub(crate) emit_module_graph: Option<PathBuf>,
+    pub(crate) module_graph_from: Option<PathBuf>,
+    pub(crate) module_graph_cycles: bool,
+    pub(crate) cache_options: CacheOptions,
 }
 
 impl LoadEditorConfig for CheckCommandPayload {
@@ -126,6 +134,10 @@ impl CommandRunner for CheckCommandPayload {
         self.stdin_file_path.as_deref()
     }
 
+    fn should_read_stdin_files(&self) -> bool {
+        self.stdin_files
+    }
+
     fn should_write(&self) -> bool {
         self.write || self.fix
     }
@@ -149,11 +161,57 @@ impl CommandRunner for CheckCommandPayload {
             console,
         )?;
 
-        Ok(Execution::new(TraversalMode::Check {
+        let mut execution = Execution::new(TraversalMode::Check {
             fix_file_mode,
             stdin: self.get_stdin(console)?,
             vcs_targeted: (self.staged, self.changed).into(),
         })
-        .set_report(cli_options))
+        .set_report(cli_options);
+        if let Some(emit_module_graph) = &self.emit_module_graph {
+            execution = execution.with_module_graph(ModuleGraphExport::new(
+                emit_module_graph.clone(),
+                self.module_graph_from.clone(),
+                self.module_graph_cycles,
+            ));
+        }
+        Ok(execution)
+    }
+
+    fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        if self.watch && self.stdin_file_path.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "watch",
+                "stdin-file-path",
+            ));
+        }
+        if self.watch && self.stdin_files {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "watch",
+                "stdin-files",
+            ));
+        }
+        // The filters of the module graph are useless without the graph
+        if self.emit_module_graph.is_none()
+            && (self.module_graph_from.is_some() || self.module_graph_cycles)
+        {
+            return Err(CliDiagnostic::missing_argument(
+                "emit-module-graph",
+                Self::COMMAND_NAME,
+            ));
+        }
+        Ok(())
+    }
+
+    fn should_watch(&self) -> bool {
+        self.watch
+    }
+
+    fn get_cache_options(&self) -> Option<&CacheOptions> {
+        // The files skipped by the cache aren't processed, so their imports would be missing
+        // from the module graph
+        if self.emit_module_graph.is_some() {
+            return None;
+        }
+        Some(&self.cache_options)
     }
 }
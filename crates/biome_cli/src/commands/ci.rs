@@ -1,6 +1,7 @@
 use crate::changed::get_changed_files;
 use crate::cli_options::CliOptions;
 use crate::commands::{CommandRunner, LoadEditorConfig};
+use crate::execute::ci_summary::{ChangedFiles, CiSummary};
 use crate::{CliDiagnostic, Execution};
 use biome_configuration::analyzer::assists::PartialAssistsConfiguration;
 use biome_configuration::{organize_imports::PartialOrganizeImports, PartialConfiguration};
@@ -10,7 +11,9 @@ use biome_deserialize::Merge;
 use biome_fs::FileSystem;
 use biome_service::configuration::LoadedConfiguration;
 use biome_service::{DynRef, Workspace, WorkspaceError};
+use rustc_hash::FxHashSet;
 use std::ffi::OsString;
+use std::path::PathBuf;
 
 pub(crate) struct CiCommandPayload {
     pub(crate) formatter_enabled: Option<bool>,
@@ -21,6 +24,10 @@ pub(crate) struct CiCommandPayload {
     pub(crate) configuration: Option<PartialConfiguration>,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) summary_file: Option<PathBuf>,
+    /// The files changed compared to the base branch, computed for the summary when the
+    /// base branch is known
+    pub(crate) summary_changed_files: Option<FxHashSet<String>>,
 }
 
 impl LoadEditorConfig for CiCommandPayload {
@@ -95,6 +102,17 @@ impl CommandRunner for CiCommandPayload {
             fs_configuration.merge_with(configuration);
         }
 
+        // The diagnostics of the changed files are new, unless only the changed files are processed
+        if self.summary_file.is_some() && !self.changed {
+            let default_branch = fs_configuration
+                .vcs
+                .as_ref()
+                .and_then(|vcs| vcs.default_branch.as_deref());
+            self.summary_changed_files = default_branch
+                .and_then(|base| fs.get_changed_files(base).ok())
+                .map(|files| files.into_iter().collect());
+        }
+
         Ok(fs_configuration)
     }
 
@@ -124,7 +142,20 @@ impl CommandRunner for CiCommandPayload {
         _console: &mut dyn Console,
         _workspace: &dyn Workspace,
     ) -> Result<Execution, CliDiagnostic> {
-        Ok(Execution::new_ci((false, self.changed).into()).set_report(cli_options))
+        let execution = Execution::new_ci((false, self.changed).into()).set_report(cli_options);
+        Ok(match &self.summary_file {
+            Some(summary_file) => {
+                let changed_files = if self.changed {
+                    ChangedFiles::All
+                } else if let Some(files) = &self.summary_changed_files {
+                    ChangedFiles::Some(files.clone())
+                } else {
+                    ChangedFiles::Unknown
+                };
+                execution.with_ci_summary(CiSummary::new(summary_file.clone(), changed_files))
+            }
+            None => execution,
+        })
     }
 
     fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
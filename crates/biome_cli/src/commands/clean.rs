@@ -1,15 +1,28 @@
 use crate::commands::daemon::default_biome_log_path;
+use crate::execute::cache::{project_index_location, DEFAULT_CACHE_LOCATION};
 use crate::{CliDiagnostic, CliSession};
 use biome_flags::biome_env;
-use std::fs::{create_dir, remove_dir_all};
+use std::fs::{create_dir, remove_dir_all, remove_file};
+use std::io;
 use std::path::PathBuf;
 
 /// Runs the clean command
-pub fn clean(_cli_session: CliSession) -> Result<(), CliDiagnostic> {
+pub fn clean(
+    _cli_session: CliSession,
+    cache_location: Option<PathBuf>,
+) -> Result<(), CliDiagnostic> {
     let logs_path = biome_env()
         .biome_log_path
         .value()
         .map_or(default_biome_log_path(), PathBuf::from);
     remove_dir_all(logs_path.clone()).and_then(|_| create_dir(logs_path))?;
+
+    let cache_location = cache_location.unwrap_or_else(|| PathBuf::from(DEFAULT_CACHE_LOCATION));
+    for location in [project_index_location(&cache_location), cache_location] {
+        match remove_file(location) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => return Err(error.into()),
+            _ => {}
+        }
+    }
     Ok(())
 }
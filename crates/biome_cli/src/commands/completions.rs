@@ -0,0 +1,90 @@
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use std::str::FromStr;
+
+/// The shells whose completion scripts can be generated
+#[derive(Debug, Clone, Copy)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    PowerShell,
+}
+
+impl FromStr for Shell {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bash" => Ok(Self::Bash),
+            "zsh" => Ok(Self::Zsh),
+            "fish" => Ok(Self::Fish),
+            "powershell" => Ok(Self::PowerShell),
+            _ => Err(format!(
+                "value {s:?} is not valid, use one of bash, zsh, fish or powershell"
+            )),
+        }
+    }
+}
+
+/// The script completes the command line by running Biome with `--bpaf-complete-rev`, so the
+/// completions are computed from the same definitions as the commands and the options.
+///
+/// The revision tells `bpaf` the format of the completions expected by the shell.
+const BASH_SCRIPT: &str = r#"_biome_completion()
+{
+    line="$1 --bpaf-complete-rev=8 ${COMP_WORDS[@]:1}"
+    if [[ ${COMP_WORDS[-1]} == "" ]]; then
+        line="${line} \"\""
+    fi
+    source <( eval ${line})
+}
+complete -o nosort -F _biome_completion biome"#;
+
+const ZSH_SCRIPT: &str = r#"#compdef biome
+local line
+line="${words[1]} --bpaf-complete-rev=7 ${words[@]:1}"
+if [[ ${words[-1]} == "" ]]; then
+    line="${line} \"\""
+fi
+source <(eval ${line})"#;
+
+const FISH_SCRIPT: &str = r#"function _biome_completion
+    set -l current (commandline --tokenize --current-process)
+    set -l tmpline --bpaf-complete-rev=9 $current[2..]
+    if test (commandline --current-process) != (string trim (commandline --current-process))
+        set tmpline $tmpline ""
+    end
+    eval $current[1] \"$tmpline\"
+end
+
+complete --no-files --command biome --arguments '(_biome_completion)'"#;
+
+/// PowerShell isn't supported by `bpaf`, so the script uses the plain format of the completions,
+/// which prints a completion and its description per line, separated by a tab.
+const POWERSHELL_SCRIPT: &str = r#"Register-ArgumentCompleter -Native -CommandName biome -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+    $arguments = @($commandAst.CommandElements | Select-Object -Skip 1 | ForEach-Object { $_.ToString() })
+    if ($wordToComplete -eq '') {
+        $arguments += '""'
+    }
+    & biome --bpaf-complete-rev=1 @arguments | ForEach-Object {
+        $completion, $description = $_ -split "`t", 2
+        if (-not $description) {
+            $description = $completion
+        }
+        [System.Management.Automation.CompletionResult]::new($completion, $completion, 'ParameterValue', $description)
+    }
+}"#;
+
+/// Prints the completion script of the `shell`
+pub(crate) fn completions(session: CliSession, shell: Shell) -> Result<(), CliDiagnostic> {
+    let script = match shell {
+        Shell::Bash => BASH_SCRIPT,
+        Shell::Zsh => ZSH_SCRIPT,
+        Shell::Fish => FISH_SCRIPT,
+        Shell::PowerShell => POWERSHELL_SCRIPT,
+    };
+    session.app.console.log(markup! {{script}});
+    Ok(())
+}
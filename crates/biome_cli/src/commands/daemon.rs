@@ -1,3 +1,5 @@
+use crate::commands::rage::WorkspaceRage;
+use crate::telemetry::{otlp_layer, SpanExporterHandle};
 use crate::{
     open_transport,
     service::{self, ensure_daemon, open_socket, run_daemon},
@@ -5,10 +7,14 @@ use crate::{
 };
 use biome_console::{markup, ConsoleExt};
 use biome_lsp::ServerFactory;
-use biome_service::{workspace::WorkspaceClient, TransportError, WorkspaceError};
+use biome_service::workspace::{client, WorkspaceClient};
+use biome_service::{TransportError, WorkspaceError};
+use std::ops::Deref;
+use std::time::Duration;
 use std::{env, fs, path::PathBuf};
 use tokio::io;
 use tokio::runtime::Runtime;
+use tokio::time;
 use tracing::subscriber::Interest;
 use tracing::{debug_span, metadata::LevelFilter, Instrument, Metadata};
 use tracing_appender::rolling::Rotation;
@@ -24,6 +30,7 @@ pub(crate) fn start(
     config_path: Option<PathBuf>,
     log_path: Option<PathBuf>,
     log_file_name_prefix: Option<String>,
+    otlp_endpoint: Option<String>,
 ) -> Result<(), CliDiagnostic> {
     let rt = Runtime::new()?;
     let did_spawn = rt.block_on(ensure_daemon(
@@ -31,6 +38,7 @@ pub(crate) fn start(
         config_path,
         log_path,
         log_file_name_prefix,
+        otlp_endpoint,
     ))?;
 
     if did_spawn {
@@ -47,43 +55,153 @@ pub(crate) fn start(
 }
 
 pub(crate) fn stop(session: CliSession) -> Result<(), CliDiagnostic> {
+    if shutdown_daemon()? {
+        session.app.console.log(markup! {
+            "The Biome server was successfully stopped"
+        });
+    } else {
+        session.app.console.log(markup! {
+            "The Biome server was not running"
+        });
+    }
+
+    Ok(())
+}
+
+/// Asks the daemon to shut down, and returns whether it was running
+fn shutdown_daemon() -> Result<bool, CliDiagnostic> {
+    let rt = Runtime::new()?;
+
+    let Some(transport) = open_transport(rt)? else {
+        return Ok(false);
+    };
+    let client = WorkspaceClient::new(transport)?;
+    match client.shutdown() {
+        // The `ChannelClosed` error is expected since the server can
+        // shutdown before sending a response
+        Ok(()) | Err(WorkspaceError::TransportError(TransportError::ChannelClosed)) => {}
+        Err(err) => return Err(CliDiagnostic::from(err)),
+    };
+
+    Ok(true)
+}
+
+/// Prints whether the daemon is running, along with the information that it reports about itself
+pub(crate) fn status(session: CliSession) -> Result<(), CliDiagnostic> {
     let rt = Runtime::new()?;
 
     if let Some(transport) = open_transport(rt)? {
-        let client = WorkspaceClient::new(transport)?;
-        match client.shutdown() {
-            // The `ChannelClosed` error is expected since the server can
-            // shutdown before sending a response
-            Ok(()) | Err(WorkspaceError::TransportError(TransportError::ChannelClosed)) => {}
-            Err(err) => return Err(CliDiagnostic::from(err)),
-        };
+        let client = client(transport)?;
+        session.app.console.log(markup! {
+            "The Biome server is running\n"
+            {WorkspaceRage(client.deref(), false)}
+        });
+    } else {
+        session.app.console.log(markup! {
+            "The Biome server is not running"
+        });
+    }
 
+    Ok(())
+}
+
+pub(crate) fn restart(
+    session: CliSession,
+    config_path: Option<PathBuf>,
+    log_path: Option<PathBuf>,
+    log_file_name_prefix: Option<String>,
+    otlp_endpoint: Option<String>,
+) -> Result<(), CliDiagnostic> {
+    let was_running = shutdown_daemon()?;
+
+    let rt = Runtime::new()?;
+    rt.block_on(async {
+        if was_running {
+            wait_for_shutdown().await?;
+        }
+        ensure_daemon(
+            false,
+            config_path,
+            log_path,
+            log_file_name_prefix,
+            otlp_endpoint,
+        )
+        .await
+    })?;
+
+    if was_running {
         session.app.console.log(markup! {
-            "The Biome server was successfully stopped"
+            "The Biome server was successfully restarted"
         });
     } else {
         session.app.console.log(markup! {
-            "The Biome server was not running"
+            "The Biome server was not running, it was successfully started"
         });
     }
 
     Ok(())
 }
 
+/// Waits for the daemon to stop accepting connections once it was asked to shut down,
+/// so that a new daemon can take its place.
+///
+/// It returns an error when the daemon still accepts connections after 5 seconds, because
+/// a new daemon would connect to the old one instead of replacing it.
+async fn wait_for_shutdown() -> io::Result<()> {
+    for _ in 0..50 {
+        if open_socket().await?.is_none() {
+            return Ok(());
+        }
+        time::sleep(Duration::from_millis(100)).await;
+    }
+    Err(io::Error::new(
+        io::ErrorKind::TimedOut,
+        "The Biome server is still running 5 seconds after it was asked to stop",
+    ))
+}
+
+/// Prints the last `lines` of the most recent log file of the daemon
+pub(crate) fn logs(
+    session: CliSession,
+    log_path: PathBuf,
+    log_file_name_prefix: String,
+    lines: usize,
+) -> Result<(), CliDiagnostic> {
+    match read_most_recent_log_file(Some(log_path), log_file_name_prefix)? {
+        Some(content) => {
+            let all_lines = content.lines().collect::<Vec<_>>();
+            let tail = all_lines[all_lines.len().saturating_sub(lines)..].join("\n");
+            session.app.console.log(markup! {{tail}});
+        }
+        None => {
+            session.app.console.log(markup! {
+                "No log file was found"
+            });
+        }
+    }
+
+    Ok(())
+}
+
 pub(crate) fn run_server(
     stop_on_disconnect: bool,
     config_path: Option<PathBuf>,
     log_path: Option<PathBuf>,
     log_file_name_prefix: Option<String>,
+    otlp_endpoint: Option<String>,
 ) -> Result<(), CliDiagnostic> {
-    setup_tracing_subscriber(log_path, log_file_name_prefix);
-
     let rt = Runtime::new()?;
+    // The exporter of the traces runs on the runtime of the server
+    let span_exporter = {
+        let _guard = rt.enter();
+        setup_tracing_subscriber(log_path, log_file_name_prefix, otlp_endpoint.as_deref())
+    };
+
     let factory = ServerFactory::new(stop_on_disconnect);
     let cancellation = factory.cancellation();
     let span = debug_span!("Running Server", pid = std::process::id());
 
-    rt.block_on(async move {
+    let result = rt.block_on(async move {
         tokio::select! {
             res = run_daemon(factory, config_path).instrument(span) => {
                 match res {
@@ -96,7 +214,12 @@ pub(crate) fn run_server(
                 Ok(())
             }
         }
-    })
+    });
+
+    if let Some(span_exporter) = span_exporter {
+        span_exporter.shutdown();
+    }
+    result
 }
 
 pub(crate) fn print_socket() -> Result<(), CliDiagnostic> {
@@ -130,7 +253,7 @@ async fn start_lsp_proxy(
     log_path: Option<PathBuf>,
     log_file_name_prefix: Option<String>,
 ) -> Result<(), CliDiagnostic> {
-    ensure_daemon(true, config_path, log_path, log_file_name_prefix).await?;
+    ensure_daemon(true, config_path, log_path, log_file_name_prefix, None).await?;
 
     match open_socket().await? {
         Some((mut owned_read_half, mut owned_write_half)) => {
@@ -205,7 +328,14 @@ pub(crate) fn read_most_recent_log_file(
 /// is written to log files rotated on a hourly basis (in
 /// `biome-logs/server.log.yyyy-MM-dd-HH` files inside the system temporary
 /// directory)
-fn setup_tracing_subscriber(log_path: Option<PathBuf>, log_file_name_prefix: Option<String>) {
+///
+/// When `otlp_endpoint` is set, the spans are also exported to the OpenTelemetry
+/// collector listening at this URL, and the exporter of the spans is returned
+fn setup_tracing_subscriber(
+    log_path: Option<PathBuf>,
+    log_file_name_prefix: Option<String>,
+    otlp_endpoint: Option<&str>,
+) -> Option<SpanExporterHandle> {
     let biome_log_path = log_path.unwrap_or(biome_fs::ensure_cache_dir().join("biome-logs"));
     let appender_builder = tracing_appender::rolling::RollingFileAppender::builder();
     let file_appender = appender_builder
@@ -215,7 +345,14 @@ fn setup_tracing_subscriber(log_path: Option<PathBuf>, log_file_name_prefix: Opt
         .build(biome_log_path)
         .expect("Failed to start the logger for the daemon.");
 
+    let (otlp_layer, span_exporter, otlp_error) = match otlp_layer(otlp_endpoint) {
+        Ok(Some((layer, span_exporter))) => (Some(layer), Some(span_exporter), None),
+        Ok(None) => (None, None, None),
+        Err(error) => (None, None, Some(error)),
+    };
+
     registry()
+        .with(otlp_layer)
         .with(
             HierarchicalLayer::default()
                 .with_indent_lines(true)
@@ -227,6 +364,11 @@ fn setup_tracing_subscriber(log_path: Option<PathBuf>, log_file_name_prefix: Opt
                 .with_filter(LoggingFilter),
         )
         .init();
+
+    if let Some(error) = otlp_error {
+        tracing::warn!("Failed to export the traces to the OpenTelemetry collector: {error}");
+    }
+    span_exporter
 }
 
 pub fn default_biome_log_path() -> PathBuf {
@@ -1,7 +1,13 @@
-use biome_analyze::{FixKind, RuleMetadata};
+use biome_analyze::{FixKind, RuleMetadata, RuleSourceKind};
+use biome_configuration::PartialConfiguration;
+use biome_console::fmt::{Display, Formatter};
 use biome_console::{markup, ConsoleExt};
 use biome_flags::biome_env;
 use biome_service::documentation::Doc;
+use schemars::schema_for;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::io;
 
 use crate::commands::daemon::default_biome_log_path;
 use crate::{CliDiagnostic, CliSession};
@@ -24,26 +30,73 @@ fn print_rule(session: CliSession, metadata: &RuleMetadata) {
         }
     }
 
-    let docs = metadata
-        .docs
-        .lines()
-        .map(|line| line.trim_start())
-        .collect::<Vec<_>>()
-        .join("\n");
+    if let Some(reason) = metadata.deprecated {
+        session.app.console.log(markup! {
+            <Warn>"This rule is deprecated: "{reason}</Warn>"\n"
+        });
+    }
 
     session.app.console.log(markup! {
         "This rule is "{if metadata.recommended {"recommended."} else {"not recommended."}}
         "\n\n"
+        {RuleSources(metadata)}
         "# Description\n"
-        {docs}
+        {RuleDocs(&docs_to_markdown(metadata.docs))}
     });
+
+    if let Some(schema) = rule_options_schema(metadata) {
+        let schema = serde_json::to_string_pretty(&schema).unwrap_or_default();
+        session.app.console.log(markup! {
+            "\n"<Emphasis>"# Options schema"</Emphasis>"\n"
+            "```json\n"
+            {schema}"\n"
+            "```"
+        });
+    }
+}
+
+/// Prints the documentation of the rule as a JSON object, so that editors can display it.
+fn print_rule_json(session: CliSession, metadata: &RuleMetadata) -> Result<(), CliDiagnostic> {
+    let explanation = RuleExplanation {
+        name: metadata.name,
+        language: metadata.language,
+        version: metadata.version,
+        recommended: metadata.recommended,
+        deprecated: metadata.deprecated,
+        fix_kind: match metadata.fix_kind {
+            FixKind::None => "none",
+            FixKind::Safe => "safe",
+            FixKind::Unsafe => "unsafe",
+        },
+        sources: metadata
+            .sources
+            .iter()
+            .map(|source| RuleSourceExplanation {
+                plugin: source.to_string(),
+                name: source.to_namespaced_rule_name(),
+                url: source.to_rule_url(),
+            })
+            .collect(),
+        docs: docs_to_markdown(metadata.docs),
+        options_schema: rule_options_schema(metadata),
+    };
+    let json = serde_json::to_string(&explanation).map_err(|error| {
+        CliDiagnostic::io_error(io::Error::new(io::ErrorKind::InvalidData, error))
+    })?;
+    session.app.console.log(markup! {{json}});
+
+    Ok(())
 }
 
-pub(crate) fn explain(session: CliSession, doc: Doc) -> Result<(), CliDiagnostic> {
+pub(crate) fn explain(session: CliSession, doc: Doc, json: bool) -> Result<(), CliDiagnostic> {
     match doc {
         Doc::Rule(metadata) => {
-            print_rule(session, &metadata);
-            Ok(())
+            if json {
+                print_rule_json(session, &metadata)
+            } else {
+                print_rule(session, &metadata);
+                Ok(())
+            }
         }
         Doc::DaemonLogs => {
             let cache_dir = biome_env()
@@ -59,3 +112,174 @@ pub(crate) fn explain(session: CliSession, doc: Doc) -> Result<(), CliDiagnostic
         Doc::Unknown(arg) => Err(CliDiagnostic::unexpected_argument(arg, "explain")),
     }
 }
+
+/// Returns the JSON schema of the options of the rule, along with the definitions that it
+/// references, or `None` when the rule doesn't have options.
+///
+/// The schema is taken from the schema of the configuration, where the options of the rule
+/// are a property of the configuration of the rule, in the group of the rule.
+fn rule_options_schema(metadata: &RuleMetadata) -> Option<Value> {
+    let schema = serde_json::to_value(schema_for!(PartialConfiguration)).ok()?;
+    let definitions = schema.get("definitions")?.as_object()?;
+    // The groups are the definitions that have a `recommended` property
+    let rule = definitions.values().find_map(|definition| {
+        let properties = definition.get("properties")?;
+        properties.get("recommended")?;
+        properties.get(metadata.name)
+    })?;
+    // The configuration of a rule is either its level, or an object with its level and options
+    let options = referenced_definitions(rule, definitions)
+        .flat_map(|configuration| referenced_definitions(configuration, definitions))
+        .find_map(|configuration| configuration.get("properties")?.get("options"))?;
+    let options = referenced_definitions(options, definitions).next()?;
+
+    let mut referenced = Map::new();
+    collect_definitions(options, definitions, &mut referenced);
+    let mut options = options.clone();
+    if !referenced.is_empty() {
+        options["definitions"] = Value::Object(referenced);
+    }
+    Some(options)
+}
+
+/// Returns the definitions that are referenced by the `anyOf` or `allOf` of `schema`
+fn referenced_definitions<'a>(
+    schema: &'a Value,
+    definitions: &'a Map<String, Value>,
+) -> impl Iterator<Item = &'a Value> + 'a {
+    ["anyOf", "allOf"]
+        .into_iter()
+        .filter_map(|key| schema.get(key)?.as_array())
+        .flatten()
+        .filter_map(|schema| definitions.get(definition_name(schema.get("$ref")?)?))
+}
+
+/// Collects the definitions that are referenced by `schema`, and the ones they reference in turn
+fn collect_definitions(
+    schema: &Value,
+    definitions: &Map<String, Value>,
+    referenced: &mut Map<String, Value>,
+) {
+    match schema {
+        Value::Object(object) => {
+            for (key, value) in object {
+                if key != "$ref" {
+                    collect_definitions(value, definitions, referenced);
+                    continue;
+                }
+                let Some(name) = definition_name(value) else {
+                    continue;
+                };
+                if referenced.contains_key(name) {
+                    continue;
+                }
+                if let Some(definition) = definitions.get(name) {
+                    referenced.insert(name.to_string(), definition.clone());
+                    collect_definitions(definition, definitions, referenced);
+                }
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                collect_definitions(value, definitions, referenced);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn definition_name(reference: &Value) -> Option<&str> {
+    reference.as_str()?.strip_prefix("#/definitions/")
+}
+
+/// Converts the documentation comments of a rule to Markdown.
+///
+/// The space that follows `///` is removed, along with the attributes of the code blocks
+/// that are only used to test the examples, such as `expect_diagnostic`.
+fn docs_to_markdown(docs: &str) -> String {
+    docs.lines()
+        .map(|line| {
+            let line = line.strip_prefix(' ').unwrap_or(line);
+            match line.strip_prefix("```") {
+                Some(info) => {
+                    let language = info.split(',').next().unwrap_or_default();
+                    format!("```{language}")
+                }
+                None => line.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Prints the Markdown documentation of a rule, with its headings emphasized.
+struct RuleDocs<'a>(&'a str);
+
+impl Display for RuleDocs<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let mut is_code_block = false;
+        for (index, line) in self.0.lines().enumerate() {
+            if index > 0 {
+                fmt.write_str("\n")?;
+            }
+            if line.starts_with("```") {
+                is_code_block = !is_code_block;
+            }
+            if !is_code_block && line.starts_with('#') {
+                fmt.write_markup(markup! {<Emphasis>{line}</Emphasis>})?;
+            } else {
+                fmt.write_str(line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Prints the rules of other linters that a rule is based on, if any.
+struct RuleSources<'a>(&'a RuleMetadata);
+
+impl Display for RuleSources<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        if self.0.sources.is_empty() {
+            return Ok(());
+        }
+        let relation = match self.0.source_kind.unwrap_or_default() {
+            RuleSourceKind::SameLogic => "Same as",
+            RuleSourceKind::Inspired => "Inspired from",
+        };
+
+        fmt.write_str("# Sources\n")?;
+        for source in self.0.sources {
+            let plugin = source.to_string();
+            let name = source.to_namespaced_rule_name();
+            let url = source.to_rule_url();
+            fmt.write_markup(markup! {
+                "- "{relation}" "{plugin}" rule "<Emphasis>{name}</Emphasis>": "{url}"\n"
+            })?;
+        }
+        fmt.write_str("\n")
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RuleExplanation<'a> {
+    name: &'a str,
+    language: &'a str,
+    version: &'a str,
+    recommended: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deprecated: Option<&'a str>,
+    fix_kind: &'a str,
+    sources: Vec<RuleSourceExplanation>,
+    docs: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options_schema: Option<Value>,
+}
+
+#[derive(Serialize)]
+struct RuleSourceExplanation {
+    plugin: String,
+    name: String,
+    url: String,
+}
@@ -1,6 +1,7 @@
-use crate::cli_options::CliOptions;
+use crate::cli_options::{CacheOptions, CliOptions};
 use crate::commands::{get_files_to_process_with_cli_options, CommandRunner, LoadEditorConfig};
 use crate::diagnostics::DeprecatedArgument;
+use crate::execute::FormatRange;
 use crate::{CliDiagnostic, Execution, TraversalMode};
 use biome_configuration::vcs::PartialVcsConfiguration;
 use biome_configuration::{
@@ -25,12 +26,15 @@ pub(crate) struct FormatCommandPayload {
     pub(crate) vcs_configuration: Option<PartialVcsConfiguration>,
     pub(crate) files_configuration: Option<PartialFilesConfiguration>,
     pub(crate) stdin_file_path: Option<String>,
+    pub(crate) stdin_files: bool,
+    pub(crate) range: Option<FormatRange>,
     pub(crate) write: bool,
     pub(crate) fix: bool,
     pub(crate) paths: Vec<OsString>,
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) cache_options: CacheOptions,
 }
 
 impl LoadEditorConfig for FormatCommandPayload {
@@ -186,6 +190,10 @@ impl CommandRunner for FormatCommandPayload {
         self.stdin_file_path.as_deref()
     }
 
+    fn should_read_stdin_files(&self) -> bool {
+        self.stdin_files
+    }
+
     fn should_write(&self) -> bool {
         self.write || self.fix
     }
@@ -196,12 +204,31 @@ impl CommandRunner for FormatCommandPayload {
         console: &mut dyn Console,
         _workspace: &dyn Workspace,
     ) -> Result<Execution, CliDiagnostic> {
+        if self.range.is_some() {
+            if self.stdin_files {
+                return Err(CliDiagnostic::incompatible_arguments(
+                    "range",
+                    "stdin-files",
+                ));
+            }
+            if self.stdin_file_path.is_none() {
+                return Err(CliDiagnostic::missing_argument(
+                    "stdin-file-path",
+                    Self::COMMAND_NAME,
+                ));
+            }
+        }
         Ok(Execution::new(TraversalMode::Format {
             ignore_errors: cli_options.skip_errors,
             write: self.should_write(),
             stdin: self.get_stdin(console)?,
+            range: self.range,
             vcs_targeted: (self.staged, self.changed).into(),
         })
         .set_report(cli_options))
     }
+
+    fn get_cache_options(&self) -> Option<&CacheOptions> {
+        Some(&self.cache_options)
+    }
 }
@@ -1,12 +1,43 @@
 use crate::{CliDiagnostic, CliSession};
-use biome_configuration::PartialConfiguration;
-use biome_console::{markup, ConsoleExt};
+use biome_configuration::analyzer::linter::{Correctness, Nursery};
+use biome_configuration::css::PartialCssLinter;
+use biome_configuration::{
+    BiomeDiagnostic, PartialConfiguration, PartialCssConfiguration, PartialCssFormatter,
+    PartialGraphqlConfiguration, PartialGraphqlFormatter, PartialGraphqlLinter,
+    PartialJavascriptConfiguration, PartialJavascriptFormatter, RuleConfiguration,
+    RulePlainConfiguration,
+};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_formatter::{IndentStyle, IndentWidth, QuoteStyle};
 use biome_fs::ConfigName;
 use biome_service::configuration::create_config;
+use biome_service::WorkspaceError;
+use std::path::Path;
 
-pub(crate) fn init(mut session: CliSession, emit_jsonc: bool) -> Result<(), CliDiagnostic> {
+/// The languages that the interactive mode asks about
+const LANGUAGES: &[&str] = &["javascript", "typescript", "json", "css", "graphql"];
+
+/// The frameworks whose rules can be enabled by the interactive mode
+const FRAMEWORKS: &[&str] = &["react", "next", "none"];
+
+pub(crate) fn init(
+    mut session: CliSession,
+    emit_jsonc: bool,
+    interactive: bool,
+) -> Result<(), CliDiagnostic> {
     let fs = &mut session.app.fs;
-    create_config(fs, PartialConfiguration::init(), emit_jsonc)?;
+    let configuration = if interactive {
+        // There's no point in asking the questions if the configuration can't be created
+        if fs.path_exists(Path::new(ConfigName::biome_json()))
+            || fs.path_exists(Path::new(ConfigName::biome_jsonc()))
+        {
+            return Err(WorkspaceError::from(BiomeDiagnostic::new_already_exists()).into());
+        }
+        ask_configuration(session.app.console)
+    } else {
+        PartialConfiguration::init()
+    };
+    create_config(fs, configuration, emit_jsonc)?;
     let file_created = if emit_jsonc {
         ConfigName::biome_jsonc()
     } else {
@@ -45,3 +76,226 @@ Welcome to Biome! Let's get you started...
     });
     Ok(())
 }
+
+/// Asks the questions of the interactive mode, and returns the configuration tailored to
+/// the answers. The configuration is the default one when all the answers are empty.
+fn ask_configuration(console: &mut dyn Console) -> PartialConfiguration {
+    let mut configuration = PartialConfiguration::init();
+
+    let languages = ask_many(
+        console,
+        "Which languages does your project use?",
+        LANGUAGES,
+        "javascript, typescript, json",
+    );
+    let uses_javascript = languages
+        .iter()
+        .any(|language| matches!(language.as_str(), "javascript" | "typescript"));
+    if languages.iter().any(|language| language == "css") {
+        configuration.css = Some(PartialCssConfiguration {
+            formatter: Some(PartialCssFormatter {
+                enabled: Some(true),
+                ..Default::default()
+            }),
+            linter: Some(PartialCssLinter {
+                enabled: Some(true),
+            }),
+            ..Default::default()
+        });
+    }
+    if languages.iter().any(|language| language == "graphql") {
+        configuration.graphql = Some(PartialGraphqlConfiguration {
+            formatter: Some(PartialGraphqlFormatter {
+                enabled: Some(true),
+                ..Default::default()
+            }),
+            linter: Some(PartialGraphqlLinter {
+                enabled: Some(true),
+                ..Default::default()
+            }),
+        });
+    }
+
+    if uses_javascript {
+        let frameworks = ask_many(
+            console,
+            "Which frameworks does your project use?",
+            FRAMEWORKS,
+            "none",
+        );
+        let uses_next = frameworks.iter().any(|framework| framework == "next");
+        let uses_react = uses_next || frameworks.iter().any(|framework| framework == "react");
+        if let Some(rules) = configuration
+            .linter
+            .as_mut()
+            .and_then(|linter| linter.rules.as_mut())
+        {
+            if uses_react {
+                rules.correctness = Some(Correctness {
+                    use_exhaustive_dependencies: Some(error()),
+                    use_hook_at_top_level: Some(error()),
+                    use_jsx_key_in_iterable: Some(error()),
+                    ..Default::default()
+                });
+            }
+            if uses_next {
+                rules.nursery = Some(Nursery {
+                    no_document_import_in_page: Some(error()),
+                    no_head_element: Some(error()),
+                    no_head_import_in_document: Some(error()),
+                    no_img_element: Some(error()),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    let indent_style = ask_one(
+        console,
+        "Do you indent your code with tabs or spaces?",
+        &["tab", "space"],
+        "tab",
+    );
+    if let Some(formatter) = configuration.formatter.as_mut() {
+        if indent_style == "space" {
+            formatter.indent_style = Some(IndentStyle::Space);
+            formatter.indent_width = Some(ask_indent_width(console));
+        } else {
+            formatter.indent_style = Some(IndentStyle::Tab);
+        }
+    }
+
+    if uses_javascript {
+        let quote_style = ask_one(
+            console,
+            "Which quotes do you use for the strings of your JavaScript code?",
+            &["double", "single"],
+            "double",
+        );
+        configuration.javascript = Some(PartialJavascriptConfiguration {
+            formatter: Some(PartialJavascriptFormatter {
+                quote_style: Some(if quote_style == "single" {
+                    QuoteStyle::Single
+                } else {
+                    QuoteStyle::Double
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+    }
+
+    let use_vcs = ask_one(
+        console,
+        "Does your project use Git? Biome can then ignore the files of your .gitignore.",
+        &["yes", "no"],
+        "yes",
+    );
+    if use_vcs == "yes" {
+        if let Some(vcs) = configuration.vcs.as_mut() {
+            vcs.enabled = Some(true);
+            vcs.use_ignore_file = Some(true);
+        }
+    }
+
+    configuration
+}
+
+/// Asks the `question` until the answer is one of the `choices`, and returns it.
+///
+/// It returns the `default` choice when the answer is empty, or when there's nothing left to read.
+fn ask_one(console: &mut dyn Console, question: &str, choices: &[&str], default: &str) -> String {
+    loop {
+        let Some(answer) = ask(console, question, choices, default) else {
+            return default.to_string();
+        };
+        if choices.contains(&answer.as_str()) {
+            return answer;
+        }
+        print_invalid_answer(console, &answer);
+    }
+}
+
+/// Asks the `question` until the answer is a list of the `choices`, separated by commas,
+/// and returns them.
+///
+/// It returns the `default` choices when the answer is empty, or when there's nothing left to read.
+fn ask_many(
+    console: &mut dyn Console,
+    question: &str,
+    choices: &[&str],
+    default: &str,
+) -> Vec<String> {
+    loop {
+        let answer =
+            ask(console, question, choices, default).unwrap_or_else(|| default.to_string());
+        let answers: Vec<String> = answer
+            .split(',')
+            .map(|choice| choice.trim().to_string())
+            .filter(|choice| !choice.is_empty())
+            .collect();
+        match answers
+            .iter()
+            .find(|choice| !choices.contains(&choice.as_str()))
+        {
+            Some(invalid) => print_invalid_answer(console, invalid),
+            None => return answers,
+        }
+    }
+}
+
+/// Asks the number of spaces of an indentation level, until the answer is a valid one.
+fn ask_indent_width(console: &mut dyn Console) -> IndentWidth {
+    loop {
+        let Some(answer) = ask(
+            console,
+            "How many spaces make an indentation level?",
+            &[],
+            "2",
+        ) else {
+            return IndentWidth::default();
+        };
+        match answer.parse::<IndentWidth>() {
+            Ok(indent_width) => return indent_width,
+            Err(_) => print_invalid_answer(console, &answer),
+        }
+    }
+}
+
+/// Prints the `question` with its `choices`, and returns the answer in lowercase, or the
+/// `default` one if the answer is empty.
+///
+/// It returns [None] when there's nothing left to read.
+fn ask(
+    console: &mut dyn Console,
+    question: &str,
+    choices: &[&str],
+    default: &str,
+) -> Option<String> {
+    if choices.is_empty() {
+        console.append(markup! {
+            <Emphasis>{question}</Emphasis>" "<Dim>"(default: "{default}")"</Dim>" "
+        });
+    } else {
+        let choices = choices.join(", ");
+        console.append(markup! {
+            <Emphasis>{question}</Emphasis>" "<Dim>"["{choices}"] (default: "{default}")"</Dim>" "
+        });
+    }
+    let answer = console.read_line()?.trim().to_lowercase();
+    Some(if answer.is_empty() {
+        default.to_string()
+    } else {
+        answer
+    })
+}
+
+fn print_invalid_answer(console: &mut dyn Console, answer: &str) {
+    console.error(markup! {
+        <Warn>"The answer "<Emphasis>{answer}</Emphasis>" isn't valid, please try again."</Warn>
+    });
+}
+
+fn error<T: Default>() -> RuleConfiguration<T> {
+    RuleConfiguration::Plain(RulePlainConfiguration::Error)
+}
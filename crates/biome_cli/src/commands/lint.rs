@@ -1,5 +1,5 @@
 use super::{determine_fix_file_mode, FixFileModeOptions};
-use crate::cli_options::CliOptions;
+use crate::cli_options::{CacheOptions, CliOptions};
 use crate::commands::{get_files_to_process_with_cli_options, CommandRunner};
 use crate::{CliDiagnostic, Execution, TraversalMode};
 use biome_configuration::analyzer::RuleSelector;
@@ -15,6 +15,7 @@ use biome_console::Console;
 use biome_deserialize::Merge;
 use biome_fs::FileSystem;
 use biome_service::configuration::LoadedConfiguration;
+use biome_service::workspace::FixFileMode;
 use biome_service::{DynRef, Workspace, WorkspaceError};
 use std::ffi::OsString;
 
@@ -26,6 +27,7 @@ pub(crate) struct LintCommandPayload {
     pub(crate) unsafe_: bool,
     pub(crate) suppress: bool,
     pub(crate) suppression_reason: Option<String>,
+    pub(crate) fix_suggested: bool,
     pub(crate) linter_configuration: Option<PartialLinterConfiguration>,
     pub(crate) vcs_configuration: Option<PartialVcsConfiguration>,
     pub(crate) files_configuration: Option<PartialFilesConfiguration>,
@@ -33,9 +35,11 @@ pub(crate) struct LintCommandPayload {
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
     pub(crate) stdin_file_path: Option<String>,
+    pub(crate) stdin_files: bool,
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) cache_options: CacheOptions,
     pub(crate) javascript_linter: Option<PartialJavascriptLinter>,
     pub(crate) json_linter: Option<PartialJsonLinter>,
     pub(crate) css_linter: Option<PartialCssLinter>,
@@ -121,8 +125,12 @@ impl CommandRunner for LintCommandPayload {
         self.stdin_file_path.as_deref()
     }
 
+    fn should_read_stdin_files(&self) -> bool {
+        self.stdin_files
+    }
+
     fn should_write(&self) -> bool {
-        self.write || self.fix
+        self.write || self.fix || self.fix_suggested
     }
 
     fn get_execution(
@@ -143,6 +151,12 @@ impl CommandRunner for LintCommandPayload {
             },
             console,
         )?;
+        // The rules are selected by the user, so all their fixes can be applied
+        let fix_file_mode = if self.fix_suggested {
+            Some(FixFileMode::SafeAndUnsafeFixes)
+        } else {
+            fix_file_mode
+        };
         Ok(Execution::new(TraversalMode::Lint {
             fix_file_mode,
             stdin: self.get_stdin(console)?,
@@ -154,4 +168,23 @@ impl CommandRunner for LintCommandPayload {
         })
         .set_report(cli_options))
     }
+
+    fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        if self.fix_suggested {
+            if self.only.is_empty() {
+                return Err(CliDiagnostic::missing_argument("--rule", "lint"));
+            }
+            if self.suppress {
+                return Err(CliDiagnostic::incompatible_arguments(
+                    "--fix-suggested",
+                    "--suppress",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn get_cache_options(&self) -> Option<&CacheOptions> {
+        Some(&self.cache_options)
+    }
 }
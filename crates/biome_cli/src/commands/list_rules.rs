@@ -0,0 +1,154 @@
+use crate::commands::validate_configuration_diagnostics;
+use crate::{CliDiagnostic, CliSession};
+use biome_analyze::{FixKind, RuleFilter};
+use biome_configuration::analyzer::RuleGroup;
+use biome_configuration::ConfigurationPathHint;
+use biome_console::{markup, ConsoleExt};
+use biome_service::configuration::load_configuration;
+use biome_service::documentation::lint_rules_metadata;
+use serde::Serialize;
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// The kind of fix of the rules listed with `--fix-kind`
+#[derive(Debug, Clone, Copy)]
+pub struct FixKindArg(FixKind);
+
+impl FromStr for FixKindArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self(FixKind::None)),
+            "safe" => Ok(Self(FixKind::Safe)),
+            "unsafe" => Ok(Self(FixKind::Unsafe)),
+            _ => Err(format!(
+                "value {s:?} is not valid for the --fix-kind argument"
+            )),
+        }
+    }
+}
+
+pub(crate) struct ListRulesCommandPayload {
+    pub(crate) language: Option<String>,
+    pub(crate) group: Option<RuleGroup>,
+    pub(crate) fix_kind: Option<FixKindArg>,
+    pub(crate) enabled: bool,
+    pub(crate) json: bool,
+    pub(crate) config_path: Option<PathBuf>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ListedRule {
+    name: &'static str,
+    group: &'static str,
+    language: &'static str,
+    recommended: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deprecated: Option<&'static str>,
+    fix_kind: &'static str,
+    enabled: bool,
+}
+
+/// Prints the lint rules that match the filters of the `payload`, sorted by group and by name.
+pub(crate) fn list_rules(
+    session: CliSession,
+    payload: ListRulesCommandPayload,
+) -> Result<(), CliDiagnostic> {
+    let ListRulesCommandPayload {
+        language,
+        group,
+        fix_kind,
+        enabled,
+        json,
+        config_path,
+    } = payload;
+
+    let configuration_path_hint = match config_path {
+        None => ConfigurationPathHint::default(),
+        Some(path) => ConfigurationPathHint::FromUser(path),
+    };
+    let loaded_configuration = load_configuration(&session.app.fs, configuration_path_hint)?;
+    validate_configuration_diagnostics(&loaded_configuration, session.app.console, false)?;
+    let enabled_rules = loaded_configuration
+        .configuration
+        .linter
+        .map(|linter| linter.get_rules())
+        .unwrap_or_default()
+        .as_enabled_rules();
+
+    let mut metadata_by_name = lint_rules_metadata();
+    let groups = match group {
+        Some(group) => vec![group],
+        None => RuleGroup::ALL.to_vec(),
+    };
+    let mut rules = Vec::new();
+    for group in groups {
+        let group_name = group.as_str();
+        for rule_name in group.rule_names() {
+            let Some(rules_metadata) = metadata_by_name.remove(rule_name) else {
+                continue;
+            };
+            let is_enabled = enabled_rules.contains(&RuleFilter::Rule(group_name, *rule_name));
+            for metadata in rules_metadata {
+                if language
+                    .as_ref()
+                    .is_some_and(|language| language.as_str() != metadata.language)
+                    || fix_kind.is_some_and(|FixKindArg(fix_kind)| fix_kind != metadata.fix_kind)
+                    || (enabled && !is_enabled)
+                {
+                    continue;
+                }
+                rules.push(ListedRule {
+                    name: metadata.name,
+                    group: group_name,
+                    language: metadata.language,
+                    recommended: metadata.recommended,
+                    deprecated: metadata.deprecated,
+                    fix_kind: match metadata.fix_kind {
+                        FixKind::None => "none",
+                        FixKind::Safe => "safe",
+                        FixKind::Unsafe => "unsafe",
+                    },
+                    enabled: is_enabled,
+                });
+            }
+        }
+    }
+
+    if json {
+        let json = serde_json::to_string(&rules).map_err(|error| {
+            CliDiagnostic::io_error(io::Error::new(io::ErrorKind::InvalidData, error))
+        })?;
+        session.app.console.log(markup! {{json}});
+        return Ok(());
+    }
+
+    for rule in &rules {
+        let mut details = vec![rule.language];
+        if rule.recommended {
+            details.push("recommended");
+        }
+        match rule.fix_kind {
+            "safe" => details.push("safe fix"),
+            "unsafe" => details.push("unsafe fix"),
+            _ => {}
+        }
+        if rule.deprecated.is_some() {
+            details.push("deprecated");
+        }
+        details.push(if rule.enabled { "enabled" } else { "disabled" });
+        let details = details.join(", ");
+        session.app.console.log(markup! {
+            <Emphasis>{rule.group}"/"{rule.name}</Emphasis>" "<Dim>"("{details}")"</Dim>
+        });
+    }
+    let count = rules.len();
+    session.app.console.log(markup! {
+        <Info>"Found "{count}" rules."</Info>
+    });
+
+    Ok(())
+}
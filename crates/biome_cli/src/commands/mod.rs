@@ -1,12 +1,18 @@
 use crate::changed::{get_changed_files, get_staged_files};
-use crate::cli_options::{cli_options, CliOptions, CliReporter, ColorsArg};
+use crate::cli_options::{
+    cache_options, cli_options, complete_rule_selectors, CacheOptions, CliOptions, CliReporter,
+    ColorsArg, RuleSelectors,
+};
+use crate::commands::completions::Shell;
+use crate::commands::list_rules::FixKindArg;
 use crate::diagnostics::{DeprecatedArgument, DeprecatedConfigurationFile};
-use crate::execute::Stdin;
+use crate::execute::cache::{cache_key, ResultCache, DEFAULT_CACHE_LOCATION};
+use crate::execute::{watch_mode, FormatRange, Stdin, StdinFile};
 use crate::logging::LoggingKind;
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, LoggingLevel, VERSION,
 };
-use biome_configuration::analyzer::RuleSelector;
+use biome_configuration::analyzer::{RuleGroup, RuleSelector};
 use biome_configuration::css::PartialCssLinter;
 use biome_configuration::javascript::PartialJavascriptLinter;
 use biome_configuration::json::PartialJsonLinter;
@@ -22,26 +28,34 @@ use biome_configuration::{
 };
 use biome_configuration::{BiomeDiagnostic, PartialConfiguration};
 use biome_console::{markup, Console, ConsoleExt};
-use biome_diagnostics::{Diagnostic, PrintDiagnostic};
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize::Merge;
+use biome_diagnostics::{Diagnostic, PrintDiagnostic, Severity};
+use biome_flags::biome_env;
 use biome_fs::{BiomePath, FileSystem};
+use biome_json_parser::JsonParserOptions;
 use biome_service::configuration::{
     load_configuration, load_editorconfig, LoadedConfiguration, PartialConfigurationExt,
 };
 use biome_service::documentation::Doc;
+use biome_service::settings::to_matcher;
 use biome_service::workspace::{FixFileMode, RegisterProjectFolderParams, UpdateSettingsParams};
 use biome_service::{DynRef, Workspace, WorkspaceError};
 use bpaf::Bpaf;
 use std::ffi::OsString;
+use std::io;
 use std::path::PathBuf;
 
 pub(crate) mod check;
 pub(crate) mod ci;
 pub(crate) mod clean;
+pub(crate) mod completions;
 pub(crate) mod daemon;
 pub(crate) mod explain;
 pub(crate) mod format;
 pub(crate) mod init;
 pub(crate) mod lint;
+pub(crate) mod list_rules;
 pub(crate) mod migrate;
 pub(crate) mod rage;
 pub(crate) mod search;
@@ -68,6 +82,11 @@ pub enum BiomeCommand {
         /// Prints the linter options applied
         #[bpaf(long("linter"), switch)]
         bool,
+        /// Prints the files of the project by language, the files that are ignored along with the
+        /// pattern that ignores them, the configuration files that apply, and the sizes of the caches
+        /// of the workspace
+        #[bpaf(long("scan"), switch)]
+        bool,
     ),
     /// Starts the Biome daemon server process.
     #[bpaf(command)]
@@ -96,12 +115,20 @@ pub enum BiomeCommand {
         /// or a custom directory path to find `biome.json` or `biome.jsonc`
         #[bpaf(env("BIOME_CONFIG_PATH"), long("config-path"), argument("PATH"))]
         config_path: Option<PathBuf>,
+        /// Exports the traces of the daemon to the OpenTelemetry collector listening at this
+        /// URL, with the OTLP protocol over gRPC. The traces aren't exported when it's not set.
+        #[bpaf(env("BIOME_OTLP_ENDPOINT"), long("otlp-endpoint"), argument("URL"))]
+        otlp_endpoint: Option<String>,
     },
 
     /// Stops the Biome daemon server process.
     #[bpaf(command)]
     Stop,
 
+    /// Manages the Biome daemon server process, and prints its status and its logs.
+    #[bpaf(command)]
+    Daemon(#[bpaf(external(daemon_sub_command))] DaemonSubCommand),
+
     /// Runs formatter, linter and import sorting to the requested files.
     #[bpaf(command)]
     Check {
@@ -161,6 +188,14 @@ pub enum BiomeCommand {
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
 
+        /// Use this option when you want to handle several files piped from `stdin`, and print the outputs to `stdout`.
+        ///
+        /// The files are read as a JSON array of objects with the `path` and the `content` of each file, and the outputs are printed in the same format. The files don't need to exist on disk.
+        ///
+        /// Example: `echo '[{"path": "file.js", "content": "let a;"}]' | biome check --stdin-files`
+        #[bpaf(long("stdin-files"), switch, hide_usage)]
+        stdin_files: bool,
+
         /// When set to true, only the files that have been staged (the ones prepared to be committed)
         /// will be linted. This option should be used when working locally.
         #[bpaf(long("staged"), switch)]
@@ -176,6 +211,28 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Keeps running once the files are checked, and checks the files again when they change.
+        /// Only the files that changed are checked again.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
+        /// Writes the graph of the imports between the scripts to the file, once they are checked.
+        /// The graph is written in the DOT format when the extension of the file is `.dot`,
+        /// and in JSON otherwise.
+        #[bpaf(long("emit-module-graph"), argument("PATH"))]
+        emit_module_graph: Option<PathBuf>,
+
+        /// Only writes the scripts imported by the given script, directly or not, to the module graph.
+        #[bpaf(long("module-graph-from"), argument("PATH"), hide_usage)]
+        module_graph_from: Option<PathBuf>,
+
+        /// Only writes the import cycles to the module graph.
+        #[bpaf(long("module-graph-cycles"), switch, hide_usage)]
+        module_graph_cycles: bool,
+
+        #[bpaf(external(cache_options), hide_usage)]
+        cache_options: CacheOptions,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -211,6 +268,13 @@ pub enum BiomeCommand {
         #[bpaf(long("reason"), argument("STRING"))]
         suppression_reason: Option<String>,
 
+        /// Writes the safe and unsafe fixes of the rules selected with `--rule` or `--only`.
+        /// The rules must be selected, so that the unsafe fixes of all the rules can't be applied by mistake.
+        ///
+        /// Example: `biome lint --rule=style/useConst --fix-suggested`
+        #[bpaf(long("fix-suggested"), switch)]
+        fix_suggested: bool,
+
         #[bpaf(external(partial_linter_configuration), hide_usage, optional)]
         linter_configuration: Option<PartialLinterConfiguration>,
 
@@ -243,6 +307,19 @@ pub enum BiomeCommand {
         #[bpaf(long("only"), argument("GROUP|RULE"))]
         only: Vec<RuleSelector>,
 
+        /// Run only the given rules or groups of rules, separated by commas.
+        /// It accepts the same values as `--only`, and can be combined with it.
+        ///
+        /// Example: `biome lint --rule=suspicious/noDebugger,performance/noDelete`
+        #[bpaf(
+            long("rule"),
+            argument::<String>("GROUP|RULE,..."),
+            complete(complete_rule_selectors),
+            parse(|value: String| value.parse::<RuleSelectors>()),
+            many
+        )]
+        rule: Vec<RuleSelectors>,
+
         /// Skip the given rule or group of rules by setting the severity level of the rules to `off`.
         /// This option takes precedence over `--only`.
         ///
@@ -257,6 +334,13 @@ pub enum BiomeCommand {
         /// Example: `echo 'let a;' | biome lint --stdin-file-path=file.js`
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
+        /// Use this option when you want to handle several files piped from `stdin`, and print the outputs to `stdout`.
+        ///
+        /// The files are read as a JSON array of objects with the `path` and the `content` of each file, and the outputs are printed in the same format. The files don't need to exist on disk.
+        ///
+        /// Example: `echo '[{"path": "file.js", "content": "let a;"}]' | biome lint --stdin-files`
+        #[bpaf(long("stdin-files"), switch, hide_usage)]
+        stdin_files: bool,
         /// When set to true, only the files that have been staged (the ones prepared to be committed)
         /// will be linted.
         #[bpaf(long("staged"), switch)]
@@ -269,6 +353,8 @@ pub enum BiomeCommand {
         /// flag and the `defaultBranch` is not set in your biome.json
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
+        #[bpaf(external(cache_options), hide_usage)]
+        cache_options: CacheOptions,
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -304,6 +390,22 @@ pub enum BiomeCommand {
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
 
+        /// Use this option when you want to handle several files piped from `stdin`, and print the outputs to `stdout`.
+        ///
+        /// The files are read as a JSON array of objects with the `path` and the `content` of each file, and the outputs are printed in the same format. The files don't need to exist on disk.
+        ///
+        /// Example: `echo '[{"path": "file.js", "content": "let a;"}]' | biome format --stdin-files`
+        #[bpaf(long("stdin-files"), switch, hide_usage)]
+        stdin_files: bool,
+
+        /// Use this option with `--stdin-file-path` to format only a part of the code piped from `stdin`.
+        ///
+        /// The range is given by byte offsets, or by lines and columns starting from 1. The whole code is printed to `stdout`.
+        ///
+        /// Example: `biome format --stdin-file-path=file.js --range=10:42` or `biome format --stdin-file-path=file.js --range=2:1-4:12`
+        #[bpaf(long("range"), argument("START:END"), optional, hide_usage)]
+        range: Option<FormatRange>,
+
         #[bpaf(external, hide_usage)]
         cli_options: CliOptions,
 
@@ -330,6 +432,9 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        #[bpaf(external(cache_options), hide_usage)]
+        cache_options: CacheOptions,
+
         /// Single file, single path or list of paths.
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -368,6 +473,12 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Writes a Markdown summary of the diagnostics to the file, such as the number of
+        /// diagnostics of each rule and the files with the most diagnostics. Pass
+        /// `$GITHUB_STEP_SUMMARY` to display the summary in the GitHub Actions job.
+        #[bpaf(long("summary-file"), argument("PATH"))]
+        summary_file: Option<PathBuf>,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -375,11 +486,16 @@ pub enum BiomeCommand {
 
     /// Bootstraps a new biome project. Creates a configuration file with some defaults.
     #[bpaf(command)]
-    Init(
+    Init {
         /// Tells Biome to emit a `biome.jsonc` file.
         #[bpaf(long("jsonc"), switch)]
-        bool,
-    ),
+        emit_jsonc: bool,
+
+        /// Asks some questions about the project, such as its languages, frameworks and
+        /// formatting preferences, and creates a configuration file tailored to the answers.
+        #[bpaf(long("interactive"), switch)]
+        interactive: bool,
+    },
     /// Acts as a server for the Language Server Protocol over stdin/stdout.
     #[bpaf(command("lsp-proxy"))]
     LspProxy {
@@ -461,9 +577,24 @@ pub enum BiomeCommand {
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
 
+        /// Use this option when you want to handle several files piped from `stdin`, and print the outputs to `stdout`.
+        ///
+        /// The files are read as a JSON array of objects with the `path` and the `content` of each file, and the outputs are printed in the same format. The files don't need to exist on disk.
+        ///
+        /// Example: `echo '[{"path": "file.js", "content": "let a;"}]' | biome search '`let $var`' --stdin-files`
+        #[bpaf(long("stdin-files"), switch, hide_usage)]
+        stdin_files: bool,
+
+        /// Writes the rewrites of the pattern to the files.
+        /// Without this option, the rewrites are only printed.
+        ///
+        /// Example: `biome search --write '`console.log($message)` => `logger.info($message)`'`
+        #[bpaf(long("write"), switch)]
+        write: bool,
+
         /// The GritQL pattern to search for.
         ///
-        /// Note that the search command (currently) does not support rewrites.
+        /// The files are rewritten when the pattern has a rewrite clause, using `=>`.
         #[bpaf(positional("PATTERN"))]
         pattern: String,
 
@@ -485,14 +616,69 @@ pub enum BiomeCommand {
     /// ```
     #[bpaf(command)]
     Explain {
+        /// Prints the documentation of the rule in the JSON format.
+        #[bpaf(long("json"), switch)]
+        json: bool,
+
         /// Single name to display documentation for.
         #[bpaf(positional("NAME"))]
         doc: Doc,
     },
 
+    /// Lists the lint rules, with their group, language and kind of fix, and whether they're
+    /// enabled by the configuration.
+    ///
+    /// ```shell
+    /// biome list-rules --language=css --fix-kind=safe
+    /// ```
+    #[bpaf(command("list-rules"))]
+    ListRules {
+        /// Lists only the rules of the given language.
+        #[bpaf(long("language"), argument("js|jsx|ts|json|css|graphql|html"))]
+        language: Option<String>,
+
+        /// Lists only the rules of the given group.
+        #[bpaf(long("group"), argument("GROUP"))]
+        group: Option<RuleGroup>,
+
+        /// Lists only the rules with the given kind of fix.
+        #[bpaf(long("fix-kind"), argument("none|safe|unsafe"))]
+        fix_kind: Option<FixKindArg>,
+
+        /// Lists only the rules that are enabled by the configuration.
+        #[bpaf(long("enabled"), switch)]
+        enabled: bool,
+
+        /// Prints the rules in the JSON format.
+        #[bpaf(long("json"), switch)]
+        json: bool,
+
+        /// Set the file path to the configuration file, or the directory path to find `biome.json` or `biome.jsonc`.
+        /// If used, it disables the default configuration file resolution.
+        #[bpaf(env("BIOME_CONFIG_PATH"), long("config-path"), argument("PATH"))]
+        config_path: Option<PathBuf>,
+    },
+
     #[bpaf(command)]
-    /// Cleans the logs emitted by the daemon.
-    Clean,
+    /// Cleans the logs emitted by the daemon, the cache of the results and the project index
+    /// stored next to it.
+    Clean {
+        /// The path of the file where the cache is stored. Defaults to `.biomecache`.
+        #[bpaf(long("cache-location"), argument("PATH"))]
+        cache_location: Option<PathBuf>,
+    },
+
+    /// Prints the script that completes the commands, the options and their values in a shell.
+    ///
+    /// ```shell
+    /// biome completions bash >> ~/.bash_completion
+    /// ```
+    #[bpaf(command)]
+    Completions {
+        /// The shell of the script: `bash`, `zsh`, `fish` or `powershell`.
+        #[bpaf(positional("SHELL"))]
+        shell: Shell,
+    },
 
     #[bpaf(command("__run_server"), hide)]
     RunServer {
@@ -522,6 +708,8 @@ pub enum BiomeCommand {
         /// or a custom directory path to find `biome.json` or `biome.jsonc`
         #[bpaf(env("BIOME_CONFIG_PATH"), long("config-path"), argument("PATH"))]
         config_path: Option<PathBuf>,
+        #[bpaf(env("BIOME_OTLP_ENDPOINT"), long("otlp-endpoint"), argument("URL"))]
+        otlp_endpoint: Option<String>,
     },
     #[bpaf(command("__print_socket"), hide)]
     PrintSocket,
@@ -544,6 +732,80 @@ pub enum MigrateSubCommand {
     },
 }
 
+#[derive(Debug, Bpaf, Clone)]
+pub enum DaemonSubCommand {
+    /// Prints whether the daemon is running, along with its process ID, its version, its memory
+    /// usage and the projects it loaded. The memory usage is only known on Linux.
+    #[bpaf(command)]
+    Status,
+
+    /// Stops the daemon.
+    #[bpaf(command)]
+    Stop,
+
+    /// Stops the daemon if it's running, and starts it again.
+    #[bpaf(command)]
+    Restart {
+        /// Allows to change the prefix applied to the file name of the logs.
+        #[bpaf(
+            env("BIOME_LOG_PREFIX_NAME"),
+            long("log-prefix-name"),
+            argument("STRING"),
+            hide_usage,
+            fallback(String::from("server.log")),
+            display_fallback
+        )]
+        log_prefix_name: String,
+
+        /// Allows to change the folder where logs are stored.
+        #[bpaf(
+            env("BIOME_LOG_PATH"),
+            long("log-path"),
+            argument("PATH"),
+            hide_usage,
+            fallback(biome_fs::ensure_cache_dir().join("biome-logs")),
+        )]
+        log_path: PathBuf,
+        /// Allows to set a custom file path to the configuration file,
+        /// or a custom directory path to find `biome.json` or `biome.jsonc`
+        #[bpaf(env("BIOME_CONFIG_PATH"), long("config-path"), argument("PATH"))]
+        config_path: Option<PathBuf>,
+        /// Exports the traces of the daemon to the OpenTelemetry collector listening at this
+        /// URL, with the OTLP protocol over gRPC.
+        #[bpaf(env("BIOME_OTLP_ENDPOINT"), long("otlp-endpoint"), argument("URL"))]
+        otlp_endpoint: Option<String>,
+    },
+
+    /// Prints the last lines of the most recent log file of the daemon.
+    #[bpaf(command)]
+    Logs {
+        /// The prefix of the file name of the logs.
+        #[bpaf(
+            env("BIOME_LOG_PREFIX_NAME"),
+            long("log-prefix-name"),
+            argument("STRING"),
+            hide_usage,
+            fallback(String::from("server.log")),
+            display_fallback
+        )]
+        log_prefix_name: String,
+
+        /// The folder where logs are stored.
+        #[bpaf(
+            env("BIOME_LOG_PATH"),
+            long("log-path"),
+            argument("PATH"),
+            hide_usage,
+            fallback(biome_fs::ensure_cache_dir().join("biome-logs")),
+        )]
+        log_path: PathBuf,
+
+        /// The number of lines to print.
+        #[bpaf(long("lines"), argument("NUMBER"), fallback(50), display_fallback)]
+        lines: usize,
+    },
+}
+
 impl MigrateSubCommand {
     pub const fn is_prettier(&self) -> bool {
         matches!(self, MigrateSubCommand::Prettier)
@@ -564,10 +826,13 @@ impl BiomeCommand {
             BiomeCommand::LspProxy { .. }
             | BiomeCommand::Start { .. }
             | BiomeCommand::Stop
-            | BiomeCommand::Init(_)
+            | BiomeCommand::Daemon(..)
+            | BiomeCommand::Init { .. }
             | BiomeCommand::Explain { .. }
+            | BiomeCommand::ListRules { .. }
             | BiomeCommand::RunServer { .. }
             | BiomeCommand::Clean { .. }
+            | BiomeCommand::Completions { .. }
             | BiomeCommand::PrintSocket => None,
         }
     }
@@ -662,6 +927,72 @@ pub(crate) fn validate_configuration_diagnostics(
     Ok(())
 }
 
+/// Returns the configuration set by the variable `BIOME_CONFIG_OVERRIDE` and by the arguments
+/// `--config-override`, in this order, so that the arguments take precedence.
+///
+/// Each override has the syntax `<key>=<value>`, where the key is the path of the option,
+/// separated by dots. The value is parsed as JSON, and it's a string when it isn't valid JSON.
+/// The overrides of the variable are separated by line breaks, because the values can contain
+/// any other character.
+pub(crate) fn load_configuration_overrides(
+    cli_options: &CliOptions,
+    console: &mut dyn Console,
+) -> Result<PartialConfiguration, CliDiagnostic> {
+    let from_env = biome_env().biome_config_override.value();
+    let overrides = from_env
+        .iter()
+        .flat_map(|value| value.lines())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .chain(cli_options.config_override.iter().map(String::as_str));
+
+    let mut configuration = PartialConfiguration::default();
+    let mut has_errors = false;
+    for config_override in overrides {
+        let Some((key, value)) = config_override.split_once('=') else {
+            let diagnostic = BiomeDiagnostic::invalid_configuration(format!(
+                "The override `{config_override}` doesn't have the syntax `<key>=<value>`."
+            ));
+            console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+            has_errors = true;
+            continue;
+        };
+        let value = value.trim();
+        let mut value = serde_json::from_str(value)
+            .unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+        for segment in key.trim().rsplit('.') {
+            let mut object = serde_json::Map::new();
+            object.insert(segment.to_string(), value);
+            value = serde_json::Value::Object(object);
+        }
+
+        let (deserialized, diagnostics) = deserialize_from_json_str::<PartialConfiguration>(
+            &value.to_string(),
+            JsonParserOptions::default(),
+            "",
+        )
+        .consume();
+        for diagnostic in &diagnostics {
+            has_errors |= diagnostic.severity() >= Severity::Error;
+            console.error(markup! {{PrintDiagnostic::simple(diagnostic)}});
+        }
+        if let Some(deserialized) = deserialized {
+            configuration.merge_with(deserialized);
+        }
+    }
+
+    if has_errors {
+        return Err(CliDiagnostic::workspace_error(
+            BiomeDiagnostic::invalid_configuration(
+                "Biome exited because the overrides of the configuration resulted in errors. Please fix them.",
+            )
+            .into(),
+        ));
+    }
+
+    Ok(configuration)
+}
+
 fn resolve_manifest(
     fs: &DynRef<'_, dyn FileSystem>,
 ) -> Result<Option<(BiomePath, String)>, WorkspaceError> {
@@ -834,7 +1165,11 @@ pub(crate) trait CommandRunner: Sized {
         let workspace = &*session.app.workspace;
         self.check_incompatible_arguments()?;
         let (execution, paths) = self.configure_workspace(fs, console, workspace, cli_options)?;
-        execute_mode(execution, session, cli_options, paths)
+        if self.should_watch() {
+            watch_mode(execution, session, cli_options, paths)
+        } else {
+            execute_mode(execution, session, cli_options, paths)
+        }
     }
 
     /// This function prepares the workspace with the following:
@@ -851,7 +1186,7 @@ pub(crate) trait CommandRunner: Sized {
         workspace: &dyn Workspace,
         cli_options: &CliOptions,
     ) -> Result<(Execution, Vec<OsString>), CliDiagnostic> {
-        let loaded_configuration =
+        let mut loaded_configuration =
             load_configuration(fs, cli_options.as_configuration_path_hint())?;
         if self.should_validate_configuration_diagnostics() {
             validate_configuration_diagnostics(
@@ -860,12 +1195,46 @@ pub(crate) trait CommandRunner: Sized {
                 cli_options.verbose,
             )?;
         }
+        // The overrides apply on top of the configuration file, and the options of the command
+        // are merged on top of them
+        loaded_configuration
+            .configuration
+            .merge_with(load_configuration_overrides(cli_options, console)?);
         let configuration_path = loaded_configuration.directory_path.clone();
         let configuration = self.merge_configuration(loaded_configuration, fs, console)?;
+        // The configuration is part of the key of the cache, and it's moved to the workspace below
+        let serialized_configuration = self
+            .get_cache_options()
+            .filter(|cache_options| cache_options.cache)
+            .map(|_| serde_json::to_string(&configuration).unwrap_or_default());
         let vcs_base_path = configuration_path.or(fs.working_directory());
         let (vcs_base_path, gitignore_matches) =
             configuration.retrieve_gitignore_matches(fs, vcs_base_path.as_deref())?;
         let paths = self.get_files_to_process(fs, &configuration)?;
+        let (threads, max_open_files) = configuration
+            .files
+            .as_ref()
+            .map(|files| (files.threads, files.max_open_files))
+            .unwrap_or_default();
+        let follow_symlinks = configuration
+            .files
+            .as_ref()
+            .and_then(|files| files.follow_symlinks)
+            .unwrap_or(true);
+        let binary_files = configuration
+            .files
+            .as_ref()
+            .and_then(|files| files.binary_files.as_ref())
+            .map(|patterns| to_matcher(fs.working_directory(), Some(patterns)))
+            .transpose()?;
+        let max_warnings = cli_options.as_max_warnings().or_else(|| {
+            let linter = configuration.linter.as_ref()?;
+            if linter.error_on_warnings == Some(true) {
+                Some(0)
+            } else {
+                linter.max_warnings
+            }
+        });
         workspace.register_project_folder(RegisterProjectFolderParams {
             path: fs.working_directory(),
             set_as_current_workspace: true,
@@ -883,7 +1252,22 @@ pub(crate) trait CommandRunner: Sized {
             gitignore_matches,
         })?;
 
-        let execution = self.get_execution(cli_options, console, workspace)?;
+        let mut execution = self
+            .get_execution(cli_options, console, workspace)?
+            .with_concurrency(threads, max_open_files)
+            .with_scanner_options(follow_symlinks, binary_files)
+            .with_max_warnings(max_warnings);
+        if let (Some(cache_options), Some(serialized_configuration)) =
+            (self.get_cache_options(), serialized_configuration)
+        {
+            let cache_location = cache_options
+                .cache_location
+                .clone()
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_CACHE_LOCATION));
+            let traversal_mode = format!("{:?}", execution.traversal_mode());
+            let key = cache_key(&[VERSION, &serialized_configuration, &traversal_mode]);
+            execution = execution.with_cache(ResultCache::load(&**fs, cache_location, key));
+        }
         Ok((execution, paths))
     }
 
@@ -891,8 +1275,23 @@ pub(crate) trait CommandRunner: Sized {
     ///
     /// ## Errors
     /// - If the user didn't provide anything via `stdin` but the option `--stdin-file-path` is passed.
+    /// - If the option `--stdin-files` is passed, and `stdin` isn't a JSON array of files.
     fn get_stdin(&self, console: &mut dyn Console) -> Result<Option<Stdin>, CliDiagnostic> {
-        let stdin = if let Some(stdin_file_path) = self.get_stdin_file_path() {
+        let stdin = if self.should_read_stdin_files() {
+            if self.get_stdin_file_path().is_some() {
+                return Err(CliDiagnostic::incompatible_arguments(
+                    "stdin-file-path",
+                    "stdin-files",
+                ));
+            }
+            let Some(input) = console.read() else {
+                return Err(CliDiagnostic::missing_argument("stdin", Self::COMMAND_NAME));
+            };
+            let files = serde_json::from_str::<Vec<StdinFile>>(&input).map_err(|error| {
+                CliDiagnostic::io_error(io::Error::new(io::ErrorKind::InvalidData, error))
+            })?;
+            Some(Stdin::Files(files))
+        } else if let Some(stdin_file_path) = self.get_stdin_file_path() {
             let input_code = console.read();
             if let Some(input_code) = input_code {
                 let path = PathBuf::from(stdin_file_path);
@@ -954,6 +1353,21 @@ pub(crate) trait CommandRunner: Sized {
     fn should_validate_configuration_diagnostics(&self) -> bool {
         true
     }
+
+    /// Whether the command should keep running, and process the files again when they change.
+    fn should_watch(&self) -> bool {
+        false
+    }
+
+    /// Whether the command reads several files from `stdin`, with `--stdin-files`.
+    fn should_read_stdin_files(&self) -> bool {
+        false
+    }
+
+    /// Returns the options of the cache, if the command can skip the files that didn't change.
+    fn get_cache_options(&self) -> Option<&CacheOptions> {
+        None
+    }
 }
 
 pub trait LoadEditorConfig: CommandRunner {
@@ -1102,4 +1516,29 @@ mod tests {
     fn check_options() {
         biome_command().check_invariants(false);
     }
+
+    #[test]
+    fn parses_the_endpoint_of_the_traces_exporter() {
+        let command = biome_command()
+            .run_inner(["start", "--otlp-endpoint=http://localhost:4317"].as_slice())
+            .unwrap();
+
+        assert!(matches!(
+            command,
+            BiomeCommand::Start { otlp_endpoint: Some(endpoint), .. } if endpoint == "http://localhost:4317"
+        ));
+    }
+
+    #[test]
+    fn exports_no_trace_by_default() {
+        let command = biome_command().run_inner(["start"].as_slice()).unwrap();
+
+        assert!(matches!(
+            command,
+            BiomeCommand::Start {
+                otlp_endpoint: None,
+                ..
+            }
+        ));
+    }
 }
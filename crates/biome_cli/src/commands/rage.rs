@@ -1,17 +1,23 @@
+use biome_configuration::suppressions::SuppressionManifest;
 use biome_configuration::{ConfigurationPathHint, Rules};
-use biome_console::fmt::{Display, Formatter};
+use biome_console::fmt::{Display, Formatter, Termcolor};
 use biome_console::{
     fmt, markup, ConsoleExt, DebugDisplay, DebugDisplayOption, HorizontalLine, KeyValuePair,
     Padding, SOFT_LINE,
 };
-use biome_diagnostics::termcolor::{ColorChoice, WriteColor};
+use biome_diagnostics::termcolor::{ColorChoice, NoColor, WriteColor};
 use biome_diagnostics::{termcolor, PrintDescription};
 use biome_flags::biome_env;
-use biome_fs::FileSystem;
-use biome_service::configuration::{load_configuration, LoadedConfiguration};
+use biome_fs::{ConfigName, FileSystem};
+use biome_service::configuration::{
+    load_configuration, LoadedConfiguration, PartialConfigurationExt,
+};
+use biome_service::file_handlers::DocumentFileSource;
+use biome_service::settings::Settings;
 use biome_service::workspace::{client, RageEntry, RageParams};
 use biome_service::{DynRef, Workspace};
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::{env, io, ops::Deref};
 use tokio::runtime::Runtime;
 
@@ -25,6 +31,7 @@ pub(crate) fn rage(
     daemon_logs: bool,
     formatter: bool,
     linter: bool,
+    scan: bool,
 ) -> Result<(), CliDiagnostic> {
     let terminal_supports_colors = termcolor::BufferWriter::stdout(ColorChoice::Auto)
         .buffer()
@@ -48,9 +55,17 @@ pub(crate) fn rage(
     {EnvVarOs("NODE_PACKAGE_MANAGER")}
 
     {RageConfiguration { fs: &session.app.fs, formatter, linter }}
-    {WorkspaceRage(session.app.workspace.deref())}
+    {WorkspaceRage(session.app.workspace.deref(), scan)}
     ));
 
+    if scan {
+        session.app.console.log(markup!({
+            RageScan {
+                fs: &session.app.fs,
+            }
+        }));
+    }
+
     if daemon_logs {
         match session.app.workspace.server_info() {
             Some(_) => {
@@ -63,20 +78,25 @@ pub(crate) fn rage(
                     .app
                     .console
                     .log(markup!("Discovering running Biome servers..."));
-                session.app.console.log(markup!({ RunningRomeServer }));
+                session
+                    .app
+                    .console
+                    .log(markup!({ RunningRomeServer(scan) }));
             }
         }
     }
     Ok(())
 }
 
-struct WorkspaceRage<'a>(&'a dyn Workspace);
+/// Prints the information that the workspace reports about itself, including the sizes of its
+/// caches when the second field is `true`
+pub(crate) struct WorkspaceRage<'a>(pub(crate) &'a dyn Workspace, pub(crate) bool);
 
 impl Display for WorkspaceRage<'_> {
     fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
         let workspace = self.0;
 
-        let rage_result = workspace.rage(RageParams {});
+        let rage_result = workspace.rage(RageParams { caches: self.1 });
 
         match rage_result {
             Ok(result) => {
@@ -104,8 +124,9 @@ impl Display for WorkspaceRage<'_> {
     }
 }
 
-/// Prints information about other running biome server instances.
-struct RunningRomeServer;
+/// Prints information about other running biome server instances, including the sizes of
+/// their caches when the field is `true`.
+struct RunningRomeServer(bool);
 
 impl Display for RunningRomeServer {
     fn fmt(&self, f: &mut Formatter) -> io::Result<()> {
@@ -137,7 +158,7 @@ impl Display for RunningRomeServer {
                 .fmt(f)?;
 
                         match client(transport) {
-                            Ok(client) => WorkspaceRage(client.deref()).fmt(f)?,
+                            Ok(client) => WorkspaceRage(client.deref(), self.0).fmt(f)?,
                             Err(err) => {
                                 markup!(<Error>"\u{2716} Failed to connect: "</Error>).fmt(f)?;
                                 writeln!(f, "{err}")?;
@@ -187,6 +208,7 @@ impl Display for RageConfiguration<'_, '_> {
                 } else {
                     let LoadedConfiguration {
                         configuration,
+                        suppression_manifest,
                         diagnostics,
                         ..
                     } = loaded_configuration;
@@ -271,6 +293,9 @@ impl Display for RageConfiguration<'_, '_> {
                             {KeyValuePair("Line ending", markup!({DebugDisplayOption(css_formatter_configuration.line_ending)}))}
                             {KeyValuePair("Line width", markup!({DebugDisplayOption(css_formatter_configuration.line_width)}))}
                             {KeyValuePair("Quote style", markup!({DebugDisplay(css_formatter_configuration.quote_style)}))}
+                            {KeyValuePair("Hex color case", markup!({DebugDisplayOption(css_formatter_configuration.hex_color_case)}))}
+                            {KeyValuePair("Keyword case", markup!({DebugDisplayOption(css_formatter_configuration.keyword_case)}))}
+                            {KeyValuePair("URL quotes", markup!({DebugDisplayOption(css_formatter_configuration.url_quotes)}))}
                         ).fmt(fmt)?;
 
                         let graphql_formatter_configuration =
@@ -284,6 +309,9 @@ impl Display for RageConfiguration<'_, '_> {
                             {KeyValuePair("Line width", markup!({DebugDisplayOption(graphql_formatter_configuration.line_width)}))}
                             {KeyValuePair("Bracket spacing", markup!({DebugDisplayOption(graphql_formatter_configuration.bracket_spacing)}))}
                             {KeyValuePair("Quote style", markup!({DebugDisplayOption(graphql_formatter_configuration.quote_style)}))}
+                            {KeyValuePair("Directive order", markup!({DebugDisplayOption(graphql_formatter_configuration.directive_order)}))}
+                            {KeyValuePair("Single field selection sets", markup!({DebugDisplayOption(graphql_formatter_configuration.single_field_selection_sets)}))}
+                            {KeyValuePair("Definition spacing", markup!({DebugDisplayOption(graphql_formatter_configuration.empty_line_between_definitions)}))}
                         ).fmt(fmt)?;
                     }
 
@@ -305,6 +333,14 @@ impl Display for RageConfiguration<'_, '_> {
                             {KeyValuePair("All", markup!({DebugDisplay(linter_configuration.all.unwrap_or_default())}))}
                             {RageConfigurationLintRules("Enabled rules", linter_configuration)}
                         ).fmt(fmt)?;
+
+                        if let Some(suppression_manifest) = &suppression_manifest {
+                            markup! (
+                                {Section("Suppressions")}
+                                {KeyValuePair("Patterns", markup!({suppression_manifest.entries.len()}))}
+                                {RageSuppressedRules(suppression_manifest)}
+                            ).fmt(fmt)?;
+                        }
                     }
                 }
             }
@@ -319,6 +355,206 @@ impl Display for RageConfiguration<'_, '_> {
     }
 }
 
+/// The directories that aren't scanned, because Biome never processes their files
+const SKIPPED_DIRECTORIES: &[&str] = &["node_modules", ".git"];
+
+/// Prints statistics about the files of the project, to understand why Biome doesn't process
+/// a file, or doesn't process it with the expected configuration
+struct RageScan<'a, 'app> {
+    fs: &'a DynRef<'app, dyn FileSystem>,
+}
+
+impl Display for RageScan<'_, '_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        Section("Project Scan").fmt(fmt)?;
+
+        let Some(working_directory) = self.fs.working_directory() else {
+            return KeyValuePair("Status", markup!(<Dim>"unknown working directory"</Dim>))
+                .fmt(fmt);
+        };
+        KeyValuePair(
+            "Working directory",
+            markup!({ working_directory.display().to_string() }),
+        )
+        .fmt(fmt)?;
+
+        let LoadedConfiguration {
+            directory_path,
+            file_path,
+            configuration,
+            ..
+        } = match load_configuration(self.fs, ConfigurationPathHint::default()) {
+            Ok(loaded_configuration) => loaded_configuration,
+            Err(err) => {
+                return markup! (
+                    {KeyValuePair("Status", markup!(<Error>"Failed to load the configuration"</Error>))}
+                    {KeyValuePair("Error", markup!({format!("{err}")}))}
+                )
+                .fmt(fmt);
+            }
+        };
+
+        // Only the configuration file found from the working directory applies, along with
+        // the files that it extends
+        match &file_path {
+            Some(file_path) => KeyValuePair(
+                "Configuration",
+                markup!({ file_path.display().to_string() }),
+            )
+            .fmt(fmt)?,
+            None => KeyValuePair("Configuration", markup!(<Dim>"default"</Dim>)).fmt(fmt)?,
+        }
+        for extended in configuration
+            .extends
+            .iter()
+            .flat_map(|extends| extends.iter())
+        {
+            KeyValuePair("Extends", markup!({ extended.as_str() })).fmt(fmt)?;
+        }
+
+        let vcs_base_path = directory_path.clone().or(Some(working_directory.clone()));
+        let (vcs_base_path, gitignore_matches) = configuration
+            .retrieve_gitignore_matches(self.fs, vcs_base_path.as_deref())
+            .unwrap_or_default();
+        let mut settings = Settings::default();
+        if let Err(err) = settings.merge_with_configuration(
+            configuration,
+            Some(working_directory.clone()),
+            vcs_base_path,
+            &gitignore_matches,
+        ) {
+            return KeyValuePair("Error", markup!({ format!("{err}") })).fmt(fmt);
+        }
+
+        let scan = ProjectScan::new(&working_directory, directory_path.as_deref(), &settings);
+        for path in &scan.unused_configurations {
+            KeyValuePair(
+                "Unused configuration",
+                markup!({ path.display().to_string() }),
+            )
+            .fmt(fmt)?;
+        }
+
+        Section("Processed Files").fmt(fmt)?;
+        if scan.languages.is_empty() {
+            KeyValuePair("Files", markup!(<Dim>"none"</Dim>)).fmt(fmt)?;
+        }
+        for (language, count) in &scan.languages {
+            KeyValuePair(language, markup!({ count })).fmt(fmt)?;
+        }
+
+        Section("Ignored Files").fmt(fmt)?;
+        if scan.ignored.is_empty() {
+            KeyValuePair("Files", markup!(<Dim>"none"</Dim>)).fmt(fmt)?;
+        }
+        for (reason, count) in &scan.ignored {
+            KeyValuePair(reason, markup!({ count })).fmt(fmt)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The files found under the working directory
+#[derive(Debug, Default)]
+struct ProjectScan {
+    /// The number of processed files of each language
+    languages: BTreeMap<String, usize>,
+    /// The number of ignored files, for each reason they are ignored for
+    ignored: BTreeMap<String, usize>,
+    /// The configuration files that don't apply, because they aren't in the directory of
+    /// the configuration file that was found from the working directory
+    unused_configurations: Vec<PathBuf>,
+}
+
+impl ProjectScan {
+    fn new(
+        working_directory: &Path,
+        configuration_directory: Option<&Path>,
+        settings: &Settings,
+    ) -> Self {
+        let mut scan = Self::default();
+        let mut pending = vec![working_directory.to_path_buf()];
+        while let Some(directory) = pending.pop() {
+            let Ok(entries) = directory.read_dir() else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                let file_name = entry.file_name();
+                let file_name = file_name.to_str().unwrap_or_default();
+                if metadata.is_dir() {
+                    if !SKIPPED_DIRECTORIES.contains(&file_name) {
+                        pending.push(path);
+                    }
+                    continue;
+                }
+                if !metadata.is_file() {
+                    continue;
+                }
+
+                // The paths are relative to the working directory, like the ones that are
+                // passed to the commands
+                let relative_path =
+                    Path::new(".").join(path.strip_prefix(working_directory).unwrap_or(&path));
+                if ConfigName::file_names().contains(&file_name)
+                    && path.parent() != configuration_directory
+                {
+                    scan.unused_configurations.push(relative_path.clone());
+                }
+
+                let reason = if metadata.len() >= settings.files.max_size.get() {
+                    Some(String::from("larger than `files.maxSize`"))
+                } else {
+                    ignore_reason(&relative_path, settings)
+                };
+                match reason {
+                    Some(reason) => *scan.ignored.entry(reason).or_default() += 1,
+                    None => {
+                        let language = language_name(&DocumentFileSource::from_path(&path));
+                        *scan.languages.entry(language).or_default() += 1;
+                    }
+                }
+            }
+        }
+        scan.unused_configurations.sort();
+        scan
+    }
+}
+
+/// Returns why the file at `path` is ignored by the top-level `include`/`ignore` and by the
+/// ignore file of the VCS, along with the pattern that ignores it
+fn ignore_reason(path: &Path, settings: &Settings) -> Option<String> {
+    let files = &settings.files;
+    if !files.included_files.is_empty() && !files.included_files.matches_path(path) {
+        return Some(String::from("not in `files.include`"));
+    }
+    if let Some(pattern) = files.ignored_files.matching_pattern(path) {
+        return Some(format!("`files.ignore` {pattern}"));
+    }
+    let matched = files.git_ignore_match(path, false);
+    if matched.is_ignore() {
+        let pattern = matched.inner().map_or("", |glob| glob.original());
+        return Some(format!("VCS ignore file {pattern}"));
+    }
+    None
+}
+
+/// Returns the name of the language of the files of `source`, such as `TypeScript`
+fn language_name(source: &DocumentFileSource) -> String {
+    let mut buffer = Termcolor(NoColor::new(Vec::new()));
+    Formatter::new(&mut buffer)
+        .write_markup(markup!({ source }))
+        // SAFETY: Writing to a memory buffer should never fail
+        .unwrap();
+
+    // SAFETY: Printing uncolored markup never generates non UTF-8 byte sequences
+    String::from_utf8(buffer.0.into_inner()).unwrap()
+}
+
 struct RageConfigurationLintRules<'a>(&'a str, Rules);
 
 impl Display for RageConfigurationLintRules<'_> {
@@ -338,6 +574,24 @@ impl Display for RageConfigurationLintRules<'_> {
     }
 }
 
+/// Prints the rules turned off by the suppressions file, with the number of patterns that
+/// turn off each of them
+struct RageSuppressedRules<'a>(&'a SuppressionManifest);
+
+impl Display for RageSuppressedRules<'_> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> io::Result<()> {
+        let padding = Padding::new(2);
+        fmt.write_markup(markup! {{padding}"Suppressed rules:"})?;
+        fmt.write_markup(markup! {{SOFT_LINE}})?;
+        for (rule, count) in self.0.rule_statistics() {
+            fmt.write_markup(markup! {{padding}{rule}" ("{count}")"})?;
+            fmt.write_markup(markup! {{SOFT_LINE}})?;
+        }
+
+        Ok(())
+    }
+}
+
 struct EnvVarOs(&'static str);
 
 impl fmt::Display for EnvVarOs {
@@ -17,7 +17,9 @@ pub(crate) struct SearchCommandPayload {
     pub(crate) paths: Vec<OsString>,
     pub(crate) pattern: String,
     pub(crate) stdin_file_path: Option<String>,
+    pub(crate) stdin_files: bool,
     pub(crate) vcs_configuration: Option<PartialVcsConfiguration>,
+    pub(crate) write: bool,
 }
 
 impl CommandRunner for SearchCommandPayload {
@@ -52,8 +54,12 @@ impl CommandRunner for SearchCommandPayload {
         self.stdin_file_path.as_deref()
     }
 
+    fn should_read_stdin_files(&self) -> bool {
+        self.stdin_files
+    }
+
     fn should_write(&self) -> bool {
-        false
+        self.write
     }
 
     fn get_execution(
@@ -69,6 +75,7 @@ impl CommandRunner for SearchCommandPayload {
             .pattern_id;
         Ok(Execution::new(TraversalMode::Search {
             pattern,
+            write: self.write,
             stdin: self.get_stdin(_console)?,
         })
         .set_report(cli_options))
@@ -417,6 +417,37 @@ impl CliDiagnostic {
         })
     }
 
+    /// Emitted when the diagnostics emitted more warnings than the maximum allowed by `--max-warnings`
+    pub fn too_many_warnings(
+        category: &'static Category,
+        warnings: u32,
+        max_warnings: u32,
+    ) -> Self {
+        Self::CheckError(CheckError {
+            category,
+            message: MessageAndDescription::from(
+                markup! {
+                    "Found "{warnings}" "<Emphasis>"warnings"</Emphasis>", which is more than the maximum of "{max_warnings}" allowed."
+                }
+                .to_owned(),
+            ),
+        })
+    }
+
+    /// Emitted when the range passed with `--range` is outside of the content read from `stdin`
+    pub fn out_of_bounds_range(range: impl Into<String>) -> Self {
+        let range = range.into();
+        Self::ParseError(ParseDiagnostic {
+            message: MessageAndDescription::from(
+                markup! {
+                    "The range "<Emphasis>{range}</Emphasis>" is outside of the content read from stdin."
+                }
+                .to_owned(),
+            ),
+            source: None,
+        })
+    }
+
     pub fn stdin() -> Self {
         Self::Stdin(StdinDiagnostic::default())
     }
@@ -0,0 +1,354 @@
+use biome_fs::{BiomePath, FileSystem, FileSystemExt};
+use biome_service::project_index::ContentHash;
+use biome_service::workspace::DocumentFileSource;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// The file where the results are cached, when `--cache-location` isn't passed
+pub(crate) const DEFAULT_CACHE_LOCATION: &str = ".biomecache";
+
+/// The files that were processed without changes nor diagnostics by the previous runs,
+/// so that they can be skipped as long as their content, and the content of the files they
+/// depend on, don't change.
+///
+/// The cache is discarded when its key changes. The key is computed from the version of Biome,
+/// the configuration and the arguments of the command, because any of them can change the results.
+///
+/// The hashes aren't stable across versions of Rust, which is fine because the version of Biome
+/// is part of the key.
+#[derive(Debug)]
+pub(crate) struct ResultCache {
+    /// The file where the cache is stored
+    location: PathBuf,
+    /// The hash of everything, other than the content of the files, that the results depend on
+    key: u64,
+    /// The hashes of the files whose results can be reused
+    files: Mutex<FxHashMap<String, CachedFile>>,
+    /// The hashes of the files that the results of the other files depend on, for the current run
+    dependencies: Mutex<DependencyHashes>,
+    /// The files that emitted a message during the current run
+    reported_files: Mutex<FxHashSet<String>>,
+}
+
+/// The content of the cache file
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct CacheFile {
+    key: u64,
+    files: BTreeMap<String, CachedFile>,
+}
+
+/// What the results of a file were computed from
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct CachedFile {
+    /// The hash of the content of the file
+    content: u64,
+    /// The hash of the files it depends on, see [DependencyHashes::for_file]
+    dependencies: u64,
+}
+
+/// The kinds of files whose content the results of the other files depend on
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Dependency {
+    /// The stylesheets, whose custom properties and keyframes are read by the CSS rules
+    Stylesheet,
+    /// The GraphQL documents, including the ones embedded in scripts, which are validated
+    /// against the schema and the fragments of the project
+    Graphql,
+    /// The JSON schemas, which validate the JSON files
+    JsonSchema,
+    /// The scripts that define custom elements or Angular components
+    Script,
+}
+
+/// The hashes of the files that the results of the other files depend on, by [Dependency].
+///
+/// Only the kinds of files that can be read by the rules of a language are taken into account,
+/// so that changing a script doesn't invalidate the results of the stylesheets.
+#[derive(Debug, Default)]
+pub(crate) struct DependencyHashes {
+    stylesheets: u64,
+    graphql: u64,
+    json_schemas: u64,
+    scripts: u64,
+}
+
+impl DependencyHashes {
+    /// Computes the hashes from the path, the kind and the content hash of the files of the project
+    pub(crate) fn from_files<'a>(
+        files: impl IntoIterator<Item = &'a (BiomePath, Dependency, ContentHash)>,
+    ) -> Self {
+        let mut by_kind: [BTreeMap<&BiomePath, ContentHash>; 4] = Default::default();
+        for (path, dependency, content_hash) in files {
+            by_kind[*dependency as usize].insert(path, *content_hash);
+        }
+        let [stylesheets, graphql, json_schemas, scripts] = by_kind.map(|files| {
+            let mut hasher = DefaultHasher::new();
+            files.hash(&mut hasher);
+            hasher.finish()
+        });
+        Self {
+            stylesheets,
+            graphql,
+            json_schemas,
+            scripts,
+        }
+    }
+
+    /// Returns the hash of the files that the results of the file at `path` can depend on
+    fn for_file(&self, path: &BiomePath) -> u64 {
+        let dependencies: &[u64] = match DocumentFileSource::from_path(path) {
+            DocumentFileSource::Css(_) => &[self.stylesheets],
+            DocumentFileSource::Json(_) => &[self.json_schemas],
+            DocumentFileSource::Graphql(_) => &[self.graphql],
+            DocumentFileSource::Js(_) => &[self.graphql, self.scripts],
+            // The documents that embed other languages can depend on anything
+            _ => &[
+                self.stylesheets,
+                self.graphql,
+                self.json_schemas,
+                self.scripts,
+            ],
+        };
+        let mut hasher = DefaultHasher::new();
+        dependencies.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// The content of the file where the project index is stored, next to the cache file
+#[derive(Debug, Deserialize, Serialize)]
+struct ProjectIndexFile {
+    key: u64,
+    /// The snapshot exported by the workspace
+    index: String,
+}
+
+impl ResultCache {
+    /// Reads the cache stored at `location`. The cache is empty if the file doesn't exist,
+    /// can't be read, or was written with another `key`.
+    pub(crate) fn load(fs: &dyn FileSystem, location: PathBuf, key: u64) -> Self {
+        let files = read_cache_file::<CacheFile>(fs, &location)
+            .filter(|cache_file| cache_file.key == key)
+            .map(|cache_file| cache_file.files.into_iter().collect())
+            .unwrap_or_default();
+        Self {
+            location,
+            key,
+            files: Mutex::new(files),
+            dependencies: Mutex::default(),
+            reported_files: Mutex::default(),
+        }
+    }
+
+    /// Records the hashes of the files that the results of the other files depend on,
+    /// before any file is processed.
+    pub(crate) fn set_dependencies(&self, dependencies: DependencyHashes) {
+        *self.dependencies.lock().unwrap() = dependencies;
+    }
+
+    /// Returns the hash of the content of `path`, or [None] if the file can't be read.
+    pub(crate) fn content_hash(fs: &dyn FileSystem, path: &Path) -> Option<u64> {
+        let mut content = String::new();
+        fs.open(path)
+            .and_then(|mut file| file.read_to_string(&mut content))
+            .ok()?;
+        Some(hash(&content))
+    }
+
+    /// Whether the results of the previous run can be reused for the file at `path`,
+    /// because neither its content nor the content of the files it depends on changed.
+    pub(crate) fn is_fresh(&self, path: &BiomePath, content_hash: u64) -> bool {
+        let cached_file = self.cached_file(path, content_hash);
+        let files = self.files.lock().unwrap();
+        files.get(&path.display().to_string()) == Some(&cached_file)
+    }
+
+    fn cached_file(&self, path: &BiomePath, content_hash: u64) -> CachedFile {
+        CachedFile {
+            content: content_hash,
+            dependencies: self.dependencies.lock().unwrap().for_file(path),
+        }
+    }
+
+    /// Records that a message was emitted for the file at `path`, so its results can't be reused.
+    pub(crate) fn mark_reported(&self, path: String) {
+        self.reported_files.lock().unwrap().insert(path);
+    }
+
+    /// Records the result of processing the file at `path`. The file is skipped by the next
+    /// runs only when it was unchanged and didn't emit any message.
+    pub(crate) fn update(&self, path: &BiomePath, content_hash: u64, is_unchanged: bool) {
+        let cached_file = self.cached_file(path, content_hash);
+        let path = path.display().to_string();
+        let is_reported = self.reported_files.lock().unwrap().contains(&path);
+        let mut files = self.files.lock().unwrap();
+        if is_unchanged && !is_reported {
+            files.insert(path, cached_file);
+        } else {
+            files.remove(&path);
+        }
+    }
+
+    /// Writes the cache, so that the next runs can reuse it.
+    pub(crate) fn save(&self, fs: &dyn FileSystem) -> io::Result<()> {
+        self.reported_files.lock().unwrap().clear();
+        let cache_file = CacheFile {
+            key: self.key,
+            files: self
+                .files
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(path, cached_file)| (path.clone(), *cached_file))
+                .collect(),
+        };
+        let content = serde_json::to_vec(&cache_file)?;
+        fs.create(&self.location)?.set_content(&content)
+    }
+
+    /// Reads the snapshot of the project index written by the previous run, unless it was
+    /// written with another `key`
+    pub(crate) fn read_project_index(&self, fs: &dyn FileSystem) -> Option<String> {
+        read_cache_file::<ProjectIndexFile>(fs, &project_index_location(&self.location))
+            .filter(|index_file| index_file.key == self.key)
+            .map(|index_file| index_file.index)
+    }
+
+    /// Writes the snapshot of the project index, so that the next runs don't scan again
+    /// the files that didn't change.
+    pub(crate) fn save_project_index(&self, fs: &dyn FileSystem, index: String) -> io::Result<()> {
+        let index_file = ProjectIndexFile {
+            key: self.key,
+            index,
+        };
+        let content = serde_json::to_vec(&index_file)?;
+        fs.create(&project_index_location(&self.location))?
+            .set_content(&content)
+    }
+}
+
+/// Returns the path of the file where the project index is stored, which is the path of the
+/// cache file followed by `.index`
+pub(crate) fn project_index_location(location: &Path) -> PathBuf {
+    let mut path = location.as_os_str().to_owned();
+    path.push(".index");
+    PathBuf::from(path)
+}
+
+fn read_cache_file<T: DeserializeOwned>(fs: &dyn FileSystem, location: &Path) -> Option<T> {
+    let mut content = String::new();
+    fs.open(location)
+        .and_then(|mut file| file.read_to_string(&mut content))
+        .ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Computes the key of a cache from the things that the results depend on
+pub(crate) fn cache_key(parts: &[&str]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    parts.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn hash(content: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_fs::MemoryFileSystem;
+    use biome_service::project_index::content_hash;
+
+    #[test]
+    fn reuses_the_unchanged_files() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(PathBuf::from("a.js"), "let a;");
+        fs.insert(PathBuf::from("b.js"), "let b;");
+        let a = BiomePath::new("a.js");
+        let b = BiomePath::new("b.js");
+
+        let cache = ResultCache::load(&fs, PathBuf::from(DEFAULT_CACHE_LOCATION), 1);
+        let a_hash = ResultCache::content_hash(&fs, a.as_path()).unwrap();
+        let b_hash = ResultCache::content_hash(&fs, b.as_path()).unwrap();
+        assert!(!cache.is_fresh(&a, a_hash));
+        cache.update(&a, a_hash, true);
+        cache.mark_reported(b.display().to_string());
+        cache.update(&b, b_hash, true);
+        cache.save(&fs).unwrap();
+
+        let cache = ResultCache::load(&fs, PathBuf::from(DEFAULT_CACHE_LOCATION), 1);
+        assert!(cache.is_fresh(&a, a_hash));
+        assert!(!cache.is_fresh(&b, b_hash));
+        assert!(!cache.is_fresh(&a, hash("let a = 1;")));
+    }
+
+    #[test]
+    fn discards_the_cache_of_another_key() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(PathBuf::from("a.js"), "let a;");
+        let a = BiomePath::new("a.js");
+        let a_hash = ResultCache::content_hash(&fs, a.as_path()).unwrap();
+
+        let cache = ResultCache::load(&fs, PathBuf::from(DEFAULT_CACHE_LOCATION), 1);
+        cache.update(&a, a_hash, true);
+        cache.save(&fs).unwrap();
+
+        let cache = ResultCache::load(&fs, PathBuf::from(DEFAULT_CACHE_LOCATION), 2);
+        assert!(!cache.is_fresh(&a, a_hash));
+    }
+
+    #[test]
+    fn discards_the_files_whose_dependencies_changed() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(PathBuf::from("a.css"), "a { color: var(--a); }");
+        fs.insert(PathBuf::from("b.json"), "{}");
+        let a = BiomePath::new("a.css");
+        let b = BiomePath::new("b.json");
+        let a_hash = ResultCache::content_hash(&fs, a.as_path()).unwrap();
+        let b_hash = ResultCache::content_hash(&fs, b.as_path()).unwrap();
+        let variables = BiomePath::new("variables.css");
+
+        let cache = ResultCache::load(&fs, PathBuf::from(DEFAULT_CACHE_LOCATION), 1);
+        cache.set_dependencies(DependencyHashes::from_files(&[(
+            variables.clone(),
+            Dependency::Stylesheet,
+            content_hash(":root { --a: red; }"),
+        )]));
+        cache.update(&a, a_hash, true);
+        cache.update(&b, b_hash, true);
+        cache.save(&fs).unwrap();
+
+        let cache = ResultCache::load(&fs, PathBuf::from(DEFAULT_CACHE_LOCATION), 1);
+        cache.set_dependencies(DependencyHashes::from_files(&[(
+            variables,
+            Dependency::Stylesheet,
+            content_hash(":root { --b: red; }"),
+        )]));
+        assert!(!cache.is_fresh(&a, a_hash));
+        assert!(cache.is_fresh(&b, b_hash));
+    }
+
+    #[test]
+    fn stores_the_project_index_next_to_the_cache() {
+        let fs = MemoryFileSystem::default();
+
+        let cache = ResultCache::load(&fs, PathBuf::from(DEFAULT_CACHE_LOCATION), 1);
+        assert_eq!(cache.read_project_index(&fs), None);
+        cache.save_project_index(&fs, "{}".to_string()).unwrap();
+        assert_eq!(cache.read_project_index(&fs).as_deref(), Some("{}"));
+        assert!(fs.path_exists(Path::new(".biomecache.index")));
+
+        let cache = ResultCache::load(&fs, PathBuf::from(DEFAULT_CACHE_LOCATION), 2);
+        assert_eq!(cache.read_project_index(&fs), None);
+    }
+}
@@ -0,0 +1,163 @@
+use crate::cli_options::CliOptions;
+use crate::TraversalSummary;
+use biome_diagnostics::{Error, Resource};
+use biome_fs::{FileSystem, FileSystemExt};
+use rustc_hash::FxHashSet;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io;
+use std::path::PathBuf;
+
+/// The maximum number of files listed by the summary
+const MAX_LISTED_FILES: usize = 10;
+
+/// The files whose diagnostics are considered new, the diagnostics of the other files are
+/// considered pre-existing
+#[derive(Debug, Clone)]
+pub(crate) enum ChangedFiles {
+    /// The base branch isn't known, so the summary doesn't tell the diagnostics apart
+    Unknown,
+    /// Only the files changed compared to the base branch are processed
+    All,
+    /// The files changed compared to the base branch
+    Some(FxHashSet<String>),
+}
+
+impl ChangedFiles {
+    fn contains(&self, path: &str) -> Option<bool> {
+        match self {
+            Self::Unknown => None,
+            Self::All => Some(true),
+            Self::Some(files) => Some(files.contains(path.strip_prefix("./").unwrap_or(path))),
+        }
+    }
+}
+
+/// A Markdown summary of the diagnostics emitted by `biome ci`, written to a file so that
+/// it can be displayed by the CI, for example with `$GITHUB_STEP_SUMMARY`.
+#[derive(Debug)]
+pub(crate) struct CiSummary {
+    /// The file where the summary is written
+    path: PathBuf,
+    changed_files: ChangedFiles,
+}
+
+/// The number of diagnostics emitted by a rule
+#[derive(Debug, Default)]
+struct RuleCount {
+    total: usize,
+    new: usize,
+}
+
+impl CiSummary {
+    pub(crate) fn new(path: PathBuf, changed_files: ChangedFiles) -> Self {
+        Self {
+            path,
+            changed_files,
+        }
+    }
+
+    /// Writes the summary of the `diagnostics` that are printed with `cli_options`,
+    /// replacing the content of the file
+    pub(crate) fn write(
+        &self,
+        fs: &dyn FileSystem,
+        summary: &TraversalSummary,
+        diagnostics: &[Error],
+        cli_options: &CliOptions,
+    ) -> io::Result<()> {
+        let content = self.to_markdown(summary, diagnostics, cli_options);
+        fs.create(&self.path)?.set_content(content.as_bytes())
+    }
+
+    fn to_markdown(
+        &self,
+        summary: &TraversalSummary,
+        diagnostics: &[Error],
+        cli_options: &CliOptions,
+    ) -> String {
+        let diagnostics = diagnostics.iter().filter(|diagnostic| {
+            (cli_options.verbose || !diagnostic.tags().is_verbose())
+                && diagnostic.severity() >= cli_options.diagnostic_level
+        });
+
+        let mut rules: BTreeMap<&str, RuleCount> = BTreeMap::new();
+        let mut files: BTreeMap<&str, usize> = BTreeMap::new();
+        for diagnostic in diagnostics {
+            let location = diagnostic.location();
+            let path = match location.resource {
+                Some(Resource::File(path)) => Some(path),
+                _ => None,
+            };
+            let rule = diagnostic
+                .category()
+                .map_or("unknown", |category| category.name());
+            let count = rules.entry(rule).or_default();
+            count.total += 1;
+            if path.and_then(|path| self.changed_files.contains(path)) == Some(true) {
+                count.new += 1;
+            }
+            if let Some(path) = path {
+                *files
+                    .entry(path.strip_prefix("./").unwrap_or(path))
+                    .or_default() += 1;
+            }
+        }
+
+        let mut markdown = String::from("## Biome\n\n");
+        let _ = writeln!(
+            markdown,
+            "Checked {} {}. Found {} {} and {} {}.",
+            summary.changed + summary.unchanged,
+            plural(summary.changed + summary.unchanged, "file", "files"),
+            summary.errors,
+            plural(summary.errors as usize, "error", "errors"),
+            summary.warnings,
+            plural(summary.warnings as usize, "warning", "warnings"),
+        );
+        if rules.is_empty() {
+            return markdown;
+        }
+
+        // The rules that emit the most diagnostics come first
+        let mut rules = rules.into_iter().collect::<Vec<_>>();
+        rules.sort_by(|(_, a), (_, b)| b.total.cmp(&a.total));
+        let is_baseline_known = !matches!(self.changed_files, ChangedFiles::Unknown);
+        markdown.push_str("\n### Diagnostics per rule\n\n");
+        if is_baseline_known {
+            markdown.push_str("| Rule | Diagnostics | New | Pre-existing |\n");
+            markdown.push_str("| :--- | ---: | ---: | ---: |\n");
+        } else {
+            markdown.push_str("| Rule | Diagnostics |\n");
+            markdown.push_str("| :--- | ---: |\n");
+        }
+        for (rule, count) in rules {
+            let _ = write!(markdown, "| `{rule}` | {} |", count.total);
+            if is_baseline_known {
+                let _ = write!(markdown, " {} | {} |", count.new, count.total - count.new);
+            }
+            markdown.push('\n');
+        }
+
+        let mut files = files.into_iter().collect::<Vec<_>>();
+        if !files.is_empty() {
+            files.sort_by(|(_, a), (_, b)| b.cmp(a));
+            markdown.push_str("\n### Top offending files\n\n");
+            markdown.push_str("| File | Diagnostics |\n");
+            markdown.push_str("| :--- | ---: |\n");
+            for (path, count) in files.into_iter().take(MAX_LISTED_FILES) {
+                let _ = writeln!(markdown, "| `{path}` | {count} |");
+            }
+        }
+
+        markdown
+    }
+}
+
+fn plural<'a>(count: usize, singular: &'a str, plural: &'a str) -> &'a str {
+    if count == 1 {
+        singular
+    } else {
+        plural
+    }
+}
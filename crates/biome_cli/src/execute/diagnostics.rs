@@ -80,6 +80,19 @@ pub(crate) struct AssistsDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "search",
+    severity = Information,
+    message = "The pattern would have rewritten the following content:"
+)]
+pub(crate) struct RewriteDiffDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
 	category = "migrate",
@@ -123,6 +136,50 @@ pub(crate) struct PanicDiagnostic {
 )]
 pub(crate) struct UnhandledDiagnostic;
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(category = "files/skipped", severity = Information, tags(VERBOSE))]
+pub(crate) struct SkippedFileDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[message]
+    #[description]
+    pub(crate) reason: &'static str,
+}
+
+impl SkippedFileDiagnostic {
+    pub(crate) fn new(file_name: impl Into<String>, reason: SkipReason) -> Self {
+        Self {
+            file_name: file_name.into(),
+            reason: reason.message(),
+        }
+    }
+}
+
+/// Why the traversal skipped a file without processing it
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum SkipReason {
+    /// The file is a symbolic link, and `files.followSymlinks` is disabled
+    Symlink,
+    /// The file matches a pattern of `files.binaryFiles`
+    BinaryPattern,
+    /// The content of the file isn't text
+    BinaryContent,
+}
+
+impl SkipReason {
+    const fn message(self) -> &'static str {
+        match self {
+            Self::Symlink => {
+                "Skipped the symbolic link, because the option files.followSymlinks is disabled."
+            }
+            Self::BinaryPattern => {
+                "Skipped the binary file, because it matches a pattern of the option files.binaryFiles."
+            }
+            Self::BinaryContent => "Skipped the binary file, because its content isn't text.",
+        }
+    }
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(category = "parse", message = "Skipped file with syntax errors")]
 pub(crate) struct SkippedDiagnostic;
@@ -22,6 +22,7 @@ use std::path::PathBuf;
 mod eslint;
 mod eslint_any_rule_to_biome;
 mod eslint_eslint;
+mod eslint_import;
 mod eslint_jsxa11y;
 mod eslint_to_biome;
 mod eslint_typescript;
@@ -235,6 +236,16 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
                     <Info>"Run the command with the option "<Emphasis>"--include-inspired"</Emphasis>" to also migrate inspired rules."</Info>
                 })
             }
+            if !results.unsupported_rules.is_empty() {
+                let unsupported_rules = results
+                    .unsupported_rules
+                    .into_iter()
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                console.log(markup! {
+                    <Warn>"The following rules have no equivalent in Biome, and were not migrated: "{unsupported_rules}"."</Warn>
+                })
+            }
         }
         None => {
             let has_deprecated_configuration =
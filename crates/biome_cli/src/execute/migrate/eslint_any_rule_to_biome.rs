@@ -1717,6 +1717,9 @@ pub(crate) fn migrate_eslint_any_rule(
             rule.set_level(rule_severity.into());
         }
         _ => {
+            if !matches!(rule_severity, eslint_eslint::Severity::Off) {
+                results.unsupported_rules.insert(eslint_name.to_string());
+            }
             return false;
         }
     }
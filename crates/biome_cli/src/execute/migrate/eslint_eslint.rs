@@ -8,11 +8,12 @@ use indexmap::IndexSet;
 use rustc_hash::FxHashMap;
 use std::borrow::Cow;
 use std::hash::{Hash, Hasher};
+use std::num::NonZeroU8;
 use std::ops::DerefMut;
 use std::vec;
 use std::{any::TypeId, marker::PhantomData, ops::Deref};
 
-use super::{eslint_jsxa11y, eslint_typescript, eslint_unicorn, ignorefile};
+use super::{eslint_import, eslint_jsxa11y, eslint_typescript, eslint_unicorn, ignorefile};
 
 /// This modules includes implementations for deserializing an eslint configuration.
 ///
@@ -489,6 +490,11 @@ impl Deserializable for Rules {
                             }
                         }
                         // Eslint plugin rules with options that we handle
+                        "import/no-extraneous-dependencies" => {
+                            if let Some(conf) = RuleConf::deserialize(&value, name, diagnostics) {
+                                result.insert(Rule::ImportNoExtraneousDependencies(conf));
+                            }
+                        }
                         "jsx-a11y/aria-role" => {
                             if let Some(conf) = RuleConf::deserialize(&value, name, diagnostics) {
                                 result.insert(Rule::Jsxa11yArioaRoles(conf));
@@ -509,6 +515,11 @@ impl Deserializable for Rules {
                                 result.insert(Rule::TypeScriptNamingConvention(conf));
                             }
                         }
+                        "sonarjs/cognitive-complexity" => {
+                            if let Some(conf) = RuleConf::deserialize(&value, name, diagnostics) {
+                                result.insert(Rule::SonarjsCognitiveComplexity(conf));
+                            }
+                        }
                         "unicorn/filename-case" => {
                             if let Some(conf) = RuleConf::deserialize(&value, name, diagnostics) {
                                 result.insert(Rule::UnicornFilenameCase(conf));
@@ -587,7 +598,9 @@ pub(crate) enum Rule {
     NoConsole(RuleConf<Box<NoConsoleOptions>>),
     NoRestrictedGlobals(RuleConf<Box<NoRestrictedGlobal>>),
     // Eslint plugins
+    ImportNoExtraneousDependencies(RuleConf<Box<eslint_import::NoExtraneousDependenciesOptions>>),
     Jsxa11yArioaRoles(RuleConf<Box<eslint_jsxa11y::AriaRoleOptions>>),
+    SonarjsCognitiveComplexity(RuleConf<NonZeroU8>),
     TypeScriptArrayType(RuleConf<eslint_typescript::ArrayTypeOptions>),
     TypeScriptExplicitMemberAccessibility(
         RuleConf<eslint_typescript::ExplicitMemberAccessibilityOptions>,
@@ -602,7 +615,11 @@ impl Rule {
             Rule::Any(name, _) => name.clone(),
             Rule::NoConsole(_) => Cow::Borrowed("no-console"),
             Rule::NoRestrictedGlobals(_) => Cow::Borrowed("no-restricted-globals"),
+            Rule::ImportNoExtraneousDependencies(_) => {
+                Cow::Borrowed("import/no-extraneous-dependencies")
+            }
             Rule::Jsxa11yArioaRoles(_) => Cow::Borrowed("jsx-a11y/aria-role"),
+            Rule::SonarjsCognitiveComplexity(_) => Cow::Borrowed("sonarjs/cognitive-complexity"),
             Rule::TypeScriptArrayType(_) => Cow::Borrowed("@typescript-eslint/array-type"),
             Rule::TypeScriptExplicitMemberAccessibility(_) => {
                 Cow::Borrowed("@typescript-eslint/explicit-member-accessibility")
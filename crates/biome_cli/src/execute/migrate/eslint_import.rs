@@ -0,0 +1,25 @@
+/// Configuration related to the
+/// [Import Eslint plugin](https://github.com/import-js/eslint-plugin-import).
+///
+/// Also, the module includes implementation to convert rule options to Biome's rule options.
+use biome_deserialize_macros::Deserializable;
+use biome_js_analyze::lint::correctness::no_undeclared_dependencies;
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct NoExtraneousDependenciesOptions {
+    dev_dependencies: Option<no_undeclared_dependencies::DependencyAvailability>,
+    peer_dependencies: Option<no_undeclared_dependencies::DependencyAvailability>,
+    optional_dependencies: Option<no_undeclared_dependencies::DependencyAvailability>,
+}
+impl From<NoExtraneousDependenciesOptions>
+    for no_undeclared_dependencies::NoUndeclaredDependenciesOptions
+{
+    fn from(val: NoExtraneousDependenciesOptions) -> Self {
+        no_undeclared_dependencies::NoUndeclaredDependenciesOptions {
+            dev_dependencies: val.dev_dependencies.unwrap_or_default(),
+            peer_dependencies: val.peer_dependencies.unwrap_or_default(),
+            optional_dependencies: val.optional_dependencies.unwrap_or_default(),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use biome_configuration::{self as biome_config};
 use biome_deserialize::{Merge, StringSet};
+use biome_js_analyze::lint::complexity::no_excessive_cognitive_complexity;
 use biome_js_analyze::lint::style::no_restricted_globals;
+use std::collections::BTreeSet;
 
 use super::{eslint_any_rule_to_biome::migrate_eslint_any_rule, eslint_eslint, eslint_typescript};
 
@@ -24,6 +26,8 @@ pub(crate) struct MigrationOptions {
 pub(crate) struct MigrationResults {
     // Contains inspired rules that were not migrated because `include_inspired` is disabled
     pub(crate) has_inspired_rules: bool,
+    // The enabled rules that were not migrated because Biome has no equivalent rule
+    pub(crate) unsupported_rules: BTreeSet<String>,
 }
 
 impl eslint_eslint::AnyConfigData {
@@ -239,6 +243,20 @@ fn migrate_eslint_rule(
                 ));
             }
         }
+        eslint_eslint::Rule::ImportNoExtraneousDependencies(conf) => {
+            if migrate_eslint_any_rule(rules, &name, conf.severity(), opts, results) {
+                if let eslint_eslint::RuleConf::Option(severity, rule_options) = conf {
+                    let group = rules.correctness.get_or_insert_with(Default::default);
+                    group.no_undeclared_dependencies =
+                        Some(biome_config::RuleConfiguration::WithOptions(
+                            biome_config::RuleWithOptions {
+                                level: severity.into(),
+                                options: (*rule_options).into(),
+                            },
+                        ));
+                }
+            }
+        }
         eslint_eslint::Rule::Jsxa11yArioaRoles(conf) => {
             if migrate_eslint_any_rule(rules, &name, conf.severity(), opts, results) {
                 if let eslint_eslint::RuleConf::Option(severity, rule_options) = conf {
@@ -254,6 +272,22 @@ fn migrate_eslint_rule(
                 }
             }
         }
+        eslint_eslint::Rule::SonarjsCognitiveComplexity(conf) => {
+            if migrate_eslint_any_rule(rules, &name, conf.severity(), opts, results) {
+                if let eslint_eslint::RuleConf::Option(severity, max_allowed_complexity) = conf {
+                    let group = rules.complexity.get_or_insert_with(Default::default);
+                    group.no_excessive_cognitive_complexity =
+                        Some(biome_config::RuleConfiguration::WithOptions(
+                            biome_config::RuleWithOptions {
+                                level: severity.into(),
+                                options: no_excessive_cognitive_complexity::ComplexityOptions {
+                                    max_allowed_complexity,
+                                },
+                            },
+                        ));
+                }
+            }
+        }
         eslint_eslint::Rule::TypeScriptArrayType(conf) => {
             if migrate_eslint_any_rule(rules, &name, conf.severity(), opts, results) {
                 if let eslint_eslint::RuleConf::Option(severity, rule_options) = conf {
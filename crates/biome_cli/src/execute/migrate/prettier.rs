@@ -9,6 +9,7 @@ use biome_formatter::{
     QuoteStyle,
 };
 use biome_fs::{FileSystem, OpenOptions};
+use biome_html_formatter::context::WhitespaceSensitivity;
 use biome_js_formatter::context::{ArrowParentheses, QuoteProperties, Semicolons, TrailingCommas};
 use biome_json_parser::JsonParserOptions;
 use biome_service::DynRef;
@@ -48,7 +49,7 @@ pub(crate) struct PrettierConfiguration {
     /// https://prettier.io/docs/en/options#bracket-spcing
     bracket_spacing: bool,
     /// https://prettier.io/docs/en/options#bracket-line
-    bracket_line: bool,
+    bracket_same_line: bool,
     /// https://prettier.io/docs/en/options#quote-props
     quote_props: QuoteProps,
     /// https://prettier.io/docs/en/options#jsx-quotes
@@ -57,6 +58,24 @@ pub(crate) struct PrettierConfiguration {
     arrow_parens: ArrowParens,
     /// https://prettier.io/docs/en/options#end-of-line
     end_of_line: EndOfLine,
+    /// https://prettier.io/docs/en/options#single-attribute-per-line
+    single_attribute_per_line: bool,
+    /// https://prettier.io/docs/en/options#html-whitespace-sensitivity
+    html_whitespace_sensitivity: Option<HtmlWhitespaceSensitivity>,
+    /// https://prettier.io/docs/en/options#embedded-language-formatting
+    embedded_language_formatting: Option<EmbeddedLanguageFormatting>,
+    /// https://prettier.io/docs/en/options#prose-wrap
+    prose_wrap: Option<String>,
+    /// https://prettier.io/docs/en/options#vue-files-script-and-style-tags-indentation
+    vue_indent_script_and_style: Option<bool>,
+    /// https://prettier.io/docs/en/options#experimental-ternaries
+    experimental_ternaries: Option<bool>,
+    /// https://prettier.io/docs/en/options#require-pragma
+    require_pragma: Option<bool>,
+    /// https://prettier.io/docs/en/options#insert-pragma
+    insert_pragma: Option<bool>,
+    /// https://prettier.io/docs/en/plugins
+    plugins: Vec<String>,
     /// https://prettier.io/docs/en/configuration.html#configuration-overrides
     overrides: Vec<Override>,
 }
@@ -71,19 +90,71 @@ impl Default for PrettierConfiguration {
             semi: false,
             single_quote: true,
             bracket_spacing: true,
-            bracket_line: false,
+            bracket_same_line: false,
             quote_props: QuoteProps::default(),
             jsx_single_quote: false,
             arrow_parens: ArrowParens::default(),
             end_of_line: EndOfLine::default(),
+            single_attribute_per_line: false,
+            html_whitespace_sensitivity: None,
+            embedded_language_formatting: None,
+            prose_wrap: None,
+            vue_indent_script_and_style: None,
+            experimental_ternaries: None,
+            require_pragma: None,
+            insert_pragma: None,
+            plugins: vec![],
             overrides: vec![],
         }
     }
 }
 
+impl PrettierConfiguration {
+    /// Returns the options that are set in the configuration, but can't be migrated
+    /// because Biome doesn't have an equivalent.
+    pub(crate) fn unsupported_options(&self) -> Vec<&'static str> {
+        let mut options = Vec::new();
+        // Biome doesn't format the languages embedded in the templates, like `off` does
+        if self.embedded_language_formatting == Some(EmbeddedLanguageFormatting::Auto) {
+            options.push("embeddedLanguageFormatting");
+        }
+        if self
+            .prose_wrap
+            .as_ref()
+            .is_some_and(|prose_wrap| prose_wrap != "preserve")
+        {
+            options.push("proseWrap");
+        }
+        if self.vue_indent_script_and_style == Some(true) {
+            options.push("vueIndentScriptAndStyle");
+        }
+        if self.experimental_ternaries == Some(true) {
+            options.push("experimentalTernaries");
+        }
+        if self.require_pragma == Some(true) {
+            options.push("requirePragma");
+        }
+        if self.insert_pragma == Some(true) {
+            options.push("insertPragma");
+        }
+        if !self.plugins.is_empty() {
+            options.push("plugins");
+        }
+        if self
+            .overrides
+            .iter()
+            .any(|override_elt| override_elt.options.parser.is_some())
+        {
+            options.push("overrides.options.parser");
+        }
+        options
+    }
+}
+
 #[derive(Debug, Default, Deserializable)]
 pub(crate) struct Override {
     files: ShorthandVec<String>,
+    exclude_files: ShorthandVec<String>,
     options: OverrideOptions,
 }
 
@@ -105,7 +176,7 @@ pub(crate) struct OverrideOptions {
     /// https://prettier.io/docs/en/options#bracket-spcing
     bracket_spacing: Option<bool>,
     /// https://prettier.io/docs/en/options#bracket-line
-    bracket_line: Option<bool>,
+    bracket_same_line: Option<bool>,
     /// https://prettier.io/docs/en/options#quote-props
     quote_props: Option<QuoteProps>,
     /// https://prettier.io/docs/en/options#jsx-quotes
@@ -114,6 +185,25 @@ pub(crate) struct OverrideOptions {
     arrow_parens: Option<ArrowParens>,
     /// https://prettier.io/docs/en/options#end-of-line
     end_of_line: Option<EndOfLine>,
+    /// https://prettier.io/docs/en/options#single-attribute-per-line
+    single_attribute_per_line: Option<bool>,
+    /// https://prettier.io/docs/en/options#html-whitespace-sensitivity
+    html_whitespace_sensitivity: Option<HtmlWhitespaceSensitivity>,
+    /// https://prettier.io/docs/en/options#parser
+    parser: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserializable, Eq, PartialEq)]
+enum HtmlWhitespaceSensitivity {
+    Css,
+    Strict,
+    Ignore,
+}
+
+#[derive(Clone, Debug, Deserializable, Eq, PartialEq)]
+enum EmbeddedLanguageFormatting {
+    Auto,
+    Off,
 }
 
 #[derive(Clone, Debug, Default, Deserializable, Eq, PartialEq)]
@@ -178,6 +268,16 @@ impl From<EndOfLine> for LineEnding {
     }
 }
 
+impl From<HtmlWhitespaceSensitivity> for WhitespaceSensitivity {
+    fn from(value: HtmlWhitespaceSensitivity) -> Self {
+        match value {
+            HtmlWhitespaceSensitivity::Css => Self::Css,
+            HtmlWhitespaceSensitivity::Strict => Self::Strict,
+            HtmlWhitespaceSensitivity::Ignore => Self::Ignore,
+        }
+    }
+}
+
 impl From<QuoteProps> for QuoteProperties {
     fn from(value: QuoteProps) -> Self {
         match value {
@@ -199,12 +299,17 @@ impl TryFrom<PrettierConfiguration> for biome_configuration::PartialConfiguratio
         } else {
             biome_formatter::IndentStyle::Space
         };
+        let attribute_position = if value.single_attribute_per_line {
+            AttributePosition::Multiline
+        } else {
+            AttributePosition::Auto
+        };
         let formatter = biome_configuration::PartialFormatterConfiguration {
             indent_width: Some(indent_width),
             line_width: Some(line_width),
             indent_style: Some(indent_style),
             line_ending: Some(value.end_of_line.into()),
-            attribute_position: Some(AttributePosition::default()),
+            attribute_position: Some(attribute_position),
             format_with_errors: Some(false),
             ignore: None,
             include: None,
@@ -243,7 +348,7 @@ impl TryFrom<PrettierConfiguration> for biome_configuration::PartialConfiguratio
             indent_size: None,
 
             // js ones
-            bracket_same_line: Some(value.bracket_line),
+            bracket_same_line: Some(value.bracket_same_line),
             arrow_parentheses: Some(value.arrow_parens.into()),
             semicolons: Some(semicolons),
             trailing_commas: Some(value.trailing_comma.into()),
@@ -253,13 +358,16 @@ impl TryFrom<PrettierConfiguration> for biome_configuration::PartialConfiguratio
             quote_properties: Some(value.quote_props.into()),
             bracket_spacing: Some(value.bracket_spacing.into()),
             jsx_quote_style: Some(jsx_quote_style),
-            attribute_position: Some(AttributePosition::default()),
+            attribute_position: Some(attribute_position),
         };
         let js_config = biome_configuration::PartialJavascriptConfiguration {
             formatter: Some(js_formatter),
             ..Default::default()
         };
         result.javascript = Some(js_config);
+        if let Some(whitespace_sensitivity) = value.html_whitespace_sensitivity {
+            result.html = Some(html_configuration(whitespace_sensitivity));
+        }
         if !value.overrides.is_empty() {
             let mut overrides = biome_configuration::Overrides::default();
             for override_elt in value.overrides {
@@ -273,15 +381,28 @@ impl TryFrom<PrettierConfiguration> for biome_configuration::PartialConfiguratio
 
 impl TryFrom<Override> for biome_configuration::OverridePattern {
     type Error = ParseFormatNumberError;
-    fn try_from(Override { files, options }: Override) -> Result<Self, Self::Error> {
+    fn try_from(
+        Override {
+            files,
+            exclude_files,
+            options,
+        }: Override,
+    ) -> Result<Self, Self::Error> {
         let mut result = biome_configuration::OverridePattern {
             include: Some(StringSet::new(files.into_iter().collect())),
             ..Default::default()
         };
+        if !exclude_files.is_empty() {
+            result.ignore = Some(StringSet::new(exclude_files.into_iter().collect()));
+        }
+        if let Some(whitespace_sensitivity) = options.html_whitespace_sensitivity {
+            result.html = Some(html_configuration(whitespace_sensitivity));
+        }
         if options.print_width.is_some()
             || options.use_tabs.is_some()
             || options.tab_width.is_some()
             || options.end_of_line.is_some()
+            || options.single_attribute_per_line.is_some()
         {
             // are global options are set
             let line_width = if let Some(print_width) = options.print_width {
@@ -307,6 +428,15 @@ impl TryFrom<Override> for biome_configuration::OverridePattern {
                 line_width,
                 indent_style,
                 line_ending: options.end_of_line.map(|end_of_line| end_of_line.into()),
+                attribute_position: options.single_attribute_per_line.map(
+                    |single_attribute_per_line| {
+                        if single_attribute_per_line {
+                            AttributePosition::Multiline
+                        } else {
+                            AttributePosition::Auto
+                        }
+                    },
+                ),
                 ..Default::default()
             };
             result.formatter = Some(formatter);
@@ -314,7 +444,7 @@ impl TryFrom<Override> for biome_configuration::OverridePattern {
         if options.semi.is_none()
             && options.single_quote.is_none()
             && options.jsx_single_quote.is_none()
-            && options.bracket_line.is_none()
+            && options.bracket_same_line.is_none()
             && options.arrow_parens.is_none()
             && options.trailing_comma.is_none()
             && options.quote_props.is_none()
@@ -346,7 +476,7 @@ impl TryFrom<Override> for biome_configuration::OverridePattern {
             }
         });
         let js_formatter = biome_configuration::PartialJavascriptFormatter {
-            bracket_same_line: options.bracket_line,
+            bracket_same_line: options.bracket_same_line,
             arrow_parentheses: options.arrow_parens.map(|arrow_parens| arrow_parens.into()),
             semicolons,
             trailing_commas: options
@@ -354,6 +484,9 @@ impl TryFrom<Override> for biome_configuration::OverridePattern {
                 .map(|trailing_comma| trailing_comma.into()),
             quote_style,
             quote_properties: options.quote_props.map(|quote_props| quote_props.into()),
+            bracket_spacing: options
+                .bracket_spacing
+                .map(|bracket_spacing| bracket_spacing.into()),
             jsx_quote_style,
             ..Default::default()
         };
@@ -366,6 +499,19 @@ impl TryFrom<Override> for biome_configuration::OverridePattern {
     }
 }
 
+/// Returns the HTML configuration that uses the given whitespace sensitivity
+fn html_configuration(
+    whitespace_sensitivity: HtmlWhitespaceSensitivity,
+) -> biome_configuration::PartialHtmlConfiguration {
+    biome_configuration::PartialHtmlConfiguration {
+        formatter: Some(biome_configuration::PartialHtmlFormatter {
+            whitespace_sensitivity: Some(whitespace_sensitivity.into()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
 /// A Prettier config can be embedded in `package.json`
 const PACKAGE_JSON: &str = "package.json";
 
@@ -487,6 +633,13 @@ fn load_config(
                 <Warn>"Prettier's `\"endOfLine\": \"auto\"` option is not supported in Biome. The default `\"lf\"` option is used instead."</Warn>
             });
         }
+        let unsupported_options = result.unsupported_options();
+        if !unsupported_options.is_empty() {
+            let unsupported_options = unsupported_options.join(", ");
+            console.log(markup! {
+                <Warn>"The following Prettier options can't be migrated because Biome doesn't have an equivalent: "{unsupported_options}"."</Warn>
+            });
+        }
         Ok(result)
     } else if path.extension().is_none() {
         // The Prettier config file may be a YAML file.
@@ -504,6 +657,8 @@ fn load_config(
 mod tests {
     use crate::execute::migrate::prettier::{PrettierConfiguration, PrettierTrailingComma};
     use biome_deserialize::json::deserialize_from_json_str;
+    use biome_formatter::AttributePosition;
+    use biome_html_formatter::context::WhitespaceSensitivity;
     use biome_json_parser::JsonParserOptions;
 
     #[test]
@@ -556,4 +711,78 @@ mod tests {
             }
         ))
     }
+
+    #[test]
+    fn html_and_attribute_options() {
+        let configuration = deserialize_from_json_str::<PrettierConfiguration>(
+            r#"
+{
+  "singleAttributePerLine": true,
+  "htmlWhitespaceSensitivity": "strict",
+  "overrides": [
+    {
+      "files": "*.html",
+      "excludeFiles": ["vendor/*.html"],
+      "options": { "htmlWhitespaceSensitivity": "ignore" }
+    }
+  ]
+}
+            "#,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+
+        let configuration = biome_configuration::PartialConfiguration::try_from(configuration)
+            .expect("the configuration to be converted");
+        let formatter = configuration.formatter.unwrap();
+        assert_eq!(
+            formatter.attribute_position,
+            Some(AttributePosition::Multiline)
+        );
+        let html_formatter = configuration.html.unwrap().formatter.unwrap();
+        assert_eq!(
+            html_formatter.whitespace_sensitivity,
+            Some(WhitespaceSensitivity::Strict)
+        );
+        let override_pattern = &configuration.overrides.unwrap().0[0];
+        assert_eq!(
+            override_pattern
+                .ignore
+                .as_ref()
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec!["vendor/*.html"]
+        );
+        let override_html_formatter = override_pattern.html.clone().unwrap().formatter.unwrap();
+        assert_eq!(
+            override_html_formatter.whitespace_sensitivity,
+            Some(WhitespaceSensitivity::Ignore)
+        );
+    }
+
+    #[test]
+    fn unsupported_options() {
+        let configuration = deserialize_from_json_str::<PrettierConfiguration>(
+            r#"
+{
+  "proseWrap": "always",
+  "embeddedLanguageFormatting": "off",
+  "plugins": ["prettier-plugin-tailwindcss"],
+  "overrides": [{ "files": "*.mdx", "options": { "parser": "markdown" } }]
+}
+            "#,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+
+        assert_eq!(
+            configuration.unsupported_options(),
+            vec!["proseWrap", "plugins", "overrides.options.parser"]
+        );
+    }
 }
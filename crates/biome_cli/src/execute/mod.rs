@@ -1,14 +1,25 @@
+pub(crate) mod cache;
+pub(crate) mod ci_summary;
 mod diagnostics;
 mod migrate;
+pub(crate) mod module_graph;
 mod process_file;
+mod profile;
 mod std_in;
 pub(crate) mod traverse;
+mod watch;
 
 use crate::cli_options::{CliOptions, CliReporter};
 use crate::commands::MigrateSubCommand;
 use crate::diagnostics::ReportDiagnostic;
+use crate::execute::cache::ResultCache;
+use crate::execute::ci_summary::CiSummary;
 use crate::execute::migrate::MigratePayload;
+use crate::execute::module_graph::ModuleGraphExport;
+use crate::execute::profile::print_profile;
 use crate::execute::traverse::{traverse, TraverseResult};
+use crate::reporter::checkstyle::{CheckstyleReporter, CheckstyleReporterVisitor};
+use crate::reporter::diff::{DiffReporter, DiffReporterVisitor};
 use crate::reporter::github::{GithubReporter, GithubReporterVisitor};
 use crate::reporter::gitlab::{GitLabReporter, GitLabReporterVisitor};
 use crate::reporter::json::{JsonReporter, JsonReporterVisitor};
@@ -16,20 +27,30 @@ use crate::reporter::junit::{JunitReporter, JunitReporterVisitor};
 use crate::reporter::summary::{SummaryReporter, SummaryReporterVisitor};
 use crate::reporter::terminal::{ConsoleReporter, ConsoleReporterVisitor};
 use crate::{CliDiagnostic, CliSession, DiagnosticsPayload, Reporter};
+use biome_analyze::profiling;
 use biome_configuration::analyzer::RuleSelector;
 use biome_console::{markup, ConsoleExt};
 use biome_diagnostics::adapters::SerdeJsonError;
-use biome_diagnostics::{category, Category};
+use biome_diagnostics::{category, Category, PrintDiagnostic};
 use biome_fs::BiomePath;
+use biome_rowan::{TextRange, TextSize};
 use biome_service::workspace::{
-    FeatureName, FeaturesBuilder, FixFileMode, FormatFileParams, OpenFileParams, PatternId,
+    ExportProjectIndexParams, FeatureName, FeaturesBuilder, FixFileMode, FormatFileParams,
+    OpenFileParams, PatternId,
 };
+use biome_service::Matcher;
+use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
 use std::ffi::OsString;
 use std::fmt::{Display, Formatter};
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
 use tracing::info;
 
+pub(crate) use watch::watch_mode;
+
 /// Useful information during the traversal of files and virtual content
 #[derive(Debug, Clone)]
 pub struct Execution {
@@ -41,6 +62,30 @@ pub struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u32,
+
+    /// The results of the previous runs, when the command is run with `--cache`
+    cache: Option<Arc<ResultCache>>,
+
+    /// The number of threads that process the files, when it's set by `files.threads`
+    threads: Option<NonZeroUsize>,
+
+    /// The maximum number of files processed at the same time, when it's set by `files.maxOpenFiles`
+    max_open_files: Option<NonZeroUsize>,
+
+    /// Whether the traversal follows the symbolic links, set by `files.followSymlinks`
+    follow_symlinks: bool,
+
+    /// The files skipped as binary files, when `files.binaryFiles` is set
+    binary_files: Option<Arc<Matcher>>,
+
+    /// The Markdown summary of the diagnostics, when `biome ci` is run with `--summary-file`
+    ci_summary: Option<Arc<CiSummary>>,
+
+    /// The number of warnings above which the command exits with an error code
+    max_warnings: Option<u32>,
+
+    /// The graph of the imports between the scripts, when `biome check` is run with `--emit-module-graph`
+    module_graph: Option<Arc<ModuleGraphExport>>,
 }
 
 impl Execution {
@@ -50,10 +95,19 @@ impl Execution {
                 ignore_errors: false,
                 write: false,
                 stdin: None,
+                range: None,
                 vcs_targeted,
             },
             report_mode: ReportMode::default(),
             max_diagnostics: 0,
+            cache: None,
+            threads: None,
+            max_open_files: None,
+            follow_symlinks: true,
+            binary_files: None,
+            ci_summary: None,
+            max_warnings: None,
+            module_graph: None,
         }
     }
 
@@ -86,26 +140,108 @@ pub enum ExecutionEnvironment {
 
 /// A type that holds the information to execute the CLI via `stdin
 #[derive(Debug, Clone)]
-pub struct Stdin(
+pub enum Stdin {
+    /// A single file, whose virtual path is passed with `--stdin-file-path`
+    File(StdinFile),
+    /// Several files, read as a JSON array of [StdinFile] with `--stdin-files`
+    Files(Vec<StdinFile>),
+}
+
+/// A virtual file whose content is read from `stdin`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StdinFile {
     /// The virtual path to the file
-    PathBuf,
+    pub(crate) path: PathBuf,
     /// The content of the file
-    String,
-);
+    pub(crate) content: String,
+}
 
-impl Stdin {
-    fn as_path(&self) -> &Path {
-        self.0.as_path()
+impl From<(PathBuf, String)> for Stdin {
+    fn from((path, content): (PathBuf, String)) -> Self {
+        Self::File(StdinFile { path, content })
     }
+}
+
+/// The part of the file read from `stdin` that is formatted, passed with `--range`
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FormatRange {
+    /// Byte offsets, for example `10:42`
+    Offsets { start: u32, end: u32 },
+    /// Lines and columns, starting from 1, for example `2:1-4:12`.
+    /// The columns are counted in characters.
+    Positions { start: (u32, u32), end: (u32, u32) },
+}
 
-    fn as_content(&self) -> &str {
-        self.1.as_str()
+impl FormatRange {
+    /// Returns the range of `content` covered by this range, or [None] if it's out of bounds
+    pub(crate) fn to_text_range(self, content: &str) -> Option<TextRange> {
+        let (start, end) = match self {
+            Self::Offsets { start, end } => (start as usize, end as usize),
+            Self::Positions { start, end } => (
+                position_to_offset(content, start)?,
+                position_to_offset(content, end)?,
+            ),
+        };
+        if start > end || end > content.len() {
+            return None;
+        }
+        Some(TextRange::new(
+            TextSize::try_from(start).ok()?,
+            TextSize::try_from(end).ok()?,
+        ))
     }
 }
 
-impl From<(PathBuf, String)> for Stdin {
-    fn from((path, content): (PathBuf, String)) -> Self {
-        Self(path, content)
+/// Returns the byte offset of a position of `content`, given by its line and its column
+fn position_to_offset(content: &str, (line, column): (u32, u32)) -> Option<usize> {
+    let line_start = match line.checked_sub(1)? {
+        0 => 0,
+        index => content.match_indices('\n').nth(index as usize - 1)?.0 + 1,
+    };
+    let line_text = content[line_start..].split('\n').next()?;
+    line_text
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(line_text.len()))
+        .nth(column.checked_sub(1)? as usize)
+        .map(|offset| line_start + offset)
+}
+
+impl FromStr for FormatRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            format!("value {s:?} is not valid, use START:END with byte offsets, or LINE:COLUMN-LINE:COLUMN")
+        };
+        let parse = |value: &str| value.parse::<u32>().map_err(|_| invalid());
+        let parse_position = |value: &str| {
+            let (line, column) = value.split_once(':').ok_or_else(invalid)?;
+            Ok::<_, String>((parse(line)?, parse(column)?))
+        };
+        if let Some((start, end)) = s.split_once('-') {
+            Ok(Self::Positions {
+                start: parse_position(start)?,
+                end: parse_position(end)?,
+            })
+        } else {
+            let (start, end) = s.split_once(':').ok_or_else(invalid)?;
+            Ok(Self::Offsets {
+                start: parse(start)?,
+                end: parse(end)?,
+            })
+        }
+    }
+}
+
+impl Display for FormatRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Offsets { start, end } => write!(f, "{start}:{end}"),
+            Self::Positions { start, end } => {
+                write!(f, "{}:{}-{}:{}", start.0, start.1, end.0, end.1)
+            }
+        }
     }
 }
 
@@ -179,6 +315,9 @@ pub enum TraversalMode {
         /// 1. The virtual path to the file
         /// 2. The content of the file
         stdin: Option<Stdin>,
+        /// The part of the file read from `stdin` that is formatted, the whole file is formatted
+        /// when it's [None]
+        range: Option<FormatRange>,
         /// A flag to know vcs integrated options such as `--staged` or `--changed` are enabled
         vcs_targeted: VcsTargeted,
     },
@@ -194,11 +333,12 @@ pub enum TraversalMode {
     },
     /// This mode is enabled when running the command `biome search`
     Search {
-        /// The GritQL pattern to search for.
-        ///
-        /// Note that the search command does not support rewrites.
+        /// The GritQL pattern to search for, which can rewrite the files
         pattern: PatternId,
 
+        /// Whether the rewrites of the pattern are written to the files
+        write: bool,
+
         /// An optional tuple.
         /// 1. The virtual path to the file
         /// 2. The content of the file
@@ -233,6 +373,11 @@ pub enum ReportMode {
     Junit,
     /// Reports information in the [GitLab Code Quality](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool) format.
     GitLab,
+    /// Reports information in the [Checkstyle](https://checkstyle.org/) XML format, which is understood by Jenkins and other CI tools.
+    Checkstyle,
+    /// Prints the changes that would be written to the files as a unified diff, which can be applied with `git apply`.
+    /// The files aren't written.
+    Diff,
 }
 
 impl Default for ReportMode {
@@ -255,6 +400,8 @@ impl From<CliReporter> for ReportMode {
             CliReporter::GitHub => Self::GitHub,
             CliReporter::Junit => Self::Junit,
             CliReporter::GitLab => Self::GitLab {},
+            CliReporter::Checkstyle => Self::Checkstyle,
+            CliReporter::Diff => Self::Diff,
         }
     }
 }
@@ -265,6 +412,14 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: 20,
+            cache: None,
+            threads: None,
+            max_open_files: None,
+            follow_symlinks: true,
+            binary_files: None,
+            ci_summary: None,
+            max_warnings: None,
+            module_graph: None,
         }
     }
 
@@ -285,19 +440,109 @@ impl Execution {
                 vcs_targeted,
             },
             max_diagnostics: 20,
+            cache: None,
+            threads: None,
+            max_open_files: None,
+            follow_symlinks: true,
+            binary_files: None,
+            ci_summary: None,
+            max_warnings: None,
+            module_graph: None,
         }
     }
 
     /// It sets the reporting mode by reading the [CliOptions]
     pub(crate) fn set_report(mut self, cli_options: &CliOptions) -> Self {
         self.report_mode = cli_options.reporter.clone().into();
+        if self.is_dry_run() {
+            // The changes are computed as if they were written, so that they can be printed
+            match &mut self.traversal_mode {
+                TraversalMode::Check { fix_file_mode, .. }
+                | TraversalMode::Lint { fix_file_mode, .. } => {
+                    fix_file_mode.get_or_insert(FixFileMode::SafeFixes);
+                }
+                TraversalMode::Format { write, .. } | TraversalMode::Search { write, .. } => {
+                    *write = true
+                }
+                TraversalMode::CI { .. } | TraversalMode::Migrate { .. } => {}
+            }
+        }
+        self
+    }
+
+    /// Reuses the results of the previous runs stored in `cache`
+    pub(crate) fn with_cache(mut self, cache: ResultCache) -> Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Bounds the number of threads and the number of files processed at the same time
+    pub(crate) fn with_concurrency(
+        mut self,
+        threads: Option<NonZeroUsize>,
+        max_open_files: Option<NonZeroUsize>,
+    ) -> Self {
+        self.threads = threads;
+        self.max_open_files = max_open_files;
+        self
+    }
+
+    /// Sets how the traversal handles the symbolic links and the binary files
+    pub(crate) fn with_scanner_options(
+        mut self,
+        follow_symlinks: bool,
+        binary_files: Option<Matcher>,
+    ) -> Self {
+        self.follow_symlinks = follow_symlinks;
+        self.binary_files = binary_files.map(Arc::new);
+        self
+    }
+
+    /// Writes a Markdown summary of the diagnostics once the files are processed
+    pub(crate) fn with_ci_summary(mut self, ci_summary: CiSummary) -> Self {
+        self.ci_summary = Some(Arc::new(ci_summary));
         self
     }
 
+    /// Writes the graph of the imports between the scripts once the files are processed
+    pub(crate) fn with_module_graph(mut self, module_graph: ModuleGraphExport) -> Self {
+        self.module_graph = Some(Arc::new(module_graph));
+        self
+    }
+
+    /// Exits with an error code when the diagnostics emit more warnings than `max_warnings`
+    pub(crate) fn with_max_warnings(mut self, max_warnings: Option<u32>) -> Self {
+        self.max_warnings = max_warnings;
+        self
+    }
+
+    pub(crate) const fn threads(&self) -> Option<NonZeroUsize> {
+        self.threads
+    }
+
+    pub(crate) const fn max_open_files(&self) -> Option<NonZeroUsize> {
+        self.max_open_files
+    }
+
+    pub(crate) const fn follow_symlinks(&self) -> bool {
+        self.follow_symlinks
+    }
+
+    /// Returns true if the file at `path` matches a pattern of `files.binaryFiles`
+    pub(crate) fn is_binary_file(&self, path: &Path) -> bool {
+        self.binary_files
+            .as_ref()
+            .is_some_and(|binary_files| binary_files.matches_path(path))
+    }
+
     pub(crate) fn traversal_mode(&self) -> &TraversalMode {
         &self.traversal_mode
     }
 
+    pub(crate) fn cache(&self) -> Option<&ResultCache> {
+        self.cache.as_deref()
+    }
+
     pub(crate) fn get_max_diagnostics(&self) -> u32 {
         self.max_diagnostics
     }
@@ -373,13 +618,23 @@ impl Execution {
         }
     }
 
+    /// Whether the changes are printed instead of being written to the files
+    pub(crate) const fn is_dry_run(&self) -> bool {
+        matches!(self.report_mode, ReportMode::Diff)
+    }
+
     /// Whether the traversal mode requires write access to files
     pub(crate) const fn requires_write_access(&self) -> bool {
+        if self.is_dry_run() {
+            return false;
+        }
         match self.traversal_mode {
             TraversalMode::Check { fix_file_mode, .. }
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.is_some(),
-            TraversalMode::CI { .. } | TraversalMode::Search { .. } => false,
-            TraversalMode::Format { write, .. } | TraversalMode::Migrate { write, .. } => write,
+            TraversalMode::CI { .. } => false,
+            TraversalMode::Format { write, .. }
+            | TraversalMode::Migrate { write, .. }
+            | TraversalMode::Search { write, .. } => write,
         }
     }
 
@@ -411,7 +666,7 @@ impl Execution {
             TraversalMode::CI { .. } => false,
             TraversalMode::Format { write, .. } => write,
             TraversalMode::Migrate { write, .. } => write,
-            TraversalMode::Search { .. } => false,
+            TraversalMode::Search { write, .. } => write,
         }
     }
 }
@@ -434,14 +689,18 @@ pub fn execute_mode(
 
     // don't do any traversal if there's some content coming from stdin
     if let Some(stdin) = execution.as_stdin_file() {
-        let biome_path = BiomePath::new(stdin.as_path());
-        std_in::run(
-            session,
-            &execution,
-            biome_path,
-            stdin.as_content(),
-            cli_options.verbose,
-        )
+        match stdin {
+            Stdin::File(file) => std_in::run(
+                session,
+                &execution,
+                BiomePath::new(&file.path),
+                &file.content,
+                cli_options.verbose,
+            ),
+            Stdin::Files(files) => {
+                std_in::run_files(session, &execution, files, cli_options.verbose)
+            }
+        }
     } else if let TraversalMode::Migrate {
         write,
         configuration_file_path,
@@ -459,16 +718,60 @@ pub fn execute_mode(
         };
         migrate::run(payload)
     } else {
+        if cli_options.profile_rules {
+            profiling::enable();
+        }
         let TraverseResult {
             summary,
             evaluated_paths,
             diagnostics,
         } = traverse(&execution, &mut session, cli_options, paths)?;
+        if let Some(cache) = execution.cache() {
+            // The results are still reported when the cache can't be written
+            if let Err(error) = cache.save(&*session.app.fs) {
+                session.app.console.error(markup! {
+                    <Warn>"The results couldn't be written to the cache: "{error.to_string()}</Warn>
+                });
+            }
+            // The project index only spares the scan of the next runs
+            if let Ok(result) = session
+                .app
+                .workspace
+                .export_project_index(ExportProjectIndexParams {})
+            {
+                if let Err(error) = cache.save_project_index(&*session.app.fs, result.index) {
+                    session.app.console.error(markup! {
+                        <Warn>"The project index couldn't be written to the cache: "{error.to_string()}</Warn>
+                    });
+                }
+            }
+        }
+        if let Some(ci_summary) = execution.ci_summary.as_deref() {
+            // The diagnostics are still reported when the summary can't be written
+            if let Err(error) =
+                ci_summary.write(&*session.app.fs, &summary, &diagnostics, cli_options)
+            {
+                session.app.console.error(markup! {
+                    <Warn>"The summary couldn't be written: "{error.to_string()}</Warn>
+                });
+            }
+        }
+        if let Some(module_graph) = execution.module_graph.as_deref() {
+            // The diagnostics are still reported when the graph can't be written
+            if let Err(error) = module_graph.write(&*session.app.fs, &*session.app.workspace) {
+                session.app.console.error(markup! {
+                    <Warn>"The module graph couldn't be written."</Warn>"\n"
+                    {PrintDiagnostic::simple(&error)}
+                });
+            }
+        }
         let console = session.app.console;
         let errors = summary.errors;
         let skipped = summary.skipped;
         let processed = summary.changed + summary.unchanged;
-        let should_exit_on_warnings = summary.warnings > 0 && cli_options.error_on_warnings;
+        let max_warnings = execution.max_warnings;
+        let should_exit_on_warnings =
+            max_warnings.map_or(false, |max_warnings| summary.warnings > max_warnings);
 
         match execution.report_mode {
             ReportMode::Terminal { with_summary } => {
@@ -574,6 +877,32 @@ pub fn execute_mode(
                 };
                 reporter.write(&mut JunitReporterVisitor::new(console))?;
             }
+            ReportMode::Checkstyle => {
+                let reporter = CheckstyleReporter {
+                    diagnostics_payload: DiagnosticsPayload {
+                        verbose: cli_options.verbose,
+                        diagnostic_level: cli_options.diagnostic_level,
+                        diagnostics,
+                    },
+                    execution: execution.clone(),
+                };
+                reporter.write(&mut CheckstyleReporterVisitor(console))?;
+            }
+            ReportMode::Diff => {
+                let reporter = DiffReporter {
+                    diagnostics_payload: DiagnosticsPayload {
+                        verbose: cli_options.verbose,
+                        diagnostic_level: cli_options.diagnostic_level,
+                        diagnostics,
+                    },
+                    execution: execution.clone(),
+                };
+                reporter.write(&mut DiffReporterVisitor(console))?;
+            }
+        }
+
+        if cli_options.profile_rules {
+            print_profile(console, profiling::take_profile(), cli_options.verbose);
         }
 
         // Processing emitted error diagnostics, exit with a non-zero code
@@ -581,13 +910,21 @@ pub fn execute_mode(
             Err(CliDiagnostic::no_files_processed())
         } else if errors > 0 || should_exit_on_warnings {
             let category = execution.as_diagnostic_category();
+            // The fixes aren't applied in dry-run mode, they are only printed
+            let is_check_apply = execution.is_check_apply() && !execution.is_dry_run();
             if should_exit_on_warnings {
-                if execution.is_check_apply() {
+                if let Some(max_warnings @ 1..) = max_warnings {
+                    Err(CliDiagnostic::too_many_warnings(
+                        category,
+                        summary.warnings,
+                        max_warnings,
+                    ))
+                } else if is_check_apply {
                     Err(CliDiagnostic::apply_warnings(category))
                 } else {
                     Err(CliDiagnostic::check_warnings(category))
                 }
-            } else if execution.is_check_apply() {
+            } else if is_check_apply {
                 Err(CliDiagnostic::apply_error(category))
             } else {
                 Err(CliDiagnostic::check_error(category))
@@ -0,0 +1,210 @@
+use crate::CliDiagnostic;
+use biome_fs::{FileSystem, FileSystemExt};
+use biome_service::workspace::{GetModuleGraphParams, ModuleGraphNode};
+use biome_service::Workspace;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::Write;
+use std::io;
+use std::path::PathBuf;
+
+/// The graph of the imports between the scripts, written by `biome check --emit-module-graph`
+/// once the files are checked, because the workspace learns the imports of a script when
+/// it's processed.
+#[derive(Debug)]
+pub(crate) struct ModuleGraphExport {
+    /// The file where the graph is written
+    path: PathBuf,
+    /// Only the scripts imported by this script, directly or not, are written
+    from: Option<PathBuf>,
+    /// Only the imports that are part of a cycle are written
+    only_cycles: bool,
+}
+
+/// The scripts keyed by their path, without the leading `./`
+type Modules = BTreeMap<String, ModuleGraphNode>;
+
+impl ModuleGraphExport {
+    pub(crate) fn new(path: PathBuf, from: Option<PathBuf>, only_cycles: bool) -> Self {
+        Self {
+            path,
+            from,
+            only_cycles,
+        }
+    }
+
+    /// Writes the graph of the scripts known by `workspace`, replacing the content of the file.
+    ///
+    /// The graph is written in the DOT format when the extension of the file is `.dot`,
+    /// and in JSON otherwise.
+    pub(crate) fn write(
+        &self,
+        fs: &dyn FileSystem,
+        workspace: &dyn Workspace,
+    ) -> Result<(), CliDiagnostic> {
+        let graph = workspace.get_module_graph(GetModuleGraphParams {})?;
+        let mut modules: Modules = graph
+            .modules
+            .into_iter()
+            .map(|mut node| {
+                node.path = strip_current_dir(&node.path).to_string();
+                for import in &mut node.imports {
+                    *import = strip_current_dir(import).to_string();
+                }
+                (node.path.clone(), node)
+            })
+            .collect();
+        if let Some(from) = &self.from {
+            let from = from.display().to_string();
+            let from = strip_current_dir(&from);
+            if !modules.contains_key(from) {
+                return Err(CliDiagnostic::io_error(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("The script {from} isn't part of the module graph."),
+                )));
+            }
+            modules = reachable_from(modules, from);
+        }
+        if self.only_cycles {
+            modules = cycles(modules);
+        }
+
+        let is_dot = self
+            .path
+            .extension()
+            .is_some_and(|extension| extension == "dot");
+        let content = if is_dot {
+            to_dot(&modules)
+        } else {
+            let modules: Vec<_> = modules.into_values().collect();
+            serde_json::to_string_pretty(&serde_json::json!({ "modules": modules })).map_err(
+                |error| CliDiagnostic::io_error(io::Error::new(io::ErrorKind::InvalidData, error)),
+            )?
+        };
+        fs.create(&self.path)?.set_content(content.as_bytes())?;
+        Ok(())
+    }
+}
+
+fn strip_current_dir(path: &str) -> &str {
+    path.strip_prefix("./").unwrap_or(path)
+}
+
+/// Keeps the scripts imported by the script at `from`, directly or not, along with the script itself
+fn reachable_from(mut modules: Modules, from: &str) -> Modules {
+    let mut reachable = Modules::new();
+    let mut queue = VecDeque::from([from.to_string()]);
+    while let Some(path) = queue.pop_front() {
+        if let Some(node) = modules.remove(&path) {
+            queue.extend(node.imports.iter().cloned());
+            reachable.insert(path, node);
+        }
+    }
+    reachable
+}
+
+/// Keeps the scripts and the imports that are part of a cycle, that's to say the imports
+/// between two scripts of the same strongly connected component.
+fn cycles(modules: Modules) -> Modules {
+    let components = strongly_connected_components(&modules);
+    let mut component_sizes: FxHashMap<usize, usize> = FxHashMap::default();
+    for component in components.values() {
+        *component_sizes.entry(*component).or_default() += 1;
+    }
+
+    modules
+        .iter()
+        .filter_map(|(path, node)| {
+            let component = components[path.as_str()];
+            let imports: Vec<_> = node
+                .imports
+                .iter()
+                .filter(|import| components.get(import.as_str()) == Some(&component))
+                .cloned()
+                .collect();
+            // A script that is alone in its component is only part of a cycle if it imports itself
+            if component_sizes[&component] == 1 && imports.is_empty() {
+                return None;
+            }
+            Some((
+                path.clone(),
+                ModuleGraphNode {
+                    path: path.clone(),
+                    imports,
+                    ..Default::default()
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Returns the strongly connected component of each script, computed with Tarjan's algorithm
+fn strongly_connected_components(modules: &Modules) -> FxHashMap<&str, usize> {
+    #[derive(Default)]
+    struct State<'a> {
+        indices: FxHashMap<&'a str, usize>,
+        low_links: FxHashMap<&'a str, usize>,
+        stack: Vec<&'a str>,
+        on_stack: FxHashSet<&'a str>,
+        components: FxHashMap<&'a str, usize>,
+        component_count: usize,
+    }
+
+    fn visit<'a>(modules: &'a Modules, path: &'a str, state: &mut State<'a>) {
+        let index = state.indices.len();
+        state.indices.insert(path, index);
+        state.low_links.insert(path, index);
+        state.stack.push(path);
+        state.on_stack.insert(path);
+
+        for import in &modules[path].imports {
+            let import = import.as_str();
+            if !modules.contains_key(import) {
+                continue;
+            }
+            if !state.indices.contains_key(import) {
+                visit(modules, import, state);
+                let low_link = state.low_links[path].min(state.low_links[import]);
+                state.low_links.insert(path, low_link);
+            } else if state.on_stack.contains(import) {
+                let low_link = state.low_links[path].min(state.indices[import]);
+                state.low_links.insert(path, low_link);
+            }
+        }
+
+        if state.low_links[path] == state.indices[path] {
+            while let Some(member) = state.stack.pop() {
+                state.on_stack.remove(member);
+                state.components.insert(member, state.component_count);
+                if member == path {
+                    break;
+                }
+            }
+            state.component_count += 1;
+        }
+    }
+
+    let mut state = State::default();
+    for path in modules.keys() {
+        if !state.indices.contains_key(path.as_str()) {
+            visit(modules, path, &mut state);
+        }
+    }
+    state.components
+}
+
+/// Prints the scripts and the imports between them in the DOT format, which can be rendered
+/// by Graphviz. The packages aren't part of the graph.
+fn to_dot(modules: &Modules) -> String {
+    let mut dot = String::from("digraph modules {\n");
+    for (path, node) in modules {
+        if node.imports.is_empty() {
+            let _ = writeln!(dot, "  {path:?};");
+        }
+        for import in &node.imports {
+            let _ = writeln!(dot, "  {path:?} -> {import:?};");
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
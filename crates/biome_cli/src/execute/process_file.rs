@@ -6,10 +6,11 @@ mod organize_imports;
 mod search;
 pub(crate) mod workspace_file;
 
+use crate::execute::cache::ResultCache;
 use crate::execute::diagnostics::{ResultExt, UnhandledDiagnostic};
 use crate::execute::traverse::TraversalOptions;
 use crate::execute::TraversalMode;
-use biome_diagnostics::{category, DiagnosticExt, DiagnosticTags, Error};
+use biome_diagnostics::{category, DiagnosticExt, DiagnosticTags, Error, Resource};
 use biome_fs::BiomePath;
 use biome_service::workspace::{FeatureKind, SupportKind, SupportsFeatureParams};
 use check::check_file;
@@ -68,6 +69,19 @@ impl Message {
     pub(crate) const fn is_failure(&self) -> bool {
         matches!(self, Message::Failure)
     }
+
+    /// Returns the name of the file that the message is about, if any
+    pub(crate) fn file_name(&self) -> Option<String> {
+        match self {
+            Message::Diagnostics { name, .. } => Some(name.clone()),
+            Message::Diff { file_name, .. } => Some(file_name.clone()),
+            Message::Error(error) => match error.location().resource {
+                Some(Resource::File(path)) => Some(path.to_string()),
+                _ => None,
+            },
+            Message::SkippedFixes { .. } | Message::Failure => None,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -75,6 +89,8 @@ pub(crate) enum DiffKind {
     Format,
     OrganizeImports,
     Assists,
+    /// The rewrites of a GritQL pattern
+    Rewrite,
 }
 
 impl<D> From<D> for Message
@@ -206,9 +222,20 @@ pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> Fi
             };
         }
 
+        // The files that didn't change since a run that didn't report anything are skipped
+        let cached = ctx.execution.cache().and_then(|cache| {
+            let content_hash = ResultCache::content_hash(ctx.fs, biome_path.as_path())?;
+            Some((cache, content_hash))
+        });
+        if let Some((cache, content_hash)) = cached {
+            if cache.is_fresh(biome_path, content_hash) {
+                return Ok(FileStatus::Unchanged);
+            }
+        }
+
         let shared_context = &SharedTraversalOptions::new(ctx);
 
-        match ctx.execution.traversal_mode {
+        let result = match ctx.execution.traversal_mode {
             TraversalMode::Lint {
                 ref suppression_reason,
                 suppress,
@@ -236,6 +263,13 @@ pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> Fi
                 // the unsupported case should be handled already at this point
                 search(shared_context, biome_path, pattern)
             }
+        };
+
+        if let Some((cache, content_hash)) = cached {
+            let is_unchanged = matches!(result, Ok(FileStatus::Unchanged));
+            cache.update(biome_path, content_hash, is_unchanged);
         }
+
+        result
     })
 }
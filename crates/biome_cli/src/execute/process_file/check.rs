@@ -101,6 +101,8 @@ pub(crate) fn check_file<'ctx>(
                 }
             }
 
+            workspace_file.report_changes(ctx)?;
+
             if has_failures {
                 Ok(FileStatus::Message(Message::Failure))
             } else if changed {
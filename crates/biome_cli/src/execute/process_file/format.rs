@@ -6,15 +6,15 @@ use crate::execute::process_file::{
 use crate::execute::TraversalMode;
 use biome_analyze::RuleCategoriesBuilder;
 use biome_diagnostics::{category, Diagnostic, DiagnosticExt, Error, Severity};
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
-use std::ffi::OsStr;
 use std::path::Path;
 use std::sync::atomic::Ordering;
 use tracing::debug;
 
 pub(crate) fn format<'ctx>(ctx: &'ctx SharedTraversalOptions<'ctx, '_>, path: &Path) -> FileResult {
     let mut workspace_file = WorkspaceFile::new(ctx, path)?;
-    format_with_guard(ctx, &mut workspace_file)
+    let result = format_with_guard(ctx, &mut workspace_file);
+    workspace_file.report_changes(ctx)?;
+    result
 }
 
 pub(crate) fn format_with_guard<'ctx>(
@@ -85,35 +85,12 @@ pub(crate) fn format_with_guard<'ctx>(
                     category!("format"),
                 )?;
 
-            let mut output = printed.into_code();
+            let output = printed.into_code();
 
             if ignore_errors {
                 return Ok(FileStatus::Ignored);
             }
 
-            match workspace_file.as_extension().map(OsStr::as_encoded_bytes) {
-                Some(b"astro") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = AstroFileHandler::output(input.as_str(), output.as_str());
-                }
-                Some(b"vue") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = VueFileHandler::output(input.as_str(), output.as_str());
-                }
-
-                Some(b"svelte") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = SvelteFileHandler::output(input.as_str(), output.as_str());
-                }
-                _ => {}
-            }
-
             if output != input {
                 if should_write {
                     workspace_file.update_file(output)?;
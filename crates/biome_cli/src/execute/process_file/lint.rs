@@ -4,7 +4,6 @@ use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraver
 use crate::TraversalMode;
 use biome_analyze::RuleCategoriesBuilder;
 use biome_diagnostics::{category, Error};
-use biome_rowan::TextSize;
 use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use std::ffi::OsStr;
 use std::path::Path;
@@ -18,7 +17,9 @@ pub(crate) fn lint<'ctx>(
     suppression_reason: Option<&str>,
 ) -> FileResult {
     let mut workspace_file = WorkspaceFile::new(ctx, path)?;
-    lint_with_guard(ctx, &mut workspace_file, suppress, suppression_reason)
+    let result = lint_with_guard(ctx, &mut workspace_file, suppress, suppression_reason);
+    workspace_file.report_changes(ctx)?;
+    result
 }
 
 pub(crate) fn lint_with_guard<'ctx>(
@@ -108,26 +109,12 @@ pub(crate) fn lint_with_guard<'ctx>(
                 && pull_diagnostics_result.skipped_diagnostics == 0;
 
             if !no_diagnostics {
-                let offset = match workspace_file.as_extension().map(OsStr::as_encoded_bytes) {
-                    Some(b"vue") => VueFileHandler::start(input.as_str()),
-                    Some(b"astro") => AstroFileHandler::start(input.as_str()),
-                    Some(b"svelte") => SvelteFileHandler::start(input.as_str()),
-                    _ => None,
-                };
-
                 ctx.push_message(Message::Diagnostics {
                     name: workspace_file.path.display().to_string(),
                     content: input,
                     diagnostics: pull_diagnostics_result
                         .diagnostics
                         .into_iter()
-                        .map(|d| {
-                            if let Some(offset) = offset {
-                                d.with_offset(TextSize::from(offset))
-                            } else {
-                                d
-                            }
-                        })
                         .map(Error::from)
                         .collect(),
                     skipped_diagnostics: pull_diagnostics_result.skipped_diagnostics as u32,
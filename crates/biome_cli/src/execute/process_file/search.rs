@@ -1,6 +1,9 @@
 use crate::execute::diagnostics::{ResultExt, SearchDiagnostic};
 use crate::execute::process_file::workspace_file::WorkspaceFile;
-use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
+use crate::execute::process_file::{
+    DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
+};
+use crate::execute::TraversalMode;
 use biome_diagnostics::{category, DiagnosticExt};
 use biome_service::workspace::PatternId;
 use std::path::Path;
@@ -11,11 +14,13 @@ pub(crate) fn search<'ctx>(
     pattern: &PatternId,
 ) -> FileResult {
     let mut workspace_file = WorkspaceFile::new(ctx, path)?;
-    search_with_guard(ctx, &mut workspace_file, pattern)
+    let result = search_with_guard(ctx, &mut workspace_file, pattern);
+    workspace_file.report_changes(ctx)?;
+    result
 }
 
 pub(crate) fn search_with_guard<'ctx>(
-    _ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
     workspace_file: &mut WorkspaceFile,
     pattern: &PatternId,
 ) -> FileResult {
@@ -33,6 +38,23 @@ pub(crate) fn search_with_guard<'ctx>(
             let file_name = workspace_file.path.display().to_string();
             let matches_len = result.matches.len();
 
+            if let Some(rewritten) = result.rewritten.filter(|rewritten| *rewritten != input) {
+                let should_write = matches!(
+                    ctx.execution.traversal_mode,
+                    TraversalMode::Search { write: true, .. }
+                );
+                if should_write {
+                    workspace_file.update_file(rewritten)?;
+                } else {
+                    ctx.push_message(Message::Diff {
+                        file_name: file_name.clone(),
+                        old: input.clone(),
+                        new: rewritten,
+                        diff_kind: DiffKind::Rewrite,
+                    });
+                }
+            }
+
             let search_results = Message::Diagnostics {
                 name: file_name,
                 content: input,
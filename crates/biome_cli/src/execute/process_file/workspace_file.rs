@@ -1,10 +1,11 @@
-use crate::execute::diagnostics::{ResultExt, ResultIoExt};
-use crate::execute::process_file::SharedTraversalOptions;
+use crate::execute::diagnostics::{ResultExt, ResultIoExt, SkipReason, SkippedFileDiagnostic};
+use crate::execute::process_file::{DiffKind, Message, SharedTraversalOptions};
 use biome_diagnostics::{category, Error};
 use biome_fs::{BiomePath, File, OpenOptions};
 use biome_service::workspace::{FileGuard, OpenFileParams};
 use biome_service::{Workspace, WorkspaceError};
 use std::ffi::OsStr;
+use std::io;
 use std::path::{Path, PathBuf};
 
 /// Small wrapper that holds information and operations around the current processed file
@@ -12,6 +13,10 @@ pub(crate) struct WorkspaceFile<'ctx, 'app> {
     guard: FileGuard<'app, dyn Workspace + 'ctx>,
     file: Box<dyn File>,
     pub(crate) path: PathBuf,
+    /// The version of the file in the workspace
+    version: i32,
+    /// The content of the file on disk, when the changes aren't written to it
+    original: Option<String>,
 }
 
 impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
@@ -31,8 +36,17 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
             .with_file_path(path.display().to_string())?;
 
         let mut input = String::new();
-        file.read_to_string(&mut input)
-            .with_file_path(path.display().to_string())?;
+        let result = file.read_to_string(&mut input);
+        let is_not_text =
+            matches!(&result, Err(error) if error.kind() == io::ErrorKind::InvalidData);
+        if is_not_text || is_binary_content(&input) {
+            return Err(SkippedFileDiagnostic::new(
+                path.display().to_string(),
+                SkipReason::BinaryContent,
+            )
+            .into());
+        }
+        result.with_file_path(path.display().to_string())?;
 
         let guard = FileGuard::open(
             ctx.workspace,
@@ -49,6 +63,8 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
             file,
             guard,
             path: PathBuf::from(path),
+            version: 0,
+            original: ctx.execution.is_dry_run().then_some(input),
         })
     }
 
@@ -64,15 +80,48 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
         self.path.extension()
     }
 
-    /// It updates the workspace file with `new_content`
+    /// It updates the workspace file with `new_content`.
+    /// In dry-run mode, the file on disk isn't written.
     pub(crate) fn update_file(&mut self, new_content: impl Into<String>) -> Result<(), Error> {
         let new_content = new_content.into();
 
-        self.file
-            .set_content(new_content.as_bytes())
-            .with_file_path(self.path.display().to_string())?;
-        self.guard
-            .change_file(self.file.file_version(), new_content)?;
+        if self.original.is_some() {
+            self.version += 1;
+        } else {
+            self.file
+                .set_content(new_content.as_bytes())
+                .with_file_path(self.path.display().to_string())?;
+            self.version = self.file.file_version();
+        }
+        self.guard.change_file(self.version, new_content)?;
+        Ok(())
+    }
+
+    /// In dry-run mode, it reports the changes that weren't written to the file as a diff
+    pub(crate) fn report_changes(&self, ctx: &SharedTraversalOptions) -> Result<(), Error> {
+        let Some(original) = &self.original else {
+            return Ok(());
+        };
+        let content = self.input()?;
+        if content != *original {
+            ctx.push_message(Message::Diff {
+                file_name: self.path.display().to_string(),
+                old: original.clone(),
+                new: content,
+                // The changes can come from the fixes too, but the diff reporter doesn't print the kind
+                diff_kind: DiffKind::Format,
+            });
+        }
         Ok(())
     }
 }
+
+/// The number of bytes at the start of a file that are looked at to detect a binary file
+const BINARY_DETECTION_LENGTH: usize = 8000;
+
+/// Returns true if `content` looks like the content of a binary file, because it has a null
+/// byte at its start, as git detects them
+fn is_binary_content(content: &str) -> bool {
+    let start = &content.as_bytes()[..content.len().min(BINARY_DETECTION_LENGTH)];
+    start.contains(&0)
+}
@@ -0,0 +1,64 @@
+use biome_analyze::profiling::Profile;
+use biome_console::{markup, Console, ConsoleExt};
+
+/// The maximum number of rules listed by the profile, unless `--verbose` is passed
+const MAX_LISTED_RULES: usize = 20;
+
+/// Prints the time spent by the phases and the rules while processing the files, aggregated
+/// across all the files, so that the slowest rules stand out.
+///
+/// The rules are measured by the process that analyzes the files, so nothing is measured
+/// when the files are processed by the daemon.
+pub(crate) fn print_profile(console: &mut dyn Console, profile: Profile, verbose: bool) {
+    if profile.phases.is_empty() && profile.rules.is_empty() {
+        console.error(markup! {
+            <Warn>"Nothing was profiled. The rules can't be profiled when "<Emphasis>"--use-server"</Emphasis>" is passed."</Warn>
+        });
+        return;
+    }
+
+    let width = profile
+        .phases
+        .iter()
+        .map(|(phase, _)| phase.len())
+        .chain(
+            profile
+                .rules
+                .iter()
+                .map(|(rule, _)| rule.group().len() + rule.rule_name().len() + 1),
+        )
+        .max()
+        .unwrap_or_default();
+
+    console.error(markup! {
+        <Emphasis>"Time spent per phase"</Emphasis>
+    });
+    for (phase, elapsed) in &profile.phases {
+        let phase = format!("{phase:<width$}");
+        console.error(markup! {
+            "  "{phase}"  "{elapsed}
+        });
+    }
+
+    let limit = if verbose {
+        profile.rules.len()
+    } else {
+        MAX_LISTED_RULES
+    };
+    console.error(markup! {
+        "\n"<Emphasis>"Time spent per rule"</Emphasis>
+    });
+    for (rule, elapsed) in profile.rules.iter().take(limit) {
+        let rule = format!("{}/{}", rule.group(), rule.rule_name());
+        let rule = format!("{rule:<width$}");
+        console.error(markup! {
+            "  "{rule}"  "{elapsed}
+        });
+    }
+    if profile.rules.len() > limit {
+        let hidden = profile.rules.len() - limit;
+        console.error(markup! {
+            <Dim>"  "{hidden}" faster rules aren't listed, use "<Emphasis>"--verbose"</Emphasis>" to list them."</Dim>
+        });
+    }
+}
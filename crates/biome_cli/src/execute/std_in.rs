@@ -1,19 +1,32 @@
 //! In here, there are the operations that run via standard input
 //!
-use crate::execute::Execution;
+use crate::execute::{Execution, StdinFile};
 use crate::{CliDiagnostic, CliSession, TraversalMode};
 use biome_analyze::RuleCategoriesBuilder;
-use biome_console::{markup, ConsoleExt};
+use biome_console::{markup, Console, ConsoleExt};
 use biome_diagnostics::Diagnostic;
 use biome_diagnostics::PrintDiagnostic;
 use biome_fs::BiomePath;
 use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use biome_service::workspace::{
     ChangeFileParams, DropPatternParams, FeaturesBuilder, FixFileParams, FormatFileParams,
-    OpenFileParams, OrganizeImportsParams, SupportsFeatureParams,
+    FormatRangeParams, OpenFileParams, OrganizeImportsParams, SearchPatternParams, SearchResults,
+    SupportsFeatureParams,
 };
-use biome_service::WorkspaceError;
+use biome_service::{Workspace, WorkspaceError};
 use std::borrow::Cow;
+use std::io;
+use std::ops::Range;
+
+/// How the content read from `stdin` was handled
+enum Outcome {
+    /// The content was handled as requested by the command
+    Processed,
+    /// The command didn't change the content
+    Unchanged,
+    /// The content wasn't formatted because the formatter is disabled
+    FormatterDisabled,
+}
 
 pub(crate) fn run<'a>(
     session: CliSession,
@@ -24,6 +37,90 @@ pub(crate) fn run<'a>(
 ) -> Result<(), CliDiagnostic> {
     let workspace = &*session.app.workspace;
     let console = &mut *session.app.console;
+    let result = process(workspace, console, mode, biome_path, content, verbose);
+    drop_search_pattern(workspace, mode);
+    let (output, outcome) = result?;
+    console.append(markup! {{output}});
+    match outcome {
+        Outcome::Processed => Ok(()),
+        Outcome::Unchanged => Err(CliDiagnostic::stdin()),
+        Outcome::FormatterDisabled => {
+            console.error(markup! {
+                <Warn>"The content was not formatted because the formatter is currently disabled."</Warn>
+            });
+            Err(CliDiagnostic::stdin())
+        }
+    }
+}
+
+/// Handles several files read from `stdin`, and prints their results as a JSON array with the
+/// `path` and the `content` of each file, in the same order.
+pub(crate) fn run_files(
+    session: CliSession,
+    mode: &Execution,
+    files: &[StdinFile],
+    verbose: bool,
+) -> Result<(), CliDiagnostic> {
+    let workspace = &*session.app.workspace;
+    let console = &mut *session.app.console;
+    let mut outputs = Vec::with_capacity(files.len());
+    let mut has_errors = false;
+    for file in files {
+        let biome_path = BiomePath::new(&file.path);
+        let result = process(workspace, console, mode, biome_path, &file.content, verbose);
+        let (output, outcome) = match result {
+            Ok(result) => result,
+            Err(error) => {
+                drop_search_pattern(workspace, mode);
+                return Err(error);
+            }
+        };
+        match outcome {
+            Outcome::Processed => {}
+            Outcome::Unchanged => has_errors = true,
+            Outcome::FormatterDisabled => {
+                console.error(markup! {
+                    <Warn>"The content of "{file.path.display().to_string()}" was not formatted because the formatter is currently disabled."</Warn>
+                });
+                has_errors = true;
+            }
+        }
+        outputs.push(StdinFile {
+            path: file.path.clone(),
+            content: output.into_owned(),
+        });
+    }
+    drop_search_pattern(workspace, mode);
+
+    let json = serde_json::to_string(&outputs).map_err(|error| {
+        CliDiagnostic::io_error(io::Error::new(io::ErrorKind::InvalidData, error))
+    })?;
+    console.append(markup! {{json}});
+    if has_errors {
+        Err(CliDiagnostic::stdin())
+    } else {
+        Ok(())
+    }
+}
+
+/// Makes sure that the pattern of the `search` command is cleaned up at the end of the execution
+fn drop_search_pattern(workspace: &dyn Workspace, mode: &Execution) {
+    if let TraversalMode::Search { pattern, .. } = mode.traversal_mode() {
+        let _ = workspace.drop_pattern(DropPatternParams {
+            pattern: pattern.clone(),
+        });
+    }
+}
+
+/// Handles the `content` of the file at `biome_path`, and returns the content to print
+fn process<'a>(
+    workspace: &dyn Workspace,
+    console: &mut dyn Console,
+    mode: &Execution,
+    biome_path: BiomePath,
+    content: &'a str,
+    verbose: bool,
+) -> Result<(Cow<'a, str>, Outcome), CliDiagnostic> {
     let mut version = 0;
 
     if mode.is_format() {
@@ -41,8 +138,7 @@ pub(crate) fn run<'a>(
             } else {
                 console.error(markup! {{PrintDiagnostic::simple(&protected_diagnostic)}})
             }
-            console.append(markup! {{content}});
-            return Ok(());
+            return Ok((Cow::Borrowed(content), Outcome::Processed));
         };
         if file_features.supports_format() {
             workspace.open_file(OpenFileParams {
@@ -51,28 +147,41 @@ pub(crate) fn run<'a>(
                 content: content.into(),
                 document_file_source: None,
             })?;
-            let printed = workspace.format_file(FormatFileParams {
-                path: biome_path.clone(),
-            })?;
-
-            let code = printed.into_code();
-            let output = match biome_path.extension().map(|ext| ext.as_encoded_bytes()) {
-                Some(b"astro") => AstroFileHandler::output(content, code.as_str()),
-                Some(b"vue") => VueFileHandler::output(content, code.as_str()),
-                Some(b"svelte") => SvelteFileHandler::output(content, code.as_str()),
-                _ => code,
+            let range = match mode.traversal_mode() {
+                TraversalMode::Format { range, .. } => *range,
+                _ => None,
             };
-            console.append(markup! {
-                {output}
-            });
+            let output = if let Some(range) = range {
+                let text_range = range
+                    .to_text_range(content)
+                    .ok_or_else(|| CliDiagnostic::out_of_bounds_range(range.to_string()))?;
+                let printed = workspace.format_range(FormatRangeParams {
+                    path: biome_path.clone(),
+                    range: text_range,
+                })?;
+                // Only the formatted part is printed, the rest of the content is kept as is
+                match printed.range() {
+                    Some(formatted_range) => {
+                        let mut output = content.to_string();
+                        output.replace_range(
+                            Range::<usize>::from(formatted_range),
+                            printed.as_code(),
+                        );
+                        output
+                    }
+                    None => printed.into_code(),
+                }
+            } else {
+                workspace
+                    .format_file(FormatFileParams {
+                        path: biome_path.clone(),
+                    })?
+                    .into_code()
+            };
+
+            Ok((Cow::Owned(output), Outcome::Processed))
         } else {
-            console.append(markup! {
-                {content}
-            });
-            console.error(markup! {
-                <Warn>"The content was not formatted because the formatter is currently disabled."</Warn>
-            });
-            return Err(CliDiagnostic::stdin());
+            Ok((Cow::Borrowed(content), Outcome::FormatterDisabled))
         }
     } else if mode.is_check() || mode.is_lint() {
         let mut new_content = Cow::Borrowed(content);
@@ -103,8 +212,7 @@ pub(crate) fn run<'a>(
             } else {
                 console.error(markup! {{PrintDiagnostic::simple(&protected_diagnostic)}})
             }
-            console.append(markup! {{content}});
-            return Ok(());
+            return Ok((Cow::Borrowed(content), Outcome::Processed));
         };
 
         let (only, skip) = if let TraversalMode::Lint { only, skip, .. } = mode.traversal_mode() {
@@ -172,43 +280,38 @@ pub(crate) fn run<'a>(
             let printed = workspace.format_file(FormatFileParams {
                 path: biome_path.clone(),
             })?;
-            let code = printed.into_code();
-            let output = match biome_path.extension().map(|ext| ext.as_encoded_bytes()) {
-                Some(b"astro") => AstroFileHandler::output(&new_content, code.as_str()),
-                Some(b"vue") => VueFileHandler::output(&new_content, code.as_str()),
-                Some(b"svelte") => SvelteFileHandler::output(&new_content, code.as_str()),
-                _ => code,
-            };
+            let output = printed.into_code();
             if (mode.is_check_apply() || mode.is_check_apply_unsafe()) && output != new_content {
                 new_content = Cow::Owned(output);
             }
         }
 
-        match new_content {
-            Cow::Borrowed(original_content) => {
-                console.append(markup! {
-                    {original_content}
-                });
-
-                if !mode.is_write() {
-                    return Err(CliDiagnostic::stdin());
-                }
-            }
-            Cow::Owned(ref new_content) => {
-                console.append(markup! {
-                    {new_content}
-                });
+        let outcome = match new_content {
+            Cow::Borrowed(_) if !mode.is_write() => Outcome::Unchanged,
+            _ => Outcome::Processed,
+        };
+        Ok((new_content, outcome))
+    } else if let TraversalMode::Search { pattern, write, .. } = mode.traversal_mode() {
+        let mut new_content = Cow::Borrowed(content);
+        if *write {
+            workspace.open_file(OpenFileParams {
+                path: biome_path.clone(),
+                version: 0,
+                content: content.into(),
+                document_file_source: None,
+            })?;
+            let SearchResults { rewritten, .. } =
+                workspace.search_pattern(SearchPatternParams {
+                    path: biome_path.clone(),
+                    pattern: pattern.clone(),
+                })?;
+            if let Some(rewritten) = rewritten {
+                new_content = Cow::Owned(rewritten);
             }
         }
-    } else if let TraversalMode::Search { pattern, .. } = mode.traversal_mode() {
-        // Make sure patterns are always cleaned up at the end of execution.
-        let _ = session.app.workspace.drop_pattern(DropPatternParams {
-            pattern: pattern.clone(),
-        });
 
-        console.append(markup! {{content}});
+        Ok((new_content, Outcome::Processed))
     } else {
-        console.append(markup! {{content}});
+        Ok((Cow::Borrowed(content), Outcome::Processed))
     }
-    Ok(())
 }
@@ -1,25 +1,33 @@
+use super::cache::{Dependency, DependencyHashes, ResultCache};
 use super::process_file::{process_file, DiffKind, FileStatus, Message};
 use super::{Execution, TraversalMode};
 use crate::cli_options::CliOptions;
 use crate::execute::diagnostics::{
     AssistsDiffDiagnostic, CIAssistsDiffDiagnostic, CIFormatDiffDiagnostic,
     CIOrganizeImportsDiffDiagnostic, ContentDiffAdvice, FormatDiffDiagnostic,
-    OrganizeImportsDiffDiagnostic, PanicDiagnostic,
+    OrganizeImportsDiffDiagnostic, PanicDiagnostic, RewriteDiffDiagnostic, SkipReason,
+    SkippedFileDiagnostic,
 };
 use crate::reporter::TraversalSummary;
 use crate::{CliDiagnostic, CliSession};
 use biome_diagnostics::DiagnosticTags;
 use biome_diagnostics::{category, DiagnosticExt, Error, Resource, Severity};
-use biome_fs::{BiomePath, FileSystem, PathInterner};
+use biome_fs::{BiomePath, FileSystem, OpenOptions, PathInterner};
 use biome_fs::{TraversalContext, TraversalScope};
 use biome_service::dome::Dome;
-use biome_service::workspace::{DropPatternParams, IsPathIgnoredParams};
+use biome_service::project_index::content_hash;
+use biome_service::workspace::{
+    CloseFileParams, DocumentFileSource, DropPatternParams, ForgetFilesParams,
+    ImportProjectIndexParams, IsPathIgnoredParams, OpenFileParams,
+};
 use biome_service::{extension_error, workspace::SupportsFeatureParams, Workspace, WorkspaceError};
 use crossbeam::channel::{unbounded, Receiver, Sender};
+use rayon::prelude::*;
 use rustc_hash::FxHashSet;
 use std::collections::BTreeSet;
+use std::num::NonZeroUsize;
 use std::sync::atomic::AtomicU32;
-use std::sync::RwLock;
+use std::sync::{Condvar, Mutex, RwLock};
 use std::{
     env::current_dir,
     ffi::OsString,
@@ -45,7 +53,7 @@ pub(crate) fn traverse(
     cli_options: &CliOptions,
     mut inputs: Vec<OsString>,
 ) -> Result<TraverseResult, CliDiagnostic> {
-    init_thread_pool();
+    init_thread_pool(execution.threads());
 
     if inputs.is_empty() {
         match &execution.traversal_mode {
@@ -115,6 +123,7 @@ pub(crate) fn traverse(
                 messages: sender,
                 remaining_diagnostics: &remaining_diagnostics,
                 evaluated_paths: RwLock::default(),
+                open_files: execution.max_open_files().map(OpenFilesLimit::new),
             },
         );
         // wait for the main thread to finish
@@ -132,6 +141,7 @@ pub(crate) fn traverse(
 
     let errors = printer.errors();
     let warnings = printer.warnings();
+    let infos = printer.infos();
     let changed = changed.load(Ordering::Relaxed);
     let unchanged = unchanged.load(Ordering::Relaxed);
     let matches = matches.load(Ordering::Relaxed);
@@ -146,6 +156,7 @@ pub(crate) fn traverse(
             errors,
             matches,
             warnings,
+            infos,
             skipped,
             suggested_fixes_skipped,
             diagnostics_not_printed,
@@ -157,17 +168,57 @@ pub(crate) fn traverse(
 
 /// This function will setup the global Rayon thread pool the first time it's called
 ///
-/// This is currently only used to assign friendly debug names to the threads of the pool
-fn init_thread_pool() {
+/// It assigns friendly debug names to the threads of the pool, and bounds their number
+/// to `threads` when it's set. Rayon defaults to the number of CPUs otherwise.
+fn init_thread_pool(threads: Option<NonZeroUsize>) {
     static INIT_ONCE: Once = Once::new();
     INIT_ONCE.call_once(|| {
         rayon::ThreadPoolBuilder::new()
             .thread_name(|index| format!("biome::worker_{index}"))
+            .num_threads(threads.map_or(0, NonZeroUsize::get))
             .build_global()
             .expect("failed to initialize the global thread pool");
     });
 }
 
+/// Bounds the number of files that are processed at the same time.
+///
+/// The threads that process the files wait until a file is done when the limit is reached.
+struct OpenFilesLimit {
+    max_open_files: usize,
+    open_files: Mutex<usize>,
+    closed: Condvar,
+}
+
+impl OpenFilesLimit {
+    fn new(max_open_files: NonZeroUsize) -> Self {
+        Self {
+            max_open_files: max_open_files.get(),
+            open_files: Mutex::new(0),
+            closed: Condvar::new(),
+        }
+    }
+
+    /// Waits until a file can be opened. The file is considered closed once the guard is dropped.
+    fn open(&self) -> OpenFileGuard<'_> {
+        let mut open_files = self.open_files.lock().unwrap();
+        while *open_files >= self.max_open_files {
+            open_files = self.closed.wait(open_files).unwrap();
+        }
+        *open_files += 1;
+        OpenFileGuard(self)
+    }
+}
+
+struct OpenFileGuard<'a>(&'a OpenFilesLimit);
+
+impl Drop for OpenFileGuard<'_> {
+    fn drop(&mut self) {
+        *self.0.open_files.lock().unwrap() -= 1;
+        self.0.closed.notify_one();
+    }
+}
+
 /// Initiate the filesystem traversal tasks with the provided input paths and
 /// run it to completion, returning the duration of the process and the evaluated paths
 fn traverse_inputs(
@@ -176,6 +227,7 @@ fn traverse_inputs(
     ctx: &TraversalOptions,
 ) -> (Duration, BTreeSet<BiomePath>) {
     let start = Instant::now();
+    let roots: Vec<_> = inputs.iter().map(BiomePath::new).collect();
     fs.traversal(Box::new(move |scope: &dyn TraversalScope| {
         for input in inputs {
             scope.evaluate(ctx, PathBuf::from(input));
@@ -183,6 +235,18 @@ fn traverse_inputs(
     }));
 
     let paths = ctx.evaluated_paths();
+    let indexed_paths =
+        if ctx.execution.is_check() || ctx.execution.is_lint() || ctx.execution.is_ci() {
+            // The workspace of the daemon, and of the watch mode, outlives the command, so the
+            // files removed since the previous command are pruned from the project index
+            let _ = ctx
+                .workspace
+                .forget_files(ForgetFilesParams { paths: roots });
+            index_project_files(ctx, &paths)
+        } else {
+            Vec::new()
+        };
+
     let dome = Dome::new(paths);
     let mut iter = dome.iter();
     fs.traversal(Box::new(|scope: &dyn TraversalScope| {
@@ -199,9 +263,146 @@ fn traverse_inputs(
         }
     }));
 
+    // The files are closed once they are processed, except the ones that weren't processed,
+    // such as the ignored ones
+    for path in indexed_paths {
+        let _ = ctx.workspace.close_file(CloseFileParams { path });
+    }
+
     (start.elapsed(), ctx.evaluated_paths())
 }
 
+/// Opens the stylesheets, the GraphQL documents and the JSON schemas among `paths` before any
+/// file is processed, so that the workspace indexes the custom properties, the GraphQL operations
+/// and fragments, and the schemas they define.
+/// This way, lint rules and schema validation can resolve references across files,
+/// regardless of the order in which files are linted.
+///
+/// With `--cache`, the indexes of the files that didn't change since the previous run are
+/// imported from the cache instead, and the content hashes of these files are recorded,
+/// so that the cached results of the files that depend on them are discarded when they change.
+///
+/// The files are read and parsed in parallel, by the threads of the global thread pool.
+/// They are left open, so that their tree is reused when they are processed, instead of being
+/// parsed again. Returns the files that were opened.
+///
+/// Errors are ignored here, they are reported when the file is actually processed.
+fn index_project_files(ctx: &TraversalOptions, paths: &BTreeSet<BiomePath>) -> Vec<BiomePath> {
+    let imported = ctx
+        .execution
+        .cache()
+        .map(|cache| import_project_index(ctx, cache, paths))
+        .unwrap_or_default();
+
+    paths
+        .par_iter()
+        .filter(|path| !imported.contains(*path))
+        .filter_map(|path| {
+            let _guard = ctx.open_files.as_ref().map(OpenFilesLimit::open);
+            let (content, dependency) = read_file_to_index(ctx, path)?;
+            // The scripts are indexed when they are processed
+            if dependency == Dependency::Script {
+                return None;
+            }
+
+            ctx.workspace
+                .open_file(OpenFileParams {
+                    document_file_source: None,
+                    path: path.clone(),
+                    version: 0,
+                    content,
+                })
+                .ok()?;
+            Some(path.clone())
+        })
+        .collect()
+}
+
+/// Returns the content of the file at `path`, if the results of the other files can depend on it,
+/// along with the kind of dependency
+fn read_file_to_index(ctx: &TraversalOptions, path: &BiomePath) -> Option<(String, Dependency)> {
+    let file_source = DocumentFileSource::from_path(path);
+    if !matches!(
+        file_source,
+        DocumentFileSource::Css(_)
+            | DocumentFileSource::Json(_)
+            | DocumentFileSource::Graphql(_)
+            | DocumentFileSource::Js(_)
+    ) {
+        return None;
+    }
+
+    let mut file = ctx
+        .fs
+        .open_with_options(path, OpenOptions::default().read(true))
+        .ok()?;
+    let mut content = String::new();
+    file.read_to_string(&mut content).ok()?;
+    let dependency = match file_source {
+        DocumentFileSource::Css(_) => Dependency::Stylesheet,
+        DocumentFileSource::Graphql(_) => Dependency::Graphql,
+        // Only the JSON files that look like schemas are worth opening ahead of time
+        DocumentFileSource::Json(_) if content.contains("json-schema.org") => {
+            Dependency::JsonSchema
+        }
+        // Same for the scripts that embed GraphQL documents in template literals
+        DocumentFileSource::Js(_)
+            if content.contains("gql`")
+                || content.contains("graphql`")
+                || content.contains("#graphql") =>
+        {
+            Dependency::Graphql
+        }
+        DocumentFileSource::Js(_)
+            if content.contains("customElements.define") || content.contains("@Component") =>
+        {
+            Dependency::Script
+        }
+        _ => return None,
+    };
+    Some((content, dependency))
+}
+
+/// Records the content hashes of the files among `paths` that the results of the other files
+/// depend on, then imports the project index saved by the previous run with `--cache`,
+/// for the files whose content didn't change since then. Returns the imported files,
+/// which don't need to be opened again.
+fn import_project_index(
+    ctx: &TraversalOptions,
+    cache: &ResultCache,
+    paths: &BTreeSet<BiomePath>,
+) -> FxHashSet<BiomePath> {
+    let files: Vec<_> = paths
+        .par_iter()
+        .filter_map(|path| {
+            let (content, dependency) = read_file_to_index(ctx, path)?;
+            Some((path.clone(), dependency, content_hash(&content)))
+        })
+        .collect();
+    cache.set_dependencies(DependencyHashes::from_files(&files));
+
+    let Some(index) = cache.read_project_index(ctx.fs) else {
+        return FxHashSet::default();
+    };
+    let content_hashes = files
+        .into_iter()
+        // The JSON schemas aren't part of the project index, and the scripts are indexed
+        // when they are processed
+        .filter(|(_, dependency, _)| {
+            matches!(dependency, Dependency::Stylesheet | Dependency::Graphql)
+        })
+        .map(|(path, _, content_hash)| (path, content_hash))
+        .collect();
+
+    ctx.workspace
+        .import_project_index(ImportProjectIndexParams {
+            index,
+            content_hashes,
+        })
+        .map(|result| result.imported.into_iter().collect())
+        .unwrap_or_default()
+}
+
 // struct DiagnosticsReporter<'ctx> {}
 
 struct DiagnosticsPrinter<'ctx> {
@@ -218,6 +419,8 @@ struct DiagnosticsPrinter<'ctx> {
     /// Mutable reference to a boolean flag tracking whether the console thread
     /// printed any warnings-level message
     warnings: AtomicU32,
+    /// The number of information-level messages printed by the console thread
+    infos: AtomicU32,
     /// Whether the console thread should print diagnostics in verbose mode
     verbose: bool,
     /// The diagnostic level the console thread should print
@@ -233,6 +436,7 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         Self {
             errors: AtomicU32::new(0),
             warnings: AtomicU32::new(0),
+            infos: AtomicU32::new(0),
             remaining_diagnostics: AtomicU32::new(0),
             execution,
             diagnostic_level: Severity::Hint,
@@ -267,6 +471,10 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         self.warnings.load(Ordering::Relaxed)
     }
 
+    fn infos(&self) -> u32 {
+        self.infos.load(Ordering::Relaxed)
+    }
+
     fn not_printed_diagnostics(&self) -> u32 {
         self.not_printed_diagnostics.load(Ordering::Relaxed)
     }
@@ -335,6 +543,9 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                         self.warnings.fetch_add(1, Ordering::Relaxed);
                         // self.warnings.set(self.warnings.get() + 1)
                     }
+                    if err.severity() == Severity::Information {
+                        self.infos.fetch_add(1, Ordering::Relaxed);
+                    }
                     if let Some(Resource::File(file_path)) = location.resource.as_ref() {
                         // Retrieves the file name from the file ID cache, if it's a miss
                         // flush entries from the interner channel until it's found
@@ -391,6 +602,9 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                             if severity == Severity::Warning {
                                 self.warnings.fetch_add(1, Ordering::Relaxed);
                             }
+                            if severity == Severity::Information {
+                                self.infos.fetch_add(1, Ordering::Relaxed);
+                            }
 
                             let diag = diag.with_file_path(&name).with_file_source_code(&content);
                             diagnostics_to_print.push(diag);
@@ -407,6 +621,9 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                             if severity == Severity::Warning {
                                 self.warnings.fetch_add(1, Ordering::Relaxed);
                             }
+                            if severity == Severity::Information {
+                                self.infos.fetch_add(1, Ordering::Relaxed);
+                            }
 
                             let should_print = self.should_print();
 
@@ -424,14 +641,20 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     new,
                     diff_kind,
                 } => {
-                    // A diff is an error in CI mode and in format check mode
-                    let is_error = self.execution.is_ci() || !self.execution.is_format_write();
+                    // A diff is an error in CI mode, in format check mode, and when the changes aren't written.
+                    // The rewrites of a search are previews, like its matches.
+                    let is_rewrite = matches!(diff_kind, DiffKind::Rewrite);
+                    let is_error = self.execution.is_ci()
+                        || (!self.execution.is_format_write() && !is_rewrite)
+                        || self.execution.is_dry_run();
                     if is_error {
                         self.errors.fetch_add(1, Ordering::Relaxed);
                     }
 
                     let severity: Severity = if is_error {
                         Severity::Error
+                    } else if is_rewrite {
+                        Severity::Information
                     } else {
                         // we set lowest
                         Severity::Hint
@@ -485,6 +708,19 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                             .with_file_source_code(old.clone()),
                                     )
                                 }
+                                DiffKind::Rewrite => {
+                                    let diag = RewriteDiffDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(
+                                        diag.with_severity(severity)
+                                            .with_file_source_code(old.clone()),
+                                    )
+                                }
                             };
                         } else {
                             match diff_kind {
@@ -527,6 +763,19 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                             .with_file_source_code(old.clone()),
                                     )
                                 }
+                                DiffKind::Rewrite => {
+                                    let diag = RewriteDiffDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(
+                                        diag.with_severity(severity)
+                                            .with_file_source_code(old.clone()),
+                                    )
+                                }
                             };
                         }
                     }
@@ -563,6 +812,9 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
 
     /// List of paths that should be processed
     pub(crate) evaluated_paths: RwLock<BTreeSet<BiomePath>>,
+
+    /// The limit of files processed at the same time, if any
+    open_files: Option<OpenFilesLimit>,
 }
 
 impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
@@ -583,7 +835,13 @@ impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
 
     /// Send a message to the display thread
     pub(crate) fn push_message(&self, msg: impl Into<Message>) {
-        self.messages.send(msg.into()).ok();
+        let msg = msg.into();
+        if let Some(cache) = self.execution.cache() {
+            if let Some(file_name) = msg.file_name() {
+                cache.mark_reported(file_name);
+            }
+        }
+        self.messages.send(msg).ok();
     }
 
     pub(crate) fn miss_handler_err(&self, err: WorkspaceError, biome_path: &BiomePath) {
@@ -594,6 +852,13 @@ impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
         );
     }
 
+    /// Reports in verbose mode a file that is skipped, and why
+    pub(crate) fn skipped_file(&self, biome_path: &BiomePath, reason: SkipReason) {
+        self.push_diagnostic(
+            SkippedFileDiagnostic::new(biome_path.display().to_string(), reason).into(),
+        );
+    }
+
     pub(crate) fn protected_file(&self, biome_path: &BiomePath) {
         self.push_diagnostic(
             WorkspaceError::protected_file(biome_path.display().to_string()).into(),
@@ -633,6 +898,10 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
                     self.push_diagnostic(err.into());
                     false
                 });
+            if can_handle && !self.execution.follow_symlinks() && self.fs.path_is_symlink(path) {
+                self.skipped_file(biome_path, SkipReason::Symlink);
+                return false;
+            }
             return can_handle;
         }
 
@@ -666,7 +935,7 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
                 return false;
             }
         };
-        match self.execution.traversal_mode() {
+        let can_handle = match self.execution.traversal_mode() {
             TraversalMode::Check { .. } | TraversalMode::CI { .. } => {
                 file_features.supports_lint()
                     || file_features.supports_format()
@@ -677,10 +946,16 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
             // Imagine if Biome can't handle its own configuration file...
             TraversalMode::Migrate { .. } => true,
             TraversalMode::Search { .. } => file_features.supports_search(),
+        };
+        if can_handle && self.execution.is_binary_file(path) {
+            self.skipped_file(biome_path, SkipReason::BinaryPattern);
+            return false;
         }
+        can_handle
     }
 
     fn handle_path(&self, path: BiomePath) {
+        let _guard = self.open_files.as_ref().map(OpenFilesLimit::open);
         handle_file(self, &path)
     }
 
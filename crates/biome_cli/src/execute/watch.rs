@@ -0,0 +1,211 @@
+use crate::cli_options::CliOptions;
+use crate::execute::{execute_mode, Execution};
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_diagnostics::{Diagnostic, PrintDiagnostic};
+use biome_fs::BiomePath;
+use biome_lsp::FileWatcher;
+use biome_service::workspace::{ForgetFilesParams, GetModuleGraphParams, ModuleGraph};
+use biome_service::{App, DynRef, Workspace, WorkspaceRef};
+use rustc_hash::FxHashSet;
+use std::env::current_dir;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::channel;
+use std::time::Duration;
+
+/// How long the watcher waits for other changes after a change, because a single change,
+/// such as a checkout, emits a burst of events
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(100);
+
+/// It runs the `execution` on the `paths`, then watches them for changes, and runs
+/// the `execution` again on the files that changed, and on the scripts that import them.
+///
+/// The changes are notified by the file watcher of the daemon. It returns an error
+/// when the current directory can't be read, or when the files can't be watched.
+pub(crate) fn watch_mode(
+    execution: Execution,
+    mut session: CliSession,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+) -> Result<(), CliDiagnostic> {
+    let roots = if paths.is_empty() {
+        vec![current_dir().map_err(CliDiagnostic::io_error)?]
+    } else {
+        paths.iter().map(PathBuf::from).collect()
+    };
+
+    run_once(&execution, &mut session, cli_options, paths);
+
+    let (sender, receiver) = channel();
+    let _watcher = FileWatcher::new(&roots, move |path| {
+        let _ = sender.send(path);
+    })
+    .map_err(CliDiagnostic::io_error)?;
+
+    // The files that are changed aren't always processed by Biome, such as the ignored ones
+    let cli_options = CliOptions {
+        no_errors_on_unmatched: true,
+        ..cli_options.clone()
+    };
+    print_watching(&mut session);
+    loop {
+        // The sender lives as long as the watcher
+        let Ok(path) = receiver.recv() else {
+            return Ok(());
+        };
+        let mut changed_paths = FxHashSet::from_iter([path]);
+        while let Ok(path) = receiver.recv_timeout(DEBOUNCE_DURATION) {
+            changed_paths.insert(path);
+        }
+        // The removed files can't be processed, but they are removed from the project index
+        let (changed_paths, removed_paths): (FxHashSet<_>, Vec<_>) = changed_paths
+            .into_iter()
+            .partition(|path| session.app.fs.path_exists(path));
+        if !removed_paths.is_empty() {
+            let paths = removed_paths.into_iter().map(BiomePath::new).collect();
+            let _ = session
+                .app
+                .workspace
+                .forget_files(ForgetFilesParams { paths });
+        }
+        if changed_paths.is_empty() {
+            continue;
+        }
+
+        let paths = with_importers(&*session.app.workspace, changed_paths);
+        run_once(&execution, &mut session, &cli_options, paths);
+        // The files written by the execution, such as the fixed ones, don't trigger another run
+        while receiver.recv_timeout(DEBOUNCE_DURATION).is_ok() {}
+        print_watching(&mut session);
+    }
+}
+
+fn print_watching(session: &mut CliSession) {
+    session.app.console.log(markup! {
+        <Info>"Watching for changes. Press Ctrl+C to stop."</Info>
+    });
+}
+
+/// It runs the `execution` on the `paths`, and prints the error that ends it, if any.
+fn run_once(
+    execution: &Execution,
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+) {
+    let app = &mut session.app;
+    let session = CliSession {
+        app: App::new(
+            DynRef::Borrowed(&mut *app.fs),
+            &mut *app.console,
+            WorkspaceRef::Borrowed(&*app.workspace),
+        ),
+    };
+    if let Err(error) = execute_mode(execution.clone(), session, cli_options, paths) {
+        if error.tags().is_verbose() && cli_options.verbose {
+            app.console
+                .error(markup! {{PrintDiagnostic::verbose(&error)}});
+        } else {
+            app.console
+                .error(markup! {{PrintDiagnostic::simple(&error)}});
+        }
+    }
+}
+
+/// Returns the `changed_paths`, along with the scripts of the module graph that import them,
+/// because the diagnostics of these scripts can depend on what the changed files export.
+fn with_importers(workspace: &dyn Workspace, changed_paths: FxHashSet<PathBuf>) -> Vec<OsString> {
+    let importers = match workspace.get_module_graph(GetModuleGraphParams {}) {
+        Ok(module_graph) => importers(module_graph, &changed_paths),
+        Err(_) => Vec::new(),
+    };
+
+    changed_paths
+        .into_iter()
+        .chain(importers)
+        .map(PathBuf::into_os_string)
+        .collect()
+}
+
+/// Returns the scripts of the `module_graph` that import the `changed_paths`, directly or
+/// through other scripts, because a script can re-export what a changed file exports.
+fn importers(module_graph: ModuleGraph, changed_paths: &FxHashSet<PathBuf>) -> Vec<PathBuf> {
+    // The paths of the module graph are relative to the working directory, when the paths
+    // passed to the command are, while the file watcher notifies absolute paths
+    let mut modules: Vec<_> = module_graph
+        .modules
+        .into_iter()
+        .map(|module| {
+            let imports: Vec<_> = module
+                .imports
+                .iter()
+                .map(|import| absolute(Path::new(import)))
+                .collect();
+            (PathBuf::from(module.path), imports)
+        })
+        .collect();
+    let mut changed_files: FxHashSet<_> = changed_paths.iter().map(|path| absolute(path)).collect();
+    let mut importers = Vec::new();
+
+    // The importers are checked again until no other script imports them
+    loop {
+        let (new_importers, other_modules): (Vec<_>, Vec<_>) =
+            modules.into_iter().partition(|(path, imports)| {
+                !changed_files.contains(&absolute(path))
+                    && imports.iter().any(|import| changed_files.contains(import))
+            });
+        modules = other_modules;
+        if new_importers.is_empty() {
+            return importers;
+        }
+        for (path, _) in new_importers {
+            changed_files.insert(absolute(&path));
+            importers.push(path);
+        }
+    }
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::importers;
+    use biome_service::workspace::{ModuleGraph, ModuleGraphNode};
+    use rustc_hash::FxHashSet;
+    use std::path::PathBuf;
+
+    fn module(path: &str, imports: &[&str]) -> ModuleGraphNode {
+        ModuleGraphNode {
+            path: path.to_string(),
+            imports: imports.iter().map(ToString::to_string).collect(),
+            ..ModuleGraphNode::default()
+        }
+    }
+
+    #[test]
+    fn returns_the_importers_of_the_importers() {
+        let module_graph = ModuleGraph {
+            modules: vec![
+                module("/project/a.js", &["/project/b.js"]),
+                module("/project/b.js", &["/project/c.js"]),
+                module("/project/c.js", &["/project/a.js"]),
+                module("/project/d.js", &[]),
+            ],
+        };
+        let changed_paths = FxHashSet::from_iter([PathBuf::from("/project/c.js")]);
+
+        let mut importers = importers(module_graph, &changed_paths);
+        importers.sort();
+
+        assert_eq!(
+            importers,
+            [
+                PathBuf::from("/project/a.js"),
+                PathBuf::from("/project/b.js")
+            ]
+        );
+    }
+}
@@ -22,15 +22,17 @@ mod metrics;
 mod panic;
 mod reporter;
 mod service;
+mod telemetry;
 
 use crate::cli_options::{CliOptions, ColorsArg};
 use crate::commands::check::CheckCommandPayload;
 use crate::commands::ci::CiCommandPayload;
 use crate::commands::format::FormatCommandPayload;
 use crate::commands::lint::LintCommandPayload;
+use crate::commands::list_rules::ListRulesCommandPayload;
 use crate::commands::migrate::MigrateCommandPayload;
-use crate::commands::CommandRunner;
 pub use crate::commands::{biome_command, BiomeCommand};
+use crate::commands::{CommandRunner, DaemonSubCommand};
 pub use crate::logging::{setup_cli_subscriber, LoggingLevel};
 pub use diagnostics::CliDiagnostic;
 pub use execute::{execute_mode, Execution, TraversalMode, VcsTargeted};
@@ -78,16 +80,45 @@ impl<'app> CliSession<'app> {
 
         let result = match command {
             BiomeCommand::Version(_) => commands::version::full_version(self),
-            BiomeCommand::Rage(_, daemon_logs, formatter, linter) => {
-                commands::rage::rage(self, daemon_logs, formatter, linter)
+            BiomeCommand::Rage(_, daemon_logs, formatter, linter, scan) => {
+                commands::rage::rage(self, daemon_logs, formatter, linter, scan)
             }
-            BiomeCommand::Clean => commands::clean::clean(self),
+            BiomeCommand::Clean { cache_location } => commands::clean::clean(self, cache_location),
+            BiomeCommand::Completions { shell } => commands::completions::completions(self, shell),
             BiomeCommand::Start {
                 config_path,
                 log_path,
                 log_prefix_name,
-            } => commands::daemon::start(self, config_path, Some(log_path), Some(log_prefix_name)),
+                otlp_endpoint,
+            } => commands::daemon::start(
+                self,
+                config_path,
+                Some(log_path),
+                Some(log_prefix_name),
+                otlp_endpoint,
+            ),
             BiomeCommand::Stop => commands::daemon::stop(self),
+            BiomeCommand::Daemon(sub_command) => match sub_command {
+                DaemonSubCommand::Status => commands::daemon::status(self),
+                DaemonSubCommand::Stop => commands::daemon::stop(self),
+                DaemonSubCommand::Restart {
+                    config_path,
+                    log_path,
+                    log_prefix_name,
+                    otlp_endpoint,
+                } => commands::daemon::restart(
+                    self,
+                    config_path,
+                    Some(log_path),
+                    Some(log_prefix_name),
+                    otlp_endpoint,
+                ),
+                DaemonSubCommand::Logs {
+                    log_prefix_name,
+                    log_path,
+                    lines,
+                } => commands::daemon::logs(self, log_path, log_prefix_name, lines),
+            },
             BiomeCommand::Check {
                 apply,
                 apply_unsafe,
@@ -98,6 +129,7 @@ impl<'app> CliSession<'app> {
                 configuration,
                 paths,
                 stdin_file_path,
+                stdin_files,
                 linter_enabled,
                 organize_imports_enabled,
                 formatter_enabled,
@@ -105,6 +137,11 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                watch,
+                emit_module_graph,
+                module_graph_from,
+                module_graph_cycles,
+                cache_options,
             } => run_command(
                 self,
                 &cli_options,
@@ -117,6 +154,7 @@ impl<'app> CliSession<'app> {
                     configuration,
                     paths,
                     stdin_file_path,
+                    stdin_files,
                     linter_enabled,
                     organize_imports_enabled,
                     formatter_enabled,
@@ -124,6 +162,11 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    watch,
+                    emit_module_graph,
+                    module_graph_from,
+                    module_graph_cycles,
+                    cache_options,
                 },
             ),
             BiomeCommand::Lint {
@@ -132,14 +175,17 @@ impl<'app> CliSession<'app> {
                 write,
                 suppress,
                 suppression_reason,
+                fix_suggested,
                 fix,
                 unsafe_,
                 cli_options,
                 linter_configuration,
                 paths,
                 only,
+                rule,
                 skip,
                 stdin_file_path,
+                stdin_files,
                 vcs_configuration,
                 files_configuration,
                 staged,
@@ -149,6 +195,7 @@ impl<'app> CliSession<'app> {
                 javascript_linter,
                 json_linter,
                 graphql_linter,
+                cache_options,
             } => run_command(
                 self,
                 &cli_options,
@@ -158,13 +205,18 @@ impl<'app> CliSession<'app> {
                     write,
                     suppress,
                     suppression_reason,
+                    fix_suggested,
                     fix,
                     unsafe_,
                     linter_configuration,
                     paths,
-                    only,
+                    only: only
+                        .into_iter()
+                        .chain(rule.into_iter().flat_map(|selectors| selectors.0))
+                        .collect(),
                     skip,
                     stdin_file_path,
+                    stdin_files,
                     vcs_configuration,
                     files_configuration,
                     staged,
@@ -174,6 +226,7 @@ impl<'app> CliSession<'app> {
                     javascript_linter,
                     json_linter,
                     graphql_linter,
+                    cache_options,
                 },
             ),
             BiomeCommand::Ci {
@@ -186,6 +239,7 @@ impl<'app> CliSession<'app> {
                 cli_options,
                 changed,
                 since,
+                summary_file,
             } => run_command(
                 self,
                 &cli_options,
@@ -198,12 +252,16 @@ impl<'app> CliSession<'app> {
                     paths,
                     changed,
                     since,
+                    summary_file,
+                    summary_changed_files: None,
                 },
             ),
             BiomeCommand::Format {
                 javascript_formatter,
                 formatter_configuration,
                 stdin_file_path,
+                stdin_files,
+                range,
                 write,
                 fix,
                 cli_options,
@@ -216,6 +274,7 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                cache_options,
             } => run_command(
                 self,
                 &cli_options,
@@ -223,6 +282,8 @@ impl<'app> CliSession<'app> {
                     javascript_formatter,
                     formatter_configuration,
                     stdin_file_path,
+                    stdin_files,
+                    range,
                     write,
                     fix,
                     paths,
@@ -234,10 +295,32 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    cache_options,
                 },
             ),
-            BiomeCommand::Explain { doc } => commands::explain::explain(self, doc),
-            BiomeCommand::Init(emit_jsonc) => commands::init::init(self, emit_jsonc),
+            BiomeCommand::Explain { doc, json } => commands::explain::explain(self, doc, json),
+            BiomeCommand::ListRules {
+                language,
+                group,
+                fix_kind,
+                enabled,
+                json,
+                config_path,
+            } => commands::list_rules::list_rules(
+                self,
+                ListRulesCommandPayload {
+                    language,
+                    group,
+                    fix_kind,
+                    enabled,
+                    json,
+                    config_path,
+                },
+            ),
+            BiomeCommand::Init {
+                emit_jsonc,
+                interactive,
+            } => commands::init::init(self, emit_jsonc, interactive),
             BiomeCommand::LspProxy {
                 config_path,
                 log_path,
@@ -266,7 +349,9 @@ impl<'app> CliSession<'app> {
                 paths,
                 pattern,
                 stdin_file_path,
+                stdin_files,
                 vcs_configuration,
+                write,
             } => run_command(
                 self,
                 &cli_options,
@@ -275,7 +360,9 @@ impl<'app> CliSession<'app> {
                     paths,
                     pattern,
                     stdin_file_path,
+                    stdin_files,
                     vcs_configuration,
+                    write,
                 },
             ),
             BiomeCommand::RunServer {
@@ -283,11 +370,13 @@ impl<'app> CliSession<'app> {
                 config_path,
                 log_path,
                 log_prefix_name,
+                otlp_endpoint,
             } => commands::daemon::run_server(
                 stop_on_disconnect,
                 config_path,
                 Some(log_path),
                 Some(log_prefix_name),
+                otlp_endpoint,
             ),
             BiomeCommand::PrintSocket => commands::daemon::print_socket(),
         };
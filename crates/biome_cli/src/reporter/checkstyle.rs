@@ -0,0 +1,128 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::display::SourceFile;
+use biome_diagnostics::{PrintDescription, Resource, Severity};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io;
+
+pub(crate) struct CheckstyleReporter {
+    pub(crate) diagnostics_payload: DiagnosticsPayload,
+    pub(crate) execution: Execution,
+}
+
+impl Reporter for CheckstyleReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics_payload)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct CheckstyleReporterVisitor<'a>(pub(crate) &'a mut dyn Console);
+
+/// An `<error>` element of a Checkstyle report
+struct CheckstyleError {
+    line: Option<usize>,
+    column: Option<usize>,
+    severity: &'static str,
+    message: String,
+    source: &'static str,
+}
+
+impl<'a> ReporterVisitor for CheckstyleReporterVisitor<'a> {
+    fn report_summary(&mut self, _: &Execution, _: TraversalSummary) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        let diagnostics = payload.diagnostics.iter().filter(|diagnostic| {
+            (payload.verbose || !diagnostic.tags().is_verbose())
+                && diagnostic.severity() >= payload.diagnostic_level
+        });
+
+        // The errors are grouped by file, and sorted by position
+        let mut files: BTreeMap<&str, Vec<CheckstyleError>> = BTreeMap::new();
+        for diagnostic in diagnostics {
+            let location = diagnostic.location();
+            let Some(Resource::File(path)) = location.resource else {
+                continue;
+            };
+            let (line, column) = match (location.span, location.source_code) {
+                (Some(span), Some(source_code)) => {
+                    let start = SourceFile::new(source_code).location(span.start())?;
+                    (
+                        Some(start.line_number.get()),
+                        Some(start.column_number.get()),
+                    )
+                }
+                _ => (None, None),
+            };
+            files.entry(path).or_default().push(CheckstyleError {
+                line,
+                column,
+                severity: match diagnostic.severity() {
+                    Severity::Hint | Severity::Information => "info",
+                    Severity::Warning => "warning",
+                    Severity::Error | Severity::Fatal => "error",
+                },
+                message: PrintDescription(diagnostic).to_string(),
+                source: diagnostic
+                    .category()
+                    .map(|category| category.name())
+                    .unwrap_or_default(),
+            });
+        }
+
+        let mut report = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        report.push_str("<checkstyle version=\"4.3\">\n");
+        for (path, mut errors) in files {
+            errors.sort_by_key(|error| (error.line, error.column));
+            let _ = writeln!(report, "  <file name=\"{}\">", escape(path));
+            for error in errors {
+                report.push_str("    <error");
+                if let Some(line) = error.line {
+                    let _ = write!(report, " line=\"{line}\"");
+                }
+                if let Some(column) = error.column {
+                    let _ = write!(report, " column=\"{column}\"");
+                }
+                let _ = writeln!(
+                    report,
+                    " severity=\"{}\" message=\"{}\" source=\"{}\" />",
+                    error.severity,
+                    escape(&error.message),
+                    escape(error.source)
+                );
+            }
+            report.push_str("  </file>\n");
+        }
+        report.push_str("</checkstyle>");
+
+        self.0.log(markup! {
+            {report}
+        });
+
+        Ok(())
+    }
+}
+
+/// Escapes the characters of `text` that can't appear in the value of an XML attribute
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
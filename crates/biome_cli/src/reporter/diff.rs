@@ -0,0 +1,98 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::{category, Error, Resource, Visit};
+use biome_text_edit::TextEdit;
+use similar::TextDiff;
+use std::collections::BTreeMap;
+use std::io;
+
+pub(crate) struct DiffReporter {
+    pub(crate) diagnostics_payload: DiagnosticsPayload,
+    pub(crate) execution: Execution,
+}
+
+impl Reporter for DiffReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics_payload)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct DiffReporterVisitor<'a>(pub(crate) &'a mut dyn Console);
+
+impl<'a> ReporterVisitor for DiffReporterVisitor<'a> {
+    fn report_summary(&mut self, _: &Execution, _: TraversalSummary) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        // The files are sorted, so that the patch is the same across runs
+        let mut files: BTreeMap<&str, (String, String)> = BTreeMap::new();
+        for diagnostic in &payload.diagnostics {
+            if let Some((path, old, new)) = changes(diagnostic)? {
+                files.insert(path, (old, new));
+            }
+        }
+
+        let mut patch = String::new();
+        for (path, (old, new)) in files {
+            let path = path.strip_prefix("./").unwrap_or(path);
+            let diff = TextDiff::from_lines(&old, &new);
+            let unified_diff = diff
+                .unified_diff()
+                .context_radius(3)
+                .header(&format!("a/{path}"), &format!("b/{path}"))
+                .to_string();
+            patch.push_str(&unified_diff);
+        }
+
+        if !patch.is_empty() {
+            self.0.log(markup! {
+                {patch.trim_end()}
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the path, the old content and the new content of the file whose changes are
+/// described by `diagnostic`, if any.
+///
+/// The changes are reported by the diagnostics of the formatter, the other diagnostics
+/// can only suggest changes that weren't applied.
+fn changes(diagnostic: &Error) -> io::Result<Option<(&str, String, String)>> {
+    if diagnostic.category() != Some(category!("format")) {
+        return Ok(None);
+    }
+    let location = diagnostic.location();
+    let (Some(Resource::File(path)), Some(source_code)) = (location.resource, location.source_code)
+    else {
+        return Ok(None);
+    };
+
+    let mut visitor = DiffVisitor::default();
+    diagnostic.advices(&mut visitor)?;
+    Ok(visitor.diff.map(|diff| {
+        let old = source_code.text.to_string();
+        let new = diff.new_string(&old);
+        (path, old, new)
+    }))
+}
+
+/// Collects the diff advice of a diagnostic
+#[derive(Default)]
+struct DiffVisitor {
+    diff: Option<TextEdit>,
+}
+
+impl Visit for DiffVisitor {
+    fn record_diff(&mut self, diff: &TextEdit) -> io::Result<()> {
+        self.diff = Some(diff.clone());
+        Ok(())
+    }
+}
@@ -8,7 +8,6 @@ use serde::Serialize;
 use std::sync::RwLock;
 use std::{
     collections::HashSet,
-    hash::{DefaultHasher, Hash, Hasher},
     path::{Path, PathBuf},
 };
 
@@ -38,9 +37,7 @@ impl GitLabHasher {
     fn rehash_until_unique(&mut self, fingerprint: u64) -> u64 {
         let mut current = fingerprint;
         while self.0.contains(&current) {
-            let mut hasher = DefaultHasher::new();
-            current.hash(&mut hasher);
-            current = hasher.finish();
+            current = stable_hash(&[&current.to_le_bytes()]);
         }
 
         self.0.insert(current);
@@ -108,11 +105,10 @@ impl<'a> GitLabDiagnostics<'a> {
             .map(|category| category.name())
             .unwrap_or_default();
 
-        calculate_hash(&Fingerprint {
-            check_name,
-            path,
-            code,
-        })
+        // Including the source code in our hash leads to more stable
+        // fingerprints. If you instead rely on e.g. the line number and change
+        // the first line of a file, all of its fingerprint would change.
+        stable_hash(&[code.as_bytes(), check_name.as_bytes(), path.as_bytes()])
     }
 }
 
@@ -124,13 +120,7 @@ impl<'a> Display for GitLabDiagnostics<'a> {
             .diagnostics
             .iter()
             .filter(|d| d.severity() >= self.0.diagnostic_level)
-            .filter(|d| {
-                if self.0.verbose {
-                    d.tags().is_verbose()
-                } else {
-                    true
-                }
-            })
+            .filter(|d| self.0.verbose || !d.tags().is_verbose())
             .filter_map(|biome_diagnostic| {
                 let absolute_path = match biome_diagnostic.location().resource {
                     Some(Resource::File(file)) => Some(file),
@@ -228,18 +218,34 @@ struct Lines {
     begin: usize,
 }
 
-#[derive(Hash)]
-struct Fingerprint<'a> {
-    // Including the source code in our hash leads to more stable
-    // fingerprints. If you instead rely on e.g. the line number and change
-    // the first line of a file, all of its fingerprint would change.
-    code: &'a str,
-    check_name: &'a str,
-    path: &'a str,
+/// Hashes the `parts` with the 64-bit FNV-1a algorithm, separated by a byte that can't appear in UTF-8.
+///
+/// Unlike the hasher of the standard library, the algorithm doesn't change between versions of Rust,
+/// so the fingerprints of the violations that didn't change stay the same across versions of Biome,
+/// and GitLab doesn't report them as new.
+fn stable_hash(parts: &[&[u8]]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    for (index, part) in parts.iter().enumerate() {
+        if index > 0 {
+            hash = (hash ^ 0xff).wrapping_mul(PRIME);
+        }
+        for byte in part.iter() {
+            hash = (hash ^ u64::from(*byte)).wrapping_mul(PRIME);
+        }
+    }
+    hash
 }
 
-fn calculate_hash<T: Hash>(t: &T) -> u64 {
-    let mut s = DefaultHasher::new();
-    t.hash(&mut s);
-    s.finish()
+#[cfg(test)]
+mod tests {
+    use super::stable_hash;
+
+    #[test]
+    fn stable_hash_is_fnv_1a() {
+        assert_eq!(stable_hash(&[]), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(stable_hash(&[b"a"]), 0xaf63_dc4c_8601_ec8c);
+    }
 }
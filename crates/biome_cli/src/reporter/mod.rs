@@ -1,3 +1,5 @@
+pub(crate) mod checkstyle;
+pub(crate) mod diff;
 pub(crate) mod github;
 pub(crate) mod gitlab;
 pub(crate) mod json;
@@ -31,6 +33,7 @@ pub struct TraversalSummary {
     pub duration: Duration,
     pub errors: u32,
     pub warnings: u32,
+    pub infos: u32,
     pub skipped: usize,
     pub suggested_fixes_skipped: u32,
     pub diagnostics_not_printed: u32,
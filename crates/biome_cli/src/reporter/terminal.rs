@@ -234,6 +234,14 @@ impl<'a> fmt::Display for ConsoleTraversalSummary<'a> {
                 fmt.write_markup(markup!("\n"<Warn>"Found "{self.1.warnings}" warnings."</Warn>))?;
             }
         }
+        // The matches of a search are information diagnostics
+        if self.1.infos > 0 && !matches!(self.0, TraversalMode::Search { .. }) {
+            if self.1.infos == 1 {
+                fmt.write_markup(markup!("\n"<Info>"Found "{self.1.infos}" info."</Info>))?;
+            } else {
+                fmt.write_markup(markup!("\n"<Info>"Found "{self.1.infos}" infos."</Info>))?;
+            }
+        }
 
         if let TraversalMode::Search { .. } = self.0 {
             if self.1.matches == 1 {
@@ -58,6 +58,7 @@ fn spawn_daemon(
     config_path: Option<PathBuf>,
     log_path: Option<PathBuf>,
     log_file_name_prefix: Option<String>,
+    otlp_endpoint: Option<String>,
 ) -> io::Result<Child> {
     let binary = env::current_exe()?;
 
@@ -78,6 +79,9 @@ fn spawn_daemon(
     if let Some(log_file_name_prefix) = log_file_name_prefix {
         cmd.arg(format!("--log-prefix-name={}", log_file_name_prefix));
     }
+    if let Some(otlp_endpoint) = otlp_endpoint {
+        cmd.arg(format!("--otlp-endpoint={otlp_endpoint}"));
+    }
 
     // Create a new session for the process and make it the leader, this will
     // ensures that the child process is fully detached from its parent and will
@@ -129,6 +133,7 @@ pub(crate) async fn ensure_daemon(
     config_path: Option<PathBuf>,
     log_path: Option<PathBuf>,
     log_file_name_prefix: Option<String>,
+    otlp_endpoint: Option<String>,
 ) -> io::Result<bool> {
     let mut current_child: Option<Child> = None;
     let mut last_error = None;
@@ -172,6 +177,7 @@ pub(crate) async fn ensure_daemon(
                         config_path.clone(),
                         log_path.clone(),
                         log_file_name_prefix.clone(),
+                        otlp_endpoint.clone(),
                     )?);
                     time::sleep(Duration::from_millis(50)).await;
                 }
@@ -194,7 +200,7 @@ pub(crate) async fn ensure_daemon(
 /// Ensure the server daemon is running and ready to receive connections and
 /// print the global socket name in the standard output
 pub(crate) async fn print_socket() -> io::Result<()> {
-    ensure_daemon(true, None, None, None).await?;
+    ensure_daemon(true, None, None, None, None).await?;
     println!("{}", get_socket_name().display());
     Ok(())
 }
@@ -73,6 +73,7 @@ fn spawn_daemon(
     config_path: Option<PathBuf>,
     log_path: Option<PathBuf>,
     log_file_name_prefix: Option<String>,
+    otlp_endpoint: Option<String>,
 ) -> io::Result<()> {
     let binary = env::current_exe()?;
 
@@ -92,6 +93,9 @@ fn spawn_daemon(
     if let Some(log_file_name_prefix) = log_file_name_prefix {
         cmd.arg(format!("--log-prefix-name={}", log_file_name_prefix));
     }
+    if let Some(otlp_endpoint) = otlp_endpoint {
+        cmd.arg(format!("--otlp-endpoint={otlp_endpoint}"));
+    }
     cmd.creation_flags(CREATE_NEW_PROCESS_GROUP);
 
     cmd.spawn()?;
@@ -188,6 +192,7 @@ pub(crate) async fn ensure_daemon(
     config_path: Option<PathBuf>,
     log_path: Option<PathBuf>,
     log_file_name_prefix: Option<String>,
+    otlp_endpoint: Option<String>,
 ) -> io::Result<bool> {
     let mut did_spawn = false;
 
@@ -200,6 +205,7 @@ pub(crate) async fn ensure_daemon(
                     config_path.clone(),
                     log_path.clone(),
                     log_file_name_prefix.clone(),
+                    otlp_endpoint.clone(),
                 )?;
                 did_spawn = true;
                 time::sleep(Duration::from_millis(50)).await;
@@ -214,7 +220,7 @@ pub(crate) async fn ensure_daemon(
 /// Ensure the server daemon is running and ready to receive connections and
 /// print the global pipe name in the standard output
 pub(crate) async fn print_socket() -> io::Result<()> {
-    ensure_daemon(true, None, None, None).await?;
+    ensure_daemon(true, None, None, None, None).await?;
     println!("{}", get_pipe_name());
     Ok(())
 }
@@ -0,0 +1,159 @@
+//! Exports the traces of the daemon to an OpenTelemetry collector, so that the latency of the
+//! requests, and of the phases they go through, can be observed along with the other services
+//! of a developer infrastructure.
+//!
+//! Only the spans of the Biome crates are exported, along with the warnings and the errors
+//! emitted inside them. The other events are too numerous, and are written to the log files.
+//!
+//! The exporter is only compiled with the `otlp` feature, because its gRPC client brings many
+//! dependencies. Without it, asking for the traces to be exported is reported as an error.
+
+#[cfg(feature = "otlp")]
+use crate::VERSION;
+#[cfg(feature = "otlp")]
+use opentelemetry::{trace::TracerProvider as _, KeyValue};
+#[cfg(feature = "otlp")]
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+#[cfg(feature = "otlp")]
+use opentelemetry_sdk::{runtime, trace::TracerProvider, Resource};
+use tracing::Subscriber;
+#[cfg(feature = "otlp")]
+use tracing::{subscriber::Interest, Level, Metadata};
+#[cfg(feature = "otlp")]
+use tracing_subscriber::layer::{Context, Filter};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+/// Creates the layer that exports the spans to the collector listening at `endpoint`, with
+/// the OTLP protocol over gRPC. No layer is created when there's no endpoint, which is the
+/// default.
+///
+/// The spans are exported in batches by a task of the current Tokio runtime. The returned
+/// exporter must be shut down before the process exits, so that the last batch is exported.
+#[cfg(feature = "otlp")]
+pub(crate) fn otlp_layer<S>(
+    endpoint: Option<&str>,
+) -> Result<Option<(impl Layer<S>, SpanExporterHandle)>, String>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    let Some(endpoint) = endpoint else {
+        return Ok(None);
+    };
+
+    let exporter = SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint)
+        .build()
+        .map_err(|error| error.to_string())?;
+    let provider = TracerProvider::builder()
+        .with_batch_exporter(exporter, runtime::Tokio)
+        .with_resource(Resource::new([
+            KeyValue::new("service.name", "biome"),
+            KeyValue::new("service.version", VERSION),
+            KeyValue::new("process.pid", i64::from(std::process::id())),
+        ]))
+        .build();
+
+    let layer = tracing_opentelemetry::layer()
+        .with_tracer(provider.tracer("biome"))
+        .with_filter(TelemetryFilter);
+    Ok(Some((layer, SpanExporterHandle { provider })))
+}
+
+/// Creates no layer, because Biome was built without the exporter
+#[cfg(not(feature = "otlp"))]
+pub(crate) fn otlp_layer<S>(
+    endpoint: Option<&str>,
+) -> Result<Option<(impl Layer<S>, SpanExporterHandle)>, String>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    match endpoint {
+        Some(_) => Err(String::from(
+            "this build of Biome doesn't include the OpenTelemetry exporter",
+        )),
+        None => Ok(None::<(tracing_subscriber::layer::Identity, _)>),
+    }
+}
+
+/// Handle to the exporter of the spans created by [otlp_layer]
+#[cfg(feature = "otlp")]
+pub(crate) struct SpanExporterHandle {
+    provider: TracerProvider,
+}
+
+/// Handle to the exporter of the spans created by [otlp_layer], which can't be created
+/// because Biome was built without the exporter
+#[cfg(not(feature = "otlp"))]
+pub(crate) enum SpanExporterHandle {}
+
+#[cfg(feature = "otlp")]
+impl SpanExporterHandle {
+    /// Exports the spans that weren't exported yet, and stops the exporter
+    pub(crate) fn shutdown(self) {
+        if let Err(error) = self.provider.shutdown() {
+            tracing::warn!("Failed to export the last traces: {error}");
+        }
+    }
+}
+
+#[cfg(not(feature = "otlp"))]
+impl SpanExporterHandle {
+    pub(crate) fn shutdown(self) {
+        match self {}
+    }
+}
+
+/// Tracing filter enabling:
+/// - The spans at level debug or higher in crates whose name starts with `biome`
+/// - The events at level warn or higher in these crates, which are attached to their span
+#[cfg(feature = "otlp")]
+struct TelemetryFilter;
+
+#[cfg(feature = "otlp")]
+impl TelemetryFilter {
+    fn is_enabled(&self, meta: &Metadata<'_>) -> bool {
+        if !meta.target().starts_with("biome") {
+            return false;
+        }
+        let level = if meta.is_span() {
+            Level::DEBUG
+        } else {
+            Level::WARN
+        };
+        meta.level() <= &level
+    }
+}
+
+#[cfg(feature = "otlp")]
+impl<S> Filter<S> for TelemetryFilter {
+    fn enabled(&self, meta: &Metadata<'_>, _cx: &Context<'_, S>) -> bool {
+        self.is_enabled(meta)
+    }
+
+    fn callsite_enabled(&self, meta: &'static Metadata<'static>) -> Interest {
+        if self.is_enabled(meta) {
+            Interest::always()
+        } else {
+            Interest::never()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::otlp_layer;
+    use tracing_subscriber::Registry;
+
+    #[test]
+    fn exports_no_trace_without_endpoint() {
+        assert!(matches!(otlp_layer::<Registry>(None), Ok(None)));
+    }
+
+    #[cfg(not(feature = "otlp"))]
+    #[test]
+    fn reports_that_the_exporter_is_not_built() {
+        assert!(otlp_layer::<Registry>(Some("http://localhost:4317")).is_err());
+    }
+}
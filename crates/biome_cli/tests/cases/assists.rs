@@ -99,3 +99,83 @@ fn assist_writes() {
         result,
     ));
 }
+
+#[test]
+fn assist_overrides() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config = Path::new("biome.json");
+    fs.insert(
+        config.into(),
+        r#"{
+  "assists": {
+    "actions": {
+      "source": {
+        "useSortedKeys": "on"
+      }
+    }
+  },
+  "formatter": { "enabled": false },
+  "overrides": [
+    {
+      "include": ["generated/**"],
+      "assists": {
+        "actions": {
+          "source": {
+            "useSortedKeys": "off"
+          }
+        }
+      }
+    },
+    {
+      "include": ["ordered/**"],
+      "assists": {
+        "actions": {
+          "source": {
+            "useSortedKeys": {
+              "level": "on",
+              "options": { "sortOrder": "lexicographic" }
+            }
+          }
+        }
+      }
+    }
+  ]
+}"#
+        .as_bytes(),
+    );
+    let generated_file = Path::new("generated/file.json");
+    fs.insert(
+        generated_file.into(),
+        r#"{ "zod": true, "lorem": "ipsum", "foo": "bar" }"#.as_bytes(),
+    );
+    let ordered_file = Path::new("ordered/file.json");
+    fs.insert(
+        ordered_file.into(),
+        r#"{ "item10": 1, "item2": 2 }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                generated_file.as_os_str().to_str().unwrap(),
+                ordered_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "assist_overrides",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -97,6 +97,24 @@ const { some } = Astro.props
 ---
 <div>{some}</div>"#;
 
+const ASTRO_FILE_STYLE_UNFORMATTED: &str = r#"---
+const a     =     1;
+---
+<div></div>
+<style>
+a{color:red}
+</style>"#;
+
+const ASTRO_FILE_STYLE_FORMATTED: &str = r#"---
+const a = 1;
+---
+<div></div>
+<style>
+a {
+	color: red;
+}
+</style>"#;
+
 #[test]
 fn format_astro_files() {
     let mut fs = MemoryFileSystem::default();
@@ -720,3 +738,40 @@ fn astro_global_object() {
         result,
     ));
 }
+
+#[test]
+fn format_astro_style_blocks_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let astro_file_path = Path::new("file.astro");
+    fs.insert(
+        astro_file_path.into(),
+        ASTRO_FILE_STYLE_UNFORMATTED.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                astro_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, astro_file_path, ASTRO_FILE_STYLE_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_astro_style_blocks_write",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -76,6 +76,21 @@ const foo = "";
 </script>
 <div></div>"#;
 
+const SVELTE_FILE_BLOCK_EXPRESSIONS: &str = r#"<script>
+const { count } = $props();
+</script>
+{#if count === count}
+<p>{count}</p>
+{/if}"#;
+
+const SVELTE_FILE_TEMPLATE_BINDINGS: &str = r#"<script lang="ts">
+import Chart from "./components/Chart.svelte";
+import { tooltip } from "./actions";
+const title = "Chart";
+</script>
+<Chart {title} />
+<p use:tooltip>{title}</p>"#;
+
 #[test]
 fn sorts_imports_check() {
     let mut fs = MemoryFileSystem::default();
@@ -575,3 +590,73 @@ fn check_stdin_write_unsafe_successfully() {
         result,
     ));
 }
+
+#[test]
+fn lint_svelte_block_expressions() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let svelte_file_path = Path::new("file.svelte");
+    fs.insert(
+        svelte_file_path.into(),
+        SVELTE_FILE_BLOCK_EXPRESSIONS.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", svelte_file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_svelte_block_expressions",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_svelte_template_bindings() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "linter": {
+    "rules": {
+      "correctness": {
+        "noUnusedImports": "error",
+        "noUnusedVariables": "error"
+      }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+    let svelte_file_path = Path::new("file.svelte");
+    fs.insert(
+        svelte_file_path.into(),
+        SVELTE_FILE_TEMPLATE_BINDINGS.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", svelte_file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_svelte_template_bindings",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -141,6 +141,45 @@ const slots = defineSlots<{
 </script>
 <template></template>"#;
 
+const VUE_FILE_STYLE_UNFORMATTED: &str = r#"<script>
+const a     =     1;
+</script>
+<template></template>
+<style>
+a{color:red}
+</style>"#;
+
+const VUE_FILE_STYLE_FORMATTED: &str = r#"<script>
+const a = 1;
+</script>
+<template></template>
+<style>
+a {
+	color: red;
+}
+</style>"#;
+
+const VUE_FILE_TEMPLATE_BINDINGS_BEFORE: &str = r#"<script setup lang="ts">
+import Chart from "./components/Chart.vue";
+import MyTable from "./components/MyTable.vue";
+import Unused from "./components/Unused.vue";
+import { formatDate } from "./format";
+</script>
+<template>
+	<Chart :title="formatDate(date)" />
+	<my-table />
+</template>"#;
+
+const VUE_FILE_TEMPLATE_BINDINGS_AFTER: &str = r#"<script setup lang="ts">
+import Chart from "./components/Chart.vue";
+import MyTable from "./components/MyTable.vue";
+import { formatDate } from "./format";
+</script>
+<template>
+	<Chart :title="formatDate(date)" />
+	<my-table />
+</template>"#;
+
 #[test]
 fn format_vue_implicit_js_files() {
     let mut fs = MemoryFileSystem::default();
@@ -913,3 +952,88 @@ fn vue_compiler_macros_as_globals() {
         result,
     ));
 }
+
+#[test]
+fn format_vue_style_blocks_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Path::new("file.vue");
+    fs.insert(vue_file_path.into(), VUE_FILE_STYLE_UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                vue_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, vue_file_path, VUE_FILE_STYLE_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_vue_style_blocks_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_write_keeps_the_imports_used_by_the_template() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "linter": {
+    "rules": {
+      "correctness": {
+        "noUnusedImports": "error",
+        "noUnusedVariables": "error"
+      }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+    let vue_file_path = Path::new("file.vue");
+    fs.insert(
+        vue_file_path.into(),
+        VUE_FILE_TEMPLATE_BINDINGS_BEFORE.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write",
+                vue_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, vue_file_path, VUE_FILE_TEMPLATE_BINDINGS_AFTER);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_write_keeps_the_imports_used_by_the_template",
+        fs,
+        console,
+        result,
+    ));
+}
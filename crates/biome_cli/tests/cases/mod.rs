@@ -18,6 +18,8 @@ mod overrides_formatter;
 mod overrides_linter;
 mod overrides_organize_imports;
 mod protected_files;
+mod reporter_checkstyle;
+mod reporter_diff;
 mod reporter_github;
 mod reporter_gitlab;
 mod reporter_junit;
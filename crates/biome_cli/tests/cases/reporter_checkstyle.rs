@@ -0,0 +1,49 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const MAIN: &str = r#"debugger;
+"#;
+
+const INDEX: &str = r#"a == b;
+"#;
+
+#[test]
+fn reports_diagnostics_checkstyle_lint_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path1 = Path::new("main.js");
+    fs.insert(file_path1.into(), MAIN.as_bytes());
+
+    let file_path2 = Path::new("index.js");
+    fs.insert(file_path2.into(), INDEX.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--reporter=checkstyle",
+                file_path1.as_os_str().to_str().unwrap(),
+                file_path2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_diagnostics_checkstyle_lint_command",
+        fs,
+        console,
+        result,
+    ));
+}
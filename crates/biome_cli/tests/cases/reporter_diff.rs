@@ -0,0 +1,86 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const MAIN: &str = r#"let a = 1;
+console.log(  a );
+"#;
+
+const INDEX: &str = r#"statement(  )  ;
+"#;
+
+#[test]
+fn reports_changes_diff_check_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path1 = Path::new("main.js");
+    fs.insert(file_path1.into(), MAIN.as_bytes());
+
+    let file_path2 = Path::new("index.js");
+    fs.insert(file_path2.into(), INDEX.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--reporter=diff",
+                file_path1.as_os_str().to_str().unwrap(),
+                file_path2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_changes_diff_check_command",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_changes_diff_format_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path1 = Path::new("main.js");
+    fs.insert(file_path1.into(), MAIN.as_bytes());
+
+    let file_path2 = Path::new("index.js");
+    fs.insert(file_path2.into(), INDEX.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--reporter=diff",
+                file_path1.as_os_str().to_str().unwrap(),
+                file_path2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_changes_diff_format_command",
+        fs,
+        console,
+        result,
+    ));
+}
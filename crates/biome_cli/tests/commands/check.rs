@@ -3419,3 +3419,48 @@ fn should_error_if_unchanged_files_only_with_changed_flag() {
         result,
     ));
 }
+
+#[test]
+fn emits_module_graph_with_cycles() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(Path::new("a.js").into(), "import \"./b.js\";\n".as_bytes());
+    fs.insert(
+        Path::new("b.js").into(),
+        "import \"react\";\nimport \"./a\";\n".as_bytes(),
+    );
+    fs.insert(Path::new("c.js").into(), "import \"./a.js\";\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--emit-module-graph=graph.dot",
+                "--module-graph-cycles",
+                "a.js",
+                "b.js",
+                "c.js",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        Path::new("graph.dot"),
+        "digraph modules {\n  \"a.js\" -> \"b.js\";\n  \"b.js\" -> \"a.js\";\n}\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "emits_module_graph_with_cycles",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -1074,3 +1074,33 @@ fn should_error_if_unchanged_files_only_with_changed_flag() {
         result,
     ));
 }
+
+#[test]
+fn ci_writes_summary_file() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.set_on_get_changed_files(Box::new(|| vec![String::from("changed.js")]));
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "vcs": { "defaultBranch": "main" } }"#.as_bytes(),
+    );
+    fs.insert(Path::new("changed.js").into(), "debugger;\n".as_bytes());
+    fs.insert(Path::new("old.js").into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("ci"), "--summary-file=summary.md", "changed.js", "old.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ci_writes_summary_file",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,28 @@
+use crate::snap_test::SnapshotPayload;
+use crate::{assert_cli_snapshot, run_cli};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+
+#[test]
+fn prints_bash_script() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("completions"), "bash"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prints_bash_script",
+        fs,
+        console,
+        result,
+    ));
+}
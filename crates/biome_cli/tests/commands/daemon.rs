@@ -0,0 +1,107 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::env::temp_dir;
+use std::fs::{create_dir_all, remove_dir_all, write};
+
+#[test]
+fn status_when_the_server_is_not_running() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("daemon"), "status"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "status_when_the_server_is_not_running",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn logs_prints_the_last_lines_of_the_most_recent_log_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let log_path = temp_dir().join("biome_daemon_logs_most_recent");
+    let _ = remove_dir_all(&log_path);
+    create_dir_all(&log_path).unwrap();
+    write(
+        log_path.join("server.log.2022-10-14-15"),
+        "Not most recent log file\n",
+    )
+    .unwrap();
+    write(
+        log_path.join("server.log.2022-10-14-16"),
+        "INFO biome_lsp::server Starting Biome Language Server...\n\
+        INFO biome_lsp::server Attempting to load the configuration from 'biome.json' file\n\
+        INFO biome_lsp::server Sending shutdown signal\n",
+    )
+    .unwrap();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("daemon"),
+                "logs",
+                "--log-path",
+                log_path.to_str().unwrap(),
+                "--lines=2",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    remove_dir_all(&log_path).unwrap();
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "logs_prints_the_last_lines_of_the_most_recent_log_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn logs_without_log_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let log_path = temp_dir().join("biome_daemon_logs_empty");
+    let _ = remove_dir_all(&log_path);
+    create_dir_all(&log_path).unwrap();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("daemon"), "logs", "--log-path", log_path.to_str().unwrap()].as_slice()),
+    );
+
+    remove_dir_all(&log_path).unwrap();
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "logs_without_log_file",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -50,6 +50,28 @@ fn explain_valid_rule() {
     ));
 }
 
+#[test]
+fn explain_valid_rule_json() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("explain"), "--json", "noBlankTarget"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "explain_valid_rule_json",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn explain_not_found() {
     let mut fs = MemoryFileSystem::default();
@@ -637,6 +637,42 @@ fn applies_custom_configuration_over_config_file_issue_3175_v2() {
     ));
 }
 
+#[test]
+fn applies_custom_configuration_over_config_override() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "import React from 'react';\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--config-override=javascript.formatter.quoteStyle=double",
+                ("--quote-style"),
+                ("single"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "import React from 'react';\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_custom_configuration_over_config_override",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn applies_custom_jsx_quote_style() {
     let mut fs = MemoryFileSystem::default();
@@ -1342,6 +1378,95 @@ fn format_stdin_with_errors() {
     ));
 }
 
+#[test]
+fn format_stdin_files_successfully() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console.in_buffer.push(
+        r#"[{"path": "mock.js", "content": "function f() {return{}}"}, {"path": "mock.json", "content": "{\"a\":1}"}]"#
+            .to_string(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--stdin-files")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .first()
+        .expect("Console should have written a message");
+
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+
+    assert_eq!(
+        content,
+        r#"[{"path":"mock.js","content":"function f() {\n\treturn {};\n}\n"},{"path":"mock.json","content":"{ \"a\": 1 }\n"}]"#
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_stdin_files_successfully",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_stdin_range_successfully() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console
+        .in_buffer
+        .push("function f() {return{}}\nfunction g() {return{}}\n".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--stdin-file-path"),
+                ("mock.js"),
+                ("--range=2:1-2:24"),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .first()
+        .expect("Console should have written a message");
+
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+
+    assert_eq!(
+        content,
+        "function f() {return{}}\nfunction g() {\n\treturn {};\n}\n"
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_stdin_range_successfully",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn does_not_format_if_disabled() {
     let mut fs = MemoryFileSystem::default();
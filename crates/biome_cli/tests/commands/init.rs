@@ -142,3 +142,36 @@ fn does_not_create_config_file_if_jsonc_exists() {
         result,
     ));
 }
+
+#[test]
+fn creates_config_file_interactively() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    console.in_buffer.extend(
+        [
+            "javascript, typescript, css",
+            "react",
+            "spaces",
+            "space",
+            "4",
+            "single",
+            "",
+        ]
+        .map(String::from),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("init"), "--interactive"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "creates_config_file_interactively",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -91,6 +91,111 @@ fn ok() {
     assert!(result.is_ok(), "run_cli returned {result:?}");
 }
 
+#[test]
+fn ok_with_bounded_concurrency() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "files": { "maxOpenFiles": 1 } }"#.as_bytes(),
+    );
+    let first_path = Path::new("first.js");
+    fs.insert(first_path.into(), FORMATTED.as_bytes());
+    let second_path = Path::new("second.js");
+    fs.insert(second_path.into(), FORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--threads=2",
+                first_path.as_os_str().to_str().unwrap(),
+                second_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn skips_binary_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "files": { "binaryFiles": ["generated.js"] } }"#.as_bytes(),
+    );
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), FORMATTED.as_bytes());
+    let binary_path = Path::new("binary.js");
+    fs.insert(binary_path.into(), b"debugger;\0\x01\x02".as_slice());
+    let generated_path = Path::new("generated.js");
+    fs.insert(generated_path.into(), "debugger;".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--verbose",
+                file_path.as_os_str().to_str().unwrap(),
+                binary_path.as_os_str().to_str().unwrap(),
+                generated_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // Do not store the content of the binary file in the snapshot
+    fs.remove(binary_path);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "skips_binary_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn file_too_large_language_limit() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "files": { "maxSizePerLanguage": { "js": 16 } } }"#.as_bytes(),
+    );
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "statement1();\nstatement2();".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "file_too_large_language_limit",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn ok_read_only() {
     let mut fs = MemoryFileSystem::new_read_only();
@@ -680,6 +785,92 @@ fn downgrade_severity_info() {
     ));
 }
 
+#[test]
+fn disabled_fix_keeps_the_diagnostic() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+      "suspicious": {
+        "noDebugger": { "level": "warn", "fix": "none" }
+      }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), NO_DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--write",
+                "--unsafe",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, NO_DEBUGGER);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "disabled_fix_keeps_the_diagnostic",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn diagnostic_level_hides_the_infos() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        CONFIG_LINTER_DOWNGRADE_DIAGNOSTIC_INFO.as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), NO_DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--diagnostic-level=warn",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "diagnostic_level_hides_the_infos",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn upgrade_severity() {
     let mut fs = MemoryFileSystem::default();
@@ -1323,6 +1514,73 @@ fn ignore_file_in_subdir_in_symlinked_dir() {
     ));
 }
 
+#[test]
+fn skip_symlinked_dir_when_symlinks_are_not_followed() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let config = r#"{
+        "files": {
+            "followSymlinks": false
+        }
+    }"#;
+
+    let root_path = temp_dir().join("skip_symlinked_dir_when_symlinks_are_not_followed");
+    let _ = remove_dir_all(&root_path);
+    create_dir(&root_path).unwrap();
+
+    let symlinked = root_path.join("symlinked");
+    create_dir(&symlinked).unwrap();
+    File::create(symlinked.join("file.js"))
+        .unwrap()
+        .write_all(APPLY_SUGGESTED_BEFORE.as_bytes())
+        .unwrap();
+
+    let subroot_path = root_path.join("subroot");
+    create_dir(&subroot_path).unwrap();
+    File::create(subroot_path.join("biome.json"))
+        .unwrap()
+        .write_all(config.as_bytes())
+        .unwrap();
+
+    #[cfg(target_family = "unix")]
+    {
+        symlink(root_path.join("symlinked"), subroot_path.join("symlink")).unwrap();
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        check_windows_symlink!(symlink_file(
+            root_path.join("symlinked"),
+            subroot_path.join("symlink")
+        ));
+    }
+
+    let result = run_cli(
+        DynRef::Owned(Box::new(OsFileSystem::new(subroot_path.clone()))),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--verbose",
+                subroot_path.display().to_string().as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    remove_dir_all(root_path).unwrap();
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "skip_symlinked_dir_when_symlinks_are_not_followed",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn file_too_large() {
     let mut fs = MemoryFileSystem::default();
@@ -1697,6 +1955,46 @@ fn suppression_syntax_error() {
     ));
 }
 
+#[test]
+fn suppression_without_reason_or_expired() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{ "linter": { "suppressionReason": "required" } }"#.as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"// biome-ignore lint/suspicious/noDebugger:
+debugger;
+// biome-ignore lint/suspicious/noDebugger until: 2020-01-01: the bundler strips it
+debugger;
+// biome-ignore lint/suspicious/noDebugger until: 2999-12-31: the bundler strips it
+debugger;"#
+            .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppression_without_reason_or_expired",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn config_recommended_group() {
     let mut fs = MemoryFileSystem::default();
@@ -2182,6 +2480,100 @@ fn ignore_vcs_os_independent_parse() {
     ));
 }
 
+#[test]
+fn ignore_vcs_nested_ignore_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let biome_json = r#"{
+        "vcs": {
+            "enabled": true,
+            "clientKind": "git",
+            "useIgnoreFile": true
+        }
+    }"#;
+    fs.insert(Path::new("biome.json").into(), biome_json.as_bytes());
+    // The negation can't include a file of an ignored directory
+    fs.insert(
+        Path::new(".gitignore").into(),
+        "build/\n!build/keep.js\n".as_bytes(),
+    );
+    fs.insert(Path::new("src/.gitignore").into(), "*.gen.js\n".as_bytes());
+
+    let file_paths = [
+        "index.gen.js",
+        "src/index.js",
+        "src/index.gen.js",
+        "src/nested/index.gen.js",
+        "build/keep.js",
+    ];
+    fs.insert(Path::new(file_paths[0]).into(), "(1 >= 0)".as_bytes());
+    fs.insert(Path::new(file_paths[1]).into(), "(1 >= 0)".as_bytes());
+    for file_path in &file_paths[2..] {
+        fs.insert(Path::new(file_path).into(), "debugger;".as_bytes());
+    }
+
+    let mut args = vec!["lint"];
+    args.extend(file_paths);
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(args.as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ignore_vcs_nested_ignore_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ignore_files_of_the_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let biome_json = r#"{
+        "files": {
+            "ignoreFiles": [".eslintignore"]
+        }
+    }"#;
+    fs.insert(Path::new("biome.json").into(), biome_json.as_bytes());
+    fs.insert(Path::new(".eslintignore").into(), "legacy/\n".as_bytes());
+
+    let file_path1 = Path::new("index.js");
+    fs.insert(file_path1.into(), "(1 >= 0)".as_bytes());
+    let file_path2 = Path::new("legacy/index.js");
+    fs.insert(file_path2.into(), "debugger;".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                file_path1.as_os_str().to_str().unwrap(),
+                file_path2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ignore_files_of_the_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn ignore_vcs_ignored_file_via_cli() {
     let mut fs = MemoryFileSystem::default();
@@ -2750,6 +3142,61 @@ A = 0;
     ));
 }
 
+#[test]
+fn does_error_with_more_warnings_than_max_warnings() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+      "suspicious": {
+        "noClassAssign": "warn"
+      }
+    }
+  }
+}
+"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"class A {};
+A = 0;
+A = 1;
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--max-warnings=1",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_error_with_more_warnings_than_max_warnings",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn should_only_processes_changed_files_when_changed_flag_is_set() {
     let mut console = BufferConsole::default();
@@ -3815,6 +4262,73 @@ fn lint_only_write() {
     ));
 }
 
+#[test]
+fn lint_rule_fix_suggested() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let content = "debugger; delete obj.prop; a === -0;";
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), content.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--rule=suspicious/noDebugger,performance/noDelete",
+                "--fix-suggested",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_rule_fix_suggested",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_fix_suggested_without_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let content = "debugger; delete obj.prop; a === -0;";
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), content.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--fix-suggested",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_fix_suggested_without_rule",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn lint_skip_rule() {
     let mut fs = MemoryFileSystem::default();
@@ -4327,3 +4841,67 @@ fn should_error_if_unchanged_files_only_with_changed_flag() {
         result,
     ));
 }
+
+#[test]
+fn config_override_turns_off_a_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("test.js");
+    fs.insert(file_path.into(), NO_DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--config-override=linter.rules.suspicious.noDebugger=off",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_override_turns_off_a_rule",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_override_without_value() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("test.js");
+    fs.insert(file_path.into(), NO_DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--config-override=linter.enabled",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_override_without_value",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,58 @@
+use crate::snap_test::SnapshotPayload;
+use crate::{assert_cli_snapshot, run_cli};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+
+#[test]
+fn lists_rules_of_language() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("list-rules"), "--language=json"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lists_rules_of_language",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lists_rules_with_fix_kind_as_json() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("list-rules"),
+                "--language=json",
+                "--fix-kind=unsafe",
+                "--json",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lists_rules_with_fix_kind_as_json",
+        fs,
+        console,
+        result,
+    ));
+}
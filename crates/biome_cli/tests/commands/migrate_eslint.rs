@@ -703,3 +703,37 @@ fn migrate_merge_with_overrides() {
         result,
     ));
 }
+
+#[test]
+fn migrate_eslintrcjson_plugin_rules() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let eslintrc = r#"{
+        "rules": {
+            "import/no-cycle": "error",
+            "import/no-extraneous-dependencies": ["error", { "devDependencies": false }],
+            "import/order": "off",
+            "sonarjs/cognitive-complexity": ["warn", 20],
+            "unicorn/no-null": "warn"
+        }
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Path::new(".eslintrc.json").into(), eslintrc.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "eslint", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_eslintrcjson_plugin_rules",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -1,12 +1,16 @@
 mod check;
 mod ci;
+mod completions;
+mod daemon;
 mod explain;
 mod format;
 mod init;
 mod lint;
+mod list_rules;
 mod lsp_proxy;
 mod migrate;
 mod migrate_eslint;
 mod migrate_prettier;
 mod rage;
+mod search;
 mod version;
@@ -53,6 +53,28 @@ fn ok() {
     ));
 }
 
+#[test]
+fn with_scan() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_rage(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("rage"), "--scan"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_rage_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "with_scan",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn with_configuration() {
     let mut fs = MemoryFileSystem::default();
@@ -0,0 +1,128 @@
+use crate::run_cli;
+use crate::snap_test::{
+    assert_cli_snapshot, assert_file_contents, markup_to_string, SnapshotPayload,
+};
+use biome_console::{markup, BufferConsole};
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const REWRITE_PATTERN: &str = "`console.log($message)` => `console.info($message)`";
+
+const SOURCE: &str = r#"console.log("hello");"#;
+
+const REWRITTEN: &str = r#"console.info("hello");"#;
+
+#[test]
+fn search_prints_the_rewrite_without_writing() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), SOURCE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("search"),
+                REWRITE_PATTERN,
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, SOURCE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_prints_the_rewrite_without_writing",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_writes_the_rewrite() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), SOURCE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("search"),
+                ("--write"),
+                REWRITE_PATTERN,
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, REWRITTEN);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_writes_the_rewrite",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_writes_the_rewrite_of_stdin() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console.in_buffer.push(SOURCE.to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("search"),
+                ("--write"),
+                ("--stdin-file-path"),
+                ("file.js"),
+                REWRITE_PATTERN,
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .first()
+        .expect("Console should have written a message");
+
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+
+    assert_eq!(content, REWRITTEN);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_writes_the_rewrite_of_stdin",
+        fs,
+        console,
+        result,
+    ));
+}
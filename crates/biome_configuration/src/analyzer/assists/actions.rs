@@ -101,19 +101,42 @@ pub struct Source {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub organize_imports:
         Option<RuleAssistConfiguration<biome_js_analyze::options::OrganizeImports>>,
+    #[doc = "Converts media features between the range syntax and the `min-`/`max-` prefixes."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_media_feature_range_notation:
+        Option<RuleAssistConfiguration<biome_css_analyze::options::UseMediaFeatureRangeNotation>>,
+    #[doc = "Normalizes colors to a single notation."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_normalized_colors:
+        Option<RuleAssistConfiguration<biome_css_analyze::options::UseNormalizedColors>>,
     #[doc = "Enforce attribute sorting in JSX elements."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_sorted_attributes:
         Option<RuleAssistConfiguration<biome_js_analyze::options::UseSortedAttributes>>,
+    #[doc = "Sorts the fields of the selection sets, the arguments and the fields of the input objects in natural order."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_fields:
+        Option<RuleAssistConfiguration<biome_graphql_analyze::options::UseSortedFields>>,
     #[doc = "Sorts the keys of a JSON object in natural order"]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_sorted_keys:
         Option<RuleAssistConfiguration<biome_json_analyze::options::UseSortedKeys>>,
+    #[doc = "Sorts the compiler options, the `paths` and the `references` of TypeScript configurations"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_tsconfig:
+        Option<RuleAssistConfiguration<biome_json_analyze::options::UseSortedTsconfig>>,
 }
 impl Source {
     const GROUP_NAME: &'static str = "source";
-    pub(crate) const GROUP_RULES: &'static [&'static str] =
-        &["organizeImports", "useSortedAttributes", "useSortedKeys"];
+    pub(crate) const GROUP_RULES: &'static [&'static str] = &[
+        "organizeImports",
+        "useMediaFeatureRangeNotation",
+        "useNormalizedColors",
+        "useSortedAttributes",
+        "useSortedFields",
+        "useSortedKeys",
+        "useSortedTsconfig",
+    ];
     pub(crate) fn get_enabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
         let mut index_set = FxHashSet::default();
         if let Some(rule) = self.organize_imports.as_ref() {
@@ -121,16 +144,36 @@ impl Source {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
             }
         }
-        if let Some(rule) = self.use_sorted_attributes.as_ref() {
+        if let Some(rule) = self.use_media_feature_range_notation.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
             }
         }
-        if let Some(rule) = self.use_sorted_keys.as_ref() {
+        if let Some(rule) = self.use_normalized_colors.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
             }
         }
+        if let Some(rule) = self.use_sorted_attributes.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_fields.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_keys.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_tsconfig.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+            }
+        }
         index_set
     }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
@@ -146,14 +189,30 @@ impl Source {
                 .organize_imports
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useMediaFeatureRangeNotation" => self
+                .use_media_feature_range_notation
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useNormalizedColors" => self
+                .use_normalized_colors
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useSortedAttributes" => self
                 .use_sorted_attributes
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useSortedFields" => self
+                .use_sorted_fields
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useSortedKeys" => self
                 .use_sorted_keys
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useSortedTsconfig" => self
+                .use_sorted_tsconfig
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             _ => None,
         }
     }
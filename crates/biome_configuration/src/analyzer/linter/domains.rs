@@ -0,0 +1,125 @@
+use crate::analyzer::linter::{RuleGroup, Rules};
+use biome_analyze::RuleFilter;
+use biome_deserialize::Merge;
+use biome_deserialize_macros::{Deserializable, Merge};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A set of rules that target a framework or a kind of files, that can be enabled together
+#[derive(
+    Clone, Copy, Debug, Deserialize, Deserializable, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum RuleDomain {
+    /// The rules of the Next.js framework
+    Next,
+    /// The rules of the React library: hooks, JSX keys and properties
+    React,
+    /// The rules of the Solid library
+    Solid,
+    /// The rules of the test files, for Jest, Vitest and the test runner of Node.js
+    Test,
+}
+
+impl RuleDomain {
+    /// Returns the group and the name of the rules of the domain
+    pub const fn rules(self) -> &'static [(RuleGroup, &'static str)] {
+        match self {
+            Self::Next => &[
+                (RuleGroup::Nursery, "noDocumentImportInPage"),
+                (RuleGroup::Nursery, "noHeadElement"),
+                (RuleGroup::Nursery, "noHeadImportInDocument"),
+                (RuleGroup::Nursery, "noImgElement"),
+                (RuleGroup::Nursery, "useGoogleFontDisplay"),
+                (RuleGroup::Nursery, "useGoogleFontPreconnect"),
+            ],
+            Self::React => &[
+                (RuleGroup::Correctness, "noChildrenProp"),
+                (RuleGroup::Correctness, "noRenderReturnValue"),
+                (RuleGroup::Correctness, "noVoidElementsWithChildren"),
+                (RuleGroup::Correctness, "useExhaustiveDependencies"),
+                (RuleGroup::Correctness, "useHookAtTopLevel"),
+                (RuleGroup::Correctness, "useJsxKeyInIterable"),
+                (RuleGroup::Nursery, "useComponentExportOnlyModules"),
+                (RuleGroup::Security, "noDangerouslySetInnerHtml"),
+                (RuleGroup::Security, "noDangerouslySetInnerHtmlWithChildren"),
+                (RuleGroup::Style, "useFragmentSyntax"),
+                (RuleGroup::Suspicious, "noArrayIndexKey"),
+                (RuleGroup::Suspicious, "noCommentText"),
+            ],
+            Self::Solid => &[(RuleGroup::Suspicious, "noReactSpecificProps")],
+            Self::Test => &[
+                (RuleGroup::Style, "noDoneCallback"),
+                (RuleGroup::Suspicious, "noDuplicateTestHooks"),
+                (RuleGroup::Suspicious, "noExportsInTest"),
+                (RuleGroup::Suspicious, "noFocusedTests"),
+                (RuleGroup::Suspicious, "noMisplacedAssertion"),
+                (RuleGroup::Suspicious, "noSkippedTests"),
+            ],
+        }
+    }
+}
+
+/// The rules of a domain that are enabled
+#[derive(Clone, Copy, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum RuleDomainValue {
+    /// Enables all the rules of the domain
+    All,
+    /// Disables all the rules of the domain
+    None,
+    /// Enables the recommended rules of the domain
+    Recommended,
+}
+
+impl Merge for RuleDomainValue {
+    fn merge_with(&mut self, other: Self) {
+        *self = other;
+    }
+}
+
+/// The domains of rules that are enabled, and which of their rules are enabled
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct RuleDomains(pub BTreeMap<RuleDomain, RuleDomainValue>);
+
+impl RuleDomains {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the rules configured by the domains. The enabled rules have their default
+    /// severity: `error` for the recommended rules, and `warn` for the other ones.
+    ///
+    /// The rules configured explicitly are meant to be merged into the returned rules, so that
+    /// they take precedence over the domains.
+    pub fn as_rules(&self) -> Rules {
+        let recommended_rules = Rules::default().as_enabled_rules();
+        let mut groups = serde_json::Map::new();
+        for (domain, value) in &self.0 {
+            for (group, rule_name) in domain.rules() {
+                let is_recommended =
+                    recommended_rules.contains(&RuleFilter::Rule(group.as_str(), *rule_name));
+                let level = match value {
+                    RuleDomainValue::None => "off",
+                    RuleDomainValue::Recommended if !is_recommended => continue,
+                    _ if is_recommended => "error",
+                    _ => "warn",
+                };
+                if let Some(group) = groups
+                    .entry(group.as_str())
+                    .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()))
+                    .as_object_mut()
+                {
+                    group.insert(rule_name.to_string(), level.into());
+                }
+            }
+        }
+        // The names of the groups and of the rules come from the generated rules
+        serde_json::from_value(serde_json::Value::Object(groups)).unwrap_or_default()
+    }
+}
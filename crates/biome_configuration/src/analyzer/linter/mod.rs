@@ -1,9 +1,11 @@
+mod domains;
 #[rustfmt::skip]
 mod rules;
 
-use biome_deserialize::StringSet;
+use biome_deserialize::{Merge, StringSet};
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use bpaf::Bpaf;
+pub use domains::{RuleDomain, RuleDomainValue, RuleDomains};
 pub use rules::*;
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +22,12 @@ pub struct LinterConfiguration {
     #[partial(bpaf(pure(Default::default()), optional, hide))]
     pub rules: Rules,
 
+    /// The domains of rules to enable, such as `react` or `test`, and which of their rules
+    /// are enabled: `recommended`, `all` or `none`. The rules configured in `rules` take
+    /// precedence over the domains.
+    #[partial(bpaf(pure(Default::default()), optional, hide))]
+    pub domains: RuleDomains,
+
     /// A list of Unix shell style patterns. The formatter will ignore files/folders that will
     /// match these patterns.
     #[partial(bpaf(hide))]
@@ -29,6 +37,21 @@ pub struct LinterConfiguration {
     /// match these patterns.
     #[partial(bpaf(hide))]
     pub include: StringSet,
+
+    /// Whether the commands exit with an error code when the diagnostics emit warnings. `false` by default.
+    /// The argument `--error-on-warnings` takes precedence over this option.
+    #[partial(bpaf(hide))]
+    pub error_on_warnings: bool,
+
+    /// The number of warnings above which the commands exit with an error code. Unlimited by default.
+    /// The argument `--max-warnings` takes precedence over this option.
+    #[partial(bpaf(hide))]
+    pub max_warnings: Option<u32>,
+
+    /// Whether the suppression comments must explain why the code is suppressed, after the
+    /// colon. `optional` by default.
+    #[partial(bpaf(pure(Default::default()), optional, hide))]
+    pub suppression_reason: SuppressionReason,
 }
 
 impl LinterConfiguration {
@@ -42,8 +65,12 @@ impl Default for LinterConfiguration {
         Self {
             enabled: true,
             rules: Default::default(),
+            domains: Default::default(),
             ignore: Default::default(),
             include: Default::default(),
+            error_on_warnings: false,
+            max_warnings: None,
+            suppression_reason: SuppressionReason::default(),
         }
     }
 }
@@ -57,3 +84,27 @@ impl PartialLinterConfiguration {
         self.rules.clone().unwrap_or_default()
     }
 }
+
+/// Whether the suppression comments must have an explanation
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SuppressionReason {
+    /// The explanation of the suppression comments can be empty
+    #[default]
+    Optional,
+    /// The suppression comments without explanation emit a diagnostic
+    Required,
+}
+
+impl SuppressionReason {
+    pub const fn is_required(&self) -> bool {
+        matches!(self, Self::Required)
+    }
+}
+
+impl Merge for SuppressionReason {
+    fn merge_with(&mut self, other: Self) {
+        *self = other;
+    }
+}
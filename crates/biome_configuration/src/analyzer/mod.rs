@@ -336,7 +336,7 @@ impl<T: Default> Merge for RuleWithOptions<T> {
 pub struct RuleWithFixOptions<T: Default> {
     /// The severity of the emitted diagnostics by the rule
     pub level: RulePlainConfiguration,
-    /// The kind of the code actions emitted by the rule
+    /// The kind of the code actions emitted by the rule: `none` disables the fix of the rule, and `unsafe` only applies it with `--unsafe`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fix: Option<FixKind>,
     /// Rule's options
@@ -1,3 +1,4 @@
+use biome_css_formatter::context::{HexColorCase, KeywordCase, UrlQuotes};
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth, QuoteStyle};
 use bpaf::Bpaf;
@@ -70,6 +71,30 @@ pub struct CssFormatter {
     /// The type of quotes used in CSS code. Defaults to double.
     #[partial(bpaf(long("css-formatter-quote-style"), argument("double|single"), optional))]
     pub quote_style: QuoteStyle,
+
+    /// The letter case of hexadecimal colors in CSS code. Defaults to lower.
+    #[partial(bpaf(
+        long("css-formatter-hex-color-case"),
+        argument("lower|upper|preserve"),
+        optional
+    ))]
+    pub hex_color_case: Option<HexColorCase>,
+
+    /// The letter case of property names, function names, unknown at-rule names and keywords in CSS code. Defaults to preserve.
+    #[partial(bpaf(
+        long("css-formatter-keyword-case"),
+        argument("preserve|lower"),
+        optional
+    ))]
+    pub keyword_case: Option<KeywordCase>,
+
+    /// Whether the argument of `url()` functions should be quoted in CSS code. Defaults to preserve.
+    #[partial(bpaf(
+        long("css-formatter-url-quotes"),
+        argument("preserve|always|never"),
+        optional
+    ))]
+    pub url_quotes: Option<UrlQuotes>,
 }
 
 impl Default for CssFormatter {
@@ -81,6 +106,9 @@ impl Default for CssFormatter {
             line_ending: Default::default(),
             line_width: Default::default(),
             quote_style: QuoteStyle::Double,
+            hex_color_case: Default::default(),
+            keyword_case: Default::default(),
+            url_quotes: Default::default(),
         }
     }
 }
@@ -94,6 +122,9 @@ impl PartialCssFormatter {
             line_ending: self.line_ending,
             line_width: self.line_width,
             quote_style: self.quote_style.unwrap_or_default(),
+            hex_color_case: self.hex_color_case,
+            keyword_case: self.keyword_case,
+            url_quotes: self.url_quotes,
         }
     }
 }
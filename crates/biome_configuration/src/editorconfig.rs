@@ -8,11 +8,16 @@
 //! | indent_size          | indent_width |
 //! | end_of_line          | line_ending  |
 //! | max_line_length      | line_width   |
+//!
+//! The `[*]` section sets the options of the formatter, and the other sections become
+//! overrides, in the order of the file. A section that sets `insert_final_newline = false`
+//! disables the formatter for its files, because Biome always inserts a final newline.
 
-use std::{collections::HashMap, str::FromStr};
+use std::{path::Path, str::FromStr};
 
 use biome_diagnostics::{adapters::IniError, Error};
 use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth};
+use indexmap::IndexMap;
 use serde::{Deserialize, Deserializer};
 
 use crate::{
@@ -36,36 +41,66 @@ pub fn parse_str(s: &str) -> Result<EditorConfig, EditorConfigDiagnostic> {
 pub struct EditorConfig {
     #[serde(deserialize_with = "deserialize_bool_from_string")]
     root: bool,
+    /// The sections of the file, in order, so that the later sections take precedence
     #[serde(flatten)]
-    options: HashMap<String, EditorConfigOptions>,
+    options: IndexMap<String, EditorConfigOptions>,
 }
 
 impl EditorConfig {
-    pub fn to_biome(mut self) -> (Option<PartialConfiguration>, Vec<EditorConfigDiagnostic>) {
-        let diagnostics = self.validate();
+    /// Whether the search of the `.editorconfig` files stops at this file
+    pub fn is_root(&self) -> bool {
+        self.root
+    }
+
+    pub fn to_biome(self) -> (Option<PartialConfiguration>, Vec<EditorConfigDiagnostic>) {
+        self.to_biome_from(Path::new(""))
+    }
+
+    /// Converts a file located in an ancestor of the working directory, where
+    /// `working_directory` is the path of the working directory relative to the file.
+    ///
+    /// The sections whose patterns contain a `/` are relative to the file: they're rebased on
+    /// the working directory, and left out when they can't match its files.
+    pub fn to_biome_from(
+        mut self,
+        working_directory: &Path,
+    ) -> (Option<PartialConfiguration>, Vec<EditorConfigDiagnostic>) {
+        let mut diagnostics = self.validate();
+        let working_directory = working_directory
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
 
         let mut config = PartialConfiguration {
-            formatter: self.options.remove("*").map(|o| o.to_biome()),
+            formatter: self.options.shift_remove("*").map(|o| o.to_biome()),
             ..Default::default()
         };
-        let mut errors = vec![];
         let overrides: Vec<_> = self
             .options
             .into_iter()
-            .map(|(k, v)| {
+            .filter_map(|(k, v)| {
                 let patterns = match expand_unknown_glob_patterns(&k) {
-                    Ok(patterns) => patterns.into_iter().map(hack_convert_double_star).collect(),
+                    Ok(patterns) => patterns,
                     Err(err) => {
-                        errors.push(err);
+                        diagnostics.push(err);
                         vec![k]
                     }
                 };
+                let patterns: Vec<_> = patterns
+                    .iter()
+                    .filter_map(|pattern| rebase_pattern(pattern, &working_directory))
+                    .map(hack_convert_double_star)
+                    .collect();
+                if patterns.is_empty() {
+                    return None;
+                }
 
-                OverridePattern {
+                Some(OverridePattern {
                     include: Some(patterns.into_iter().collect()),
                     formatter: Some(v.to_biome_override()),
                     ..Default::default()
-                }
+                })
             })
             .collect();
         config.overrides = Some(Overrides(overrides));
@@ -74,9 +109,11 @@ impl EditorConfig {
     }
 
     fn validate(&self) -> Vec<EditorConfigDiagnostic> {
-        let errors: Vec<_> = self.options.values().flat_map(|o| o.validate()).collect();
-
-        errors
+        // The other sections disable the formatter of their files instead
+        self.options
+            .get("*")
+            .map(|options| options.validate())
+            .unwrap_or_default()
     }
 }
 
@@ -109,6 +146,7 @@ impl EditorConfigOptions {
 
     pub fn to_biome_override(self) -> OverrideFormatterConfiguration {
         OverrideFormatterConfiguration {
+            enabled: (self.insert_final_newline == Some(false)).then_some(false),
             indent_style: self.indent_style.into(),
             indent_width: self.indent_size.into(),
             line_ending: self.end_of_line.into(),
@@ -327,6 +365,25 @@ fn expand_unknown_glob_patterns(pattern: &str) -> Result<Vec<String>, EditorConf
     Ok(expanded_patterns)
 }
 
+/// Returns the pattern of a section relative to the working directory, or `None` if the section
+/// can't match the files of the working directory.
+///
+/// Like in the EditorConfig spec, the patterns without a `/` match the files at any depth, and
+/// the other ones are relative to the directory of the `.editorconfig` file.
+fn rebase_pattern(pattern: &str, working_directory: &str) -> Option<String> {
+    if !pattern.contains('/') || pattern.starts_with("**/") {
+        return Some(pattern.to_string());
+    }
+    let pattern = pattern.strip_prefix('/').unwrap_or(pattern);
+    if working_directory.is_empty() {
+        return Some(pattern.to_string());
+    }
+    pattern
+        .strip_prefix(working_directory)
+        .and_then(|pattern| pattern.strip_prefix('/'))
+        .map(str::to_string)
+}
+
 /// The EditorConfig spec allows for patterns like `**.yml`, which is not supported by biome. This function corrects such patterns so that they can be parsed by biome's glob parser.
 fn hack_convert_double_star(pattern: impl Into<String>) -> String {
     pattern
@@ -531,4 +588,60 @@ max_line_length = off
         let corrected = hack_convert_double_star(pattern);
         assert_eq!(corrected, "**/*.yml",);
     }
+
+    #[test]
+    fn should_convert_sections_to_overrides_in_order() {
+        let input = r#"
+[*.md]
+max_line_length = 120
+insert_final_newline = false
+
+[Makefile]
+indent_style = tab
+
+[*.{js,md}]
+max_line_length = 100
+"#;
+
+        let conf = parse_str(input).expect("Failed to parse editorconfig");
+        let (conf, errors) = conf.to_biome();
+        assert!(errors.is_empty());
+        let overrides = conf
+            .and_then(|conf| conf.overrides)
+            .expect("Overrides not set");
+        let includes: Vec<Vec<_>> = overrides
+            .0
+            .iter()
+            .map(|pattern| {
+                let include = pattern.include.as_ref().unwrap();
+                include.iter().map(String::as_str).collect()
+            })
+            .collect();
+        assert_eq!(
+            includes,
+            [vec!["*.md"], vec!["Makefile"], vec!["*.js", "*.md"]]
+        );
+
+        let formatter = overrides.0[0].formatter.as_ref().unwrap();
+        assert_eq!(formatter.enabled, Some(false));
+        assert_eq!(formatter.line_width.map(|v| v.value()), Some(120));
+        assert_eq!(overrides.0[2].formatter.as_ref().unwrap().enabled, None);
+    }
+
+    #[test]
+    fn should_rebase_the_patterns_of_a_parent_directory() {
+        assert_eq!(
+            rebase_pattern("*.md", "packages/app"),
+            Some("*.md".to_string())
+        );
+        assert_eq!(
+            rebase_pattern("/packages/app/src/*.js", "packages/app"),
+            Some("src/*.js".to_string())
+        );
+        assert_eq!(rebase_pattern("packages/lib/*.js", "packages/app"), None);
+        assert_eq!(
+            rebase_pattern("/lib/*.js", ""),
+            Some("lib/*.js".to_string())
+        );
+    }
 }
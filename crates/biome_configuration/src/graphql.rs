@@ -2,6 +2,9 @@ use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use biome_formatter::{
     BracketSpacing, IndentStyle, IndentWidth, LineEnding, LineWidth, QuoteStyle,
 };
+use biome_graphql_formatter::context::{
+    DirectiveOrder, EmptyLineBetweenDefinitions, SingleFieldSelectionSets,
+};
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
 
@@ -66,6 +69,30 @@ pub struct GraphqlFormatter {
     /// Whether to insert spaces around brackets in object literals. Defaults to true.
     #[partial(bpaf(long("bracket-spacing"), argument("true|false"), optional))]
     pub bracket_spacing: Option<BracketSpacing>,
+
+    /// The order of the directives in GraphQL code. Defaults to preserve.
+    #[partial(bpaf(
+        long("graphql-formatter-directive-order"),
+        argument("preserve|sorted"),
+        optional
+    ))]
+    pub directive_order: Option<DirectiveOrder>,
+
+    /// Whether the selection sets that contain a single field without selection set are printed on one line when they fit. Defaults to expand.
+    #[partial(bpaf(
+        long("graphql-formatter-single-field-selection-sets"),
+        argument("expand|collapse"),
+        optional
+    ))]
+    pub single_field_selection_sets: Option<SingleFieldSelectionSets>,
+
+    /// Whether the top-level definitions of GraphQL documents are always separated by an empty line. Defaults to preserve.
+    #[partial(bpaf(
+        long("graphql-formatter-empty-line-between-definitions"),
+        argument("preserve|always"),
+        optional
+    ))]
+    pub empty_line_between_definitions: Option<EmptyLineBetweenDefinitions>,
 }
 
 impl Default for GraphqlFormatter {
@@ -78,6 +105,9 @@ impl Default for GraphqlFormatter {
             line_width: Default::default(),
             quote_style: Default::default(),
             bracket_spacing: Default::default(),
+            directive_order: Default::default(),
+            single_field_selection_sets: Default::default(),
+            empty_line_between_definitions: Default::default(),
         }
     }
 }
@@ -92,6 +122,9 @@ impl PartialGraphqlFormatter {
             line_width: self.line_width,
             quote_style: self.quote_style,
             bracket_spacing: self.bracket_spacing,
+            directive_order: self.directive_order,
+            single_field_selection_sets: self.single_field_selection_sets,
+            empty_line_between_definitions: self.empty_line_between_definitions,
         }
     }
 }
@@ -105,12 +138,19 @@ pub struct GraphqlLinter {
     /// Control the formatter for GraphQL files.
     #[partial(bpaf(long("graphql-linter-enabled"), argument("true|false"), optional))]
     pub enabled: bool,
+
+    /// The path of the GraphQL schema that the operations are validated against, relative to the root of the project.
+    /// It's either a schema definition file, such as `schema.graphql`,
+    /// or a `.json` file that contains the result of an introspection query.
+    #[partial(bpaf(hide))]
+    pub schema: Option<String>,
 }
 
 impl PartialGraphqlLinter {
     pub fn get_linter_configuration(&self) -> GraphqlLinter {
         GraphqlLinter {
             enabled: self.enabled.unwrap_or_default(),
+            schema: self.schema.clone(),
         }
     }
 }
@@ -132,4 +172,5 @@ fn default_graphql_linter() {
     let graphql_configuration = GraphqlLinter::default();
 
     assert!(!graphql_configuration.enabled);
+    assert_eq!(graphql_configuration.schema, None);
 }
@@ -0,0 +1,161 @@
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use biome_formatter::{
+    AttributePosition, BracketSameLine, IndentStyle, IndentWidth, LineEnding, LineWidth,
+};
+use biome_html_formatter::context::WhitespaceSensitivity;
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Options applied to HTML files
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct HtmlConfiguration {
+    /// HTML parsing options
+    #[partial(type, bpaf(external(partial_html_parser), optional))]
+    pub parser: HtmlParser,
+
+    /// HTML formatter options
+    #[partial(type, bpaf(external(partial_html_formatter), optional))]
+    pub formatter: HtmlFormatter,
+}
+
+/// Options that changes how the HTML parser behaves
+#[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct HtmlParser {
+    /// The delimiters of the regions of the templates that are kept as they are written,
+    /// such as `{ "start": "{%", "end": "%}" }` for the tags of the Jinja templates.
+    /// These regions are formatted as they are, instead of being parsed as markup.
+    #[partial(bpaf(hide))]
+    pub ignore_delimiters: HtmlIgnoreDelimiters,
+}
+
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct HtmlIgnoreDelimiters(#[bpaf(hide)] pub Vec<HtmlIgnoreDelimiter>);
+
+impl FromStr for HtmlIgnoreDelimiters {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct HtmlIgnoreDelimiter {
+    /// The delimiter that starts a region, such as `{%`
+    #[bpaf(hide)]
+    pub start: String,
+
+    /// The delimiter that ends a region, such as `%}`
+    #[bpaf(hide)]
+    pub end: String,
+}
+
+/// Options that changes how the HTML formatter behaves
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct HtmlFormatter {
+    /// Control the formatter for HTML files.
+    #[partial(bpaf(long("html-formatter-enabled"), argument("true|false"), optional))]
+    pub enabled: Option<bool>,
+
+    /// The indent style applied to HTML files.
+    #[partial(bpaf(long("html-formatter-indent-style"), argument("tab|space"), optional))]
+    pub indent_style: Option<IndentStyle>,
+
+    /// The size of the indentation applied to HTML files. Default to 2.
+    #[partial(bpaf(long("html-formatter-indent-width"), argument("NUMBER"), optional))]
+    pub indent_width: Option<IndentWidth>,
+
+    /// The type of line ending applied to HTML files.
+    #[partial(bpaf(long("html-formatter-line-ending"), argument("lf|crlf|cr"), optional))]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to HTML files. Defaults to 80.
+    #[partial(bpaf(long("html-formatter-line-width"), argument("NUMBER"), optional))]
+    pub line_width: Option<LineWidth>,
+
+    /// The attribute position style in HTML elements. Defaults to auto.
+    #[partial(bpaf(
+        long("html-formatter-attribute-position"),
+        argument("multiline|auto"),
+        optional
+    ))]
+    pub attribute_position: Option<AttributePosition>,
+
+    /// Whether to hug the closing bracket of multiline HTML tags to the end of the last line, rather than being alone on the following line. Defaults to false.
+    #[partial(bpaf(
+        long("html-formatter-bracket-same-line"),
+        argument("true|false"),
+        optional
+    ))]
+    pub bracket_same_line: Option<BracketSameLine>,
+
+    /// Whether the whitespace around and inside the HTML elements is significant. Defaults to css.
+    #[partial(bpaf(
+        long("html-formatter-whitespace-sensitivity"),
+        argument("css|strict|ignore"),
+        optional
+    ))]
+    pub whitespace_sensitivity: Option<WhitespaceSensitivity>,
+}
+
+impl Default for HtmlFormatter {
+    fn default() -> Self {
+        Self {
+            enabled: Some(false),
+            indent_style: Default::default(),
+            indent_width: Default::default(),
+            line_ending: Default::default(),
+            line_width: Default::default(),
+            attribute_position: Default::default(),
+            bracket_same_line: Default::default(),
+            whitespace_sensitivity: Default::default(),
+        }
+    }
+}
+
+impl PartialHtmlFormatter {
+    pub fn get_formatter_configuration(&self) -> HtmlFormatter {
+        HtmlFormatter {
+            enabled: self.enabled,
+            indent_style: self.indent_style,
+            indent_width: self.indent_width,
+            line_ending: self.line_ending,
+            line_width: self.line_width,
+            attribute_position: self.attribute_position,
+            bracket_same_line: self.bracket_same_line,
+            whitespace_sensitivity: self.whitespace_sensitivity,
+        }
+    }
+}
+
+#[test]
+fn default_html_formatter() {
+    let html_configuration = HtmlFormatter::default();
+
+    assert_eq!(html_configuration.enabled, Some(false));
+    assert_eq!(html_configuration.indent_style, None);
+    assert_eq!(html_configuration.indent_width, None);
+    assert_eq!(html_configuration.line_ending, None);
+    assert_eq!(html_configuration.line_width, None);
+    assert_eq!(html_configuration.attribute_position, None);
+    assert_eq!(html_configuration.bracket_same_line, None);
+    assert_eq!(html_configuration.whitespace_sensitivity, None);
+}
@@ -1,8 +1,10 @@
+use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth};
 use biome_json_formatter::context::TrailingCommas;
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// Options applied to JSON files
 #[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
@@ -25,6 +27,10 @@ pub struct JsonConfiguration {
     /// Assists options
     #[partial(type, bpaf(external(partial_json_assists), optional))]
     pub assists: JsonAssists,
+
+    /// A list of JSON schemas, associated with the files that must satisfy them
+    #[partial(bpaf(hide))]
+    pub schemas: JsonSchemas,
 }
 
 /// Options that changes how the JSON parser behaves
@@ -40,6 +46,10 @@ pub struct JsonParser {
     #[partial(bpaf(hide))]
     /// Allow parsing trailing commas in `.json` files
     pub allow_trailing_commas: bool,
+
+    #[partial(bpaf(hide))]
+    /// Parse the files as JSON5, which allows unquoted keys, single quoted strings, hexadecimal numbers and multi-line strings, in addition to comments and trailing commas
+    pub json5: bool,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
@@ -154,3 +164,36 @@ impl Default for JsonAssists {
         Self { enabled: true }
     }
 }
+
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct JsonSchemas(#[bpaf(hide)] pub Vec<JsonSchemaAssociation>);
+
+impl FromStr for JsonSchemas {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JsonSchemaAssociation {
+    /// A list of Unix shell style patterns. The files that match these patterns
+    /// must satisfy the schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub include: Option<StringSet>,
+
+    /// The path of the JSON schema, relative to the root of the project.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub schema: Option<String>,
+}
@@ -9,10 +9,13 @@ pub mod editorconfig;
 pub mod formatter;
 pub mod generated;
 pub mod graphql;
+pub mod html;
 pub mod javascript;
 pub mod json;
+pub mod migrations;
 pub mod organize_imports;
 mod overrides;
+pub mod suppressions;
 pub mod vcs;
 
 use crate::analyzer::assists::{
@@ -31,7 +34,7 @@ pub use analyzer::{
     RuleConfiguration, RuleFixConfiguration, RulePlainConfiguration, RuleWithFixOptions,
     RuleWithOptions, Rules,
 };
-use biome_deserialize::{Deserialized, StringSet};
+use biome_deserialize::{Deserialized, Merge, StringSet};
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use biome_formatter::{IndentStyle, QuoteStyle};
 use bpaf::Bpaf;
@@ -46,13 +49,19 @@ pub use graphql::{
     partial_graphql_configuration, GraphqlConfiguration, GraphqlFormatter, GraphqlLinter,
     PartialGraphqlConfiguration, PartialGraphqlFormatter, PartialGraphqlLinter,
 };
+pub use html::{
+    partial_html_configuration, HtmlConfiguration, HtmlFormatter, HtmlIgnoreDelimiter,
+    HtmlIgnoreDelimiters, HtmlParser, PartialHtmlConfiguration, PartialHtmlFormatter,
+    PartialHtmlParser,
+};
+use indexmap::IndexMap;
 pub use javascript::{
     partial_javascript_configuration, JavascriptConfiguration, JavascriptFormatter,
     PartialJavascriptConfiguration, PartialJavascriptFormatter,
 };
 pub use json::{
-    partial_json_configuration, JsonConfiguration, JsonFormatter, PartialJsonConfiguration,
-    PartialJsonFormatter,
+    partial_json_configuration, JsonConfiguration, JsonFormatter, JsonSchemaAssociation,
+    JsonSchemas, PartialJsonConfiguration, PartialJsonFormatter,
 };
 pub use overrides::{
     OverrideAssistsConfiguration, OverrideFormatterConfiguration, OverrideLinterConfiguration,
@@ -60,7 +69,7 @@ pub use overrides::{
 };
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
-use std::num::NonZeroU64;
+use std::num::{NonZeroU64, NonZeroUsize};
 use std::path::PathBuf;
 use vcs::VcsClientKind;
 
@@ -128,6 +137,10 @@ pub struct Configuration {
     #[partial(type, bpaf(external(partial_graphql_configuration), optional))]
     pub graphql: GraphqlConfiguration,
 
+    /// Specific configuration for the HTML language
+    #[partial(type, bpaf(external(partial_html_configuration), optional, hide))]
+    pub html: HtmlConfiguration,
+
     /// A list of granular patterns that should be applied only to a sub set of files
     #[partial(bpaf(hide))]
     pub overrides: Overrides,
@@ -287,6 +300,18 @@ impl PartialConfiguration {
             .unwrap_or_default()
     }
 
+    pub fn get_html_formatter_configuration(&self) -> HtmlFormatter {
+        self.html
+            .as_ref()
+            .map(|f| {
+                f.formatter
+                    .as_ref()
+                    .map(|f| f.get_formatter_configuration())
+                    .unwrap_or_default()
+            })
+            .unwrap_or_default()
+    }
+
     pub fn is_linter_disabled(&self) -> bool {
         self.linter.as_ref().map_or(false, |f| f.is_disabled())
     }
@@ -329,32 +354,123 @@ pub struct FilesConfiguration {
     #[partial(bpaf(long("files-max-size"), argument("NUMBER")))]
     pub max_size: NonZeroU64,
 
+    /// The maximum allowed size in bytes for the files of a language, which takes precedence
+    /// over `maxSize` for these files. For example, the generated JSON files are often larger
+    /// than the scripts.
+    #[partial(bpaf(pure(Default::default()), optional, hide))]
+    pub max_size_per_language: FileSizeLimits,
+
     /// Tells Biome to not emit diagnostics when handling files that doesn't know
     #[partial(bpaf(long("files-ignore-unknown"), argument("true|false"), optional))]
     pub ignore_unknown: bool,
 
+    /// The number of threads that process the files. Defaults to the number of CPUs.
+    #[partial(bpaf(long("threads"), argument("NUMBER"), optional))]
+    pub threads: Option<NonZeroUsize>,
+
+    /// The maximum number of files that are processed at the same time, for the systems that
+    /// limit the number of open files. Unlimited by default.
+    #[partial(bpaf(long("max-open-files"), argument("NUMBER"), optional))]
+    pub max_open_files: Option<NonZeroUsize>,
+
     /// A list of Unix shell style patterns. Biome will ignore files/folders that will
     /// match these patterns.
     #[partial(bpaf(hide))]
     pub ignore: StringSet,
 
+    /// A list of files with the syntax of `.gitignore`, such as `.eslintignore`, relative to the
+    /// configuration file. Biome will ignore the files/folders that they ignore, even when the
+    /// integration with the VCS is disabled.
+    #[partial(bpaf(hide))]
+    pub ignore_files: StringSet,
+
     /// A list of Unix shell style patterns. Biome will handle only those files/folders that will
     /// match these patterns.
     #[partial(bpaf(hide))]
     pub include: StringSet,
+
+    /// Associates Unix shell style patterns to the language of the files that match them, for
+    /// the files with an extension that Biome doesn't know, or that must be handled as another
+    /// language. The first pattern that matches a file applies.
+    #[partial(bpaf(pure(Default::default()), optional, hide))]
+    pub associations: FileAssociations,
+
+    /// Whether Biome follows the symbolic links when it traverses the directories. The symbolic
+    /// links are skipped otherwise. Defaults to `true`.
+    #[partial(bpaf(pure(Default::default()), optional, hide))]
+    pub follow_symlinks: bool,
+
+    /// A list of Unix shell style patterns. Biome will skip the files that match these patterns
+    /// as binary files, in addition to the files whose content isn't text.
+    #[partial(bpaf(pure(Default::default()), optional, hide))]
+    pub binary_files: StringSet,
 }
 
 impl Default for FilesConfiguration {
     fn default() -> Self {
         Self {
             max_size: DEFAULT_FILE_SIZE_LIMIT,
+            max_size_per_language: Default::default(),
             ignore: Default::default(),
+            ignore_files: Default::default(),
             include: Default::default(),
             ignore_unknown: false,
+            threads: None,
+            max_open_files: None,
+            associations: Default::default(),
+            follow_symlinks: true,
+            binary_files: Default::default(),
         }
     }
 }
 
+/// The languages associated with the files that match a pattern
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FileAssociations(pub IndexMap<String, FileLanguage>);
+
+/// The maximum sizes of the files of a language, in bytes
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FileSizeLimits(pub IndexMap<FileLanguage, NonZeroU64>);
+
+/// The language of the files associated to a pattern
+#[derive(Clone, Copy, Debug, Deserialize, Deserializable, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum FileLanguage {
+    Css,
+    Graphql,
+    Js,
+    Json,
+    Jsonc,
+    Jsx,
+    Ts,
+    Tsx,
+}
+
+impl FileLanguage {
+    /// Returns the extension of the files of the language
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Css => "css",
+            Self::Graphql => "graphql",
+            Self::Js => "js",
+            Self::Json => "json",
+            Self::Jsonc => "jsonc",
+            Self::Jsx => "jsx",
+            Self::Ts => "ts",
+            Self::Tsx => "tsx",
+        }
+    }
+}
+
+impl Merge for FileLanguage {
+    fn merge_with(&mut self, other: Self) {
+        *self = other;
+    }
+}
+
 pub struct ConfigurationPayload {
     /// The result of the deserialization
     pub deserialized: Deserialized<PartialConfiguration>,
@@ -362,6 +478,9 @@ pub struct ConfigurationPayload {
     pub configuration_file_path: PathBuf,
     /// The base path where the external configuration in a package should be resolved from
     pub external_resolution_base_path: PathBuf,
+    /// The configuration as it's written in the file, which tells the options of the rules that
+    /// the overrides set explicitly
+    pub written_configuration: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Default, PartialEq, Clone)]
@@ -0,0 +1,382 @@
+//! The shapes of the configuration written for the previous versions of Biome.
+//!
+//! A configuration file declares the version of Biome it's written for with the URL of its
+//! `$schema`. The rules that were renamed, or moved to another group, since that version are
+//! still accepted with their previous name, and a diagnostic suggests to run `biome migrate`,
+//! which rewrites them with their current name.
+
+use biome_console::markup;
+use biome_deserialize::json::{deserialize_from_json_ast, deserialize_from_json_str};
+use biome_deserialize::{DeserializationDiagnostic, Deserialized, Merge};
+use biome_diagnostics::{DiagnosticExt, Error};
+use biome_json_parser::{parse_json, JsonParserOptions};
+use biome_json_syntax::{AnyJsonValue, JsonMember, JsonObjectValue, JsonRoot};
+use biome_rowan::{AstNode, TextRange};
+
+use crate::analyzer::linter::Rules;
+use crate::PartialConfiguration;
+
+/// A rule that was renamed, or moved to another group, by a version of Biome
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RenamedRule {
+    /// The version of Biome that renamed the rule
+    pub version: &'static str,
+    pub old_group: &'static str,
+    pub old_name: &'static str,
+    pub new_group: &'static str,
+    pub new_name: &'static str,
+}
+
+/// A rule of the `nursery` group that was promoted to another group by Biome 1.8.0
+const fn promoted(
+    old_name: &'static str,
+    new_group: &'static str,
+    new_name: &'static str,
+) -> RenamedRule {
+    RenamedRule {
+        version: "1.8.0",
+        old_group: "nursery",
+        old_name,
+        new_group,
+        new_name,
+    }
+}
+
+/// The rules that were renamed, or moved to another group, from the oldest to the most recent
+#[rustfmt::skip]
+pub const RENAMED_RULES: &[RenamedRule] = &[
+    // CSS
+    promoted("noDuplicateAtImportRules", "suspicious", "noDuplicateAtImportRules"),
+    promoted("noDuplicateFontNames", "suspicious", "noDuplicateFontNames"),
+    promoted("noDuplicateSelectorsKeyframeBlock", "suspicious", "noDuplicateSelectorsKeyframeBlock"),
+    promoted("noEmptyBlock", "suspicious", "noEmptyBlock"),
+    promoted("noImportantInKeyframe", "suspicious", "noImportantInKeyframe"),
+    promoted("noInvalidDirectionInLinearGradient", "correctness", "noInvalidDirectionInLinearGradient"),
+    promoted("noInvalidPositionAtImportRule", "correctness", "noInvalidPositionAtImportRule"),
+    promoted("noShorthandPropertyOverrides", "suspicious", "noShorthandPropertyOverrides"),
+    promoted("noUnknownFunction", "correctness", "noUnknownFunction"),
+    promoted("noUnknownMediaFeatureName", "correctness", "noUnknownMediaFeatureName"),
+    promoted("noUnknownProperty", "correctness", "noUnknownProperty"),
+    promoted("noUnknownUnit", "correctness", "noUnknownUnit"),
+    promoted("noUnmatchableAnbSelector", "correctness", "noUnmatchableAnbSelector"),
+    promoted("useConsistentGridAreas", "correctness", "noInvalidGridAreas"),
+    promoted("useGenericFontNames", "a11y", "useGenericFontNames"),
+    // JS
+    promoted("noBarrelFile", "performance", "noBarrelFile"),
+    promoted("noConsole", "suspicious", "noConsole"),
+    promoted("noConstantMathMinMaxClamp", "correctness", "noConstantMathMinMaxClamp"),
+    promoted("noDoneCallback", "style", "noDoneCallback"),
+    promoted("noDuplicateTestHooks", "suspicious", "noDuplicateTestHooks"),
+    promoted("noEvolvingTypes", "suspicious", "noEvolvingTypes"),
+    promoted("noExcessiveNestedTestSuites", "complexity", "noExcessiveNestedTestSuites"),
+    promoted("noExportsInTest", "suspicious", "noExportsInTest"),
+    promoted("noFlatMapIdentity", "correctness", "noFlatMapIdentity"),
+    promoted("noFocusedTests", "suspicious", "noFocusedTests"),
+    promoted("noLabelWithoutControl", "a11y", "noLabelWithoutControl"),
+    promoted("noMisplacedAssertion", "suspicious", "noMisplacedAssertion"),
+    promoted("noNamespaceImport", "style", "noNamespaceImport"),
+    promoted("noNodejsModules", "correctness", "noNodejsModules"),
+    promoted("noReactSpecificProps", "suspicious", "noReactSpecificProps"),
+    promoted("noReExportAll", "performance", "noReExportAll"),
+    promoted("noSkippedTests", "suspicious", "noSkippedTests"),
+    promoted("noSuspiciousSemicolonInJsx", "suspicious", "noSuspiciousSemicolonInJsx"),
+    promoted("noUndeclaredDependencies", "correctness", "noUndeclaredDependencies"),
+    promoted("noUnusedFunctionParameters", "correctness", "noUnusedFunctionParameters"),
+    promoted("noUselessStringConcat", "complexity", "noUselessStringConcat"),
+    promoted("noUselessTernary", "complexity", "noUselessTernary"),
+    promoted("noUselessUndefinedInitialization", "complexity", "noUselessUndefinedInitialization"),
+    promoted("noYodaExpression", "style", "noYodaExpression"),
+    promoted("useArrayLiterals", "correctness", "useArrayLiterals"),
+    promoted("useConsistentBuiltinInstantiation", "style", "useConsistentBuiltinInstantiation"),
+    promoted("useDateNow", "complexity", "useDateNow"),
+    promoted("useDefaultSwitchClause", "style", "useDefaultSwitchClause"),
+    promoted("useErrorMessage", "suspicious", "useErrorMessage"),
+    promoted("useExplicitLengthCheck", "style", "useExplicitLengthCheck"),
+    promoted("useFocusableInteractive", "a11y", "useFocusableInteractive"),
+    promoted("useImportExtensions", "correctness", "useImportExtensions"),
+    promoted("useJsxKeyInIterable", "correctness", "useJsxKeyInIterable"),
+    promoted("useNodeAssertStrict", "style", "useNodeAssertStrict"),
+    promoted("useNumberToFixedDigitsArgument", "suspicious", "useNumberToFixedDigitsArgument"),
+    promoted("useSemanticElements", "a11y", "useSemanticElements"),
+    promoted("useThrowNewError", "style", "useThrowNewError"),
+    promoted("useThrowOnlyError", "style", "useThrowOnlyError"),
+    promoted("useTopLevelRegex", "performance", "useTopLevelRegex"),
+];
+
+/// Returns the version of Biome of the URL of the `$schema` of the configuration, such as
+/// `1.8.3` for `https://biomejs.dev/schemas/1.8.3/schema.json`
+pub fn schema_version(root: &JsonRoot) -> Option<String> {
+    let object = root.value().ok()?.as_json_object_value()?.clone();
+    let schema = property(&object, "$schema")?;
+    let schema = schema.as_json_string_value()?.inner_string_text().ok()?;
+    let version = schema
+        .text()
+        .strip_prefix("https://biomejs.dev/schemas/")?
+        .strip_suffix("/schema.json")?;
+    Some(version.to_string())
+}
+
+/// Returns `true` if `version` is older than `other`. The pre-release suffixes are ignored.
+fn is_older_version(version: &str, other: &str) -> bool {
+    let parse = |version: &str| -> Vec<u64> {
+        version
+            .split('-')
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .map(|part| part.parse().unwrap_or_default())
+            .collect()
+    };
+    parse(version) < parse(other)
+}
+
+/// Deserializes the configuration of `source`, like [deserialize_from_json_str], and moves the
+/// rules renamed since the version of its schema to their current name. The configuration
+/// without a versioned schema accepts the previous names of all the renamed rules.
+///
+/// Each renamed rule emits a deprecation diagnostic, instead of the diagnostic of an unknown key.
+pub fn deserialize_configuration(
+    source: &str,
+    options: JsonParserOptions,
+) -> Deserialized<PartialConfiguration> {
+    let (configuration, mut diagnostics) =
+        deserialize_from_json_str::<PartialConfiguration>(source, options, "").consume();
+    let root = parse_json(source, options).tree();
+    let renamed_members = renamed_rule_members(&root);
+    if renamed_members.is_empty() {
+        return Deserialized::new(configuration, diagnostics);
+    }
+    let Some(mut configuration) = configuration else {
+        return Deserialized::new(None, diagnostics);
+    };
+
+    // The previous names are unknown keys for the deserializer
+    diagnostics.retain(|diagnostic| {
+        let span = diagnostic.location().span;
+        !renamed_members
+            .iter()
+            .any(|member| span == Some(member.name_range))
+    });
+    for member in renamed_members {
+        let rules = match member.override_index {
+            None => configuration
+                .linter
+                .get_or_insert_with(Default::default)
+                .rules
+                .get_or_insert_with(Default::default),
+            Some(index) => {
+                let Some(pattern) = configuration.overrides.0.get_mut(index) else {
+                    continue;
+                };
+                pattern
+                    .linter
+                    .get_or_insert_with(Default::default)
+                    .rules
+                    .get_or_insert_with(Default::default)
+            }
+        };
+        let (migrated, migrated_diagnostics) = member.deserialize_rule(source, options);
+        // The rules configured with their current name take precedence
+        if let Some(mut migrated) = migrated {
+            migrated.merge_with(rules.clone());
+            *rules = migrated;
+        }
+        diagnostics.extend(
+            migrated_diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_file_source_code(source)),
+        );
+        diagnostics.push(Error::from(member.diagnostic()).with_file_source_code(source));
+    }
+
+    Deserialized::new(Some(configuration), diagnostics)
+}
+
+/// The member of a renamed rule, written with its previous name
+struct RenamedRuleMember {
+    rule: &'static RenamedRule,
+    /// The index of the override that configures the rule, or `None` for `linter.rules`
+    override_index: Option<usize>,
+    name_range: TextRange,
+    value_range: TextRange,
+}
+
+impl RenamedRuleMember {
+    /// Deserializes the value of the member as the configuration of the rule with its current
+    /// name. The value keeps its offset, so that the diagnostics point to the configuration file.
+    fn deserialize_rule(
+        &self,
+        source: &str,
+        options: JsonParserOptions,
+    ) -> (Option<Rules>, Vec<Error>) {
+        let RenamedRule {
+            new_group,
+            new_name,
+            ..
+        } = self.rule;
+        let prefix = format!("{{\"{new_group}\":{{\"{new_name}\":");
+        let start = usize::from(self.value_range.start());
+        let value = &source[start..usize::from(self.value_range.end())];
+        let padding = " ".repeat(start.saturating_sub(prefix.len()));
+        let rules = format!("{padding}{prefix}{value}}}}}");
+        deserialize_from_json_ast::<Rules>(&parse_json(&rules, options).tree(), "").consume()
+    }
+
+    fn diagnostic(&self) -> DeserializationDiagnostic {
+        let RenamedRule {
+            old_group,
+            old_name,
+            new_group,
+            new_name,
+            version,
+        } = self.rule;
+        DeserializationDiagnostic::new_deprecated_use_instead(
+            &format!("{old_group}.{old_name}"),
+            self.name_range,
+            &format!("{new_group}.{new_name}"),
+        )
+        .with_note(markup! {
+            "The rule was renamed by Biome "{version}". Run "<Emphasis>"biome migrate --write"</Emphasis>" to update the configuration."
+        })
+    }
+}
+
+/// Returns the members of the rules written with a name they had before the version of the
+/// schema of the configuration, in `linter.rules` and in the `linter.rules` of the overrides
+fn renamed_rule_members(root: &JsonRoot) -> Vec<RenamedRuleMember> {
+    let mut members = Vec::new();
+    let Some(object) = root
+        .value()
+        .ok()
+        .and_then(|value| value.as_json_object_value().cloned())
+    else {
+        return members;
+    };
+    let version = schema_version(root);
+    let rules_of = |object: &JsonObjectValue| {
+        let linter = property(object, "linter")?;
+        property(linter.as_json_object_value()?, "rules")
+    };
+
+    if let Some(rules) = rules_of(&object) {
+        push_renamed_rule_members(&rules, version.as_deref(), None, &mut members);
+    }
+    if let Some(AnyJsonValue::JsonArrayValue(overrides)) = property(&object, "overrides") {
+        for (index, pattern) in overrides.elements().iter().enumerate() {
+            let Some(rules) = pattern
+                .ok()
+                .and_then(|pattern| rules_of(pattern.as_json_object_value()?))
+            else {
+                continue;
+            };
+            push_renamed_rule_members(&rules, version.as_deref(), Some(index), &mut members);
+        }
+    }
+    members
+}
+
+fn push_renamed_rule_members(
+    rules: &AnyJsonValue,
+    version: Option<&str>,
+    override_index: Option<usize>,
+    members: &mut Vec<RenamedRuleMember>,
+) {
+    let Some(rules) = rules.as_json_object_value() else {
+        return;
+    };
+    for group in rules.json_member_list().iter().flatten() {
+        let Some(group_name) = member_name(&group) else {
+            continue;
+        };
+        let Some(AnyJsonValue::JsonObjectValue(group_value)) = group.value().ok() else {
+            continue;
+        };
+        for rule_member in group_value.json_member_list().iter().flatten() {
+            let (Some(rule_name), Ok(value), Ok(name)) = (
+                member_name(&rule_member),
+                rule_member.value(),
+                rule_member.name(),
+            ) else {
+                continue;
+            };
+            let rule = RENAMED_RULES.iter().find(|rule| {
+                rule.old_group == group_name
+                    && rule.old_name == rule_name
+                    && version.map_or(true, |version| is_older_version(version, rule.version))
+            });
+            if let Some(rule) = rule {
+                members.push(RenamedRuleMember {
+                    rule,
+                    override_index,
+                    name_range: name.range(),
+                    value_range: value.range(),
+                });
+            }
+        }
+    }
+}
+
+fn member_name(member: &JsonMember) -> Option<String> {
+    Some(member.name().ok()?.inner_string_text().ok()?.to_string())
+}
+
+/// Returns the value of the property `name` of `object`
+fn property(object: &JsonObjectValue, name: &str) -> Option<AnyJsonValue> {
+    object
+        .json_member_list()
+        .iter()
+        .flatten()
+        .find(|member| member_name(member).as_deref() == Some(name))
+        .and_then(|member| member.value().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::RuleFixConfiguration;
+    use biome_diagnostics::Severity;
+
+    #[test]
+    fn moves_the_renamed_rules_of_the_previous_versions() {
+        let source = r#"{
+    "$schema": "https://biomejs.dev/schemas/1.7.3/schema.json",
+    "linter": {
+        "rules": {
+            "nursery": {
+                "noConsole": "warn"
+            }
+        }
+    }
+}"#;
+        let (configuration, diagnostics) =
+            deserialize_configuration(source, JsonParserOptions::default()).consume();
+        let rules = configuration.unwrap().linter.unwrap().rules.unwrap();
+
+        assert!(matches!(
+            rules.suspicious.and_then(|group| group.no_console),
+            Some(RuleFixConfiguration::Plain(_))
+        ));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity(), Severity::Warning);
+    }
+
+    #[test]
+    fn keeps_the_unknown_rules_of_the_recent_versions() {
+        let source = r#"{
+    "$schema": "https://biomejs.dev/schemas/1.9.0/schema.json",
+    "linter": { "rules": { "nursery": { "noConsole": "warn" } } }
+}"#;
+        let (_, diagnostics) =
+            deserialize_configuration(source, JsonParserOptions::default()).consume();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity(), Severity::Error);
+    }
+
+    #[test]
+    fn compares_the_versions() {
+        assert!(is_older_version("1.7.3", "1.8.0"));
+        assert!(is_older_version("1.8.0-nightly.1", "1.9.0"));
+        assert!(!is_older_version("1.10.0", "1.8.0"));
+    }
+}
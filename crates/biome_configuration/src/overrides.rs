@@ -1,9 +1,9 @@
 use super::javascript::PartialJavascriptConfiguration;
 use super::json::PartialJsonConfiguration;
-use super::{PartialCssConfiguration, PartialGraphqlConfiguration};
+use super::{PartialCssConfiguration, PartialGraphqlConfiguration, PartialHtmlConfiguration};
 use crate::{
-    partial_css_configuration, partial_graphql_configuration, partial_javascript_configuration,
-    partial_json_configuration,
+    partial_css_configuration, partial_graphql_configuration, partial_html_configuration,
+    partial_javascript_configuration, partial_json_configuration,
 };
 use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Deserializable, Merge};
@@ -67,6 +67,11 @@ pub struct OverridePattern {
     #[bpaf(external(partial_graphql_configuration), optional, hide)]
     pub graphql: Option<PartialGraphqlConfiguration>,
 
+    /// Specific configuration for the HTML language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(external(partial_html_configuration), optional, hide)]
+    pub html: Option<PartialHtmlConfiguration>,
+
     /// Specific configuration for the Json language
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(external(override_formatter_configuration), optional, hide)]
@@ -81,6 +86,11 @@ pub struct OverridePattern {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(external(override_organize_imports_configuration), optional, hide)]
     pub organize_imports: Option<OverrideOrganizeImportsConfiguration>,
+
+    /// Specific configuration for the assists
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(external(override_assists_configuration), optional, hide)]
+    pub assists: Option<OverrideAssistsConfiguration>,
 }
 
 impl FromStr for OverridePattern {
@@ -160,6 +170,11 @@ pub struct OverrideLinterConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(pure(crate::analyzer::linter::Rules::default()), optional, hide)]
     pub rules: Option<crate::analyzer::linter::Rules>,
+
+    /// The domains of rules to enable in the files of the override
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(pure(crate::analyzer::linter::RuleDomains::default()), optional, hide)]
+    pub domains: Option<crate::analyzer::linter::RuleDomains>,
 }
 
 #[derive(
@@ -180,13 +195,13 @@ pub struct OverrideOrganizeImportsConfiguration {
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase", default, deny_unknown_fields)]
 pub struct OverrideAssistsConfiguration {
-    /// if `false`, it disables the feature and the linter won't be executed. `true` by default
+    /// if `false`, it disables the feature and the assists won't be executed. `true` by default
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub enabled: Option<bool>,
 
-    /// List of rules
+    /// List of actions
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(pure(crate::analyzer::assists::Actions::default()), optional, hide)]
-    pub rules: Option<crate::analyzer::assists::Actions>,
+    pub actions: Option<crate::analyzer::assists::Actions>,
 }
@@ -0,0 +1,243 @@
+//! Helpers for handling the `.biome-suppressions` files, which turn off lint rules for the
+//! files that match a glob pattern, without adding overrides to the configuration file.
+//!
+//! Each line holds a glob pattern followed by the rules, or the groups of rules, that are
+//! turned off for the files that match it. The lines that start with `#` are comments that
+//! explain the entries that follow them:
+//!
+//! ```text
+//! # The legacy code will be removed once the new API is released
+//! legacy/**  suspicious/noExplicitAny  style/noVar
+//! ```
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use biome_console::markup;
+use biome_deserialize::{DeserializationDiagnostic, StringSet};
+use biome_rowan::{TextRange, TextSize};
+
+use crate::analyzer::{RuleGroup, RuleSelector};
+use crate::{OverrideLinterConfiguration, OverridePattern, Overrides, Rules};
+
+/// The name of the suppressions file, searched next to the configuration file
+pub const SUPPRESSIONS_FILE_NAME: &str = ".biome-suppressions";
+
+/// A parsed `.biome-suppressions` file
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SuppressionManifest {
+    pub entries: Vec<SuppressionEntry>,
+}
+
+/// The rules turned off for the files that match a glob pattern
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SuppressionEntry {
+    /// The glob pattern of the files, relative to the directory of the configuration file
+    pub pattern: String,
+    /// The rules and the groups of rules that are turned off
+    pub rules: Vec<RuleSelector>,
+    /// The comment that precedes the entry, without the `#` of its lines
+    pub comment: Option<String>,
+}
+
+/// Parses the content of a `.biome-suppressions` file. The unknown rules and the patterns
+/// without rules emit a diagnostic, and are left out of the manifest.
+pub fn parse_str(s: &str) -> (SuppressionManifest, Vec<DeserializationDiagnostic>) {
+    let mut manifest = SuppressionManifest::default();
+    let mut diagnostics = Vec::new();
+    let mut comment: Option<String> = None;
+
+    for line in s.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            // A blank line ends the comment, it doesn't describe the next entry
+            comment = None;
+            continue;
+        }
+        if let Some(text) = line.strip_prefix('#') {
+            let comment = comment.get_or_insert_with(String::new);
+            if !comment.is_empty() {
+                comment.push('\n');
+            }
+            comment.push_str(text.trim());
+            continue;
+        }
+
+        // The words are slices of `s`
+        let range_of = |word: &str| {
+            let offset = word.as_ptr() as usize - s.as_ptr() as usize;
+            TextRange::at(
+                TextSize::from(offset as u32),
+                TextSize::from(word.len() as u32),
+            )
+        };
+
+        let mut words = line.split_whitespace().peekable();
+        let Some(pattern) = words.next() else {
+            continue;
+        };
+        if words.peek().is_none() {
+            diagnostics.push(
+                DeserializationDiagnostic::new(markup! {
+                    "The pattern "<Emphasis>{pattern}</Emphasis>" doesn't list the rules to turn off."
+                })
+                .with_range(range_of(pattern)),
+            );
+            continue;
+        }
+
+        let mut rules = Vec::new();
+        for word in words {
+            match RuleSelector::from_str(word) {
+                Ok(selector) => rules.push(selector),
+                Err(error) => diagnostics.push(
+                    DeserializationDiagnostic::new(markup! {
+                        "Unknown rule "<Emphasis>{word}</Emphasis>": "{error}
+                    })
+                    .with_range(range_of(word)),
+                ),
+            }
+        }
+        if rules.is_empty() {
+            continue;
+        }
+
+        manifest.entries.push(SuppressionEntry {
+            pattern: pattern.to_string(),
+            rules,
+            comment: comment.take(),
+        });
+    }
+
+    (manifest, diagnostics)
+}
+
+impl SuppressionManifest {
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an override for each entry, that turns its rules off.
+    ///
+    /// The overrides are meant to be applied after the ones of the configuration file, so
+    /// that they take precedence over them.
+    pub fn to_overrides(&self) -> Overrides {
+        Overrides(
+            self.entries
+                .iter()
+                .map(|entry| OverridePattern {
+                    include: Some(StringSet::from_iter([entry.pattern.clone()])),
+                    linter: Some(OverrideLinterConfiguration {
+                        rules: Some(entry.rules_off()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the number of patterns that turn off each rule, with the syntax
+    /// `<group>/<rule>`. A group counts for each of its rules.
+    pub fn rule_statistics(&self) -> BTreeMap<String, usize> {
+        let mut statistics = BTreeMap::new();
+        for entry in &self.entries {
+            for (group, rule_name) in entry.rule_names() {
+                *statistics
+                    .entry(format!("{}/{rule_name}", group.as_str()))
+                    .or_default() += 1;
+            }
+        }
+        statistics
+    }
+}
+
+impl SuppressionEntry {
+    /// Returns the group and the name of the rules of the entry, without duplicates
+    fn rule_names(&self) -> Vec<(RuleGroup, &'static str)> {
+        let mut names = Vec::new();
+        for selector in &self.rules {
+            let rules: Vec<_> = match selector {
+                RuleSelector::Group(group) => group
+                    .rule_names()
+                    .iter()
+                    .map(|rule_name| (*group, *rule_name))
+                    .collect(),
+                RuleSelector::Rule(group, rule_name) => vec![(*group, *rule_name)],
+            };
+            for rule in rules {
+                if !names.contains(&rule) {
+                    names.push(rule);
+                }
+            }
+        }
+        names
+    }
+
+    fn rules_off(&self) -> Rules {
+        let mut groups = serde_json::Map::new();
+        for (group, rule_name) in self.rule_names() {
+            if let Some(group) = groups
+                .entry(group.as_str())
+                .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()))
+                .as_object_mut()
+            {
+                group.insert(rule_name.to_string(), "off".into());
+            }
+        }
+        // The names of the groups and of the rules come from the generated rules
+        serde_json::from_value(serde_json::Value::Object(groups)).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_entries_and_their_comments() {
+        let input = r#"# The legacy code will be removed
+# once the new API is released
+legacy/**  suspicious/noExplicitAny  lint/style/noVar
+
+# Unrelated comment
+
+generated/*.js complexity
+"#;
+        let (manifest, diagnostics) = parse_str(input);
+
+        assert!(diagnostics.is_empty());
+        assert_eq!(
+            manifest.entries,
+            [
+                SuppressionEntry {
+                    pattern: "legacy/**".to_string(),
+                    rules: vec![
+                        RuleSelector::Rule(RuleGroup::Suspicious, "noExplicitAny"),
+                        RuleSelector::Rule(RuleGroup::Style, "noVar"),
+                    ],
+                    comment: Some(
+                        "The legacy code will be removed\nonce the new API is released".to_string()
+                    ),
+                },
+                SuppressionEntry {
+                    pattern: "generated/*.js".to_string(),
+                    rules: vec![RuleSelector::Group(RuleGroup::Complexity)],
+                    comment: None,
+                },
+            ]
+        );
+        assert_eq!(
+            manifest.rule_statistics().get("suspicious/noExplicitAny"),
+            Some(&1)
+        );
+    }
+
+    #[test]
+    fn reports_the_unknown_rules_and_the_patterns_without_rules() {
+        let (manifest, diagnostics) = parse_str("legacy/** style/noUnknownRule\nsrc/*.js\n");
+
+        assert!(manifest.is_empty());
+        assert_eq!(diagnostics.len(), 2);
+    }
+}
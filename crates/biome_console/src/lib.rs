@@ -1,5 +1,5 @@
 use std::io;
-use std::io::{IsTerminal, Read, Write};
+use std::io::{BufRead, IsTerminal, Read, Write};
 use std::panic::RefUnwindSafe;
 use termcolor::{ColorChoice, StandardStream};
 use write::Termcolor;
@@ -37,6 +37,10 @@ pub trait Console: Send + Sync + RefUnwindSafe {
 
     /// It reads from a source, and if this source contains something, it's converted into a [String]
     fn read(&mut self) -> Option<String>;
+
+    /// It reads a line from a source, without the line terminator. It returns [None] when
+    /// the source doesn't contain anything else.
+    fn read_line(&mut self) -> Option<String>;
 }
 
 /// Extension trait for [Console] providing convenience printing methods
@@ -182,6 +186,16 @@ impl Console for EnvConsole {
             None
         }
     }
+
+    fn read_line(&mut self) -> Option<String> {
+        // The question that was printed before must be visible while the user answers it
+        self.out.flush().ok()?;
+        let mut buffer = String::new();
+        match self.r#in.lock().read_line(&mut buffer) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(buffer.trim_end_matches(['\r', '\n']).to_string()),
+        }
+    }
 }
 
 /// Implementation of [Console] storing all printed messages to a memory buffer
@@ -229,4 +243,13 @@ impl Console for BufferConsole {
             Some(self.in_buffer[0].clone())
         }
     }
+
+    fn read_line(&mut self) -> Option<String> {
+        // Each message of the buffer is a line
+        if self.in_buffer.is_empty() {
+            None
+        } else {
+            Some(self.in_buffer.remove(0))
+        }
+    }
 }
@@ -0,0 +1,16 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_assists_group;
+
+pub mod use_media_feature_range_notation;
+pub mod use_normalized_colors;
+
+declare_assists_group! {
+    pub Source {
+        name : "source" ,
+        rules : [
+            self :: use_media_feature_range_notation :: UseMediaFeatureRangeNotation ,
+            self :: use_normalized_colors :: UseNormalizedColors ,
+        ]
+     }
+}
@@ -0,0 +1,472 @@
+use crate::CssRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_source_rule, ActionCategory, Ast, Rule, RuleAction,
+    SourceActionKind,
+};
+use biome_console::markup;
+use biome_css_factory::make;
+use biome_css_syntax::{
+    AnyCssMediaAndCombinableCondition, AnyCssMediaCondition, AnyCssMediaInParens, AnyCssMediaQuery,
+    AnyCssMediaTypeCondition, AnyCssMediaTypeQuery, AnyCssQueryFeature, AnyCssQueryFeatureValue,
+    CssIdentifier, CssMediaAtRule, CssQueryFeatureRangeComparison, CssSyntaxKind, CssSyntaxNode,
+    CssSyntaxToken, T,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, TriviaPiece};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+declare_source_rule! {
+    /// Converts media features between the range syntax and the `min-`/`max-` prefixes.
+    ///
+    /// Media Queries Level 4 introduced a range syntax for the media features that accept a range of values,
+    /// such as `width` or `resolution`: `(400px <= width <= 700px)` is equivalent to
+    /// `(min-width: 400px) and (max-width: 700px)`.
+    /// This action rewrites the media features of `@media` at-rules to the notation
+    /// configured with the `notation` option.
+    ///
+    /// A `min-` feature and a `max-` feature of the same media feature that are next to each other
+    /// are merged into a single range, and a range with two bounds is split into two features.
+    /// Strict comparisons, such as `(width > 400px)`, can't be written with prefixes and are left untouched.
+    ///
+    /// Only the media features at the top level of a media query are converted:
+    /// the ones nested in parentheses or combined with `or` or `not` are left untouched.
+    ///
+    /// ## Examples
+    ///
+    /// ```css,expect_diff
+    /// @media (min-width: 400px) and (max-width: 700px) {}
+    /// ```
+    ///
+    /// ```css,expect_diff
+    /// @media screen and (max-width: 700px) {}
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "notation": "prefix"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### notation
+    ///
+    /// The notation to use:
+    /// - `range`: the range syntax, e.g. `(width >= 400px)`
+    /// - `prefix`: the `min-` and `max-` prefixes, e.g. `(min-width: 400px)`.
+    ///   Use this notation when your stylesheets must work in browsers that don't support the range syntax:
+    ///   Chrome and Edge before version 104, Firefox before version 63, and Safari before version 16.4.
+    ///
+    /// Default: `range`
+    ///
+    pub UseMediaFeatureRangeNotation {
+        version: "next",
+        name: "useMediaFeatureRangeNotation",
+        language: "css",
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseMediaFeatureRangeNotationOptions {
+    pub notation: MediaFeatureRangeNotation,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum MediaFeatureRangeNotation {
+    /// `(width >= 400px)`
+    #[default]
+    Range,
+    /// `(min-width: 400px)`
+    Prefix,
+}
+
+/// The media features that accept a range of values, and therefore the `min-` and `max-` prefixes.
+const RANGE_MEDIA_FEATURES: [&str; 10] = [
+    "aspect-ratio",
+    "color",
+    "color-index",
+    "device-aspect-ratio",
+    "device-height",
+    "device-width",
+    "height",
+    "monochrome",
+    "resolution",
+    "width",
+];
+
+/// A media condition to replace, along with its replacement.
+pub struct ConvertedCondition {
+    condition: CssSyntaxNode,
+    replacement: CssSyntaxNode,
+}
+
+impl Rule for UseMediaFeatureRangeNotation {
+    type Query = Ast<CssMediaAtRule>;
+    type State = Box<[ConvertedCondition]>;
+    type Signals = Option<Self::State>;
+    type Options = UseMediaFeatureRangeNotationOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let notation = ctx.options().notation;
+        let conditions: Box<[_]> = ctx
+            .query()
+            .queries()
+            .iter()
+            .filter_map(|query| top_level_condition(&query.ok()?))
+            .filter(|condition| !condition.has_comments_descendants())
+            .filter_map(|condition| {
+                let features = and_chain(&condition)?;
+                let converted = convert_features(&features, notation)?;
+                Some(ConvertedCondition {
+                    condition,
+                    replacement: make_and_chain(converted)?,
+                })
+            })
+            .collect();
+
+        (!conditions.is_empty()).then_some(conditions)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        let mut mutation = ctx.root().begin();
+        for ConvertedCondition {
+            condition,
+            replacement,
+        } in state.iter()
+        {
+            mutation.replace_element(condition.clone().into(), replacement.clone().into());
+        }
+
+        let message = match ctx.options().notation {
+            MediaFeatureRangeNotation::Range => markup! {
+                "Use the range syntax for the media features."
+            },
+            MediaFeatureRangeNotation::Prefix => markup! {
+                "Use the "<Emphasis>"min-"</Emphasis>" and "<Emphasis>"max-"</Emphasis>" prefixes for the media features."
+            },
+        };
+
+        Some(RuleAction::new(
+            rule_action_category!(),
+            Applicability::Always,
+            message,
+            mutation,
+        ))
+    }
+}
+
+/// Returns the condition of a media query, without its media type.
+///
+/// ```css
+/// @media screen and (width > 400px), (color) {}
+/// /*                ^^^^^^^^^^^^^^^  ^^^^^^^ */
+/// ```
+fn top_level_condition(query: &AnyCssMediaQuery) -> Option<CssSyntaxNode> {
+    match query {
+        AnyCssMediaQuery::CssMediaConditionQuery(query) => match query.condition().ok()? {
+            condition @ (AnyCssMediaCondition::CssMediaAndCondition(_)
+            | AnyCssMediaCondition::AnyCssMediaInParens(_)) => Some(condition.into_syntax()),
+            _ => None,
+        },
+        AnyCssMediaQuery::AnyCssMediaTypeQuery(AnyCssMediaTypeQuery::CssMediaAndTypeQuery(
+            query,
+        )) => match query.right().ok()? {
+            condition @ (AnyCssMediaTypeCondition::CssMediaAndCondition(_)
+            | AnyCssMediaTypeCondition::AnyCssMediaInParens(_)) => Some(condition.into_syntax()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the conditions combined with `and`, in source order.
+///
+/// ```css
+/// @media (min-width: 400px) and (color) and (max-width: 700px) {}
+/// /*     ^^^^^^^^^^^^^^^^^^     ^^^^^^^     ^^^^^^^^^^^^^^^^^^ */
+/// ```
+fn and_chain(condition: &CssSyntaxNode) -> Option<Vec<AnyCssMediaInParens>> {
+    let mut conditions = Vec::new();
+    let mut next = AnyCssMediaAndCombinableCondition::cast_ref(condition)?;
+    loop {
+        match next {
+            AnyCssMediaAndCombinableCondition::CssMediaAndCondition(condition) => {
+                conditions.push(condition.left().ok()?);
+                next = condition.right().ok()?;
+            }
+            AnyCssMediaAndCombinableCondition::AnyCssMediaInParens(condition) => {
+                conditions.push(condition);
+                return Some(conditions);
+            }
+        }
+    }
+}
+
+/// Converts the media features of the list to `notation`.
+///
+/// Returns `None` if none of them needs to be converted.
+fn convert_features(
+    conditions: &[AnyCssMediaInParens],
+    notation: MediaFeatureRangeNotation,
+) -> Option<Vec<AnyCssMediaInParens>> {
+    let mut converted = Vec::with_capacity(conditions.len());
+    let mut has_changes = false;
+    let mut index = 0;
+    while index < conditions.len() {
+        let feature = media_feature(&conditions[index]);
+        let replacement = match notation {
+            MediaFeatureRangeNotation::Range => {
+                let next = conditions.get(index + 1).and_then(media_feature);
+                match (feature.and_then(|feature| prefixed_feature(&feature)), next) {
+                    (Some(feature), Some(next)) => match prefixed_feature(&next) {
+                        Some(next) if feature.can_merge_with(&next) => {
+                            index += 1;
+                            Some(vec![feature.merge_to_range(next)])
+                        }
+                        _ => Some(vec![feature.to_range()]),
+                    },
+                    (Some(feature), None) => Some(vec![feature.to_range()]),
+                    (None, _) => None,
+                }
+            }
+            MediaFeatureRangeNotation::Prefix => {
+                feature.and_then(|feature| range_to_prefixed_features(&feature))
+            }
+        };
+
+        match replacement {
+            Some(replacement) => {
+                has_changes = true;
+                converted.extend(replacement);
+            }
+            None => converted.push(conditions[index].clone().trim_trivia()?),
+        }
+        index += 1;
+    }
+
+    has_changes.then_some(converted)
+}
+
+fn media_feature(condition: &AnyCssMediaInParens) -> Option<AnyCssQueryFeature> {
+    condition.as_css_media_feature_in_parens()?.feature().ok()
+}
+
+/// Returns the name of the feature, if it's a media feature that accepts a range of values.
+fn range_feature_name(name: &str) -> Option<&str> {
+    RANGE_MEDIA_FEATURES
+        .iter()
+        .any(|feature| feature.eq_ignore_ascii_case(name))
+        .then_some(name)
+}
+
+/// A media feature with a `min-` or `max-` prefix.
+///
+/// ```css
+/// @media (min-width: 400px) {}
+/// /*      ^^^^^^^^^^^^^^^^ */
+/// ```
+struct PrefixedFeature {
+    /// The name of the feature, without its prefix.
+    name: String,
+    is_min: bool,
+    value: AnyCssQueryFeatureValue,
+}
+
+fn prefixed_feature(feature: &AnyCssQueryFeature) -> Option<PrefixedFeature> {
+    let feature = feature.as_css_query_feature_plain()?;
+    let name = feature.name().ok()?.value_token().ok()?;
+    let name = name.text_trimmed();
+    let (prefix, unprefixed) = name.split_at_checked(4)?;
+    let is_min = if prefix.eq_ignore_ascii_case("min-") {
+        true
+    } else if prefix.eq_ignore_ascii_case("max-") {
+        false
+    } else {
+        return None;
+    };
+
+    Some(PrefixedFeature {
+        name: range_feature_name(unprefixed)?.to_string(),
+        is_min,
+        value: feature.value().ok()?.trim_trivia()?,
+    })
+}
+
+impl PrefixedFeature {
+    /// Returns `true` if the features are the two bounds of the same media feature.
+    fn can_merge_with(&self, other: &Self) -> bool {
+        self.is_min != other.is_min && self.name.eq_ignore_ascii_case(&other.name)
+    }
+
+    /// `(min-width: 400px)` becomes `(width >= 400px)`.
+    fn to_range(&self) -> AnyCssMediaInParens {
+        let operator = if self.is_min { T![>=] } else { T![<=] };
+        make_media_feature(AnyCssQueryFeature::CssQueryFeatureRange(
+            make::css_query_feature_range(
+                make_identifier(&self.name),
+                make_comparison(operator),
+                self.value.clone(),
+            ),
+        ))
+    }
+
+    /// `(min-width: 400px) and (max-width: 700px)` becomes `(400px <= width <= 700px)`.
+    fn merge_to_range(self, other: Self) -> AnyCssMediaInParens {
+        let (min, max) = if self.is_min {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        make_media_feature(AnyCssQueryFeature::CssQueryFeatureRangeInterval(
+            make::css_query_feature_range_interval(
+                min.value,
+                make_comparison(T![<=]),
+                make_identifier(&min.name),
+                make_comparison(T![<=]),
+                max.value,
+            ),
+        ))
+    }
+
+    /// `(width >= 400px)` becomes `(min-width: 400px)`.
+    fn to_prefixed(&self) -> AnyCssMediaInParens {
+        let prefix = if self.is_min { "min-" } else { "max-" };
+        make_media_feature(AnyCssQueryFeature::CssQueryFeaturePlain(
+            make::css_query_feature_plain(
+                make_identifier(&format!("{prefix}{}", self.name)),
+                make_token(T![:], ":", false, true),
+                self.value.clone(),
+            ),
+        ))
+    }
+}
+
+/// Converts a range to the equivalent `min-` and `max-` features.
+///
+/// Returns `None` if the feature isn't a range, or if it uses a strict comparison.
+fn range_to_prefixed_features(feature: &AnyCssQueryFeature) -> Option<Vec<AnyCssMediaInParens>> {
+    let bounds = match feature {
+        AnyCssQueryFeature::CssQueryFeatureRange(range) => {
+            let name = range.left().ok()?.value_token().ok()?;
+            let value = range.right().ok()?.trim_trivia()?;
+            vec![bound(
+                name.text_trimmed(),
+                &range.comparison().ok()?,
+                value,
+                false,
+            )?]
+        }
+        AnyCssQueryFeature::CssQueryFeatureReverseRange(range) => {
+            let name = range.right().ok()?.value_token().ok()?;
+            let value = range.left().ok()?.trim_trivia()?;
+            vec![bound(
+                name.text_trimmed(),
+                &range.comparison().ok()?,
+                value,
+                true,
+            )?]
+        }
+        AnyCssQueryFeature::CssQueryFeatureRangeInterval(range) => {
+            let name = range.name().ok()?.value_token().ok()?;
+            let left = range.left().ok()?.trim_trivia()?;
+            let right = range.right().ok()?.trim_trivia()?;
+            let left = bound(
+                name.text_trimmed(),
+                &range.left_comparison().ok()?,
+                left,
+                true,
+            )?;
+            let right = bound(
+                name.text_trimmed(),
+                &range.right_comparison().ok()?,
+                right,
+                false,
+            )?;
+            // `(400px = width = 700px)` isn't valid
+            if !left.can_merge_with(&right) {
+                return None;
+            }
+            vec![left, right]
+        }
+        _ => return None,
+    };
+
+    Some(bounds.iter().map(PrefixedFeature::to_prefixed).collect())
+}
+
+/// Returns the bound described by `name`, `comparison` and `value`.
+/// When `is_reversed` is `true`, the value comes before the name, e.g. `400px <= width`.
+fn bound(
+    name: &str,
+    comparison: &CssQueryFeatureRangeComparison,
+    value: AnyCssQueryFeatureValue,
+    is_reversed: bool,
+) -> Option<PrefixedFeature> {
+    let is_min = match (comparison.operator().ok()?.kind(), is_reversed) {
+        (T![>=], false) | (T![<=], true) => true,
+        (T![<=], false) | (T![>=], true) => false,
+        _ => return None,
+    };
+
+    Some(PrefixedFeature {
+        name: range_feature_name(name)?.to_string(),
+        is_min,
+        value,
+    })
+}
+
+fn make_token(
+    kind: CssSyntaxKind,
+    text: &str,
+    leading_space: bool,
+    trailing_space: bool,
+) -> CssSyntaxToken {
+    let leading = if leading_space { " " } else { "" };
+    let trailing = if trailing_space { " " } else { "" };
+    let leading_trivia = leading_space.then(|| TriviaPiece::whitespace(1));
+    let trailing_trivia = trailing_space.then(|| TriviaPiece::whitespace(1));
+    CssSyntaxToken::new_detached(
+        kind,
+        &format!("{leading}{text}{trailing}"),
+        leading_trivia,
+        trailing_trivia,
+    )
+}
+
+fn make_identifier(name: &str) -> CssIdentifier {
+    make::css_identifier(make_token(T![ident], name, false, false))
+}
+
+fn make_comparison(operator: CssSyntaxKind) -> CssQueryFeatureRangeComparison {
+    let text = if operator == T![>=] { ">=" } else { "<=" };
+    make::css_query_feature_range_comparison(make_token(operator, text, true, true))
+}
+
+fn make_media_feature(feature: AnyCssQueryFeature) -> AnyCssMediaInParens {
+    AnyCssMediaInParens::CssMediaFeatureInParens(make::css_media_feature_in_parens(
+        make_token(T!['('], "(", false, false),
+        feature,
+        make_token(T![')'], ")", false, false),
+    ))
+}
+
+/// Combines the conditions with `and`.
+fn make_and_chain(conditions: Vec<AnyCssMediaInParens>) -> Option<CssSyntaxNode> {
+    let mut conditions = conditions.into_iter().rev();
+    let mut chain = AnyCssMediaAndCombinableCondition::AnyCssMediaInParens(conditions.next()?);
+    for condition in conditions {
+        chain = AnyCssMediaAndCombinableCondition::CssMediaAndCondition(
+            make::css_media_and_condition(condition, make_token(T![and], "and", true, true), chain),
+        );
+    }
+    Some(chain.into_syntax())
+}
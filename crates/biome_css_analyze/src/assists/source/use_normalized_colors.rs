@@ -0,0 +1,460 @@
+use crate::CssRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_source_rule, ActionCategory, Ast, Rule, RuleAction,
+    SourceActionKind,
+};
+use biome_console::markup;
+use biome_css_factory::make;
+use biome_css_syntax::{
+    AnyCssDimension, AnyCssExpression, AnyCssFunction, AnyCssValue, CssFunction, CssSyntaxKind,
+    CssSyntaxToken, T,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_rowan::{AstNode, BatchMutationExt, TriviaPiece};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+declare_source_rule! {
+    /// Normalizes colors to a single notation.
+    ///
+    /// CSS offers several equivalent ways to write the same color: `#f00`, `#ff0000`,
+    /// `rgb(255, 0, 0)` and `rgb(255 0 0)` all describe the same red.
+    /// This action rewrites hexadecimal colors and `rgb()`/`rgba()` functions to the notation
+    /// configured with the `format` option, so that a codebase uses one color format consistently.
+    ///
+    /// Colors that can't be converted without changing their meaning are left untouched,
+    /// for instance `rgb()` functions that contain `var()`, `calc()`, `none`
+    /// or fractional channels.
+    ///
+    /// ## Examples
+    ///
+    /// ```css,expect_diff
+    /// a {
+    ///   color: #FF0000;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diff
+    /// a {
+    ///   color: rgba(255, 255, 255, 0.5);
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "format": "rgb"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### format
+    ///
+    /// The notation to use:
+    /// - `shortHex`: `#rgb` or `#rgba` when possible, `#rrggbb` or `#rrggbbaa` otherwise
+    /// - `longHex`: `#rrggbb` or `#rrggbbaa`
+    /// - `rgb`: the space-separated `rgb(r g b)` or `rgb(r g b / alpha)` syntax
+    /// - `oklch`: `oklch(l c h)` or `oklch(l c h / alpha)`.
+    ///   The conversion to `oklch()` is rounded, so it's applied as an unsafe action.
+    ///
+    /// Default: `shortHex`
+    ///
+    pub UseNormalizedColors {
+        version: "next",
+        name: "useNormalizedColors",
+        language: "css",
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseNormalizedColorsOptions {
+    pub format: ColorFormat,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ColorFormat {
+    /// `#rgb`, or `#rrggbb` when the color can't be shortened
+    #[default]
+    ShortHex,
+    /// `#rrggbb`
+    LongHex,
+    /// `rgb(r g b)`
+    Rgb,
+    /// `oklch(l c h)`
+    Oklch,
+}
+
+impl Rule for UseNormalizedColors {
+    type Query = Ast<AnyCssValue>;
+    type State = AnyCssValue;
+    type Signals = Option<Self::State>;
+    type Options = UseNormalizedColorsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let node = ctx.query();
+        let color = match node {
+            AnyCssValue::CssColor(color) => {
+                Rgba::from_hex(color.value_token().ok()?.text_trimmed())?
+            }
+            AnyCssValue::AnyCssFunction(AnyCssFunction::CssFunction(function)) => {
+                Rgba::from_rgb_function(function)?
+            }
+            _ => return None,
+        };
+
+        let normalized = color.to_css_value(ctx.options().format);
+        let original = node.syntax().text_trimmed().to_string();
+        let normalized_text = normalized.syntax().text_trimmed().to_string();
+        if original.eq_ignore_ascii_case(&normalized_text) {
+            return None;
+        }
+
+        Some(normalized)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(ctx.query().clone(), state.clone());
+
+        // The conversion to `oklch()` is rounded, the other ones are exact
+        let applicability = if ctx.options().format == ColorFormat::Oklch {
+            Applicability::MaybeIncorrect
+        } else {
+            Applicability::Always
+        };
+
+        let normalized = state.syntax().text_trimmed().to_string();
+        Some(RuleAction::new(
+            rule_action_category!(),
+            applicability,
+            markup! {
+                "This color can be written as "<Emphasis>{normalized}</Emphasis>"."
+            },
+            mutation,
+        ))
+    }
+}
+
+/// An sRGB color with 8-bit channels, which is what hexadecimal colors can represent.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Rgba {
+    red: u8,
+    green: u8,
+    blue: u8,
+    alpha: u8,
+}
+
+impl Rgba {
+    /// Parses the value of a hexadecimal color, without the leading `#`.
+    fn from_hex(value: &str) -> Option<Self> {
+        if !value.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let digits: Vec<u8> = match value.len() {
+            3 | 4 => value
+                .chars()
+                .map(|digit| u8::from_str_radix(&format!("{digit}{digit}"), 16).ok())
+                .collect::<Option<_>>()?,
+            6 | 8 => (0..value.len())
+                .step_by(2)
+                .map(|index| u8::from_str_radix(&value[index..index + 2], 16).ok())
+                .collect::<Option<_>>()?,
+            _ => return None,
+        };
+
+        Some(Self {
+            red: digits[0],
+            green: digits[1],
+            blue: digits[2],
+            alpha: digits.get(3).copied().unwrap_or(u8::MAX),
+        })
+    }
+
+    /// Parses a `rgb()` or `rgba()` function, in either the legacy comma-separated
+    /// syntax or the modern space-separated syntax.
+    ///
+    /// Returns `None` when a channel isn't a plain number or percentage,
+    /// or when it doesn't map to an integer in the `0..=255` range.
+    fn from_rgb_function(function: &CssFunction) -> Option<Self> {
+        let name = function.name().ok()?.value_token().ok()?;
+        let name = name.text_trimmed();
+        if !name.eq_ignore_ascii_case("rgb") && !name.eq_ignore_ascii_case("rgba") {
+            return None;
+        }
+
+        let arguments = function.items().syntax().text_trimmed().to_string();
+        let (channels, alpha): (Vec<&str>, Option<&str>) = if arguments.contains(',') {
+            if arguments.contains('/') {
+                return None;
+            }
+            let mut components: Vec<_> = arguments.split(',').map(str::trim).collect();
+            let alpha = if components.len() == 4 {
+                components.pop()
+            } else {
+                None
+            };
+            (components, alpha)
+        } else {
+            let (channels, alpha) = match arguments.split_once('/') {
+                Some((channels, alpha)) => (channels, Some(alpha.trim())),
+                None => (arguments.as_str(), None),
+            };
+            (channels.split_whitespace().collect(), alpha)
+        };
+
+        let [red, green, blue] = channels.as_slice() else {
+            return None;
+        };
+
+        Some(Self {
+            red: parse_channel(red)?,
+            green: parse_channel(green)?,
+            blue: parse_channel(blue)?,
+            alpha: match alpha {
+                Some(alpha) => parse_alpha(alpha)?,
+                None => u8::MAX,
+            },
+        })
+    }
+
+    fn is_opaque(self) -> bool {
+        self.alpha == u8::MAX
+    }
+
+    fn to_css_value(self, format: ColorFormat) -> AnyCssValue {
+        match format {
+            ColorFormat::ShortHex => make_hex_color(&self.to_hex(true)),
+            ColorFormat::LongHex => make_hex_color(&self.to_hex(false)),
+            ColorFormat::Rgb => make_color_function(
+                "rgb",
+                [
+                    Component::Number(self.red.to_string()),
+                    Component::Number(self.green.to_string()),
+                    Component::Number(self.blue.to_string()),
+                ],
+                self.alpha_component(),
+            ),
+            ColorFormat::Oklch => {
+                let (lightness, chroma, hue) = self.to_oklch();
+                make_color_function(
+                    "oklch",
+                    [
+                        Component::Percentage(format_number(lightness * 100.0, 2)),
+                        Component::Number(format_number(chroma, 4)),
+                        Component::Number(format_number(hue, 2)),
+                    ],
+                    self.alpha_component(),
+                )
+            }
+        }
+    }
+
+    /// Returns the hexadecimal digits of the color, omitting the alpha channel
+    /// when the color is opaque.
+    fn to_hex(self, allow_short: bool) -> String {
+        let mut channels = vec![self.red, self.green, self.blue];
+        if !self.is_opaque() {
+            channels.push(self.alpha);
+        }
+
+        // A channel can be shortened when both of its digits are the same, e.g. `ff`
+        let is_shortenable = channels.iter().all(|channel| channel % 17 == 0);
+        if allow_short && is_shortenable {
+            channels
+                .iter()
+                .map(|channel| format!("{:x}", channel / 17))
+                .collect()
+        } else {
+            channels
+                .iter()
+                .map(|channel| format!("{channel:02x}"))
+                .collect()
+        }
+    }
+
+    fn alpha_component(self) -> Option<Component> {
+        if self.is_opaque() {
+            return None;
+        }
+
+        // Use the shortest decimal representation that maps back to the same 8-bit value,
+        // which is how browsers serialize the alpha channel of colors.
+        let alpha = f64::from(self.alpha) / 255.0;
+        let rounded = (alpha * 100.0).round() / 100.0;
+        let alpha = if (rounded * 255.0).round() as u8 == self.alpha {
+            format_number(rounded, 2)
+        } else {
+            format_number(alpha, 3)
+        };
+        Some(Component::Number(alpha))
+    }
+
+    /// Converts the color to OKLCH, returning its lightness (`0..=1`),
+    /// chroma and hue (in degrees).
+    ///
+    /// See <https://bottosson.github.io/posts/oklab/>.
+    fn to_oklch(self) -> (f64, f64, f64) {
+        fn to_linear(channel: u8) -> f64 {
+            let channel = f64::from(channel) / 255.0;
+            if channel <= 0.04045 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        }
+
+        let (red, green, blue) = (
+            to_linear(self.red),
+            to_linear(self.green),
+            to_linear(self.blue),
+        );
+
+        let l = (0.412_221_470_8 * red + 0.536_332_536_3 * green + 0.051_445_992_9 * blue).cbrt();
+        let m = (0.211_903_498_2 * red + 0.680_699_545_1 * green + 0.107_396_956_6 * blue).cbrt();
+        let s = (0.088_302_461_9 * red + 0.281_718_837_6 * green + 0.629_978_700_5 * blue).cbrt();
+
+        let lightness = 0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s;
+        let a = 1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s;
+        let b = 0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s;
+
+        let chroma = a.hypot(b);
+        // The hue of achromatic colors is meaningless
+        let hue = if chroma < 0.000_05 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        };
+
+        (lightness, chroma, hue)
+    }
+}
+
+/// Parses a color channel of `rgb()`, either a number or a percentage.
+fn parse_channel(text: &str) -> Option<u8> {
+    let value = match text.strip_suffix('%') {
+        Some(percentage) => percentage.parse::<f64>().ok()? * 2.55,
+        None => text.parse::<f64>().ok()?,
+    };
+
+    // Percentages are multiplied by a float, account for the rounding error
+    let rounded = value.round();
+    if (value - rounded).abs() > 1e-9 || !(0.0..=255.0).contains(&rounded) {
+        return None;
+    }
+
+    Some(rounded as u8)
+}
+
+/// Parses the alpha channel of `rgb()`, either a number or a percentage.
+fn parse_alpha(text: &str) -> Option<u8> {
+    let value = match text.strip_suffix('%') {
+        Some(percentage) => percentage.parse::<f64>().ok()? / 100.0,
+        None => text.parse::<f64>().ok()?,
+    };
+
+    if !(0.0..=1.0).contains(&value) {
+        return None;
+    }
+
+    Some((value * 255.0).round() as u8)
+}
+
+/// Formats a number with at most `precision` decimals, without trailing zeros.
+fn format_number(value: f64, precision: usize) -> String {
+    let formatted = format!("{value:.precision$}");
+    let formatted = if formatted.contains('.') {
+        formatted.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        formatted.as_str()
+    };
+
+    if formatted == "-0" {
+        String::from("0")
+    } else {
+        formatted.to_string()
+    }
+}
+
+enum Component {
+    Number(String),
+    Percentage(String),
+}
+
+impl Component {
+    fn into_css_value(self, trailing_space: bool) -> AnyCssValue {
+        match self {
+            Self::Number(value) => {
+                let value = make_token(CssSyntaxKind::CSS_NUMBER_LITERAL, &value, trailing_space);
+                AnyCssValue::CssNumber(make::css_number(value))
+            }
+            Self::Percentage(value) => {
+                let percentage = make::css_percentage(
+                    make_token(CssSyntaxKind::CSS_NUMBER_LITERAL, &value, false),
+                    make_token(T![%], "%", trailing_space),
+                );
+                AnyCssValue::AnyCssDimension(AnyCssDimension::CssPercentage(percentage))
+            }
+        }
+    }
+}
+
+fn make_token(kind: CssSyntaxKind, text: &str, trailing_space: bool) -> CssSyntaxToken {
+    if trailing_space {
+        CssSyntaxToken::new_detached(kind, &format!("{text} "), [], [TriviaPiece::whitespace(1)])
+    } else {
+        CssSyntaxToken::new_detached(kind, text, [], [])
+    }
+}
+
+fn make_hex_color(digits: &str) -> AnyCssValue {
+    AnyCssValue::CssColor(make::css_color(
+        make_token(T![#], "#", false),
+        make_token(CssSyntaxKind::CSS_COLOR_LITERAL, digits, false),
+    ))
+}
+
+/// Creates a color function using the space-separated syntax, e.g. `rgb(255 0 0 / 0.5)`.
+fn make_color_function(
+    name: &str,
+    channels: [Component; 3],
+    alpha: Option<Component>,
+) -> AnyCssValue {
+    let has_alpha = alpha.is_some();
+    let channels = channels.into_iter().enumerate().map(|(index, channel)| {
+        let is_last = index == 2;
+        channel.into_css_value(!is_last || has_alpha)
+    });
+    let channels = AnyCssExpression::CssListOfComponentValuesExpression(
+        make::css_list_of_component_values_expression(make::css_component_value_list(channels)),
+    );
+
+    let expression = match alpha {
+        Some(alpha) => AnyCssExpression::CssBinaryExpression(make::css_binary_expression(
+            channels,
+            make_token(T![/], "/", true),
+            AnyCssExpression::CssListOfComponentValuesExpression(
+                make::css_list_of_component_values_expression(make::css_component_value_list([
+                    alpha.into_css_value(false),
+                ])),
+            ),
+        )),
+        None => channels,
+    };
+
+    AnyCssValue::AnyCssFunction(AnyCssFunction::CssFunction(make::css_function(
+        make::css_identifier(make_token(T![ident], name, false)),
+        make_token(T!['('], "(", false),
+        make::css_parameter_list([make::css_parameter(expression)], []),
+        make_token(T![')'], ")", false),
+    )))
+}
@@ -82,6 +82,29 @@ pub const FONT_SIZE_KEYWORDS: [&str; 9] = [
 
 pub const LINE_HEIGHT_KEYWORDS: [&str; 1] = ["normal"];
 
+/// Keywords of the `animation` shorthand that aren't animation names.
+pub const ANIMATION_SHORTHAND_KEYWORDS: [&str; 19] = [
+    "alternate",
+    "alternate-reverse",
+    "auto",
+    "backwards",
+    "both",
+    "ease",
+    "ease-in",
+    "ease-in-out",
+    "ease-out",
+    "forwards",
+    "infinite",
+    "linear",
+    "none",
+    "normal",
+    "paused",
+    "reverse",
+    "running",
+    "step-end",
+    "step-start",
+];
+
 /// List of known CSS value functions sourced from [`css-functions-list`](https://www.npmjs.com/package/css-functions-list).
 /// See the original list [here](https://github.com/niksy/css-functions-list/blob/master/index.json).
 pub const FUNCTION_KEYWORDS: [&str; 685] = [
@@ -5811,6 +5834,13 @@ mod tests {
         assert_eq!(LINE_HEIGHT_KEYWORDS, sorted.as_slice());
     }
 
+    #[test]
+    fn test_animation_shorthand_keywords_sorted() {
+        let sorted = ANIMATION_SHORTHAND_KEYWORDS.to_vec();
+        let _ = sorted.is_sorted();
+        assert_eq!(ANIMATION_SHORTHAND_KEYWORDS, sorted.as_slice());
+    }
+
     #[test]
     fn test_function_keywords_sorted() {
         let sorted = FUNCTION_KEYWORDS.to_vec();
@@ -2,29 +2,39 @@
 
 use biome_analyze::declare_lint_group;
 
+pub mod no_conflicting_layer_order;
 pub mod no_descending_specificity;
 pub mod no_duplicate_custom_properties;
 pub mod no_duplicate_properties;
+pub mod no_invalid_keyframe_selector;
 pub mod no_irregular_whitespace;
 pub mod no_missing_var_function;
+pub mod no_nonexistent_animation_name;
+pub mod no_undefined_custom_properties;
 pub mod no_unknown_pseudo_class;
 pub mod no_unknown_pseudo_element;
 pub mod no_unknown_type_selector;
 pub mod no_value_at_rule;
+pub mod use_allowed_units;
 
 declare_lint_group! {
     pub Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_conflicting_layer_order :: NoConflictingLayerOrder ,
             self :: no_descending_specificity :: NoDescendingSpecificity ,
             self :: no_duplicate_custom_properties :: NoDuplicateCustomProperties ,
             self :: no_duplicate_properties :: NoDuplicateProperties ,
+            self :: no_invalid_keyframe_selector :: NoInvalidKeyframeSelector ,
             self :: no_irregular_whitespace :: NoIrregularWhitespace ,
             self :: no_missing_var_function :: NoMissingVarFunction ,
+            self :: no_nonexistent_animation_name :: NoNonexistentAnimationName ,
+            self :: no_undefined_custom_properties :: NoUndefinedCustomProperties ,
             self :: no_unknown_pseudo_class :: NoUnknownPseudoClass ,
             self :: no_unknown_pseudo_element :: NoUnknownPseudoElement ,
             self :: no_unknown_type_selector :: NoUnknownTypeSelector ,
             self :: no_value_at_rule :: NoValueAtRule ,
+            self :: use_allowed_units :: UseAllowedUnits ,
         ]
      }
 }
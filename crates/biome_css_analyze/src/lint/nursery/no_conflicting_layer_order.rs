@@ -0,0 +1,238 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{AnyCssLayer, CssLayerAtRule, CssLayerNameList, CssRoot};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+use rustc_hash::FxHashMap;
+
+declare_lint_rule! {
+    /// Disallow `@layer` statements that conflict with the layer order established earlier in the stylesheet.
+    ///
+    /// The order of cascade layers is defined by the first appearance of each layer name.
+    /// Once a layer has been declared, listing it again in another `@layer` statement doesn't move it.
+    /// A statement that lists layers in a different order than the established one
+    /// has no effect on their order, which is usually a mistake.
+    ///
+    /// Layers nested in named `@layer` blocks are compared with their siblings only.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// @layer reset, base, components;
+    /// @layer components, base;
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// @layer components {
+    ///   a { color: red; }
+    /// }
+    ///
+    /// @layer base, components;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// @layer reset, base, components;
+    /// @layer reset, components;
+    /// ```
+    ///
+    /// ```css
+    /// @layer base, components;
+    /// @layer components, utilities;
+    /// ```
+    ///
+    pub NoConflictingLayerOrder {
+        version: "next",
+        name: "noConflictingLayerOrder",
+        language: "css",
+        recommended: false,
+    }
+}
+
+pub struct LayerOrderConflict {
+    /// The layer that is listed out of order.
+    name: String,
+    range: TextRange,
+    /// The layer that precedes `name` in the statement, but follows it in the established order.
+    previous: String,
+    /// The first declaration of `name`, which established its position.
+    first_declaration: TextRange,
+}
+
+impl Rule for NoConflictingLayerOrder {
+    type Query = Ast<CssRoot>;
+    type State = LayerOrderConflict;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let mut order = LayerOrder::default();
+        let mut conflicts = Vec::new();
+
+        for at_rule in root.syntax().descendants().filter_map(CssLayerAtRule::cast) {
+            let Some(parent) = parent_layer_name(&at_rule) else {
+                continue;
+            };
+
+            match at_rule.layer() {
+                Ok(AnyCssLayer::CssLayerDeclaration(declaration)) => {
+                    for names in declaration.references().iter().flatten() {
+                        if let Some(name) = layer_name(parent.as_deref(), &names) {
+                            order.declare(&name, names.range());
+                        }
+                    }
+                }
+                Ok(AnyCssLayer::CssLayerReference(reference)) => {
+                    let mut previous: Option<String> = None;
+                    for names in reference.references().iter().flatten() {
+                        let Some(name) = layer_name(parent.as_deref(), &names) else {
+                            continue;
+                        };
+                        order.declare(&name, names.range());
+
+                        if let Some(previous) = &previous {
+                            if order.is_before(&name, previous) {
+                                conflicts.push(LayerOrderConflict {
+                                    first_declaration: order.first_declaration(&name),
+                                    name,
+                                    range: names.range(),
+                                    previous: previous.clone(),
+                                });
+                                continue;
+                            }
+                        }
+                        previous = Some(name);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        conflicts.into_boxed_slice()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let LayerOrderConflict {
+            name,
+            range,
+            previous,
+            first_declaration,
+        } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The layer "<Emphasis>{name}</Emphasis>" is listed after "<Emphasis>{previous}</Emphasis>", but it was already declared before it."
+                },
+            )
+            .detail(first_declaration, markup! {
+                "The position of "<Emphasis>{name}</Emphasis>" was established here."
+            })
+            .note(markup! {
+                "The order of cascade layers is defined by the first appearance of each layer, so this statement doesn't change it."
+            })
+            .note(markup! {
+                "List the layers in the established order, or move the statement before the first declaration of "<Emphasis>{name}</Emphasis>"."
+            }),
+        )
+    }
+}
+
+/// The position of every layer, in the order of their first appearance.
+#[derive(Default)]
+struct LayerOrder {
+    layers: FxHashMap<String, (usize, TextRange)>,
+}
+
+impl LayerOrder {
+    /// Declares the layer `name` and its parent layers, unless they were already declared.
+    fn declare(&mut self, name: &str, range: TextRange) {
+        let mut end = 0;
+        for segment in name.split('.') {
+            end += segment.len();
+            let position = self.layers.len();
+            self.layers
+                .entry(name[..end].to_string())
+                .or_insert((position, range));
+            end += 1;
+        }
+    }
+
+    /// Returns `true` if the layer `name` comes before the layer `other`.
+    ///
+    /// Layers that don't share the same parent are compared through their
+    /// ancestors, e.g. `a.b` comes before `c` when `a` comes before `c`.
+    fn is_before(&self, name: &str, other: &str) -> bool {
+        let common = name
+            .split('.')
+            .zip(other.split('.'))
+            .take_while(|(left, right)| left == right)
+            .count();
+
+        let (Some(name), Some(other)) = (layer_prefix(name, common), layer_prefix(other, common))
+        else {
+            // One of the layers is nested in the other one
+            return false;
+        };
+
+        match (self.layers.get(name), self.layers.get(other)) {
+            (Some((name, _)), Some((other, _))) => name < other,
+            _ => false,
+        }
+    }
+
+    fn first_declaration(&self, name: &str) -> TextRange {
+        self.layers[name].1
+    }
+}
+
+/// Returns the name of the layer made of the first `segments + 1` segments of `name`,
+/// or `None` if `name` doesn't have that many segments.
+fn layer_prefix(name: &str, segments: usize) -> Option<&str> {
+    let mut end = 0;
+    let mut parts = name.split('.');
+    for _ in 0..=segments {
+        end += parts.next()?.len() + 1;
+    }
+    Some(&name[..end - 1])
+}
+
+/// Returns the full name of the layer that contains the at-rule,
+/// `Some(None)` at the top level of the stylesheet,
+/// and `None` if the at-rule is nested in an anonymous layer.
+fn parent_layer_name(at_rule: &CssLayerAtRule) -> Option<Option<String>> {
+    let mut segments = Vec::new();
+    for ancestor in at_rule.syntax().ancestors().skip(1) {
+        let Some(at_rule) = CssLayerAtRule::cast(ancestor) else {
+            continue;
+        };
+        let Ok(AnyCssLayer::CssLayerDeclaration(declaration)) = at_rule.layer() else {
+            continue;
+        };
+        let names = declaration.references().iter().next()?.ok()?;
+        segments.push(layer_name(None, &names)?);
+    }
+
+    if segments.is_empty() {
+        return Some(None);
+    }
+    segments.reverse();
+    Some(Some(segments.join(".")))
+}
+
+/// Returns the full name of a layer, e.g. `framework.base`.
+fn layer_name(parent: Option<&str>, names: &CssLayerNameList) -> Option<String> {
+    let mut name = parent.map(str::to_string).unwrap_or_default();
+    for identifier in names.iter() {
+        let identifier = identifier.ok()?;
+        if !name.is_empty() {
+            name.push('.');
+        }
+        name.push_str(identifier.value_token().ok()?.text_trimmed());
+    }
+    Some(name)
+}
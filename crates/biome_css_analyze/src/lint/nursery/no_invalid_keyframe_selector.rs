@@ -0,0 +1,82 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_semantic::CssKeyframes;
+use biome_css_syntax::{AnyCssKeyframesSelector, CssKeyframesBlock};
+use biome_rowan::AstNode;
+
+declare_lint_rule! {
+    /// Disallow keyframe selectors outside of the `0%` to `100%` range.
+    ///
+    /// A keyframe selector defines the position of the keyframe in the animation,
+    /// as a percentage of its duration.
+    /// Browsers ignore the keyframes whose selectors are lower than `0%` or greater than `100%`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// @keyframes fade {
+    ///     from { opacity: 0; }
+    ///     150% { opacity: 1; }
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// @keyframes fade {
+    ///     -10%, 50% { opacity: 0; }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// @keyframes fade {
+    ///     from { opacity: 0; }
+    ///     50% { opacity: 0.5; }
+    ///     to { opacity: 1; }
+    /// }
+    /// ```
+    ///
+    pub NoInvalidKeyframeSelector {
+        version: "next",
+        name: "noInvalidKeyframeSelector",
+        language: "css",
+        recommended: false,
+    }
+}
+
+impl Rule for NoInvalidKeyframeSelector {
+    type Query = Ast<CssKeyframesBlock>;
+    type State = AnyCssKeyframesSelector;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let keyframes = CssKeyframes::from_block(ctx.query());
+        keyframes
+            .selectors()
+            .filter(|selector| !selector.is_in_range())
+            .map(|selector| selector.node().clone())
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, node: &Self::State) -> Option<RuleDiagnostic> {
+        let selector = node.text();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "The keyframe selector "<Emphasis>{selector}</Emphasis>" is outside of the range from "<Emphasis>"0%"</Emphasis>" to "<Emphasis>"100%"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Browsers ignore this keyframe."
+            })
+            .note(markup! {
+                "Use a percentage between "<Emphasis>"0%"</Emphasis>" and "<Emphasis>"100%"</Emphasis>", or the "<Emphasis>"from"</Emphasis>" and "<Emphasis>"to"</Emphasis>" keywords."
+            }),
+        )
+    }
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_semantic::{CssFileIndex, CssProjectIndex};
+use biome_css_syntax::{
+    AnyCssGenericComponentValue, AnyCssValue, CssGenericProperty, CssRoot,
+    CssSupportsFeatureDeclaration,
+};
+use biome_rowan::AstNode;
+use biome_string_case::StrLikeExtension;
+
+use crate::utils::{is_animation_name_keyword, is_animation_shorthand_keyword, vender_prefix};
+
+declare_lint_rule! {
+    /// Disallow animation names that do not match any `@keyframes` of the project.
+    ///
+    /// An animation that references keyframes that don't exist doesn't run.
+    /// This usually happens after a `@keyframes` at-rule is renamed or removed, or because of a typo.
+    ///
+    /// The names used by the `animation-name` and `animation` properties are looked up
+    /// in the current file and in all the stylesheets of the project known to Biome.
+    /// Keyframes names are case-sensitive.
+    /// The declarations of `@supports` conditions are ignored.
+    ///
+    /// Only the stylesheets processed by Biome are taken into account, files that are ignored
+    /// or excluded from the command line are not.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   animation-name: fade-in;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// @keyframes fade-in {
+    ///   from { opacity: 0; }
+    /// }
+    ///
+    /// a {
+    ///   animation: 1s ease-in fade-ni;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// @keyframes fade-in {
+    ///   from { opacity: 0; }
+    /// }
+    ///
+    /// a {
+    ///   animation: 1s ease-in fade-in;
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// a {
+    ///   animation-name: none;
+    /// }
+    /// ```
+    ///
+    pub NoNonexistentAnimationName {
+        version: "next",
+        name: "noNonexistentAnimationName",
+        language: "css",
+        recommended: false,
+    }
+}
+
+impl Rule for NoNonexistentAnimationName {
+    type Query = Ast<CssRoot>;
+    type State = AnyCssValue;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let file = CssFileIndex::from_root(root);
+        let project = ctx.get_service::<Arc<CssProjectIndex>>();
+
+        root.syntax()
+            .descendants()
+            .filter_map(CssGenericProperty::cast)
+            // `@supports (animation-name: foo)` only tests the syntax of the value
+            .filter(|property| {
+                !property
+                    .syntax()
+                    .ancestors()
+                    .any(|ancestor| CssSupportsFeatureDeclaration::can_cast(ancestor.kind()))
+            })
+            .flat_map(|property| animation_names(&property))
+            .filter(|(_, name)| {
+                file.keyframes_definitions(name).next().is_none()
+                    && !project.is_some_and(|project| project.is_keyframes_defined(name))
+            })
+            .map(|(value, _)| value)
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, node: &Self::State) -> Option<RuleDiagnostic> {
+        let name = node.text();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "The animation "<Emphasis>{name}</Emphasis>" doesn't match any "<Emphasis>"@keyframes"</Emphasis>" of the project."
+                },
+            )
+            .note(markup! {
+                "An animation without keyframes doesn't run."
+            })
+            .note(markup! {
+                "Define the keyframes with "<Emphasis>"@keyframes "{name}</Emphasis>", or fix the name of the animation."
+            }),
+        )
+    }
+}
+
+/// Returns the animation names used by an `animation-name` or `animation` property,
+/// along with their unquoted text.
+///
+/// ```css
+/// a { animation: 1s ease-in fade, 2s "slide"; }
+/// /*                        ^^^^     ^^^^^^^ */
+/// ```
+fn animation_names(property: &CssGenericProperty) -> Vec<(AnyCssValue, String)> {
+    let Ok(property_name) = property.name() else {
+        return Vec::new();
+    };
+    let property_name = property_name.text();
+    let property_name = property_name.to_ascii_lowercase_cow();
+    let property_name = &property_name[vender_prefix(&property_name).len()..];
+    let is_shorthand = match property_name {
+        "animation" => true,
+        "animation-name" => false,
+        _ => return Vec::new(),
+    };
+
+    property
+        .value()
+        .into_iter()
+        .filter_map(|value| match value {
+            AnyCssGenericComponentValue::AnyCssValue(value) => Some(value),
+            AnyCssGenericComponentValue::CssGenericDelimiter(_) => None,
+        })
+        .filter_map(|value| {
+            let name = match &value {
+                AnyCssValue::CssIdentifier(identifier) => {
+                    let name = identifier.value_token().ok()?.text_trimmed().to_string();
+                    let keyword = name.to_ascii_lowercase_cow();
+                    let is_keyword = if is_shorthand {
+                        is_animation_shorthand_keyword(&keyword)
+                    } else {
+                        is_animation_name_keyword(&keyword)
+                    };
+                    if is_keyword {
+                        return None;
+                    }
+                    name
+                }
+                AnyCssValue::CssString(string) => {
+                    let token = string.value_token().ok()?;
+                    let text = token.text_trimmed();
+                    text.get(1..text.len() - 1)?.to_string()
+                }
+                _ => return None,
+            };
+            Some((value, name))
+        })
+        .collect()
+}
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_semantic::{CssFileIndex, CssProjectIndex};
+use biome_css_syntax::{AnyCssExpression, AnyCssValue, CssDashedIdentifier, CssFunction, CssRoot};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList};
+
+declare_lint_rule! {
+    /// Disallow references to custom properties that are not defined anywhere in the project.
+    ///
+    /// A `var()` function that references a custom property that doesn't exist
+    /// resolves to the initial value of the property, which is rarely what was intended.
+    /// This usually happens after a custom property is renamed or removed, or because of a typo.
+    ///
+    /// Custom properties are looked up in the current file and in all the stylesheets
+    /// of the project known to Biome, including custom properties registered with `@property`.
+    ///
+    /// References that provide a fallback value, such as `var(--foo, red)`, are ignored.
+    ///
+    /// This rule has the following limitations:
+    /// - It doesn't know about custom properties that are defined in inline styles or set from scripts.
+    /// - Only the stylesheets processed by Biome are taken into account, files that are ignored
+    ///   or excluded from the command line are not.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   color: var(--undefined-color);
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// :root {
+    ///   --brand-color: blue;
+    /// }
+    ///
+    /// a {
+    ///   color: var(--brand-colour);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// :root {
+    ///   --brand-color: blue;
+    /// }
+    ///
+    /// a {
+    ///   color: var(--brand-color);
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// @property --angle {
+    ///   syntax: "<angle>";
+    ///   inherits: false;
+    ///   initial-value: 0deg;
+    /// }
+    ///
+    /// a {
+    ///   rotate: var(--angle);
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// a {
+    ///   color: var(--undefined-color, black);
+    /// }
+    /// ```
+    ///
+    pub NoUndefinedCustomProperties {
+        version: "next",
+        name: "noUndefinedCustomProperties",
+        language: "css",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUndefinedCustomProperties {
+    type Query = Ast<CssRoot>;
+    type State = CssDashedIdentifier;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let file = CssFileIndex::from_root(root);
+        let project = ctx.get_service::<Arc<CssProjectIndex>>();
+
+        root.syntax()
+            .descendants()
+            .filter_map(CssFunction::cast)
+            .filter_map(|function| referenced_custom_property(&function))
+            .filter(|name| {
+                let name = name.text();
+                file.custom_property_definitions(&name).next().is_none()
+                    && !project.is_some_and(|project| project.is_custom_property_defined(&name))
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, node: &Self::State) -> Option<RuleDiagnostic> {
+        let name = node.text();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "The custom property "<Emphasis>{name}</Emphasis>" isn't defined anywhere in the project."
+                },
+            )
+            .note(markup! {
+                "The declaration will fall back to the initial value of the property."
+            })
+            .note(markup! {
+                "Define the custom property, fix its name, or provide a fallback value with "<Emphasis>"var("{name}", <fallback>)"</Emphasis>"."
+            }),
+        )
+    }
+}
+
+/// Returns the custom property referenced by a `var()` function without fallback.
+///
+/// ```css
+/// a { color: var(--foo); }
+/// /*             ^^^^^ */
+/// ```
+fn referenced_custom_property(function: &CssFunction) -> Option<CssDashedIdentifier> {
+    let name = function.name().ok()?.value_token().ok()?;
+    if !name.text_trimmed().eq_ignore_ascii_case("var") {
+        return None;
+    }
+
+    let items = function.items();
+    if items.len() != 1 {
+        return None;
+    }
+
+    let AnyCssExpression::CssListOfComponentValuesExpression(expression) =
+        items.first()?.ok()?.any_css_expression().ok()?
+    else {
+        return None;
+    };
+
+    let values = expression.css_component_value_list();
+    if values.len() != 1 {
+        return None;
+    }
+
+    match values.first()? {
+        AnyCssValue::CssDashedIdentifier(name) => Some(name),
+        _ => None,
+    }
+}
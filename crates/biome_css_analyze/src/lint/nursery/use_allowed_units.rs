@@ -0,0 +1,366 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, FixKind, Rule, RuleAction, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_css_factory::make;
+use biome_css_syntax::{
+    AnyCssDimension, AnyCssQueryFeature, CssContainerAtRule, CssGenericProperty, CssIdentifier,
+    CssSyntaxKind, CssSyntaxToken, T,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::{AstNode, BatchMutationExt};
+use biome_string_case::StrLikeExtension;
+use rustc_hash::FxHashMap;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::vender_prefix;
+use crate::CssRuleAction;
+
+declare_lint_rule! {
+    /// Enforce the units allowed in the values of properties and media features.
+    ///
+    /// Projects usually settle on a unit policy: `rem` for font sizes so that they follow the
+    /// font size preferred by the user, `em` for the widths of media queries so that they
+    /// scale with the zoom, and so on.
+    /// This rule reports the units that aren't allowed by the policy configured with its options.
+    ///
+    /// The rule doesn't report anything until the allowed units are configured.
+    ///
+    /// When the value can be converted to an allowed unit, the rule suggests an unsafe fix.
+    /// Absolute lengths, such as `px` and `pt`, are converted to `rem` with the `rootFontSize` option.
+    /// In media queries, `em` is relative to the initial font size, so it's converted like `rem`.
+    ///
+    /// ## Examples
+    ///
+    /// The following examples use these options:
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "properties": {
+    ///             "font-size": ["rem"],
+    ///             "margin": ["px", "rem"]
+    ///         },
+    ///         "mediaFeatures": {
+    ///             "width": ["em"]
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic,use_options
+    /// a {
+    ///   font-size: 24px;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic,use_options
+    /// a {
+    ///   margin-top: 2vh;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic,use_options
+    /// @media (min-width: 640px) {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css,use_options
+    /// a {
+    ///   font-size: 1.5rem;
+    ///   margin: 4px 1rem;
+    ///   padding: 2vh;
+    /// }
+    /// ```
+    ///
+    /// ```css,use_options
+    /// @media (width >= 40em) {}
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `properties`
+    ///
+    /// The units allowed in the values of properties.
+    /// A property also uses the units of its shorthands when it isn't listed:
+    /// `margin` applies to `margin-top` and `margin-block-start`, unless `margin-top` is listed too.
+    /// Use `%` for percentages.
+    ///
+    /// ### `mediaFeatures`
+    ///
+    /// The units allowed in the values of media features.
+    /// A feature also applies to its `min-` and `max-` prefixed forms:
+    /// `width` applies to `min-width` and `max-width`.
+    ///
+    /// ### `rootFontSize`
+    ///
+    /// The font size of the root element, in pixels, used to convert lengths to `rem`.
+    /// Defaults to `16`.
+    ///
+    pub UseAllowedUnits {
+        version: "next",
+        name: "useAllowedUnits",
+        language: "css",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+#[derive(Clone, Debug, Deserializable, Deserialize, Serialize, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseAllowedUnitsOptions {
+    /// A map where the key is a property name and the value the units allowed in its values
+    pub properties: FxHashMap<Box<str>, Box<[Box<str>]>>,
+    /// A map where the key is a media feature name and the value the units allowed in its values
+    pub media_features: FxHashMap<Box<str>, Box<[Box<str>]>>,
+    /// The font size of the root element, in pixels
+    pub root_font_size: u16,
+}
+
+impl Default for UseAllowedUnitsOptions {
+    fn default() -> Self {
+        Self {
+            properties: FxHashMap::default(),
+            media_features: FxHashMap::default(),
+            root_font_size: 16,
+        }
+    }
+}
+
+pub struct DisallowedUnit {
+    unit: String,
+    /// The property or the media feature that contains the value
+    context: UnitContext,
+    allowed_units: Box<[Box<str>]>,
+    /// The value converted to the first allowed length unit
+    conversion: Option<(String, Box<str>)>,
+}
+
+pub enum UnitContext {
+    Property(String),
+    MediaFeature(String),
+}
+
+impl Rule for UseAllowedUnits {
+    type Query = Ast<AnyCssDimension>;
+    type State = DisallowedUnit;
+    type Signals = Option<Self::State>;
+    type Options = Box<UseAllowedUnitsOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let options = ctx.options();
+
+        let (value, unit) = match node {
+            AnyCssDimension::CssRegularDimension(dimension) => (
+                dimension.value_token().ok()?,
+                dimension
+                    .unit_token()
+                    .ok()?
+                    .text_trimmed()
+                    .to_ascii_lowercase(),
+            ),
+            AnyCssDimension::CssUnknownDimension(dimension) => (
+                dimension.value_token().ok()?,
+                dimension
+                    .unit_token()
+                    .ok()?
+                    .text_trimmed()
+                    .to_ascii_lowercase(),
+            ),
+            AnyCssDimension::CssPercentage(percentage) => {
+                (percentage.value_token().ok()?, "%".to_string())
+            }
+        };
+
+        let context = unit_context(node)?;
+        let allowed_units = match &context {
+            UnitContext::Property(name) => lookup_units(&options.properties, name)?,
+            UnitContext::MediaFeature(name) => lookup_units(&options.media_features, name)?,
+        };
+        if allowed_units
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(&unit))
+        {
+            return None;
+        }
+
+        let in_media_feature = matches!(context, UnitContext::MediaFeature(_));
+        let conversion = value.text_trimmed().parse::<f64>().ok().and_then(|value| {
+            let root_font_size = f64::from(options.root_font_size);
+            let pixels = value * pixels_per_unit(&unit, root_font_size, in_media_feature)?;
+            allowed_units.iter().find_map(|target| {
+                let target_unit = target.to_ascii_lowercase_cow();
+                let ratio = pixels_per_unit(&target_unit, root_font_size, in_media_feature)?;
+                Some((format_number(pixels / ratio)?, target.clone()))
+            })
+        });
+
+        Some(DisallowedUnit {
+            unit,
+            context,
+            allowed_units: allowed_units.into(),
+            conversion,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let DisallowedUnit {
+            unit,
+            context,
+            allowed_units,
+            ..
+        } = state;
+        let allowed_units = allowed_units.join(", ");
+        let diagnostic = match context {
+            UnitContext::Property(name) => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "The unit "<Emphasis>{unit}</Emphasis>" isn't allowed in the value of "<Emphasis>{name}</Emphasis>"."
+                },
+            ),
+            UnitContext::MediaFeature(name) => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "The unit "<Emphasis>{unit}</Emphasis>" isn't allowed in the media feature "<Emphasis>{name}</Emphasis>"."
+                },
+            ),
+        };
+        Some(diagnostic.note(markup! {
+            "The allowed units are: "<Emphasis>{allowed_units}</Emphasis>"."
+        }))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        let (value, unit) = state.conversion.as_ref()?;
+        let unit: &str = unit;
+        let replacement = make::css_regular_dimension(
+            CssSyntaxToken::new_detached(CssSyntaxKind::CSS_NUMBER_LITERAL, value, [], []),
+            CssSyntaxToken::new_detached(T![ident], unit, [], []),
+        );
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            ctx.query().clone(),
+            AnyCssDimension::CssRegularDimension(replacement),
+        );
+
+        Some(RuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Convert the value to "<Emphasis>{value}{unit}</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the property or the media feature whose value contains the dimension.
+///
+/// The features of `@container` queries are ignored.
+fn unit_context(node: &AnyCssDimension) -> Option<UnitContext> {
+    for ancestor in node.syntax().ancestors().skip(1) {
+        if let Some(property) = CssGenericProperty::cast_ref(&ancestor) {
+            let name = property.name().ok()?.text().to_ascii_lowercase();
+            // Custom properties don't have a unit policy
+            if name.starts_with("--") {
+                return None;
+            }
+            let name = name[vender_prefix(&name).len()..].to_string();
+            return Some(UnitContext::Property(name));
+        }
+
+        if let Some(feature) = AnyCssQueryFeature::cast_ref(&ancestor) {
+            if ancestor
+                .ancestors()
+                .any(|ancestor| CssContainerAtRule::can_cast(ancestor.kind()))
+            {
+                return None;
+            }
+            let name: CssIdentifier = match feature {
+                AnyCssQueryFeature::CssQueryFeaturePlain(feature) => feature.name().ok()?,
+                AnyCssQueryFeature::CssQueryFeatureRange(feature) => feature.left().ok()?,
+                AnyCssQueryFeature::CssQueryFeatureReverseRange(feature) => feature.right().ok()?,
+                AnyCssQueryFeature::CssQueryFeatureRangeInterval(feature) => feature.name().ok()?,
+                AnyCssQueryFeature::CssQueryFeatureBoolean(_) => return None,
+            };
+            let name = name.value_token().ok()?.text_trimmed().to_ascii_lowercase();
+            return Some(UnitContext::MediaFeature(name));
+        }
+    }
+
+    None
+}
+
+/// Returns the units allowed for `name`, falling back to its shorthands:
+/// `margin-block-start`, then `margin-block`, then `margin`.
+///
+/// The `min-` and `max-` prefixes of media features are also tried without the prefix.
+fn lookup_units<'a>(
+    policy: &'a FxHashMap<Box<str>, Box<[Box<str>]>>,
+    name: &str,
+) -> Option<&'a [Box<str>]> {
+    let get = |name: &str| {
+        policy
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, units)| units.as_ref())
+    };
+
+    let mut candidate = name;
+    loop {
+        if let Some(units) = get(candidate) {
+            return Some(units);
+        }
+        if let Some(unprefixed) = candidate
+            .strip_prefix("min-")
+            .or_else(|| candidate.strip_prefix("max-"))
+        {
+            if let Some(units) = get(unprefixed) {
+                return Some(units);
+            }
+        }
+        candidate = &candidate[..candidate.rfind('-')?];
+    }
+}
+
+/// Returns the number of pixels in one unit, or `None` when the unit can't be converted.
+///
+/// `rem` is relative to the font size of the root element. In media queries,
+/// `em` is relative to the initial font size, which is assumed to be the root font size.
+fn pixels_per_unit(unit: &str, root_font_size: f64, in_media_feature: bool) -> Option<f64> {
+    let pixels = match unit {
+        "px" => 1.0,
+        "pt" => 96.0 / 72.0,
+        "pc" => 16.0,
+        "in" => 96.0,
+        "cm" => 96.0 / 2.54,
+        "mm" => 96.0 / 25.4,
+        "q" => 96.0 / 101.6,
+        "rem" => root_font_size,
+        "em" if in_media_feature => root_font_size,
+        _ => return None,
+    };
+    (pixels > 0.0).then_some(pixels)
+}
+
+/// Formats a number with at most four decimals, without trailing zeros.
+fn format_number(value: f64) -> Option<String> {
+    if !value.is_finite() {
+        return None;
+    }
+    let formatted = format!("{value:.4}");
+    let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+    Some(if formatted == "-0" {
+        "0".to_string()
+    } else {
+        formatted.to_string()
+    })
+}
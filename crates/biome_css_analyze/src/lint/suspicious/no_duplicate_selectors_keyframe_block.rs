@@ -4,13 +4,16 @@ use biome_analyze::{
     context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
 };
 use biome_console::markup;
-use biome_css_syntax::{AnyCssKeyframesItem, AnyCssKeyframesSelector, CssKeyframesBlock};
+use biome_css_semantic::CssKeyframes;
+use biome_css_syntax::{AnyCssKeyframesSelector, CssKeyframesBlock};
 use biome_rowan::AstNode;
-use biome_string_case::StrLikeExtension;
 
 declare_lint_rule! {
     /// Disallow duplicate selectors within keyframe blocks.
     ///
+    /// Selectors are compared by the position they refer to in the animation,
+    /// so `from` is a duplicate of `0%`, and `to` is a duplicate of `100%`.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -27,6 +30,10 @@ declare_lint_rule! {
     /// @keyframes foo { 0% {} 0% {} }
     /// ```
     ///
+    /// ```css,expect_diagnostic
+    /// @keyframes foo { from {} 0% {} }
+    /// ```
+    ///
     /// ### Valid
     ///
     /// ```css
@@ -49,29 +56,17 @@ declare_lint_rule! {
 impl Rule for NoDuplicateSelectorsKeyframeBlock {
     type Query = Ast<CssKeyframesBlock>;
     type State = AnyCssKeyframesSelector;
-    type Signals = Option<Self::State>;
+    type Signals = Box<[Self::State]>;
     type Options = ();
 
-    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
-        let node = ctx.query();
-        let mut selector_list: HashSet<String> = HashSet::new();
-        for keyframe_item in node.items() {
-            match keyframe_item {
-                AnyCssKeyframesItem::CssKeyframesItem(item) => {
-                    let keyframe_selector = item.selectors().into_iter().next()?.ok()?;
-                    if !selector_list.insert(
-                        keyframe_selector
-                            .text()
-                            .to_ascii_lowercase_cow()
-                            .to_string(),
-                    ) {
-                        return Some(keyframe_selector);
-                    }
-                }
-                _ => return None,
-            }
-        }
-        None
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let keyframes = CssKeyframes::from_block(ctx.query());
+        let mut offsets = HashSet::new();
+        keyframes
+            .selectors()
+            .filter(|selector| !offsets.insert(selector.offset().to_bits()))
+            .map(|selector| selector.node().clone())
+            .collect()
     }
 
     fn diagnostic(_: &RuleContext<Self>, node: &Self::State) -> Option<RuleDiagnostic> {
@@ -2,9 +2,8 @@ use biome_analyze::{
     context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
 };
 use biome_console::markup;
-use biome_css_syntax::{
-    AnyCssDeclarationBlock, AnyCssKeyframesItem, CssDeclarationImportant, CssKeyframesBlock,
-};
+use biome_css_semantic::CssKeyframes;
+use biome_css_syntax::{CssDeclarationImportant, CssKeyframesBlock};
 use biome_rowan::AstNode;
 
 declare_lint_rule! {
@@ -52,27 +51,16 @@ declare_lint_rule! {
 impl Rule for NoImportantInKeyframe {
     type Query = Ast<CssKeyframesBlock>;
     type State = CssDeclarationImportant;
-    type Signals = Option<Self::State>;
+    type Signals = Box<[Self::State]>;
     type Options = ();
 
-    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
-        let node = ctx.query();
-        for item in node.items() {
-            let AnyCssKeyframesItem::CssKeyframesItem(keyframe_item) = item else {
-                return None;
-            };
-            let AnyCssDeclarationBlock::CssDeclarationBlock(block_declaration) =
-                keyframe_item.block().ok()?
-            else {
-                return None;
-            };
-            for colon_declaration in block_declaration.declarations() {
-                if let Some(important) = colon_declaration.declaration().ok()?.important() {
-                    return Some(important);
-                }
-            }
-        }
-        None
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let keyframes = CssKeyframes::from_block(ctx.query());
+        keyframes
+            .keyframes()
+            .iter()
+            .flat_map(|keyframe| keyframe.important_annotations())
+            .collect()
     }
 
     fn diagnostic(_ctx: &RuleContext<Self>, node: &Self::State) -> Option<RuleDiagnostic> {
@@ -1,7 +1,9 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
+use crate::assists;
 use crate::lint;
 
+pub type NoConflictingLayerOrder = < lint :: nursery :: no_conflicting_layer_order :: NoConflictingLayerOrder as biome_analyze :: Rule > :: Options ;
 pub type NoDescendingSpecificity = < lint :: nursery :: no_descending_specificity :: NoDescendingSpecificity as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateAtImportRules = < lint :: suspicious :: no_duplicate_at_import_rules :: NoDuplicateAtImportRules as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateCustomProperties = < lint :: nursery :: no_duplicate_custom_properties :: NoDuplicateCustomProperties as biome_analyze :: Rule > :: Options ;
@@ -15,12 +17,15 @@ pub type NoImportantInKeyframe = < lint :: suspicious :: no_important_in_keyfram
 pub type NoInvalidDirectionInLinearGradient = < lint :: correctness :: no_invalid_direction_in_linear_gradient :: NoInvalidDirectionInLinearGradient as biome_analyze :: Rule > :: Options ;
 pub type NoInvalidGridAreas =
     <lint::correctness::no_invalid_grid_areas::NoInvalidGridAreas as biome_analyze::Rule>::Options;
+pub type NoInvalidKeyframeSelector = < lint :: nursery :: no_invalid_keyframe_selector :: NoInvalidKeyframeSelector as biome_analyze :: Rule > :: Options ;
 pub type NoInvalidPositionAtImportRule = < lint :: correctness :: no_invalid_position_at_import_rule :: NoInvalidPositionAtImportRule as biome_analyze :: Rule > :: Options ;
 pub type NoIrregularWhitespace =
     <lint::nursery::no_irregular_whitespace::NoIrregularWhitespace as biome_analyze::Rule>::Options;
 pub type NoMissingVarFunction =
     <lint::nursery::no_missing_var_function::NoMissingVarFunction as biome_analyze::Rule>::Options;
+pub type NoNonexistentAnimationName = < lint :: nursery :: no_nonexistent_animation_name :: NoNonexistentAnimationName as biome_analyze :: Rule > :: Options ;
 pub type NoShorthandPropertyOverrides = < lint :: suspicious :: no_shorthand_property_overrides :: NoShorthandPropertyOverrides as biome_analyze :: Rule > :: Options ;
+pub type NoUndefinedCustomProperties = < lint :: nursery :: no_undefined_custom_properties :: NoUndefinedCustomProperties as biome_analyze :: Rule > :: Options ;
 pub type NoUnknownFunction =
     <lint::correctness::no_unknown_function::NoUnknownFunction as biome_analyze::Rule>::Options;
 pub type NoUnknownMediaFeatureName = < lint :: correctness :: no_unknown_media_feature_name :: NoUnknownMediaFeatureName as biome_analyze :: Rule > :: Options ;
@@ -35,5 +40,10 @@ pub type NoUnknownUnit =
 pub type NoUnmatchableAnbSelector = < lint :: correctness :: no_unmatchable_anb_selector :: NoUnmatchableAnbSelector as biome_analyze :: Rule > :: Options ;
 pub type NoValueAtRule =
     <lint::nursery::no_value_at_rule::NoValueAtRule as biome_analyze::Rule>::Options;
+pub type UseAllowedUnits =
+    <lint::nursery::use_allowed_units::UseAllowedUnits as biome_analyze::Rule>::Options;
 pub type UseGenericFontNames =
     <lint::a11y::use_generic_font_names::UseGenericFontNames as biome_analyze::Rule>::Options;
+pub type UseMediaFeatureRangeNotation = < assists :: source :: use_media_feature_range_notation :: UseMediaFeatureRangeNotation as biome_analyze :: Rule > :: Options ;
+pub type UseNormalizedColors =
+    <assists::source::use_normalized_colors::UseNormalizedColors as biome_analyze::Rule>::Options;
@@ -1,5 +1,5 @@
 use crate::keywords::{
-    AT_RULE_PAGE_PSEUDO_CLASSES, A_NPLUS_BNOTATION_PSEUDO_CLASSES,
+    ANIMATION_SHORTHAND_KEYWORDS, AT_RULE_PAGE_PSEUDO_CLASSES, A_NPLUS_BNOTATION_PSEUDO_CLASSES,
     A_NPLUS_BOF_SNOTATION_PSEUDO_CLASSES, BASIC_KEYWORDS, FONT_FAMILY_KEYWORDS, FONT_SIZE_KEYWORDS,
     FONT_STRETCH_KEYWORDS, FONT_STYLE_KEYWORDS, FONT_VARIANTS_KEYWORDS,
     FONT_WEIGHT_ABSOLUTE_KEYWORDS, FONT_WEIGHT_NUMERIC_KEYWORDS, FUNCTION_KEYWORDS, HTML_TAGS,
@@ -40,6 +40,17 @@ pub fn is_font_shorthand_keyword(value: &str) -> bool {
         || FONT_FAMILY_KEYWORDS.binary_search(&value).is_ok()
 }
 
+/// Check if the value is a keyword of the `animation-name` property.
+pub fn is_animation_name_keyword(value: &str) -> bool {
+    value == "none" || BASIC_KEYWORDS.binary_search(&value).is_ok()
+}
+
+/// Check if the value is a keyword of the `animation` shorthand property, other than an animation name.
+pub fn is_animation_shorthand_keyword(value: &str) -> bool {
+    BASIC_KEYWORDS.binary_search(&value).is_ok()
+        || ANIMATION_SHORTHAND_KEYWORDS.binary_search(&value).is_ok()
+}
+
 pub fn is_css_variable(value: &str) -> bool {
     value.to_ascii_lowercase_cow().starts_with("var(")
 }
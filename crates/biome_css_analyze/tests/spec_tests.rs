@@ -10,6 +10,7 @@ use biome_test_utils::{
     write_analyzer_snapshot, CheckActionType,
 };
 use std::ops::Deref;
+use std::sync::Arc;
 use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{css,json,jsonc}", crate::run_test, "module"}
@@ -116,8 +117,9 @@ pub(crate) fn analyze_and_snap(
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
     let options = create_analyzer_options(input_file, &mut diagnostics);
+    let project = Arc::default();
 
-    let (_, errors) = biome_css_analyze::analyze(&root, filter, &options, |event| {
+    let (_, errors) = biome_css_analyze::analyze(&root, filter, &options, project, |event| {
         if let Some(mut diag) = event.diagnostic() {
             for action in event.actions() {
                 if check_action_type.is_suppression() {
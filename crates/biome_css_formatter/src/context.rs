@@ -7,8 +7,10 @@ use biome_formatter::{
 
 use crate::comments::{CssComments, FormatCssLeadingComment};
 use biome_css_syntax::{CssFileSource, CssLanguage};
+use biome_deserialize_macros::{Deserializable, Merge};
 use std::fmt;
 use std::rc::Rc;
+use std::str::FromStr;
 
 #[derive(Debug)]
 pub struct CssFormatContext {
@@ -62,6 +64,9 @@ pub struct CssFormatOptions {
     line_ending: LineEnding,
     line_width: LineWidth,
     quote_style: QuoteStyle,
+    hex_color_case: HexColorCase,
+    keyword_case: KeywordCase,
+    url_quotes: UrlQuotes,
     _file_source: CssFileSource,
 }
 
@@ -74,6 +79,9 @@ impl CssFormatOptions {
             line_ending: LineEnding::default(),
             line_width: LineWidth::default(),
             quote_style: QuoteStyle::default(),
+            hex_color_case: HexColorCase::default(),
+            keyword_case: KeywordCase::default(),
+            url_quotes: UrlQuotes::default(),
         }
     }
 
@@ -102,6 +110,21 @@ impl CssFormatOptions {
         self
     }
 
+    pub fn with_hex_color_case(mut self, hex_color_case: HexColorCase) -> Self {
+        self.hex_color_case = hex_color_case;
+        self
+    }
+
+    pub fn with_keyword_case(mut self, keyword_case: KeywordCase) -> Self {
+        self.keyword_case = keyword_case;
+        self
+    }
+
+    pub fn with_url_quotes(mut self, url_quotes: UrlQuotes) -> Self {
+        self.url_quotes = url_quotes;
+        self
+    }
+
     pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
         self.indent_style = indent_style;
     }
@@ -122,9 +145,33 @@ impl CssFormatOptions {
         self.quote_style = quote_style;
     }
 
+    pub fn set_hex_color_case(&mut self, hex_color_case: HexColorCase) {
+        self.hex_color_case = hex_color_case;
+    }
+
+    pub fn set_keyword_case(&mut self, keyword_case: KeywordCase) {
+        self.keyword_case = keyword_case;
+    }
+
+    pub fn set_url_quotes(&mut self, url_quotes: UrlQuotes) {
+        self.url_quotes = url_quotes;
+    }
+
     pub fn quote_style(&self) -> QuoteStyle {
         self.quote_style
     }
+
+    pub fn hex_color_case(&self) -> HexColorCase {
+        self.hex_color_case
+    }
+
+    pub fn keyword_case(&self) -> KeywordCase {
+        self.keyword_case
+    }
+
+    pub fn url_quotes(&self) -> UrlQuotes {
+        self.url_quotes
+    }
 }
 
 impl FormatOptions for CssFormatOptions {
@@ -163,6 +210,133 @@ impl fmt::Display for CssFormatOptions {
         writeln!(f, "Indent width: {}", self.indent_width.value())?;
         writeln!(f, "Line ending: {}", self.line_ending)?;
         writeln!(f, "Line width: {}", self.line_width.value())?;
-        writeln!(f, "Quote style: {}", self.quote_style)
+        writeln!(f, "Quote style: {}", self.quote_style)?;
+        writeln!(f, "Hex color case: {}", self.hex_color_case)?;
+        writeln!(f, "Keyword case: {}", self.keyword_case)?;
+        writeln!(f, "URL quotes: {}", self.url_quotes)
+    }
+}
+
+/// The letter case of hexadecimal colors, e.g. `#fff` or `#FFF`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Deserializable, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum HexColorCase {
+    /// Hexadecimal colors are printed in lowercase
+    #[default]
+    Lower,
+    /// Hexadecimal colors are printed in uppercase
+    Upper,
+    /// Hexadecimal colors are printed as they are written
+    Preserve,
+}
+
+impl FromStr for HexColorCase {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lower" => Ok(Self::Lower),
+            "upper" => Ok(Self::Upper),
+            "preserve" => Ok(Self::Preserve),
+            _ => Err("Value not supported for HexColorCase"),
+        }
+    }
+}
+
+impl fmt::Display for HexColorCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HexColorCase::Lower => std::write!(f, "Lower"),
+            HexColorCase::Upper => std::write!(f, "Upper"),
+            HexColorCase::Preserve => std::write!(f, "Preserve"),
+        }
+    }
+}
+
+/// The letter case of case-insensitive keywords: property names, function names,
+/// names of unknown at-rules and keywords in property values and media queries.
+/// Known at-rule names and keywords such as `!important` are always printed in lowercase.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Deserializable, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum KeywordCase {
+    /// Keywords are printed as they are written
+    #[default]
+    Preserve,
+    /// Keywords are printed in lowercase
+    Lower,
+}
+
+impl KeywordCase {
+    pub const fn is_lower(&self) -> bool {
+        matches!(self, Self::Lower)
+    }
+}
+
+impl FromStr for KeywordCase {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" => Ok(Self::Preserve),
+            "lower" => Ok(Self::Lower),
+            _ => Err("Value not supported for KeywordCase"),
+        }
+    }
+}
+
+impl fmt::Display for KeywordCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeywordCase::Preserve => std::write!(f, "Preserve"),
+            KeywordCase::Lower => std::write!(f, "Lower"),
+        }
+    }
+}
+
+/// Whether the argument of `url()` functions should be quoted.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Deserializable, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum UrlQuotes {
+    /// URLs are printed as they are written
+    #[default]
+    Preserve,
+    /// URLs are always quoted, e.g. `url("image.png")`
+    Always,
+    /// URLs are unquoted when possible, e.g. `url(image.png)`
+    Never,
+}
+
+impl FromStr for UrlQuotes {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" => Ok(Self::Preserve),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err("Value not supported for UrlQuotes"),
+        }
+    }
+}
+
+impl fmt::Display for UrlQuotes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UrlQuotes::Preserve => std::write!(f, "Preserve"),
+            UrlQuotes::Always => std::write!(f, "Always"),
+            UrlQuotes::Never => std::write!(f, "Never"),
+        }
     }
 }
@@ -1,5 +1,6 @@
+use crate::context::UrlQuotes;
 use crate::prelude::*;
-use biome_css_syntax::{CssUrlFunction, CssUrlFunctionFields};
+use biome_css_syntax::{AnyCssUrlValue, CssUrlFunction, CssUrlFunctionFields};
 use biome_formatter::write;
 
 #[derive(Debug, Clone, Default)]
@@ -14,15 +15,52 @@ impl FormatNodeRule<CssUrlFunction> for FormatCssUrlFunction {
             r_paren_token,
         } = node.as_fields();
 
-        write!(
-            f,
-            [
-                name.format(),
-                l_paren_token.format(),
-                value.format(),
-                modifiers.format(),
-                r_paren_token.format()
-            ]
-        )
+        write!(f, [name.format(), l_paren_token.format()])?;
+
+        match value {
+            // Modifiers are only allowed after a quoted URL, so the quotes must be kept
+            Some(AnyCssUrlValue::CssString(string))
+                if f.options().url_quotes() == UrlQuotes::Never && modifiers.is_empty() =>
+            {
+                let value_token = string.value_token()?;
+                let text = value_token.text_trimmed();
+
+                if let Some(content) = unquote(text).filter(|content| can_be_unquoted(content)) {
+                    write!(
+                        f,
+                        [format_replaced(
+                            &value_token,
+                            &dynamic_text(content, value_token.text_trimmed_range().start())
+                        )]
+                    )?;
+                } else {
+                    write!(f, [string.format()])?;
+                }
+            }
+            value => write!(f, [value.format()])?,
+        }
+
+        write!(f, [modifiers.format(), r_paren_token.format()])
     }
 }
+
+/// Returns the content of a string token that is delimited by a matching pair of quotes,
+/// or `None` when the string isn't terminated.
+fn unquote(text: &str) -> Option<&str> {
+    let quote = text
+        .chars()
+        .next()
+        .filter(|quote| matches!(quote, '"' | '\''))?;
+    text.strip_prefix(quote)?.strip_suffix(quote)
+}
+
+/// Returns `true` if the content of a quoted URL can be written without quotes
+/// without changing its meaning.
+///
+/// An unquoted URL can't contain whitespace, quotes, parentheses or escape sequences.
+fn can_be_unquoted(content: &str) -> bool {
+    !content.is_empty()
+        && !content.chars().any(|c| {
+            c.is_ascii_whitespace() || c.is_control() || matches!(c, '"' | '\'' | '(' | ')' | '\\')
+        })
+}
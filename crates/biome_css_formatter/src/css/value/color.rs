@@ -1,5 +1,6 @@
+use crate::context::HexColorCase;
 use crate::prelude::*;
-use crate::utils::string_utils::FormatTokenAsLowercase;
+use crate::utils::string_utils::{FormatTokenAsLowercase, FormatTokenAsUppercase};
 use biome_css_syntax::{CssColor, CssColorFields};
 use biome_formatter::write;
 
@@ -12,12 +13,13 @@ impl FormatNodeRule<CssColor> for FormatCssColor {
             value_token,
         } = node.as_fields();
 
-        write!(
-            f,
-            [
-                hash_token.format(),
-                FormatTokenAsLowercase::from(value_token?)
-            ]
-        )
+        let value_token = value_token?;
+        write!(f, [hash_token.format()])?;
+
+        match f.options().hex_color_case() {
+            HexColorCase::Lower => write!(f, [FormatTokenAsLowercase::from(value_token)]),
+            HexColorCase::Upper => write!(f, [FormatTokenAsUppercase::from(value_token)]),
+            HexColorCase::Preserve => write!(f, [value_token.format()]),
+        }
     }
 }
@@ -1,6 +1,10 @@
 use crate::prelude::*;
-use biome_css_syntax::{CssIdentifier, CssIdentifierFields};
+use crate::utils::string_utils::FormatTokenAsLowercase;
+use biome_css_syntax::{
+    AnyCssDeclarationName, CssGenericProperty, CssIdentifier, CssIdentifierFields, CssSyntaxKind,
+};
 use biome_formatter::write;
+use biome_rowan::AstNode;
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatCssIdentifier;
@@ -9,6 +13,94 @@ impl FormatNodeRule<CssIdentifier> for FormatCssIdentifier {
     fn fmt_fields(&self, node: &CssIdentifier, f: &mut CssFormatter) -> FormatResult<()> {
         let CssIdentifierFields { value_token } = node.as_fields();
 
-        write!(f, [value_token.format()])
+        if f.options().keyword_case().is_lower() && is_case_insensitive_keyword(node) {
+            write!(f, [FormatTokenAsLowercase::from(value_token?)])
+        } else {
+            write!(f, [value_token.format()])
+        }
     }
 }
+
+/// Properties whose values may contain author-defined names, such as animation
+/// or grid area names, which are case-sensitive and must be preserved as written.
+const PROPERTIES_WITH_CUSTOM_IDENTIFIERS: &[&str] = &[
+    "animation",
+    "animation-name",
+    "container",
+    "container-name",
+    "counter-increment",
+    "counter-reset",
+    "counter-set",
+    "font",
+    "font-family",
+    "grid",
+    "grid-area",
+    "grid-column",
+    "grid-column-end",
+    "grid-column-start",
+    "grid-row",
+    "grid-row-end",
+    "grid-row-start",
+    "grid-template",
+    "grid-template-areas",
+    "grid-template-columns",
+    "grid-template-rows",
+    "list-style",
+    "list-style-type",
+    "transition",
+    "transition-property",
+    "view-transition-name",
+    "will-change",
+];
+
+/// Returns `true` if the identifier is a keyword that CSS treats case-insensitively
+/// and that can be safely lowercased:
+///
+/// - the name of a property, e.g. `COLOR` in `COLOR: red`;
+/// - the name of a function, e.g. `RGB` in `RGB(0 0 0)`;
+/// - the name of an unknown at-rule, e.g. `TAILWIND` in `@TAILWIND base;`;
+/// - a media type or a media feature, e.g. `SCREEN` and `COLOR` in `@media SCREEN and (COLOR)`;
+/// - a keyword in the value of a property, e.g. `BLOCK` in `display: BLOCK`,
+///   unless the property accepts author-defined names.
+fn is_case_insensitive_keyword(node: &CssIdentifier) -> bool {
+    let Some(parent) = node.syntax().parent() else {
+        return false;
+    };
+
+    match parent.kind() {
+        CssSyntaxKind::CSS_GENERIC_PROPERTY
+        | CssSyntaxKind::CSS_FUNCTION
+        | CssSyntaxKind::CSS_UNKNOWN_BLOCK_AT_RULE
+        | CssSyntaxKind::CSS_UNKNOWN_VALUE_AT_RULE
+        | CssSyntaxKind::CSS_MEDIA_TYPE
+        | CssSyntaxKind::CSS_QUERY_FEATURE_PLAIN
+        | CssSyntaxKind::CSS_QUERY_FEATURE_BOOLEAN
+        | CssSyntaxKind::CSS_QUERY_FEATURE_RANGE
+        | CssSyntaxKind::CSS_QUERY_FEATURE_REVERSE_RANGE
+        | CssSyntaxKind::CSS_QUERY_FEATURE_RANGE_INTERVAL => true,
+        CssSyntaxKind::CSS_GENERIC_COMPONENT_VALUE_LIST => {
+            let Some(property) = parent.parent().and_then(CssGenericProperty::cast) else {
+                return false;
+            };
+
+            match property.name() {
+                Ok(AnyCssDeclarationName::CssIdentifier(name)) => {
+                    name.value_token().is_ok_and(|token| {
+                        let name = token.text_trimmed().to_ascii_lowercase();
+                        !PROPERTIES_WITH_CUSTOM_IDENTIFIERS.contains(&unprefixed(&name))
+                    })
+                }
+                // Values of custom properties are case-sensitive
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Strips the vendor prefix from a property name, e.g. `-webkit-animation` becomes `animation`.
+fn unprefixed(name: &str) -> &str {
+    name.strip_prefix('-')
+        .and_then(|name| name.split_once('-'))
+        .map_or(name, |(_, unprefixed)| unprefixed)
+}
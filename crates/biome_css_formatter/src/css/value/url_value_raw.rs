@@ -1,3 +1,4 @@
+use crate::context::UrlQuotes;
 use crate::prelude::*;
 use biome_css_syntax::{CssUrlValueRaw, CssUrlValueRawFields};
 use biome_formatter::write;
@@ -9,12 +10,29 @@ impl FormatNodeRule<CssUrlValueRaw> for FormatCssUrlValueRaw {
         let CssUrlValueRawFields { value_token } = node.as_fields();
         let value_token = value_token?;
         let text = value_token.token_text();
-        write!(
-            f,
-            [format_replaced(
-                &value_token,
-                &dynamic_text(text.trim(), value_token.text_trimmed_range().start())
-            )]
-        )
+        let text = text.trim();
+
+        if f.options().url_quotes() == UrlQuotes::Always {
+            // Unquoted URLs can't contain quotes, so the content doesn't need any escaping
+            let quote = f.options().quote_style().as_char();
+            write!(
+                f,
+                [format_replaced(
+                    &value_token,
+                    &dynamic_text(
+                        &std::format!("{quote}{text}{quote}"),
+                        value_token.text_trimmed_range().start()
+                    )
+                )]
+            )
+        } else {
+            write!(
+                f,
+                [format_replaced(
+                    &value_token,
+                    &dynamic_text(text, value_token.text_trimmed_range().start())
+                )]
+            )
+        }
     }
 }
@@ -43,6 +43,36 @@ impl Format<CssFormatContext> for FormatTokenAsLowercase {
     }
 }
 
+pub(crate) struct FormatTokenAsUppercase {
+    token: SyntaxToken<CssLanguage>,
+}
+
+impl From<SyntaxToken<CssLanguage>> for FormatTokenAsUppercase {
+    fn from(value: SyntaxToken<CssLanguage>) -> Self {
+        Self { token: value }
+    }
+}
+
+impl Format<CssFormatContext> for FormatTokenAsUppercase {
+    fn fmt(&self, f: &mut CssFormatter) -> FormatResult<()> {
+        let original = self.token.text_trimmed();
+        if !original.bytes().any(|byte| byte.is_ascii_lowercase()) {
+            return write!(f, [self.token.format()]);
+        }
+
+        write!(
+            f,
+            [format_replaced(
+                &self.token,
+                &dynamic_text(
+                    &original.to_ascii_uppercase(),
+                    self.token.text_trimmed_range().start()
+                ),
+            )]
+        )
+    }
+}
+
 #[derive(Eq, PartialEq, Debug)]
 pub(crate) enum StringLiteralParentKind {
     /// Variants to track tokens that are inside a CssCharasetRule
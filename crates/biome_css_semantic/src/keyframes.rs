@@ -0,0 +1,191 @@
+use biome_css_syntax::{
+    AnyCssDeclarationBlock, AnyCssKeyframesItem, AnyCssKeyframesSelector, CssDeclarationImportant,
+    CssKeyframesBlock, CssKeyframesItem,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList};
+
+/// A typed view of the keyframes declared in the block of a `@keyframes` at-rule.
+///
+/// ```css
+/// @keyframes fade {
+///     from, 50% { opacity: 0; }
+///     to { opacity: 1; }
+/// }
+/// ```
+///
+/// Keyframes and selectors that couldn't be parsed are skipped.
+#[derive(Debug, Clone)]
+pub struct CssKeyframes {
+    keyframes: Vec<CssKeyframe>,
+}
+
+impl CssKeyframes {
+    pub fn from_block(block: &CssKeyframesBlock) -> Self {
+        let keyframes = block
+            .items()
+            .iter()
+            .filter_map(|item| match item {
+                AnyCssKeyframesItem::CssKeyframesItem(item) => Some(CssKeyframe::from_item(item)),
+                AnyCssKeyframesItem::CssBogusKeyframesItem(_) => None,
+            })
+            .collect();
+
+        Self { keyframes }
+    }
+
+    /// Returns the keyframes in source order.
+    pub fn keyframes(&self) -> &[CssKeyframe] {
+        &self.keyframes
+    }
+
+    /// Returns the selectors of all the keyframes in source order.
+    pub fn selectors(&self) -> impl Iterator<Item = &CssKeyframeSelector> {
+        self.keyframes
+            .iter()
+            .flat_map(|keyframe| keyframe.selectors.iter())
+    }
+}
+
+/// A keyframe, made of one or more selectors and a declaration block.
+///
+/// ```css
+/// @keyframes fade {
+///     from, 50% { opacity: 0; }
+/// /*  ^^^^^^^^^^^^^^^^^^^^^^^^^ */
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CssKeyframe {
+    node: CssKeyframesItem,
+    selectors: Vec<CssKeyframeSelector>,
+}
+
+impl CssKeyframe {
+    fn from_item(node: CssKeyframesItem) -> Self {
+        let selectors = node
+            .selectors()
+            .iter()
+            .flatten()
+            .filter_map(CssKeyframeSelector::from_node)
+            .collect();
+
+        Self { node, selectors }
+    }
+
+    pub fn node(&self) -> &CssKeyframesItem {
+        &self.node
+    }
+
+    pub fn selectors(&self) -> &[CssKeyframeSelector] {
+        &self.selectors
+    }
+
+    /// Returns the `!important` annotations of the declarations of the keyframe.
+    pub fn important_annotations(&self) -> impl Iterator<Item = CssDeclarationImportant> {
+        let declarations = match self.node.block() {
+            Ok(AnyCssDeclarationBlock::CssDeclarationBlock(block)) => Some(block.declarations()),
+            _ => None,
+        };
+
+        declarations
+            .into_iter()
+            .flat_map(|declarations| declarations.iter())
+            .filter_map(|declaration| declaration.declaration().ok()?.important())
+    }
+}
+
+/// A keyframe selector, such as `from`, `to` or `50%`.
+#[derive(Debug, Clone)]
+pub struct CssKeyframeSelector {
+    node: AnyCssKeyframesSelector,
+    offset: f64,
+}
+
+impl CssKeyframeSelector {
+    fn from_node(node: AnyCssKeyframesSelector) -> Option<Self> {
+        let offset = match &node {
+            AnyCssKeyframesSelector::CssKeyframesIdentSelector(selector) => {
+                let selector = selector.selector().ok()?;
+                if selector.text_trimmed().eq_ignore_ascii_case("from") {
+                    0.0
+                } else if selector.text_trimmed().eq_ignore_ascii_case("to") {
+                    100.0
+                } else {
+                    return None;
+                }
+            }
+            AnyCssKeyframesSelector::CssKeyframesPercentageSelector(selector) => {
+                let value = selector.selector().ok()?.value_token().ok()?;
+                // Adding zero turns `-0` into `0`
+                value.text_trimmed().parse::<f64>().ok()? + 0.0
+            }
+            AnyCssKeyframesSelector::CssBogusSelector(_) => return None,
+        };
+
+        Some(Self { node, offset })
+    }
+
+    pub fn node(&self) -> &AnyCssKeyframesSelector {
+        &self.node
+    }
+
+    /// Returns the position of the keyframe in the animation, as a percentage:
+    /// `from` is `0` and `to` is `100`.
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    /// Returns `true` if the selector is between `0%` and `100%`.
+    ///
+    /// Browsers ignore the keyframes whose selectors are outside of this range.
+    pub fn is_in_range(&self) -> bool {
+        (0.0..=100.0).contains(&self.offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_css_parser::{parse_css, CssParserOptions};
+
+    fn keyframes(source: &str) -> CssKeyframes {
+        let parse = parse_css(source, CssParserOptions::default());
+        let block = parse
+            .syntax()
+            .descendants()
+            .find_map(CssKeyframesBlock::cast)
+            .unwrap();
+        CssKeyframes::from_block(&block)
+    }
+
+    #[test]
+    fn resolves_selector_offsets() {
+        let keyframes = keyframes(
+            r#"@keyframes a {
+    FROM, 25.5% {}
+    -0% {}
+    150% {}
+    to { color: red !important; }
+}"#,
+        );
+
+        let offsets: Vec<_> = keyframes
+            .selectors()
+            .map(|selector| (selector.offset(), selector.is_in_range()))
+            .collect();
+        assert_eq!(
+            offsets,
+            vec![
+                (0.0, true),
+                (25.5, true),
+                (0.0, true),
+                (150.0, false),
+                (100.0, true)
+            ]
+        );
+
+        assert_eq!(keyframes.keyframes().len(), 4);
+        let last = &keyframes.keyframes()[3];
+        assert_eq!(last.important_annotations().count(), 1);
+    }
+}
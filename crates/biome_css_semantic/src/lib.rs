@@ -1,5 +1,9 @@
 mod events;
+mod keyframes;
+mod project_index;
 mod semantic_model;
 
 pub use events::*;
+pub use keyframes::*;
+pub use project_index::*;
 pub use semantic_model::*;
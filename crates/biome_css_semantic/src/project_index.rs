@@ -0,0 +1,319 @@
+use std::path::{Path, PathBuf};
+
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssKeyframesIdentifier, AnyCssKeyframesName, AnyCssKeyframesScope,
+    AnyCssProperty, CssDeclaration, CssKeyframesAtRule, CssPropertyAtRule, CssRoot,
+};
+use biome_rowan::{AstNode, TextRange};
+use rustc_hash::FxHashMap;
+
+/// An index of the symbols defined by the stylesheets of a project.
+///
+/// Unlike the [SemanticModel](crate::model::SemanticModel), which only knows
+/// about a single document, the index collects information across files,
+/// so that lint rules and editor features can reason about definitions that
+/// live in other stylesheets.
+///
+/// The index is keyed by file path: indexing a file again replaces whatever
+/// was previously recorded for it.
+#[derive(Debug, Clone, Default)]
+pub struct CssProjectIndex {
+    files: FxHashMap<PathBuf, CssFileIndex>,
+}
+
+impl CssProjectIndex {
+    /// Records the symbols of the file at `path`, replacing the previous entry, if any.
+    pub fn insert_file(&mut self, path: PathBuf, index: CssFileIndex) {
+        self.files.insert(path, index);
+    }
+
+    /// Forgets everything that was recorded for the file at `path`.
+    pub fn remove_file(&mut self, path: &Path) -> Option<CssFileIndex> {
+        self.files.remove(path)
+    }
+
+    /// Returns the number of files whose symbols are recorded.
+    pub fn file_count(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Returns the symbols recorded for the file at `path`.
+    pub fn get_file(&self, path: &Path) -> Option<&CssFileIndex> {
+        self.files.get(path)
+    }
+
+    /// Returns `true` if the custom property `name` (e.g. `--primary-color`)
+    /// is defined in at least one file of the project.
+    pub fn is_custom_property_defined(&self, name: &str) -> bool {
+        self.files
+            .values()
+            .any(|file| file.custom_property_definitions(name).next().is_some())
+    }
+
+    /// Returns all the definitions of the custom property `name`, along with
+    /// the path of the file where they are located.
+    pub fn custom_property_definitions<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = (&'a Path, &'a CssCustomPropertyDefinition)> + 'a {
+        self.files.iter().flat_map(move |(path, file)| {
+            file.custom_property_definitions(name)
+                .map(move |definition| (path.as_path(), definition))
+        })
+    }
+
+    /// Returns `true` if the `@keyframes` named `name` is defined in at least one file of the project.
+    pub fn is_keyframes_defined(&self, name: &str) -> bool {
+        self.files
+            .values()
+            .any(|file| file.keyframes_definitions(name).next().is_some())
+    }
+}
+
+/// The symbols defined by a single stylesheet.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CssFileIndex {
+    custom_properties: Vec<CssCustomPropertyDefinition>,
+    keyframes: Vec<CssKeyframesDefinition>,
+}
+
+impl CssFileIndex {
+    /// Collects the symbols defined in the given stylesheet.
+    ///
+    /// Custom properties are collected regardless of the rule they are
+    /// declared in, since any element matched by that rule will see them.
+    pub fn from_root(root: &CssRoot) -> Self {
+        let mut custom_properties = Vec::new();
+        let mut keyframes = Vec::new();
+
+        for node in root.syntax().descendants() {
+            if let Some(declaration) = CssDeclaration::cast_ref(&node) {
+                let Ok(AnyCssProperty::CssGenericProperty(property)) = declaration.property()
+                else {
+                    continue;
+                };
+                if let Ok(AnyCssDeclarationName::CssDashedIdentifier(name)) = property.name() {
+                    custom_properties.push(CssCustomPropertyDefinition {
+                        name: name.text(),
+                        range: name.range(),
+                        kind: CssCustomPropertyDefinitionKind::Declaration,
+                    });
+                }
+            } else if let Some(at_rule) = CssPropertyAtRule::cast_ref(&node) {
+                if let Ok(name) = at_rule.name() {
+                    custom_properties.push(CssCustomPropertyDefinition {
+                        name: name.text(),
+                        range: name.range(),
+                        kind: CssCustomPropertyDefinitionKind::AtProperty,
+                    });
+                }
+            } else if let Some(at_rule) = CssKeyframesAtRule::cast_ref(&node) {
+                if let Some(definition) = at_rule.name().ok().and_then(keyframes_definition) {
+                    keyframes.push(definition);
+                }
+            }
+        }
+
+        Self {
+            custom_properties,
+            keyframes,
+        }
+    }
+
+    /// Returns all the custom properties defined in the file.
+    pub fn custom_properties(&self) -> &[CssCustomPropertyDefinition] {
+        &self.custom_properties
+    }
+
+    /// Returns the definitions of the custom property `name` in the file.
+    pub fn custom_property_definitions<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a CssCustomPropertyDefinition> + 'a {
+        self.custom_properties
+            .iter()
+            .filter(move |definition| definition.name == name)
+    }
+
+    /// Returns all the `@keyframes` defined in the file.
+    pub fn keyframes(&self) -> &[CssKeyframesDefinition] {
+        &self.keyframes
+    }
+
+    /// Returns the definitions of the `@keyframes` named `name` in the file.
+    pub fn keyframes_definitions<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a CssKeyframesDefinition> + 'a {
+        self.keyframes
+            .iter()
+            .filter(move |definition| definition.name == name)
+    }
+}
+
+/// Returns the definition of a `@keyframes` at-rule, given its name.
+///
+/// The scope of CSS Modules keyframes, such as `:global(fade)`, is ignored.
+fn keyframes_definition(name: AnyCssKeyframesName) -> Option<CssKeyframesDefinition> {
+    let identifier = match name {
+        AnyCssKeyframesName::AnyCssKeyframesIdentifier(identifier) => identifier,
+        AnyCssKeyframesName::CssKeyframesScopedName(name) => match name.scope().ok()? {
+            AnyCssKeyframesScope::CssKeyframesScopeFunction(scope) => scope.name().ok()?,
+            AnyCssKeyframesScope::CssKeyframesScopePrefix(scope) => scope.name().ok()?,
+        },
+        AnyCssKeyframesName::CssBogusKeyframesName(_) => return None,
+    };
+
+    let name = match &identifier {
+        AnyCssKeyframesIdentifier::CssCustomIdentifier(identifier) => {
+            identifier.value_token().ok()?.text_trimmed().to_string()
+        }
+        AnyCssKeyframesIdentifier::CssString(string) => {
+            let token = string.value_token().ok()?;
+            let text = token.text_trimmed();
+            text.get(1..text.len() - 1)?.to_string()
+        }
+    };
+
+    Some(CssKeyframesDefinition {
+        name,
+        range: identifier.range(),
+    })
+}
+
+/// A place where a custom property is defined.
+///
+/// ```css
+/// @property --foo {}
+/// /*        ^^^^^ */
+/// a { --bar: red; }
+/// /*  ^^^^^ */
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CssCustomPropertyDefinition {
+    /// The name of the custom property, including the leading dashes.
+    pub name: String,
+    /// The range of the name of the custom property in its file.
+    pub range: TextRange,
+    pub kind: CssCustomPropertyDefinitionKind,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum CssCustomPropertyDefinitionKind {
+    /// A declaration such as `--foo: red;`
+    Declaration,
+    /// A registration such as `@property --foo {}`
+    AtProperty,
+}
+
+/// A `@keyframes` at-rule.
+///
+/// ```css
+/// @keyframes fade {}
+/// /*         ^^^^ */
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CssKeyframesDefinition {
+    /// The name of the keyframes, without quotes.
+    pub name: String,
+    /// The range of the name of the keyframes in its file.
+    pub range: TextRange,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_css_parser::{parse_css, CssParserOptions};
+
+    fn index(source: &str) -> CssFileIndex {
+        let parse = parse_css(source, CssParserOptions::default());
+        CssFileIndex::from_root(&parse.tree())
+    }
+
+    #[test]
+    fn collects_declarations_and_at_property() {
+        let file = index(
+            r#"@property --foo {
+  syntax: "<color>";
+  inherits: false;
+}
+
+.a {
+  --bar: red;
+  color: var(--baz);
+  .b { --nested: 1px; }
+}
+
+@media (min-width: 600px) {
+  :root { --media: 0; }
+}"#,
+        );
+
+        let names: Vec<_> = file
+            .custom_properties()
+            .iter()
+            .map(|definition| (definition.name.as_str(), definition.kind))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("--foo", CssCustomPropertyDefinitionKind::AtProperty),
+                ("--bar", CssCustomPropertyDefinitionKind::Declaration),
+                ("--nested", CssCustomPropertyDefinitionKind::Declaration),
+                ("--media", CssCustomPropertyDefinitionKind::Declaration),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_definitions_across_files() {
+        let mut project = CssProjectIndex::default();
+        project.insert_file(PathBuf::from("a.css"), index(":root { --foo: red; }"));
+        project.insert_file(PathBuf::from("b.css"), index("a { color: var(--foo); }"));
+
+        assert!(project.is_custom_property_defined("--foo"));
+        assert!(!project.is_custom_property_defined("--bar"));
+
+        let definitions: Vec<_> = project.custom_property_definitions("--foo").collect();
+        assert_eq!(definitions.len(), 1);
+        assert_eq!(definitions[0].0, Path::new("a.css"));
+        assert_eq!(definitions[0].1.range, TextRange::new(8.into(), 13.into()));
+
+        project.insert_file(PathBuf::from("a.css"), index(":root { --bar: red; }"));
+        assert!(!project.is_custom_property_defined("--foo"));
+        assert!(project.is_custom_property_defined("--bar"));
+
+        project.remove_file(Path::new("a.css"));
+        assert!(!project.is_custom_property_defined("--bar"));
+    }
+
+    #[test]
+    fn collects_keyframes() {
+        let parse = parse_css(
+            r#"@keyframes fade {}
+@keyframes "slide in" {}
+@keyframes :global(spin) {}
+@media (prefers-reduced-motion: no-preference) {
+  @keyframes bounce {}
+}"#,
+            CssParserOptions::default().allow_css_modules(),
+        );
+        let file = CssFileIndex::from_root(&parse.tree());
+
+        let names: Vec<_> = file
+            .keyframes()
+            .iter()
+            .map(|definition| definition.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["fade", "slide in", "spin", "bounce"]);
+
+        let mut project = CssProjectIndex::default();
+        project.insert_file(PathBuf::from("a.css"), file);
+        assert!(project.is_keyframes_defined("slide in"));
+        assert!(!project.is_keyframes_defined("Fade"));
+    }
+}
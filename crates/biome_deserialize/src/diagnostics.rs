@@ -160,6 +160,7 @@ impl DeserializationDiagnostic {
     pub fn new_unknown_key(key_name: &str, range: impl AsSpan, allowed_keys: &[&str]) -> Self {
         Self::new(markup!("Found an unknown key `"<Emphasis>{key_name}</Emphasis>"`." ))
             .with_range(range)
+            .with_suggestion(key_name, allowed_keys)
             .note_with_list("Known keys:", allowed_keys)
     }
 
@@ -171,6 +172,7 @@ impl DeserializationDiagnostic {
     ) -> Self {
         Self::new(markup! {"Found an unknown value `"<Emphasis>{variant_name}</Emphasis>"`."})
             .with_range(range)
+            .with_suggestion(variant_name, allowed_variants)
             .note_with_list("Accepted values:", allowed_variants)
     }
 
@@ -217,6 +219,16 @@ impl DeserializationDiagnostic {
         self
     }
 
+    /// Adds a note that suggests the closest of `candidates` to `name`, if one is close enough
+    fn with_suggestion(self, name: &str, candidates: &[&str]) -> Self {
+        match closest_candidate(name, candidates) {
+            Some(candidate) => {
+                self.with_note(markup! {"Did you mean `"<Emphasis>{candidate}</Emphasis>"`?"})
+            }
+            None => self,
+        }
+    }
+
     /// Adds a note with a list of strings
     pub fn note_with_list(mut self, message: impl Display, list: &[impl Display]) -> Self {
         self.deserialization_advice.notes.push((
@@ -229,6 +241,43 @@ impl DeserializationDiagnostic {
     }
 }
 
+/// Returns the candidate with the smallest edit distance to `name`, ignoring the case. The
+/// candidates that need more edits than a third of the length of `name` aren't suggested.
+fn closest_candidate<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let name = name.to_lowercase();
+    let max_distance = name.chars().count().max(3) / 3;
+    candidates
+        .iter()
+        .map(|candidate| {
+            (
+                levenshtein_distance(&name, &candidate.to_lowercase()),
+                *candidate,
+            )
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Returns the number of insertions, deletions and substitutions of characters that turn `a`
+/// into `b`
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b: Vec<_> = b.chars().collect();
+    let mut previous_row: Vec<_> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut row = Vec::with_capacity(b.len() + 1);
+        row.push(i + 1);
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous_row[j] + usize::from(a_char != *b_char);
+            let deletion = previous_row[j + 1] + 1;
+            let insertion = row[j] + 1;
+            row.push(substitution.min(deletion).min(insertion));
+        }
+        previous_row = row;
+    }
+    previous_row[b.len()]
+}
+
 impl From<SyntaxError> for DeserializationDiagnostic {
     fn from(_: SyntaxError) -> Self {
         DeserializationDiagnostic::new("Syntax error")
@@ -284,4 +333,16 @@ mod test {
             "null, or a boolean, or a number, or a string, or an array, or an object"
         );
     }
+
+    #[test]
+    fn test_closest_candidate() {
+        let candidates = ["useNamingConvention", "useNumberNamespace", "noVar"];
+        assert_eq!(
+            closest_candidate("useNamingConvension", &candidates),
+            Some("useNamingConvention")
+        );
+        assert_eq!(closest_candidate("novar", &candidates), Some("noVar"));
+        assert_eq!(closest_candidate("noVal", &candidates), Some("noVar"));
+        assert_eq!(closest_candidate("foo", &candidates), None);
+    }
 }
@@ -108,6 +108,11 @@ impl DeserializableValue for AnyJsonValue {
                 let value = value.value_token().ok()?;
                 visitor.visit_bool(value.kind() == T![true], range, name, diagnostics)
             }
+            AnyJsonValue::JsonLinesValue(lines) => {
+                // The records of a JSON Lines document are deserialized as an array
+                let items = lines.records().into_iter().map(Some);
+                visitor.visit_array(items, range, name, diagnostics)
+            }
             AnyJsonValue::JsonNullValue(_) => visitor.visit_null(range, name, diagnostics),
             AnyJsonValue::JsonNumberValue(value) => {
                 let value = value.value_token().ok()?;
@@ -133,6 +138,7 @@ impl DeserializableValue for AnyJsonValue {
             AnyJsonValue::JsonArrayValue(_) => Some(DeserializableType::Array),
             AnyJsonValue::JsonBogusValue(_) => None,
             AnyJsonValue::JsonBooleanValue(_) => Some(DeserializableType::Bool),
+            AnyJsonValue::JsonLinesValue(_) => Some(DeserializableType::Array),
             AnyJsonValue::JsonNullValue(_) => Some(DeserializableType::Null),
             AnyJsonValue::JsonNumberValue(_) => Some(DeserializableType::Number),
             AnyJsonValue::JsonObjectValue(_) => Some(DeserializableType::Map),
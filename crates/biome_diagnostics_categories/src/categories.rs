@@ -135,31 +135,42 @@ define_categories! {
     "lint/nursery/colorNoInvalidHex": "https://biomejs.dev/linter/rules/color-no-invalid-hex",
     "lint/nursery/noColorInvalidHex": "https://biomejs.dev/linter/rules/no-color-invalid-hex",
     "lint/nursery/noCommonJs": "https://biomejs.dev/linter/rules/no-common-js",
+    "lint/nursery/noConflictingLayerOrder": "https://biomejs.dev/linter/rules/no-conflicting-layer-order",
     "lint/nursery/noConsole": "https://biomejs.dev/linter/rules/no-console",
+    "lint/nursery/noDeprecatedCompilerOption": "https://biomejs.dev/linter/rules/no-deprecated-compiler-option",
+    "lint/nursery/noDeprecatedFields": "https://biomejs.dev/linter/rules/no-deprecated-fields",
     "lint/nursery/noDescendingSpecificity": "https://biomejs.dev/linter/rules/no-descending-specificity",
     "lint/nursery/noDocumentCookie": "https://biomejs.dev/linter/rules/no-document-cookie",
     "lint/nursery/noDocumentImportInPage": "https://biomejs.dev/linter/rules/no-document-import-in-page",
     "lint/nursery/noDoneCallback": "https://biomejs.dev/linter/rules/no-done-callback",
     "lint/nursery/noDuplicateAtImportRules": "https://biomejs.dev/linter/rules/no-duplicate-at-import-rules",
+    "lint/nursery/noDuplicateCustomElementNames": "https://biomejs.dev/linter/rules/no-duplicate-custom-element-names",
     "lint/nursery/noDuplicateCustomProperties": "https://biomejs.dev/linter/rules/no-duplicate-custom-properties",
     "lint/nursery/noDuplicateElseIf": "https://biomejs.dev/linter/rules/no-duplicate-else-if",
+    "lint/nursery/noDuplicateIds": "https://biomejs.dev/linter/rules/no-duplicate-ids",
+    "lint/nursery/noDuplicateKeysAcrossMerge": "https://biomejs.dev/linter/rules/no-duplicate-keys-across-merge",
     "lint/nursery/noDuplicateProperties": "https://biomejs.dev/linter/rules/no-duplicate-properties",
     "lint/nursery/noDuplicatedFields": "https://biomejs.dev/linter/rules/no-duplicated-fields",
     "lint/nursery/noDynamicNamespaceImportAccess": "https://biomejs.dev/linter/rules/no-dynamic-namespace-import-access",
     "lint/nursery/noEnum": "https://biomejs.dev/linter/rules/no-enum",
+    "lint/nursery/noExcessiveQueryDepth": "https://biomejs.dev/linter/rules/no-excessive-query-depth",
     "lint/nursery/noExportedImports": "https://biomejs.dev/linter/rules/no-exported-imports",
     "lint/nursery/noGlobalDirnameFilename": "https://biomejs.dev/linter/rules/no-global-dirname-filename",
     "lint/nursery/noHeadElement": "https://biomejs.dev/linter/rules/no-head-element",
     "lint/nursery/noHeadImportInDocument": "https://biomejs.dev/linter/rules/no-head-import-in-document",
+    "lint/nursery/noHtmlLabelWithoutControl": "https://biomejs.dev/linter/rules/no-html-label-without-control",
     "lint/nursery/noImgElement": "https://biomejs.dev/linter/rules/no-img-element",
     "lint/nursery/noImportantInKeyframe": "https://biomejs.dev/linter/rules/no-important-in-keyframe",
     "lint/nursery/noInvalidDirectionInLinearGradient": "https://biomejs.dev/linter/rules/no-invalid-direction-in-linear-gradient",
     "lint/nursery/noInvalidGridAreas": "https://biomejs.dev/linter/rules/use-consistent-grid-areas",
+    "lint/nursery/noInvalidKeyframeSelector": "https://biomejs.dev/linter/rules/no-invalid-keyframe-selector",
     "lint/nursery/noInvalidPositionAtImportRule": "https://biomejs.dev/linter/rules/no-invalid-position-at-import-rule",
     "lint/nursery/noIrregularWhitespace": "https://biomejs.dev/linter/rules/no-irregular-whitespace",
+    "lint/nursery/noMismatchedVariableTypes": "https://biomejs.dev/linter/rules/no-mismatched-variable-types",
     "lint/nursery/noMissingGenericFamilyKeyword": "https://biomejs.dev/linter/rules/no-missing-generic-family-keyword",
     "lint/nursery/noMissingVarFunction": "https://biomejs.dev/linter/rules/no-missing-var-function",
     "lint/nursery/noNestedTernary": "https://biomejs.dev/linter/rules/no-nested-ternary",
+    "lint/nursery/noNonexistentAnimationName": "https://biomejs.dev/linter/rules/no-nonexistent-animation-name",
     "lint/nursery/noOctalEscape": "https://biomejs.dev/linter/rules/no-octal-escape",
     "lint/nursery/noProcessEnv": "https://biomejs.dev/linter/rules/no-process-env",
     "lint/nursery/noReactSpecificProps": "https://biomejs.dev/linter/rules/no-react-specific-props",
@@ -171,6 +182,12 @@ define_categories! {
     "lint/nursery/noSubstr": "https://biomejs.dev/linter/rules/no-substr",
     "lint/nursery/noTemplateCurlyInString": "https://biomejs.dev/linter/rules/no-template-curly-in-string",
     "lint/nursery/noUndeclaredDependencies": "https://biomejs.dev/linter/rules/no-undeclared-dependencies",
+    "lint/nursery/noUndefinedCustomProperties": "https://biomejs.dev/linter/rules/no-undefined-custom-properties",
+    "lint/nursery/noUndefinedFragmentSpreads": "https://biomejs.dev/linter/rules/no-undefined-fragment-spreads",
+    "lint/nursery/noUnhandledObservedAttributes": "https://biomejs.dev/linter/rules/no-unhandled-observed-attributes",
+    "lint/nursery/noUnknownArguments": "https://biomejs.dev/linter/rules/no-unknown-arguments",
+    "lint/nursery/noUnknownCompilerOption": "https://biomejs.dev/linter/rules/no-unknown-compiler-option",
+    "lint/nursery/noUnknownFields": "https://biomejs.dev/linter/rules/no-unknown-fields",
     "lint/nursery/noUnknownFunction": "https://biomejs.dev/linter/rules/no-unknown-function",
     "lint/nursery/noUnknownMediaFeatureName": "https://biomejs.dev/linter/rules/no-unknown-media-feature-name",
     "lint/nursery/noUnknownProperty": "https://biomejs.dev/linter/rules/no-unknown-property",
@@ -181,12 +198,15 @@ define_categories! {
     "lint/nursery/noUnknownTypeSelector": "https://biomejs.dev/linter/rules/no-unknown-type-selector",
     "lint/nursery/noUnknownUnit": "https://biomejs.dev/linter/rules/no-unknown-unit",
     "lint/nursery/noUnmatchableAnbSelector": "https://biomejs.dev/linter/rules/no-unmatchable-anb-selector",
+    "lint/nursery/noUnresolvedTsconfigPaths": "https://biomejs.dev/linter/rules/no-unresolved-tsconfig-paths",
+    "lint/nursery/noUnusedFragments": "https://biomejs.dev/linter/rules/no-unused-fragments",
     "lint/nursery/noUnusedFunctionParameters": "https://biomejs.dev/linter/rules/no-unused-function-parameters",
     "lint/nursery/noUselessEscapeInRegex": "https://biomejs.dev/linter/rules/no-useless-escape-in-regex",
     "lint/nursery/noUselessStringRaw": "https://biomejs.dev/linter/rules/no-useless-string-raw",
     "lint/nursery/noUselessUndefined": "https://biomejs.dev/linter/rules/no-useless-undefined",
     "lint/nursery/noValueAtRule": "https://biomejs.dev/linter/rules/no-value-at-rule",
     "lint/nursery/useAdjacentOverloadSignatures": "https://biomejs.dev/linter/rules/use-adjacent-overload-signatures",
+    "lint/nursery/useAllowedUnits": "https://biomejs.dev/linter/rules/use-allowed-units",
     "lint/nursery/useAriaPropsSupportedByRole": "https://biomejs.dev/linter/rules/use-aria-props-supported-by-role",
     "lint/nursery/useAtIndex": "https://biomejs.dev/linter/rules/use-at-index",
     "lint/nursery/useBiomeSuppressionComment": "https://biomejs.dev/linter/rules/use-biome-suppression-comment",
@@ -200,13 +220,21 @@ define_categories! {
     "lint/nursery/useGoogleFontDisplay": "https://biomejs.dev/linter/rules/use-google-font-display",
     "lint/nursery/useGoogleFontPreconnect": "https://biomejs.dev/linter/rules/use-google-font-preconnect",
     "lint/nursery/useGuardForIn": "https://biomejs.dev/linter/rules/use-guard-for-in",
+    "lint/nursery/useHeadingOrder": "https://biomejs.dev/linter/rules/use-heading-order",
+    "lint/nursery/useHtmlAltText": "https://biomejs.dev/linter/rules/use-html-alt-text",
     "lint/nursery/useImportRestrictions": "https://biomejs.dev/linter/rules/use-import-restrictions",
     "lint/nursery/useJsxCurlyBraceConvention": "https://biomejs.dev/linter/rules/use-jsx-curly-brace-convention",
     "lint/nursery/useNamedOperation": "https://biomejs.dev/linter/rules/use-named-operation",
+    "lint/nursery/useOperationNameConvention": "https://biomejs.dev/linter/rules/use-operation-name-convention",
+    "lint/nursery/useRequiredArguments": "https://biomejs.dev/linter/rules/use-required-arguments",
+    "lint/nursery/useRequiredIdSelection": "https://biomejs.dev/linter/rules/use-required-id-selection",
     "lint/nursery/useSortedClasses": "https://biomejs.dev/linter/rules/use-sorted-classes",
+    "lint/nursery/useStableArrayOrder": "https://biomejs.dev/linter/rules/use-stable-array-order",
     "lint/nursery/useStrictMode": "https://biomejs.dev/linter/rules/use-strict-mode",
     "lint/nursery/useTrimStartEnd": "https://biomejs.dev/linter/rules/use-trim-start-end",
     "lint/nursery/useValidAutocomplete": "https://biomejs.dev/linter/rules/use-valid-autocomplete",
+    "lint/nursery/useValidCustomElementName": "https://biomejs.dev/linter/rules/use-valid-custom-element-name",
+    "lint/nursery/useValidLandmarkRoles": "https://biomejs.dev/linter/rules/use-valid-landmark-roles",
     "lint/performance/noAccumulatingSpread": "https://biomejs.dev/linter/rules/no-accumulating-spread",
     "lint/performance/noBarrelFile": "https://biomejs.dev/linter/rules/no-barrel-file",
     "lint/performance/noDelete": "https://biomejs.dev/linter/rules/no-delete",
@@ -334,7 +362,11 @@ define_categories! {
     "lint/suspicious/useValidTypeof": "https://biomejs.dev/linter/rules/use-valid-typeof",
     // end lint rules
     // start assists rules
+    "assists/source/useMediaFeatureRangeNotation": "https://biomejs.dev/linter/actions/use-media-feature-range-notation",
+    "assists/source/useNormalizedColors": "https://biomejs.dev/linter/actions/use-normalized-colors",
+    "assists/source/useSortedFields": "https://biomejs.dev/linter/actions/use-sorted-fields",
     "assists/source/useSortedKeys": "https://biomejs.dev/linter/actions/use-sorted-keys",
+    "assists/source/useSortedTsconfig": "https://biomejs.dev/linter/actions/use-sorted-tsconfig",
     // end assists rules
     ; // start syntax rules
     "syntax/correctness/noTypeOnlyImportAttributes",
@@ -345,6 +377,7 @@ define_categories! {
 
     // General categories
     "files/missingHandler",
+    "files/skipped",
     "format",
     "check",
     "ci",
@@ -355,6 +388,7 @@ define_categories! {
     "migrate",
     "deserialize",
     "project",
+    "jsonSchema",
     "search",
     "internalError/io",
     "internalError/fs",
@@ -383,6 +417,8 @@ define_categories! {
     "suppressions/unknownRule",
     "suppressions/unused",
     "suppressions/deprecatedSuppressionComment",
+    "suppressions/missingReason",
+    "suppressions/expired",
 
     // Used in tests and examples
     "args/fileNotFound",
@@ -0,0 +1,46 @@
+use biome_diagnostics::{Diagnostic as _, PrintDescription};
+use std::ops::Range;
+
+/// A diagnostic emitted for a document, such as a syntax error or the violation of a lint rule.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    /// The category of the diagnostic, such as `lint/suspicious/noDebugger` for the lint rules.
+    pub category: Option<String>,
+    pub severity: Severity,
+    /// The message of the diagnostic, in plain text.
+    pub message: String,
+    /// The range of the diagnostic in the document, in bytes.
+    pub range: Option<Range<u32>>,
+}
+
+/// The severity of a [Diagnostic].
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    Hint,
+    Information,
+    Warning,
+    Error,
+}
+
+impl Diagnostic {
+    pub(crate) fn new(diagnostic: &biome_diagnostics::serde::Diagnostic) -> Self {
+        Self {
+            category: diagnostic
+                .category()
+                .map(|category| category.name().to_string()),
+            severity: match diagnostic.severity() {
+                biome_diagnostics::Severity::Hint => Severity::Hint,
+                biome_diagnostics::Severity::Information => Severity::Information,
+                biome_diagnostics::Severity::Warning => Severity::Warning,
+                biome_diagnostics::Severity::Error | biome_diagnostics::Severity::Fatal => {
+                    Severity::Error
+                }
+            },
+            message: PrintDescription(diagnostic).to_string(),
+            range: diagnostic
+                .location()
+                .span
+                .map(|span| u32::from(span.start())..u32::from(span.end())),
+        }
+    }
+}
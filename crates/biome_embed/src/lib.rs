@@ -0,0 +1,337 @@
+//! A stable API to embed Biome in other tools, such as bundlers and code review bots.
+//!
+//! The crates of the workspace, such as `biome_service`, change with every release of Biome.
+//! This crate wraps them in a small API that follows semantic versioning on its own: the types
+//! of the internal crates are never part of it.
+//!
+//! ```no_run
+//! use biome_embed::{Biome, FixMode};
+//!
+//! # fn main() -> Result<(), biome_embed::Error> {
+//! let biome = Biome::new();
+//! // The configuration file of the project is loaded from the directory, or its parents
+//! let project = biome.open_project("path/to/project")?;
+//!
+//! let mut document = project.open_document("src/main.js", "debugger;\nlet a=1")?;
+//! for diagnostic in document.diagnostics()? {
+//!     println!("{:?} {}", diagnostic.category, diagnostic.message);
+//! }
+//! document.apply_fixes(FixMode::Safe)?;
+//! println!("{}", document.format()?);
+//! # Ok(())
+//! # }
+//! ```
+
+mod diagnostic;
+
+pub use diagnostic::{Diagnostic, Severity};
+
+use biome_analyze::RuleCategoriesBuilder;
+use biome_configuration::ConfigurationPathHint;
+use biome_deserialize::Merge;
+use biome_diagnostics::PrintDescription;
+use biome_fs::{BiomePath, FileSystem, OsFileSystem};
+use biome_service::configuration::{
+    load_configuration, load_editorconfig, LoadedConfiguration, PartialConfigurationExt,
+};
+use biome_service::workspace::{
+    self, ChangeFileParams, CloseFileParams, FixFileMode, FixFileParams, FormatFileParams,
+    GetFileContentParams, OpenFileParams, ProjectKey, PullDiagnosticsParams,
+    RegisterProjectFolderParams, UpdateSettingsParams,
+};
+use biome_service::{DynRef, WorkspaceError};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// An instance of Biome, which processes the documents of the projects opened with it.
+///
+/// It can be shared between threads. The operations on the documents of different projects
+/// run one at a time, because they select the settings of their project in the workspace.
+pub struct Biome {
+    fs: DynRef<'static, dyn FileSystem>,
+    workspace: Box<dyn workspace::Workspace>,
+    /// Held while an operation selects the current project of the workspace, and uses it
+    current_project: Mutex<()>,
+}
+
+impl Default for Biome {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Biome {
+    /// Creates an instance of Biome that runs in the current process, and reads the
+    /// configuration files from the disk.
+    pub fn new() -> Self {
+        Self {
+            fs: DynRef::Owned(Box::<OsFileSystem>::default()),
+            workspace: workspace::server(),
+            current_project: Mutex::default(),
+        }
+    }
+
+    /// Locks the current project of the workspace, until the guard is dropped.
+    fn lock_current_project(&self) -> MutexGuard<'_, ()> {
+        // The lock doesn't protect any data, which can't be left in an invalid state
+        self.current_project
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Opens the project of the directory `root`, with the configuration file found in the
+    /// directory or in its parents, as the CLI does. The project has the default configuration
+    /// when there's no configuration file.
+    ///
+    /// Several projects can be opened at once: the documents of each project use its own
+    /// configuration.
+    pub fn open_project(&self, root: impl Into<PathBuf>) -> Result<Project<'_>, Error> {
+        let root = root.into();
+        let loaded =
+            load_configuration(&self.fs, ConfigurationPathHint::FromWorkspace(root.clone()))?;
+        if loaded.has_errors() {
+            let message = loaded
+                .as_diagnostics_iter()
+                .map(|diagnostic| PrintDescription(diagnostic).to_string())
+                .collect::<Vec<_>>()
+                .join("\n");
+            return Err(Error::Configuration(message));
+        }
+        let LoadedConfiguration {
+            configuration: fs_configuration,
+            directory_path,
+            ..
+        } = loaded;
+
+        let mut configuration = if fs_configuration.use_editorconfig().unwrap_or_default() {
+            let search_path = directory_path.clone().unwrap_or_else(|| root.clone());
+            load_editorconfig(&self.fs, search_path)?
+                .0
+                .unwrap_or_default()
+        } else {
+            Default::default()
+        };
+        configuration.merge_with(fs_configuration);
+        let (vcs_base_path, gitignore_matches) =
+            configuration.retrieve_gitignore_matches(&self.fs, directory_path.as_deref())?;
+
+        let _guard = self.lock_current_project();
+        let key = self
+            .workspace
+            .register_project_folder(RegisterProjectFolderParams {
+                path: Some(root.clone()),
+                set_as_current_workspace: true,
+            })?;
+        self.workspace.update_settings(UpdateSettingsParams {
+            configuration,
+            vcs_base_path,
+            gitignore_matches,
+            workspace_directory: Some(root.clone()),
+        })?;
+
+        Ok(Project {
+            biome: self,
+            handle: ProjectHandle { key, root },
+        })
+    }
+}
+
+/// A project opened with [Biome::open_project].
+pub struct Project<'biome> {
+    biome: &'biome Biome,
+    handle: ProjectHandle,
+}
+
+/// The key of a project in the workspace, along with its root
+#[derive(Clone, Debug)]
+struct ProjectHandle {
+    key: ProjectKey,
+    root: PathBuf,
+}
+
+impl ProjectHandle {
+    /// Makes the project the current one of the workspace of `biome`, so that the next
+    /// operation uses its settings, even when other projects were opened since.
+    ///
+    /// The other threads can't select another project until the returned guard is dropped.
+    fn select<'biome>(&self, biome: &'biome Biome) -> Result<MutexGuard<'biome, ()>, Error> {
+        let guard = biome.lock_current_project();
+        // The key of a project that is registered again doesn't change
+        let key = biome
+            .workspace
+            .register_project_folder(RegisterProjectFolderParams {
+                path: Some(self.root.clone()),
+                set_as_current_workspace: true,
+            })?;
+        debug_assert_eq!(key, self.key);
+        Ok(guard)
+    }
+}
+
+impl<'biome> Project<'biome> {
+    /// Returns the directory of the project.
+    pub fn root(&self) -> &Path {
+        &self.handle.root
+    }
+
+    /// Opens the document at `path`, relative to the root of the project, with its content.
+    /// The document doesn't need to exist on the disk.
+    ///
+    /// The document is closed when it's dropped.
+    pub fn open_document(
+        &self,
+        path: impl AsRef<Path>,
+        content: impl Into<String>,
+    ) -> Result<Document<'biome>, Error> {
+        let path = BiomePath::new(self.handle.root.join(path));
+        let _guard = self.handle.select(self.biome)?;
+        self.biome.workspace.open_file(OpenFileParams {
+            path: path.clone(),
+            content: content.into(),
+            version: 0,
+            document_file_source: None,
+        })?;
+        Ok(Document {
+            biome: self.biome,
+            project: self.handle.clone(),
+            path,
+            version: 0,
+        })
+    }
+}
+
+/// A document opened with [Project::open_document].
+pub struct Document<'biome> {
+    biome: &'biome Biome,
+    /// The project of the document, whose settings apply to it
+    project: ProjectHandle,
+    path: BiomePath,
+    version: i32,
+}
+
+/// The fixes applied by [Document::apply_fixes].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FixMode {
+    /// Applies the safe fixes, which don't change the behavior of the code
+    Safe,
+    /// Applies the safe and the unsafe fixes
+    SafeAndUnsafe,
+}
+
+impl Document<'_> {
+    /// Returns the path of the document.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the current content of the document.
+    pub fn content(&self) -> Result<String, Error> {
+        let _guard = self.project.select(self.biome)?;
+        Ok(self.workspace().get_file_content(GetFileContentParams {
+            path: self.path.clone(),
+        })?)
+    }
+
+    /// Replaces the content of the document.
+    pub fn change(&mut self, content: impl Into<String>) -> Result<(), Error> {
+        let _guard = self.project.select(self.biome)?;
+        self.change_selected(content.into())
+    }
+
+    /// Replaces the content of the document, once its project is selected.
+    fn change_selected(&mut self, content: String) -> Result<(), Error> {
+        self.version += 1;
+        Ok(self.workspace().change_file(ChangeFileParams {
+            path: self.path.clone(),
+            content,
+            version: self.version,
+        })?)
+    }
+
+    /// Returns the syntax errors and the diagnostics of the lint rules of the document.
+    pub fn diagnostics(&self) -> Result<Vec<Diagnostic>, Error> {
+        let _guard = self.project.select(self.biome)?;
+        let result = self.workspace().pull_diagnostics(PullDiagnosticsParams {
+            path: self.path.clone(),
+            categories: RuleCategoriesBuilder::default()
+                .with_syntax()
+                .with_lint()
+                .build(),
+            max_diagnostics: u64::MAX,
+            only: Vec::new(),
+            skip: Vec::new(),
+        })?;
+        Ok(result.diagnostics.iter().map(Diagnostic::new).collect())
+    }
+
+    /// Returns the content of the document, formatted.
+    pub fn format(&self) -> Result<String, Error> {
+        let _guard = self.project.select(self.biome)?;
+        let printed = self.workspace().format_file(FormatFileParams {
+            path: self.path.clone(),
+        })?;
+        Ok(printed.into_code())
+    }
+
+    /// Applies the fixes of the lint rules to the document, and returns its new content.
+    pub fn apply_fixes(&mut self, mode: FixMode) -> Result<String, Error> {
+        let _guard = self.project.select(self.biome)?;
+        let result = self.workspace().fix_file(FixFileParams {
+            path: self.path.clone(),
+            fix_file_mode: match mode {
+                FixMode::Safe => FixFileMode::SafeFixes,
+                FixMode::SafeAndUnsafe => FixFileMode::SafeAndUnsafeFixes,
+            },
+            should_format: false,
+            only: Vec::new(),
+            skip: Vec::new(),
+            rule_categories: RuleCategoriesBuilder::default()
+                .with_syntax()
+                .with_lint()
+                .build(),
+            suppression_reason: None,
+        })?;
+        self.change_selected(result.code.clone())?;
+        Ok(result.code)
+    }
+
+    fn workspace(&self) -> &dyn workspace::Workspace {
+        self.biome.workspace.as_ref()
+    }
+}
+
+impl Drop for Document<'_> {
+    fn drop(&mut self) {
+        let _ = self.workspace().close_file(CloseFileParams {
+            path: self.path.clone(),
+        });
+    }
+}
+
+/// An error of Biome.
+#[derive(Debug)]
+pub enum Error {
+    /// The configuration file of the project is invalid
+    Configuration(String),
+    /// Biome couldn't process the request, for example because the language of the document
+    /// isn't supported
+    Workspace(String),
+}
+
+impl From<WorkspaceError> for Error {
+    fn from(error: WorkspaceError) -> Self {
+        Self::Workspace(PrintDescription(&error).to_string())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Configuration(message) => write!(f, "Invalid configuration: {message}"),
+            Self::Workspace(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,99 @@
+use biome_embed::{Biome, FixMode, Severity};
+use std::path::PathBuf;
+
+fn project_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/project")
+}
+
+#[test]
+fn lints_fixes_and_formats_a_document() {
+    let biome = Biome::new();
+    let project = biome.open_project(project_root()).unwrap();
+    let mut document = project
+        .open_document("src/main.js", "debugger;\nlet a=1")
+        .unwrap();
+
+    let diagnostics = document.diagnostics().unwrap();
+    let diagnostic = diagnostics
+        .iter()
+        .find(|diagnostic| diagnostic.category.as_deref() == Some("lint/suspicious/noDebugger"))
+        .unwrap();
+    assert_eq!(diagnostic.severity, Severity::Error);
+    assert_eq!(diagnostic.range, Some(0..8));
+
+    assert_eq!(document.format().unwrap(), "debugger;\nlet a = 1;\n");
+
+    let code = document.apply_fixes(FixMode::SafeAndUnsafe).unwrap();
+    assert!(!code.contains("debugger"));
+    assert_eq!(document.content().unwrap(), code);
+}
+
+#[test]
+fn reports_the_documents_that_are_not_supported() {
+    let biome = Biome::new();
+    let project = biome.open_project(project_root()).unwrap();
+    let result = project
+        .open_document("image.png", "")
+        .and_then(|document| document.format());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn applies_the_configuration_of_the_project_of_each_document() {
+    let biome = Biome::new();
+    let project = biome.open_project(project_root()).unwrap();
+    let other_project = biome
+        .open_project(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/other_project"),
+        )
+        .unwrap();
+    let content = "function f() {\nreturn 1;\n}";
+    let document = project.open_document("src/main.js", content).unwrap();
+    let other_document = other_project.open_document("src/main.js", content).unwrap();
+
+    assert_eq!(
+        document.format().unwrap(),
+        "function f() {\n\treturn 1;\n}\n"
+    );
+    assert_eq!(
+        other_document.format().unwrap(),
+        "function f() {\n    return 1;\n}\n"
+    );
+}
+
+#[test]
+fn applies_the_configuration_of_each_project_across_threads() {
+    let biome = Biome::new();
+    let project = biome.open_project(project_root()).unwrap();
+    let other_project = biome
+        .open_project(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/other_project"),
+        )
+        .unwrap();
+    let content = "function f() {\nreturn 1;\n}";
+
+    std::thread::scope(|scope| {
+        let formatted = scope.spawn(|| {
+            let document = project.open_document("src/threads.js", content).unwrap();
+            (0..100)
+                .map(|_| document.format().unwrap())
+                .collect::<Vec<_>>()
+        });
+        let other_formatted = scope.spawn(|| {
+            let document = other_project
+                .open_document("src/threads.js", content)
+                .unwrap();
+            (0..100)
+                .map(|_| document.format().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        for formatted in formatted.join().unwrap() {
+            assert_eq!(formatted, "function f() {\n\treturn 1;\n}\n");
+        }
+        for formatted in other_formatted.join().unwrap() {
+            assert_eq!(formatted, "function f() {\n    return 1;\n}\n");
+        }
+    });
+}
@@ -18,6 +18,7 @@ pub struct BiomeEnv {
     pub biome_log_path: BiomeEnvVariable,
     pub biome_log_prefix: BiomeEnvVariable,
     pub biome_config_path: BiomeEnvVariable,
+    pub biome_config_override: BiomeEnvVariable,
 }
 
 pub static BIOME_ENV: OnceLock<BiomeEnv> = OnceLock::new();
@@ -37,6 +38,10 @@ impl BiomeEnv {
                 "BIOME_CONFIG_PATH",
                 "A path to the configuration file",
             ),
+            biome_config_override: BiomeEnvVariable::new(
+                "BIOME_CONFIG_OVERRIDE",
+                "Overrides of the configuration, one per line. Example: `formatter.lineWidth=120`",
+            ),
         }
     }
 }
@@ -106,6 +111,23 @@ impl Display for BiomeEnv {
             }
         };
 
+        match self.biome_config_override.value() {
+            None => {
+                KeyValuePair(
+                    self.biome_config_override.name,
+                    markup! { <Dim>"unset"</Dim> },
+                )
+                .fmt(fmt)?;
+            }
+            Some(value) => {
+                KeyValuePair(
+                    self.biome_config_override.name,
+                    markup! {{DebugDisplay(value)}},
+                )
+                .fmt(fmt)?;
+            }
+        };
+
         Ok(())
     }
 }
@@ -37,6 +37,28 @@ impl OsFileSystem {
             })),
         }
     }
+
+    /// Runs `git` with the given arguments in the working directory, and returns its
+    /// standard output.
+    ///
+    /// It returns an error with the standard error of `git` when the command fails,
+    /// such as when the working directory isn't a repository or a ref doesn't exist.
+    fn run_git(&self, args: &[&str]) -> io::Result<String> {
+        let mut command = Command::new("git");
+        if let Some(working_directory) = &self.working_directory {
+            command.current_dir(working_directory);
+        }
+        let output = command.args(args).output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(io::Error::new(
+                IoErrorKind::Other,
+                format!("`git {}` failed: {}", args.join(" "), stderr.trim()),
+            ));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
 }
 
 impl Default for OsFileSystem {
@@ -99,43 +121,44 @@ impl FileSystem for OsFileSystem {
     }
 
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
-        let output = Command::new("git")
-            .arg("diff")
-            .arg("--name-only")
-            .arg("--relative")
+        // The files are compared to the commit where the current branch forked from `base`,
+        // so that the changes made to `base` since then aren't reported
+        let merge_base = self.run_git(&["merge-base", base, "HEAD"])?;
+        let merge_base = merge_base.trim();
+
+        // The working tree is compared to the merge base, so the uncommitted changes are
+        // reported too
+        let output = self.run_git(&[
+            "diff",
+            "--name-only",
+            "--relative",
             // A: added
             // C: copied
             // M: modified
             // R: renamed
             // Source: https://git-scm.com/docs/git-diff#Documentation/git-diff.txt---diff-filterACDMRTUXB82308203
-            .arg("--diff-filter=ACMR")
-            .arg(format!("{base}...HEAD"))
-            .output()?;
+            "--diff-filter=ACMR",
+            merge_base,
+        ])?;
 
-        Ok(String::from_utf8_lossy(&output.stdout)
-            .lines()
-            .map(|l| l.to_string())
-            .collect())
+        Ok(output.lines().map(|l| l.to_string()).collect())
     }
 
     fn get_staged_files(&self) -> io::Result<Vec<String>> {
-        let output = Command::new("git")
-            .arg("diff")
-            .arg("--name-only")
-            .arg("--relative")
-            .arg("--staged")
+        let output = self.run_git(&[
+            "diff",
+            "--name-only",
+            "--relative",
+            "--staged",
             // A: added
             // C: copied
             // M: modified
             // R: renamed
             // Source: https://git-scm.com/docs/git-diff#Documentation/git-diff.txt---diff-filterACDMRTUXB82308203
-            .arg("--diff-filter=ACMR")
-            .output()?;
+            "--diff-filter=ACMR",
+        ])?;
 
-        Ok(String::from_utf8_lossy(&output.stdout)
-            .lines()
-            .map(|l| l.to_string())
-            .collect())
+        Ok(output.lines().map(|l| l.to_string()).collect())
     }
 }
 
@@ -448,3 +471,104 @@ impl From<FileType> for ErrorKind {
         Self::UnknownFileType
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::OsFileSystem;
+    use crate::FileSystem;
+    use std::env::temp_dir;
+    use std::fs::{create_dir_all, remove_dir_all, write};
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+
+    /// Creates an empty repository in a temporary directory, with a first commit on `main`
+    fn create_repository(name: &str) -> PathBuf {
+        let root = temp_dir().join(name);
+        let _ = remove_dir_all(&root);
+        create_dir_all(&root).unwrap();
+        git(&root, &["init", "--quiet"]);
+        git(&root, &["symbolic-ref", "HEAD", "refs/heads/main"]);
+        commit(&root, "main.js", "main");
+        root
+    }
+
+    fn git(root: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .current_dir(root)
+            .args([
+                "-c",
+                "user.name=Biome",
+                "-c",
+                "user.email=biome@example.com",
+                "-c",
+                "commit.gpgsign=false",
+            ])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "`git {}` failed", args.join(" "));
+    }
+
+    fn commit(root: &Path, file: &str, content: &str) {
+        write(root.join(file), content).unwrap();
+        git(root, &["add", file]);
+        git(root, &["commit", "--quiet", "--message", file]);
+    }
+
+    fn changed_files(root: &Path, base: &str) -> std::io::Result<Vec<String>> {
+        let mut files = OsFileSystem::new(root.to_path_buf()).get_changed_files(base)?;
+        files.sort_unstable();
+        Ok(files)
+    }
+
+    #[test]
+    fn changed_files_are_compared_to_the_merge_base() {
+        let root = create_repository("biome_fs_changed_files_merge_base");
+        git(&root, &["checkout", "--quiet", "-b", "feature"]);
+        commit(&root, "feature.js", "feature");
+        git(&root, &["checkout", "--quiet", "main"]);
+        commit(&root, "base.js", "base");
+        git(&root, &["checkout", "--quiet", "feature"]);
+
+        // The files committed to `main` after the branch forked aren't reported
+        assert_eq!(changed_files(&root, "main").unwrap(), ["feature.js"]);
+
+        remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn changed_files_include_the_uncommitted_changes() {
+        let root = create_repository("biome_fs_changed_files_uncommitted");
+        git(&root, &["checkout", "--quiet", "-b", "feature"]);
+        commit(&root, "feature.js", "feature");
+        write(root.join("main.js"), "changed").unwrap();
+
+        assert_eq!(
+            changed_files(&root, "main").unwrap(),
+            ["feature.js", "main.js"]
+        );
+
+        remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn changed_files_fail_when_the_base_does_not_exist() {
+        let root = create_repository("biome_fs_changed_files_unknown_base");
+
+        let error = changed_files(&root, "unknown").unwrap_err();
+        assert!(error.to_string().contains("git merge-base unknown HEAD"));
+
+        remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn changed_files_fail_outside_of_a_repository() {
+        let root = temp_dir().join("biome_fs_changed_files_no_repository");
+        let _ = remove_dir_all(&root);
+        create_dir_all(&root).unwrap();
+
+        assert!(changed_files(&root, "main").is_err());
+
+        remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,205 @@
+use crate::sort::SortableElements;
+use crate::GraphqlRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_source_rule, ActionCategory, Ast, Rule, RuleAction,
+    SourceActionKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_graphql_factory::make;
+use biome_graphql_syntax::{
+    AnyGraphqlSelection, GraphqlArgument, GraphqlArgumentList, GraphqlInputFieldList,
+    GraphqlInputValueDefinition, GraphqlObjectField, GraphqlObjectValueMemberList,
+    GraphqlSelectionList,
+};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+declare_source_rule! {
+    /// Sorts the fields of the selection sets, the arguments and the fields of the input objects in natural order.
+    ///
+    /// The fields of a selection set are sorted by their alias if they have one, because it's the name
+    /// of the field in the response, and by their name otherwise.
+    /// The fragment spreads and the inline fragments stay in place:
+    /// the fields that precede them and the ones that follow them are sorted independently.
+    ///
+    /// Elements separated by an empty line are sorted independently of each other.
+    /// The comments attached to an element move with it: the comments that precede the element,
+    /// and the comment that follows it on the same line.
+    /// The directives and the arguments of a field move with it too.
+    ///
+    /// ## Examples
+    ///
+    /// ```graphql,expect_diff
+    /// query {
+    ///   user(name: "Ada", id: 1) {
+    ///     name
+    ///     # The user's avatar
+    ///     avatar @include(if: $withAvatar)
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diff
+    /// input UserFilter {
+    ///   name: String
+    ///   age: Int
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Each kind of list can be sorted or left untouched independently of the others.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "arguments": false
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### selections
+    ///
+    /// Sort the fields of the selection sets.
+    ///
+    /// Default: `true`
+    ///
+    /// ### arguments
+    ///
+    /// Sort the arguments passed to the fields and to the directives.
+    ///
+    /// Default: `true`
+    ///
+    /// ### inputFields
+    ///
+    /// Sort the fields of the input object types, such as `input UserFilter { ... }`,
+    /// and the fields of the input object values, such as `{ name: "Ada", age: 36 }`.
+    ///
+    /// Default: `true`
+    ///
+    pub UseSortedFields {
+        version: "next",
+        name: "useSortedFields",
+        language: "graphql",
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseSortedFieldsOptions {
+    /// Sort the fields of the selection sets
+    pub selections: bool,
+    /// Sort the arguments passed to the fields and to the directives
+    pub arguments: bool,
+    /// Sort the fields of the input object types and of the input object values
+    pub input_fields: bool,
+}
+
+impl Default for UseSortedFieldsOptions {
+    fn default() -> Self {
+        Self {
+            selections: true,
+            arguments: true,
+            input_fields: true,
+        }
+    }
+}
+
+declare_node_union! {
+    pub AnyGraphqlSortableList =
+        GraphqlSelectionList
+        | GraphqlArgumentList
+        | GraphqlInputFieldList
+        | GraphqlObjectValueMemberList
+}
+
+pub enum SortableList {
+    Selections(SortableElements<AnyGraphqlSelection>),
+    Arguments(SortableElements<GraphqlArgument>),
+    InputFields(SortableElements<GraphqlInputValueDefinition>),
+    ObjectFields(SortableElements<GraphqlObjectField>),
+}
+
+impl Rule for UseSortedFields {
+    type Query = Ast<AnyGraphqlSortableList>;
+    type State = SortableList;
+    type Signals = Option<Self::State>;
+    type Options = UseSortedFieldsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let options = ctx.options();
+        let (state, is_sorted) = match ctx.query() {
+            AnyGraphqlSortableList::GraphqlSelectionList(list) if options.selections => {
+                let elements = SortableElements::from_list(list, |selection| match selection {
+                    AnyGraphqlSelection::GraphqlField(field) => match field.alias() {
+                        Some(alias) => Some(alias.value().ok()?.text()),
+                        None => Some(field.name().ok()?.text()),
+                    },
+                    _ => None,
+                })?;
+                let is_sorted = elements.is_sorted();
+                (SortableList::Selections(elements), is_sorted)
+            }
+            AnyGraphqlSortableList::GraphqlArgumentList(list) if options.arguments => {
+                let elements = SortableElements::from_list(list, |argument| {
+                    Some(argument.name().ok()?.text())
+                })?;
+                let is_sorted = elements.is_sorted();
+                (SortableList::Arguments(elements), is_sorted)
+            }
+            AnyGraphqlSortableList::GraphqlInputFieldList(list) if options.input_fields => {
+                let elements =
+                    SortableElements::from_list(list, |field| Some(field.name().ok()?.text()))?;
+                let is_sorted = elements.is_sorted();
+                (SortableList::InputFields(elements), is_sorted)
+            }
+            AnyGraphqlSortableList::GraphqlObjectValueMemberList(list) if options.input_fields => {
+                let elements =
+                    SortableElements::from_list(list, |field| Some(field.name().ok()?.text()))?;
+                let is_sorted = elements.is_sorted();
+                (SortableList::ObjectFields(elements), is_sorted)
+            }
+            _ => return None,
+        };
+
+        (!is_sorted).then_some(state)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<GraphqlRuleAction> {
+        let (list, message) = match state {
+            SortableList::Selections(elements) => (
+                make::graphql_selection_list(elements.to_sorted_elements()?).into_syntax(),
+                markup! { "The fields of the current selection set can be sorted." },
+            ),
+            SortableList::Arguments(elements) => (
+                make::graphql_argument_list(elements.to_sorted_elements()?).into_syntax(),
+                markup! { "The current arguments can be sorted." },
+            ),
+            SortableList::InputFields(elements) => (
+                make::graphql_input_field_list(elements.to_sorted_elements()?).into_syntax(),
+                markup! { "The fields of the current input object can be sorted." },
+            ),
+            SortableList::ObjectFields(elements) => (
+                make::graphql_object_value_member_list(elements.to_sorted_elements()?)
+                    .into_syntax(),
+                markup! { "The fields of the current input object can be sorted." },
+            ),
+        };
+
+        // The sorted elements already carry the trivia that surrounds the list
+        let mut mutation = ctx.root().begin();
+        mutation.replace_element_discard_trivia(ctx.query().syntax().clone().into(), list.into());
+
+        Some(RuleAction::new(
+            rule_action_category!(),
+            Applicability::Always,
+            message,
+            mutation,
+        ))
+    }
+}
@@ -1,19 +1,23 @@
+mod assists;
 mod lint;
 pub mod options;
 mod registry;
+mod sort;
 mod suppression_action;
 
 pub use crate::registry::visit_registry;
 use crate::suppression_action::GraphqlSuppressionAction;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleAction, RuleRegistry, SuppressionKind,
+    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, AnalyzerSuppression, ControlFlow,
+    LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry, SuppressionKind,
 };
 use biome_diagnostics::{category, Error};
+use biome_graphql_schema::GraphqlSchema;
+use biome_graphql_semantic::GraphqlProjectIndex;
 use biome_graphql_syntax::GraphqlLanguage;
 use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic};
 use std::ops::Deref;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 
 pub(crate) type GraphqlRuleAction = RuleAction<GraphqlLanguage>;
 
@@ -25,18 +29,24 @@ pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
-/// then call `emit_signal` when an analysis rule emits a diagnostic or action
+/// then call `emit_signal` when an analysis rule emits a diagnostic or action.
+/// The `schema` is the schema configured for the project, if any: the rules that
+/// validate the operations against it don't report anything without it.
+/// The `project` index gives rules access to the symbols defined in the other
+/// documents of the project.
 pub fn analyze<'a, F, B>(
     root: &LanguageRoot<GraphqlLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
+    schema: Option<Arc<GraphqlSchema>>,
+    project: Arc<GraphqlProjectIndex>,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<GraphqlLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, emit_signal)
+    analyze_with_inspect_matcher(root, filter, |_| {}, options, schema, project, emit_signal)
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -50,6 +60,8 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    schema: Option<Arc<GraphqlSchema>>,
+    project: Arc<GraphqlProjectIndex>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -59,37 +71,42 @@ where
 {
     fn parse_linter_suppression_comment(
         text: &str,
-    ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
+    ) -> Vec<Result<AnalyzerSuppression, SuppressionDiagnostic>> {
         let mut result = Vec::new();
 
         for comment in parse_suppression_comment(text) {
-            let categories = match comment {
-                Ok(comment) => {
-                    if comment.is_legacy {
-                        result.push(Ok(SuppressionKind::Deprecated));
-                    }
-                    comment.categories
-                }
+            let comment = match comment {
+                Ok(comment) => comment,
                 Err(err) => {
                     result.push(Err(err));
                     continue;
                 }
             };
 
-            for (key, value) in categories {
+            let mut kinds = Vec::new();
+            if comment.is_legacy {
+                kinds.push(SuppressionKind::Deprecated);
+            }
+            for (key, value) in comment.categories {
                 if key == category!("lint") {
                     if let Some(value) = value {
-                        result.push(Ok(SuppressionKind::MaybeLegacy(value)));
+                        kinds.push(SuppressionKind::MaybeLegacy(value));
                     } else {
-                        result.push(Ok(SuppressionKind::Everything));
+                        kinds.push(SuppressionKind::Everything);
                     }
                 } else {
                     let category = key.name();
                     if let Some(rule) = category.strip_prefix("lint/") {
-                        result.push(Ok(SuppressionKind::Rule(rule)));
+                        kinds.push(SuppressionKind::Rule(rule));
                     }
                 }
             }
+
+            result.push(Ok(AnalyzerSuppression {
+                kinds,
+                reason: comment.reason,
+                until: comment.until,
+            }));
         }
 
         result
@@ -98,18 +115,21 @@ where
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
-    let (registry, services, diagnostics, visitors) = registry.build();
+    let (registry, mut services, diagnostics, visitors) = registry.build();
 
     // Bail if we can't parse a rule option
     if !diagnostics.is_empty() {
         return (None, diagnostics);
     }
 
+    services.insert_service(schema);
+    services.insert_service(project);
+
     let mut analyzer = biome_analyze::Analyzer::new(
         METADATA.deref(),
         biome_analyze::InspectMatcher::new(registry, inspect_matcher),
         parse_linter_suppression_comment,
-        Box::new(GraphqlSuppressionAction),
+        Some(Box::new(GraphqlSuppressionAction)),
         &mut emit_signal,
     );
 
@@ -166,6 +186,8 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
+            None,
+            Arc::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -2,17 +2,37 @@
 
 use biome_analyze::declare_lint_group;
 
+pub mod no_deprecated_fields;
 pub mod no_duplicated_fields;
+pub mod no_excessive_query_depth;
+pub mod no_mismatched_variable_types;
+pub mod no_undefined_fragment_spreads;
+pub mod no_unknown_arguments;
+pub mod no_unknown_fields;
+pub mod no_unused_fragments;
 pub mod use_deprecated_reason;
 pub mod use_named_operation;
+pub mod use_operation_name_convention;
+pub mod use_required_arguments;
+pub mod use_required_id_selection;
 
 declare_lint_group! {
     pub Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_deprecated_fields :: NoDeprecatedFields ,
             self :: no_duplicated_fields :: NoDuplicatedFields ,
+            self :: no_excessive_query_depth :: NoExcessiveQueryDepth ,
+            self :: no_mismatched_variable_types :: NoMismatchedVariableTypes ,
+            self :: no_undefined_fragment_spreads :: NoUndefinedFragmentSpreads ,
+            self :: no_unknown_arguments :: NoUnknownArguments ,
+            self :: no_unknown_fields :: NoUnknownFields ,
+            self :: no_unused_fragments :: NoUnusedFragments ,
             self :: use_deprecated_reason :: UseDeprecatedReason ,
             self :: use_named_operation :: UseNamedOperation ,
+            self :: use_operation_name_convention :: UseOperationNameConvention ,
+            self :: use_required_arguments :: UseRequiredArguments ,
+            self :: use_required_id_selection :: UseRequiredIdSelection ,
         ]
      }
 }
@@ -0,0 +1,167 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_graphql_schema::GraphqlSchema;
+use biome_graphql_syntax::{AnyGraphqlValue, GraphqlEnumValue, GraphqlField};
+use biome_rowan::{declare_node_union, AstNode, TextRange};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Disallow selecting deprecated fields and using deprecated enum values.
+    ///
+    /// The schema marks the fields and the enum values that are going to be removed with the `@deprecated` directive,
+    /// which usually gives the reason of the deprecation, and what to use instead.
+    /// The diagnostics of this rule include this reason.
+    ///
+    /// The rule validates the operations against the schema configured with `graphql.linter.schema`,
+    /// and doesn't report anything if no schema is configured.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query { user: User }
+    /// type User { name: String, login: String @deprecated(reason: "Use `name` instead.") }
+    ///
+    /// query { user { login } }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query { users(role: Role): [User] }
+    /// type User { name: String }
+    /// enum Role { ADMIN, MEMBER @deprecated, USER }
+    ///
+    /// query { users(role: MEMBER) { name } }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// type Query { user: User }
+    /// type User { name: String, login: String @deprecated(reason: "Use `name` instead.") }
+    ///
+    /// query { user { name } }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `ignore`
+    ///
+    /// The coordinates of the fields and the enum values that can be used even though they're deprecated,
+    /// such as `User.login` or `Role.MEMBER`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "ignore": ["User.login"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,use_options
+    /// type Query { user: User }
+    /// type User { name: String, login: String @deprecated(reason: "Use `name` instead.") }
+    ///
+    /// query { user { login } }
+    /// ```
+    ///
+    pub NoDeprecatedFields {
+        version: "next",
+        name: "noDeprecatedFields",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("no-deprecated")],
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyGraphqlDeprecatable = GraphqlField | GraphqlEnumValue
+}
+
+#[derive(Clone, Debug, Default, Deserializable, Deserialize, Serialize, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoDeprecatedFieldsOptions {
+    /// The coordinates of the fields and the enum values that can be used even though they're deprecated, such as `User.login` or `Role.MEMBER`
+    pub ignore: Box<[Box<str>]>,
+}
+
+pub struct DeprecatedUsage {
+    range: TextRange,
+    /// The coordinate of the field or the enum value, such as `User.login`
+    coordinate: String,
+    reason: String,
+}
+
+impl Rule for NoDeprecatedFields {
+    type Query = Ast<AnyGraphqlDeprecatable>;
+    type State = DeprecatedUsage;
+    type Signals = Option<Self::State>;
+    type Options = Box<NoDeprecatedFieldsOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let schema = ctx
+            .get_service::<Option<Arc<GraphqlSchema>>>()?
+            .as_deref()?;
+        let usage = match ctx.query() {
+            AnyGraphqlDeprecatable::GraphqlField(field) => {
+                let name = field.name().ok()?;
+                let parent = schema.parent_type(field)?;
+                let definition = parent.field(&name.text())?;
+                DeprecatedUsage {
+                    range: name.range(),
+                    coordinate: format!("{}.{}", parent.name(), definition.name()),
+                    reason: definition.deprecation_reason()?.to_string(),
+                }
+            }
+            AnyGraphqlDeprecatable::GraphqlEnumValue(value) => {
+                let ty = schema
+                    .value_type(&AnyGraphqlValue::GraphqlEnumValue(value.clone()))?
+                    .named_type();
+                let definition = schema.get_type(ty)?.enum_value(&value.text())?;
+                DeprecatedUsage {
+                    range: value.range(),
+                    coordinate: format!("{ty}.{}", definition.name()),
+                    reason: definition.deprecation_reason()?.to_string(),
+                }
+            }
+        };
+
+        let is_ignored = ctx
+            .options()
+            .ignore
+            .iter()
+            .any(|coordinate| coordinate.as_ref() == usage.coordinate);
+        (!is_ignored).then_some(usage)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let DeprecatedUsage {
+            range,
+            coordinate,
+            reason,
+        } = state;
+        let kind = match ctx.query() {
+            AnyGraphqlDeprecatable::GraphqlField(_) => "field",
+            AnyGraphqlDeprecatable::GraphqlEnumValue(_) => "enum value",
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "The "{kind}" "<Emphasis>{coordinate}</Emphasis>" is deprecated."
+                },
+            )
+            .note(markup! {
+                "The schema gives the following reason: "{reason}
+            }),
+        )
+    }
+}
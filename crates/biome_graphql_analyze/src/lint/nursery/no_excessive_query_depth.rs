@@ -0,0 +1,355 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_graphql_schema::GraphqlSchema;
+use biome_graphql_syntax::{
+    AnyGraphqlDefinition, AnyGraphqlSelection, GraphqlField, GraphqlFragmentDefinition,
+    GraphqlRoot, GraphqlSelectionSet,
+};
+use biome_rowan::{AstNode, TextRange};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Disallow operations that select fields too deeply, or whose estimated cost is too high.
+    ///
+    /// Deeply nested operations, such as `{ user { friends { friends { friends { name } } } } }`,
+    /// can make the server resolve a huge number of objects.
+    /// This rule reports the operations whose fields are nested more than `maxDepth` levels deep,
+    /// so that such operations are caught at lint time rather than in production.
+    ///
+    /// The fields of the fragments spread by an operation count as if they were written in place,
+    /// if the fragments are defined in the same document.
+    /// Inline fragments and fragment spreads don't add a level.
+    ///
+    /// The rule can also estimate the cost of an operation when the `maxCost` option is set.
+    /// Each field costs `1`, and the fields selected under a list field cost `listFactor` times more,
+    /// because the server resolves them once per item of the list.
+    /// The list fields are known from the schema configured with `graphql.linter.schema`;
+    /// without schema, all the fields cost `1`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "maxDepth": 3
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic,use_options
+    /// query Friends {
+    ///   user {
+    ///     friends {
+    ///       friends {
+    ///         name
+    ///       }
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql,use_options
+    /// query Friends {
+    ///   user {
+    ///     friends {
+    ///       name
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `maxDepth`
+    ///
+    /// The maximum depth of the fields selected by an operation.
+    /// The fields selected at the root of an operation are at depth `1`.
+    /// Default to `10`.
+    ///
+    /// ### `maxCost`
+    ///
+    /// The maximum estimated cost of an operation.
+    /// The cost isn't checked if it's not set, which is the default.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "maxCost": 20
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic,use_options
+    /// type Query { users: [User] }
+    /// type User { name: String, friends: [User] }
+    ///
+    /// # The friends of each user cost 10 times more: 1 + 10 * (1 + 10 * 1) = 111
+    /// query Users { users { friends { name } } }
+    /// ```
+    ///
+    /// ### `listFactor`
+    ///
+    /// How many times more the fields selected under a list field cost than the other fields.
+    /// Default to `10`.
+    ///
+    pub NoExcessiveQueryDepth {
+        version: "next",
+        name: "noExcessiveQueryDepth",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("selection-set-depth")],
+        recommended: false,
+    }
+}
+
+#[derive(Clone, Debug, Deserializable, Deserialize, Serialize, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoExcessiveQueryDepthOptions {
+    /// The maximum depth of the fields selected by an operation
+    pub max_depth: u16,
+    /// The maximum estimated cost of an operation. The cost isn't checked if it's not set
+    pub max_cost: Option<u32>,
+    /// How many times more the fields selected under a list field cost than the other fields
+    pub list_factor: u16,
+}
+
+impl Default for NoExcessiveQueryDepthOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 10,
+            max_cost: None,
+            list_factor: 10,
+        }
+    }
+}
+
+pub struct ExcessiveOperation {
+    /// The range of the name of the operation, or of its type if it's anonymous
+    range: TextRange,
+    kind: ExcessiveOperationKind,
+}
+
+pub enum ExcessiveOperationKind {
+    /// The operation selects fields at the given depth, the deepest being at the given range
+    Depth(u16, TextRange),
+    /// The operation has the given estimated cost
+    Cost(u64),
+}
+
+impl Rule for NoExcessiveQueryDepth {
+    type Query = Ast<GraphqlRoot>;
+    type State = ExcessiveOperation;
+    type Signals = Box<[Self::State]>;
+    type Options = Box<NoExcessiveQueryDepthOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        let root = ctx.query();
+        let fragments: HashMap<_, _> = root
+            .definitions()
+            .into_iter()
+            .filter_map(|definition| match definition {
+                AnyGraphqlDefinition::GraphqlFragmentDefinition(fragment) => {
+                    Some((fragment.name().ok()?.text(), fragment))
+                }
+                _ => None,
+            })
+            .collect();
+        let measure = Measure {
+            fragments: &fragments,
+            schema: ctx
+                .get_service::<Option<Arc<GraphqlSchema>>>()
+                .and_then(|schema| schema.as_deref()),
+            list_factor: u64::from(options.list_factor),
+        };
+
+        let mut signals = Vec::new();
+        for definition in root.definitions() {
+            let (range, selection_set) = match definition {
+                AnyGraphqlDefinition::GraphqlOperationDefinition(operation) => {
+                    let Ok(selection_set) = operation.selection_set() else {
+                        continue;
+                    };
+                    let range = match operation.name() {
+                        Some(name) => name.range(),
+                        None => match operation.ty() {
+                            Ok(ty) => ty.range(),
+                            Err(_) => continue,
+                        },
+                    };
+                    (range, selection_set)
+                }
+                // The shorthand `{ ... }` is an anonymous query
+                AnyGraphqlDefinition::GraphqlSelectionSet(selection_set) => {
+                    let Ok(l_curly) = selection_set.l_curly_token() else {
+                        continue;
+                    };
+                    (l_curly.text_trimmed_range(), selection_set)
+                }
+                _ => continue,
+            };
+
+            let measurement = measure.selection_set(&selection_set, 0, &mut Vec::new());
+            if let Some((depth, deepest)) = measurement.deepest {
+                if depth > options.max_depth {
+                    signals.push(ExcessiveOperation {
+                        range,
+                        kind: ExcessiveOperationKind::Depth(depth, deepest),
+                    });
+                }
+            }
+            if let Some(max_cost) = options.max_cost {
+                if measurement.cost > u64::from(max_cost) {
+                    signals.push(ExcessiveOperation {
+                        range,
+                        kind: ExcessiveOperationKind::Cost(measurement.cost),
+                    });
+                }
+            }
+        }
+
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let options = ctx.options();
+        let ExcessiveOperation { range, kind } = state;
+        let diagnostic = match kind {
+            ExcessiveOperationKind::Depth(depth, deepest) => {
+                let max_depth = options.max_depth;
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    *range,
+                    markup! {
+                        "This operation selects fields "{depth}" levels deep, which is more than the maximum of "{max_depth}"."
+                    },
+                )
+                .detail(
+                    *deepest,
+                    markup! {
+                        "The deepest field is here."
+                    },
+                )
+                .note(markup! {
+                    "Deeply nested operations can make the server resolve a huge number of objects. Split the operation, or select fewer nested fields."
+                })
+            }
+            ExcessiveOperationKind::Cost(cost) => {
+                let max_cost = options.max_cost.unwrap_or_default();
+                let list_factor = options.list_factor;
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    *range,
+                    markup! {
+                        "The estimated cost of this operation is "{cost}", which is more than the maximum of "{max_cost}"."
+                    },
+                )
+                .note(markup! {
+                    "Each field costs 1, and the fields selected under a list field cost "{list_factor}" times more."
+                })
+            }
+        };
+        Some(diagnostic)
+    }
+}
+
+struct Measure<'a> {
+    /// The fragments defined in the document, by name
+    fragments: &'a HashMap<String, GraphqlFragmentDefinition>,
+    schema: Option<&'a GraphqlSchema>,
+    list_factor: u64,
+}
+
+#[derive(Default)]
+struct Measurement {
+    /// The depth of the deepest field, and the range of its name
+    deepest: Option<(u16, TextRange)>,
+    cost: u64,
+}
+
+impl Measurement {
+    fn merge(&mut self, other: Self) {
+        if other.deepest.map(|(depth, _)| depth) > self.deepest.map(|(depth, _)| depth) {
+            self.deepest = other.deepest;
+        }
+        self.cost = self.cost.saturating_add(other.cost);
+    }
+}
+
+impl Measure<'_> {
+    /// Measures the fields of `selection_set`, whose parent field is at `depth`.
+    ///
+    /// `spreads` contains the fragments being measured, so that cyclic spreads are measured once.
+    fn selection_set(
+        &self,
+        selection_set: &GraphqlSelectionSet,
+        depth: u16,
+        spreads: &mut Vec<String>,
+    ) -> Measurement {
+        let mut measurement = Measurement::default();
+        for selection in selection_set.selections() {
+            match selection {
+                AnyGraphqlSelection::GraphqlField(field) => {
+                    let Ok(name) = field.name() else {
+                        continue;
+                    };
+                    let depth = depth.saturating_add(1);
+                    let mut field_measurement = Measurement {
+                        deepest: Some((depth, name.range())),
+                        cost: 1,
+                    };
+                    if let Some(selection_set) = field.selection_set() {
+                        let mut nested = self.selection_set(&selection_set, depth, spreads);
+                        if self.is_list_field(&field) {
+                            nested.cost = nested.cost.saturating_mul(self.list_factor);
+                        }
+                        field_measurement.merge(nested);
+                    }
+                    measurement.merge(field_measurement);
+                }
+                AnyGraphqlSelection::GraphqlFragmentSpread(spread) => {
+                    let Some(name) = spread.name().ok().map(|name| name.text()) else {
+                        continue;
+                    };
+                    if spreads.contains(&name) {
+                        continue;
+                    }
+                    let Some(selection_set) = self
+                        .fragments
+                        .get(&name)
+                        .and_then(|fragment| fragment.selection_set().ok())
+                    else {
+                        continue;
+                    };
+                    spreads.push(name);
+                    measurement.merge(self.selection_set(&selection_set, depth, spreads));
+                    spreads.pop();
+                }
+                AnyGraphqlSelection::GraphqlInlineFragment(fragment) => {
+                    if let Ok(selection_set) = fragment.selection_set() {
+                        measurement.merge(self.selection_set(&selection_set, depth, spreads));
+                    }
+                }
+                AnyGraphqlSelection::GraphqlBogusSelection(_) => {}
+            }
+        }
+        measurement
+    }
+
+    fn is_list_field(&self, field: &GraphqlField) -> bool {
+        self.schema
+            .and_then(|schema| schema.field_definition(field))
+            .is_some_and(|definition| definition.ty().is_list())
+    }
+}
@@ -0,0 +1,146 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_graphql_schema::{GraphqlSchema, TypeRef};
+use biome_graphql_syntax::{
+    AnyGraphqlValue, GraphqlArgument, GraphqlArgumentList, GraphqlArguments, GraphqlField,
+    GraphqlOperationDefinition, GraphqlVariableReference,
+};
+use biome_rowan::{AstNode, AstNodeList};
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Disallow using variables whose type doesn't match the argument they're passed to.
+    ///
+    /// A variable can be passed to an argument when the type of the variable matches the type of the argument.
+    /// A variable of a non-null type can be passed to an argument of the corresponding nullable type,
+    /// but a nullable variable can only be passed to a non-null argument if the variable or the argument has a default value.
+    ///
+    /// The rule validates the operations against the schema configured with `graphql.linter.schema`,
+    /// and doesn't report anything if no schema is configured.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query { user(id: ID!): User }
+    /// type User { name: String }
+    ///
+    /// query User($id: String!) { user(id: $id) { name } }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query { user(id: ID!): User }
+    /// type User { name: String }
+    ///
+    /// query User($id: ID) { user(id: $id) { name } }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// type Query { user(id: ID!): User }
+    /// type User { name: String }
+    ///
+    /// query User($id: ID = 1) { user(id: $id) { name } }
+    /// ```
+    ///
+    pub NoMismatchedVariableTypes {
+        version: "next",
+        name: "noMismatchedVariableTypes",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("variables-in-allowed-position")],
+        recommended: false,
+    }
+}
+
+pub struct MismatchedVariable {
+    variable_type: TypeRef,
+    argument_name: String,
+    argument_type: TypeRef,
+}
+
+impl Rule for NoMismatchedVariableTypes {
+    type Query = Ast<GraphqlVariableReference>;
+    type State = MismatchedVariable;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let reference = ctx.query();
+        let schema = ctx
+            .get_service::<Option<Arc<GraphqlSchema>>>()?
+            .as_deref()?;
+        // Only the variables passed directly to the arguments of a field are validated,
+        // not the ones nested in lists and input objects.
+        let argument = reference.parent::<GraphqlArgument>()?;
+        let field = argument
+            .parent::<GraphqlArgumentList>()?
+            .parent::<GraphqlArguments>()?
+            .parent::<GraphqlField>()?;
+        let argument_definition = schema
+            .field_definition(&field)?
+            .argument(&argument.name().ok()?.text())?;
+
+        // The variables used in fragments depend on the operations that spread them
+        let operation = reference
+            .syntax()
+            .ancestors()
+            .find_map(GraphqlOperationDefinition::cast)?;
+        let name = reference.name().ok()?.text();
+        let variable = operation.variables()?.elements().iter().find(|variable| {
+            variable
+                .variable()
+                .and_then(|binding| binding.name())
+                .is_ok_and(|binding_name| binding_name.text() == name)
+        })?;
+        let variable_type = TypeRef::from_syntax(&variable.ty().ok()?)?;
+
+        let argument_type = argument_definition.ty();
+        let location_type = match argument_type {
+            // A nullable variable can be used in a non-null location that provides a default value,
+            // see https://spec.graphql.org/October2021/#IsVariableUsageAllowed()
+            TypeRef::NonNull(nullable_type) if !variable_type.is_non_null() => {
+                let has_default = variable.default().is_some_and(|default| {
+                    !matches!(default.value(), Ok(AnyGraphqlValue::GraphqlNullValue(_)))
+                });
+                if has_default || argument_definition.has_default() {
+                    nullable_type.as_ref()
+                } else {
+                    argument_type
+                }
+            }
+            _ => argument_type,
+        };
+        if variable_type.is_compatible_with(location_type) {
+            return None;
+        }
+        Some(MismatchedVariable {
+            variable_type,
+            argument_name: argument_definition.name().to_string(),
+            argument_type: argument_type.clone(),
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let reference = ctx.query();
+        let name = reference.name().ok()?.text();
+        let variable_type = state.variable_type.to_string();
+        let argument_name = &state.argument_name;
+        let argument_type = state.argument_type.to_string();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                reference.range(),
+                markup! {
+                    "The variable "<Emphasis>"$"{name}</Emphasis>" of type "<Emphasis>{variable_type}</Emphasis>" can't be passed to the argument "<Emphasis>{argument_name}</Emphasis>" of type "<Emphasis>{argument_type}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Change the type of the variable to match the type of the argument."
+            }),
+        )
+    }
+}
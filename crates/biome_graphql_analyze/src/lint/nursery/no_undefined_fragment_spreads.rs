@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_graphql_semantic::{GraphqlFileIndex, GraphqlProjectIndex};
+use biome_graphql_syntax::{GraphqlFragmentSpread, GraphqlNameReference, GraphqlRoot};
+use biome_rowan::AstNode;
+
+declare_lint_rule! {
+    /// Disallow spreading fragments that aren't defined anywhere in the project.
+    ///
+    /// The server rejects the operations that spread an unknown fragment.
+    /// This usually happens after a fragment is renamed or removed, or because of a typo.
+    ///
+    /// Fragments are looked up in the current document and in all the GraphQL documents of the project
+    /// known to Biome, including the ones embedded in the template literals of JavaScript and TypeScript files.
+    /// Only the documents processed by Biome are taken into account, files that are ignored
+    /// or excluded from the command line are not.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query User { user { ...UserFields } }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// fragment UserFields on User { name }
+    ///
+    /// query User { user { ...UserField } }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// fragment UserFields on User { name }
+    ///
+    /// query User { user { ...UserFields } }
+    /// ```
+    ///
+    pub NoUndefinedFragmentSpreads {
+        version: "next",
+        name: "noUndefinedFragmentSpreads",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("known-fragment-names")],
+        recommended: false,
+    }
+}
+
+impl Rule for NoUndefinedFragmentSpreads {
+    type Query = Ast<GraphqlRoot>;
+    type State = GraphqlNameReference;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let file = GraphqlFileIndex::from_root(root);
+        let project = ctx.get_service::<Arc<GraphqlProjectIndex>>();
+
+        root.syntax()
+            .descendants()
+            .filter_map(GraphqlFragmentSpread::cast)
+            .filter_map(|spread| spread.name().ok())
+            .filter(|name| {
+                let name = name.text();
+                file.fragment_definitions(&name).next().is_none()
+                    && !project.is_some_and(|project| project.is_fragment_defined(&name))
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, node: &Self::State) -> Option<RuleDiagnostic> {
+        let name = node.text();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "The fragment "<Emphasis>{name}</Emphasis>" isn't defined anywhere in the project."
+                },
+            )
+            .note(markup! {
+                "The server rejects the operations that spread an unknown fragment."
+            })
+            .note(markup! {
+                "Define the fragment, or fix the name of the spread."
+            }),
+        )
+    }
+}
@@ -0,0 +1,90 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_graphql_schema::GraphqlSchema;
+use biome_graphql_syntax::{GraphqlArgument, GraphqlArgumentList, GraphqlArguments, GraphqlField};
+use biome_rowan::AstNode;
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Disallow passing arguments that aren't defined by the schema.
+    ///
+    /// The arguments passed to a field must be defined by the field in the schema.
+    ///
+    /// The rule validates the operations against the schema configured with `graphql.linter.schema`,
+    /// and doesn't report anything if no schema is configured.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query { user(id: ID!): User }
+    /// type User { name: String }
+    ///
+    /// query { user(id: 1, name: "Ana") { name } }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// type Query { user(id: ID!): User }
+    /// type User { name: String }
+    ///
+    /// query { user(id: 1) { name } }
+    /// ```
+    ///
+    pub NoUnknownArguments {
+        version: "next",
+        name: "noUnknownArguments",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("known-argument-names")],
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnknownArguments {
+    type Query = Ast<GraphqlArgument>;
+    /// The coordinate of the field, such as `User.friends`
+    type State = String;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let argument = ctx.query();
+        let schema = ctx
+            .get_service::<Option<Arc<GraphqlSchema>>>()?
+            .as_deref()?;
+        // The arguments of the directives aren't validated
+        let field = argument
+            .parent::<GraphqlArgumentList>()?
+            .parent::<GraphqlArguments>()?
+            .parent::<GraphqlField>()?;
+
+        let parent = schema.parent_type(&field)?;
+        let definition = parent.field(&field.name().ok()?.text())?;
+        let name = argument.name().ok()?.text();
+        if definition.argument(&name).is_some() {
+            return None;
+        }
+        Some(format!("{}.{}", parent.name(), definition.name()))
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, field: &Self::State) -> Option<RuleDiagnostic> {
+        let name = ctx.query().name().ok()?;
+        let argument_name = name.text();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                name.range(),
+                markup! {
+                    "The argument "<Emphasis>{argument_name}</Emphasis>" doesn't exist on the field "<Emphasis>{field}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Only the arguments defined by the schema can be passed to a field."
+            }),
+        )
+    }
+}
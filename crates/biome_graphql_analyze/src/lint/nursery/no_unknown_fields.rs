@@ -0,0 +1,102 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_graphql_schema::{GraphqlSchema, SchemaTypeKind};
+use biome_graphql_syntax::GraphqlField;
+use biome_rowan::AstNode;
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Disallow selecting fields that aren't defined by the schema.
+    ///
+    /// The fields of an operation must be defined by the type they're selected on.
+    /// The fields of a union can't be selected directly, only through an inline fragment on one of its members.
+    /// The meta-fields, such as `__typename`, can be selected on any type.
+    ///
+    /// The rule validates the operations against the schema configured with `graphql.linter.schema`,
+    /// and doesn't report anything if no schema is configured.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query { user: User }
+    /// type User { name: String }
+    ///
+    /// query { user { email } }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// type Query { user: User }
+    /// type User { name: String }
+    ///
+    /// query { user { __typename name } }
+    /// ```
+    ///
+    pub NoUnknownFields {
+        version: "next",
+        name: "noUnknownFields",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("fields-on-correct-type")],
+        recommended: false,
+    }
+}
+
+pub struct UnknownField {
+    type_name: String,
+    type_kind: SchemaTypeKind,
+}
+
+impl Rule for NoUnknownFields {
+    type Query = Ast<GraphqlField>;
+    type State = UnknownField;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let field = ctx.query();
+        let schema = ctx
+            .get_service::<Option<Arc<GraphqlSchema>>>()?
+            .as_deref()?;
+        let name = field.name().ok()?.text();
+        if name.starts_with("__") {
+            return None;
+        }
+
+        let parent = schema.parent_type(field)?;
+        if !parent.is_composite() || parent.field(&name).is_some() {
+            return None;
+        }
+        Some(UnknownField {
+            type_name: parent.name().to_string(),
+            type_kind: parent.kind(),
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = ctx.query().name().ok()?;
+        let field_name = name.text();
+        let type_name = &state.type_name;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            name.range(),
+            markup! {
+                "The field "<Emphasis>{field_name}</Emphasis>" doesn't exist on the type "<Emphasis>{type_name}</Emphasis>"."
+            },
+        );
+        let diagnostic = if state.type_kind == SchemaTypeKind::Union {
+            diagnostic.note(markup! {
+                "The fields of a union can only be selected through an inline fragment on one of its members, such as "<Emphasis>"... on Member"</Emphasis>"."
+            })
+        } else {
+            diagnostic.note(markup! {
+                "Only the fields defined by the schema can be selected."
+            })
+        };
+        Some(diagnostic)
+    }
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_graphql_semantic::{GraphqlFileIndex, GraphqlProjectIndex};
+use biome_graphql_syntax::{AnyGraphqlDefinition, GraphqlNameBinding, GraphqlRoot};
+use biome_rowan::AstNode;
+
+declare_lint_rule! {
+    /// Disallow fragments that aren't spread anywhere in the project.
+    ///
+    /// A fragment that isn't spread is never sent to the server.
+    /// It's usually the leftover of an operation that was changed or removed.
+    ///
+    /// Spreads are looked up in the current document and in all the GraphQL documents of the project
+    /// known to Biome, including the ones embedded in the template literals of JavaScript and TypeScript files.
+    /// Only the documents processed by Biome are taken into account, files that are ignored
+    /// or excluded from the command line are not.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// fragment UserFields on User { name }
+    ///
+    /// query User { user { name } }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// fragment UserFields on User { name }
+    ///
+    /// query User { user { ...UserFields } }
+    /// ```
+    ///
+    pub NoUnusedFragments {
+        version: "next",
+        name: "noUnusedFragments",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("no-unused-fragments")],
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnusedFragments {
+    type Query = Ast<GraphqlRoot>;
+    type State = GraphqlNameBinding;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let file = GraphqlFileIndex::from_root(root);
+        let project = ctx.get_service::<Arc<GraphqlProjectIndex>>();
+
+        root.definitions()
+            .into_iter()
+            .filter_map(|definition| match definition {
+                AnyGraphqlDefinition::GraphqlFragmentDefinition(fragment) => fragment.name().ok(),
+                _ => None,
+            })
+            .filter(|name| {
+                let name = name.text();
+                file.fragment_spreads(&name).next().is_none()
+                    && !project.is_some_and(|project| project.is_fragment_spread(&name))
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, node: &Self::State) -> Option<RuleDiagnostic> {
+        let name = node.text();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "The fragment "<Emphasis>{name}</Emphasis>" isn't spread anywhere in the project."
+                },
+            )
+            .note(markup! {
+                "Remove the fragment, or spread it in an operation."
+            }),
+        )
+    }
+}
@@ -0,0 +1,306 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_glob::{CandidatePath, Glob};
+use biome_graphql_semantic::GraphqlProjectIndex;
+use biome_graphql_syntax::{AnyGraphqlDefinition, GraphqlRoot};
+use biome_rowan::{AstNode, TextRange};
+use biome_string_case::Case;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Enforce a naming convention for the operations.
+    ///
+    /// Most GraphQL clients and servers identify the operations by their name,
+    /// for instance to cache their results or to report their usage.
+    /// This rule reports the operations that:
+    ///
+    /// - don't have a name, unless they're in one of the files of the `allowAnonymousIn` option;
+    /// - have the same name as another operation of the document or of the project;
+    /// - don't follow the case of the `format` option;
+    /// - don't end with their type when the `requireTypeSuffix` option is enabled, such as `Query` for a query.
+    ///
+    /// The names of the operations are compared to the ones of the other GraphQL documents of the project.
+    /// Only the documents processed by Biome are taken into account, files that are ignored
+    /// or excluded from the command line are not.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query { user { name } }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query User { user { name } }
+    /// query User { viewer { name } }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query current_user { viewer { name } }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query User { user { name } }
+    /// query Viewer { viewer { name } }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `format`
+    ///
+    /// The case of the names of the operations: `PascalCase` or `camelCase`.
+    /// Default to `PascalCase`.
+    ///
+    /// ### `requireTypeSuffix`
+    ///
+    /// If `true`, the names of the operations must end with their type:
+    /// `Query`, `Mutation` or `Subscription`.
+    /// Default to `false`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "requireTypeSuffix": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic,use_options
+    /// mutation UpdateUser { updateUser { name } }
+    /// ```
+    ///
+    /// ```graphql,use_options
+    /// mutation UpdateUserMutation { updateUser { name } }
+    /// ```
+    ///
+    /// ### `allowAnonymousIn`
+    ///
+    /// The globs of the files where the operations can be anonymous, such as the files that are only used by tests.
+    /// Default to `[]`.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "allowAnonymousIn": ["**/*.test.graphql"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseOperationNameConvention {
+        version: "next",
+        name: "useOperationNameConvention",
+        language: "graphql",
+        sources: &[
+            RuleSource::EslintGraphql("naming-convention"),
+            RuleSource::EslintGraphql("unique-operation-name"),
+        ],
+        recommended: false,
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserializable, Deserialize, Serialize, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseOperationNameConventionOptions {
+    /// The case of the names of the operations
+    pub format: OperationNameFormat,
+    /// If `true`, the names of the operations must end with their type, such as `Query`
+    pub require_type_suffix: bool,
+    /// The globs of the files where the operations can be anonymous
+    pub allow_anonymous_in: Box<[Glob]>,
+}
+
+/// Supported cases for the names of the operations.
+#[derive(
+    Clone, Copy, Debug, Default, Deserializable, Deserialize, Serialize, Eq, Hash, PartialEq,
+)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum OperationNameFormat {
+    /// camelCase
+    #[serde(rename = "camelCase")]
+    Camel,
+
+    /// PascalCase
+    #[serde(rename = "PascalCase")]
+    #[default]
+    Pascal,
+}
+
+impl From<OperationNameFormat> for Case {
+    fn from(value: OperationNameFormat) -> Self {
+        match value {
+            OperationNameFormat::Camel => Case::Camel,
+            OperationNameFormat::Pascal => Case::Pascal,
+        }
+    }
+}
+
+pub struct InvalidOperationName {
+    range: TextRange,
+    kind: InvalidOperationNameKind,
+}
+
+pub enum InvalidOperationNameKind {
+    /// The operation doesn't have a name
+    Anonymous,
+    /// The name is already used by the operation at the given range of the document
+    DuplicatedInDocument(TextRange),
+    /// The name is already used by an operation of the document at the given path
+    DuplicatedInProject(PathBuf),
+    /// The name isn't in the case of the `format` option
+    Case,
+    /// The name doesn't end with the given type
+    MissingTypeSuffix(String),
+}
+
+impl Rule for UseOperationNameConvention {
+    type Query = Ast<GraphqlRoot>;
+    type State = InvalidOperationName;
+    type Signals = Box<[Self::State]>;
+    type Options = Box<UseOperationNameConventionOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        let project = ctx.get_service::<Arc<GraphqlProjectIndex>>();
+        let file_path = ctx.file_path();
+        let allows_anonymous = CandidatePath::new(&file_path)
+            .matches_with_exceptions(options.allow_anonymous_in.iter());
+
+        let mut signals = Vec::new();
+        let mut names = HashMap::new();
+        for definition in ctx.query().definitions() {
+            let operation = match definition {
+                AnyGraphqlDefinition::GraphqlOperationDefinition(operation) => operation,
+                // The shorthand `{ ... }` is an anonymous query
+                AnyGraphqlDefinition::GraphqlSelectionSet(selection_set) => {
+                    if let Ok(l_curly) = selection_set.l_curly_token() {
+                        if !allows_anonymous {
+                            signals.push(InvalidOperationName {
+                                range: l_curly.text_trimmed_range(),
+                                kind: InvalidOperationNameKind::Anonymous,
+                            });
+                        }
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            let Ok(ty) = operation.ty() else {
+                continue;
+            };
+            let Some(name) = operation.name() else {
+                if !allows_anonymous {
+                    signals.push(InvalidOperationName {
+                        range: ty.range(),
+                        kind: InvalidOperationNameKind::Anonymous,
+                    });
+                }
+                continue;
+            };
+
+            let range = name.range();
+            let name = name.text();
+            let kind = if let Some(first_range) = names.get(&name) {
+                InvalidOperationNameKind::DuplicatedInDocument(*first_range)
+            } else if let Some((path, _)) = project.and_then(|project| {
+                project
+                    .operation_definitions(&name)
+                    .find(|(path, _)| *path != file_path)
+            }) {
+                InvalidOperationNameKind::DuplicatedInProject(path.to_path_buf())
+            } else if !(Case::from(options.format) | Case::Uni)
+                .contains(Case::identify(&name, false))
+            {
+                InvalidOperationNameKind::Case
+            } else {
+                let suffix = Case::Pascal.convert(&ty.text());
+                if !options.require_type_suffix || name.ends_with(&suffix) {
+                    names.insert(name, range);
+                    continue;
+                }
+                InvalidOperationNameKind::MissingTypeSuffix(suffix)
+            };
+            names.entry(name).or_insert(range);
+            signals.push(InvalidOperationName { range, kind });
+        }
+
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let InvalidOperationName { range, kind } = state;
+        let diagnostic = match kind {
+            InvalidOperationNameKind::Anonymous => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "Anonymous operations aren't allowed in this file."
+                },
+            )
+            .note(markup! {
+                "Name the operation, or add the file to the "<Emphasis>"allowAnonymousIn"</Emphasis>" option."
+            }),
+            InvalidOperationNameKind::DuplicatedInDocument(first_range) => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "The name of this operation is already used by another operation."
+                },
+            )
+            .detail(
+                *first_range,
+                markup! {
+                    "The name is first used here."
+                },
+            )
+            .note(markup! {
+                "The names of the operations must be unique across the project."
+            }),
+            InvalidOperationNameKind::DuplicatedInProject(path) => {
+                let path = path.display().to_string();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    *range,
+                    markup! {
+                        "The name of this operation is already used by an operation of "<Emphasis>{path}</Emphasis>"."
+                    },
+                )
+                .note(markup! {
+                    "The names of the operations must be unique across the project."
+                })
+            }
+            InvalidOperationNameKind::Case => {
+                let case = Case::from(ctx.options().format).to_string();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    *range,
+                    markup! {
+                        "The name of this operation isn't in "<Emphasis>{case}</Emphasis>"."
+                    },
+                )
+            }
+            InvalidOperationNameKind::MissingTypeSuffix(suffix) => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "The name of this operation doesn't end with "<Emphasis>{suffix}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The names of the operations must end with their type because "<Emphasis>"requireTypeSuffix"</Emphasis>" is enabled."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
@@ -0,0 +1,111 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_graphql_schema::GraphqlSchema;
+use biome_graphql_syntax::GraphqlField;
+use biome_rowan::{AstNode, AstNodeList};
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Require the arguments that the schema marks as required.
+    ///
+    /// An argument is required when its type is non-null, such as `ID!`, and it doesn't have a default value.
+    ///
+    /// The rule validates the operations against the schema configured with `graphql.linter.schema`,
+    /// and doesn't report anything if no schema is configured.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query { user(id: ID!): User }
+    /// type User { name: String }
+    ///
+    /// query { user { name } }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// type Query { users(first: Int! = 10): [User] }
+    /// type User { name: String }
+    ///
+    /// query { users { name } }
+    /// ```
+    ///
+    pub UseRequiredArguments {
+        version: "next",
+        name: "useRequiredArguments",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("provided-required-arguments")],
+        recommended: false,
+    }
+}
+
+pub struct MissingArgument {
+    name: String,
+    ty: String,
+}
+
+impl Rule for UseRequiredArguments {
+    type Query = Ast<GraphqlField>;
+    type State = MissingArgument;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let field = ctx.query();
+        let Some(definition) = ctx
+            .get_service::<Option<Arc<GraphqlSchema>>>()
+            .and_then(|schema| schema.as_deref())
+            .and_then(|schema| schema.field_definition(field))
+        else {
+            return Box::default();
+        };
+
+        let provided: Vec<_> = field
+            .arguments()
+            .map(|arguments| {
+                arguments
+                    .arguments()
+                    .iter()
+                    .filter_map(|argument| Some(argument.name().ok()?.text()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        definition
+            .arguments()
+            .iter()
+            .filter(|argument| {
+                argument.is_required() && !provided.iter().any(|name| name == argument.name())
+            })
+            .map(|argument| MissingArgument {
+                name: argument.name().to_string(),
+                ty: argument.ty().to_string(),
+            })
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = ctx.query().name().ok()?;
+        let field_name = name.text();
+        let MissingArgument {
+            name: argument_name,
+            ty,
+        } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                name.range(),
+                markup! {
+                    "The field "<Emphasis>{field_name}</Emphasis>" is missing the required argument "<Emphasis>{argument_name}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The argument has the non-null type "<Emphasis>{ty}</Emphasis>" and no default value, so it must be provided."
+            }),
+        )
+    }
+}
@@ -0,0 +1,238 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_graphql_schema::GraphqlSchema;
+use biome_graphql_syntax::{
+    AnyGraphqlDefinition, AnyGraphqlSelection, GraphqlField, GraphqlFragmentDefinition,
+    GraphqlRoot, GraphqlSelectionSet,
+};
+use biome_rowan::{AstNode, TextRange};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Require selecting the `id` field of the objects that have one.
+    ///
+    /// Normalized caches, such as the ones of Apollo Client and Relay, identify the objects by their `id`
+    /// to merge the results of the different operations.
+    /// An object selected without its `id` can't be normalized, and the data displayed by the application
+    /// can get out of sync.
+    ///
+    /// This rule reports the fields whose type has an `id` field, but whose selection set doesn't select it.
+    /// The `id` can be selected directly, or in a fragment on the same type, including the inline fragments
+    /// and the fragments defined in the same document.
+    /// An aliased `id`, such as `userId: id`, doesn't count, because the caches look for the `id` key.
+    ///
+    /// The rule validates the operations against the schema configured with `graphql.linter.schema`,
+    /// and doesn't report anything if no schema is configured.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query { user: User }
+    /// type User { id: ID!, name: String }
+    ///
+    /// query { user { name } }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// type Query { user: User }
+    /// type User { id: ID!, name: String }
+    ///
+    /// query { user { id name } }
+    /// ```
+    ///
+    /// ```graphql
+    /// type Query { user: User }
+    /// type User { id: ID!, name: String }
+    ///
+    /// query { user { ...UserFields } }
+    ///
+    /// fragment UserFields on User { id name }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `ignore`
+    ///
+    /// The types whose objects can be selected without their `id`, such as `PageInfo`.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "ignore": ["User"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,use_options
+    /// type Query { user: User }
+    /// type User { id: ID!, name: String }
+    ///
+    /// query { user { name } }
+    /// ```
+    ///
+    pub UseRequiredIdSelection {
+        version: "next",
+        name: "useRequiredIdSelection",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("require-selections")],
+        recommended: false,
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserializable, Deserialize, Serialize, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseRequiredIdSelectionOptions {
+    /// The types whose objects can be selected without their `id`, such as `PageInfo`
+    pub ignore: Box<[Box<str>]>,
+}
+
+pub struct MissingId {
+    range: TextRange,
+    /// The name of the type of the field
+    ty: String,
+}
+
+impl Rule for UseRequiredIdSelection {
+    type Query = Ast<GraphqlField>;
+    type State = MissingId;
+    type Signals = Option<Self::State>;
+    type Options = Box<UseRequiredIdSelectionOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let field = ctx.query();
+        let selection_set = field.selection_set()?;
+        let schema = ctx
+            .get_service::<Option<Arc<GraphqlSchema>>>()?
+            .as_deref()?;
+        let ty = schema.selection_set_type(&selection_set)?;
+        ty.field("id")?;
+
+        if ctx
+            .options()
+            .ignore
+            .iter()
+            .any(|ignored| ignored.as_ref() == ty.name())
+        {
+            return None;
+        }
+
+        let selects_id = selects_id(&selection_set, ty.name(), &ctx.root(), &mut Vec::new());
+        // The `id` may be selected by a fragment defined in another document
+        if selects_id != Some(false) {
+            return None;
+        }
+
+        Some(MissingId {
+            range: field.name().ok()?.range(),
+            ty: ty.name().to_string(),
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let MissingId { range, ty } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "This field selects a "<Emphasis>{ty}</Emphasis>" without its "<Emphasis>"id"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Normalized caches use the "<Emphasis>"id"</Emphasis>" to identify the objects, and can't merge the objects selected without it."
+            })
+            .note(markup! {
+                "Select the "<Emphasis>"id"</Emphasis>" field, or add "<Emphasis>{ty}</Emphasis>" to the "<Emphasis>"ignore"</Emphasis>" option if its objects aren't cached."
+            }),
+        )
+    }
+}
+
+/// Returns whether `selection_set` selects the `id` of the objects of type `ty`,
+/// or `None` if it spreads a fragment that isn't defined in the document.
+///
+/// `spreads` contains the fragments being visited, so that cyclic spreads are visited once.
+fn selects_id(
+    selection_set: &GraphqlSelectionSet,
+    ty: &str,
+    root: &GraphqlRoot,
+    spreads: &mut Vec<String>,
+) -> Option<bool> {
+    let mut is_unknown = false;
+    for selection in selection_set.selections() {
+        let selects = match selection {
+            AnyGraphqlSelection::GraphqlField(field) => {
+                Some(field.alias().is_none() && field.name().is_ok_and(|name| name.text() == "id"))
+            }
+            AnyGraphqlSelection::GraphqlInlineFragment(fragment) => {
+                let applies = fragment.type_condition().map_or(Some(true), |condition| {
+                    Some(condition.ty().ok()?.text() == ty)
+                });
+                match (applies, fragment.selection_set()) {
+                    (Some(true), Ok(selection_set)) => {
+                        selects_id(&selection_set, ty, root, spreads)
+                    }
+                    _ => Some(false),
+                }
+            }
+            AnyGraphqlSelection::GraphqlFragmentSpread(spread) => {
+                let name = spread.name().ok()?.text();
+                if spreads.contains(&name) {
+                    continue;
+                }
+                match find_fragment(root, &name) {
+                    Some(fragment) => {
+                        let applies = fragment
+                            .type_condition()
+                            .and_then(|condition| condition.ty())
+                            .is_ok_and(|condition| condition.text() == ty);
+                        match (applies, fragment.selection_set()) {
+                            (true, Ok(selection_set)) => {
+                                spreads.push(name);
+                                let selects = selects_id(&selection_set, ty, root, spreads);
+                                spreads.pop();
+                                selects
+                            }
+                            _ => Some(false),
+                        }
+                    }
+                    None => None,
+                }
+            }
+            AnyGraphqlSelection::GraphqlBogusSelection(_) => None,
+        };
+        match selects {
+            Some(true) => return Some(true),
+            Some(false) => {}
+            None => is_unknown = true,
+        }
+    }
+
+    (!is_unknown).then_some(false)
+}
+
+fn find_fragment(root: &GraphqlRoot, name: &str) -> Option<GraphqlFragmentDefinition> {
+    root.definitions()
+        .into_iter()
+        .find_map(|definition| match definition {
+            AnyGraphqlDefinition::GraphqlFragmentDefinition(fragment)
+                if fragment
+                    .name()
+                    .is_ok_and(|fragment_name| fragment_name.text() == name) =>
+            {
+                Some(fragment)
+            }
+            _ => None,
+        })
+}
@@ -1,10 +1,28 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
+use crate::assists;
 use crate::lint;
 
+pub type NoDeprecatedFields =
+    <lint::nursery::no_deprecated_fields::NoDeprecatedFields as biome_analyze::Rule>::Options;
 pub type NoDuplicatedFields =
     <lint::nursery::no_duplicated_fields::NoDuplicatedFields as biome_analyze::Rule>::Options;
+pub type NoExcessiveQueryDepth = < lint :: nursery :: no_excessive_query_depth :: NoExcessiveQueryDepth as biome_analyze :: Rule > :: Options ;
+pub type NoMismatchedVariableTypes = < lint :: nursery :: no_mismatched_variable_types :: NoMismatchedVariableTypes as biome_analyze :: Rule > :: Options ;
+pub type NoUndefinedFragmentSpreads = < lint :: nursery :: no_undefined_fragment_spreads :: NoUndefinedFragmentSpreads as biome_analyze :: Rule > :: Options ;
+pub type NoUnknownArguments =
+    <lint::nursery::no_unknown_arguments::NoUnknownArguments as biome_analyze::Rule>::Options;
+pub type NoUnknownFields =
+    <lint::nursery::no_unknown_fields::NoUnknownFields as biome_analyze::Rule>::Options;
+pub type NoUnusedFragments =
+    <lint::nursery::no_unused_fragments::NoUnusedFragments as biome_analyze::Rule>::Options;
 pub type UseDeprecatedReason =
     <lint::nursery::use_deprecated_reason::UseDeprecatedReason as biome_analyze::Rule>::Options;
 pub type UseNamedOperation =
     <lint::nursery::use_named_operation::UseNamedOperation as biome_analyze::Rule>::Options;
+pub type UseOperationNameConvention = < lint :: nursery :: use_operation_name_convention :: UseOperationNameConvention as biome_analyze :: Rule > :: Options ;
+pub type UseRequiredArguments =
+    <lint::nursery::use_required_arguments::UseRequiredArguments as biome_analyze::Rule>::Options;
+pub type UseRequiredIdSelection = < lint :: nursery :: use_required_id_selection :: UseRequiredIdSelection as biome_analyze :: Rule > :: Options ;
+pub type UseSortedFields =
+    <assists::source::use_sorted_fields::UseSortedFields as biome_analyze::Rule>::Options;
@@ -0,0 +1,218 @@
+use biome_graphql_syntax::GraphqlLanguage;
+use biome_rowan::{AstNode, AstNodeList, SyntaxTriviaPiece};
+use std::cmp::Ordering;
+
+type TriviaPieces = Vec<SyntaxTriviaPiece<GraphqlLanguage>>;
+
+/// An element of a list, along with the trivia that surrounds it
+pub struct SortableElement<N> {
+    /// The key used to sort the element, or `None` if the element stays in place
+    key: Option<String>,
+    node: N,
+    /// The empty lines that separate the element from the previous group
+    group_leading_trivia: TriviaPieces,
+    /// The leading trivia of the element, without the empty lines, for example the comments
+    /// that precede it
+    leading_trivia: TriviaPieces,
+    /// The trailing trivia of the element, for example the commas and the comment in `a: 1, # comment`
+    trailing_trivia: TriviaPieces,
+    /// The newline that follows the element, if it's the last element of its line
+    newline: Option<SyntaxTriviaPiece<GraphqlLanguage>>,
+}
+
+/// The elements of a list, grouped by the empty lines and the elements without key
+/// that separate them
+pub struct SortableElements<N>(Vec<Vec<SortableElement<N>>>);
+
+impl<N: AstNode<Language = GraphqlLanguage>> SortableElements<N> {
+    /// Collects the elements of `list`, using `key` to compute the key of each element.
+    ///
+    /// The elements for which `key` returns `None`, such as the fragment spreads of a selection set,
+    /// stay in place and are sorted neither with the elements that precede them nor with the ones
+    /// that follow them.
+    pub fn from_list<L>(list: &L, key: impl Fn(&N) -> Option<String>) -> Option<Self>
+    where
+        L: AstNodeList<Language = GraphqlLanguage, Node = N>,
+    {
+        let mut groups: Vec<Vec<SortableElement<N>>> = Vec::new();
+        for node in list.iter() {
+            let key = key(&node);
+            let first_token = node.syntax().first_token()?;
+            let last_token = node.syntax().last_token()?;
+
+            let leading_trivia: TriviaPieces = first_token.leading_trivia().pieces().collect();
+            let starts_group = has_empty_line(&leading_trivia);
+            let (group_leading_trivia, leading_trivia) = split_group_leading_trivia(leading_trivia);
+            let newline = last_token
+                .next_token()
+                .and_then(|token| token.leading_trivia().pieces().next())
+                .filter(|piece| piece.is_newline());
+
+            let element = SortableElement {
+                key,
+                node,
+                group_leading_trivia,
+                leading_trivia,
+                trailing_trivia: last_token.trailing_trivia().pieces().collect(),
+                newline,
+            };
+            match groups.last_mut() {
+                Some(group)
+                    if !starts_group
+                        && element.key.is_some()
+                        && group.last().is_some_and(|last| last.key.is_some()) =>
+                {
+                    group.push(element)
+                }
+                _ => groups.push(vec![element]),
+            }
+        }
+
+        Some(Self(groups))
+    }
+
+    /// Returns true if the elements of each group are already sorted in the file
+    pub fn is_sorted(&self) -> bool {
+        self.0.iter().all(|group| {
+            group
+                .windows(2)
+                .all(|pair| compare(&pair[0], &pair[1]) != Ordering::Greater)
+        })
+    }
+
+    /// Returns the sorted elements, ready to be passed to the factory function of the list.
+    ///
+    /// The comments move with the elements, but the whitespace and the commas stay in place,
+    /// so that `(b: 1, a: 2)` becomes `(a: 2, b: 1)`.
+    pub fn to_sorted_elements(&self) -> Option<Vec<N>> {
+        let original: Vec<_> = self.0.iter().flatten().collect();
+        let mut items = Vec::with_capacity(original.len());
+        // The newline that must precede the next element, because the previous one ends with a comment
+        let mut pending_newline = None;
+
+        for group in &self.0 {
+            let mut sorted: Vec<_> = group.iter().collect();
+            sorted.sort_by(|left, right| compare(left, right));
+
+            for element in sorted {
+                let in_place = original[items.len()];
+
+                let mut leading_trivia = in_place.group_leading_trivia.clone();
+                if has_comments(&element.leading_trivia) {
+                    leading_trivia.extend(element.leading_trivia.iter().cloned());
+                } else {
+                    leading_trivia.extend(after_last_comment(&in_place.leading_trivia));
+                }
+                if let Some(newline) = pending_newline.take() {
+                    if !leading_trivia
+                        .first()
+                        .is_some_and(|piece| piece.is_newline())
+                    {
+                        leading_trivia.insert(0, newline);
+                    }
+                }
+
+                let trailing_trivia = if has_comments(&element.trailing_trivia) {
+                    // A comment runs until the end of the line
+                    if in_place.newline.is_none() {
+                        if items.len() + 1 == original.len() {
+                            return None;
+                        }
+                        pending_newline = element.newline.clone();
+                    }
+                    element.trailing_trivia.clone()
+                } else {
+                    before_first_comment(&in_place.trailing_trivia)
+                };
+
+                let node = element
+                    .node
+                    .clone()
+                    .detach()
+                    .with_leading_trivia_pieces(leading_trivia)?
+                    .with_trailing_trivia_pieces(trailing_trivia)?;
+                items.push(node);
+            }
+        }
+
+        Some(items)
+    }
+}
+
+fn compare<N>(left: &SortableElement<N>, right: &SortableElement<N>) -> Ordering {
+    match (&left.key, &right.key) {
+        (Some(left), Some(right)) => natord::compare(left, right),
+        _ => Ordering::Equal,
+    }
+}
+
+fn has_comments(trivia: &[SyntaxTriviaPiece<GraphqlLanguage>]) -> bool {
+    trivia.iter().any(|piece| piece.is_comments())
+}
+
+/// Returns the trivia that follows the last comment, for example the indentation of the element
+fn after_last_comment(trivia: &[SyntaxTriviaPiece<GraphqlLanguage>]) -> TriviaPieces {
+    let start = trivia
+        .iter()
+        .rposition(|piece| piece.is_comments())
+        .map_or(0, |index| index + 1);
+    trivia[start..].to_vec()
+}
+
+/// Returns the trivia that precedes the first comment, for example the comma after the element,
+/// without the whitespace that separates it from the comment
+fn before_first_comment(trivia: &[SyntaxTriviaPiece<GraphqlLanguage>]) -> TriviaPieces {
+    let Some(end) = trivia.iter().position(|piece| piece.is_comments()) else {
+        return trivia.to_vec();
+    };
+    let mut pieces = trivia[..end].to_vec();
+    while pieces.last().is_some_and(|piece| piece.is_whitespace()) {
+        pieces.pop();
+    }
+    pieces
+}
+
+/// Returns true if the provided trivia contains an empty line (two consecutive newline pieces,
+/// ignoring whitespace and commas)
+fn has_empty_line(trivia: &[SyntaxTriviaPiece<GraphqlLanguage>]) -> bool {
+    let mut was_newline = false;
+    trivia
+        .iter()
+        .filter(|piece| !piece.is_whitespace() && !piece.is_skipped())
+        .any(|piece| {
+            let prev_newline = was_newline;
+            was_newline = piece.is_newline();
+            prev_newline && was_newline
+        })
+}
+
+/// Splits the leading trivia of an element in two parts:
+/// the empty lines that separate the element from the previous group, and the trivia
+/// that belongs to the element itself, which starts with a newline.
+///
+/// The first part is empty if the trivia doesn't contain an empty line.
+fn split_group_leading_trivia(mut pieces: TriviaPieces) -> (TriviaPieces, TriviaPieces) {
+    // Find the last newline of the empty line that is closest to the element
+    let mut next_newline = None;
+    let split_index = pieces
+        .iter()
+        .enumerate()
+        .rev()
+        .find_map(|(index, piece)| {
+            if piece.is_whitespace() || piece.is_skipped() {
+                return None;
+            }
+
+            let is_newline = piece.is_newline();
+            if let Some(next_newline) = next_newline.filter(|_| is_newline) {
+                return Some(next_newline);
+            }
+
+            next_newline = is_newline.then_some(index);
+            None
+        })
+        .unwrap_or(0);
+
+    let element_trivia = pieces.split_off(split_index);
+    (pieces, element_trivia)
+}
@@ -6,9 +6,11 @@ use biome_formatter::{
 };
 
 use crate::comments::{FormatGraphqlLeadingComment, GraphqlComments};
+use biome_deserialize_macros::{Deserializable, Merge};
 use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
 use std::fmt;
 use std::rc::Rc;
+use std::str::FromStr;
 
 #[derive(Debug)]
 pub struct GraphqlFormatContext {
@@ -64,6 +66,9 @@ pub struct GraphqlFormatOptions {
     quote_style: QuoteStyle,
     attribute_position: AttributePosition,
     bracket_spacing: BracketSpacing,
+    directive_order: DirectiveOrder,
+    single_field_selection_sets: SingleFieldSelectionSets,
+    empty_line_between_definitions: EmptyLineBetweenDefinitions,
     _file_source: GraphqlFileSource,
 }
 
@@ -78,6 +83,9 @@ impl GraphqlFormatOptions {
             quote_style: QuoteStyle::default(),
             attribute_position: AttributePosition::default(),
             bracket_spacing: BracketSpacing::default(),
+            directive_order: DirectiveOrder::default(),
+            single_field_selection_sets: SingleFieldSelectionSets::default(),
+            empty_line_between_definitions: EmptyLineBetweenDefinitions::default(),
         }
     }
 
@@ -111,6 +119,27 @@ impl GraphqlFormatOptions {
         self
     }
 
+    pub fn with_directive_order(mut self, directive_order: DirectiveOrder) -> Self {
+        self.directive_order = directive_order;
+        self
+    }
+
+    pub fn with_single_field_selection_sets(
+        mut self,
+        single_field_selection_sets: SingleFieldSelectionSets,
+    ) -> Self {
+        self.single_field_selection_sets = single_field_selection_sets;
+        self
+    }
+
+    pub fn with_empty_line_between_definitions(
+        mut self,
+        empty_line_between_definitions: EmptyLineBetweenDefinitions,
+    ) -> Self {
+        self.empty_line_between_definitions = empty_line_between_definitions;
+        self
+    }
+
     pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
         self.indent_style = indent_style;
     }
@@ -135,9 +164,39 @@ impl GraphqlFormatOptions {
         self.bracket_spacing = bracket_spacing;
     }
 
+    pub fn set_directive_order(&mut self, directive_order: DirectiveOrder) {
+        self.directive_order = directive_order;
+    }
+
+    pub fn set_single_field_selection_sets(
+        &mut self,
+        single_field_selection_sets: SingleFieldSelectionSets,
+    ) {
+        self.single_field_selection_sets = single_field_selection_sets;
+    }
+
+    pub fn set_empty_line_between_definitions(
+        &mut self,
+        empty_line_between_definitions: EmptyLineBetweenDefinitions,
+    ) {
+        self.empty_line_between_definitions = empty_line_between_definitions;
+    }
+
     pub fn quote_style(&self) -> QuoteStyle {
         self.quote_style
     }
+
+    pub fn directive_order(&self) -> DirectiveOrder {
+        self.directive_order
+    }
+
+    pub fn single_field_selection_sets(&self) -> SingleFieldSelectionSets {
+        self.single_field_selection_sets
+    }
+
+    pub fn empty_line_between_definitions(&self) -> EmptyLineBetweenDefinitions {
+        self.empty_line_between_definitions
+    }
 }
 
 impl FormatOptions for GraphqlFormatOptions {
@@ -177,6 +236,143 @@ impl fmt::Display for GraphqlFormatOptions {
         writeln!(f, "Line ending: {}", self.line_ending)?;
         writeln!(f, "Line width: {}", self.line_width.value())?;
         writeln!(f, "Bracket spacing: {}", self.bracket_spacing.value())?;
-        writeln!(f, "Quote style: {}", self.quote_style)
+        writeln!(f, "Quote style: {}", self.quote_style)?;
+        writeln!(f, "Directive order: {}", self.directive_order)?;
+        writeln!(
+            f,
+            "Single field selection sets: {}",
+            self.single_field_selection_sets
+        )?;
+        writeln!(
+            f,
+            "Empty line between definitions: {}",
+            self.empty_line_between_definitions
+        )
+    }
+}
+
+/// The order of the directives applied to a definition, a field or a fragment.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Deserializable, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum DirectiveOrder {
+    /// Directives are printed in the order they are written
+    #[default]
+    Preserve,
+    /// Directives are sorted by name, e.g. `@include(if: $a) @skip(if: $b)`
+    Sorted,
+}
+
+impl DirectiveOrder {
+    pub const fn is_sorted(&self) -> bool {
+        matches!(self, Self::Sorted)
+    }
+}
+
+impl FromStr for DirectiveOrder {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" => Ok(Self::Preserve),
+            "sorted" => Ok(Self::Sorted),
+            _ => Err("Value not supported for DirectiveOrder"),
+        }
+    }
+}
+
+impl fmt::Display for DirectiveOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DirectiveOrder::Preserve => std::write!(f, "Preserve"),
+            DirectiveOrder::Sorted => std::write!(f, "Sorted"),
+        }
+    }
+}
+
+/// How the selection sets that contain a single field without selection set are printed.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Deserializable, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum SingleFieldSelectionSets {
+    /// The field is printed on its own line
+    #[default]
+    Expand,
+    /// The field is printed on the same line as the braces when it fits, e.g. `user { name }`
+    Collapse,
+}
+
+impl SingleFieldSelectionSets {
+    pub const fn is_collapse(&self) -> bool {
+        matches!(self, Self::Collapse)
+    }
+}
+
+impl FromStr for SingleFieldSelectionSets {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "expand" => Ok(Self::Expand),
+            "collapse" => Ok(Self::Collapse),
+            _ => Err("Value not supported for SingleFieldSelectionSets"),
+        }
+    }
+}
+
+impl fmt::Display for SingleFieldSelectionSets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SingleFieldSelectionSets::Expand => std::write!(f, "Expand"),
+            SingleFieldSelectionSets::Collapse => std::write!(f, "Collapse"),
+        }
+    }
+}
+
+/// Whether the top-level definitions of a document are separated by an empty line.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Deserializable, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum EmptyLineBetweenDefinitions {
+    /// Definitions are separated by an empty line only if they are in the source
+    #[default]
+    Preserve,
+    /// Definitions are always separated by an empty line
+    Always,
+}
+
+impl EmptyLineBetweenDefinitions {
+    pub const fn is_always(&self) -> bool {
+        matches!(self, Self::Always)
+    }
+}
+
+impl FromStr for EmptyLineBetweenDefinitions {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" => Ok(Self::Preserve),
+            "always" => Ok(Self::Always),
+            _ => Err("Value not supported for EmptyLineBetweenDefinitions"),
+        }
+    }
+}
+
+impl fmt::Display for EmptyLineBetweenDefinitions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmptyLineBetweenDefinitions::Preserve => std::write!(f, "Preserve"),
+            EmptyLineBetweenDefinitions::Always => std::write!(f, "Always"),
+        }
     }
 }
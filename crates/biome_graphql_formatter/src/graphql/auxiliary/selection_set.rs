@@ -1,6 +1,8 @@
 use crate::prelude::*;
-use biome_formatter::write;
-use biome_graphql_syntax::{GraphqlSelectionSet, GraphqlSelectionSetFields};
+use biome_formatter::{format_args, write};
+use biome_graphql_syntax::{
+    AnyGraphqlSelection, GraphqlSelectionList, GraphqlSelectionSet, GraphqlSelectionSetFields,
+};
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatGraphqlSelectionSet;
@@ -12,6 +14,19 @@ impl FormatNodeRule<GraphqlSelectionSet> for FormatGraphqlSelectionSet {
             r_curly_token,
         } = node.as_fields();
 
+        if f.options().single_field_selection_sets().is_collapse()
+            && is_single_leaf_field(&selections)
+        {
+            return write!(
+                f,
+                [group(&format_args![
+                    l_curly_token.format(),
+                    soft_block_indent_with_maybe_space(&selections.format(), true),
+                    r_curly_token.format(),
+                ])]
+            );
+        }
+
         write!(
             f,
             [
@@ -22,3 +37,12 @@ impl FormatNodeRule<GraphqlSelectionSet> for FormatGraphqlSelectionSet {
         )
     }
 }
+
+/// Returns `true` if the list only contains a field that doesn't have its own selection set.
+fn is_single_leaf_field(selections: &GraphqlSelectionList) -> bool {
+    let mut iter = selections.iter();
+    matches!(
+        (iter.next(), iter.next()),
+        (Some(AnyGraphqlSelection::GraphqlField(field)), None) if field.selection_set().is_none()
+    )
+}
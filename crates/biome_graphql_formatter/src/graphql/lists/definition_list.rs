@@ -6,6 +6,16 @@ pub(crate) struct FormatGraphqlDefinitionList;
 impl FormatRule<GraphqlDefinitionList> for FormatGraphqlDefinitionList {
     type Context = GraphqlFormatContext;
     fn fmt(&self, node: &GraphqlDefinitionList, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        if f.options().empty_line_between_definitions().is_always() {
+            return f
+                .join_with(empty_line())
+                .entries(
+                    node.iter()
+                        .map(|definition| format_or_verbatim(definition.format())),
+                )
+                .finish();
+        }
+
         let mut join = f.join_nodes_with_hardline();
 
         for definition in node {
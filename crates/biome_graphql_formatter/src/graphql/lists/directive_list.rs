@@ -1,6 +1,6 @@
 use crate::prelude::*;
 use biome_formatter::{format_args, write};
-use biome_graphql_syntax::{GraphqlDirectiveList, GraphqlSyntaxKind};
+use biome_graphql_syntax::{GraphqlDirective, GraphqlDirectiveList, GraphqlSyntaxKind};
 use biome_rowan::SyntaxNodeOptionExt;
 
 #[derive(Debug, Clone, Default)]
@@ -12,9 +12,15 @@ impl FormatRule<GraphqlDirectiveList> for FormatGraphqlDirectiveList {
             return Ok(());
         }
 
+        let mut directives: Vec<_> = node.iter().collect();
+        if f.options().directive_order().is_sorted() {
+            // The sort is stable: repeated directives keep their relative order
+            directives.sort_by_cached_key(directive_name);
+        }
+
         let list = format_once(|f| {
             f.join_with(soft_line_break_or_space())
-                .entries(node.iter().formatted())
+                .entries(directives.iter().map(|directive| directive.format()))
                 .finish()
         });
 
@@ -34,3 +40,11 @@ impl FormatRule<GraphqlDirectiveList> for FormatGraphqlDirectiveList {
         }
     }
 }
+
+fn directive_name(directive: &GraphqlDirective) -> String {
+    directive
+        .name()
+        .and_then(|name| name.value_token())
+        .map(|token| token.text_trimmed().to_string())
+        .unwrap_or_default()
+}
@@ -0,0 +1,219 @@
+use crate::schema::{
+    GraphqlSchema, SchemaEnumValue, SchemaField, SchemaInputValue, SchemaType, SchemaTypeKind,
+    DEFAULT_DEPRECATION_REASON,
+};
+use crate::type_ref::TypeRef;
+use biome_json_syntax::{AnyJsonValue, JsonObjectValue, JsonRoot};
+use biome_rowan::AstSeparatedList;
+use rustc_hash::FxHashMap;
+
+impl GraphqlSchema {
+    /// Builds the schema described by the result of an introspection query,
+    /// such as `{ "data": { "__schema": { ... } } }`. The `data` wrapper is optional.
+    ///
+    /// Returns `None` if the document doesn't contain a `__schema` object.
+    pub fn from_introspection(root: &JsonRoot) -> Option<Self> {
+        let document = root.value().ok()?;
+        let document = document.as_json_object_value()?;
+        let introspection = member(document, "data")
+            .as_ref()
+            .and_then(AnyJsonValue::as_json_object_value)
+            .map_or_else(
+                || member(document, "__schema"),
+                |data| member(data, "__schema"),
+            )?;
+        let introspection = introspection.as_json_object_value()?;
+
+        let mut schema = Self {
+            query_type: root_type_name(introspection, "queryType"),
+            mutation_type: root_type_name(introspection, "mutationType"),
+            subscription_type: root_type_name(introspection, "subscriptionType"),
+            ..Self::default()
+        };
+        for ty in array(introspection, "types") {
+            let Some(ty) = ty.as_json_object_value().and_then(schema_type) else {
+                continue;
+            };
+            schema.types.insert(ty.name.clone(), ty);
+        }
+
+        Some(schema)
+    }
+}
+
+fn schema_type(object: &JsonObjectValue) -> Option<SchemaType> {
+    let kind = match string(object, "kind")?.as_str() {
+        "SCALAR" => SchemaTypeKind::Scalar,
+        "OBJECT" => SchemaTypeKind::Object,
+        "INTERFACE" => SchemaTypeKind::Interface,
+        "UNION" => SchemaTypeKind::Union,
+        "ENUM" => SchemaTypeKind::Enum,
+        "INPUT_OBJECT" => SchemaTypeKind::InputObject,
+        _ => return None,
+    };
+
+    let mut fields = FxHashMap::default();
+    for field in array(object, "fields") {
+        let Some(field) = field.as_json_object_value().and_then(schema_field) else {
+            continue;
+        };
+        fields.insert(field.name.clone(), field);
+    }
+
+    Some(SchemaType {
+        name: string(object, "name")?,
+        kind,
+        fields,
+        input_fields: input_values(object, "inputFields"),
+        enum_values: array(object, "enumValues")
+            .iter()
+            .filter_map(|value| {
+                let value = value.as_json_object_value()?;
+                Some(SchemaEnumValue {
+                    name: string(value, "name")?,
+                    deprecation_reason: deprecation_reason(value),
+                })
+            })
+            .collect(),
+    })
+}
+
+fn schema_field(object: &JsonObjectValue) -> Option<SchemaField> {
+    Some(SchemaField {
+        name: string(object, "name")?,
+        ty: type_ref(member(object, "type")?.as_json_object_value()?)?,
+        arguments: input_values(object, "args"),
+        deprecation_reason: deprecation_reason(object),
+    })
+}
+
+/// Returns the `deprecationReason` of a field or an enum value, or `None` if it isn't deprecated
+fn deprecation_reason(object: &JsonObjectValue) -> Option<String> {
+    let is_deprecated = member(object, "isDeprecated")
+        .and_then(|value| value.as_json_boolean_value()?.value_token().ok())
+        .is_some_and(|value| value.text_trimmed() == "true");
+    is_deprecated.then(|| {
+        string(object, "deprecationReason")
+            .unwrap_or_else(|| DEFAULT_DEPRECATION_REASON.to_string())
+    })
+}
+
+fn input_values(object: &JsonObjectValue, name: &str) -> Vec<SchemaInputValue> {
+    array(object, name)
+        .iter()
+        .filter_map(|value| {
+            let value = value.as_json_object_value()?;
+            Some(SchemaInputValue {
+                name: string(value, "name")?,
+                ty: type_ref(member(value, "type")?.as_json_object_value()?)?,
+                has_default: member(value, "defaultValue")
+                    .is_some_and(|default| !matches!(default, AnyJsonValue::JsonNullValue(_))),
+            })
+        })
+        .collect()
+}
+
+/// Converts a type reference such as `{ "kind": "NON_NULL", "ofType": { "kind": "SCALAR", "name": "ID" } }`
+fn type_ref(object: &JsonObjectValue) -> Option<TypeRef> {
+    let of_type = || type_ref(member(object, "ofType")?.as_json_object_value()?);
+    match string(object, "kind")?.as_str() {
+        "NON_NULL" => Some(TypeRef::NonNull(Box::new(of_type()?))),
+        "LIST" => Some(TypeRef::List(Box::new(of_type()?))),
+        _ => Some(TypeRef::Named(string(object, "name")?)),
+    }
+}
+
+/// Returns the name of a root operation type, such as `"queryType": { "name": "Query" }`
+fn root_type_name(object: &JsonObjectValue, name: &str) -> Option<String> {
+    string(member(object, name)?.as_json_object_value()?, "name")
+}
+
+fn member(object: &JsonObjectValue, name: &str) -> Option<AnyJsonValue> {
+    object
+        .json_member_list()
+        .iter()
+        .flatten()
+        .find(|member| {
+            member
+                .name()
+                .ok()
+                .and_then(|member_name| member_name.inner_string_text().ok())
+                .is_some_and(|member_name| member_name.text() == name)
+        })?
+        .value()
+        .ok()
+}
+
+fn string(object: &JsonObjectValue, name: &str) -> Option<String> {
+    let value = member(object, name)?;
+    let text = value.as_json_string_value()?.inner_string_text().ok()?;
+    Some(text.to_string())
+}
+
+/// Returns the elements of the array `name`, or nothing if the member isn't an array
+fn array(object: &JsonObjectValue, name: &str) -> Vec<AnyJsonValue> {
+    match member(object, name) {
+        Some(AnyJsonValue::JsonArrayValue(array)) => array.elements().iter().flatten().collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GraphqlSchema, SchemaTypeKind, TypeRef};
+    use std::path::Path;
+
+    const INTROSPECTION: &str = r#"{
+  "data": {
+    "__schema": {
+      "queryType": { "name": "Root" },
+      "mutationType": null,
+      "subscriptionType": null,
+      "types": [
+        {
+          "kind": "OBJECT",
+          "name": "Root",
+          "fields": [
+            {
+              "name": "user",
+              "args": [
+                {
+                  "name": "id",
+                  "type": { "kind": "NON_NULL", "name": null, "ofType": { "kind": "SCALAR", "name": "ID", "ofType": null } },
+                  "defaultValue": null
+                },
+                {
+                  "name": "first",
+                  "type": { "kind": "SCALAR", "name": "Int", "ofType": null },
+                  "defaultValue": "10"
+                }
+              ],
+              "type": { "kind": "OBJECT", "name": "User", "ofType": null }
+            }
+          ],
+          "inputFields": null
+        },
+        { "kind": "SCALAR", "name": "ID", "fields": null, "inputFields": null },
+        { "kind": "OBJECT", "name": "User", "fields": [], "inputFields": null }
+      ]
+    }
+  }
+}"#;
+
+    #[test]
+    fn reads_introspection_result() {
+        let schema = GraphqlSchema::from_source(Path::new("schema.json"), INTROSPECTION).unwrap();
+
+        let root = schema.root_type("query").unwrap();
+        assert_eq!(root.name(), "Root");
+        assert_eq!(root.kind(), SchemaTypeKind::Object);
+        assert!(schema.root_type("mutation").is_none());
+
+        let user = root.field("user").unwrap();
+        assert_eq!(user.ty(), &TypeRef::Named("User".to_string()));
+        let id = user.argument("id").unwrap();
+        assert_eq!(id.ty().to_string(), "ID!");
+        assert!(id.is_required());
+        assert!(!user.argument("first").unwrap().is_required());
+    }
+}
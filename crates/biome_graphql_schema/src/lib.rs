@@ -0,0 +1,15 @@
+//! Model of [GraphQL schemas](https://spec.graphql.org/October2021/#sec-Schema), used to validate operations.
+//!
+//! A [GraphqlSchema] is built either from a schema definition document (SDL),
+//! or from the JSON result of an introspection query. It records the types of the schema,
+//! with their fields and arguments, and resolves the type of the selection sets of an operation.
+
+mod introspection;
+mod schema;
+mod sdl;
+mod type_ref;
+
+pub use crate::schema::{
+    GraphqlSchema, SchemaEnumValue, SchemaField, SchemaInputValue, SchemaType, SchemaTypeKind,
+};
+pub use crate::type_ref::TypeRef;
@@ -0,0 +1,386 @@
+use crate::type_ref::TypeRef;
+use biome_graphql_parser::parse_graphql;
+use biome_graphql_syntax::{
+    AnyGraphqlValue, GraphqlArgument, GraphqlArgumentList, GraphqlArguments, GraphqlDefinitionList,
+    GraphqlField, GraphqlFragmentDefinition, GraphqlInlineFragment, GraphqlListValue,
+    GraphqlListValueElementList, GraphqlObjectField, GraphqlObjectValue,
+    GraphqlObjectValueMemberList, GraphqlOperationDefinition, GraphqlSelectionList,
+    GraphqlSelectionSet, GraphqlSyntaxNode,
+};
+use biome_json_parser::{parse_json, JsonParserOptions};
+use biome_rowan::AstNode;
+use rustc_hash::FxHashMap;
+use std::path::Path;
+
+/// The scalars that every schema defines, even when its document doesn't declare them
+pub(crate) const BUILT_IN_SCALARS: [&str; 5] = ["Int", "Float", "String", "Boolean", "ID"];
+
+/// The reason of a `@deprecated` directive that doesn't specify one
+pub(crate) const DEFAULT_DEPRECATION_REASON: &str = "No longer supported";
+
+/// The types of a GraphQL schema, and its root operation types
+#[derive(Debug, Default)]
+pub struct GraphqlSchema {
+    pub(crate) types: FxHashMap<String, SchemaType>,
+    pub(crate) query_type: Option<String>,
+    pub(crate) mutation_type: Option<String>,
+    pub(crate) subscription_type: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SchemaTypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+}
+
+#[derive(Debug)]
+pub struct SchemaType {
+    pub(crate) name: String,
+    pub(crate) kind: SchemaTypeKind,
+    /// The fields of an object or an interface
+    pub(crate) fields: FxHashMap<String, SchemaField>,
+    /// The fields of an input object
+    pub(crate) input_fields: Vec<SchemaInputValue>,
+    /// The values of an enum
+    pub(crate) enum_values: Vec<SchemaEnumValue>,
+}
+
+/// A field of an object or an interface
+#[derive(Debug)]
+pub struct SchemaField {
+    pub(crate) name: String,
+    pub(crate) ty: TypeRef,
+    pub(crate) arguments: Vec<SchemaInputValue>,
+    /// The reason of the deprecation of the field, if it's deprecated
+    pub(crate) deprecation_reason: Option<String>,
+}
+
+/// An argument of a field, or a field of an input object
+#[derive(Debug)]
+pub struct SchemaInputValue {
+    pub(crate) name: String,
+    pub(crate) ty: TypeRef,
+    pub(crate) has_default: bool,
+}
+
+/// A value of an enum
+#[derive(Debug)]
+pub struct SchemaEnumValue {
+    pub(crate) name: String,
+    /// The reason of the deprecation of the value, if it's deprecated
+    pub(crate) deprecation_reason: Option<String>,
+}
+
+impl GraphqlSchema {
+    /// Builds the schema defined by the file at `path`, whose content is `source`.
+    ///
+    /// A `.json` file must contain the result of an introspection query,
+    /// any other file must contain a schema definition document.
+    /// Returns `None` if the file can't be parsed.
+    pub fn from_source(path: &Path, source: &str) -> Option<Self> {
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            let parse = parse_json(source, JsonParserOptions::default());
+            if parse.has_errors() {
+                return None;
+            }
+            Self::from_introspection(&parse.tree())
+        } else {
+            let parse = parse_graphql(source);
+            if parse.has_errors() {
+                return None;
+            }
+            Some(Self::from_sdl(&parse.tree()))
+        }
+    }
+
+    pub fn get_type(&self, name: &str) -> Option<&SchemaType> {
+        self.types.get(name)
+    }
+
+    /// Returns the root type of the operations of kind `operation`: `query`, `mutation` or `subscription`
+    pub fn root_type(&self, operation: &str) -> Option<&SchemaType> {
+        let name = match operation {
+            "query" => self.query_type.as_deref().unwrap_or("Query"),
+            "mutation" => self.mutation_type.as_deref().unwrap_or("Mutation"),
+            "subscription" => self.subscription_type.as_deref().unwrap_or("Subscription"),
+            _ => return None,
+        };
+        self.get_type(name)
+    }
+
+    /// Returns the type whose fields are selected by `selection_set`.
+    ///
+    /// Returns `None` if the selection set belongs to a field or a fragment unknown to the schema.
+    pub fn selection_set_type(&self, selection_set: &GraphqlSelectionSet) -> Option<&SchemaType> {
+        let parent = selection_set.syntax().parent()?;
+        if let Some(field) = GraphqlField::cast_ref(&parent) {
+            let name = field.name().ok()?.text();
+            let field = self.parent_type(&field)?.field(&name)?;
+            self.get_type(field.ty.named_type())
+        } else if let Some(operation) = GraphqlOperationDefinition::cast_ref(&parent) {
+            self.root_type(&operation.ty().ok()?.text())
+        } else if let Some(fragment) = GraphqlFragmentDefinition::cast_ref(&parent) {
+            self.get_type(&fragment.type_condition().ok()?.ty().ok()?.text())
+        } else if let Some(fragment) = GraphqlInlineFragment::cast_ref(&parent) {
+            match fragment.type_condition() {
+                Some(condition) => self.get_type(&condition.ty().ok()?.text()),
+                None => self.selection_set_type(&enclosing_selection_set(fragment.syntax())?),
+            }
+        } else if GraphqlDefinitionList::can_cast(parent.kind()) {
+            // The query shorthand, such as `{ user { name } }`
+            self.root_type("query")
+        } else {
+            None
+        }
+    }
+
+    /// Returns the type that defines `field`
+    pub fn parent_type(&self, field: &GraphqlField) -> Option<&SchemaType> {
+        self.selection_set_type(&enclosing_selection_set(field.syntax())?)
+    }
+
+    /// Returns the definition of `field`
+    pub fn field_definition(&self, field: &GraphqlField) -> Option<&SchemaField> {
+        self.parent_type(field)?.field(&field.name().ok()?.text())
+    }
+
+    /// Returns the type expected for `value`, which is passed to an argument of a field,
+    /// or nested in the lists and the input objects passed to such an argument.
+    pub fn value_type(&self, value: &AnyGraphqlValue) -> Option<&TypeRef> {
+        let parent = value.syntax().parent()?;
+        if let Some(argument) = GraphqlArgument::cast_ref(&parent) {
+            let field = argument
+                .parent::<GraphqlArgumentList>()?
+                .parent::<GraphqlArguments>()?
+                .parent::<GraphqlField>()?;
+            let name = argument.name().ok()?.text();
+            Some(self.field_definition(&field)?.argument(&name)?.ty())
+        } else if GraphqlListValueElementList::can_cast(parent.kind()) {
+            let list = GraphqlListValue::cast(parent.parent()?)?;
+            match self
+                .value_type(&AnyGraphqlValue::GraphqlListValue(list))?
+                .nullable()
+            {
+                TypeRef::List(element) => Some(element),
+                _ => None,
+            }
+        } else if let Some(field) = GraphqlObjectField::cast_ref(&parent) {
+            let object = field
+                .parent::<GraphqlObjectValueMemberList>()?
+                .parent::<GraphqlObjectValue>()?;
+            let ty = self.value_type(&AnyGraphqlValue::GraphqlObjectValue(object))?;
+            let name = field.name().ok()?.text();
+            self.get_type(ty.named_type())?
+                .input_fields()
+                .iter()
+                .find(|input_field| input_field.name == name)
+                .map(SchemaInputValue::ty)
+        } else {
+            None
+        }
+    }
+}
+
+impl SchemaType {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> SchemaTypeKind {
+        self.kind
+    }
+
+    /// Returns `true` if the fields of the type can be selected
+    pub fn is_composite(&self) -> bool {
+        matches!(
+            self.kind,
+            SchemaTypeKind::Object | SchemaTypeKind::Interface | SchemaTypeKind::Union
+        )
+    }
+
+    pub fn field(&self, name: &str) -> Option<&SchemaField> {
+        self.fields.get(name)
+    }
+
+    pub fn input_fields(&self) -> &[SchemaInputValue] {
+        &self.input_fields
+    }
+
+    pub fn enum_value(&self, name: &str) -> Option<&SchemaEnumValue> {
+        self.enum_values.iter().find(|value| value.name == name)
+    }
+}
+
+impl SchemaField {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn ty(&self) -> &TypeRef {
+        &self.ty
+    }
+
+    pub fn arguments(&self) -> &[SchemaInputValue] {
+        &self.arguments
+    }
+
+    pub fn argument(&self, name: &str) -> Option<&SchemaInputValue> {
+        self.arguments.iter().find(|argument| argument.name == name)
+    }
+
+    /// Returns the reason of the deprecation of the field, or `None` if it isn't deprecated
+    pub fn deprecation_reason(&self) -> Option<&str> {
+        self.deprecation_reason.as_deref()
+    }
+}
+
+impl SchemaInputValue {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn ty(&self) -> &TypeRef {
+        &self.ty
+    }
+
+    pub fn has_default(&self) -> bool {
+        self.has_default
+    }
+
+    /// Returns `true` if the value must be provided: its type is non-null, and it doesn't have a default value
+    pub fn is_required(&self) -> bool {
+        self.ty.is_non_null() && !self.has_default
+    }
+}
+
+impl SchemaEnumValue {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the reason of the deprecation of the value, or `None` if it isn't deprecated
+    pub fn deprecation_reason(&self) -> Option<&str> {
+        self.deprecation_reason.as_deref()
+    }
+}
+
+/// Returns the selection set that contains the selection `node`
+fn enclosing_selection_set(node: &GraphqlSyntaxNode) -> Option<GraphqlSelectionSet> {
+    let list = GraphqlSelectionList::cast(node.parent()?)?;
+    GraphqlSelectionSet::cast(list.syntax().parent()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GraphqlSchema, SchemaTypeKind};
+    use biome_graphql_parser::parse_graphql;
+    use biome_graphql_syntax::{AnyGraphqlValue, GraphqlEnumValue, GraphqlField};
+    use biome_rowan::AstNode;
+    use std::path::Path;
+
+    const SCHEMA: &str = r#"
+schema { query: Root }
+
+type Root {
+  user(id: ID!): User
+  node(id: ID!): Node
+  users(filter: UserFilter): [User]
+}
+
+input UserFilter { roles: [Role!] }
+
+enum Role { ADMIN, MEMBER @deprecated(reason: "Use `USER` instead"), USER }
+
+interface Node { id: ID! }
+
+type User implements Node {
+  id: ID!
+  name: String
+  friends(first: Int = 10): [User!]!
+  login: String @deprecated
+}
+"#;
+
+    fn field_named<'a>(fields: &'a [GraphqlField], name: &str) -> &'a GraphqlField {
+        fields
+            .iter()
+            .find(|field| {
+                field
+                    .name()
+                    .is_ok_and(|field_name| field_name.text() == name)
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn resolves_selection_sets() {
+        let schema = GraphqlSchema::from_source(Path::new("schema.graphql"), SCHEMA).unwrap();
+        let parse = parse_graphql(
+            r#"
+query { user(id: 1) { friends { name } } }
+fragment F on Node { ... on User { name } }
+"#,
+        );
+        let fields: Vec<_> = parse
+            .syntax()
+            .descendants()
+            .filter_map(GraphqlField::cast)
+            .collect();
+
+        let user = field_named(&fields, "user");
+        assert_eq!(schema.parent_type(user).unwrap().name(), "Root");
+
+        let friends = field_named(&fields, "friends");
+        assert_eq!(schema.parent_type(friends).unwrap().name(), "User");
+        assert!(schema.field_definition(friends).unwrap().arguments()[0].has_default());
+
+        let names: Vec<_> = fields
+            .iter()
+            .filter(|field| field.name().is_ok_and(|name| name.text() == "name"))
+            .map(|field| schema.parent_type(field).unwrap().name())
+            .collect();
+        assert_eq!(names, ["User", "User"]);
+
+        assert_eq!(
+            schema.get_type("Node").unwrap().kind(),
+            SchemaTypeKind::Interface
+        );
+        assert_eq!(
+            schema.get_type("Int").unwrap().kind(),
+            SchemaTypeKind::Scalar
+        );
+    }
+
+    #[test]
+    fn resolves_value_types() {
+        let schema = GraphqlSchema::from_source(Path::new("schema.graphql"), SCHEMA).unwrap();
+        let parse = parse_graphql("query { users(filter: { roles: [MEMBER] }) { login } }");
+        let value = parse
+            .syntax()
+            .descendants()
+            .find_map(GraphqlEnumValue::cast)
+            .unwrap();
+
+        let ty = schema.value_type(&AnyGraphqlValue::GraphqlEnumValue(value));
+        assert_eq!(ty.unwrap().to_string(), "Role!");
+        let role = schema.get_type("Role").unwrap();
+        assert_eq!(
+            role.enum_value("MEMBER").unwrap().deprecation_reason(),
+            Some("Use `USER` instead")
+        );
+        assert_eq!(role.enum_value("USER").unwrap().deprecation_reason(), None);
+
+        let user = schema.get_type("User").unwrap();
+        assert_eq!(
+            user.field("login").unwrap().deprecation_reason(),
+            Some("No longer supported")
+        );
+    }
+}
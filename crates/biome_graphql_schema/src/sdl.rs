@@ -0,0 +1,226 @@
+use crate::schema::{
+    GraphqlSchema, SchemaEnumValue, SchemaField, SchemaInputValue, SchemaType, SchemaTypeKind,
+    BUILT_IN_SCALARS, DEFAULT_DEPRECATION_REASON,
+};
+use crate::type_ref::TypeRef;
+use biome_graphql_syntax::{
+    AnyGraphqlDefinition, AnyGraphqlTypeDefinition, AnyGraphqlTypeExtension,
+    GraphqlArgumentsDefinition, GraphqlDirectiveList, GraphqlEnumValuesDefinition,
+    GraphqlFieldsDefinition, GraphqlInputFieldsDefinition, GraphqlInputValueDefinition,
+    GraphqlRoot, GraphqlRootOperationTypes,
+};
+use biome_rowan::{AstNode, AstNodeList};
+use rustc_hash::FxHashMap;
+
+impl GraphqlSchema {
+    /// Builds the schema defined by a schema definition document.
+    ///
+    /// The type extensions of the document are merged into the types they extend,
+    /// even when they precede the definition of the type.
+    /// The definitions that contain syntax errors are ignored.
+    pub fn from_sdl(root: &GraphqlRoot) -> Self {
+        let mut schema = Self::default();
+        for name in BUILT_IN_SCALARS {
+            schema.insert_type(name.to_string(), SchemaTypeKind::Scalar);
+        }
+
+        for definition in root.definitions() {
+            match definition {
+                AnyGraphqlDefinition::AnyGraphqlTypeDefinition(definition) => {
+                    schema.add_type_definition(&definition);
+                }
+                AnyGraphqlDefinition::AnyGraphqlTypeExtension(extension) => {
+                    schema.add_type_extension(&extension);
+                }
+                AnyGraphqlDefinition::GraphqlSchemaDefinition(definition) => {
+                    if let Ok(root_types) = definition.root_operation_types() {
+                        schema.set_root_types(&root_types);
+                    }
+                }
+                AnyGraphqlDefinition::GraphqlSchemaExtension(extension) => {
+                    if let Some(root_types) = extension.root_operation_types() {
+                        schema.set_root_types(&root_types);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        schema
+    }
+
+    fn add_type_definition(&mut self, definition: &AnyGraphqlTypeDefinition) -> Option<()> {
+        match definition {
+            AnyGraphqlTypeDefinition::GraphqlScalarTypeDefinition(definition) => {
+                self.insert_type(definition.name().ok()?.text(), SchemaTypeKind::Scalar);
+            }
+            AnyGraphqlTypeDefinition::GraphqlEnumTypeDefinition(definition) => {
+                let ty = self.insert_type(definition.name().ok()?.text(), SchemaTypeKind::Enum);
+                add_enum_values(ty, definition.enum_values());
+            }
+            AnyGraphqlTypeDefinition::GraphqlUnionTypeDefinition(definition) => {
+                self.insert_type(definition.name().ok()?.text(), SchemaTypeKind::Union);
+            }
+            AnyGraphqlTypeDefinition::GraphqlObjectTypeDefinition(definition) => {
+                let ty = self.insert_type(definition.name().ok()?.text(), SchemaTypeKind::Object);
+                add_fields(ty, definition.fields());
+            }
+            AnyGraphqlTypeDefinition::GraphqlInterfaceTypeDefinition(definition) => {
+                let ty =
+                    self.insert_type(definition.name().ok()?.text(), SchemaTypeKind::Interface);
+                add_fields(ty, definition.fields());
+            }
+            AnyGraphqlTypeDefinition::GraphqlInputObjectTypeDefinition(definition) => {
+                let ty =
+                    self.insert_type(definition.name().ok()?.text(), SchemaTypeKind::InputObject);
+                add_input_fields(ty, definition.input_fields());
+            }
+        }
+        Some(())
+    }
+
+    fn add_type_extension(&mut self, extension: &AnyGraphqlTypeExtension) -> Option<()> {
+        match extension {
+            AnyGraphqlTypeExtension::GraphqlObjectTypeExtension(extension) => {
+                let ty = self.insert_type(extension.name().ok()?.text(), SchemaTypeKind::Object);
+                add_fields(ty, extension.fields());
+            }
+            AnyGraphqlTypeExtension::GraphqlInterfaceTypeExtension(extension) => {
+                let ty = self.insert_type(extension.name().ok()?.text(), SchemaTypeKind::Interface);
+                add_fields(ty, extension.fields());
+            }
+            AnyGraphqlTypeExtension::GraphqlEnumTypeExtension(extension) => {
+                let ty = self.insert_type(extension.name().ok()?.text(), SchemaTypeKind::Enum);
+                add_enum_values(ty, extension.enum_values());
+            }
+            AnyGraphqlTypeExtension::GraphqlInputObjectTypeExtension(extension) => {
+                let ty =
+                    self.insert_type(extension.name().ok()?.text(), SchemaTypeKind::InputObject);
+                add_input_fields(ty, extension.input_fields());
+            }
+            _ => {}
+        }
+        Some(())
+    }
+
+    fn set_root_types(&mut self, root_types: &GraphqlRootOperationTypes) {
+        for root_type in root_types.root_operation_type() {
+            let (Ok(operation), Ok(name)) = (root_type.operation_type(), root_type.named_type())
+            else {
+                continue;
+            };
+            let name = Some(name.text());
+            match operation.text().as_str() {
+                "query" => self.query_type = name,
+                "mutation" => self.mutation_type = name,
+                "subscription" => self.subscription_type = name,
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns the type named `name`, after inserting an empty one if it doesn't exist yet
+    fn insert_type(&mut self, name: String, kind: SchemaTypeKind) -> &mut SchemaType {
+        self.types.entry(name.clone()).or_insert(SchemaType {
+            name,
+            kind,
+            fields: FxHashMap::default(),
+            input_fields: Vec::new(),
+            enum_values: Vec::new(),
+        })
+    }
+}
+
+fn add_fields(ty: &mut SchemaType, fields: Option<GraphqlFieldsDefinition>) {
+    let Some(fields) = fields else {
+        return;
+    };
+    for field in fields.fields() {
+        let (Ok(name), Some(field_type)) = (
+            field.name(),
+            field.ty().ok().and_then(|ty| TypeRef::from_syntax(&ty)),
+        ) else {
+            continue;
+        };
+        let name = name.text();
+        ty.fields.insert(
+            name.clone(),
+            SchemaField {
+                name,
+                ty: field_type,
+                arguments: input_values(field.arguments()),
+                deprecation_reason: deprecation_reason(&field.directives()),
+            },
+        );
+    }
+}
+
+fn add_input_fields(ty: &mut SchemaType, fields: Option<GraphqlInputFieldsDefinition>) {
+    if let Some(fields) = fields {
+        ty.input_fields.extend(
+            fields
+                .fields()
+                .iter()
+                .filter_map(|field| input_value(&field)),
+        );
+    }
+}
+
+fn add_enum_values(ty: &mut SchemaType, values: Option<GraphqlEnumValuesDefinition>) {
+    let Some(values) = values else {
+        return;
+    };
+    for value in values.values() {
+        let Ok(name) = value.value() else {
+            continue;
+        };
+        ty.enum_values.push(SchemaEnumValue {
+            name: name.text(),
+            deprecation_reason: deprecation_reason(&value.directives()),
+        });
+    }
+}
+
+/// Returns the reason of the `@deprecated` directive of `directives`,
+/// or `None` if the list doesn't contain this directive
+fn deprecation_reason(directives: &GraphqlDirectiveList) -> Option<String> {
+    let directive = directives.iter().find(|directive| {
+        directive
+            .name()
+            .is_ok_and(|name| name.text() == "deprecated")
+    })?;
+    let reason = directive
+        .arguments()
+        .and_then(|arguments| {
+            arguments
+                .arguments()
+                .iter()
+                .find(|argument| argument.name().is_ok_and(|name| name.text() == "reason"))
+        })
+        .and_then(|argument| argument.value().ok())
+        .and_then(|value| {
+            let reason = value.as_graphql_string_value()?.inner_string_text().ok()?;
+            Some(reason.to_string())
+        });
+    Some(reason.unwrap_or_else(|| DEFAULT_DEPRECATION_REASON.to_string()))
+}
+
+fn input_values(arguments: Option<GraphqlArgumentsDefinition>) -> Vec<SchemaInputValue> {
+    arguments
+        .map(|arguments| {
+            arguments
+                .arguments()
+                .iter()
+                .filter_map(|argument| input_value(&argument))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn input_value(definition: &GraphqlInputValueDefinition) -> Option<SchemaInputValue> {
+    Some(SchemaInputValue {
+        name: definition.name().ok()?.text(),
+        ty: TypeRef::from_syntax(&definition.ty().ok()?)?,
+        has_default: definition.default().is_some(),
+    })
+}
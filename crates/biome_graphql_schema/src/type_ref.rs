@@ -0,0 +1,119 @@
+use biome_graphql_syntax::{AnyGraphqlPrimitiveType, AnyGraphqlType};
+use biome_rowan::AstNode;
+use std::fmt::{Display, Formatter};
+
+/// A reference to a type, wrapped in lists and non-null modifiers, such as `[String!]!`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TypeRef {
+    Named(String),
+    List(Box<TypeRef>),
+    NonNull(Box<TypeRef>),
+}
+
+impl TypeRef {
+    /// Returns the reference written in a document, or `None` if it contains a bogus type.
+    pub fn from_syntax(ty: &AnyGraphqlType) -> Option<Self> {
+        match ty {
+            AnyGraphqlType::AnyGraphqlPrimitiveType(ty) => Self::from_primitive(ty),
+            AnyGraphqlType::GraphqlNonNullType(ty) => Some(Self::NonNull(Box::new(
+                Self::from_primitive(&ty.base().ok()?)?,
+            ))),
+            AnyGraphqlType::GraphqlBogusType(_) => None,
+        }
+    }
+
+    fn from_primitive(ty: &AnyGraphqlPrimitiveType) -> Option<Self> {
+        match ty {
+            AnyGraphqlPrimitiveType::GraphqlNameReference(name) => Some(Self::Named(name.text())),
+            AnyGraphqlPrimitiveType::GraphqlListType(list) => Some(Self::List(Box::new(
+                Self::from_syntax(&list.element().ok()?)?,
+            ))),
+        }
+    }
+
+    /// Returns the name of the type, without the modifiers
+    pub fn named_type(&self) -> &str {
+        match self {
+            Self::Named(name) => name,
+            Self::List(ty) | Self::NonNull(ty) => ty.named_type(),
+        }
+    }
+
+    pub fn is_non_null(&self) -> bool {
+        matches!(self, Self::NonNull(_))
+    }
+
+    /// Returns `true` if the type is a list, such as `[String]` or `[String]!`
+    pub fn is_list(&self) -> bool {
+        matches!(self.nullable(), Self::List(_))
+    }
+
+    /// Returns the type without its non-null modifier, such as `[String!]` for `[String!]!`
+    pub fn nullable(&self) -> &Self {
+        match self {
+            Self::NonNull(ty) => ty,
+            _ => self,
+        }
+    }
+
+    /// Returns `true` if a variable of this type can be used where `location` is expected.
+    ///
+    /// See [`AreTypesCompatible`](https://spec.graphql.org/October2021/#AreTypesCompatible()).
+    pub fn is_compatible_with(&self, location: &Self) -> bool {
+        match (self, location) {
+            (Self::NonNull(variable), Self::NonNull(location)) => {
+                variable.is_compatible_with(location)
+            }
+            (_, Self::NonNull(_)) => false,
+            (Self::NonNull(variable), location) => variable.is_compatible_with(location),
+            (Self::List(variable), Self::List(location)) => variable.is_compatible_with(location),
+            (Self::Named(variable), Self::Named(location)) => variable == location,
+            _ => false,
+        }
+    }
+}
+
+impl Display for TypeRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Named(name) => write!(f, "{name}"),
+            Self::List(ty) => write!(f, "[{ty}]"),
+            Self::NonNull(ty) => write!(f, "{ty}!"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TypeRef;
+
+    fn named(name: &str) -> TypeRef {
+        TypeRef::Named(name.to_string())
+    }
+
+    fn list(ty: TypeRef) -> TypeRef {
+        TypeRef::List(Box::new(ty))
+    }
+
+    fn non_null(ty: TypeRef) -> TypeRef {
+        TypeRef::NonNull(Box::new(ty))
+    }
+
+    #[test]
+    fn prints_modifiers() {
+        let ty = non_null(list(non_null(named("String"))));
+        assert_eq!(ty.to_string(), "[String!]!");
+        assert_eq!(ty.named_type(), "String");
+    }
+
+    #[test]
+    fn compatible_types() {
+        assert!(named("ID").is_compatible_with(&named("ID")));
+        assert!(non_null(named("ID")).is_compatible_with(&named("ID")));
+        assert!(!named("ID").is_compatible_with(&non_null(named("ID"))));
+        assert!(!named("ID").is_compatible_with(&named("String")));
+        assert!(list(non_null(named("ID"))).is_compatible_with(&list(named("ID"))));
+        assert!(!list(named("ID")).is_compatible_with(&named("ID")));
+        assert!(!named("ID").is_compatible_with(&list(named("ID"))));
+    }
+}
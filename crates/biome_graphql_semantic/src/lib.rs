@@ -1,7 +1,9 @@
 mod events;
+mod project_index;
 mod semantic_model;
 
 pub use events::*;
+pub use project_index::*;
 pub use semantic_model::*;
 
 #[cfg(test)]
@@ -0,0 +1,290 @@
+use std::path::{Path, PathBuf};
+
+use biome_graphql_syntax::{AnyGraphqlDefinition, GraphqlFragmentSpread, GraphqlRoot};
+use biome_rowan::{AstNode, TextRange, TextSize};
+use rustc_hash::FxHashMap;
+
+/// An index of the symbols defined by the GraphQL documents of a project.
+///
+/// Unlike the [SemanticModel](crate::SemanticModel), which only knows
+/// about a single document, the index collects information across files,
+/// so that lint rules can reason about definitions that live in other documents.
+///
+/// The index is keyed by file path: indexing a file again replaces whatever
+/// was previously recorded for it.
+#[derive(Debug, Clone, Default)]
+pub struct GraphqlProjectIndex {
+    files: FxHashMap<PathBuf, GraphqlFileIndex>,
+}
+
+impl GraphqlProjectIndex {
+    /// Records the symbols of the file at `path`, replacing the previous entry, if any.
+    pub fn insert_file(&mut self, path: PathBuf, index: GraphqlFileIndex) {
+        self.files.insert(path, index);
+    }
+
+    /// Forgets everything that was recorded for the file at `path`.
+    pub fn remove_file(&mut self, path: &Path) -> Option<GraphqlFileIndex> {
+        self.files.remove(path)
+    }
+
+    /// Returns the number of files whose symbols are recorded.
+    pub fn file_count(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Returns the symbols recorded for the file at `path`.
+    pub fn get_file(&self, path: &Path) -> Option<&GraphqlFileIndex> {
+        self.files.get(path)
+    }
+
+    /// Returns all the operations named `name`, along with the path of the file
+    /// where they are defined.
+    pub fn operation_definitions<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = (&'a Path, &'a GraphqlOperationName)> + 'a {
+        self.files.iter().flat_map(move |(path, file)| {
+            file.operation_definitions(name)
+                .map(move |definition| (path.as_path(), definition))
+        })
+    }
+
+    /// Returns all the fragments named `name`, along with the path of the file
+    /// where they are defined.
+    pub fn fragment_definitions<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = (&'a Path, &'a GraphqlFragmentName)> + 'a {
+        self.files.iter().flat_map(move |(path, file)| {
+            file.fragment_definitions(name)
+                .map(move |definition| (path.as_path(), definition))
+        })
+    }
+
+    /// Returns `true` if a fragment named `name` is defined in any file of the project.
+    pub fn is_fragment_defined(&self, name: &str) -> bool {
+        self.fragment_definitions(name).next().is_some()
+    }
+
+    /// Returns `true` if a fragment named `name` is spread in any file of the project.
+    pub fn is_fragment_spread(&self, name: &str) -> bool {
+        self.files
+            .values()
+            .any(|file| file.fragment_spreads(name).next().is_some())
+    }
+}
+
+/// The symbols defined by a single GraphQL document.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct GraphqlFileIndex {
+    operations: Vec<GraphqlOperationName>,
+    fragments: Vec<GraphqlFragmentName>,
+    fragment_spreads: Vec<GraphqlFragmentName>,
+}
+
+impl GraphqlFileIndex {
+    /// Collects the symbols defined in the given document.
+    pub fn from_root(root: &GraphqlRoot) -> Self {
+        let mut index = Self::default();
+        for definition in root.definitions() {
+            match definition {
+                AnyGraphqlDefinition::GraphqlOperationDefinition(operation) => {
+                    if let Some(name) = operation.name() {
+                        index.operations.push(GraphqlOperationName {
+                            name: name.text(),
+                            range: name.range(),
+                        });
+                    }
+                }
+                AnyGraphqlDefinition::GraphqlFragmentDefinition(fragment) => {
+                    if let Ok(name) = fragment.name() {
+                        index.fragments.push(GraphqlFragmentName {
+                            name: name.text(),
+                            range: name.range(),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        index.fragment_spreads = root
+            .syntax()
+            .descendants()
+            .filter_map(GraphqlFragmentSpread::cast)
+            .filter_map(|spread| {
+                let name = spread.name().ok()?;
+                Some(GraphqlFragmentName {
+                    name: name.text(),
+                    range: name.range(),
+                })
+            })
+            .collect();
+
+        index
+    }
+
+    /// Adds the symbols of `other`, whose ranges start at `offset` in the file.
+    ///
+    /// This is used for the documents embedded in another file, such as the template literals
+    /// of a JavaScript file, which are all recorded under the path of that file.
+    pub fn extend(&mut self, other: Self, offset: TextSize) {
+        self.operations
+            .extend(other.operations.into_iter().map(|mut operation| {
+                operation.range += offset;
+                operation
+            }));
+        self.fragments
+            .extend(other.fragments.into_iter().map(|mut fragment| {
+                fragment.range += offset;
+                fragment
+            }));
+        self.fragment_spreads
+            .extend(other.fragment_spreads.into_iter().map(|mut spread| {
+                spread.range += offset;
+                spread
+            }));
+    }
+
+    /// Returns `true` if the file doesn't define or use any symbol.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty() && self.fragments.is_empty() && self.fragment_spreads.is_empty()
+    }
+
+    /// Returns all the named operations defined in the file.
+    pub fn operations(&self) -> &[GraphqlOperationName] {
+        &self.operations
+    }
+
+    /// Returns the definitions of the operations named `name` in the file.
+    pub fn operation_definitions<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a GraphqlOperationName> + 'a {
+        self.operations
+            .iter()
+            .filter(move |definition| definition.name == name)
+    }
+
+    /// Returns the definitions of the fragments named `name` in the file.
+    pub fn fragment_definitions<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a GraphqlFragmentName> + 'a {
+        self.fragments
+            .iter()
+            .filter(move |definition| definition.name == name)
+    }
+
+    /// Returns the spreads of the fragments named `name` in the file.
+    pub fn fragment_spreads<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a GraphqlFragmentName> + 'a {
+        self.fragment_spreads
+            .iter()
+            .filter(move |spread| spread.name == name)
+    }
+}
+
+/// The name of an operation.
+///
+/// ```graphql
+/// query User { user { name } }
+/// #     ^^^^
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct GraphqlOperationName {
+    /// The name of the operation.
+    pub name: String,
+    /// The range of the name of the operation in its file.
+    pub range: TextRange,
+}
+
+/// The name of a fragment, in its definition or in a spread.
+///
+/// ```graphql
+/// fragment UserFields on User { name }
+/// #        ^^^^^^^^^^
+/// query User { user { ...UserFields } }
+/// #                      ^^^^^^^^^^
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct GraphqlFragmentName {
+    /// The name of the fragment.
+    pub name: String,
+    /// The range of the name of the fragment in its file.
+    pub range: TextRange,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_graphql_parser::parse_graphql;
+
+    fn index(source: &str) -> GraphqlFileIndex {
+        GraphqlFileIndex::from_root(&parse_graphql(source).tree())
+    }
+
+    #[test]
+    fn collects_named_operations() {
+        let file = index(
+            r#"query User { user { name } }
+mutation UpdateUser { updateUser { name } }
+query { users { name } }
+{ users { name } }
+fragment UserFields on User { name }"#,
+        );
+
+        let names: Vec<_> = file
+            .operations()
+            .iter()
+            .map(|definition| definition.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["User", "UpdateUser"]);
+    }
+
+    #[test]
+    fn resolves_operations_across_files() {
+        let mut project = GraphqlProjectIndex::default();
+        project.insert_file(PathBuf::from("a.graphql"), index("query User { user }"));
+        project.insert_file(PathBuf::from("b.graphql"), index("query Users { users }"));
+
+        let definitions: Vec<_> = project.operation_definitions("User").collect();
+        assert_eq!(definitions.len(), 1);
+        assert_eq!(definitions[0].0, Path::new("a.graphql"));
+        assert_eq!(definitions[0].1.range, TextRange::new(6.into(), 10.into()));
+
+        project.insert_file(PathBuf::from("a.graphql"), index("query Viewer { viewer }"));
+        assert_eq!(project.operation_definitions("User").count(), 0);
+
+        project.remove_file(Path::new("b.graphql"));
+        assert_eq!(project.operation_definitions("Users").count(), 0);
+    }
+
+    #[test]
+    fn resolves_fragments_across_files() {
+        let mut project = GraphqlProjectIndex::default();
+        project.insert_file(
+            PathBuf::from("fragments.graphql"),
+            index("fragment UserFields on User { name ...AvatarFields }"),
+        );
+        let mut script = index("query User { user { ...UserFields } }");
+        script.extend(index("fragment AvatarFields on User { avatar }"), 40.into());
+        project.insert_file(PathBuf::from("user.js"), script);
+
+        assert!(project.is_fragment_defined("UserFields"));
+        assert!(project.is_fragment_defined("AvatarFields"));
+        assert!(!project.is_fragment_defined("PostFields"));
+        assert!(project.is_fragment_spread("UserFields"));
+        assert!(project.is_fragment_spread("AvatarFields"));
+
+        let definitions: Vec<_> = project.fragment_definitions("AvatarFields").collect();
+        assert_eq!(definitions[0].0, Path::new("user.js"));
+        assert_eq!(definitions[0].1.range, TextRange::new(49.into(), 61.into()));
+    }
+}
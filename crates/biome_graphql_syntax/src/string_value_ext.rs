@@ -1,4 +1,5 @@
 use crate::GraphqlStringValue;
+use biome_rowan::{SyntaxResult, TextRange, TextSize, TokenText};
 
 impl GraphqlStringValue {
     /// Check if the string is a block string
@@ -15,4 +16,18 @@ impl GraphqlStringValue {
         self.graphql_string_literal_token()
             .map_or(false, |token| token.text_trimmed().starts_with("\"\"\""))
     }
+
+    /// Returns the content of the string, without its quotes
+    ///
+    /// The escape sequences and the indentation of block strings are left as they are.
+    pub fn inner_string_text(&self) -> SyntaxResult<TokenText> {
+        let delimiter = if self.is_block() { 3 } else { 1 };
+        let text = self.graphql_string_literal_token()?.token_text_trimmed();
+        // SAFETY: string literal tokens have a delimiter at the start and the end of the string
+        let range = TextRange::new(
+            TextSize::from(delimiter),
+            text.len() - TextSize::from(delimiter),
+        );
+        Ok(text.slice(range))
+    }
 }
@@ -0,0 +1,22 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_lint_group;
+
+pub mod no_duplicate_ids;
+pub mod no_html_label_without_control;
+pub mod use_heading_order;
+pub mod use_html_alt_text;
+pub mod use_valid_landmark_roles;
+
+declare_lint_group! {
+    pub Nursery {
+        name : "nursery" ,
+        rules : [
+            self :: no_duplicate_ids :: NoDuplicateIds ,
+            self :: no_html_label_without_control :: NoHtmlLabelWithoutControl ,
+            self :: use_heading_order :: UseHeadingOrder ,
+            self :: use_html_alt_text :: UseHtmlAltText ,
+            self :: use_valid_landmark_roles :: UseValidLandmarkRoles ,
+        ]
+     }
+}
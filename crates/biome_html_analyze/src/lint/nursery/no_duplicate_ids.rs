@@ -0,0 +1,116 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_html_syntax::element_ext::AnyHtmlTag;
+use biome_html_syntax::HtmlRoot;
+use biome_rowan::{AstNode, TextRange};
+use rustc_hash::FxHashMap;
+
+declare_lint_rule! {
+    /// Disallow duplicate `id` attributes.
+    ///
+    /// The `id` of an element must be unique in the document.
+    /// The assistive technologies, the `for` attribute of the labels, the `aria-labelledby` and
+    /// `aria-describedby` attributes and the fragment of the URLs refer to the elements by their `id`,
+    /// and only reach the first element when several elements share the same `id`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <input id="name">
+    /// <input id="name">
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <input id="first-name">
+    /// <input id="last-name">
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 4.1.1](https://www.w3.org/WAI/WCAG21/Understanding/parsing)
+    ///
+    pub NoDuplicateIds {
+        version: "next",
+        name: "noDuplicateIds",
+        language: "html",
+        recommended: false,
+    }
+}
+
+pub struct DuplicateId {
+    /// The range of the value of the duplicate `id`
+    range: TextRange,
+    /// The range of the value of the first `id`
+    first: TextRange,
+    id: String,
+}
+
+impl Rule for NoDuplicateIds {
+    type Query = Ast<HtmlRoot>;
+    type State = DuplicateId;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut first_ids: FxHashMap<String, TextRange> = FxHashMap::default();
+        let mut signals = Vec::new();
+        for tag in ctx
+            .query()
+            .syntax()
+            .descendants()
+            .filter_map(AnyHtmlTag::cast)
+        {
+            let Some(attribute) = tag.find_attribute_by_name("id") else {
+                continue;
+            };
+            let Some(value) = attribute
+                .initializer()
+                .and_then(|initializer| initializer.value().ok())
+            else {
+                continue;
+            };
+            let Some(id) = attribute.value().filter(|id| !id.is_empty()) else {
+                continue;
+            };
+            let range = value.range();
+            match first_ids.get(id.text()) {
+                Some(first) => signals.push(DuplicateId {
+                    range,
+                    first: *first,
+                    id: id.to_string(),
+                }),
+                None => {
+                    first_ids.insert(id.to_string(), range);
+                }
+            }
+        }
+
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let DuplicateId { range, first, id } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "The id "<Emphasis>{id}</Emphasis>" is already used by another element."
+                },
+            )
+            .detail(
+                *first,
+                markup! {
+                    "The id is first used here."
+                },
+            )
+            .note(markup! {
+                "The elements are referred to by their id, which must be unique in the document. Rename one of the ids."
+            }),
+        )
+    }
+}
@@ -0,0 +1,141 @@
+use crate::utils::{has_accessible_content, has_non_empty_attribute};
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_html_syntax::element_ext::AnyHtmlTag;
+use biome_html_syntax::HtmlElement;
+use biome_rowan::AstNode;
+
+declare_lint_rule! {
+    /// Enforce that a `label` element has a text label and an associated control.
+    ///
+    /// This is the HTML counterpart of [noLabelWithoutControl](https://biomejs.dev/linter/rules/no-label-without-control), which checks JSX.
+    ///
+    /// A control is one of the following elements: `input`, `meter`, `output`, `progress`, `select` or `textarea`.
+    /// There are two ways to associate a label with a control:
+    /// - Wrapping the control in the `label` element.
+    /// - Adding a `for` attribute to the `label` element, set to the `id` of the control.
+    ///
+    /// The text of the label is its text content, or the `aria-label`, `aria-labelledby` or `alt`
+    /// attribute of the label or of one of its descendants.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <label for="email"></label>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <label>Email</label>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <label for="email">Email</label>
+    /// ```
+    ///
+    /// ```html
+    /// <label>Email <input type="email"></label>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 1.3.1](https://www.w3.org/WAI/WCAG21/Understanding/info-and-relationships)
+    /// - [WCAG 4.1.2](https://www.w3.org/WAI/WCAG21/Understanding/name-role-value)
+    ///
+    pub NoHtmlLabelWithoutControl {
+        version: "next",
+        name: "noHtmlLabelWithoutControl",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("label-has-associated-control")],
+        recommended: false,
+    }
+}
+
+pub struct NoHtmlLabelWithoutControlState {
+    pub has_text_content: bool,
+    pub has_control_association: bool,
+}
+
+impl Rule for NoHtmlLabelWithoutControl {
+    type Query = Ast<AnyHtmlTag>;
+    type State = NoHtmlLabelWithoutControlState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let tag = ctx.query();
+        if !tag.is_element("label") {
+            return None;
+        }
+
+        let element = match tag {
+            AnyHtmlTag::HtmlOpeningElement(opening) => opening.parent::<HtmlElement>(),
+            AnyHtmlTag::HtmlSelfClosingElement(_) => None,
+        };
+        let has_text_content = element.as_ref().map_or_else(
+            || {
+                ["aria-label", "aria-labelledby", "alt"]
+                    .iter()
+                    .any(|name| has_non_empty_attribute(tag, name))
+            },
+            has_accessible_content,
+        );
+        let has_control_association =
+            has_non_empty_attribute(tag, "for") || element.as_ref().is_some_and(has_nested_control);
+
+        if has_text_content && has_control_association {
+            return None;
+        }
+
+        Some(NoHtmlLabelWithoutControlState {
+            has_text_content,
+            has_control_association,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            node.range(),
+            markup! {
+                "A form label must be associated with a control."
+            },
+        );
+
+        if !state.has_text_content {
+            diagnostic = diagnostic.note(
+                markup! { "Consider adding an accessible text content to the label element." },
+            );
+        }
+
+        if !state.has_control_association {
+            diagnostic = diagnostic.note(
+                markup! { "Consider adding a `for` attribute to the label element or moving the control inside the label element." },
+            );
+        }
+
+        Some(diagnostic)
+    }
+}
+
+const CONTROL_ELEMENTS: [&str; 6] = ["input", "meter", "output", "progress", "select", "textarea"];
+
+/// Returns `true` if the label wraps a control.
+fn has_nested_control(label: &HtmlElement) -> bool {
+    label
+        .children()
+        .syntax()
+        .descendants()
+        .filter_map(AnyHtmlTag::cast)
+        .any(|tag| {
+            CONTROL_ELEMENTS
+                .iter()
+                .any(|control| tag.is_element(control))
+        })
+}
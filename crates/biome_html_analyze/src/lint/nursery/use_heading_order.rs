@@ -0,0 +1,129 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_html_syntax::element_ext::AnyHtmlTag;
+use biome_html_syntax::HtmlRoot;
+use biome_rowan::{AstNode, TextRange};
+
+declare_lint_rule! {
+    /// Enforce that the heading levels increase one by one.
+    ///
+    /// The assistive technologies use the headings to build an outline of the page,
+    /// which their users navigate to find the content they look for.
+    /// A heading that skips levels, such as an `<h4>` that follows an `<h2>`,
+    /// breaks the outline and suggests that some content is missing.
+    ///
+    /// The level of a heading can decrease by any number of levels, for example from `<h4>` to `<h2>`,
+    /// because a new section starts.
+    /// The first heading of the document can have any level.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <h1>Pets</h1>
+    /// <h3>Cats</h3>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <h1>Pets</h1>
+    /// <h2>Cats</h2>
+    /// <h3>Food</h3>
+    /// <h2>Dogs</h2>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 1.3.1](https://www.w3.org/WAI/WCAG21/Understanding/info-and-relationships)
+    ///
+    pub UseHeadingOrder {
+        version: "next",
+        name: "useHeadingOrder",
+        language: "html",
+        recommended: false,
+    }
+}
+
+pub struct SkippedHeading {
+    /// The range of the heading that skips levels
+    range: TextRange,
+    level: u8,
+    /// The range and the level of the previous heading
+    previous: (TextRange, u8),
+}
+
+impl Rule for UseHeadingOrder {
+    type Query = Ast<HtmlRoot>;
+    type State = SkippedHeading;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut previous: Option<(TextRange, u8)> = None;
+        let mut signals = Vec::new();
+        for tag in ctx
+            .query()
+            .syntax()
+            .descendants()
+            .filter_map(AnyHtmlTag::cast)
+        {
+            let Some(level) = heading_level(&tag) else {
+                continue;
+            };
+            let range = tag.range();
+            if let Some(previous) = previous {
+                if level > previous.1 + 1 {
+                    signals.push(SkippedHeading {
+                        range,
+                        level,
+                        previous,
+                    });
+                }
+            }
+            previous = Some((range, level));
+        }
+
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let SkippedHeading {
+            range,
+            level,
+            previous: (previous_range, previous_level),
+        } = state;
+        let expected = previous_level + 1;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "This "<Emphasis>"h"{level}</Emphasis>" heading skips levels."
+                },
+            )
+            .detail(
+                *previous_range,
+                markup! {
+                    "The previous heading is an "<Emphasis>"h"{previous_level}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The assistive technologies use the heading levels to build the outline of the page. Use an "<Emphasis>"h"{expected}</Emphasis>" heading, or a lower level."
+            }),
+        )
+    }
+}
+
+/// Returns the level of the heading, from 1 for `<h1>` to 6 for `<h6>`,
+/// or `None` if the element isn't a heading.
+fn heading_level(tag: &AnyHtmlTag) -> Option<u8> {
+    let name = tag.name_value_token().ok()?;
+    let level = name
+        .text_trimmed()
+        .strip_prefix(['h', 'H'])?
+        .parse::<u8>()
+        .ok()?;
+    (1..=6).contains(&level).then_some(level)
+}
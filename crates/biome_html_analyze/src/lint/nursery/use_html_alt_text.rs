@@ -0,0 +1,132 @@
+use crate::utils::{has_accessible_content, has_non_empty_attribute, is_aria_hidden};
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::{fmt::Display, fmt::Formatter, markup};
+use biome_html_syntax::element_ext::AnyHtmlTag;
+use biome_html_syntax::{HtmlElement, TextRange};
+use biome_rowan::AstNode;
+
+declare_lint_rule! {
+    /// Enforce that all elements that require alternative text have meaningful information to relay back to the end user.
+    ///
+    /// This is the HTML counterpart of [useAltText](https://biomejs.dev/linter/rules/use-alt-text), which checks JSX.
+    /// This rule checks for alternative text on the following elements: `<img>`, `<area>`, `<input type="image">`, and `<object>`.
+    ///
+    /// An empty `alt` attribute, such as `<img src="divider.png" alt="">`, is allowed:
+    /// it marks the image as decorative, and the assistive technologies skip it.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <img src="image.png">
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <input type="image" src="image.png">
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <img src="image.png" alt="image alt">
+    /// ```
+    ///
+    /// ```html
+    /// <input type="image" src="image.png" aria-label="alt text">
+    /// ```
+    ///
+    /// ```html
+    /// <object data="movie.mp4">A movie about cats</object>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 1.1.1](https://www.w3.org/WAI/WCAG21/Understanding/non-text-content.html)
+    ///
+    pub UseHtmlAltText {
+        version: "next",
+        name: "useHtmlAltText",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("alt-text")],
+        recommended: false,
+    }
+}
+
+pub enum ValidatedElement {
+    Object,
+    Img,
+    Area,
+    Input,
+}
+
+impl Display for ValidatedElement {
+    fn fmt(&self, fmt: &mut Formatter) -> std::io::Result<()> {
+        match self {
+            ValidatedElement::Object => fmt.write_markup(markup!(<Emphasis>"title"</Emphasis>)),
+            _ => fmt.write_markup(markup!(<Emphasis>"alt"</Emphasis>)),
+        }
+    }
+}
+
+impl Rule for UseHtmlAltText {
+    type Query = Ast<AnyHtmlTag>;
+    type State = (ValidatedElement, TextRange);
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let tag = ctx.query();
+        let has_label = has_non_empty_attribute(tag, "aria-label")
+            || has_non_empty_attribute(tag, "aria-labelledby");
+        if has_label || is_aria_hidden(tag) {
+            return None;
+        }
+
+        let name = tag.name_value_token().ok()?;
+        let element = match name.text_trimmed().to_ascii_lowercase().as_str() {
+            "object" => {
+                if has_non_empty_attribute(tag, "title") {
+                    return None;
+                }
+                // The content of an object is displayed when the object can't be rendered
+                let has_content = tag
+                    .parent::<HtmlElement>()
+                    .is_some_and(|element| has_accessible_content(&element));
+                if has_content {
+                    return None;
+                }
+                ValidatedElement::Object
+            }
+            // An empty `alt` marks the image as decorative
+            "img" if !tag.has_attribute("alt") => ValidatedElement::Img,
+            "area" if !tag.has_attribute("alt") => ValidatedElement::Area,
+            "input" if !tag.has_attribute("alt") && has_type_image_attribute(tag) => {
+                ValidatedElement::Input
+            }
+            _ => return None,
+        };
+
+        Some((element, tag.range()))
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (validate_element, range) = state;
+        let message = markup!(
+            "Provide a text alternative through the "{{validate_element}}", "<Emphasis>"aria-label"</Emphasis>" or "<Emphasis>"aria-labelledby"</Emphasis>" attribute"
+        ).to_owned();
+        Some(
+            RuleDiagnostic::new(rule_category!(), range, message).note(markup! {
+                "Meaningful alternative text on elements helps users relying on screen readers to understand content's purpose within a page."
+            }).note(markup! { "If the content is decorative, redundant, or obscured, consider hiding it from assistive technologies with the "<Emphasis>"aria-hidden"</Emphasis>" attribute."}),
+        )
+    }
+}
+
+fn has_type_image_attribute(tag: &AnyHtmlTag) -> bool {
+    tag.find_attribute_by_name("type")
+        .and_then(|attribute| attribute.value())
+        .is_some_and(|value| value.eq_ignore_ascii_case("image"))
+}
@@ -0,0 +1,157 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_aria::AriaRoles;
+use biome_aria_metadata::AriaRole;
+use biome_console::markup;
+use biome_html_syntax::element_ext::AnyHtmlTag;
+use biome_html_syntax::HtmlRoot;
+use biome_rowan::{AstNode, TextRange};
+
+declare_lint_rule! {
+    /// Enforce the valid usage of the landmark roles.
+    ///
+    /// The landmarks, such as `<main>`, `<nav>` or the elements with `role="search"`, are the regions of the page
+    /// that the users of assistive technologies navigate to.
+    /// This rule reports:
+    /// - the landmark roles that are redundant, because the element already has this role implicitly,
+    ///   such as `<nav role="navigation">`. Like [noRedundantRoles](https://biomejs.dev/linter/rules/no-redundant-roles),
+    ///   the implicit roles are the ones of the [HTML-ARIA](https://www.w3.org/TR/html-aria/) specification;
+    /// - the documents that have several `main` landmarks, which aren't `hidden`.
+    ///   The `main` landmark is the main content of the document, which is unique.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <nav role="navigation"></nav>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <main>Cats</main>
+    /// <div role="main">Dogs</div>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <nav></nav>
+    /// <main>Cats</main>
+    /// <main hidden>Dogs</main>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 1.3.1](https://www.w3.org/WAI/WCAG21/Understanding/info-and-relationships)
+    ///
+    pub UseValidLandmarkRoles {
+        version: "next",
+        name: "useValidLandmarkRoles",
+        language: "html",
+        recommended: false,
+    }
+}
+
+pub enum InvalidLandmark {
+    /// The `role` attribute, whose value is the implicit role of the element
+    Redundant {
+        range: TextRange,
+        role: AriaRole,
+        element: String,
+    },
+    /// A `main` landmark that follows another one, at the given range
+    DuplicateMain { range: TextRange, first: TextRange },
+}
+
+impl Rule for UseValidLandmarkRoles {
+    type Query = Ast<HtmlRoot>;
+    type State = InvalidLandmark;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let aria_roles = AriaRoles;
+        let mut first_main = None;
+        let mut signals = Vec::new();
+        for tag in ctx
+            .query()
+            .syntax()
+            .descendants()
+            .filter_map(AnyHtmlTag::cast)
+        {
+            let implicit_role = aria_roles.get_implicit_role(&tag);
+            let role_attribute = tag.find_attribute_by_name("role");
+            let explicit_role = role_attribute
+                .as_ref()
+                .and_then(|attribute| attribute.value())
+                .and_then(|value| AriaRole::from_roles(&value));
+
+            if let (Some(attribute), Some(role)) = (&role_attribute, explicit_role) {
+                if role.is_landmark() && implicit_role == Some(role) {
+                    signals.push(InvalidLandmark::Redundant {
+                        range: attribute.range(),
+                        role,
+                        element: tag
+                            .name_value_token()
+                            .map(|name| name.text_trimmed().to_string())
+                            .unwrap_or_default(),
+                    });
+                }
+            }
+
+            // The hidden landmarks, such as the main content of the other pages of an application,
+            // aren't exposed to the assistive technologies
+            if explicit_role.or(implicit_role) == Some(AriaRole::Main)
+                && !tag.has_attribute("hidden")
+            {
+                let range = tag.range();
+                match first_main {
+                    Some(first) => {
+                        signals.push(InvalidLandmark::DuplicateMain { range, first });
+                    }
+                    None => first_main = Some(range),
+                }
+            }
+        }
+
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            InvalidLandmark::Redundant {
+                range,
+                role,
+                element,
+            } => {
+                let role = role.as_str();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    *range,
+                    markup! {
+                        "Using the role attribute '"{role}"' on the '"{element}"' element is redundant, because it is implied by its semantic."
+                    },
+                )
+                .note(markup! {
+                    "Remove the "<Emphasis>"role"</Emphasis>" attribute."
+                })
+            }
+            InvalidLandmark::DuplicateMain { range, first } => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "The document has more than one "<Emphasis>"main"</Emphasis>" landmark."
+                },
+            )
+            .detail(
+                *first,
+                markup! {
+                    "The first "<Emphasis>"main"</Emphasis>" landmark is here."
+                },
+            )
+            .note(markup! {
+                "The "<Emphasis>"main"</Emphasis>" landmark holds the main content of the document, which is unique. Remove this landmark, or add the "<Emphasis>"hidden"</Emphasis>" attribute to the landmarks that aren't displayed."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
@@ -0,0 +1,12 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::lint;
+
+pub type NoDuplicateIds =
+    <lint::nursery::no_duplicate_ids::NoDuplicateIds as biome_analyze::Rule>::Options;
+pub type NoHtmlLabelWithoutControl = < lint :: nursery :: no_html_label_without_control :: NoHtmlLabelWithoutControl as biome_analyze :: Rule > :: Options ;
+pub type UseHeadingOrder =
+    <lint::nursery::use_heading_order::UseHeadingOrder as biome_analyze::Rule>::Options;
+pub type UseHtmlAltText =
+    <lint::nursery::use_html_alt_text::UseHtmlAltText as biome_analyze::Rule>::Options;
+pub type UseValidLandmarkRoles = < lint :: nursery :: use_valid_landmark_roles :: UseValidLandmarkRoles as biome_analyze :: Rule > :: Options ;
@@ -0,0 +1,38 @@
+use biome_html_syntax::element_ext::AnyHtmlTag;
+use biome_html_syntax::{AnyHtmlElement, HtmlContent, HtmlElement};
+use biome_rowan::AstNode;
+
+/// Returns `true` if the element has an attribute named `name` whose value isn't blank.
+pub(crate) fn has_non_empty_attribute(tag: &AnyHtmlTag, name: &str) -> bool {
+    tag.find_attribute_by_name(name)
+        .and_then(|attribute| attribute.value())
+        .is_some_and(|value| !value.trim().is_empty())
+}
+
+/// Returns `true` if the element is hidden from the assistive technologies with `aria-hidden="true"`.
+pub(crate) fn is_aria_hidden(tag: &AnyHtmlTag) -> bool {
+    tag.find_attribute_by_name("aria-hidden")
+        .and_then(|attribute| attribute.value())
+        .is_some_and(|value| value.eq_ignore_ascii_case("true"))
+}
+
+/// Returns `true` if the element has a text that the assistive technologies can announce:
+/// a text content, or an `alt`, `aria-label` or `aria-labelledby` attribute on the element
+/// or on one of its descendants, such as `<img alt="Logo">`.
+pub(crate) fn has_accessible_content(element: &HtmlElement) -> bool {
+    element.syntax().descendants().any(|node| {
+        if let Some(content) = HtmlContent::cast_ref(&node) {
+            return content
+                .value_token()
+                .is_ok_and(|token| !token.text_trimmed().trim().is_empty());
+        }
+        AnyHtmlElement::cast(node)
+            .and_then(|element| element.tag())
+            .is_some_and(|tag| {
+                !is_aria_hidden(&tag)
+                    && ["alt", "aria-label", "aria-labelledby"]
+                        .iter()
+                        .any(|name| has_non_empty_attribute(&tag, name))
+            })
+    })
+}
@@ -179,6 +179,12 @@ pub fn html_opening_element(
         ],
     ))
 }
+pub fn html_passthrough(value_token: SyntaxToken) -> HtmlPassthrough {
+    HtmlPassthrough::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::HTML_PASSTHROUGH,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
 pub fn html_root(html: HtmlElementList, eof_token: SyntaxToken) -> HtmlRootBuilder {
     HtmlRootBuilder {
         html,
@@ -1,8 +1,10 @@
-use std::{fmt, rc::Rc};
+use std::{fmt, rc::Rc, str::FromStr};
 
+use biome_deserialize_macros::{Deserializable, Merge};
 use biome_formatter::{
-    printer::PrinterOptions, AttributePosition, BracketSpacing, CstFormatContext, FormatContext,
-    FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth, TransformSourceMap,
+    printer::PrinterOptions, AttributePosition, BracketSameLine, BracketSpacing, CstFormatContext,
+    FormatContext, FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth,
+    TransformSourceMap,
 };
 use biome_html_syntax::{HtmlFileSource, HtmlLanguage};
 
@@ -24,6 +26,12 @@ pub struct HtmlFormatOptions {
 
     /// Attribute position style. By default auto.
     attribute_position: AttributePosition,
+
+    /// Whether to hug the closing bracket of multiline HTML tags to the end of the last line, rather than being alone on the following line. Defaults to false.
+    bracket_same_line: BracketSameLine,
+
+    /// Whether the whitespace around and inside the elements is significant. Defaults to css.
+    whitespace_sensitivity: WhitespaceSensitivity,
 }
 
 impl HtmlFormatOptions {
@@ -58,6 +66,19 @@ impl HtmlFormatOptions {
         self
     }
 
+    pub fn with_bracket_same_line(mut self, bracket_same_line: BracketSameLine) -> Self {
+        self.bracket_same_line = bracket_same_line;
+        self
+    }
+
+    pub fn with_whitespace_sensitivity(
+        mut self,
+        whitespace_sensitivity: WhitespaceSensitivity,
+    ) -> Self {
+        self.whitespace_sensitivity = whitespace_sensitivity;
+        self
+    }
+
     pub fn indent_style(&self) -> IndentStyle {
         self.indent_style
     }
@@ -78,6 +99,14 @@ impl HtmlFormatOptions {
         self.attribute_position
     }
 
+    pub fn bracket_same_line(&self) -> BracketSameLine {
+        self.bracket_same_line
+    }
+
+    pub fn whitespace_sensitivity(&self) -> WhitespaceSensitivity {
+        self.whitespace_sensitivity
+    }
+
     pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
         self.indent_style = indent_style;
     }
@@ -97,6 +126,14 @@ impl HtmlFormatOptions {
     pub fn set_attribute_position(&mut self, attribute_position: AttributePosition) {
         self.attribute_position = attribute_position;
     }
+
+    pub fn set_bracket_same_line(&mut self, bracket_same_line: BracketSameLine) {
+        self.bracket_same_line = bracket_same_line;
+    }
+
+    pub fn set_whitespace_sensitivity(&mut self, whitespace_sensitivity: WhitespaceSensitivity) {
+        self.whitespace_sensitivity = whitespace_sensitivity;
+    }
 }
 
 impl fmt::Display for HtmlFormatOptions {
@@ -105,7 +142,9 @@ impl fmt::Display for HtmlFormatOptions {
         writeln!(f, "Indent width: {}", self.indent_width.value())?;
         writeln!(f, "Line ending: {}", self.line_ending)?;
         writeln!(f, "Line width: {}", self.line_width.value())?;
-        writeln!(f, "Attribute Position: {}", self.attribute_position)
+        writeln!(f, "Attribute Position: {}", self.attribute_position)?;
+        writeln!(f, "Bracket same line: {}", self.bracket_same_line)?;
+        writeln!(f, "Whitespace sensitivity: {}", self.whitespace_sensitivity)
     }
 }
 
@@ -139,6 +178,50 @@ impl FormatOptions for HtmlFormatOptions {
     }
 }
 
+/// Whether the whitespace around and inside the HTML elements is significant.
+///
+/// Browsers render the whitespace between the inline elements, such as `<span>` or `<a>`,
+/// so adding or removing it can change how the document looks.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Deserializable, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum WhitespaceSensitivity {
+    /// The whitespace around and inside the inline elements is significant,
+    /// following the default CSS `display` of the elements
+    #[default]
+    Css,
+    /// The whitespace around and inside all the elements is significant
+    Strict,
+    /// The whitespace is never significant, and the formatter can freely add or remove it
+    Ignore,
+}
+
+impl FromStr for WhitespaceSensitivity {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "css" => Ok(Self::Css),
+            "strict" => Ok(Self::Strict),
+            "ignore" => Ok(Self::Ignore),
+            _ => Err("Value not supported for WhitespaceSensitivity"),
+        }
+    }
+}
+
+impl fmt::Display for WhitespaceSensitivity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WhitespaceSensitivity::Css => std::write!(f, "Css"),
+            WhitespaceSensitivity::Strict => std::write!(f, "Strict"),
+            WhitespaceSensitivity::Ignore => std::write!(f, "Ignore"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct HtmlFormatContext {
     options: HtmlFormatOptions,
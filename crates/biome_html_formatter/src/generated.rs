@@ -352,6 +352,46 @@ impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlOpeningElement {
         )
     }
 }
+impl FormatRule<biome_html_syntax::HtmlPassthrough>
+    for crate::html::auxiliary::passthrough::FormatHtmlPassthrough
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::HtmlPassthrough,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlPassthrough>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlPassthrough {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlPassthrough,
+        crate::html::auxiliary::passthrough::FormatHtmlPassthrough,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::auxiliary::passthrough::FormatHtmlPassthrough::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlPassthrough {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlPassthrough,
+        crate::html::auxiliary::passthrough::FormatHtmlPassthrough,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::auxiliary::passthrough::FormatHtmlPassthrough::default(),
+        )
+    }
+}
 impl FormatRule<biome_html_syntax::HtmlRoot> for crate::html::auxiliary::root::FormatHtmlRoot {
     type Context = HtmlFormatContext;
     #[inline(always)]
@@ -1,6 +1,8 @@
 use crate::prelude::*;
-use biome_formatter::write;
+use crate::utils::metadata::{element_name, is_element_in, HTML_PREFORMATTED_ELEMENTS};
+use biome_formatter::{normalize_newlines, write, CstFormatContext, LINE_TERMINATORS};
 use biome_html_syntax::{HtmlElement, HtmlElementFields};
+use biome_rowan::{Direction, SyntaxElement};
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatHtmlElement;
 impl FormatNodeRule<HtmlElement> for FormatHtmlElement {
@@ -11,6 +13,19 @@ impl FormatNodeRule<HtmlElement> for FormatHtmlElement {
             closing_element,
         } = node.as_fields();
 
+        let is_preformatted = element_name(&node.clone().into())
+            .is_some_and(|name| is_element_in(name.text(), HTML_PREFORMATTED_ELEMENTS));
+        if is_preformatted {
+            return write!(
+                f,
+                [
+                    opening_element.format(),
+                    FormatPreformattedChildren { element: node },
+                    closing_element.format(),
+                ]
+            );
+        }
+
         write!(
             f,
             [
@@ -23,3 +38,48 @@ impl FormatNodeRule<HtmlElement> for FormatHtmlElement {
         Ok(())
     }
 }
+
+/// Prints the children of a `<pre>` or a `<textarea>` as they are written,
+/// including the whitespace that follows the opening tag and precedes the closing tag.
+struct FormatPreformattedChildren<'a> {
+    element: &'a HtmlElement,
+}
+
+impl Format<HtmlFormatContext> for FormatPreformattedChildren<'_> {
+    fn fmt(&self, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let r_angle_token = self.element.opening_element()?.r_angle_token()?;
+        let l_angle_token = self.element.closing_element()?.l_angle_token()?;
+        let children = self.element.children();
+
+        for element in children.syntax().descendants_with_tokens(Direction::Next) {
+            match element {
+                SyntaxElement::Token(token) => f.state_mut().track_token(&token),
+                SyntaxElement::Node(node) => {
+                    let comments = f.context().comments();
+                    comments.mark_suppression_checked(&node);
+
+                    for comment in comments.leading_dangling_trailing_comments(&node) {
+                        comment.mark_formatted();
+                    }
+                }
+            }
+        }
+
+        // The whitespace around the children is the trivia of the tags
+        let content = format!(
+            "{}{}{}",
+            r_angle_token.trailing_trivia().text(),
+            children.syntax(),
+            l_angle_token.leading_trivia().text()
+        );
+        if content.is_empty() {
+            return Ok(());
+        }
+
+        dynamic_text(
+            &normalize_newlines(&content, LINE_TERMINATORS),
+            r_angle_token.text_trimmed_range().end(),
+        )
+        .fmt(f)
+    }
+}
@@ -9,6 +9,7 @@ pub(crate) mod directive;
 pub(crate) mod element;
 pub(crate) mod name;
 pub(crate) mod opening_element;
+pub(crate) mod passthrough;
 pub(crate) mod root;
 pub(crate) mod self_closing_element;
 pub(crate) mod string;
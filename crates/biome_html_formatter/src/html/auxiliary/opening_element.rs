@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use biome_formatter::write;
+use biome_formatter::{format_args, write};
 use biome_html_syntax::{HtmlOpeningElement, HtmlOpeningElementFields};
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatHtmlOpeningElement;
@@ -12,9 +12,20 @@ impl FormatNodeRule<HtmlOpeningElement> for FormatHtmlOpeningElement {
             r_angle_token,
         } = node.as_fields();
 
-        write!(f, [l_angle_token.format(), name.format(),])?;
-        if attributes.len() > 0 {
-            write!(f, [space(), attributes.format()])?
+        write!(f, [l_angle_token.format(), name.format()])?;
+        if !attributes.is_empty() {
+            let bracket_same_line = f.options().bracket_same_line().value();
+            write!(
+                f,
+                [group(&format_args![
+                    indent(&format_args![
+                        soft_line_break_or_space(),
+                        attributes.format()
+                    ]),
+                    // When the attributes break, the `>` is printed on its own line
+                    (!bracket_same_line).then_some(soft_line_break()),
+                ])]
+            )?;
         }
         write!(f, [r_angle_token.format()])?;
 
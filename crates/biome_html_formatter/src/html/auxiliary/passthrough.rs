@@ -0,0 +1,10 @@
+use crate::prelude::*;
+use biome_html_syntax::HtmlPassthrough;
+use biome_rowan::AstNode;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatHtmlPassthrough;
+impl FormatNodeRule<HtmlPassthrough> for FormatHtmlPassthrough {
+    fn fmt_fields(&self, node: &HtmlPassthrough, f: &mut HtmlFormatter) -> FormatResult<()> {
+        format_verbatim_node(node.syntax()).fmt(f)
+    }
+}
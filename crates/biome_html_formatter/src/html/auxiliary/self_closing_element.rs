@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use biome_formatter::write;
+use biome_formatter::{format_args, write};
 use biome_html_syntax::{HtmlSelfClosingElement, HtmlSelfClosingElementFields};
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatHtmlSelfClosingElement;
@@ -13,16 +13,27 @@ impl FormatNodeRule<HtmlSelfClosingElement> for FormatHtmlSelfClosingElement {
             r_angle_token,
         } = node.as_fields();
 
-        write!(
-            f,
-            [
-                l_angle_token.format(),
-                name.format(),
-                space(),
-                attributes.format(),
-                space(),
-            ]
-        )?;
+        write!(f, [l_angle_token.format(), name.format()])?;
+        if attributes.is_empty() {
+            write!(f, [space()])?;
+        } else {
+            let bracket_same_line = f.options().bracket_same_line().value();
+            write!(
+                f,
+                [group(&format_args![
+                    indent(&format_args![
+                        soft_line_break_or_space(),
+                        attributes.format()
+                    ]),
+                    // When the attributes break, the `/>` is printed on its own line
+                    format_with(|f| if bracket_same_line {
+                        write!(f, [space()])
+                    } else {
+                        write!(f, [soft_line_break_or_space()])
+                    }),
+                ])]
+            )?;
+        }
 
         if slash_token.is_some() {
             write!(f, [slash_token.format()])?;
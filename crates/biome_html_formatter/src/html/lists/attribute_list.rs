@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use biome_formatter::{write, AttributePosition};
+use biome_formatter::AttributePosition;
 use biome_html_syntax::HtmlAttributeList;
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatHtmlAttributeList;
@@ -12,13 +12,8 @@ impl FormatRule<HtmlAttributeList> for FormatHtmlAttributeList {
             soft_line_break_or_space()
         };
 
-        write!(
-            f,
-            [&group(&soft_block_indent(&format_with(|f| {
-                f.join_with(&line_break)
-                    .entries(node.iter().formatted())
-                    .finish()
-            })))]
-        )
+        f.join_with(&line_break)
+            .entries(node.iter().formatted())
+            .finish()
     }
 }
@@ -5,13 +5,17 @@ use std::cell::RefCell;
 use crate::{
     comments::HtmlComments,
     prelude::*,
-    utils::children::{
-        html_split_children, is_meaningful_html_text, HtmlChild, HtmlChildrenIterator, HtmlSpace,
+    utils::{
+        children::{
+            html_split_children, is_meaningful_html_text, HtmlChild, HtmlChildrenIterator,
+            HtmlSpace,
+        },
+        metadata::is_whitespace_sensitive,
     },
 };
 use biome_formatter::{best_fitting, prelude::*, CstFormatContext};
 use biome_formatter::{format_args, write, VecBuffer};
-use biome_html_syntax::{AnyHtmlElement, HtmlElementList, HtmlRoot};
+use biome_html_syntax::{AnyHtmlElement, HtmlElement, HtmlElementList, HtmlRoot};
 use tag::GroupMode;
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatHtmlElementList {
@@ -69,26 +73,82 @@ impl FormatHtmlElementList {
     ) -> FormatResult<FormatChildrenResult> {
         self.disarm_debug_assertions(list, f);
 
+        let sensitivity = f.options().whitespace_sensitivity();
+        let parent = list.syntax().parent();
+        let is_root_parent = parent
+            .as_ref()
+            .is_some_and(|parent| HtmlRoot::can_cast(parent.kind()));
+        // The whitespace inside an inline element, such as `<span> a </span>`, can't be removed
+        let sensitive_parent = parent
+            .and_then(HtmlElement::cast)
+            .filter(|element| is_whitespace_sensitive(&element.clone().into(), sensitivity));
+
         let children_meta = self.children_meta(list, f.context().comments());
-        let layout = self.layout(children_meta);
+        let mut layout = self.layout(children_meta);
 
-        let multiline_layout = if children_meta.meaningful_text {
+        let mut multiline_layout = if children_meta.meaningful_text {
             MultilineLayout::Fill
         } else {
             MultilineLayout::NoFill
         };
 
-        let is_root_parent = list
-            .syntax()
-            .parent()
-            .is_some_and(|parent| HtmlRoot::can_cast(parent.kind()));
+        let mut children = html_split_children(list.iter(), f.context().comments())?;
+
+        let multiline_mode = if let Some(parent) = &sensitive_parent {
+            // The whitespace that follows the opening tag and precedes the closing tag is
+            // the trivia of the tags
+            let leading_whitespace = matches!(
+                children.first(),
+                Some(HtmlChild::Whitespace | HtmlChild::Newline | HtmlChild::EmptyLine)
+            ) || parent
+                .opening_element()
+                .and_then(|element| element.r_angle_token())
+                .is_ok_and(|token| !token.trailing_trivia().is_empty());
+            let trailing_whitespace = matches!(
+                children.last(),
+                Some(HtmlChild::Whitespace | HtmlChild::Newline | HtmlChild::EmptyLine)
+            ) || parent
+                .closing_element()
+                .and_then(|element| element.l_angle_token())
+                .is_ok_and(|token| !token.leading_trivia().is_empty());
+
+            if let Some(HtmlChild::Whitespace | HtmlChild::Newline | HtmlChild::EmptyLine) =
+                children.last()
+            {
+                children.pop();
+            }
+            if let Some(HtmlChild::Whitespace | HtmlChild::Newline | HtmlChild::EmptyLine) =
+                children.first()
+            {
+                children.remove(0);
+            }
+
+            // The children of an inline element flow with the surrounding text
+            layout = HtmlChildListLayout::BestFitting;
+            multiline_layout = MultilineLayout::Fill;
+
+            MultilineMode::WhitespaceSensitive {
+                leading_whitespace,
+                trailing_whitespace,
+            }
+        } else if is_root_parent {
+            MultilineMode::Root
+        } else {
+            MultilineMode::Block
+        };
 
         let mut flat = FlatBuilder::new();
-        let mut multiline = MultilineBuilder::new(multiline_layout, is_root_parent);
+        let mut multiline = MultilineBuilder::new(multiline_layout, multiline_mode);
 
         let mut force_multiline = layout.is_multiline();
 
-        let mut children = html_split_children(list.iter(), f.context().comments())?;
+        if let MultilineMode::WhitespaceSensitive {
+            leading_whitespace: true,
+            ..
+        } = multiline_mode
+        {
+            flat.write(&space(), f);
+        }
 
         // Trim trailing new lines
         if let Some(HtmlChild::EmptyLine | HtmlChild::Newline) = children.last() {
@@ -114,6 +174,14 @@ impl FormatHtmlElementList {
                             Some(WordSeparator::BetweenWords)
                         }
 
+                        // The whitespace sensitive element that follows the word without whitespace
+                        // in between must stay attached to it: `a<b>c</b>`
+                        Some(HtmlChild::NonText(next_child))
+                            if is_whitespace_sensitive(next_child, sensitivity) =>
+                        {
+                            Some(WordSeparator::Hug)
+                        }
+
                         // Last word or last word before an element without any whitespace in between
                         Some(HtmlChild::NonText(next_child)) => Some(WordSeparator::EndOfText {
                             is_soft_line_break: !matches!(
@@ -221,7 +289,18 @@ impl FormatHtmlElementList {
 
                 // Any child that isn't text
                 HtmlChild::NonText(non_text) => {
-                    let line_mode = match children_iter.peek() {
+                    let is_sensitive = is_whitespace_sensitive(non_text, sensitivity);
+                    let format_separator = match children_iter.peek() {
+                        // A whitespace sensitive element must stay attached to the text that follows it
+                        // without whitespace in between: `<b>a</b>c`
+                        Some(HtmlChild::Word(_)) if is_sensitive => Some(ElementSeparator::Hug),
+
+                        Some(HtmlChild::NonText(next_child))
+                            if is_sensitive || is_whitespace_sensitive(next_child, sensitivity) =>
+                        {
+                            Some(ElementSeparator::Hug)
+                        }
+
                         Some(HtmlChild::Word(word)) => {
                             // Break if the current or next element is a self closing element
                             // ```javascript
@@ -235,14 +314,14 @@ impl FormatHtmlElementList {
                             if matches!(non_text, AnyHtmlElement::HtmlSelfClosingElement(_))
                                 && !word.is_single_character()
                             {
-                                Some(LineMode::Hard)
+                                Some(ElementSeparator::Line(LineMode::Hard))
                             } else {
-                                Some(LineMode::Soft)
+                                Some(ElementSeparator::Line(LineMode::Soft))
                             }
                         }
 
                         // Add a hard line break if what comes after the element is not a text or is all whitespace
-                        Some(HtmlChild::NonText(_)) => Some(LineMode::Hard),
+                        Some(HtmlChild::NonText(_)) => Some(ElementSeparator::Line(LineMode::Hard)),
 
                         Some(HtmlChild::Newline | HtmlChild::Whitespace | HtmlChild::EmptyLine) => {
                             None
@@ -251,11 +330,10 @@ impl FormatHtmlElementList {
                         None => None,
                     };
 
-                    child_breaks = line_mode.map_or(false, |mode| mode.is_hard());
-
-                    let format_separator = line_mode.map(|mode| {
-                        format_with(move |f| f.write_element(FormatElement::Line(mode)))
-                    });
+                    child_breaks = matches!(
+                        format_separator,
+                        Some(ElementSeparator::Line(LineMode::Hard))
+                    );
 
                     if force_multiline {
                         if let Some(format_separator) = format_separator {
@@ -292,6 +370,14 @@ impl FormatHtmlElementList {
             last = Some(child);
         }
 
+        if let MultilineMode::WhitespaceSensitive {
+            trailing_whitespace: true,
+            ..
+        } = multiline_mode
+        {
+            flat.write(&space(), f);
+        }
+
         if force_multiline {
             Ok(FormatChildrenResult::ForceMultiline(multiline.finish()?))
         } else {
@@ -428,6 +514,14 @@ enum WordSeparator {
     /// );
     /// ```
     EndOfText { is_soft_line_break: bool },
+
+    /// A word that is directly followed by a whitespace sensitive element. Nothing is inserted
+    /// between them, because a line break would add a whitespace.
+    ///
+    /// ```html
+    /// <p>a<b>c</b></p>
+    /// ```
+    Hug,
 }
 
 impl WordSeparator {
@@ -465,10 +559,55 @@ impl Format<HtmlFormatContext> for WordSeparator {
                     hard_line_break().fmt(f)
                 }
             }
+            WordSeparator::Hug => Ok(()),
+        }
+    }
+}
+
+/// The separator that follows an element that isn't a text.
+#[derive(Copy, Clone, Debug)]
+enum ElementSeparator {
+    /// A line break, either soft or hard
+    Line(LineMode),
+
+    /// Nothing is inserted between a whitespace sensitive element and the text or element
+    /// that follows it, because a line break would add a whitespace.
+    ///
+    /// ```html
+    /// <p><b>a</b>c</p>
+    /// ```
+    Hug,
+}
+
+impl Format<HtmlFormatContext> for ElementSeparator {
+    fn fmt(&self, f: &mut Formatter<HtmlFormatContext>) -> FormatResult<()> {
+        match self {
+            ElementSeparator::Line(mode) => f.write_element(FormatElement::Line(*mode)),
+            ElementSeparator::Hug => Ok(()),
         }
     }
 }
 
+/// How the children are printed when they break over multiple lines.
+#[derive(Copy, Clone, Debug)]
+enum MultilineMode {
+    /// The children of the root node, which aren't indented
+    Root,
+
+    /// The children are printed in an indented block, between the line of the opening tag
+    /// and the line of the closing tag
+    Block,
+
+    /// The children of a whitespace sensitive element, such as a `<span>`. The lines are only broken
+    /// where the source has whitespace, and the tags hug the children otherwise.
+    WhitespaceSensitive {
+        /// Whether there's whitespace between the opening tag and the first child
+        leading_whitespace: bool,
+        /// Whether there's whitespace between the last child and the closing tag
+        trailing_whitespace: bool,
+    },
+}
+
 #[derive(Copy, Clone, Debug, Default)]
 enum MultilineLayout {
     Fill,
@@ -491,15 +630,15 @@ enum MultilineLayout {
 #[derive(Debug, Clone)]
 struct MultilineBuilder {
     layout: MultilineLayout,
-    is_root: bool,
+    mode: MultilineMode,
     result: FormatResult<Vec<FormatElement>>,
 }
 
 impl MultilineBuilder {
-    fn new(layout: MultilineLayout, is_root: bool) -> Self {
+    fn new(layout: MultilineLayout, mode: MultilineMode) -> Self {
         Self {
             layout,
-            is_root,
+            mode,
             result: Ok(Vec::new()),
         }
     }
@@ -570,7 +709,7 @@ impl MultilineBuilder {
     fn finish(self) -> FormatResult<FormatMultilineChildren> {
         Ok(FormatMultilineChildren {
             layout: self.layout,
-            is_root: self.is_root,
+            mode: self.mode,
             elements: RefCell::new(self.result?),
         })
     }
@@ -579,7 +718,7 @@ impl MultilineBuilder {
 #[derive(Debug)]
 pub(crate) struct FormatMultilineChildren {
     layout: MultilineLayout,
-    is_root: bool,
+    mode: MultilineMode,
     elements: RefCell<Vec<FormatElement>>,
 }
 
@@ -605,9 +744,27 @@ impl Format<HtmlFormatContext> for FormatMultilineChildren {
 
             Ok(())
         });
-        // We do not need the block ident when the list node is at the html root node
-        if self.is_root {
-            return write!(f, [format_inner]);
+        match self.mode {
+            // We do not need the block ident when the list node is at the html root node
+            MultilineMode::Root => return write!(f, [format_inner]),
+            // The line breaks around the children of a whitespace sensitive element replace
+            // the whitespace of the source, and can't be added where there's none
+            MultilineMode::WhitespaceSensitive {
+                leading_whitespace,
+                trailing_whitespace,
+            } => {
+                return write!(
+                    f,
+                    [group(&format_args![
+                        indent(&format_args![
+                            leading_whitespace.then_some(soft_line_break_or_space()),
+                            format_inner
+                        ]),
+                        trailing_whitespace.then_some(soft_line_break_or_space())
+                    ])]
+                );
+            }
+            MultilineMode::Block => {}
         }
 
         // This indent is wrapped with a group to ensure that the print mode is
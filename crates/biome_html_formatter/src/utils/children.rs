@@ -7,7 +7,7 @@ use biome_formatter::{
     format_args, prelude::*, write, Buffer, Format, FormatElement, FormatResult,
 };
 use biome_html_syntax::AnyHtmlElement;
-use biome_rowan::{SyntaxResult, TextLen, TextRange, TextSize, TokenText};
+use biome_rowan::{AstNode, SyntaxResult, TextLen, TextRange, TextSize, TokenText};
 
 use crate::{comments::HtmlComments, context::HtmlFormatContext, HtmlFormatter};
 
@@ -275,7 +275,20 @@ where
                     }
                 }
 
+                // The whitespace that follows the element on the same line is the trailing trivia of its last token.
+                // It's dropped when the line ends right after it.
+                let has_trailing_whitespace = text.ends_with([' ', '\t'])
+                    && !child
+                        .syntax()
+                        .last_token()
+                        .and_then(|token| token.next_token())
+                        .and_then(|token| token.leading_trivia().pieces().next())
+                        .is_some_and(|piece| piece.is_newline());
+
                 builder.entry(HtmlChild::NonText(child));
+                if has_trailing_whitespace {
+                    builder.entry(HtmlChild::Whitespace);
+                }
                 prev_was_content = false;
             }
         }
@@ -287,6 +300,7 @@ where
 /// The builder is used to:
 /// 1. Remove [HtmlChild::EmptyLine], [HtmlChild::Newline], [HtmlChild::Whitespace] if a next element is [HtmlChild::Whitespace]
 /// 2. Don't push a new element [HtmlChild::EmptyLine], [HtmlChild::Newline], [HtmlChild::Whitespace] if previous one is [HtmlChild::EmptyLine], [HtmlChild::Newline], [HtmlChild::Whitespace]
+/// 3. Replace a [HtmlChild::Whitespace] with the [HtmlChild::EmptyLine] or [HtmlChild::Newline] that follows it
 ///
 /// [Prettier applies]: https://github.com/prettier/prettier/blob/b0d9387b95cdd4e9d50f5999d3be53b0b5d03a97/src/language-js/print/jsx.js#L144-L180
 #[derive(Debug)]
@@ -304,6 +318,10 @@ impl HtmlSplitChildrenBuilder {
             Some(last @ (HtmlChild::EmptyLine | HtmlChild::Newline | HtmlChild::Whitespace)) => {
                 if matches!(child, HtmlChild::Whitespace) {
                     *last = child;
+                } else if matches!(last, HtmlChild::Whitespace)
+                    && matches!(child, HtmlChild::EmptyLine | HtmlChild::Newline)
+                {
+                    *last = child;
                 } else if matches!(child, HtmlChild::NonText(_) | HtmlChild::Word(_)) {
                     self.buffer.push(child);
                 }
@@ -0,0 +1,52 @@
+use biome_html_syntax::AnyHtmlElement;
+use biome_rowan::TokenText;
+
+use crate::context::WhitespaceSensitivity;
+
+/// The elements whose default CSS `display` is `inline` or `inline-block`.
+///
+/// Browsers render the whitespace around these elements, so the formatter must neither add nor remove it.
+/// See [Prettier's list](https://github.com/prettier/prettier/blob/main/src/language-html/constants.evaluate.js).
+pub(crate) const HTML_INLINE_ELEMENTS: &[&str] = &[
+    "a", "abbr", "acronym", "audio", "b", "bdo", "big", "br", "button", "cite", "code", "dfn",
+    "em", "font", "i", "img", "input", "kbd", "label", "map", "object", "output", "q", "samp",
+    "select", "small", "span", "strike", "strong", "sub", "sup", "textarea", "tt", "u", "var",
+    "video",
+];
+
+/// The elements whose content is printed as is, because all of its whitespace is rendered.
+pub(crate) const HTML_PREFORMATTED_ELEMENTS: &[&str] = &["pre", "textarea"];
+
+/// Returns the tag name of `element`, or `None` if it isn't a tag.
+pub(crate) fn element_name(element: &AnyHtmlElement) -> Option<TokenText> {
+    let name = match element {
+        AnyHtmlElement::HtmlElement(element) => element.opening_element().ok()?.name().ok()?,
+        AnyHtmlElement::HtmlSelfClosingElement(element) => element.name().ok()?,
+        _ => return None,
+    };
+    Some(name.value_token().ok()?.token_text_trimmed())
+}
+
+/// Returns `true` if the tag name `name` is one of `names`, ignoring the case.
+pub(crate) fn is_element_in(name: &str, names: &[&str]) -> bool {
+    names
+        .iter()
+        .any(|element| element.eq_ignore_ascii_case(name))
+}
+
+/// Returns `true` if the whitespace around and inside `element` is significant,
+/// so that the formatter must neither add nor remove it.
+pub(crate) fn is_whitespace_sensitive(
+    element: &AnyHtmlElement,
+    sensitivity: WhitespaceSensitivity,
+) -> bool {
+    match sensitivity {
+        WhitespaceSensitivity::Css => element_name(element)
+            .is_some_and(|name| is_element_in(name.text(), HTML_INLINE_ELEMENTS)),
+        WhitespaceSensitivity::Strict => matches!(
+            element,
+            AnyHtmlElement::HtmlElement(_) | AnyHtmlElement::HtmlSelfClosingElement(_)
+        ),
+        WhitespaceSensitivity::Ignore => false,
+    }
+}
@@ -1 +1,2 @@
 pub mod children;
+pub(crate) mod metadata;
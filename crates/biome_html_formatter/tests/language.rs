@@ -2,7 +2,7 @@ use biome_formatter_test::TestFormatLanguage;
 use biome_fs::BiomePath;
 use biome_html_formatter::context::HtmlFormatContext;
 use biome_html_formatter::HtmlFormatLanguage;
-use biome_html_parser::parse_html;
+use biome_html_parser::{parse_html, HtmlParserOptions};
 use biome_html_syntax::{HtmlFileSource, HtmlLanguage};
 use biome_parser::AnyParse;
 use biome_service::{
@@ -27,7 +27,7 @@ impl TestFormatLanguage for HtmlTestFormatLanguage {
     type FormatLanguage = HtmlFormatLanguage;
 
     fn parse(&self, text: &str) -> AnyParse {
-        parse_html(text).into()
+        parse_html(text, HtmlParserOptions::default()).into()
     }
 
     fn to_format_language(
@@ -35,10 +35,11 @@ impl TestFormatLanguage for HtmlTestFormatLanguage {
         settings: &Settings,
         file_source: &DocumentFileSource,
     ) -> Self::FormatLanguage {
+        let language_settings = &settings.languages.html.formatter;
         let options = Self::ServiceLanguage::resolve_format_options(
             Some(&settings.formatter),
             Some(&settings.override_settings),
-            None,
+            Some(language_settings),
             &BiomePath::new(""),
             file_source,
         );
@@ -2,7 +2,7 @@ use biome_formatter::{AttributePosition, IndentStyle, LineWidth};
 use biome_formatter_test::check_reformat::CheckReformat;
 use biome_html_formatter::context::HtmlFormatOptions;
 use biome_html_formatter::{format_node, HtmlFormatLanguage};
-use biome_html_parser::parse_html;
+use biome_html_parser::{parse_html, HtmlParserOptions};
 use biome_html_syntax::HtmlFileSource;
 
 mod language {
@@ -21,7 +21,7 @@ fn quick_test() {
 
     "#;
     let source_type = HtmlFileSource::html();
-    let tree = parse_html(src);
+    let tree = parse_html(src, HtmlParserOptions::default());
     let options = HtmlFormatOptions::new(HtmlFileSource::html())
         .with_indent_style(IndentStyle::Space)
         .with_line_width(LineWidth::try_from(80).unwrap())
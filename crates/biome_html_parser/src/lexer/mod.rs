@@ -1,5 +1,6 @@
 mod tests;
 
+use crate::parser::IgnoreDelimiter;
 use crate::token_source::{HtmlEmbededLanguage, HtmlLexContext};
 use biome_html_syntax::HtmlSyntaxKind::{
     DOCTYPE_KW, EOF, ERROR_TOKEN, HTML_KW, HTML_LITERAL, HTML_STRING_LITERAL, NEWLINE, TOMBSTONE,
@@ -33,6 +34,9 @@ pub(crate) struct HtmlLexer<'src> {
     after_newline: bool,
 
     unicode_bom_length: usize,
+
+    /// The delimiters of the regions that are lexed as a single literal, such as `{%` and `%}`
+    ignore_delimiters: Vec<IgnoreDelimiter>,
 }
 
 impl<'src> HtmlLexer<'src> {
@@ -47,11 +51,20 @@ impl<'src> HtmlLexer<'src> {
             after_newline: false,
             current_flags: TokenFlags::empty(),
             unicode_bom_length: 0,
+            ignore_delimiters: Vec::new(),
         }
     }
 
+    pub fn with_ignore_delimiters(mut self, ignore_delimiters: Vec<IgnoreDelimiter>) -> Self {
+        self.ignore_delimiters = ignore_delimiters;
+        self
+    }
+
     /// Consume a token in the [HtmlLexContext::Regular] context.
     fn consume_token(&mut self, current: u8) -> HtmlSyntaxKind {
+        if let Some(delimiter) = self.at_ignored_region() {
+            return self.consume_ignored_region(delimiter);
+        }
         match current {
             b'\n' | b'\r' | b'\t' | b' ' => self.consume_newline_or_whitespaces(),
             b'<' => self.consume_l_angle(),
@@ -78,6 +91,9 @@ impl<'src> HtmlLexer<'src> {
 
     /// Consume a token in the [HtmlLexContext::OutsideTag] context.
     fn consume_token_outside_tag(&mut self, current: u8) -> HtmlSyntaxKind {
+        if let Some(delimiter) = self.at_ignored_region() {
+            return self.consume_ignored_region(delimiter);
+        }
         match current {
             b'\n' | b'\r' | b'\t' | b' ' => self.consume_newline_or_whitespaces(),
             b'<' => self.consume_l_angle(),
@@ -155,6 +171,46 @@ impl<'src> HtmlLexer<'src> {
         }
     }
 
+    /// Returns the index of the delimiters of the region that starts at the current position,
+    /// if any. See [crate::HtmlParserOptions::ignore_delimiters].
+    fn at_ignored_region(&self) -> Option<usize> {
+        let rest = &self.source.as_bytes()[self.position..];
+        self.ignore_delimiters.iter().position(|delimiter| {
+            !delimiter.start.is_empty() && rest.starts_with(delimiter.start.as_bytes())
+        })
+    }
+
+    /// Consume a region of a template, from its start delimiter to its end delimiter included,
+    /// as a single literal.
+    fn consume_ignored_region(&mut self, delimiter: usize) -> HtmlSyntaxKind {
+        let start = self.text_position();
+        let IgnoreDelimiter {
+            start: start_delimiter,
+            end: end_delimiter,
+        } = &self.ignore_delimiters[delimiter];
+        let content_start = self.position + start_delimiter.len();
+
+        match self.source[content_start..].find(end_delimiter.as_str()) {
+            Some(end) => {
+                self.position = content_start + end + end_delimiter.len();
+            }
+            None => {
+                let diagnostic = ParseDiagnostic::new(
+                    format!("Missing the closing delimiter `{end_delimiter}`"),
+                    start..start + start_delimiter.text_len(),
+                )
+                .with_detail(
+                    self.source.text_len()..self.source.text_len(),
+                    "file ends here",
+                );
+                self.diagnostics.push(diagnostic);
+                self.position = self.source.len();
+            }
+        }
+
+        HTML_LITERAL
+    }
+
     /// Bumps the current byte and creates a lexed token of the passed in kind.
     #[inline]
     fn consume_byte(&mut self, tok: HtmlSyntaxKind) -> HtmlSyntaxKind {
@@ -432,6 +488,8 @@ impl<'src> HtmlLexer<'src> {
                     self.advance(1);
                     saw_space = true;
                 }
+                // The text stops before a region of a template
+                _ if self.at_ignored_region().is_some() => break,
                 _ => {
                     self.advance(1);
                     saw_space = false;
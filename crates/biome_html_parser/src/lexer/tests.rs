@@ -2,6 +2,7 @@
 #![allow(unused_mut, unused_variables, unused_assignments)]
 
 use super::{HtmlLexer, TextSize};
+use crate::parser::IgnoreDelimiter;
 use crate::token_source::HtmlLexContext;
 use biome_html_syntax::HtmlSyntaxKind::{self, *};
 use biome_parser::lexer::Lexer;
@@ -326,3 +327,32 @@ fn comment_full() {
         COMMENT_END: 3,
     }
 }
+
+#[test]
+fn ignored_region() {
+    let source = "Hello {{ user.name }}!<?php echo 1 ?>";
+    let mut lexer = HtmlLexer::from_str(source).with_ignore_delimiters(vec![
+        IgnoreDelimiter {
+            start: "{{".into(),
+            end: "}}".into(),
+        },
+        IgnoreDelimiter {
+            start: "<?php".into(),
+            end: "?>".into(),
+        },
+    ]);
+    let mut tokens = vec![];
+    while lexer.next_token(HtmlLexContext::OutsideTag) != EOF {
+        tokens.push((lexer.current(), &source[lexer.current_range()]));
+    }
+
+    assert_eq!(
+        tokens,
+        vec![
+            (HTML_LITERAL, "Hello "),
+            (HTML_LITERAL, "{{ user.name }}"),
+            (HTML_LITERAL, "!"),
+            (HTML_LITERAL, "<?php echo 1 ?>"),
+        ]
+    );
+}
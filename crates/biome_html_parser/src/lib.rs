@@ -4,6 +4,7 @@ mod syntax;
 mod token_source;
 
 use crate::parser::{HtmlLosslessTreeSink, HtmlParser};
+pub use crate::parser::{HtmlParserOptions, IgnoreDelimiter};
 use crate::syntax::parse_root;
 use biome_html_syntax::{HtmlRoot, HtmlSyntaxNode};
 use biome_parser::diagnostic::ParseDiagnostic;
@@ -11,9 +12,13 @@ use biome_parser::AnyParse;
 use biome_rowan::{AstNode, NodeCache};
 
 /// Parses the provided string as HTML program using the provided node cache.
-pub fn parse_html_with_cache(source: &str, cache: &mut NodeCache) -> HtmlParse {
+pub fn parse_html_with_cache(
+    source: &str,
+    cache: &mut NodeCache,
+    options: HtmlParserOptions,
+) -> HtmlParse {
     tracing::debug_span!("Parsing phase").in_scope(move || {
-        let mut parser = HtmlParser::new(source);
+        let mut parser = HtmlParser::new(source, options);
 
         parse_root(&mut parser);
 
@@ -26,9 +31,9 @@ pub fn parse_html_with_cache(source: &str, cache: &mut NodeCache) -> HtmlParse {
         HtmlParse::new(green, diagnostics)
     })
 }
-pub fn parse_html(source: &str) -> HtmlParse {
+pub fn parse_html(source: &str, options: HtmlParserOptions) -> HtmlParse {
     let mut cache = NodeCache::default();
-    parse_html_with_cache(source, &mut cache)
+    parse_html_with_cache(source, &mut cache, options)
 }
 
 /// A utility struct for managing the result of a parser job
@@ -13,16 +13,64 @@ pub(crate) type HtmlLosslessTreeSink<'source> =
 pub(crate) struct HtmlParser<'source> {
     context: ParserContext<HtmlSyntaxKind>,
     source: HtmlTokenSource<'source>,
+    options: HtmlParserOptions,
+}
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct HtmlParserOptions {
+    /// The delimiters of the regions of a template that are kept as they are written,
+    /// such as `{%` and `%}` for the tags of the Jinja templates.
+    ///
+    /// These regions are parsed as opaque content, instead of markup or text,
+    /// so that the templates written with Jinja, ERB or Blade can be parsed.
+    ///
+    /// Defaults to no delimiters.
+    pub ignore_delimiters: Vec<IgnoreDelimiter>,
+}
+
+/// The delimiters of a region of a template, such as `{{` and `}}`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct IgnoreDelimiter {
+    pub start: String,
+    pub end: String,
+}
+
+impl HtmlParserOptions {
+    /// Keeps the regions between `start` and `end` as they are written.
+    pub fn with_ignore_delimiter(
+        mut self,
+        start: impl Into<String>,
+        end: impl Into<String>,
+    ) -> Self {
+        self.ignore_delimiters.push(IgnoreDelimiter {
+            start: start.into(),
+            end: end.into(),
+        });
+        self
+    }
+
+    /// Returns `true` if the `text` starts with the start delimiter of a region
+    /// that is kept as it is written.
+    pub fn is_ignored_region(&self, text: &str) -> bool {
+        self.ignore_delimiters
+            .iter()
+            .any(|delimiter| !delimiter.start.is_empty() && text.starts_with(&delimiter.start))
+    }
 }
 
 impl<'source> HtmlParser<'source> {
-    pub fn new(source: &'source str) -> Self {
+    pub fn new(source: &'source str, options: HtmlParserOptions) -> Self {
         Self {
             context: ParserContext::default(),
-            source: HtmlTokenSource::from_str(source),
+            source: HtmlTokenSource::from_str(source, &options),
+            options,
         }
     }
 
+    pub fn options(&self) -> &HtmlParserOptions {
+        &self.options
+    }
+
     pub fn finish(
         self,
     ) -> (
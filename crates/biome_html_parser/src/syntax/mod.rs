@@ -160,6 +160,11 @@ impl ParseNodeList for ElementList {
         match p.cur() {
             T![<!--] => parse_comment(p),
             T![<] => parse_element(p),
+            HTML_LITERAL if p.options().is_ignored_region(p.cur_text()) => {
+                let m = p.start();
+                p.bump_with_context(HTML_LITERAL, HtmlLexContext::OutsideTag);
+                Present(m.complete(p, HTML_PASSTHROUGH))
+            }
             HTML_LITERAL => {
                 let m = p.start();
                 p.bump_with_context(HTML_LITERAL, HtmlLexContext::OutsideTag);
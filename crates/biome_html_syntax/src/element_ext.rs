@@ -0,0 +1,123 @@
+use crate::{
+    inner_string_text, AnyHtmlAttribute, AnyHtmlElement, HtmlAttribute, HtmlAttributeList,
+    HtmlName, HtmlOpeningElement, HtmlSelfClosingElement, HtmlSyntaxToken,
+};
+use biome_rowan::{declare_node_union, AstNodeList, SyntaxResult, TokenText};
+
+declare_node_union! {
+    /// The tag that holds the name and the attributes of an element:
+    /// the opening tag of an element with children, such as `<label>`,
+    /// or a self-closing element, such as `<img>`.
+    pub AnyHtmlTag = HtmlOpeningElement | HtmlSelfClosingElement
+}
+
+impl AnyHtmlTag {
+    pub fn name(&self) -> SyntaxResult<HtmlName> {
+        match self {
+            Self::HtmlOpeningElement(element) => element.name(),
+            Self::HtmlSelfClosingElement(element) => element.name(),
+        }
+    }
+
+    pub fn attributes(&self) -> HtmlAttributeList {
+        match self {
+            Self::HtmlOpeningElement(element) => element.attributes(),
+            Self::HtmlSelfClosingElement(element) => element.attributes(),
+        }
+    }
+
+    pub fn name_value_token(&self) -> SyntaxResult<HtmlSyntaxToken> {
+        self.name()?.value_token()
+    }
+
+    /// Returns `true` if the name of the element is `name`, ignoring the ASCII case.
+    pub fn is_element(&self, name: &str) -> bool {
+        self.name_value_token()
+            .is_ok_and(|token| token.text_trimmed().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the first attribute named `name`, ignoring the ASCII case.
+    pub fn find_attribute_by_name(&self, name: &str) -> Option<HtmlAttribute> {
+        self.attributes()
+            .iter()
+            .find_map(|attribute| match attribute {
+                AnyHtmlAttribute::HtmlAttribute(attribute)
+                    if attribute
+                        .name_value_token()
+                        .is_ok_and(|token| token.text_trimmed().eq_ignore_ascii_case(name)) =>
+                {
+                    Some(attribute)
+                }
+                _ => None,
+            })
+    }
+
+    /// Returns `true` if the element has an attribute named `name`, with or without value.
+    pub fn has_attribute(&self, name: &str) -> bool {
+        self.find_attribute_by_name(name).is_some()
+    }
+}
+
+impl AnyHtmlElement {
+    /// Returns the tag that holds the name and the attributes of the element,
+    /// or `None` if the node isn't an element, for example a comment.
+    pub fn tag(&self) -> Option<AnyHtmlTag> {
+        match self {
+            Self::HtmlElement(element) => element.opening_element().ok().map(AnyHtmlTag::from),
+            Self::HtmlSelfClosingElement(element) => Some(AnyHtmlTag::from(element.clone())),
+            Self::HtmlBogusElement(_)
+            | Self::HtmlComment(_)
+            | Self::HtmlContent(_)
+            | Self::HtmlPassthrough(_) => None,
+        }
+    }
+}
+
+impl HtmlAttribute {
+    pub fn name_value_token(&self) -> SyntaxResult<HtmlSyntaxToken> {
+        self.name()?.value_token()
+    }
+
+    /// Returns the value of the attribute, without the quotes,
+    /// or `None` if the attribute doesn't have a value, such as `disabled` in `<input disabled>`.
+    pub fn value(&self) -> Option<TokenText> {
+        let value = self.initializer()?.value().ok()?.value_token().ok()?;
+        Some(inner_string_text(&value))
+    }
+}
+
+impl biome_aria::Element for AnyHtmlTag {
+    fn name(&self) -> Option<impl AsRef<str>> {
+        // The names of the HTML elements are case-insensitive
+        Some(
+            self.name_value_token()
+                .ok()?
+                .text_trimmed()
+                .to_ascii_lowercase(),
+        )
+    }
+
+    fn attributes(&self) -> impl Iterator<Item = impl biome_aria::Attribute> {
+        Self::attributes(self)
+            .into_iter()
+            .filter_map(|attribute| match attribute {
+                AnyHtmlAttribute::HtmlAttribute(attribute) => Some(attribute),
+                AnyHtmlAttribute::HtmlBogusAttribute(_) => None,
+            })
+    }
+}
+
+impl biome_aria::Attribute for HtmlAttribute {
+    fn name(&self) -> Option<impl AsRef<str>> {
+        Some(
+            self.name_value_token()
+                .ok()?
+                .text_trimmed()
+                .to_ascii_lowercase(),
+        )
+    }
+
+    fn value(&self) -> Option<impl AsRef<str>> {
+        Self::value(self)
+    }
+}
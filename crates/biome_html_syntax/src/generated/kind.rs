@@ -48,6 +48,7 @@ pub enum HtmlSyntaxKind {
     HTML_ATTRIBUTE_LIST,
     HTML_CONTENT,
     HTML_COMMENT,
+    HTML_PASSTHROUGH,
     HTML_BOGUS,
     HTML_BOGUS_ELEMENT,
     HTML_BOGUS_ATTRIBUTE,
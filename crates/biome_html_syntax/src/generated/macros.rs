@@ -53,6 +53,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::HtmlOpeningElement::new_unchecked(node) };
                     $body
                 }
+                $crate::HtmlSyntaxKind::HTML_PASSTHROUGH => {
+                    let $pattern = unsafe { $crate::HtmlPassthrough::new_unchecked(node) };
+                    $body
+                }
                 $crate::HtmlSyntaxKind::HTML_ROOT => {
                     let $pattern = unsafe { $crate::HtmlRoot::new_unchecked(node) };
                     $body
@@ -433,6 +433,41 @@ pub struct HtmlOpeningElementFields {
     pub r_angle_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct HtmlPassthrough {
+    pub(crate) syntax: SyntaxNode,
+}
+impl HtmlPassthrough {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> HtmlPassthroughFields {
+        HtmlPassthroughFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for HtmlPassthrough {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct HtmlPassthroughFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct HtmlRoot {
     pub(crate) syntax: SyntaxNode,
 }
@@ -597,6 +632,7 @@ pub enum AnyHtmlElement {
     HtmlComment(HtmlComment),
     HtmlContent(HtmlContent),
     HtmlElement(HtmlElement),
+    HtmlPassthrough(HtmlPassthrough),
     HtmlSelfClosingElement(HtmlSelfClosingElement),
 }
 impl AnyHtmlElement {
@@ -624,6 +660,12 @@ impl AnyHtmlElement {
             _ => None,
         }
     }
+    pub fn as_html_passthrough(&self) -> Option<&HtmlPassthrough> {
+        match &self {
+            AnyHtmlElement::HtmlPassthrough(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_html_self_closing_element(&self) -> Option<&HtmlSelfClosingElement> {
         match &self {
             AnyHtmlElement::HtmlSelfClosingElement(item) => Some(item),
@@ -1052,6 +1094,47 @@ impl From<HtmlOpeningElement> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for HtmlPassthrough {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_PASSTHROUGH as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_PASSTHROUGH
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlPassthrough {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlPassthrough")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<HtmlPassthrough> for SyntaxNode {
+    fn from(n: HtmlPassthrough) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlPassthrough> for SyntaxElement {
+    fn from(n: HtmlPassthrough) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
 impl AstNode for HtmlRoot {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -1273,6 +1356,11 @@ impl From<HtmlElement> for AnyHtmlElement {
         AnyHtmlElement::HtmlElement(node)
     }
 }
+impl From<HtmlPassthrough> for AnyHtmlElement {
+    fn from(node: HtmlPassthrough) -> AnyHtmlElement {
+        AnyHtmlElement::HtmlPassthrough(node)
+    }
+}
 impl From<HtmlSelfClosingElement> for AnyHtmlElement {
     fn from(node: HtmlSelfClosingElement) -> AnyHtmlElement {
         AnyHtmlElement::HtmlSelfClosingElement(node)
@@ -1284,6 +1372,7 @@ impl AstNode for AnyHtmlElement {
         .union(HtmlComment::KIND_SET)
         .union(HtmlContent::KIND_SET)
         .union(HtmlElement::KIND_SET)
+        .union(HtmlPassthrough::KIND_SET)
         .union(HtmlSelfClosingElement::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
@@ -1292,6 +1381,7 @@ impl AstNode for AnyHtmlElement {
                 | HTML_COMMENT
                 | HTML_CONTENT
                 | HTML_ELEMENT
+                | HTML_PASSTHROUGH
                 | HTML_SELF_CLOSING_ELEMENT
         )
     }
@@ -1301,6 +1391,7 @@ impl AstNode for AnyHtmlElement {
             HTML_COMMENT => AnyHtmlElement::HtmlComment(HtmlComment { syntax }),
             HTML_CONTENT => AnyHtmlElement::HtmlContent(HtmlContent { syntax }),
             HTML_ELEMENT => AnyHtmlElement::HtmlElement(HtmlElement { syntax }),
+            HTML_PASSTHROUGH => AnyHtmlElement::HtmlPassthrough(HtmlPassthrough { syntax }),
             HTML_SELF_CLOSING_ELEMENT => {
                 AnyHtmlElement::HtmlSelfClosingElement(HtmlSelfClosingElement { syntax })
             }
@@ -1314,6 +1405,7 @@ impl AstNode for AnyHtmlElement {
             AnyHtmlElement::HtmlComment(it) => &it.syntax,
             AnyHtmlElement::HtmlContent(it) => &it.syntax,
             AnyHtmlElement::HtmlElement(it) => &it.syntax,
+            AnyHtmlElement::HtmlPassthrough(it) => &it.syntax,
             AnyHtmlElement::HtmlSelfClosingElement(it) => &it.syntax,
         }
     }
@@ -1323,6 +1415,7 @@ impl AstNode for AnyHtmlElement {
             AnyHtmlElement::HtmlComment(it) => it.syntax,
             AnyHtmlElement::HtmlContent(it) => it.syntax,
             AnyHtmlElement::HtmlElement(it) => it.syntax,
+            AnyHtmlElement::HtmlPassthrough(it) => it.syntax,
             AnyHtmlElement::HtmlSelfClosingElement(it) => it.syntax,
         }
     }
@@ -1334,6 +1427,7 @@ impl std::fmt::Debug for AnyHtmlElement {
             AnyHtmlElement::HtmlComment(it) => std::fmt::Debug::fmt(it, f),
             AnyHtmlElement::HtmlContent(it) => std::fmt::Debug::fmt(it, f),
             AnyHtmlElement::HtmlElement(it) => std::fmt::Debug::fmt(it, f),
+            AnyHtmlElement::HtmlPassthrough(it) => std::fmt::Debug::fmt(it, f),
             AnyHtmlElement::HtmlSelfClosingElement(it) => std::fmt::Debug::fmt(it, f),
         }
     }
@@ -1345,6 +1439,7 @@ impl From<AnyHtmlElement> for SyntaxNode {
             AnyHtmlElement::HtmlComment(it) => it.into(),
             AnyHtmlElement::HtmlContent(it) => it.into(),
             AnyHtmlElement::HtmlElement(it) => it.into(),
+            AnyHtmlElement::HtmlPassthrough(it) => it.into(),
             AnyHtmlElement::HtmlSelfClosingElement(it) => it.into(),
         }
     }
@@ -1410,6 +1505,11 @@ impl std::fmt::Display for HtmlOpeningElement {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for HtmlPassthrough {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for HtmlRoot {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
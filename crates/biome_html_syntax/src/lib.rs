@@ -1,5 +1,6 @@
 #[macro_use]
 mod generated;
+pub mod element_ext;
 mod file_source;
 mod syntax_node;
 
@@ -111,9 +112,9 @@ impl TryFrom<HtmlSyntaxKind> for TriviaPieceKind {
 /// Text of `token`, excluding all trivia and removing quotes if `token` is a string literal.
 pub fn inner_string_text(token: &HtmlSyntaxToken) -> TokenText {
     let mut text = token.token_text_trimmed();
-    if token.kind() == HtmlSyntaxKind::HTML_STRING_LITERAL {
-        // remove string delimiters
-        // SAFETY: string literal token have a delimiters at the start and the end of the string
+    if token.kind() == HtmlSyntaxKind::HTML_STRING_LITERAL && text.starts_with(['"', '\'']) {
+        // remove string delimiters, unquoted attribute values don't have any
+        // SAFETY: quoted string literal token have a delimiters at the start and the end of the string
         let range = TextRange::new(1.into(), text.len() - TextSize::from(1));
         text = text.slice(range);
     }
@@ -2,12 +2,13 @@
 
 use crate::suppression_action::JsSuppressionAction;
 use biome_analyze::{
-    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal, ControlFlow,
-    InspectMatcher, LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
-    SuppressionKind,
+    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal,
+    AnalyzerSuppression, ControlFlow, InspectMatcher, LanguageRoot, MatchQueryParams,
+    MetadataRegistry, RuleAction, RuleRegistry, SuppressionKind,
 };
 use biome_aria::AriaRoles;
 use biome_diagnostics::{category, Error as DiagnosticError};
+use biome_js_semantic::JsProjectIndex;
 use biome_js_syntax::{JsFileSource, JsLanguage};
 use biome_project::PackageJson;
 use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic};
@@ -50,6 +51,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
     manifest: Option<PackageJson>,
+    project: Arc<JsProjectIndex>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -59,41 +61,46 @@ where
 {
     fn parse_linter_suppression_comment(
         text: &str,
-    ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
+    ) -> Vec<Result<AnalyzerSuppression, SuppressionDiagnostic>> {
         let mut result = Vec::new();
 
         for comment in parse_suppression_comment(text) {
-            let categories = match comment {
-                Ok(comment) => {
-                    if comment.is_legacy {
-                        result.push(Ok(SuppressionKind::Deprecated));
-                    }
-                    comment.categories
-                }
+            let comment = match comment {
+                Ok(comment) => comment,
                 Err(err) => {
                     result.push(Err(err));
                     continue;
                 }
             };
 
-            for (key, value) in categories {
+            let mut kinds = Vec::new();
+            if comment.is_legacy {
+                kinds.push(SuppressionKind::Deprecated);
+            }
+            for (key, value) in comment.categories {
                 if key == category!("lint") {
                     if let Some(value) = value {
-                        result.push(Ok(SuppressionKind::MaybeLegacy(value)));
+                        kinds.push(SuppressionKind::MaybeLegacy(value));
                     } else {
-                        result.push(Ok(SuppressionKind::Everything));
+                        kinds.push(SuppressionKind::Everything);
                     }
                 } else {
                     let category = key.name();
                     if let Some(rule) = category.strip_prefix("lint/") {
                         if let Some(instance) = value {
-                            result.push(Ok(SuppressionKind::RuleInstance(rule, instance)));
+                            kinds.push(SuppressionKind::RuleInstance(rule, instance));
                         } else {
-                            result.push(Ok(SuppressionKind::Rule(rule)));
+                            kinds.push(SuppressionKind::Rule(rule));
                         }
                     }
                 }
             }
+
+            result.push(Ok(AnalyzerSuppression {
+                kinds,
+                reason: comment.reason,
+                until: comment.until,
+            }));
         }
 
         result
@@ -113,7 +120,7 @@ where
         METADATA.deref(),
         InspectMatcher::new(registry, inspect_matcher),
         parse_linter_suppression_comment,
-        Box::new(JsSuppressionAction),
+        Some(Box::new(JsSuppressionAction)),
         &mut emit_signal,
     );
 
@@ -123,6 +130,7 @@ where
 
     services.insert_service(Arc::new(AriaRoles));
     services.insert_service(Arc::new(manifest));
+    services.insert_service(project);
     services.insert_service(source_type);
     (
         analyzer.run(AnalyzerContext {
@@ -144,6 +152,7 @@ pub fn analyze<'a, F, B>(
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
     manifest: Option<PackageJson>,
+    project: Arc<JsProjectIndex>,
     emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -157,6 +166,7 @@ where
         options,
         source_type,
         manifest,
+        project,
         emit_signal,
     )
 }
@@ -173,6 +183,7 @@ mod tests {
     use biome_js_syntax::{JsFileSource, TextRange, TextSize};
     use biome_project::{Dependencies, PackageJson};
     use std::slice;
+    use std::sync::Arc;
 
     use crate::{analyze, AnalysisFilter, ControlFlow};
 
@@ -210,6 +221,7 @@ mod tests {
                 dependencies,
                 ..Default::default()
             }),
+            Arc::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -302,6 +314,7 @@ mod tests {
             &options,
             JsFileSource::js_module(),
             None,
+            Arc::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let span = diag.get_span();
@@ -388,6 +401,7 @@ mod tests {
             &options,
             JsFileSource::js_module(),
             None,
+            Arc::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let code = diag.category().unwrap();
@@ -89,7 +89,7 @@ declare_lint_rule! {
 
 #[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(untagged)]
-enum DependencyAvailability {
+pub enum DependencyAvailability {
     /// Dependencies are always available or unavailable.
     Bool(bool),
 
@@ -181,15 +181,15 @@ impl DependencyAvailability {
 pub struct NoUndeclaredDependenciesOptions {
     /// If set to `false`, then the rule will show an error when `devDependencies` are imported. Defaults to `true`.
     #[serde(default)]
-    dev_dependencies: DependencyAvailability,
+    pub dev_dependencies: DependencyAvailability,
 
     /// If set to `false`, then the rule will show an error when `peerDependencies` are imported. Defaults to `true`.
     #[serde(default)]
-    peer_dependencies: DependencyAvailability,
+    pub peer_dependencies: DependencyAvailability,
 
     /// If set to `false`, then the rule will show an error when `optionalDependencies` are imported. Defaults to `true`.
     #[serde(default)]
-    optional_dependencies: DependencyAvailability,
+    pub optional_dependencies: DependencyAvailability,
 }
 
 pub struct RuleState {
@@ -5,6 +5,7 @@ use biome_analyze::declare_lint_group;
 pub mod no_common_js;
 pub mod no_document_cookie;
 pub mod no_document_import_in_page;
+pub mod no_duplicate_custom_element_names;
 pub mod no_duplicate_else_if;
 pub mod no_dynamic_namespace_import_access;
 pub mod no_enum;
@@ -23,6 +24,7 @@ pub mod no_secrets;
 pub mod no_static_element_interactions;
 pub mod no_substr;
 pub mod no_template_curly_in_string;
+pub mod no_unhandled_observed_attributes;
 pub mod no_useless_escape_in_regex;
 pub mod no_useless_string_raw;
 pub mod no_useless_undefined;
@@ -42,6 +44,7 @@ pub mod use_sorted_classes;
 pub mod use_strict_mode;
 pub mod use_trim_start_end;
 pub mod use_valid_autocomplete;
+pub mod use_valid_custom_element_name;
 
 declare_lint_group! {
     pub Nursery {
@@ -50,6 +53,7 @@ declare_lint_group! {
             self :: no_common_js :: NoCommonJs ,
             self :: no_document_cookie :: NoDocumentCookie ,
             self :: no_document_import_in_page :: NoDocumentImportInPage ,
+            self :: no_duplicate_custom_element_names :: NoDuplicateCustomElementNames ,
             self :: no_duplicate_else_if :: NoDuplicateElseIf ,
             self :: no_dynamic_namespace_import_access :: NoDynamicNamespaceImportAccess ,
             self :: no_enum :: NoEnum ,
@@ -68,6 +72,7 @@ declare_lint_group! {
             self :: no_static_element_interactions :: NoStaticElementInteractions ,
             self :: no_substr :: NoSubstr ,
             self :: no_template_curly_in_string :: NoTemplateCurlyInString ,
+            self :: no_unhandled_observed_attributes :: NoUnhandledObservedAttributes ,
             self :: no_useless_escape_in_regex :: NoUselessEscapeInRegex ,
             self :: no_useless_string_raw :: NoUselessStringRaw ,
             self :: no_useless_undefined :: NoUselessUndefined ,
@@ -87,6 +92,7 @@ declare_lint_group! {
             self :: use_strict_mode :: UseStrictMode ,
             self :: use_trim_start_end :: UseTrimStartEnd ,
             self :: use_valid_autocomplete :: UseValidAutocomplete ,
+            self :: use_valid_custom_element_name :: UseValidCustomElementName ,
         ]
      }
 }
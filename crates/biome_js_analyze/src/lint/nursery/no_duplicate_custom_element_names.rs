@@ -0,0 +1,121 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_semantic::{JsFileIndex, JsProjectIndex};
+use biome_js_syntax::AnyJsRoot;
+use biome_rowan::TextRange;
+use rustc_hash::FxHashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Disallow defining several custom elements with the same name.
+    ///
+    /// A name can only be defined once by `customElements.define`: the browser throws
+    /// a `NotSupportedError` when a custom element is defined with a name that is already used.
+    ///
+    /// The names of the custom elements are compared to the ones of the other scripts of the project.
+    /// Only the scripts processed by Biome are taken into account, files that are ignored
+    /// or excluded from the command line are not.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// customElements.define("my-button", MyButton);
+    /// customElements.define("my-button", MyOtherButton);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// customElements.define("my-button", MyButton);
+    /// customElements.define("my-other-button", MyOtherButton);
+    /// ```
+    pub NoDuplicateCustomElementNames {
+        version: "next",
+        name: "noDuplicateCustomElementNames",
+        language: "js",
+        recommended: false,
+    }
+}
+
+pub struct DuplicateCustomElementName {
+    range: TextRange,
+    kind: DuplicateCustomElementNameKind,
+}
+
+pub enum DuplicateCustomElementNameKind {
+    /// The name is already used by the custom element at the given range of the file
+    DuplicatedInFile(TextRange),
+    /// The name is already used by a custom element of the script at the given path
+    DuplicatedInProject(PathBuf),
+}
+
+impl Rule for NoDuplicateCustomElementNames {
+    type Query = Ast<AnyJsRoot>;
+    type State = DuplicateCustomElementName;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let project = ctx.get_service::<Arc<JsProjectIndex>>();
+        let file_path = ctx.file_path();
+
+        let mut signals = Vec::new();
+        let mut names = FxHashMap::default();
+        for definition in JsFileIndex::from_root(ctx.query()).custom_elements() {
+            let kind = if let Some(first_range) = names.get(definition.name.as_str()) {
+                DuplicateCustomElementNameKind::DuplicatedInFile(*first_range)
+            } else if let Some((path, _)) = project.and_then(|project| {
+                project
+                    .custom_element_definitions(&definition.name)
+                    .find(|(path, _)| *path != file_path)
+            }) {
+                DuplicateCustomElementNameKind::DuplicatedInProject(path.to_path_buf())
+            } else {
+                names.insert(definition.name.as_str(), definition.range);
+                continue;
+            };
+            signals.push(DuplicateCustomElementName {
+                range: definition.range,
+                kind,
+            });
+        }
+
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let DuplicateCustomElementName { range, kind } = state;
+        let diagnostic = match kind {
+            DuplicateCustomElementNameKind::DuplicatedInFile(first_range) => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "The name of this custom element is already used by another custom element."
+                },
+            )
+            .detail(
+                *first_range,
+                markup! {
+                    "The name is first used here."
+                },
+            ),
+            DuplicateCustomElementNameKind::DuplicatedInProject(path) => {
+                let path = path.display().to_string();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    *range,
+                    markup! {
+                        "The name of this custom element is already used by a custom element of "<Emphasis>{path}</Emphasis>"."
+                    },
+                )
+            }
+        };
+
+        Some(diagnostic.note(markup! {
+            "The browser throws a "<Emphasis>"NotSupportedError"</Emphasis>" when a name is defined twice."
+        }))
+    }
+}
@@ -0,0 +1,236 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyJsArrayElement, AnyJsClass, AnyJsClassMember, AnyJsExpression, AnyJsMethodModifier,
+    AnyJsPropertyModifier, AnyJsStatement, JsSyntaxKind,
+};
+use biome_rowan::{AstNode, Direction, TextRange};
+use rustc_hash::FxHashSet;
+
+declare_lint_rule! {
+    /// Require `attributeChangedCallback` to handle the attributes listed by `observedAttributes`.
+    ///
+    /// The browser calls the `attributeChangedCallback` method of a custom element when one of
+    /// the attributes listed by its static `observedAttributes` property changes.
+    /// An attribute that is observed, but isn't handled by the callback, is usually a mistake,
+    /// such as a typo in the name of the attribute, or an attribute that is no longer supported.
+    ///
+    /// The rule considers that an attribute is handled when its name appears in the callback,
+    /// such as in a `switch` statement or a comparison. The callbacks that don't compare
+    /// the name of the attribute with any of the observed attributes are considered to handle
+    /// all of them, such as a callback that renders the element again.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// class MyButton extends HTMLElement {
+    ///     static observedAttributes = ["disabled"];
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// class MyButton extends HTMLElement {
+    ///     static get observedAttributes() {
+    ///         return ["disabled", "label"];
+    ///     }
+    ///
+    ///     attributeChangedCallback(name, oldValue, newValue) {
+    ///         if (name === "disabled") {
+    ///             this.toggleAttribute("aria-disabled", newValue !== null);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// class MyButton extends HTMLElement {
+    ///     static observedAttributes = ["disabled", "label"];
+    ///
+    ///     attributeChangedCallback(name, oldValue, newValue) {
+    ///         switch (name) {
+    ///             case "disabled":
+    ///                 this.toggleAttribute("aria-disabled", newValue !== null);
+    ///                 break;
+    ///             case "label":
+    ///                 this.textContent = newValue;
+    ///                 break;
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// class MyButton extends HTMLElement {
+    ///     static observedAttributes = ["disabled", "label"];
+    ///
+    ///     attributeChangedCallback() {
+    ///         this.render();
+    ///     }
+    /// }
+    /// ```
+    pub NoUnhandledObservedAttributes {
+        version: "next",
+        name: "noUnhandledObservedAttributes",
+        language: "js",
+        recommended: false,
+    }
+}
+
+pub enum UnhandledObservedAttributes {
+    /// The class doesn't define `attributeChangedCallback`, the range is the one of `observedAttributes`
+    MissingCallback(TextRange),
+    /// The attribute at the given range isn't handled by `attributeChangedCallback`
+    UnhandledAttribute { name: String, range: TextRange },
+}
+
+impl Rule for NoUnhandledObservedAttributes {
+    type Query = Ast<AnyJsClass>;
+    type State = UnhandledObservedAttributes;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let members = ctx.query().members();
+        let Some((observed_member, attributes)) = members.iter().find_map(|member| {
+            let attributes = observed_attributes(&member)?;
+            Some((member, attributes))
+        }) else {
+            return Box::default();
+        };
+
+        let callback = members.iter().find_map(|member| {
+            let method = member.as_js_method_class_member()?;
+            let is_static = method
+                .modifiers()
+                .iter()
+                .any(|modifier| matches!(modifier, AnyJsMethodModifier::JsStaticModifier(_)));
+            (member.has_name("attributeChangedCallback").ok()? && !is_static)
+                .then(|| method.clone())
+        });
+        let Some(callback) = callback else {
+            let range = observed_member
+                .name()
+                .ok()
+                .flatten()
+                .map_or_else(|| observed_member.range(), |name| name.range());
+            return Box::new([UnhandledObservedAttributes::MissingCallback(range)]);
+        };
+
+        let strings: FxHashSet<_> = callback
+            .syntax()
+            .descendants_tokens(Direction::Next)
+            .filter(|token| {
+                matches!(
+                    token.kind(),
+                    JsSyntaxKind::JS_STRING_LITERAL | JsSyntaxKind::TEMPLATE_CHUNK
+                )
+            })
+            .map(|token| {
+                let text = token.text_trimmed();
+                match token.kind() {
+                    JsSyntaxKind::JS_STRING_LITERAL => text[1..text.len() - 1].to_string(),
+                    _ => text.to_string(),
+                }
+            })
+            .collect();
+        // The callback handles all the attributes the same way
+        if !attributes.iter().any(|(name, _)| strings.contains(name)) {
+            return Box::default();
+        }
+
+        attributes
+            .into_iter()
+            .filter(|(name, _)| !strings.contains(name))
+            .map(|(name, range)| UnhandledObservedAttributes::UnhandledAttribute { name, range })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            UnhandledObservedAttributes::MissingCallback(range) => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "This class observes attributes, but doesn't define "<Emphasis>"attributeChangedCallback"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The browser calls "<Emphasis>"attributeChangedCallback"</Emphasis>" when an observed attribute changes, so the changes are ignored."
+            }),
+            UnhandledObservedAttributes::UnhandledAttribute { name, range } => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "The attribute "<Emphasis>{name}</Emphasis>" is observed, but "<Emphasis>"attributeChangedCallback"</Emphasis>" doesn't handle it."
+                },
+            )
+            .note(markup! {
+                "Handle the changes of the attribute in "<Emphasis>"attributeChangedCallback"</Emphasis>", or remove it from "<Emphasis>"observedAttributes"</Emphasis>"."
+            }),
+        };
+
+        Some(diagnostic)
+    }
+}
+
+/// Returns the names of the attributes listed by `member`, along with their range,
+/// if it's the static `observedAttributes` property or getter of the class.
+fn observed_attributes(member: &AnyJsClassMember) -> Option<Vec<(String, TextRange)>> {
+    if !member.has_name("observedAttributes").ok()? {
+        return None;
+    }
+    let array = match member {
+        AnyJsClassMember::JsPropertyClassMember(property) => {
+            let is_static = property
+                .modifiers()
+                .iter()
+                .any(|modifier| matches!(modifier, AnyJsPropertyModifier::JsStaticModifier(_)));
+            if !is_static {
+                return None;
+            }
+            property.value()?.expression().ok()?
+        }
+        AnyJsClassMember::JsGetterClassMember(getter) => {
+            let is_static = getter
+                .modifiers()
+                .iter()
+                .any(|modifier| matches!(modifier, AnyJsMethodModifier::JsStaticModifier(_)));
+            if !is_static {
+                return None;
+            }
+            getter
+                .body()
+                .ok()?
+                .statements()
+                .iter()
+                .find_map(|statement| match statement {
+                    AnyJsStatement::JsReturnStatement(statement) => statement.argument(),
+                    _ => None,
+                })?
+        }
+        _ => return None,
+    };
+    let AnyJsExpression::JsArrayExpression(array) = array.omit_parentheses() else {
+        return None;
+    };
+
+    let attributes = array
+        .elements()
+        .iter()
+        .filter_map(|element| {
+            let AnyJsArrayElement::AnyJsExpression(expression) = element.ok()? else {
+                return None;
+            };
+            let name = expression
+                .as_static_value()?
+                .as_string_constant()?
+                .to_string();
+            Some((name, expression.range()))
+        })
+        .collect();
+    Some(attributes)
+}
@@ -50,7 +50,6 @@ declare_lint_rule! {
     /// - Screen variant sorting (e.g. `md:`, `max-lg:`). Only static, dynamic and arbitrary variants are supported.
     /// - Custom utilitites and variants (such as ones introduced by Tailwind CSS plugins). Only the default Tailwind CSS configuration is supported.
     /// - Options such as `prefix` and `separator`.
-    /// - Object properties (e.g. in `clsx` calls).
     ///
     /// Please don't report issues about these features.
     /// :::
@@ -75,7 +74,7 @@ declare_lint_rule! {
     /// {
     ///     "options": {
     ///         "attributes": ["classList"],
-    ///         "functions": ["clsx", "cva", "tw"]
+    ///         "functions": ["clsx", "cva", "tw", "tw.merge"]
     ///     }
     /// }
     /// ```
@@ -86,7 +85,9 @@ declare_lint_rule! {
     ///
     /// #### functions
     ///
-    /// If specified, strings in the indicated functions will be sorted. This is useful when working with libraries like [`clsx`](https://github.com/lukeed/clsx) or [`cva`](https://cva.style/).
+    /// If specified, strings in the indicated functions will be sorted. This is useful when working with libraries like [`clsx`](https://github.com/lukeed/clsx), [`classnames`](https://github.com/JedWatson/classnames), [`cva`](https://cva.style/) or [`tailwind-merge`](https://github.com/dcastil/tailwind-merge).
+    ///
+    /// The strings passed as arguments are sorted, as well as the keys and the values of the objects passed as arguments:
     ///
     /// ```js,expect_diagnostic,use_options
     /// clsx("px-2 foo p-4 bar", {
@@ -100,6 +101,20 @@ declare_lint_rule! {
     /// });
     /// ```
     ///
+    /// ```js,expect_diagnostic,use_options
+    /// cva("base", {
+    ///     variants: {
+    ///         size: { small: "px-2 foo p-4 bar" },
+    ///     },
+    /// });
+    /// ```
+    ///
+    /// The methods of an object are matched by their full name, such as `tw.merge`, or by the name of the object, such as `tw`:
+    ///
+    /// ```js,expect_diagnostic,use_options
+    /// tw.merge("px-2 foo p-4 bar");
+    /// ```
+    ///
     /// Tagged template literals are also supported, for example:
     ///
     /// ```js,use_options
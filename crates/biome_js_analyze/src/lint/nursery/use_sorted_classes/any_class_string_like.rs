@@ -6,22 +6,43 @@ use biome_js_syntax::{
 };
 use biome_rowan::{declare_node_union, AstNode, TokenText};
 
-fn get_callee_name(call_expression: &JsCallExpression) -> Option<TokenText> {
-    call_expression
-        .callee()
-        .ok()?
-        .as_js_identifier_expression()?
-        .name()
-        .ok()?
-        .name()
-        .ok()
+/// Returns the name of a function or a tag, such as `clsx` or `tw.merge`,
+/// and the name of the object it belongs to, such as `tw`.
+fn get_function_name(expression: &AnyJsExpression) -> Option<(String, TokenText)> {
+    match expression {
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            let name = identifier.name().ok()?.name().ok()?;
+            Some((name.text().to_string(), name))
+        }
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            let (object_name, root) = get_function_name(&member.object().ok()?)?;
+            let member = member.member().ok()?;
+            let member_name = member.as_js_name()?.value_token().ok()?;
+            Some((
+                format!("{object_name}.{}", member_name.text_trimmed()),
+                root,
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if the function or the tag `expression` is listed in the options,
+/// either by its name, such as `tw.merge`, or by the name of the object it belongs to,
+/// such as `tw` for `tw.div`.
+fn is_target_function(expression: &AnyJsExpression, options: &UtilityClassSortingOptions) -> bool {
+    get_function_name(expression).is_some_and(|(name, root)| {
+        options.has_function(&name) || options.has_function(root.text())
+    })
 }
 
 fn is_call_expression_of_target_function(
     call_expression: &JsCallExpression,
     options: &UtilityClassSortingOptions,
 ) -> bool {
-    get_callee_name(call_expression).is_some_and(|name| options.has_function(name.text()))
+    call_expression
+        .callee()
+        .is_ok_and(|callee| is_target_function(&callee, options))
 }
 
 fn get_attribute_name(attribute: &JsxAttribute) -> Option<TokenText> {
@@ -96,13 +117,11 @@ impl AnyClassStringLike {
             AnyClassStringLike::JsTemplateChunkElement(template) => {
                 for ancestor in template.syntax().ancestors().skip(1) {
                     if let Some(template_expression) = JsTemplateExpression::cast_ref(&ancestor) {
-                        if let Some(AnyJsExpression::JsIdentifierExpression(tag)) =
-                            template_expression.tag()
+                        if template_expression
+                            .tag()
+                            .is_some_and(|tag| is_target_function(&tag, options))
                         {
-                            let name = tag.name().ok()?.name().ok()?;
-                            if options.has_function(name.text()) {
-                                return Some(true);
-                            }
+                            return Some(true);
                         }
                     } else if let Some(jsx_attribute) = JsxAttribute::cast_ref(&ancestor) {
                         let attribute_name = get_attribute_name(&jsx_attribute)?;
@@ -0,0 +1,181 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_semantic::CustomElementName;
+use biome_js_syntax::JsCallExpression;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Enforce valid names for the custom elements.
+    ///
+    /// The name of a custom element, as given to `customElements.define`, must contain a dash,
+    /// start with a lowercase letter, and not contain uppercase letters.
+    /// Otherwise, the browser throws a `SyntaxError` when the element is defined.
+    /// The names that are used by the elements of SVG and MathML, such as `font-face`, are reserved.
+    ///
+    /// See the [HTML specification](https://html.spec.whatwg.org/multipage/custom-elements.html#valid-custom-element-name)
+    /// for the complete list of rules.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// customElements.define("button", MyButton);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// customElements.define("My-Button", MyButton);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// customElements.define("font-face", FontFace);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// customElements.define("my-button", MyButton);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `prefix`
+    ///
+    /// The prefix that the names of the custom elements must start with,
+    /// such as the name of the design system they belong to.
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "prefix": "acme-"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic,use_options
+    /// customElements.define("my-button", MyButton);
+    /// ```
+    ///
+    /// ```js,use_options
+    /// customElements.define("acme-button", AcmeButton);
+    /// ```
+    pub UseValidCustomElementName {
+        version: "next",
+        name: "useValidCustomElementName",
+        language: "js",
+        recommended: false,
+    }
+}
+
+/// The names used by the elements of SVG and MathML, which can't be used by custom elements
+const RESERVED_NAMES: &[&str] = &[
+    "annotation-xml",
+    "color-profile",
+    "font-face",
+    "font-face-format",
+    "font-face-name",
+    "font-face-src",
+    "font-face-uri",
+    "missing-glyph",
+];
+
+#[derive(Debug, Clone, Deserialize, Deserializable, Eq, PartialEq, Serialize, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseValidCustomElementNameOptions {
+    /// The prefix that the names of the custom elements must start with, such as `acme-`
+    #[serde(default, skip_serializing_if = "str::is_empty")]
+    prefix: Box<str>,
+}
+
+pub enum InvalidCustomElementName {
+    /// The name doesn't contain a dash
+    MissingDash,
+    /// The name doesn't start with a lowercase letter, or contains characters that aren't allowed
+    InvalidCharacters,
+    /// The name is used by an element of SVG or MathML
+    Reserved,
+    /// The name doesn't start with the prefix of the options
+    MissingPrefix,
+}
+
+impl Rule for UseValidCustomElementName {
+    type Query = Ast<JsCallExpression>;
+    type State = (CustomElementName, InvalidCustomElementName);
+    type Signals = Option<Self::State>;
+    type Options = UseValidCustomElementNameOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let definition = CustomElementName::from_define_call(ctx.query())?;
+        let name = definition.name.as_str();
+
+        let is_valid_character =
+            |c: char| !c.is_ascii() || matches!(c, 'a'..='z' | '0'..='9' | '-' | '.' | '_');
+        let kind = if !name.starts_with(|c: char| c.is_ascii_lowercase())
+            || !name.chars().all(is_valid_character)
+        {
+            InvalidCustomElementName::InvalidCharacters
+        } else if !name.contains('-') {
+            InvalidCustomElementName::MissingDash
+        } else if RESERVED_NAMES.contains(&name) {
+            InvalidCustomElementName::Reserved
+        } else if !name.starts_with(&*ctx.options().prefix) {
+            InvalidCustomElementName::MissingPrefix
+        } else {
+            return None;
+        };
+
+        Some((definition, kind))
+    }
+
+    fn diagnostic(
+        ctx: &RuleContext<Self>,
+        (definition, kind): &Self::State,
+    ) -> Option<RuleDiagnostic> {
+        let diagnostic = match kind {
+            InvalidCustomElementName::MissingDash => RuleDiagnostic::new(
+                rule_category!(),
+                definition.range,
+                markup! {
+                    "The name of a custom element must contain a dash."
+                },
+            )
+            .note(markup! {
+                "The names without a dash are reserved for the elements of HTML, such as "<Emphasis>"button"</Emphasis>"."
+            }),
+            InvalidCustomElementName::InvalidCharacters => RuleDiagnostic::new(
+                rule_category!(),
+                definition.range,
+                markup! {
+                    "The name of a custom element must start with a lowercase letter, and can't contain uppercase letters."
+                },
+            )
+            .note(markup! {
+                "The browser throws a "<Emphasis>"SyntaxError"</Emphasis>" when the element is defined."
+            }),
+            InvalidCustomElementName::Reserved => RuleDiagnostic::new(
+                rule_category!(),
+                definition.range,
+                markup! {
+                    "The name "<Emphasis>{definition.name}</Emphasis>" is reserved by an element of SVG or MathML."
+                },
+            ),
+            InvalidCustomElementName::MissingPrefix => {
+                let prefix: &str = &ctx.options().prefix;
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    definition.range,
+                    markup! {
+                        "The name of this custom element doesn't start with "<Emphasis>{prefix}</Emphasis>"."
+                    },
+                )
+                .note(markup! {
+                    "The names of the custom elements must start with the prefix configured by the "<Emphasis>"prefix"</Emphasis>" option."
+                })
+            }
+        };
+
+        Some(diagnostic)
+    }
+}
@@ -68,6 +68,7 @@ pub type NoDoubleEquals =
 pub type NoDuplicateCase =
     <lint::suspicious::no_duplicate_case::NoDuplicateCase as biome_analyze::Rule>::Options;
 pub type NoDuplicateClassMembers = < lint :: suspicious :: no_duplicate_class_members :: NoDuplicateClassMembers as biome_analyze :: Rule > :: Options ;
+pub type NoDuplicateCustomElementNames = < lint :: nursery :: no_duplicate_custom_element_names :: NoDuplicateCustomElementNames as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateElseIf =
     <lint::nursery::no_duplicate_else_if::NoDuplicateElseIf as biome_analyze::Rule>::Options;
 pub type NoDuplicateJsxProps =
@@ -224,6 +225,7 @@ pub type NoThisInStatic =
     <lint::complexity::no_this_in_static::NoThisInStatic as biome_analyze::Rule>::Options;
 pub type NoUndeclaredDependencies = < lint :: correctness :: no_undeclared_dependencies :: NoUndeclaredDependencies as biome_analyze :: Rule > :: Options ;
 pub type NoUndeclaredVariables = < lint :: correctness :: no_undeclared_variables :: NoUndeclaredVariables as biome_analyze :: Rule > :: Options ;
+pub type NoUnhandledObservedAttributes = < lint :: nursery :: no_unhandled_observed_attributes :: NoUnhandledObservedAttributes as biome_analyze :: Rule > :: Options ;
 pub type NoUnnecessaryContinue = < lint :: correctness :: no_unnecessary_continue :: NoUnnecessaryContinue as biome_analyze :: Rule > :: Options ;
 pub type NoUnreachable =
     <lint::correctness::no_unreachable::NoUnreachable as biome_analyze::Rule>::Options;
@@ -419,6 +421,7 @@ pub type UseValidAriaValues =
     <lint::a11y::use_valid_aria_values::UseValidAriaValues as biome_analyze::Rule>::Options;
 pub type UseValidAutocomplete =
     <lint::nursery::use_valid_autocomplete::UseValidAutocomplete as biome_analyze::Rule>::Options;
+pub type UseValidCustomElementName = < lint :: nursery :: use_valid_custom_element_name :: UseValidCustomElementName as biome_analyze :: Rule > :: Options ;
 pub type UseValidForDirection = < lint :: correctness :: use_valid_for_direction :: UseValidForDirection as biome_analyze :: Rule > :: Options ;
 pub type UseValidLang = <lint::a11y::use_valid_lang::UseValidLang as biome_analyze::Rule>::Options;
 pub type UseValidTypeof =
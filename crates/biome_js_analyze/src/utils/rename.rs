@@ -2,9 +2,10 @@ use biome_console::fmt::Formatter;
 use biome_console::markup;
 use biome_diagnostics::{Diagnostic, Location, Severity};
 use biome_js_factory::make;
-use biome_js_semantic::{ReferencesExtensions, SemanticModel};
+use biome_js_semantic::{Binding, ReferencesExtensions, SemanticModel};
 use biome_js_syntax::{
-    binding_ext::AnyJsIdentifierBinding, AnyJsIdentifierUsage, JsIdentifierAssignment,
+    binding_ext::AnyJsIdentifierBinding, AnyJsIdentifierUsage, AnyJsNamedImportSpecifier,
+    JsExportNamedFromClause, JsExportNamedFromSpecifier, JsIdentifierAssignment,
     JsIdentifierBinding, JsLanguage, JsReferenceIdentifier, JsSyntaxKind, JsSyntaxNode, TextRange,
     TsIdentifierBinding, T,
 };
@@ -80,6 +81,12 @@ pub enum RenameError {
         original_range: TextRange,
         new_name: String,
     },
+    /// Another binding named `new_name` would shadow the renamed binding, or one of its references
+    NameConflict {
+        original_name: String,
+        new_name: String,
+        conflicting_range: TextRange,
+    },
 }
 
 impl std::fmt::Display for RenameError {
@@ -101,6 +108,16 @@ impl std::fmt::Display for RenameError {
                     "encountered an error finding a declaration at the specified position"
                 )
             }
+            RenameError::NameConflict {
+                original_name,
+                new_name,
+                ..
+            } => {
+                write!(
+                    f,
+                    "the symbol \"{original_name}\" can't be renamed to \"{new_name}\" because another declaration has this name"
+                )
+            }
         }
     }
 }
@@ -122,15 +139,24 @@ impl Diagnostic for RenameError {
                     markup! { "Can't rename from "<Emphasis>{{original_name}}</Emphasis>" to "<Emphasis>{{new_name}}</Emphasis>"" }
                 )
             }
+            RenameError::NameConflict { original_name, new_name, .. } => {
+                fmt.write_markup(
+                    markup! { "Can't rename from "<Emphasis>{{original_name}}</Emphasis>" to "<Emphasis>{{new_name}}</Emphasis>" because it would conflict with this declaration of "<Emphasis>{{new_name}}</Emphasis>"" }
+                )
+            }
         }
     }
 
     fn location(&self) -> Location<'_> {
         let location = Location::builder();
-        if let RenameError::CannotBeRenamed { original_range, .. } = self {
-            location.span(original_range).build()
-        } else {
-            location.build()
+        match self {
+            RenameError::CannotBeRenamed { original_range, .. } => {
+                location.span(original_range).build()
+            }
+            RenameError::NameConflict {
+                conflicting_range, ..
+            } => location.span(conflicting_range).build(),
+            RenameError::CannotFindDeclaration(_) => location.build(),
         }
     }
 }
@@ -162,6 +188,173 @@ impl TryFrom<JsSyntaxNode> for AnyJsRenamableDeclaration {
     }
 }
 
+/// Returns the binding that prevents the binding of `node` from being renamed to `new_name`.
+///
+/// The binding can't be renamed when `new_name` is already declared in its scope,
+/// or when a binding named `new_name` would shadow one of its references.
+/// The bindings of the parent scopes can be shadowed by the renamed binding itself.
+pub fn find_rename_conflict(
+    model: &SemanticModel,
+    node: &impl RenamableNode,
+    new_name: &str,
+) -> Option<Binding> {
+    let binding = node.binding(model).and_then(AnyJsIdentifierBinding::cast)?;
+    let syntax = binding.syntax();
+    let scope = model
+        .scope_hoisted_to(syntax)
+        .unwrap_or_else(|| model.scope(syntax));
+    if let Some(conflict) = scope.get_binding(new_name) {
+        return Some(conflict);
+    }
+
+    binding.all_references(model).find_map(|reference| {
+        reference
+            .scope()
+            .ancestors()
+            .find_map(|scope| scope.get_binding(new_name))
+    })
+}
+
+/// Returns `true` if the binding of `node` is exported under its own name, as in
+/// `export function a() {}` or `export { a }`, so that renaming it also renames
+/// the symbol imported by the other modules.
+///
+/// The default exports and the aliased exports, such as `export { a as b }`,
+/// don't depend on the name of the binding.
+pub fn is_exported_under_its_name(model: &SemanticModel, node: &impl RenamableNode) -> bool {
+    let Some(binding) = node.binding(model).and_then(AnyJsIdentifierBinding::cast) else {
+        return false;
+    };
+    if !model.is_exported(&binding) {
+        return false;
+    }
+
+    let is_exported_declaration = binding
+        .syntax()
+        .ancestors()
+        .take_while(|node| node.kind() != JsSyntaxKind::JS_EXPORT_DEFAULT_DECLARATION_CLAUSE)
+        .any(|node| node.kind() == JsSyntaxKind::JS_EXPORT);
+    is_exported_declaration
+        || binding.all_references(model).any(|reference| {
+            reference.syntax().parent().is_some_and(|parent| {
+                parent.kind() == JsSyntaxKind::JS_EXPORT_NAMED_SHORTHAND_SPECIFIER
+            })
+        })
+}
+
+/// Renames the symbol `name`, exported by another module, in the named imports and
+/// the re-exports of the module of `batch`. `is_renamed_module` tells whether
+/// the specifier of an import refers to the module that exports the symbol.
+///
+/// The shorthand imports are renamed along with their references, while the aliased
+/// imports only have their imported name renamed:
+///
+/// ```js
+/// import { a } from "./a"; a();         // import { b } from "./a"; b();
+/// import { a as c } from "./a"; c();    // import { b as c } from "./a"; c();
+/// ```
+///
+/// The re-exports keep the name of the symbol, so that the modules that import it from
+/// the re-exporting module don't have to change: `export { a } from "./a"` becomes
+/// `export { b as a } from "./a"`.
+pub fn rename_imported_symbol(
+    batch: &mut BatchMutation<JsLanguage>,
+    model: &SemanticModel,
+    is_renamed_module: impl Fn(&str) -> bool,
+    name: &str,
+    new_name: &str,
+) -> Result<(), RenameError> {
+    let root = batch.root().clone();
+    for node in root.descendants() {
+        if let Some(specifier) = AnyJsNamedImportSpecifier::cast_ref(&node) {
+            let is_renamed = specifier
+                .import_clause()
+                .and_then(|clause| clause.source().ok())
+                .and_then(|source| source.inner_string_text().ok())
+                .is_some_and(|source| is_renamed_module(source.text()));
+            if !is_renamed {
+                continue;
+            }
+
+            match specifier {
+                AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+                    let Some(binding) = specifier
+                        .local_name()
+                        .ok()
+                        .and_then(|local_name| local_name.as_js_identifier_binding().cloned())
+                    else {
+                        continue;
+                    };
+                    let Ok(name_token) = binding.name_token() else {
+                        continue;
+                    };
+                    if name_token.text_trimmed() != name {
+                        continue;
+                    }
+                    if let Some(conflict) = find_rename_conflict(model, &binding, new_name) {
+                        return Err(RenameError::NameConflict {
+                            original_name: name.to_string(),
+                            new_name: new_name.to_string(),
+                            conflicting_range: conflict.syntax().text_trimmed_range(),
+                        });
+                    }
+                    if !batch.rename_node_declaration(model, &binding, new_name) {
+                        return Err(RenameError::CannotBeRenamed {
+                            original_name: name.to_string(),
+                            original_range: binding.range(),
+                            new_name: new_name.to_string(),
+                        });
+                    }
+                }
+                AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+                    let Ok(name_token) = specifier.name().and_then(|name| name.value()) else {
+                        continue;
+                    };
+                    if name_token.text_trimmed() == name {
+                        batch.replace_token_transfer_trivia(name_token, make::ident(new_name));
+                    }
+                }
+                AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => {}
+            }
+        } else if let Some(specifier) = JsExportNamedFromSpecifier::cast_ref(&node) {
+            let is_renamed = node
+                .ancestors()
+                .find_map(JsExportNamedFromClause::cast)
+                .and_then(|clause| clause.source().ok())
+                .and_then(|source| source.as_js_module_source()?.inner_string_text().ok())
+                .is_some_and(|source| is_renamed_module(source.text()));
+            let Ok(name_token) = specifier.source_name().and_then(|name| name.value()) else {
+                continue;
+            };
+            if !is_renamed || name_token.text_trimmed() != name {
+                continue;
+            }
+
+            if specifier.export_as().is_some() {
+                batch.replace_token_transfer_trivia(name_token, make::ident(new_name));
+            } else {
+                let source_name = make::ident(new_name)
+                    .with_leading_trivia_pieces(name_token.leading_trivia().pieces());
+                let exported_name = make::ident(name)
+                    .with_trailing_trivia_pieces(name_token.trailing_trivia().pieces());
+                let export_as = make::js_export_as_clause(
+                    make::token(T![as])
+                        .with_leading_trivia([(TriviaPieceKind::Whitespace, " ")])
+                        .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                    make::js_literal_export_name(exported_name),
+                );
+                let next_specifier = specifier
+                    .clone()
+                    .with_source_name(make::js_literal_export_name(source_name))
+                    .with_export_as(Some(export_as));
+                batch.replace_node(specifier, next_specifier);
+            }
+        }
+    }
+
+    Ok(())
+}
+
 pub trait RenameSymbolExtensions {
     /// Rename the binding and all its references to "new_name".
     fn rename_node_declaration(
@@ -227,13 +420,9 @@ impl RenameSymbolExtensions for BatchMutation<JsLanguage> {
             None => return false,
         };
 
-        // We can rename a binding if there is no conflicts in the current scope.
-        // We can shadow parent scopes, so we don´t check them.
-        let syntax = prev_binding.syntax();
-        let scope = model
-            .scope_hoisted_to(syntax)
-            .unwrap_or_else(|| model.scope(syntax));
-        if scope.get_binding(new_name).is_some() {
+        // We can rename a binding if there is no conflicts in the current scope,
+        // nor in the scopes of its references.
+        if find_rename_conflict(model, &prev_binding, new_name).is_some() {
             return false;
         }
 
@@ -241,24 +430,11 @@ impl RenameSymbolExtensions for BatchMutation<JsLanguage> {
             return false;
         };
 
-        // We can rename references, if there is no conflicts in any scope
-        // until the root.
-
         let all_references: Vec<_> = prev_binding.all_references(model).collect();
         let mut token_changes = Vec::with_capacity(all_references.len());
         let mut node_changes = vec![];
 
         for reference in all_references {
-            // We can rename a reference if there is no binding named `new_name`
-            // in the current scope or a parent scope.
-            if reference
-                .scope()
-                .ancestors()
-                .any(|scope| scope.get_binding(new_name).is_some())
-            {
-                return false;
-            }
-
             let reference_syntax = reference.syntax();
             let Some(id_usage) = AnyJsIdentifierUsage::cast_ref(reference_syntax) else {
                 continue;
@@ -310,10 +486,13 @@ impl RenameSymbolExtensions for BatchMutation<JsLanguage> {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::rename::RenameError;
+    use crate::utils::rename::{rename_imported_symbol, RenameError};
     use crate::{assert_rename_nok, assert_rename_ok};
     use biome_diagnostics::{print_diagnostic_to_string, DiagnosticExt, Error};
-    use biome_js_syntax::TextRange;
+    use biome_js_parser::JsParserOptions;
+    use biome_js_semantic::{semantic_model, SemanticModelOptions};
+    use biome_js_syntax::{JsFileSource, TextRange};
+    use biome_rowan::BatchMutationExt;
 
     assert_rename_ok! {
         ok_rename_declaration,
@@ -369,6 +548,35 @@ mod tests {
         nok_rename_function_conflict, "function a() {} function b() {}",
     }
 
+    fn rename_imported_a_to_b(source: &str) -> Result<String, RenameError> {
+        let parsed = biome_js_parser::parse(
+            source,
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+        let model = semantic_model(&parsed.tree(), SemanticModelOptions::default());
+        let mut batch = parsed.tree().begin();
+        rename_imported_symbol(&mut batch, &model, |source| source == "./a", "a", "b")?;
+        Ok(batch.commit().to_string())
+    }
+
+    #[test]
+    fn ok_rename_imported_symbol() {
+        let renamed = rename_imported_a_to_b(
+            r#"import { a } from "./a"; import { a as c } from "./a"; import { a as d } from "./d"; export { a } from "./a"; a(); c();"#,
+        );
+        assert_eq!(
+            renamed.unwrap(),
+            r#"import { b } from "./a"; import { b as c } from "./a"; import { a as d } from "./d"; export { b as a } from "./a"; b(); c();"#
+        );
+    }
+
+    #[test]
+    fn nok_rename_imported_symbol_conflict() {
+        let renamed = rename_imported_a_to_b(r#"import { a } from "./a"; let b = a;"#);
+        assert!(matches!(renamed, Err(RenameError::NameConflict { .. })));
+    }
+
     fn snap_diagnostic(test_name: &str, diagnostic: &Error) {
         let content = print_diagnostic_to_string(diagnostic);
 
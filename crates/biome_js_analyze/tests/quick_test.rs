@@ -8,6 +8,7 @@ use biome_test_utils::{
     parse_test_path, scripts_from_json,
 };
 use std::ops::Deref;
+use std::sync::Arc;
 use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
 // use this test check if your snippet produces the diagnostics you wish, without using a snapshot
@@ -72,8 +73,15 @@ fn analyze(
     let options = create_analyzer_options(input_file, &mut diagnostics);
     let manifest = load_manifest(input_file, &mut diagnostics);
 
-    let (_, errors) =
-        biome_js_analyze::analyze(&root, filter, &options, source_type, manifest, |event| {
+    let project = Arc::default();
+    let (_, errors) = biome_js_analyze::analyze(
+        &root,
+        filter,
+        &options,
+        source_type,
+        manifest,
+        project,
+        |event| {
             if let Some(mut diag) = event.diagnostic() {
                 for action in event.actions() {
                     diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
@@ -89,7 +97,8 @@ fn analyze(
             }
 
             ControlFlow::<Never>::Continue(())
-        });
+        },
+    );
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
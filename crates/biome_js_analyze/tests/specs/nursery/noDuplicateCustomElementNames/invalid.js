@@ -0,0 +1,4 @@
+customElements.define("my-button", MyButton);
+customElements.define("my-dialog", MyDialog);
+window.customElements.define("my-button", MyOtherButton);
+customElements.define('my-dialog', class extends HTMLElement {});
@@ -0,0 +1,4 @@
+customElements.define("my-button", MyButton);
+customElements.define("my-dialog", MyDialog);
+customElements.define(name, MyElement);
+customElements.define(name, MyOtherElement);
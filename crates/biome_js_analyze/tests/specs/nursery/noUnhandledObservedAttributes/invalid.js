@@ -0,0 +1,26 @@
+class MyButton extends HTMLElement {
+    static observedAttributes = ["disabled"];
+}
+
+class MyDialog extends HTMLElement {
+    static get observedAttributes() {
+        return ["open", "modal", "lable"];
+    }
+
+    attributeChangedCallback(name, oldValue, newValue) {
+        if (name === "open") {
+            this.#toggle(newValue !== null);
+        }
+        if (name === "modal") {
+            this.#render();
+        }
+    }
+}
+
+const MyCard = class extends HTMLElement {
+    static observedAttributes = ["title", "subtitle"];
+
+    static attributeChangedCallback(name) {
+        console.log(name);
+    }
+};
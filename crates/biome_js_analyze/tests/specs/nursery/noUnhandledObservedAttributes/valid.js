@@ -0,0 +1,42 @@
+class MyButton extends HTMLElement {
+    static observedAttributes = ["disabled", "label"];
+
+    attributeChangedCallback(name, oldValue, newValue) {
+        switch (name) {
+            case "disabled":
+                this.toggleAttribute("aria-disabled", newValue !== null);
+                break;
+            case "label":
+                this.textContent = newValue;
+                break;
+        }
+    }
+}
+
+class MyDialog extends HTMLElement {
+    static get observedAttributes() {
+        return ["open", "modal"];
+    }
+
+    attributeChangedCallback(name) {
+        if (name === `open`) {
+            this.#toggle();
+        } else if (name === "modal") {
+            this.#render();
+        }
+    }
+}
+
+// The attributes are all handled the same way
+class MyCard extends HTMLElement {
+    static observedAttributes = ["title", "subtitle"];
+
+    attributeChangedCallback() {
+        this.render();
+    }
+}
+
+// Not the static property
+class MyMenu extends HTMLElement {
+    observedAttributes = ["items"];
+}
@@ -0,0 +1,12 @@
+/* SHOULD emit diagnostics (functions specified in options) */
+classnames("px-2 foo p-4 bar", { "block mx-4": condition });
+tw.merge("px-2 foo p-4 bar");
+cva("px-2 foo p-4 bar", {
+	variants: {
+		size: { small: "px-2 foo p-4 bar" },
+	},
+});
+/* SHOULD NOT emit diagnostics (functions NOT specified in options) */
+tw.join("px-2 foo p-4 bar");
+merge("px-2 foo p-4 bar");
+styles.classnames("px-2 foo p-4 bar");
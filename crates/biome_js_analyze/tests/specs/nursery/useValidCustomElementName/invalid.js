@@ -0,0 +1,5 @@
+customElements.define("button", MyButton);
+customElements.define("My-Button", MyButton);
+customElements.define("1-button", MyButton);
+window.customElements.define("my button", MyButton);
+customElements.define("font-face", FontFace);
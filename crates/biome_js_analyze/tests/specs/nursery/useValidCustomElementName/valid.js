@@ -0,0 +1,6 @@
+customElements.define("my-button", MyButton);
+window.customElements.define("my-dialog", MyDialog);
+globalThis.customElements.define("x-1", XOne);
+customElements.define("my-element.v2", MyElement);
+customElements.define(name, MyElement);
+registry.define("button", Button);
@@ -0,0 +1,2 @@
+customElements.define("acme-button", AcmeButton);
+customElements.define("my-button", MyButton);
@@ -1,8 +1,12 @@
 mod events;
 
+mod module_graph;
+mod project_index;
 mod semantic_model;
 #[cfg(test)]
 mod tests;
 
 pub use events::*;
+pub use module_graph::*;
+pub use project_index::*;
 pub use semantic_model::*;
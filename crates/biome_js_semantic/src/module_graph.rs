@@ -0,0 +1,287 @@
+use std::collections::hash_map::Entry;
+use std::path::{Path, PathBuf};
+
+use rayon::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::project_index::{normalize_path, resolution_candidates};
+
+/// The imports between the indexed scripts, resolved to the files they import.
+///
+/// The graph is patched when a file is indexed, instead of being built again: the imports of
+/// the file are resolved again, and when the file is new or forgotten, so are the imports of
+/// the other files that could resolve to it. The paths tried by each import are recorded, so
+/// that these imports are found without resolving all the imports of the project.
+#[derive(Debug, Clone, Default)]
+pub struct JsModuleGraph {
+    /// The indexed files, keyed by their normalized path
+    files: FxHashMap<PathBuf, PathBuf>,
+    /// The imports of each file, in the order they appear
+    imports: FxHashMap<PathBuf, Vec<ModuleImport>>,
+    /// The files with an import that tries each normalized path
+    dependents: FxHashMap<PathBuf, FxHashSet<PathBuf>>,
+}
+
+/// An import of a script, with the file it resolves to.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ModuleImport {
+    /// The specifier of the imported module, as written in the script.
+    pub specifier: String,
+    /// The file imported by the specifier, or [None] if the specifier isn't relative,
+    /// or doesn't match an indexed file.
+    pub resolved: Option<PathBuf>,
+}
+
+impl JsModuleGraph {
+    /// Returns the imports of the file at `path`, in the order they appear.
+    pub fn imports(&self, path: &Path) -> &[ModuleImport] {
+        self.imports.get(path).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns all the files, along with their imports.
+    pub fn modules(&self) -> impl Iterator<Item = (&Path, &[ModuleImport])> {
+        self.imports
+            .iter()
+            .map(|(path, imports)| (path.as_path(), imports.as_slice()))
+    }
+
+    /// Returns the files with an import that resolves to the file at `path`.
+    pub fn importers<'a>(&'a self, path: &'a Path) -> impl Iterator<Item = &'a Path> + 'a {
+        self.dependents
+            .get(&normalize_path(path))
+            .into_iter()
+            .flatten()
+            .filter(move |dependent| {
+                self.imports(dependent)
+                    .iter()
+                    .any(|import| import.resolved.as_deref() == Some(path))
+            })
+            .map(PathBuf::as_path)
+    }
+
+    /// Returns the indexed files, keyed by their normalized path.
+    pub(crate) fn normalized_files(&self) -> &FxHashMap<PathBuf, PathBuf> {
+        &self.files
+    }
+
+    /// Records the file at `path` and resolves its imports, then resolves again the imports of
+    /// the other files that could resolve to it if the file is new.
+    pub(crate) fn insert_file(&mut self, path: &Path, specifiers: &[String]) {
+        self.unlink_imports(path);
+        let is_new = self
+            .files
+            .insert(normalize_path(path), path.to_path_buf())
+            .is_none();
+        let imports = specifiers
+            .iter()
+            .map(|specifier| self.resolve(path, specifier))
+            .collect();
+        self.link_imports(path, imports);
+        if is_new {
+            self.update_dependents(path, &FxHashSet::default());
+        }
+    }
+
+    /// Records many files at once. Their imports are resolved in parallel, once all the
+    /// files are recorded, so that they don't need to be resolved again.
+    pub(crate) fn insert_files<'a>(
+        &mut self,
+        files: impl Iterator<Item = (&'a Path, &'a [String])>,
+    ) {
+        let files: Vec<_> = files.collect();
+        let mut new_files = Vec::new();
+        for (path, _) in &files {
+            self.unlink_imports(path);
+            if self
+                .files
+                .insert(normalize_path(path), path.to_path_buf())
+                .is_none()
+            {
+                new_files.push(*path);
+            }
+        }
+
+        let resolved: Vec<_> = files
+            .par_iter()
+            .map(|(path, specifiers)| {
+                let imports: Vec<_> = specifiers
+                    .iter()
+                    .map(|specifier| self.resolve(path, specifier))
+                    .collect();
+                (*path, imports)
+            })
+            .collect();
+        for (path, imports) in resolved {
+            self.link_imports(path, imports);
+        }
+
+        // The imports of the inserted files were resolved with all the files known already
+        let inserted: FxHashSet<_> = files.iter().map(|(path, _)| path.to_path_buf()).collect();
+        for path in new_files {
+            self.update_dependents(path, &inserted);
+        }
+    }
+
+    /// Forgets the file at `path`, and resolves again the imports of the other files
+    /// that resolved to it.
+    pub(crate) fn remove_file(&mut self, path: &Path) {
+        self.unlink_imports(path);
+        if self.files.remove(&normalize_path(path)).is_some() {
+            self.update_dependents(path, &FxHashSet::default());
+        }
+    }
+
+    fn resolve(&self, path: &Path, specifier: &str) -> ModuleImport {
+        ModuleImport {
+            specifier: specifier.to_string(),
+            resolved: self.find_file(&resolution_candidates(path, specifier)),
+        }
+    }
+
+    /// Returns the first of the `candidates` that is an indexed file
+    fn find_file(&self, candidates: &[PathBuf]) -> Option<PathBuf> {
+        candidates
+            .iter()
+            .find_map(|candidate| self.files.get(candidate))
+            .cloned()
+    }
+
+    /// Records the imports of the file at `path`, and the paths they try
+    fn link_imports(&mut self, path: &Path, imports: Vec<ModuleImport>) {
+        for import in &imports {
+            for candidate in resolution_candidates(path, &import.specifier) {
+                self.dependents
+                    .entry(candidate)
+                    .or_default()
+                    .insert(path.to_path_buf());
+            }
+        }
+        self.imports.insert(path.to_path_buf(), imports);
+    }
+
+    /// Forgets the imports of the file at `path`, and the paths they try
+    fn unlink_imports(&mut self, path: &Path) {
+        let Some(imports) = self.imports.remove(path) else {
+            return;
+        };
+        for import in imports {
+            for candidate in resolution_candidates(path, &import.specifier) {
+                if let Entry::Occupied(mut entry) = self.dependents.entry(candidate) {
+                    entry.get_mut().remove(path);
+                    if entry.get().is_empty() {
+                        entry.remove();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Resolves again the imports that try the path of the file at `path`, because the file
+    /// was added or removed. The imports of the files in `skipped` are left untouched.
+    fn update_dependents(&mut self, path: &Path, skipped: &FxHashSet<PathBuf>) {
+        let normalized = normalize_path(path);
+        let Some(dependents) = self.dependents.get(&normalized).cloned() else {
+            return;
+        };
+        for dependent in dependents {
+            if skipped.contains(&dependent) {
+                continue;
+            }
+            let updates: Vec<_> = self
+                .imports(&dependent)
+                .iter()
+                .map(|import| {
+                    let candidates = resolution_candidates(&dependent, &import.specifier);
+                    candidates
+                        .contains(&normalized)
+                        .then(|| self.find_file(&candidates))
+                })
+                .collect();
+            if let Some(imports) = self.imports.get_mut(&dependent) {
+                for (import, resolved) in imports.iter_mut().zip(updates) {
+                    if let Some(resolved) = resolved {
+                        import.resolved = resolved;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn imports(graph: &JsModuleGraph, path: &str) -> Vec<Option<String>> {
+        graph
+            .imports(Path::new(path))
+            .iter()
+            .map(|import| {
+                import
+                    .resolved
+                    .as_ref()
+                    .map(|path| path.display().to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn patches_the_imports_when_a_file_changes() {
+        let mut graph = JsModuleGraph::default();
+        graph.insert_file(
+            Path::new("src/main.ts"),
+            &["./utils".to_string(), "react".to_string()],
+        );
+        assert_eq!(imports(&graph, "src/main.ts"), [None, None]);
+
+        graph.insert_file(Path::new("src/utils/index.ts"), &[]);
+        assert_eq!(
+            imports(&graph, "src/main.ts"),
+            [Some("src/utils/index.ts".to_string()), None]
+        );
+
+        // The file takes precedence over the index of the directory
+        graph.insert_file(Path::new("src/utils.ts"), &[]);
+        assert_eq!(
+            imports(&graph, "src/main.ts"),
+            [Some("src/utils.ts".to_string()), None]
+        );
+        assert_eq!(
+            graph
+                .importers(Path::new("src/utils.ts"))
+                .collect::<Vec<_>>(),
+            [Path::new("src/main.ts")]
+        );
+
+        graph.remove_file(Path::new("src/utils.ts"));
+        assert_eq!(
+            imports(&graph, "src/main.ts"),
+            [Some("src/utils/index.ts".to_string()), None]
+        );
+
+        graph.insert_file(Path::new("src/main.ts"), &["./missing".to_string()]);
+        assert_eq!(imports(&graph, "src/main.ts"), [None]);
+        assert_eq!(graph.importers(Path::new("src/utils/index.ts")).count(), 0);
+    }
+
+    #[test]
+    fn resolves_the_files_inserted_at_once() {
+        let files = [
+            ("a.js", vec!["./b.js".to_string()]),
+            ("b.ts", vec!["./c".to_string()]),
+            ("c.js", vec!["./a.js".to_string()]),
+        ];
+        let mut graph = JsModuleGraph::default();
+        graph.insert_file(Path::new("main.js"), &["./a.js".to_string()]);
+        graph.insert_files(
+            files
+                .iter()
+                .map(|(path, specifiers)| (Path::new(*path), specifiers.as_slice())),
+        );
+
+        assert_eq!(imports(&graph, "main.js"), [Some("a.js".to_string())]);
+        assert_eq!(imports(&graph, "a.js"), [Some("b.ts".to_string())]);
+        assert_eq!(imports(&graph, "b.ts"), [Some("c.js".to_string())]);
+        assert_eq!(imports(&graph, "c.js"), [Some("a.js".to_string())]);
+    }
+}
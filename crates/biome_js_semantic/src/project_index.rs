@@ -0,0 +1,415 @@
+use std::path::{Component, Path, PathBuf};
+
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsImportLike, AnyJsRoot, JsCallExpression, JsStaticMemberExpression,
+};
+use biome_rowan::{AstNode, TextRange};
+use rustc_hash::FxHashMap;
+
+use crate::JsModuleGraph;
+
+/// The extensions tried, in order, when an import doesn't have one, as in `import "./utils"`.
+const RESOLVED_EXTENSIONS: [&str; 8] = ["ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs"];
+
+/// An index of the symbols defined by the scripts of a project.
+///
+/// Unlike the [SemanticModel](crate::SemanticModel), which only knows
+/// about a single script, the index collects information across files,
+/// so that lint rules can reason about definitions that live in other scripts.
+///
+/// The index is keyed by file path: indexing a file again replaces whatever
+/// was previously recorded for it.
+#[derive(Debug, Clone, Default)]
+pub struct JsProjectIndex {
+    files: FxHashMap<PathBuf, JsFileIndex>,
+    graph: JsModuleGraph,
+}
+
+impl JsProjectIndex {
+    /// Records the symbols of the file at `path`, replacing the previous entry, if any.
+    pub fn insert_file(&mut self, path: PathBuf, index: JsFileIndex) {
+        self.graph.insert_file(&path, index.imports());
+        self.files.insert(path, index);
+    }
+
+    /// Records the symbols of many files at once, replacing their previous entries.
+    ///
+    /// Unlike calling [Self::insert_file] for each file, the imports of the files are
+    /// resolved in parallel, once all the files are known.
+    pub fn insert_files(&mut self, files: Vec<(PathBuf, JsFileIndex)>) {
+        self.graph.insert_files(
+            files
+                .iter()
+                .map(|(path, index)| (path.as_path(), index.imports())),
+        );
+        self.files.extend(files);
+    }
+
+    /// Forgets everything that was recorded for the file at `path`.
+    pub fn remove_file(&mut self, path: &Path) -> Option<JsFileIndex> {
+        self.graph.remove_file(path);
+        self.files.remove(path)
+    }
+
+    /// Returns the symbols recorded for the file at `path`.
+    pub fn get_file(&self, path: &Path) -> Option<&JsFileIndex> {
+        self.files.get(path)
+    }
+
+    /// Returns all the indexed files, along with their symbols.
+    pub fn files(&self) -> impl Iterator<Item = (&Path, &JsFileIndex)> {
+        self.files.iter().map(|(path, file)| (path.as_path(), file))
+    }
+
+    /// Returns the imports between the indexed files, resolved to the files they import.
+    pub fn module_graph(&self) -> &JsModuleGraph {
+        &self.graph
+    }
+
+    /// Returns a resolver of the imports between the indexed files.
+    pub fn module_resolver(&self) -> ModuleResolver<'_> {
+        ModuleResolver {
+            files: self.graph.normalized_files(),
+        }
+    }
+
+    /// Returns all the custom elements named `name`, along with the path of the file
+    /// where they are defined.
+    pub fn custom_element_definitions<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = (&'a Path, &'a CustomElementName)> + 'a {
+        self.files.iter().flat_map(move |(path, file)| {
+            file.custom_element_definitions(name)
+                .map(move |definition| (path.as_path(), definition))
+        })
+    }
+}
+
+/// The symbols defined by a single script.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct JsFileIndex {
+    custom_elements: Vec<CustomElementName>,
+    imports: Vec<String>,
+}
+
+impl JsFileIndex {
+    /// Collects the symbols defined in the given script.
+    pub fn from_root(root: &AnyJsRoot) -> Self {
+        let custom_elements = root
+            .syntax()
+            .descendants()
+            .filter_map(JsCallExpression::cast)
+            .filter_map(|call| CustomElementName::from_define_call(&call))
+            .collect();
+        let imports = root
+            .syntax()
+            .descendants()
+            .filter_map(AnyJsImportLike::cast)
+            .filter(|import| !import.is_in_ts_module_declaration())
+            .filter_map(|import| Some(import.inner_string_text()?.to_string()))
+            .collect();
+
+        Self {
+            custom_elements,
+            imports,
+        }
+    }
+
+    /// Returns `true` if the file doesn't define any symbol, nor imports another module.
+    pub fn is_empty(&self) -> bool {
+        self.custom_elements.is_empty() && self.imports.is_empty()
+    }
+
+    /// Returns the specifiers of the modules imported by the file, in the order they appear.
+    ///
+    /// The imports, the re-exports, the calls of `require` and the dynamic imports are
+    /// taken into account, as long as their specifier is a static string.
+    pub fn imports(&self) -> &[String] {
+        &self.imports
+    }
+
+    /// Returns all the custom elements defined in the file.
+    pub fn custom_elements(&self) -> &[CustomElementName] {
+        &self.custom_elements
+    }
+
+    /// Returns the definitions of the custom elements named `name` in the file.
+    pub fn custom_element_definitions<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a CustomElementName> + 'a {
+        self.custom_elements
+            .iter()
+            .filter(move |definition| definition.name == name)
+    }
+}
+
+/// The name of a custom element, as given to `customElements.define`.
+///
+/// ```js
+/// customElements.define("my-button", MyButton);
+/// //                    ^^^^^^^^^^^
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CustomElementName {
+    /// The name of the custom element.
+    pub name: String,
+    /// The range of the string of the name in its file.
+    pub range: TextRange,
+}
+
+impl CustomElementName {
+    /// Returns the name defined by `call` if it's a call of `customElements.define`,
+    /// or of `window.customElements.define`, whose name is a static string.
+    pub fn from_define_call(call: &JsCallExpression) -> Option<Self> {
+        let callee = call.callee().ok()?.omit_parentheses();
+        let callee = callee.as_js_static_member_expression()?;
+        if !has_member_name(callee, "define")
+            || !is_custom_element_registry(&callee.object().ok()?.omit_parentheses())
+        {
+            return None;
+        }
+
+        let [Some(name)] = call.arguments().ok()?.get_arguments_by_index([0]) else {
+            return None;
+        };
+        let name = name.as_any_js_expression()?.clone().omit_parentheses();
+        let value = name.as_static_value()?;
+        Some(Self {
+            name: value.as_string_constant()?.to_string(),
+            range: name.range(),
+        })
+    }
+}
+
+/// Resolves the relative imports of the scripts of a project to the indexed files.
+///
+/// Like bundlers, the resolver tries the usual extensions when the specifier doesn't have one,
+/// then the `index` file of the directory. A specifier ending with `.js` can also be resolved to
+/// a TypeScript file, as TypeScript requires when ES modules are imported.
+#[derive(Debug)]
+pub struct ModuleResolver<'a> {
+    /// The indexed files, keyed by their normalized path
+    files: &'a FxHashMap<PathBuf, PathBuf>,
+}
+
+impl<'a> ModuleResolver<'a> {
+    /// Returns the file imported by `specifier` from the file at `path`,
+    /// or [None] if the specifier isn't relative or doesn't match an indexed file.
+    pub fn resolve(&self, path: &Path, specifier: &str) -> Option<&'a Path> {
+        resolution_candidates(path, specifier)
+            .iter()
+            .find_map(|candidate| self.files.get(candidate))
+            .map(PathBuf::as_path)
+    }
+}
+
+/// Returns the normalized paths that the import `specifier` of the file at `path` tries, in order.
+/// The first one that matches a file is the file imported by the specifier.
+///
+/// There's no candidate when the specifier isn't relative.
+pub(crate) fn resolution_candidates(path: &Path, specifier: &str) -> Vec<PathBuf> {
+    if !is_relative_specifier(specifier) {
+        return Vec::new();
+    }
+    let base = normalize_path(&path.parent().unwrap_or(Path::new("")).join(specifier));
+    let mut candidates = vec![base.clone()];
+    if let Some(extension) = base.extension().and_then(|extension| extension.to_str()) {
+        let typescript_extensions: &[&str] = match extension {
+            "js" => &["ts", "tsx"],
+            "jsx" => &["tsx"],
+            "mjs" => &["mts"],
+            "cjs" => &["cts"],
+            _ => &[],
+        };
+        candidates.extend(
+            typescript_extensions
+                .iter()
+                .map(|extension| base.with_extension(extension)),
+        );
+    }
+    let file_name = base.file_name().and_then(|name| name.to_str());
+    candidates.extend(
+        RESOLVED_EXTENSIONS.iter().filter_map(|extension| {
+            Some(base.with_file_name(format!("{}.{extension}", file_name?)))
+        }),
+    );
+    candidates.extend(
+        RESOLVED_EXTENSIONS
+            .iter()
+            .map(|extension| base.join(format!("index.{extension}"))),
+    );
+    candidates
+}
+
+/// Returns `true` if `specifier` refers to a file of the project, instead of a package.
+pub fn is_relative_specifier(specifier: &str) -> bool {
+    specifier == "."
+        || specifier == ".."
+        || specifier.starts_with("./")
+        || specifier.starts_with("../")
+        || specifier.starts_with('/')
+}
+
+/// Removes the `.` and `..` components of `path`, without accessing the file system.
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) {
+                    normalized.pop();
+                } else {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Returns `true` if `expression` is the global `customElements`, optionally accessed
+/// from `window`, `self` or `globalThis`.
+fn is_custom_element_registry(expression: &AnyJsExpression) -> bool {
+    match expression {
+        AnyJsExpression::JsIdentifierExpression(identifier) => identifier
+            .name()
+            .is_ok_and(|name| name.has_name("customElements")),
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            let is_global = member.object().ok().is_some_and(|object| {
+                object
+                    .omit_parentheses()
+                    .as_js_identifier_expression()
+                    .and_then(|identifier| identifier.name().ok())
+                    .is_some_and(|name| {
+                        name.has_name("window") || name.has_name("self") || name.is_global_this()
+                    })
+            });
+            has_member_name(member, "customElements") && is_global
+        }
+        _ => false,
+    }
+}
+
+fn has_member_name(member: &JsStaticMemberExpression, name: &str) -> bool {
+    member
+        .member()
+        .ok()
+        .and_then(|member| member.as_js_name()?.value_token().ok())
+        .is_some_and(|token| token.text_trimmed() == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_js_parser::{parse, JsParserOptions};
+    use biome_js_syntax::JsFileSource;
+
+    fn index(source: &str) -> JsFileIndex {
+        let parse = parse(
+            source,
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+        JsFileIndex::from_root(&parse.tree())
+    }
+
+    #[test]
+    fn collects_custom_elements() {
+        let file = index(
+            r#"customElements.define("my-button", MyButton);
+window.customElements.define('my-dialog', class extends HTMLElement {});
+registry.define("my-menu", MyMenu);
+customElements.define(name, MyTabs);
+customElements.get("my-card");"#,
+        );
+
+        let names: Vec<_> = file
+            .custom_elements()
+            .iter()
+            .map(|definition| definition.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["my-button", "my-dialog"]);
+        assert_eq!(
+            file.custom_elements()[0].range,
+            TextRange::new(22.into(), 33.into())
+        );
+    }
+
+    #[test]
+    fn resolves_custom_elements_across_files() {
+        let mut project = JsProjectIndex::default();
+        project.insert_file(
+            PathBuf::from("a.js"),
+            index(r#"customElements.define("my-button", MyButton);"#),
+        );
+        project.insert_file(
+            PathBuf::from("b.js"),
+            index(r#"customElements.define("my-dialog", MyDialog);"#),
+        );
+
+        let definitions: Vec<_> = project.custom_element_definitions("my-button").collect();
+        assert_eq!(definitions.len(), 1);
+        assert_eq!(definitions[0].0, Path::new("a.js"));
+
+        project.remove_file(Path::new("a.js"));
+        assert_eq!(project.custom_element_definitions("my-button").count(), 0);
+        assert_eq!(project.custom_element_definitions("my-dialog").count(), 1);
+    }
+
+    #[test]
+    fn collects_imports() {
+        let file = index(
+            r#"import a from "./a";
+export * from "./b.js";
+const c = require("c");
+const d = await import("./d");
+const e = import(name);
+declare module "f" {}"#,
+        );
+
+        assert_eq!(file.imports(), ["./a", "./b.js", "c", "./d"]);
+    }
+
+    #[test]
+    fn resolves_imports_across_files() {
+        let mut project = JsProjectIndex::default();
+        for path in [
+            "./src/main.ts",
+            "./src/utils.ts",
+            "./src/components/index.tsx",
+            "./lib/legacy.js",
+        ] {
+            project.insert_file(PathBuf::from(path), JsFileIndex::default());
+        }
+
+        let resolver = project.module_resolver();
+        let main = Path::new("./src/main.ts");
+        assert_eq!(
+            resolver.resolve(main, "./utils"),
+            Some(Path::new("./src/utils.ts"))
+        );
+        assert_eq!(
+            resolver.resolve(main, "./utils.js"),
+            Some(Path::new("./src/utils.ts"))
+        );
+        assert_eq!(
+            resolver.resolve(main, "./components"),
+            Some(Path::new("./src/components/index.tsx"))
+        );
+        assert_eq!(
+            resolver.resolve(main, "../lib/legacy.js"),
+            Some(Path::new("./lib/legacy.js"))
+        );
+        assert_eq!(resolver.resolve(main, "./missing"), None);
+        assert_eq!(resolver.resolve(main, "react"), None);
+    }
+}
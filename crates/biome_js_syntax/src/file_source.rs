@@ -118,6 +118,7 @@ pub enum EmbeddingKind {
     Astro,
     Vue,
     Svelte,
+    Mdx,
     #[default]
     None,
 }
@@ -132,6 +133,9 @@ impl EmbeddingKind {
     pub const fn is_svelte(&self) -> bool {
         matches!(self, EmbeddingKind::Svelte)
     }
+    pub const fn is_mdx(&self) -> bool {
+        matches!(self, EmbeddingKind::Mdx)
+    }
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -143,7 +147,7 @@ pub struct JsFileSource {
     variant: LanguageVariant,
     module_kind: ModuleKind,
     version: LanguageVersion,
-    /// Used to mark if the source is being used for an Astro, Svelte or Vue file
+    /// Used to mark if the source is being used for an Astro, Svelte, Vue or MDX file
     embedding_kind: EmbeddingKind,
 }
 
@@ -209,6 +213,11 @@ impl JsFileSource {
         Self::js_module().with_embedding_kind(EmbeddingKind::Svelte)
     }
 
+    /// MDX file definition
+    pub fn mdx() -> Self {
+        Self::jsx().with_embedding_kind(EmbeddingKind::Mdx)
+    }
+
     pub const fn with_module_kind(mut self, kind: ModuleKind) -> Self {
         self.module_kind = kind;
         self
@@ -320,6 +329,7 @@ impl JsFileSource {
             b"vue" => Ok(Self::vue()),
             // TODO: Remove once we have full support of svelte files
             b"svelte" => Ok(Self::svelte()),
+            b"mdx" => Ok(Self::mdx()),
             _ => Err(FileSourceError::UnknownExtension),
         }
     }
@@ -352,6 +362,7 @@ impl JsFileSource {
             "vue" => Ok(Self::vue()),
             // TODO: Remove once we have full support of svelte files
             "svelte" => Ok(Self::svelte()),
+            "mdx" => Ok(Self::mdx()),
             _ => Err(FileSourceError::UnknownLanguageId),
         }
     }
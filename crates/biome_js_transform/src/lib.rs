@@ -75,7 +75,7 @@ where
         METADATA.deref(),
         InspectMatcher::new(registry, inspect_matcher),
         |_| -> Vec<Result<_, Infallible>> { unreachable!() },
-        Box::new(TestAction),
+        Some(Box::new(TestAction)),
         &mut emit_signal,
     );
 
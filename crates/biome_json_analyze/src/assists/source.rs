@@ -3,12 +3,14 @@
 use biome_analyze::declare_assists_group;
 
 pub mod use_sorted_keys;
+pub mod use_sorted_tsconfig;
 
 declare_assists_group! {
     pub Source {
         name : "source" ,
         rules : [
             self :: use_sorted_keys :: UseSortedKeys ,
+            self :: use_sorted_tsconfig :: UseSortedTsconfig ,
         ]
      }
 }
@@ -1,20 +1,25 @@
+use crate::sort::{SortOrder, SortableElements};
 use crate::JsonRuleAction;
 use biome_analyze::{
     context::RuleContext, declare_source_rule, ActionCategory, Ast, Rule, RuleAction,
     SourceActionKind,
 };
 use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
 use biome_diagnostics::Applicability;
-use biome_json_factory::make::{json_member_list, token};
-use biome_json_syntax::{JsonMember, JsonMemberList, T};
-use biome_rowan::{AstNode, AstNodeExt, AstSeparatedList, BatchMutationExt};
-use std::borrow::Cow;
-use std::cmp::Ordering;
-use std::collections::BTreeSet;
+use biome_json_factory::make::json_member_list;
+use biome_json_syntax::{JsonMember, JsonMemberList};
+use biome_rowan::{AstSeparatedList, BatchMutationExt};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 declare_source_rule! {
     /// Sorts the keys of a JSON object in natural order
     ///
+    /// Members separated by an empty line are sorted independently of each other.
+    /// The comments attached to a member move with it: the comments that precede the member,
+    /// and the comment that follows it on the same line.
+    ///
     /// ## Examples
     ///
     /// ```json,expect_diff
@@ -26,6 +31,37 @@ declare_source_rule! {
     ///     }
     /// }
     /// ```
+    ///
+    /// ```jsonc,expect_diff
+    /// {
+    ///     // Output
+    ///     "outDir": "dist",
+    ///     "declaration": true, // Emit the types
+    ///
+    ///     "strict": true,
+    ///     "noEmit": false
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "sortOrder": "lexicographic"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### sortOrder
+    ///
+    /// The order of the keys:
+    /// - `natural`: the numbers are compared by their value, so that `item2` precedes `item10`
+    /// - `lexicographic`: the keys are compared character by character, so that `item10`
+    ///   precedes `item2`
+    ///
+    /// Default: `natural`
+    ///
     pub UseSortedKeys {
         version: "1.9.0",
         name: "useSortedKeys",
@@ -33,69 +69,18 @@ declare_source_rule! {
     }
 }
 
-#[derive(Eq, PartialEq)]
-pub struct MemberKey {
-    node: JsonMember,
-}
-
-impl Ord for MemberKey {
-    fn cmp(&self, other: &Self) -> Ordering {
-        // Sort keys using natural ordering
-        natord::compare(
-            &self.node.name().unwrap().text(),
-            &other.node.name().unwrap().text(),
-        )
-    }
-}
-
-impl PartialOrd for MemberKey {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-pub struct Members(pub BTreeSet<MemberKey>);
-
-impl Members {
-    /// Returns true if the nodes in the group are already sorted in the file
-    fn is_sorted(&self) -> bool {
-        // The imports are sorted if the text position of each node in the `BTreeMap`
-        // (sorted in natural order) is higher than the previous item in
-        // the sequence
-        let mut iter = self
-            .0
-            .iter()
-            .map(|node| node.node.syntax().text_range().start());
-        let mut previous_start = iter.next().unwrap_or_default();
-        iter.all(|start| {
-            let is_sorted = previous_start < start;
-            previous_start = start;
-            is_sorted
-        })
-    }
-
-    fn to_sorted_node(&self) -> JsonMemberList {
-        let items = self.0.iter().map(|key| key.node.clone().detach());
-
-        let separator_count = items.len().saturating_sub(1);
-
-        let mut separators = Vec::new();
-
-        for (index, _) in self.0.iter().enumerate() {
-            if index != separator_count {
-                separators.push(token(T![,]))
-            }
-        }
-
-        json_member_list(items, separators)
-    }
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseSortedKeysOptions {
+    pub sort_order: SortOrder,
 }
 
 impl Rule for UseSortedKeys {
     type Query = Ast<JsonMemberList>;
-    type State = Members;
+    type State = SortableElements<JsonMember>;
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = UseSortedKeysOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let node = ctx.query();
@@ -104,17 +89,11 @@ impl Rule for UseSortedKeys {
             return None;
         }
 
-        let state = node
-            .iter()
-            .filter_map(|node| {
-                let node = node.ok()?;
-                Some(MemberKey { node })
-            })
-            .collect::<BTreeSet<_>>();
-
-        let state = Members(state);
+        let state = SortableElements::from_list(node, |member: &JsonMember| {
+            Some(member.name().ok()?.inner_string_text().ok()?.to_string())
+        })?;
 
-        if !state.is_sorted() {
+        if !state.is_sorted(ctx.options().sort_order) {
             Some(state)
         } else {
             None
@@ -122,7 +101,8 @@ impl Rule for UseSortedKeys {
     }
 
     fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsonRuleAction> {
-        let list = state.to_sorted_node();
+        let (items, separators) = state.to_sorted_elements(ctx.options().sort_order)?;
+        let list = json_member_list(items, separators);
         let mut mutation = ctx.root().begin();
         let node = ctx.query().clone();
         mutation.replace_node(node, list);
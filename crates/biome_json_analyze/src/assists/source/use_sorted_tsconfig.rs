@@ -0,0 +1,148 @@
+use crate::sort::{SortOrder, SortableElements};
+use crate::utils::{compiler_option_name, is_root_member, is_tsconfig_path};
+use crate::JsonRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_source_rule, ActionCategory, Ast, Rule, RuleAction,
+    SourceActionKind,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_json_factory::make::{json_array_element_list, json_member_list};
+use biome_json_syntax::{AnyJsonValue, JsonArrayElementList, JsonMember, JsonMemberList};
+use biome_rowan::{AstSeparatedList, BatchMutationExt};
+
+declare_source_rule! {
+    /// Sorts the compiler options, the `paths` and the `references` of TypeScript configurations
+    ///
+    /// The assist sorts in natural order:
+    /// - the keys of `compilerOptions`;
+    /// - the patterns of `compilerOptions.paths`;
+    /// - the elements of `references`, by their `path`.
+    ///
+    /// Like `useSortedKeys`, the elements separated by an empty line are sorted independently of each other,
+    /// and the comments attached to an element move with it.
+    /// The other keys of the configuration keep their order.
+    ///
+    /// The assist only applies to the TypeScript configurations: `tsconfig.json`, `tsconfig.*.json` and `jsconfig.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ```json,expect_diff,file=tsconfig.json
+    /// {
+    ///     "compilerOptions": {
+    ///         "strict": true,
+    ///         "outDir": "dist"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```json,expect_diff,file=tsconfig.json
+    /// {
+    ///     "references": [
+    ///         { "path": "./packages/utils" },
+    ///         { "path": "./packages/core" }
+    ///     ]
+    /// }
+    /// ```
+    pub UseSortedTsconfig {
+        version: "next",
+        name: "useSortedTsconfig",
+        language: "json",
+    }
+}
+
+pub enum SortableTsconfigList {
+    /// The members of `compilerOptions` or of `compilerOptions.paths`
+    Members(JsonMemberList, SortableElements<JsonMember>),
+    /// The elements of `references`
+    References(JsonArrayElementList, SortableElements<AnyJsonValue>),
+}
+
+impl Rule for UseSortedTsconfig {
+    type Query = Ast<JsonMember>;
+    type State = SortableTsconfigList;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        if !is_tsconfig_path(ctx.file_path()) {
+            return None;
+        }
+        let node = ctx.query();
+        let name = node.name().ok()?.inner_string_text().ok()?;
+        let is_sortable_object = if is_root_member(node) {
+            name.text() == "compilerOptions"
+        } else {
+            compiler_option_name(node).is_some_and(|option| option.text() == "paths")
+        };
+
+        let state = match node.value().ok()? {
+            AnyJsonValue::JsonObjectValue(object) if is_sortable_object => {
+                let list = object.json_member_list();
+                let elements = SortableElements::from_list(&list, |member: &JsonMember| {
+                    Some(member.name().ok()?.inner_string_text().ok()?.to_string())
+                })?;
+                SortableTsconfigList::Members(list, elements)
+            }
+            AnyJsonValue::JsonArrayValue(array)
+                if is_root_member(node) && name.text() == "references" =>
+            {
+                let list = array.elements();
+                let elements = SortableElements::from_list(&list, reference_path)?;
+                SortableTsconfigList::References(list, elements)
+            }
+            _ => return None,
+        };
+
+        let is_sorted = match &state {
+            SortableTsconfigList::Members(_, elements) => elements.is_sorted(SortOrder::Natural),
+            SortableTsconfigList::References(_, elements) => elements.is_sorted(SortOrder::Natural),
+        };
+        (!is_sorted).then_some(state)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsonRuleAction> {
+        let mut mutation = ctx.root().begin();
+        match state {
+            SortableTsconfigList::Members(list, elements) => {
+                let (items, separators) = elements.to_sorted_elements(SortOrder::Natural)?;
+                mutation.replace_node(list.clone(), json_member_list(items, separators));
+            }
+            SortableTsconfigList::References(list, elements) => {
+                let (items, separators) = elements.to_sorted_elements(SortOrder::Natural)?;
+                mutation.replace_node(list.clone(), json_array_element_list(items, separators));
+            }
+        }
+
+        Some(RuleAction::new(
+            rule_action_category!(),
+            Applicability::Always,
+            markup! {
+                "The TypeScript configuration can be sorted."
+            },
+            mutation,
+        ))
+    }
+}
+
+/// Returns the `path` of a reference, such as `{ "path": "./packages/core" }`
+fn reference_path(reference: &AnyJsonValue) -> Option<String> {
+    reference
+        .as_json_object_value()?
+        .json_member_list()
+        .iter()
+        .flatten()
+        .find(|member| {
+            member
+                .name()
+                .ok()
+                .and_then(|name| name.inner_string_text().ok())
+                .is_some_and(|name| name.text() == "path")
+        })?
+        .value()
+        .ok()?
+        .as_json_string_value()?
+        .inner_string_text()
+        .ok()
+        .map(|path| path.to_string())
+}
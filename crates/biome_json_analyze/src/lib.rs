@@ -3,19 +3,22 @@ mod lint;
 
 pub mod options;
 mod registry;
+mod sort;
 mod suppression_action;
 pub mod utils;
 
 pub use crate::registry::visit_registry;
 use crate::suppression_action::JsonSuppressionAction;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleAction, RuleRegistry, SuppressionDiagnostic, SuppressionKind,
+    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, AnalyzerSuppression, ControlFlow,
+    LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
+    SuppressionDiagnostic,
 };
 use biome_diagnostics::Error;
 use biome_json_syntax::{JsonFileSource, JsonLanguage};
+use biome_project::ResolvedTsConfig;
 use std::ops::Deref;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 
 pub(crate) type JsonRuleAction = RuleAction<JsonLanguage>;
 
@@ -27,19 +30,29 @@ pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
-/// then call `emit_signal` when an analysis rule emits a diagnostic or action
+/// then call `emit_signal` when an analysis rule emits a diagnostic or action.
+/// `tsconfig` is the resolution of the analyzed file, when it's a TypeScript configuration
 pub fn analyze<'a, F, B>(
     root: &LanguageRoot<JsonLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
     file_source: JsonFileSource,
+    tsconfig: Option<ResolvedTsConfig>,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<JsonLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, file_source, emit_signal)
+    analyze_with_inspect_matcher(
+        root,
+        filter,
+        |_| {},
+        options,
+        file_source,
+        tsconfig,
+        emit_signal,
+    )
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -54,6 +67,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
     file_source: JsonFileSource,
+    tsconfig: Option<ResolvedTsConfig>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -63,7 +77,7 @@ where
 {
     fn parse_linter_suppression_comment(
         _text: &str,
-    ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
+    ) -> Vec<Result<AnalyzerSuppression, SuppressionDiagnostic>> {
         vec![]
     }
     let mut registry = RuleRegistry::builder(&filter, root);
@@ -80,7 +94,7 @@ where
         METADATA.deref(),
         biome_analyze::InspectMatcher::new(registry, inspect_matcher),
         parse_linter_suppression_comment,
-        Box::new(JsonSuppressionAction),
+        Some(Box::new(JsonSuppressionAction)),
         &mut emit_signal,
     );
 
@@ -89,6 +103,7 @@ where
     }
 
     services.insert_service(file_source);
+    services.insert_service(Arc::new(tsconfig));
 
     (
         analyzer.run(biome_analyze::AnalyzerContext {
@@ -146,6 +161,7 @@ mod tests {
             },
             &options,
             JsonFileSource::json(),
+            None,
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
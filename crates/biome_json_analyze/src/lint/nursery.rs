@@ -0,0 +1,22 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_lint_group;
+
+pub mod no_deprecated_compiler_option;
+pub mod no_duplicate_keys_across_merge;
+pub mod no_unknown_compiler_option;
+pub mod no_unresolved_tsconfig_paths;
+pub mod use_stable_array_order;
+
+declare_lint_group! {
+    pub Nursery {
+        name : "nursery" ,
+        rules : [
+            self :: no_deprecated_compiler_option :: NoDeprecatedCompilerOption ,
+            self :: no_duplicate_keys_across_merge :: NoDuplicateKeysAcrossMerge ,
+            self :: no_unknown_compiler_option :: NoUnknownCompilerOption ,
+            self :: no_unresolved_tsconfig_paths :: NoUnresolvedTsconfigPaths ,
+            self :: use_stable_array_order :: UseStableArrayOrder ,
+        ]
+     }
+}
@@ -0,0 +1,140 @@
+use crate::utils::{compiler_option_name, is_tsconfig_path};
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::JsonMember;
+use biome_rowan::AstNode;
+
+declare_lint_rule! {
+    /// Disallow the compiler options that are deprecated in TypeScript configurations.
+    ///
+    /// TypeScript 5.0 deprecated several compiler options, and TypeScript 5.5 removed them:
+    /// a configuration that still uses them doesn't compile anymore.
+    /// Some of these options have a replacement, for example `outFile` replaces `out`.
+    ///
+    /// The rule only applies to the TypeScript configurations: `tsconfig.json`, `tsconfig.*.json` and `jsconfig.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,expect_diagnostic,file=tsconfig.json
+    /// {
+    ///     "compilerOptions": {
+    ///         "importsNotUsedAsValues": "error"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```json,expect_diagnostic,file=tsconfig.json
+    /// {
+    ///     "compilerOptions": {
+    ///         "keyofStringsOnly": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,file=tsconfig.json
+    /// {
+    ///     "compilerOptions": {
+    ///         "verbatimModuleSyntax": true
+    ///     }
+    /// }
+    /// ```
+    pub NoDeprecatedCompilerOption {
+        version: "next",
+        name: "noDeprecatedCompilerOption",
+        language: "json",
+        recommended: false,
+    }
+}
+
+/// A compiler option deprecated by TypeScript 5.0
+pub struct DeprecatedCompilerOption {
+    name: &'static str,
+    /// The option to use instead, if any
+    replacement: Option<&'static str>,
+}
+
+/// The options deprecated by TypeScript 5.0, sorted by name.
+/// See https://github.com/microsoft/TypeScript/issues/51909
+const DEPRECATED_COMPILER_OPTIONS: &[DeprecatedCompilerOption] = &[
+    DeprecatedCompilerOption {
+        name: "charset",
+        replacement: None,
+    },
+    DeprecatedCompilerOption {
+        name: "importsNotUsedAsValues",
+        replacement: Some("verbatimModuleSyntax"),
+    },
+    DeprecatedCompilerOption {
+        name: "keyofStringsOnly",
+        replacement: None,
+    },
+    DeprecatedCompilerOption {
+        name: "noImplicitUseStrict",
+        replacement: None,
+    },
+    DeprecatedCompilerOption {
+        name: "noStrictGenericChecks",
+        replacement: None,
+    },
+    DeprecatedCompilerOption {
+        name: "out",
+        replacement: Some("outFile"),
+    },
+    DeprecatedCompilerOption {
+        name: "preserveValueImports",
+        replacement: Some("verbatimModuleSyntax"),
+    },
+    DeprecatedCompilerOption {
+        name: "suppressExcessPropertyErrors",
+        replacement: None,
+    },
+    DeprecatedCompilerOption {
+        name: "suppressImplicitAnyIndexErrors",
+        replacement: None,
+    },
+];
+
+impl Rule for NoDeprecatedCompilerOption {
+    type Query = Ast<JsonMember>;
+    type State = &'static DeprecatedCompilerOption;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_tsconfig_path(ctx.file_path()) {
+            return None;
+        }
+        let name = compiler_option_name(ctx.query())?;
+        DEPRECATED_COMPILER_OPTIONS
+            .binary_search_by(|option| option.name.cmp(name.text()))
+            .ok()
+            .map(|index| &DEPRECATED_COMPILER_OPTIONS[index])
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, option: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query().name().ok()?;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            node.range(),
+            markup! {
+                "The compiler option "<Emphasis>{option.name}</Emphasis>" is deprecated."
+            },
+        )
+        .note(markup! {
+            "TypeScript 5.0 deprecated this option, and TypeScript 5.5 removed it."
+        });
+        Some(if let Some(replacement) = option.replacement {
+            diagnostic.note(markup! {
+                "Use "<Emphasis>{replacement}</Emphasis>" instead."
+            })
+        } else {
+            diagnostic.note(markup! {
+                "Remove this option."
+            })
+        })
+    }
+}
@@ -0,0 +1,338 @@
+use crate::utils::is_root_member;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{
+    AnyJsonValue, JsonMember, JsonMemberList, JsonObjectValue, JsonSyntaxToken,
+};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange, TokenText};
+use rustc_hash::FxHashSet;
+
+declare_lint_rule! {
+    /// Disallow the keys of an override that don't change the base configuration, and the overrides that never apply.
+    ///
+    /// A configuration such as `biome.json` can contain an `overrides` array at its root.
+    /// Each override applies to the files matched by its `include` patterns, except the files matched by its `ignore` patterns,
+    /// and its other keys are merged into the base configuration.
+    ///
+    /// The rule reports:
+    /// - the keys of an override that set the same value as the base configuration:
+    ///   they don't change anything once merged;
+    /// - the overrides that never apply, because their `include` list is empty,
+    ///   or because every pattern of their `include` list is also in their `ignore` list.
+    ///
+    /// Objects are merged key by key, so the rule compares the nested keys of an object
+    /// with the nested keys of the same object in the base configuration.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,expect_diagnostic
+    /// {
+    ///     "formatter": {
+    ///         "indentStyle": "space"
+    ///     },
+    ///     "overrides": [
+    ///         {
+    ///             "include": ["scripts/**"],
+    ///             "formatter": {
+    ///                 "indentStyle": "space"
+    ///             }
+    ///         }
+    ///     ]
+    /// }
+    /// ```
+    ///
+    /// ```json,expect_diagnostic
+    /// {
+    ///     "overrides": [
+    ///         {
+    ///             "include": ["generated/**"],
+    ///             "ignore": ["generated/**"],
+    ///             "linter": {
+    ///                 "enabled": false
+    ///             }
+    ///         }
+    ///     ]
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json
+    /// {
+    ///     "formatter": {
+    ///         "indentStyle": "space"
+    ///     },
+    ///     "overrides": [
+    ///         {
+    ///             "include": ["scripts/**"],
+    ///             "formatter": {
+    ///                 "indentStyle": "tab"
+    ///             }
+    ///         }
+    ///     ]
+    /// }
+    /// ```
+    pub NoDuplicateKeysAcrossMerge {
+        version: "next",
+        name: "noDuplicateKeysAcrossMerge",
+        language: "json",
+        recommended: false,
+    }
+}
+
+pub enum RedundantOverride {
+    /// A key of an override that sets the same value as the base configuration
+    SameAsBase {
+        member: JsonMember,
+        /// The range of the name of the same key in the base configuration
+        base_range: TextRange,
+    },
+    /// An override that doesn't apply to any file
+    NeverMatches {
+        /// The `include` member of the override
+        include: JsonMember,
+        /// Whether the `include` list is empty, or all its patterns are ignored
+        is_empty: bool,
+    },
+}
+
+impl Rule for NoDuplicateKeysAcrossMerge {
+    type Query = Ast<JsonMember>;
+    type State = RedundantOverride;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if !is_root_member(node) || !has_name(node, "overrides") {
+            return Box::default();
+        }
+        let Some(base) = node.parent::<JsonMemberList>() else {
+            return Box::default();
+        };
+        let Ok(AnyJsonValue::JsonArrayValue(overrides)) = node.value() else {
+            return Box::default();
+        };
+
+        let mut signals = Vec::new();
+        for element in overrides.elements().iter().flatten() {
+            let AnyJsonValue::JsonObjectValue(override_object) = element else {
+                continue;
+            };
+            let members = override_object.json_member_list();
+
+            if let Some(include) = find_member(&members, "include") {
+                if let Some(is_empty) = never_matches(&include, find_member(&members, "ignore")) {
+                    signals.push(RedundantOverride::NeverMatches { include, is_empty });
+                }
+            }
+
+            for member in members.iter().flatten() {
+                if has_name(&member, "include") || has_name(&member, "ignore") {
+                    continue;
+                }
+                let Some(base_member) =
+                    member_name(&member).and_then(|name| find_member(&base, name.text()))
+                else {
+                    continue;
+                };
+                collect_same_as_base(&member, &base_member, &mut signals);
+            }
+        }
+
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(match state {
+            RedundantOverride::SameAsBase { member, base_range } => {
+                let name = member.name().ok()?;
+                let text = name.inner_string_text().ok()?;
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    name.range(),
+                    markup! {
+                        "The override sets "<Emphasis>{text.text()}</Emphasis>" to the same value as the base configuration."
+                    },
+                )
+                .detail(
+                    *base_range,
+                    markup! {
+                        "The base configuration sets the same value here."
+                    },
+                )
+                .note(markup! {
+                    "Once merged, this key doesn't change the configuration. Remove it."
+                })
+            }
+            RedundantOverride::NeverMatches { include, is_empty } => {
+                let diagnostic = RuleDiagnostic::new(
+                    rule_category!(),
+                    include.name().ok()?.range(),
+                    markup! {
+                        "This override never applies."
+                    },
+                );
+                let diagnostic = if *is_empty {
+                    diagnostic.note(markup! {
+                        "The "<Emphasis>"include"</Emphasis>" list is empty, so the override doesn't match any file."
+                    })
+                } else {
+                    diagnostic.note(markup! {
+                        "Every pattern of the "<Emphasis>"include"</Emphasis>" list is also in the "<Emphasis>"ignore"</Emphasis>" list, so the override doesn't match any file."
+                    })
+                };
+                diagnostic.note(markup! {
+                    "Fix the patterns of the override, or remove it."
+                })
+            }
+        })
+    }
+}
+
+/// Reports `member` if its value is the same as the value of `base`,
+/// otherwise compares the nested members when both values are objects
+fn collect_same_as_base(
+    member: &JsonMember,
+    base: &JsonMember,
+    signals: &mut Vec<RedundantOverride>,
+) {
+    let (Ok(value), Ok(base_value)) = (member.value(), base.value()) else {
+        return;
+    };
+    if is_same_value(&value, &base_value) {
+        if let Ok(base_name) = base.name() {
+            signals.push(RedundantOverride::SameAsBase {
+                member: member.clone(),
+                base_range: base_name.range(),
+            });
+        }
+        return;
+    }
+
+    let (AnyJsonValue::JsonObjectValue(object), AnyJsonValue::JsonObjectValue(base_object)) =
+        (value, base_value)
+    else {
+        return;
+    };
+    let base_members = base_object.json_member_list();
+    for nested in object.json_member_list().iter().flatten() {
+        let Some(base_nested) =
+            member_name(&nested).and_then(|name| find_member(&base_members, name.text()))
+        else {
+            continue;
+        };
+        collect_same_as_base(&nested, &base_nested, signals);
+    }
+}
+
+/// Returns `Some(true)` if the `include` list is empty, `Some(false)` if all its patterns
+/// are in the `ignore` list, and `None` if the override can match a file
+fn never_matches(include: &JsonMember, ignore: Option<JsonMember>) -> Option<bool> {
+    let include = patterns(include)?;
+    if include.is_empty() {
+        return Some(true);
+    }
+    let ignore = patterns(&ignore?)?;
+    include
+        .iter()
+        .all(|pattern| ignore.contains(pattern))
+        .then_some(false)
+}
+
+/// Returns the patterns of a list of globs, or `None` if the list contains anything but strings
+fn patterns(member: &JsonMember) -> Option<FxHashSet<TokenText>> {
+    let AnyJsonValue::JsonArrayValue(array) = member.value().ok()? else {
+        return None;
+    };
+    array
+        .elements()
+        .iter()
+        .map(|element| {
+            element
+                .ok()?
+                .as_json_string_value()?
+                .inner_string_text()
+                .ok()
+        })
+        .collect()
+}
+
+/// Returns `true` if both values are equal once parsed
+fn is_same_value(left: &AnyJsonValue, right: &AnyJsonValue) -> bool {
+    match (left, right) {
+        (AnyJsonValue::JsonNullValue(_), AnyJsonValue::JsonNullValue(_)) => true,
+        (AnyJsonValue::JsonBooleanValue(left), AnyJsonValue::JsonBooleanValue(right)) => {
+            match (left.value_token(), right.value_token()) {
+                (Ok(left), Ok(right)) => left.kind() == right.kind(),
+                _ => false,
+            }
+        }
+        (AnyJsonValue::JsonNumberValue(left), AnyJsonValue::JsonNumberValue(right)) => {
+            let parse = |token: JsonSyntaxToken| token.text_trimmed().parse::<f64>().ok();
+            match (
+                left.value_token().ok().and_then(parse),
+                right.value_token().ok().and_then(parse),
+            ) {
+                (Some(left), Some(right)) => left == right,
+                _ => false,
+            }
+        }
+        (AnyJsonValue::JsonStringValue(left), AnyJsonValue::JsonStringValue(right)) => {
+            match (left.inner_string_text(), right.inner_string_text()) {
+                (Ok(left), Ok(right)) => left == right,
+                _ => false,
+            }
+        }
+        (AnyJsonValue::JsonArrayValue(left), AnyJsonValue::JsonArrayValue(right)) => {
+            let (left, right) = (left.elements(), right.elements());
+            left.len() == right.len()
+                && left.iter().zip(right.iter()).all(|pair| match pair {
+                    (Ok(left), Ok(right)) => is_same_value(&left, &right),
+                    _ => false,
+                })
+        }
+        (AnyJsonValue::JsonObjectValue(left), AnyJsonValue::JsonObjectValue(right)) => {
+            is_same_object(left, right)
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if both objects have the same keys with the same values, in any order
+fn is_same_object(left: &JsonObjectValue, right: &JsonObjectValue) -> bool {
+    let (left, right) = (left.json_member_list(), right.json_member_list());
+    left.len() == right.len()
+        && left.iter().all(|member| {
+            let Ok(member) = member else {
+                return false;
+            };
+            let Some(other) =
+                member_name(&member).and_then(|name| find_member(&right, name.text()))
+            else {
+                return false;
+            };
+            match (member.value(), other.value()) {
+                (Ok(value), Ok(other_value)) => is_same_value(&value, &other_value),
+                _ => false,
+            }
+        })
+}
+
+fn member_name(member: &JsonMember) -> Option<TokenText> {
+    member.name().ok()?.inner_string_text().ok()
+}
+
+fn has_name(member: &JsonMember, name: &str) -> bool {
+    member_name(member).is_some_and(|text| text.text() == name)
+}
+
+/// Returns the last member named `name`, which is the one that takes effect
+fn find_member(list: &JsonMemberList, name: &str) -> Option<JsonMember> {
+    list.iter()
+        .flatten()
+        .filter(|member| has_name(member, name))
+        .last()
+}
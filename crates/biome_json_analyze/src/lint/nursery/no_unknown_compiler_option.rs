@@ -0,0 +1,232 @@
+use crate::utils::{compiler_option_name, is_tsconfig_path};
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::JsonMember;
+use biome_rowan::{AstNode, TokenText};
+
+declare_lint_rule! {
+    /// Disallow the compiler options that are unknown to TypeScript.
+    ///
+    /// TypeScript refuses to compile a project whose configuration contains an unknown compiler option.
+    /// Such an option is usually a typo, or an option that belongs to another tool.
+    /// Options are case-sensitive: `"Strict"` isn't the same option as `"strict"`.
+    ///
+    /// The rule only applies to the TypeScript configurations: `tsconfig.json`, `tsconfig.*.json` and `jsconfig.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,expect_diagnostic,file=tsconfig.json
+    /// {
+    ///     "compilerOptions": {
+    ///         "stritc": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```json,expect_diagnostic,file=tsconfig.json
+    /// {
+    ///     "compilerOptions": {
+    ///         "OutDir": "dist"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,file=tsconfig.json
+    /// {
+    ///     "compilerOptions": {
+    ///         "strict": true,
+    ///         "outDir": "dist"
+    ///     }
+    /// }
+    /// ```
+    pub NoUnknownCompilerOption {
+        version: "next",
+        name: "noUnknownCompilerOption",
+        language: "json",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnknownCompilerOption {
+    type Query = Ast<JsonMember>;
+    type State = (TokenText, Option<&'static str>);
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_tsconfig_path(ctx.file_path()) {
+            return None;
+        }
+        let name = compiler_option_name(ctx.query())?;
+        if KNOWN_COMPILER_OPTIONS.binary_search(&name.text()).is_ok() {
+            return None;
+        }
+
+        // The option may be known with a different letter case
+        let suggestion = KNOWN_COMPILER_OPTIONS
+            .iter()
+            .find(|option| option.eq_ignore_ascii_case(name.text()))
+            .copied();
+        Some((name, suggestion))
+    }
+
+    fn diagnostic(
+        ctx: &RuleContext<Self>,
+        (name, suggestion): &Self::State,
+    ) -> Option<RuleDiagnostic> {
+        let node = ctx.query().name().ok()?;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            node.range(),
+            markup! {
+                "The compiler option "<Emphasis>{name.text()}</Emphasis>" is unknown."
+            },
+        );
+        Some(if let Some(suggestion) = suggestion {
+            diagnostic.note(markup! {
+                "Compiler options are case-sensitive. Did you mean "<Emphasis>{suggestion}</Emphasis>"?"
+            })
+        } else {
+            diagnostic.note(markup! {
+                "TypeScript doesn't compile a project whose configuration contains an unknown compiler option. Fix the name of the option, or remove it."
+            })
+        })
+    }
+}
+
+/// The compiler options of TypeScript, sorted in ASCII order.
+///
+/// The list includes the deprecated options, which are reported by `noDeprecatedCompilerOption`.
+const KNOWN_COMPILER_OPTIONS: &[&str] = &[
+    "allowArbitraryExtensions",
+    "allowImportingTsExtensions",
+    "allowJs",
+    "allowSyntheticDefaultImports",
+    "allowUmdGlobalAccess",
+    "allowUnreachableCode",
+    "allowUnusedLabels",
+    "alwaysStrict",
+    "assumeChangesOnlyAffectDirectDependencies",
+    "baseUrl",
+    "charset",
+    "checkJs",
+    "composite",
+    "customConditions",
+    "declaration",
+    "declarationDir",
+    "declarationMap",
+    "diagnostics",
+    "disableReferencedProjectLoad",
+    "disableSizeLimit",
+    "disableSolutionSearching",
+    "disableSourceOfProjectReferenceRedirect",
+    "downlevelIteration",
+    "emitBOM",
+    "emitDeclarationOnly",
+    "emitDecoratorMetadata",
+    "esModuleInterop",
+    "exactOptionalPropertyTypes",
+    "experimentalDecorators",
+    "explainFiles",
+    "extendedDiagnostics",
+    "forceConsistentCasingInFileNames",
+    "generateCpuProfile",
+    "generateTrace",
+    "importHelpers",
+    "importsNotUsedAsValues",
+    "incremental",
+    "inlineSourceMap",
+    "inlineSources",
+    "isolatedDeclarations",
+    "isolatedModules",
+    "jsx",
+    "jsxFactory",
+    "jsxFragmentFactory",
+    "jsxImportSource",
+    "keyofStringsOnly",
+    "lib",
+    "listEmittedFiles",
+    "listFiles",
+    "locale",
+    "mapRoot",
+    "maxNodeModuleJsDepth",
+    "module",
+    "moduleDetection",
+    "moduleResolution",
+    "moduleSuffixes",
+    "newLine",
+    "noCheck",
+    "noEmit",
+    "noEmitHelpers",
+    "noEmitOnError",
+    "noErrorTruncation",
+    "noFallthroughCasesInSwitch",
+    "noImplicitAny",
+    "noImplicitOverride",
+    "noImplicitReturns",
+    "noImplicitThis",
+    "noImplicitUseStrict",
+    "noLib",
+    "noPropertyAccessFromIndexSignature",
+    "noResolve",
+    "noStrictGenericChecks",
+    "noUncheckedIndexedAccess",
+    "noUncheckedSideEffectImports",
+    "noUnusedLocals",
+    "noUnusedParameters",
+    "out",
+    "outDir",
+    "outFile",
+    "paths",
+    "plugins",
+    "preserveConstEnums",
+    "preserveSymlinks",
+    "preserveValueImports",
+    "preserveWatchOutput",
+    "pretty",
+    "reactNamespace",
+    "removeComments",
+    "resolveJsonModule",
+    "resolvePackageJsonExports",
+    "resolvePackageJsonImports",
+    "rewriteRelativeImportExtensions",
+    "rootDir",
+    "rootDirs",
+    "skipDefaultLibCheck",
+    "skipLibCheck",
+    "sourceMap",
+    "sourceRoot",
+    "strict",
+    "strictBindCallApply",
+    "strictBuiltinIteratorReturn",
+    "strictFunctionTypes",
+    "strictNullChecks",
+    "strictPropertyInitialization",
+    "stripInternal",
+    "suppressExcessPropertyErrors",
+    "suppressImplicitAnyIndexErrors",
+    "target",
+    "traceResolution",
+    "tsBuildInfoFile",
+    "typeRoots",
+    "types",
+    "useDefineForClassFields",
+    "useUnknownInCatchVariables",
+    "verbatimModuleSyntax",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::KNOWN_COMPILER_OPTIONS;
+
+    #[test]
+    fn known_compiler_options_are_sorted() {
+        assert!(KNOWN_COMPILER_OPTIONS
+            .windows(2)
+            .all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::utils::{
+    compiler_option_name, is_root_member, is_tsconfig_path, owner_member, parent_member,
+};
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::JsonStringValue;
+use biome_project::ResolvedTsConfig;
+use biome_rowan::{AstNode, TokenText};
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Disallow the paths of TypeScript configurations that don't resolve to any file.
+    ///
+    /// The rule reports:
+    /// - the configurations referenced by `extends` that can't be found, either relative to the configuration,
+    ///   or in the `node_modules` of an installed package;
+    /// - the targets of `compilerOptions.paths` that don't match any file or directory.
+    ///   The targets are relative to `baseUrl` when it's set, including when it's inherited from an extended configuration.
+    ///   A target with a wildcard, such as `src/*`, only needs the directory that precedes the wildcard to exist.
+    ///
+    /// The rule only applies to the TypeScript configurations: `tsconfig.json`, `tsconfig.*.json` and `jsconfig.json`.
+    ///
+    /// ## Examples
+    ///
+    /// The following examples assume that the project doesn't contain a `tsconfig.base.json` file,
+    /// nor a `vendor` directory.
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "extends": "./tsconfig.base.json"
+    /// }
+    /// ```
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "compilerOptions": {
+    ///         "paths": {
+    ///             "@vendor/*": ["vendor/*"]
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub NoUnresolvedTsconfigPaths {
+        version: "next",
+        name: "noUnresolvedTsconfigPaths",
+        language: "json",
+        recommended: false,
+    }
+}
+
+pub enum UnresolvedPath {
+    /// A configuration referenced by `extends`
+    Extends(TokenText),
+    /// A target of `compilerOptions.paths`
+    PathsTarget(TokenText),
+}
+
+impl Rule for NoUnresolvedTsconfigPaths {
+    type Query = Ast<JsonStringValue>;
+    type State = UnresolvedPath;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_tsconfig_path(ctx.file_path()) {
+            return None;
+        }
+        let tsconfig = ctx
+            .get_service::<Arc<Option<ResolvedTsConfig>>>()?
+            .as_ref()
+            .as_ref()?;
+
+        let node = ctx.query();
+        let text = node.inner_string_text().ok()?;
+        let member = owner_member(node)?;
+        let member_name = member.name().ok()?.inner_string_text().ok()?;
+
+        if member_name.text() == "extends" && is_root_member(&member) {
+            return tsconfig
+                .is_unresolved_extends(text.text())
+                .then_some(UnresolvedPath::Extends(text));
+        }
+
+        // The targets are the elements of the arrays of `"paths": { "<pattern>": [...] }`
+        if member.value().ok()?.as_json_array_value().is_none() {
+            return None;
+        }
+        let paths = parent_member(&member)?;
+        if compiler_option_name(&paths)?.text() == "paths"
+            && tsconfig.is_unresolved_path(text.text())
+        {
+            return Some(UnresolvedPath::PathsTarget(text));
+        }
+
+        None
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        Some(match state {
+            UnresolvedPath::Extends(specifier) => RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "The configuration "<Emphasis>{specifier.text()}</Emphasis>" can't be found."
+                },
+            )
+            .note(markup! {
+                "TypeScript doesn't compile a project whose configuration extends a configuration that doesn't exist."
+            })
+            .note(markup! {
+                "Fix the path of the configuration, or install the package that provides it."
+            }),
+            UnresolvedPath::PathsTarget(target) => RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "The path "<Emphasis>{target.text()}</Emphasis>" doesn't match any file or directory."
+                },
+            )
+            .note(markup! {
+                "The imports that use this mapping can't be resolved to this path."
+            }),
+        })
+    }
+}
@@ -0,0 +1,199 @@
+use crate::sort::{SortOrder, SortableElements};
+use crate::utils::{is_root_member, parent_member};
+use crate::JsonRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, FixKind, Rule, RuleAction, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_json_factory::make::json_array_element_list;
+use biome_json_syntax::{AnyJsonValue, JsonArrayElementList, JsonMember};
+use biome_rowan::{AstNode, BatchMutationExt};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Enforce the order of the elements of the arrays at the configured paths.
+    ///
+    /// Some arrays are easier to review when they're sorted, such as the `keywords` of a `package.json`,
+    /// or the `lib` of the compiler options of a `tsconfig.json`.
+    /// This rule reports the arrays at the paths configured with its options whose elements aren't sorted
+    /// in natural order, and suggests an unsafe fix that sorts them.
+    ///
+    /// The rule doesn't report anything until the paths are configured.
+    /// A path lists the names of the members that lead to the array from the root of the document,
+    /// separated by dots.
+    /// The rule only checks the arrays of strings.
+    ///
+    /// Like the `useSortedKeys` assist, the elements separated by an empty line are sorted independently of each other,
+    /// and the comments attached to an element move with it.
+    /// Use [overrides](https://biomejs.dev/reference/configuration/#overrides) to apply the rule to specific files.
+    ///
+    /// ## Examples
+    ///
+    /// The following examples use these options:
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "paths": ["keywords", "compilerOptions.lib"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,expect_diagnostic,use_options
+    /// {
+    ///     "keywords": ["linter", "formatter", "biome"]
+    /// }
+    /// ```
+    ///
+    /// ```json,expect_diagnostic,use_options
+    /// {
+    ///     "compilerOptions": {
+    ///         "lib": ["esnext", "dom"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,use_options
+    /// {
+    ///     "keywords": ["biome", "formatter", "linter"],
+    ///     "files": ["src", "dist"]
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `paths`
+    ///
+    /// The paths of the arrays to sort, such as `keywords` or `compilerOptions.lib`.
+    ///
+    pub UseStableArrayOrder {
+        version: "next",
+        name: "useStableArrayOrder",
+        language: "json",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserializable, Deserialize, Serialize, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseStableArrayOrderOptions {
+    /// The paths of the arrays to sort, such as `keywords` or `compilerOptions.lib`
+    pub paths: Box<[Box<str>]>,
+}
+
+pub struct UnsortedArray {
+    /// The path of the array, as written in the options
+    path: String,
+    list: JsonArrayElementList,
+    elements: SortableElements<AnyJsonValue>,
+}
+
+impl Rule for UseStableArrayOrder {
+    type Query = Ast<JsonMember>;
+    type State = UnsortedArray;
+    type Signals = Option<Self::State>;
+    type Options = Box<UseStableArrayOrderOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        if options.paths.is_empty() {
+            return None;
+        }
+
+        let node = ctx.query();
+        let AnyJsonValue::JsonArrayValue(array) = node.value().ok()? else {
+            return None;
+        };
+        let path = member_path(node)?;
+        if !options
+            .paths
+            .iter()
+            .any(|configured| configured.as_ref() == path)
+        {
+            return None;
+        }
+
+        let list = array.elements();
+        let elements = SortableElements::from_list(&list, |element: &AnyJsonValue| {
+            Some(
+                element
+                    .as_json_string_value()?
+                    .inner_string_text()
+                    .ok()?
+                    .to_string(),
+            )
+        })?;
+        if elements.is_sorted(SortOrder::Natural) {
+            return None;
+        }
+
+        Some(UnsortedArray {
+            path,
+            list,
+            elements,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = ctx.query().name().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                name.range(),
+                markup! {
+                    "The elements of "<Emphasis>{state.path}</Emphasis>" aren't sorted."
+                },
+            )
+            .note(markup! {
+                "The configuration of the rule requires the elements of this array to be sorted in natural order."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsonRuleAction> {
+        let (items, separators) = state.elements.to_sorted_elements(SortOrder::Natural)?;
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            state.list.clone(),
+            json_array_element_list(items, separators),
+        );
+
+        Some(RuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Sort the elements of the array." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the names of the members that lead to `member` from the root of the document,
+/// separated by dots.
+///
+/// Returns `None` if `member` belongs to an object nested in an array.
+fn member_path(member: &JsonMember) -> Option<String> {
+    let mut names = vec![member.name().ok()?.inner_string_text().ok()?];
+    let mut current = member.clone();
+    while !is_root_member(&current) {
+        current = parent_member(&current)?;
+        names.push(current.name().ok()?.inner_string_text().ok()?);
+    }
+
+    let mut path = String::new();
+    for (index, name) in names.iter().rev().enumerate() {
+        if index > 0 {
+            path.push('.');
+        }
+        path.push_str(name.text());
+    }
+    Some(path)
+}
@@ -3,6 +3,14 @@
 use crate::assists;
 use crate::lint;
 
+pub type NoDeprecatedCompilerOption = < lint :: nursery :: no_deprecated_compiler_option :: NoDeprecatedCompilerOption as biome_analyze :: Rule > :: Options ;
+pub type NoDuplicateKeysAcrossMerge = < lint :: nursery :: no_duplicate_keys_across_merge :: NoDuplicateKeysAcrossMerge as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateObjectKeys = < lint :: suspicious :: no_duplicate_object_keys :: NoDuplicateObjectKeys as biome_analyze :: Rule > :: Options ;
+pub type NoUnknownCompilerOption = < lint :: nursery :: no_unknown_compiler_option :: NoUnknownCompilerOption as biome_analyze :: Rule > :: Options ;
+pub type NoUnresolvedTsconfigPaths = < lint :: nursery :: no_unresolved_tsconfig_paths :: NoUnresolvedTsconfigPaths as biome_analyze :: Rule > :: Options ;
 pub type UseSortedKeys =
     <assists::source::use_sorted_keys::UseSortedKeys as biome_analyze::Rule>::Options;
+pub type UseSortedTsconfig =
+    <assists::source::use_sorted_tsconfig::UseSortedTsconfig as biome_analyze::Rule>::Options;
+pub type UseStableArrayOrder =
+    <lint::nursery::use_stable_array_order::UseStableArrayOrder as biome_analyze::Rule>::Options;
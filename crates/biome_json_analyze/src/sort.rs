@@ -0,0 +1,195 @@
+use biome_deserialize_macros::Deserializable;
+use biome_json_factory::make::token;
+use biome_json_syntax::{JsonLanguage, JsonSyntaxToken, JsonSyntaxTrivia, T};
+use biome_rowan::{AstNode, AstNodeExt, AstSeparatedList, SyntaxTriviaPiece};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// The order of the keys
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SortOrder {
+    /// The numbers are compared by their value, so that `item2` precedes `item10`
+    #[default]
+    Natural,
+    /// The keys are compared character by character, so that `item10` precedes `item2`
+    Lexicographic,
+}
+
+impl SortOrder {
+    fn compare(self, left: &str, right: &str) -> Ordering {
+        match self {
+            Self::Natural => natord::compare(left, right),
+            Self::Lexicographic => left.cmp(right),
+        }
+    }
+}
+
+/// An element of a separated list, along with the trivia that follows it on the same line
+pub struct SortableElement<N> {
+    key: String,
+    node: N,
+    /// The trailing trivia of the element and of its separator,
+    /// for example the comment in `"a": 1, // comment`
+    trailing_trivia: Vec<SyntaxTriviaPiece<JsonLanguage>>,
+}
+
+/// The elements of a separated list, grouped by the empty lines that separate them
+pub struct SortableElements<N>(Vec<Vec<SortableElement<N>>>);
+
+impl<N: AstNode<Language = JsonLanguage>> SortableElements<N> {
+    /// Collects the elements of `list`, using `key` to compute the key of each element.
+    ///
+    /// Returns `None` if the list contains a bogus element, or an element without a key.
+    pub fn from_list<L>(list: &L, key: impl Fn(&N) -> Option<String>) -> Option<Self>
+    where
+        L: AstSeparatedList<Language = JsonLanguage, Node = N>,
+    {
+        let mut groups: Vec<Vec<SortableElement<N>>> = Vec::new();
+        for element in list.elements() {
+            let node = element.node().ok()?.clone();
+            let key = key(&node)?;
+            let first_token = node.syntax().first_token()?;
+            let last_token = node.syntax().last_token()?;
+
+            let mut trailing_trivia: Vec<_> = last_token.trailing_trivia().pieces().collect();
+            if let Some(separator) = element.trailing_separator().ok()? {
+                trailing_trivia.extend(separator.trailing_trivia().pieces());
+            }
+
+            let element = SortableElement {
+                key,
+                node,
+                trailing_trivia,
+            };
+            match groups.last_mut() {
+                Some(group) if !has_empty_line(&first_token.leading_trivia()) => {
+                    group.push(element)
+                }
+                _ => groups.push(vec![element]),
+            }
+        }
+
+        Some(Self(groups))
+    }
+
+    /// Returns true if the elements of each group are already sorted in the file
+    pub fn is_sorted(&self, order: SortOrder) -> bool {
+        self.0.iter().all(|group| {
+            group
+                .windows(2)
+                .all(|pair| order.compare(&pair[0].key, &pair[1].key) != Ordering::Greater)
+        })
+    }
+
+    /// Returns the sorted elements and the separators that go between them,
+    /// ready to be passed to the factory function of the list
+    pub fn to_sorted_elements(&self, order: SortOrder) -> Option<(Vec<N>, Vec<JsonSyntaxToken>)> {
+        let element_count: usize = self.0.iter().map(Vec::len).sum();
+        let original: Vec<_> = self.0.iter().flatten().collect();
+        let mut items = Vec::with_capacity(element_count);
+        let mut separators = Vec::with_capacity(element_count.saturating_sub(1));
+
+        for group in &self.0 {
+            let first_token = group.first()?.node.syntax().first_token()?;
+            let (group_leading_trivia, first_leading_trivia) =
+                split_group_leading_trivia(&first_token.leading_trivia());
+
+            let mut sorted: Vec<_> = group.iter().enumerate().collect();
+            sorted.sort_by(|(_, left), (_, right)| order.compare(&left.key, &right.key));
+
+            for (index, (original_index, element)) in sorted.into_iter().enumerate() {
+                let mut node = element.node.clone().detach();
+
+                // The empty line stays at the start of the group
+                if index == 0 && original_index != 0 {
+                    node = node.prepend_trivia_pieces(group_leading_trivia.iter().cloned())?;
+                } else if index != 0 && original_index == 0 {
+                    node = node.with_leading_trivia_pieces(first_leading_trivia.iter().cloned())?;
+                }
+
+                // The trailing comments move with the element, but the whitespace stays in place,
+                // so that `["b", "a"]` becomes `["a", "b"]`
+                let trailing_trivia = if has_comments(&element.trailing_trivia) {
+                    element.trailing_trivia.clone()
+                } else {
+                    let in_place = &original[items.len()].trailing_trivia;
+                    if has_comments(in_place) {
+                        Vec::new()
+                    } else {
+                        in_place.clone()
+                    }
+                };
+
+                // The trailing trivia goes after the separator, so that a single-line comment
+                // doesn't swallow it
+                if items.len() + 1 < element_count {
+                    node = node.with_trailing_trivia_pieces(Vec::new())?;
+                    separators.push(token(T![,]).with_trailing_trivia_pieces(trailing_trivia));
+                } else {
+                    node = node.with_trailing_trivia_pieces(trailing_trivia)?;
+                }
+                items.push(node);
+            }
+        }
+
+        Some((items, separators))
+    }
+}
+
+fn has_comments(trivia: &[SyntaxTriviaPiece<JsonLanguage>]) -> bool {
+    trivia.iter().any(|piece| piece.is_comments())
+}
+
+/// Returns true if the provided trivia contains an empty line (two consecutive newline pieces, ignoring whitespace)
+fn has_empty_line(trivia: &JsonSyntaxTrivia) -> bool {
+    let mut was_newline = false;
+    trivia
+        .pieces()
+        .filter(|piece| !piece.is_whitespace())
+        .any(|piece| {
+            let prev_newline = was_newline;
+            was_newline = piece.is_newline();
+            prev_newline && was_newline
+        })
+}
+
+/// Splits the leading trivia of the first element of a group in two parts:
+/// the empty lines that separate the group from the previous one, and the trivia
+/// that belongs to the element itself, which starts with a newline.
+///
+/// The first part is empty if the trivia doesn't contain an empty line.
+fn split_group_leading_trivia(
+    trivia: &JsonSyntaxTrivia,
+) -> (
+    Vec<SyntaxTriviaPiece<JsonLanguage>>,
+    Vec<SyntaxTriviaPiece<JsonLanguage>>,
+) {
+    let mut pieces: Vec<_> = trivia.pieces().collect();
+
+    // Find the last newline of the empty line that is closest to the element
+    let mut next_newline = None;
+    let split_index = pieces
+        .iter()
+        .enumerate()
+        .rev()
+        .find_map(|(index, piece)| {
+            if piece.is_whitespace() {
+                return None;
+            }
+
+            let is_newline = piece.is_newline();
+            if let Some(next_newline) = next_newline.filter(|_| is_newline) {
+                return Some(next_newline);
+            }
+
+            next_newline = is_newline.then_some(index);
+            None
+        })
+        .unwrap_or(0);
+
+    let element_trivia = pieces.split_off(split_index);
+    (pieces, element_trivia)
+}
@@ -1,5 +1,9 @@
-use biome_json_syntax::{JsonMember, JsonMemberList, JsonMemberName, JsonObjectValue};
-use biome_rowan::AstNode;
+use biome_json_syntax::{
+    JsonArrayElementList, JsonArrayValue, JsonLanguage, JsonMember, JsonMemberList, JsonMemberName,
+    JsonObjectValue, JsonRoot,
+};
+use biome_rowan::{AstNode, TokenText};
+use std::path::Path;
 
 /// Matches a JSON member name node against a path
 pub fn matches_path(optional_node: Option<&JsonMemberName>, path: &[&str]) -> bool {
@@ -46,3 +50,58 @@ pub fn matches_path(optional_node: Option<&JsonMemberName>, path: &[&str]) -> bo
 
     matches_path(optional_parent_node.as_ref(), &path[..path.len() - 1])
 }
+
+/// Returns `true` if `path` is the path of a TypeScript configuration:
+/// `tsconfig.json`, `tsconfig.*.json` or `jsconfig.json`
+pub fn is_tsconfig_path(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|file_name| file_name.to_str())
+        .is_some_and(|file_name| {
+            file_name == "jsconfig.json"
+                || (file_name.starts_with("tsconfig.") && file_name.ends_with(".json"))
+        })
+}
+
+/// Returns `true` if `member` is a member of the object at the root of the document
+pub fn is_root_member(member: &JsonMember) -> bool {
+    member
+        .parent::<JsonMemberList>()
+        .and_then(|list| list.parent::<JsonObjectValue>())
+        .and_then(|object| object.parent::<JsonRoot>())
+        .is_some()
+}
+
+/// Returns the member that contains `node`, either directly or as an element of an array
+pub fn owner_member<N: AstNode<Language = JsonLanguage>>(node: &N) -> Option<JsonMember> {
+    node.parent::<JsonMember>().or_else(|| {
+        node.parent::<JsonArrayElementList>()?
+            .parent::<JsonArrayValue>()?
+            .parent::<JsonMember>()
+    })
+}
+
+/// Returns the member of the object that contains `member`
+pub fn parent_member(member: &JsonMember) -> Option<JsonMember> {
+    member
+        .parent::<JsonMemberList>()?
+        .parent::<JsonObjectValue>()?
+        .parent::<JsonMember>()
+}
+
+/// Returns the name of the compiler option defined by `member`,
+/// if `member` belongs to the `compilerOptions` of a TypeScript configuration
+pub fn compiler_option_name(member: &JsonMember) -> Option<TokenText> {
+    let compiler_options = parent_member(member)?;
+    if !is_root_member(&compiler_options)
+        || compiler_options
+            .name()
+            .ok()?
+            .inner_string_text()
+            .ok()?
+            .text()
+            != "compilerOptions"
+    {
+        return None;
+    }
+    member.name().ok()?.inner_string_text().ok()
+}
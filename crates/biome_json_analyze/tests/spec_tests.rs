@@ -1,8 +1,10 @@
 use biome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilter};
 use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::{DiagnosticExt, Severity};
+use biome_json_analyze::utils::is_tsconfig_path;
 use biome_json_parser::{parse_json, JsonParserOptions};
 use biome_json_syntax::{JsonFileSource, JsonLanguage};
+use biome_project::{Manifest, OsTsConfigHost, ResolvedTsConfig, TsConfigJson};
 use biome_rowan::AstNode;
 use biome_test_utils::{
     assert_errors_are_absent, code_fix_to_string, create_analyzer_options, diagnostic_to_string,
@@ -12,7 +14,7 @@ use biome_test_utils::{
 use std::ops::Deref;
 use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
-tests_macros::gen_tests! {"tests/specs/**/*.{json}", crate::run_test, "module"}
+tests_macros::gen_tests! {"tests/specs/**/*.{json,jsonc}", crate::run_test, "module"}
 
 fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     register_leak_checker();
@@ -80,36 +82,43 @@ pub(crate) fn analyze_and_snap(
     file_name: &str,
     input_file: &Path,
 ) -> usize {
-    let parsed = parse_json(input_code, JsonParserOptions::default());
+    let parser_options = JsonParserOptions::from(&file_source);
+    let parsed = parse_json(input_code, parser_options);
     let root = parsed.tree();
 
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
     let options = create_analyzer_options(input_file, &mut diagnostics);
 
-    let (_, errors) = biome_json_analyze::analyze(&root, filter, &options, file_source, |event| {
-        if let Some(mut diag) = event.diagnostic() {
-            for action in event.actions() {
-                if !action.is_suppression() {
-                    check_code_action(input_file, input_code, &action);
-                    diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
+    let tsconfig = is_tsconfig_path(input_file).then(|| {
+        let (tsconfig, _) = TsConfigJson::deserialize_manifest(&root).consume();
+        ResolvedTsConfig::resolve(input_file, &tsconfig.unwrap_or_default(), &OsTsConfigHost)
+    });
+
+    let (_, errors) =
+        biome_json_analyze::analyze(&root, filter, &options, file_source, tsconfig, |event| {
+            if let Some(mut diag) = event.diagnostic() {
+                for action in event.actions() {
+                    if !action.is_suppression() {
+                        check_code_action(input_file, input_code, parser_options, &action);
+                        diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
+                    }
                 }
-            }
 
-            let error = diag.with_severity(Severity::Warning);
-            diagnostics.push(diagnostic_to_string(file_name, input_code, error));
-            return ControlFlow::Continue(());
-        }
+                let error = diag.with_severity(Severity::Warning);
+                diagnostics.push(diagnostic_to_string(file_name, input_code, error));
+                return ControlFlow::Continue(());
+            }
 
-        for action in event.actions() {
-            if !action.is_suppression() {
-                check_code_action(input_file, input_code, &action);
-                code_fixes.push(code_fix_to_string(input_code, action));
+            for action in event.actions() {
+                if !action.is_suppression() {
+                    check_code_action(input_file, input_code, parser_options, &action);
+                    code_fixes.push(code_fix_to_string(input_code, action));
+                }
             }
-        }
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        });
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
@@ -125,7 +134,12 @@ pub(crate) fn analyze_and_snap(
     diagnostics.len()
 }
 
-fn check_code_action(path: &Path, source: &str, action: &AnalyzerAction<JsonLanguage>) {
+fn check_code_action(
+    path: &Path,
+    source: &str,
+    parser_options: JsonParserOptions,
+    action: &AnalyzerAction<JsonLanguage>,
+) {
     let (new_tree, text_edit) = match action
         .mutation
         .clone()
@@ -151,6 +165,6 @@ fn check_code_action(path: &Path, source: &str, action: &AnalyzerAction<JsonLang
     }
 
     // Re-parse the modified code and panic if the resulting tree has syntax errors
-    let re_parse = parse_json(&output, JsonParserOptions::default());
+    let re_parse = parse_json(&output, parser_options);
     assert_errors_are_absent(re_parse.tree().syntax(), re_parse.diagnostics(), path);
 }
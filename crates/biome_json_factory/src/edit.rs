@@ -0,0 +1,355 @@
+//! Helpers to edit a JSON document while preserving its formatting and its comments.
+//!
+//! The helpers don't mutate the tree: they return a new version of the edited node,
+//! which can replace the original one with a `BatchMutation`.
+
+use crate::make::{
+    json_array_element_list, json_member, json_member_list, json_member_name, json_object_value,
+    json_string_literal, token,
+};
+use biome_json_syntax::{
+    AnyJsonValue, JsonArrayValue, JsonLanguage, JsonMember, JsonMemberList, JsonObjectValue,
+    JsonSyntaxToken, JsonSyntaxTrivia, T,
+};
+use biome_rowan::{AstNode, AstNodeExt, AstSeparatedList, SyntaxTriviaPiece, TriviaPieceKind};
+
+/// Returns a new version of `object` where the member at `path` is set to `value`.
+///
+/// An existing value is replaced, and keeps its leading and trailing trivia.
+/// The members missing along the path are appended to their object,
+/// with the indentation of the last member of the object.
+///
+/// Returns `None` if `path` is empty, or if a value along the path isn't an object.
+///
+/// ## Examples
+///
+/// ```
+/// use biome_json_factory::edit::set_member_at_path;
+/// use biome_json_factory::make::{json_boolean_value, token};
+/// use biome_json_syntax::{AnyJsonValue, T};
+/// # use biome_json_parser::{parse_json, JsonParserOptions};
+///
+/// let root = parse_json(r#"{ "linter": { "enabled": true } }"#, JsonParserOptions::default()).tree();
+/// let object = root.value().unwrap();
+/// let object = object.as_json_object_value().unwrap();
+///
+/// let value = AnyJsonValue::JsonBooleanValue(json_boolean_value(token(T![false])));
+/// let object = set_member_at_path(object, &["linter", "enabled"], value).unwrap();
+/// assert_eq!(object.to_string(), r#"{ "linter": { "enabled": false } }"#);
+/// ```
+pub fn set_member_at_path(
+    object: &JsonObjectValue,
+    path: &[&str],
+    value: AnyJsonValue,
+) -> Option<JsonObjectValue> {
+    let (name, rest) = path.split_first()?;
+    let list = object.json_member_list();
+
+    if let Some(member) = find_member(&list, name) {
+        let old_value = member.value().ok()?;
+        let new_value = if rest.is_empty() {
+            value
+        } else {
+            let nested = old_value.as_json_object_value()?;
+            AnyJsonValue::JsonObjectValue(set_member_at_path(nested, rest, value)?)
+        };
+        return object.clone().replace_node(old_value, new_value);
+    }
+
+    let value = make_nested_value(rest, value);
+    if list.is_empty() {
+        // `{}` becomes `{ "name": value }`
+        let name =
+            json_string_literal(name).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]);
+        let object = object
+            .clone()
+            .with_json_member_list(json_member_list(vec![make_member(name, value)], vec![]));
+        let r_curly = object.r_curly_token().ok()?;
+        if !r_curly.leading_trivia().is_empty() {
+            return Some(object);
+        }
+        return Some(object.with_r_curly_token(
+            r_curly.with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        ));
+    }
+
+    let member = make_member(json_string_literal(name), value);
+    let (items, separators) = append_element(&list, member)?;
+    Some(
+        object
+            .clone()
+            .with_json_member_list(json_member_list(items, separators)),
+    )
+}
+
+/// Returns a new version of `list` without `member`.
+///
+/// The comments attached to `member` are removed along with it.
+/// When `member` is the last member, the separator that precedes it is removed,
+/// unless the list ends with a trailing comma.
+///
+/// Returns `None` if `member` doesn't belong to `list`.
+pub fn remove_member(list: &JsonMemberList, member: &JsonMember) -> Option<JsonMemberList> {
+    let mut items = Vec::with_capacity(list.len());
+    let mut separators = Vec::with_capacity(list.len());
+    let mut is_removed = false;
+
+    for element in list.elements() {
+        let node = element.node().ok()?;
+        if node == member {
+            is_removed = true;
+            continue;
+        }
+        items.push(node.clone());
+        if let Some(separator) = element.trailing_separator().ok()? {
+            separators.push(separator.clone());
+        }
+    }
+
+    if !is_removed {
+        return None;
+    }
+
+    // Without a trailing comma, the separator of the new last member is now extraneous.
+    // Its trailing trivia, such as a comment, goes to the member.
+    if list.trailing_separator().is_none() && separators.len() == items.len() {
+        if let (Some(separator), Some(last)) = (separators.pop(), items.pop()) {
+            let last_token = last.syntax().last_token()?;
+            let trailing_trivia: Vec<_> = last_token
+                .trailing_trivia()
+                .pieces()
+                .chain(separator.trailing_trivia().pieces())
+                .collect();
+            items.push(last.with_trailing_trivia_pieces(trailing_trivia)?);
+        }
+    }
+
+    Some(json_member_list(items, separators))
+}
+
+/// Returns a new version of `array` with `value` appended to its elements.
+///
+/// The new element has the indentation of the last element,
+/// so that an array with an element per line keeps an element per line.
+pub fn append_to_array(array: &JsonArrayValue, value: AnyJsonValue) -> Option<JsonArrayValue> {
+    let list = array.elements();
+    let (items, separators) = if list.is_empty() {
+        (vec![value], Vec::new())
+    } else {
+        append_element(&list, value)?
+    };
+    Some(
+        array
+            .clone()
+            .with_elements(json_array_element_list(items, separators)),
+    )
+}
+
+/// Returns the last member of `list` named `name`, which is the one that takes effect
+fn find_member(list: &JsonMemberList, name: &str) -> Option<JsonMember> {
+    list.iter()
+        .flatten()
+        .filter(|member| {
+            member
+                .name()
+                .and_then(|name| name.inner_string_text())
+                .is_ok_and(|text| text.text() == name)
+        })
+        .last()
+}
+
+/// Returns the elements and the separators of the non-empty `list`, followed by `node`
+fn append_element<L, N>(list: &L, node: N) -> Option<(Vec<N>, Vec<JsonSyntaxToken>)>
+where
+    L: AstSeparatedList<Language = JsonLanguage, Node = N>,
+    N: AstNode<Language = JsonLanguage>,
+{
+    let mut items = Vec::with_capacity(list.len() + 1);
+    let mut separators = Vec::with_capacity(list.len() + 1);
+    for element in list.elements() {
+        items.push(element.node().ok()?.clone());
+        if let Some(separator) = element.trailing_separator().ok()? {
+            separators.push(separator.clone());
+        }
+    }
+
+    let last = items.pop()?;
+    let indentation = indentation(&last.syntax().first_token()?.leading_trivia());
+    let has_trailing_separator = list.trailing_separator().is_some();
+
+    let mut node = node.with_leading_trivia_pieces(indentation)?;
+    if has_trailing_separator {
+        items.push(last);
+        items.push(node);
+        separators.push(token(T![,]));
+        return Some((items, separators));
+    }
+
+    // A comment that follows the last element goes after the new separator,
+    // so that a single-line comment doesn't swallow it.
+    // Otherwise, the new element is followed by the same whitespace as the last element,
+    // for example the space that precedes `}` in `{ "a": 1 }`
+    let trailing_trivia: Vec<_> = last
+        .syntax()
+        .last_token()?
+        .trailing_trivia()
+        .pieces()
+        .collect();
+    let separator = if trailing_trivia.iter().any(|piece| piece.is_comments()) {
+        token(T![,]).with_trailing_trivia_pieces(trailing_trivia)
+    } else {
+        let separator_trivia = match separators.last() {
+            Some(previous) => previous.trailing_trivia().pieces().collect(),
+            None => trailing_trivia.clone(),
+        };
+        node = node.with_trailing_trivia_pieces(trailing_trivia)?;
+        token(T![,]).with_trailing_trivia_pieces(separator_trivia)
+    };
+    items.push(last.with_trailing_trivia_pieces(Vec::new())?);
+    items.push(node);
+    separators.push(separator);
+
+    Some((items, separators))
+}
+
+/// Returns the line break and the whitespace that precede an element,
+/// without the comments and the empty lines
+fn indentation(trivia: &JsonSyntaxTrivia) -> Vec<SyntaxTriviaPiece<JsonLanguage>> {
+    let pieces: Vec<_> = trivia.pieces().collect();
+    let start = pieces
+        .iter()
+        .rposition(|piece| piece.is_newline() || piece.is_comments())
+        .map_or(0, |index| {
+            if pieces[index].is_newline() {
+                index
+            } else {
+                index + 1
+            }
+        });
+    pieces[start..].to_vec()
+}
+
+/// Creates the member `"name": value`, where `name` is a string literal
+fn make_member(name: JsonSyntaxToken, value: AnyJsonValue) -> JsonMember {
+    json_member(
+        json_member_name(name),
+        token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        value,
+    )
+}
+
+/// Creates the nested objects of `path`, on a single line, with `value` at the end of the path
+fn make_nested_value(path: &[&str], value: AnyJsonValue) -> AnyJsonValue {
+    path.iter().rev().fold(value, |value, name| {
+        let name =
+            json_string_literal(name).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]);
+        let member = make_member(name, value);
+        AnyJsonValue::JsonObjectValue(json_object_value(
+            token(T!['{']),
+            json_member_list(vec![member], vec![]),
+            token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::make::{json_number_value, json_string_value};
+    use biome_json_parser::{parse_json, JsonParserOptions};
+    use biome_json_syntax::JsonSyntaxKind;
+
+    fn parse_object(source: &str) -> JsonObjectValue {
+        let root = parse_json(source, JsonParserOptions::default().with_allow_comments()).tree();
+        root.value()
+            .unwrap()
+            .as_json_object_value()
+            .unwrap()
+            .clone()
+    }
+
+    fn string(text: &str) -> AnyJsonValue {
+        AnyJsonValue::JsonStringValue(json_string_value(json_string_literal(text)))
+    }
+
+    fn number(text: &str) -> AnyJsonValue {
+        AnyJsonValue::JsonNumberValue(json_number_value(JsonSyntaxToken::new_detached(
+            JsonSyntaxKind::JSON_NUMBER_LITERAL,
+            text,
+            [],
+            [],
+        )))
+    }
+
+    #[test]
+    fn set_existing_member() {
+        let object = parse_object("{\n\t\"a\": 1, // one\n\t\"b\": 2\n}");
+        let object = set_member_at_path(&object, &["a"], number("3")).unwrap();
+        assert_eq!(object.to_string(), "{\n\t\"a\": 3, // one\n\t\"b\": 2\n}");
+    }
+
+    #[test]
+    fn set_missing_member() {
+        let object = parse_object("{\n\t\"a\": 1 // one\n}");
+        let object = set_member_at_path(&object, &["b", "c"], number("2")).unwrap();
+        assert_eq!(
+            object.to_string(),
+            "{\n\t\"a\": 1, // one\n\t\"b\": { \"c\": 2 }\n}"
+        );
+
+        let object = parse_object("{ \"a\": 1 }");
+        let object = set_member_at_path(&object, &["b"], number("2")).unwrap();
+        assert_eq!(object.to_string(), "{ \"a\": 1, \"b\": 2 }");
+
+        let object = parse_object("{}");
+        let object = set_member_at_path(&object, &["a"], number("1")).unwrap();
+        assert_eq!(object.to_string(), "{ \"a\": 1 }");
+    }
+
+    #[test]
+    fn set_member_through_non_object() {
+        let object = parse_object("{ \"a\": 1 }");
+        assert!(set_member_at_path(&object, &["a", "b"], number("2")).is_none());
+        assert!(set_member_at_path(&object, &[], number("2")).is_none());
+    }
+
+    #[test]
+    fn remove_members() {
+        let object = parse_object("{\n\t\"a\": 1, // one\n\t// two\n\t\"b\": 2\n}");
+        let list = object.json_member_list();
+        let members: Vec<_> = list.iter().flatten().collect();
+
+        let without_last = remove_member(&list, &members[1]).unwrap();
+        assert_eq!(without_last.to_string(), "\n\t\"a\": 1 // one");
+
+        let without_first = remove_member(&list, &members[0]).unwrap();
+        assert_eq!(without_first.to_string(), "\n\t// two\n\t\"b\": 2");
+    }
+
+    #[test]
+    fn append_to_arrays() {
+        let object = parse_object("{ \"a\": [\"x\", \"y\"], \"b\": [], \"c\": [\n\t\"x\"\n]\n}");
+        let arrays: Vec<_> = object
+            .json_member_list()
+            .iter()
+            .flatten()
+            .map(|member| {
+                member
+                    .value()
+                    .unwrap()
+                    .as_json_array_value()
+                    .unwrap()
+                    .clone()
+            })
+            .collect();
+
+        let array = append_to_array(&arrays[0], string("z")).unwrap();
+        assert_eq!(array.to_string(), "[\"x\", \"y\", \"z\"]");
+
+        let array = append_to_array(&arrays[1], string("z")).unwrap();
+        assert_eq!(array.to_string(), "[\"z\"]");
+
+        let array = append_to_array(&arrays[2], string("z")).unwrap();
+        assert_eq!(array.to_string(), "[\n\t\"x\",\n\t\"z\"\n]");
+    }
+}
@@ -27,6 +27,12 @@ pub fn json_boolean_value(value_token_token: SyntaxToken) -> JsonBooleanValue {
         [Some(SyntaxElement::Token(value_token_token))],
     ))
 }
+pub fn json_lines_value(records: JsonRecordList) -> JsonLinesValue {
+    JsonLinesValue::unwrap_cast(SyntaxNode::new_detached(
+        JsonSyntaxKind::JSON_LINES_VALUE,
+        [Some(SyntaxElement::Node(records.into_syntax()))],
+    ))
+}
 pub fn json_member(
     name: JsonMemberName,
     colon_token: SyntaxToken,
@@ -149,6 +155,18 @@ where
         }),
     ))
 }
+pub fn json_record_list<I>(items: I) -> JsonRecordList
+where
+    I: IntoIterator<Item = AnyJsonValue>,
+    I::IntoIter: ExactSizeIterator,
+{
+    JsonRecordList::unwrap_cast(SyntaxNode::new_detached(
+        JsonSyntaxKind::JSON_RECORD_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn json_bogus<I>(slots: I) -> JsonBogus
 where
     I: IntoIterator<Item = Option<SyntaxElement>>,
@@ -69,6 +69,25 @@ impl SyntaxFactory for JsonSyntaxFactory {
                 }
                 slots.into_node(JSON_BOOLEAN_VALUE, children)
             }
+            JSON_LINES_VALUE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if JsonRecordList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        JSON_LINES_VALUE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(JSON_LINES_VALUE, children)
+            }
             JSON_MEMBER => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
@@ -107,7 +126,7 @@ impl SyntaxFactory for JsonSyntaxFactory {
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == JSON_STRING_LITERAL {
+                    if matches!(element.kind(), JSON_STRING_LITERAL | IDENT) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -258,6 +277,7 @@ impl SyntaxFactory for JsonSyntaxFactory {
                 T ! [,],
                 true,
             ),
+            JSON_RECORD_LIST => Self::make_node_list_syntax(kind, children, AnyJsonValue::can_cast),
             _ => unreachable!("Is {:?} a token?", kind),
         }
     }
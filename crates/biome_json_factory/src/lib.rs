@@ -1,6 +1,7 @@
 use biome_json_syntax::JsonLanguage;
 use biome_rowan::TreeBuilder;
 
+pub mod edit;
 mod generated;
 pub use crate::generated::JsonSyntaxFactory;
 pub mod make;
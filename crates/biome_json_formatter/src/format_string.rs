@@ -14,6 +14,10 @@ pub(crate) struct CleanedStringLiteralText<'token> {
 impl Format<JsonFormatContext> for CleanedStringLiteralText<'_> {
     fn fmt(&self, f: &mut Formatter<JsonFormatContext>) -> FormatResult<()> {
         let content = self.token.text_trimmed();
+        // The unquoted keys and the single quoted strings of JSON5 are kept as they are
+        if !content.starts_with('"') {
+            return self.token.format().fmt(f);
+        }
         let raw_content = &content[1..content.len() - 1];
 
         let text = match normalize_string(raw_content, Quote::Double, false) {
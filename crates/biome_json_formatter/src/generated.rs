@@ -84,6 +84,46 @@ impl IntoFormat<JsonFormatContext> for biome_json_syntax::JsonBooleanValue {
         )
     }
 }
+impl FormatRule<biome_json_syntax::JsonLinesValue>
+    for crate::json::value::lines_value::FormatJsonLinesValue
+{
+    type Context = JsonFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_json_syntax::JsonLinesValue,
+        f: &mut JsonFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_json_syntax::JsonLinesValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<JsonFormatContext> for biome_json_syntax::JsonLinesValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_json_syntax::JsonLinesValue,
+        crate::json::value::lines_value::FormatJsonLinesValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::json::value::lines_value::FormatJsonLinesValue::default(),
+        )
+    }
+}
+impl IntoFormat<JsonFormatContext> for biome_json_syntax::JsonLinesValue {
+    type Format = FormatOwnedWithRule<
+        biome_json_syntax::JsonLinesValue,
+        crate::json::value::lines_value::FormatJsonLinesValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::json::value::lines_value::FormatJsonLinesValue::default(),
+        )
+    }
+}
 impl FormatRule<biome_json_syntax::JsonMember>
     for crate::json::auxiliary::member::FormatJsonMember
 {
@@ -408,6 +448,33 @@ impl IntoFormat<JsonFormatContext> for biome_json_syntax::JsonMemberList {
         )
     }
 }
+impl AsFormat<JsonFormatContext> for biome_json_syntax::JsonRecordList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_json_syntax::JsonRecordList,
+        crate::json::lists::record_list::FormatJsonRecordList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::json::lists::record_list::FormatJsonRecordList::default(),
+        )
+    }
+}
+impl IntoFormat<JsonFormatContext> for biome_json_syntax::JsonRecordList {
+    type Format = FormatOwnedWithRule<
+        biome_json_syntax::JsonRecordList,
+        crate::json::lists::record_list::FormatJsonRecordList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::json::lists::record_list::FormatJsonRecordList::default(),
+        )
+    }
+}
 impl FormatRule<biome_json_syntax::JsonBogus> for crate::json::bogus::bogus::FormatJsonBogus {
     type Context = JsonFormatContext;
     #[inline(always)]
@@ -2,3 +2,4 @@
 
 pub(crate) mod array_element_list;
 pub(crate) mod member_list;
+pub(crate) mod record_list;
@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use biome_formatter::{write, RemoveSoftLinesBuffer};
+use biome_json_syntax::JsonRecordList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatJsonRecordList;
+
+impl FormatRule<JsonRecordList> for FormatJsonRecordList {
+    type Context = JsonFormatContext;
+    fn fmt(&self, node: &JsonRecordList, f: &mut JsonFormatter) -> FormatResult<()> {
+        let mut join = f.join_with(hard_line_break());
+
+        for record in node {
+            // A record can't span multiple lines, regardless of its width
+            join.entry(&format_once(|f| {
+                let mut buffer = RemoveSoftLinesBuffer::new(f);
+                write!(buffer, [record.format()])
+            }));
+        }
+
+        join.finish()
+    }
+}
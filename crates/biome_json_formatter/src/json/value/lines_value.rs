@@ -0,0 +1,11 @@
+use crate::prelude::*;
+use biome_json_syntax::JsonLinesValue;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatJsonLinesValue;
+
+impl FormatNodeRule<JsonLinesValue> for FormatJsonLinesValue {
+    fn fmt_fields(&self, node: &JsonLinesValue, f: &mut JsonFormatter) -> FormatResult<()> {
+        node.records().format().fmt(f)
+    }
+}
@@ -2,6 +2,7 @@
 
 pub(crate) mod array_value;
 pub(crate) mod boolean_value;
+pub(crate) mod lines_value;
 pub(crate) mod null_value;
 pub(crate) mod number_value;
 pub(crate) mod object_value;
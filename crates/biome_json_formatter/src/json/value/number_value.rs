@@ -7,6 +7,14 @@ pub(crate) struct FormatJsonNumberValue;
 
 impl FormatNodeRule<JsonNumberValue> for FormatJsonNumberValue {
     fn fmt_fields(&self, node: &JsonNumberValue, f: &mut JsonFormatter) -> FormatResult<()> {
-        format_number_token(&node.value_token()?).fmt(f)
+        let value_token = node.value_token()?;
+        let text = value_token.text_trimmed();
+        // The JSON5 hexadecimal numbers are kept as they are
+        if text.trim_start_matches('-').starts_with("0x")
+            || text.trim_start_matches('-').starts_with("0X")
+        {
+            return value_token.format().fmt(f);
+        }
+        format_number_token(&value_token).fmt(f)
     }
 }
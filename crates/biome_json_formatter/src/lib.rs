@@ -352,4 +352,39 @@ mod tests {
             "{\n\t\"a\": 5,\n\t\"b\": [1, 2, 3, 4],\n\t\"c\": null,\n\t\"d\": true,\n\t\"e\": false\n}\n"
         );
     }
+
+    #[test]
+    fn json5_test() {
+        let src = r#"
+{
+    unquoted: 'single',
+    "hex": 0xFF,
+    text: 'multi\
+line',
+}
+"#;
+        let parse = parse_json(src, JsonParserOptions::default().with_json5());
+        let options = JsonFormatOptions::default();
+        let formatted = format_node(options, &parse.syntax()).unwrap();
+        assert_eq!(
+            formatted.print().unwrap().as_code(),
+            "{\n\tunquoted: 'single',\n\t\"hex\": 0xFF,\n\ttext: 'multi\\\nline'\n}\n"
+        );
+    }
+
+    #[test]
+    fn json_lines_test() {
+        let src = r#"{"id":1,"tags":["a","b"]}
+  [ 1,2 ]
+
+{"message": "a record that is longer than the line width isn't split over multiple lines"}
+"#;
+        let parse = parse_json(src, JsonParserOptions::default().with_json_lines());
+        let options = JsonFormatOptions::default();
+        let formatted = format_node(options, &parse.syntax()).unwrap();
+        assert_eq!(
+            formatted.print().unwrap().as_code(),
+            "{ \"id\": 1, \"tags\": [\"a\", \"b\"] }\n[1, 2]\n{ \"message\": \"a record that is longer than the line width isn't split over multiple lines\" }\n"
+        );
+    }
 }
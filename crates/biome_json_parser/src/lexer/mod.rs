@@ -362,6 +362,13 @@ impl<'src> Lexer<'src> {
             self.advance(1);
         }
 
+        if self.options.json5
+            && self.current_byte() == Some(b'0')
+            && matches!(self.peek_byte(), Some(b'x' | b'X'))
+        {
+            return self.lex_hex_number(start);
+        }
+
         let mut state = LexNumberState::FirstDigit;
 
         loop {
@@ -492,6 +499,31 @@ impl<'src> Lexer<'src> {
         }
     }
 
+    /// Lexes a JSON5 hexadecimal number literal, such as `0xFF`.
+    /// Assumes that the lexer is positioned at the leading `0`.
+    fn lex_hex_number(&mut self, start: TextSize) -> JsonSyntaxKind {
+        self.assert_byte(b'0');
+        self.advance(2); // Skip over `0x`
+
+        let digits_start = self.text_position();
+        while let Some(byte) = self.current_byte() {
+            if byte.is_ascii_hexdigit() {
+                self.advance(1);
+            } else {
+                break;
+            }
+        }
+
+        if self.text_position() == digits_start {
+            let err = ParseDiagnostic::new("Missing hexadecimal digits", start..digits_start)
+                .with_hint("A hexadecimal number must have at least one digit after `0x`.");
+            self.diagnostics.push(err);
+            return ERROR_TOKEN;
+        }
+
+        JSON_NUMBER_LITERAL
+    }
+
     fn lex_string_literal(&mut self, quote: u8) -> JsonSyntaxKind {
         // Handle invalid quotes
         self.assert_at_char_boundary();
@@ -499,7 +531,7 @@ impl<'src> Lexer<'src> {
 
         self.advance(1); // Skip over the quote
         let mut state = match quote {
-            b'\'' => LexStringState::InvalidQuote,
+            b'\'' if !self.options.json5 => LexStringState::InvalidQuote,
             _ => LexStringState::InString,
         };
 
@@ -540,6 +572,34 @@ impl<'src> Lexer<'src> {
                             self.advance(1);
                         }
 
+                        Some(b'x') if self.options.json5 => match (self.lex_hex_escape(), state) {
+                            (Ok(_), _) => {}
+                            (Err(err), LexStringState::InString) => {
+                                self.diagnostics.push(err);
+                                state = LexStringState::InvalidEscapeSequence;
+                            }
+                            (Err(_), _) => {}
+                        },
+
+                        // A line continuation, the string continues on the next line
+                        Some(b'\r') if self.options.json5 => {
+                            self.advance(1);
+                            if self.current_byte() == Some(b'\n') {
+                                self.advance(1);
+                            }
+                        }
+
+                        // JSON5 allows to escape any character but the decimal digits,
+                        // `\0` is allowed if it isn't followed by a digit.
+                        Some(byte)
+                            if self.options.json5
+                                && (!byte.is_ascii_digit()
+                                    || (byte == b'0'
+                                        && !matches!(self.peek_byte(), Some(b'0'..=b'9')))) =>
+                        {
+                            self.advance_byte_or_char(byte);
+                        }
+
                         Some(_) => {
                             if matches!(state, LexStringState::InString) {
                                 let c = self.current_char_unchecked();
@@ -677,6 +737,33 @@ impl<'src> Lexer<'src> {
         Ok(())
     }
 
+    /// Lexes a JSON5 `\x00` escape sequence. Assumes that the lexer is positioned at the `x` token.
+    ///
+    /// A hexadecimal escape sequence must consist of 2 hex characters.
+    fn lex_hex_escape(&mut self) -> Result<(), ParseDiagnostic> {
+        self.assert_byte(b'x');
+
+        // Subtract 1 to get position of `\`
+        let start = self.text_position() - TextSize::from(1);
+
+        self.advance(1); // Advance over `x`
+
+        for _ in 0..2 {
+            match self.current_byte() {
+                Some(byte) if byte.is_ascii_hexdigit() => self.advance(1),
+                _ => {
+                    return Err(ParseDiagnostic::new(
+                        "Invalid hexadecimal escape sequence",
+                        start..self.text_position(),
+                    )
+                    .with_hint("A hexadecimal escape sequence must consist of 2 hexadecimal numbers: `\\xXX`, e.g. `\\x2F` for '/'."));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Implements basic lexing of identifiers without support for escape sequences.
     /// This is merely for improved error recovery as identifiers are not valid in JSON.
     fn lex_identifier(&mut self, first: u8) -> JsonSyntaxKind {
@@ -2,6 +2,7 @@
 #![allow(unused_mut, unused_variables, unused_assignments)]
 
 use super::{Lexer, TextSize};
+use crate::JsonParserOptions;
 use biome_json_syntax::JsonSyntaxKind::{self, EOF};
 use quickcheck_macros::quickcheck;
 use std::sync::mpsc::channel;
@@ -11,8 +12,11 @@ use std::time::Duration;
 // Assert the result of lexing a piece of source code,
 // and make sure the tokens yielded are fully lossless and the source can be reconstructed from only the tokens
 macro_rules! assert_lex {
-    ($src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
-        let mut lexer = Lexer::from_str($src);
+    ($src:expr, $($kind:ident:$len:expr $(,)?)*) => {
+        assert_lex!(JsonParserOptions::default(); $src, $($kind:$len,)*)
+    };
+    ($options:expr; $src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
+        let mut lexer = Lexer::from_str($src).with_options($options);
         let mut idx = 0;
         let mut tok_idx = TextSize::default();
 
@@ -352,6 +356,91 @@ fn single_quote_escape_in_single_quote_string() {
     }
 }
 
+#[test]
+fn json5_single_quote_string() {
+    assert_lex! {
+        JsonParserOptions::default().with_json5();
+        r#"'A string with "double" and \' single quotes'"#,
+        JSON_STRING_LITERAL:45,
+        EOF:0
+    }
+}
+
+#[test]
+fn json5_escape_sequences() {
+    assert_lex! {
+        JsonParserOptions::default().with_json5();
+        r#""Escaped \x2F, \v, \0 and \a""#,
+        JSON_STRING_LITERAL:29,
+        EOF:0
+    }
+
+    assert_lex! {
+        JsonParserOptions::default().with_json5();
+        r#""Invalid \x2""#,
+        ERROR_TOKEN:13,
+        EOF:0
+    }
+
+    assert_lex! {
+        JsonParserOptions::default().with_json5();
+        r#""Invalid \1""#,
+        ERROR_TOKEN:12,
+        EOF:0
+    }
+}
+
+#[test]
+fn json5_multi_line_string() {
+    assert_lex! {
+        JsonParserOptions::default().with_json5();
+        "'first \\\nsecond \\\r\nthird'",
+        JSON_STRING_LITERAL:25,
+        EOF:0
+    }
+
+    assert_lex! {
+        JsonParserOptions::default().with_json5();
+        "'first\nsecond'",
+        ERROR_TOKEN:6,
+        NEWLINE:1,
+        IDENT:6,
+        ERROR_TOKEN:1,
+        EOF:0
+    }
+}
+
+#[test]
+fn json5_hex_number() {
+    assert_lex! {
+        JsonParserOptions::default().with_json5();
+        "0xFF",
+        JSON_NUMBER_LITERAL:4,
+        EOF:0
+    }
+
+    assert_lex! {
+        JsonParserOptions::default().with_json5();
+        "-0x1a",
+        JSON_NUMBER_LITERAL:5,
+        EOF:0
+    }
+
+    assert_lex! {
+        JsonParserOptions::default().with_json5();
+        "0x",
+        ERROR_TOKEN:2,
+        EOF:0
+    }
+
+    assert_lex! {
+        "0xFF",
+        JSON_NUMBER_LITERAL:1,
+        IDENT:3,
+        EOF:0
+    }
+}
+
 #[test]
 fn identifiers() {
     assert_lex! {
@@ -16,6 +16,11 @@ pub(crate) struct JsonParser<'source> {
 pub struct JsonParserOptions {
     pub allow_comments: bool,
     pub allow_trailing_commas: bool,
+    /// Parses the file as [JSON5](https://spec.json5.org/): unquoted keys, single quoted strings,
+    /// hexadecimal numbers and multi-line strings are allowed.
+    pub json5: bool,
+    /// Parses the file as [JSON Lines](https://jsonlines.org/): each line contains a value.
+    pub json_lines: bool,
 }
 
 impl JsonParserOptions {
@@ -30,6 +35,21 @@ impl JsonParserOptions {
         self.allow_trailing_commas = true;
         self
     }
+
+    /// JSON5 allows comments and trailing commas too.
+    #[must_use]
+    pub fn with_json5(mut self) -> Self {
+        self.json5 = true;
+        self.allow_comments = true;
+        self.allow_trailing_commas = true;
+        self
+    }
+
+    #[must_use]
+    pub fn with_json_lines(mut self) -> Self {
+        self.json_lines = true;
+        self
+    }
 }
 
 impl From<&JsonFileSource> for JsonParserOptions {
@@ -41,6 +61,12 @@ impl From<&JsonFileSource> for JsonParserOptions {
         if file_source.allow_trailing_commas() {
             options = options.with_allow_trailing_commas();
         }
+        if file_source.is_json5() {
+            options = options.with_json5();
+        }
+        if file_source.is_json_lines() {
+            options = options.with_json_lines();
+        }
         options
     }
 }
@@ -114,5 +140,16 @@ mod tests {
             JsonParserOptions::from(&JsonFileSource::json_allow_comments_and_trailing_commas());
         assert!(p3.allow_comments);
         assert!(p3.allow_trailing_commas);
+        assert!(!p3.json5);
+
+        let p4 = JsonParserOptions::from(&JsonFileSource::json5());
+        assert!(p4.allow_comments);
+        assert!(p4.allow_trailing_commas);
+        assert!(p4.json5);
+
+        let p5 = JsonParserOptions::from(&JsonFileSource::json_lines());
+        assert!(!p5.allow_comments);
+        assert!(!p5.allow_trailing_commas);
+        assert!(p5.json_lines);
     }
 }
@@ -25,6 +25,12 @@ pub(crate) fn parse_root(p: &mut JsonParser) {
     let m = p.start();
     p.eat(UNICODE_BOM);
 
+    if p.options().json_lines {
+        parse_lines(p);
+        m.complete(p, JSON_ROOT);
+        return;
+    }
+
     let value = match parse_value(p) {
         Present(value) => Present(value),
         Absent => {
@@ -44,6 +50,52 @@ pub(crate) fn parse_root(p: &mut JsonParser) {
     m.complete(p, JSON_ROOT);
 }
 
+/// Parses the records of a JSON Lines document.
+///
+/// Each line is parsed as an independent value: a line break ends the current record,
+/// even if the record is incomplete, so that an error doesn't spill over the next lines.
+fn parse_lines(p: &mut JsonParser) {
+    let m = p.start();
+    let list = p.start();
+    let mut progress = ParserProgress::default();
+
+    while !p.at(EOF) {
+        progress.assert_progressing(p);
+
+        if parse_value(p).is_absent() {
+            p.error(expected_value(p, p.cur_range()));
+            skip_rest_of_line(p);
+            continue;
+        }
+
+        if !p.at(EOF) && !p.has_preceding_line_break() {
+            p.error(
+                p.err_builder("Expected a line break after the record", p.cur_range())
+                    .with_hint("Each line of a JSON Lines file contains a single value."),
+            );
+            skip_rest_of_line(p);
+        }
+    }
+
+    list.complete(p, JSON_RECORD_LIST);
+    m.complete(p, JSON_LINES_VALUE);
+}
+
+/// Wraps the tokens up to the next line break in a bogus value
+fn skip_rest_of_line(p: &mut JsonParser) {
+    let m = p.start();
+    p.bump_any();
+    while !p.at(EOF) && !p.has_preceding_line_break() {
+        p.bump_any();
+    }
+    m.complete(p, JSON_BOGUS_VALUE);
+}
+
+/// Returns `true` if the current token starts a new record of a JSON Lines document
+fn at_record_end(p: &JsonParser) -> bool {
+    p.options().json_lines && p.has_preceding_line_break()
+}
+
 fn parse_value(p: &mut JsonParser) -> ParsedSyntax {
     match p.cur() {
         T![null] => {
@@ -178,7 +230,7 @@ fn parse_sequence(p: &mut JsonParser, root_kind: SequenceKind) -> ParsedSyntax {
 
         let mut progress = ParserProgress::default();
 
-        while !p.at(EOF) && !p.at(current.kind.close_paren()) {
+        while !p.at(EOF) && !p.at(current.kind.close_paren()) && !at_record_end(p) {
             if first {
                 first = false;
             } else {
@@ -227,7 +279,12 @@ fn parse_sequence(p: &mut JsonParser, root_kind: SequenceKind) -> ParsedSyntax {
         }
 
         current.list.complete(p, current.kind.list_kind());
-        p.expect(current.kind.close_paren());
+        if at_record_end(p) {
+            // A bracket on the next line belongs to the next record
+            p.error(expected_record_close_paren(p, current.kind.close_paren()));
+        } else {
+            p.expect(current.kind.close_paren());
+        }
         let node = current.node.complete(p, current.kind.node_kind());
 
         match stack.pop() {
@@ -289,11 +346,16 @@ fn parse_member_name(p: &mut JsonParser) -> ParsedSyntax {
             p.bump(JSON_STRING_LITERAL);
             Present(m.complete(p, JSON_MEMBER_NAME))
         }
+        IDENT | T![null] | T![true] | T![false] if p.options().json5 => {
+            let m = p.start();
+            p.bump_remap(IDENT);
+            Present(m.complete(p, JSON_MEMBER_NAME))
+        }
         IDENT | T![null] | T![true] | T![false] => {
             let m = p.start();
             p.error(p.err_builder("Property key must be double quoted", p.cur_range()));
             p.bump_remap(IDENT);
-            Present(m.complete(p, JSON_MEMBER_NAME))
+            Present(m.complete(p, JSON_BOGUS))
         }
         _ => Absent,
     }
@@ -307,6 +369,10 @@ fn parse_array_element(p: &mut JsonParser) -> SequenceItem {
 }
 
 fn parse_sequence_value(p: &mut JsonParser) -> Result<ParsedSyntax, SequenceKind> {
+    if at_record_end(p) {
+        return Ok(Absent);
+    }
+
     match p.cur() {
         // Special handling for arrays and objects, suspend the current sequence and start parsing
         // the nested array or object.
@@ -348,3 +414,15 @@ fn expected_value(p: &JsonParser, range: TextRange) -> ParseDiagnostic {
 fn expected_property(p: &JsonParser, range: TextRange) -> ParseDiagnostic {
     expected_node("property", range, p)
 }
+
+fn expected_record_close_paren(p: &JsonParser, kind: JsonSyntaxKind) -> ParseDiagnostic {
+    let end = p.last_end().unwrap_or_default();
+    p.err_builder(
+        format!(
+            "expected `{}` before the end of the line",
+            kind.to_string().unwrap_or_default()
+        ),
+        TextRange::empty(end),
+    )
+    .with_hint("A record of a JSON Lines file can't span multiple lines.")
+}
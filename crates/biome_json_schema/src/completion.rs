@@ -0,0 +1,173 @@
+use crate::schema::{InstanceType, JsonSchema, SchemaNode, SchemaObject, MAX_REFERENCE_DEPTH};
+
+/// A step of the path from the root of a document to one of its values
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum JsonPathSegment {
+    /// The value of the property with this name
+    Property(String),
+    /// An element of an array
+    Item,
+}
+
+impl JsonSchema {
+    /// Returns the names of the properties that the schema declares for the object at `path`,
+    /// in the order of their declaration.
+    ///
+    /// The properties matched by `patternProperties` and `additionalProperties` can't be listed,
+    /// so they are ignored.
+    pub fn property_names(&self, path: &[JsonPathSegment]) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        for schema in self.schemas_at(path) {
+            for (name, _) in &schema.properties {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+        }
+        names
+    }
+
+    /// Returns the values that the schema accepts at `path`, written in JSON: the values of
+    /// the `enum` and `const` keywords, and the booleans.
+    pub fn value_suggestions(&self, path: &[JsonPathSegment]) -> Vec<String> {
+        let mut values: Vec<String> = Vec::new();
+        let mut push = |value: String| {
+            if !values.contains(&value) {
+                values.push(value);
+            }
+        };
+        for schema in self.schemas_at(path) {
+            for value in schema.enum_values.iter().flatten() {
+                push(value.to_string());
+            }
+            if let Some(value) = &schema.const_value {
+                push(value.to_string());
+            }
+            if schema
+                .types
+                .as_ref()
+                .is_some_and(|types| types.contains(&InstanceType::Boolean))
+            {
+                push("true".to_string());
+                push("false".to_string());
+            }
+        }
+        values
+    }
+
+    /// Returns the schemas that apply to the value at `path`, including the alternatives of
+    /// `allOf`, `anyOf` and `oneOf`
+    fn schemas_at(&self, path: &[JsonPathSegment]) -> Vec<&SchemaObject> {
+        let mut schemas = Vec::new();
+        self.collect_schemas(self.root(), 0, &mut schemas);
+        for segment in path {
+            let mut children = Vec::new();
+            for schema in schemas {
+                let child = match segment {
+                    JsonPathSegment::Property(name) => property_schema(schema, name),
+                    JsonPathSegment::Item => schema.items.as_ref(),
+                };
+                if let Some(child) = child {
+                    self.collect_schemas(child, 0, &mut children);
+                }
+            }
+            schemas = children;
+        }
+        schemas
+    }
+
+    fn collect_schemas<'a>(
+        &'a self,
+        node: &'a SchemaNode,
+        depth: usize,
+        schemas: &mut Vec<&'a SchemaObject>,
+    ) {
+        if depth > MAX_REFERENCE_DEPTH {
+            return;
+        }
+        let SchemaNode::Object(schema) = self.resolve(node) else {
+            return;
+        };
+        schemas.push(schema);
+        for node in schema
+            .all_of
+            .iter()
+            .chain(&schema.any_of)
+            .chain(&schema.one_of)
+        {
+            self.collect_schemas(node, depth + 1, schemas);
+        }
+    }
+}
+
+/// Returns the schema of the property `name` of the objects described by `schema`
+fn property_schema<'a>(schema: &'a SchemaObject, name: &str) -> Option<&'a SchemaNode> {
+    if let Some((_, node)) = schema
+        .properties
+        .iter()
+        .find(|(property, _)| property == name)
+    {
+        return Some(node);
+    }
+    if let Some((_, node)) = schema
+        .pattern_properties
+        .iter()
+        .find(|(pattern, _)| pattern.is_match(name))
+    {
+        return Some(node);
+    }
+    schema.additional_properties.as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_the_properties_and_the_values_at_a_path() {
+        let schema = JsonSchema::from_source(
+            r##"{
+                "type": "object",
+                "properties": {
+                    "linter": { "anyOf": [{ "$ref": "#/definitions/Linter" }, { "type": "null" }] },
+                    "files": { "type": "array", "items": { "enum": ["a.js", "b.js"] } }
+                },
+                "definitions": {
+                    "Linter": {
+                        "type": "object",
+                        "properties": {
+                            "enabled": { "type": ["boolean", "null"] },
+                            "level": { "type": "string", "enum": ["error", "warn"] }
+                        }
+                    }
+                }
+            }"##,
+        )
+        .unwrap();
+
+        assert_eq!(schema.property_names(&[]), ["linter", "files"]);
+        let linter = JsonPathSegment::Property("linter".to_string());
+        assert_eq!(
+            schema.property_names(std::slice::from_ref(&linter)),
+            ["enabled", "level"]
+        );
+        assert_eq!(
+            schema.value_suggestions(&[
+                linter.clone(),
+                JsonPathSegment::Property("level".to_string())
+            ]),
+            [r#""error""#, r#""warn""#]
+        );
+        assert_eq!(
+            schema.value_suggestions(&[linter, JsonPathSegment::Property("enabled".to_string())]),
+            ["true", "false"]
+        );
+        assert_eq!(
+            schema.value_suggestions(&[
+                JsonPathSegment::Property("files".to_string()),
+                JsonPathSegment::Item
+            ]),
+            [r#""a.js""#, r#""b.js""#]
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use biome_console::fmt::Display;
+use biome_console::{markup, MarkupBuf};
+use biome_diagnostics::{Advices, Diagnostic, LogCategory, MessageAndDescription, Visit};
+use biome_rowan::TextRange;
+
+/// A value of a JSON document that doesn't satisfy the schema associated with the document.
+#[derive(Debug, Diagnostic)]
+#[diagnostic(category = "jsonSchema", severity = Error)]
+pub struct JsonSchemaDiagnostic {
+    #[message]
+    #[description]
+    message: MessageAndDescription,
+
+    #[location(span)]
+    range: TextRange,
+
+    #[advice]
+    advice: JsonSchemaAdvice,
+}
+
+impl JsonSchemaDiagnostic {
+    pub(crate) fn new(range: TextRange, message: impl Display) -> Self {
+        Self {
+            message: MessageAndDescription::from(markup! {{message}}.to_owned()),
+            range,
+            advice: JsonSchemaAdvice::default(),
+        }
+    }
+
+    pub(crate) fn with_note(mut self, note: impl Display) -> Self {
+        self.advice.notes.push(markup! {{note}}.to_owned());
+        self
+    }
+
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+}
+
+#[derive(Debug, Default)]
+struct JsonSchemaAdvice {
+    notes: Vec<MarkupBuf>,
+}
+
+impl Advices for JsonSchemaAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> std::io::Result<()> {
+        for note in &self.notes {
+            visitor.record_log(LogCategory::Info, note)?;
+        }
+        Ok(())
+    }
+}
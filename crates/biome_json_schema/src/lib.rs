@@ -0,0 +1,19 @@
+//! Validation of JSON documents against [JSON schemas](https://json-schema.org/).
+//!
+//! A [JsonSchema] is compiled from the syntax tree of a schema document, and [validate]
+//! reports the values of a document that don't satisfy it. The [JsonSchemaStore] keeps track
+//! of the schemas of a project, and finds the schema associated with a document.
+//! The properties and the values that a schema declares are listed by
+//! [JsonSchema::property_names] and [JsonSchema::value_suggestions], to complete documents.
+
+mod completion;
+mod diagnostics;
+mod schema;
+mod store;
+mod validator;
+
+pub use crate::completion::JsonPathSegment;
+pub use crate::diagnostics::JsonSchemaDiagnostic;
+pub use crate::schema::{is_schema_document, schema_reference, JsonSchema};
+pub use crate::store::JsonSchemaStore;
+pub use crate::validator::validate;
@@ -0,0 +1,425 @@
+use biome_json_parser::{parse_json, JsonParserOptions};
+use biome_json_syntax::{AnyJsonValue, JsonObjectValue, JsonRoot, T};
+use biome_rowan::AstSeparatedList;
+use regex::Regex;
+use rustc_hash::FxHashMap;
+use std::fmt::{Display, Formatter};
+
+/// The maximum number of `$ref` that are followed to resolve a schema.
+/// It prevents infinite loops when references are cyclic.
+pub(crate) const MAX_REFERENCE_DEPTH: usize = 32;
+
+static ANY: SchemaNode = SchemaNode::Any;
+
+/// A JSON schema, compiled from a JSON document.
+///
+/// Only the validation keywords are kept, the annotations such as `description` are dropped.
+/// The supported keywords are:
+/// - `type`, `enum` and `const`
+/// - `properties`, `patternProperties`, `additionalProperties` and `required`
+/// - `items`, `minItems` and `maxItems`
+/// - `minimum`, `maximum`, `exclusiveMinimum` and `exclusiveMaximum`
+/// - `minLength`, `maxLength` and `pattern`
+/// - `allOf`, `anyOf`, `oneOf` and `not`
+/// - `$ref`, when it points to `#`, `#/definitions/<name>` or `#/$defs/<name>`
+///
+/// The other keywords are ignored, as well as the references to other documents.
+#[derive(Debug)]
+pub struct JsonSchema {
+    root: SchemaNode,
+    definitions: FxHashMap<String, SchemaNode>,
+}
+
+impl JsonSchema {
+    /// Compiles the schema contained in the document `root`.
+    ///
+    /// Returns `None` if the document isn't an object or a boolean.
+    pub fn from_root(root: &JsonRoot) -> Option<Self> {
+        let value = root.value().ok()?;
+        let root = match &value {
+            AnyJsonValue::JsonObjectValue(_) | AnyJsonValue::JsonBooleanValue(_) => {
+                SchemaNode::from_value(&value)
+            }
+            _ => return None,
+        };
+
+        let mut definitions = FxHashMap::default();
+        if let AnyJsonValue::JsonObjectValue(object) = &value {
+            for (name, value) in members(object) {
+                if name != "definitions" && name != "$defs" {
+                    continue;
+                }
+                let AnyJsonValue::JsonObjectValue(object) = value else {
+                    continue;
+                };
+                for (definition, value) in members(&object) {
+                    definitions.insert(
+                        format!("#/{name}/{definition}"),
+                        SchemaNode::from_value(&value),
+                    );
+                }
+            }
+        }
+
+        Some(Self { root, definitions })
+    }
+
+    /// Parses and compiles a schema. The comments and the trailing commas are allowed.
+    pub fn from_source(source: &str) -> Option<Self> {
+        let parse = parse_json(
+            source,
+            JsonParserOptions::default()
+                .with_allow_comments()
+                .with_allow_trailing_commas(),
+        );
+        if parse.has_errors() {
+            return None;
+        }
+        Self::from_root(&parse.tree())
+    }
+
+    pub(crate) fn root(&self) -> &SchemaNode {
+        &self.root
+    }
+
+    /// Follows the references of `node`, until a schema that isn't a reference is found.
+    ///
+    /// The unresolved references match any value.
+    pub(crate) fn resolve<'a>(&'a self, mut node: &'a SchemaNode) -> &'a SchemaNode {
+        for _ in 0..MAX_REFERENCE_DEPTH {
+            let SchemaNode::Object(object) = node else {
+                return node;
+            };
+            let Some(reference) = &object.reference else {
+                return node;
+            };
+            node = if reference == "#" {
+                &self.root
+            } else {
+                match self.definitions.get(reference.as_str()) {
+                    Some(definition) => definition,
+                    None => return &ANY,
+                }
+            };
+        }
+        &ANY
+    }
+}
+
+/// Returns `true` if the document `root` is a JSON schema, which means that its
+/// `$schema` property points to a meta-schema of `json-schema.org`.
+pub fn is_schema_document(root: &JsonRoot) -> bool {
+    schema_reference(root).is_some_and(|reference| reference.contains("json-schema.org"))
+}
+
+/// Returns the value of the `$schema` property of the document `root`.
+pub fn schema_reference(root: &JsonRoot) -> Option<String> {
+    let AnyJsonValue::JsonObjectValue(object) = root.value().ok()? else {
+        return None;
+    };
+    members(&object).find_map(|(name, value)| match value {
+        AnyJsonValue::JsonStringValue(value) if name == "$schema" => {
+            Some(value.inner_string_text().ok()?.to_string())
+        }
+        _ => None,
+    })
+}
+
+#[derive(Debug)]
+pub(crate) enum SchemaNode {
+    /// The schema `true`, or a schema without any validation keyword
+    Any,
+    /// The schema `false`
+    Nothing,
+    Object(Box<SchemaObject>),
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct SchemaObject {
+    pub(crate) reference: Option<String>,
+    pub(crate) types: Option<Vec<InstanceType>>,
+    pub(crate) enum_values: Option<Vec<JsonLiteral>>,
+    pub(crate) const_value: Option<JsonLiteral>,
+
+    pub(crate) properties: Vec<(String, SchemaNode)>,
+    pub(crate) pattern_properties: Vec<(Regex, SchemaNode)>,
+    pub(crate) additional_properties: Option<SchemaNode>,
+    pub(crate) required: Vec<String>,
+
+    pub(crate) items: Option<SchemaNode>,
+    pub(crate) min_items: Option<usize>,
+    pub(crate) max_items: Option<usize>,
+
+    pub(crate) minimum: Option<f64>,
+    pub(crate) maximum: Option<f64>,
+    pub(crate) exclusive_minimum: Option<f64>,
+    pub(crate) exclusive_maximum: Option<f64>,
+
+    pub(crate) min_length: Option<usize>,
+    pub(crate) max_length: Option<usize>,
+    pub(crate) pattern: Option<Regex>,
+
+    pub(crate) all_of: Vec<SchemaNode>,
+    pub(crate) any_of: Vec<SchemaNode>,
+    pub(crate) one_of: Vec<SchemaNode>,
+    pub(crate) not: Option<SchemaNode>,
+}
+
+impl SchemaNode {
+    fn from_value(value: &AnyJsonValue) -> Self {
+        match value {
+            AnyJsonValue::JsonBooleanValue(value) => match value.value_token() {
+                Ok(token) if token.kind() == T![false] => Self::Nothing,
+                _ => Self::Any,
+            },
+            AnyJsonValue::JsonObjectValue(object) => {
+                let mut schema = SchemaObject::default();
+                let mut has_keywords = false;
+                for (name, value) in members(object) {
+                    has_keywords |= schema.set_keyword(&name, &value);
+                }
+                if has_keywords {
+                    Self::Object(Box::new(schema))
+                } else {
+                    Self::Any
+                }
+            }
+            _ => Self::Any,
+        }
+    }
+}
+
+impl SchemaObject {
+    /// Stores the keyword `name`. Returns `false` if the keyword isn't supported.
+    fn set_keyword(&mut self, name: &str, value: &AnyJsonValue) -> bool {
+        match name {
+            "$ref" => self.reference = string(value),
+            "type" => {
+                self.types = match value {
+                    AnyJsonValue::JsonArrayValue(array) => Some(
+                        array
+                            .elements()
+                            .iter()
+                            .flatten()
+                            .filter_map(|value| string(&value)?.parse().ok())
+                            .collect(),
+                    ),
+                    _ => string(value)
+                        .and_then(|value| value.parse().ok())
+                        .map(|value| vec![value]),
+                }
+            }
+            "enum" => {
+                let AnyJsonValue::JsonArrayValue(array) = value else {
+                    return false;
+                };
+                self.enum_values = Some(
+                    array
+                        .elements()
+                        .iter()
+                        .flatten()
+                        .map(|value| JsonLiteral::from_value(&value))
+                        .collect(),
+                );
+            }
+            "const" => self.const_value = Some(JsonLiteral::from_value(value)),
+            "properties" | "patternProperties" => {
+                let AnyJsonValue::JsonObjectValue(object) = value else {
+                    return false;
+                };
+                for (property, value) in members(object) {
+                    let schema = SchemaNode::from_value(&value);
+                    if name == "properties" {
+                        self.properties.push((property, schema));
+                    } else if let Ok(pattern) = Regex::new(&property) {
+                        self.pattern_properties.push((pattern, schema));
+                    }
+                }
+            }
+            "additionalProperties" => {
+                self.additional_properties = Some(SchemaNode::from_value(value))
+            }
+            "required" => {
+                let AnyJsonValue::JsonArrayValue(array) = value else {
+                    return false;
+                };
+                self.required = array
+                    .elements()
+                    .iter()
+                    .flatten()
+                    .filter_map(|value| string(&value))
+                    .collect();
+            }
+            "items" => self.items = Some(SchemaNode::from_value(value)),
+            "minItems" => self.min_items = count(value),
+            "maxItems" => self.max_items = count(value),
+            "minimum" => self.minimum = number(value),
+            "maximum" => self.maximum = number(value),
+            "exclusiveMinimum" => self.exclusive_minimum = number(value),
+            "exclusiveMaximum" => self.exclusive_maximum = number(value),
+            "minLength" => self.min_length = count(value),
+            "maxLength" => self.max_length = count(value),
+            "pattern" => self.pattern = string(value).and_then(|value| Regex::new(&value).ok()),
+            "allOf" | "anyOf" | "oneOf" => {
+                let AnyJsonValue::JsonArrayValue(array) = value else {
+                    return false;
+                };
+                let schemas = array
+                    .elements()
+                    .iter()
+                    .flatten()
+                    .map(|value| SchemaNode::from_value(&value))
+                    .collect();
+                match name {
+                    "allOf" => self.all_of = schemas,
+                    "anyOf" => self.any_of = schemas,
+                    _ => self.one_of = schemas,
+                }
+            }
+            "not" => self.not = Some(SchemaNode::from_value(value)),
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// The types of the values that a schema accepts
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum InstanceType {
+    Null,
+    Boolean,
+    Object,
+    Array,
+    Number,
+    Integer,
+    String,
+}
+
+impl std::str::FromStr for InstanceType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "null" => Ok(Self::Null),
+            "boolean" => Ok(Self::Boolean),
+            "object" => Ok(Self::Object),
+            "array" => Ok(Self::Array),
+            "number" => Ok(Self::Number),
+            "integer" => Ok(Self::Integer),
+            "string" => Ok(Self::String),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for InstanceType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Null => "null",
+            Self::Boolean => "boolean",
+            Self::Object => "object",
+            Self::Array => "array",
+            Self::Number => "number",
+            Self::Integer => "integer",
+            Self::String => "string",
+        };
+        f.write_str(name)
+    }
+}
+
+/// An owned JSON value, used to compare the values of a document with
+/// the values of the `enum` and `const` keywords
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum JsonLiteral {
+    Null,
+    Boolean(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonLiteral>),
+    Object(Vec<(String, JsonLiteral)>),
+    /// A value that contains syntax errors, which never matches
+    Invalid,
+}
+
+impl JsonLiteral {
+    pub(crate) fn from_value(value: &AnyJsonValue) -> Self {
+        match value {
+            AnyJsonValue::JsonNullValue(_) => Self::Null,
+            AnyJsonValue::JsonBooleanValue(value) => match value.value_token() {
+                Ok(token) => Self::Boolean(token.kind() == T![true]),
+                Err(_) => Self::Invalid,
+            },
+            AnyJsonValue::JsonNumberValue(_) => number(value).map_or(Self::Invalid, Self::Number),
+            AnyJsonValue::JsonStringValue(_) => string(value).map_or(Self::Invalid, Self::String),
+            AnyJsonValue::JsonArrayValue(array) => Self::Array(
+                array
+                    .elements()
+                    .iter()
+                    .map(|value| value.map_or(Self::Invalid, |value| Self::from_value(&value)))
+                    .collect(),
+            ),
+            AnyJsonValue::JsonObjectValue(object) => {
+                let mut members: Vec<_> = members(object)
+                    .map(|(name, value)| (name, Self::from_value(&value)))
+                    .collect();
+                // The order of the properties doesn't matter
+                members.sort_by(|(a, _), (b, _)| a.cmp(b));
+                Self::Object(members)
+            }
+            AnyJsonValue::JsonLinesValue(lines) => Self::Array(
+                lines
+                    .records()
+                    .into_iter()
+                    .map(|value| Self::from_value(&value))
+                    .collect(),
+            ),
+            AnyJsonValue::JsonBogusValue(_) => Self::Invalid,
+        }
+    }
+}
+
+impl Display for JsonLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Null => f.write_str("null"),
+            Self::Boolean(value) => write!(f, "{value}"),
+            Self::Number(value) => write!(f, "{value}"),
+            Self::String(value) => write!(f, "\"{value}\""),
+            Self::Array(_) => f.write_str("[...]"),
+            Self::Object(_) => f.write_str("{...}"),
+            Self::Invalid => f.write_str("?"),
+        }
+    }
+}
+
+/// Returns the names and the values of the members of `object`, skipping the invalid members
+pub(crate) fn members(object: &JsonObjectValue) -> impl Iterator<Item = (String, AnyJsonValue)> {
+    object
+        .json_member_list()
+        .iter()
+        .flatten()
+        .filter_map(|member| {
+            let name = member.name().ok()?.inner_string_text().ok()?.to_string();
+            Some((name, member.value().ok()?))
+        })
+}
+
+pub(crate) fn string(value: &AnyJsonValue) -> Option<String> {
+    let AnyJsonValue::JsonStringValue(value) = value else {
+        return None;
+    };
+    Some(value.inner_string_text().ok()?.to_string())
+}
+
+pub(crate) fn number(value: &AnyJsonValue) -> Option<f64> {
+    let AnyJsonValue::JsonNumberValue(value) = value else {
+        return None;
+    };
+    value.value_token().ok()?.text_trimmed().parse().ok()
+}
+
+fn count(value: &AnyJsonValue) -> Option<usize> {
+    number(value)
+        .filter(|value| *value >= 0.0 && value.fract() == 0.0)
+        .map(|value| value as usize)
+}
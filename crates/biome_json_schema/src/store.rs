@@ -0,0 +1,169 @@
+use crate::schema::{schema_reference, JsonSchema};
+use biome_json_syntax::JsonRoot;
+use rustc_hash::FxHashMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+
+static PACKAGE_SCHEMA: LazyLock<Arc<JsonSchema>> = LazyLock::new(|| {
+    Arc::new(
+        JsonSchema::from_source(include_str!("schemas/package.schema.json"))
+            .expect("the bundled schema of package.json to be valid"),
+    )
+});
+
+static TSCONFIG_SCHEMA: LazyLock<Arc<JsonSchema>> = LazyLock::new(|| {
+    Arc::new(
+        JsonSchema::from_source(include_str!("schemas/tsconfig.schema.json"))
+            .expect("the bundled schema of tsconfig.json to be valid"),
+    )
+});
+
+/// The schemas known by a project, keyed by the path of the document that defines them.
+///
+/// The store associates a JSON document with its schema, in order of priority:
+/// 1. the schema referenced by the `$schema` property of the document;
+/// 2. the schema configured for the path of the document;
+/// 3. the schema bundled with Biome for well-known files, such as `package.json` and `tsconfig.json`.
+///
+/// Schemas are never fetched from the network: a `$schema` URL is only resolved
+/// when it points to a schema bundled with Biome.
+#[derive(Debug, Clone, Default)]
+pub struct JsonSchemaStore {
+    schemas: FxHashMap<PathBuf, Arc<JsonSchema>>,
+}
+
+impl JsonSchemaStore {
+    /// Records the schema defined by the document at `path`, replacing the previous entry, if any.
+    pub fn insert_schema(&mut self, path: &Path, schema: JsonSchema) {
+        self.schemas.insert(normalize(path), Arc::new(schema));
+    }
+
+    /// Forgets the schema defined by the document at `path`.
+    pub fn remove_schema(&mut self, path: &Path) -> Option<Arc<JsonSchema>> {
+        self.schemas.remove(&normalize(path))
+    }
+
+    /// Returns the number of documents that define a schema.
+    pub fn schema_count(&self) -> usize {
+        self.schemas.len()
+    }
+
+    /// Returns the schema defined by the document at `path`.
+    pub fn get_schema(&self, path: &Path) -> Option<Arc<JsonSchema>> {
+        self.schemas.get(&normalize(path)).cloned()
+    }
+
+    /// Returns the schema that the document `root`, located at `path`, must satisfy.
+    ///
+    /// `configured` is the path of the schema configured for the document, if any.
+    pub fn resolve(
+        &self,
+        path: &Path,
+        root: &JsonRoot,
+        configured: Option<&Path>,
+    ) -> Option<Arc<JsonSchema>> {
+        if let Some(reference) = schema_reference(root) {
+            return if is_url(&reference) {
+                bundled_schema_for_url(&reference)
+            } else {
+                let directory = path.parent().unwrap_or(Path::new(""));
+                self.get_schema(&directory.join(reference))
+            };
+        }
+        if let Some(configured) = configured {
+            return self.get_schema(configured);
+        }
+        path.file_name()
+            .and_then(|file_name| file_name.to_str())
+            .and_then(bundled_schema_for_file_name)
+    }
+}
+
+fn is_url(reference: &str) -> bool {
+    reference.starts_with("https://") || reference.starts_with("http://")
+}
+
+/// Returns the bundled schema of the SchemaStore URL `url`, e.g. `https://json.schemastore.org/package.json`
+fn bundled_schema_for_url(url: &str) -> Option<Arc<JsonSchema>> {
+    let (_, name) = url.split_once("://json.schemastore.org/")?;
+    match name.trim_end_matches(".json") {
+        "package" => Some(PACKAGE_SCHEMA.clone()),
+        "tsconfig" | "jsconfig" => Some(TSCONFIG_SCHEMA.clone()),
+        _ => None,
+    }
+}
+
+/// Returns the bundled schema of the well-known file named `file_name`
+fn bundled_schema_for_file_name(file_name: &str) -> Option<Arc<JsonSchema>> {
+    if file_name == "package.json" {
+        Some(PACKAGE_SCHEMA.clone())
+    } else if file_name == "jsconfig.json"
+        || (file_name.starts_with("tsconfig.") && file_name.ends_with(".json"))
+    {
+        Some(TSCONFIG_SCHEMA.clone())
+    } else {
+        None
+    }
+}
+
+/// Removes the `.` and `..` components of `path`, without accessing the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_json_parser::{parse_json, JsonParserOptions};
+
+    #[test]
+    fn resolves_relative_references_and_well_known_files() {
+        let mut store = JsonSchemaStore::default();
+        store.insert_schema(
+            Path::new("project/schemas/./app.json"),
+            JsonSchema::from_source(r#"{ "type": "object" }"#).unwrap(),
+        );
+
+        let parse = parse_json(
+            r#"{ "$schema": "../schemas/app.json" }"#,
+            JsonParserOptions::default(),
+        );
+        assert!(store
+            .resolve(Path::new("project/config/app.json"), &parse.tree(), None)
+            .is_some());
+
+        let parse = parse_json(r#"{ "name": "app" }"#, JsonParserOptions::default());
+        assert!(store
+            .resolve(Path::new("project/package.json"), &parse.tree(), None)
+            .is_some());
+        assert!(store
+            .resolve(
+                Path::new("project/tsconfig.build.json"),
+                &parse.tree(),
+                None
+            )
+            .is_some());
+        assert!(store
+            .resolve(Path::new("project/data.json"), &parse.tree(), None)
+            .is_none());
+        assert!(store
+            .resolve(
+                Path::new("project/data.json"),
+                &parse.tree(),
+                Some(Path::new("project/schemas/app.json"))
+            )
+            .is_some());
+    }
+}
@@ -0,0 +1,495 @@
+use crate::diagnostics::JsonSchemaDiagnostic;
+use crate::schema::{
+    members, number, string, InstanceType, JsonLiteral, JsonSchema, SchemaNode, SchemaObject,
+};
+use biome_console::markup;
+use biome_json_syntax::{AnyJsonValue, JsonObjectValue, JsonRoot};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+
+/// Validates the document `root` against `schema`, and returns a diagnostic for each violation.
+///
+/// The diagnostics of a value point to the value. The diagnostics about a missing property
+/// point to the name of the member that holds the object, or to the object itself
+/// if it's the root value.
+///
+/// Each record of a JSON Lines document is validated against `schema`.
+pub fn validate(schema: &JsonSchema, root: &JsonRoot) -> Vec<JsonSchemaDiagnostic> {
+    let Ok(value) = root.value() else {
+        return Vec::new();
+    };
+    let mut validator = Validator::new(schema);
+    if let AnyJsonValue::JsonLinesValue(lines) = &value {
+        for record in lines.records() {
+            validator.validate(schema.root(), &record, record.range());
+        }
+    } else {
+        validator.validate(schema.root(), &value, value.range());
+    }
+    validator.diagnostics
+}
+
+struct Validator<'a> {
+    schema: &'a JsonSchema,
+    diagnostics: Vec<JsonSchemaDiagnostic>,
+}
+
+impl<'a> Validator<'a> {
+    fn new(schema: &'a JsonSchema) -> Self {
+        Self {
+            schema,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if `value` satisfies `node`, without recording any diagnostic.
+    fn is_valid(&self, node: &SchemaNode, value: &AnyJsonValue) -> bool {
+        let mut validator = Validator::new(self.schema);
+        validator.validate(node, value, value.range());
+        validator.diagnostics.is_empty()
+    }
+
+    /// Validates `value` against `node`.
+    ///
+    /// `anchor` is the range that is reported for the violations that concern the value as a whole,
+    /// such as a missing property.
+    fn validate(&mut self, node: &SchemaNode, value: &AnyJsonValue, anchor: TextRange) {
+        if matches!(value, AnyJsonValue::JsonBogusValue(_)) {
+            return;
+        }
+        let schema = match self.schema.resolve(node) {
+            SchemaNode::Any => return,
+            SchemaNode::Nothing => {
+                self.diagnostics.push(JsonSchemaDiagnostic::new(
+                    value.range(),
+                    markup! { "No value is allowed here." },
+                ));
+                return;
+            }
+            SchemaNode::Object(schema) => schema,
+        };
+
+        if let Some(types) = &schema.types {
+            let actual = instance_type(value);
+            let matches = types.iter().any(|expected| {
+                *expected == actual
+                    || (*expected == InstanceType::Number && actual == InstanceType::Integer)
+            });
+            if !matches {
+                let expected = types
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" or ");
+                let actual = match actual {
+                    InstanceType::Integer => InstanceType::Number,
+                    actual => actual,
+                };
+                self.diagnostics.push(JsonSchemaDiagnostic::new(
+                    value.range(),
+                    markup! {
+                        "Expected a value of type "<Emphasis>{expected}</Emphasis>", but found "<Emphasis>{actual.to_string()}</Emphasis>"."
+                    },
+                ));
+                // The other keywords would report confusing errors
+                return;
+            }
+        }
+
+        self.validate_values(schema, value);
+
+        match value {
+            AnyJsonValue::JsonObjectValue(object) => self.validate_object(schema, object, anchor),
+            AnyJsonValue::JsonArrayValue(array) => {
+                let elements: Vec<_> = array.elements().iter().flatten().collect();
+                if let Some(items) = &schema.items {
+                    for element in &elements {
+                        self.validate(items, element, element.range());
+                    }
+                }
+                self.validate_bounds(
+                    value.range(),
+                    elements.len(),
+                    schema.min_items,
+                    schema.max_items,
+                    "items",
+                );
+            }
+            AnyJsonValue::JsonNumberValue(_) => self.validate_number(schema, value),
+            AnyJsonValue::JsonStringValue(_) => {
+                let Some(text) = string(value) else {
+                    return;
+                };
+                self.validate_bounds(
+                    value.range(),
+                    text.chars().count(),
+                    schema.min_length,
+                    schema.max_length,
+                    "characters",
+                );
+                if let Some(pattern) = &schema.pattern {
+                    if !pattern.is_match(&text) {
+                        self.diagnostics.push(JsonSchemaDiagnostic::new(
+                            value.range(),
+                            markup! {
+                                "The string doesn't match the pattern "<Emphasis>{pattern.as_str()}</Emphasis>"."
+                            },
+                        ));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        self.validate_combinators(schema, value, anchor);
+    }
+
+    /// Validates the `enum` and `const` keywords
+    fn validate_values(&mut self, schema: &SchemaObject, value: &AnyJsonValue) {
+        let literal = JsonLiteral::from_value(value);
+        if let Some(values) = &schema.enum_values {
+            if !values.contains(&literal) {
+                let allowed = values
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                self.diagnostics.push(
+                    JsonSchemaDiagnostic::new(
+                        value.range(),
+                        markup! { "The value isn't one of the allowed values." },
+                    )
+                    .with_note(markup! { "The allowed values are: "{allowed} }),
+                );
+            }
+        }
+        if let Some(expected) = &schema.const_value {
+            if *expected != literal {
+                self.diagnostics.push(JsonSchemaDiagnostic::new(
+                    value.range(),
+                    markup! { "Expected the value "<Emphasis>{expected.to_string()}</Emphasis>"." },
+                ));
+            }
+        }
+    }
+
+    fn validate_object(
+        &mut self,
+        schema: &SchemaObject,
+        object: &JsonObjectValue,
+        anchor: TextRange,
+    ) {
+        let mut names = Vec::new();
+        for member in object.json_member_list().iter().flatten() {
+            let (Ok(name), Ok(value)) = (member.name(), member.value()) else {
+                continue;
+            };
+            let Ok(text) = name.inner_string_text() else {
+                continue;
+            };
+            let text = text.to_string();
+            let name_range = name.range();
+
+            let mut is_known = false;
+            if let Some((_, property)) = schema.properties.iter().find(|(name, _)| *name == text) {
+                is_known = true;
+                self.validate(property, &value, name_range);
+            }
+            for (pattern, property) in &schema.pattern_properties {
+                if pattern.is_match(&text) {
+                    is_known = true;
+                    self.validate(property, &value, name_range);
+                }
+            }
+            if !is_known {
+                if let Some(additional) = &schema.additional_properties {
+                    if matches!(self.schema.resolve(additional), SchemaNode::Nothing) {
+                        self.diagnostics.push(JsonSchemaDiagnostic::new(
+                            name_range,
+                            markup! { "The property "<Emphasis>{text}</Emphasis>" isn't allowed." },
+                        ));
+                    } else {
+                        self.validate(additional, &value, name_range);
+                    }
+                }
+            }
+            names.push(text);
+        }
+
+        for required in &schema.required {
+            if !names.contains(required) {
+                self.diagnostics.push(JsonSchemaDiagnostic::new(
+                    anchor,
+                    markup! { "The property "<Emphasis>{required}</Emphasis>" is missing." },
+                ));
+            }
+        }
+    }
+
+    fn validate_number(&mut self, schema: &SchemaObject, value: &AnyJsonValue) {
+        let Some(number) = number(value) else {
+            return;
+        };
+        let violation = if let Some(minimum) = schema.minimum.filter(|minimum| number < *minimum) {
+            format!("The number must be greater than or equal to {minimum}.")
+        } else if let Some(maximum) = schema.maximum.filter(|maximum| number > *maximum) {
+            format!("The number must be less than or equal to {maximum}.")
+        } else if let Some(minimum) = schema
+            .exclusive_minimum
+            .filter(|minimum| number <= *minimum)
+        {
+            format!("The number must be greater than {minimum}.")
+        } else if let Some(maximum) = schema
+            .exclusive_maximum
+            .filter(|maximum| number >= *maximum)
+        {
+            format!("The number must be less than {maximum}.")
+        } else {
+            return;
+        };
+        self.diagnostics
+            .push(JsonSchemaDiagnostic::new(value.range(), violation));
+    }
+
+    /// Validates the `minItems`/`maxItems` and the `minLength`/`maxLength` keywords
+    fn validate_bounds(
+        &mut self,
+        range: TextRange,
+        count: usize,
+        min: Option<usize>,
+        max: Option<usize>,
+        unit: &str,
+    ) {
+        if let Some(min) = min.filter(|min| count < *min) {
+            self.diagnostics.push(JsonSchemaDiagnostic::new(
+                range,
+                markup! { "Expected at least "{min}" "{unit}", but found "{count}"." },
+            ));
+        }
+        if let Some(max) = max.filter(|max| count > *max) {
+            self.diagnostics.push(JsonSchemaDiagnostic::new(
+                range,
+                markup! { "Expected at most "{max}" "{unit}", but found "{count}"." },
+            ));
+        }
+    }
+
+    /// Validates the `allOf`, `anyOf`, `oneOf` and `not` keywords
+    fn validate_combinators(
+        &mut self,
+        schema: &SchemaObject,
+        value: &AnyJsonValue,
+        anchor: TextRange,
+    ) {
+        for node in &schema.all_of {
+            self.validate(node, value, anchor);
+        }
+
+        if !schema.any_of.is_empty() && !schema.any_of.iter().any(|node| self.is_valid(node, value))
+        {
+            self.report_no_match(&schema.any_of, value, anchor);
+        }
+
+        if !schema.one_of.is_empty() {
+            let matches = schema
+                .one_of
+                .iter()
+                .filter(|node| self.is_valid(node, value))
+                .count();
+            if matches == 0 {
+                self.report_no_match(&schema.one_of, value, anchor);
+            } else if matches > 1 {
+                self.diagnostics.push(JsonSchemaDiagnostic::new(
+                    value.range(),
+                    markup! { "The value matches more than one of the allowed schemas." },
+                ));
+            }
+        }
+
+        if let Some(not) = &schema.not {
+            if self.is_valid(not, value) {
+                self.diagnostics.push(JsonSchemaDiagnostic::new(
+                    value.range(),
+                    markup! { "The value matches a schema that isn't allowed." },
+                ));
+            }
+        }
+    }
+
+    /// Reports a value that doesn't match any of the `alternatives`.
+    ///
+    /// When a single alternative accepts the type of the value, its violations are more
+    /// helpful than a generic message, so they are reported instead.
+    fn report_no_match(
+        &mut self,
+        alternatives: &[SchemaNode],
+        value: &AnyJsonValue,
+        anchor: TextRange,
+    ) {
+        let schema = self.schema;
+        let actual = instance_type(value);
+        let mut candidates = alternatives
+            .iter()
+            .filter(|node| match schema.resolve(node) {
+                SchemaNode::Object(schema) => schema.types.as_ref().map_or(true, |types| {
+                    types.iter().any(|expected| {
+                        *expected == actual
+                            || (*expected == InstanceType::Number
+                                && actual == InstanceType::Integer)
+                    })
+                }),
+                SchemaNode::Any => true,
+                SchemaNode::Nothing => false,
+            });
+        if let (Some(candidate), None) = (candidates.next(), candidates.next()) {
+            self.validate(candidate, value, anchor);
+            return;
+        }
+
+        let expected = alternatives
+            .iter()
+            .filter_map(|node| match schema.resolve(node) {
+                SchemaNode::Object(schema) => schema.types.as_ref(),
+                _ => None,
+            })
+            .flatten()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let diagnostic = JsonSchemaDiagnostic::new(
+            value.range(),
+            markup! { "The value doesn't match any of the allowed schemas." },
+        );
+        self.diagnostics.push(if expected.is_empty() {
+            diagnostic
+        } else {
+            let mut expected = expected;
+            expected.dedup();
+            let expected = expected.join(", ");
+            diagnostic.with_note(markup! { "The allowed types are: "{expected} })
+        });
+    }
+}
+
+fn instance_type(value: &AnyJsonValue) -> InstanceType {
+    match value {
+        AnyJsonValue::JsonArrayValue(_) | AnyJsonValue::JsonLinesValue(_) => InstanceType::Array,
+        AnyJsonValue::JsonBooleanValue(_) => InstanceType::Boolean,
+        AnyJsonValue::JsonNullValue(_) => InstanceType::Null,
+        AnyJsonValue::JsonNumberValue(_) => match number(value) {
+            Some(number) if number.fract() == 0.0 => InstanceType::Integer,
+            _ => InstanceType::Number,
+        },
+        AnyJsonValue::JsonObjectValue(_) => InstanceType::Object,
+        AnyJsonValue::JsonStringValue(_) | AnyJsonValue::JsonBogusValue(_) => InstanceType::String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_diagnostics::PrintDescription;
+    use biome_json_parser::{parse_json, JsonParserOptions};
+
+    fn messages(schema: &str, source: &str) -> Vec<(String, String)> {
+        let schema = JsonSchema::from_source(schema).unwrap();
+        let parse = parse_json(source, JsonParserOptions::default());
+        validate(&schema, &parse.tree())
+            .into_iter()
+            .map(|diagnostic| {
+                let description = PrintDescription(&diagnostic).to_string();
+                let text = source[diagnostic.range()].to_string();
+                (description, text)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reports_types_and_required_properties() {
+        let schema = r#"{
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "version": { "type": "string" },
+                "private": { "type": "boolean" }
+            }
+        }"#;
+
+        assert_eq!(
+            messages(schema, r#"{ "version": 1, "private": true }"#),
+            vec![
+                (
+                    "Expected a value of type string, but found number.".to_string(),
+                    "1".to_string()
+                ),
+                (
+                    "The property name is missing.".to_string(),
+                    r#"{ "version": 1, "private": true }"#.to_string()
+                ),
+            ]
+        );
+        assert!(messages(schema, r#"{ "name": "a", "version": "1.0.0" }"#).is_empty());
+    }
+
+    #[test]
+    fn reports_additional_properties_and_enums() {
+        let schema = r##"{
+            "additionalProperties": false,
+            "properties": {
+                "type": { "enum": ["module", "commonjs"] },
+                "nested": { "$ref": "#/definitions/nested" }
+            },
+            "definitions": {
+                "nested": { "type": "array", "items": { "type": "integer", "minimum": 0 } }
+            }
+        }"##;
+
+        assert_eq!(
+            messages(
+                schema,
+                r#"{ "type": "esm", "nested": [1, -1], "other": 0 }"#
+            ),
+            vec![
+                (
+                    "The value isn't one of the allowed values.".to_string(),
+                    r#""esm""#.to_string()
+                ),
+                (
+                    "The number must be greater than or equal to 0.".to_string(),
+                    "-1".to_string()
+                ),
+                (
+                    "The property other isn't allowed.".to_string(),
+                    r#""other""#.to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_the_violations_of_the_matching_alternative() {
+        let schema = r#"{
+            "properties": {
+                "bin": {
+                    "anyOf": [
+                        { "type": "string" },
+                        { "type": "object", "additionalProperties": { "type": "string" } }
+                    ]
+                }
+            }
+        }"#;
+
+        assert_eq!(
+            messages(schema, r#"{ "bin": { "a": 1 } }"#),
+            vec![(
+                "Expected a value of type string, but found number.".to_string(),
+                "1".to_string()
+            )]
+        );
+        assert_eq!(
+            messages(schema, r#"{ "bin": true }"#),
+            vec![(
+                "The value doesn't match any of the allowed schemas.".to_string(),
+                "true".to_string()
+            )]
+        );
+    }
+}
@@ -13,6 +13,8 @@ use std::{
 pub struct JsonFileSource {
     allow_trailing_commas: bool,
     allow_comments: bool,
+    json5: bool,
+    json_lines: bool,
 }
 
 impl JsonFileSource {
@@ -143,6 +145,8 @@ impl JsonFileSource {
         Self {
             allow_comments: false,
             allow_trailing_commas: false,
+            json5: false,
+            json_lines: false,
         }
     }
 
@@ -150,6 +154,8 @@ impl JsonFileSource {
         Self {
             allow_comments: true,
             allow_trailing_commas: false,
+            json5: false,
+            json_lines: false,
         }
     }
 
@@ -157,6 +163,28 @@ impl JsonFileSource {
         Self {
             allow_comments: true,
             allow_trailing_commas: true,
+            json5: false,
+            json_lines: false,
+        }
+    }
+
+    /// [JSON5](https://spec.json5.org/) allows comments and trailing commas
+    pub fn json5() -> Self {
+        Self {
+            allow_comments: true,
+            allow_trailing_commas: true,
+            json5: true,
+            json_lines: false,
+        }
+    }
+
+    /// [JSON Lines](https://jsonlines.org/) contains a value per line
+    pub fn json_lines() -> Self {
+        Self {
+            allow_comments: false,
+            allow_trailing_commas: false,
+            json5: false,
+            json_lines: true,
         }
     }
 
@@ -181,6 +209,14 @@ impl JsonFileSource {
         self.allow_comments
     }
 
+    pub fn is_json5(&self) -> bool {
+        self.json5
+    }
+
+    pub fn is_json_lines(&self) -> bool {
+        self.json_lines
+    }
+
     pub fn is_well_known_json_file(file_name: &OsStr) -> bool {
         Self::WELL_KNOWN_JSON_FILES
             .binary_search(&file_name.as_encoded_bytes())
@@ -250,6 +286,9 @@ impl JsonFileSource {
             | b"sublime-workspace"
             | b"sublime_metrics"
             | b"sublime_session" => Ok(Self::json_allow_comments_and_trailing_commas()),
+            b"json5" => Ok(Self::json5()),
+            // https://jsonlines.org/
+            b"jsonl" | b"ndjson" => Ok(Self::json_lines()),
             _ => Err(FileSourceError::UnknownExtension),
         }
     }
@@ -267,6 +306,8 @@ impl JsonFileSource {
         match language_id {
             "json" => Ok(Self::json()),
             "jsonc" | "snippets" => Ok(Self::json_allow_comments_and_trailing_commas()),
+            "json5" => Ok(Self::json5()),
+            "jsonl" => Ok(Self::json_lines()),
             _ => Err(FileSourceError::UnknownLanguageId),
         }
     }
@@ -43,6 +43,8 @@ pub enum JsonSyntaxKind {
     JSON_MEMBER,
     JSON_MEMBER_NAME,
     JSON_ARRAY_ELEMENT_LIST,
+    JSON_LINES_VALUE,
+    JSON_RECORD_LIST,
     JSON_BOGUS,
     JSON_BOGUS_MEMBER_NAME,
     JSON_BOGUS_VALUE,
@@ -65,7 +67,7 @@ impl JsonSyntaxKind {
     }
     pub const fn is_list(self) -> bool {
         match self {
-            JSON_MEMBER_LIST | JSON_ARRAY_ELEMENT_LIST => true,
+            JSON_MEMBER_LIST | JSON_ARRAY_ELEMENT_LIST | JSON_RECORD_LIST => true,
             _ => false,
         }
     }
@@ -24,6 +24,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::JsonBooleanValue::new_unchecked(node) };
                     $body
                 }
+                $crate::JsonSyntaxKind::JSON_LINES_VALUE => {
+                    let $pattern = unsafe { $crate::JsonLinesValue::new_unchecked(node) };
+                    $body
+                }
                 $crate::JsonSyntaxKind::JSON_MEMBER => {
                     let $pattern = unsafe { $crate::JsonMember::new_unchecked(node) };
                     $body
@@ -68,6 +72,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::JsonMemberList::new_unchecked(node) };
                     $body
                 }
+                $crate::JsonSyntaxKind::JSON_RECORD_LIST => {
+                    let $pattern = unsafe { $crate::JsonRecordList::new_unchecked(node) };
+                    $body
+                }
                 _ => unreachable!(),
             },
         }
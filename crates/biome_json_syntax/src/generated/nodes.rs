@@ -103,6 +103,41 @@ pub struct JsonBooleanValueFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct JsonLinesValue {
+    pub(crate) syntax: SyntaxNode,
+}
+impl JsonLinesValue {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> JsonLinesValueFields {
+        JsonLinesValueFields {
+            records: self.records(),
+        }
+    }
+    pub fn records(&self) -> JsonRecordList {
+        support::list(&self.syntax, 0usize)
+    }
+}
+impl Serialize for JsonLinesValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct JsonLinesValueFields {
+    pub records: JsonRecordList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct JsonMember {
     pub(crate) syntax: SyntaxNode,
 }
@@ -382,6 +417,7 @@ pub enum AnyJsonValue {
     JsonArrayValue(JsonArrayValue),
     JsonBogusValue(JsonBogusValue),
     JsonBooleanValue(JsonBooleanValue),
+    JsonLinesValue(JsonLinesValue),
     JsonNullValue(JsonNullValue),
     JsonNumberValue(JsonNumberValue),
     JsonObjectValue(JsonObjectValue),
@@ -406,6 +442,12 @@ impl AnyJsonValue {
             _ => None,
         }
     }
+    pub fn as_json_lines_value(&self) -> Option<&JsonLinesValue> {
+        match &self {
+            AnyJsonValue::JsonLinesValue(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_json_null_value(&self) -> Option<&JsonNullValue> {
         match &self {
             AnyJsonValue::JsonNullValue(item) => Some(item),
@@ -518,6 +560,44 @@ impl From<JsonBooleanValue> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for JsonLinesValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(JSON_LINES_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == JSON_LINES_VALUE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for JsonLinesValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsonLinesValue")
+            .field("records", &self.records())
+            .finish()
+    }
+}
+impl From<JsonLinesValue> for SyntaxNode {
+    fn from(n: JsonLinesValue) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<JsonLinesValue> for SyntaxElement {
+    fn from(n: JsonLinesValue) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
 impl AstNode for JsonMember {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -829,6 +909,11 @@ impl From<JsonBooleanValue> for AnyJsonValue {
         AnyJsonValue::JsonBooleanValue(node)
     }
 }
+impl From<JsonLinesValue> for AnyJsonValue {
+    fn from(node: JsonLinesValue) -> AnyJsonValue {
+        AnyJsonValue::JsonLinesValue(node)
+    }
+}
 impl From<JsonNullValue> for AnyJsonValue {
     fn from(node: JsonNullValue) -> AnyJsonValue {
         AnyJsonValue::JsonNullValue(node)
@@ -854,6 +939,7 @@ impl AstNode for AnyJsonValue {
     const KIND_SET: SyntaxKindSet<Language> = JsonArrayValue::KIND_SET
         .union(JsonBogusValue::KIND_SET)
         .union(JsonBooleanValue::KIND_SET)
+        .union(JsonLinesValue::KIND_SET)
         .union(JsonNullValue::KIND_SET)
         .union(JsonNumberValue::KIND_SET)
         .union(JsonObjectValue::KIND_SET)
@@ -864,6 +950,7 @@ impl AstNode for AnyJsonValue {
             JSON_ARRAY_VALUE
                 | JSON_BOGUS_VALUE
                 | JSON_BOOLEAN_VALUE
+                | JSON_LINES_VALUE
                 | JSON_NULL_VALUE
                 | JSON_NUMBER_VALUE
                 | JSON_OBJECT_VALUE
@@ -875,6 +962,7 @@ impl AstNode for AnyJsonValue {
             JSON_ARRAY_VALUE => AnyJsonValue::JsonArrayValue(JsonArrayValue { syntax }),
             JSON_BOGUS_VALUE => AnyJsonValue::JsonBogusValue(JsonBogusValue { syntax }),
             JSON_BOOLEAN_VALUE => AnyJsonValue::JsonBooleanValue(JsonBooleanValue { syntax }),
+            JSON_LINES_VALUE => AnyJsonValue::JsonLinesValue(JsonLinesValue { syntax }),
             JSON_NULL_VALUE => AnyJsonValue::JsonNullValue(JsonNullValue { syntax }),
             JSON_NUMBER_VALUE => AnyJsonValue::JsonNumberValue(JsonNumberValue { syntax }),
             JSON_OBJECT_VALUE => AnyJsonValue::JsonObjectValue(JsonObjectValue { syntax }),
@@ -888,6 +976,7 @@ impl AstNode for AnyJsonValue {
             AnyJsonValue::JsonArrayValue(it) => &it.syntax,
             AnyJsonValue::JsonBogusValue(it) => &it.syntax,
             AnyJsonValue::JsonBooleanValue(it) => &it.syntax,
+            AnyJsonValue::JsonLinesValue(it) => &it.syntax,
             AnyJsonValue::JsonNullValue(it) => &it.syntax,
             AnyJsonValue::JsonNumberValue(it) => &it.syntax,
             AnyJsonValue::JsonObjectValue(it) => &it.syntax,
@@ -899,6 +988,7 @@ impl AstNode for AnyJsonValue {
             AnyJsonValue::JsonArrayValue(it) => it.syntax,
             AnyJsonValue::JsonBogusValue(it) => it.syntax,
             AnyJsonValue::JsonBooleanValue(it) => it.syntax,
+            AnyJsonValue::JsonLinesValue(it) => it.syntax,
             AnyJsonValue::JsonNullValue(it) => it.syntax,
             AnyJsonValue::JsonNumberValue(it) => it.syntax,
             AnyJsonValue::JsonObjectValue(it) => it.syntax,
@@ -912,6 +1002,7 @@ impl std::fmt::Debug for AnyJsonValue {
             AnyJsonValue::JsonArrayValue(it) => std::fmt::Debug::fmt(it, f),
             AnyJsonValue::JsonBogusValue(it) => std::fmt::Debug::fmt(it, f),
             AnyJsonValue::JsonBooleanValue(it) => std::fmt::Debug::fmt(it, f),
+            AnyJsonValue::JsonLinesValue(it) => std::fmt::Debug::fmt(it, f),
             AnyJsonValue::JsonNullValue(it) => std::fmt::Debug::fmt(it, f),
             AnyJsonValue::JsonNumberValue(it) => std::fmt::Debug::fmt(it, f),
             AnyJsonValue::JsonObjectValue(it) => std::fmt::Debug::fmt(it, f),
@@ -925,6 +1016,7 @@ impl From<AnyJsonValue> for SyntaxNode {
             AnyJsonValue::JsonArrayValue(it) => it.into(),
             AnyJsonValue::JsonBogusValue(it) => it.into(),
             AnyJsonValue::JsonBooleanValue(it) => it.into(),
+            AnyJsonValue::JsonLinesValue(it) => it.into(),
             AnyJsonValue::JsonNullValue(it) => it.into(),
             AnyJsonValue::JsonNumberValue(it) => it.into(),
             AnyJsonValue::JsonObjectValue(it) => it.into(),
@@ -953,6 +1045,11 @@ impl std::fmt::Display for JsonBooleanValue {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for JsonLinesValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for JsonMember {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -1264,6 +1361,88 @@ impl IntoIterator for &JsonMemberList {
         self.iter()
     }
 }
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct JsonRecordList {
+    syntax_list: SyntaxList,
+}
+impl JsonRecordList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for JsonRecordList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(JSON_RECORD_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == JSON_RECORD_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<JsonRecordList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(JsonRecordList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for JsonRecordList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for JsonRecordList {
+    type Language = Language;
+    type Node = AnyJsonValue;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for JsonRecordList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("JsonRecordList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &JsonRecordList {
+    type Item = AnyJsonValue;
+    type IntoIter = AstNodeListIterator<Language, AnyJsonValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for JsonRecordList {
+    type Item = AnyJsonValue;
+    type IntoIter = AstNodeListIterator<Language, AnyJsonValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
 #[derive(Clone)]
 pub struct DebugSyntaxElementChildren(pub SyntaxElementChildren);
 impl Debug for DebugSyntaxElementChildren {
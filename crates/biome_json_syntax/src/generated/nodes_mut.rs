@@ -31,6 +31,14 @@ impl JsonBooleanValue {
         )
     }
 }
+impl JsonLinesValue {
+    pub fn with_records(self, element: JsonRecordList) -> Self {
+        Self::unwrap_cast(
+            self.syntax
+                .splice_slots(0usize..=0usize, once(Some(element.into_syntax().into()))),
+        )
+    }
+}
 impl JsonMember {
     pub fn with_name(self, element: JsonMemberName) -> Self {
         Self::unwrap_cast(
@@ -1,9 +1,17 @@
-use biome_analyze::SUPPRESSION_ACTION_CATEGORY;
+use crate::handlers::semantic_tokens::semantic_tokens_legend;
+use crate::handlers::{code_lens, completion, signature_help};
+use crate::requests::resolve_configuration::RESOLVE_CONFIGURATION_COMMAND;
+use biome_analyze::{ActionCategory, SourceActionKind, SUPPRESSION_ACTION_CATEGORY};
 use biome_lsp_converters::{negotiated_encoding, PositionEncoding, WideEncoding};
+use biome_service::documentation::assist_rules_metadata;
+use std::borrow::Cow;
 use tower_lsp::lsp_types::{
     ClientCapabilities, CodeActionKind, CodeActionOptions, CodeActionProviderCapability,
-    DocumentOnTypeFormattingOptions, OneOf, PositionEncodingKind, ServerCapabilities,
-    TextDocumentSyncCapability, TextDocumentSyncKind,
+    CodeLensOptions, CompletionOptions, DiagnosticOptions, DiagnosticServerCapabilities,
+    DocumentOnTypeFormattingOptions, ExecuteCommandOptions, FoldingRangeProviderCapability,
+    HoverProviderCapability, OneOf, PositionEncodingKind, SelectionRangeProviderCapability,
+    SemanticTokensFullOptions, SemanticTokensOptions, SemanticTokensServerCapabilities,
+    ServerCapabilities, SignatureHelpOptions, TextDocumentSyncCapability, TextDocumentSyncKind,
 };
 
 /// The capabilities to send from server as part of [`InitializeResult`]
@@ -58,23 +66,32 @@ pub(crate) fn server_capabilities(capabilities: &ClientCapabilities) -> ServerCa
         .and_then(|text_document| text_document.code_action.as_ref())
         .and_then(|code_action| code_action.code_action_literal_support.as_ref())
         .map(|_| {
+            let mut code_action_kinds = vec![
+                CodeActionKind::from("quickfix.biome"),
+                // quickfix.suppressRule
+                CodeActionKind::from(SUPPRESSION_ACTION_CATEGORY),
+                CodeActionKind::from("source.fixAll.biome"),
+                CodeActionKind::from("source.organizeImports.biome"),
+                CodeActionKind::from("refactor.biome"),
+                CodeActionKind::from("refactor.extract.biome"),
+                CodeActionKind::from("refactor.inline.biome"),
+                CodeActionKind::from("refactor.rewrite.biome"),
+            ];
+            code_action_kinds.extend(assist_action_kinds());
             CodeActionOptions {
-                code_action_kinds: Some(vec![
-                    CodeActionKind::from("quickfix.biome"),
-                    // quickfix.suppressRule
-                    CodeActionKind::from(SUPPRESSION_ACTION_CATEGORY),
-                    CodeActionKind::from("source.fixAll.biome"),
-                    CodeActionKind::from("source.organizeImports.biome"),
-                    CodeActionKind::from("refactor.biome"),
-                    CodeActionKind::from("refactor.extract.biome"),
-                    CodeActionKind::from("refactor.inline.biome"),
-                    CodeActionKind::from("refactor.rewrite.biome"),
-                ]),
+                code_action_kinds: Some(code_action_kinds),
                 ..Default::default()
             }
             .into()
         })
         .or(Some(CodeActionProviderCapability::Simple(true)));
+
+    // The diagnostics are pushed to the clients that can't pull them
+    let supports_pull_diagnostics = capabilities
+        .text_document
+        .as_ref()
+        .is_some_and(|text_document| text_document.diagnostic.is_some());
+
     ServerCapabilities {
         position_encoding: Some(match negotiated_encoding(capabilities) {
             PositionEncoding::Utf8 => PositionEncodingKind::UTF8,
@@ -91,6 +108,69 @@ pub(crate) fn server_capabilities(capabilities: &ClientCapabilities) -> ServerCa
         document_on_type_formatting_provider: supports_on_type_formatter_dynamic_registration,
         code_action_provider,
         rename_provider: None,
+        definition_provider: Some(OneOf::Left(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        workspace_symbol_provider: Some(OneOf::Left(true)),
+        diagnostic_provider: supports_pull_diagnostics.then(|| {
+            DiagnosticServerCapabilities::Options(DiagnosticOptions {
+                identifier: Some("biome".to_string()),
+                inter_file_dependencies: false,
+                workspace_diagnostics: false,
+                ..Default::default()
+            })
+        }),
+        inlay_hint_provider: Some(OneOf::Left(true)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(
+                completion::TRIGGER_CHARACTERS
+                    .iter()
+                    .map(|character| character.to_string())
+                    .collect(),
+            ),
+            ..Default::default()
+        }),
+        signature_help_provider: Some(SignatureHelpOptions {
+            trigger_characters: Some(
+                signature_help::TRIGGER_CHARACTERS
+                    .iter()
+                    .map(|character| character.to_string())
+                    .collect(),
+            ),
+            ..Default::default()
+        }),
+        folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+        selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
+        code_lens_provider: Some(CodeLensOptions {
+            resolve_provider: Some(false),
+        }),
+        execute_command_provider: Some(ExecuteCommandOptions {
+            commands: code_lens::commands()
+                .into_iter()
+                .chain([RESOLVE_CONFIGURATION_COMMAND.to_string()])
+                .collect(),
+            ..Default::default()
+        }),
+        semantic_tokens_provider: Some(SemanticTokensServerCapabilities::SemanticTokensOptions(
+            SemanticTokensOptions {
+                legend: semantic_tokens_legend(),
+                full: Some(SemanticTokensFullOptions::Bool(true)),
+                ..Default::default()
+            },
+        )),
         ..Default::default()
     }
 }
+
+/// The kinds of the source actions of the assist rules, such as `source.action.useSortedKeys.biome`,
+/// so that editors can run each of them on save
+fn assist_action_kinds() -> impl Iterator<Item = CodeActionKind> {
+    assist_rules_metadata()
+        .into_keys()
+        // The imports are organized by the `source.organizeImports.biome` action
+        .filter(|name| *name != "organizeImports")
+        .map(|name| {
+            let category = ActionCategory::Source(SourceActionKind::Action(Cow::Borrowed(name)));
+            CodeActionKind::from(category.to_str().into_owned())
+        })
+}
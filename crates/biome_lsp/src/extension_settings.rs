@@ -21,6 +21,9 @@ pub struct WorkspaceSettings {
 
     /// Experimental settings
     pub experimental: Option<ExperimentalSettings>,
+
+    /// The inlay hints shown in the editor
+    pub inlay_hints: Option<InlayHintsSettings>,
 }
 
 #[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq, Eq)]
@@ -30,6 +33,16 @@ pub struct ExperimentalSettings {
     pub rename: Option<bool>,
 }
 
+#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct InlayHintsSettings {
+    /// Show the names of the parameters before the arguments of the calls. Enabled by default
+    pub parameter_names: Option<bool>,
+
+    /// Show the types inferred for the `const` declarations. Enabled by default
+    pub variable_types: Option<bool>,
+}
+
 /// The `biome.*` extension settings
 #[derive(Debug)]
 pub(crate) struct ExtensionSettings {
@@ -68,4 +81,20 @@ impl ExtensionSettings {
     pub(crate) fn requires_configuration(&self) -> bool {
         self.settings.require_configuration.unwrap_or_default()
     }
+
+    pub(crate) fn parameter_name_hints_enabled(&self) -> bool {
+        self.settings
+            .inlay_hints
+            .as_ref()
+            .and_then(|inlay_hints| inlay_hints.parameter_names)
+            .unwrap_or(true)
+    }
+
+    pub(crate) fn variable_type_hints_enabled(&self) -> bool {
+        self.settings
+            .inlay_hints
+            .as_ref()
+            .and_then(|inlay_hints| inlay_hints.variable_types)
+            .unwrap_or(true)
+    }
 }
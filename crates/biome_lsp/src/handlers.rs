@@ -1,4 +1,13 @@
 pub(crate) mod analysis;
+pub(crate) mod code_lens;
+pub(crate) mod completion;
+pub(crate) mod definition;
 pub(crate) mod formatting;
+pub(crate) mod hover;
+pub(crate) mod inlay_hints;
+pub(crate) mod ranges;
 pub(crate) mod rename;
+pub(crate) mod semantic_tokens;
+pub(crate) mod signature_help;
+pub(crate) mod symbols;
 pub(crate) mod text_document;
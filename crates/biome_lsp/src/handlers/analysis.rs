@@ -247,10 +247,11 @@ fn fix_all(
         only: vec![],
         skip: vec![],
         suppression_reason: None,
+        // The assists have their own kinds, such as `source.organizeImports.biome`, so that
+        // editors can choose the ones that run on save
         rule_categories: RuleCategoriesBuilder::default()
             .with_syntax()
             .with_lint()
-            .with_action()
             .build(),
     })?;
 
@@ -0,0 +1,438 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::{anyhow, Context};
+use biome_json_parser::{parse_json, JsonParserOptions};
+use biome_json_syntax::{AnyJsonValue, JsonArrayValue, JsonMember, JsonObjectValue, JsonRoot};
+use biome_lsp_converters::line_index::LineIndex;
+use biome_lsp_converters::to_proto;
+use biome_rowan::{AstNode, AstSeparatedList, TextRange, TextSize};
+use biome_service::documentation::lint_rules_metadata;
+use biome_service::workspace::GetFileContentParams;
+use biome_suppression::parse_suppression_comment;
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+use serde_json::ser::PrettyFormatter;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::LazyLock;
+use tower_lsp::lsp_types::{
+    CodeLens, CodeLensParams, Command, ExecuteCommandParams, NumberOrString, TextEdit, Url,
+    WorkspaceEdit,
+};
+
+/// Turns a rule off for a file, by adding an override to the configuration file
+pub(crate) const DISABLE_RULE_FOR_FILE_COMMAND: &str = "biome.disableRuleForFile";
+
+/// Turns a rule off for all the files, in the configuration file
+pub(crate) const DISABLE_RULE_COMMAND: &str = "biome.disableRule";
+
+/// The openers of the comments that can hold a suppression
+pub(crate) const COMMENT_OPENERS: [&str; 4] = ["//", "/*", "<!--", "#"];
+
+/// The first line of the documentation of each lint rule, by name
+pub(crate) static RULE_SUMMARIES: LazyLock<BTreeMap<&'static str, &'static str>> =
+    LazyLock::new(|| {
+        lint_rules_metadata()
+            .into_iter()
+            .filter_map(|(name, metadata)| {
+                let summary = metadata
+                    .last()?
+                    .docs
+                    .lines()
+                    .find(|line| !line.trim().is_empty())?;
+                Some((name, summary.trim()))
+            })
+            .collect()
+    });
+
+/// The commands run by the code lenses, executed by the server
+pub(crate) fn commands() -> Vec<String> {
+    vec![
+        DISABLE_RULE_FOR_FILE_COMMAND.to_string(),
+        DISABLE_RULE_COMMAND.to_string(),
+    ]
+}
+
+/// Shows the summary of the rules suppressed by the `biome-ignore` comments, and offers to
+/// turn off the rules of the lint diagnostics, for the file or for all the files.
+///
+/// The rules can only be turned off when the file has a configuration file.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) async fn code_lens(
+    session: &Session,
+    params: CodeLensParams,
+) -> Result<Option<Vec<CodeLens>>, LspError> {
+    let url = params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+
+    let content = session.workspace.get_file_content(GetFileContentParams {
+        path: biome_path.clone(),
+    })?;
+    let mut lenses = Vec::new();
+    for (range, rules) in suppression_comments(&content) {
+        let range = to_proto::range(&doc.line_index, range, position_encoding)?;
+        for rule in rules {
+            let Some(summary) = RULE_SUMMARIES.get(rule) else {
+                continue;
+            };
+            lenses.push(CodeLens {
+                range,
+                // The lens only shows the summary, it doesn't run anything
+                command: Some(Command {
+                    title: format!("{rule}: {summary}"),
+                    command: String::new(),
+                    arguments: None,
+                }),
+                data: None,
+            });
+        }
+    }
+
+    let Some(configuration_path) = session.configuration_file_path(&biome_path) else {
+        return Ok(Some(lenses));
+    };
+    let configuration_name = configuration_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let mut disabled_rules = FxHashSet::default();
+    for diagnostic in session.compute_diagnostics(&url, &doc).await? {
+        let Some(NumberOrString::String(category)) = diagnostic.code else {
+            continue;
+        };
+        let Some((_, rule)) = lint_rule(&category) else {
+            continue;
+        };
+        // The rules are only offered once per line
+        if !disabled_rules.insert((diagnostic.range.start.line, rule.to_string())) {
+            continue;
+        }
+
+        let arguments = vec![json!(url), json!(category)];
+        lenses.push(CodeLens {
+            range: diagnostic.range,
+            command: Some(Command {
+                title: format!("Disable {rule} for this file"),
+                command: DISABLE_RULE_FOR_FILE_COMMAND.to_string(),
+                arguments: Some(arguments.clone()),
+            }),
+            data: None,
+        });
+        lenses.push(CodeLens {
+            range: diagnostic.range,
+            command: Some(Command {
+                title: format!("Disable {rule} in {configuration_name}"),
+                command: DISABLE_RULE_COMMAND.to_string(),
+                arguments: Some(arguments),
+            }),
+            data: None,
+        });
+    }
+
+    Ok(Some(lenses))
+}
+
+/// Runs the commands of the code lenses, by asking the client to apply an edit of the
+/// configuration file
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) async fn execute_command(
+    session: &Session,
+    params: ExecuteCommandParams,
+) -> Result<Option<Value>, LspError> {
+    let for_file = match params.command.as_str() {
+        DISABLE_RULE_FOR_FILE_COMMAND => true,
+        DISABLE_RULE_COMMAND => false,
+        command => return Err(anyhow!("Unknown command {command}").into()),
+    };
+    let [url, category] = params.arguments.as_slice() else {
+        return Err(anyhow!("The command {} expects two arguments", params.command).into());
+    };
+    let url: Url = serde_json::from_value(url.clone()).context("Invalid document URL")?;
+    let category: String =
+        serde_json::from_value(category.clone()).context("Invalid rule category")?;
+    let Some((group, rule)) = lint_rule(&category) else {
+        return Err(anyhow!("{category} isn't a lint rule").into());
+    };
+
+    let biome_path = session.file_path(&url)?;
+    let configuration_path = session
+        .configuration_file_path(&biome_path)
+        .context("The file doesn't have a configuration file")?;
+    let content = session
+        .fs
+        .read_file_from_path(&configuration_path)
+        .map_err(biome_diagnostics::Error::from)?;
+    let options = JsonParserOptions::default()
+        .with_allow_comments()
+        .with_allow_trailing_commas();
+    let root = parse_json(&content, options).tree();
+
+    let edit = if for_file {
+        // The globs of the overrides are relative to the directory of the configuration file
+        let directory = configuration_path.parent().unwrap_or(&configuration_path);
+        let include = biome_path
+            .strip_prefix(directory)
+            .unwrap_or(&biome_path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        disable_rule_for_file(&root, group, rule, &include)
+    } else {
+        disable_rule(&root, group, rule)
+    };
+    let Some((range, new_text)) = edit else {
+        return Err(anyhow!("The configuration file isn't an object").into());
+    };
+
+    let line_index = LineIndex::new(&content);
+    let range = to_proto::range(&line_index, range, session.position_encoding())?;
+    let configuration_url = Url::from_file_path(&configuration_path)
+        .map_err(|_| anyhow!("Invalid configuration path {configuration_path:?}"))?;
+    let edit = WorkspaceEdit {
+        changes: Some(HashMap::from([(
+            configuration_url,
+            vec![TextEdit { range, new_text }],
+        )])),
+        ..WorkspaceEdit::default()
+    };
+
+    let response = session
+        .client
+        .apply_edit(edit)
+        .await
+        .context("Failed to edit the configuration file")?;
+    if !response.applied {
+        let reason = response.failure_reason.unwrap_or_default();
+        return Err(anyhow!("The client didn't edit the configuration file: {reason}").into());
+    }
+
+    Ok(None)
+}
+
+/// Returns the group and the name of a rule from the category of its diagnostics,
+/// for example `lint/suspicious/noExplicitAny`
+fn lint_rule(category: &str) -> Option<(&str, &str)> {
+    let (group, rule) = category.strip_prefix("lint/")?.split_once('/')?;
+    Some((group, rule))
+}
+
+/// Returns the range of the suppression comments of a file, with the names of the rules
+/// they suppress.
+///
+/// Only the comments that start on the same line as the suppression are found.
+fn suppression_comments(content: &str) -> Vec<(TextRange, Vec<&'static str>)> {
+    let mut comments = Vec::new();
+    let mut line_start = 0;
+    for line in content.split_inclusive('\n') {
+        let start = line_start;
+        line_start += line.len();
+
+        let Some(index) = line.find("biome-ignore") else {
+            continue;
+        };
+        // Only whitespace can separate the opener of the comment and the suppression
+        let before = line[..index].trim_end();
+        let Some(opener) = COMMENT_OPENERS
+            .iter()
+            .find(|opener| before.ends_with(*opener))
+        else {
+            continue;
+        };
+        let comment_start = before.len() - opener.len();
+        let comment = line[comment_start..].trim_end();
+
+        let rules: Vec<_> = parse_suppression_comment(comment)
+            .filter_map(Result::ok)
+            .flat_map(|suppression| suppression.categories)
+            .filter_map(|(category, _)| {
+                let (_, rule) = lint_rule(category.name())?;
+                Some(rule)
+            })
+            .collect();
+        if !rules.is_empty() {
+            let range = TextRange::at(
+                TextSize::from((start + comment_start) as u32),
+                TextSize::from(comment.len() as u32),
+            );
+            comments.push((range, rules));
+        }
+    }
+    comments
+}
+
+/// Returns the edit of the configuration file that turns a rule off for all the files
+fn disable_rule(root: &JsonRoot, group: &str, rule: &str) -> Option<(TextRange, String)> {
+    let object = root.value().ok()?;
+    let object = object.as_json_object_value()?;
+    set_member(object, &["linter", "rules", group, rule], json!("off"), 1)
+}
+
+/// Returns the edit of the configuration file that adds an override turning a rule off
+/// for the files that match `include`
+fn disable_rule_for_file(
+    root: &JsonRoot,
+    group: &str,
+    rule: &str,
+    include: &str,
+) -> Option<(TextRange, String)> {
+    let object = root.value().ok()?;
+    let object = object.as_json_object_value()?;
+
+    let mut override_pattern = Map::new();
+    override_pattern.insert("include".to_string(), json!([include]));
+    override_pattern.insert(
+        "linter".to_string(),
+        nest(&["rules", group, rule], json!("off")),
+    );
+    let override_pattern = Value::Object(override_pattern);
+
+    // The override is added after the other overrides, so that it takes precedence
+    match find_member(object, "overrides").and_then(|member| member.value().ok()) {
+        Some(AnyJsonValue::JsonArrayValue(overrides)) => {
+            append_element(&overrides, &override_pattern, 2)
+        }
+        _ => set_member(object, &["overrides"], json!([override_pattern]), 1),
+    }
+}
+
+/// Returns the edit that sets the member at `path` to `value`, creating the missing objects.
+///
+/// `depth` is the nesting depth of the members of `object`, used to guess the indentation
+/// of the file.
+fn set_member(
+    object: &JsonObjectValue,
+    path: &[&str],
+    value: Value,
+    depth: usize,
+) -> Option<(TextRange, String)> {
+    let (name, rest) = path.split_first()?;
+    let Some(member) = find_member(object, name) else {
+        return insert_member(object, name, &nest(rest, value), depth);
+    };
+
+    match member.value().ok()? {
+        AnyJsonValue::JsonObjectValue(child) if !rest.is_empty() => {
+            set_member(&child, rest, value, depth + 1)
+        }
+        // The rules configured with an object lose their options
+        member_value => {
+            let indentation = line_indentation(member.syntax());
+            let value = format_json(&nest(rest, value), indentation.as_deref(), depth);
+            Some((member_value.range(), value))
+        }
+    }
+}
+
+/// Returns the edit that adds a member before the other members of `object`
+fn insert_member(
+    object: &JsonObjectValue,
+    name: &str,
+    value: &Value,
+    depth: usize,
+) -> Option<(TextRange, String)> {
+    let members = object.json_member_list();
+    let first = members.first().and_then(Result::ok);
+    let indentation = first
+        .as_ref()
+        .and_then(|first| line_indentation(first.syntax()));
+    let member = format!(
+        "{}: {}",
+        Value::from(name),
+        format_json(value, indentation.as_deref(), depth)
+    );
+
+    let Some(first) = first else {
+        let offset = object.l_curly_token().ok()?.text_trimmed_range().end();
+        return Some((TextRange::empty(offset), member));
+    };
+    let separator = match indentation {
+        Some(indentation) => format!(",\n{indentation}"),
+        None => ", ".to_string(),
+    };
+    Some((
+        TextRange::empty(first.range().start()),
+        format!("{member}{separator}"),
+    ))
+}
+
+/// Returns the edit that adds an element after the other elements of `array`
+fn append_element(
+    array: &JsonArrayValue,
+    value: &Value,
+    depth: usize,
+) -> Option<(TextRange, String)> {
+    let elements = array.elements();
+    let indentation = elements
+        .first()
+        .and_then(Result::ok)
+        .and_then(|first| line_indentation(first.syntax()));
+    let element = format_json(value, indentation.as_deref(), depth);
+    let separator = match indentation {
+        Some(indentation) => format!("\n{indentation}"),
+        None => " ".to_string(),
+    };
+
+    if let Some(trailing_separator) = elements.trailing_separator() {
+        let offset = trailing_separator.text_trimmed_range().end();
+        Some((TextRange::empty(offset), format!("{separator}{element},")))
+    } else if let Some(last) = elements.last().and_then(Result::ok) {
+        let offset = last.range().end();
+        Some((TextRange::empty(offset), format!(",{separator}{element}")))
+    } else {
+        let offset = array.l_brack_token().ok()?.text_trimmed_range().end();
+        Some((TextRange::empty(offset), element))
+    }
+}
+
+fn find_member(object: &JsonObjectValue, name: &str) -> Option<JsonMember> {
+    object.json_member_list().iter().flatten().find(|member| {
+        member
+            .name()
+            .and_then(|member_name| member_name.inner_string_text())
+            .is_ok_and(|member_name| member_name.text() == name)
+    })
+}
+
+/// Wraps `value` in an object for each name of `path`
+fn nest(path: &[&str], value: Value) -> Value {
+    path.iter().rev().fold(value, |value, name| {
+        let mut object = Map::new();
+        object.insert(name.to_string(), value);
+        Value::Object(object)
+    })
+}
+
+/// Returns the whitespace before a node, when the node starts a line
+fn line_indentation(node: &biome_json_syntax::JsonSyntaxNode) -> Option<String> {
+    let trivia: String = node
+        .first_token()?
+        .leading_trivia()
+        .pieces()
+        .map(|piece| piece.text().to_string())
+        .collect();
+    let (_, indentation) = trivia.rsplit_once('\n')?;
+    Some(indentation.to_string())
+}
+
+/// Prints `value` on several lines indented like the file, when the members at `depth`
+/// are indented with `indentation`, or on a single line otherwise
+fn format_json(value: &Value, indentation: Option<&str>, depth: usize) -> String {
+    let Some(indentation) = indentation else {
+        return value.to_string();
+    };
+    let indent_unit = indentation
+        .get(..indentation.len() / depth.max(1))
+        .unwrap_or_default();
+
+    let mut buffer = Vec::new();
+    let mut serializer = serde_json::Serializer::with_formatter(
+        &mut buffer,
+        PrettyFormatter::with_indent(indent_unit.as_bytes()),
+    );
+    if value.serialize(&mut serializer).is_err() {
+        return value.to_string();
+    }
+    String::from_utf8_lossy(&buffer).replace('\n', &format!("\n{indentation}"))
+}
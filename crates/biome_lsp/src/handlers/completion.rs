@@ -0,0 +1,310 @@
+use crate::diagnostics::LspError;
+use crate::handlers::code_lens::{COMMENT_OPENERS, RULE_SUMMARIES};
+use crate::session::Session;
+use biome_configuration::analyzer::RuleGroup;
+use biome_configuration::PartialConfiguration;
+use biome_json_parser::{parse_json, JsonParserOptions};
+use biome_json_schema::{JsonPathSegment, JsonSchema};
+use biome_json_syntax::{JsonMember, JsonObjectValue, JsonSyntaxKind, JsonSyntaxNode, T};
+use biome_lsp_converters::{from_proto, to_proto};
+use biome_rowan::{AstNode, AstSeparatedList, Direction, TextRange, TextSize};
+use biome_service::workspace::GetFileContentParams;
+use std::sync::LazyLock;
+use tower_lsp::lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionParams, CompletionResponse, CompletionTextEdit,
+    TextEdit,
+};
+
+/// The characters that open a completion, besides the identifiers: the quotes of the
+/// properties of the configuration file, and the separators of the suppressed categories
+pub(crate) const TRIGGER_CHARACTERS: [&str; 2] = ["\"", "/"];
+
+/// The schema of the configuration file, generated from the types of the configuration so
+/// that it's always in sync with the rules and the options
+static CONFIGURATION_SCHEMA: LazyLock<Option<JsonSchema>> = LazyLock::new(|| {
+    let schema = serde_json::to_string(&schemars::schema_for!(PartialConfiguration)).ok()?;
+    JsonSchema::from_source(&schema)
+});
+
+/// A completion, which replaces `range` with `new_text`
+struct Completion {
+    label: String,
+    kind: CompletionItemKind,
+    detail: Option<String>,
+    range: TextRange,
+    new_text: String,
+}
+
+/// Completes the categories of the `biome-ignore` comments, and the properties and the values
+/// of the configuration file
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn completion(
+    session: &Session,
+    params: CompletionParams,
+) -> Result<Option<CompletionResponse>, LspError> {
+    let url = params.text_document_position.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(
+        &doc.line_index,
+        params.text_document_position.position,
+        position_encoding,
+    )?;
+    let content = session.workspace.get_file_content(GetFileContentParams {
+        path: biome_path.clone(),
+    })?;
+
+    let completions = match suppression_completions(&content, offset) {
+        Some(completions) => completions,
+        None if biome_path.is_config() => configuration_completions(&content, offset),
+        None => return Ok(None),
+    };
+
+    let mut items = Vec::with_capacity(completions.len());
+    for completion in completions {
+        let range = to_proto::range(&doc.line_index, completion.range, position_encoding)?;
+        items.push(CompletionItem {
+            label: completion.label,
+            kind: Some(completion.kind),
+            detail: completion.detail,
+            // The editors filter the items with the text of the range, quotes included
+            filter_text: Some(completion.new_text.clone()),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                range,
+                new_text: completion.new_text,
+            })),
+            ..CompletionItem::default()
+        });
+    }
+
+    Ok(Some(CompletionResponse::Array(items)))
+}
+
+/// Returns the completions of the suppression comment under the cursor, with the syntax
+/// `biome-ignore <category>+: <explanation>`, or `None` if the cursor isn't in a suppression.
+///
+/// The categories are completed until the colon, which is offered once a category is written.
+fn suppression_completions(content: &str, offset: TextSize) -> Option<Vec<Completion>> {
+    let offset = usize::from(offset);
+    let line_start = content[..offset].rfind('\n').map_or(0, |index| index + 1);
+    let line = &content[line_start..offset];
+
+    let index = line.find("biome-ignore")?;
+    // Only whitespace can separate the opener of the comment and the suppression
+    let before = line[..index].trim_end();
+    if !COMMENT_OPENERS
+        .iter()
+        .any(|opener| before.ends_with(opener))
+    {
+        return None;
+    }
+
+    let suppression = &line[index + "biome-ignore".len()..];
+    // The explanation follows the colon, and the values of the categories aren't known
+    if !suppression.starts_with(char::is_whitespace) || suppression.contains([':', '(']) {
+        return Some(Vec::new());
+    }
+    let word = suppression
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or_default();
+    let range = TextRange::new(
+        TextSize::from((offset - word.len()) as u32),
+        TextSize::from(offset as u32),
+    );
+    let category = |name: String, detail: String| Completion {
+        label: name.clone(),
+        kind: CompletionItemKind::VALUE,
+        detail: Some(detail),
+        range,
+        new_text: name,
+    };
+
+    let mut completions = Vec::new();
+    if word.is_empty() && !suppression.trim().is_empty() {
+        completions.push(Completion {
+            label: ":".to_string(),
+            kind: CompletionItemKind::OPERATOR,
+            detail: Some(
+                "Ends the categories, the explanation of the suppression follows".to_string(),
+            ),
+            range,
+            new_text: ": ".to_string(),
+        });
+    }
+    completions.push(category(
+        "format".to_string(),
+        "Suppresses the formatting of the next node".to_string(),
+    ));
+    completions.push(category(
+        "lint".to_string(),
+        "Suppresses all the lint rules".to_string(),
+    ));
+    for group in RuleGroup::ALL {
+        let name = group.as_str();
+        completions.push(category(
+            format!("lint/{name}"),
+            format!("Suppresses the rules of the group {name}"),
+        ));
+        for rule in group.rule_names() {
+            let summary = RULE_SUMMARIES.get(rule).copied().unwrap_or_default();
+            completions.push(category(format!("lint/{name}/{rule}"), summary.to_string()));
+        }
+    }
+    Some(completions)
+}
+
+/// Returns the completions of the configuration file at the cursor: the properties of the
+/// object, or the values of the property, declared by the schema of the configuration
+fn configuration_completions(content: &str, offset: TextSize) -> Vec<Completion> {
+    let Some(schema) = CONFIGURATION_SCHEMA.as_ref() else {
+        return Vec::new();
+    };
+    let options = JsonParserOptions::default()
+        .with_allow_comments()
+        .with_allow_trailing_commas();
+    let root = parse_json(content, options).syntax();
+
+    // The string under the cursor is replaced, otherwise the completion is inserted at the cursor
+    let string = root.token_at_offset(offset).find(|token| {
+        let range = token.text_trimmed_range();
+        token.kind() == JsonSyntaxKind::JSON_STRING_LITERAL
+            && range.start() < offset
+            && offset < range.end()
+    });
+    if let Some(token) = string {
+        let range = token.text_trimmed_range();
+        let Some(parent) = token.parent() else {
+            return Vec::new();
+        };
+        return match parent.kind() {
+            JsonSyntaxKind::JSON_MEMBER_NAME => {
+                let Some(member) = parent.parent().and_then(JsonMember::cast) else {
+                    return Vec::new();
+                };
+                let Some(object) = member.syntax().ancestors().find_map(JsonObjectValue::cast)
+                else {
+                    return Vec::new();
+                };
+                property_completions(schema, &object, Some(&member), range)
+            }
+            JsonSyntaxKind::JSON_STRING_VALUE => {
+                value_completions(schema, &value_path(&parent), range)
+            }
+            _ => Vec::new(),
+        };
+    }
+
+    let Some(previous) = root
+        .descendants_tokens(Direction::Next)
+        .take_while(|token| token.text_trimmed_range().end() <= offset)
+        .last()
+    else {
+        return Vec::new();
+    };
+    let Some(parent) = previous.parent() else {
+        return Vec::new();
+    };
+    let range = TextRange::empty(offset);
+    match (previous.kind(), parent.kind()) {
+        (T!['{'], JsonSyntaxKind::JSON_OBJECT_VALUE) => match JsonObjectValue::cast(parent) {
+            Some(object) => property_completions(schema, &object, None, range),
+            None => Vec::new(),
+        },
+        (T![,], JsonSyntaxKind::JSON_MEMBER_LIST) => {
+            match parent.parent().and_then(JsonObjectValue::cast) {
+                Some(object) => property_completions(schema, &object, None, range),
+                None => Vec::new(),
+            }
+        }
+        (T![:], JsonSyntaxKind::JSON_MEMBER) | (T![,], JsonSyntaxKind::JSON_ARRAY_ELEMENT_LIST) => {
+            value_completions(schema, &value_path(&parent), range)
+        }
+        (T!['['], JsonSyntaxKind::JSON_ARRAY_VALUE) => {
+            let mut path = value_path(&parent);
+            path.push(JsonPathSegment::Item);
+            value_completions(schema, &path, range)
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the properties of `object` that aren't written yet, except `member` which is
+/// being edited. The rules are described by the first line of their documentation.
+fn property_completions(
+    schema: &JsonSchema,
+    object: &JsonObjectValue,
+    member: Option<&JsonMember>,
+    range: TextRange,
+) -> Vec<Completion> {
+    let path = value_path(object.syntax());
+    let written: Vec<_> = object
+        .json_member_list()
+        .iter()
+        .flatten()
+        .filter(|written| Some(written) != member)
+        .filter_map(|written| Some(written.name().ok()?.inner_string_text().ok()?.to_string()))
+        .collect();
+    let is_rule_group = matches!(
+        path.iter().rev().nth(1),
+        Some(JsonPathSegment::Property(name)) if name == "rules"
+    );
+
+    schema
+        .property_names(&path)
+        .into_iter()
+        .filter(|name| !written.contains(name))
+        .map(|name| Completion {
+            detail: is_rule_group
+                .then(|| RULE_SUMMARIES.get(name.as_str()))
+                .flatten()
+                .map(|summary| summary.to_string()),
+            kind: CompletionItemKind::PROPERTY,
+            new_text: format!("\"{name}\""),
+            label: name,
+            range,
+        })
+        .collect()
+}
+
+fn value_completions(
+    schema: &JsonSchema,
+    path: &[JsonPathSegment],
+    range: TextRange,
+) -> Vec<Completion> {
+    schema
+        .value_suggestions(path)
+        .into_iter()
+        .map(|value| Completion {
+            label: value.clone(),
+            kind: CompletionItemKind::VALUE,
+            detail: None,
+            range,
+            new_text: value,
+        })
+        .collect()
+}
+
+/// Returns the path from the root of the document to the value `node`, or to the value that
+/// contains it
+fn value_path(node: &JsonSyntaxNode) -> Vec<JsonPathSegment> {
+    let mut path: Vec<_> = node
+        .ancestors()
+        .filter_map(|node| match node.kind() {
+            JsonSyntaxKind::JSON_MEMBER => {
+                let name = JsonMember::cast(node)?
+                    .name()
+                    .ok()?
+                    .inner_string_text()
+                    .ok()?;
+                Some(JsonPathSegment::Property(name.to_string()))
+            }
+            JsonSyntaxKind::JSON_ARRAY_ELEMENT_LIST => Some(JsonPathSegment::Item),
+            _ => None,
+        })
+        .collect();
+    path.reverse();
+    path
+}
@@ -0,0 +1,49 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::Context;
+use biome_lsp_converters::{from_proto, to_proto};
+use biome_service::workspace::GetDefinitionParams;
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{GotoDefinitionParams, GotoDefinitionResponse, Location, Url};
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn goto_definition(
+    session: &Session,
+    params: GotoDefinitionParams,
+) -> Result<Option<GotoDefinitionResponse>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let biome_path = session.file_path(&url)?;
+
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in document {url}"))?;
+
+    let result = match session.workspace.get_definition(GetDefinitionParams {
+        path: biome_path,
+        definition_of: offset,
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let mut locations = Vec::with_capacity(result.definitions.len());
+    for definition in result.definitions {
+        let Ok(url) = Url::from_file_path(definition.path.as_path()) else {
+            continue;
+        };
+        let Some(line_index) = session.line_index(&url) else {
+            continue;
+        };
+        let range = to_proto::range(&line_index, definition.range, position_encoding)?;
+        locations.push(Location::new(url, range));
+    }
+
+    Ok(match locations.len() {
+        0 => None,
+        1 => locations.pop().map(GotoDefinitionResponse::Scalar),
+        _ => Some(GotoDefinitionResponse::Array(locations)),
+    })
+}
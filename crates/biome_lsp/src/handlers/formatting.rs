@@ -37,25 +37,10 @@ pub(crate) fn format(
             path: biome_path.clone(),
         })?;
 
-        let mut output = printed.into_code();
-        let input = session.workspace.get_file_content(GetFileContentParams {
-            path: biome_path.clone(),
-        })?;
+        let output = printed.into_code();
         if output.is_empty() {
             return Ok(None);
         }
-        match biome_path.extension().map(OsStr::as_encoded_bytes) {
-            Some(b"astro") => {
-                output = AstroFileHandler::output(input.as_str(), output.as_str());
-            }
-            Some(b"vue") => {
-                output = VueFileHandler::output(input.as_str(), output.as_str());
-            }
-            Some(b"svelte") => {
-                output = SvelteFileHandler::output(input.as_str(), output.as_str());
-            }
-            _ => {}
-        }
 
         let content = session.workspace.get_file_content(GetFileContentParams {
             path: biome_path.clone(),
@@ -0,0 +1,96 @@
+use crate::diagnostics::LspError;
+use crate::handlers::code_lens::RULE_SUMMARIES;
+use crate::session::Session;
+use biome_lsp_converters::from_proto;
+use biome_service::workspace::{GetDefinitionParams, GetRuleConfigurationParams};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{
+    Hover, HoverContents, HoverParams, MarkupContent, MarkupKind, NumberOrString,
+};
+
+/// Describes the lint diagnostics under the cursor, with the summary of their rule and the
+/// configuration the rule runs with, and the file that an import specifier resolves to
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) async fn hover(
+    session: &Session,
+    params: HoverParams,
+) -> Result<Option<Hover>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let biome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+
+    let mut sections = Vec::new();
+    let mut range = None;
+    for diagnostic in session.compute_diagnostics(&url, &doc).await? {
+        if position < diagnostic.range.start || diagnostic.range.end < position {
+            continue;
+        }
+        let Some(NumberOrString::String(category)) = diagnostic.code else {
+            continue;
+        };
+        let Some(rule) = category
+            .strip_prefix("lint/")
+            .and_then(|rule| rule.split_once('/'))
+            .map(|(_, rule)| rule)
+        else {
+            continue;
+        };
+
+        let mut section = format!("**{category}**");
+        if let Some(summary) = RULE_SUMMARIES.get(rule) {
+            section.push_str(&format!("\n\n{summary}"));
+        }
+        let configuration = session
+            .workspace
+            .get_rule_configuration(GetRuleConfigurationParams {
+                path: biome_path.clone(),
+                category: category.clone(),
+            })?
+            .configuration;
+        match configuration {
+            Some(configuration) => section.push_str(&format!(
+                "\n\nConfigured with:\n```json\n{configuration}\n```"
+            )),
+            None => {
+                section.push_str("\n\nThe rule isn't configured, it runs with its default options.")
+            }
+        }
+        if let Some(description) = diagnostic.code_description {
+            section.push_str(&format!("\n\n[Documentation]({})", description.href));
+        }
+        sections.push(section);
+        range.get_or_insert(diagnostic.range);
+    }
+
+    let offset = from_proto::offset(&doc.line_index, position, session.position_encoding())?;
+    let definitions = match session.workspace.get_definition(GetDefinitionParams {
+        path: biome_path,
+        definition_of: offset,
+    }) {
+        Ok(result) => result.definitions,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => Vec::new(),
+        Err(error) => return Err(error.into()),
+    };
+    // The definition of an import specifier is the whole file, unlike the definitions of
+    // the custom properties of the stylesheets
+    if let [definition] = definitions.as_slice() {
+        if definition.range.is_empty() {
+            sections.push(format!(
+                "Resolved to `{}`",
+                definition.path.as_path().display()
+            ));
+        }
+    }
+
+    if sections.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: sections.join("\n\n---\n\n"),
+        }),
+        range,
+    }))
+}
@@ -0,0 +1,64 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use biome_lsp_converters::{from_proto, to_proto};
+use biome_service::workspace::{GetInlayHintsParams, InlayHintKind};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{self, InlayHintLabel, InlayHintParams};
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn inlay_hint(
+    session: &Session,
+    params: InlayHintParams,
+) -> Result<Option<Vec<lsp_types::InlayHint>>, LspError> {
+    let (parameter_names, variable_types) = match session.extension_settings.read() {
+        Ok(settings) => (
+            settings.parameter_name_hints_enabled(),
+            settings.variable_type_hints_enabled(),
+        ),
+        Err(_) => (true, true),
+    };
+    if !parameter_names && !variable_types {
+        return Ok(None);
+    }
+
+    let url = params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let range = from_proto::text_range(&doc.line_index, params.range, position_encoding)?;
+    let result = match session.workspace.get_inlay_hints(GetInlayHintsParams {
+        path: biome_path,
+        range,
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let mut hints = Vec::with_capacity(result.hints.len());
+    for hint in result.hints {
+        let (label, kind) = match hint.kind {
+            InlayHintKind::Parameter if parameter_names => (
+                format!("{}:", hint.label),
+                lsp_types::InlayHintKind::PARAMETER,
+            ),
+            InlayHintKind::Type if variable_types => {
+                (format!(": {}", hint.label), lsp_types::InlayHintKind::TYPE)
+            }
+            _ => continue,
+        };
+        hints.push(lsp_types::InlayHint {
+            position: to_proto::position(&doc.line_index, hint.position, position_encoding)?,
+            label: InlayHintLabel::from(label),
+            kind: Some(kind),
+            text_edits: None,
+            tooltip: None,
+            padding_left: None,
+            padding_right: Some(kind == lsp_types::InlayHintKind::PARAMETER),
+            data: None,
+        });
+    }
+
+    Ok(Some(hints))
+}
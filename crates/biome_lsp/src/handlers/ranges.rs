@@ -0,0 +1,99 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use biome_lsp_converters::{from_proto, to_proto};
+use biome_service::workspace::{
+    FoldingRangeKind, GetFoldingRangesParams, GetSelectionRangesParams,
+};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{self, FoldingRangeParams, SelectionRange, SelectionRangeParams};
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn folding_range(
+    session: &Session,
+    params: FoldingRangeParams,
+) -> Result<Option<Vec<lsp_types::FoldingRange>>, LspError> {
+    let url = params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+
+    let doc = session.document(&url)?;
+    let result = match session
+        .workspace
+        .get_folding_ranges(GetFoldingRangesParams { path: biome_path })
+    {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let position_encoding = session.position_encoding();
+    let mut ranges = Vec::with_capacity(result.ranges.len());
+    for folding_range in result.ranges {
+        let range = to_proto::range(&doc.line_index, folding_range.range, position_encoding)?;
+        // The editors fold whole lines, so the ranges within a line can't be folded
+        if range.start.line == range.end.line {
+            continue;
+        }
+
+        ranges.push(lsp_types::FoldingRange {
+            start_line: range.start.line,
+            start_character: None,
+            end_line: range.end.line,
+            end_character: None,
+            kind: match folding_range.kind {
+                FoldingRangeKind::Block => None,
+                FoldingRangeKind::Comment => Some(lsp_types::FoldingRangeKind::Comment),
+                FoldingRangeKind::Imports => Some(lsp_types::FoldingRangeKind::Imports),
+                FoldingRangeKind::Region => Some(lsp_types::FoldingRangeKind::Region),
+            },
+            collapsed_text: None,
+        });
+    }
+
+    Ok(Some(ranges))
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn selection_range(
+    session: &Session,
+    params: SelectionRangeParams,
+) -> Result<Option<Vec<SelectionRange>>, LspError> {
+    let url = params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let positions = params
+        .positions
+        .iter()
+        .map(|position| from_proto::offset(&doc.line_index, *position, position_encoding))
+        .collect::<Result<Vec<_>, _>>()?;
+    let result = match session
+        .workspace
+        .get_selection_ranges(GetSelectionRangesParams {
+            path: biome_path,
+            positions,
+        }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    // Each range is linked to the range that contains it, so the ranges are linked starting
+    // from the largest. A position without ranges only selects itself.
+    let mut selection_ranges = Vec::with_capacity(params.positions.len());
+    for (position, ranges) in params.positions.into_iter().zip(result.ranges) {
+        let mut selection_range = None;
+        for range in ranges.into_iter().rev() {
+            selection_range = Some(SelectionRange {
+                range: to_proto::range(&doc.line_index, range, position_encoding)?,
+                parent: selection_range.map(Box::new),
+            });
+        }
+        selection_ranges.push(selection_range.unwrap_or(SelectionRange {
+            range: lsp_types::Range::new(position, position),
+            parent: None,
+        }));
+    }
+
+    Ok(Some(selection_ranges))
+}
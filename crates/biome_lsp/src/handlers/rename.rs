@@ -3,10 +3,45 @@ use std::collections::HashMap;
 use crate::diagnostics::LspError;
 use crate::{session::Session, utils};
 use anyhow::{Context, Result};
-use biome_lsp_converters::from_proto;
-use tower_lsp::lsp_types::{RenameParams, WorkspaceEdit};
+use biome_lsp_converters::{from_proto, to_proto};
+use tower_lsp::lsp_types::{
+    PrepareRenameResponse, RenameParams, TextDocumentPositionParams, Url, WorkspaceEdit,
+};
 use tracing::trace;
 
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn prepare_rename(
+    session: &Session,
+    params: TextDocumentPositionParams,
+) -> Result<Option<PrepareRenameResponse>, LspError> {
+    let url = params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let cursor_range = from_proto::offset(&doc.line_index, params.position, position_encoding)
+        .with_context(|| {
+            format!(
+                "failed to access position {:?} in document {url}",
+                params.position
+            )
+        })?;
+
+    let result =
+        session
+            .workspace
+            .prepare_rename(biome_service::workspace::PrepareRenameParams {
+                path: biome_path,
+                symbol_at: cursor_range,
+            })?;
+
+    let range = to_proto::range(&doc.line_index, result.range, position_encoding)?;
+    Ok(Some(PrepareRenameResponse::RangeWithPlaceholder {
+        range,
+        placeholder: result.name,
+    }))
+}
+
 #[tracing::instrument(level = "debug", skip(session), err)]
 pub(crate) fn rename(
     session: &Session,
@@ -45,6 +80,19 @@ pub(crate) fn rename(
         utils::text_edit(&doc.line_index, result.indels, position_encoding, None)?,
     );
 
+    // The scripts that import the renamed symbol are edited too, as long as
+    // they are open in the editor
+    for file in result.other_files {
+        let Ok(url) = Url::from_file_path(file.path.as_path()) else {
+            continue;
+        };
+        let Ok(doc) = session.document(&url) else {
+            continue;
+        };
+        let edits = utils::text_edit(&doc.line_index, file.indels, position_encoding, None)?;
+        changes.insert(url, edits);
+    }
+
     let workspace_edit = WorkspaceEdit {
         changes: Some(changes),
         document_changes: None,
@@ -0,0 +1,115 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use biome_lsp_converters::to_proto;
+use biome_service::workspace::{GetSemanticTokensParams, SemanticTokenKind, SemanticTokenModifier};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{
+    self, SemanticTokenType, SemanticTokens, SemanticTokensLegend, SemanticTokensParams,
+    SemanticTokensResult,
+};
+
+/// The modifier of the bindings declared by an import, which isn't one of the standard modifiers
+const IMPORTED_MODIFIER: &str = "imported";
+
+/// The legend of the tokens returned by [semantic_tokens_full]. The index of a token type
+/// in the legend is returned by [token_type], and the bit of a modifier by [token_modifier].
+pub(crate) fn semantic_tokens_legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: vec![
+            SemanticTokenType::NAMESPACE,
+            SemanticTokenType::TYPE,
+            SemanticTokenType::CLASS,
+            SemanticTokenType::ENUM,
+            SemanticTokenType::INTERFACE,
+            SemanticTokenType::TYPE_PARAMETER,
+            SemanticTokenType::PARAMETER,
+            SemanticTokenType::VARIABLE,
+            SemanticTokenType::ENUM_MEMBER,
+            SemanticTokenType::FUNCTION,
+        ],
+        token_modifiers: vec![
+            lsp_types::SemanticTokenModifier::DECLARATION,
+            lsp_types::SemanticTokenModifier::READONLY,
+            lsp_types::SemanticTokenModifier::new(IMPORTED_MODIFIER),
+        ],
+    }
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn semantic_tokens_full(
+    session: &Session,
+    params: SemanticTokensParams,
+) -> Result<Option<SemanticTokensResult>, LspError> {
+    let url = params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+
+    let doc = session.document(&url)?;
+    let result = match session
+        .workspace
+        .get_semantic_tokens(GetSemanticTokensParams { path: biome_path })
+    {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    // The position of each token is encoded relatively to the position of the previous token
+    let position_encoding = session.position_encoding();
+    let mut data = Vec::with_capacity(result.tokens.len());
+    let mut previous_line = 0;
+    let mut previous_start = 0;
+    for token in result.tokens {
+        let range = to_proto::range(&doc.line_index, token.range, position_encoding)?;
+        // Not all the editors support the tokens that span several lines
+        if range.start.line != range.end.line {
+            continue;
+        }
+
+        let delta_line = range.start.line - previous_line;
+        let delta_start = if delta_line == 0 {
+            range.start.character - previous_start
+        } else {
+            range.start.character
+        };
+        data.push(lsp_types::SemanticToken {
+            delta_line,
+            delta_start,
+            length: range.end.character - range.start.character,
+            token_type: token_type(token.kind),
+            token_modifiers_bitset: token
+                .modifiers
+                .into_iter()
+                .fold(0, |bitset, modifier| bitset | token_modifier(modifier)),
+        });
+        previous_line = range.start.line;
+        previous_start = range.start.character;
+    }
+
+    Ok(Some(SemanticTokensResult::Tokens(SemanticTokens {
+        result_id: None,
+        data,
+    })))
+}
+
+fn token_type(kind: SemanticTokenKind) -> u32 {
+    match kind {
+        SemanticTokenKind::Namespace => 0,
+        SemanticTokenKind::Type => 1,
+        SemanticTokenKind::Class => 2,
+        SemanticTokenKind::Enum => 3,
+        SemanticTokenKind::Interface => 4,
+        SemanticTokenKind::TypeParameter => 5,
+        SemanticTokenKind::Parameter => 6,
+        SemanticTokenKind::Variable => 7,
+        SemanticTokenKind::EnumMember => 8,
+        SemanticTokenKind::Function => 9,
+    }
+}
+
+fn token_modifier(modifier: SemanticTokenModifier) -> u32 {
+    match modifier {
+        SemanticTokenModifier::Declaration => 1 << 0,
+        SemanticTokenModifier::Readonly => 1 << 1,
+        SemanticTokenModifier::Imported => 1 << 2,
+    }
+}
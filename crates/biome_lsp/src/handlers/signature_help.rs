@@ -0,0 +1,70 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use biome_lsp_converters::from_proto;
+use biome_service::workspace::GetSignatureHelpParams;
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{
+    Documentation, MarkupContent, MarkupKind, ParameterInformation, ParameterLabel, SignatureHelp,
+    SignatureHelpParams, SignatureInformation,
+};
+
+/// The characters that open the signature help: the parenthesis that opens the arguments, and
+/// the comma that starts the next argument
+pub(crate) const TRIGGER_CHARACTERS: [&str; 2] = ["(", ","];
+
+/// Shows the signature of the function called at the cursor, when the function is declared
+/// in the same file, with the documentation of its JSDoc comment
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn signature_help(
+    session: &Session,
+    params: SignatureHelpParams,
+) -> Result<Option<SignatureHelp>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+
+    let doc = session.document(&url)?;
+    let position = from_proto::offset(
+        &doc.line_index,
+        params.text_document_position_params.position,
+        session.position_encoding(),
+    )?;
+    let result = match session
+        .workspace
+        .get_signature_help(GetSignatureHelpParams {
+            path: biome_path,
+            position,
+        }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+    let Some(signature) = result.signature else {
+        return Ok(None);
+    };
+
+    let markdown = |value: String| {
+        Documentation::MarkupContent(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        })
+    };
+    let parameters = signature
+        .parameters
+        .into_iter()
+        .map(|parameter| ParameterInformation {
+            label: ParameterLabel::Simple(parameter.label),
+            documentation: parameter.documentation.map(markdown),
+        })
+        .collect();
+
+    Ok(Some(SignatureHelp {
+        signatures: vec![SignatureInformation {
+            label: signature.label,
+            documentation: signature.documentation.map(markdown),
+            parameters: Some(parameters),
+            active_parameter: signature.active_parameter,
+        }],
+        active_signature: Some(0),
+        active_parameter: signature.active_parameter,
+    }))
+}
@@ -0,0 +1,129 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use biome_lsp_converters::line_index::LineIndex;
+use biome_lsp_converters::{to_proto, PositionEncoding};
+use biome_service::workspace::{
+    DocumentSymbol, GetDocumentSymbolsParams, GetWorkspaceSymbolsParams, SymbolKind,
+};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{
+    self, DocumentSymbolParams, DocumentSymbolResponse, Location, SymbolInformation, Url,
+    WorkspaceSymbolParams,
+};
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn document_symbol(
+    session: &Session,
+    params: DocumentSymbolParams,
+) -> Result<Option<DocumentSymbolResponse>, LspError> {
+    let url = params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+
+    let doc = session.document(&url)?;
+    let result = match session
+        .workspace
+        .get_document_symbols(GetDocumentSymbolsParams { path: biome_path })
+    {
+        Ok(result) => result,
+        // The files of the languages without symbols don't have an outline
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let symbols = to_lsp_symbols(&doc.line_index, result.symbols, session.position_encoding())?;
+    Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn workspace_symbol(
+    session: &Session,
+    params: WorkspaceSymbolParams,
+) -> Result<Option<Vec<SymbolInformation>>, LspError> {
+    let result = session
+        .workspace
+        .get_workspace_symbols(GetWorkspaceSymbolsParams {
+            query: params.query,
+        })?;
+
+    let position_encoding = session.position_encoding();
+    let mut line_indexes = HashMap::new();
+    let mut symbols = Vec::with_capacity(result.symbols.len());
+    for symbol in result.symbols {
+        let Ok(url) = Url::from_file_path(symbol.path.as_path()) else {
+            continue;
+        };
+        let line_index = match line_indexes.entry(url.clone()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let Some(line_index) = session.line_index(&url) else {
+                    continue;
+                };
+                entry.insert(line_index)
+            }
+        };
+
+        let range = to_proto::range(line_index, symbol.range, position_encoding)?;
+        #[allow(deprecated)]
+        symbols.push(SymbolInformation {
+            name: symbol.name,
+            kind: to_lsp_kind(symbol.kind),
+            tags: None,
+            deprecated: None,
+            location: Location::new(url, range),
+            container_name: symbol.container_name,
+        });
+    }
+
+    Ok(Some(symbols))
+}
+
+fn to_lsp_symbols(
+    line_index: &LineIndex,
+    symbols: Vec<DocumentSymbol>,
+    position_encoding: PositionEncoding,
+) -> Result<Vec<lsp_types::DocumentSymbol>, LspError> {
+    symbols
+        .into_iter()
+        .map(|symbol| {
+            let children = to_lsp_symbols(line_index, symbol.children, position_encoding)?;
+            #[allow(deprecated)]
+            let symbol = lsp_types::DocumentSymbol {
+                name: symbol.name,
+                detail: None,
+                kind: to_lsp_kind(symbol.kind),
+                tags: None,
+                deprecated: None,
+                range: to_proto::range(line_index, symbol.range, position_encoding)?,
+                selection_range: to_proto::range(
+                    line_index,
+                    symbol.selection_range,
+                    position_encoding,
+                )?,
+                children: Some(children),
+            };
+            Ok(symbol)
+        })
+        .collect()
+}
+
+/// The editors don't have a kind for the CSS rules, so they are shown like the classes,
+/// and the at-rules like the modules
+fn to_lsp_kind(kind: SymbolKind) -> lsp_types::SymbolKind {
+    match kind {
+        SymbolKind::Class | SymbolKind::Rule => lsp_types::SymbolKind::CLASS,
+        SymbolKind::Constant => lsp_types::SymbolKind::CONSTANT,
+        SymbolKind::Enum => lsp_types::SymbolKind::ENUM,
+        SymbolKind::Function => lsp_types::SymbolKind::FUNCTION,
+        SymbolKind::Interface => lsp_types::SymbolKind::INTERFACE,
+        SymbolKind::Method => lsp_types::SymbolKind::METHOD,
+        SymbolKind::Namespace => lsp_types::SymbolKind::NAMESPACE,
+        SymbolKind::Property => lsp_types::SymbolKind::PROPERTY,
+        SymbolKind::TypeAlias => lsp_types::SymbolKind::TYPE_PARAMETER,
+        SymbolKind::Variable => lsp_types::SymbolKind::VARIABLE,
+        SymbolKind::AtRule => lsp_types::SymbolKind::MODULE,
+        SymbolKind::Key => lsp_types::SymbolKind::KEY,
+    }
+}
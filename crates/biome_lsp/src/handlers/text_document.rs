@@ -1,10 +1,15 @@
+use crate::diagnostics::LspError;
 use crate::utils::apply_document_changes;
 use crate::{documents::Document, session::Session};
 use anyhow::Result;
 use biome_service::workspace::{
     ChangeFileParams, CloseFileParams, DocumentFileSource, GetFileContentParams, OpenFileParams,
 };
-use tower_lsp::lsp_types;
+use tower_lsp::lsp_types::{
+    self, DocumentDiagnosticReport, DocumentDiagnosticReportResult, FullDocumentDiagnosticReport,
+    RelatedFullDocumentDiagnosticReport, RelatedUnchangedDocumentDiagnosticReport,
+    UnchangedDocumentDiagnosticReport,
+};
 use tracing::{error, field};
 
 /// Handler for `textDocument/didOpen` LSP notification
@@ -28,6 +33,9 @@ pub(crate) async fn did_open(
     let biome_path = session.file_path(&url)?;
     let doc = Document::new(version, &content);
 
+    // The packages of a monorepo can have their own configuration file
+    session.load_project_of(biome_path.as_path()).await;
+
     session.workspace.open_file(OpenFileParams {
         path: biome_path,
         version,
@@ -108,3 +116,42 @@ pub(crate) async fn did_close(
 
     Ok(())
 }
+
+/// Handler for `textDocument/diagnostic` LSP request
+///
+/// The client sends the identifier of the diagnostics it pulled before, so they are only
+/// computed again when the document or the settings changed since then.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) async fn document_diagnostic(
+    session: &Session,
+    params: lsp_types::DocumentDiagnosticParams,
+) -> Result<DocumentDiagnosticReportResult, LspError> {
+    let url = params.text_document.uri;
+    let doc = session.document(&url)?;
+    let result_id = session.diagnostics_result_id(&doc);
+
+    if params.previous_result_id.as_ref() == Some(&result_id) {
+        let report = RelatedUnchangedDocumentDiagnosticReport {
+            related_documents: None,
+            unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport { result_id },
+        };
+        return Ok(DocumentDiagnosticReportResult::Report(
+            DocumentDiagnosticReport::Unchanged(report),
+        ));
+    }
+
+    let diagnostics = session.compute_diagnostics(&url, &doc).await?;
+    Ok(full_diagnostic_report(Some(result_id), diagnostics))
+}
+
+/// Creates the report of all the diagnostics of a document
+pub(crate) fn full_diagnostic_report(
+    result_id: Option<String>,
+    items: Vec<lsp_types::Diagnostic>,
+) -> DocumentDiagnosticReportResult {
+    let report = RelatedFullDocumentDiagnosticReport {
+        related_documents: None,
+        full_document_diagnostic_report: FullDocumentDiagnosticReport { result_id, items },
+    };
+    DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(report))
+}
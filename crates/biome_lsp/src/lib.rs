@@ -7,6 +7,8 @@ mod requests;
 mod server;
 mod session;
 mod utils;
+mod watcher;
 
 pub use crate::extension_settings::WorkspaceSettings;
 pub use crate::server::{LSPServer, ServerConnection, ServerFactory};
+pub use crate::watcher::FileWatcher;
@@ -1 +1,2 @@
+pub(crate) mod resolve_configuration;
 pub(crate) mod syntax_tree;
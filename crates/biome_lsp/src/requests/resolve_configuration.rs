@@ -0,0 +1,31 @@
+use crate::session::Session;
+use anyhow::{Context, Result};
+use biome_service::workspace::ResolveConfigurationParams;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tower_lsp::lsp_types::{TextDocumentIdentifier, Url};
+use tracing::info;
+
+pub const RESOLVE_CONFIGURATION_REQUEST: &str = "biome/resolveConfig";
+
+/// The command that returns the resolved configuration of a document, for the editors that
+/// can't send custom requests. Its argument is the URL of the document.
+pub(crate) const RESOLVE_CONFIGURATION_COMMAND: &str = "biome.resolveConfiguration";
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveConfigurationPayload {
+    pub text_document: TextDocumentIdentifier,
+}
+
+/// Returns the configuration that applies to a document: its configuration file with the
+/// configurations that it extends, the `.editorconfig` file, and the overrides that match the
+/// document
+pub(crate) fn resolve_configuration(session: &Session, url: &Url) -> Result<Value> {
+    info!("Resolving the configuration");
+    let biome_path = session.file_path(url)?;
+    let result = session
+        .workspace
+        .resolve_configuration(ResolveConfigurationParams { path: biome_path })?;
+    serde_json::from_str(&result.configuration).context("Invalid resolved configuration")
+}
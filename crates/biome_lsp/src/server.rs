@@ -1,22 +1,25 @@
 use crate::capabilities::server_capabilities;
 use crate::diagnostics::{handle_lsp_error, LspError};
+use crate::requests::resolve_configuration::{
+    ResolveConfigurationPayload, RESOLVE_CONFIGURATION_COMMAND, RESOLVE_CONFIGURATION_REQUEST,
+};
 use crate::requests::syntax_tree::{SyntaxTreePayload, SYNTAX_TREE_REQUEST};
 use crate::session::{
     CapabilitySet, CapabilityStatus, ClientInformation, Session, SessionHandle, SessionKey,
 };
 use crate::utils::{into_lsp_error, panic_to_lsp_error};
-use crate::{handlers, requests};
+use crate::watcher::{is_configuration_file, FileWatcher};
+use crate::{handlers, requests, watcher};
 use biome_console::markup;
 use biome_diagnostics::panic::PanicError;
-use biome_fs::{ConfigName, FileSystem, OsFileSystem, ROME_JSON};
-use biome_service::workspace::{
-    RageEntry, RageParams, RageResult, RegisterProjectFolderParams, UnregisterProjectFolderParams,
-};
+use biome_fs::{FileSystem, OsFileSystem};
+use biome_service::workspace::{RageEntry, RageParams, RageResult};
 use biome_service::{workspace, DynRef, Workspace};
 use futures::future::ready;
 use futures::FutureExt;
 use rustc_hash::FxHashMap;
 use serde_json::json;
+use std::ffi::OsStr;
 use std::panic::RefUnwindSafe;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
@@ -39,6 +42,9 @@ pub struct LSPServer {
     /// This shared flag is set to true once at least one session has been
     /// initialized on this server instance
     is_initialized: Arc<AtomicBool>,
+    /// Watches the files of the workspace once the session is initialized, if the file
+    /// system of the platform can be watched
+    watcher: Mutex<Option<FileWatcher>>,
 }
 
 impl RefUnwindSafe for LSPServer {}
@@ -55,6 +61,7 @@ impl LSPServer {
             sessions,
             stop_on_disconnect,
             is_initialized,
+            watcher: Mutex::default(),
         }
     }
 
@@ -69,6 +76,21 @@ impl LSPServer {
         requests::syntax_tree::syntax_tree(&self.session, &url).map_err(into_lsp_error)
     }
 
+    async fn resolve_configuration_request(
+        &self,
+        params: ResolveConfigurationPayload,
+    ) -> LspResult<serde_json::Value> {
+        trace!(
+            "Calling method: {}\n with params: {:?}",
+            RESOLVE_CONFIGURATION_REQUEST,
+            &params
+        );
+
+        let url = params.text_document.uri;
+        requests::resolve_configuration::resolve_configuration(&self.session, &url)
+            .map_err(into_lsp_error)
+    }
+
     #[tracing::instrument(skip(self), name = "biome/rage", level = "debug")]
     async fn rage(&self, params: RageParams) -> LspResult<RageResult> {
         let mut entries = vec![
@@ -77,6 +99,13 @@ impl LSPServer {
             RageEntry::pair("Name", env!("CARGO_PKG_NAME")),
             RageEntry::pair("CPU Architecture", std::env::consts::ARCH),
             RageEntry::pair("OS", std::env::consts::OS),
+            RageEntry::pair("Process ID", &std::process::id().to_string()),
+            RageEntry::pair(
+                "Memory Usage",
+                &memory_usage().map_or(String::from("unknown"), |kilobytes| {
+                    format!("{} MiB", kilobytes / 1024)
+                }),
+            ),
         ];
 
         let RageResult {
@@ -117,111 +146,6 @@ impl LSPServer {
         Ok(RageResult { entries })
     }
 
-    async fn setup_capabilities(&self) {
-        let mut capabilities = CapabilitySet::default();
-
-        capabilities.add_capability(
-            "biome_did_change_extension_settings",
-            "workspace/didChangeConfiguration",
-            if self.session.can_register_did_change_configuration() {
-                CapabilityStatus::Enable(None)
-            } else {
-                CapabilityStatus::Disable
-            },
-        );
-
-        capabilities.add_capability(
-            "biome_did_change_workspace_settings",
-            "workspace/didChangeWatchedFiles",
-            if let Some(base_path) = self.session.base_path() {
-                CapabilityStatus::Enable(Some(json!(DidChangeWatchedFilesRegistrationOptions {
-                    watchers: vec![
-                        FileSystemWatcher {
-                            glob_pattern: GlobPattern::String(format!(
-                                "{}/biome.json",
-                                base_path.display()
-                            )),
-                            kind: Some(WatchKind::all()),
-                        },
-                        FileSystemWatcher {
-                            glob_pattern: GlobPattern::String(format!(
-                                "{}/biome.jsonc",
-                                base_path.display()
-                            )),
-                            kind: Some(WatchKind::all()),
-                        },
-                        FileSystemWatcher {
-                            glob_pattern: GlobPattern::String(format!(
-                                "{}/.editorconfig",
-                                base_path.display()
-                            )),
-                            kind: Some(WatchKind::all()),
-                        },
-                        // TODO: Biome 2.0 remove it
-                        FileSystemWatcher {
-                            glob_pattern: GlobPattern::String(format!(
-                                "{}/rome.json",
-                                base_path.display()
-                            )),
-                            kind: Some(WatchKind::all()),
-                        }
-                    ],
-                })))
-            } else {
-                CapabilityStatus::Disable
-            },
-        );
-
-        capabilities.add_capability(
-            "biome_formatting",
-            "textDocument/formatting",
-            if self.session.is_linting_and_formatting_disabled() {
-                CapabilityStatus::Disable
-            } else {
-                CapabilityStatus::Enable(None)
-            },
-        );
-        capabilities.add_capability(
-            "biome_range_formatting",
-            "textDocument/rangeFormatting",
-            if self.session.is_linting_and_formatting_disabled() {
-                CapabilityStatus::Disable
-            } else {
-                CapabilityStatus::Enable(None)
-            },
-        );
-        capabilities.add_capability(
-            "biome_on_type_formatting",
-            "textDocument/onTypeFormatting",
-            if self.session.is_linting_and_formatting_disabled() {
-                CapabilityStatus::Disable
-            } else {
-                CapabilityStatus::Enable(Some(json!(DocumentOnTypeFormattingRegistrationOptions {
-                    document_selector: None,
-                    first_trigger_character: String::from("}"),
-                    more_trigger_character: Some(vec![String::from("]"), String::from(")")]),
-                })))
-            },
-        );
-
-        let rename = {
-            let config = self.session.extension_settings.read().ok();
-            config.is_some_and(|x| x.rename_enabled())
-        };
-
-        capabilities.add_capability(
-            "biome_rename",
-            "textDocument/rename",
-            if rename {
-                CapabilityStatus::Enable(None)
-            } else {
-                CapabilityStatus::Disable
-            },
-        );
-
-        self.session.register_capabilities(capabilities).await;
-    }
-
     async fn map_op_error<T>(
         &self,
         result: Result<Result<Option<T>, LspError>, PanicError>,
@@ -237,6 +161,149 @@ impl LSPServer {
     }
 }
 
+/// Registers the capabilities that depend on the settings of the session
+async fn setup_capabilities(session: &Session) {
+    let mut capabilities = CapabilitySet::default();
+
+    capabilities.add_capability(
+        "biome_did_change_extension_settings",
+        "workspace/didChangeConfiguration",
+        if session.can_register_did_change_configuration() {
+            CapabilityStatus::Enable(None)
+        } else {
+            CapabilityStatus::Disable
+        },
+    );
+
+    capabilities.add_capability(
+        "biome_did_change_workspace_settings",
+        "workspace/didChangeWatchedFiles",
+        if let Some(base_path) = session.base_path() {
+            CapabilityStatus::Enable(Some(json!(DidChangeWatchedFilesRegistrationOptions {
+                watchers: vec![
+                    FileSystemWatcher {
+                        glob_pattern: GlobPattern::String(format!(
+                            "{}/**/biome.json",
+                            base_path.display()
+                        )),
+                        kind: Some(WatchKind::all()),
+                    },
+                    FileSystemWatcher {
+                        glob_pattern: GlobPattern::String(format!(
+                            "{}/**/biome.jsonc",
+                            base_path.display()
+                        )),
+                        kind: Some(WatchKind::all()),
+                    },
+                    FileSystemWatcher {
+                        glob_pattern: GlobPattern::String(format!(
+                            "{}/**/.editorconfig",
+                            base_path.display()
+                        )),
+                        kind: Some(WatchKind::all()),
+                    },
+                    // TODO: Biome 2.0 remove it
+                    FileSystemWatcher {
+                        glob_pattern: GlobPattern::String(format!(
+                            "{}/rome.json",
+                            base_path.display()
+                        )),
+                        kind: Some(WatchKind::all()),
+                    }
+                ],
+            })))
+        } else {
+            CapabilityStatus::Disable
+        },
+    );
+
+    capabilities.add_capability(
+        "biome_formatting",
+        "textDocument/formatting",
+        if session.is_linting_and_formatting_disabled() {
+            CapabilityStatus::Disable
+        } else {
+            CapabilityStatus::Enable(None)
+        },
+    );
+    capabilities.add_capability(
+        "biome_range_formatting",
+        "textDocument/rangeFormatting",
+        if session.is_linting_and_formatting_disabled() {
+            CapabilityStatus::Disable
+        } else {
+            CapabilityStatus::Enable(None)
+        },
+    );
+    capabilities.add_capability(
+        "biome_on_type_formatting",
+        "textDocument/onTypeFormatting",
+        if session.is_linting_and_formatting_disabled() {
+            CapabilityStatus::Disable
+        } else {
+            CapabilityStatus::Enable(Some(json!(DocumentOnTypeFormattingRegistrationOptions {
+                document_selector: None,
+                first_trigger_character: String::from("}"),
+                more_trigger_character: Some(vec![String::from("]"), String::from(")")]),
+            })))
+        },
+    );
+
+    let rename = {
+        let config = session.extension_settings.read().ok();
+        config.is_some_and(|x| x.rename_enabled())
+    };
+
+    capabilities.add_capability(
+        "biome_rename",
+        "textDocument/rename",
+        if rename {
+            CapabilityStatus::Enable(Some(json!(RenameOptions {
+                prepare_provider: Some(true),
+                work_done_progress_options: WorkDoneProgressOptions::default(),
+            })))
+        } else {
+            CapabilityStatus::Disable
+        },
+    );
+
+    session.register_capabilities(capabilities).await;
+}
+
+/// Loads again the configuration files and the `.editorconfig` files among `file_paths`,
+/// which changed on the disk. The other files are ignored.
+pub(crate) async fn reload_configuration_files(
+    session: &Session,
+    file_paths: impl IntoIterator<Item = PathBuf>,
+) {
+    let Some(base_path) = session.base_path() else {
+        return;
+    };
+    for file_path in file_paths {
+        if !file_path.starts_with(&base_path) || !is_configuration_file(&file_path) {
+            continue;
+        }
+        let is_configuration =
+            file_path.file_name().and_then(OsStr::to_str) != Some(".editorconfig");
+        let directory = file_path.parent().unwrap_or(&base_path);
+        // Only the project of a nested configuration file is loaded again,
+        // and the `.editorconfig` files only apply to the projects
+        if session.config_path.is_none() && directory != base_path {
+            if is_configuration || session.is_project_root(directory) {
+                session.load_project(directory).await;
+                session.update_project_diagnostics(directory).await;
+            }
+            continue;
+        }
+        session.load_workspace_settings().await;
+        session.load_manifest().await;
+        setup_capabilities(session).await;
+        session.update_all_diagnostics().await;
+        // The settings of the whole workspace were loaded again, so it's OK to exit the loop
+        break;
+    }
+}
+
 #[tower_lsp::async_trait]
 impl LanguageServer for LSPServer {
     // The `root_path` field is deprecated, but we still read it so we can print a warning about it
@@ -301,7 +368,12 @@ impl LanguageServer for LSPServer {
             .log_message(MessageType::INFO, msg)
             .await;
 
-        self.setup_capabilities().await;
+        setup_capabilities(&self.session).await;
+
+        if let Some(base_path) = self.session.base_path() {
+            *self.watcher.lock().unwrap() = watcher::watch(&self.session, &base_path);
+            watcher::index_workspace(&self.session, &base_path);
+        }
 
         // Diagnostics are disabled by default, so update them after fetching workspace config
         self.session.update_all_diagnostics().await;
@@ -316,45 +388,29 @@ impl LanguageServer for LSPServer {
         let _ = params;
         self.session.load_workspace_settings().await;
         self.session.load_extension_settings().await;
-        self.setup_capabilities().await;
+        setup_capabilities(&self.session).await;
         self.session.update_all_diagnostics().await;
     }
 
     #[tracing::instrument(level = "trace", skip(self))]
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
-        let file_paths = params
-            .changes
-            .iter()
-            .map(|change| change.uri.to_file_path());
-        for file_path in file_paths {
-            match file_path {
-                Ok(file_path) => {
-                    let base_path = self.session.base_path();
-                    if let Some(base_path) = base_path {
-                        let possible_rome_json = file_path.strip_prefix(&base_path);
-                        if let Ok(watched_file) = possible_rome_json {
-                            if watched_file.display().to_string() == ROME_JSON
-                                || ConfigName::file_names()
-                                    .contains(&&*watched_file.display().to_string())
-                                || watched_file.ends_with(".editorconfig")
-                            {
-                                self.session.load_workspace_settings().await;
-                                self.session.load_manifest().await;
-                                self.setup_capabilities().await;
-                                self.session.update_all_diagnostics().await;
-                                // for now we are only interested to the configuration file,
-                                // so it's OK to exist the loop
-                                break;
-                            }
-                        }
-                    }
-                }
+        // The file watcher of the server reloads the configuration files already
+        if self.watcher.lock().unwrap().is_some() {
+            return;
+        }
+        let mut file_paths = Vec::new();
+        for change in params.changes {
+            match change.uri.to_file_path() {
+                Ok(file_path) => file_paths.push(file_path),
                 Err(_) => {
-                    error!("The Workspace root URI {file_path:?} could not be parsed as a filesystem path");
-                    continue;
+                    error!(
+                        "The Workspace root URI {:?} could not be parsed as a filesystem path",
+                        change.uri
+                    );
                 }
             }
         }
+        reload_configuration_files(&self.session, file_paths).await;
     }
 
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
@@ -375,13 +431,30 @@ impl LanguageServer for LSPServer {
             .ok();
     }
 
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> LspResult<DocumentDiagnosticReportResult> {
+        match handlers::text_document::document_diagnostic(&self.session, params).await {
+            Ok(report) => Ok(report),
+            // The error is reported to the user, and the document doesn't have diagnostics
+            Err(err) => {
+                handle_lsp_error::<()>(err, &self.session.client).await?;
+                Ok(handlers::text_document::full_diagnostic_report(
+                    None,
+                    Vec::new(),
+                ))
+            }
+        }
+    }
+
     async fn did_change_workspace_folders(&self, params: DidChangeWorkspaceFoldersParams) {
         for removed in &params.event.removed {
             if let Ok(project_path) = self.session.file_path(&removed.uri) {
+                // The nested projects of the folder are removed with it
                 let result = self
                     .session
-                    .workspace
-                    .unregister_project_folder(UnregisterProjectFolderParams { path: project_path })
+                    .unload_projects(project_path.as_path())
                     .map_err(into_lsp_error);
 
                 if let Err(err) = result {
@@ -396,22 +469,12 @@ impl LanguageServer for LSPServer {
 
         for added in &params.event.added {
             if let Ok(project_path) = self.session.file_path(&added.uri) {
-                let result = self
-                    .session
-                    .workspace
-                    .register_project_folder(RegisterProjectFolderParams {
-                        path: Some(project_path.to_path_buf()),
-                        set_as_current_workspace: true,
-                    })
-                    .map_err(into_lsp_error);
-
-                if let Err(err) = result {
-                    error!("Failed to add project to the workspace: {}", err);
-                    self.session
-                        .client
-                        .log_message(MessageType::ERROR, err)
-                        .await;
-                }
+                // The configuration of the folder is loaded, as for the folders of the
+                // "initialize" request
+                self.session.load_project(project_path.as_path()).await;
+                self.session
+                    .update_project_diagnostics(project_path.as_path())
+                    .await;
             }
         }
     }
@@ -455,6 +518,27 @@ impl LanguageServer for LSPServer {
         self.map_op_error(result).await
     }
 
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> LspResult<Option<PrepareRenameResponse>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            let rename_enabled = self
+                .session
+                .extension_settings
+                .read()
+                .ok()
+                .is_some_and(|config| config.rename_enabled());
+
+            if rename_enabled {
+                handlers::rename::prepare_rename(&self.session, params).map_err(into_lsp_error)
+            } else {
+                Ok(None)
+            }
+        })
+        .map_err(into_lsp_error)?
+    }
+
     async fn rename(&self, params: RenameParams) -> LspResult<Option<WorkspaceEdit>> {
         biome_diagnostics::panic::catch_unwind(move || {
             let rename_enabled = self
@@ -462,8 +546,7 @@ impl LanguageServer for LSPServer {
                 .extension_settings
                 .read()
                 .ok()
-                .and_then(|config| config.settings.rename)
-                .unwrap_or(false);
+                .is_some_and(|config| config.rename_enabled());
 
             if rename_enabled {
                 handlers::rename::rename(&self.session, params).map_err(into_lsp_error)
@@ -473,6 +556,138 @@ impl LanguageServer for LSPServer {
         })
         .map_err(into_lsp_error)?
     }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> LspResult<Option<GotoDefinitionResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::definition::goto_definition(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> LspResult<Option<DocumentSymbolResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::symbols::document_symbol(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn code_lens(&self, params: CodeLensParams) -> LspResult<Option<Vec<CodeLens>>> {
+        match handlers::code_lens::code_lens(&self.session, params).await {
+            Ok(lenses) => Ok(lenses),
+            Err(err) => handle_lsp_error(err, &self.session.client).await,
+        }
+    }
+
+    async fn execute_command(
+        &self,
+        params: ExecuteCommandParams,
+    ) -> LspResult<Option<serde_json::Value>> {
+        if params.command == RESOLVE_CONFIGURATION_COMMAND {
+            let url = params
+                .arguments
+                .first()
+                .and_then(|url| serde_json::from_value::<Url>(url.clone()).ok());
+            let Some(url) = url else {
+                return Err(into_lsp_error(format!(
+                    "The command {RESOLVE_CONFIGURATION_COMMAND} expects the URL of a document"
+                )));
+            };
+            return requests::resolve_configuration::resolve_configuration(&self.session, &url)
+                .map(Some)
+                .map_err(into_lsp_error);
+        }
+
+        match handlers::code_lens::execute_command(&self.session, params).await {
+            Ok(result) => Ok(result),
+            Err(err) => handle_lsp_error(err, &self.session.client).await,
+        }
+    }
+
+    async fn completion(&self, params: CompletionParams) -> LspResult<Option<CompletionResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::completion::completion(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn hover(&self, params: HoverParams) -> LspResult<Option<Hover>> {
+        match handlers::hover::hover(&self.session, params).await {
+            Ok(hover) => Ok(hover),
+            Err(err) => handle_lsp_error(err, &self.session.client).await,
+        }
+    }
+
+    async fn folding_range(
+        &self,
+        params: FoldingRangeParams,
+    ) -> LspResult<Option<Vec<FoldingRange>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::ranges::folding_range(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> LspResult<Option<Vec<SelectionRange>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::ranges::selection_range(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn inlay_hint(&self, params: InlayHintParams) -> LspResult<Option<Vec<InlayHint>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::inlay_hints::inlay_hint(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn signature_help(
+        &self,
+        params: SignatureHelpParams,
+    ) -> LspResult<Option<SignatureHelp>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::signature_help::signature_help(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn semantic_tokens_full(
+        &self,
+        params: SemanticTokensParams,
+    ) -> LspResult<Option<SemanticTokensResult>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::semantic_tokens::semantic_tokens_full(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> LspResult<Option<Vec<SymbolInformation>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::symbols::workspace_symbol(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
 }
 
 impl Drop for LSPServer {
@@ -491,6 +706,20 @@ impl Drop for LSPServer {
     }
 }
 
+/// Returns the resident memory of the server process in kilobytes.
+///
+/// It's only supported on Linux, where it's read from `/proc/self/status`. It returns `None`
+/// on the other operating systems, and the memory usage is reported as unknown.
+fn memory_usage() -> Option<u64> {
+    if cfg!(target_os = "linux") {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+        line.split_whitespace().nth(1)?.parse().ok()
+    } else {
+        None
+    }
+}
+
 /// Map of active sessions connected to a [ServerFactory].
 type Sessions = Arc<Mutex<FxHashMap<SessionKey, SessionHandle>>>;
 
@@ -501,11 +730,14 @@ macro_rules! workspace_method {
         $builder = $builder.custom_method(
             concat!("biome/", stringify!($method)),
             |server: &LSPServer, params| {
-                let span = tracing::trace_span!(concat!("biome/", stringify!($method)), params = ?params).or_current();
+                // The parameters may contain the content of a file, so they're logged in an
+                // event instead of being recorded by the span, which may be exported
+                let span = tracing::debug_span!(concat!("biome/", stringify!($method))).or_current();
 
                 let workspace = server.session.workspace.clone();
                 let result = spawn_blocking(move || {
                     let _guard = span.entered();
+                    tracing::trace!(params = ?params, "Handling the request");
                     workspace.$method(params)
                 });
 
@@ -609,6 +841,10 @@ impl ServerFactory {
         });
 
         builder = builder.custom_method(SYNTAX_TREE_REQUEST, LSPServer::syntax_tree_request);
+        builder = builder.custom_method(
+            RESOLVE_CONFIGURATION_REQUEST,
+            LSPServer::resolve_configuration_request,
+        );
 
         // "shutdown" is not part of the Workspace API
         builder = builder.custom_method("biome/shutdown", |server: &LSPServer, (): ()| {
@@ -638,8 +874,23 @@ impl ServerFactory {
         workspace_method!(builder, format_range);
         workspace_method!(builder, format_on_type);
         workspace_method!(builder, fix_file);
+        workspace_method!(builder, prepare_rename);
         workspace_method!(builder, rename);
         workspace_method!(builder, organize_imports);
+        workspace_method!(builder, get_module_graph);
+        workspace_method!(builder, export_project_index);
+        workspace_method!(builder, import_project_index);
+        workspace_method!(builder, forget_files);
+        workspace_method!(builder, get_document_symbols);
+        workspace_method!(builder, get_workspace_symbols);
+        workspace_method!(builder, get_definition);
+        workspace_method!(builder, get_semantic_tokens);
+        workspace_method!(builder, get_inlay_hints);
+        workspace_method!(builder, get_signature_help);
+        workspace_method!(builder, get_folding_ranges);
+        workspace_method!(builder, get_selection_ranges);
+        workspace_method!(builder, get_rule_configuration);
+        workspace_method!(builder, resolve_configuration);
 
         let (service, socket) = builder.finish();
         ServerConnection { socket, service }
@@ -10,26 +10,25 @@ use biome_console::markup;
 use biome_deserialize::Merge;
 use biome_diagnostics::{DiagnosticExt, Error, PrintDescription};
 use biome_fs::{BiomePath, FileSystem};
+use biome_lsp_converters::line_index::LineIndex;
 use biome_lsp_converters::{negotiated_encoding, PositionEncoding, WideEncoding};
 use biome_service::configuration::{
     load_configuration, load_editorconfig, LoadedConfiguration, PartialConfigurationExt,
 };
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use biome_service::workspace::{
-    FeaturesBuilder, GetFileContentParams, PullDiagnosticsParams, RegisterProjectFolderParams,
-    SetManifestForProjectParams, SupportsFeatureParams,
+    FeaturesBuilder, PullDiagnosticsParams, RegisterProjectFolderParams,
+    SetManifestForProjectParams, SupportsFeatureParams, UnregisterProjectFolderParams,
 };
 use biome_service::workspace::{RageEntry, RageParams, RageResult, UpdateSettingsParams};
 use biome_service::Workspace;
 use biome_service::{DynRef, WorkspaceError};
 use futures::stream::futures_unordered::FuturesUnordered;
 use futures::StreamExt;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde_json::Value;
-use std::ffi::OsStr;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::Ordering;
-use std::sync::atomic::{AtomicBool, AtomicU8};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8};
 use std::sync::Arc;
 use std::sync::RwLock;
 use tokio::sync::Notify;
@@ -74,11 +73,19 @@ pub(crate) struct Session {
     /// to update the diagnostics
     notified_broken_configuration: AtomicBool,
 
+    /// Incremented each time the settings change, it's part of the identifier of the
+    /// diagnostics pulled by the client
+    diagnostics_generation: AtomicU64,
+
     /// File system to read files inside the workspace
     pub(crate) fs: DynRef<'static, dyn FileSystem>,
 
     documents: RwLock<FxHashMap<lsp_types::Url, Document>>,
 
+    /// The root directories of the loaded projects: the workspace folders, and the packages
+    /// of a monorepo that have their own configuration file
+    projects: RwLock<FxHashSet<PathBuf>>,
+
     pub(crate) cancellation: Arc<Notify>,
 
     pub(crate) config_path: Option<PathBuf>,
@@ -176,12 +183,14 @@ impl Session {
             workspace,
             configuration_status: AtomicU8::new(ConfigurationStatus::Missing as u8),
             documents,
+            projects: Default::default(),
             extension_settings: config,
             fs,
             cancellation,
             config_path: None,
             manifest_path: None,
             notified_broken_configuration: AtomicBool::new(false),
+            diagnostics_generation: AtomicU64::new(0),
         }
     }
 
@@ -272,6 +281,17 @@ impl Session {
             .ok_or_else(|| WorkspaceError::not_found().with_file_path(url.to_string()))
     }
 
+    /// Returns the line index of the file at `url`, which is read from the disk when
+    /// the file isn't open in the editor
+    pub(crate) fn line_index(&self, url: &lsp_types::Url) -> Option<LineIndex> {
+        if let Ok(document) = self.document(url) {
+            return Some(document.line_index);
+        }
+        let path = url.to_file_path().ok()?;
+        let content = self.fs.read_file_from_path(&path).ok()?;
+        Some(LineIndex::new(&content))
+    }
+
     /// Set the [`Document`] for the provided [`lsp_types::Url`]
     ///
     /// Used by [`handlers::text_document] to synchronize documents with the client.
@@ -300,10 +320,33 @@ impl Session {
     /// Computes diagnostics for the file matching the provided url and publishes
     /// them to the client. Called from [`handlers::text_document`] when a file's
     /// contents changes.
+    ///
+    /// Nothing is published when the client pulls the diagnostics.
     #[tracing::instrument(level = "trace", skip_all, fields(url = display(&url), diagnostic_count), err)]
     pub(crate) async fn update_diagnostics(&self, url: lsp_types::Url) -> Result<(), LspError> {
-        let biome_path = self.file_path(&url)?;
+        if self.supports_pull_diagnostics() {
+            return Ok(());
+        }
+
         let doc = self.document(&url)?;
+        let diagnostics = self.compute_diagnostics(&url, &doc).await?;
+
+        tracing::Span::current().record("diagnostic_count", diagnostics.len());
+
+        self.client
+            .publish_diagnostics(url, diagnostics, Some(doc.version))
+            .await;
+
+        Ok(())
+    }
+
+    /// Computes the diagnostics of the document `doc`, opened at `url`
+    pub(crate) async fn compute_diagnostics(
+        &self,
+        url: &lsp_types::Url,
+        doc: &Document,
+    ) -> Result<Vec<Diagnostic>, LspError> {
+        let biome_path = self.file_path(url)?;
         if self.configuration_status().is_error() && !self.notified_broken_configuration() {
             self.set_notified_broken_configuration();
             self.client
@@ -323,10 +366,7 @@ impl Session {
             && !file_features.supports_organize_imports()
             && !file_features.supports_assists()
         {
-            self.client
-                .publish_diagnostics(url, vec![], Some(doc.version))
-                .await;
-            return Ok(());
+            return Ok(Vec::new());
         }
 
         let diagnostics: Vec<Diagnostic> = {
@@ -348,26 +388,16 @@ impl Session {
             })?;
 
             tracing::trace!("biome diagnostics: {:#?}", result.diagnostics);
-            let content = self.workspace.get_file_content(GetFileContentParams {
-                path: biome_path.clone(),
-            })?;
-            let offset = match biome_path.extension().map(OsStr::as_encoded_bytes) {
-                Some(b"vue") => VueFileHandler::start(content.as_str()),
-                Some(b"astro") => AstroFileHandler::start(content.as_str()),
-                Some(b"svelte") => SvelteFileHandler::start(content.as_str()),
-                _ => None,
-            };
-
             result
                 .diagnostics
                 .into_iter()
                 .filter_map(|d| {
                     match utils::diagnostic_to_lsp(
                         d,
-                        &url,
+                        url,
                         &doc.line_index,
                         self.position_encoding(),
-                        offset,
+                        None,
                     ) {
                         Ok(diag) => Some(diag),
                         Err(err) => {
@@ -379,22 +409,54 @@ impl Session {
                 .collect()
         };
 
-        tracing::Span::current().record("diagnostic_count", diagnostics.len());
-
-        self.client
-            .publish_diagnostics(url, diagnostics, Some(doc.version))
-            .await;
+        Ok(diagnostics)
+    }
 
-        Ok(())
+    /// Returns the identifier of the diagnostics of `doc`, which changes when the document
+    /// or the settings change, so that the client doesn't pull the same diagnostics again
+    pub(crate) fn diagnostics_result_id(&self, doc: &Document) -> String {
+        let generation = self.diagnostics_generation.load(Ordering::Relaxed);
+        format!("{generation}:{}", doc.version)
     }
 
     /// Updates diagnostics for every [`Document`] in this [`Session`]
+    ///
+    /// When the client pulls the diagnostics, it's asked to pull them again instead.
     pub(crate) async fn update_all_diagnostics(&self) {
+        self.update_documents_diagnostics(None).await;
+    }
+
+    /// Updates diagnostics for the documents of the project rooted in `directory`, after its
+    /// settings changed
+    pub(crate) async fn update_project_diagnostics(&self, directory: &Path) {
+        self.update_documents_diagnostics(Some(directory)).await;
+    }
+
+    async fn update_documents_diagnostics(&self, directory: Option<&Path>) {
+        // The settings changed, so the diagnostics pulled until now are outdated
+        self.diagnostics_generation.fetch_add(1, Ordering::Relaxed);
+        // The client pulls the diagnostics of all its documents again, it can't be asked to
+        // pull only some of them
+        if self.supports_pull_diagnostics() {
+            if self.can_refresh_diagnostics() {
+                if let Err(error) = self.client.workspace_diagnostic_refresh().await {
+                    error!("Error while refreshing the diagnostics: {}", error);
+                }
+            }
+            return;
+        }
+
         let mut futures: FuturesUnordered<_> = self
             .documents
             .read()
             .unwrap()
             .keys()
+            .filter(|url| {
+                directory.map_or(true, |directory| {
+                    url.to_file_path()
+                        .is_ok_and(|path| path.starts_with(directory))
+                })
+            })
             .map(|url| self.update_diagnostics(url.clone()))
             .collect();
 
@@ -405,6 +467,24 @@ impl Session {
         }
     }
 
+    /// True if the client pulls the diagnostics with "textDocument/diagnostic" requests
+    pub(crate) fn supports_pull_diagnostics(&self) -> bool {
+        self.initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.text_document.as_ref())
+            .is_some_and(|c| c.diagnostic.is_some())
+    }
+
+    /// True if the client supports the "workspace/diagnostic/refresh" requests
+    fn can_refresh_diagnostics(&self) -> bool {
+        self.initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.workspace.as_ref())
+            .and_then(|c| c.diagnostic.as_ref())
+            .and_then(|c| c.refresh_support)
+            == Some(true)
+    }
+
     /// True if the client supports dynamic registration of "workspace/didChangeConfiguration" requests
     pub(crate) fn can_register_did_change_configuration(&self) -> bool {
         self.initialize_params
@@ -485,6 +565,74 @@ impl Session {
         }
     }
 
+    /// Loads the configuration file of the project rooted in `directory`: a workspace folder,
+    /// or a package of a monorepo that has its own configuration file. A project that is
+    /// loaded again keeps its key, and its settings are replaced.
+    pub(crate) async fn load_project(&self, directory: &Path) {
+        let status = self
+            .load_biome_configuration_file(ConfigurationPathHint::FromWorkspace(
+                directory.to_path_buf(),
+            ))
+            .await;
+        self.set_configuration_status(status);
+    }
+
+    /// Loads the project of the configuration file nearest to `path`, when it isn't loaded yet,
+    /// so that the files of the packages of a monorepo use the configuration of their package
+    pub(crate) async fn load_project_of(&self, path: &Path) {
+        // The configuration provided by the user applies to every file
+        if self.config_path.is_some() {
+            return;
+        }
+        let Some(directory) = self
+            .configuration_file_path(path)
+            .and_then(|file_path| file_path.parent().map(Path::to_path_buf))
+        else {
+            return;
+        };
+        if self.projects.read().unwrap().contains(&directory) {
+            return;
+        }
+        info!("Load the project in {}", directory.display());
+        self.load_project(&directory).await;
+    }
+
+    /// Returns true if a project is loaded from the configuration file in `directory`
+    pub(crate) fn is_project_root(&self, directory: &Path) -> bool {
+        self.projects.read().unwrap().contains(directory)
+    }
+
+    /// Removes the projects rooted in `directory` and in its subdirectories
+    pub(crate) fn unload_projects(&self, directory: &Path) -> Result<(), WorkspaceError> {
+        let mut removed = vec![directory.to_path_buf()];
+        {
+            let mut projects = self.projects.write().unwrap();
+            removed.extend(
+                projects
+                    .iter()
+                    .filter(|root| root.starts_with(directory) && *root != directory)
+                    .cloned(),
+            );
+            projects.retain(|root| !root.starts_with(directory));
+        }
+        for root in removed {
+            self.workspace
+                .unregister_project_folder(UnregisterProjectFolderParams {
+                    path: BiomePath::new(root),
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Returns the path of the configuration file that applies to a file, if there's one
+    pub(crate) fn configuration_file_path(&self, path: &Path) -> Option<PathBuf> {
+        let base_path = match &self.config_path {
+            Some(config_path) => ConfigurationPathHint::FromUser(config_path.clone()),
+            None => ConfigurationPathHint::FromLsp(path.parent()?.to_path_buf()),
+        };
+        load_configuration(&self.fs, base_path).ok()?.file_path
+    }
+
     async fn load_biome_configuration_file(
         &self,
         base_path: ConfigurationPathHint,
@@ -579,6 +727,13 @@ impl Session {
                                 self.client.log_message(MessageType::ERROR, &error).await;
                                 ConfigurationStatus::Error
                             } else {
+                                // The files of these directories don't need to load their
+                                // project again
+                                let mut projects = self.projects.write().unwrap();
+                                if let ConfigurationPathHint::FromWorkspace(path) = &base_path {
+                                    projects.insert(path.clone());
+                                }
+                                projects.extend(configuration_path);
                                 ConfigurationStatus::Loaded
                             }
                         }
@@ -2,10 +2,11 @@ use anyhow::{ensure, Context, Result};
 use biome_analyze::ActionCategory;
 use biome_console::fmt::Termcolor;
 use biome_console::fmt::{self, Formatter};
-use biome_console::MarkupBuf;
+use biome_console::{markup, MarkupBuf};
 use biome_diagnostics::termcolor::NoColor;
 use biome_diagnostics::{
-    Applicability, {Diagnostic, DiagnosticTags, Location, PrintDescription, Severity, Visit},
+    Applicability, LogCategory,
+    {Diagnostic, DiagnosticTags, Location, PrintDescription, Severity, Visit},
 };
 use biome_lsp_converters::line_index::LineIndex;
 use biome_lsp_converters::{from_proto, to_proto, PositionEncoding};
@@ -215,6 +216,7 @@ pub(crate) fn diagnostic_to_lsp<D: Diagnostic>(
         line_index,
         position_encoding,
         related_information: &mut related_information,
+        last_log: None,
     };
 
     diagnostic.advices(&mut visitor).unwrap();
@@ -256,9 +258,16 @@ struct RelatedInformationVisitor<'a> {
     line_index: &'a LineIndex,
     position_encoding: PositionEncoding,
     related_information: &'a mut Option<Vec<lsp::DiagnosticRelatedInformation>>,
+    /// The message of the last log advice, which usually describes the code frame that follows it
+    last_log: Option<String>,
 }
 
 impl Visit for RelatedInformationVisitor<'_> {
+    fn record_log(&mut self, _: LogCategory, text: &dyn fmt::Display) -> io::Result<()> {
+        self.last_log = Some(print_markup(&markup!({ text }).to_owned()));
+        Ok(())
+    }
+
     fn record_frame(&mut self, location: Location<'_>) -> io::Result<()> {
         let span = match location.span {
             Some(span) => span,
@@ -277,7 +286,7 @@ impl Visit for RelatedInformationVisitor<'_> {
                 uri: self.url.clone(),
                 range,
             },
-            message: String::new(),
+            message: self.last_log.take().unwrap_or_default(),
         });
 
         Ok(())
@@ -0,0 +1,384 @@
+//! Watches the files of the workspace on the disk, so that the server stays up to date when
+//! they change outside of the editor, for example when switching to another branch.
+//!
+//! The editors only notify the changes of the configuration files, which they are asked to
+//! watch with `workspace/didChangeWatchedFiles`. The watcher also notifies the changes of the
+//! other files, which are indexed again so that the module graph and the rules that look at
+//! the other files of the project use their new content.
+//!
+//! The files of the workspace are indexed once when the session starts, so that these rules
+//! also know about the files that aren't opened in the editor. The snapshot of the project index
+//! is then saved in the cache directory, and the next sessions import it for the files whose
+//! content didn't change, instead of parsing them again.
+
+use crate::server::reload_configuration_files;
+use crate::session::{Session, SessionHandle};
+use biome_diagnostics::Error;
+use biome_fs::{
+    ensure_cache_dir, BiomePath, ConfigName, FileSystemExt, PathInterner, TraversalContext,
+    TraversalScope, ROME_JSON,
+};
+use biome_service::file_handlers::DocumentFileSource;
+use biome_service::project_index::content_hash;
+use biome_service::workspace::{
+    ExportProjectIndexParams, FeaturesBuilder, FileGuard, ForgetFilesParams,
+    ImportProjectIndexParams, IsPathIgnoredParams, OpenFileParams,
+};
+use notify::event::{EventKind, ModifyKind};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use rustc_hash::FxHashSet;
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tokio::sync::mpsc::unbounded_channel;
+use tokio::task::spawn_blocking;
+use tower_lsp::lsp_types::Url;
+use tracing::{info, warn};
+
+/// The directories whose files are never indexed, and change too often to be watched
+const IGNORED_DIRECTORIES: &[&str] = &[".git", "node_modules"];
+
+/// Watches the files in some directories, and in their subdirectories, until it's dropped.
+///
+/// It's used by the server, and by the watch mode of the CLI.
+pub struct FileWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+impl FileWatcher {
+    /// Starts watching the files in `roots`, and calls `on_change` with the path of each file
+    /// whose content changed, or that was removed. The changes in the `.git` and `node_modules`
+    /// directories are ignored.
+    ///
+    /// It returns an error if the file system of the platform can't be watched.
+    pub fn new<F>(roots: &[PathBuf], on_change: F) -> io::Result<Self>
+    where
+        F: Fn(PathBuf) + Send + 'static,
+    {
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<Event>| match event {
+                Ok(event) => {
+                    if is_content_change(&event.kind) {
+                        for path in event.paths {
+                            if !is_in_ignored_directory(&path) {
+                                on_change(path);
+                            }
+                        }
+                    }
+                }
+                Err(error) => warn!("Failed to watch the files: {error}"),
+            })
+            .map_err(io::Error::other)?;
+        for root in roots {
+            watcher
+                .watch(root, RecursiveMode::Recursive)
+                .map_err(io::Error::other)?;
+        }
+        Ok(Self { _watcher: watcher })
+    }
+}
+
+/// Starts watching the files in `base_path`, and in its subdirectories.
+///
+/// Returns `None` if the file system of the platform can't be watched, in which case the
+/// server relies on the notifications of the editor only.
+pub(crate) fn watch(session: &SessionHandle, base_path: &Path) -> Option<FileWatcher> {
+    let (sender, mut receiver) = unbounded_channel();
+    let watcher = FileWatcher::new(&[base_path.to_path_buf()], move |path| {
+        let _ = sender.send(path);
+    });
+    let watcher = match watcher {
+        Ok(watcher) => watcher,
+        Err(error) => {
+            warn!("Failed to watch {}: {error}", base_path.display());
+            return None;
+        }
+    };
+    info!("Watching the files in {}", base_path.display());
+
+    // The task ends once the watcher is dropped, along with the sender of the events
+    let session = session.clone();
+    tokio::spawn(async move {
+        while let Some(path) = receiver.recv().await {
+            // A single change, such as a checkout, emits a burst of events
+            let mut paths = FxHashSet::from_iter([path]);
+            while let Ok(path) = receiver.try_recv() {
+                paths.insert(path);
+            }
+            did_change_files(&session, paths).await;
+        }
+    });
+
+    Some(watcher)
+}
+
+/// Indexes the files in `base_path`, and in its subdirectories, then updates the diagnostics
+/// of the opened documents, which may depend on them. The files that didn't change since the
+/// snapshot saved by the previous session are imported from it, and the new snapshot is saved
+/// once the files are indexed.
+///
+/// It runs in the background, so that the editor doesn't wait for the whole workspace to be
+/// read before the first diagnostics.
+pub(crate) fn index_workspace(session: &SessionHandle, base_path: &Path) {
+    let session = session.clone();
+    let base_path = base_path.to_path_buf();
+    tokio::spawn(async move {
+        let indexed = {
+            let session = session.clone();
+            spawn_blocking(move || {
+                let (interner, _) = PathInterner::new();
+                let files = WorkspaceFiles {
+                    session: &session,
+                    interner,
+                    paths: Mutex::default(),
+                };
+                let context = &files;
+                session
+                    .fs
+                    .traversal(Box::new(move |scope: &dyn TraversalScope| {
+                        scope.evaluate(context, base_path);
+                    }));
+                let paths = files.paths.into_inner().unwrap_or_default();
+                let location = project_index_location(&base_path);
+                let paths = import_project_index(&session, &location, paths);
+                info!("Indexing {} files of the workspace", paths.len());
+                let indexed = index_files(&session, paths);
+                save_project_index(&session, &location);
+                indexed
+            })
+            .await
+            .unwrap_or_default()
+        };
+        if indexed {
+            session.update_all_diagnostics().await;
+        }
+    });
+}
+
+/// Returns the file where the snapshot of the project index of the workspace in `base_path` is
+/// saved. It's in the cache directory of Biome, so that nothing is written in the workspace.
+fn project_index_location(base_path: &Path) -> PathBuf {
+    let workspace_hash = content_hash(&base_path.to_string_lossy());
+    ensure_cache_dir().join(format!("biome-index-{workspace_hash}.json"))
+}
+
+/// Imports the snapshot of the project index saved at `location` for the files among `paths`
+/// whose content didn't change since it was saved. Returns the other files, which need to be
+/// indexed.
+fn import_project_index(session: &Session, location: &Path, paths: Vec<PathBuf>) -> Vec<PathBuf> {
+    let Ok(index) = session.fs.read_file_from_path(&location.to_path_buf()) else {
+        return paths;
+    };
+    let content_hashes = paths
+        .iter()
+        .filter_map(|path| {
+            let path = BiomePath::new(path);
+            let content = read_file_to_index(session, &path)?;
+            Some((path, content_hash(&content)))
+        })
+        .collect();
+    let result = session
+        .workspace
+        .import_project_index(ImportProjectIndexParams {
+            index,
+            content_hashes,
+        });
+    let imported: FxHashSet<_> = match result {
+        Ok(result) => result.imported.into_iter().collect(),
+        Err(error) => {
+            warn!("Failed to import the project index: {error}");
+            return paths;
+        }
+    };
+    info!("Imported the index of {} files", imported.len());
+    paths
+        .into_iter()
+        .filter(|path| !imported.contains(&BiomePath::new(path)))
+        .collect()
+}
+
+/// Saves the snapshot of the project index at `location`, for the next sessions
+fn save_project_index(session: &Session, location: &Path) {
+    let result = session
+        .workspace
+        .export_project_index(ExportProjectIndexParams {})
+        .map_err(|error| error.to_string())
+        .and_then(|result| {
+            session
+                .fs
+                .create(location)
+                .and_then(|mut file| file.set_content(result.index.as_bytes()))
+                .map_err(|error| error.to_string())
+        });
+    if let Err(error) = result {
+        warn!("Failed to save the project index: {error}");
+    }
+}
+
+/// Collects the files of the workspace that can be part of the project index
+struct WorkspaceFiles<'a> {
+    session: &'a Session,
+    interner: PathInterner,
+    paths: Mutex<Vec<PathBuf>>,
+}
+
+impl TraversalContext for WorkspaceFiles<'_> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    // The files that can't be read aren't indexed, there's nothing to report
+    fn push_diagnostic(&self, _error: Error) {}
+
+    fn can_handle(&self, path: &BiomePath) -> bool {
+        if is_in_ignored_directory(path) {
+            return false;
+        }
+        self.session.fs.path_is_dir(path)
+            || matches!(
+                DocumentFileSource::from_path(path),
+                DocumentFileSource::Css(_)
+                    | DocumentFileSource::Json(_)
+                    | DocumentFileSource::Graphql(_)
+                    | DocumentFileSource::Js(_)
+            )
+    }
+
+    fn handle_path(&self, _path: BiomePath) {}
+
+    fn store_path(&self, path: BiomePath) {
+        self.paths.lock().unwrap().push(path.to_path_buf());
+    }
+
+    fn evaluated_paths(&self) -> BTreeSet<BiomePath> {
+        self.paths
+            .lock()
+            .unwrap()
+            .iter()
+            .map(BiomePath::new)
+            .collect()
+    }
+}
+
+/// Handles the files that changed on the disk
+async fn did_change_files(session: &SessionHandle, paths: FxHashSet<PathBuf>) {
+    let (configuration_files, other_files): (Vec<_>, Vec<_>) = paths
+        .into_iter()
+        .partition(|path| is_configuration_file(path));
+
+    let indexed = {
+        let session = session.clone();
+        spawn_blocking(move || index_files(&session, other_files))
+            .await
+            .unwrap_or_default()
+    };
+
+    if !configuration_files.is_empty() {
+        reload_configuration_files(session, configuration_files).await;
+    }
+    // The diagnostics of the documents may depend on the other files of the project
+    if indexed {
+        session.update_all_diagnostics().await;
+    }
+}
+
+/// Indexes again the files that changed, and forgets the ones that were removed. The documents
+/// opened in the editor are skipped, because their content is the one of the editor.
+///
+/// Returns true if the project index was updated.
+fn index_files(session: &Session, paths: Vec<PathBuf>) -> bool {
+    let mut removed = Vec::new();
+    let mut indexed = false;
+    for path in paths {
+        let is_opened = Url::from_file_path(&path).is_ok_and(|url| session.document(&url).is_ok());
+        if is_opened {
+            continue;
+        }
+        let biome_path = BiomePath::new(&path);
+        if !session.fs.path_exists(&path) {
+            removed.push(biome_path);
+            continue;
+        }
+        let Some(content) = read_file_to_index(session, &biome_path) else {
+            continue;
+        };
+
+        // The guard closes the file right away, but the workspace retains what it indexed
+        let result = FileGuard::open(
+            session.workspace.as_ref(),
+            OpenFileParams {
+                path: biome_path,
+                content,
+                version: 0,
+                document_file_source: None,
+            },
+        );
+        indexed |= result.is_ok();
+    }
+
+    if !removed.is_empty() {
+        let result = session
+            .workspace
+            .forget_files(ForgetFilesParams { paths: removed });
+        indexed |= result.is_ok();
+    }
+    indexed
+}
+
+/// Returns the content of the file at `path` if it's part of the project index
+fn read_file_to_index(session: &Session, path: &BiomePath) -> Option<String> {
+    let file_source = DocumentFileSource::from_path(path);
+    if !matches!(
+        file_source,
+        DocumentFileSource::Css(_)
+            | DocumentFileSource::Json(_)
+            | DocumentFileSource::Graphql(_)
+            | DocumentFileSource::Js(_)
+    ) {
+        return None;
+    }
+    let is_ignored = session
+        .workspace
+        .is_path_ignored(IsPathIgnoredParams {
+            biome_path: path.clone(),
+            features: FeaturesBuilder::new().with_linter().build(),
+        })
+        .unwrap_or(true);
+    if is_ignored {
+        return None;
+    }
+
+    let content = session.fs.read_file_from_path(&path.to_path_buf()).ok()?;
+    // Only the JSON files that look like schemas are indexed
+    if matches!(file_source, DocumentFileSource::Json(_)) && !content.contains("json-schema.org") {
+        return None;
+    }
+    Some(content)
+}
+
+/// Returns true if the event may have changed the content of the files, or removed them
+fn is_content_change(kind: &EventKind) -> bool {
+    match kind {
+        EventKind::Any | EventKind::Create(_) | EventKind::Remove(_) => true,
+        EventKind::Modify(kind) => !matches!(kind, ModifyKind::Metadata(_)),
+        EventKind::Access(_) | EventKind::Other => false,
+    }
+}
+
+fn is_in_ignored_directory(path: &Path) -> bool {
+    path.components().any(|component| {
+        IGNORED_DIRECTORIES
+            .iter()
+            .any(|directory| component.as_os_str() == *directory)
+    })
+}
+
+/// Returns true if the file at `path` is a configuration file, or an `.editorconfig` file
+pub(crate) fn is_configuration_file(path: &Path) -> bool {
+    let file_name = path.file_name().and_then(OsStr::to_str).unwrap_or_default();
+    file_name == ROME_JSON
+        || file_name == ".editorconfig"
+        || ConfigName::file_names().contains(&file_name)
+}
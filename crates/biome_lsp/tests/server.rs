@@ -692,7 +692,7 @@ async fn pull_diagnostics() -> Result<()> {
                                 },
                             },
                         },
-                        message: String::new(),
+                        message: String::from("== is only allowed when comparing against null"),
                     }]),
                     tags: None,
                     data: None,
@@ -836,7 +836,7 @@ async fn pull_diagnostics_from_new_file() -> Result<()> {
                                 },
                             },
                         },
-                        message: String::new(),
+                        message: String::from("== is only allowed when comparing against null"),
                     }]),
                     tags: None,
                     data: None,
@@ -853,6 +853,111 @@ async fn pull_diagnostics_from_new_file() -> Result<()> {
     Ok(())
 }
 
+// The `root_path` field is deprecated, but we still need to specify it
+#[allow(deprecated)]
+#[tokio::test]
+async fn pull_diagnostics_with_document_diagnostic_requests() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    let result: InitializeResult = server
+        .request(
+            "initialize",
+            "_init",
+            lsp::InitializeParams {
+                process_id: None,
+                root_path: None,
+                root_uri: Some(url!("")),
+                initialization_options: None,
+                capabilities: lsp::ClientCapabilities {
+                    text_document: Some(lsp::TextDocumentClientCapabilities {
+                        diagnostic: Some(lsp::DiagnosticClientCapabilities::default()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                trace: None,
+                workspace_folders: None,
+                client_info: None,
+                locale: None,
+            },
+        )
+        .await?
+        .context("initialize returned None")?;
+    assert!(result.capabilities.diagnostic_provider.is_some());
+    server.initialized().await?;
+
+    server.open_document("if(a == b) {}").await?;
+
+    let pull = |previous_result_id: Option<String>| lsp::DocumentDiagnosticParams {
+        text_document: TextDocumentIdentifier {
+            uri: url!("document.js"),
+        },
+        identifier: None,
+        previous_result_id,
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    let report: lsp::DocumentDiagnosticReportResult = server
+        .request("textDocument/diagnostic", "pull_diagnostics", pull(None))
+        .await?
+        .context("textDocument/diagnostic returned None")?;
+    let lsp::DocumentDiagnosticReportResult::Report(lsp::DocumentDiagnosticReport::Full(report)) =
+        report
+    else {
+        panic!("expected a full report, got {report:?}");
+    };
+    let report = report.full_document_diagnostic_report;
+    assert_eq!(report.items.len(), 1);
+    assert_eq!(
+        report.items[0].code,
+        Some(lsp::NumberOrString::String(String::from(
+            "lint/suspicious/noDoubleEquals"
+        )))
+    );
+
+    // The document didn't change, so the diagnostics pulled before are still valid
+    let result_id = report.result_id.context("the report has no result id")?;
+    let report: lsp::DocumentDiagnosticReportResult = server
+        .request(
+            "textDocument/diagnostic",
+            "pull_diagnostics_again",
+            pull(Some(result_id.clone())),
+        )
+        .await?
+        .context("textDocument/diagnostic returned None")?;
+    assert_eq!(
+        report,
+        lsp::DocumentDiagnosticReportResult::Report(lsp::DocumentDiagnosticReport::Unchanged(
+            lsp::RelatedUnchangedDocumentDiagnosticReport {
+                related_documents: None,
+                unchanged_document_diagnostic_report: lsp::UnchangedDocumentDiagnosticReport {
+                    result_id
+                },
+            }
+        ))
+    );
+
+    // The diagnostics are pulled by the client, so they aren't published
+    let notification = tokio::select! {
+        msg = receiver.next() => msg,
+        _ = sleep(Duration::from_millis(100)) => None,
+    };
+    assert_eq!(notification, None);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn pull_quick_fixes() -> Result<()> {
     let factory = ServerFactory::default();
@@ -1836,6 +1941,389 @@ async fn does_not_pull_action_for_disabled_rule_in_override_issue_2782() -> Resu
     Ok(())
 }
 
+#[tokio::test]
+async fn pull_code_lenses_of_suppressions_and_diagnostics() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let config = r#"{
+    "linter": {
+        "enabled": true
+    }
+}"#;
+
+    fs.insert(url!("biome.json").to_file_path().unwrap(), config);
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+    server.load_configuration().await?;
+    server
+        .open_document(
+            "// biome-ignore lint/suspicious/noDebugger: testing\ndebugger;\nif (a == b) {}\n",
+        )
+        .await?;
+
+    let lenses: Vec<lsp::CodeLens> = server
+        .request(
+            "textDocument/codeLens",
+            "pull_code_lenses",
+            lsp::CodeLensParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeLens returned None")?;
+
+    let lenses: Vec<_> = lenses
+        .into_iter()
+        .filter_map(|lens| Some((lens.range.start.line, lens.command?.title)))
+        .collect();
+    assert_eq!(
+        lenses,
+        vec![
+            (
+                0,
+                String::from("noDebugger: Disallow the use of `debugger`")
+            ),
+            (2, String::from("Disable noDoubleEquals for this file")),
+            (2, String::from("Disable noDoubleEquals in biome.json")),
+        ]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn completes_the_configuration_and_the_suppressions() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    let config = r#"{
+  "javascript": { "formatter": { "quoteStyle": "" } },
+  "linter": { "rules": { "suspicious": { "": "error" } } }
+}"#;
+    server
+        .open_named_document(config, url!("biome.json"), "json")
+        .await?;
+    server
+        .open_document("// biome-ignore lint/suspicious/noDeb\n// biome-ignore lint/suspicious/noDebugger \ndebugger;\n")
+        .await?;
+
+    let mut completions = Vec::new();
+    for (uri, line, character) in [
+        (url!("biome.json"), 1, 48),
+        (url!("biome.json"), 2, 42),
+        (url!("document.js"), 0, 37),
+        (url!("document.js"), 1, 43),
+    ] {
+        let response: lsp::CompletionResponse = server
+            .request(
+                "textDocument/completion",
+                "completion",
+                lsp::CompletionParams {
+                    text_document_position: lsp::TextDocumentPositionParams {
+                        text_document: TextDocumentIdentifier { uri },
+                        position: Position { line, character },
+                    },
+                    work_done_progress_params: WorkDoneProgressParams {
+                        work_done_token: None,
+                    },
+                    partial_result_params: lsp::PartialResultParams {
+                        partial_result_token: None,
+                    },
+                    context: None,
+                },
+            )
+            .await?
+            .context("completion returned None")?;
+        let lsp::CompletionResponse::Array(items) = response else {
+            bail!("expected a list of completions");
+        };
+        completions.push(items);
+    }
+
+    let quote_styles: Vec<_> = completions[0]
+        .iter()
+        .map(|item| item.label.as_str())
+        .collect();
+    assert_eq!(quote_styles, ["\"double\"", "\"single\""]);
+
+    let no_debugger = completions[1]
+        .iter()
+        .find(|item| item.label == "noDebugger")
+        .context("noDebugger isn't completed")?;
+    assert_eq!(
+        no_debugger.detail.as_deref(),
+        Some("Disallow the use of `debugger`")
+    );
+    assert_eq!(
+        no_debugger.text_edit,
+        Some(lsp::CompletionTextEdit::Edit(TextEdit {
+            range: Range {
+                start: Position {
+                    line: 2,
+                    character: 41,
+                },
+                end: Position {
+                    line: 2,
+                    character: 43,
+                },
+            },
+            new_text: String::from("\"noDebugger\""),
+        }))
+    );
+
+    assert!(completions[2]
+        .iter()
+        .any(|item| item.label == "lint/suspicious/noDebugger"));
+    assert_eq!(completions[3][0].label, ":");
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn hover_shows_the_rules_and_the_resolved_imports() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let config = r#"{
+    "linter": {
+        "rules": { "suspicious": { "noDebugger": "warn" } }
+    }
+}"#;
+
+    fs.insert(url!("biome.json").to_file_path().unwrap(), config);
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+    server.load_configuration().await?;
+    server
+        .open_named_document("export const one = 1;\n", url!("utils.ts"), "typescript")
+        .await?;
+    server
+        .open_document("import { one } from \"./utils.ts\";\ndebugger;\n")
+        .await?;
+
+    let mut hovers = Vec::new();
+    for (line, character) in [(1, 2), (0, 25)] {
+        let hover: lsp::Hover = server
+            .request(
+                "textDocument/hover",
+                "hover",
+                lsp::HoverParams {
+                    text_document_position_params: lsp::TextDocumentPositionParams {
+                        text_document: TextDocumentIdentifier {
+                            uri: url!("document.js"),
+                        },
+                        position: Position { line, character },
+                    },
+                    work_done_progress_params: WorkDoneProgressParams {
+                        work_done_token: None,
+                    },
+                },
+            )
+            .await?
+            .context("hover returned None")?;
+        let lsp::HoverContents::Markup(contents) = hover.contents else {
+            bail!("expected markup contents");
+        };
+        hovers.push(contents.value);
+    }
+
+    assert!(hovers[0].starts_with(
+        "**lint/suspicious/noDebugger**\n\nDisallow the use of `debugger`\n\nConfigured with:\n```json\n\"warn\"\n```"
+    ));
+    assert!(hovers[1].starts_with("Resolved to `"));
+    assert!(hovers[1].ends_with("utils.ts`"));
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn documents_use_the_configuration_of_their_nearest_project() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let config = r#"{
+    "linter": {
+        "rules": { "suspicious": { "noDebugger": "warn" } }
+    }
+}"#;
+    let package_config = r#"{
+    "linter": {
+        "rules": { "suspicious": { "noDebugger": "error" } }
+    }
+}"#;
+
+    fs.insert(url!("biome.json").to_file_path().unwrap(), config);
+    fs.insert(
+        url!("packages/a/biome.json").to_file_path().unwrap(),
+        package_config,
+    );
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+    server.load_configuration().await?;
+    server
+        .open_named_document("debugger;\n", url!("packages/a/index.js"), "javascript")
+        .await?;
+    server.open_document("debugger;\n").await?;
+
+    let mut hovers = Vec::new();
+    for uri in [url!("packages/a/index.js"), url!("document.js")] {
+        let hover: lsp::Hover = server
+            .request(
+                "textDocument/hover",
+                "hover",
+                lsp::HoverParams {
+                    text_document_position_params: lsp::TextDocumentPositionParams {
+                        text_document: TextDocumentIdentifier { uri },
+                        position: Position {
+                            line: 0,
+                            character: 2,
+                        },
+                    },
+                    work_done_progress_params: WorkDoneProgressParams {
+                        work_done_token: None,
+                    },
+                },
+            )
+            .await?
+            .context("hover returned None")?;
+        let lsp::HoverContents::Markup(contents) = hover.contents else {
+            bail!("expected markup contents");
+        };
+        hovers.push(contents.value);
+    }
+
+    assert!(hovers[0].contains("Configured with:\n```json\n\"error\"\n```"));
+    assert!(hovers[1].contains("Configured with:\n```json\n\"warn\"\n```"));
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn resolves_the_configuration_of_a_document() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let config = r#"{
+    "formatter": { "indentStyle": "space" },
+    "linter": {
+        "rules": { "suspicious": { "noDebugger": "warn" } }
+    },
+    "overrides": [
+        {
+            "include": ["*.test.js"],
+            "linter": {
+                "rules": { "suspicious": { "noDebugger": "off" } }
+            }
+        }
+    ]
+}"#;
+
+    fs.insert(url!("biome.json").to_file_path().unwrap(), config);
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+    server.load_configuration().await?;
+
+    let mut configurations = Vec::new();
+    for uri in [url!("document.js"), url!("document.test.js")] {
+        let configuration: serde_json::Value = server
+            .request(
+                "biome/resolveConfig",
+                "resolve_config",
+                serde_json::json!({ "textDocument": { "uri": uri } }),
+            )
+            .await?
+            .context("biome/resolveConfig returned None")?;
+        configurations.push(configuration);
+    }
+
+    for configuration in &configurations {
+        assert_eq!(configuration["formatter"]["indentStyle"], "space");
+        assert!(configuration.get("overrides").is_none());
+    }
+    assert_eq!(
+        configurations[0]["linter"]["rules"]["suspicious"]["noDebugger"],
+        "warn"
+    );
+    assert_eq!(
+        configurations[1]["linter"]["rules"]["suspicious"]["noDebugger"],
+        "off"
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn pull_refactors() -> Result<()> {
     let factory = ServerFactory::default();
@@ -2049,6 +2537,94 @@ async fn pull_fix_all() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn pull_assist_action_by_kind() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let config = r#"{
+        "assists": {
+            "enabled": true,
+            "actions": { "source": { "useSortedKeys": "on" } }
+        }
+    }"#;
+
+    fs.insert(url!("biome.json").to_file_path().unwrap(), config);
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_named_document(
+            r#"{ "zod": true, "lorem": "ipsum" }"#,
+            url!("document.json"),
+            "json",
+        )
+        .await?;
+
+    let res: lsp::CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "pull_code_actions",
+            lsp::CodeActionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.json"),
+                },
+                range: Range {
+                    start: Position {
+                        line: 0,
+                        character: 0,
+                    },
+                    end: Position {
+                        line: 0,
+                        character: 33,
+                    },
+                },
+                context: lsp::CodeActionContext {
+                    diagnostics: vec![],
+                    only: Some(vec![lsp::CodeActionKind::new(
+                        "source.action.useSortedKeys.biome",
+                    )]),
+                    ..Default::default()
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    let kinds: Vec<_> = res
+        .iter()
+        .filter_map(|action| match action {
+            lsp::CodeActionOrCommand::CodeAction(action) => action.kind.as_ref(),
+            lsp::CodeActionOrCommand::Command(_) => None,
+        })
+        .map(|kind| kind.as_str())
+        .collect();
+    assert_eq!(kinds, vec!["source.action.useSortedKeys.biome"]);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn change_document_remove_line() -> Result<()> {
     let factory = ServerFactory::default();
@@ -2385,7 +2961,7 @@ async fn pull_diagnostics_from_manifest() -> Result<()> {
                                 },
                             },
                         },
-                        message: String::new(),
+                        message: String::from("== is only allowed when comparing against null"),
                     }]),
                     tags: None,
                     data: None,
@@ -1,6 +1,7 @@
 use crate::{declare_migration, MigrationAction};
 use biome_analyze::context::RuleContext;
 use biome_analyze::{Ast, FixKind, Rule, RuleDiagnostic};
+use biome_configuration::migrations::RENAMED_RULES;
 use biome_console::markup;
 use biome_diagnostics::category;
 use biome_json_factory::make::{
@@ -70,68 +71,6 @@ fn find_group_by_name(root: &JsonRoot, group_name: &str) -> Option<JsonMember> {
     group
 }
 
-/// - Left: name of the rule in the nursery group
-/// - Right: name of the target group and name of the target rule (sometimes we change name)
-#[rustfmt::skip]
-const RULES_TO_MIGRATE: &[(&str, (&str, &str))] = &[
-    // CSS
-    ("noDuplicateAtImportRules", ("suspicious", "noDuplicateAtImportRules")),
-    ("noDuplicateFontNames", ("suspicious", "noDuplicateFontNames")),
-    ("noDuplicateSelectorsKeyframeBlock", ("suspicious", "noDuplicateSelectorsKeyframeBlock")),
-    ("noEmptyBlock", ("suspicious", "noEmptyBlock")),
-    ("noImportantInKeyframe", ("suspicious", "noImportantInKeyframe")),
-    ("noInvalidDirectionInLinearGradient", ("correctness", "noInvalidDirectionInLinearGradient")),
-    ("noInvalidPositionAtImportRule", ("correctness", "noInvalidPositionAtImportRule")),
-    ("noShorthandPropertyOverrides", ("suspicious", "noShorthandPropertyOverrides")),
-    ("noUnknownFunction", ("correctness", "noUnknownFunction")),
-    ("noUnknownMediaFeatureName", ("correctness", "noUnknownMediaFeatureName")),
-    ("noUnknownProperty", ("correctness", "noUnknownProperty")),
-    ("noUnknownUnit", ("correctness", "noUnknownUnit")),
-    ("noUnmatchableAnbSelector", ("correctness", "noUnmatchableAnbSelector")),
-    ("useConsistentGridAreas", ("correctness", "noInvalidGridAreas")),
-    ("useGenericFontNames", ("a11y", "useGenericFontNames")),
-    // JS
-    ("noBarrelFile", ("performance", "noBarrelFile")),
-    ("noConsole", ("suspicious", "noConsole")),
-    ("noConstantMathMinMaxClamp", ("correctness", "noConstantMathMinMaxClamp")),
-    ("noDoneCallback", ("style", "noDoneCallback")),
-    ("noDuplicateTestHooks", ("suspicious", "noDuplicateTestHooks")),
-    ("noEvolvingTypes", ("suspicious", "noEvolvingTypes")),
-    ("noExcessiveNestedTestSuites", ("complexity", "noExcessiveNestedTestSuites")),
-    ("noExportsInTest", ("suspicious", "noExportsInTest")),
-    ("noFlatMapIdentity", ("correctness", "noFlatMapIdentity")),
-    ("noFocusedTests", ("suspicious", "noFocusedTests")),
-    ("noLabelWithoutControl", ("a11y", "noLabelWithoutControl")),
-    ("noMisplacedAssertion", ("suspicious", "noMisplacedAssertion")),
-    ("noNamespaceImport", ("style", "noNamespaceImport")),
-    ("noNodejsModules", ("correctness", "noNodejsModules")),
-    ("noReactSpecificProps", ("suspicious", "noReactSpecificProps")),
-    ("noReExportAll", ("performance", "noReExportAll")),
-    ("noSkippedTests", ("suspicious", "noSkippedTests")),
-    ("noSuspiciousSemicolonInJsx", ("suspicious", "noSuspiciousSemicolonInJsx")),
-    ("noUndeclaredDependencies", ("correctness", "noUndeclaredDependencies")),
-    ("noUnusedFunctionParameters", ("correctness", "noUnusedFunctionParameters")),
-    ("noUselessStringConcat", ("complexity", "noUselessStringConcat")),
-    ("noUselessTernary", ("complexity", "noUselessTernary")),
-    ("noUselessUndefinedInitialization", ("complexity", "noUselessUndefinedInitialization")),
-    ("noYodaExpression", ("style", "noYodaExpression")),
-    ("useArrayLiterals", ("correctness", "useArrayLiterals")),
-    ("useConsistentBuiltinInstantiation", ("style", "useConsistentBuiltinInstantiation")),
-    ("useDateNow", ("complexity", "useDateNow")),
-    ("useDefaultSwitchClause", ("style", "useDefaultSwitchClause")),
-    ("useErrorMessage", ("suspicious", "useErrorMessage")),
-    ("useExplicitLengthCheck", ("style", "useExplicitLengthCheck")),
-    ("useFocusableInteractive", ("a11y", "useFocusableInteractive")),
-    ("useImportExtensions", ("correctness", "useImportExtensions")),
-    ("useJsxKeyInIterable", ("correctness", "useJsxKeyInIterable") ),
-    ("useNodeAssertStrict", ("style", "useNodeAssertStrict")),
-    ("useNumberToFixedDigitsArgument", ("suspicious", "useNumberToFixedDigitsArgument")),
-    ("useSemanticElements", ("a11y", "useSemanticElements")),
-    ("useThrowNewError", ("style", "useThrowNewError")),
-    ("useThrowOnlyError", ("style", "useThrowOnlyError")),
-    ("useTopLevelRegex", ("performance", "useTopLevelRegex")),
-];
-
 impl Rule for NurseryRules {
     type Query = Ast<JsonRoot>;
     type State = MigrateRuleState;
@@ -144,9 +83,11 @@ impl Rule for NurseryRules {
 
         if let Some(nursery_group) = find_group_by_name(node, "nursery") {
             let mut rules_should_be_migrated = FxHashMap::default();
-            for (nursery_rule_name, (target_group_name, target_rule_name)) in RULES_TO_MIGRATE {
-                rules_should_be_migrated
-                    .insert(*nursery_rule_name, (*target_group_name, *target_rule_name));
+            for rule in RENAMED_RULES
+                .iter()
+                .filter(|rule| rule.old_group == "nursery")
+            {
+                rules_should_be_migrated.insert(rule.old_name, (rule.new_group, rule.new_name));
             }
             let Some(nursery_group_object) = nursery_group
                 .value()
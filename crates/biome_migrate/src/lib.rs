@@ -83,7 +83,7 @@ where
         METADATA.deref(),
         InspectMatcher::new(migration_registry, inspect_matcher),
         |_| -> Vec<Result<_, Infallible>> { unreachable!() },
-        Box::new(TestAction),
+        Some(Box::new(TestAction)),
         &mut emit_signal,
     );
 
@@ -8,7 +8,11 @@ use biome_diagnostics::serde::Diagnostic;
 use biome_parser::diagnostic::ParseDiagnostic;
 use biome_rowan::Language;
 pub use license::generated::*;
-pub use node_js_project::{Dependencies, NodeJsProject, PackageJson, PackageType};
+pub use node_js_project::{
+    resolve_module, resolve_tsconfig_extends, CompilerOptions, Dependencies, ModuleResolutionHost,
+    NodeJsProject, OsTsConfigHost, PackageExports, PackageJson, PackageType, ResolvedTsConfig,
+    TsConfigExtends, TsConfigHost, TsConfigJson,
+};
 use std::any::TypeId;
 use std::fmt::Debug;
 use std::path::Path;
@@ -1,8 +1,15 @@
+mod module_resolution;
 mod package_json;
 mod tsconfig_json;
 
-pub use crate::node_js_project::package_json::{Dependencies, PackageJson, PackageType};
-use crate::node_js_project::tsconfig_json::TsConfigJson;
+pub use crate::node_js_project::module_resolution::{resolve_module, ModuleResolutionHost};
+pub use crate::node_js_project::package_json::{
+    Dependencies, PackageExports, PackageJson, PackageType,
+};
+pub use crate::node_js_project::tsconfig_json::{
+    resolve_tsconfig_extends, CompilerOptions, OsTsConfigHost, ResolvedTsConfig, TsConfigExtends,
+    TsConfigHost, TsConfigJson,
+};
 use crate::{Manifest, Project, ProjectAnalyzeDiagnostic, ProjectAnalyzeResult, LICENSE_LIST};
 use biome_rowan::Language;
 use std::path::{Path, PathBuf};
@@ -0,0 +1,373 @@
+use crate::node_js_project::package_json::{PackageExports, PackageJson};
+use crate::node_js_project::tsconfig_json::{OsTsConfigHost, ResolvedTsConfig, TsConfigHost};
+use crate::Manifest;
+use biome_json_parser::{parse_json, JsonParserOptions};
+use std::path::{Path, PathBuf};
+
+/// The extensions tried, in order, when a specifier doesn't point to an existing file.
+const RESOLVED_EXTENSIONS: &[&str] = &[
+    ".ts", ".tsx", ".d.ts", ".mts", ".cts", ".js", ".jsx", ".mjs", ".cjs", ".json",
+];
+
+/// The conditions of the `exports` of a package that are supported, the first condition
+/// of the package that is part of this list is used.
+const EXPORTS_CONDITIONS: &[&str] = &["types", "import", "module", "node", "require", "default"];
+
+/// Provides the access to the file system required to resolve an import.
+pub trait ModuleResolutionHost: TsConfigHost {
+    /// Reads and deserializes the `package.json` located at `path`.
+    fn read_package_json(&self, path: &Path) -> Option<PackageJson>;
+}
+
+impl ModuleResolutionHost for OsTsConfigHost {
+    fn read_package_json(&self, path: &Path) -> Option<PackageJson> {
+        let content = std::fs::read_to_string(path).ok()?;
+        let parsed = parse_json(&content, JsonParserOptions::default());
+        let (package_json, _) = PackageJson::deserialize_manifest(&parsed.tree()).consume();
+        package_json
+    }
+}
+
+/// Resolves the import `specifier` of the file at `importer` to a file, like TypeScript does:
+///
+/// - a relative or absolute specifier points to a file, with or without its extension,
+///   or to the `index` file of a directory;
+/// - a specifier that matches the `paths` of the closest `tsconfig.json` is resolved to its
+///   targets, and the other specifiers are resolved against its `baseUrl`;
+/// - any other specifier points to a package installed in a `node_modules` directory, whose
+///   entry points are given by its `exports`, or by its `types`, `module` and `main` fields.
+///
+/// Returns [None] if the specifier doesn't resolve to an existing file.
+pub fn resolve_module(
+    specifier: &str,
+    importer: &Path,
+    host: &impl ModuleResolutionHost,
+) -> Option<PathBuf> {
+    let directory = importer.parent().unwrap_or(Path::new(""));
+    if specifier.starts_with("./")
+        || specifier.starts_with("../")
+        || specifier == "."
+        || specifier == ".."
+        || Path::new(specifier).is_absolute()
+    {
+        return resolve_file(&directory.join(specifier), host);
+    }
+
+    resolve_tsconfig_paths(specifier, directory, host)
+        .or_else(|| resolve_package(specifier, directory, host))
+}
+
+/// Resolves `specifier` with the `paths` and the `baseUrl` of the closest `tsconfig.json`
+fn resolve_tsconfig_paths(
+    specifier: &str,
+    directory: &Path,
+    host: &impl ModuleResolutionHost,
+) -> Option<PathBuf> {
+    let tsconfig_path = directory
+        .ancestors()
+        .map(|ancestor| ancestor.join("tsconfig.json"))
+        .find(|path| host.is_file(path))?;
+    let tsconfig = host.read_tsconfig(&tsconfig_path)?;
+    let resolved = ResolvedTsConfig::resolve(&tsconfig_path, &tsconfig, host);
+    let tsconfig_directory = tsconfig_path.parent().unwrap_or(Path::new(""));
+    let root = resolved.base_url.as_deref().unwrap_or(tsconfig_directory);
+
+    // Like TypeScript, the pattern with the longest prefix wins
+    let matched = tsconfig
+        .compiler_options
+        .paths
+        .iter()
+        .filter_map(|(pattern, targets)| {
+            let captured = match_pattern(pattern, specifier)?;
+            let prefix_len = pattern.find('*').unwrap_or(pattern.len());
+            Some((prefix_len, captured, targets))
+        })
+        .max_by_key(|(prefix_len, _, _)| *prefix_len);
+    if let Some((_, captured, targets)) = matched {
+        return targets
+            .iter()
+            .find_map(|target| resolve_file(&root.join(target.replacen('*', captured, 1)), host));
+    }
+
+    let base_url = resolved.base_url?;
+    resolve_file(&base_url.join(specifier), host)
+}
+
+/// Resolves `specifier` to a package installed in the `node_modules` directory of
+/// `directory` or of one of its ancestors
+fn resolve_package(
+    specifier: &str,
+    directory: &Path,
+    host: &impl ModuleResolutionHost,
+) -> Option<PathBuf> {
+    // The name of a scoped package, such as `@scope/name`, has two segments
+    let segments = if specifier.starts_with('@') { 2 } else { 1 };
+    let mut split = specifier.splitn(segments + 1, '/');
+    let name: Vec<_> = split.by_ref().take(segments).collect();
+    let name = name.join("/");
+    let subpath = split.next();
+
+    let package_directory = directory
+        .ancestors()
+        .map(|ancestor| ancestor.join("node_modules").join(&name))
+        .find(|path| host.path_exists(path))?;
+    let package_json = host.read_package_json(&package_directory.join("package.json"));
+
+    if let Some(exports) = package_json
+        .as_ref()
+        .and_then(|package_json| package_json.exports.as_ref())
+    {
+        let subpath = subpath.map_or_else(|| ".".to_string(), |subpath| format!("./{subpath}"));
+        let target = resolve_exports(exports, &subpath)?;
+        let path = package_directory.join(target.trim_start_matches("./"));
+        return host.is_file(&path).then_some(path);
+    }
+
+    if let Some(subpath) = subpath {
+        return resolve_file(&package_directory.join(subpath), host);
+    }
+    package_json
+        .iter()
+        .flat_map(|package_json| {
+            [
+                &package_json.types,
+                &package_json.module,
+                &package_json.main,
+            ]
+        })
+        .flatten()
+        .find_map(|entry| resolve_file(&package_directory.join(entry), host))
+        .or_else(|| resolve_file(&package_directory.join("index"), host))
+}
+
+/// Returns the target of the entry point `subpath` of a package, such as `.` or `./utils`
+fn resolve_exports(exports: &PackageExports, subpath: &str) -> Option<String> {
+    if !exports.has_subpaths() {
+        return (subpath == ".").then(|| resolve_conditions(exports, ""))?;
+    }
+
+    let PackageExports::Map(entries) = exports else {
+        return None;
+    };
+    if let Some((_, target)) = entries.iter().find(|(key, _)| key == subpath) {
+        return resolve_conditions(target, "");
+    }
+    entries
+        .iter()
+        .filter_map(|(pattern, target)| {
+            let captured = match_pattern(pattern, subpath)?;
+            Some((pattern.find('*')?, captured, target))
+        })
+        .max_by_key(|(prefix_len, _, _)| *prefix_len)
+        .and_then(|(_, captured, target)| resolve_conditions(target, captured))
+}
+
+/// Returns the first target of `exports` whose conditions are supported, replacing
+/// its wildcard with `captured`
+fn resolve_conditions(exports: &PackageExports, captured: &str) -> Option<String> {
+    match exports {
+        PackageExports::Target(target) => Some(target.replace('*', captured)),
+        PackageExports::Fallbacks(targets) => targets
+            .iter()
+            .find_map(|target| resolve_conditions(target, captured)),
+        PackageExports::Map(conditions) => conditions
+            .iter()
+            .filter(|(condition, _)| EXPORTS_CONDITIONS.contains(&condition.as_str()))
+            .find_map(|(_, target)| resolve_conditions(target, captured)),
+        PackageExports::Excluded => None,
+    }
+}
+
+/// Returns the text matched by the wildcard of `pattern`, such as `utils` for the
+/// pattern `@/*` and the specifier `@/utils`, or an empty string if the pattern
+/// doesn't have a wildcard and is equal to the specifier.
+fn match_pattern<'a>(pattern: &str, specifier: &'a str) -> Option<&'a str> {
+    match pattern.split_once('*') {
+        Some((prefix, suffix)) => {
+            let captured = specifier.strip_prefix(prefix)?.strip_suffix(suffix)?;
+            Some(captured)
+        }
+        None => (pattern == specifier).then_some(""),
+    }
+}
+
+/// Resolves `path` to a file, trying the TypeScript file of a JavaScript import,
+/// the usual extensions, and the `index` file of a directory
+fn resolve_file(path: &Path, host: &impl ModuleResolutionHost) -> Option<PathBuf> {
+    if host.is_file(path) {
+        return Some(path.to_path_buf());
+    }
+
+    let typescript_extensions: &[&str] = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("js") => &["ts", "tsx"],
+        Some("jsx") => &["tsx"],
+        Some("mjs") => &["mts"],
+        Some("cjs") => &["cts"],
+        _ => &[],
+    };
+    let with_extensions = RESOLVED_EXTENSIONS.iter().map(|extension| {
+        let mut path = path.to_path_buf().into_os_string();
+        path.push(extension);
+        PathBuf::from(path)
+    });
+    let index_files = RESOLVED_EXTENSIONS
+        .iter()
+        .map(|extension| path.join(format!("index{extension}")));
+
+    typescript_extensions
+        .iter()
+        .map(|extension| path.with_extension(extension))
+        .chain(with_extensions)
+        .chain(index_files)
+        .find(|candidate| host.is_file(candidate))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node_js_project::tsconfig_json::TsConfigJson;
+    use rustc_hash::{FxHashMap, FxHashSet};
+
+    #[derive(Default)]
+    struct MemoryHost {
+        files: FxHashSet<PathBuf>,
+        tsconfigs: FxHashMap<PathBuf, TsConfigJson>,
+        package_jsons: FxHashMap<PathBuf, PackageJson>,
+    }
+
+    impl MemoryHost {
+        fn with_file(mut self, path: &str) -> Self {
+            self.files.insert(PathBuf::from(path));
+            self
+        }
+
+        fn with_tsconfig(
+            mut self,
+            path: &str,
+            base_url: Option<&str>,
+            paths: &[(&str, &str)],
+        ) -> Self {
+            let mut tsconfig = TsConfigJson::default();
+            tsconfig.compiler_options.base_url = base_url.map(str::to_string);
+            for (pattern, target) in paths {
+                tsconfig
+                    .compiler_options
+                    .paths
+                    .insert(pattern.to_string(), vec![target.to_string()]);
+            }
+            self.tsconfigs.insert(PathBuf::from(path), tsconfig);
+            self.with_file(path)
+        }
+
+        fn with_package_json(mut self, path: &str, package_json: PackageJson) -> Self {
+            self.package_jsons.insert(PathBuf::from(path), package_json);
+            self.with_file(path)
+        }
+    }
+
+    impl TsConfigHost for MemoryHost {
+        fn path_exists(&self, path: &Path) -> bool {
+            self.files.iter().any(|file| file.starts_with(path))
+        }
+
+        fn is_file(&self, path: &Path) -> bool {
+            self.files.contains(path)
+        }
+
+        fn read_tsconfig(&self, path: &Path) -> Option<TsConfigJson> {
+            self.tsconfigs.get(path).cloned()
+        }
+    }
+
+    impl ModuleResolutionHost for MemoryHost {
+        fn read_package_json(&self, path: &Path) -> Option<PackageJson> {
+            self.package_jsons.get(path).cloned()
+        }
+    }
+
+    #[test]
+    fn resolves_relative_and_tsconfig_paths() {
+        let host = MemoryHost::default()
+            .with_tsconfig(
+                "/project/tsconfig.json",
+                Some("src"),
+                &[("@/*", "components/*")],
+            )
+            .with_file("/project/src/components/button.tsx")
+            .with_file("/project/src/utils/index.ts")
+            .with_file("/project/src/main.ts");
+
+        let importer = Path::new("/project/src/main.ts");
+        assert_eq!(
+            resolve_module("./utils", importer, &host),
+            Some(PathBuf::from("/project/src/utils/index.ts"))
+        );
+        assert_eq!(
+            resolve_module("@/button", importer, &host),
+            Some(PathBuf::from("/project/src/components/button.tsx"))
+        );
+        assert_eq!(
+            resolve_module("utils", importer, &host),
+            Some(PathBuf::from("/project/src/utils/index.ts"))
+        );
+        assert_eq!(resolve_module("./missing", importer, &host), None);
+    }
+
+    #[test]
+    fn resolves_package_exports() {
+        let exports = PackageExports::Map(vec![
+            (
+                ".".to_string(),
+                PackageExports::Map(vec![
+                    (
+                        "browser".to_string(),
+                        PackageExports::Target("./browser.js".to_string()),
+                    ),
+                    (
+                        "import".to_string(),
+                        PackageExports::Target("./index.mjs".to_string()),
+                    ),
+                ]),
+            ),
+            (
+                "./utils/*".to_string(),
+                PackageExports::Target("./src/utils/*.js".to_string()),
+            ),
+        ]);
+        let host = MemoryHost::default()
+            .with_package_json(
+                "/project/node_modules/lib/package.json",
+                PackageJson {
+                    exports: Some(exports),
+                    ..Default::default()
+                },
+            )
+            .with_file("/project/node_modules/lib/index.mjs")
+            .with_file("/project/node_modules/lib/src/utils/math.js")
+            .with_package_json(
+                "/project/node_modules/@scope/legacy/package.json",
+                PackageJson {
+                    main: Some("lib/main".to_string()),
+                    ..Default::default()
+                },
+            )
+            .with_file("/project/node_modules/@scope/legacy/lib/main.js");
+
+        let importer = Path::new("/project/src/main.js");
+        assert_eq!(
+            resolve_module("lib", importer, &host),
+            Some(PathBuf::from("/project/node_modules/lib/index.mjs"))
+        );
+        assert_eq!(
+            resolve_module("lib/utils/math", importer, &host),
+            Some(PathBuf::from("/project/node_modules/lib/src/utils/math.js"))
+        );
+        assert_eq!(resolve_module("lib/internal", importer, &host), None);
+        assert_eq!(
+            resolve_module("@scope/legacy", importer, &host),
+            Some(PathBuf::from(
+                "/project/node_modules/@scope/legacy/lib/main.js"
+            ))
+        );
+    }
+}
@@ -19,6 +19,14 @@ pub struct PackageJson {
     pub optional_dependencies: Dependencies,
     pub license: Option<(String, TextRange)>,
     pub r#type: Option<PackageType>,
+    /// The entry point of the package, used when it doesn't define `exports`
+    pub main: Option<String>,
+    /// The entry point of the package for the bundlers that support ES modules
+    pub module: Option<String>,
+    /// The declaration file of the entry point of the package
+    pub types: Option<String>,
+    /// The entry points of the package, which take precedence over `main`
+    pub exports: Option<PackageExports>,
 }
 
 impl Manifest for PackageJson {
@@ -136,6 +144,18 @@ impl DeserializationVisitor for PackageJsonVisitor {
                 "type" => {
                     result.r#type = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "main" => {
+                    result.main = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "module" => {
+                    result.module = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "types" | "typings" => {
+                    result.types = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "exports" => {
+                    result.exports = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 _ => {
                     // each package can add their own field, so we should ignore any extraneous key
                     // and only deserialize the ones that Biome deems important
@@ -160,6 +180,112 @@ impl Deserializable for Version {
     }
 }
 
+/// The `exports` of a package.
+///
+/// ```json
+/// {
+///   "exports": {
+///     ".": { "import": "./index.mjs", "require": "./index.cjs" },
+///     "./utils/*": "./src/utils/*.js",
+///     "./internal/*": null
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PackageExports {
+    /// The path of a file, relative to the package, such as `./index.js`
+    Target(String),
+    /// The targets tried in order, until one of them is supported
+    Fallbacks(Vec<PackageExports>),
+    /// The entry points keyed by their subpath, such as `./utils`, or the targets keyed
+    /// by their condition, such as `import`, in the order they are declared
+    Map(Vec<(String, PackageExports)>),
+    /// An entry point that isn't exported
+    Excluded,
+}
+
+impl PackageExports {
+    /// Returns `true` if the keys of the map are subpaths, such as `./utils`,
+    /// instead of conditions
+    pub fn has_subpaths(&self) -> bool {
+        match self {
+            Self::Map(entries) => entries.iter().any(|(key, _)| key.starts_with('.')),
+            _ => false,
+        }
+    }
+}
+
+impl Deserializable for PackageExports {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(PackageExportsVisitor, name, diagnostics)
+    }
+}
+
+struct PackageExportsVisitor;
+impl DeserializationVisitor for PackageExportsVisitor {
+    type Output = PackageExports;
+
+    const EXPECTED_TYPE: DeserializableTypes = DeserializableTypes::NULL
+        .union(DeserializableTypes::STR)
+        .union(DeserializableTypes::ARRAY)
+        .union(DeserializableTypes::MAP);
+
+    fn visit_null(
+        self,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageExports::Excluded)
+    }
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageExports::Target(value.text().to_string()))
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let targets = items
+            .flatten()
+            .filter_map(|item| PackageExports::deserialize(&item, name, diagnostics))
+            .collect();
+        Some(PackageExports::Fallbacks(targets))
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let entries = members
+            .flatten()
+            .filter_map(|(key, value)| {
+                let key = Text::deserialize(&key, name, diagnostics)?;
+                let value = PackageExports::deserialize(&value, key.text(), diagnostics)?;
+                Some((key.text().to_string(), value))
+            })
+            .collect();
+        Some(PackageExports::Map(entries))
+    }
+}
+
 #[derive(Debug, Default, Clone, Eq, PartialEq, biome_deserialize_macros::Deserializable)]
 pub enum PackageType {
     #[default]
@@ -1,15 +1,21 @@
 use crate::{LanguageRoot, Manifest};
 use biome_deserialize::json::deserialize_from_json_ast;
-use biome_deserialize::Deserialized;
+use biome_deserialize::{
+    Deserializable, DeserializableTypes, DeserializableValue, DeserializationDiagnostic,
+    DeserializationVisitor, Deserialized, Text,
+};
 use biome_deserialize_macros::Deserializable;
+use biome_json_parser::{parse_json, JsonParserOptions};
 use biome_json_syntax::JsonLanguage;
+use biome_text_size::TextRange;
 use rustc_hash::FxHashMap;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Default, Clone, Deserializable)]
 #[deserializable(unknown_fields = "allow")]
 pub struct TsConfigJson {
-    base_url: Option<String>,
-    paths: FxHashMap<String, Vec<String>>,
+    pub extends: TsConfigExtends,
+    pub compiler_options: CompilerOptions,
 }
 
 impl Manifest for TsConfigJson {
@@ -19,3 +25,397 @@ impl Manifest for TsConfigJson {
         deserialize_from_json_ast::<TsConfigJson>(root, "")
     }
 }
+
+#[derive(Debug, Default, Clone, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub struct CompilerOptions {
+    pub base_url: Option<String>,
+    pub paths: FxHashMap<String, Vec<String>>,
+}
+
+/// The configurations extended by a `tsconfig.json`, in order of application.
+///
+/// The `extends` property accepts a single specifier, or a list of specifiers since TypeScript 5.0.
+#[derive(Debug, Default, Clone)]
+pub struct TsConfigExtends(Vec<String>);
+
+impl TsConfigExtends {
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+}
+
+impl Deserializable for TsConfigExtends {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(TsConfigExtendsVisitor, name, diagnostics)
+    }
+}
+
+struct TsConfigExtendsVisitor;
+impl DeserializationVisitor for TsConfigExtendsVisitor {
+    type Output = TsConfigExtends;
+
+    const EXPECTED_TYPE: DeserializableTypes =
+        DeserializableTypes::STR.union(DeserializableTypes::ARRAY);
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(TsConfigExtends(vec![value.text().to_string()]))
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let specifiers = items
+            .flatten()
+            .filter_map(|item| String::deserialize(&item, name, diagnostics))
+            .collect();
+        Some(TsConfigExtends(specifiers))
+    }
+}
+
+/// Provides the access to the file system required to resolve a `tsconfig.json`.
+pub trait TsConfigHost {
+    /// Returns `true` if a file or a directory exists at `path`.
+    fn path_exists(&self, path: &Path) -> bool;
+
+    /// Returns `true` if a file exists at `path`.
+    fn is_file(&self, path: &Path) -> bool;
+
+    /// Reads and deserializes the `tsconfig.json` located at `path`.
+    fn read_tsconfig(&self, path: &Path) -> Option<TsConfigJson>;
+}
+
+/// A [TsConfigHost] that reads the configurations from the file system of the operating system.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OsTsConfigHost;
+
+impl TsConfigHost for OsTsConfigHost {
+    fn path_exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        path.is_file()
+    }
+
+    fn read_tsconfig(&self, path: &Path) -> Option<TsConfigJson> {
+        let content = std::fs::read_to_string(path).ok()?;
+        let parsed = parse_json(
+            &content,
+            JsonParserOptions::default()
+                .with_allow_comments()
+                .with_allow_trailing_commas(),
+        );
+        let (tsconfig, _) = TsConfigJson::deserialize_manifest(&parsed.tree()).consume();
+        tsconfig
+    }
+}
+
+/// The extensions tried by TypeScript when a `paths` target doesn't point to an existing file.
+const PATHS_TARGET_EXTENSIONS: &[&str] = &[".ts", ".tsx", ".d.ts", ".js", ".jsx", ".json"];
+
+/// The result of the resolution of a `tsconfig.json` and of its `extends` chain.
+#[derive(Debug, Default, Clone)]
+pub struct ResolvedTsConfig {
+    /// The paths of the configurations extended directly or indirectly, in order of application
+    pub extended: Vec<PathBuf>,
+    /// The effective `baseUrl`, resolved against the configuration that defines it
+    pub base_url: Option<PathBuf>,
+    /// The specifiers of `extends` that don't resolve to a configuration
+    pub unresolved_extends: Vec<String>,
+    /// The targets of `paths` that don't resolve to a file or a directory
+    pub unresolved_paths: Vec<String>,
+}
+
+impl ResolvedTsConfig {
+    /// Resolves the `extends` chain of `tsconfig`, located at `path`, and checks
+    /// the targets of its `paths`.
+    ///
+    /// A configuration that is extended more than once, including through a cycle, is only applied once.
+    pub fn resolve(path: &Path, tsconfig: &TsConfigJson, host: &impl TsConfigHost) -> Self {
+        let mut resolved = Self::default();
+        let mut visited = vec![path.to_path_buf()];
+        for specifier in tsconfig.extends.iter() {
+            match resolve_tsconfig_extends(specifier, path, host) {
+                Some(extended_path) => resolved.extend_from(&extended_path, host, &mut visited),
+                None => resolved.unresolved_extends.push(specifier.to_string()),
+            }
+        }
+
+        let directory = path.parent().unwrap_or(Path::new(""));
+        if let Some(base_url) = &tsconfig.compiler_options.base_url {
+            resolved.base_url = Some(directory.join(base_url));
+        }
+
+        // Without `baseUrl`, the targets are relative to the configuration that defines `paths`
+        let paths_root = resolved.base_url.as_deref().unwrap_or(directory);
+        for target in tsconfig.compiler_options.paths.values().flatten() {
+            if !paths_target_exists(paths_root, target, host)
+                && !resolved.unresolved_paths.contains(target)
+            {
+                resolved.unresolved_paths.push(target.clone());
+            }
+        }
+
+        resolved
+    }
+
+    /// Returns `true` if the `extends` specifier doesn't resolve to a configuration.
+    pub fn is_unresolved_extends(&self, specifier: &str) -> bool {
+        self.unresolved_extends
+            .iter()
+            .any(|unresolved| unresolved == specifier)
+    }
+
+    /// Returns `true` if the `paths` target doesn't resolve to a file or a directory.
+    pub fn is_unresolved_path(&self, target: &str) -> bool {
+        self.unresolved_paths
+            .iter()
+            .any(|unresolved| unresolved == target)
+    }
+
+    fn extend_from(&mut self, path: &Path, host: &impl TsConfigHost, visited: &mut Vec<PathBuf>) {
+        if visited.iter().any(|visited_path| visited_path == path) {
+            return;
+        }
+        visited.push(path.to_path_buf());
+
+        let Some(tsconfig) = host.read_tsconfig(path) else {
+            return;
+        };
+        for specifier in tsconfig.extends.iter() {
+            if let Some(extended_path) = resolve_tsconfig_extends(specifier, path, host) {
+                self.extend_from(&extended_path, host, visited);
+            }
+        }
+
+        if let Some(base_url) = &tsconfig.compiler_options.base_url {
+            let directory = path.parent().unwrap_or(Path::new(""));
+            self.base_url = Some(directory.join(base_url));
+        }
+        self.extended.push(path.to_path_buf());
+    }
+}
+
+/// Resolves the `extends` specifier of the `tsconfig.json` located at `tsconfig_path`.
+///
+/// A relative or absolute specifier points to a configuration, with or without the `.json` extension.
+/// Any other specifier references a package installed in a `node_modules` directory:
+/// `@tsconfig/node20/tsconfig.json`, `@tsconfig/node20/tsconfig` and `@tsconfig/node20`,
+/// whose configuration is `tsconfig.json`, all point to the same file.
+pub fn resolve_tsconfig_extends(
+    specifier: &str,
+    tsconfig_path: &Path,
+    host: &impl TsConfigHost,
+) -> Option<PathBuf> {
+    let directory = tsconfig_path.parent().unwrap_or(Path::new(""));
+    if specifier.starts_with("./")
+        || specifier.starts_with("../")
+        || Path::new(specifier).is_absolute()
+    {
+        let path = directory.join(specifier);
+        let path = with_json_extension(path);
+        return host.is_file(&path).then_some(path);
+    }
+
+    directory.ancestors().find_map(|ancestor| {
+        let package_path = ancestor.join("node_modules").join(specifier);
+        if host.is_file(&package_path) {
+            return Some(package_path);
+        }
+        [
+            with_json_extension(package_path.clone()),
+            package_path.join("tsconfig.json"),
+        ]
+        .into_iter()
+        .find(|path| host.is_file(path))
+    })
+}
+
+/// Appends the `.json` extension to `path`, unless it already has it
+fn with_json_extension(path: PathBuf) -> PathBuf {
+    if path
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    {
+        return path;
+    }
+    let mut path = path.into_os_string();
+    path.push(".json");
+    path.into()
+}
+
+/// Returns `true` if the `paths` target matches a file or a directory.
+///
+/// The target of a pattern with a wildcard, such as `src/*`, only needs the directory
+/// that precedes the wildcard to exist.
+fn paths_target_exists(root: &Path, target: &str, host: &impl TsConfigHost) -> bool {
+    if let Some((prefix, _)) = target.split_once('*') {
+        let directory = match prefix.rfind('/') {
+            Some(index) => root.join(&prefix[..index]),
+            None => root.to_path_buf(),
+        };
+        return host.path_exists(&directory);
+    }
+
+    let path = root.join(target);
+    host.path_exists(&path)
+        || PATHS_TARGET_EXTENSIONS.iter().any(|extension| {
+            let mut path_with_extension = path.clone().into_os_string();
+            path_with_extension.push(extension);
+            host.is_file(Path::new(&path_with_extension))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustc_hash::FxHashSet;
+    use std::path::Component;
+
+    /// Removes the `..` components of `path`, like the file system does
+    fn normalize(path: &Path) -> PathBuf {
+        let mut normalized = PathBuf::new();
+        for component in path.components() {
+            match component {
+                Component::ParentDir => {
+                    normalized.pop();
+                }
+                _ => normalized.push(component),
+            }
+        }
+        normalized
+    }
+
+    #[derive(Default)]
+    struct MemoryHost {
+        files: FxHashMap<PathBuf, TsConfigJson>,
+        directories: FxHashSet<PathBuf>,
+    }
+
+    impl MemoryHost {
+        fn with_tsconfig(mut self, path: &str, extends: &[&str], base_url: Option<&str>) -> Self {
+            let tsconfig = TsConfigJson {
+                extends: TsConfigExtends(extends.iter().map(|s| s.to_string()).collect()),
+                compiler_options: CompilerOptions {
+                    base_url: base_url.map(str::to_string),
+                    paths: FxHashMap::default(),
+                },
+            };
+            self.files.insert(PathBuf::from(path), tsconfig);
+            self
+        }
+
+        fn with_file(mut self, path: &str) -> Self {
+            self.files
+                .insert(PathBuf::from(path), TsConfigJson::default());
+            self
+        }
+
+        fn with_directory(mut self, path: &str) -> Self {
+            self.directories.insert(PathBuf::from(path));
+            self
+        }
+    }
+
+    impl TsConfigHost for MemoryHost {
+        fn path_exists(&self, path: &Path) -> bool {
+            self.is_file(path) || self.directories.contains(&normalize(path))
+        }
+
+        fn is_file(&self, path: &Path) -> bool {
+            self.files.contains_key(&normalize(path))
+        }
+
+        fn read_tsconfig(&self, path: &Path) -> Option<TsConfigJson> {
+            self.files.get(&normalize(path)).cloned()
+        }
+    }
+
+    #[test]
+    fn resolves_relative_and_package_extends() {
+        let host = MemoryHost::default()
+            .with_tsconfig("/project/tsconfig.base.json", &[], Some("src"))
+            .with_file("/node_modules/@tsconfig/node20/tsconfig.json")
+            .with_file("/project/node_modules/@company/config/strict.json");
+
+        let path = Path::new("/project/packages/app/tsconfig.json");
+        assert_eq!(
+            resolve_tsconfig_extends("../../tsconfig.base", path, &host),
+            Some(PathBuf::from(
+                "/project/packages/app/../../tsconfig.base.json"
+            ))
+        );
+        assert_eq!(
+            resolve_tsconfig_extends("@tsconfig/node20", path, &host),
+            Some(PathBuf::from(
+                "/node_modules/@tsconfig/node20/tsconfig.json"
+            ))
+        );
+        assert_eq!(
+            resolve_tsconfig_extends("@company/config/strict", path, &host),
+            Some(PathBuf::from(
+                "/project/node_modules/@company/config/strict.json"
+            ))
+        );
+        assert_eq!(resolve_tsconfig_extends("./missing", path, &host), None);
+    }
+
+    #[test]
+    fn resolves_extends_chain_and_paths() {
+        let host = MemoryHost::default()
+            .with_tsconfig(
+                "/project/tsconfig.base.json",
+                &["./tsconfig.json"],
+                Some("src"),
+            )
+            .with_file("/project/tsconfig.json")
+            .with_directory("/project/src")
+            .with_directory("/project/src/components")
+            .with_file("/project/src/utils.ts");
+
+        let mut tsconfig = TsConfigJson {
+            extends: TsConfigExtends(vec![
+                "./tsconfig.base.json".to_string(),
+                "./tsconfig.missing.json".to_string(),
+            ]),
+            ..Default::default()
+        };
+        tsconfig.compiler_options.paths.insert(
+            "@/*".to_string(),
+            vec!["components/*".to_string(), "vendor/*".to_string()],
+        );
+        tsconfig
+            .compiler_options
+            .paths
+            .insert("utils".to_string(), vec!["utils".to_string()]);
+
+        let resolved =
+            ResolvedTsConfig::resolve(Path::new("/project/tsconfig.json"), &tsconfig, &host);
+
+        assert_eq!(
+            resolved.extended,
+            vec![PathBuf::from("/project/tsconfig.base.json")]
+        );
+        assert_eq!(resolved.base_url, Some(PathBuf::from("/project/src")));
+        assert!(resolved.is_unresolved_extends("./tsconfig.missing.json"));
+        assert!(!resolved.is_unresolved_extends("./tsconfig.base.json"));
+        assert!(resolved.is_unresolved_path("vendor/*"));
+        assert!(!resolved.is_unresolved_path("components/*"));
+        assert!(!resolved.is_unresolved_path("utils"));
+    }
+}
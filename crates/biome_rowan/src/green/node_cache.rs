@@ -169,6 +169,10 @@ pub struct NodeCache {
     tokens: HashMap<CachedToken, ()>,
     trivia: TriviaCache,
     generation: Generation,
+    /// Whether the cache is shared by the trees of several documents, see [NodeCache::shared]
+    shared: bool,
+    /// The number of entries that were kept by the last collection of a shared cache
+    collected_len: usize,
 }
 
 /// Represents a "generation" in the garbage collection scheme of the node
@@ -216,6 +220,25 @@ impl NodeCache {
     /// one of its children wasn't cached.
     const UNCACHED_NODE_HASH: u64 = 0;
 
+    /// The number of entries under which a shared cache is never collected
+    const MIN_SHARED_COLLECTION_LEN: usize = 64 * 1024;
+
+    /// Creates a cache shared by the trees of many documents, such as the files of a project,
+    /// so that the nodes, the tokens and the trivia they have in common are only stored once.
+    /// The tokens are keyed by their text, so the cache interns the text of the identifiers
+    /// and of the strings across all the documents.
+    ///
+    /// Unlike the cache of a single document, the entries that a tree doesn't reuse aren't
+    /// evicted once the tree is built, because the trees of the other documents may use them.
+    /// Instead, the entries that weren't used since the previous collection are evicted when
+    /// the size of the cache doubles.
+    pub fn shared() -> Self {
+        Self {
+            shared: true,
+            ..Self::default()
+        }
+    }
+
     /// Tries to retrieve a node with the given `kind` and `children` from the cache.
     ///
     /// Returns an entry that allows the caller to:
@@ -323,6 +346,11 @@ impl NodeCache {
     /// this point onward will update the generation of the corresponding entry
     /// to this new value
     pub(crate) fn increment_generation(&mut self) {
+        // The generation of a shared cache changes when it's collected
+        if self.shared {
+            return;
+        }
+
         debug_assert!(
             self.nodes
                 .keys()
@@ -344,6 +372,33 @@ impl NodeCache {
     /// Removes nodes, tokens and trivia entries from the cache when their
     /// generation doesn't match the current generation of the whole cache
     pub(crate) fn retain_cache(&mut self) {
+        if self.shared {
+            self.collect_shared();
+            return;
+        }
+
+        self.evict_previous_generation();
+    }
+
+    /// Evicts the entries of a shared cache that weren't used since its previous collection,
+    /// once the cache is twice as large as what the previous collection kept
+    fn collect_shared(&mut self) {
+        let len = self.len();
+        if len < Self::MIN_SHARED_COLLECTION_LEN.max(self.collected_len * 2) {
+            return;
+        }
+
+        self.evict_previous_generation();
+        self.collected_len = self.len();
+        // The entries used from now on are marked, and the others are evicted by the next collection
+        self.generation = !self.generation;
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.len() + self.tokens.len() + self.trivia.cache.len()
+    }
+
+    fn evict_previous_generation(&mut self) {
         self.nodes
             .retain(|node, _| node.node.generation() == self.generation);
 
@@ -514,6 +569,7 @@ mod tests {
 
     use crate::green::node_cache::{token_hash, CachedNode, CachedToken, CachedTrivia};
     use crate::green::trivia::GreenTrivia;
+    use crate::green::NodeCache;
     use crate::{GreenToken, RawSyntaxKind};
     use biome_text_size::TextSize;
 
@@ -548,6 +604,25 @@ mod tests {
         assert_ne!(token_hash(&t1), token_hash(&t4));
     }
 
+    #[test]
+    fn shared_cache_keeps_the_entries_of_other_trees() {
+        let mut cache = NodeCache::shared();
+        let kind = RawSyntaxKind(0);
+
+        cache.increment_generation();
+        let (_, first) = cache.token(kind, "value");
+        cache.retain_cache();
+
+        // Another tree that doesn't use the token
+        cache.increment_generation();
+        cache.token(kind, "other");
+        cache.retain_cache();
+
+        let (_, second) = cache.token(kind, "value");
+        assert!(std::ptr::eq(&*first, &*second));
+        assert_eq!(cache.len(), 2);
+    }
+
     #[test]
     fn cache_entry_size() {
         assert_eq!(size_of::<CachedNode>(), 16);
@@ -1,30 +1,42 @@
 use crate::matcher::Pattern;
-use crate::settings::Settings;
+use crate::settings::{matched_path_or_parents, merge_json_values, Settings};
 use crate::{DynRef, WorkspaceError};
 use biome_analyze::AnalyzerRules;
 use biome_configuration::diagnostics::{CantLoadExtendFile, EditorConfigDiagnostic};
+use biome_configuration::migrations::deserialize_configuration;
+use biome_configuration::suppressions::{self, SuppressionManifest, SUPPRESSIONS_FILE_NAME};
 use biome_configuration::{push_to_analyzer_assists, VERSION};
 use biome_configuration::{
     push_to_analyzer_rules, BiomeDiagnostic, ConfigurationPathHint, ConfigurationPayload,
     PartialConfiguration,
 };
 use biome_console::markup;
+#[cfg(feature = "css")]
 use biome_css_analyze::METADATA as css_lint_metadata;
 use biome_deserialize::json::deserialize_from_json_str;
 use biome_deserialize::{Deserialized, Merge};
 use biome_diagnostics::{DiagnosticExt, Error, Severity};
-use biome_fs::{AutoSearchResult, ConfigName, FileSystem, OpenOptions};
+use biome_fs::{
+    AutoSearchResult, BiomePath, ConfigName, FileSystem, OpenOptions, PathInterner,
+    TraversalContext,
+};
+#[cfg(feature = "graphql")]
 use biome_graphql_analyze::METADATA as graphql_lint_metadata;
+use biome_html_analyze::METADATA as html_lint_metadata;
 use biome_js_analyze::METADATA as js_lint_metadata;
 use biome_json_analyze::METADATA as json_lint_metadata;
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::{parse_json, JsonParserOptions};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use oxc_resolver::ResolveError;
+use std::collections::BTreeSet;
 use std::ffi::OsStr;
 use std::fmt::Debug;
 use std::io::ErrorKind;
 use std::iter::FusedIterator;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 /// Information regarding the configuration that was found.
 ///
@@ -38,6 +50,9 @@ pub struct LoadedConfiguration {
     pub file_path: Option<PathBuf>,
     /// The Deserialized configuration
     pub configuration: PartialConfiguration,
+    /// The `.biome-suppressions` file next to the configuration file, if present. Its entries
+    /// are already added to the overrides of the configuration.
+    pub suppression_manifest: Option<SuppressionManifest>,
     /// All diagnostics that were emitted during parsing and deserialization
     pub diagnostics: Vec<Error>,
 }
@@ -111,8 +126,11 @@ impl LoadedConfiguration {
             external_resolution_base_path,
             configuration_file_path,
             deserialized,
+            written_configuration,
         } = value;
         let (partial_configuration, mut diagnostics) = deserialized.consume();
+        let mut suppression_manifest = None;
+        let mut suppression_diagnostics = Vec::new();
 
         Ok(Self {
             configuration: match partial_configuration {
@@ -123,16 +141,34 @@ impl LoadedConfiguration {
                         &external_resolution_base_path,
                         &mut diagnostics,
                     )?;
+                    if let Some(written_configuration) = &written_configuration {
+                        partial_configuration.merge_override_rule_options(written_configuration);
+                    }
                     partial_configuration.migrate_deprecated_fields();
+                    suppression_manifest = load_suppression_manifest(
+                        fs,
+                        &configuration_file_path,
+                        &mut suppression_diagnostics,
+                    )?;
+                    if let Some(manifest) = &suppression_manifest {
+                        // The suppressions take precedence over the overrides of the configuration
+                        partial_configuration
+                            .overrides
+                            .get_or_insert_with(Default::default)
+                            .0
+                            .extend(manifest.to_overrides().0);
+                    }
                     partial_configuration
                 }
                 None => PartialConfiguration::default(),
             },
+            suppression_manifest,
             diagnostics: diagnostics
                 .into_iter()
                 .map(|diagnostic| {
                     diagnostic.with_file_path(configuration_file_path.display().to_string())
                 })
+                .chain(suppression_diagnostics)
                 .collect(),
             directory_path: configuration_file_path.parent().map(PathBuf::from),
             file_path: Some(configuration_file_path),
@@ -140,6 +176,33 @@ impl LoadedConfiguration {
     }
 }
 
+/// Loads the `.biome-suppressions` file next to the configuration file, if present.
+///
+/// The diagnostics of its entries are added to `diagnostics`, with the path of the file.
+fn load_suppression_manifest(
+    fs: &DynRef<'_, dyn FileSystem>,
+    configuration_file_path: &Path,
+    diagnostics: &mut Vec<Error>,
+) -> Result<Option<SuppressionManifest>, WorkspaceError> {
+    let Some(directory) = configuration_file_path.parent() else {
+        return Ok(None);
+    };
+    let manifest_path = directory.join(SUPPRESSIONS_FILE_NAME);
+    if !fs.path_is_file(&manifest_path) {
+        return Ok(None);
+    }
+
+    let content = fs.read_file_from_path(&manifest_path)?;
+    let (manifest, manifest_diagnostics) = suppressions::parse_str(&content);
+    diagnostics.extend(manifest_diagnostics.into_iter().map(|diagnostic| {
+        diagnostic
+            .with_file_path(manifest_path.display().to_string())
+            .with_file_source_code(&content)
+    }));
+
+    Ok((!manifest.is_empty()).then_some(manifest))
+}
+
 /// Load the partial configuration for this session of the CLI.
 pub fn load_configuration(
     fs: &DynRef<'_, dyn FileSystem>,
@@ -196,12 +259,15 @@ fn load_config(
                     .with_allow_comments()
                     .with_allow_trailing_commas(),
             };
-            let deserialized =
-                deserialize_from_json_str::<PartialConfiguration>(&content, parser_options, "");
+            let deserialized = deserialize_configuration(&content, parser_options);
+            let written_configuration =
+                deserialize_from_json_str::<serde_json::Value>(&content, parser_options, "")
+                    .into_deserialized();
             return Ok(Some(ConfigurationPayload {
                 deserialized,
                 configuration_file_path: PathBuf::from(config_file_path),
                 external_resolution_base_path,
+                written_configuration,
             }));
         }
     }
@@ -250,13 +316,16 @@ fn load_config(
                 .with_allow_trailing_commas(),
         };
 
-        let deserialized =
-            deserialize_from_json_str::<PartialConfiguration>(&content, parser_options, "");
+        let deserialized = deserialize_configuration(&content, parser_options);
+        let written_configuration =
+            deserialize_from_json_str::<serde_json::Value>(&content, parser_options, "")
+                .into_deserialized();
 
         Ok(Some(ConfigurationPayload {
             deserialized,
             configuration_file_path: file_path,
             external_resolution_base_path,
+            written_configuration,
         }))
     } else {
         Ok(None)
@@ -268,22 +337,36 @@ pub fn load_editorconfig(
     workspace_root: PathBuf,
 ) -> Result<(Option<PartialConfiguration>, Vec<EditorConfigDiagnostic>), WorkspaceError> {
     // How .editorconfig is supposed to be resolved: https://editorconfig.org/#file-location
-    // We currently don't support the `root` property, so we just search for the file like we do for biome.json
-    if let Some(auto_search_result) =
-        match file_system.auto_search(&workspace_root, [".editorconfig"].as_slice(), false) {
-            Ok(result) => result,
-            Err(error) => return Err(WorkspaceError::from(error)),
-        }
-    {
-        let AutoSearchResult {
-            content,
-            file_path: path,
-        } = auto_search_result;
+    // The files are searched from the workspace root up to the one with `root = true`
+    let mut editorconfigs = Vec::new();
+    for directory in workspace_root.ancestors() {
+        let path = directory.join(".editorconfig");
+        let Ok(content) = file_system.read_file_from_path(&path) else {
+            continue;
+        };
         let editorconfig = biome_configuration::editorconfig::parse_str(&content)?;
-        let config = editorconfig.to_biome();
+        let is_root = editorconfig.is_root();
+        editorconfigs.push((directory, path, editorconfig));
+        if is_root {
+            break;
+        }
+    }
+
+    // The closest file takes precedence, so it's merged last
+    let mut configuration: Option<PartialConfiguration> = None;
+    let mut diagnostics = Vec::new();
+    for (directory, path, editorconfig) in editorconfigs.into_iter().rev() {
+        let working_directory = workspace_root
+            .strip_prefix(directory)
+            .unwrap_or(Path::new(""));
+        let (config, config_diagnostics) = editorconfig.to_biome_from(working_directory);
+        diagnostics.extend(config_diagnostics);
+        let Some(config) = config else {
+            continue;
+        };
 
         // test the patterns to see if they are parsable so we can emit a better diagnostic
-        if let Some(overrides) = config.0.as_ref().and_then(|c| c.overrides.as_ref()) {
+        if let Some(overrides) = config.overrides.as_ref() {
             for override_pattern in &overrides.0 {
                 if let Some(pattern_set) = &override_pattern.include {
                     for pattern in pattern_set.iter() {
@@ -300,10 +383,13 @@ pub fn load_editorconfig(
             }
         }
 
-        Ok(config)
-    } else {
-        Ok((None, vec![]))
+        match configuration.as_mut() {
+            Some(configuration) => configuration.merge_with(config),
+            None => configuration = Some(config),
+        }
     }
+
+    Ok((configuration, diagnostics))
 }
 
 /// Creates a new configuration on file system
@@ -369,14 +455,19 @@ pub fn to_analyzer_rules(settings: &Settings, path: &Path) -> AnalyzerRules {
     let mut analyzer_rules = AnalyzerRules::default();
     if let Some(rules) = settings.linter.rules.as_ref() {
         push_to_analyzer_rules(rules, js_lint_metadata.deref(), &mut analyzer_rules);
+        #[cfg(feature = "css")]
         push_to_analyzer_rules(rules, css_lint_metadata.deref(), &mut analyzer_rules);
         push_to_analyzer_rules(rules, json_lint_metadata.deref(), &mut analyzer_rules);
+        #[cfg(feature = "graphql")]
         push_to_analyzer_rules(rules, graphql_lint_metadata.deref(), &mut analyzer_rules);
+        push_to_analyzer_rules(rules, html_lint_metadata.deref(), &mut analyzer_rules);
     }
     if let Some(rules) = settings.assists.actions.as_ref() {
         push_to_analyzer_assists(rules, js_lint_metadata.deref(), &mut analyzer_rules);
+        #[cfg(feature = "css")]
         push_to_analyzer_assists(rules, css_lint_metadata.deref(), &mut analyzer_rules);
         push_to_analyzer_assists(rules, json_lint_metadata.deref(), &mut analyzer_rules);
+        #[cfg(feature = "graphql")]
         push_to_analyzer_assists(rules, graphql_lint_metadata.deref(), &mut analyzer_rules);
     }
     let overrides = &settings.override_settings;
@@ -399,6 +490,8 @@ pub trait PartialConfigurationExt {
         external_resolution_base_path: &Path,
     ) -> Result<Vec<Deserialized<PartialConfiguration>>, WorkspaceError>;
 
+    fn merge_override_rule_options(&mut self, written_configuration: &serde_json::Value);
+
     fn migrate_deprecated_fields(&mut self);
 
     fn retrieve_gitignore_matches(
@@ -479,14 +572,18 @@ impl PartialConfigurationExt for PartialConfiguration {
                 ) {
                 relative_resolution_base_path.join(extend_entry)
             } else {
-                fs.resolve_configuration(extend_entry.as_str(), external_resolution_base_path)
-                    .map_err(|error| {
-                        BiomeDiagnostic::cant_resolve(
-                            external_resolution_base_path.display().to_string(),
-                            error,
-                        )
-                    })?
-                    .into_path_buf()
+                resolve_extended_package(
+                    fs,
+                    extend_entry,
+                    relative_resolution_base_path,
+                    external_resolution_base_path,
+                )
+                .map_err(|error| {
+                    BiomeDiagnostic::cant_resolve(
+                        external_resolution_base_path.display().to_string(),
+                        error,
+                    )
+                })?
             };
 
             let mut file = fs
@@ -517,7 +614,7 @@ impl PartialConfigurationExt for PartialConfiguration {
                 )
 
             })?;
-            let deserialized = deserialize_from_json_str::<PartialConfiguration>(
+            let deserialized = deserialize_configuration(
                 content.as_str(),
                 match extend_configuration_file_path
                     .extension()
@@ -528,13 +625,94 @@ impl PartialConfigurationExt for PartialConfiguration {
                         .with_allow_comments()
                         .with_allow_trailing_commas(),
                 },
-                "",
             );
             deserialized_configurations.push(deserialized)
         }
         Ok(deserialized_configurations)
     }
 
+    /// Merges the options of the rules set by the overrides of the file with the options of the
+    /// same rules in the configuration, extended configurations included, so that an override
+    /// only changes the options that it writes.
+    ///
+    /// The overrides of the file follow the ones of the extended configurations.
+    fn merge_override_rule_options(&mut self, written_configuration: &serde_json::Value) {
+        let Some(base_rules) = self
+            .linter
+            .as_ref()
+            .and_then(|linter| linter.rules.as_ref())
+        else {
+            return;
+        };
+        let Ok(base_rules) = serde_json::to_value(base_rules) else {
+            return;
+        };
+        let Some(written_overrides) = written_configuration
+            .get("overrides")
+            .and_then(serde_json::Value::as_array)
+        else {
+            return;
+        };
+        let Some(overrides) = self.overrides.as_mut() else {
+            return;
+        };
+
+        let own_overrides = overrides.0.len().saturating_sub(written_overrides.len());
+        for (pattern, written_pattern) in overrides.0[own_overrides..]
+            .iter_mut()
+            .zip(written_overrides)
+        {
+            let Some(written_rules) = written_pattern
+                .pointer("/linter/rules")
+                .and_then(serde_json::Value::as_object)
+            else {
+                continue;
+            };
+            let Some(rules) = pattern
+                .linter
+                .as_mut()
+                .and_then(|linter| linter.rules.as_mut())
+            else {
+                continue;
+            };
+            let Ok(mut merged_rules) = serde_json::to_value(&*rules) else {
+                continue;
+            };
+
+            for (group, written_group) in written_rules {
+                let Some(written_group) = written_group.as_object() else {
+                    continue;
+                };
+                for (rule, written_rule) in written_group {
+                    let Some(written_options) = written_rule.get("options") else {
+                        continue;
+                    };
+                    let Some(base_options) = base_rules
+                        .get(group)
+                        .and_then(|base_group| base_group.get(rule))
+                        .and_then(|base_rule| base_rule.get("options"))
+                    else {
+                        continue;
+                    };
+                    let mut options = base_options.clone();
+                    merge_json_values(&mut options, written_options.clone());
+                    if let Some(merged_rule) = merged_rules
+                        .get_mut(group)
+                        .and_then(|merged_group| merged_group.get_mut(rule))
+                        .and_then(serde_json::Value::as_object_mut)
+                    {
+                        merged_rule.insert("options".to_string(), options);
+                    }
+                }
+            }
+
+            // The rules were validated when they were deserialized
+            if let Ok(merged_rules) = serde_json::from_value(merged_rules) {
+                *rules = merged_rules;
+            }
+        }
+    }
+
     /// Checks for the presence of deprecated fields and updates the
     /// configuration to apply them to the new schema.
     fn migrate_deprecated_fields(&mut self) {
@@ -568,21 +746,17 @@ impl PartialConfigurationExt for PartialConfiguration {
         }
     }
 
-    /// This function checks if the VCS integration is enabled, and if so, it will attempts to resolve the
-    /// VCS root directory and the `.gitignore` file.
-    ///
-    /// ## Returns
-    ///
-    /// A tuple with VCS root folder and the contents of the `.gitignore` file
+    /// Returns the root of the ignore files and their patterns, rebased on the root: the ones of
+    /// the ignore file of the VCS, of the nested ignore files of the VCS, from the outermost to
+    /// the innermost so that the closest file takes precedence, and of `files.ignoreFiles`.
     fn retrieve_gitignore_matches(
         &self,
         file_system: &DynRef<'_, dyn FileSystem>,
         vcs_base_path: Option<&Path>,
     ) -> Result<(Option<PathBuf>, Vec<String>), WorkspaceError> {
-        let Some(vcs) = &self.vcs else {
-            return Ok((None, vec![]));
-        };
-        if vcs.is_enabled() {
+        let mut root = None;
+        let mut matches = Vec::new();
+        if let Some(vcs) = self.vcs.as_ref().filter(|vcs| vcs.is_enabled()) {
             let vcs_base_path = match (vcs_base_path, &vcs.root) {
                 (Some(vcs_base_path), Some(root)) => vcs_base_path.join(root),
                 (None, Some(root)) => PathBuf::from(root),
@@ -591,23 +765,324 @@ impl PartialConfigurationExt for PartialConfiguration {
             };
             if let Some(client_kind) = &vcs.client_kind {
                 if !vcs.ignore_file_disabled() {
+                    let file_name = client_kind.ignore_file();
                     let result = file_system
-                        .auto_search(&vcs_base_path, &[client_kind.ignore_file()], false)
+                        .auto_search(&vcs_base_path, &[file_name], false)
                         .map_err(WorkspaceError::from)?;
-
-                    if let Some(result) = result {
-                        return Ok((
-                            result.file_path.parent().map(PathBuf::from),
+                    let vcs_root = match result {
+                        Some(result) => {
+                            matches.extend(result.content.lines().map(String::from));
                             result
-                                .content
-                                .lines()
-                                .map(String::from)
-                                .collect::<Vec<String>>(),
-                        ));
+                                .file_path
+                                .parent()
+                                .map_or_else(|| vcs_base_path.clone(), PathBuf::from)
+                        }
+                        None => vcs_base_path.clone(),
+                    };
+                    for ignore_file in find_nested_ignore_files(
+                        file_system,
+                        &vcs_root,
+                        &vcs_base_path,
+                        file_name,
+                        &matches,
+                    ) {
+                        let content = file_system.read_file_from_path(&ignore_file)?;
+                        matches.extend(rebase_ignore_file(&content, &ignore_file, &vcs_root));
                     }
+                    root = Some(vcs_root);
                 }
             }
         }
-        Ok((None, vec![]))
+
+        let ignore_files = self
+            .files
+            .as_ref()
+            .and_then(|files| files.ignore_files.as_ref());
+        if let (Some(ignore_files), Some(base_path)) = (ignore_files, vcs_base_path) {
+            let root = root.get_or_insert_with(|| base_path.to_path_buf());
+            for ignore_file in ignore_files.iter() {
+                let ignore_file = base_path.join(ignore_file);
+                let content = file_system.read_file_from_path(&ignore_file)?;
+                matches.extend(rebase_ignore_file(&content, &ignore_file, root));
+            }
+        }
+
+        Ok(if matches.is_empty() {
+            (None, matches)
+        } else {
+            (root, matches)
+        })
+    }
+}
+
+/// Returns the ignore files named `file_name` nested in `root`, from the outermost to the
+/// innermost: the ones of the directories between `root` and `base_path`, and the ones found
+/// under `base_path`, except in the directories that `root_matches` ignores.
+fn find_nested_ignore_files(
+    file_system: &DynRef<'_, dyn FileSystem>,
+    root: &Path,
+    base_path: &Path,
+    file_name: &str,
+    root_matches: &[String],
+) -> Vec<PathBuf> {
+    let mut ignore_files: Vec<_> = base_path
+        .ancestors()
+        .skip(1)
+        .take_while(|directory| directory.starts_with(root) && *directory != root)
+        .map(|directory| directory.join(file_name))
+        .filter(|path| file_system.path_is_file(path))
+        .collect();
+    ignore_files.reverse();
+
+    let mut builder = GitignoreBuilder::new(root);
+    for line in root_matches {
+        // The invalid patterns are reported once the settings are built
+        let _ = builder.add_line(None, line);
+    }
+    let (interner, _) = PathInterner::new();
+    let collector = IgnoreFilesCollector {
+        file_system: &**file_system,
+        file_name,
+        root_ignore: builder.build().unwrap_or_else(|_| Gitignore::empty()),
+        interner,
+        ignore_files: Mutex::default(),
+    };
+    file_system.traversal(Box::new(|scope| {
+        scope.evaluate(&collector, base_path.to_path_buf());
+    }));
+    let mut nested: Vec<_> = collector
+        .evaluated_paths()
+        .into_iter()
+        .map(|path| path.to_path_buf())
+        .filter(|path| path.parent() != Some(root))
+        .collect();
+    nested.sort_by_key(|path| path.components().count());
+    ignore_files.extend(nested);
+    ignore_files
+}
+
+/// Collects the ignore files found by a traversal, except in the directories ignored by the
+/// ignore file of the root
+struct IgnoreFilesCollector<'a> {
+    file_system: &'a dyn FileSystem,
+    file_name: &'a str,
+    root_ignore: Gitignore,
+    interner: PathInterner,
+    ignore_files: Mutex<BTreeSet<BiomePath>>,
+}
+
+impl TraversalContext for IgnoreFilesCollector<'_> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, _error: Error) {
+        // The directories that can't be read are reported by the traversal of the command
+    }
+
+    fn can_handle(&self, path: &BiomePath) -> bool {
+        let is_dir = self.file_system.path_is_dir(path);
+        if !is_dir && path.file_name() != Some(OsStr::new(self.file_name)) {
+            return false;
+        }
+        !matched_path_or_parents(&self.root_ignore, path, is_dir).is_ignore()
+    }
+
+    fn handle_path(&self, path: BiomePath) {
+        self.store_path(path);
+    }
+
+    fn store_path(&self, path: BiomePath) {
+        self.ignore_files.lock().unwrap().insert(path);
+    }
+
+    fn evaluated_paths(&self) -> BTreeSet<BiomePath> {
+        self.ignore_files.lock().unwrap().clone()
+    }
+}
+
+/// Returns the patterns of the ignore file at `path`, rebased on `root`
+fn rebase_ignore_file(content: &str, path: &Path, root: &Path) -> Vec<String> {
+    let directory = path
+        .parent()
+        .and_then(|directory| directory.strip_prefix(root).ok())
+        .unwrap_or(Path::new(""));
+    content
+        .lines()
+        .filter_map(|line| rebase_ignore_pattern(line, directory))
+        .collect()
+}
+
+/// Rebases a line of an ignore file on the root of the ignore files, where `directory` is the
+/// directory of the file relative to the root. Like git, a pattern without a slash, except a
+/// trailing one, matches at any depth below the directory of its file.
+///
+/// The blank lines and the comments are dropped.
+fn rebase_ignore_pattern(line: &str, directory: &Path) -> Option<String> {
+    if line.trim().is_empty() || line.starts_with('#') {
+        return None;
+    }
+    if directory.as_os_str().is_empty() {
+        return Some(line.to_string());
+    }
+    let (negation, pattern) = match line.strip_prefix('!') {
+        Some(pattern) => ("!", pattern),
+        None => ("", line),
+    };
+    let directory = directory.to_string_lossy().replace('\\', "/");
+    Some(if pattern.trim_end_matches('/').contains('/') {
+        format!("{negation}{directory}/{}", pattern.trim_start_matches('/'))
+    } else {
+        format!("{negation}{directory}/**/{pattern}")
+    })
+}
+
+/// Resolves a package listed in `extends` to its configuration file: the entry point of the
+/// package, or its `./biome` export when the package doesn't export its root.
+///
+/// The package is searched in the `node_modules` directories of the configuration file and of
+/// its ancestors, then in the ones of `external_resolution_base_path`, so that a nested
+/// project can extend a package installed next to it.
+fn resolve_extended_package(
+    fs: &DynRef<'_, dyn FileSystem>,
+    specifier: &str,
+    relative_resolution_base_path: &Path,
+    external_resolution_base_path: &Path,
+) -> Result<PathBuf, ResolveError> {
+    let mut specifiers = vec![specifier.to_string()];
+    if is_package_name(specifier) {
+        specifiers.push(format!("{specifier}/biome"));
+    }
+    let mut base_paths = vec![relative_resolution_base_path];
+    if external_resolution_base_path != relative_resolution_base_path {
+        base_paths.push(external_resolution_base_path);
+    }
+
+    let mut first_error = None;
+    for base_path in base_paths {
+        for specifier in &specifiers {
+            match fs.resolve_configuration(specifier, base_path) {
+                Ok(resolution) => return Ok(resolution.into_path_buf()),
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+    }
+    Err(first_error.unwrap_or_else(|| ResolveError::NotFound(specifier.to_string())))
+}
+
+/// Whether `specifier` is the name of a package, such as `@acme/biome-config`, rather than
+/// a file of a package
+fn is_package_name(specifier: &str) -> bool {
+    let segments = specifier.split('/').count();
+    if specifier.starts_with('@') {
+        segments == 2
+    } else {
+        segments == 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_package_name, rebase_ignore_pattern, resolve_extended_package};
+    use crate::DynRef;
+    use biome_fs::{FileSystem, OsFileSystem};
+    use std::path::{Path, PathBuf};
+
+    fn fixtures() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/extends")
+    }
+
+    fn resolve(specifier: &str, relative_base_path: &Path, external_base_path: &Path) -> PathBuf {
+        let fs: DynRef<'_, dyn FileSystem> = DynRef::Owned(Box::<OsFileSystem>::default());
+        resolve_extended_package(&fs, specifier, relative_base_path, external_base_path).unwrap()
+    }
+
+    #[test]
+    fn rebases_the_patterns_of_nested_ignore_files() {
+        let directory = Path::new("packages/app");
+        let rebase = |line| rebase_ignore_pattern(line, directory);
+
+        assert_eq!(rebase("*.log"), Some("packages/app/**/*.log".to_string()));
+        assert_eq!(rebase("dist/"), Some("packages/app/**/dist/".to_string()));
+        assert_eq!(rebase("/dist"), Some("packages/app/dist".to_string()));
+        assert_eq!(
+            rebase("src/*.js"),
+            Some("packages/app/src/*.js".to_string())
+        );
+        assert_eq!(
+            rebase("!keep.log"),
+            Some("!packages/app/**/keep.log".to_string())
+        );
+        assert_eq!(rebase("# comment"), None);
+        assert_eq!(rebase(""), None);
+        assert_eq!(
+            rebase_ignore_pattern("*.log", Path::new("")),
+            Some("*.log".to_string())
+        );
+    }
+
+    #[test]
+    fn detects_the_names_of_packages() {
+        assert!(is_package_name("shared-config"));
+        assert!(is_package_name("@acme/biome-config"));
+        assert!(!is_package_name("shared-config/biome"));
+        assert!(!is_package_name("@acme/biome-config/biome"));
+    }
+
+    #[test]
+    fn resolves_the_biome_export_of_a_package_that_does_not_export_its_root() {
+        let workspace = fixtures().join("workspace");
+
+        assert_eq!(
+            resolve("@acme/biome-config", &workspace, &workspace),
+            workspace.join("node_modules/@acme/biome-config/biome.json")
+        );
+    }
+
+    #[test]
+    fn resolves_the_main_entry_of_a_package_before_its_biome_file() {
+        let workspace = fixtures().join("workspace");
+
+        assert_eq!(
+            resolve("shared-config", &workspace, &workspace),
+            workspace.join("node_modules/shared-config/config.json")
+        );
+        assert_eq!(
+            resolve("shared-config/biome", &workspace, &workspace),
+            workspace.join("node_modules/shared-config/biome.json")
+        );
+    }
+
+    #[test]
+    fn resolves_the_packages_of_a_nested_project() {
+        let workspace = fixtures().join("workspace");
+        let app = workspace.join("packages/app");
+
+        // The packages installed next to the nested project are found first, then the ones of
+        // its ancestors
+        assert_eq!(
+            resolve("nested-config", &app, &workspace),
+            app.join("node_modules/nested-config/biome.json")
+        );
+        assert_eq!(
+            resolve("shared-config", &app, &workspace),
+            workspace.join("node_modules/shared-config/config.json")
+        );
+    }
+
+    #[test]
+    fn resolves_the_packages_from_the_external_base_path_last() {
+        let workspace = fixtures().join("workspace");
+        let standalone = fixtures().join("standalone");
+
+        assert_eq!(
+            resolve("shared-config", &standalone, &workspace),
+            workspace.join("node_modules/shared-config/config.json")
+        );
+
+        let fs: DynRef<'_, dyn FileSystem> = DynRef::Owned(Box::<OsFileSystem>::default());
+        assert!(resolve_extended_package(&fs, "nested-config", &standalone, &workspace).is_err());
     }
 }
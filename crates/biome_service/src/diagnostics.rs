@@ -4,15 +4,16 @@ use biome_configuration::diagnostics::{ConfigurationDiagnostic, EditorConfigDiag
 use biome_configuration::{BiomeDiagnostic, CantLoadExtendFile};
 use biome_console::fmt::Bytes;
 use biome_console::markup;
-use biome_css_parser::ParseDiagnostic;
 use biome_diagnostics::{
     category, Advices, Category, Diagnostic, DiagnosticTags, Location, LogCategory,
     MessageAndDescription, Severity, Visit,
 };
 use biome_formatter::{FormatError, PrintError};
 use biome_fs::{BiomePath, FileSystemDiagnostic};
+#[cfg(feature = "grit")]
 use biome_grit_patterns::CompileError;
 use biome_js_analyze::utils::rename::RenameError;
+use biome_parser::diagnostic::ParseDiagnostic;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::ffi::OsStr;
@@ -472,6 +473,7 @@ impl From<VcsDiagnostic> for WorkspaceError {
     }
 }
 
+#[cfg(feature = "grit")]
 impl From<CompileError> for WorkspaceError {
     fn from(value: CompileError) -> Self {
         match value {
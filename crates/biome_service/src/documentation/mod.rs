@@ -1,6 +1,9 @@
 use biome_analyze::{GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleMetadata};
+#[cfg(feature = "css")]
 use biome_css_syntax::CssLanguage;
+#[cfg(feature = "graphql")]
 use biome_graphql_syntax::GraphqlLanguage;
+use biome_html_syntax::HtmlLanguage;
 use biome_js_syntax::JsLanguage;
 use biome_json_syntax::JsonLanguage;
 use std::{collections::BTreeMap, str::FromStr};
@@ -19,7 +22,9 @@ impl FromStr for Doc {
         match s {
             "daemon-logs" => Ok(Doc::DaemonLogs),
             _ => {
-                if let Some(metadata) = LintRulesVisitor::new().get_metadata(s) {
+                if let Some(metadata) =
+                    RulesMetadataVisitor::new(RuleCategory::Lint).get_metadata(s)
+                {
                     return Ok(Doc::Rule(metadata));
                 };
 
@@ -29,17 +34,35 @@ impl FromStr for Doc {
     }
 }
 
-struct LintRulesVisitor {
-    rules_metadata: BTreeMap<&'static str, RuleMetadata>,
+/// Returns the metadata of the lint rules of all the languages, by name.
+///
+/// The rules of different languages can have the same name, such as `noDuplicateObjectKeys`.
+pub fn lint_rules_metadata() -> BTreeMap<&'static str, Vec<RuleMetadata>> {
+    RulesMetadataVisitor::new(RuleCategory::Lint).rules_metadata
 }
 
-impl LintRulesVisitor {
-    fn new() -> Self {
+/// Returns the metadata of the assist rules of all the languages, by name.
+pub fn assist_rules_metadata() -> BTreeMap<&'static str, Vec<RuleMetadata>> {
+    RulesMetadataVisitor::new(RuleCategory::Action).rules_metadata
+}
+
+/// Collects the metadata of the rules of a category
+struct RulesMetadataVisitor {
+    category: RuleCategory,
+    rules_metadata: BTreeMap<&'static str, Vec<RuleMetadata>>,
+}
+
+impl RulesMetadataVisitor {
+    fn new(category: RuleCategory) -> Self {
         let mut visitor = Self {
+            category,
             rules_metadata: BTreeMap::new(),
         };
 
+        #[cfg(feature = "graphql")]
         biome_graphql_analyze::visit_registry(&mut visitor);
+        biome_html_analyze::visit_registry(&mut visitor);
+        #[cfg(feature = "css")]
         biome_css_analyze::visit_registry(&mut visitor);
         biome_json_analyze::visit_registry(&mut visitor);
         biome_js_analyze::visit_registry(&mut visitor);
@@ -48,68 +71,104 @@ impl LintRulesVisitor {
     }
 
     fn get_metadata(&mut self, name: &str) -> Option<RuleMetadata> {
-        self.rules_metadata.remove(name)
+        // The rules of JavaScript are visited last, so they take precedence over the others
+        self.rules_metadata
+            .remove(name)
+            .and_then(|mut rules_metadata| rules_metadata.pop())
     }
 }
 
-impl RegistryVisitor<JsLanguage> for LintRulesVisitor {
+impl RegistryVisitor<JsLanguage> for RulesMetadataVisitor {
     fn record_rule<R>(&mut self)
     where
         R: Rule<Options: Default, Query: Queryable<Language = JsLanguage, Output: Clone>> + 'static,
     {
-        self.rules_metadata.insert(R::METADATA.name, R::METADATA);
+        self.rules_metadata
+            .entry(R::METADATA.name)
+            .or_default()
+            .push(R::METADATA);
     }
 
     fn record_category<C: biome_analyze::GroupCategory<Language = JsLanguage>>(&mut self) {
-        if matches!(C::CATEGORY, RuleCategory::Lint) {
+        if C::CATEGORY == self.category {
             C::record_groups(self);
         }
     }
 }
 
-impl RegistryVisitor<JsonLanguage> for LintRulesVisitor {
+impl RegistryVisitor<JsonLanguage> for RulesMetadataVisitor {
     fn record_rule<R>(&mut self)
     where
         R: Rule<Options: Default, Query: Queryable<Language = JsonLanguage, Output: Clone>>
             + 'static,
     {
-        self.rules_metadata.insert(R::METADATA.name, R::METADATA);
+        self.rules_metadata
+            .entry(R::METADATA.name)
+            .or_default()
+            .push(R::METADATA);
     }
 
     fn record_category<C: biome_analyze::GroupCategory<Language = JsonLanguage>>(&mut self) {
-        if matches!(C::CATEGORY, RuleCategory::Lint) {
+        if C::CATEGORY == self.category {
             C::record_groups(self);
         }
     }
 }
 
-impl RegistryVisitor<CssLanguage> for LintRulesVisitor {
+#[cfg(feature = "css")]
+impl RegistryVisitor<CssLanguage> for RulesMetadataVisitor {
     fn record_rule<R>(&mut self)
     where
         R: Rule<Options: Default, Query: Queryable<Language = CssLanguage, Output: Clone>>
             + 'static,
     {
-        self.rules_metadata.insert(R::METADATA.name, R::METADATA);
+        self.rules_metadata
+            .entry(R::METADATA.name)
+            .or_default()
+            .push(R::METADATA);
     }
 
     fn record_category<C: GroupCategory<Language = CssLanguage>>(&mut self) {
-        if matches!(C::CATEGORY, RuleCategory::Lint) {
+        if C::CATEGORY == self.category {
             C::record_groups(self);
         }
     }
 }
 
-impl RegistryVisitor<GraphqlLanguage> for LintRulesVisitor {
+#[cfg(feature = "graphql")]
+impl RegistryVisitor<GraphqlLanguage> for RulesMetadataVisitor {
     fn record_rule<R>(&mut self)
     where
         R: Rule<Options: Default, Query: Queryable<Language = GraphqlLanguage, Output: Clone>>
             + 'static,
     {
-        self.rules_metadata.insert(R::METADATA.name, R::METADATA);
+        self.rules_metadata
+            .entry(R::METADATA.name)
+            .or_default()
+            .push(R::METADATA);
     }
 
     fn record_category<C: GroupCategory<Language = GraphqlLanguage>>(&mut self) {
-        if matches!(C::CATEGORY, RuleCategory::Lint) {
+        if C::CATEGORY == self.category {
+            C::record_groups(self);
+        }
+    }
+}
+
+impl RegistryVisitor<HtmlLanguage> for RulesMetadataVisitor {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.rules_metadata
+            .entry(R::METADATA.name)
+            .or_default()
+            .push(R::METADATA);
+    }
+
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if C::CATEGORY == self.category {
             C::record_groups(self);
         }
     }
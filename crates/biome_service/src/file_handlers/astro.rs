@@ -1,5 +1,7 @@
+#[cfg(feature = "css")]
+use crate::file_handlers::embedded_css;
 use crate::file_handlers::{
-    javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
+    html, javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
     ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
     ParserCapabilities,
 };
@@ -8,16 +10,21 @@ use crate::workspace::{
     DocumentFileSource, FixFileResult, OrganizeImportsResult, PullActionsResult,
 };
 use crate::WorkspaceError;
+#[cfg(feature = "css")]
+use biome_css_syntax::{CssFileSource, CssLanguage};
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
+use biome_html_parser::{parse_html_with_cache, HtmlParserOptions};
+use biome_html_syntax::{HtmlFileSource, HtmlLanguage};
+use biome_js_formatter::context::JsFormatOptions;
 use biome_js_parser::{parse_js_with_cache, JsParserOptions};
-use biome_js_syntax::{JsFileSource, TextRange, TextSize};
+use biome_js_syntax::{JsFileSource, JsLanguage, TextRange, TextSize};
 use biome_parser::AnyParse;
 use biome_rowan::NodeCache;
 use regex::{Matches, Regex, RegexBuilder};
 use std::sync::LazyLock;
 
-use super::SearchCapabilities;
+use super::{SearchCapabilities, SymbolCapabilities};
 
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct AstroFileHandler;
@@ -29,6 +36,10 @@ pub static ASTRO_FENCE: LazyLock<Regex> = LazyLock::new(|| {
         .unwrap()
 });
 
+// The expressions of the template that don't contain braces or tags, such as `{title}`
+static TEMPLATE_EXPRESSION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"\{(?<expression>[^{}<>]+)\}"#).unwrap());
+
 impl AstroFileHandler {
     /// It extracts the JavaScript code contained in the frontmatter of an Astro file
     ///
@@ -46,6 +57,15 @@ impl AstroFileHandler {
         ASTRO_FENCE.find_iter(input).next().map(|m| m.end() as u32)
     }
 
+    /// Returns the byte offset at which the template starts, after the frontmatter
+    fn template_start(input: &str) -> usize {
+        let mut matches = Self::matches(input);
+        match (matches.next(), matches.next()) {
+            (Some(_), Some(end)) => end.end(),
+            _ => 0,
+        }
+    }
+
     fn matches(input: &str) -> Matches {
         ASTRO_FENCE.find_iter(input)
     }
@@ -79,6 +99,7 @@ impl ExtensionHandler for AstroFileHandler {
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
+                prepare_rename: None,
                 rename: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
@@ -89,34 +110,61 @@ impl ExtensionHandler for AstroFileHandler {
                 format_on_type: Some(format_on_type),
             },
             // TODO: We should be able to search JS portions already
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities::default(),
+            symbols: SymbolCapabilities {
+                document_symbols: None,
+                semantic_tokens: None,
+                inlay_hints: None,
+                signature_help: None,
+                folding_ranges: None,
+                selection_ranges: None,
+            },
         }
     }
 }
 
+/// An Astro file is parsed with the HTML parser, so that the capabilities have access to the
+/// frontmatter and the template. The frontmatter is parsed with the JavaScript parser by the
+/// capabilities that need it.
 fn parse(
     _rome_path: &BiomePath,
-    file_source: DocumentFileSource,
+    _file_source: DocumentFileSource,
     text: &str,
     _settings: Option<&Settings>,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let frontmatter = AstroFileHandler::input(text);
-    let parse = parse_js_with_cache(
-        frontmatter,
-        file_source
-            .to_js_file_source()
-            .unwrap_or(JsFileSource::ts()),
-        JsParserOptions::default(),
-        cache,
-    );
+    let parse = parse_html_with_cache(text, cache, HtmlParserOptions::default());
 
     ParseResult {
-        any_parse: parse.into(),
+        // The syntax errors of the frontmatter are reported by the linter. The ones of the template
+        // aren't, because the HTML parser doesn't support the expressions of the Astro templates.
+        any_parse: AnyParse::new(parse.syntax().as_send().unwrap(), Vec::new()),
         language: Some(JsFileSource::astro().into()),
     }
 }
 
+/// Returns the text of the Astro document
+fn document(parse: &AnyParse) -> String {
+    parse.syntax::<HtmlLanguage>().to_string()
+}
+
+/// Parses the frontmatter of the Astro document. The ranges of the returned tree
+/// start at the end of the opening fence.
+fn parse_frontmatter(document: &str) -> AnyParse {
+    parse_js_with_cache(
+        AstroFileHandler::input(document),
+        JsFileSource::astro(),
+        JsParserOptions::default(),
+        &mut NodeCache::default(),
+    )
+    .into()
+}
+
+/// It formats the whole document:
+/// - the frontmatter with the JavaScript formatter;
+/// - the template with the HTML formatter, and its expressions with the JavaScript formatter,
+///   when the HTML formatter is enabled;
+/// - the style blocks with the CSS formatter.
 #[tracing::instrument(level = "trace", skip(parse, settings))]
 fn format(
     biome_path: &BiomePath,
@@ -124,8 +172,99 @@ fn format(
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format(biome_path, document_file_source, parse, settings)
+    let document = document(&parse);
+    let (format_with_errors, html_formatter_enabled) =
+        settings.settings().map_or((false, false), |settings| {
+            (
+                settings.formatter().format_with_errors,
+                !settings.html_formatter_disabled(),
+            )
+        });
+    let html_options = settings.format_options::<HtmlLanguage>(
+        biome_path,
+        &DocumentFileSource::Html(HtmlFileSource::html()),
+    );
+    let js_options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
+    #[cfg(feature = "css")]
+    let css_options = settings
+        .format_options::<CssLanguage>(biome_path, &DocumentFileSource::Css(CssFileSource::css()));
+
+    let frontmatter = parse_frontmatter(&document);
+    if !format_with_errors && frontmatter.has_errors() {
+        return Err(WorkspaceError::format_with_errors_disabled());
+    }
+    let printed = javascript::format(biome_path, document_file_source, frontmatter, settings)?;
+    let mut output = if printed.as_code().is_empty() {
+        document
+    } else {
+        AstroFileHandler::output(&document, printed.as_code())
+    };
+
+    if html_formatter_enabled {
+        // The template starts after the line break that follows the frontmatter
+        let template_start = AstroFileHandler::template_start(&output);
+        let template = &output[template_start..];
+        let template_start = template_start + (template.len() - template.trim_start().len());
+        let template = html::format_markup(&output[template_start..], html_options)?;
+        let template = format_expressions(&template, js_options)?;
+        output.replace_range(template_start.., &template);
+    }
+    #[cfg(feature = "css")]
+    let output = embedded_css::format(&output, css_options, None)?;
+
+    Ok(Printed::new(output, None, Vec::new(), Vec::new()))
+}
+
+/// It formats the expressions of the template `text` that fit on a single line, such as
+/// `{title}` or `class={styles.title}`. The expressions that contain braces or tags,
+/// such as the objects or the JSX elements, are left as they are.
+fn format_expressions(text: &str, options: JsFormatOptions) -> Result<String, WorkspaceError> {
+    let raw_text_blocks: Vec<_> = html::RAW_TEXT_BLOCK
+        .find_iter(text)
+        .map(|block| block.range())
+        .collect();
+
+    let mut output = text.to_string();
+    let expressions: Vec<_> = TEMPLATE_EXPRESSION
+        .captures_iter(text)
+        .filter_map(|captures| captures.name("expression"))
+        .collect();
+    for expression in expressions.into_iter().rev() {
+        let start = expression.start();
+        if raw_text_blocks.iter().any(|block| block.contains(&start)) {
+            continue;
+        }
+        // The braces of a tag are the value of an attribute, the others are text
+        let before = &text[..start - 1];
+        let is_in_tag = before.rfind('<') > before.rfind('>');
+        if is_in_tag && !before.ends_with('=') {
+            continue;
+        }
+
+        let parse = biome_js_parser::parse(
+            &format!("({})", expression.as_str()),
+            JsFileSource::ts(),
+            JsParserOptions::default(),
+        );
+        if parse.has_errors() {
+            continue;
+        }
+        let formatted = biome_js_formatter::format_node(options.clone(), &parse.syntax())?;
+        let printed = formatted
+            .print()
+            .map_err(|error| WorkspaceError::FormatError(error.into()))?;
+        let code = printed.as_code().trim_end().trim_end_matches(';');
+        // The parentheses that the formatter keeps, such as the ones of an object, aren't part
+        // of the expression
+        if code.contains('\n') || code.starts_with('(') {
+            continue;
+        }
+        output.replace_range(expression.range(), code);
+    }
+
+    Ok(output)
 }
+
 pub(crate) fn format_range(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
@@ -133,7 +272,14 @@ pub(crate) fn format_range(
     settings: WorkspaceSettingsHandle,
     range: TextRange,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format_range(biome_path, document_file_source, parse, settings, range)
+    let frontmatter = parse_frontmatter(&document(&parse));
+    javascript::format_range(
+        biome_path,
+        document_file_source,
+        frontmatter,
+        settings,
+        range,
+    )
 }
 
 pub(crate) fn format_on_type(
@@ -143,21 +289,81 @@ pub(crate) fn format_on_type(
     settings: WorkspaceSettingsHandle,
     offset: TextSize,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format_on_type(biome_path, document_file_source, parse, settings, offset)
+    let frontmatter = parse_frontmatter(&document(&parse));
+    javascript::format_on_type(
+        biome_path,
+        document_file_source,
+        frontmatter,
+        settings,
+        offset,
+    )
 }
 
+/// It lints the frontmatter with the JavaScript rules, and the style blocks and the `style`
+/// attributes with the CSS rules. The ranges of the diagnostics are the ones of the document.
 pub(crate) fn lint(params: LintParams) -> LintResults {
-    javascript::lint(params)
+    let document = document(&params.parse);
+    let offset = AstroFileHandler::start(&document).map_or(TextSize::from(0), TextSize::from);
+
+    let mut results = javascript::lint(LintParams {
+        parse: parse_frontmatter(&document),
+        only: params.only.clone(),
+        skip: params.skip.clone(),
+        manifest: params.manifest.clone(),
+        #[cfg(feature = "css")]
+        css_project: params.css_project.clone(),
+        js_project: params.js_project.clone(),
+        json_schemas: params.json_schemas.clone(),
+        #[cfg(feature = "graphql")]
+        graphql_schema: params.graphql_schema.clone(),
+        #[cfg(feature = "graphql")]
+        graphql_project: params.graphql_project.clone(),
+        angular_project: params.angular_project.clone(),
+        suppression_reason: params.suppression_reason.clone(),
+        ..params
+    });
+    results.diagnostics = results
+        .diagnostics
+        .into_iter()
+        .map(|diagnostic| diagnostic.with_offset(offset))
+        .collect();
+
+    #[cfg(feature = "css")]
+    {
+        let max_diagnostics = params
+            .max_diagnostics
+            .saturating_sub(results.diagnostics.len() as u32);
+        let css_results = embedded_css::lint(
+            &document,
+            LintParams {
+                max_diagnostics,
+                ..params
+            },
+        );
+        results.diagnostics.extend(css_results.diagnostics);
+        results.errors += css_results.errors;
+        results.skipped_diagnostics += css_results.skipped_diagnostics;
+    }
+
+    results
 }
 
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
-    javascript::code_actions(params)
+    let frontmatter = parse_frontmatter(&document(&params.parse));
+    javascript::code_actions(CodeActionsParams {
+        parse: frontmatter,
+        ..params
+    })
 }
 
 fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
-    javascript::fix_all(params)
+    let frontmatter = parse_frontmatter(&document(&params.parse));
+    javascript::fix_all(FixAllParams {
+        parse: frontmatter,
+        ..params
+    })
 }
 
 fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
-    javascript::organize_imports(parse)
+    javascript::organize_imports(parse_frontmatter(&document(&parse)))
 }
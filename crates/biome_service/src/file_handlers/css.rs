@@ -1,6 +1,7 @@
+use super::syntax_ranges::{block_folding_range, push_comment_folding_ranges, selection_ranges};
 use super::{
     is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, ExtensionHandler, FixAllParams,
-    LintParams, LintResults, ParseResult, SearchCapabilities,
+    FixCycleGuard, LintParams, LintResults, ParseResult, SearchCapabilities, SymbolCapabilities,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::file_handlers::DebugCapabilities;
@@ -12,8 +13,9 @@ use crate::settings::{
     ServiceLanguage, Settings, WorkspaceSettingsHandle,
 };
 use crate::workspace::{
-    CodeAction, DocumentFileSource, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult,
-    OrganizeImportsResult, PullActionsResult,
+    CodeAction, DocumentFileSource, DocumentSymbol, FixAction, FixFileMode, FixFileResult,
+    FoldingRange, GetSyntaxTreeResult, OrganizeImportsResult, PullActionsResult, SemanticToken,
+    SemanticTokenKind, SemanticTokenModifier, SymbolKind,
 };
 use crate::WorkspaceError;
 use biome_analyze::options::PreferredQuote;
@@ -22,10 +24,14 @@ use biome_analyze::{
     RuleCategoriesBuilder, RuleCategory, RuleError,
 };
 use biome_css_analyze::analyze;
-use biome_css_formatter::context::CssFormatOptions;
+use biome_css_formatter::context::{CssFormatOptions, HexColorCase, KeywordCase, UrlQuotes};
 use biome_css_formatter::format_node;
 use biome_css_parser::CssParserOptions;
-use biome_css_syntax::{CssLanguage, CssRoot, CssSyntaxNode};
+use biome_css_semantic::model::CssGlobalCustomVariable;
+use biome_css_semantic::semantic_model;
+use biome_css_syntax::{
+    CssClassSelector, CssDashedIdentifier, CssLanguage, CssRoot, CssSyntaxKind, CssSyntaxNode,
+};
 use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
 use biome_formatter::{
     FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle,
@@ -45,6 +51,9 @@ pub struct CssFormatterSettings {
     pub indent_width: Option<IndentWidth>,
     pub indent_style: Option<IndentStyle>,
     pub quote_style: Option<QuoteStyle>,
+    pub hex_color_case: Option<HexColorCase>,
+    pub keyword_case: Option<KeywordCase>,
+    pub url_quotes: Option<UrlQuotes>,
     pub enabled: Option<bool>,
 }
 
@@ -107,7 +116,10 @@ impl ServiceLanguage for CssLanguage {
         .with_indent_width(indent_width)
         .with_line_width(line_width)
         .with_line_ending(line_ending)
-        .with_quote_style(language.and_then(|l| l.quote_style).unwrap_or_default());
+        .with_quote_style(language.and_then(|l| l.quote_style).unwrap_or_default())
+        .with_hex_color_case(language.and_then(|l| l.hex_color_case).unwrap_or_default())
+        .with_keyword_case(language.and_then(|l| l.keyword_case).unwrap_or_default())
+        .with_url_quotes(language.and_then(|l| l.url_quotes).unwrap_or_default());
         if let Some(overrides) = overrides {
             overrides.to_override_css_format_options(path, options)
         } else {
@@ -148,6 +160,8 @@ impl ServiceLanguage for CssLanguage {
             globals: Vec::new(),
             preferred_quote,
             jsx_runtime: None,
+            require_suppression_reason: global
+                .is_some_and(|global| global.linter.suppression_reason.is_required()),
         };
 
         AnalyzerOptions {
@@ -173,6 +187,7 @@ impl ExtensionHandler for CssFileHandler {
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
+                prepare_rename: None,
                 rename: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
@@ -182,7 +197,15 @@ impl ExtensionHandler for CssFileHandler {
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities::default(),
+            symbols: SymbolCapabilities {
+                document_symbols: Some(document_symbols),
+                semantic_tokens: Some(semantic_tokens),
+                inlay_hints: None,
+                signature_help: None,
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(selection_ranges::<CssLanguage>),
+            },
         }
     }
 }
@@ -310,7 +333,7 @@ fn format_on_type(
     Ok(printed)
 }
 
-fn lint(params: LintParams) -> LintResults {
+pub(crate) fn lint(params: LintParams) -> LintResults {
     debug_span!("Linting CSS file", path =? params.path, language =? params.language).in_scope(
         move || {
             let workspace_settings = &params.workspace;
@@ -356,51 +379,57 @@ fn lint(params: LintParams) -> LintResults {
                 .count();
 
             info!("Analyze file {}", params.path.display());
-            let (_, analyze_diagnostics) = analyze(&tree, filter, &analyzer_options, |signal| {
-                if let Some(mut diagnostic) = signal.diagnostic() {
-                    // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
-                    if ignores_suppression_comment
-                        && diagnostic.category() == Some(category!("suppressions/unused"))
-                    {
-                        return ControlFlow::<Never>::Continue(());
-                    }
+            let (_, analyze_diagnostics) = analyze(
+                &tree,
+                filter,
+                &analyzer_options,
+                params.css_project,
+                |signal| {
+                    if let Some(mut diagnostic) = signal.diagnostic() {
+                        // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
+                        if ignores_suppression_comment
+                            && diagnostic.category() == Some(category!("suppressions/unused"))
+                        {
+                            return ControlFlow::<Never>::Continue(());
+                        }
 
-                    diagnostic_count += 1;
-
-                    // We do now check if the severity of the diagnostics should be changed.
-                    // The configuration allows to change the severity of the diagnostics emitted by rules.
-                    let severity = diagnostic
-                        .category()
-                        .filter(|category| category.name().starts_with("lint/"))
-                        .map_or_else(
-                            || diagnostic.severity(),
-                            |category| {
-                                rules
-                                    .as_ref()
-                                    .and_then(|rules| rules.get_severity_from_code(category))
-                                    .unwrap_or(Severity::Warning)
-                            },
-                        );
-
-                    if severity >= Severity::Error {
-                        errors += 1;
-                    }
+                        diagnostic_count += 1;
+
+                        // We do now check if the severity of the diagnostics should be changed.
+                        // The configuration allows to change the severity of the diagnostics emitted by rules.
+                        let severity = diagnostic
+                            .category()
+                            .filter(|category| category.name().starts_with("lint/"))
+                            .map_or_else(
+                                || diagnostic.severity(),
+                                |category| {
+                                    rules
+                                        .as_ref()
+                                        .and_then(|rules| rules.get_severity_from_code(category))
+                                        .unwrap_or(Severity::Warning)
+                                },
+                            );
 
-                    if diagnostic_count <= params.max_diagnostics {
-                        for action in signal.actions() {
-                            if !action.is_suppression() {
-                                diagnostic = diagnostic.add_code_suggestion(action.into());
-                            }
+                        if severity >= Severity::Error {
+                            errors += 1;
                         }
 
-                        let error = diagnostic.with_severity(severity);
+                        if diagnostic_count <= params.max_diagnostics {
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diagnostic = diagnostic.add_code_suggestion(action.into());
+                                }
+                            }
+
+                            let error = diagnostic.with_severity(severity);
 
-                        diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                            diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                        }
                     }
-                }
 
-                ControlFlow::<Never>::Continue(())
-            });
+                    ControlFlow::<Never>::Continue(())
+                },
+            );
 
             diagnostics.extend(
                 analyze_diagnostics
@@ -426,6 +455,142 @@ fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceE
 }
 
 #[tracing::instrument(level = "debug", skip(params))]
+/// Returns the name of the custom property at `offset`, such as `--primary` in `var(--primary)`
+pub(crate) fn custom_property_at(parse: &AnyParse, offset: TextSize) -> Option<String> {
+    parse
+        .syntax::<CssLanguage>()
+        .descendants()
+        .filter_map(CssDashedIdentifier::cast)
+        .find(|identifier| identifier.range().contains_inclusive(offset))
+        .map(|identifier| identifier.text())
+}
+
+fn document_symbols(parse: AnyParse) -> Vec<DocumentSymbol> {
+    let root: CssRoot = parse.tree();
+    let mut symbols = Vec::new();
+    collect_css_symbols(root.syntax(), &mut symbols);
+    symbols
+}
+
+/// Collects the rules of the lists of `node`, along with the rules nested in their blocks.
+///
+/// A rule is named after the text that precedes its block, for example `.button:hover`
+/// or `@media (width > 600px)`.
+fn collect_css_symbols(node: &CssSyntaxNode, symbols: &mut Vec<DocumentSymbol>) {
+    for list in node.children().filter(|child| child.kind().is_list()) {
+        for rule in list.children() {
+            let kind = match rule.kind() {
+                CssSyntaxKind::CSS_QUALIFIED_RULE | CssSyntaxKind::CSS_NESTED_QUALIFIED_RULE => {
+                    SymbolKind::Rule
+                }
+                CssSyntaxKind::CSS_AT_RULE => SymbolKind::AtRule,
+                _ => continue,
+            };
+            let text = rule.text_trimmed().to_string();
+            let header = text.split('{').next().unwrap_or_default().trim_end();
+            if header.is_empty() {
+                continue;
+            }
+
+            let range = rule.text_trimmed_range();
+            let mut children = Vec::new();
+            let block = rule.descendants().find(|descendant| {
+                matches!(
+                    descendant.kind(),
+                    CssSyntaxKind::CSS_RULE_BLOCK
+                        | CssSyntaxKind::CSS_DECLARATION_OR_RULE_BLOCK
+                        | CssSyntaxKind::CSS_DECLARATION_OR_AT_RULE_BLOCK
+                )
+            });
+            if let Some(block) = block {
+                collect_css_symbols(&block, &mut children);
+            }
+            symbols.push(DocumentSymbol {
+                name: header.split_whitespace().collect::<Vec<_>>().join(" "),
+                kind,
+                range,
+                selection_range: TextRange::at(range.start(), TextSize::of(header)),
+                children,
+            });
+        }
+    }
+}
+
+/// Classifies the custom properties, declared when they are the name of a declaration or of
+/// an `@property` at-rule, and the class selectors
+fn semantic_tokens(parse: AnyParse) -> Vec<SemanticToken> {
+    let root: CssRoot = parse.tree();
+    let model = semantic_model(&root);
+    let declared_properties: Vec<_> = model
+        .rules()
+        .iter()
+        .flat_map(|rule| &rule.declarations)
+        .map(|declaration| declaration.property.range)
+        .chain(
+            model
+                .global_custom_variables()
+                .values()
+                .map(|variable| match variable {
+                    CssGlobalCustomVariable::Root(declaration) => declaration.property.range,
+                    CssGlobalCustomVariable::AtProperty { property, .. } => property.range,
+                }),
+        )
+        .collect();
+
+    root.syntax()
+        .descendants()
+        .filter_map(|node| {
+            if let Some(identifier) = CssDashedIdentifier::cast_ref(&node) {
+                let range = identifier.range();
+                let modifiers = if declared_properties
+                    .iter()
+                    .any(|declared| declared.contains_range(range))
+                {
+                    vec![SemanticTokenModifier::Declaration]
+                } else {
+                    Vec::new()
+                };
+                return Some(SemanticToken {
+                    range,
+                    kind: SemanticTokenKind::Variable,
+                    modifiers,
+                });
+            }
+            let selector = CssClassSelector::cast(node)?;
+            Some(SemanticToken {
+                range: selector.name().ok()?.range(),
+                kind: SemanticTokenKind::Class,
+                modifiers: Vec::new(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the folding ranges of the blocks of the rules and of the at-rules, and of the comments
+fn folding_ranges(parse: AnyParse) -> Vec<FoldingRange> {
+    let root = parse.syntax::<CssLanguage>();
+    let mut ranges: Vec<_> = root
+        .descendants()
+        .filter(|node| {
+            matches!(
+                node.kind(),
+                CssSyntaxKind::CSS_DECLARATION_BLOCK
+                    | CssSyntaxKind::CSS_RULE_BLOCK
+                    | CssSyntaxKind::CSS_DECLARATION_OR_AT_RULE_BLOCK
+                    | CssSyntaxKind::CSS_DECLARATION_OR_RULE_BLOCK
+                    | CssSyntaxKind::CSS_FONT_FEATURE_VALUES_BLOCK
+                    | CssSyntaxKind::CSS_KEYFRAMES_BLOCK
+                    | CssSyntaxKind::CSS_PAGE_AT_RULE_BLOCK
+            )
+        })
+        .filter_map(|node| block_folding_range(&node))
+        .collect();
+
+    push_comment_folding_ranges(&root, &mut ranges);
+    ranges.sort_by_key(|folding_range| folding_range.range.start());
+    ranges
+}
+
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
     let CodeActionsParams {
         parse,
@@ -433,10 +598,13 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest: _,
+        css_project,
+        js_project: _,
         language,
         only,
         skip,
         suppression_reason,
+        ..
     } = params;
     debug_span!("Code actions CSS", range =? range, path =? path).in_scope(move || {
         let tree = parse.tree();
@@ -471,7 +639,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
 
             info!("CSS runs the analyzer");
 
-            analyze(&tree, filter, &analyzer_options, |signal| {
+            analyze(&tree, filter, &analyzer_options, css_project, |signal| {
                 actions.extend(signal.actions().into_code_action_iter().map(|item| {
                     CodeAction {
                         category: item.category.clone(),
@@ -514,6 +682,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         categories: RuleCategoriesBuilder::default()
             .with_syntax()
             .with_lint()
+            .with_action()
             .build(),
         enabled_rules: Some(enabled_rules.as_slice()),
         disabled_rules: &disabled_rules,
@@ -521,6 +690,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     };
 
     let mut actions = Vec::new();
+    let mut fix_cycle_guard = FixCycleGuard::new(tree.syntax());
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
     let analyzer_options = params.workspace.analyzer_options::<CssLanguage>(
@@ -529,7 +699,8 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         params.suppression_reason,
     );
     loop {
-        let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
+        let project = params.css_project.clone();
+        let (action, _) = analyze(&tree, filter, &analyzer_options, project, |signal| {
             let current_diagnostic = signal.diagnostic();
 
             if let Some(diagnostic) = current_diagnostic.as_ref() {
@@ -595,6 +766,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                             .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
                         range,
                     });
+                    fix_cycle_guard.record(tree.syntax(), &actions)?;
                 }
             }
             None => {
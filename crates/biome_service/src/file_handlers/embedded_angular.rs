@@ -0,0 +1,716 @@
+use crate::file_handlers::{html, javascript, LintParams, LintResults};
+use crate::workspace::DocumentFileSource;
+use biome_diagnostics::{Diagnostic, Severity};
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsClass, AnyJsClassMember, AnyJsDecorator, AnyJsExpression,
+    AnyJsLiteralExpression, AnyJsObjectMember, ClassMemberName, JsDecorator, JsExport,
+    JsFileSource, JsSyntaxNode,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, TextRange, TextSize};
+use regex::Regex;
+use rustc_hash::FxHashMap;
+use std::collections::BTreeSet;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// The attributes of the elements whose value is an expression: the property bindings `[value]`,
+/// the two-way bindings `[(value)]`, the event bindings `(click)` and the structural directives `*ngIf`.
+static BINDING_ATTRIBUTE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"\s(?<name>\[\(?[^\s="'<>/\[\]()]+\)?\]|\([^\s="'<>/()]+\)|\*[A-Za-z][\w-]*|(?:bind|on|bindon)-[^\s="'<>/]+)\s*=\s*(?:"(?<double>[^"]*)"|'(?<single>[^']*)')"#,
+    )
+    .unwrap()
+});
+
+/// The text interpolations, such as `{{ user.name }}`
+static INTERPOLATION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)\{\{(?<expression>.*?)\}\}").unwrap());
+
+/// The blocks of the built-in control flow, such as `@if (user.isLoggedIn) {`
+static CONTROL_FLOW_BLOCK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"@(?:if|else\s+if|switch|case|for)\s*\((?<expression>[^()]*(?:\([^()]*\)[^()]*)*)\)",
+    )
+    .unwrap()
+});
+
+/// The key that precedes an expression of the microsyntax of the structural directives and
+/// of the control flow blocks, such as `let item of` or `trackBy:`
+static MICROSYNTAX_KEY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(?:(?:let\s+)?[A-Za-z_$][\w$]*\s+of\s+|[A-Za-z]\w*\s*:\s*|(?:else|then|track|trackBy)\s+)",
+    )
+    .unwrap()
+});
+
+/// The alias that follows an expression of the microsyntax, such as `as user`
+static MICROSYNTAX_ALIAS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\s+as\s+[A-Za-z_$][\w$]*\s*$").unwrap());
+
+/// The variables declared by a template: the template references `#input` and `ref-input`,
+/// the template input variables `let-item` and `let item`, the aliases `as user`,
+/// and the items of the `@for` blocks.
+static TEMPLATE_VARIABLE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:\s#|\sref-|\slet-|\blet\s+|\bas\s+|@for\s*\(\s*)(?<name>[A-Za-z_$][\w$]*)")
+        .unwrap()
+});
+
+static IDENTIFIER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[A-Za-z_$][\w$]*$").unwrap());
+
+/// The variables that every template can refer to
+const TEMPLATE_GLOBALS: [&str; 9] = [
+    "$any",
+    "$count",
+    "$event",
+    "$even",
+    "$first",
+    "$implicit",
+    "$index",
+    "$last",
+    "$odd",
+];
+
+/// The reserved words that can name a class member, but not a variable
+const RESERVED_WORDS: [&str; 36] = [
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "let",
+    "new",
+    "null",
+    "return",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+];
+
+/// The rules of the `correctness` group that don't apply to the expressions of a template
+const EXCLUDED_RULES: [&str; 4] = [
+    "lint/correctness/noUnusedFunctionParameters",
+    "lint/correctness/noUnusedImports",
+    "lint/correctness/noUnusedPrivateClassMembers",
+    "lint/correctness/noUnusedVariables",
+];
+
+/// An Angular component, declared by a class decorated with `@Component`.
+///
+/// ```ts
+/// @Component({
+///   selector: "app-user",
+///   template: `<p *ngIf="user">{{ user.name }}</p>`,
+/// })
+/// export class UserComponent {
+///   user?: User;
+/// }
+/// ```
+///
+/// The template of a component is either inline, in its `template` property,
+/// or in the file referred to by its `templateUrl` property.
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct AngularComponent {
+    template: Option<TextRange>,
+    template_url: Option<String>,
+    members: Option<Vec<String>>,
+}
+
+impl AngularComponent {
+    /// It extracts the components declared in the script `root`, in source order.
+    pub fn extract(root: &JsSyntaxNode) -> Vec<Self> {
+        root.descendants()
+            .filter_map(JsDecorator::cast)
+            .filter_map(|decorator| Self::from_decorator(&decorator))
+            .collect()
+    }
+
+    fn from_decorator(decorator: &JsDecorator) -> Option<Self> {
+        let AnyJsDecorator::JsCallExpression(call) = decorator.expression().ok()? else {
+            return None;
+        };
+        let AnyJsExpression::JsIdentifierExpression(callee) = call.callee().ok()? else {
+            return None;
+        };
+        if callee.name().ok()?.value_token().ok()?.text_trimmed() != "Component" {
+            return None;
+        }
+        let Some(Ok(AnyJsCallArgument::AnyJsExpression(AnyJsExpression::JsObjectExpression(
+            metadata,
+        )))) = call.arguments().ok()?.args().first()
+        else {
+            return None;
+        };
+
+        let mut template = None;
+        let mut template_url = None;
+        for member in metadata.members().iter().flatten() {
+            let AnyJsObjectMember::JsPropertyObjectMember(property) = member else {
+                continue;
+            };
+            let (Some(name), Ok(value)) = (
+                property.name().ok().and_then(|name| name.name()),
+                property.value(),
+            ) else {
+                continue;
+            };
+            match name.text() {
+                "template" => template = string_content(&value),
+                "templateUrl" => {
+                    template_url = value
+                        .as_any_js_literal_expression()
+                        .and_then(|literal| literal.as_js_string_literal_expression())
+                        .and_then(|string| string.inner_string_text().ok())
+                        .map(|url| url.text().to_string());
+                }
+                _ => {}
+            }
+        }
+
+        // The decorators of an exported class can precede the `export` keyword
+        let owner = decorator.syntax().grandparent()?;
+        let class = match AnyJsClass::cast_ref(&owner) {
+            Some(class) => class,
+            None => JsExport::cast(owner)?
+                .syntax()
+                .descendants()
+                .find_map(AnyJsClass::cast)?,
+        };
+
+        Some(Self {
+            template,
+            template_url,
+            members: class_members(&class),
+        })
+    }
+
+    /// Returns the range of the inline template in the script, without its quotes
+    pub fn template(&self) -> Option<TextRange> {
+        self.template
+    }
+
+    /// Returns the value of the `templateUrl` property, relative to the script
+    pub fn template_url(&self) -> Option<&str> {
+        self.template_url.as_deref()
+    }
+
+    /// Returns the names of the members of the component, which its template can refer to,
+    /// or `None` if the component extends another class, whose members are unknown.
+    pub fn members(&self) -> Option<&[String]> {
+        self.members.as_deref()
+    }
+}
+
+/// Returns the range of the content of a string literal or of a template literal without substitutions
+fn string_content(value: &AnyJsExpression) -> Option<TextRange> {
+    match value {
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsStringLiteralExpression(string),
+        ) => {
+            let range = string.value_token().ok()?.text_trimmed_range();
+            let quote = TextSize::from(1);
+            Some(TextRange::new(range.start() + quote, range.end() - quote))
+        }
+        AnyJsExpression::JsTemplateExpression(template) if template.is_constant() => {
+            Some(TextRange::new(
+                template.l_tick_token().ok()?.text_trimmed_range().end(),
+                template.r_tick_token().ok()?.text_trimmed_range().start(),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the names of the public members of `class`, including the parameter properties
+/// of its constructor, or `None` if the class extends another class.
+fn class_members(class: &AnyJsClass) -> Option<Vec<String>> {
+    if class.extends_clause().is_some() {
+        return None;
+    }
+
+    let mut members = Vec::new();
+    for member in class.members().iter() {
+        if let AnyJsClassMember::JsConstructorClassMember(constructor) = &member {
+            let parameters = constructor
+                .parameters()
+                .ok()
+                .into_iter()
+                .flat_map(|parameters| parameters.parameters().iter())
+                .flatten();
+            for parameter in parameters {
+                let name = parameter
+                    .as_ts_property_parameter()
+                    .and_then(|parameter| parameter.formal_parameter().ok())
+                    .and_then(|parameter| parameter.as_js_formal_parameter()?.binding().ok())
+                    .and_then(|binding| {
+                        binding
+                            .as_any_js_binding()?
+                            .as_js_identifier_binding()?
+                            .name_token()
+                            .ok()
+                    });
+                if let Some(name) = name {
+                    members.push(name.text_trimmed().to_string());
+                }
+            }
+            continue;
+        }
+        if let Some(ClassMemberName::Public(name)) =
+            member.name().ok().flatten().and_then(|name| name.name())
+        {
+            members.push(name.text().to_string());
+        }
+    }
+
+    Some(members)
+}
+
+/// Stores the Angular components of the project whose template is in its own file,
+/// indexed by the path of the script that declares them.
+#[derive(Debug, Clone, Default)]
+pub struct AngularProjectIndex {
+    components: FxHashMap<PathBuf, Vec<AngularComponent>>,
+}
+
+impl AngularProjectIndex {
+    pub fn get_file(&self, path: &Path) -> Option<&[AngularComponent]> {
+        self.components.get(path).map(Vec::as_slice)
+    }
+
+    pub fn insert_file(&mut self, path: PathBuf, components: Vec<AngularComponent>) {
+        self.components.insert(path, components);
+    }
+
+    pub fn remove_file(&mut self, path: &Path) {
+        self.components.remove(path);
+    }
+
+    /// Returns the component whose `templateUrl` refers to the template at `path`
+    pub fn find_component(&self, path: &Path) -> Option<&AngularComponent> {
+        self.components.iter().find_map(|(script, components)| {
+            let directory = script.parent()?;
+            components.iter().find(|component| {
+                component
+                    .template_url()
+                    .is_some_and(|url| directory.join(url) == path)
+            })
+        })
+    }
+}
+
+/// A template and the names of the members of its component, `None` if they're unknown
+struct AngularTemplate<'a> {
+    range: Range<usize>,
+    members: Option<&'a [String]>,
+}
+
+/// It lints the expressions of the inline templates of the components declared in the script `root`.
+///
+/// The ranges of the diagnostics are the ones of the script.
+pub(crate) fn lint(root: &JsSyntaxNode, params: &LintParams) -> LintResults {
+    let components = AngularComponent::extract(root);
+    let templates: Vec<_> = components
+        .iter()
+        .filter_map(|component| {
+            Some(AngularTemplate {
+                range: component.template()?.into(),
+                members: component.members(),
+            })
+        })
+        .collect();
+
+    lint_templates(&root.to_string(), &templates, params)
+}
+
+/// It lints the expressions of the template at `params.path`, whose content is `text`, if the
+/// document is the template of a component: either its path is the `templateUrl` of a component
+/// of the project, or its name ends with `.component.html`.
+pub(crate) fn lint_template_file(text: &str, params: &LintParams) -> Option<LintResults> {
+    let component = params.angular_project.find_component(params.path);
+    let is_component_template = params
+        .path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.ends_with(".component.html"));
+    if component.is_none() && !is_component_template {
+        return None;
+    }
+
+    let template = AngularTemplate {
+        range: 0..text.len(),
+        members: component.and_then(AngularComponent::members),
+    };
+    Some(lint_templates(text, &[template], params))
+}
+
+/// Lints the expressions of the `templates` of the document `text` with the `correctness` rules
+/// of JavaScript.
+///
+/// The expressions are linted as the statements of a TypeScript program, which has the same length
+/// and the same line breaks as the document, like the Svelte templates. The program declares the
+/// members of the components and the template variables as `let` variables, because the event
+/// bindings can assign them, so that `noUndeclaredVariables` reports
+/// the names that the templates can't refer to. When the members of a component are unknown,
+/// because it extends another class, this rule isn't reported for its template.
+fn lint_templates(text: &str, templates: &[AngularTemplate], params: &LintParams) -> LintResults {
+    if templates.is_empty() {
+        return LintResults {
+            diagnostics: Vec::new(),
+            errors: 0,
+            skipped_diagnostics: 0,
+        };
+    }
+
+    let file_source = JsFileSource::ts();
+    let mut program = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '\n' | '\r' => program.push(character),
+            _ => program.push_str(&" ".repeat(character.len_utf8())),
+        }
+    }
+
+    let mut names: BTreeSet<&str> = TEMPLATE_GLOBALS.into_iter().collect();
+    let mut expressions = Vec::new();
+    for template in templates {
+        let source = &text[template.range.clone()];
+        expressions.extend(template_expressions(source).into_iter().map(|expression| {
+            expression.start + template.range.start..expression.end + template.range.start
+        }));
+        names.extend(template.members.into_iter().flatten().map(String::as_str));
+        names.extend(
+            TEMPLATE_VARIABLE
+                .captures_iter(source)
+                .filter_map(|captures| Some(captures.name("name")?.as_str())),
+        );
+    }
+    expressions.sort_by_key(|expression| expression.start);
+
+    let mut last_end = 0;
+    for expression in expressions {
+        // Each expression becomes a statement `;(expression)`, which needs two characters before
+        // the expression and one character after it
+        if expression.is_empty() || expression.start < last_end + 2 || expression.end >= text.len()
+        {
+            continue;
+        }
+        let source = &text[expression.clone()];
+        // The statements of an event binding become a sequence expression
+        let mut statement = source.to_string();
+        for position in top_level_separators(source, b';') {
+            statement.replace_range(position..position + 1, ",");
+        }
+        let statement_parse = parse(
+            &format!("({statement})"),
+            file_source,
+            JsParserOptions::default(),
+        );
+        if statement_parse.has_errors() {
+            continue;
+        }
+
+        program.replace_range(expression.start - 2..expression.start, ";(");
+        program.replace_range(expression.clone(), &statement);
+        program.replace_range(expression.end..expression.end + 1, ")");
+        last_end = expression.end + 1;
+    }
+
+    program.push('\n');
+    for name in names {
+        if IDENTIFIER.is_match(name) && !RESERVED_WORDS.contains(&name) {
+            program.push_str(&format!("declare let {name}: any;\n"));
+        }
+    }
+
+    let mut results = javascript::lint(LintParams {
+        parse: parse(&program, file_source, JsParserOptions::default()).into(),
+        workspace: params.workspace,
+        language: DocumentFileSource::Js(file_source),
+        max_diagnostics: params.max_diagnostics,
+        path: params.path,
+        only: params.only.clone(),
+        skip: params.skip.clone(),
+        categories: params.categories,
+        manifest: None,
+        #[cfg(feature = "css")]
+        css_project: params.css_project.clone(),
+        js_project: params.js_project.clone(),
+        json_schemas: params.json_schemas.clone(),
+        #[cfg(feature = "graphql")]
+        graphql_schema: params.graphql_schema.clone(),
+        #[cfg(feature = "graphql")]
+        graphql_project: params.graphql_project.clone(),
+        angular_project: params.angular_project.clone(),
+        suppression_reason: params.suppression_reason.clone(),
+    });
+
+    let document_range = TextRange::up_to(TextSize::from(text.len() as u32));
+    let unknown_scopes: Vec<TextRange> = templates
+        .iter()
+        .filter(|template| template.members.is_none())
+        .map(|template| {
+            TextRange::new(
+                TextSize::from(template.range.start as u32),
+                TextSize::from(template.range.end as u32),
+            )
+        })
+        .collect();
+    results.diagnostics.retain(|diagnostic| {
+        let (Some(category), Some(span)) = (diagnostic.category(), diagnostic.location().span)
+        else {
+            return false;
+        };
+        let name = category.name();
+        name.starts_with("lint/correctness/")
+            && !EXCLUDED_RULES.contains(&name)
+            && document_range.contains_range(span)
+            && !(name == "lint/correctness/noUndeclaredVariables"
+                && unknown_scopes
+                    .iter()
+                    .any(|scope| scope.contains_range(span)))
+    });
+    results.errors = results
+        .diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity() >= Severity::Error)
+        .count();
+
+    results
+}
+
+/// Returns the ranges of the expressions of the template `source`, in source order.
+///
+/// The expressions are the values of the bindings, the text interpolations and the conditions of the
+/// control flow blocks. The pipes that follow an expression, such as `| async`, aren't part of it.
+fn template_expressions(source: &str) -> Vec<Range<usize>> {
+    let mut expressions = Vec::new();
+    for captures in BINDING_ATTRIBUTE.captures_iter(source) {
+        let (Some(name), Some(value)) = (
+            captures.name("name"),
+            captures.name("double").or_else(|| captures.name("single")),
+        ) else {
+            continue;
+        };
+        let name = name.as_str();
+        let value = value.range();
+        if name.starts_with('*') {
+            expressions.extend(microsyntax_expressions(source, value));
+        } else if name.starts_with('(') || name.starts_with("on-") {
+            expressions.push(event_expression(source, value));
+        } else {
+            expressions.push(pipe_input(source, value));
+        }
+    }
+    for captures in INTERPOLATION.captures_iter(source) {
+        if let Some(expression) = captures.name("expression") {
+            expressions.push(pipe_input(source, expression.range()));
+        }
+    }
+    for captures in CONTROL_FLOW_BLOCK.captures_iter(source) {
+        if let Some(expression) = captures.name("expression") {
+            expressions.extend(microsyntax_expressions(source, expression.range()));
+        }
+    }
+
+    let raw_text_blocks: Vec<_> = html::RAW_TEXT_BLOCK
+        .find_iter(source)
+        .map(|block| block.range())
+        .collect();
+    expressions.retain(|expression| {
+        !expression.is_empty()
+            && !raw_text_blocks
+                .iter()
+                .any(|block| block.contains(&expression.start))
+    });
+    expressions.sort_by_key(|expression| expression.start);
+    expressions
+}
+
+/// Returns the expressions of the microsyntax `source[value]` of a structural directive or
+/// of a control flow block, such as `let user of users; trackBy: trackById`: the first expression,
+/// and the expressions that follow a key.
+fn microsyntax_expressions(source: &str, value: Range<usize>) -> Vec<Range<usize>> {
+    let mut expressions = Vec::new();
+    for (index, segment) in split_top_level(&source[value.clone()], b';').enumerate() {
+        let segment = trim(
+            source,
+            segment.start + value.start..segment.end + value.start,
+        );
+        let (start, is_keyed) = match MICROSYNTAX_KEY.find(&source[segment.clone()]) {
+            Some(key) => (segment.start + key.end(), true),
+            None => (segment.start, false),
+        };
+        // The other segments declare variables, such as `let i = index` or `index as i`
+        if index > 0 && !is_keyed {
+            continue;
+        }
+        let end = MICROSYNTAX_ALIAS
+            .find(&source[start..segment.end])
+            .map_or(segment.end, |alias| start + alias.start());
+        expressions.push(pipe_input(source, start..end));
+    }
+    expressions
+}
+
+/// Returns the statements of the event binding `source[value]`, without their trailing semicolon
+fn event_expression(source: &str, value: Range<usize>) -> Range<usize> {
+    let mut expression = trim(source, value);
+    while source[expression.clone()].ends_with(';') {
+        expression = trim(source, expression.start..expression.end - 1);
+    }
+    expression
+}
+
+/// Returns the expression of `source[value]` that precedes its pipes
+fn pipe_input(source: &str, value: Range<usize>) -> Range<usize> {
+    let input = split_top_level(&source[value.clone()], b'|')
+        .next()
+        .unwrap_or(0..0);
+    trim(source, input.start + value.start..input.end + value.start)
+}
+
+fn trim(source: &str, range: Range<usize>) -> Range<usize> {
+    let text = &source[range.clone()];
+    let start = range.start + (text.len() - text.trim_start().len());
+    let end = range.end - (text.len() - text.trim_end().len());
+    start..end.max(start)
+}
+
+/// Splits `text` at its top-level `separator` characters
+fn split_top_level(text: &str, separator: u8) -> impl Iterator<Item = Range<usize>> {
+    let mut start = 0;
+    top_level_separators(text, separator)
+        .into_iter()
+        .chain(std::iter::once(text.len()))
+        .map(move |end| {
+            let range = start..end;
+            start = end + 1;
+            range
+        })
+}
+
+/// Returns the positions of the `separator` characters of `text` that aren't nested in brackets
+/// or in strings. The `|` characters of the `||` operator aren't separators.
+fn top_level_separators(text: &str, separator: u8) -> Vec<usize> {
+    let bytes = text.as_bytes();
+    let mut positions = Vec::new();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut index = 0;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        match quote {
+            Some(_) if byte == b'\\' => index += 1,
+            Some(delimiter) if byte == delimiter => quote = None,
+            Some(_) => {}
+            None => match byte {
+                b'"' | b'\'' | b'`' => quote = Some(byte),
+                b'(' | b'[' | b'{' => depth += 1,
+                b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+                b'|' if bytes.get(index + 1) == Some(&b'|') => index += 1,
+                _ if byte == separator && depth == 0 => positions.push(index),
+                _ => {}
+            },
+        }
+        index += 1;
+    }
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expressions(source: &str) -> Vec<&str> {
+        template_expressions(source)
+            .into_iter()
+            .map(|expression| &source[expression])
+            .collect()
+    }
+
+    #[test]
+    fn extracts_template_expressions() {
+        let source = r#"<input [value]="name | uppercase" (input)="name = $event.target.value; save();">
+<li *ngFor="let user of users; index as i; trackBy: trackById">{{ user?.name || 'Anonymous' }}</li>
+@if (users.length > 0; as count) {
+  <p>{{ count }}</p>
+}
+<style>p { color: red; }</style>"#;
+
+        assert_eq!(
+            expressions(source),
+            vec![
+                "name",
+                "name = $event.target.value; save()",
+                "users",
+                "trackById",
+                "user?.name || 'Anonymous'",
+                "users.length > 0",
+                "count",
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_components() {
+        let text = r#"@Component({
+  selector: "app-users",
+  template: `<p>{{ title }}</p>`,
+})
+export class UsersComponent {
+  title = "Users";
+  constructor(private service: UserService) {}
+  load() {}
+}
+
+@Component({ templateUrl: "./user.component.html" })
+class UserComponent extends BaseComponent {}
+"#;
+        let parse = parse(text, JsFileSource::ts(), JsParserOptions::default());
+
+        let components = AngularComponent::extract(&parse.syntax());
+        assert_eq!(components.len(), 2);
+        assert_eq!(
+            &text[std::ops::Range::<usize>::from(components[0].template().unwrap())],
+            "<p>{{ title }}</p>"
+        );
+        assert_eq!(
+            components[0].members(),
+            Some(
+                [
+                    "title".to_string(),
+                    "service".to_string(),
+                    "load".to_string()
+                ]
+                .as_slice()
+            )
+        );
+        assert_eq!(components[1].template_url(), Some("./user.component.html"));
+        assert_eq!(components[1].members(), None);
+    }
+}
@@ -0,0 +1,341 @@
+use crate::file_handlers::{css, html, LintParams, LintResults};
+use crate::workspace::DocumentFileSource;
+use crate::WorkspaceError;
+use biome_css_formatter::context::CssFormatOptions;
+use biome_css_formatter::format_node;
+use biome_css_parser::{parse_css, CssParse, CssParserOptions};
+use biome_css_syntax::CssFileSource;
+use biome_rowan::{TextRange, TextSize};
+use regex::Regex;
+use std::borrow::Cow;
+use std::sync::LazyLock;
+
+pub static STYLE_FENCE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?ixs)(?<opening><style(?:\s[^>]*)?>)\r?\n?(?<style>(?U:.*))</style>"#).unwrap()
+});
+
+static STYLE_ATTRIBUTE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?is)<[a-z][^\s/>]*(?:\s[^>]*?)?\sstyle\s*=\s*(?:"(?<double>[^"]*)"|'(?<single>[^']*)')"#,
+    )
+    .unwrap()
+});
+
+static SCRIPT_BLOCK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?is)<script(?:\s[^>]*)?>.*?</script>"#).unwrap());
+
+static LANG_ATTRIBUTE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)\slang\s*=\s*(?:"(?<double>[^"]*)"|'(?<single>[^']*)'|(?<bare>[^\s>]+))"#)
+        .unwrap()
+});
+
+/// The style attributes only contain declarations, so they are wrapped in a rule
+/// to be parsed as a stylesheet.
+const STYLE_ATTRIBUTE_PREFIX: &str = "*{";
+const STYLE_ATTRIBUTE_SUFFIX: &str = "}";
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum EmbeddedCssKind {
+    /// The content of a `<style>` element
+    StyleElement,
+    /// The value of a `style` attribute, which is a list of declarations
+    StyleAttribute,
+}
+
+/// A snippet of CSS embedded in an HTML, Vue or Svelte document.
+///
+/// ```html
+/// <p style="color: red">Hello</p>
+/// <style>
+/// p { margin: 0; }
+/// </style>
+/// ```
+///
+/// The snippets are `color: red` and `p { margin: 0; }`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EmbeddedCss<'a> {
+    kind: EmbeddedCssKind,
+    content: &'a str,
+    range: TextRange,
+}
+
+impl<'a> EmbeddedCss<'a> {
+    /// It extracts the CSS contained in the `<style>` elements and in the `style` attributes of a document,
+    /// in source order.
+    ///
+    /// The `<style>` elements with a `lang` attribute other than `css`, such as `scss`, are skipped.
+    /// The attributes of the elements contained in `<script>` blocks are skipped too.
+    pub fn extract(text: &'a str) -> Vec<Self> {
+        let scripts: Vec<_> = SCRIPT_BLOCK
+            .find_iter(text)
+            .map(|script| script.range())
+            .collect();
+        let is_in_script = |offset: usize| scripts.iter().any(|script| script.contains(&offset));
+
+        let mut snippets: Vec<_> = STYLE_FENCE
+            .captures_iter(text)
+            .filter(|captures| {
+                captures
+                    .name("opening")
+                    .is_some_and(|opening| is_css_style_opening_tag(opening.as_str()))
+            })
+            .filter_map(|captures| captures.name("style"))
+            .filter(|style| !is_in_script(style.start()))
+            .map(|style| Self::new(EmbeddedCssKind::StyleElement, text, style.range()))
+            .collect();
+
+        let styles: Vec<_> = snippets.iter().map(|snippet| snippet.range).collect();
+        snippets.extend(
+            STYLE_ATTRIBUTE
+                .captures_iter(text)
+                .filter_map(|captures| captures.name("double").or_else(|| captures.name("single")))
+                .filter(|value| !is_in_script(value.start()))
+                .filter(|value| {
+                    let offset = TextSize::from(value.start() as u32);
+                    !styles.iter().any(|style| style.contains(offset))
+                })
+                .map(|value| Self::new(EmbeddedCssKind::StyleAttribute, text, value.range())),
+        );
+        snippets.sort_by_key(|snippet| snippet.range.start());
+        snippets
+    }
+
+    fn new(kind: EmbeddedCssKind, text: &'a str, range: std::ops::Range<usize>) -> Self {
+        Self {
+            kind,
+            content: &text[range.clone()],
+            range: TextRange::new(
+                TextSize::from(range.start as u32),
+                TextSize::from(range.end as u32),
+            ),
+        }
+    }
+
+    pub fn kind(&self) -> EmbeddedCssKind {
+        self.kind
+    }
+
+    /// Returns the CSS, as written in the document
+    pub fn content(&self) -> &'a str {
+        self.content
+    }
+
+    /// Returns the range of the CSS in the document
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// Returns the stylesheet that is parsed for this snippet.
+    ///
+    /// The declarations of a `style` attribute are wrapped in a rule.
+    pub fn source(&self) -> Cow<'a, str> {
+        match self.kind {
+            EmbeddedCssKind::StyleElement => Cow::Borrowed(self.content),
+            EmbeddedCssKind::StyleAttribute => Cow::Owned(format!(
+                "{STYLE_ATTRIBUTE_PREFIX}{}{STYLE_ATTRIBUTE_SUFFIX}",
+                self.content
+            )),
+        }
+    }
+
+    /// Returns the offset to add to the ranges of the parsed snippet
+    /// to map them to the document.
+    pub fn offset(&self) -> TextSize {
+        match self.kind {
+            EmbeddedCssKind::StyleElement => self.range.start(),
+            EmbeddedCssKind::StyleAttribute => {
+                self.range.start() - TextSize::of(STYLE_ATTRIBUTE_PREFIX)
+            }
+        }
+    }
+
+    pub fn parse(&self) -> CssParse {
+        parse_css(&self.source(), CssParserOptions::default())
+    }
+}
+
+/// Returns `true` if the opening tag of a `<style>` element doesn't have a `lang` attribute,
+/// or if its `lang` attribute is `css`.
+fn is_css_style_opening_tag(opening_tag: &str) -> bool {
+    LANG_ATTRIBUTE
+        .captures(opening_tag)
+        .and_then(|captures| {
+            captures
+                .name("double")
+                .or_else(|| captures.name("single"))
+                .or_else(|| captures.name("bare"))
+        })
+        .map_or(true, |lang| lang.as_str().eq_ignore_ascii_case("css"))
+}
+
+/// It lints the CSS embedded in the document `text` with the CSS rules.
+///
+/// The ranges of the diagnostics are mapped to the document.
+pub(crate) fn lint(text: &str, params: LintParams) -> LintResults {
+    let mut results = LintResults {
+        diagnostics: Vec::new(),
+        errors: 0,
+        skipped_diagnostics: 0,
+    };
+
+    for snippet in EmbeddedCss::extract(text) {
+        let max_diagnostics = params
+            .max_diagnostics
+            .saturating_sub(results.diagnostics.len() as u32);
+        let snippet_results = css::lint(LintParams {
+            parse: snippet.parse().into(),
+            workspace: params.workspace,
+            language: DocumentFileSource::Css(CssFileSource::css()),
+            max_diagnostics,
+            path: params.path,
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            categories: params.categories,
+            manifest: None,
+            css_project: params.css_project.clone(),
+            js_project: params.js_project.clone(),
+            json_schemas: params.json_schemas.clone(),
+            #[cfg(feature = "graphql")]
+            graphql_schema: None,
+            #[cfg(feature = "graphql")]
+            graphql_project: params.graphql_project.clone(),
+            angular_project: params.angular_project.clone(),
+            suppression_reason: params.suppression_reason.clone(),
+        });
+
+        results.errors += snippet_results.errors;
+        results.skipped_diagnostics += snippet_results.skipped_diagnostics;
+        results.diagnostics.extend(
+            snippet_results
+                .diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_offset(snippet.offset())),
+        );
+    }
+
+    results
+}
+
+/// It formats the CSS of the `<style>` elements of the document `text`,
+/// and returns the document with the formatted CSS.
+///
+/// When there's an `indent_unit`, the CSS is indented by it from the indentation of the
+/// elements, like in the HTML documents. Otherwise, it starts at the beginning of the lines,
+/// like in the Vue, Svelte and Astro components.
+///
+/// The `style` attributes are left as they are, because the formatter would
+/// break their declarations over several lines. The snippets that contain
+/// syntax errors are left as they are too.
+pub(crate) fn format(
+    text: &str,
+    options: CssFormatOptions,
+    indent_unit: Option<&str>,
+) -> Result<String, WorkspaceError> {
+    let mut output = text.to_string();
+
+    for snippet in EmbeddedCss::extract(text).iter().rev() {
+        if snippet.kind() != EmbeddedCssKind::StyleElement || snippet.content().trim().is_empty() {
+            continue;
+        }
+        let parse = snippet.parse();
+        if parse.has_errors() {
+            continue;
+        }
+
+        let formatted = format_node(options.clone(), &parse.syntax())?;
+        let printed = formatted
+            .print()
+            .map_err(|error| WorkspaceError::FormatError(error.into()))?;
+        let range = std::ops::Range::<usize>::from(snippet.range());
+        match indent_unit {
+            Some(indent_unit) => {
+                // The line break that follows the opening tag is replaced too
+                let before = &text[..range.start];
+                let start = before
+                    .strip_suffix('\n')
+                    .map(|before| before.strip_suffix('\r').unwrap_or(before))
+                    .map_or(range.start, str::len);
+                let code = html::indent_embedded_code(text, start, printed.as_code(), indent_unit);
+                output.replace_range(start..range.end, &code);
+            }
+            None => output.replace_range(range, printed.as_code()),
+        }
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_style_elements_and_attributes() {
+        let text = r#"<div style="color: red">
+<style>
+a { color: blue; }
+</style>
+<style lang="scss">
+$a: red;
+</style>
+<script>
+const a = '<b style="color: green">';
+</script>
+<p style='margin: 0'></p>
+</div>"#;
+
+        let snippets = EmbeddedCss::extract(text);
+        let snippets: Vec<_> = snippets
+            .iter()
+            .map(|snippet| (snippet.kind(), snippet.content()))
+            .collect();
+        assert_eq!(
+            snippets,
+            vec![
+                (EmbeddedCssKind::StyleAttribute, "color: red"),
+                (EmbeddedCssKind::StyleElement, "a { color: blue; }\n"),
+                (EmbeddedCssKind::StyleAttribute, "margin: 0"),
+            ]
+        );
+    }
+
+    #[test]
+    fn maps_offsets_to_the_document() {
+        let text = r#"<p style="color: red"></p>"#;
+        let snippet = &EmbeddedCss::extract(text)[0];
+
+        assert_eq!(snippet.source(), "*{color: red}");
+        let color = TextSize::from(snippet.source().find("color").unwrap() as u32);
+        assert_eq!(
+            usize::from(color + snippet.offset()),
+            text.find("color").unwrap()
+        );
+    }
+
+    #[test]
+    fn formats_style_elements() {
+        let text = r#"<p style="color:red"></p>
+<style>
+a{color:blue}
+</style>
+<style>
+a{
+</style>
+"#;
+
+        let output = format(text, CssFormatOptions::default(), None).unwrap();
+        assert_eq!(
+            output,
+            r#"<p style="color:red"></p>
+<style>
+a {
+	color: blue;
+}
+</style>
+<style>
+a{
+</style>
+"#
+        );
+    }
+}
@@ -0,0 +1,301 @@
+use crate::file_handlers::{graphql, LintParams, LintResults};
+use crate::workspace::DocumentFileSource;
+use crate::WorkspaceError;
+use biome_formatter::{FormatOptions, IndentStyle};
+use biome_graphql_formatter::context::GraphqlFormatOptions;
+use biome_graphql_formatter::format_node;
+use biome_graphql_parser::{parse_graphql, GraphqlParse};
+use biome_graphql_syntax::GraphqlFileSource;
+use biome_js_formatter::context::JsFormatOptions;
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsTemplateElement, JsFileSource, JsSyntaxNode, JsTemplateExpression,
+};
+use biome_rowan::{AstNode, AstNodeList, TextRange, TextSize};
+
+/// The tags of the template literals that contain a GraphQL document
+const GRAPHQL_TAGS: [&str; 2] = ["gql", "graphql"];
+
+/// The comment that marks an untagged template literal as a GraphQL document
+const GRAPHQL_COMMENT: &str = "#graphql";
+
+/// A GraphQL document embedded in a template literal of a JavaScript document.
+///
+/// ```js
+/// const USER = gql`
+///   query User { user { name } }
+/// `;
+/// const POSTS = `#graphql
+///   query Posts { posts { title } }
+/// `;
+/// ```
+///
+/// The template literals tagged with `gql` or `graphql` are GraphQL documents,
+/// as well as the untagged ones that start with a `#graphql` comment.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EmbeddedGraphql {
+    source: String,
+    range: TextRange,
+    has_substitutions: bool,
+}
+
+impl EmbeddedGraphql {
+    /// It extracts the GraphQL documents contained in the template literals of the script `root`,
+    /// in source order.
+    pub fn extract(root: &JsSyntaxNode) -> Vec<Self> {
+        root.descendants()
+            .filter_map(JsTemplateExpression::cast)
+            .filter_map(|template| Self::from_template(&template))
+            .collect()
+    }
+
+    fn from_template(template: &JsTemplateExpression) -> Option<Self> {
+        let range = TextRange::new(
+            template.l_tick_token().ok()?.text_trimmed_range().end(),
+            template.r_tick_token().ok()?.text_trimmed_range().start(),
+        );
+
+        // The substitutions are replaced with spaces, so that the ranges of the document
+        // still match the ones of the template literal.
+        let mut source = String::with_capacity(usize::from(range.len()));
+        let mut has_substitutions = false;
+        for element in template.elements().iter() {
+            match element {
+                AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                    source.push_str(chunk.template_chunk_token().ok()?.text());
+                }
+                AnyJsTemplateElement::JsTemplateElement(substitution) => {
+                    has_substitutions = true;
+                    let len = usize::from(substitution.syntax().text_range().len());
+                    source.extend(std::iter::repeat(' ').take(len));
+                }
+            }
+        }
+
+        let is_graphql = match template.tag() {
+            Some(AnyJsExpression::JsIdentifierExpression(identifier)) => {
+                let name = identifier.name().ok()?.value_token().ok()?;
+                GRAPHQL_TAGS.contains(&name.text_trimmed())
+            }
+            Some(_) => false,
+            None => source.trim_start().starts_with(GRAPHQL_COMMENT),
+        };
+
+        is_graphql.then_some(Self {
+            source,
+            range,
+            has_substitutions,
+        })
+    }
+
+    /// Returns the GraphQL document, with its substitutions replaced with spaces
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the range of the content of the template literal in the document
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// Returns the offset to add to the ranges of the parsed document
+    /// to map them to the JavaScript document.
+    pub fn offset(&self) -> TextSize {
+        self.range.start()
+    }
+
+    /// Returns `true` if the template literal contains substitutions, such as `${fragment}`
+    pub fn has_substitutions(&self) -> bool {
+        self.has_substitutions
+    }
+
+    pub fn parse(&self) -> GraphqlParse {
+        parse_graphql(&self.source)
+    }
+}
+
+/// It lints the GraphQL documents embedded in the script `root` with the GraphQL rules.
+///
+/// The ranges of the diagnostics are mapped to the script. The documents that contain
+/// syntax errors are skipped, because the errors can come from their substitutions.
+pub(crate) fn lint(root: &JsSyntaxNode, params: &LintParams) -> LintResults {
+    let mut results = LintResults {
+        diagnostics: Vec::new(),
+        errors: 0,
+        skipped_diagnostics: 0,
+    };
+
+    for snippet in EmbeddedGraphql::extract(root) {
+        let parse = snippet.parse();
+        if parse.has_errors() {
+            continue;
+        }
+
+        let max_diagnostics = params
+            .max_diagnostics
+            .saturating_sub(results.diagnostics.len() as u32);
+        let snippet_results = graphql::lint(LintParams {
+            parse: parse.into(),
+            workspace: params.workspace,
+            language: DocumentFileSource::Graphql(GraphqlFileSource::default()),
+            max_diagnostics,
+            path: params.path,
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            categories: params.categories,
+            manifest: None,
+            #[cfg(feature = "css")]
+            css_project: params.css_project.clone(),
+            js_project: params.js_project.clone(),
+            json_schemas: params.json_schemas.clone(),
+            graphql_schema: params.graphql_schema.clone(),
+            graphql_project: params.graphql_project.clone(),
+            angular_project: params.angular_project.clone(),
+            suppression_reason: params.suppression_reason.clone(),
+        });
+
+        results.errors += snippet_results.errors;
+        results.skipped_diagnostics += snippet_results.skipped_diagnostics;
+        results.diagnostics.extend(
+            snippet_results
+                .diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_offset(snippet.offset())),
+        );
+    }
+
+    results
+}
+
+/// It formats the GraphQL documents embedded in the script `text`,
+/// and returns the script with the formatted documents.
+///
+/// A formatted document starts on the line after the opening backtick, and it's indented
+/// one level deeper than the line of the backtick, where the closing backtick goes.
+/// The documents that contain substitutions or syntax errors are left as they are.
+pub(crate) fn format(
+    text: &str,
+    file_source: JsFileSource,
+    js_options: &JsFormatOptions,
+    options: GraphqlFormatOptions,
+) -> Result<String, WorkspaceError> {
+    let parse = parse(text, file_source, JsParserOptions::default());
+    let line_ending = js_options.line_ending().as_str();
+    let indent = match js_options.indent_style() {
+        IndentStyle::Tab => "\t".to_string(),
+        IndentStyle::Space => " ".repeat(js_options.indent_width().value() as usize),
+    };
+    let mut output = text.to_string();
+
+    for snippet in EmbeddedGraphql::extract(&parse.syntax()).iter().rev() {
+        if snippet.has_substitutions() || snippet.source().trim().is_empty() {
+            continue;
+        }
+        let parse = snippet.parse();
+        if parse.has_errors() {
+            continue;
+        }
+
+        let formatted = format_node(options.clone(), &parse.syntax())?;
+        let printed = formatted
+            .print()
+            .map_err(|error| WorkspaceError::FormatError(error.into()))?;
+
+        let start = usize::from(snippet.range().start());
+        let line_start = text[..start].rfind('\n').map_or(0, |index| index + 1);
+        let base_indent: String = text[line_start..start]
+            .chars()
+            .take_while(|char| matches!(char, ' ' | '\t'))
+            .collect();
+
+        let mut content = String::from(line_ending);
+        for line in printed.as_code().lines() {
+            if !line.is_empty() {
+                content.push_str(&base_indent);
+                content.push_str(&indent);
+                content.push_str(line);
+            }
+            content.push_str(line_ending);
+        }
+        content.push_str(&base_indent);
+        output.replace_range(std::ops::Range::<usize>::from(snippet.range()), &content);
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_tagged_and_commented_templates() {
+        let text = r#"const a = gql`query A { a }`;
+const b = graphql`fragment B on User { ...${a} }`;
+const c = `#graphql
+  query C { c }`;
+const d = css`a { color: red; }`;
+const e = `query E { e }`;
+"#;
+        let parse = parse(text, JsFileSource::js_module(), JsParserOptions::default());
+
+        let snippets = EmbeddedGraphql::extract(&parse.syntax());
+        let snippets: Vec<_> = snippets
+            .iter()
+            .map(|snippet| (snippet.source(), snippet.has_substitutions()))
+            .collect();
+        assert_eq!(
+            snippets,
+            vec![
+                ("query A { a }", false),
+                ("fragment B on User { ...     }", true),
+                ("#graphql\n  query C { c }", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn maps_offsets_to_the_document() {
+        let text = "const a = gql`query A { a }`;";
+        let parse = parse(text, JsFileSource::js_module(), JsParserOptions::default());
+        let snippet = &EmbeddedGraphql::extract(&parse.syntax())[0];
+
+        let field = TextSize::from(snippet.source().find("{ a").unwrap() as u32);
+        assert_eq!(
+            usize::from(field + snippet.offset()),
+            text.find("{ a").unwrap()
+        );
+    }
+
+    #[test]
+    fn formats_templates() {
+        let text = r#"function f() {
+	return gql`query A{a b}`;
+}
+const b = gql`query B { ${fields} }`;
+const c = gql`query C {`;
+"#;
+
+        let output = format(
+            text,
+            JsFileSource::js_module(),
+            &JsFormatOptions::new(JsFileSource::js_module()),
+            GraphqlFormatOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            output,
+            r#"function f() {
+	return gql`
+		query A {
+			a
+			b
+		}
+	`;
+}
+const b = gql`query B { ${fields} }`;
+const c = gql`query C {`;
+"#
+        );
+    }
+}
@@ -0,0 +1,265 @@
+use crate::file_handlers::{html, javascript, LintParams, LintResults};
+use crate::workspace::DocumentFileSource;
+use crate::WorkspaceError;
+use biome_js_formatter::context::JsFormatOptions;
+use biome_js_formatter::format_node;
+use biome_js_parser::{parse, JsParserOptions, Parse};
+use biome_js_syntax::{AnyJsRoot, JsFileSource};
+use biome_rowan::{TextRange, TextSize};
+use regex::Regex;
+use std::sync::LazyLock;
+
+static SCRIPT_ELEMENT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?is)(?<opening><script(?:\s[^>]*)?>)(?<script>.*?)</script>"#).unwrap()
+});
+
+static TYPE_ATTRIBUTE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)\stype\s*=\s*(?:"(?<double>[^"]*)"|'(?<single>[^']*)'|(?<bare>[^\s>]+))"#)
+        .unwrap()
+});
+
+/// The values of the `type` attribute of the classic scripts, as listed by the
+/// [HTML specification](https://html.spec.whatwg.org/multipage/scripting.html#javascript-mime-type)
+const JAVASCRIPT_MIME_TYPES: &[&str] = &[
+    "application/ecmascript",
+    "application/javascript",
+    "application/x-ecmascript",
+    "application/x-javascript",
+    "text/ecmascript",
+    "text/javascript",
+    "text/javascript1.0",
+    "text/javascript1.1",
+    "text/javascript1.2",
+    "text/javascript1.3",
+    "text/javascript1.4",
+    "text/javascript1.5",
+    "text/jscript",
+    "text/livescript",
+    "text/x-ecmascript",
+    "text/x-javascript",
+];
+
+/// The JavaScript code of a `<script>` element of an HTML document.
+///
+/// ```html
+/// <script type="module">
+/// import { run } from "./app.js";
+/// </script>
+/// ```
+///
+/// The scripts whose `type` attribute is `module` are parsed as modules, the other ones as
+/// classic scripts. The `<script>` elements that don't contain JavaScript, such as the JSON data
+/// blocks and the import maps, are skipped.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EmbeddedScript<'a> {
+    content: &'a str,
+    range: TextRange,
+    file_source: JsFileSource,
+}
+
+impl<'a> EmbeddedScript<'a> {
+    /// It extracts the JavaScript code of the `<script>` elements of a document, in source order.
+    ///
+    /// The elements without code, such as the ones that load a script with a `src` attribute,
+    /// are skipped.
+    pub fn extract(text: &'a str) -> Vec<Self> {
+        SCRIPT_ELEMENT
+            .captures_iter(text)
+            .filter_map(|captures| {
+                let file_source = script_file_source(captures.name("opening")?.as_str())?;
+                let script = captures.name("script")?;
+                if script.as_str().trim().is_empty() {
+                    return None;
+                }
+                Some(Self {
+                    content: script.as_str(),
+                    range: TextRange::new(
+                        TextSize::from(script.start() as u32),
+                        TextSize::from(script.end() as u32),
+                    ),
+                    file_source,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the JavaScript code, as written in the document
+    pub fn content(&self) -> &'a str {
+        self.content
+    }
+
+    /// Returns the range of the JavaScript code in the document
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    pub fn file_source(&self) -> JsFileSource {
+        self.file_source
+    }
+
+    pub fn parse(&self) -> Parse<AnyJsRoot> {
+        parse(self.content, self.file_source, JsParserOptions::default())
+    }
+}
+
+/// Returns the file source of the script of a `<script>` element, from its opening tag,
+/// or `None` if it doesn't contain JavaScript.
+fn script_file_source(opening_tag: &str) -> Option<JsFileSource> {
+    let script_type = TYPE_ATTRIBUTE.captures(opening_tag).and_then(|captures| {
+        captures
+            .name("double")
+            .or_else(|| captures.name("single"))
+            .or_else(|| captures.name("bare"))
+    });
+    let Some(script_type) = script_type.map(|script_type| script_type.as_str().trim()) else {
+        return Some(JsFileSource::js_script());
+    };
+
+    if script_type.eq_ignore_ascii_case("module") {
+        Some(JsFileSource::js_module())
+    } else if script_type.is_empty()
+        || JAVASCRIPT_MIME_TYPES
+            .iter()
+            .any(|mime_type| script_type.eq_ignore_ascii_case(mime_type))
+    {
+        Some(JsFileSource::js_script())
+    } else {
+        None
+    }
+}
+
+/// It lints the JavaScript of the `<script>` elements of the document `text` with the
+/// JavaScript rules.
+///
+/// The ranges of the diagnostics are mapped to the document.
+pub(crate) fn lint(text: &str, params: &LintParams) -> LintResults {
+    let mut results = LintResults {
+        diagnostics: Vec::new(),
+        errors: 0,
+        skipped_diagnostics: 0,
+    };
+
+    for script in EmbeddedScript::extract(text) {
+        let max_diagnostics = params
+            .max_diagnostics
+            .saturating_sub(results.diagnostics.len() as u32);
+        let script_results = javascript::lint(LintParams {
+            parse: script.parse().into(),
+            workspace: params.workspace,
+            language: DocumentFileSource::Js(script.file_source()),
+            max_diagnostics,
+            path: params.path,
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            categories: params.categories,
+            manifest: params.manifest.clone(),
+            #[cfg(feature = "css")]
+            css_project: params.css_project.clone(),
+            js_project: params.js_project.clone(),
+            json_schemas: params.json_schemas.clone(),
+            #[cfg(feature = "graphql")]
+            graphql_schema: params.graphql_schema.clone(),
+            #[cfg(feature = "graphql")]
+            graphql_project: params.graphql_project.clone(),
+            angular_project: params.angular_project.clone(),
+            suppression_reason: params.suppression_reason.clone(),
+        });
+
+        results.errors += script_results.errors;
+        results.skipped_diagnostics += script_results.skipped_diagnostics;
+        results.diagnostics.extend(
+            script_results
+                .diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_offset(script.range().start())),
+        );
+    }
+
+    results
+}
+
+/// It formats the JavaScript of the `<script>` elements of the document `text`,
+/// and returns the document with the formatted JavaScript, indented by `indent_unit`
+/// from the indentation of the elements.
+///
+/// The scripts that contain syntax errors are left as they are.
+pub(crate) fn format(
+    text: &str,
+    options: JsFormatOptions,
+    indent_unit: &str,
+) -> Result<String, WorkspaceError> {
+    let mut output = text.to_string();
+
+    for script in EmbeddedScript::extract(text).iter().rev() {
+        let parse = script.parse();
+        if parse.has_errors() {
+            continue;
+        }
+
+        let formatted = format_node(options.clone(), &parse.syntax())?;
+        let printed = formatted
+            .print()
+            .map_err(|error| WorkspaceError::FormatError(error.into()))?;
+        let range = std::ops::Range::<usize>::from(script.range());
+        let code = html::indent_embedded_code(text, range.start, printed.as_code(), indent_unit);
+        output.replace_range(range, &code);
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_javascript_scripts() {
+        let text = r#"<script>var a = 1;</script>
+<script type="module">import b from "b";</script>
+<script type="application/ld+json">{ "name": "c" }</script>
+<script src="d.js"></script>
+<script type='text/javascript'>var e = 2;</script>"#;
+
+        let scripts: Vec<_> = EmbeddedScript::extract(text)
+            .iter()
+            .map(|script| (script.content(), script.file_source().is_module()))
+            .collect();
+        assert_eq!(
+            scripts,
+            vec![
+                ("var a = 1;", false),
+                ("import b from \"b\";", true),
+                ("var e = 2;", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn formats_and_indents_scripts() {
+        let text = r#"<body>
+  <script type="module">
+import {a} from "a"
+a( )
+  </script>
+  <script>
+    if (
+  </script>
+</body>
+"#;
+
+        let output = format(text, JsFormatOptions::new(JsFileSource::js_module()), "  ").unwrap();
+        assert_eq!(
+            output,
+            r#"<body>
+  <script type="module">
+    import { a } from "a";
+    a();
+  </script>
+  <script>
+    if (
+  </script>
+</body>
+"#
+        );
+    }
+}
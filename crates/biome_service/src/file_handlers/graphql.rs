@@ -1,6 +1,8 @@
+use super::syntax_ranges::selection_ranges;
 use super::{
     is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource,
-    ExtensionHandler, FixAllParams, LintParams, LintResults, ParseResult, SearchCapabilities,
+    ExtensionHandler, FixAllParams, FixCycleGuard, LintParams, LintResults, ParseResult,
+    SearchCapabilities, SymbolCapabilities,
 };
 use crate::file_handlers::DebugCapabilities;
 use crate::file_handlers::{
@@ -12,6 +14,7 @@ use crate::settings::{
 };
 use crate::workspace::{
     CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
+    SemanticToken, SemanticTokenKind, SemanticTokenModifier,
 };
 use crate::WorkspaceError;
 use biome_analyze::{
@@ -25,13 +28,19 @@ use biome_formatter::{
 };
 use biome_fs::BiomePath;
 use biome_graphql_analyze::analyze;
-use biome_graphql_formatter::context::GraphqlFormatOptions;
+use biome_graphql_formatter::context::{
+    DirectiveOrder, EmptyLineBetweenDefinitions, GraphqlFormatOptions, SingleFieldSelectionSets,
+};
 use biome_graphql_formatter::format_node;
 use biome_graphql_parser::parse_graphql_with_cache;
-use biome_graphql_syntax::{GraphqlLanguage, GraphqlRoot, GraphqlSyntaxNode, TextRange, TextSize};
+use biome_graphql_semantic::semantic_model;
+use biome_graphql_syntax::{
+    GraphqlLanguage, GraphqlRoot, GraphqlSyntaxKind, GraphqlSyntaxNode, TextRange, TextSize,
+};
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, NodeCache, TokenAtOffset};
 use std::borrow::Cow;
+use std::path::PathBuf;
 use tracing::{debug_span, error, info, trace_span};
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -43,6 +52,9 @@ pub struct GraphqlFormatterSettings {
     pub indent_style: Option<IndentStyle>,
     pub quote_style: Option<QuoteStyle>,
     pub bracket_spacing: Option<BracketSpacing>,
+    pub directive_order: Option<DirectiveOrder>,
+    pub single_field_selection_sets: Option<SingleFieldSelectionSets>,
+    pub empty_line_between_definitions: Option<EmptyLineBetweenDefinitions>,
     pub enabled: Option<bool>,
 }
 
@@ -56,6 +68,9 @@ impl Default for GraphqlFormatterSettings {
             line_width: Default::default(),
             quote_style: Default::default(),
             bracket_spacing: Default::default(),
+            directive_order: Default::default(),
+            single_field_selection_sets: Default::default(),
+            empty_line_between_definitions: Default::default(),
         }
     }
 }
@@ -64,12 +79,15 @@ impl Default for GraphqlFormatterSettings {
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GraphqlLinterSettings {
     pub enabled: Option<bool>,
+    /// The path of the schema that the operations are validated against
+    pub schema: Option<PathBuf>,
 }
 
 impl Default for GraphqlLinterSettings {
     fn default() -> Self {
         Self {
             enabled: Some(false),
+            schema: None,
         }
     }
 }
@@ -126,7 +144,18 @@ impl ServiceLanguage for GraphqlLanguage {
         .with_line_width(line_width)
         .with_line_ending(line_ending)
         .with_bracket_spacing(bracket_spacing)
-        .with_quote_style(language.and_then(|l| l.quote_style).unwrap_or_default());
+        .with_quote_style(language.and_then(|l| l.quote_style).unwrap_or_default())
+        .with_directive_order(language.and_then(|l| l.directive_order).unwrap_or_default())
+        .with_single_field_selection_sets(
+            language
+                .and_then(|l| l.single_field_selection_sets)
+                .unwrap_or_default(),
+        )
+        .with_empty_line_between_definitions(
+            language
+                .and_then(|l| l.empty_line_between_definitions)
+                .unwrap_or_default(),
+        );
         if let Some(overrides) = overrides {
             overrides.to_override_graphql_format_options(path, options)
         } else {
@@ -166,6 +195,7 @@ impl ExtensionHandler for GraphqlFileHandler {
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
+                prepare_rename: None,
                 rename: None,
                 fix_all: Some(fix_all),
                 organize_imports: None,
@@ -175,7 +205,15 @@ impl ExtensionHandler for GraphqlFileHandler {
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities::default(),
+            symbols: SymbolCapabilities {
+                document_symbols: None,
+                semantic_tokens: Some(semantic_tokens),
+                inlay_hints: None,
+                signature_help: None,
+                folding_ranges: None,
+                selection_ranges: Some(selection_ranges::<GraphqlLanguage>),
+            },
         }
     }
 }
@@ -290,7 +328,7 @@ fn format_on_type(
     Ok(printed)
 }
 
-fn lint(params: LintParams) -> LintResults {
+pub(crate) fn lint(params: LintParams) -> LintResults {
     debug_span!("Linting GraphQL file", path =? params.path, language =? params.language).in_scope(
         move || {
             let workspace_settings = &params.workspace;
@@ -336,51 +374,60 @@ fn lint(params: LintParams) -> LintResults {
                 .count();
 
             info!("Analyze file {}", params.path.display());
-            let (_, analyze_diagnostics) = analyze(&tree, filter, &analyzer_options, |signal| {
-                if let Some(mut diagnostic) = signal.diagnostic() {
-                    // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
-                    if ignores_suppression_comment
-                        && diagnostic.category() == Some(category!("suppressions/unused"))
-                    {
-                        return ControlFlow::<Never>::Continue(());
-                    }
+            let schema = params.graphql_schema.clone();
+            let project = params.graphql_project.clone();
+            let (_, analyze_diagnostics) = analyze(
+                &tree,
+                filter,
+                &analyzer_options,
+                schema,
+                project,
+                |signal| {
+                    if let Some(mut diagnostic) = signal.diagnostic() {
+                        // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
+                        if ignores_suppression_comment
+                            && diagnostic.category() == Some(category!("suppressions/unused"))
+                        {
+                            return ControlFlow::<Never>::Continue(());
+                        }
 
-                    diagnostic_count += 1;
-
-                    // We do now check if the severity of the diagnostics should be changed.
-                    // The configuration allows to change the severity of the diagnostics emitted by rules.
-                    let severity = diagnostic
-                        .category()
-                        .filter(|category| category.name().starts_with("lint/"))
-                        .map_or_else(
-                            || diagnostic.severity(),
-                            |category| {
-                                rules
-                                    .as_ref()
-                                    .and_then(|rules| rules.get_severity_from_code(category))
-                                    .unwrap_or(Severity::Warning)
-                            },
-                        );
-
-                    if severity >= Severity::Error {
-                        errors += 1;
-                    }
+                        diagnostic_count += 1;
+
+                        // We do now check if the severity of the diagnostics should be changed.
+                        // The configuration allows to change the severity of the diagnostics emitted by rules.
+                        let severity = diagnostic
+                            .category()
+                            .filter(|category| category.name().starts_with("lint/"))
+                            .map_or_else(
+                                || diagnostic.severity(),
+                                |category| {
+                                    rules
+                                        .as_ref()
+                                        .and_then(|rules| rules.get_severity_from_code(category))
+                                        .unwrap_or(Severity::Warning)
+                                },
+                            );
 
-                    if diagnostic_count <= params.max_diagnostics {
-                        for action in signal.actions() {
-                            if !action.is_suppression() {
-                                diagnostic = diagnostic.add_code_suggestion(action.into());
-                            }
+                        if severity >= Severity::Error {
+                            errors += 1;
                         }
 
-                        let error = diagnostic.with_severity(severity);
+                        if diagnostic_count <= params.max_diagnostics {
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diagnostic = diagnostic.add_code_suggestion(action.into());
+                                }
+                            }
+
+                            let error = diagnostic.with_severity(severity);
 
-                        diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                            diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                        }
                     }
-                }
 
-                ControlFlow::<Never>::Continue(())
-            });
+                    ControlFlow::<Never>::Continue(())
+                },
+            );
 
             diagnostics.extend(
                 analyze_diagnostics
@@ -400,6 +447,83 @@ fn lint(params: LintParams) -> LintResults {
 }
 
 #[tracing::instrument(level = "debug", skip(params))]
+fn semantic_tokens(parse: AnyParse) -> Vec<SemanticToken> {
+    let root: GraphqlRoot = parse.tree();
+    let model = semantic_model(&root);
+
+    let mut tokens = Vec::new();
+    for binding in model.all_bindings() {
+        let node = binding.syntax();
+        let Some(kind) = node
+            .parent()
+            .and_then(|parent| graphql_token_kind(parent.kind()))
+        else {
+            continue;
+        };
+        tokens.push(SemanticToken {
+            range: node.text_trimmed_range(),
+            kind,
+            modifiers: vec![SemanticTokenModifier::Declaration],
+        });
+        tokens.extend(
+            binding
+                .all_references()
+                .iter()
+                .map(|reference| SemanticToken {
+                    range: reference.syntax().text_trimmed_range(),
+                    kind,
+                    modifiers: Vec::new(),
+                }),
+        );
+    }
+    // The built-in scalars and the types defined by the other files of the schema
+    // are only known by the kind of their references
+    for reference in model.all_unresolved_references() {
+        let node = reference.syntax();
+        let kind = match node.parent().map(|parent| parent.kind()) {
+            Some(GraphqlSyntaxKind::GRAPHQL_FRAGMENT_SPREAD) => SemanticTokenKind::Function,
+            Some(GraphqlSyntaxKind::GRAPHQL_DIRECTIVE) => continue,
+            _ => SemanticTokenKind::Type,
+        };
+        tokens.push(SemanticToken {
+            range: node.text_trimmed_range(),
+            kind,
+            modifiers: Vec::new(),
+        });
+    }
+    for node in root.syntax().descendants() {
+        let modifiers = match node.kind() {
+            GraphqlSyntaxKind::GRAPHQL_VARIABLE_BINDING => vec![SemanticTokenModifier::Declaration],
+            GraphqlSyntaxKind::GRAPHQL_VARIABLE_REFERENCE => Vec::new(),
+            _ => continue,
+        };
+        tokens.push(SemanticToken {
+            range: node.text_trimmed_range(),
+            kind: SemanticTokenKind::Parameter,
+            modifiers,
+        });
+    }
+
+    tokens.sort_by_key(|token| token.range.start());
+    tokens
+}
+
+/// Returns the kind of the tokens of a name declared by a definition of kind `definition`
+fn graphql_token_kind(definition: GraphqlSyntaxKind) -> Option<SemanticTokenKind> {
+    let kind = match definition {
+        GraphqlSyntaxKind::GRAPHQL_OPERATION_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_FRAGMENT_DEFINITION => SemanticTokenKind::Function,
+        GraphqlSyntaxKind::GRAPHQL_OBJECT_TYPE_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_SCALAR_TYPE_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_UNION_TYPE_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_INPUT_OBJECT_TYPE_DEFINITION => SemanticTokenKind::Type,
+        GraphqlSyntaxKind::GRAPHQL_INTERFACE_TYPE_DEFINITION => SemanticTokenKind::Interface,
+        GraphqlSyntaxKind::GRAPHQL_ENUM_TYPE_DEFINITION => SemanticTokenKind::Enum,
+        _ => return None,
+    };
+    Some(kind)
+}
+
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
     let CodeActionsParams {
         parse,
@@ -407,10 +531,14 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest: _,
+        js_project: _,
+        graphql_schema,
+        graphql_project,
         language,
         only,
         skip,
         suppression_reason,
+        ..
     } = params;
     debug_span!("Code actions GraphQL", range =? range, path =? path).in_scope(move || {
         let tree = parse.tree();
@@ -445,19 +573,26 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
 
             info!("GraphQL runs the analyzer");
 
-            analyze(&tree, filter, &analyzer_options, |signal| {
-                actions.extend(signal.actions().into_code_action_iter().map(|item| {
-                    CodeAction {
-                        category: item.category.clone(),
-                        rule_name: item
-                            .rule_name
-                            .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
-                        suggestion: item.suggestion,
-                    }
-                }));
+            analyze(
+                &tree,
+                filter,
+                &analyzer_options,
+                graphql_schema,
+                graphql_project,
+                |signal| {
+                    actions.extend(signal.actions().into_code_action_iter().map(|item| {
+                        CodeAction {
+                            category: item.category.clone(),
+                            rule_name: item
+                                .rule_name
+                                .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                            suggestion: item.suggestion,
+                        }
+                    }));
 
-                ControlFlow::<Never>::Continue(())
-            });
+                    ControlFlow::<Never>::Continue(())
+                },
+            );
 
             PullActionsResult { actions }
         })
@@ -489,6 +624,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         categories: RuleCategoriesBuilder::default()
             .with_syntax()
             .with_lint()
+            .with_action()
             .build(),
         enabled_rules: Some(enabled_rules.as_slice()),
         disabled_rules: &disabled_rules,
@@ -496,6 +632,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     };
 
     let mut actions = Vec::new();
+    let mut fix_cycle_guard = FixCycleGuard::new(tree.syntax());
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
     let analyzer_options = params.workspace.analyzer_options::<GraphqlLanguage>(
@@ -504,48 +641,57 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         params.suppression_reason,
     );
     loop {
-        let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
-            let current_diagnostic = signal.diagnostic();
-
-            if let Some(diagnostic) = current_diagnostic.as_ref() {
-                if is_diagnostic_error(diagnostic, rules.as_deref()) {
-                    errors += 1;
+        let schema = params.graphql_schema.clone();
+        let project = params.graphql_project.clone();
+        let (action, _) = analyze(
+            &tree,
+            filter,
+            &analyzer_options,
+            schema,
+            project,
+            |signal| {
+                let current_diagnostic = signal.diagnostic();
+
+                if let Some(diagnostic) = current_diagnostic.as_ref() {
+                    if is_diagnostic_error(diagnostic, rules.as_deref()) {
+                        errors += 1;
+                    }
                 }
-            }
 
-            for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
-                    continue;
-                }
+                for action in signal.actions() {
+                    // suppression actions should not be part of the fixes (safe or suggested)
+                    if action.is_suppression() {
+                        continue;
+                    }
 
-                match params.fix_file_mode {
-                    FixFileMode::SafeFixes => {
-                        if action.applicability == Applicability::MaybeIncorrect {
-                            skipped_suggested_fixes += 1;
+                    match params.fix_file_mode {
+                        FixFileMode::SafeFixes => {
+                            if action.applicability == Applicability::MaybeIncorrect {
+                                skipped_suggested_fixes += 1;
+                            }
+                            if action.applicability == Applicability::Always {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
-                        if action.applicability == Applicability::Always {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                        FixFileMode::SafeAndUnsafeFixes => {
+                            if matches!(
+                                action.applicability,
+                                Applicability::Always | Applicability::MaybeIncorrect
+                            ) {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
-                    }
-                    FixFileMode::SafeAndUnsafeFixes => {
-                        if matches!(
-                            action.applicability,
-                            Applicability::Always | Applicability::MaybeIncorrect
-                        ) {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                        FixFileMode::ApplySuppressions => {
+                            // TODO: implement once a GraphQL suppression action is available
                         }
                     }
-                    FixFileMode::ApplySuppressions => {
-                        // TODO: implement once a GraphQL suppression action is available
-                    }
                 }
-            }
 
-            ControlFlow::Continue(())
-        });
+                ControlFlow::Continue(())
+            },
+        );
 
         match action {
             Some(action) => {
@@ -570,6 +716,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                             .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
                         range,
                     });
+                    fix_cycle_guard.record(tree.syntax(), &actions)?;
                 }
             }
             None => {
@@ -1,7 +1,7 @@
 use super::{
     AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, ExtensionHandler,
     FormatterCapabilities, LintParams, LintResults, ParseResult, ParserCapabilities,
-    SearchCapabilities,
+    SearchCapabilities, SymbolCapabilities,
 };
 use crate::workspace::GetSyntaxTreeResult;
 use crate::{
@@ -123,6 +123,7 @@ impl ExtensionHandler for GritFileHandler {
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
                 code_actions: None,
+                prepare_rename: None,
                 rename: None,
                 fix_all: None,
                 organize_imports: None,
@@ -132,7 +133,15 @@ impl ExtensionHandler for GritFileHandler {
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities::default(),
+            symbols: SymbolCapabilities {
+                document_symbols: None,
+                semantic_tokens: None,
+                inlay_hints: None,
+                signature_help: None,
+                folding_ranges: None,
+                selection_ranges: None,
+            },
         }
     }
 }
@@ -1,11 +1,26 @@
-use biome_analyze::{AnalyzerConfiguration, AnalyzerOptions};
-use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
+use biome_analyze::{
+    AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never, RuleCategory,
+};
+use biome_configuration::html::HtmlIgnoreDelimiter;
+#[cfg(feature = "css")]
+use biome_css_syntax::{CssFileSource, CssLanguage};
+use biome_diagnostics::{category, Diagnostic, DiagnosticExt, Severity};
+use biome_formatter::{
+    AttributePosition, BracketSameLine, FormatOptions, IndentStyle, IndentWidth, LineEnding,
+    LineWidth, Printed,
+};
 use biome_fs::BiomePath;
-use biome_html_formatter::{format_node, HtmlFormatOptions};
-use biome_html_parser::parse_html_with_cache;
+use biome_html_analyze::analyze;
+use biome_html_formatter::{context::WhitespaceSensitivity, format_node, HtmlFormatOptions};
+use biome_html_parser::{parse_html, parse_html_with_cache, HtmlParserOptions};
 use biome_html_syntax::{HtmlLanguage, HtmlRoot, HtmlSyntaxNode};
+use biome_js_syntax::{JsFileSource, JsLanguage};
 use biome_parser::AnyParse;
-use biome_rowan::NodeCache;
+use biome_rowan::{AstNode, NodeCache};
+use regex::Regex;
+use std::ops::Range;
+use std::sync::LazyLock;
+use tracing::debug_span;
 
 use crate::{
     settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
@@ -13,11 +28,23 @@ use crate::{
     WorkspaceError,
 };
 
+use super::syntax_ranges::selection_ranges;
 use super::{
-    AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, ExtensionHandler,
-    FormatterCapabilities, ParseResult, ParserCapabilities, SearchCapabilities,
+    embedded_angular, embedded_css, embedded_js, AnalyzerCapabilities, AnalyzerVisitorBuilder,
+    Capabilities, DebugCapabilities, DocumentFileSource, ExtensionHandler, FormatterCapabilities,
+    LintParams, LintResults, ParseResult, ParserCapabilities, SearchCapabilities,
+    SymbolCapabilities,
 };
 
+/// The blocks of a document whose content isn't markup: the `<script>` and `<style>` blocks,
+/// whose content is captured by the `script` and `style` groups, and the comments.
+pub(crate) static RAW_TEXT_BLOCK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?is)<script(?:\s[^>]*)?>(?<script>.*?)</script>|<style(?:\s[^>]*)?>(?<style>.*?)</style>|<!--.*?-->"#,
+    )
+    .unwrap()
+});
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HtmlFormatterSettings {
@@ -25,6 +52,9 @@ pub struct HtmlFormatterSettings {
     pub line_width: Option<LineWidth>,
     pub indent_width: Option<IndentWidth>,
     pub indent_style: Option<IndentStyle>,
+    pub attribute_position: Option<AttributePosition>,
+    pub bracket_same_line: Option<BracketSameLine>,
+    pub whitespace_sensitivity: Option<WhitespaceSensitivity>,
     pub enabled: Option<bool>,
 }
 
@@ -36,16 +66,36 @@ impl Default for HtmlFormatterSettings {
             indent_width: Default::default(),
             line_ending: Default::default(),
             line_width: Default::default(),
+            attribute_position: Default::default(),
+            bracket_same_line: Default::default(),
+            whitespace_sensitivity: Default::default(),
         }
     }
 }
 
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HtmlParserSettings {
+    pub ignore_delimiters: Vec<HtmlIgnoreDelimiter>,
+}
+
+impl From<&HtmlParserSettings> for HtmlParserOptions {
+    fn from(settings: &HtmlParserSettings) -> Self {
+        settings
+            .ignore_delimiters
+            .iter()
+            .fold(Self::default(), |options, delimiter| {
+                options.with_ignore_delimiter(&delimiter.start, &delimiter.end)
+            })
+    }
+}
+
 impl ServiceLanguage for HtmlLanguage {
     type FormatterSettings = HtmlFormatterSettings;
     type LinterSettings = ();
     type OrganizeImportsSettings = ();
     type FormatOptions = HtmlFormatOptions;
-    type ParserSettings = ();
+    type ParserSettings = HtmlParserSettings;
     type EnvironmentSettings = ();
 
     fn lookup_settings(
@@ -78,12 +128,27 @@ impl ServiceLanguage for HtmlLanguage {
             .and_then(|l| l.line_ending)
             .or(global.and_then(|g| g.line_ending))
             .unwrap_or_default();
+        let attribute_position = language
+            .and_then(|l| l.attribute_position)
+            .or(global.and_then(|g| g.attribute_position))
+            .unwrap_or_default();
 
         let options = HtmlFormatOptions::new(file_source.to_html_file_source().unwrap_or_default())
             .with_indent_style(indent_style)
             .with_indent_width(indent_width)
             .with_line_width(line_width)
-            .with_line_ending(line_ending);
+            .with_line_ending(line_ending)
+            .with_attribute_position(attribute_position)
+            .with_bracket_same_line(
+                language
+                    .and_then(|l| l.bracket_same_line)
+                    .unwrap_or_default(),
+            )
+            .with_whitespace_sensitivity(
+                language
+                    .and_then(|l| l.whitespace_sensitivity)
+                    .unwrap_or_default(),
+            );
         if let Some(overrides) = overrides {
             overrides.to_override_html_format_options(path, options)
         } else {
@@ -121,8 +186,9 @@ impl ExtensionHandler for HtmlFileHandler {
                 debug_formatter_ir: Some(debug_formatter_ir),
             },
             analyzer: AnalyzerCapabilities {
-                lint: None,
+                lint: Some(lint),
                 code_actions: None,
+                prepare_rename: None,
                 rename: None,
                 fix_all: None,
                 organize_imports: None,
@@ -132,7 +198,15 @@ impl ExtensionHandler for HtmlFileHandler {
                 format_range: None,
                 format_on_type: None,
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities::default(),
+            symbols: SymbolCapabilities {
+                document_symbols: None,
+                semantic_tokens: None,
+                inlay_hints: None,
+                signature_help: None,
+                folding_ranges: None,
+                selection_ranges: Some(selection_ranges::<HtmlLanguage>),
+            },
         }
     }
 }
@@ -141,10 +215,13 @@ fn parse(
     _biome_path: &BiomePath,
     file_source: DocumentFileSource,
     text: &str,
-    _settings: Option<&Settings>,
+    settings: Option<&Settings>,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let parse = parse_html_with_cache(text, cache);
+    let options = settings
+        .map(|settings| HtmlParserOptions::from(&settings.languages.html.parser))
+        .unwrap_or_default();
+    let parse = parse_html_with_cache(text, cache, options);
 
     ParseResult {
         any_parse: parse.into(),
@@ -187,11 +264,245 @@ fn format(
 
     tracing::debug!("Format with the following options: \n{}", options);
 
+    let indent_unit = match options.indent_style() {
+        IndentStyle::Tab => "\t".to_string(),
+        IndentStyle::Space => " ".repeat(options.indent_width().value() as usize),
+    };
     let tree = parse.syntax();
     let formatted = format_node(options, &tree)?;
 
-    match formatted.print() {
-        Ok(printed) => Ok(printed),
-        Err(error) => Err(WorkspaceError::FormatError(error.into())),
+    let printed = match formatted.print() {
+        Ok(printed) => printed,
+        Err(error) => return Err(WorkspaceError::FormatError(error.into())),
+    };
+
+    // The content of the `<script>` and `<style>` elements is formatted with the JavaScript
+    // and CSS formatters, and indented from the elements
+    let js_options = settings.format_options::<JsLanguage>(
+        biome_path,
+        &DocumentFileSource::Js(JsFileSource::js_module()),
+    );
+    let code = embedded_js::format(printed.as_code(), js_options, &indent_unit)?;
+    #[cfg(feature = "css")]
+    let css_options = settings
+        .format_options::<CssLanguage>(biome_path, &DocumentFileSource::Css(CssFileSource::css()));
+    #[cfg(feature = "css")]
+    let code = embedded_css::format(&code, css_options, Some(&indent_unit))?;
+    if code == printed.as_code() {
+        Ok(printed)
+    } else {
+        Ok(Printed::new(code, None, Vec::new(), Vec::new()))
     }
 }
+
+/// Returns the code of an element of the document `text`, such as a `<script>` or a `<style>`,
+/// whose content starts at `start`, indented by `indent_unit` from the indentation of the line
+/// of the element. The code starts and ends with a line break, so that the closing tag is on its
+/// own line, at the indentation of the element.
+pub(crate) fn indent_embedded_code(
+    text: &str,
+    start: usize,
+    code: &str,
+    indent_unit: &str,
+) -> String {
+    let line_start = text[..start]
+        .rfind('<')
+        .and_then(|tag| text[..tag].rfind('\n'))
+        .map_or(0, |line_break| line_break + 1);
+    let line = &text[line_start..];
+    let indentation = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+    let line_ending = if code.contains("\r\n") { "\r\n" } else { "\n" };
+
+    let mut output = String::from(line_ending);
+    for line in code.lines() {
+        if !line.is_empty() {
+            output.push_str(indentation);
+            output.push_str(indent_unit);
+            output.push_str(line);
+        }
+        output.push_str(line_ending);
+    }
+    output.push_str(indentation);
+    output
+}
+
+/// It formats the markup of a Svelte or Astro document `text`. The content of the `<script>`
+/// and `<style>` blocks is kept as it's written, because it's formatted by the JavaScript and
+/// CSS formatters. The markup is left as it is if it contains syntax errors, such as the syntax
+/// that the HTML parser doesn't support.
+pub(crate) fn format_markup(
+    text: &str,
+    options: HtmlFormatOptions,
+) -> Result<String, WorkspaceError> {
+    let parse = parse_html(text, HtmlParserOptions::default());
+    if parse.has_errors() {
+        return Ok(text.to_string());
+    }
+
+    let formatted = format_node(options, &parse.syntax())?;
+    let printed = formatted
+        .print()
+        .map_err(|error| WorkspaceError::FormatError(error.into()))?;
+    let mut output = printed.into_code();
+
+    let raw_text = |text: &str| -> Vec<Range<usize>> {
+        RAW_TEXT_BLOCK
+            .captures_iter(text)
+            .filter_map(|captures| captures.name("script").or_else(|| captures.name("style")))
+            .map(|content| content.range())
+            .collect()
+    };
+    let blocks = raw_text(text);
+    let formatted_blocks = raw_text(&output);
+    if blocks.len() != formatted_blocks.len() {
+        return Ok(text.to_string());
+    }
+    for (block, formatted_block) in blocks.into_iter().zip(formatted_blocks).rev() {
+        output.replace_range(formatted_block, &text[block]);
+    }
+
+    Ok(output)
+}
+
+fn lint(params: LintParams) -> LintResults {
+    debug_span!("Linting HTML file", path =? params.path, language =? params.language).in_scope(
+        move || {
+            let analyzer_options = params.workspace.analyzer_options::<HtmlLanguage>(
+                params.path,
+                &params.language,
+                params.suppression_reason.clone(),
+            );
+            let tree: HtmlRoot = params.parse.tree();
+            let text = tree.syntax().to_string();
+
+            let has_only_filter = !params.only.is_empty();
+            let rules = params
+                .workspace
+                .settings()
+                .as_ref()
+                .and_then(|settings| settings.as_linter_rules(params.path.as_path()));
+
+            let (enabled_rules, disabled_rules) =
+                AnalyzerVisitorBuilder::new(params.workspace.settings())
+                    .with_syntax_rules()
+                    .with_linter_rules(&params.only, &params.skip, params.path.as_path())
+                    .with_assists_rules(&params.only, &params.skip, params.path.as_path())
+                    .finish();
+
+            let filter = AnalysisFilter {
+                categories: params.categories,
+                enabled_rules: Some(enabled_rules.as_slice()),
+                disabled_rules: &disabled_rules,
+                range: None,
+            };
+
+            // Do not report unused suppression comment diagnostics if:
+            // - it is a syntax-only analyzer pass, or
+            // - if a single rule is run.
+            let ignores_suppression_comment =
+                !filter.categories.contains(RuleCategory::Lint) || has_only_filter;
+
+            let mut diagnostics = Vec::new();
+            let mut diagnostic_count = 0u32;
+            let mut errors = 0;
+
+            let (_, analyze_diagnostics) = analyze(&tree, filter, &analyzer_options, |signal| {
+                if let Some(mut diagnostic) = signal.diagnostic() {
+                    if ignores_suppression_comment
+                        && diagnostic.category() == Some(category!("suppressions/unused"))
+                    {
+                        return ControlFlow::<Never>::Continue(());
+                    }
+
+                    diagnostic_count += 1;
+
+                    // We do now check if the severity of the diagnostics should be changed.
+                    // The configuration allows to change the severity of the diagnostics emitted by rules.
+                    let severity = diagnostic
+                        .category()
+                        .filter(|category| category.name().starts_with("lint/"))
+                        .map_or_else(
+                            || diagnostic.severity(),
+                            |category| {
+                                rules
+                                    .as_ref()
+                                    .and_then(|rules| rules.get_severity_from_code(category))
+                                    .unwrap_or(Severity::Warning)
+                            },
+                        );
+
+                    if severity >= Severity::Error {
+                        errors += 1;
+                    }
+
+                    if diagnostic_count <= params.max_diagnostics {
+                        for action in signal.actions() {
+                            if !action.is_suppression() {
+                                diagnostic = diagnostic.add_code_suggestion(action.into());
+                            }
+                        }
+
+                        let error = diagnostic.with_severity(severity);
+
+                        diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            });
+
+            diagnostics.extend(
+                analyze_diagnostics
+                    .into_iter()
+                    .map(biome_diagnostics::serde::Diagnostic::new)
+                    .collect::<Vec<_>>(),
+            );
+            let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
+
+            // The expressions of the templates of the Angular components are linted
+            // with the correctness rules
+            let angular_results = embedded_angular::lint_template_file(&text, &params);
+
+            // The JavaScript of the `<script>` elements is linted with the JavaScript rules
+            let script_results = embedded_js::lint(&text, &params);
+            let room = (params.max_diagnostics as usize).saturating_sub(diagnostics.len());
+            let mut errors = errors + script_results.errors;
+            let mut skipped_diagnostics = skipped_diagnostics
+                + script_results.skipped_diagnostics
+                + script_results.diagnostics.len().saturating_sub(room) as u32;
+            diagnostics.extend(script_results.diagnostics.into_iter().take(room));
+
+            // The CSS of the `<style>` elements and of the `style` attributes is linted with the CSS rules
+            #[cfg(feature = "css")]
+            {
+                let max_diagnostics = params
+                    .max_diagnostics
+                    .saturating_sub(diagnostics.len() as u32);
+                let css_results = embedded_css::lint(
+                    &text,
+                    LintParams {
+                        max_diagnostics,
+                        ..params
+                    },
+                );
+                diagnostics.extend(css_results.diagnostics);
+                errors += css_results.errors;
+                skipped_diagnostics += css_results.skipped_diagnostics;
+            }
+
+            if let Some(angular_results) = angular_results {
+                let room = (params.max_diagnostics as usize).saturating_sub(diagnostics.len());
+                errors += angular_results.errors;
+                skipped_diagnostics += angular_results.skipped_diagnostics
+                    + angular_results.diagnostics.len().saturating_sub(room) as u32;
+                diagnostics.extend(angular_results.diagnostics.into_iter().take(room));
+            }
+
+            LintResults {
+                diagnostics,
+                errors,
+                skipped_diagnostics,
+            }
+        },
+    )
+}
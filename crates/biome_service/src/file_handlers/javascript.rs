@@ -1,11 +1,16 @@
+#[cfg(feature = "grit")]
+use super::search;
+use super::syntax_ranges::{block_folding_range, push_comment_folding_ranges, selection_ranges};
 use super::{
-    search, AnalyzerCapabilities, AnalyzerVisitorBuilder, CodeActionsParams, DebugCapabilities,
-    ExtensionHandler, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities, SearchCapabilities,
+    AnalyzerCapabilities, AnalyzerVisitorBuilder, CodeActionsParams, DebugCapabilities,
+    ExtensionHandler, FixCycleGuard, FormatterCapabilities, LintParams, LintResults, ParseResult,
+    ParserCapabilities, SearchCapabilities, SymbolCapabilities,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::diagnostics::extension_error;
-use crate::file_handlers::{is_diagnostic_error, FixAllParams};
+#[cfg(feature = "graphql")]
+use crate::file_handlers::embedded_graphql;
+use crate::file_handlers::{embedded_angular, is_diagnostic_error, FixAllParams};
 use crate::settings::{LinterSettings, OverrideSettings, Settings};
 use crate::workspace::{DocumentFileSource, OrganizeImportsResult};
 use crate::{
@@ -14,8 +19,10 @@ use crate::{
         WorkspaceSettingsHandle,
     },
     workspace::{
-        CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
-        RenameResult,
+        CodeAction, DocumentSymbol, FixAction, FixFileMode, FixFileResult, FoldingRange,
+        FoldingRangeKind, GetSyntaxTreeResult, InlayHint, InlayHintKind, PrepareRenameResult,
+        PullActionsResult, RenameResult, SemanticToken, SemanticTokenKind, SemanticTokenModifier,
+        Signature, SignatureParameter, SymbolKind,
     },
     WorkspaceError,
 };
@@ -31,7 +38,12 @@ use biome_formatter::{
     LineWidth, Printed, QuoteStyle,
 };
 use biome_fs::BiomePath;
-use biome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
+#[cfg(feature = "graphql")]
+use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
+use biome_js_analyze::utils::rename::{
+    find_rename_conflict, is_exported_under_its_name, rename_imported_symbol,
+    AnyJsRenamableDeclaration, RenamableNode, RenameError, RenameSymbolExtensions,
+};
 use biome_js_analyze::{analyze, analyze_with_inspect_matcher, ControlFlowGraph};
 use biome_js_formatter::context::trailing_commas::TrailingCommas;
 use biome_js_formatter::context::{
@@ -39,15 +51,27 @@ use biome_js_formatter::context::{
 };
 use biome_js_formatter::format_node;
 use biome_js_parser::JsParserOptions;
-use biome_js_semantic::{semantic_model, SemanticModelOptions};
+use biome_js_semantic::{semantic_model, SemanticModel, SemanticModelOptions};
+use biome_js_syntax::binding_ext::{AnyJsBindingDeclaration, AnyJsIdentifierBinding};
+use biome_js_syntax::parameter_ext::{AnyJsParameterList, AnyParameter};
 use biome_js_syntax::{
-    AnyJsRoot, JsFileSource, JsLanguage, JsSyntaxNode, TextRange, TextSize, TokenAtOffset,
+    AnyJsArrayElement, AnyJsArrowFunctionParameters, AnyJsBinding, AnyJsCallArgument,
+    AnyJsClassMember, AnyJsConstructorParameter, AnyJsExpression, AnyJsImportLike,
+    AnyJsLiteralExpression, AnyJsParameter, AnyJsRoot, JsCallExpression, JsClassDeclaration,
+    JsClassExportDefaultDeclaration, JsClassMemberList, JsExport, JsExportDefaultDeclarationClause,
+    JsFileSource, JsFunctionDeclaration, JsFunctionExportDefaultDeclaration, JsLanguage,
+    JsNewExpression, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, JsVariableDeclaration,
+    JsVariableDeclarationClause, JsVariableDeclarator, JsVariableStatement, TextRange, TextSize,
+    TokenAtOffset, TsDeclareFunctionDeclaration, TsEnumDeclaration, TsInterfaceDeclaration,
+    TsModuleDeclaration, TsTypeAliasDeclaration, T,
 };
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, BatchMutationExt, Direction, NodeCache};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, BatchMutationExt, Direction, NodeCache};
+use biome_text_edit::TextEdit;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::fmt::Debug;
+use std::sync::Arc;
 use tracing::{debug, debug_span, error, info, trace, trace_span};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -277,6 +301,8 @@ impl ServiceLanguage for JsLanguage {
             globals,
             preferred_quote,
             jsx_runtime,
+            require_suppression_reason: global
+                .is_some_and(|global| global.linter.suppression_reason.is_required()),
         };
 
         AnalyzerOptions {
@@ -303,6 +329,7 @@ impl ExtensionHandler for JsFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 fix_all: Some(fix_all),
+                prepare_rename: Some(prepare_rename),
                 rename: Some(rename),
                 organize_imports: Some(organize_imports),
             },
@@ -312,8 +339,17 @@ impl ExtensionHandler for JsFileHandler {
                 format_on_type: Some(format_on_type),
             },
             search: SearchCapabilities {
+                #[cfg(feature = "grit")]
                 search: Some(search),
             },
+            symbols: SymbolCapabilities {
+                document_symbols: Some(document_symbols),
+                semantic_tokens: Some(semantic_tokens),
+                inlay_hints: Some(inlay_hints),
+                signature_help: Some(signature_help),
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(selection_ranges::<JsLanguage>),
+            },
         }
     }
 }
@@ -396,6 +432,7 @@ fn debug_control_flow(parse: AnyParse, cursor: TextSize) -> String {
         &options,
         JsFileSource::default(),
         None,
+        Arc::default(),
         |_| ControlFlow::<Never>::Continue(()),
     );
 
@@ -432,6 +469,18 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                 };
             };
             let tree = params.parse.tree();
+            // The GraphQL documents embedded in template literals are linted with the GraphQL rules
+            #[cfg(feature = "graphql")]
+            let embedded_results = params
+                .workspace
+                .settings()
+                .is_some_and(|settings| !settings.graphql_linter_disabled())
+                .then(|| embedded_graphql::lint(tree.syntax(), &params));
+            #[cfg(not(feature = "graphql"))]
+            let embedded_results: Option<LintResults> = None;
+            // The expressions of the inline templates of the Angular components are linted
+            // with the correctness rules
+            let angular_results = embedded_angular::lint(tree.syntax(), &params);
             let analyzer_options = &params.workspace.analyzer_options::<JsLanguage>(
                 params.path,
                 &params.language,
@@ -475,6 +524,7 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                 analyzer_options,
                 file_source,
                 params.manifest,
+                params.js_project,
                 |signal| {
                     if let Some(mut diagnostic) = signal.diagnostic() {
                         if ignores_suppression_comment
@@ -527,7 +577,20 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                     .map(biome_diagnostics::serde::Diagnostic::new)
                     .collect::<Vec<_>>(),
             );
-            let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
+            let mut skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
+
+            if let Some(embedded_results) = embedded_results {
+                let room = (params.max_diagnostics as usize).saturating_sub(diagnostics.len());
+                errors += embedded_results.errors;
+                skipped_diagnostics += embedded_results.skipped_diagnostics
+                    + embedded_results.diagnostics.len().saturating_sub(room) as u32;
+                diagnostics.extend(embedded_results.diagnostics.into_iter().take(room));
+            }
+            let room = (params.max_diagnostics as usize).saturating_sub(diagnostics.len());
+            errors += angular_results.errors;
+            skipped_diagnostics += angular_results.skipped_diagnostics
+                + angular_results.diagnostics.len().saturating_sub(room) as u32;
+            diagnostics.extend(angular_results.diagnostics.into_iter().take(room));
 
             LintResults {
                 diagnostics,
@@ -545,10 +608,12 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest,
+        js_project,
         language,
         only,
         skip,
         suppression_reason,
+        ..
     } = params;
     debug_span!("Code actions JavaScript", range =? range, path =? path).in_scope(move || {
         let tree = parse.tree();
@@ -588,6 +653,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                 &analyzer_options,
                 source_type,
                 manifest,
+                js_project,
                 |signal| {
                     actions.extend(signal.actions().into_code_action_iter().map(|item| {
                         CodeAction {
@@ -645,6 +711,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     };
 
     let mut actions = Vec::new();
+    let mut fix_cycle_guard = FixCycleGuard::new(tree.syntax());
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
     let analyzer_options = params.workspace.analyzer_options::<JsLanguage>(
@@ -659,6 +726,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             &analyzer_options,
             file_source,
             params.manifest.clone(),
+            params.js_project.clone(),
             |signal| {
                 let current_diagnostic = signal.diagnostic();
 
@@ -729,6 +797,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                             .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
                         range,
                     });
+                    fix_cycle_guard.record(tree.syntax(), &actions)?;
                 }
             }
             None => {
@@ -769,14 +838,34 @@ pub(crate) fn format(
 
     let tree = parse.syntax();
     info!("Format file {}", biome_path.display());
-    let formatted = format_node(options, &tree)?;
-    match formatted.print() {
-        Ok(printed) => Ok(printed),
+    let formatted = format_node(options.clone(), &tree)?;
+    let printed = match formatted.print() {
+        Ok(printed) => printed,
         Err(error) => {
             error!("The file {} couldn't be formatted", biome_path.display());
-            Err(WorkspaceError::FormatError(error.into()))
+            return Err(WorkspaceError::FormatError(error.into()));
+        }
+    };
+
+    // The GraphQL documents embedded in template literals are formatted with the GraphQL formatter
+    #[cfg(feature = "graphql")]
+    if settings
+        .settings()
+        .is_some_and(|settings| !settings.graphql_formatter_disabled())
+    {
+        let graphql_options = settings.format_options::<GraphqlLanguage>(
+            biome_path,
+            &DocumentFileSource::Graphql(GraphqlFileSource::default()),
+        );
+        let file_source = document_file_source.to_js_file_source().unwrap_or_default();
+        let code =
+            embedded_graphql::format(printed.as_code(), file_source, &options, graphql_options)?;
+        if code != printed.as_code() {
+            return Ok(Printed::new(code, None, Vec::new(), Vec::new()));
         }
     }
+
+    Ok(printed)
 }
 
 #[tracing::instrument(level = "trace", skip(parse, settings))]
@@ -832,6 +921,41 @@ pub(crate) fn format_on_type(
     Ok(printed)
 }
 
+/// Returns the node of the token at `offset`, which is renamed when the cursor is at `offset`
+fn renamable_node_at(parse: &AnyParse, offset: TextSize) -> Option<JsSyntaxNode> {
+    parse
+        .syntax()
+        .descendants_tokens(Direction::Next)
+        .find(|token| token.text_range().contains(offset))
+        .and_then(|token| token.parent())
+}
+
+fn prepare_rename(
+    parse: AnyParse,
+    symbol_at: TextSize,
+) -> Result<PrepareRenameResult, WorkspaceError> {
+    let root = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+
+    let Some(node) = renamable_node_at(&parse, symbol_at) else {
+        return Err(WorkspaceError::RenameError(
+            RenameError::CannotFindDeclaration(String::new()),
+        ));
+    };
+    let name = node.text_trimmed().to_string();
+    let range = node.text_trimmed_range();
+    let declaration =
+        AnyJsRenamableDeclaration::try_from(node).map_err(WorkspaceError::RenameError)?;
+    // The references to the globals don't have a binding in the file
+    if declaration.binding(&model).is_none() {
+        return Err(WorkspaceError::RenameError(
+            RenameError::CannotFindDeclaration(name),
+        ));
+    }
+
+    Ok(PrepareRenameResult { range, name })
+}
+
 fn rename(
     _rome_path: &BiomePath,
     parse: AnyParse,
@@ -841,12 +965,7 @@ fn rename(
     let root = parse.tree();
     let model = semantic_model(&root, SemanticModelOptions::default());
 
-    if let Some(node) = parse
-        .syntax()
-        .descendants_tokens(Direction::Next)
-        .find(|token| token.text_range().contains(symbol_at))
-        .and_then(|token| token.parent())
-    {
+    if let Some(node) = renamable_node_at(&parse, symbol_at) {
         let original_name = node.text_trimmed();
         let range = node.text_range();
         match node.try_into() {
@@ -854,14 +973,26 @@ fn rename(
                 let mut batch = root.begin();
                 let result = batch.rename_any_renamable_node(&model, &node, &new_name);
                 if !result {
-                    Err(WorkspaceError::RenameError(RenameError::CannotBeRenamed {
-                        original_name: original_name.to_string(),
-                        original_range: range,
-                        new_name,
-                    }))
+                    let error = match find_rename_conflict(&model, &node, &new_name) {
+                        Some(conflict) => RenameError::NameConflict {
+                            original_name: original_name.to_string(),
+                            new_name,
+                            conflicting_range: conflict.syntax().text_trimmed_range(),
+                        },
+                        None => RenameError::CannotBeRenamed {
+                            original_name: original_name.to_string(),
+                            original_range: range,
+                            new_name,
+                        },
+                    };
+                    Err(WorkspaceError::RenameError(error))
                 } else {
                     let (range, indels) = batch.as_text_range_and_edit().unwrap_or_default();
-                    Ok(RenameResult { range, indels })
+                    Ok(RenameResult {
+                        range,
+                        indels,
+                        other_files: Vec::new(),
+                    })
                 }
             }
             Err(err) => Err(WorkspaceError::RenameError(err)),
@@ -873,6 +1004,893 @@ fn rename(
     }
 }
 
+/// Returns the name of the symbol at `symbol_at` if it's exported under its own name,
+/// in which case renaming it also renames the symbol imported by the other scripts
+pub(crate) fn exported_symbol_at(parse: &AnyParse, symbol_at: TextSize) -> Option<String> {
+    let root = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let node = renamable_node_at(parse, symbol_at)?;
+    let name = node.text_trimmed().to_string();
+    let declaration = AnyJsRenamableDeclaration::try_from(node).ok()?;
+    is_exported_under_its_name(&model, &declaration).then_some(name)
+}
+
+/// Returns the specifier of the import, the re-export, the call of `require` or the dynamic
+/// import whose specifier is at `offset`
+pub(crate) fn import_specifier_at(parse: &AnyParse, offset: TextSize) -> Option<String> {
+    let is_at_offset =
+        |token: &JsSyntaxToken| token.text_trimmed_range().contains_inclusive(offset);
+    let token = parse
+        .syntax::<JsLanguage>()
+        .descendants_tokens(Direction::Next)
+        .find(is_at_offset)?;
+    token
+        .ancestors()
+        .filter_map(AnyJsImportLike::cast)
+        .find(|import| {
+            import
+                .module_name_token()
+                .as_ref()
+                .is_some_and(is_at_offset)
+        })
+        .and_then(|import| Some(import.inner_string_text()?.to_string()))
+}
+
+/// Renames the symbol `name` in the imports of the script whose specifier refers to
+/// the script that exports the symbol, according to `is_renamed_module`.
+///
+/// Returns [None] if the script doesn't import the symbol.
+pub(crate) fn rename_imports(
+    parse: AnyParse,
+    is_renamed_module: impl Fn(&str) -> bool,
+    name: &str,
+    new_name: &str,
+) -> Result<Option<TextEdit>, WorkspaceError> {
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let mut batch = root.begin();
+    rename_imported_symbol(&mut batch, &model, is_renamed_module, name, new_name)
+        .map_err(WorkspaceError::RenameError)?;
+
+    Ok(batch.as_text_range_and_edit().map(|(_, indels)| indels))
+}
+
+fn document_symbols(parse: AnyParse) -> Vec<DocumentSymbol> {
+    let root: AnyJsRoot = parse.tree();
+    let items: Vec<_> = match &root {
+        AnyJsRoot::JsModule(module) => module.items().iter().map(AstNode::into_syntax).collect(),
+        AnyJsRoot::JsScript(script) => script
+            .statements()
+            .iter()
+            .map(AstNode::into_syntax)
+            .collect(),
+        AnyJsRoot::TsDeclarationModule(module) => {
+            module.items().iter().map(AstNode::into_syntax).collect()
+        }
+        AnyJsRoot::JsExpressionSnipped(_) => Vec::new(),
+    };
+
+    let mut symbols = Vec::new();
+    collect_js_symbols(items, &mut symbols);
+    symbols
+}
+
+/// Collects the symbols declared by `items`, the statements of a script or of a namespace
+fn collect_js_symbols(
+    items: impl IntoIterator<Item = JsSyntaxNode>,
+    symbols: &mut Vec<DocumentSymbol>,
+) {
+    for item in items {
+        let range = item.text_trimmed_range();
+        let mut declaration = item;
+        if let Some(export) = JsExport::cast_ref(&declaration) {
+            let Ok(clause) = export.export_clause() else {
+                continue;
+            };
+            declaration = clause.into_syntax();
+        }
+        if let Some(clause) = JsExportDefaultDeclarationClause::cast_ref(&declaration) {
+            let Ok(default_declaration) = clause.declaration() else {
+                continue;
+            };
+            declaration = default_declaration.into_syntax();
+        }
+
+        let variables = JsVariableStatement::cast_ref(&declaration)
+            .and_then(|statement| statement.declaration().ok())
+            .or_else(|| {
+                JsVariableDeclarationClause::cast_ref(&declaration)
+                    .and_then(|clause| clause.declaration().ok())
+            });
+        if let Some(variables) = variables {
+            collect_variable_symbols(&variables, symbols);
+        } else if let Some(symbol) = js_declaration_symbol(&declaration, range) {
+            symbols.push(symbol);
+        }
+    }
+}
+
+/// Returns the symbol of a declaration whose whole range, including the `export` keyword, is `range`
+fn js_declaration_symbol(declaration: &JsSyntaxNode, range: TextRange) -> Option<DocumentSymbol> {
+    if let Some(function) = JsFunctionDeclaration::cast_ref(declaration) {
+        let id = function.id().ok()?;
+        Some(js_symbol(
+            Some(id.syntax()),
+            SymbolKind::Function,
+            range,
+            Vec::new(),
+        ))
+    } else if let Some(function) = JsFunctionExportDefaultDeclaration::cast_ref(declaration) {
+        let id = function.id();
+        Some(js_symbol(
+            id.as_ref().map(AstNode::syntax),
+            SymbolKind::Function,
+            range,
+            Vec::new(),
+        ))
+    } else if let Some(function) = TsDeclareFunctionDeclaration::cast_ref(declaration) {
+        let id = function.id().ok()?;
+        Some(js_symbol(
+            Some(id.syntax()),
+            SymbolKind::Function,
+            range,
+            Vec::new(),
+        ))
+    } else if let Some(class) = JsClassDeclaration::cast_ref(declaration) {
+        let id = class.id().ok()?;
+        let members = class_member_symbols(class.members());
+        Some(js_symbol(
+            Some(id.syntax()),
+            SymbolKind::Class,
+            range,
+            members,
+        ))
+    } else if let Some(class) = JsClassExportDefaultDeclaration::cast_ref(declaration) {
+        let id = class.id();
+        let members = class_member_symbols(class.members());
+        Some(js_symbol(
+            id.as_ref().map(AstNode::syntax),
+            SymbolKind::Class,
+            range,
+            members,
+        ))
+    } else if let Some(interface) = TsInterfaceDeclaration::cast_ref(declaration) {
+        let id = interface.id().ok()?;
+        Some(js_symbol(
+            Some(id.syntax()),
+            SymbolKind::Interface,
+            range,
+            Vec::new(),
+        ))
+    } else if let Some(enumeration) = TsEnumDeclaration::cast_ref(declaration) {
+        let id = enumeration.id().ok()?;
+        Some(js_symbol(
+            Some(id.syntax()),
+            SymbolKind::Enum,
+            range,
+            Vec::new(),
+        ))
+    } else if let Some(alias) = TsTypeAliasDeclaration::cast_ref(declaration) {
+        let id = alias.binding_identifier().ok()?;
+        Some(js_symbol(
+            Some(id.syntax()),
+            SymbolKind::TypeAlias,
+            range,
+            Vec::new(),
+        ))
+    } else if let Some(namespace) = TsModuleDeclaration::cast_ref(declaration) {
+        let name = namespace.name().ok()?;
+        let mut children = Vec::new();
+        if let Ok(body) = namespace.body() {
+            collect_js_symbols(body.items().iter().map(AstNode::into_syntax), &mut children);
+        }
+        Some(js_symbol(
+            Some(name.syntax()),
+            SymbolKind::Namespace,
+            range,
+            children,
+        ))
+    } else {
+        None
+    }
+}
+
+/// Collects the symbols of the declarators of `declaration`, except the destructuring patterns
+fn collect_variable_symbols(
+    declaration: &JsVariableDeclaration,
+    symbols: &mut Vec<DocumentSymbol>,
+) {
+    let is_const = declaration
+        .kind()
+        .is_ok_and(|kind| kind.kind() == T![const]);
+    for declarator in declaration.declarators().iter().flatten() {
+        let Some(binding) = declarator
+            .id()
+            .ok()
+            .and_then(|id| id.as_any_js_binding().cloned())
+        else {
+            continue;
+        };
+        let initializer = declarator
+            .initializer()
+            .and_then(|initializer| initializer.expression().ok());
+        let (kind, children) = match initializer {
+            Some(
+                AnyJsExpression::JsArrowFunctionExpression(_)
+                | AnyJsExpression::JsFunctionExpression(_),
+            ) => (SymbolKind::Function, Vec::new()),
+            Some(AnyJsExpression::JsClassExpression(class)) => {
+                (SymbolKind::Class, class_member_symbols(class.members()))
+            }
+            _ if is_const => (SymbolKind::Constant, Vec::new()),
+            _ => (SymbolKind::Variable, Vec::new()),
+        };
+        symbols.push(js_symbol(
+            Some(binding.syntax()),
+            kind,
+            declarator.syntax().text_trimmed_range(),
+            children,
+        ));
+    }
+}
+
+fn class_member_symbols(members: JsClassMemberList) -> Vec<DocumentSymbol> {
+    members
+        .iter()
+        .filter_map(|member| {
+            let name = member.name().ok()??;
+            let kind = match member {
+                AnyJsClassMember::JsPropertyClassMember(_)
+                | AnyJsClassMember::TsPropertySignatureClassMember(_)
+                | AnyJsClassMember::TsInitializedPropertySignatureClassMember(_) => {
+                    SymbolKind::Property
+                }
+                _ => SymbolKind::Method,
+            };
+            Some(js_symbol(
+                Some(name.syntax()),
+                kind,
+                member.syntax().text_trimmed_range(),
+                Vec::new(),
+            ))
+        })
+        .collect()
+}
+
+/// Creates the symbol named after the `name` node, or `default` for the anonymous default exports
+fn js_symbol(
+    name: Option<&JsSyntaxNode>,
+    kind: SymbolKind,
+    range: TextRange,
+    children: Vec<DocumentSymbol>,
+) -> DocumentSymbol {
+    let (name, selection_range) = match name {
+        Some(name) => (name.text_trimmed().to_string(), name.text_trimmed_range()),
+        None => ("default".to_string(), range),
+    };
+    DocumentSymbol {
+        name,
+        kind,
+        range,
+        selection_range,
+        children,
+    }
+}
+
+fn semantic_tokens(parse: AnyParse) -> Vec<SemanticToken> {
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+
+    let mut tokens = Vec::new();
+    for binding in model.all_bindings() {
+        let identifier = binding.tree();
+        let Some((kind, mut modifiers)) = js_binding_token(&identifier) else {
+            continue;
+        };
+        if binding.is_imported() {
+            modifiers.push(SemanticTokenModifier::Imported);
+        }
+
+        tokens.extend(binding.all_references().map(|reference| SemanticToken {
+            range: reference.syntax().text_trimmed_range(),
+            kind,
+            modifiers: modifiers.clone(),
+        }));
+        modifiers.push(SemanticTokenModifier::Declaration);
+        tokens.push(SemanticToken {
+            range: identifier.syntax().text_trimmed_range(),
+            kind,
+            modifiers,
+        });
+    }
+
+    tokens.sort_by_key(|token| token.range.start());
+    tokens
+}
+
+/// Returns the kind of the tokens of a binding and of its references, along with their
+/// modifiers, from the declaration of the binding
+fn js_binding_token(
+    identifier: &AnyJsIdentifierBinding,
+) -> Option<(SemanticTokenKind, Vec<SemanticTokenModifier>)> {
+    let mut declaration = identifier.declaration()?;
+    // The bindings of a destructuring pattern are classified by the variable or the parameter
+    // that declares the pattern
+    if let Some(pattern_declaration) = declaration.parent_binding_pattern_declaration() {
+        declaration = pattern_declaration;
+    }
+
+    let token = match declaration {
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+            let is_const = declarator
+                .declaration()
+                .is_some_and(|declaration| declaration.is_const());
+            let modifiers = if is_const {
+                vec![SemanticTokenModifier::Readonly]
+            } else {
+                Vec::new()
+            };
+            (SemanticTokenKind::Variable, modifiers)
+        }
+        AnyJsBindingDeclaration::JsArrowFunctionExpression(_)
+        | AnyJsBindingDeclaration::JsFormalParameter(_)
+        | AnyJsBindingDeclaration::JsRestParameter(_)
+        | AnyJsBindingDeclaration::JsBogusParameter(_)
+        | AnyJsBindingDeclaration::TsIndexSignatureParameter(_)
+        | AnyJsBindingDeclaration::TsPropertyParameter(_) => {
+            (SemanticTokenKind::Parameter, Vec::new())
+        }
+        AnyJsBindingDeclaration::TsInferType(_)
+        | AnyJsBindingDeclaration::TsMappedType(_)
+        | AnyJsBindingDeclaration::TsTypeParameter(_) => {
+            (SemanticTokenKind::TypeParameter, Vec::new())
+        }
+        AnyJsBindingDeclaration::JsFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::JsFunctionExpression(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::JsFunctionExportDefaultDeclaration(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionExportDefaultDeclaration(_) => {
+            (SemanticTokenKind::Function, Vec::new())
+        }
+        AnyJsBindingDeclaration::TsEnumMember(_) => (
+            SemanticTokenKind::EnumMember,
+            vec![SemanticTokenModifier::Readonly],
+        ),
+        AnyJsBindingDeclaration::JsClassDeclaration(_)
+        | AnyJsBindingDeclaration::JsClassExpression(_)
+        | AnyJsBindingDeclaration::JsClassExportDefaultDeclaration(_) => {
+            (SemanticTokenKind::Class, Vec::new())
+        }
+        AnyJsBindingDeclaration::TsInterfaceDeclaration(_) => {
+            (SemanticTokenKind::Interface, Vec::new())
+        }
+        AnyJsBindingDeclaration::TsTypeAliasDeclaration(_) => (SemanticTokenKind::Type, Vec::new()),
+        AnyJsBindingDeclaration::TsEnumDeclaration(_) => (SemanticTokenKind::Enum, Vec::new()),
+        AnyJsBindingDeclaration::TsModuleDeclaration(_)
+        | AnyJsBindingDeclaration::JsNamespaceImportSpecifier(_) => {
+            (SemanticTokenKind::Namespace, Vec::new())
+        }
+        // The kind of an imported binding is only known by the imported module, so only
+        // the imports of types are distinguished from the imports of values
+        AnyJsBindingDeclaration::JsShorthandNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsBogusNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsDefaultImportSpecifier(_)
+        | AnyJsBindingDeclaration::TsImportEqualsDeclaration(_) => {
+            let kind = if identifier.is_type_only() {
+                SemanticTokenKind::Type
+            } else {
+                SemanticTokenKind::Variable
+            };
+            (kind, Vec::new())
+        }
+        AnyJsBindingDeclaration::JsCatchDeclaration(_) => (SemanticTokenKind::Variable, Vec::new()),
+        AnyJsBindingDeclaration::JsArrayBindingPatternElement(_)
+        | AnyJsBindingDeclaration::JsArrayBindingPatternRestElement(_)
+        | AnyJsBindingDeclaration::JsObjectBindingPatternProperty(_)
+        | AnyJsBindingDeclaration::JsObjectBindingPatternRest(_)
+        | AnyJsBindingDeclaration::JsObjectBindingPatternShorthandProperty(_) => return None,
+    };
+    Some(token)
+}
+
+/// Returns the names of the parameters that receive the arguments of the calls, and the
+/// types of the `const` declarations without a type annotation, inside `range`
+fn inlay_hints(parse: AnyParse, range: TextRange) -> Vec<InlayHint> {
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+
+    let mut hints = Vec::new();
+    for node in root.syntax().descendants() {
+        if node.text_trimmed_range().intersect(range).is_none() {
+            continue;
+        }
+
+        let (callee, arguments) = if let Some(call) = JsCallExpression::cast_ref(&node) {
+            (call.callee(), call.arguments().ok())
+        } else if let Some(new) = JsNewExpression::cast_ref(&node) {
+            (new.callee(), new.arguments())
+        } else {
+            if let Some(declarator) = JsVariableDeclarator::cast_ref(&node) {
+                hints.extend(variable_type_hint(&declarator));
+            }
+            continue;
+        };
+        let (Ok(callee), Some(arguments)) = (callee, arguments) else {
+            continue;
+        };
+        let Some(names) = callee_parameter_names(&model, &callee) else {
+            continue;
+        };
+
+        for (argument, name) in arguments.args().iter().zip(names) {
+            // The parameters after a spread argument can't be known
+            let Ok(AnyJsCallArgument::AnyJsExpression(argument)) = argument else {
+                break;
+            };
+            let Some(name) = name else {
+                continue;
+            };
+            // The name is redundant when the argument is a variable with the same name
+            let is_same_name = argument
+                .as_js_identifier_expression()
+                .and_then(|expression| expression.name().ok())
+                .is_some_and(|reference| reference.has_name(&name));
+            if !is_same_name {
+                hints.push(InlayHint {
+                    position: argument.syntax().text_trimmed_range().start(),
+                    label: name,
+                    kind: InlayHintKind::Parameter,
+                });
+            }
+        }
+    }
+
+    hints.retain(|hint| range.contains_inclusive(hint.position));
+    hints.sort_by_key(|hint| hint.position);
+    hints
+}
+
+/// The parameters of a function, or of the constructor of a class
+enum CalleeParameters {
+    List(AnyJsParameterList),
+    /// The parameter of an arrow function written without parentheses
+    Binding(AnyJsBinding),
+}
+
+/// Returns the declaration of the function or the class called by `callee`, and its
+/// parameters, when `callee` references a function declaration, a `const` function or a class
+/// declaration. The declaration of a class is its constructor.
+fn callee_declaration(
+    model: &SemanticModel,
+    callee: &AnyJsExpression,
+) -> Option<(JsSyntaxNode, CalleeParameters)> {
+    let reference = callee
+        .clone()
+        .omit_parentheses()
+        .as_js_identifier_expression()?
+        .name()
+        .ok()?;
+    let (node, parameters): (JsSyntaxNode, AnyJsParameterList) =
+        match model.binding(&reference)?.tree().declaration()? {
+            AnyJsBindingDeclaration::JsFunctionDeclaration(function) => (
+                function.syntax().clone(),
+                function.parameters().ok()?.items().into(),
+            ),
+            AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(function) => (
+                function.syntax().clone(),
+                function.parameters().ok()?.items().into(),
+            ),
+            AnyJsBindingDeclaration::JsClassDeclaration(class) => {
+                let constructor = class.members().iter().find_map(|member| match member {
+                    AnyJsClassMember::JsConstructorClassMember(constructor) => Some(constructor),
+                    _ => None,
+                })?;
+                (
+                    constructor.syntax().clone(),
+                    constructor.parameters().ok()?.parameters().into(),
+                )
+            }
+            AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+                if !declarator
+                    .declaration()
+                    .is_some_and(|declaration| declaration.is_const())
+                {
+                    return None;
+                }
+                let parameters: AnyJsParameterList = match declarator
+                    .initializer()?
+                    .expression()
+                    .ok()?
+                    .omit_parentheses()
+                {
+                    AnyJsExpression::JsFunctionExpression(function) => {
+                        function.parameters().ok()?.items().into()
+                    }
+                    AnyJsExpression::JsArrowFunctionExpression(function) => {
+                        match function.parameters().ok()? {
+                            AnyJsArrowFunctionParameters::AnyJsBinding(binding) => {
+                                return Some((
+                                    declarator.syntax().clone(),
+                                    CalleeParameters::Binding(binding),
+                                ));
+                            }
+                            AnyJsArrowFunctionParameters::JsParameters(parameters) => {
+                                parameters.items().into()
+                            }
+                        }
+                    }
+                    _ => return None,
+                };
+                (declarator.syntax().clone(), parameters)
+            }
+            _ => return None,
+        };
+    Some((node, CalleeParameters::List(parameters)))
+}
+
+/// Returns the names of the parameters of the function or the class called by `callee`,
+/// when `callee` references a function declaration, a `const` function or a class declaration.
+///
+/// The names stop before the rest parameter, and are `None` for the destructured parameters.
+fn callee_parameter_names(
+    model: &SemanticModel,
+    callee: &AnyJsExpression,
+) -> Option<Vec<Option<String>>> {
+    let parameters = match callee_declaration(model, callee)?.1 {
+        CalleeParameters::List(parameters) => parameters,
+        CalleeParameters::Binding(binding) => return Some(vec![binding_name(&binding)]),
+    };
+
+    let mut names = Vec::new();
+    for parameter in parameters.iter() {
+        let parameter = parameter.ok()?;
+        match &parameter {
+            AnyParameter::AnyJsParameter(AnyJsParameter::TsThisParameter(_)) => continue,
+            AnyParameter::AnyJsParameter(AnyJsParameter::JsRestParameter(_))
+            | AnyParameter::AnyJsConstructorParameter(
+                AnyJsConstructorParameter::JsRestParameter(_),
+            ) => break,
+            _ => {}
+        }
+        let name = parameter
+            .binding()
+            .and_then(|binding| binding.as_any_js_binding().and_then(binding_name));
+        names.push(name);
+    }
+    Some(names)
+}
+
+/// Returns the signature of the function or the class called by the call or the `new`
+/// expression whose arguments contain `position`, with the documentation of its JSDoc comment
+fn signature_help(parse: AnyParse, position: TextSize) -> Option<Signature> {
+    let root: AnyJsRoot = parse.tree();
+    // The innermost call is the last one, the calls are visited before their arguments
+    let (callee, arguments) = root
+        .syntax()
+        .descendants()
+        .filter_map(|node| {
+            if let Some(call) = JsCallExpression::cast_ref(&node) {
+                Some((call.callee().ok()?, call.arguments().ok()?))
+            } else {
+                let new = JsNewExpression::cast_ref(&node)?;
+                Some((new.callee().ok()?, new.arguments()?))
+            }
+        })
+        .filter(|(_, arguments)| {
+            let Ok(l_paren) = arguments.l_paren_token() else {
+                return false;
+            };
+            // The closing parenthesis is missing while the arguments are written
+            let end = match arguments.r_paren_token() {
+                Ok(r_paren) => r_paren.text_trimmed_range().start(),
+                Err(_) => arguments.syntax().text_range().end(),
+            };
+            l_paren.text_trimmed_range().end() <= position && position <= end
+        })
+        .last()?;
+
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let (declaration, parameters) = callee_declaration(&model, &callee)?;
+    let doc = jsdoc_comment(&declaration)
+        .or_else(|| {
+            // The constructors are usually documented by the comment of their class
+            let class = declaration.ancestors().find_map(JsClassDeclaration::cast)?;
+            jsdoc_comment(class.syntax())
+        })
+        .map(|comment| JsDoc::parse(&comment))
+        .unwrap_or_default();
+
+    // The label, the name and whether it's the rest parameter, of each parameter
+    let parameters: Vec<(String, Option<String>, bool)> = match parameters {
+        CalleeParameters::List(parameters) => parameters
+            .iter()
+            .flatten()
+            .filter(|parameter| {
+                !matches!(
+                    parameter,
+                    AnyParameter::AnyJsParameter(AnyJsParameter::TsThisParameter(_))
+                )
+            })
+            .map(|parameter| {
+                let is_rest = matches!(
+                    parameter,
+                    AnyParameter::AnyJsParameter(AnyJsParameter::JsRestParameter(_))
+                        | AnyParameter::AnyJsConstructorParameter(
+                            AnyJsConstructorParameter::JsRestParameter(_)
+                        )
+                );
+                let name = parameter
+                    .binding()
+                    .and_then(|binding| binding.as_any_js_binding().and_then(binding_name));
+                (parameter.syntax().text_trimmed().to_string(), name, is_rest)
+            })
+            .collect(),
+        CalleeParameters::Binding(binding) => vec![(
+            binding.syntax().text_trimmed().to_string(),
+            binding_name(&binding),
+            false,
+        )],
+    };
+
+    let argument_index = arguments
+        .args()
+        .separators()
+        .flatten()
+        .take_while(|separator| separator.text_trimmed_range().end() <= position)
+        .count();
+    let active_parameter = match parameters.last() {
+        _ if argument_index < parameters.len() => Some(argument_index),
+        // The rest parameter receives all the remaining arguments
+        Some((_, _, true)) => Some(parameters.len() - 1),
+        _ => None,
+    };
+
+    let name = callee
+        .omit_parentheses()
+        .syntax()
+        .text_trimmed()
+        .to_string();
+    let labels: Vec<_> = parameters
+        .iter()
+        .map(|(label, _, _)| label.as_str())
+        .collect();
+    Some(Signature {
+        label: format!("{name}({})", labels.join(", ")),
+        documentation: doc.description,
+        parameters: parameters
+            .iter()
+            .map(|(label, name, _)| SignatureParameter {
+                label: label.clone(),
+                documentation: name
+                    .as_ref()
+                    .and_then(|name| doc.parameters.iter().find(|(tag, _)| tag == name))
+                    .map(|(_, documentation)| documentation.clone()),
+            })
+            .collect(),
+        active_parameter: active_parameter.map(|index| index as u32),
+    })
+}
+
+/// Returns the text of the JSDoc comment of a declaration, without the `/**`, `*/` and the
+/// leading `*` of its lines. The comment is the last one before the statement, the export or
+/// the class member that contains the declaration.
+fn jsdoc_comment(declaration: &JsSyntaxNode) -> Option<String> {
+    let item = declaration.ancestors().find(|node| {
+        node.parent().is_some_and(|parent| {
+            matches!(
+                parent.kind(),
+                JsSyntaxKind::JS_STATEMENT_LIST
+                    | JsSyntaxKind::JS_MODULE_ITEM_LIST
+                    | JsSyntaxKind::JS_CLASS_MEMBER_LIST
+            )
+        })
+    })?;
+    let comment = item
+        .first_token()?
+        .leading_trivia()
+        .pieces()
+        .filter_map(|piece| piece.as_comments())
+        .last()?;
+    let text = comment.text().strip_prefix("/**")?.strip_suffix("*/")?;
+    let lines: Vec<_> = text
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            line.strip_prefix('*').unwrap_or(line).trim()
+        })
+        .collect();
+    Some(lines.join("\n").trim().to_string())
+}
+
+/// The description and the `@param` tags of a JSDoc comment
+#[derive(Debug, Default)]
+struct JsDoc {
+    /// The text before the first tag
+    description: Option<String>,
+    /// The names of the parameters, and their type and description
+    parameters: Vec<(String, String)>,
+}
+
+impl JsDoc {
+    fn parse(comment: &str) -> Self {
+        let mut doc = Self::default();
+        let mut description = Vec::new();
+        let mut tags: Vec<String> = Vec::new();
+        for line in comment.lines() {
+            if line.starts_with('@') {
+                tags.push(line.to_string());
+            } else if let Some(tag) = tags.last_mut() {
+                tag.push('\n');
+                tag.push_str(line);
+            } else {
+                description.push(line);
+            }
+        }
+        let description = description.join("\n").trim().to_string();
+        doc.description = (!description.is_empty()).then_some(description);
+
+        for tag in &tags {
+            let Some(tag) = tag
+                .strip_prefix("@param")
+                .or_else(|| tag.strip_prefix("@arg"))
+                .filter(|tag| tag.starts_with(char::is_whitespace))
+            else {
+                continue;
+            };
+            let tag = tag.trim_start();
+            // The type is between braces, which can be nested as in `{{ a: number }}`
+            let (type_annotation, tag) = if tag.starts_with('{') {
+                let mut depth = 0;
+                let Some(end) = tag.find(|c: char| {
+                    match c {
+                        '{' => depth += 1,
+                        '}' => depth -= 1,
+                        _ => {}
+                    }
+                    depth == 0
+                }) else {
+                    continue;
+                };
+                (Some(&tag[1..end]), tag[end + 1..].trim_start())
+            } else {
+                (None, tag)
+            };
+            let (name, description) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            // The optional parameters are written `[name]` or `[name=default]`
+            let name = name.trim_start_matches('[').trim_end_matches(']');
+            let name = name.split_once('=').map_or(name, |(name, _)| name);
+            let description = description.trim();
+            let description = description.strip_prefix("- ").unwrap_or(description);
+
+            let documentation = match type_annotation {
+                Some(type_annotation) if description.is_empty() => format!("`{type_annotation}`"),
+                Some(type_annotation) => format!("`{type_annotation}` {description}"),
+                None => description.to_string(),
+            };
+            if !name.is_empty() && !documentation.is_empty() {
+                doc.parameters.push((name.to_string(), documentation));
+            }
+        }
+        doc
+    }
+}
+
+fn binding_name(binding: &AnyJsBinding) -> Option<String> {
+    let name = binding.as_js_identifier_binding()?.name_token().ok()?;
+    Some(name.text_trimmed().to_string())
+}
+
+/// Returns the type of a `const` declaration without a type annotation, when it can be
+/// inferred from the syntax of its initializer alone
+fn variable_type_hint(declarator: &JsVariableDeclarator) -> Option<InlayHint> {
+    if declarator.variable_annotation().is_some()
+        || !declarator
+            .declaration()
+            .is_some_and(|declaration| declaration.is_const())
+    {
+        return None;
+    }
+    let id = declarator.id().ok()?;
+    let id = id.as_any_js_binding()?.as_js_identifier_binding()?;
+    let initializer = declarator.initializer()?.expression().ok()?;
+
+    let label = match initializer.omit_parentheses() {
+        // The type of an instance is its class, with the type arguments of the `new` expression
+        AnyJsExpression::JsNewExpression(new) => {
+            let callee = new.callee().ok()?;
+            if !matches!(
+                callee,
+                AnyJsExpression::JsIdentifierExpression(_)
+                    | AnyJsExpression::JsStaticMemberExpression(_)
+            ) {
+                return None;
+            }
+            let type_arguments = new
+                .type_arguments()
+                .map(|arguments| arguments.syntax().text_trimmed().to_string())
+                .unwrap_or_default();
+            format!("{}{type_arguments}", callee.syntax().text_trimmed())
+        }
+        // The type of an array is only inferred when all its elements widen to the same type
+        AnyJsExpression::JsArrayExpression(array) => {
+            let mut element_type = None;
+            for element in array.elements() {
+                let AnyJsArrayElement::AnyJsExpression(element) = element.ok()? else {
+                    return None;
+                };
+                let widened_type = widened_literal_type(&element)?;
+                if *element_type.get_or_insert(widened_type) != widened_type {
+                    return None;
+                }
+            }
+            format!("{}[]", element_type?)
+        }
+        // The type of a literal is the literal itself, which is already shown by the initializer
+        _ => return None,
+    };
+
+    Some(InlayHint {
+        position: id.syntax().text_trimmed_range().end(),
+        label,
+        kind: InlayHintKind::Type,
+    })
+}
+
+/// Returns the type of the values of a literal, for example `string` for `"a"`
+fn widened_literal_type(expression: &AnyJsExpression) -> Option<&'static str> {
+    let widened_type = match expression {
+        AnyJsExpression::AnyJsLiteralExpression(literal) => match literal {
+            AnyJsLiteralExpression::JsBigintLiteralExpression(_) => "bigint",
+            AnyJsLiteralExpression::JsBooleanLiteralExpression(_) => "boolean",
+            AnyJsLiteralExpression::JsNumberLiteralExpression(_) => "number",
+            AnyJsLiteralExpression::JsRegexLiteralExpression(_) => "RegExp",
+            AnyJsLiteralExpression::JsStringLiteralExpression(_) => "string",
+            AnyJsLiteralExpression::JsNullLiteralExpression(_) => return None,
+        },
+        AnyJsExpression::JsTemplateExpression(template) if template.tag().is_none() => "string",
+        _ => return None,
+    };
+    Some(widened_type)
+}
+
+/// Returns the folding ranges of the bodies of the functions, of the classes, of the
+/// interfaces, of the enums and of the namespaces, of the consecutive imports, and of the comments
+fn folding_ranges(parse: AnyParse) -> Vec<FoldingRange> {
+    let root = parse.syntax::<JsLanguage>();
+    let mut ranges = Vec::new();
+    for node in root.descendants() {
+        match node.kind() {
+            JsSyntaxKind::JS_FUNCTION_BODY
+            | JsSyntaxKind::JS_CLASS_DECLARATION
+            | JsSyntaxKind::JS_CLASS_EXPRESSION
+            | JsSyntaxKind::JS_CLASS_EXPORT_DEFAULT_DECLARATION
+            | JsSyntaxKind::TS_INTERFACE_DECLARATION
+            | JsSyntaxKind::TS_ENUM_DECLARATION
+            | JsSyntaxKind::TS_MODULE_BLOCK => ranges.extend(block_folding_range(&node)),
+            JsSyntaxKind::JS_MODULE_ITEM_LIST => {
+                let mut imports = node
+                    .children()
+                    .skip_while(|item| item.kind() != JsSyntaxKind::JS_IMPORT)
+                    .take_while(|item| item.kind() == JsSyntaxKind::JS_IMPORT);
+                let Some(first) = imports.next() else {
+                    continue;
+                };
+                let last = imports.last().unwrap_or_else(|| first.clone());
+                ranges.push(FoldingRange {
+                    range: first.text_trimmed_range().cover(last.text_trimmed_range()),
+                    kind: FoldingRangeKind::Imports,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    push_comment_folding_ranges(&root, &mut ranges);
+    ranges.sort_by_key(|folding_range| folding_range.range.start());
+    ranges
+}
+
 pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
     let mut tree: AnyJsRoot = parse.tree();
 
@@ -888,6 +1906,7 @@ pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult,
         &AnalyzerOptions::default(),
         JsFileSource::default(),
         None,
+        Arc::default(),
         |signal| {
             for action in signal.actions() {
                 if action.is_suppression() {
@@ -1,9 +1,10 @@
 use std::borrow::Cow;
 use std::ffi::OsStr;
 
+use super::syntax_ranges::{block_folding_range, push_comment_folding_ranges, selection_ranges};
 use super::{
     is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource,
-    ExtensionHandler, ParseResult, SearchCapabilities,
+    ExtensionHandler, FixCycleGuard, ParseResult, SearchCapabilities, SymbolCapabilities,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::file_handlers::DebugCapabilities;
@@ -16,8 +17,8 @@ use crate::settings::{
     ServiceLanguage, Settings, WorkspaceSettingsHandle,
 };
 use crate::workspace::{
-    CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, OrganizeImportsResult,
-    PullActionsResult,
+    CodeAction, DocumentSymbol, FixAction, FixFileMode, FixFileResult, FoldingRange,
+    GetSyntaxTreeResult, OrganizeImportsResult, PullActionsResult, SymbolKind,
 };
 use crate::{extension_error, WorkspaceError};
 use biome_analyze::options::PreferredQuote;
@@ -31,11 +32,16 @@ use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Seve
 use biome_formatter::{FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
 use biome_fs::{BiomePath, ConfigName, ROME_JSON};
 use biome_json_analyze::analyze;
+use biome_json_analyze::utils::is_tsconfig_path;
 use biome_json_formatter::context::{JsonFormatOptions, TrailingCommas};
 use biome_json_formatter::format_node;
 use biome_json_parser::JsonParserOptions;
-use biome_json_syntax::{JsonFileSource, JsonLanguage, JsonRoot, JsonSyntaxNode};
+use biome_json_schema::validate;
+use biome_json_syntax::{
+    AnyJsonValue, JsonFileSource, JsonLanguage, JsonRoot, JsonSyntaxKind, JsonSyntaxNode,
+};
 use biome_parser::AnyParse;
+use biome_project::{Manifest, OsTsConfigHost, ResolvedTsConfig, TsConfigJson};
 use biome_rowan::{AstNode, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
 use tracing::{debug_span, error, trace, trace_span};
@@ -56,6 +62,7 @@ pub struct JsonFormatterSettings {
 pub struct JsonParserSettings {
     pub allow_comments: Option<bool>,
     pub allow_trailing_commas: Option<bool>,
+    pub json5: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -141,6 +148,8 @@ impl ServiceLanguage for JsonLanguage {
             globals: vec![],
             preferred_quote: PreferredQuote::Double,
             jsx_runtime: Default::default(),
+            require_suppression_reason: global
+                .is_some_and(|global| global.linter.suppression_reason.is_required()),
         };
         AnalyzerOptions {
             configuration,
@@ -165,6 +174,7 @@ impl ExtensionHandler for JsonFileHandler {
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
+                prepare_rename: None,
                 rename: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
@@ -174,7 +184,15 @@ impl ExtensionHandler for JsonFileHandler {
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities::default(),
+            symbols: SymbolCapabilities {
+                document_symbols: Some(document_symbols),
+                semantic_tokens: None,
+                inlay_hints: None,
+                signature_help: None,
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(selection_ranges::<JsonLanguage>),
+            },
         }
     }
 }
@@ -194,7 +212,7 @@ fn parse(
         let parser = settings.map(|s| &s.languages.json.parser);
         let overrides = settings.map(|s| &s.override_settings);
         let optional_json_file_source = file_source.to_json_file_source();
-        let options = JsonParserOptions {
+        let mut options = JsonParserOptions {
             allow_comments: parser.and_then(|p| p.allow_comments).map_or_else(
                 || optional_json_file_source.map_or(false, |x| x.allow_comments()),
                 |value| value,
@@ -203,7 +221,15 @@ fn parse(
                 || optional_json_file_source.map_or(false, |x| x.allow_trailing_commas()),
                 |value| value,
             ),
+            json5: false,
+            json_lines: optional_json_file_source.map_or(false, |x| x.is_json_lines()),
         };
+        if parser.and_then(|p| p.json5).map_or_else(
+            || optional_json_file_source.map_or(false, |x| x.is_json5()),
+            |value| value,
+        ) {
+            options = options.with_json5();
+        }
         if let Some(overrides) = overrides {
             overrides.to_override_json_parser_options(biome_path, options)
         } else {
@@ -364,6 +390,24 @@ fn lint(params: LintParams) -> LintResults {
                         .map(biome_diagnostics::serde::Diagnostic::new)
                         .collect::<Vec<_>>(),
                 );
+            } else if params.categories.contains(RuleCategory::Lint) {
+                // otherwise, we validate the file against its JSON schema, if it has one
+                let configured = params
+                    .workspace
+                    .settings()
+                    .and_then(|settings| settings.json_schemas.schema_for(params.path.as_path()));
+                if let Some(schema) =
+                    params
+                        .json_schemas
+                        .resolve(params.path.as_path(), &root, configured)
+                {
+                    diagnostics.extend(
+                        validate(&schema, &root)
+                            .into_iter()
+                            .map(biome_diagnostics::serde::Diagnostic::new)
+                            .collect::<Vec<_>>(),
+                    );
+                }
             }
 
             let filter = AnalysisFilter {
@@ -386,8 +430,14 @@ fn lint(params: LintParams) -> LintResults {
                 .count();
             let skipped_diagnostics = diagnostic_count - diagnostics.len() as u32;
 
-            let (_, analyze_diagnostics) =
-                analyze(&root, filter, analyzer_options, file_source, |signal| {
+            let tsconfig = resolve_tsconfig(params.path, &root);
+            let (_, analyze_diagnostics) = analyze(
+                &root,
+                filter,
+                analyzer_options,
+                file_source,
+                tsconfig,
+                |signal| {
                     if let Some(mut diagnostic) = signal.diagnostic() {
                         if ignores_suppression_comment
                             && diagnostic.category() == Some(category!("suppressions/unused"))
@@ -430,7 +480,8 @@ fn lint(params: LintParams) -> LintResults {
                     }
 
                     ControlFlow::<Never>::Continue(())
-                });
+                },
+            );
 
             diagnostics.extend(
                 analyze_diagnostics
@@ -454,10 +505,12 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest: _,
+        js_project: _,
         language,
         skip,
         only,
         suppression_reason: _,
+        ..
     } = params;
 
     debug_span!("Code actions JSON",  range =? range, path =? path).in_scope(move || {
@@ -490,19 +543,27 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
             };
 
             trace!("JSON runs the analyzer");
-            analyze(&tree, filter, &analyzer_options, file_source, |signal| {
-                actions.extend(signal.actions().into_code_action_iter().map(|item| {
-                    CodeAction {
-                        category: item.category.clone(),
-                        rule_name: item
-                            .rule_name
-                            .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
-                        suggestion: item.suggestion,
-                    }
-                }));
+            let tsconfig = resolve_tsconfig(path, &tree);
+            analyze(
+                &tree,
+                filter,
+                &analyzer_options,
+                file_source,
+                tsconfig,
+                |signal| {
+                    actions.extend(signal.actions().into_code_action_iter().map(|item| {
+                        CodeAction {
+                            category: item.category.clone(),
+                            rule_name: item
+                                .rule_name
+                                .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                            suggestion: item.suggestion,
+                        }
+                    }));
 
-                ControlFlow::<Never>::Continue(())
-            });
+                    ControlFlow::<Never>::Continue(())
+                },
+            );
 
             PullActionsResult { actions }
         })
@@ -549,6 +610,7 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     };
 
     let mut actions = Vec::new();
+    let mut fix_cycle_guard = FixCycleGuard::new(tree.syntax());
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
     let analyzer_options = params.workspace.analyzer_options::<JsonLanguage>(
@@ -556,49 +618,58 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
         &params.document_file_source,
         params.suppression_reason,
     );
+    // The fixes don't change the paths referenced by the configuration
+    let tsconfig = resolve_tsconfig(params.biome_path, &tree);
     loop {
-        let (action, _) = analyze(&tree, filter, &analyzer_options, file_source, |signal| {
-            let current_diagnostic = signal.diagnostic();
-
-            if let Some(diagnostic) = current_diagnostic.as_ref() {
-                if is_diagnostic_error(diagnostic, rules.as_deref()) {
-                    errors += 1;
+        let (action, _) = analyze(
+            &tree,
+            filter,
+            &analyzer_options,
+            file_source,
+            tsconfig.clone(),
+            |signal| {
+                let current_diagnostic = signal.diagnostic();
+
+                if let Some(diagnostic) = current_diagnostic.as_ref() {
+                    if is_diagnostic_error(diagnostic, rules.as_deref()) {
+                        errors += 1;
+                    }
                 }
-            }
 
-            for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
-                    continue;
-                }
+                for action in signal.actions() {
+                    // suppression actions should not be part of the fixes (safe or suggested)
+                    if action.is_suppression() {
+                        continue;
+                    }
 
-                match params.fix_file_mode {
-                    FixFileMode::SafeFixes => {
-                        if action.applicability == Applicability::MaybeIncorrect {
-                            skipped_suggested_fixes += 1;
+                    match params.fix_file_mode {
+                        FixFileMode::SafeFixes => {
+                            if action.applicability == Applicability::MaybeIncorrect {
+                                skipped_suggested_fixes += 1;
+                            }
+                            if action.applicability == Applicability::Always {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
-                        if action.applicability == Applicability::Always {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                        FixFileMode::SafeAndUnsafeFixes => {
+                            if matches!(
+                                action.applicability,
+                                Applicability::Always | Applicability::MaybeIncorrect
+                            ) {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
-                    }
-                    FixFileMode::SafeAndUnsafeFixes => {
-                        if matches!(
-                            action.applicability,
-                            Applicability::Always | Applicability::MaybeIncorrect
-                        ) {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                        FixFileMode::ApplySuppressions => {
+                            // TODO: implement once a JSON suppression action is available
                         }
                     }
-                    FixFileMode::ApplySuppressions => {
-                        // TODO: implement once a JSON suppression action is available
-                    }
                 }
-            }
 
-            ControlFlow::Continue(())
-        });
+                ControlFlow::Continue(())
+            },
+        );
 
         match action {
             Some(action) => {
@@ -623,6 +694,7 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
                             .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
                         range,
                     });
+                    fix_cycle_guard.record(tree.syntax(), &actions)?;
                 }
             }
             None => {
@@ -650,6 +722,61 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     }
 }
 
+/// Resolves the `extends` chain and the `paths` of `root`, if it's a TypeScript configuration
+fn resolve_tsconfig(path: &BiomePath, root: &JsonRoot) -> Option<ResolvedTsConfig> {
+    if !is_tsconfig_path(path.as_path()) {
+        return None;
+    }
+    let (tsconfig, _) = TsConfigJson::deserialize_manifest(root).consume();
+    Some(ResolvedTsConfig::resolve(
+        path.as_path(),
+        &tsconfig?,
+        &OsTsConfigHost,
+    ))
+}
+
+/// Returns the keys of the top-level object, the nested keys aren't listed
+fn document_symbols(parse: AnyParse) -> Vec<DocumentSymbol> {
+    let root: JsonRoot = parse.tree();
+    let Ok(AnyJsonValue::JsonObjectValue(object)) = root.value() else {
+        return Vec::new();
+    };
+    object
+        .json_member_list()
+        .iter()
+        .flatten()
+        .filter_map(|member| {
+            let name = member.name().ok()?;
+            Some(DocumentSymbol {
+                name: name.inner_string_text().ok()?.to_string(),
+                kind: SymbolKind::Key,
+                range: member.syntax().text_trimmed_range(),
+                selection_range: name.syntax().text_trimmed_range(),
+                children: Vec::new(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the folding ranges of the objects, of the arrays and of the comments
+fn folding_ranges(parse: AnyParse) -> Vec<FoldingRange> {
+    let root = parse.syntax::<JsonLanguage>();
+    let mut ranges: Vec<_> = root
+        .descendants()
+        .filter(|node| {
+            matches!(
+                node.kind(),
+                JsonSyntaxKind::JSON_OBJECT_VALUE | JsonSyntaxKind::JSON_ARRAY_VALUE
+            )
+        })
+        .filter_map(|node| block_folding_range(&node))
+        .collect();
+
+    push_comment_folding_ranges(&root, &mut ranges);
+    ranges.sort_by_key(|folding_range| folding_range.range.start());
+    ranges
+}
+
 fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
     Ok(OrganizeImportsResult {
         code: parse.syntax::<JsonLanguage>().to_string(),
@@ -0,0 +1,740 @@
+use crate::file_handlers::{
+    javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
+    ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
+    ParserCapabilities, SearchCapabilities, SymbolCapabilities,
+};
+use crate::settings::{Settings, WorkspaceSettingsHandle};
+use crate::workspace::{
+    DocumentFileSource, FixFileResult, OrganizeImportsResult, PullActionsResult,
+};
+use crate::WorkspaceError;
+use biome_formatter::{FormatOptions, Printed};
+use biome_fs::BiomePath;
+use biome_html_parser::{parse_html_with_cache, HtmlParserOptions};
+use biome_html_syntax::HtmlLanguage;
+use biome_js_formatter::context::JsFormatOptions;
+use biome_js_parser::{parse_js_with_cache, JsParserOptions};
+use biome_js_syntax::{JsFileSource, JsLanguage};
+use biome_parser::AnyParse;
+use biome_rowan::NodeCache;
+use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use regex::Regex;
+use std::ops::Range;
+use std::sync::LazyLock;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct MdxFileHandler;
+
+// The ATX headings, such as `## Installation`, with their optional closing sequence
+static ATX_HEADING: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^(?<hashes>#{1,6})(?:[ \t]+(?<content>.*?))?(?:[ \t]+#+)?[ \t]*$"#).unwrap()
+});
+
+// The items of the bullet lists that use `*` or `+` as marker
+static BULLET_ITEM: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^(?<indent>[ \t]*)[*+](?<space>[ \t]+)(?<content>\S.*)$"#).unwrap()
+});
+
+static THEMATIC_BREAK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^ {0,3}(?:(?:\*[ \t]*){3,}|(?:-[ \t]*){3,}|(?:_[ \t]*){3,})$"#).unwrap()
+});
+
+// The opening tags of the JSX components, such as `<Chart` or `<Tabs.Item`
+static COMPONENT_TAG: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"^<(?<name>[A-Z][\w$]*(?:\.[A-Za-z_$][\w$]*)*)[\s/>]"#).unwrap());
+
+impl ExtensionHandler for MdxFileHandler {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            parser: ParserCapabilities { parse: Some(parse) },
+            debug: DebugCapabilities {
+                debug_syntax_tree: None,
+                debug_control_flow: None,
+                debug_formatter_ir: None,
+            },
+            analyzer: AnalyzerCapabilities {
+                lint: Some(lint),
+                code_actions: Some(code_actions),
+                prepare_rename: None,
+                rename: None,
+                fix_all: Some(fix_all),
+                organize_imports: Some(organize_imports),
+            },
+            formatter: FormatterCapabilities {
+                format: Some(format),
+                format_range: None,
+                format_on_type: None,
+            },
+            search: SearchCapabilities::default(),
+            symbols: SymbolCapabilities {
+                document_symbols: None,
+                semantic_tokens: None,
+                inlay_hints: None,
+                signature_help: None,
+                folding_ranges: None,
+                selection_ranges: None,
+            },
+        }
+    }
+}
+
+/// The kinds of the blocks of an MDX document
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum MdxBlockKind {
+    /// The YAML frontmatter, between two `---` lines at the start of the document
+    Frontmatter,
+    /// A fenced code block, between two lines of backticks or tildes
+    Code,
+    /// The `import` and `export` statements, from a line that starts with one of them
+    /// to the next blank line
+    Esm,
+    /// The Markdown content, which contains the JSX elements and the `{expressions}`
+    Markdown,
+}
+
+/// A block of an MDX document. Its range covers whole lines, including their line breaks.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct MdxBlock {
+    kind: MdxBlockKind,
+    range: Range<usize>,
+}
+
+impl MdxBlock {
+    /// It splits the `document` into blocks, in source order. The consecutive lines of
+    /// Markdown content are a single block.
+    fn extract(document: &str) -> Vec<Self> {
+        let mut lines = Vec::new();
+        let mut start = 0;
+        for line in document.split_inclusive('\n') {
+            lines.push(start..start + line.len());
+            start += line.len();
+        }
+        let text = |index: usize| document[lines[index].clone()].trim_end_matches(['\r', '\n']);
+        let block = |kind, first: usize, last: usize| Self {
+            kind,
+            range: lines[first].start..lines[last].end,
+        };
+
+        let mut blocks: Vec<Self> = Vec::new();
+        let mut index = 0;
+        if !lines.is_empty() && text(0) == "---" {
+            if let Some(end) = (1..lines.len()).find(|&index| text(index) == "---") {
+                blocks.push(block(MdxBlockKind::Frontmatter, 0, end));
+                index = end + 1;
+            }
+        }
+
+        while index < lines.len() {
+            let line = text(index);
+            if let Some((marker, length)) = code_fence(line) {
+                let end = (index + 1..lines.len())
+                    .find(|&index| {
+                        code_fence(text(index)).is_some_and(|(closing, closing_length)| {
+                            closing == marker
+                                && closing_length >= length
+                                && text(index).trim().chars().all(|char| char == marker)
+                        })
+                    })
+                    .unwrap_or(lines.len() - 1);
+                blocks.push(block(MdxBlockKind::Code, index, end));
+                index = end + 1;
+                continue;
+            }
+
+            let follows_blank_line = index == 0 || text(index - 1).trim().is_empty();
+            if follows_blank_line && (line.starts_with("import ") || line.starts_with("export ")) {
+                let end = (index + 1..lines.len())
+                    .find(|&index| text(index).trim().is_empty())
+                    .unwrap_or(lines.len());
+                blocks.push(block(MdxBlockKind::Esm, index, end - 1));
+                index = end;
+                continue;
+            }
+
+            match blocks.last_mut() {
+                Some(last) if last.kind == MdxBlockKind::Markdown => {
+                    last.range.end = lines[index].end;
+                }
+                _ => blocks.push(block(MdxBlockKind::Markdown, index, index)),
+            }
+            index += 1;
+        }
+
+        blocks
+    }
+}
+
+/// Returns the character and the length of the code fence that opens or closes a fenced code block
+fn code_fence(line: &str) -> Option<(char, usize)> {
+    let content = line.trim_start_matches(' ');
+    if line.len() - content.len() > 3 {
+        return None;
+    }
+    let marker = content
+        .chars()
+        .next()
+        .filter(|char| matches!(char, '`' | '~'))?;
+    let length = content.chars().take_while(|char| *char == marker).count();
+    (length >= 3).then_some((marker, length))
+}
+
+/// A statement of the program of an MDX document
+enum Statement {
+    /// The range of an expression, without its braces
+    Expression(Range<usize>),
+    /// The range of the name of a component, after the `<` of its opening tag
+    Component(Range<usize>),
+}
+
+/// Returns the expressions and the components of the Markdown `block` of the `document`,
+/// in source order. The content of the inline code spans is skipped.
+fn statements(document: &str, block: Range<usize>) -> Vec<Statement> {
+    let bytes = document.as_bytes();
+    let mut statements = Vec::new();
+    let mut index = block.start;
+    while index < block.end {
+        match bytes[index] {
+            b'`' => {
+                let length = bytes[index..block.end]
+                    .iter()
+                    .take_while(|byte| **byte == b'`')
+                    .count();
+                let fence = &document[index..index + length];
+                index = document[index + length..block.end]
+                    .find(fence)
+                    .map_or(index + length, |end| index + length + end + length);
+                continue;
+            }
+            b'{' => {
+                if let Some(end) = closing_brace(document, index, block.end) {
+                    statements.push(Statement::Expression(index + 1..end));
+                    index = end + 1;
+                    continue;
+                }
+            }
+            b'<' => {
+                if let Some(name) = COMPONENT_TAG
+                    .captures(&document[index..block.end])
+                    .and_then(|captures| captures.name("name"))
+                {
+                    statements.push(Statement::Component(
+                        index + name.start()..index + name.end(),
+                    ));
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    statements
+}
+
+/// Returns the position of the brace that closes the one at `open`, skipping the strings
+fn closing_brace(document: &str, open: usize, end: usize) -> Option<usize> {
+    let bytes = document.as_bytes();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut index = open;
+    while index < end {
+        let byte = bytes[index];
+        match quote {
+            Some(_) if byte == b'\\' => index += 1,
+            Some(delimiter) if byte == delimiter => quote = None,
+            Some(_) => {}
+            None => match byte {
+                b'"' | b'\'' | b'`' => quote = Some(byte),
+                b'{' => depth += 1,
+                b'}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(index);
+                    }
+                }
+                _ => {}
+            },
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Returns the program of the MDX `document`, which contains its JavaScript code.
+///
+/// The program has the same length and the same line breaks as the document, so its ranges are
+/// the ones of the document. The `import` and `export` statements are kept as they are,
+/// the rest of the document is replaced by spaces, and:
+/// - each expression becomes a statement, for example `{count}` becomes `;(count)`;
+/// - each component becomes a statement, for example `<Chart />` becomes `;Chart;  `,
+///   so that its import is used.
+///
+/// The expressions that aren't valid JavaScript expressions are skipped.
+fn program(document: &str) -> String {
+    let file_source = JsFileSource::mdx();
+    let mut program = String::with_capacity(document.len());
+    for character in document.chars() {
+        match character {
+            '\n' | '\r' => program.push(character),
+            _ => program.push_str(&" ".repeat(character.len_utf8())),
+        }
+    }
+
+    let bytes = document.as_bytes();
+    // The end of the previous statement, and whether it ends with a semicolon
+    let mut last_end = 0;
+    let mut terminated = true;
+    for block in MdxBlock::extract(document) {
+        match block.kind {
+            MdxBlockKind::Esm => {
+                program.replace_range(block.range.clone(), &document[block.range.clone()]);
+                last_end = block.range.end;
+                terminated = false;
+            }
+            MdxBlockKind::Markdown => {
+                for statement in statements(document, block.range) {
+                    match statement {
+                        // The expression is wrapped in parentheses instead of braces, and it's
+                        // separated from the previous statement by a semicolon
+                        Statement::Expression(expression) => {
+                            let open = expression.start - 1;
+                            if open < last_end {
+                                continue;
+                            }
+                            let separator = separator(document, last_end, open);
+                            if separator.is_none() && !terminated {
+                                continue;
+                            }
+                            let source = &document[expression.clone()];
+                            let parse = biome_js_parser::parse(
+                                &format!("({source})"),
+                                file_source,
+                                JsParserOptions::default(),
+                            );
+                            if parse.has_errors() {
+                                continue;
+                            }
+                            if let Some(separator) = separator {
+                                program.replace_range(separator..separator + 1, ";");
+                            }
+                            program.replace_range(open..open + 1, "(");
+                            program.replace_range(expression.clone(), source);
+                            program.replace_range(expression.end..expression.end + 1, ")");
+                            last_end = expression.end + 1;
+                            terminated = false;
+                        }
+                        // The `<` and the character that follows the name become semicolons,
+                        // unless it's a line break
+                        Statement::Component(name) => {
+                            if name.start - 1 < last_end {
+                                continue;
+                            }
+                            program.replace_range(name.start - 1..name.start, ";");
+                            program.replace_range(name.clone(), &document[name.clone()]);
+                            terminated = !matches!(bytes[name.end], b'\n' | b'\r');
+                            if terminated {
+                                program.replace_range(name.end..name.end + 1, ";");
+                            }
+                            last_end = name.end + 1;
+                        }
+                    }
+                }
+            }
+            MdxBlockKind::Frontmatter | MdxBlockKind::Code => {}
+        }
+    }
+
+    program
+}
+
+/// Returns the position of the semicolon that separates a statement that starts at `start` from
+/// the previous one, which ends at `last_end`: the last position between them that isn't
+/// a line break.
+fn separator(document: &str, last_end: usize, start: usize) -> Option<usize> {
+    (last_end..start)
+        .rev()
+        .find(|&index| !matches!(document.as_bytes()[index], b'\n' | b'\r'))
+}
+
+fn parse_program(program: &str) -> AnyParse {
+    parse_js_with_cache(
+        program,
+        JsFileSource::mdx(),
+        JsParserOptions::default(),
+        &mut NodeCache::default(),
+    )
+    .into()
+}
+
+/// Applies the changes between the `program` of the `document` and the `changed` program
+/// to the document.
+///
+/// The changes that would delete the Markdown content, which isn't part of the program, are skipped.
+fn apply_program_changes(document: &str, program: &str, changed: &str) -> String {
+    let edit = TextEdit::from_unicode_words(program, changed);
+    let mut output = String::with_capacity(document.len());
+    let mut position = 0;
+    for op in edit.iter() {
+        match op {
+            CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                let end = position + usize::from(range.len());
+                output.push_str(&document[position..end]);
+                position = end;
+            }
+            CompressedOp::DiffOp(op @ DiffOp::Insert { .. }) => {
+                output.push_str(op.text(&edit));
+            }
+            CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                let end = position + usize::from(range.len());
+                if document[position..end] != program[position..end] {
+                    output.push_str(&document[position..end]);
+                }
+                position = end;
+            }
+            CompressedOp::EqualLines { line_count } => {
+                for line in program[position..]
+                    .split_inclusive('\n')
+                    .take(line_count.get() as usize + 1)
+                {
+                    output.push_str(&document[position..position + line.len()]);
+                    position += line.len();
+                }
+            }
+        }
+    }
+    output.push_str(&document[position..]);
+    output
+}
+
+/// An MDX file is parsed with the HTML parser, so that the capabilities have access to the
+/// whole document. The JavaScript code of the document is parsed as a single program by the
+/// capabilities that need it.
+fn parse(
+    _rome_path: &BiomePath,
+    _file_source: DocumentFileSource,
+    text: &str,
+    _settings: Option<&Settings>,
+    cache: &mut NodeCache,
+) -> ParseResult {
+    let parse = parse_html_with_cache(text, cache, HtmlParserOptions::default());
+
+    ParseResult {
+        // The syntax errors of the JavaScript code are reported by the linter. The ones of the
+        // Markdown content aren't, because it isn't HTML.
+        any_parse: AnyParse::new(parse.syntax().as_send().unwrap(), Vec::new()),
+        language: Some(JsFileSource::mdx().into()),
+    }
+}
+
+/// Returns the text of the MDX document
+fn document(parse: &AnyParse) -> String {
+    parse.syntax::<HtmlLanguage>().to_string()
+}
+
+/// It formats the whole document:
+/// - the `import` and `export` statements with the JavaScript formatter;
+/// - the structure of the Markdown content: the ATX headings are separated from the rest of the
+///   content by a blank line and lose their closing sequence, the bullet lists use `-` as marker,
+///   the trailing whitespace and the consecutive blank lines are removed.
+///
+/// The frontmatter, the fenced code blocks and the line breaks of the hard line breaks are left
+/// as they are.
+#[tracing::instrument(level = "trace", skip(parse, settings))]
+fn format(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let document = document(&parse);
+    let format_with_errors = settings
+        .settings()
+        .is_some_and(|settings| settings.formatter().format_with_errors);
+    if !format_with_errors && parse_program(&program(&document)).has_errors() {
+        return Err(WorkspaceError::format_with_errors_disabled());
+    }
+    let options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
+
+    let output = format_document(&document, &options)?;
+
+    Ok(Printed::new(output, None, Vec::new(), Vec::new()))
+}
+
+fn format_document(document: &str, options: &JsFormatOptions) -> Result<String, WorkspaceError> {
+    let mut printer = MarkdownPrinter::default();
+    for block in MdxBlock::extract(document) {
+        let text = &document[block.range];
+        match block.kind {
+            MdxBlockKind::Frontmatter | MdxBlockKind::Code => {
+                text.lines().for_each(|line| printer.push_verbatim(line));
+            }
+            MdxBlockKind::Esm => {
+                let parse =
+                    biome_js_parser::parse(text, JsFileSource::mdx(), JsParserOptions::default());
+                if parse.has_errors() {
+                    text.lines().for_each(|line| printer.push_verbatim(line));
+                    continue;
+                }
+                let formatted = biome_js_formatter::format_node(options.clone(), &parse.syntax())?;
+                let printed = formatted
+                    .print()
+                    .map_err(|error| WorkspaceError::FormatError(error.into()))?;
+                printed
+                    .as_code()
+                    .lines()
+                    .for_each(|line| printer.push_verbatim(line));
+            }
+            MdxBlockKind::Markdown => {
+                text.lines().for_each(|line| printer.push_markdown(line));
+            }
+        }
+    }
+
+    Ok(printer.finish(options.line_ending().as_str()))
+}
+
+/// Prints the lines of a formatted MDX document
+#[derive(Debug, Default)]
+struct MarkdownPrinter {
+    lines: Vec<String>,
+    /// Whether the last line that isn't blank is a heading
+    after_heading: bool,
+}
+
+impl MarkdownPrinter {
+    fn push_verbatim(&mut self, line: &str) {
+        self.push_line(line.to_string(), false);
+    }
+
+    fn push_markdown(&mut self, line: &str) {
+        // Two spaces or more at the end of a line are a hard line break
+        let line = if line.trim().is_empty() || !line.ends_with("  ") {
+            line.trim_end()
+        } else {
+            line
+        };
+
+        if line.is_empty() {
+            if self.lines.last().is_some_and(|last| !last.is_empty()) {
+                self.lines.push(String::new());
+            }
+            return;
+        }
+
+        if let Some(captures) = ATX_HEADING.captures(line) {
+            let hashes = &captures["hashes"];
+            let heading = match captures.name("content") {
+                Some(content) if !content.as_str().trim().is_empty() => {
+                    format!("{hashes} {}", content.as_str().trim())
+                }
+                _ => hashes.to_string(),
+            };
+            if self.lines.last().is_some_and(|last| !last.is_empty()) {
+                self.lines.push(String::new());
+            }
+            self.push_line(heading, true);
+            return;
+        }
+
+        match BULLET_ITEM.captures(line) {
+            Some(captures) if !THEMATIC_BREAK.is_match(line) => {
+                let line = format!(
+                    "{}-{}{}",
+                    &captures["indent"], &captures["space"], &captures["content"]
+                );
+                self.push_line(line, false);
+            }
+            _ => self.push_line(line.to_string(), false),
+        }
+    }
+
+    fn push_line(&mut self, line: String, is_heading: bool) {
+        if self.after_heading
+            && !line.is_empty()
+            && !self.lines.last().is_some_and(String::is_empty)
+        {
+            self.lines.push(String::new());
+        }
+        if !line.is_empty() {
+            self.after_heading = is_heading;
+        }
+        self.lines.push(line);
+    }
+
+    fn finish(mut self, line_ending: &str) -> String {
+        while self.lines.last().is_some_and(String::is_empty) {
+            self.lines.pop();
+        }
+        let mut output = String::new();
+        for line in self.lines {
+            output.push_str(&line);
+            output.push_str(line_ending);
+        }
+        output
+    }
+}
+
+/// It lints the JavaScript code of the document with the JavaScript rules: the `import` and
+/// `export` statements, the expressions and the components. The ranges of the diagnostics are
+/// the ones of the document.
+pub(crate) fn lint(params: LintParams) -> LintResults {
+    let program = program(&document(&params.parse));
+    javascript::lint(LintParams {
+        parse: parse_program(&program),
+        ..params
+    })
+}
+
+pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
+    let program = program(&document(&params.parse));
+    javascript::code_actions(CodeActionsParams {
+        parse: parse_program(&program),
+        ..params
+    })
+}
+
+/// The fixes are applied to the program of the document, whose changes are then applied
+/// to the document.
+fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+    let document = document(&params.parse);
+    let program = program(&document);
+    let result = javascript::fix_all(FixAllParams {
+        parse: parse_program(&program),
+        // The formatter of the JavaScript files can't format the program
+        should_format: false,
+        ..params
+    })?;
+
+    Ok(FixFileResult {
+        code: apply_program_changes(&document, &program, &result.code),
+        ..result
+    })
+}
+
+fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
+    let document = document(&parse);
+    let program = program(&document);
+    let result = javascript::organize_imports(parse_program(&program))?;
+
+    Ok(OrganizeImportsResult {
+        code: apply_program_changes(&document, &program, &result.code),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_the_blocks() {
+        let document = r#"---
+title: Charts
+---
+
+import { Chart } from "./chart";
+import data from "./data.json";
+
+# Charts
+
+```js
+export const notEsm = true;
+```
+
+export const meta = { title: "Charts" };
+"#;
+
+        let blocks: Vec<_> = MdxBlock::extract(document)
+            .into_iter()
+            .map(|block| (block.kind, document[block.range].trim_end()))
+            .collect();
+        assert_eq!(
+            blocks,
+            vec![
+                (MdxBlockKind::Frontmatter, "---\ntitle: Charts\n---"),
+                (MdxBlockKind::Markdown, ""),
+                (
+                    MdxBlockKind::Esm,
+                    "import { Chart } from \"./chart\";\nimport data from \"./data.json\";"
+                ),
+                (MdxBlockKind::Markdown, "\n# Charts"),
+                (
+                    MdxBlockKind::Code,
+                    "```js\nexport const notEsm = true;\n```"
+                ),
+                (MdxBlockKind::Markdown, ""),
+                (
+                    MdxBlockKind::Esm,
+                    "export const meta = { title: \"Charts\" };"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_the_program() {
+        let document = r#"import { Chart } from "./chart";
+
+Total: {count * 2} items, `{not code}`.
+
+<Chart data={data} />
+"#;
+
+        let program = program(document);
+        assert_eq!(program.len(), document.len());
+        let lines: Vec<_> = program.lines().map(str::trim_end).collect();
+        assert_eq!(
+            lines,
+            vec![
+                r#"import { Chart } from "./chart";"#,
+                "",
+                "      ;(count * 2)",
+                "",
+                ";Chart;    ;(data)",
+            ]
+        );
+    }
+
+    #[test]
+    fn applies_the_changes_of_the_program() {
+        let document = "import a from \"a\";\nimport b from \"b\";\n\n# {b}\n";
+        let program = program(document);
+        let changed = program.replacen("import a from \"a\";\n", "", 1);
+
+        assert_eq!(
+            apply_program_changes(document, &program, &changed),
+            "import b from \"b\";\n\n# {b}\n"
+        );
+    }
+
+    #[test]
+    fn formats_the_markdown_structure() {
+        let document = r#"import {Chart} from "./chart"
+
+# Charts ##
+Some text
+with a hard break.
+end
+
+
+
+* first
++ second
+* * *
+"#;
+
+        let output = format_document(document, &JsFormatOptions::new(JsFileSource::mdx())).unwrap();
+        assert_eq!(
+            output,
+            r#"import { Chart } from "./chart";
+
+# Charts
+
+Some text
+with a hard break.
+end
+
+- first
+- second
+* * *
+"#
+        );
+    }
+}
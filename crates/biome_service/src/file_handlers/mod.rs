@@ -1,62 +1,110 @@
-use self::{
-    css::CssFileHandler, javascript::JsFileHandler, json::JsonFileHandler,
-    unknown::UnknownFileHandler,
-};
+#[cfg(feature = "css")]
+use self::css::CssFileHandler;
+use self::{javascript::JsFileHandler, json::JsonFileHandler, unknown::UnknownFileHandler};
+#[cfg(feature = "grit")]
 use crate::diagnostics::{QueryDiagnostic, SearchError};
 pub use crate::file_handlers::astro::{AstroFileHandler, ASTRO_FENCE};
+pub use crate::file_handlers::embedded_angular::{AngularComponent, AngularProjectIndex};
+#[cfg(feature = "css")]
+pub use crate::file_handlers::embedded_css::{EmbeddedCss, EmbeddedCssKind, STYLE_FENCE};
+#[cfg(feature = "graphql")]
+pub use crate::file_handlers::embedded_graphql::EmbeddedGraphql;
+pub use crate::file_handlers::embedded_js::EmbeddedScript;
+#[cfg(feature = "graphql")]
 use crate::file_handlers::graphql::GraphqlFileHandler;
+pub use crate::file_handlers::mdx::MdxFileHandler;
 pub use crate::file_handlers::svelte::{SvelteFileHandler, SVELTE_FENCE};
 pub use crate::file_handlers::vue::{VueFileHandler, VUE_FENCE};
 use crate::settings::Settings;
-use crate::workspace::{FixFileMode, OrganizeImportsResult};
+#[cfg(feature = "grit")]
+use crate::workspace::SearchResults;
+use crate::workspace::{
+    DocumentSymbol, FixFileMode, FoldingRange, InlayHint, OrganizeImportsResult, SemanticToken,
+    Signature,
+};
 use crate::{
     settings::WorkspaceSettingsHandle,
-    workspace::{FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult},
+    workspace::{
+        FixAction, FixFileResult, GetSyntaxTreeResult, PrepareRenameResult, PullActionsResult,
+        RenameResult,
+    },
     WorkspaceError,
 };
 use biome_analyze::{
     AnalyzerDiagnostic, GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategories,
-    RuleCategory, RuleFilter, RuleGroup,
+    RuleCategory, RuleError, RuleFilter, RuleGroup,
 };
 use biome_configuration::analyzer::RuleSelector;
 use biome_configuration::Rules;
 use biome_console::fmt::Formatter;
 use biome_console::markup;
-use biome_css_syntax::{CssFileSource, CssLanguage};
+#[cfg(feature = "css")]
+use biome_css_semantic::CssProjectIndex;
+use biome_css_syntax::CssFileSource;
+#[cfg(feature = "css")]
+use biome_css_syntax::CssLanguage;
 use biome_diagnostics::{Diagnostic, Severity};
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
-use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
+#[cfg(feature = "graphql")]
+use biome_graphql_schema::GraphqlSchema;
+#[cfg(feature = "graphql")]
+use biome_graphql_semantic::GraphqlProjectIndex;
+use biome_graphql_syntax::GraphqlFileSource;
+#[cfg(feature = "graphql")]
+use biome_graphql_syntax::GraphqlLanguage;
+#[cfg(feature = "grit")]
 use biome_grit_patterns::{GritQuery, GritQueryResult, GritTargetFile};
 use biome_grit_syntax::file_source::GritFileSource;
-use biome_html_syntax::HtmlFileSource;
+use biome_html_syntax::{HtmlFileSource, HtmlLanguage};
 use biome_js_parser::{parse, JsParserOptions};
+use biome_js_semantic::JsProjectIndex;
 use biome_js_syntax::{
     EmbeddingKind, JsFileSource, JsLanguage, Language, LanguageVariant, TextRange, TextSize,
 };
+use biome_json_schema::JsonSchemaStore;
 use biome_json_syntax::{JsonFileSource, JsonLanguage};
 use biome_parser::AnyParse;
 use biome_project::PackageJson;
-use biome_rowan::{FileSourceError, NodeCache};
+use biome_rowan::{FileSourceError, NodeCache, SyntaxNode};
 use biome_string_case::StrLikeExtension;
 
+#[cfg(feature = "css")]
+pub(crate) use css::custom_property_at;
+#[cfg(feature = "grit")]
 use grit::GritFileHandler;
 use html::HtmlFileHandler;
 pub use javascript::JsFormatterSettings;
-use rustc_hash::FxHashSet;
+pub(crate) use javascript::{exported_symbol_at, import_specifier_at, rename_imports};
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
 use std::ffi::OsStr;
+use std::hash::Hasher;
 use std::path::Path;
+use std::sync::{Arc, OnceLock};
 use tracing::instrument;
 
 mod astro;
+#[cfg(feature = "css")]
 mod css;
+mod embedded_angular;
+#[cfg(feature = "css")]
+mod embedded_css;
+#[cfg(feature = "graphql")]
+mod embedded_graphql;
+mod embedded_js;
+#[cfg(feature = "graphql")]
 mod graphql;
+#[cfg(feature = "grit")]
 mod grit;
 mod html;
 mod javascript;
 mod json;
+mod mdx;
 mod svelte;
+mod syntax_ranges;
+mod template_references;
 mod unknown;
 mod vue;
 
@@ -126,9 +174,11 @@ impl DocumentFileSource {
         if let Ok(file_source) = JsFileSource::try_from_well_known(path) {
             return Ok(file_source.into());
         }
+        #[cfg(feature = "css")]
         if let Ok(file_source) = CssFileSource::try_from_well_known(path) {
             return Ok(file_source.into());
         }
+        #[cfg(feature = "graphql")]
         if let Ok(file_source) = GraphqlFileSource::try_from_well_known(path) {
             return Ok(file_source.into());
         }
@@ -150,9 +200,11 @@ impl DocumentFileSource {
         if let Ok(file_source) = JsFileSource::try_from_extension(extension) {
             return Ok(file_source.into());
         }
+        #[cfg(feature = "css")]
         if let Ok(file_source) = CssFileSource::try_from_extension(extension) {
             return Ok(file_source.into());
         }
+        #[cfg(feature = "graphql")]
         if let Ok(file_source) = GraphqlFileSource::try_from_extension(extension) {
             return Ok(file_source.into());
         }
@@ -160,6 +212,7 @@ impl DocumentFileSource {
         if let Ok(file_source) = HtmlFileSource::try_from_extension(extension) {
             return Ok(file_source.into());
         }
+        #[cfg(feature = "grit")]
         if let Ok(file_source) = GritFileSource::try_from_extension(extension) {
             return Ok(file_source.into());
         }
@@ -180,9 +233,11 @@ impl DocumentFileSource {
         if let Ok(file_source) = JsFileSource::try_from_language_id(language_id) {
             return Ok(file_source.into());
         }
+        #[cfg(feature = "css")]
         if let Ok(file_source) = CssFileSource::try_from_language_id(language_id) {
             return Ok(file_source.into());
         }
+        #[cfg(feature = "graphql")]
         if let Ok(file_source) = GraphqlFileSource::try_from_language_id(language_id) {
             return Ok(file_source.into());
         }
@@ -190,6 +245,7 @@ impl DocumentFileSource {
         if let Ok(file_source) = HtmlFileSource::try_from_language_id(language_id) {
             return Ok(file_source.into());
         }
+        #[cfg(feature = "grit")]
         if let Ok(file_source) = GritFileSource::try_from_language_id(language_id) {
             return Ok(file_source.into());
         }
@@ -343,12 +399,13 @@ impl DocumentFileSource {
                 EmbeddingKind::Astro => ASTRO_FENCE.is_match(content),
                 EmbeddingKind::Vue => VUE_FENCE.is_match(content),
                 EmbeddingKind::Svelte => SVELTE_FENCE.is_match(content),
+                EmbeddingKind::Mdx => true,
                 EmbeddingKind::None => true,
             },
-            DocumentFileSource::Css(_)
-            | DocumentFileSource::Graphql(_)
-            | DocumentFileSource::Json(_)
-            | DocumentFileSource::Grit(_) => true,
+            DocumentFileSource::Json(_) => true,
+            DocumentFileSource::Css(_) => cfg!(feature = "css"),
+            DocumentFileSource::Graphql(_) => cfg!(feature = "graphql"),
+            DocumentFileSource::Grit(_) => cfg!(feature = "grit"),
             DocumentFileSource::Html(_) => cfg!(feature = "experimental-html"),
             DocumentFileSource::Unknown => false,
         }
@@ -373,7 +430,11 @@ impl biome_console::fmt::Display for DocumentFileSource {
                 }
             }
             DocumentFileSource::Json(json) => {
-                if json.allow_comments() {
+                if json.is_json5() {
+                    fmt.write_markup(markup! { "JSON5" })
+                } else if json.is_json_lines() {
+                    fmt.write_markup(markup! { "JSON Lines" })
+                } else if json.allow_comments() {
                     fmt.write_markup(markup! { "JSONC" })
                 } else {
                     fmt.write_markup(markup! { "JSON" })
@@ -396,6 +457,13 @@ pub struct FixAllParams<'a> {
     pub(crate) should_format: bool,
     pub(crate) biome_path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    #[cfg(feature = "css")]
+    pub(crate) css_project: Arc<CssProjectIndex>,
+    pub(crate) js_project: Arc<JsProjectIndex>,
+    #[cfg(feature = "graphql")]
+    pub(crate) graphql_schema: Option<Arc<GraphqlSchema>>,
+    #[cfg(feature = "graphql")]
+    pub(crate) graphql_project: Arc<GraphqlProjectIndex>,
     pub(crate) document_file_source: DocumentFileSource,
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
@@ -403,6 +471,71 @@ pub struct FixAllParams<'a> {
     pub(crate) suppression_reason: Option<String>,
 }
 
+/// Detects the fixes that undo each other, while `fix_all` applies the fixes of a file until
+/// none is left.
+///
+/// The fixes are applied one at a time, and all the rules run again on the fixed tree before
+/// the next fix is picked, so the edits of two fixes never overlap: a fix is always computed
+/// from the code that the previous fixes produced. However, the fixes of two rules may change
+/// the same code back and forth, and would then be applied forever. The guard records the
+/// content of the file after each fix: when a content is produced twice, the fixes applied in
+/// between form a cycle.
+pub(crate) struct FixCycleGuard<L: biome_rowan::Language> {
+    /// Each content of the file, with the number of fixes applied before it, by the hash of
+    /// its text. The trees share their unchanged nodes, so keeping them is cheap.
+    contents: FxHashMap<u64, Vec<(SyntaxNode<L>, usize)>>,
+}
+
+impl<L: biome_rowan::Language> FixCycleGuard<L> {
+    pub(crate) fn new(root: &SyntaxNode<L>) -> Self {
+        Self {
+            contents: FxHashMap::from_iter([(content_hash(root), vec![(root.clone(), 0)])]),
+        }
+    }
+
+    /// Records the content of the file once the last of `actions` was applied to it.
+    ///
+    /// Returns an error naming the rules of the fixes in the cycle if the file had this
+    /// content already.
+    pub(crate) fn record(
+        &mut self,
+        root: &SyntaxNode<L>,
+        actions: &[FixAction],
+    ) -> Result<(), WorkspaceError> {
+        let contents = self.contents.entry(content_hash(root)).or_default();
+        // Two different contents can have the same hash, so the texts are compared too
+        let previous = contents
+            .iter()
+            .find(|(content, _)| content.text() == root.text());
+        match previous {
+            Some((_, applied_fixes)) => {
+                let mut rules: Vec<_> = actions[*applied_fixes..]
+                    .iter()
+                    .filter_map(|action| action.rule_name.clone())
+                    .collect();
+                rules.sort();
+                rules.dedup();
+                Err(WorkspaceError::RuleError(
+                    RuleError::ConflictingRuleFixesError { rules },
+                ))
+            }
+            None => {
+                contents.push((root.clone(), actions.len()));
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Returns the hash of the text of `root`, without building the text
+fn content_hash<L: biome_rowan::Language>(root: &SyntaxNode<L>) -> u64 {
+    // The hasher gives the same hash to the text whatever its chunks are
+    let mut hasher = DefaultHasher::new();
+    root.text()
+        .for_each_chunk(|chunk| hasher.write(chunk.as_bytes()));
+    hasher.finish()
+}
+
 #[derive(Default)]
 /// The list of capabilities that are available for a language
 pub struct Capabilities {
@@ -411,6 +544,7 @@ pub struct Capabilities {
     pub(crate) analyzer: AnalyzerCapabilities,
     pub(crate) formatter: FormatterCapabilities,
     pub(crate) search: SearchCapabilities,
+    pub(crate) symbols: SymbolCapabilities,
 }
 
 #[derive(Clone)]
@@ -458,6 +592,15 @@ pub(crate) struct LintParams<'a> {
     pub(crate) skip: Vec<RuleSelector>,
     pub(crate) categories: RuleCategories,
     pub(crate) manifest: Option<PackageJson>,
+    #[cfg(feature = "css")]
+    pub(crate) css_project: Arc<CssProjectIndex>,
+    pub(crate) js_project: Arc<JsProjectIndex>,
+    pub(crate) json_schemas: Arc<JsonSchemaStore>,
+    #[cfg(feature = "graphql")]
+    pub(crate) graphql_schema: Option<Arc<GraphqlSchema>>,
+    #[cfg(feature = "graphql")]
+    pub(crate) graphql_project: Arc<GraphqlProjectIndex>,
+    pub(crate) angular_project: Arc<AngularProjectIndex>,
     pub(crate) suppression_reason: Option<String>,
 }
 
@@ -473,6 +616,13 @@ pub(crate) struct CodeActionsParams<'a> {
     pub(crate) workspace: &'a WorkspaceSettingsHandle<'a>,
     pub(crate) path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    #[cfg(feature = "css")]
+    pub(crate) css_project: Arc<CssProjectIndex>,
+    pub(crate) js_project: Arc<JsProjectIndex>,
+    #[cfg(feature = "graphql")]
+    pub(crate) graphql_schema: Option<Arc<GraphqlSchema>>,
+    #[cfg(feature = "graphql")]
+    pub(crate) graphql_project: Arc<GraphqlProjectIndex>,
     pub(crate) language: DocumentFileSource,
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
@@ -482,6 +632,7 @@ pub(crate) struct CodeActionsParams<'a> {
 type Lint = fn(LintParams) -> LintResults;
 type CodeActions = fn(CodeActionsParams) -> PullActionsResult;
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, WorkspaceError>;
+type PrepareRename = fn(AnyParse, TextSize) -> Result<PrepareRenameResult, WorkspaceError>;
 type Rename = fn(&BiomePath, AnyParse, TextSize, String) -> Result<RenameResult, WorkspaceError>;
 type OrganizeImports = fn(AnyParse) -> Result<OrganizeImportsResult, WorkspaceError>;
 
@@ -493,6 +644,8 @@ pub struct AnalyzerCapabilities {
     pub(crate) code_actions: Option<CodeActions>,
     /// Applies fixes to a file
     pub(crate) fix_all: Option<FixAll>,
+    /// It finds the binding renamed at a position of a file
+    pub(crate) prepare_rename: Option<PrepareRename>,
     /// It renames a binding inside a file
     pub(crate) rename: Option<Rename>,
     /// It organizes imports
@@ -530,20 +683,45 @@ pub(crate) struct FormatterCapabilities {
     pub(crate) format_on_type: Option<FormatOnType>,
 }
 
+#[cfg(feature = "grit")]
 type Search = fn(
     &BiomePath,
     &DocumentFileSource,
     AnyParse,
     &GritQuery,
     WorkspaceSettingsHandle,
-) -> Result<Vec<TextRange>, WorkspaceError>;
+) -> Result<SearchResults, WorkspaceError>;
 
 #[derive(Default)]
 pub(crate) struct SearchCapabilities {
     /// It searches through a file
+    #[cfg(feature = "grit")]
     pub(crate) search: Option<Search>,
 }
 
+type DocumentSymbols = fn(AnyParse) -> Vec<DocumentSymbol>;
+type SemanticTokens = fn(AnyParse) -> Vec<SemanticToken>;
+type InlayHints = fn(AnyParse, TextRange) -> Vec<InlayHint>;
+type SignatureHelp = fn(AnyParse, TextSize) -> Option<Signature>;
+type FoldingRanges = fn(AnyParse) -> Vec<FoldingRange>;
+type SelectionRanges = fn(AnyParse, Vec<TextSize>) -> Vec<Vec<TextRange>>;
+
+#[derive(Default)]
+pub(crate) struct SymbolCapabilities {
+    /// It lists the symbols defined by a file
+    pub(crate) document_symbols: Option<DocumentSymbols>,
+    /// It classifies the identifiers of a file, sorted by position
+    pub(crate) semantic_tokens: Option<SemanticTokens>,
+    /// It returns the hints of a range of a file, sorted by position
+    pub(crate) inlay_hints: Option<InlayHints>,
+    /// It returns the signature of the function called at a position
+    pub(crate) signature_help: Option<SignatureHelp>,
+    /// It returns the parts of a file that can be collapsed, sorted by start
+    pub(crate) folding_ranges: Option<FoldingRanges>,
+    /// It returns the ranges that contain each position, from the smallest to the largest
+    pub(crate) selection_ranges: Option<SelectionRanges>,
+}
+
 /// Main trait to use to add a new language to Biome
 pub(crate) trait ExtensionHandler {
     /// Capabilities that can applied to a file
@@ -552,35 +730,32 @@ pub(crate) trait ExtensionHandler {
     }
 }
 
-/// Features available for each language
+/// Features available for each language.
+///
+/// The capabilities of a language are only built when the first file of the language is
+/// processed. The languages whose cargo feature is disabled get the capabilities of the
+/// unknown files, since their handlers aren't compiled.
+#[derive(Default)]
 pub(crate) struct Features {
-    js: JsFileHandler,
-    json: JsonFileHandler,
-    #[allow(unused)]
-    css: CssFileHandler,
-    astro: AstroFileHandler,
-    vue: VueFileHandler,
-    svelte: SvelteFileHandler,
-    unknown: UnknownFileHandler,
-    graphql: GraphqlFileHandler,
-    html: HtmlFileHandler,
-    grit: GritFileHandler,
+    js: OnceLock<Capabilities>,
+    json: OnceLock<Capabilities>,
+    #[cfg(feature = "css")]
+    css: OnceLock<Capabilities>,
+    astro: OnceLock<Capabilities>,
+    vue: OnceLock<Capabilities>,
+    svelte: OnceLock<Capabilities>,
+    mdx: OnceLock<Capabilities>,
+    unknown: OnceLock<Capabilities>,
+    #[cfg(feature = "graphql")]
+    graphql: OnceLock<Capabilities>,
+    html: OnceLock<Capabilities>,
+    #[cfg(feature = "grit")]
+    grit: OnceLock<Capabilities>,
 }
 
 impl Features {
     pub(crate) fn new() -> Self {
-        Features {
-            js: JsFileHandler {},
-            json: JsonFileHandler {},
-            css: CssFileHandler {},
-            astro: AstroFileHandler {},
-            vue: VueFileHandler {},
-            svelte: SvelteFileHandler {},
-            graphql: GraphqlFileHandler {},
-            html: HtmlFileHandler {},
-            grit: GritFileHandler {},
-            unknown: UnknownFileHandler::default(),
-        }
+        Self::default()
     }
 
     /// Returns the [Capabilities] associated with a [BiomePath]
@@ -588,20 +763,38 @@ impl Features {
         &self,
         biome_path: &BiomePath,
         language_hint: DocumentFileSource,
-    ) -> Capabilities {
+    ) -> &Capabilities {
         match DocumentFileSource::from_path(biome_path).or(language_hint) {
             DocumentFileSource::Js(source) => match source.as_embedding_kind() {
-                EmbeddingKind::Astro => self.astro.capabilities(),
-                EmbeddingKind::Vue => self.vue.capabilities(),
-                EmbeddingKind::Svelte => self.svelte.capabilities(),
-                EmbeddingKind::None => self.js.capabilities(),
+                EmbeddingKind::Astro => self
+                    .astro
+                    .get_or_init(|| AstroFileHandler {}.capabilities()),
+                EmbeddingKind::Vue => self.vue.get_or_init(|| VueFileHandler {}.capabilities()),
+                EmbeddingKind::Svelte => self
+                    .svelte
+                    .get_or_init(|| SvelteFileHandler {}.capabilities()),
+                EmbeddingKind::Mdx => self.mdx.get_or_init(|| MdxFileHandler {}.capabilities()),
+                EmbeddingKind::None => self.js.get_or_init(|| JsFileHandler {}.capabilities()),
             },
-            DocumentFileSource::Json(_) => self.json.capabilities(),
-            DocumentFileSource::Css(_) => self.css.capabilities(),
-            DocumentFileSource::Graphql(_) => self.graphql.capabilities(),
-            DocumentFileSource::Html(_) => self.html.capabilities(),
-            DocumentFileSource::Grit(_) => self.grit.capabilities(),
-            DocumentFileSource::Unknown => self.unknown.capabilities(),
+            DocumentFileSource::Json(_) => {
+                self.json.get_or_init(|| JsonFileHandler {}.capabilities())
+            }
+            #[cfg(feature = "css")]
+            DocumentFileSource::Css(_) => self.css.get_or_init(|| CssFileHandler {}.capabilities()),
+            #[cfg(feature = "graphql")]
+            DocumentFileSource::Graphql(_) => self
+                .graphql
+                .get_or_init(|| GraphqlFileHandler {}.capabilities()),
+            DocumentFileSource::Html(_) => {
+                self.html.get_or_init(|| HtmlFileHandler {}.capabilities())
+            }
+            #[cfg(feature = "grit")]
+            DocumentFileSource::Grit(_) => {
+                self.grit.get_or_init(|| GritFileHandler {}.capabilities())
+            }
+            _ => self
+                .unknown
+                .get_or_init(|| UnknownFileHandler::default().capabilities()),
         }
     }
 }
@@ -680,13 +873,14 @@ pub(crate) fn parse_lang_from_script_opening_tag(
     })
 }
 
+#[cfg(feature = "grit")]
 pub(crate) fn search(
     path: &BiomePath,
     _file_source: &DocumentFileSource,
     parse: AnyParse,
     query: &GritQuery,
     _settings: WorkspaceSettingsHandle,
-) -> Result<Vec<TextRange>, WorkspaceError> {
+) -> Result<SearchResults, WorkspaceError> {
     let (query_result, _logs) = query
         .execute(GritTargetFile {
             path: path.to_path_buf(),
@@ -696,16 +890,27 @@ pub(crate) fn search(
             WorkspaceError::SearchError(SearchError::QueryError(QueryDiagnostic(err.to_string())))
         })?;
 
-    let matches = query_result
-        .into_iter()
-        .flat_map(|result| match result {
-            GritQueryResult::Match(m) => m.ranges,
-            _ => Vec::new(),
-        })
-        .map(|range| TextRange::new(range.start_byte.into(), range.end_byte.into()))
-        .collect();
+    let mut matches = Vec::new();
+    let mut rewritten = None;
+    for result in query_result {
+        match result {
+            GritQueryResult::Match(m) => matches.extend(m.ranges),
+            GritQueryResult::Rewrite(rewrite) => {
+                matches.extend(rewrite.original.ranges);
+                rewritten = Some(rewrite.rewritten.content);
+            }
+            GritQueryResult::CreateFile(_) => {}
+        }
+    }
 
-    Ok(matches)
+    Ok(SearchResults {
+        file: path.clone(),
+        matches: matches
+            .into_iter()
+            .map(|range| TextRange::new(range.start_byte.into(), range.end_byte.into()))
+            .collect(),
+        rewritten,
+    })
 }
 
 #[test]
@@ -784,6 +989,7 @@ impl<'a> RegistryVisitor<JsonLanguage> for SyntaxVisitor<'a> {
     }
 }
 
+#[cfg(feature = "css")]
 impl<'a> RegistryVisitor<CssLanguage> for SyntaxVisitor<'a> {
     fn record_category<C: GroupCategory<Language = CssLanguage>>(&mut self) {
         if C::CATEGORY == RuleCategory::Syntax {
@@ -803,6 +1009,7 @@ impl<'a> RegistryVisitor<CssLanguage> for SyntaxVisitor<'a> {
     }
 }
 
+#[cfg(feature = "graphql")]
 impl<'a> RegistryVisitor<GraphqlLanguage> for SyntaxVisitor<'a> {
     fn record_category<C: GroupCategory<Language = GraphqlLanguage>>(&mut self) {
         if C::CATEGORY == RuleCategory::Syntax {
@@ -822,6 +1029,25 @@ impl<'a> RegistryVisitor<GraphqlLanguage> for SyntaxVisitor<'a> {
     }
 }
 
+impl<'a> RegistryVisitor<HtmlLanguage> for SyntaxVisitor<'a> {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Syntax {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.enabled_rules.push(RuleFilter::Rule(
+            <R::Group as RuleGroup>::NAME,
+            R::METADATA.name,
+        ))
+    }
+}
+
 /// Type meant to register all the lint rules for each language supported by Biome
 ///
 #[derive(Debug)]
@@ -946,6 +1172,7 @@ impl<'a, 'b> RegistryVisitor<JsonLanguage> for LintVisitor<'a, 'b> {
     }
 }
 
+#[cfg(feature = "css")]
 impl<'a, 'b> RegistryVisitor<CssLanguage> for LintVisitor<'a, 'b> {
     fn record_category<C: GroupCategory<Language = CssLanguage>>(&mut self) {
         if C::CATEGORY == RuleCategory::Lint {
@@ -976,6 +1203,7 @@ impl<'a, 'b> RegistryVisitor<CssLanguage> for LintVisitor<'a, 'b> {
     }
 }
 
+#[cfg(feature = "graphql")]
 impl<'a, 'b> RegistryVisitor<GraphqlLanguage> for LintVisitor<'a, 'b> {
     fn record_category<C: GroupCategory<Language = GraphqlLanguage>>(&mut self) {
         if C::CATEGORY == RuleCategory::Lint {
@@ -1006,6 +1234,36 @@ impl<'a, 'b> RegistryVisitor<GraphqlLanguage> for LintVisitor<'a, 'b> {
     }
 }
 
+impl<'a, 'b> RegistryVisitor<HtmlLanguage> for LintVisitor<'a, 'b> {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Lint {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_group<G: RuleGroup<Language = HtmlLanguage>>(&mut self) {
+        for selector in self.only {
+            if RuleFilter::from(selector).match_group::<G>() {
+                G::record_rules(self)
+            }
+        }
+
+        for selector in self.skip {
+            if RuleFilter::from(selector).match_group::<G>() {
+                G::record_rules(self)
+            }
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>()
+    }
+}
+
 struct AssistsVisitor<'a, 'b> {
     settings: Option<&'b Settings>,
     enabled_rules: Vec<RuleFilter<'a>>,
@@ -1112,6 +1370,7 @@ impl<'a, 'b> RegistryVisitor<JsonLanguage> for AssistsVisitor<'a, 'b> {
     }
 }
 
+#[cfg(feature = "css")]
 impl<'a, 'b> RegistryVisitor<CssLanguage> for AssistsVisitor<'a, 'b> {
     fn record_category<C: GroupCategory<Language = CssLanguage>>(&mut self) {
         if C::CATEGORY == RuleCategory::Action {
@@ -1128,6 +1387,7 @@ impl<'a, 'b> RegistryVisitor<CssLanguage> for AssistsVisitor<'a, 'b> {
     }
 }
 
+#[cfg(feature = "graphql")]
 impl<'a, 'b> RegistryVisitor<GraphqlLanguage> for AssistsVisitor<'a, 'b> {
     fn record_category<C: GroupCategory<Language = GraphqlLanguage>>(&mut self) {
         if C::CATEGORY == RuleCategory::Action {
@@ -1144,6 +1404,22 @@ impl<'a, 'b> RegistryVisitor<GraphqlLanguage> for AssistsVisitor<'a, 'b> {
     }
 }
 
+impl<'a, 'b> RegistryVisitor<HtmlLanguage> for AssistsVisitor<'a, 'b> {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Action {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>();
+    }
+}
+
 pub(crate) struct AnalyzerVisitorBuilder<'a, 'b> {
     syntax: Option<SyntaxVisitor<'a>>,
     lint: Option<LintVisitor<'a, 'b>>,
@@ -1194,17 +1470,23 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
         let mut enabled_rules = vec![];
         if let Some(mut syntax) = self.syntax {
             biome_js_analyze::visit_registry(&mut syntax);
+            #[cfg(feature = "css")]
             biome_css_analyze::visit_registry(&mut syntax);
             biome_json_analyze::visit_registry(&mut syntax);
+            #[cfg(feature = "graphql")]
             biome_graphql_analyze::visit_registry(&mut syntax);
+            biome_html_analyze::visit_registry(&mut syntax);
             enabled_rules.extend(syntax.enabled_rules);
         }
 
         if let Some(mut lint) = self.lint {
             biome_js_analyze::visit_registry(&mut lint);
+            #[cfg(feature = "css")]
             biome_css_analyze::visit_registry(&mut lint);
             biome_json_analyze::visit_registry(&mut lint);
+            #[cfg(feature = "graphql")]
             biome_graphql_analyze::visit_registry(&mut lint);
+            biome_html_analyze::visit_registry(&mut lint);
             let (linter_enabled_rules, linter_disabled_rules) = lint.finish();
             enabled_rules.extend(linter_enabled_rules);
             disabled_rules.extend(linter_disabled_rules);
@@ -1212,9 +1494,12 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
 
         if let Some(mut assists) = self.assists {
             biome_js_analyze::visit_registry(&mut assists);
+            #[cfg(feature = "css")]
             biome_css_analyze::visit_registry(&mut assists);
             biome_json_analyze::visit_registry(&mut assists);
+            #[cfg(feature = "graphql")]
             biome_graphql_analyze::visit_registry(&mut assists);
+            biome_html_analyze::visit_registry(&mut assists);
             let (assists_enabled_rules, assists_disabled_rules) = assists.finish();
             enabled_rules.extend(assists_enabled_rules);
             disabled_rules.extend(assists_disabled_rules);
@@ -1279,3 +1564,31 @@ fn test_vue_script_lang() {
             .is_typescript()
     );
 }
+
+#[test]
+fn fix_cycle_guard_detects_the_fixes_that_undo_each_other() {
+    use biome_json_parser::{parse_json, JsonParserOptions};
+
+    let fix = |rule: &'static str| FixAction {
+        rule_name: Some((Cow::Borrowed("style"), Cow::Borrowed(rule))),
+        range: TextRange::default(),
+    };
+    let root = |content: &str| parse_json(content, JsonParserOptions::default()).syntax();
+
+    let mut guard = FixCycleGuard::new(&root("[1]"));
+    let mut actions = vec![fix("useSpread")];
+    assert!(guard.record(&root("[2]"), &actions).is_ok());
+    actions.push(fix("noSpread"));
+    assert!(guard.record(&root("[3]"), &actions).is_ok());
+    actions.push(fix("useSpread"));
+    let error = guard.record(&root("[2]"), &actions).unwrap_err();
+
+    assert!(matches!(
+        error,
+        WorkspaceError::RuleError(RuleError::ConflictingRuleFixesError { rules })
+            if rules == [
+                (Cow::Borrowed("style"), Cow::Borrowed("noSpread")),
+                (Cow::Borrowed("style"), Cow::Borrowed("useSpread")),
+            ]
+    ));
+}
@@ -1,5 +1,8 @@
+#[cfg(feature = "css")]
+use crate::file_handlers::embedded_css;
+use crate::file_handlers::template_references::{self, TemplateReferences};
 use crate::file_handlers::{
-    javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
+    html, javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
     ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
     ParserCapabilities,
 };
@@ -8,17 +11,22 @@ use crate::workspace::{
     DocumentFileSource, FixFileResult, OrganizeImportsResult, PullActionsResult,
 };
 use crate::WorkspaceError;
+#[cfg(feature = "css")]
+use biome_css_syntax::{CssFileSource, CssLanguage};
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
+use biome_html_parser::{parse_html_with_cache, HtmlParserOptions};
+use biome_html_syntax::{HtmlFileSource, HtmlLanguage};
 use biome_js_parser::{parse_js_with_cache, JsParserOptions};
 use biome_js_syntax::{EmbeddingKind, JsFileSource, TextRange, TextSize};
 use biome_parser::AnyParse;
 use biome_rowan::NodeCache;
-use regex::{Match, Regex};
+use regex::{Captures, Match, Regex};
+use std::ops::Range;
 use std::sync::LazyLock;
 use tracing::debug;
 
-use super::{parse_lang_from_script_opening_tag, SearchCapabilities};
+use super::{parse_lang_from_script_opening_tag, SearchCapabilities, SymbolCapabilities};
 
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct SvelteFileHandler;
@@ -28,6 +36,26 @@ pub static SVELTE_FENCE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r#"(?ixs)(?<opening><script(?:\s.*?)?>)\r?\n(?<script>(?U:.*))</script>"#).unwrap()
 });
 
+// The expressions of the `{#if}`, `{:else if}`, `{#each}`, `{#await}` and `{#key}` blocks
+static BLOCK_EXPRESSION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"\{(?<tag>#if|:else\s+if|#each|#await|#key)\s+(?<expression>[^}]*)\}"#).unwrap()
+});
+
+// The expression of an `{#each}` block is followed by the item, and the one of an `{#await}` block
+// by the resolved value or the error
+static BLOCK_EXPRESSION_END: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"\s(?:as|then|catch)(?:\s|$)"#).unwrap());
+
+// The components of the template, such as `<Chart`
+static COMPONENT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"<(?<name>[A-Z][\w$.]*)"#).unwrap());
+
+// The directives of the template that use a function of the script, such as `use:tooltip`
+// or `transition:fade`
+static DIRECTIVE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"\s(?:use|transition|in|out|animate):(?<name>[A-Za-z_$][\w$]*)"#).unwrap()
+});
+
 impl SvelteFileHandler {
     /// It extracts the JavaScript/TypeScript code contained in the script block of a Svelte file
     ///
@@ -93,6 +121,7 @@ impl ExtensionHandler for SvelteFileHandler {
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
+                prepare_rename: None,
                 rename: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
@@ -103,11 +132,22 @@ impl ExtensionHandler for SvelteFileHandler {
                 format_on_type: Some(format_on_type),
             },
             // TODO: We should be able to search JS portions already
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities::default(),
+            symbols: SymbolCapabilities {
+                document_symbols: None,
+                semantic_tokens: None,
+                inlay_hints: None,
+                signature_help: None,
+                folding_ranges: None,
+                selection_ranges: None,
+            },
         }
     }
 }
 
+/// A Svelte file is parsed with the HTML parser, so that the capabilities have access to all
+/// the blocks of the document. The script block is parsed with the JavaScript parser by the
+/// capabilities that need it.
 fn parse(
     _rome_path: &BiomePath,
     _file_source: DocumentFileSource,
@@ -115,19 +155,186 @@ fn parse(
     _settings: Option<&Settings>,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let script = SvelteFileHandler::input(text);
     let file_source = SvelteFileHandler::file_source(text);
 
     debug!("Parsing file with language {:?}", file_source);
 
-    let parse = parse_js_with_cache(script, file_source, JsParserOptions::default(), cache);
+    let parse = parse_html_with_cache(text, cache, HtmlParserOptions::default());
 
     ParseResult {
-        any_parse: parse.into(),
+        // The syntax errors of the script are reported by the linter. The ones of the markup
+        // aren't, because the HTML parser doesn't support the syntax of the Svelte templates.
+        any_parse: AnyParse::new(parse.syntax().as_send().unwrap(), Vec::new()),
         language: Some(file_source.into()),
     }
 }
 
+/// Returns the text of the Svelte document
+fn document(parse: &AnyParse) -> String {
+    parse.syntax::<HtmlLanguage>().to_string()
+}
+
+/// Parses the script block of the Svelte document. The ranges of the returned tree
+/// start at the beginning of the script block.
+fn parse_script(document: &str) -> AnyParse {
+    parse_js_with_cache(
+        SvelteFileHandler::input(document),
+        SvelteFileHandler::file_source(document),
+        JsParserOptions::default(),
+        &mut NodeCache::default(),
+    )
+    .into()
+}
+
+/// Returns the statements that reference the bindings of the script that the template of the
+/// Svelte `document` uses: its components, its directives and the names of its expressions.
+fn template_references(document: &str) -> String {
+    let raw_text_blocks: Vec<_> = html::RAW_TEXT_BLOCK
+        .find_iter(document)
+        .map(|block| block.range())
+        .collect();
+    let is_markup = |offset: usize| !raw_text_blocks.iter().any(|block| block.contains(&offset));
+
+    let mut references = TemplateReferences::default();
+    for captures in COMPONENT.captures_iter(document) {
+        if let Some(name) = captures.name("name").filter(|name| is_markup(name.start())) {
+            references.add_tag(name.as_str());
+        }
+    }
+    for captures in DIRECTIVE.captures_iter(document) {
+        if let Some(name) = captures.name("name").filter(|name| is_markup(name.start())) {
+            references.add_name(name.as_str());
+        }
+    }
+
+    // The expressions can contain braces, such as `{format({ short: true })}`
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, byte) in document.bytes().enumerate() {
+        match byte {
+            b'{' if is_markup(index) => {
+                if depth == 0 {
+                    start = index + 1;
+                }
+                depth += 1;
+            }
+            b'}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    references.add_expression(&document[start..index]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    references.statements(
+        SvelteFileHandler::input(document),
+        SvelteFileHandler::file_source(document),
+    )
+}
+
+/// Parses the script block of the Svelte document followed by the references of the template to
+/// the bindings of the script, so that the linter sees that the template uses them.
+/// The ranges of the returned tree start at the beginning of the script block.
+fn parse_script_with_references(document: &str) -> AnyParse {
+    parse_js_with_cache(
+        &format!(
+            "{}{}",
+            SvelteFileHandler::input(document),
+            template_references(document)
+        ),
+        SvelteFileHandler::file_source(document),
+        JsParserOptions::default(),
+        &mut NodeCache::default(),
+    )
+    .into()
+}
+
+/// Parses the script block and the expressions of the blocks of the template as a single program,
+/// so that the expressions are linted in the scope of the script.
+///
+/// The program has the same length and the same line breaks as the document, so its ranges are
+/// the ones of the document. The rest of the document is replaced by spaces, and each expression
+/// becomes a statement. For example, `{#if count > 0}` becomes `   ;(count > 0)`. The program
+/// ends with the references of the template to the bindings of the script.
+///
+/// The expressions that aren't valid JavaScript expressions are skipped.
+fn parse_program(document: &str) -> AnyParse {
+    let file_source = SvelteFileHandler::file_source(document);
+    let mut program = String::with_capacity(document.len());
+    for character in document.chars() {
+        match character {
+            '\n' | '\r' => program.push(character),
+            _ => program.push_str(&" ".repeat(character.len_utf8())),
+        }
+    }
+
+    if let Some(script) = SvelteFileHandler::matches_script(document) {
+        program.replace_range(script.range(), script.as_str());
+    }
+
+    let raw_text_blocks: Vec<_> = html::RAW_TEXT_BLOCK
+        .find_iter(document)
+        .map(|block| block.range())
+        .collect();
+    for captures in BLOCK_EXPRESSION.captures_iter(document) {
+        let Some(expression) = block_expression(&captures) else {
+            continue;
+        };
+        if raw_text_blocks
+            .iter()
+            .any(|block| block.contains(&expression.start))
+        {
+            continue;
+        }
+        let source = &document[expression.clone()];
+        let parse = biome_js_parser::parse(
+            &format!("({source})"),
+            file_source,
+            JsParserOptions::default(),
+        );
+        if parse.has_errors() {
+            continue;
+        }
+
+        // The expression is preceded by the tag and a space, and followed by a space or by `}`
+        program.replace_range(expression.start - 2..expression.start, ";(");
+        program.replace_range(expression.clone(), source);
+        program.replace_range(expression.end..expression.end + 1, ")");
+    }
+    program.push_str(&template_references(document));
+
+    parse_js_with_cache(
+        &program,
+        file_source,
+        JsParserOptions::default(),
+        &mut NodeCache::default(),
+    )
+    .into()
+}
+
+/// Returns the range of the expression of a block of the template, without the item
+/// of an `{#each}` block or the value of an `{#await}` block.
+fn block_expression(captures: &Captures) -> Option<Range<usize>> {
+    let tag = captures.name("tag")?;
+    let expression = captures.name("expression")?;
+    let text = expression.as_str();
+    let end = match tag.as_str() {
+        "#each" | "#await" => BLOCK_EXPRESSION_END
+            .find(text)
+            .map_or(text.len(), |end| end.start()),
+        _ => text.len(),
+    };
+    let length = text[..end].trim_end().len();
+
+    (length > 0).then(|| expression.start()..expression.start() + length)
+}
+
+/// It formats the whole document:
+/// - the markup with the HTML formatter, when it's enabled;
+/// - the script block with the JavaScript formatter;
+/// - the style blocks with the CSS formatter.
 #[tracing::instrument(level = "trace", skip(parse, settings))]
 fn format(
     biome_path: &BiomePath,
@@ -135,8 +342,47 @@ fn format(
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format(biome_path, document_file_source, parse, settings)
+    let document = document(&parse);
+    let (format_with_errors, html_formatter_enabled) =
+        settings.settings().map_or((false, false), |settings| {
+            (
+                settings.formatter().format_with_errors,
+                !settings.html_formatter_disabled(),
+            )
+        });
+    let html_options = settings.format_options::<HtmlLanguage>(
+        biome_path,
+        &DocumentFileSource::Html(HtmlFileSource::html()),
+    );
+    #[cfg(feature = "css")]
+    let css_options = settings
+        .format_options::<CssLanguage>(biome_path, &DocumentFileSource::Css(CssFileSource::css()));
+
+    let script = parse_script(&document);
+    if !format_with_errors && script.has_errors() {
+        return Err(WorkspaceError::format_with_errors_disabled());
+    }
+
+    let mut output = if html_formatter_enabled {
+        html::format_markup(&document, html_options)?
+    } else {
+        document
+    };
+    let printed = javascript::format(
+        biome_path,
+        document_file_source,
+        parse_script(&output),
+        settings,
+    )?;
+    if !printed.as_code().is_empty() {
+        output = SvelteFileHandler::output(&output, printed.as_code());
+    }
+    #[cfg(feature = "css")]
+    let output = embedded_css::format(&output, css_options, None)?;
+
+    Ok(Printed::new(output, None, Vec::new(), Vec::new()))
 }
+
 pub(crate) fn format_range(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
@@ -144,7 +390,8 @@ pub(crate) fn format_range(
     settings: WorkspaceSettingsHandle,
     range: TextRange,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format_range(biome_path, document_file_source, parse, settings, range)
+    let script = parse_script(&document(&parse));
+    javascript::format_range(biome_path, document_file_source, script, settings, range)
 }
 
 pub(crate) fn format_on_type(
@@ -154,21 +401,74 @@ pub(crate) fn format_on_type(
     settings: WorkspaceSettingsHandle,
     offset: TextSize,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format_on_type(biome_path, document_file_source, parse, settings, offset)
+    let script = parse_script(&document(&parse));
+    javascript::format_on_type(biome_path, document_file_source, script, settings, offset)
 }
 
+/// It lints the script block and the expressions of the blocks of the template with the
+/// JavaScript rules, and the style blocks and the `style` attributes with the CSS rules.
+/// The ranges of the diagnostics are the ones of the document.
+///
+/// The bindings of the script that are only used by the template aren't reported as unused.
 pub(crate) fn lint(params: LintParams) -> LintResults {
-    javascript::lint(params)
+    let document = document(&params.parse);
+
+    let mut results = javascript::lint(LintParams {
+        parse: parse_program(&document),
+        only: params.only.clone(),
+        skip: params.skip.clone(),
+        manifest: params.manifest.clone(),
+        #[cfg(feature = "css")]
+        css_project: params.css_project.clone(),
+        js_project: params.js_project.clone(),
+        json_schemas: params.json_schemas.clone(),
+        #[cfg(feature = "graphql")]
+        graphql_schema: params.graphql_schema.clone(),
+        #[cfg(feature = "graphql")]
+        graphql_project: params.graphql_project.clone(),
+        angular_project: params.angular_project.clone(),
+        suppression_reason: params.suppression_reason.clone(),
+        ..params
+    });
+
+    #[cfg(feature = "css")]
+    {
+        let max_diagnostics = params
+            .max_diagnostics
+            .saturating_sub(results.diagnostics.len() as u32);
+        let css_results = embedded_css::lint(
+            &document,
+            LintParams {
+                max_diagnostics,
+                ..params
+            },
+        );
+        results.diagnostics.extend(css_results.diagnostics);
+        results.errors += css_results.errors;
+        results.skipped_diagnostics += css_results.skipped_diagnostics;
+    }
+
+    results
 }
 
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
-    javascript::code_actions(params)
+    let script = parse_script_with_references(&document(&params.parse));
+    javascript::code_actions(CodeActionsParams {
+        parse: script,
+        ..params
+    })
 }
 
 fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
-    javascript::fix_all(params)
+    let document = document(&params.parse);
+    template_references::fix_all(
+        params,
+        SvelteFileHandler::input(&document),
+        &template_references(&document),
+        SvelteFileHandler::file_source(&document),
+    )
 }
 
 fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
-    javascript::organize_imports(parse)
+    javascript::organize_imports(parse_script(&document(&parse)))
 }
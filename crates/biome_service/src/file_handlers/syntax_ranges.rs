@@ -0,0 +1,110 @@
+//! The ranges of the syntax trees that are shared by the languages: the folding ranges of the
+//! blocks and of the comments, and the selection ranges.
+
+use crate::workspace::{FoldingRange, FoldingRangeKind};
+use biome_parser::AnyParse;
+use biome_rowan::{
+    Direction, Language, SyntaxNode, SyntaxToken, TextRange, TextSize, TokenAtOffset,
+};
+
+/// Returns the folding range of a node that ends with a closing bracket, from the start of
+/// the node to the end of the token before the bracket, so that the bracket stays visible
+pub(crate) fn block_folding_range<L: Language>(node: &SyntaxNode<L>) -> Option<FoldingRange> {
+    let start = node.text_trimmed_range().start();
+    let end = node.last_token()?.prev_token()?.text_trimmed_range().end();
+    (start < end).then(|| FoldingRange {
+        range: TextRange::new(start, end),
+        kind: FoldingRangeKind::Block,
+    })
+}
+
+/// Pushes the folding ranges of the comments that span several lines, and of the regions
+/// delimited by the `#region` and `#endregion` comments
+pub(crate) fn push_comment_folding_ranges<L: Language>(
+    root: &SyntaxNode<L>,
+    ranges: &mut Vec<FoldingRange>,
+) {
+    let mut region_starts = Vec::new();
+    for token in root.descendants_tokens(Direction::Next) {
+        let trivia = token
+            .leading_trivia()
+            .pieces()
+            .chain(token.trailing_trivia().pieces());
+        for comment in trivia.filter_map(|piece| piece.as_comments()) {
+            let text = comment.text();
+            let text = ["//", "/*", "<!--"]
+                .into_iter()
+                .find_map(|opener| text.strip_prefix(opener))
+                .unwrap_or(text)
+                .trim_start();
+
+            let range = comment.text_range();
+            if text.starts_with("#region") {
+                region_starts.push(range.start());
+            } else if text.starts_with("#endregion") {
+                // An `#endregion` without `#region` doesn't fold anything
+                if let Some(start) = region_starts.pop() {
+                    ranges.push(FoldingRange {
+                        range: TextRange::new(start, range.end()),
+                        kind: FoldingRangeKind::Region,
+                    });
+                }
+            } else if comment.has_newline() {
+                ranges.push(FoldingRange {
+                    range,
+                    kind: FoldingRangeKind::Comment,
+                });
+            }
+        }
+    }
+}
+
+/// Returns, for each offset, the range of the token at the offset and the ranges of the nodes
+/// that contain it, from the smallest to the largest. The ranges that are equal to the
+/// previous one are skipped.
+pub(crate) fn selection_ranges<L: Language + 'static>(
+    parse: AnyParse,
+    offsets: Vec<TextSize>,
+) -> Vec<Vec<TextRange>> {
+    let root = parse.syntax::<L>();
+    offsets
+        .into_iter()
+        .map(|offset| {
+            let token = match root.token_at_offset(offset) {
+                TokenAtOffset::None => return Vec::new(),
+                TokenAtOffset::Single(token) => token,
+                TokenAtOffset::Between(left, right) => pick_token(left, right, offset),
+            };
+
+            let mut ranges = vec![token.text_trimmed_range()];
+            for node in token.ancestors() {
+                let range = node.text_trimmed_range();
+                if ranges.last() != Some(&range) {
+                    ranges.push(range);
+                }
+            }
+            ranges
+        })
+        .collect()
+}
+
+/// Picks the token selected by a cursor between two tokens: the token that touches the
+/// cursor, and the word when both touch it, as in `a|.b`
+fn pick_token<L: Language>(
+    left: SyntaxToken<L>,
+    right: SyntaxToken<L>,
+    offset: TextSize,
+) -> SyntaxToken<L> {
+    let is_word = |token: &SyntaxToken<L>| {
+        token
+            .text_trimmed()
+            .starts_with(|c: char| c.is_alphanumeric() || c == '_' || c == '$')
+    };
+    let left_touches = left.text_trimmed_range().end() == offset;
+    let right_touches = right.text_trimmed_range().start() == offset;
+    match (left_touches, right_touches) {
+        (true, true) if is_word(&left) && !is_word(&right) => left,
+        (true, false) => left,
+        _ => right,
+    }
+}
@@ -0,0 +1,217 @@
+use crate::file_handlers::{javascript, FixAllParams};
+use crate::workspace::FixFileResult;
+use crate::WorkspaceError;
+use biome_js_formatter::format_node;
+use biome_js_parser::{parse, parse_js_with_cache, JsParserOptions};
+use biome_js_semantic::{semantic_model, SemanticModelOptions};
+use biome_js_syntax::{JsFileSource, JsLanguage};
+use biome_rowan::NodeCache;
+use rustc_hash::FxHashSet;
+use std::collections::BTreeSet;
+
+/// The names used by the template of a Vue or Svelte component, such as the name of the
+/// component `<Chart />`, or the names of the expression `{{ count + 1 }}`.
+///
+/// The script of a component doesn't see its template, so the bindings that are only used by
+/// the template look unused to the linter. The bindings of the script that the template uses
+/// are referenced by statements appended to the script, such as `;Chart;count;`, so that
+/// the rules such as `noUnusedImports` and `noUnusedVariables` see them as used.
+#[derive(Debug, Default)]
+pub(crate) struct TemplateReferences {
+    names: FxHashSet<String>,
+}
+
+impl TemplateReferences {
+    pub(crate) fn add_name(&mut self, name: &str) {
+        self.names.insert(name.to_string());
+    }
+
+    /// Adds the name of a tag, and its name in PascalCase when it's in kebab-case,
+    /// such as `MyChart` for `<my-chart>`. The namespace of a tag such as `<Charts.Line>`
+    /// is the name that is used.
+    pub(crate) fn add_tag(&mut self, tag: &str) {
+        let name = tag.split('.').next().unwrap_or(tag);
+        if name.contains('-') {
+            self.names.insert(to_pascal_case(name));
+        }
+        self.add_name(name);
+    }
+
+    /// Adds the name of the binding of a custom directive of a Vue template, such as `vFocus`
+    /// for `v-focus`.
+    pub(crate) fn add_directive(&mut self, directive: &str) {
+        if let Some(name) = directive.strip_prefix("v-") {
+            self.names.insert(format!("v{}", to_pascal_case(name)));
+        }
+    }
+
+    /// Adds the identifiers of the JavaScript `expression`. The names of the properties,
+    /// such as `b` in `a.b`, and the content of the strings are skipped.
+    pub(crate) fn add_expression(&mut self, expression: &str) {
+        let bytes = expression.as_bytes();
+        let mut index = 0;
+        while index < bytes.len() {
+            let byte = bytes[index];
+            if matches!(byte, b'"' | b'\'' | b'`') {
+                index += 1;
+                while index < bytes.len() && bytes[index] != byte {
+                    if bytes[index] == b'\\' {
+                        index += 1;
+                    }
+                    index += 1;
+                }
+                index += 1;
+            } else if is_identifier_start(byte) {
+                let start = index;
+                while index < bytes.len() && is_identifier_part(bytes[index]) {
+                    index += 1;
+                }
+                let before = expression[..start].trim_end();
+                let is_property = before.ends_with('.') && !before.ends_with("...");
+                if !is_property {
+                    self.add_name(&expression[start..index]);
+                }
+            } else if byte.is_ascii_digit() {
+                while index < bytes.len() && is_identifier_part(bytes[index]) {
+                    index += 1;
+                }
+            } else {
+                index += 1;
+            }
+        }
+    }
+
+    /// Returns the statements that reference the top-level bindings of the `script` that
+    /// the template uses, in alphabetical order. They start with a line break, so that they
+    /// don't join the last line of the script, such as a comment.
+    ///
+    /// It returns an empty string when the template doesn't use any binding of the script.
+    pub(crate) fn statements(&self, script: &str, file_source: JsFileSource) -> String {
+        let parse = parse(script, file_source, JsParserOptions::default());
+        let model = semantic_model(&parse.tree(), SemanticModelOptions::default());
+        let names: BTreeSet<_> = model
+            .global_scope()
+            .bindings()
+            .filter_map(|binding| binding.tree().name_token().ok())
+            .map(|token| token.text_trimmed().to_string())
+            .filter(|name| self.names.contains(name))
+            .collect();
+        if names.is_empty() {
+            return String::new();
+        }
+
+        let mut statements = String::from("\n");
+        for name in names {
+            statements.push(';');
+            statements.push_str(&name);
+        }
+        statements.push(';');
+        statements
+    }
+}
+
+/// It applies the fixes to the `script` of a Vue or Svelte component followed by the `references`
+/// of its template, so that the bindings used by the template aren't removed, and returns
+/// the fixed script.
+pub(crate) fn fix_all(
+    params: FixAllParams,
+    script: &str,
+    references: &str,
+    file_source: JsFileSource,
+) -> Result<FixFileResult, WorkspaceError> {
+    // The references would be formatted with the script, so the script is formatted
+    // once they are removed
+    let should_format = params.should_format;
+    let format_options = params
+        .workspace
+        .format_options::<JsLanguage>(params.biome_path, &params.document_file_source);
+    let program = format!("{script}{references}");
+    let result = javascript::fix_all(FixAllParams {
+        parse: parse_js_with_cache(
+            &program,
+            file_source,
+            JsParserOptions::default(),
+            &mut NodeCache::default(),
+        )
+        .into(),
+        should_format: false,
+        ..params
+    })?;
+
+    // The rules don't report the references, so their fixes don't change them. The fixes are
+    // skipped otherwise, because the script can't be told apart from the references.
+    let Some(code) = result.code.strip_suffix(references) else {
+        return Ok(FixFileResult {
+            code: script.to_string(),
+            actions: Vec::new(),
+            ..result
+        });
+    };
+    let code = if should_format {
+        let parse = parse(code, file_source, JsParserOptions::default());
+        format_node(format_options, &parse.syntax())?
+            .print()?
+            .into_code()
+    } else {
+        code.to_string()
+    };
+
+    Ok(FixFileResult { code, ..result })
+}
+
+fn is_identifier_start(byte: u8) -> bool {
+    byte.is_ascii_alphabetic() || matches!(byte, b'_' | b'$')
+}
+
+fn is_identifier_part(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'$')
+}
+
+/// Converts a kebab-case name to PascalCase, such as `MyChart` for `my-chart`
+fn to_pascal_case(name: &str) -> String {
+    name.split('-')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map_or(String::new(), |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn references_the_used_bindings() {
+        let script = r#"import Chart from "./Chart.vue";
+import MyTable from "./MyTable.vue";
+import Unused from "./Unused.vue";
+const count = 0;
+const label = "Count";
+const vFocus = { mounted: (element) => element.focus() };
+const rest = {};
+// A comment"#;
+
+        let mut references = TemplateReferences::default();
+        references.add_tag("Chart");
+        references.add_tag("my-table");
+        references.add_tag("div");
+        references.add_directive("v-focus");
+        references.add_expression("count + other.label + 'label'");
+        references.add_expression("{ ...rest }");
+
+        assert_eq!(
+            references.statements(script, JsFileSource::ts()),
+            "\n;Chart;MyTable;count;rest;vFocus;"
+        );
+    }
+
+    #[test]
+    fn converts_to_pascal_case() {
+        assert_eq!(to_pascal_case("my-chart"), "MyChart");
+        assert_eq!(to_pascal_case("chart"), "Chart");
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(feature = "css")]
+use crate::file_handlers::embedded_css;
+use crate::file_handlers::template_references::{self, TemplateReferences};
 use crate::file_handlers::{
     javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
     ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
@@ -8,8 +11,13 @@ use crate::workspace::{
     DocumentFileSource, FixFileResult, OrganizeImportsResult, PullActionsResult,
 };
 use crate::WorkspaceError;
+#[cfg(feature = "css")]
+use biome_css_syntax::{CssFileSource, CssLanguage};
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
+use biome_html_formatter::HtmlFormatOptions;
+use biome_html_parser::{parse_html, parse_html_with_cache, HtmlParserOptions};
+use biome_html_syntax::{HtmlFileSource, HtmlLanguage};
 use biome_js_parser::{parse_js_with_cache, JsParserOptions};
 use biome_js_syntax::{EmbeddingKind, JsFileSource, TextRange, TextSize};
 use biome_parser::AnyParse;
@@ -18,7 +26,7 @@ use regex::{Match, Regex};
 use std::sync::LazyLock;
 use tracing::debug;
 
-use super::{parse_lang_from_script_opening_tag, SearchCapabilities};
+use super::{parse_lang_from_script_opening_tag, SearchCapabilities, SymbolCapabilities};
 
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct VueFileHandler;
@@ -28,6 +36,26 @@ pub static VUE_FENCE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r#"(?ixs)(?<opening><script(?:\s.*?)?>)\r?\n(?<script>(?U:.*))</script>"#).unwrap()
 });
 
+// The `<template>` elements can be nested, so the block ends at the last closing tag
+static TEMPLATE_FENCE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?is)<template(?:\s[^>]*)?>.*</template>"#).unwrap());
+
+// The tags of the template, such as `<Chart` or `<my-table`
+static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"<(?<name>[A-Za-z][\w.-]*)"#).unwrap());
+
+// The interpolations of the template, such as `{{ count }}`
+static INTERPOLATION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?s)\{\{(?<expression>.*?)\}\}"#).unwrap());
+
+// The directives of the template, such as `v-if="visible"`, `:title="title"`, `@click="toggle"`
+// or `v-focus`
+static DIRECTIVE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"\s(?<name>v-[\w-]+|[:@#])[^\s=>/]*(?:\s*=\s*(?:"(?<double>[^"]*)"|'(?<single>[^']*)'))?"#,
+    )
+    .unwrap()
+});
+
 impl VueFileHandler {
     /// It extracts the JavaScript/TypeScript code contained in the script block of a Vue file
     ///
@@ -93,6 +121,7 @@ impl ExtensionHandler for VueFileHandler {
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
+                prepare_rename: None,
                 rename: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
@@ -103,11 +132,22 @@ impl ExtensionHandler for VueFileHandler {
                 format_on_type: Some(format_on_type),
             },
             // TODO: We should be able to search JS portions already
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities::default(),
+            symbols: SymbolCapabilities {
+                document_symbols: None,
+                semantic_tokens: None,
+                inlay_hints: None,
+                signature_help: None,
+                folding_ranges: None,
+                selection_ranges: None,
+            },
         }
     }
 }
 
+/// A Vue file is parsed with the HTML parser, so that the capabilities have access to all
+/// the blocks of the document. The script block is parsed with the JavaScript parser by the
+/// capabilities that need it.
 fn parse(
     _rome_path: &BiomePath,
     _file_source: DocumentFileSource,
@@ -115,19 +155,92 @@ fn parse(
     _settings: Option<&Settings>,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let script = VueFileHandler::input(text);
     let file_source = VueFileHandler::file_source(text);
 
     debug!("Parsing file with language {:?}", file_source);
 
-    let parse = parse_js_with_cache(script, file_source, JsParserOptions::default(), cache);
+    let parse = parse_html_with_cache(text, cache, HtmlParserOptions::default());
 
     ParseResult {
-        any_parse: parse.into(),
+        // The syntax errors of the script are reported by the linter. The ones of the template
+        // aren't, because the HTML parser doesn't support all the syntax of the Vue templates.
+        any_parse: AnyParse::new(parse.syntax().as_send().unwrap(), Vec::new()),
         language: Some(file_source.into()),
     }
 }
 
+/// Returns the text of the Vue document
+fn document(parse: &AnyParse) -> String {
+    parse.syntax::<HtmlLanguage>().to_string()
+}
+
+/// Parses the script block of the Vue document. The ranges of the returned tree
+/// start at the beginning of the script block.
+fn parse_script(document: &str) -> AnyParse {
+    parse_js_with_cache(
+        VueFileHandler::input(document),
+        VueFileHandler::file_source(document),
+        JsParserOptions::default(),
+        &mut NodeCache::default(),
+    )
+    .into()
+}
+
+/// Returns the statements that reference the bindings of the script that the template of the
+/// Vue `document` uses: its components, its custom directives and the names of its expressions.
+fn template_references(document: &str) -> String {
+    let Some(template) = TEMPLATE_FENCE.find(document) else {
+        return String::new();
+    };
+    let template = template.as_str();
+
+    let mut references = TemplateReferences::default();
+    for captures in TAG.captures_iter(template) {
+        if let Some(name) = captures.name("name") {
+            references.add_tag(name.as_str());
+        }
+    }
+    for captures in INTERPOLATION.captures_iter(template) {
+        if let Some(expression) = captures.name("expression") {
+            references.add_expression(expression.as_str());
+        }
+    }
+    for captures in DIRECTIVE.captures_iter(template) {
+        if let Some(name) = captures.name("name") {
+            references.add_directive(name.as_str());
+        }
+        if let Some(value) = captures.name("double").or_else(|| captures.name("single")) {
+            references.add_expression(value.as_str());
+        }
+    }
+
+    references.statements(
+        VueFileHandler::input(document),
+        VueFileHandler::file_source(document),
+    )
+}
+
+/// Parses the script block of the Vue document followed by the references of the template to
+/// the bindings of the script, so that the linter sees that the template uses them.
+/// The ranges of the returned tree start at the beginning of the script block.
+fn parse_script_with_references(document: &str) -> AnyParse {
+    parse_js_with_cache(
+        &format!(
+            "{}{}",
+            VueFileHandler::input(document),
+            template_references(document)
+        ),
+        VueFileHandler::file_source(document),
+        JsParserOptions::default(),
+        &mut NodeCache::default(),
+    )
+    .into()
+}
+
+/// It formats the whole document:
+/// - the script block with the JavaScript formatter;
+/// - the template with the HTML formatter, when it's enabled;
+/// - the style blocks with the CSS formatter.
 #[tracing::instrument(level = "trace", skip(parse, settings))]
 fn format(
     biome_path: &BiomePath,
@@ -135,7 +248,63 @@ fn format(
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format(biome_path, document_file_source, parse, settings)
+    let document = document(&parse);
+    let (format_with_errors, html_formatter_enabled) =
+        settings.settings().map_or((false, false), |settings| {
+            (
+                settings.formatter().format_with_errors,
+                !settings.html_formatter_disabled(),
+            )
+        });
+    let html_options = settings.format_options::<HtmlLanguage>(
+        biome_path,
+        &DocumentFileSource::Html(HtmlFileSource::html()),
+    );
+    #[cfg(feature = "css")]
+    let css_options = settings
+        .format_options::<CssLanguage>(biome_path, &DocumentFileSource::Css(CssFileSource::css()));
+
+    let script = parse_script(&document);
+    if !format_with_errors && script.has_errors() {
+        return Err(WorkspaceError::format_with_errors_disabled());
+    }
+    let printed = javascript::format(biome_path, document_file_source, script, settings)?;
+    let mut output = if printed.as_code().is_empty() {
+        document
+    } else {
+        VueFileHandler::output(&document, printed.as_code())
+    };
+
+    if html_formatter_enabled {
+        output = format_template(&output, html_options)?;
+    }
+    #[cfg(feature = "css")]
+    let output = embedded_css::format(&output, css_options, None)?;
+
+    Ok(Printed::new(output, None, Vec::new(), Vec::new()))
+}
+
+/// It formats the `<template>` block of the document `text`. The template is left as it is
+/// if it contains syntax errors, such as the syntax that the HTML parser doesn't support.
+fn format_template(text: &str, options: HtmlFormatOptions) -> Result<String, WorkspaceError> {
+    let Some(template) = TEMPLATE_FENCE.find(text) else {
+        return Ok(text.to_string());
+    };
+    let parse = parse_html(template.as_str(), HtmlParserOptions::default());
+    if parse.has_errors() {
+        return Ok(text.to_string());
+    }
+
+    let formatted = biome_html_formatter::format_node(options, &parse.syntax())?;
+    let printed = formatted
+        .print()
+        .map_err(|error| WorkspaceError::FormatError(error.into()))?;
+    Ok(format!(
+        "{}{}{}",
+        &text[..template.start()],
+        printed.as_code().trim_end(),
+        &text[template.end()..]
+    ))
 }
 
 pub(crate) fn format_range(
@@ -145,7 +314,8 @@ pub(crate) fn format_range(
     settings: WorkspaceSettingsHandle,
     range: TextRange,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format_range(biome_path, document_file_source, parse, settings, range)
+    let script = parse_script(&document(&parse));
+    javascript::format_range(biome_path, document_file_source, script, settings, range)
 }
 
 pub(crate) fn format_on_type(
@@ -155,21 +325,79 @@ pub(crate) fn format_on_type(
     settings: WorkspaceSettingsHandle,
     offset: TextSize,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format_on_type(biome_path, document_file_source, parse, settings, offset)
+    let script = parse_script(&document(&parse));
+    javascript::format_on_type(biome_path, document_file_source, script, settings, offset)
 }
 
+/// It lints the script block with the JavaScript rules, and the style blocks and the `style`
+/// attributes with the CSS rules. The ranges of the diagnostics are the ones of the document.
+///
+/// The bindings of the script that are only used by the template aren't reported as unused.
 pub(crate) fn lint(params: LintParams) -> LintResults {
-    javascript::lint(params)
+    let document = document(&params.parse);
+    let offset = VueFileHandler::start(&document).map_or(TextSize::from(0), TextSize::from);
+
+    let mut results = javascript::lint(LintParams {
+        parse: parse_script_with_references(&document),
+        only: params.only.clone(),
+        skip: params.skip.clone(),
+        manifest: params.manifest.clone(),
+        #[cfg(feature = "css")]
+        css_project: params.css_project.clone(),
+        js_project: params.js_project.clone(),
+        json_schemas: params.json_schemas.clone(),
+        #[cfg(feature = "graphql")]
+        graphql_schema: params.graphql_schema.clone(),
+        #[cfg(feature = "graphql")]
+        graphql_project: params.graphql_project.clone(),
+        angular_project: params.angular_project.clone(),
+        suppression_reason: params.suppression_reason.clone(),
+        ..params
+    });
+    results.diagnostics = results
+        .diagnostics
+        .into_iter()
+        .map(|diagnostic| diagnostic.with_offset(offset))
+        .collect();
+
+    #[cfg(feature = "css")]
+    {
+        let max_diagnostics = params
+            .max_diagnostics
+            .saturating_sub(results.diagnostics.len() as u32);
+        let css_results = embedded_css::lint(
+            &document,
+            LintParams {
+                max_diagnostics,
+                ..params
+            },
+        );
+        results.diagnostics.extend(css_results.diagnostics);
+        results.errors += css_results.errors;
+        results.skipped_diagnostics += css_results.skipped_diagnostics;
+    }
+
+    results
 }
 
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
-    javascript::code_actions(params)
+    let script = parse_script_with_references(&document(&params.parse));
+    javascript::code_actions(CodeActionsParams {
+        parse: script,
+        ..params
+    })
 }
 
 fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
-    javascript::fix_all(params)
+    let document = document(&params.parse);
+    template_references::fix_all(
+        params,
+        VueFileHandler::input(&document),
+        &template_references(&document),
+        VueFileHandler::file_source(&document),
+    )
 }
 
 fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
-    javascript::organize_imports(parse)
+    javascript::organize_imports(parse_script(&document(&parse)))
 }
@@ -7,6 +7,7 @@ pub mod documentation;
 pub mod file_handlers;
 
 pub mod matcher;
+pub mod project_index;
 pub mod settings;
 pub mod workspace;
 
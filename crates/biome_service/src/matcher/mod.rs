@@ -97,6 +97,13 @@ impl Matcher {
     }
 
     fn run_match(&self, source: &Path) -> bool {
+        self.matching_pattern(source).is_some()
+    }
+
+    /// Returns the first stored pattern that matches the given path, if any.
+    ///
+    /// Unlike [Matcher::matches_path], the result isn't cached.
+    pub fn matching_pattern(&self, source: &Path) -> Option<&str> {
         for pattern in &self.patterns {
             let matches = if pattern.matches_path_with(source, self.options) {
                 true
@@ -116,10 +123,10 @@ impl Matcher {
             };
 
             if matches {
-                return true;
+                return Some(pattern.as_str());
             }
         }
-        false
+        None
     }
 }
 
@@ -0,0 +1,173 @@
+//! The snapshot of the project index, which the clients persist between two runs, so that the
+//! files that didn't change since the previous run aren't scanned again.
+//!
+//! A snapshot is only valid for the version of Biome that wrote it. Each of its files records the
+//! hash of the content it was indexed from, and the files whose content changed are discarded.
+
+use crate::file_handlers::AngularComponent;
+#[cfg(feature = "css")]
+use biome_css_semantic::CssFileIndex;
+#[cfg(feature = "graphql")]
+use biome_graphql_semantic::GraphqlFileIndex;
+use biome_js_semantic::JsFileIndex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::num::ParseIntError;
+use std::path::PathBuf;
+
+/// The version of the format of the snapshot. It's bumped when the indexes of the files change
+/// in a way that the snapshots written before can't be read anymore.
+pub const FORMAT_VERSION: u32 = 2;
+
+/// Returns the hash of the content of a file, recorded with its index.
+///
+/// The content is hashed with the 64-bit FNV-1a algorithm. Unlike the hasher of the standard
+/// library, it doesn't change between versions of Rust, so the hashes computed by the clients,
+/// such as the WebAssembly bindings, stay valid when they're saved.
+pub fn content_hash(content: &str) -> ContentHash {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let hash = content.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    });
+    ContentHash(hash)
+}
+
+/// The hash of the content of a file.
+///
+/// It's written as a hexadecimal string, because a JavaScript number can't represent all
+/// the 64-bit values.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct ContentHash(u64);
+
+impl fmt::Display for ContentHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+impl From<ContentHash> for String {
+    fn from(hash: ContentHash) -> Self {
+        hash.to_string()
+    }
+}
+
+impl TryFrom<String> for ContentHash {
+    type Error = ParseIntError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        u64::from_str_radix(&value, 16).map(Self)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for ContentHash {
+    fn schema_name() -> String {
+        "ContentHash".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <String>::json_schema(gen)
+    }
+}
+
+/// The indexes of the files of the project, with the version of Biome that computed them
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProjectIndexSnapshot {
+    version: String,
+    format: u32,
+    pub files: BTreeMap<PathBuf, IndexedFile>,
+}
+
+impl Default for ProjectIndexSnapshot {
+    fn default() -> Self {
+        Self {
+            version: biome_configuration::VERSION.to_string(),
+            format: FORMAT_VERSION,
+            files: BTreeMap::new(),
+        }
+    }
+}
+
+impl ProjectIndexSnapshot {
+    /// Reads a snapshot, or returns `None` if it's invalid or if it was written by another
+    /// version of Biome
+    pub fn from_json(json: &str) -> Option<Self> {
+        let snapshot: Self = serde_json::from_str(json).ok()?;
+        let current = Self::default();
+        (snapshot.version == current.version && snapshot.format == current.format)
+            .then_some(snapshot)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// The indexes of a single file.
+///
+/// The indexes of the languages whose feature is disabled are skipped when a snapshot is read.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct IndexedFile {
+    /// The hash of the content the indexes were computed from
+    pub content_hash: ContentHash,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub script: Option<JsFileIndex>,
+    #[cfg(feature = "css")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stylesheet: Option<CssFileIndex>,
+    #[cfg(feature = "graphql")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub graphql: Option<GraphqlFileIndex>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub angular_components: Option<Vec<AngularComponent>>,
+}
+
+impl IndexedFile {
+    pub fn is_empty(&self) -> bool {
+        #[cfg(feature = "css")]
+        if self.stylesheet.is_some() {
+            return false;
+        }
+        #[cfg(feature = "graphql")]
+        if self.graphql.is_some() {
+            return false;
+        }
+        self.script.is_none() && self.angular_components.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discards_the_snapshots_of_other_versions() {
+        let mut snapshot = ProjectIndexSnapshot::default();
+        snapshot.files.insert(
+            PathBuf::from("src/index.js"),
+            IndexedFile {
+                content_hash: content_hash("import './style.css';"),
+                script: Some(JsFileIndex::default()),
+                ..Default::default()
+            },
+        );
+
+        let json = snapshot.to_json();
+        let read = ProjectIndexSnapshot::from_json(&json).expect("the snapshot to be read");
+        assert_eq!(read.files.len(), 1);
+
+        snapshot.format = FORMAT_VERSION + 1;
+        assert!(ProjectIndexSnapshot::from_json(&snapshot.to_json()).is_none());
+        assert!(ProjectIndexSnapshot::from_json("{").is_none());
+    }
+
+    #[test]
+    fn content_hash_is_fnv_1a() {
+        assert_eq!(content_hash("").to_string(), "cbf29ce484222325");
+        assert_eq!(content_hash("a").to_string(), "af63dc4c8601ec8c");
+    }
+}
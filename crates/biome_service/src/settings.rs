@@ -2,18 +2,27 @@ use crate::workspace::{DocumentFileSource, ProjectKey, WorkspaceData};
 use crate::{Matcher, WorkspaceError};
 use biome_analyze::{AnalyzerOptions, AnalyzerRules};
 use biome_configuration::analyzer::assists::AssistsConfiguration;
+use biome_configuration::analyzer::{RuleDomains, SuppressionReason};
 use biome_configuration::diagnostics::InvalidIgnorePattern;
 use biome_configuration::javascript::JsxRuntime;
 use biome_configuration::organize_imports::OrganizeImports;
+#[cfg(feature = "css")]
+use biome_configuration::PartialCssConfiguration;
+#[cfg(feature = "graphql")]
+use biome_configuration::PartialGraphqlConfiguration;
 use biome_configuration::{
-    push_to_analyzer_rules, BiomeDiagnostic, FilesConfiguration, FormatterConfiguration,
-    JavascriptConfiguration, LinterConfiguration, OverrideAssistsConfiguration,
-    OverrideFormatterConfiguration, OverrideLinterConfiguration,
-    OverrideOrganizeImportsConfiguration, Overrides, PartialConfiguration, PartialCssConfiguration,
-    PartialGraphqlConfiguration, PartialJavascriptConfiguration, PartialJsonConfiguration,
+    push_to_analyzer_assists, push_to_analyzer_rules, BiomeDiagnostic, FileLanguage,
+    FilesConfiguration, FormatterConfiguration, JavascriptConfiguration, JsonSchemas,
+    LinterConfiguration, OverrideAssistsConfiguration, OverrideFormatterConfiguration,
+    OverrideLinterConfiguration, OverrideOrganizeImportsConfiguration, Overrides,
+    PartialConfiguration, PartialHtmlConfiguration, PartialJavascriptConfiguration,
+    PartialJsonConfiguration,
 };
+#[cfg(feature = "css")]
 use biome_css_formatter::context::CssFormatOptions;
+#[cfg(feature = "css")]
 use biome_css_parser::CssParserOptions;
+#[cfg(feature = "css")]
 use biome_css_syntax::CssLanguage;
 use biome_deserialize::{Merge, StringSet};
 use biome_diagnostics::Category;
@@ -21,9 +30,13 @@ use biome_formatter::{
     AttributePosition, BracketSpacing, IndentStyle, IndentWidth, LineEnding, LineWidth,
 };
 use biome_fs::BiomePath;
+#[cfg(feature = "graphql")]
 use biome_graphql_formatter::context::GraphqlFormatOptions;
+#[cfg(feature = "graphql")]
 use biome_graphql_syntax::GraphqlLanguage;
+#[cfg(feature = "grit")]
 use biome_grit_formatter::context::GritFormatOptions;
+#[cfg(feature = "grit")]
 use biome_grit_syntax::GritLanguage;
 use biome_html_formatter::HtmlFormatOptions;
 use biome_html_syntax::HtmlLanguage;
@@ -34,7 +47,8 @@ use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
 use biome_project::{NodeJsProject, PackageJson};
-use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::gitignore::{Gitignore, GitignoreBuilder, Glob};
+use ignore::Match;
 use indexmap::IndexSet;
 use rustc_hash::FxHashMap;
 use std::borrow::Cow;
@@ -149,31 +163,39 @@ impl WorkspaceSettings {
         }
     }
 
+    /// Returns the root paths of the registered projects
+    pub fn project_paths(&self) -> impl Iterator<Item = &BiomePath> {
+        self.data.iter().map(|(_, project_data)| &project_data.path)
+    }
+
+    /// Returns the key of the project registered with the root `workspace_path`
+    pub fn get_project_key(&self, workspace_path: &Path) -> Option<ProjectKey> {
+        self.data
+            .iter()
+            .find(|(_, project_data)| project_data.path.as_path() == workspace_path)
+            .map(|(key, _)| key)
+    }
+
     /// Checks if the current path belongs to a registered project.
     ///
-    /// If there's a match, and the match **isn't** the current project, it returns the new key.
+    /// When the projects are nested, the path belongs to the nearest project, which is the
+    /// project with the longest root. If there's a match, and the match **isn't** the current
+    /// project, it returns the new key.
     pub fn path_belongs_to_current_workspace(&self, path: &BiomePath) -> Option<ProjectKey> {
-        if self.data.is_empty() {
-            return None;
-        }
         trace!("Current key: {:?}", self.current_project);
-        let iter = self.data.iter();
-        for (key, path_to_settings) in iter {
-            trace!(
-                "Workspace path {:?}, file path {:?}",
-                path_to_settings.path,
-                path
-            );
-            trace!("Iter key: {:?}", key);
-            if key == self.current_project {
-                continue;
-            }
-            if path.strip_prefix(path_to_settings.path.as_path()).is_ok() {
-                trace!("Update workspace to {:?}", key);
-                return Some(key);
-            }
-        }
-        None
+        let key = self.get_project_key_for(path)?;
+        trace!("Nearest workspace key: {:?}", key);
+        (key != self.current_project).then_some(key)
+    }
+
+    /// Returns the key of the nearest project that contains `path`, which is the project
+    /// with the longest root when the projects are nested
+    pub fn get_project_key_for(&self, path: &BiomePath) -> Option<ProjectKey> {
+        self.data
+            .iter()
+            .filter(|(_, project_data)| path.strip_prefix(project_data.path.as_path()).is_ok())
+            .max_by_key(|(_, project_data)| project_data.path.components().count())
+            .map(|(key, _)| key)
     }
 
     /// Checks if the current path belongs to a registered project.
@@ -201,6 +223,11 @@ pub struct Settings {
     pub assists: AssistsSettings,
     /// overrides
     pub override_settings: OverrideSettings,
+    /// The JSON schemas associated with the files of the workspace
+    pub json_schemas: JsonSchemaSettings,
+    /// The configuration that the settings are computed from, with the configurations that
+    /// it extends merged in
+    pub configuration: PartialConfiguration,
 }
 
 impl Settings {
@@ -213,6 +240,8 @@ impl Settings {
         vcs_path: Option<PathBuf>,
         gitignore_matches: &[String],
     ) -> Result<(), WorkspaceError> {
+        self.configuration = configuration.clone();
+
         // formatter part
         if let Some(formatter) = configuration.formatter {
             self.formatter = to_format_settings(
@@ -257,16 +286,33 @@ impl Settings {
             self.languages.javascript = JavascriptConfiguration::from(javascript).into();
         }
         // json settings
-        if let Some(json) = configuration.json {
+        if let Some(mut json) = configuration.json {
+            if let Some(schemas) = json.schemas.take() {
+                self.json_schemas = to_json_schema_settings(working_directory.clone(), schemas)?;
+            }
             self.languages.json = json.into()
         }
         // css settings
+        #[cfg(feature = "css")]
         if let Some(css) = configuration.css {
             self.languages.css = css.into()
         }
         // graphql settings
+        #[cfg(feature = "graphql")]
         if let Some(graphql) = configuration.graphql {
-            self.languages.graphql = graphql.into()
+            let schema = graphql
+                .linter
+                .as_ref()
+                .and_then(|linter| linter.schema.clone());
+            self.languages.graphql = graphql.into();
+            self.languages.graphql.linter.schema = schema.map(|schema| match &working_directory {
+                Some(working_directory) => working_directory.join(schema),
+                None => PathBuf::from(schema),
+            });
+        }
+        // html settings
+        if let Some(html) = configuration.html {
+            self.languages.html = html.into()
         }
 
         // NOTE: keep this last. Computing the overrides require reading the settings computed by the parent settings.
@@ -295,9 +341,25 @@ impl Settings {
         enabled == Some(&false)
     }
 
-    /// Whether the formatter is disabled for CSS files
+    /// Whether the formatter is disabled for CSS files, as it is when the `css` feature is disabled
     pub fn css_formatter_disabled(&self) -> bool {
+        #[cfg(feature = "css")]
         let enabled = self.languages.css.formatter.enabled.as_ref();
+        #[cfg(not(feature = "css"))]
+        let enabled = Some(&false);
+        enabled == Some(&false)
+    }
+
+    /// Whether the formatter is disabled for GraphQL files
+    #[cfg(feature = "graphql")]
+    pub fn graphql_formatter_disabled(&self) -> bool {
+        let enabled = self.languages.graphql.formatter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
+    /// Whether the formatter is disabled for HTML files
+    pub fn html_formatter_disabled(&self) -> bool {
+        let enabled = self.languages.html.formatter.enabled.as_ref();
         enabled == Some(&false)
     }
 
@@ -313,13 +375,23 @@ impl Settings {
         enabled == Some(&false)
     }
 
-    /// Whether the linter is disabled for CSS files
+    /// Whether the linter is disabled for CSS files, as it is when the `css` feature is disabled
     pub fn css_linter_disabled(&self) -> bool {
+        #[cfg(feature = "css")]
         let enabled = self.languages.css.linter.enabled.as_ref();
+        #[cfg(not(feature = "css"))]
+        let enabled = Some(&false);
         trace!("CSS LINTER DISABLED {:?}", enabled);
         enabled == Some(&false)
     }
 
+    /// Whether the linter is disabled for GraphQL files
+    #[cfg(feature = "graphql")]
+    pub fn graphql_linter_disabled(&self) -> bool {
+        let enabled = self.languages.graphql.linter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
     /// Retrieves the settings of the linter
     pub fn linter(&self) -> &LinterSettings {
         &self.linter
@@ -358,8 +430,24 @@ impl Settings {
         path: &Path,
     ) -> Option<Cow<biome_configuration::analyzer::linter::Rules>> {
         let mut result = self.linter.rules.as_ref().map(Cow::Borrowed);
+        // The rules configured explicitly take precedence over the domains
+        if !self.linter.domains.is_empty() {
+            let mut rules = self.linter.domains.as_rules();
+            if let Some(result) = result.take() {
+                rules.merge_with(result.into_owned());
+            }
+            result = Some(Cow::Owned(rules));
+        }
         let overrides = &self.override_settings;
         for pattern in overrides.patterns.iter() {
+            let pattern_domains = pattern.linter.domains.as_ref();
+            if let Some(pattern_domains) = pattern_domains {
+                if pattern.include.matches_path(path) && !pattern.exclude.matches_path(path) {
+                    let mut rules = result.map(Cow::into_owned).unwrap_or_default();
+                    rules.merge_with(pattern_domains.as_rules());
+                    result = Some(Cow::Owned(rules));
+                }
+            }
             let pattern_rules = pattern.linter.rules.as_ref();
             if let Some(pattern_rules) = pattern_rules {
                 if pattern.include.matches_path(path) && !pattern.exclude.matches_path(path) {
@@ -376,6 +464,36 @@ impl Settings {
         result
     }
 
+    /// Returns the configuration that applies to `path`, written in JSON: the fields of the
+    /// overrides that match `path` are merged into the configuration, in order.
+    pub fn resolved_configuration(&self, path: &Path) -> serde_json::Value {
+        let mut resolved = serde_json::to_value(&self.configuration).unwrap_or_default();
+        let overrides = self
+            .configuration
+            .overrides
+            .as_ref()
+            .map(|overrides| overrides.0.as_slice())
+            .unwrap_or_default();
+        for (pattern, settings) in overrides.iter().zip(&self.override_settings.patterns) {
+            if !settings.include.matches_path(path) || settings.exclude.matches_path(path) {
+                continue;
+            }
+            let Ok(mut fields) = serde_json::to_value(pattern) else {
+                continue;
+            };
+            if let Some(fields) = fields.as_object_mut() {
+                fields.remove("include");
+                fields.remove("ignore");
+            }
+            merge_json_values(&mut resolved, fields);
+        }
+        // The overrides are applied
+        if let Some(resolved) = resolved.as_object_mut() {
+            resolved.remove("overrides");
+        }
+        resolved
+    }
+
     /// Returns assists rules taking overrides into account.
     pub fn as_assists_rules(
         &self,
@@ -463,6 +581,12 @@ pub struct LinterSettings {
     /// List of rules
     pub rules: Option<biome_configuration::analyzer::linter::Rules>,
 
+    /// The domains of rules that are enabled
+    pub domains: RuleDomains,
+
+    /// Whether the suppression comments must have an explanation
+    pub suppression_reason: SuppressionReason,
+
     /// List of ignored paths/files to match
     pub ignored_files: Matcher,
 
@@ -475,6 +599,8 @@ impl Default for LinterSettings {
         Self {
             enabled: true,
             rules: Some(biome_configuration::analyzer::linter::Rules::default()),
+            domains: RuleDomains::default(),
+            suppression_reason: SuppressionReason::default(),
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
         }
@@ -489,6 +615,9 @@ pub struct OverrideLinterSettings {
 
     /// List of rules
     pub rules: Option<biome_configuration::analyzer::linter::Rules>,
+
+    /// The domains of rules that are enabled
+    pub domains: Option<RuleDomains>,
 }
 
 /// Linter settings for the entire workspace
@@ -563,9 +692,12 @@ pub struct OverrideAssistsSettings {
 pub struct LanguageListSettings {
     pub javascript: LanguageSettings<JsLanguage>,
     pub json: LanguageSettings<JsonLanguage>,
+    #[cfg(feature = "css")]
     pub css: LanguageSettings<CssLanguage>,
+    #[cfg(feature = "graphql")]
     pub graphql: LanguageSettings<GraphqlLanguage>,
     pub html: LanguageSettings<HtmlLanguage>,
+    #[cfg(feature = "grit")]
     pub grit: LanguageSettings<GritLanguage>,
 }
 
@@ -606,6 +738,7 @@ impl From<PartialJsonConfiguration> for LanguageSettings<JsonLanguage> {
         if let Some(parser) = json.parser {
             language_setting.parser.allow_comments = parser.allow_comments;
             language_setting.parser.allow_trailing_commas = parser.allow_trailing_commas;
+            language_setting.parser.json5 = parser.json5;
         }
         if let Some(formatter) = json.formatter {
             language_setting.formatter.trailing_commas = formatter.trailing_commas;
@@ -622,6 +755,7 @@ impl From<PartialJsonConfiguration> for LanguageSettings<JsonLanguage> {
     }
 }
 
+#[cfg(feature = "css")]
 impl From<PartialCssConfiguration> for LanguageSettings<CssLanguage> {
     fn from(css: PartialCssConfiguration) -> Self {
         let mut language_setting: LanguageSettings<CssLanguage> = LanguageSettings::default();
@@ -637,6 +771,9 @@ impl From<PartialCssConfiguration> for LanguageSettings<CssLanguage> {
             language_setting.formatter.line_width = formatter.line_width;
             language_setting.formatter.line_ending = formatter.line_ending;
             language_setting.formatter.quote_style = formatter.quote_style;
+            language_setting.formatter.hex_color_case = formatter.hex_color_case;
+            language_setting.formatter.keyword_case = formatter.keyword_case;
+            language_setting.formatter.url_quotes = formatter.url_quotes;
         }
         if let Some(linter) = css.linter {
             language_setting.linter.enabled = linter.enabled;
@@ -646,6 +783,7 @@ impl From<PartialCssConfiguration> for LanguageSettings<CssLanguage> {
     }
 }
 
+#[cfg(feature = "graphql")]
 impl From<PartialGraphqlConfiguration> for LanguageSettings<GraphqlLanguage> {
     fn from(graphql: PartialGraphqlConfiguration) -> Self {
         let mut language_setting: LanguageSettings<GraphqlLanguage> = LanguageSettings::default();
@@ -659,6 +797,11 @@ impl From<PartialGraphqlConfiguration> for LanguageSettings<GraphqlLanguage> {
             language_setting.formatter.line_ending = formatter.line_ending;
             language_setting.formatter.quote_style = formatter.quote_style;
             language_setting.formatter.bracket_spacing = formatter.bracket_spacing;
+            language_setting.formatter.directive_order = formatter.directive_order;
+            language_setting.formatter.single_field_selection_sets =
+                formatter.single_field_selection_sets;
+            language_setting.formatter.empty_line_between_definitions =
+                formatter.empty_line_between_definitions;
         }
 
         if let Some(linter) = graphql.linter {
@@ -670,6 +813,33 @@ impl From<PartialGraphqlConfiguration> for LanguageSettings<GraphqlLanguage> {
     }
 }
 
+impl From<PartialHtmlConfiguration> for LanguageSettings<HtmlLanguage> {
+    fn from(html: PartialHtmlConfiguration) -> Self {
+        let mut language_setting: LanguageSettings<HtmlLanguage> = LanguageSettings::default();
+
+        if let Some(formatter) = html.formatter {
+            // TODO: change RHS to `formatter.enabled` when html formatting is enabled by default
+            language_setting.formatter.enabled = Some(formatter.enabled.unwrap_or_default());
+            language_setting.formatter.indent_width = formatter.indent_width;
+            language_setting.formatter.indent_style = formatter.indent_style;
+            language_setting.formatter.line_width = formatter.line_width;
+            language_setting.formatter.line_ending = formatter.line_ending;
+            language_setting.formatter.attribute_position = formatter.attribute_position;
+            language_setting.formatter.bracket_same_line = formatter.bracket_same_line;
+            language_setting.formatter.whitespace_sensitivity = formatter.whitespace_sensitivity;
+        }
+
+        if let Some(parser) = html.parser {
+            language_setting.parser.ignore_delimiters = parser
+                .ignore_delimiters
+                .map(|delimiters| delimiters.0)
+                .unwrap_or_default();
+        }
+
+        language_setting
+    }
+}
+
 pub trait ServiceLanguage: biome_rowan::Language {
     /// Formatter settings type for this language
     type FormatterSettings: Default;
@@ -741,6 +911,9 @@ pub struct FilesSettings {
     /// File size limit in bytes
     pub max_size: NonZeroU64,
 
+    /// File size limits in bytes of the languages, which take precedence over `max_size`
+    pub max_size_per_language: FxHashMap<FileLanguage, NonZeroU64>,
+
     /// gitignore file patterns
     pub git_ignore: Option<Gitignore>,
 
@@ -752,6 +925,94 @@ pub struct FilesSettings {
 
     /// Files not recognized by Biome should not emit a diagnostic
     pub ignore_unknown: bool,
+
+    /// The languages associated with the files that match a pattern, in the order of the
+    /// configuration
+    pub associations: Vec<FileAssociationSettings>,
+}
+
+impl FilesSettings {
+    /// Returns the size limit of the files of the language `file_source`
+    pub fn max_size_for(&self, file_source: &DocumentFileSource) -> NonZeroU64 {
+        to_file_language(file_source)
+            .and_then(|language| self.max_size_per_language.get(&language))
+            .copied()
+            .unwrap_or(self.max_size)
+    }
+
+    /// Returns the language of the first association that matches the file at `path`
+    pub fn associated_file_source(&self, path: &Path) -> Option<DocumentFileSource> {
+        self.associations
+            .iter()
+            .find(|association| association.include.matches_path(path))
+            .map(|association| association.file_source)
+    }
+
+    /// Returns the pattern of the ignore files that matches the file at `path`, with the
+    /// semantics of git
+    pub fn git_ignore_match(&self, path: &Path, is_dir: bool) -> Match<&Glob> {
+        match &self.git_ignore {
+            Some(git_ignore) => matched_path_or_parents(git_ignore, path, is_dir),
+            None => Match::None,
+        }
+    }
+}
+
+/// Matches `path` with the patterns of `git_ignore` like git does: the parent directories are
+/// matched first, from the root, because a file can't be included again by a negated pattern
+/// once one of its parent directories is ignored.
+pub(crate) fn matched_path_or_parents<'a>(
+    git_ignore: &'a Gitignore,
+    path: &Path,
+    is_dir: bool,
+) -> Match<&'a Glob> {
+    // The absolute paths outside of the root of the patterns can't be ignored
+    if path.has_root() && !path.starts_with(git_ignore.path()) {
+        return Match::None;
+    }
+    let path = path.strip_prefix(git_ignore.path()).unwrap_or(path);
+    let mut parents: Vec<_> = path
+        .ancestors()
+        .skip(1)
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .collect();
+    parents.reverse();
+    for parent in parents {
+        let matched = git_ignore.matched(parent, true);
+        if matched.is_ignore() {
+            return matched;
+        }
+    }
+    git_ignore.matched(path, is_dir)
+}
+
+/// Returns the language of the configuration that `file_source` belongs to
+fn to_file_language(file_source: &DocumentFileSource) -> Option<FileLanguage> {
+    match file_source {
+        DocumentFileSource::Js(file_source) => {
+            Some(match (file_source.is_typescript(), file_source.is_jsx()) {
+                (false, false) => FileLanguage::Js,
+                (false, true) => FileLanguage::Jsx,
+                (true, false) => FileLanguage::Ts,
+                (true, true) => FileLanguage::Tsx,
+            })
+        }
+        DocumentFileSource::Json(file_source) if file_source.allow_comments() => {
+            Some(FileLanguage::Jsonc)
+        }
+        DocumentFileSource::Json(_) => Some(FileLanguage::Json),
+        DocumentFileSource::Css(_) => Some(FileLanguage::Css),
+        DocumentFileSource::Graphql(_) => Some(FileLanguage::Graphql),
+        _ => None,
+    }
+}
+
+#[derive(Debug)]
+pub struct FileAssociationSettings {
+    /// The files associated with the language
+    pub include: Matcher,
+    /// The language of the files
+    pub file_source: DocumentFileSource,
 }
 
 /// Limit the size of files to 1.0 MiB by default
@@ -763,10 +1024,12 @@ impl Default for FilesSettings {
     fn default() -> Self {
         Self {
             max_size: DEFAULT_FILE_SIZE_LIMIT,
+            max_size_per_language: FxHashMap::default(),
             git_ignore: None,
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
             ignore_unknown: false,
+            associations: Vec::new(),
         }
     }
 }
@@ -790,12 +1053,24 @@ fn to_file_settings(
         None
     };
     Ok(if let Some(config) = config {
+        let mut associations = Vec::with_capacity(config.associations.0.len());
+        for (pattern, language) in config.associations.0 {
+            associations.push(FileAssociationSettings {
+                include: to_matcher(
+                    working_directory.clone(),
+                    Some(&StringSet::from_iter([pattern])),
+                )?,
+                file_source: DocumentFileSource::from_extension(language.extension()),
+            });
+        }
         Some(FilesSettings {
             max_size: config.max_size,
+            max_size_per_language: config.max_size_per_language.0.into_iter().collect(),
             git_ignore,
             ignored_files: to_matcher(working_directory.clone(), Some(&config.ignore))?,
             included_files: to_matcher(working_directory, Some(&config.include))?,
             ignore_unknown: config.ignore_unknown,
+            associations,
         })
     } else {
         None
@@ -983,6 +1258,7 @@ impl OverrideSettings {
     }
 
     /// It scans the current override rules and return the formatting options that of the first override is matched
+    #[cfg(feature = "css")]
     pub fn to_override_css_format_options(
         &self,
         path: &Path,
@@ -997,6 +1273,7 @@ impl OverrideSettings {
     }
 
     /// It scans the current override rules and return the formatting options that of the first override is matched
+    #[cfg(feature = "graphql")]
     pub fn to_override_graphql_format_options(
         &self,
         path: &Path,
@@ -1010,6 +1287,7 @@ impl OverrideSettings {
         options
     }
 
+    #[cfg(feature = "grit")]
     pub fn to_override_grit_format_options(
         &self,
         path: &Path,
@@ -1063,6 +1341,7 @@ impl OverrideSettings {
     }
 
     /// It scans the current override rules and return the parser options that of the first override is matched
+    #[cfg(feature = "css")]
     pub fn to_override_css_parser_options(
         &self,
         path: &Path,
@@ -1076,7 +1355,7 @@ impl OverrideSettings {
         options
     }
 
-    /// Retrieves the options of lint rules that have been overridden
+    /// Retrieves the options of lint rules and assists that have been overridden
     pub fn override_analyzer_rules(
         &self,
         path: &Path,
@@ -1095,13 +1374,44 @@ impl OverrideSettings {
                         biome_json_analyze::METADATA.deref(),
                         &mut analyzer_rules,
                     );
+                    #[cfg(feature = "css")]
                     push_to_analyzer_rules(
                         rules,
                         biome_css_analyze::METADATA.deref(),
                         &mut analyzer_rules,
                     );
+                    #[cfg(feature = "graphql")]
+                    push_to_analyzer_rules(
+                        rules,
+                        biome_graphql_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
                     push_to_analyzer_rules(
                         rules,
+                        biome_html_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
+                }
+                if let Some(actions) = pattern.assists.actions.as_ref() {
+                    push_to_analyzer_assists(
+                        actions,
+                        biome_js_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
+                    push_to_analyzer_assists(
+                        actions,
+                        biome_json_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
+                    #[cfg(feature = "css")]
+                    push_to_analyzer_assists(
+                        actions,
+                        biome_css_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
+                    #[cfg(feature = "graphql")]
+                    push_to_analyzer_assists(
+                        actions,
                         biome_graphql_analyze::METADATA.deref(),
                         &mut analyzer_rules,
                     );
@@ -1164,6 +1474,30 @@ impl OverrideSettings {
     }
 }
 
+/// The JSON schemas associated with the files of the workspace
+#[derive(Debug, Default)]
+pub struct JsonSchemaSettings {
+    pub associations: Vec<JsonSchemaAssociationSettings>,
+}
+
+impl JsonSchemaSettings {
+    /// Returns the path of the first schema associated with the file at `path`
+    pub fn schema_for(&self, path: &Path) -> Option<&Path> {
+        self.associations
+            .iter()
+            .find(|association| association.include.matches_path(path))
+            .map(|association| association.schema.as_path())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct JsonSchemaAssociationSettings {
+    /// The files that must satisfy the schema
+    pub include: Matcher,
+    /// The path of the schema
+    pub schema: PathBuf,
+}
+
 #[derive(Debug, Default)]
 pub struct OverrideSettingPattern {
     pub exclude: Matcher,
@@ -1185,12 +1519,16 @@ pub struct OverrideSettingPattern {
     // So we cannot reuse the same format options for all js-family files.
     pub(crate) cached_js_format_options: RwLock<FxHashMap<JsFileSource, JsFormatOptions>>,
     pub(crate) cached_json_format_options: RwLock<Option<JsonFormatOptions>>,
+    #[cfg(feature = "css")]
     pub(crate) cached_css_format_options: RwLock<Option<CssFormatOptions>>,
+    #[cfg(feature = "grit")]
     pub(crate) cached_grit_format_options: RwLock<Option<GritFormatOptions>>,
+    #[cfg(feature = "graphql")]
     pub(crate) cached_graphql_format_options: RwLock<Option<GraphqlFormatOptions>>,
     pub(crate) cached_html_format_options: RwLock<Option<HtmlFormatOptions>>,
     pub(crate) cached_js_parser_options: RwLock<Option<JsParserOptions>>,
     pub(crate) _cached_json_parser_options: RwLock<Option<JsonParserOptions>>,
+    #[cfg(feature = "css")]
     pub(crate) cached_css_parser_options: RwLock<Option<CssParserOptions>>,
 }
 impl OverrideSettingPattern {
@@ -1286,6 +1624,7 @@ impl OverrideSettingPattern {
         }
     }
 
+    #[cfg(feature = "css")]
     fn apply_overrides_to_css_format_options(&self, options: &mut CssFormatOptions) {
         if let Ok(readonly_cache) = self.cached_css_format_options.read() {
             if let Some(cached_options) = readonly_cache.as_ref() {
@@ -1312,6 +1651,15 @@ impl OverrideSettingPattern {
         if let Some(quote_style) = css_formatter.quote_style {
             options.set_quote_style(quote_style);
         }
+        if let Some(hex_color_case) = css_formatter.hex_color_case {
+            options.set_hex_color_case(hex_color_case);
+        }
+        if let Some(keyword_case) = css_formatter.keyword_case {
+            options.set_keyword_case(keyword_case);
+        }
+        if let Some(url_quotes) = css_formatter.url_quotes {
+            options.set_url_quotes(url_quotes);
+        }
 
         if let Ok(mut writeonly_cache) = self.cached_css_format_options.write() {
             let options = options.clone();
@@ -1319,6 +1667,7 @@ impl OverrideSettingPattern {
         }
     }
 
+    #[cfg(feature = "graphql")]
     fn apply_overrides_to_graphql_format_options(&self, options: &mut GraphqlFormatOptions) {
         if let Ok(readonly_cache) = self.cached_graphql_format_options.read() {
             if let Some(cached_options) = readonly_cache.as_ref() {
@@ -1351,6 +1700,17 @@ impl OverrideSettingPattern {
         if let Some(quote_style) = graphql_formatter.quote_style {
             options.set_quote_style(quote_style);
         }
+        if let Some(directive_order) = graphql_formatter.directive_order {
+            options.set_directive_order(directive_order);
+        }
+        if let Some(single_field_selection_sets) = graphql_formatter.single_field_selection_sets {
+            options.set_single_field_selection_sets(single_field_selection_sets);
+        }
+        if let Some(empty_line_between_definitions) =
+            graphql_formatter.empty_line_between_definitions
+        {
+            options.set_empty_line_between_definitions(empty_line_between_definitions);
+        }
 
         if let Ok(mut writeonly_cache) = self.cached_graphql_format_options.write() {
             let options = options.clone();
@@ -1358,6 +1718,7 @@ impl OverrideSettingPattern {
         }
     }
 
+    #[cfg(feature = "grit")]
     fn apply_overrides_to_grit_format_options(&self, options: &mut GritFormatOptions) {
         if let Ok(readonly_cache) = self.cached_grit_format_options.read() {
             if let Some(cached_options) = readonly_cache.as_ref() {
@@ -1411,6 +1772,18 @@ impl OverrideSettingPattern {
         if let Some(line_width) = html_formatter.line_width.or(formatter.line_width) {
             options.set_line_width(line_width);
         }
+        if let Some(attribute_position) = html_formatter
+            .attribute_position
+            .or(formatter.attribute_position)
+        {
+            options.set_attribute_position(attribute_position);
+        }
+        if let Some(bracket_same_line) = html_formatter.bracket_same_line {
+            options.set_bracket_same_line(bracket_same_line);
+        }
+        if let Some(whitespace_sensitivity) = html_formatter.whitespace_sensitivity {
+            options.set_whitespace_sensitivity(whitespace_sensitivity);
+        }
 
         if let Ok(mut writeonly_cache) = self.cached_html_format_options.write() {
             let options = options.clone();
@@ -1446,8 +1819,14 @@ impl OverrideSettingPattern {
         if let Some(allow_trailing_commas) = json_parser.allow_trailing_commas {
             options.allow_trailing_commas = allow_trailing_commas;
         }
+        match json_parser.json5 {
+            Some(true) => *options = options.with_json5(),
+            Some(false) => options.json5 = false,
+            None => {}
+        }
     }
 
+    #[cfg(feature = "css")]
     fn apply_overrides_to_css_parser_options(&self, options: &mut CssParserOptions) {
         if let Ok(readonly_cache) = self.cached_css_parser_options.read() {
             if let Some(cached_options) = readonly_cache.as_ref() {
@@ -1482,6 +1861,27 @@ impl OverrideSettingPattern {
 /// ## Errors
 ///
 /// It can raise an error if the patterns aren't valid
+fn to_json_schema_settings(
+    working_directory: Option<PathBuf>,
+    schemas: JsonSchemas,
+) -> Result<JsonSchemaSettings, WorkspaceError> {
+    let mut settings = JsonSchemaSettings::default();
+    for association in schemas.0 {
+        let Some(schema) = association.schema else {
+            continue;
+        };
+        let schema = match &working_directory {
+            Some(working_directory) => working_directory.join(schema),
+            None => PathBuf::from(schema),
+        };
+        settings.associations.push(JsonSchemaAssociationSettings {
+            include: to_matcher(working_directory.clone(), association.include.as_ref())?,
+            schema,
+        });
+    }
+    Ok(settings)
+}
+
 pub fn to_matcher(
     working_directory: Option<PathBuf>,
     string_set: Option<&StringSet>,
@@ -1503,6 +1903,37 @@ pub fn to_matcher(
     Ok(matcher)
 }
 
+/// Returns the matchers of the files that an override includes and excludes. The patterns of
+/// `include` that start with `!` exclude the files they match, and an `include` made only of
+/// such patterns includes all the other files.
+fn to_override_matchers(
+    working_directory: Option<PathBuf>,
+    include: Option<&StringSet>,
+    ignore: Option<&StringSet>,
+) -> Result<(Matcher, Matcher), WorkspaceError> {
+    let mut include_matcher = to_matcher(working_directory.clone(), None)?;
+    let mut exclude_matcher = to_matcher(working_directory, ignore)?;
+    let mut has_negated_patterns = false;
+    for pattern in include.into_iter().flat_map(|include| include.iter()) {
+        let (matcher, glob) = match pattern.strip_prefix('!') {
+            Some(glob) => {
+                has_negated_patterns = true;
+                (&mut exclude_matcher, glob)
+            }
+            None => (&mut include_matcher, pattern.as_str()),
+        };
+        matcher.add_pattern(glob).map_err(|err| {
+            BiomeDiagnostic::new_invalid_ignore_pattern(pattern.to_string(), err.msg.to_string())
+        })?;
+    }
+    if has_negated_patterns && include_matcher.is_empty() {
+        include_matcher.add_pattern("**").map_err(|err| {
+            BiomeDiagnostic::new_invalid_ignore_pattern("**".to_string(), err.msg.to_string())
+        })?;
+    }
+    Ok((include_matcher, exclude_matcher))
+}
+
 fn to_git_ignore(path: PathBuf, matches: &[String]) -> Result<Gitignore, WorkspaceError> {
     let mut gitignore_builder = GitignoreBuilder::new(path.clone());
 
@@ -1550,6 +1981,24 @@ impl TryFrom<OverrideOrganizeImportsConfiguration> for OrganizeImportsSettings {
     }
 }
 
+/// Merges `value` into `target`: the properties of two objects are merged, and any other
+/// value replaces `target`
+pub(crate) fn merge_json_values(target: &mut serde_json::Value, value: serde_json::Value) {
+    match (target, value) {
+        (serde_json::Value::Object(target), serde_json::Value::Object(value)) => {
+            for (key, value) in value {
+                match target.get_mut(&key) {
+                    Some(target) => merge_json_values(target, value),
+                    None => {
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (target, value) => *target = value,
+    }
+}
+
 pub fn to_override_settings(
     working_directory: Option<PathBuf>,
     overrides: Overrides,
@@ -1577,6 +2026,7 @@ pub fn to_override_settings(
             .map(|linter| OverrideLinterSettings {
                 enabled: linter.enabled,
                 rules: linter.rules,
+                domains: linter.domains,
             })
             .unwrap_or_default();
         let organize_imports = OverrideOrganizeImportsSettings {
@@ -1584,26 +2034,45 @@ pub fn to_override_settings(
                 .organize_imports
                 .and_then(|organize_imports| organize_imports.enabled),
         };
+        let assists = pattern
+            .assists
+            .map(|assists| OverrideAssistsSettings {
+                enabled: assists.enabled,
+                actions: assists.actions,
+            })
+            .unwrap_or_default();
 
         let mut languages = LanguageListSettings::default();
         let javascript = pattern.javascript.take().unwrap_or_default();
         let json = pattern.json.take().unwrap_or_default();
+        #[cfg(feature = "css")]
         let css = pattern.css.take().unwrap_or_default();
+        #[cfg(feature = "graphql")]
         let graphql = pattern.graphql.take().unwrap_or_default();
+        let html = pattern.html.take().unwrap_or_default();
         languages.javascript =
             to_javascript_language_settings(javascript, &current_settings.languages.javascript);
 
         languages.json = to_json_language_settings(json, &current_settings.languages.json);
+        #[cfg(feature = "css")]
         languages.css = to_css_language_settings(css, &current_settings.languages.css);
+        #[cfg(feature = "graphql")]
         languages.graphql =
             to_graphql_language_settings(graphql, &current_settings.languages.graphql);
+        languages.html = to_html_language_settings(html, &current_settings.languages.html);
 
+        let (include, exclude) = to_override_matchers(
+            working_directory.clone(),
+            pattern.include.as_ref(),
+            pattern.ignore.as_ref(),
+        )?;
         let pattern_setting = OverrideSettingPattern {
-            include: to_matcher(working_directory.clone(), pattern.include.as_ref())?,
-            exclude: to_matcher(working_directory.clone(), pattern.ignore.as_ref())?,
+            include,
+            exclude,
             formatter,
             linter,
             organize_imports,
+            assists,
             languages,
             ..OverrideSettingPattern::default()
         };
@@ -1681,9 +2150,12 @@ fn to_json_language_settings(
         .allow_trailing_commas
         .or(parent_parser.allow_trailing_commas);
 
+    language_setting.parser.json5 = parser.json5.or(parent_parser.json5);
+
     language_setting
 }
 
+#[cfg(feature = "css")]
 fn to_css_language_settings(
     mut conf: PartialCssConfiguration,
     parent_settings: &LanguageSettings<CssLanguage>,
@@ -1697,6 +2169,9 @@ fn to_css_language_settings(
     language_setting.formatter.indent_width = formatter.indent_width.map(Into::into);
     language_setting.formatter.indent_style = formatter.indent_style.map(Into::into);
     language_setting.formatter.quote_style = formatter.quote_style;
+    language_setting.formatter.hex_color_case = formatter.hex_color_case;
+    language_setting.formatter.keyword_case = formatter.keyword_case;
+    language_setting.formatter.url_quotes = formatter.url_quotes;
 
     let parser = conf.parser.take().unwrap_or_default();
     let parent_parser = &parent_settings.parser;
@@ -1708,6 +2183,7 @@ fn to_css_language_settings(
     language_setting
 }
 
+#[cfg(feature = "graphql")]
 fn to_graphql_language_settings(
     mut conf: PartialGraphqlConfiguration,
     _parent_settings: &LanguageSettings<GraphqlLanguage>,
@@ -1722,6 +2198,34 @@ fn to_graphql_language_settings(
     language_setting.formatter.indent_style = formatter.indent_style.map(Into::into);
     language_setting.formatter.quote_style = formatter.quote_style;
     language_setting.formatter.bracket_spacing = formatter.bracket_spacing;
+    language_setting.formatter.directive_order = formatter.directive_order;
+    language_setting.formatter.single_field_selection_sets = formatter.single_field_selection_sets;
+    language_setting.formatter.empty_line_between_definitions =
+        formatter.empty_line_between_definitions;
+
+    language_setting
+}
+
+fn to_html_language_settings(
+    mut conf: PartialHtmlConfiguration,
+    parent_settings: &LanguageSettings<HtmlLanguage>,
+) -> LanguageSettings<HtmlLanguage> {
+    let mut language_setting: LanguageSettings<HtmlLanguage> = LanguageSettings::default();
+    let formatter = conf.formatter.take().unwrap_or_default();
+    let parser = conf.parser.take().unwrap_or_default();
+
+    language_setting.formatter.enabled = formatter.enabled;
+    language_setting.formatter.line_width = formatter.line_width;
+    language_setting.formatter.line_ending = formatter.line_ending;
+    language_setting.formatter.indent_width = formatter.indent_width;
+    language_setting.formatter.indent_style = formatter.indent_style;
+    language_setting.formatter.attribute_position = formatter.attribute_position;
+    language_setting.formatter.bracket_same_line = formatter.bracket_same_line;
+    language_setting.formatter.whitespace_sensitivity = formatter.whitespace_sensitivity;
+    language_setting.parser.ignore_delimiters = parser.ignore_delimiters.map_or_else(
+        || parent_settings.parser.ignore_delimiters.clone(),
+        |delimiters| delimiters.0,
+    );
 
     language_setting
 }
@@ -1784,6 +2288,8 @@ pub fn to_linter_settings(
     Ok(LinterSettings {
         enabled: conf.enabled,
         rules: Some(conf.rules),
+        domains: conf.domains,
+        suppression_reason: conf.suppression_reason,
         ignored_files: to_matcher(working_directory.clone(), Some(&conf.ignore))?,
         included_files: to_matcher(working_directory.clone(), Some(&conf.include))?,
     })
@@ -1796,6 +2302,8 @@ impl TryFrom<OverrideLinterConfiguration> for LinterSettings {
         Ok(Self {
             enabled: conf.enabled.unwrap_or_default(),
             rules: conf.rules,
+            domains: conf.domains.unwrap_or_default(),
+            suppression_reason: conf.suppression_reason.unwrap_or_default(),
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
         })
@@ -1820,7 +2328,7 @@ impl TryFrom<OverrideAssistsConfiguration> for AssistsSettings {
     fn try_from(conf: OverrideAssistsConfiguration) -> Result<Self, Self::Error> {
         Ok(Self {
             enabled: conf.enabled.unwrap_or_default(),
-            actions: conf.rules,
+            actions: conf.actions,
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
         })
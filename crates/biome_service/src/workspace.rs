@@ -54,6 +54,7 @@
 pub use self::client::{TransportRequest, WorkspaceClient, WorkspaceTransport};
 use crate::file_handlers::Capabilities;
 pub use crate::file_handlers::DocumentFileSource;
+use crate::project_index::ContentHash;
 use crate::settings::Settings;
 use crate::{Deserialize, Serialize, WorkspaceError};
 use biome_analyze::ActionCategory;
@@ -78,6 +79,7 @@ use std::{borrow::Cow, panic::RefUnwindSafe, sync::Arc};
 use tracing::{debug, instrument};
 
 mod client;
+mod node_cache;
 mod server;
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -154,6 +156,7 @@ impl FileFeaturesResult {
                 .insert(FeatureKind::Assists, SupportKind::Supported);
         }
 
+        #[cfg(feature = "grit")]
         if capabilities.search.search.is_some() {
             self.features_supported
                 .insert(FeatureKind::Search, SupportKind::Supported);
@@ -721,6 +724,34 @@ pub struct RenameResult {
     pub range: TextRange,
     /// List of text edit operations to apply on the source code
     pub indels: TextEdit,
+    /// The edits of the other scripts of the workspace that import the symbol,
+    /// when it's exported under its own name
+    #[serde(default)]
+    pub other_files: Vec<RenamedFile>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RenamedFile {
+    pub path: BiomePath,
+    /// List of text edit operations to apply on the source code of the file
+    pub indels: TextEdit,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PrepareRenameParams {
+    pub path: BiomePath,
+    pub symbol_at: TextSize,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PrepareRenameResult {
+    /// Range of the name of the symbol that can be renamed
+    pub range: TextRange,
+    /// The current name of the symbol
+    pub name: String,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Default, Deserialize, Serialize)]
@@ -736,7 +767,11 @@ pub struct ServerInfo {
 
 #[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-pub struct RageParams {}
+pub struct RageParams {
+    /// Whether the sizes of the caches of the workspace are reported
+    #[serde(default)]
+    pub caches: bool,
+}
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -752,6 +787,379 @@ pub enum RageEntry {
     Markup(MarkupBuf),
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetDocumentSymbolsParams {
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetDocumentSymbolsResult {
+    /// The symbols defined at the top level of the file, in the order they appear
+    pub symbols: Vec<DocumentSymbol>,
+}
+
+/// A symbol defined by a file, as shown in the outline of the file
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DocumentSymbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// The range of the whole definition of the symbol
+    pub range: TextRange,
+    /// The range of the name of the symbol
+    pub selection_range: TextRange,
+    /// The symbols defined inside the symbol, such as the methods of a class
+    /// or the nested rules of a CSS rule
+    pub children: Vec<DocumentSymbol>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SymbolKind {
+    Class,
+    Constant,
+    Enum,
+    Function,
+    Interface,
+    Method,
+    Namespace,
+    Property,
+    TypeAlias,
+    Variable,
+    /// A CSS rule, named after its selectors
+    Rule,
+    /// A CSS at-rule, such as `@media`
+    AtRule,
+    /// A top-level key of a JSON document
+    Key,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetWorkspaceSymbolsParams {
+    /// The symbols whose name contains the characters of the query, in the same order,
+    /// are returned. All the symbols are returned when the query is empty.
+    pub query: String,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetWorkspaceSymbolsResult {
+    /// The matching symbols, sorted by path and position
+    pub symbols: Vec<WorkspaceSymbol>,
+}
+
+/// A symbol defined by one of the files processed by the workspace
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WorkspaceSymbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// The file that defines the symbol
+    pub path: BiomePath,
+    /// The range of the name of the symbol
+    pub range: TextRange,
+    /// The name of the symbol that contains the symbol, such as the class of a method
+    pub container_name: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetDefinitionParams {
+    pub path: BiomePath,
+    /// The position of the import specifier or of the custom property whose definition is returned
+    pub definition_of: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetDefinitionResult {
+    /// The definitions of the symbol, empty if it can't be resolved.
+    /// A custom property can be defined more than once.
+    pub definitions: Vec<Definition>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Definition {
+    pub path: BiomePath,
+    /// The range of the definition in the file, which is empty at the start of the file
+    /// when the definition is the file itself, as for an import specifier
+    pub range: TextRange,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSemanticTokensParams {
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSemanticTokensResult {
+    /// The tokens of the file, sorted by position and without overlaps
+    pub tokens: Vec<SemanticToken>,
+}
+
+/// An identifier whose meaning is known from the semantic model of the file
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SemanticToken {
+    pub range: TextRange,
+    pub kind: SemanticTokenKind,
+    pub modifiers: Vec<SemanticTokenModifier>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SemanticTokenKind {
+    Namespace,
+    /// A type alias, or a type of a GraphQL schema
+    Type,
+    /// A class, or a class selector of a stylesheet
+    Class,
+    Enum,
+    Interface,
+    TypeParameter,
+    /// A parameter of a function, or a variable of a GraphQL operation
+    Parameter,
+    /// A variable, or a custom property of a stylesheet
+    Variable,
+    EnumMember,
+    /// A function, or a GraphQL operation or fragment
+    Function,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SemanticTokenModifier {
+    /// The token is the name of the binding in its declaration
+    Declaration,
+    /// The binding can't be reassigned, such as a `const` variable
+    Readonly,
+    /// The binding is declared by an import
+    Imported,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetInlayHintsParams {
+    pub path: BiomePath,
+    /// Only the hints inside this range are returned, usually the part of the file
+    /// visible in the editor
+    pub range: TextRange,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetInlayHintsResult {
+    /// The hints, sorted by position
+    pub hints: Vec<InlayHint>,
+}
+
+/// A piece of information shown by the editors inside the code
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct InlayHint {
+    /// The position where the hint is shown
+    pub position: TextSize,
+    /// The name of the parameter or the inferred type
+    pub label: String,
+    pub kind: InlayHintKind,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum InlayHintKind {
+    /// The name of the parameter that receives an argument, shown before the argument
+    Parameter,
+    /// The type inferred for a `const` declaration, shown after its name
+    Type,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSignatureHelpParams {
+    pub path: BiomePath,
+    /// The position of the cursor, inside the arguments of a call
+    pub position: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSignatureHelpResult {
+    /// The signature of the called function, when it's declared in the file
+    pub signature: Option<Signature>,
+}
+
+/// The signature of a function, shown by the editors while the arguments of a call are written
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Signature {
+    /// The name of the function followed by its parameters, for example `sum(a, b)`
+    pub label: String,
+    /// The description of the function in its JSDoc comment
+    pub documentation: Option<String>,
+    pub parameters: Vec<SignatureParameter>,
+    /// The index of the parameter that receives the argument under the cursor
+    pub active_parameter: Option<u32>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SignatureParameter {
+    /// The text of the parameter, as written in the label of the signature
+    pub label: String,
+    /// The type and the description of the `@param` tag of the parameter
+    pub documentation: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetFoldingRangesParams {
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetFoldingRangesResult {
+    /// The ranges, sorted by start
+    pub ranges: Vec<FoldingRange>,
+}
+
+/// A part of a file that the editors can collapse
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FoldingRange {
+    /// The range of the text hidden by the folding, which ends before the closing bracket
+    /// of the blocks
+    pub range: TextRange,
+    pub kind: FoldingRangeKind,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FoldingRangeKind {
+    /// A block of code, such as the body of a function, a class, a CSS rule or a JSON object
+    Block,
+    /// A comment that spans several lines
+    Comment,
+    /// The consecutive imports of a module
+    Imports,
+    /// The code between a `#region` comment and an `#endregion` comment
+    Region,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSelectionRangesParams {
+    pub path: BiomePath,
+    /// The positions of the cursors
+    pub positions: Vec<TextSize>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSelectionRangesResult {
+    /// For each position, the ranges of the token and of the nodes that contain it, from
+    /// the smallest to the largest
+    pub ranges: Vec<Vec<TextRange>>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetRuleConfigurationParams {
+    pub path: BiomePath,
+    /// The category of the diagnostics of the rule, for example `lint/suspicious/noDebugger`
+    pub category: String,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetRuleConfigurationResult {
+    /// The configuration of the rule that applies to the file, overrides included, written
+    /// in JSON. It's `None` when the configuration doesn't mention the rule, which then runs
+    /// with its default options.
+    pub configuration: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ResolveConfigurationParams {
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ResolveConfigurationResult {
+    /// The configuration that applies to the file, written in JSON: the configuration file
+    /// with the configurations that it extends, the `.editorconfig` file when it's enabled,
+    /// and the overrides that match the file
+    pub configuration: String,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetModuleGraphParams {}
+
+/// The imports between the scripts known by the workspace
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ModuleGraph {
+    /// The scripts, sorted by path
+    pub modules: Vec<ModuleGraphNode>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ModuleGraphNode {
+    /// The path of the script
+    pub path: String,
+    /// The paths of the scripts imported by the script
+    pub imports: Vec<String>,
+    /// The packages imported by the script
+    pub external: Vec<String>,
+    /// The relative imports that don't match any script of the workspace
+    pub unresolved: Vec<String>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExportProjectIndexParams {}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExportProjectIndexResult {
+    /// The snapshot of the project index, written in JSON
+    pub index: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ImportProjectIndexParams {
+    /// A snapshot returned by [Workspace::export_project_index]
+    pub index: String,
+    /// The files to import, with the hash of their current content. The files of the snapshot
+    /// that aren't listed, or whose hash is different, aren't imported.
+    pub content_hashes: Vec<(BiomePath, ContentHash)>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ImportProjectIndexResult {
+    /// The files whose indexes were imported, which don't need to be scanned again
+    pub imported: Vec<BiomePath>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ForgetFilesParams {
+    /// The files, or the directories, that were removed from the disk
+    pub paths: Vec<BiomePath>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct OrganizeImportsParams {
@@ -809,6 +1217,9 @@ pub struct SearchPatternParams {
 pub struct SearchResults {
     pub file: BiomePath,
     pub matches: Vec<TextRange>,
+    /// The content of the file once the rewrites of the pattern are applied,
+    /// if the pattern rewrites the file
+    pub rewritten: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -957,6 +1368,13 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Return the content of the file with all safe code actions applied
     fn fix_file(&self, params: FixFileParams) -> Result<FixFileResult, WorkspaceError>;
 
+    /// Return the range and the name of the symbol that would be renamed at the
+    /// specified position, or an error if it can't be renamed
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<PrepareRenameResult, WorkspaceError>;
+
     /// Return the content of the file after renaming a symbol
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError>;
 
@@ -980,6 +1398,97 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Returns information about the server this workspace is connected to or `None` if the workspace isn't connected to a server.
     fn server_info(&self) -> Option<&ServerInfo>;
 
+    /// Returns the imports between the scripts that were opened in the workspace,
+    /// resolved to the paths of the scripts when they are relative.
+    fn get_module_graph(&self, params: GetModuleGraphParams)
+        -> Result<ModuleGraph, WorkspaceError>;
+
+    /// Returns a snapshot of the project index, which can be imported by another workspace
+    /// with [Self::import_project_index], for example after a restart of the daemon
+    fn export_project_index(
+        &self,
+        params: ExportProjectIndexParams,
+    ) -> Result<ExportProjectIndexResult, WorkspaceError>;
+
+    /// Imports the indexes of the files of a snapshot whose content didn't change since it
+    /// was exported. The whole snapshot is discarded if it was exported by another version.
+    fn import_project_index(
+        &self,
+        params: ImportProjectIndexParams,
+    ) -> Result<ImportProjectIndexResult, WorkspaceError>;
+
+    /// Removes the files from the project index, because they were removed from the disk.
+    /// A directory removes the indexed files under it. The files that are still on the disk,
+    /// and the documents opened by the client, are kept.
+    /// The imports of the other scripts that resolved to them are resolved again.
+    fn forget_files(&self, params: ForgetFilesParams) -> Result<(), WorkspaceError>;
+
+    /// Returns the symbols defined by a file, such as its classes and its functions
+    fn get_document_symbols(
+        &self,
+        params: GetDocumentSymbolsParams,
+    ) -> Result<GetDocumentSymbolsResult, WorkspaceError>;
+
+    /// Returns the symbols defined by the files processed by the workspace whose name
+    /// matches a query, even if the files were closed since then
+    fn get_workspace_symbols(
+        &self,
+        params: GetWorkspaceSymbolsParams,
+    ) -> Result<GetWorkspaceSymbolsResult, WorkspaceError>;
+
+    /// Returns the file imported by an import specifier of a script, or the definitions
+    /// of a custom property used by a stylesheet
+    fn get_definition(
+        &self,
+        params: GetDefinitionParams,
+    ) -> Result<GetDefinitionResult, WorkspaceError>;
+
+    /// Returns the identifiers of a file classified by the semantic model, so that
+    /// the editors can highlight them
+    fn get_semantic_tokens(
+        &self,
+        params: GetSemanticTokensParams,
+    ) -> Result<GetSemanticTokensResult, WorkspaceError>;
+
+    /// Returns the names of the parameters at the call sites, and the types inferred for
+    /// the `const` declarations, of a range of a file
+    fn get_inlay_hints(
+        &self,
+        params: GetInlayHintsParams,
+    ) -> Result<GetInlayHintsResult, WorkspaceError>;
+
+    /// Returns the signature of the function called at a position, when the function is
+    /// declared in the same file
+    fn get_signature_help(
+        &self,
+        params: GetSignatureHelpParams,
+    ) -> Result<GetSignatureHelpResult, WorkspaceError>;
+
+    /// Returns the parts of a file that the editors can collapse
+    fn get_folding_ranges(
+        &self,
+        params: GetFoldingRangesParams,
+    ) -> Result<GetFoldingRangesResult, WorkspaceError>;
+
+    /// Returns the ranges that the editors select when expanding the selection from
+    /// some positions, following the syntax tree
+    fn get_selection_ranges(
+        &self,
+        params: GetSelectionRangesParams,
+    ) -> Result<GetSelectionRangesResult, WorkspaceError>;
+
+    /// Returns the configuration of a lint rule for a file
+    fn get_rule_configuration(
+        &self,
+        params: GetRuleConfigurationParams,
+    ) -> Result<GetRuleConfigurationResult, WorkspaceError>;
+
+    /// Returns the configuration that applies to a file, with the overrides that match it
+    fn resolve_configuration(
+        &self,
+        params: ResolveConfigurationParams,
+    ) -> Result<ResolveConfigurationResult, WorkspaceError>;
+
     /// Applies import sorting
     fn organize_imports(
         &self,
@@ -1,6 +1,14 @@
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, ProjectKey, RageParams, RageResult, RegisterProjectFolderParams,
+    ExportProjectIndexParams, ExportProjectIndexResult, FileFeaturesResult, ForgetFilesParams,
+    GetDefinitionParams, GetDefinitionResult, GetDocumentSymbolsParams, GetDocumentSymbolsResult,
+    GetFileContentParams, GetFoldingRangesParams, GetFoldingRangesResult, GetInlayHintsParams,
+    GetInlayHintsResult, GetModuleGraphParams, GetRuleConfigurationParams,
+    GetRuleConfigurationResult, GetSelectionRangesParams, GetSelectionRangesResult,
+    GetSemanticTokensParams, GetSemanticTokensResult, GetSignatureHelpParams,
+    GetSignatureHelpResult, GetWorkspaceSymbolsParams, GetWorkspaceSymbolsResult,
+    ImportProjectIndexParams, ImportProjectIndexResult, IsPathIgnoredParams, ModuleGraph,
+    OrganizeImportsParams, OrganizeImportsResult, ProjectKey, RageParams, RageResult,
+    RegisterProjectFolderParams, ResolveConfigurationParams, ResolveConfigurationResult,
     ServerInfo, SetManifestForProjectParams, UnregisterProjectFolderParams,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
@@ -15,9 +23,10 @@ use std::{
 use super::{
     ChangeFileParams, CloseFileParams, FixFileParams, FixFileResult, FormatFileParams,
     FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameParams, RenameResult, SearchPatternParams,
-    SearchResults, SupportsFeatureParams, UpdateSettingsParams,
+    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PrepareRenameParams,
+    PrepareRenameResult, PullActionsParams, PullActionsResult, PullDiagnosticsParams,
+    PullDiagnosticsResult, RenameParams, RenameResult, SearchPatternParams, SearchResults,
+    SupportsFeatureParams, UpdateSettingsParams,
 };
 
 pub struct WorkspaceClient<T> {
@@ -196,6 +205,13 @@ where
         self.request("biome/fix_file", params)
     }
 
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<PrepareRenameResult, WorkspaceError> {
+        self.request("biome/prepare_rename", params)
+    }
+
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError> {
         self.request("biome/rename", params)
     }
@@ -223,6 +239,101 @@ where
         self.server_info.as_ref()
     }
 
+    fn get_module_graph(
+        &self,
+        params: GetModuleGraphParams,
+    ) -> Result<ModuleGraph, WorkspaceError> {
+        self.request("biome/get_module_graph", params)
+    }
+
+    fn export_project_index(
+        &self,
+        params: ExportProjectIndexParams,
+    ) -> Result<ExportProjectIndexResult, WorkspaceError> {
+        self.request("biome/export_project_index", params)
+    }
+
+    fn import_project_index(
+        &self,
+        params: ImportProjectIndexParams,
+    ) -> Result<ImportProjectIndexResult, WorkspaceError> {
+        self.request("biome/import_project_index", params)
+    }
+
+    fn forget_files(&self, params: ForgetFilesParams) -> Result<(), WorkspaceError> {
+        self.request("biome/forget_files", params)
+    }
+
+    fn get_document_symbols(
+        &self,
+        params: GetDocumentSymbolsParams,
+    ) -> Result<GetDocumentSymbolsResult, WorkspaceError> {
+        self.request("biome/get_document_symbols", params)
+    }
+
+    fn get_workspace_symbols(
+        &self,
+        params: GetWorkspaceSymbolsParams,
+    ) -> Result<GetWorkspaceSymbolsResult, WorkspaceError> {
+        self.request("biome/get_workspace_symbols", params)
+    }
+
+    fn get_definition(
+        &self,
+        params: GetDefinitionParams,
+    ) -> Result<GetDefinitionResult, WorkspaceError> {
+        self.request("biome/get_definition", params)
+    }
+
+    fn get_semantic_tokens(
+        &self,
+        params: GetSemanticTokensParams,
+    ) -> Result<GetSemanticTokensResult, WorkspaceError> {
+        self.request("biome/get_semantic_tokens", params)
+    }
+
+    fn get_inlay_hints(
+        &self,
+        params: GetInlayHintsParams,
+    ) -> Result<GetInlayHintsResult, WorkspaceError> {
+        self.request("biome/get_inlay_hints", params)
+    }
+
+    fn get_signature_help(
+        &self,
+        params: GetSignatureHelpParams,
+    ) -> Result<GetSignatureHelpResult, WorkspaceError> {
+        self.request("biome/get_signature_help", params)
+    }
+
+    fn get_folding_ranges(
+        &self,
+        params: GetFoldingRangesParams,
+    ) -> Result<GetFoldingRangesResult, WorkspaceError> {
+        self.request("biome/get_folding_ranges", params)
+    }
+
+    fn get_selection_ranges(
+        &self,
+        params: GetSelectionRangesParams,
+    ) -> Result<GetSelectionRangesResult, WorkspaceError> {
+        self.request("biome/get_selection_ranges", params)
+    }
+
+    fn get_rule_configuration(
+        &self,
+        params: GetRuleConfigurationParams,
+    ) -> Result<GetRuleConfigurationResult, WorkspaceError> {
+        self.request("biome/get_rule_configuration", params)
+    }
+
+    fn resolve_configuration(
+        &self,
+        params: ResolveConfigurationParams,
+    ) -> Result<ResolveConfigurationResult, WorkspaceError> {
+        self.request("biome/resolve_configuration", params)
+    }
+
     fn organize_imports(
         &self,
         params: OrganizeImportsParams,
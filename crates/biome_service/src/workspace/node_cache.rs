@@ -0,0 +1,108 @@
+use biome_rowan::NodeCache;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::thread::available_parallelism;
+
+/// The caches of the green nodes of the workspace, one for each thread that can parse at the
+/// same time, so that the trees of the files retained for the project rules share their
+/// identifiers, their strings, and the nodes they have in common, instead of each document
+/// having its own cache.
+///
+/// The nodes are only shared by the documents parsed by the same thread: each thread always
+/// parses with the same cache, picked from its index, so the threads of a pool of that size
+/// never wait for each other. A text used by the documents of several threads is stored once
+/// per thread. Each cache evicts the entries that its own documents stopped using.
+pub(super) struct ThreadNodeCaches {
+    caches: Box<[Mutex<NodeCache>]>,
+}
+
+impl Default for ThreadNodeCaches {
+    fn default() -> Self {
+        let len = available_parallelism().map_or(1, NonZeroUsize::get);
+        Self {
+            caches: (0..len).map(|_| Mutex::new(NodeCache::shared())).collect(),
+        }
+    }
+}
+
+impl ThreadNodeCaches {
+    /// Calls `func` with the cache of the current thread, which is locked until `func` returns
+    pub(super) fn with<R>(&self, func: impl FnOnce(&mut NodeCache) -> R) -> R {
+        let cache = &self.caches[thread_index() % self.caches.len()];
+        let mut cache = cache.lock().unwrap_or_else(PoisonError::into_inner);
+        func(&mut cache)
+    }
+}
+
+/// Returns the index of the current thread, which is assigned the first time it's requested
+fn thread_index() -> usize {
+    static NEXT_INDEX: AtomicUsize = AtomicUsize::new(0);
+    thread_local! {
+        static INDEX: usize = NEXT_INDEX.fetch_add(1, Ordering::Relaxed);
+    }
+    INDEX.with(|index| *index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ThreadNodeCaches;
+    use biome_js_parser::{parse_js_with_cache, JsParserOptions, Parse};
+    use biome_js_syntax::{AnyJsRoot, JsFileSource};
+    use biome_rowan::syntax::SyntaxElementKey;
+    use std::fmt::Write;
+    use std::thread;
+
+    fn parse(caches: &ThreadNodeCaches, source: &str) -> Parse<AnyJsRoot> {
+        caches.with(|cache| {
+            parse_js_with_cache(
+                source,
+                JsFileSource::js_module(),
+                JsParserOptions::default(),
+                cache,
+            )
+        })
+    }
+
+    /// Returns the key of the first token of the tree, which identifies its green token
+    fn first_token_key(parse: &Parse<AnyJsRoot>) -> SyntaxElementKey {
+        parse.syntax().first_token().unwrap().key()
+    }
+
+    fn declarations(prefix: &str, len: usize) -> String {
+        let mut source = String::new();
+        for index in 0..len {
+            writeln!(source, "let {prefix}{index} = {index};").unwrap();
+        }
+        source
+    }
+
+    #[test]
+    fn reuses_the_nodes_parsed_by_the_same_thread() {
+        let caches = ThreadNodeCaches::default();
+
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    let first = parse(&caches, "identifier;\n");
+                    let second = parse(&caches, "identifier;\n");
+                    assert!(first_token_key(&first) == first_token_key(&second));
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn collects_the_nodes_that_are_no_longer_parsed() {
+        let caches = ThreadNodeCaches::default();
+
+        let first = parse(&caches, "identifier;\n");
+        // The first collection keeps everything, and the next one evicts the nodes that
+        // weren't parsed since
+        let _ = parse(&caches, &declarations("b", 20_000));
+        let _ = parse(&caches, &declarations("c", 60_000));
+        let second = parse(&caches, "identifier;\n");
+
+        assert!(first_token_key(&first) != first_token_key(&second));
+    }
+}
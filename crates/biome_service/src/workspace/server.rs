@@ -1,16 +1,36 @@
+use super::node_cache::ThreadNodeCaches;
+#[cfg(feature = "grit")]
+use super::PatternId;
 use super::{
-    ChangeFileParams, CloseFileParams, FeatureKind, FeatureName, FixFileResult, FormatFileParams,
-    FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, ParsePatternParams,
-    ParsePatternResult, PatternId, ProjectKey, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RegisterProjectFolderParams, RenameResult,
-    SearchPatternParams, SearchResults, SetManifestForProjectParams, SupportsFeatureParams,
-    UnregisterProjectFolderParams, UpdateSettingsParams,
+    ChangeFileParams, CloseFileParams, Definition, DocumentSymbol, ExportProjectIndexParams,
+    ExportProjectIndexResult, FeatureKind, FeatureName, FixFileResult, ForgetFilesParams,
+    FormatFileParams, FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams,
+    GetDefinitionParams, GetDefinitionResult, GetDocumentSymbolsParams, GetDocumentSymbolsResult,
+    GetFoldingRangesParams, GetFoldingRangesResult, GetFormatterIRParams, GetInlayHintsParams,
+    GetInlayHintsResult, GetModuleGraphParams, GetRuleConfigurationParams,
+    GetRuleConfigurationResult, GetSelectionRangesParams, GetSelectionRangesResult,
+    GetSemanticTokensParams, GetSemanticTokensResult, GetSignatureHelpParams,
+    GetSignatureHelpResult, GetSyntaxTreeParams, GetSyntaxTreeResult, GetWorkspaceSymbolsParams,
+    GetWorkspaceSymbolsResult, ImportProjectIndexParams, ImportProjectIndexResult, ModuleGraph,
+    ModuleGraphNode, OpenFileParams, ParsePatternParams, ParsePatternResult, PrepareRenameParams,
+    PrepareRenameResult, ProjectKey, PullActionsParams, PullActionsResult, PullDiagnosticsParams,
+    PullDiagnosticsResult, RegisterProjectFolderParams, RenameResult, RenamedFile,
+    ResolveConfigurationParams, ResolveConfigurationResult, SearchPatternParams, SearchResults,
+    SetManifestForProjectParams, SupportsFeatureParams, UnregisterProjectFolderParams,
+    UpdateSettingsParams, WorkspaceSymbol,
 };
+#[cfg(not(feature = "grit"))]
+use crate::diagnostics::QueryDiagnostic;
 use crate::diagnostics::{InvalidPattern, SearchError};
+#[cfg(feature = "css")]
+use crate::file_handlers::custom_property_at;
+#[cfg(feature = "graphql")]
+use crate::file_handlers::EmbeddedGraphql;
 use crate::file_handlers::{
+    exported_symbol_at, import_specifier_at, rename_imports, AngularComponent, AngularProjectIndex,
     Capabilities, CodeActionsParams, DocumentFileSource, FixAllParams, LintParams, ParseResult,
 };
+use crate::project_index::{content_hash, ContentHash, IndexedFile, ProjectIndexSnapshot};
 use crate::settings::{WorkspaceSettings, WorkspaceSettingsHandleMut};
 use crate::workspace::{
     FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
@@ -19,27 +39,44 @@ use crate::workspace::{
 use crate::{
     file_handlers::Features, settings::WorkspaceSettingsHandle, Workspace, WorkspaceError,
 };
+use biome_analyze::profiling;
 use biome_configuration::DEFAULT_FILE_SIZE_LIMIT;
+#[cfg(feature = "css")]
+use biome_css_semantic::{CssFileIndex, CssProjectIndex};
 use biome_diagnostics::{
     serde::Diagnostic as SerdeDiagnostic, Diagnostic, DiagnosticExt, Severity,
 };
 use biome_formatter::Printed;
 use biome_fs::{BiomePath, ConfigName};
+#[cfg(feature = "graphql")]
+use biome_graphql_schema::GraphqlSchema;
+#[cfg(feature = "graphql")]
+use biome_graphql_semantic::{GraphqlFileIndex, GraphqlProjectIndex};
+#[cfg(feature = "grit")]
 use biome_grit_patterns::GritQuery;
-use biome_js_syntax::ModuleKind;
+use biome_js_semantic::{is_relative_specifier, JsFileIndex, JsProjectIndex};
+use biome_js_syntax::{EmbeddingKind, JsLanguage, ModuleKind};
 use biome_json_parser::{parse_json_with_cache, JsonParserOptions};
-use biome_json_syntax::JsonFileSource;
+use biome_json_schema::{is_schema_document, JsonSchema, JsonSchemaStore};
+use biome_json_syntax::{JsonFileSource, JsonRoot};
 use biome_parser::AnyParse;
-use biome_project::{NodeJsProject, PackageJson, PackageType, Project};
-use biome_rowan::NodeCache;
+use biome_project::{
+    resolve_module, NodeJsProject, OsTsConfigHost, PackageJson, PackageType, Project,
+};
+use biome_rowan::TextRange;
 use dashmap::{mapref::entry::Entry, DashMap};
 use indexmap::IndexSet;
+#[cfg(feature = "css")]
+use rustc_hash::FxHashMap;
+use rustc_hash::FxHashSet;
 use std::ffi::OsStr;
 use std::fs;
 use std::path::{Path, PathBuf};
+#[cfg(feature = "grit")]
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::{panic::RefUnwindSafe, sync::RwLock};
-use tracing::{debug, info, info_span};
+use tracing::{debug, debug_span, info, info_span};
 
 pub(super) struct WorkspaceServer {
     /// features available throughout the application
@@ -55,9 +92,56 @@ pub(super) struct WorkspaceServer {
     /// Stores the document sources used across the workspace
     file_sources: RwLock<IndexSet<DocumentFileSource>>,
     /// Stores patterns to search for.
+    #[cfg(feature = "grit")]
     patterns: DashMap<PatternId, GritQuery>,
+    /// Stores the symbols defined by the stylesheets of each project, such as custom properties,
+    /// so that the stylesheets of a project don't define the symbols of another one.
+    /// The stylesheets outside of the registered projects are indexed under the default key.
+    ///
+    /// Unlike [WorkspaceServer::syntax], entries are kept when a document is closed,
+    /// since the definitions it contains are still part of the project.
+    #[cfg(feature = "css")]
+    css_projects: RwLock<FxHashMap<ProjectKey, Arc<CssProjectIndex>>>,
+    /// Stores the symbols defined by the scripts of the project, such as the names of the custom elements.
+    ///
+    /// Like [WorkspaceServer::css_projects], entries are kept when a document is closed.
+    js_project: RwLock<Arc<JsProjectIndex>>,
+    /// Stores the JSON schemas defined by the documents of the project.
+    ///
+    /// Like [WorkspaceServer::css_projects], entries are kept when a document is closed.
+    json_schemas: RwLock<Arc<JsonSchemaStore>>,
+    /// Stores the GraphQL schema configured with `graphql.linter.schema`, along with its path.
+    ///
+    /// The schema is `None` if the file can't be read or parsed.
+    #[cfg(feature = "graphql")]
+    graphql_schema: RwLock<Option<(PathBuf, Option<Arc<GraphqlSchema>>)>>,
+    /// Stores the symbols defined by the GraphQL documents of the project, such as operation names.
+    ///
+    /// Like [WorkspaceServer::css_projects], entries are kept when a document is closed.
+    #[cfg(feature = "graphql")]
+    graphql_project: RwLock<Arc<GraphqlProjectIndex>>,
+    /// Stores the Angular components of the project whose template is in its own file.
+    ///
+    /// Like [WorkspaceServer::css_projects], entries are kept when a document is closed.
+    angular_project: RwLock<Arc<AngularProjectIndex>>,
+    /// Stores the symbols defined by each file, as listed by the outline of the file.
+    ///
+    /// Like [WorkspaceServer::css_projects], entries are kept when a document is closed,
+    /// so that the symbols of the whole project can be searched.
+    symbols: DashMap<BiomePath, Vec<DocumentSymbol>>,
+    /// Stores the hash of the content that the project index of each file was computed from,
+    /// so that the index can be exported and imported again while the file doesn't change.
+    ///
+    /// Like [WorkspaceServer::css_projects], entries are kept when a document is closed. They
+    /// are removed when the index is exported, once the file is no longer in any index.
+    content_hashes: DashMap<BiomePath, ContentHash>,
+    /// The caches of the green nodes, shared by the documents parsed by the same thread
+    node_cache: ThreadNodeCaches,
 }
 
+/// The maximum number of symbols returned by [Workspace::get_workspace_symbols]
+const MAX_WORKSPACE_SYMBOLS: usize = 512;
+
 /// The `Workspace` object is long-lived, so we want it to be able to cross
 /// unwind boundaries.
 /// In return, we have to make sure operations on the workspace either do not
@@ -73,7 +157,6 @@ pub(crate) struct Document {
     /// The index of where the original file source is saved
     /// Use `WorkspaceServer#file_sources` to retrieve the file source that belongs to the document.
     pub(crate) file_source_index: usize,
-    node_cache: NodeCache,
 }
 
 impl WorkspaceServer {
@@ -90,7 +173,20 @@ impl WorkspaceServer {
             syntax: DashMap::default(),
             current_project_path: RwLock::default(),
             file_sources: RwLock::default(),
+            #[cfg(feature = "grit")]
             patterns: Default::default(),
+            #[cfg(feature = "css")]
+            css_projects: RwLock::default(),
+            js_project: RwLock::default(),
+            json_schemas: RwLock::default(),
+            #[cfg(feature = "graphql")]
+            graphql_schema: RwLock::default(),
+            #[cfg(feature = "graphql")]
+            graphql_project: RwLock::default(),
+            angular_project: RwLock::default(),
+            symbols: DashMap::default(),
+            content_hashes: DashMap::default(),
+            node_cache: ThreadNodeCaches::default(),
         }
     }
 
@@ -104,7 +200,7 @@ impl WorkspaceServer {
     }
 
     /// Get the supported capabilities for a given file path
-    fn get_file_capabilities(&self, path: &BiomePath) -> Capabilities {
+    fn get_file_capabilities(&self, path: &BiomePath) -> &Capabilities {
         let language = self.get_file_source(path);
 
         debug!("File capabilities: {:?} {:?}", &language, &path);
@@ -120,6 +216,14 @@ impl WorkspaceServer {
             .unwrap_or(DocumentFileSource::from_path(path))
     }
 
+    /// Returns the language that the configuration associates with the file at `path`
+    fn associated_file_source(&self, path: &Path) -> Option<DocumentFileSource> {
+        self.workspace()
+            .settings()?
+            .files
+            .associated_file_source(path)
+    }
+
     /// Return an error factory function for unsupported features at a given path
     fn build_capability_error<'a>(
         &'a self,
@@ -188,11 +292,13 @@ impl WorkspaceServer {
         let workspace_mut = workspace.as_mut();
         let mut document = self.documents.get_mut(&manifest_path);
         if let Some(document) = document.as_deref_mut() {
-            let parsed = parse_json_with_cache(
-                document.content.as_str(),
-                &mut document.node_cache,
-                JsonParserOptions::default(),
-            );
+            let parsed = self.node_cache.with(|node_cache| {
+                parse_json_with_cache(
+                    document.content.as_str(),
+                    node_cache,
+                    JsonParserOptions::default(),
+                )
+            });
 
             let mut node_js_project = NodeJsProject::default();
             node_js_project.deserialize_manifest(&parsed.tree());
@@ -215,6 +321,15 @@ impl WorkspaceServer {
         workspace.as_ref().path_belongs_to_current_workspace(path)
     }
 
+    /// Makes the nearest project that contains `path` the current project, so that its
+    /// settings apply to the file. In a monorepo, the packages that have their own
+    /// configuration file are registered as projects nested in the project of the monorepo.
+    fn set_current_project_for(&self, path: &BiomePath) {
+        if let Some(project_key) = self.path_belongs_to_current_workspace(path) {
+            self.set_current_project(project_key);
+        }
+    }
+
     /// Get the parser result for a given file
     ///
     /// Returns and error if no file exists in the workspace with this path or
@@ -236,15 +351,19 @@ impl WorkspaceServer {
                     .parse
                     .ok_or_else(self.build_capability_error(biome_path))?;
 
+                let document = &mut *document;
+                let Some(file_source) = self.get_source(document.file_source_index) else {
+                    return Err(WorkspaceError::not_found());
+                };
+                let workspace = self.workspace();
+                let settings = workspace.settings();
+
                 let size_limit = {
-                    let workspace = self.workspace();
-                    let settings = workspace.settings();
-                    let limit =
-                        settings.map_or(DEFAULT_FILE_SIZE_LIMIT.get(), |s| s.files.max_size.get());
+                    let limit = settings.map_or(DEFAULT_FILE_SIZE_LIMIT.get(), |s| {
+                        s.files.max_size_for(&file_source).get()
+                    });
                     usize::try_from(limit).unwrap_or(usize::MAX)
                 };
-
-                let document = &mut *document;
                 let size = document.content.as_bytes().len();
                 if size >= size_limit {
                     return Err(WorkspaceError::file_too_large(
@@ -254,18 +373,17 @@ impl WorkspaceServer {
                     ));
                 }
 
-                let workspace = self.workspace();
-                let Some(file_source) = self.get_source(document.file_source_index) else {
-                    return Err(WorkspaceError::not_found());
-                };
-                let settings = workspace.settings();
-                let parsed = parse(
-                    biome_path,
-                    file_source,
-                    document.content.as_str(),
-                    settings,
-                    &mut document.node_cache,
-                );
+                let parsed = measure_phase("parse", biome_path, || {
+                    self.node_cache.with(|node_cache| {
+                        parse(
+                            biome_path,
+                            file_source,
+                            document.content.as_str(),
+                            settings,
+                            node_cache,
+                        )
+                    })
+                });
                 let ParseResult {
                     language,
                     any_parse,
@@ -273,11 +391,253 @@ impl WorkspaceServer {
                 if let Some(language) = language {
                     document.file_source_index = self.set_source(language);
                 }
+                self.content_hashes
+                    .insert(biome_path.clone(), content_hash(&document.content));
+                #[cfg(feature = "css")]
+                if matches!(file_source, DocumentFileSource::Css(_)) {
+                    let project_key = workspace
+                        .as_ref()
+                        .get_project_key_for(biome_path)
+                        .unwrap_or_default();
+                    self.index_stylesheet(project_key, biome_path, &any_parse);
+                }
+                if matches!(file_source, DocumentFileSource::Json(_)) {
+                    self.register_json_schema(biome_path, &any_parse);
+                }
+                #[cfg(feature = "graphql")]
+                if matches!(file_source, DocumentFileSource::Graphql(_)) {
+                    self.index_graphql_document(biome_path, &any_parse);
+                }
+                // The Astro, Vue and Svelte files are parsed as HTML documents
+                #[cfg(feature = "graphql")]
+                if matches!(file_source, DocumentFileSource::Js(js) if js.as_embedding_kind() == &EmbeddingKind::None)
+                    && settings.is_some_and(|settings| !settings.graphql_linter_disabled())
+                {
+                    self.index_embedded_graphql_documents(biome_path, &any_parse);
+                }
+                if matches!(file_source, DocumentFileSource::Js(js) if js.as_embedding_kind() == &EmbeddingKind::None)
+                {
+                    self.index_script(biome_path, &any_parse);
+                    self.index_angular_components(biome_path, &any_parse);
+                }
+                if let Some(document_symbols) = capabilities.symbols.document_symbols {
+                    self.symbols
+                        .insert(biome_path.clone(), document_symbols(any_parse.clone()));
+                }
+                #[cfg(feature = "graphql")]
+                self.invalidate_graphql_schema(biome_path);
                 Ok(entry.insert(any_parse).clone())
             }
         }
     }
 
+    /// Returns the key of the nearest project that contains `biome_path`, or the default key
+    /// when the path is outside of the registered projects
+    #[cfg(feature = "css")]
+    fn get_project_key_for(&self, biome_path: &BiomePath) -> ProjectKey {
+        self.workspace()
+            .as_ref()
+            .get_project_key_for(biome_path)
+            .unwrap_or_default()
+    }
+
+    /// Records the symbols defined by the stylesheet at `biome_path` in the index of its project
+    #[cfg(feature = "css")]
+    fn index_stylesheet(&self, project_key: ProjectKey, biome_path: &BiomePath, parse: &AnyParse) {
+        let index = CssFileIndex::from_root(&parse.tree());
+        let mut projects = self.css_projects.write().unwrap();
+        let project = projects.entry(project_key).or_default();
+        if project.get_file(biome_path) != Some(&index) {
+            Arc::make_mut(project).insert_file(biome_path.to_path_buf(), index);
+        }
+    }
+
+    /// Returns a snapshot of the symbols defined by the stylesheets of the project of `biome_path`
+    #[cfg(feature = "css")]
+    fn get_css_project(&self, biome_path: &BiomePath) -> Arc<CssProjectIndex> {
+        let project_key = self.get_project_key_for(biome_path);
+        let projects = self.css_projects.read().unwrap();
+        projects.get(&project_key).cloned().unwrap_or_default()
+    }
+
+    /// Records the symbols defined by the script at `biome_path` in the project index.
+    ///
+    /// The scripts that don't define any symbol are recorded too, so that the imports
+    /// of the other scripts can be resolved to them.
+    fn index_script(&self, biome_path: &BiomePath, parse: &AnyParse) {
+        let index = JsFileIndex::from_root(&parse.tree());
+        let mut project = self.js_project.write().unwrap();
+        if project.get_file(biome_path) != Some(&index) {
+            Arc::make_mut(&mut *project).insert_file(biome_path.to_path_buf(), index);
+        }
+    }
+
+    /// Returns a snapshot of the symbols defined by the scripts of the project
+    fn get_js_project(&self) -> Arc<JsProjectIndex> {
+        self.js_project.read().unwrap().clone()
+    }
+
+    /// Resolves the import `specifier` of the script at `biome_path` to the indexed scripts,
+    /// or else to the files on the disk, using the `paths` of the `tsconfig.json` and
+    /// the `exports` of the packages
+    fn resolve_import(&self, biome_path: &BiomePath, specifier: &str) -> Option<PathBuf> {
+        let project = self.get_js_project();
+        if let Some(path) = project.module_resolver().resolve(biome_path, specifier) {
+            return Some(path.to_path_buf());
+        }
+        resolve_module(specifier, biome_path, &OsTsConfigHost)
+    }
+
+    /// Renames the symbol `name`, exported by the script at `biome_path`, in the scripts
+    /// of the project index that import it.
+    ///
+    /// The scripts that were closed since they were indexed are skipped, because their
+    /// content isn't known anymore.
+    fn rename_in_importers(
+        &self,
+        biome_path: &BiomePath,
+        name: &str,
+        new_name: &str,
+    ) -> Result<Vec<RenamedFile>, WorkspaceError> {
+        let project = self.get_js_project();
+        let resolver = project.module_resolver();
+        let mut other_files = Vec::new();
+        for path in project.module_graph().importers(biome_path.as_path()) {
+            let is_renamed_module =
+                |specifier: &str| resolver.resolve(path, specifier) == Some(biome_path.as_path());
+            if path == biome_path.as_path() {
+                continue;
+            }
+
+            let path = BiomePath::new(path);
+            let Ok(parse) = self.get_parse(path.clone()) else {
+                continue;
+            };
+            if let Some(indels) = rename_imports(parse, is_renamed_module, name, new_name)? {
+                other_files.push(RenamedFile { path, indels });
+            }
+        }
+        other_files.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(other_files)
+    }
+
+    /// Records the symbols defined by the GraphQL document at `biome_path` in the project index
+    #[cfg(feature = "graphql")]
+    fn index_graphql_document(&self, biome_path: &BiomePath, parse: &AnyParse) {
+        let index = GraphqlFileIndex::from_root(&parse.tree());
+        let mut project = self.graphql_project.write().unwrap();
+        if project.get_file(biome_path) != Some(&index) {
+            Arc::make_mut(&mut *project).insert_file(biome_path.to_path_buf(), index);
+        }
+    }
+
+    /// Records the symbols defined by the GraphQL documents embedded in the script at `biome_path`
+    /// in the project index, all under the path of the script.
+    #[cfg(feature = "graphql")]
+    fn index_embedded_graphql_documents(&self, biome_path: &BiomePath, parse: &AnyParse) {
+        let mut index = GraphqlFileIndex::default();
+        for snippet in EmbeddedGraphql::extract(&parse.syntax::<JsLanguage>()) {
+            let parse = snippet.parse();
+            if !parse.has_errors() {
+                index.extend(GraphqlFileIndex::from_root(&parse.tree()), snippet.offset());
+            }
+        }
+
+        let mut project = self.graphql_project.write().unwrap();
+        if index.is_empty() {
+            if project.get_file(biome_path).is_some() {
+                Arc::make_mut(&mut *project).remove_file(biome_path);
+            }
+        } else if project.get_file(biome_path) != Some(&index) {
+            Arc::make_mut(&mut *project).insert_file(biome_path.to_path_buf(), index);
+        }
+    }
+
+    /// Returns a snapshot of the symbols defined by the GraphQL documents of the project
+    #[cfg(feature = "graphql")]
+    fn get_graphql_project(&self) -> Arc<GraphqlProjectIndex> {
+        self.graphql_project.read().unwrap().clone()
+    }
+
+    /// Records the Angular components of the script at `biome_path` whose template is in its own file,
+    /// so that the template is linted against the members of its component.
+    fn index_angular_components(&self, biome_path: &BiomePath, parse: &AnyParse) {
+        let components: Vec<_> = AngularComponent::extract(&parse.syntax::<JsLanguage>())
+            .into_iter()
+            .filter(|component| component.template_url().is_some())
+            .collect();
+
+        let mut project = self.angular_project.write().unwrap();
+        if components.is_empty() {
+            if project.get_file(biome_path).is_some() {
+                Arc::make_mut(&mut *project).remove_file(biome_path);
+            }
+        } else if project.get_file(biome_path) != Some(components.as_slice()) {
+            Arc::make_mut(&mut *project).insert_file(biome_path.to_path_buf(), components);
+        }
+    }
+
+    /// Returns a snapshot of the Angular components of the project
+    fn get_angular_project(&self) -> Arc<AngularProjectIndex> {
+        self.angular_project.read().unwrap().clone()
+    }
+
+    /// Records the schema defined by the document at `biome_path`, if the document is a JSON schema
+    fn register_json_schema(&self, biome_path: &BiomePath, parse: &AnyParse) {
+        let root: JsonRoot = parse.tree();
+        let mut store = self.json_schemas.write().unwrap();
+        if is_schema_document(&root) {
+            if let Some(schema) = JsonSchema::from_root(&root) {
+                Arc::make_mut(&mut *store).insert_schema(biome_path, schema);
+            }
+        } else if store.get_schema(biome_path).is_some() {
+            Arc::make_mut(&mut *store).remove_schema(biome_path);
+        }
+    }
+
+    /// Returns a snapshot of the JSON schemas defined by the documents of the project
+    fn get_json_schemas(&self) -> Arc<JsonSchemaStore> {
+        self.json_schemas.read().unwrap().clone()
+    }
+
+    /// Returns the GraphQL schema configured for the project, loading it if it isn't loaded yet.
+    ///
+    /// The schema is read from the open document at its path if there's one, and from the disk otherwise.
+    #[cfg(feature = "graphql")]
+    fn get_graphql_schema(&self) -> Option<Arc<GraphqlSchema>> {
+        let workspace = self.workspace();
+        let settings = workspace.settings()?;
+        let path = settings.languages.graphql.linter.schema.clone()?;
+        if let Some((cached_path, schema)) = self.graphql_schema.read().unwrap().as_ref() {
+            if cached_path == &path {
+                return schema.clone();
+            }
+        }
+
+        let content = match self.documents.get(&BiomePath::new(&path)) {
+            Some(document) => Some(document.content.clone()),
+            None => fs::read_to_string(&path).ok(),
+        };
+        let schema = content
+            .and_then(|content| GraphqlSchema::from_source(&path, &content))
+            .map(Arc::new);
+        *self.graphql_schema.write().unwrap() = Some((path, schema.clone()));
+        schema
+    }
+
+    /// Discards the loaded GraphQL schema if it was read from the document at `biome_path`
+    #[cfg(feature = "graphql")]
+    fn invalidate_graphql_schema(&self, biome_path: &BiomePath) {
+        let mut schema = self.graphql_schema.write().unwrap();
+        if schema
+            .as_ref()
+            .is_some_and(|(path, _)| path.as_path() == biome_path.as_path())
+        {
+            *schema = None;
+        }
+    }
+
     /// Check whether a file is ignored in the top-level config `files.ignore`/`files.include`
     /// or in the feature `ignore`/`include`
     fn is_ignored(&self, path: &Path, features: FeatureName) -> bool {
@@ -311,19 +671,11 @@ impl WorkspaceServer {
             || settings.files.included_files.matches_path(path);
         !is_included
             || settings.files.ignored_files.matches_path(path)
-            || settings.files.git_ignore.as_ref().is_some_and(|ignore| {
-                // `matched_path_or_any_parents` panics if `source` is not under the gitignore root.
-                // This checks excludes absolute paths that are not a prefix of the base root.
-                if !path.has_root() || path.starts_with(ignore.path()) {
-                    // Because Biome passes a list of paths,
-                    // we use `matched_path_or_any_parents` instead of `matched`.
-                    ignore
-                        .matched_path_or_any_parents(path, path.is_dir())
-                        .is_ignore()
-                } else {
-                    false
-                }
-            })
+            // Because Biome passes a list of paths, the parent directories are matched too
+            || settings
+                .files
+                .git_ignore_match(path, path.is_dir())
+                .is_ignore()
     }
 
     /// Check whether a file is ignored in the feature `ignore`/`include`
@@ -369,8 +721,14 @@ impl Workspace for WorkspaceServer {
         &self,
         params: SupportsFeatureParams,
     ) -> Result<FileFeaturesResult, WorkspaceError> {
-        let capabilities = self.get_file_capabilities(&params.path);
-        let language = DocumentFileSource::from_path(&params.path);
+        self.set_current_project_for(&params.path);
+        let language = self
+            .associated_file_source(&params.path)
+            .unwrap_or_else(|| DocumentFileSource::from_path(&params.path));
+        let capabilities = self.features.get_capabilities(
+            &params.path,
+            language.or(self.get_file_source(&params.path)),
+        );
         let path = params.path.as_path();
         let settings = self.workspace();
         let settings = settings.settings();
@@ -436,9 +794,14 @@ impl Workspace for WorkspaceServer {
     /// Add a new file to the workspace
     #[tracing::instrument(level = "trace", skip(self))]
     fn open_file(&self, params: OpenFileParams) -> Result<(), WorkspaceError> {
-        let mut source = params
-            .document_file_source
-            .unwrap_or(DocumentFileSource::from_path(&params.path));
+        self.set_current_project_for(&params.path);
+        // The associations of the configuration take precedence over the language of the client
+        let mut source = match self.associated_file_source(&params.path) {
+            Some(source) => source,
+            None => params
+                .document_file_source
+                .unwrap_or(DocumentFileSource::from_path(&params.path)),
+        };
         let manifest = self.get_current_manifest()?;
 
         if let DocumentFileSource::Js(js) = &mut source {
@@ -450,18 +813,30 @@ impl Workspace for WorkspaceServer {
         }
 
         let index = self.set_source(source);
-        self.syntax.remove(&params.path);
+        // The tree of a document that is opened again with the same content is still valid,
+        // so that the files parsed to build the project index aren't parsed twice
+        let is_unchanged = self.documents.get(&params.path).is_some_and(|document| {
+            document.file_source_index == index && document.content == params.content
+        });
+        if !is_unchanged {
+            self.syntax.remove(&params.path);
+        }
         self.documents.insert(
             params.path.clone(),
             Document {
                 content: params.content,
                 version: params.version,
-                node_cache: NodeCache::default(),
                 file_source_index: index,
             },
         );
-        if let Some(project_key) = self.path_belongs_to_current_workspace(&params.path) {
-            self.set_current_project(project_key);
+
+        // Stylesheets are parsed right away, so that the symbols they define are
+        // available to the other documents of the project.
+        #[cfg(feature = "css")]
+        if matches!(source, DocumentFileSource::Css(_)) {
+            if let Err(error) = self.get_parse(params.path) {
+                debug!("Could not index the stylesheet: {error}");
+            }
         }
 
         Ok(())
@@ -478,7 +853,6 @@ impl Workspace for WorkspaceServer {
                 content: params.content,
                 version: params.version,
                 file_source_index: index,
-                node_cache: NodeCache::default(),
             },
         );
         self.register_manifest_for_project(params.manifest_path);
@@ -489,6 +863,19 @@ impl Workspace for WorkspaceServer {
         &self,
         params: RegisterProjectFolderParams,
     ) -> Result<ProjectKey, WorkspaceError> {
+        // A project that is registered again, for example when its configuration file changes,
+        // keeps its key
+        if let Some(path) = params.path.as_deref() {
+            let key = self.workspace().as_ref().get_project_key(path);
+            if let Some(key) = key {
+                if params.set_as_current_workspace {
+                    self.set_current_project(key);
+                    self.set_current_project_path(BiomePath::new(path));
+                }
+                return Ok(key);
+            }
+        }
+
         let current_project_path = self.get_current_project_path();
         debug!(
             "Compare the current project with the new one {:?} {:?} {:?}",
@@ -587,6 +974,7 @@ impl Workspace for WorkspaceServer {
 
     /// Change the content of an open file
     fn change_file(&self, params: ChangeFileParams) -> Result<(), WorkspaceError> {
+        self.set_current_project_for(&params.path);
         {
             let mut document = self
                 .documents
@@ -618,22 +1006,34 @@ impl Workspace for WorkspaceServer {
         &self,
         params: PullDiagnosticsParams,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
+        self.set_current_project_for(&params.path);
         let parse = self.get_parse(params.path.clone())?;
         let manifest = self.get_current_manifest()?;
         let (diagnostics, errors, skipped_diagnostics) =
             if let Some(lint) = self.get_file_capabilities(&params.path).analyzer.lint {
                 info_span!("Pulling diagnostics", categories =? params.categories).in_scope(|| {
-                    let results = lint(LintParams {
-                        parse,
-                        workspace: &self.workspace(),
-                        max_diagnostics: params.max_diagnostics as u32,
-                        path: &params.path,
-                        only: params.only,
-                        skip: params.skip,
-                        language: self.get_file_source(&params.path),
-                        categories: params.categories,
-                        manifest,
-                        suppression_reason: None,
+                    let results = measure_phase("analyze", &params.path, || {
+                        lint(LintParams {
+                            parse,
+                            workspace: &self.workspace(),
+                            max_diagnostics: params.max_diagnostics as u32,
+                            path: &params.path,
+                            only: params.only,
+                            skip: params.skip,
+                            language: self.get_file_source(&params.path),
+                            categories: params.categories,
+                            manifest,
+                            #[cfg(feature = "css")]
+                            css_project: self.get_css_project(&params.path),
+                            js_project: self.get_js_project(),
+                            json_schemas: self.get_json_schemas(),
+                            #[cfg(feature = "graphql")]
+                            graphql_schema: self.get_graphql_schema(),
+                            #[cfg(feature = "graphql")]
+                            graphql_project: self.get_graphql_project(),
+                            angular_project: self.get_angular_project(),
+                            suppression_reason: None,
+                        })
                     });
 
                     (
@@ -670,6 +1070,7 @@ impl Workspace for WorkspaceServer {
     /// position within a file
     #[tracing::instrument(level = "trace", skip(self))]
     fn pull_actions(&self, params: PullActionsParams) -> Result<PullActionsResult, WorkspaceError> {
+        self.set_current_project_for(&params.path);
         let capabilities = self.get_file_capabilities(&params.path);
         let code_actions = capabilities
             .analyzer
@@ -686,6 +1087,13 @@ impl Workspace for WorkspaceServer {
             workspace: &workspace,
             path: &params.path,
             manifest,
+            #[cfg(feature = "css")]
+            css_project: self.get_css_project(&params.path),
+            js_project: self.get_js_project(),
+            #[cfg(feature = "graphql")]
+            graphql_schema: self.get_graphql_schema(),
+            #[cfg(feature = "graphql")]
+            graphql_project: self.get_graphql_project(),
             language,
             only: params.only,
             skip: params.skip,
@@ -696,6 +1104,7 @@ impl Workspace for WorkspaceServer {
     /// Runs the given file through the formatter using the provided options
     /// and returns the resulting source code
     fn format_file(&self, params: FormatFileParams) -> Result<Printed, WorkspaceError> {
+        self.set_current_project_for(&params.path);
         let capabilities = self.get_file_capabilities(&params.path);
         let format = capabilities
             .formatter
@@ -711,10 +1120,13 @@ impl Workspace for WorkspaceServer {
             }
         }
         let document_file_source = self.get_file_source(&params.path);
-        format(&params.path, &document_file_source, parse, workspace)
+        measure_phase("format", &params.path, || {
+            format(&params.path, &document_file_source, parse, workspace)
+        })
     }
 
     fn format_range(&self, params: FormatRangeParams) -> Result<Printed, WorkspaceError> {
+        self.set_current_project_for(&params.path);
         let capabilities = self.get_file_capabilities(&params.path);
         let format_range = capabilities
             .formatter
@@ -730,16 +1142,19 @@ impl Workspace for WorkspaceServer {
             }
         }
         let document_file_source = self.get_file_source(&params.path);
-        format_range(
-            &params.path,
-            &document_file_source,
-            parse,
-            workspace,
-            params.range,
-        )
+        measure_phase("format", &params.path, || {
+            format_range(
+                &params.path,
+                &document_file_source,
+                parse,
+                workspace,
+                params.range,
+            )
+        })
     }
 
     fn format_on_type(&self, params: FormatOnTypeParams) -> Result<Printed, WorkspaceError> {
+        self.set_current_project_for(&params.path);
         let capabilities = self.get_file_capabilities(&params.path);
         let format_on_type = capabilities
             .formatter
@@ -756,16 +1171,19 @@ impl Workspace for WorkspaceServer {
         }
         let document_file_source = self.get_file_source(&params.path);
 
-        format_on_type(
-            &params.path,
-            &document_file_source,
-            parse,
-            workspace,
-            params.offset,
-        )
+        measure_phase("format", &params.path, || {
+            format_on_type(
+                &params.path,
+                &document_file_source,
+                parse,
+                workspace,
+                params.offset,
+            )
+        })
     }
 
     fn fix_file(&self, params: super::FixFileParams) -> Result<FixFileResult, WorkspaceError> {
+        self.set_current_project_for(&params.path);
         let capabilities = self.get_file_capabilities(&params.path);
 
         let fix_all = capabilities
@@ -776,23 +1194,46 @@ impl Workspace for WorkspaceServer {
 
         let manifest = self.get_current_manifest()?;
         let language = self.get_file_source(&params.path);
-        fix_all(FixAllParams {
-            parse,
-            // rules: rules.as_ref().map(|x| x.borrow()),
-            fix_file_mode: params.fix_file_mode,
-            // filter,
-            workspace: self.workspace(),
-            should_format: params.should_format,
-            biome_path: &params.path,
-            manifest,
-            document_file_source: language,
-            only: params.only,
-            skip: params.skip,
-            rule_categories: params.rule_categories,
-            suppression_reason: params.suppression_reason,
+        measure_phase("analyze", &params.path, || {
+            fix_all(FixAllParams {
+                parse,
+                // rules: rules.as_ref().map(|x| x.borrow()),
+                fix_file_mode: params.fix_file_mode,
+                // filter,
+                workspace: self.workspace(),
+                should_format: params.should_format,
+                biome_path: &params.path,
+                manifest,
+                #[cfg(feature = "css")]
+                css_project: self.get_css_project(&params.path),
+                js_project: self.get_js_project(),
+                #[cfg(feature = "graphql")]
+                graphql_schema: self.get_graphql_schema(),
+                #[cfg(feature = "graphql")]
+                graphql_project: self.get_graphql_project(),
+                document_file_source: language,
+                only: params.only,
+                skip: params.skip,
+                rule_categories: params.rule_categories,
+                suppression_reason: params.suppression_reason,
+            })
         })
     }
 
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<PrepareRenameResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let prepare_rename = capabilities
+            .analyzer
+            .prepare_rename
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path)?;
+        prepare_rename(parse, params.symbol_at)
+    }
+
     fn rename(&self, params: super::RenameParams) -> Result<RenameResult, WorkspaceError> {
         let capabilities = self.get_file_capabilities(&params.path);
         let rename = capabilities
@@ -801,20 +1242,70 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(self.build_capability_error(&params.path))?;
 
         let parse = self.get_parse(params.path.clone())?;
-        let result = rename(&params.path, parse, params.symbol_at, params.new_name)?;
+        let exported_symbol = exported_symbol_at(&parse, params.symbol_at);
+        let mut result = rename(
+            &params.path,
+            parse,
+            params.symbol_at,
+            params.new_name.clone(),
+        )?;
+        if let Some(name) = exported_symbol {
+            result.other_files = self.rename_in_importers(&params.path, &name, &params.new_name)?;
+        }
 
         Ok(result)
     }
 
-    fn rage(&self, _: RageParams) -> Result<RageResult, WorkspaceError> {
-        let entries = vec![
+    fn rage(&self, params: RageParams) -> Result<RageResult, WorkspaceError> {
+        let mut entries = vec![
             RageEntry::section("Workspace"),
             RageEntry::pair("Open Documents", &format!("{}", self.documents.len())),
         ];
+        let workspace = self.workspace();
+        for path in workspace.as_ref().project_paths() {
+            entries.push(RageEntry::pair("Project", &path.display().to_string()));
+        }
+
+        if params.caches {
+            entries.extend([
+                RageEntry::section("Workspace Caches"),
+                RageEntry::pair("Parsed Documents", &format!("{}", self.syntax.len())),
+                #[cfg(feature = "css")]
+                RageEntry::pair(
+                    "Indexed Stylesheets",
+                    &format!(
+                        "{}",
+                        self.css_projects
+                            .read()
+                            .unwrap()
+                            .values()
+                            .map(|project| project.file_count())
+                            .sum::<usize>()
+                    ),
+                ),
+                RageEntry::pair(
+                    "Indexed Scripts",
+                    &format!("{}", self.get_js_project().files().count()),
+                ),
+                #[cfg(feature = "graphql")]
+                RageEntry::pair(
+                    "Indexed GraphQL Documents",
+                    &format!("{}", self.get_graphql_project().file_count()),
+                ),
+                RageEntry::pair(
+                    "JSON Schemas",
+                    &format!("{}", self.get_json_schemas().schema_count()),
+                ),
+                RageEntry::pair("Indexed Symbols", &format!("{}", self.symbols.len())),
+                #[cfg(feature = "grit")]
+                RageEntry::pair("Search Patterns", &format!("{}", self.patterns.len())),
+            ]);
+        }
 
         Ok(RageResult { entries })
     }
 
+    #[cfg(feature = "grit")]
     fn parse_pattern(
         &self,
         params: ParsePatternParams,
@@ -830,6 +1321,15 @@ impl Workspace for WorkspaceServer {
         Ok(ParsePatternResult { pattern_id })
     }
 
+    /// The patterns can't be compiled when the `grit` feature is disabled
+    #[cfg(not(feature = "grit"))]
+    fn parse_pattern(&self, _: ParsePatternParams) -> Result<ParsePatternResult, WorkspaceError> {
+        Err(WorkspaceError::SearchError(SearchError::QueryError(
+            QueryDiagnostic("The search requires the support of GritQL.".to_string()),
+        )))
+    }
+
+    #[cfg(feature = "grit")]
     fn search_pattern(&self, params: SearchPatternParams) -> Result<SearchResults, WorkspaceError> {
         let Some(query) = self.patterns.get(&params.pattern) else {
             return Err(WorkspaceError::SearchError(SearchError::InvalidPattern(
@@ -846,22 +1346,28 @@ impl Workspace for WorkspaceServer {
         let parse = self.get_parse(params.path.clone())?;
 
         let document_file_source = self.get_file_source(&params.path);
-        let matches = search(
+        search(
             &params.path,
             &document_file_source,
             parse,
             &query,
             workspace,
-        )?;
+        )
+    }
 
-        Ok(SearchResults {
-            file: params.path,
-            matches,
-        })
+    /// No pattern is ever parsed when the `grit` feature is disabled
+    #[cfg(not(feature = "grit"))]
+    fn search_pattern(&self, _: SearchPatternParams) -> Result<SearchResults, WorkspaceError> {
+        Err(WorkspaceError::SearchError(SearchError::InvalidPattern(
+            InvalidPattern,
+        )))
     }
 
     fn drop_pattern(&self, params: super::DropPatternParams) -> Result<(), WorkspaceError> {
+        #[cfg(feature = "grit")]
         self.patterns.remove(&params.pattern);
+        #[cfg(not(feature = "grit"))]
+        let _ = params;
         Ok(())
     }
 
@@ -869,10 +1375,402 @@ impl Workspace for WorkspaceServer {
         None
     }
 
+    fn get_module_graph(&self, _: GetModuleGraphParams) -> Result<ModuleGraph, WorkspaceError> {
+        let project = self.get_js_project();
+        let mut modules: Vec<_> = project
+            .module_graph()
+            .modules()
+            .map(|(path, imports)| {
+                let mut node = ModuleGraphNode {
+                    path: path.display().to_string(),
+                    ..Default::default()
+                };
+                for import in imports {
+                    if let Some(resolved) = &import.resolved {
+                        node.imports.push(resolved.display().to_string());
+                    } else if is_relative_specifier(&import.specifier) {
+                        node.unresolved.push(import.specifier.clone());
+                    } else {
+                        node.external.push(import.specifier.clone());
+                    }
+                }
+                for list in [&mut node.imports, &mut node.external, &mut node.unresolved] {
+                    list.sort_unstable();
+                    list.dedup();
+                }
+                node
+            })
+            .collect();
+        modules.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(ModuleGraph { modules })
+    }
+
+    fn export_project_index(
+        &self,
+        _: ExportProjectIndexParams,
+    ) -> Result<ExportProjectIndexResult, WorkspaceError> {
+        let js_project = self.get_js_project();
+        #[cfg(feature = "graphql")]
+        let graphql_project = self.get_graphql_project();
+        let angular_project = self.get_angular_project();
+
+        let mut snapshot = ProjectIndexSnapshot::default();
+        let mut stale_paths = Vec::new();
+        for entry in self.content_hashes.iter() {
+            let path = entry.key().as_path();
+            let file = IndexedFile {
+                content_hash: *entry.value(),
+                script: js_project.get_file(path).cloned(),
+                #[cfg(feature = "css")]
+                stylesheet: self.get_css_project(entry.key()).get_file(path).cloned(),
+                #[cfg(feature = "graphql")]
+                graphql: graphql_project.get_file(path).cloned(),
+                angular_components: angular_project.get_file(path).map(<[_]>::to_vec),
+            };
+            if !file.is_empty() {
+                snapshot.files.insert(path.to_path_buf(), file);
+            } else if !self.documents.contains_key(entry.key()) {
+                stale_paths.push(entry.key().clone());
+            }
+        }
+        // The hashes of the files that are no longer in the indexes would be kept forever
+        for path in stale_paths {
+            self.content_hashes.remove(&path);
+        }
+
+        Ok(ExportProjectIndexResult {
+            index: snapshot.to_json(),
+        })
+    }
+
+    fn import_project_index(
+        &self,
+        params: ImportProjectIndexParams,
+    ) -> Result<ImportProjectIndexResult, WorkspaceError> {
+        let Some(mut snapshot) = ProjectIndexSnapshot::from_json(&params.index) else {
+            return Ok(ImportProjectIndexResult::default());
+        };
+
+        // The settings are locked before the indexes, like when a file is parsed
+        #[cfg(feature = "css")]
+        let workspace = self.workspace();
+        #[cfg(feature = "css")]
+        let mut css_projects = self.css_projects.write().unwrap();
+        let mut js_project = self.js_project.write().unwrap();
+        #[cfg(feature = "graphql")]
+        let mut graphql_project = self.graphql_project.write().unwrap();
+        let mut angular_project = self.angular_project.write().unwrap();
+        let mut scripts = Vec::new();
+        let mut imported = Vec::new();
+        for (path, content_hash) in params.content_hashes {
+            let Some(file) = snapshot.files.remove(path.as_path()) else {
+                continue;
+            };
+            if file.content_hash != content_hash {
+                continue;
+            }
+
+            if let Some(index) = file.script {
+                scripts.push((path.to_path_buf(), index));
+            }
+            #[cfg(feature = "css")]
+            if let Some(index) = file.stylesheet {
+                let project_key = workspace
+                    .as_ref()
+                    .get_project_key_for(&path)
+                    .unwrap_or_default();
+                let css_project = css_projects.entry(project_key).or_default();
+                Arc::make_mut(css_project).insert_file(path.to_path_buf(), index);
+            }
+            #[cfg(feature = "graphql")]
+            if let Some(index) = file.graphql {
+                Arc::make_mut(&mut *graphql_project).insert_file(path.to_path_buf(), index);
+            }
+            if let Some(components) = file.angular_components {
+                Arc::make_mut(&mut *angular_project).insert_file(path.to_path_buf(), components);
+            }
+            self.content_hashes.insert(path.clone(), content_hash);
+            imported.push(path);
+        }
+        // The imports of the scripts are resolved together, in parallel
+        Arc::make_mut(&mut *js_project).insert_files(scripts);
+
+        Ok(ImportProjectIndexResult { imported })
+    }
+
+    fn forget_files(&self, params: ForgetFilesParams) -> Result<(), WorkspaceError> {
+        // The removed directories are expanded to the indexed files under them. The files that
+        // are still on the disk are kept, so that a directory can be pruned of its removed files.
+        // The symbols are searched too, because the hashes are pruned when the index is exported.
+        let paths: FxHashSet<BiomePath> = params
+            .paths
+            .into_iter()
+            .flat_map(|path| {
+                let is_under = |indexed: &BiomePath| indexed.as_path().starts_with(path.as_path());
+                let mut paths: Vec<_> = self
+                    .content_hashes
+                    .iter()
+                    .map(|entry| entry.key().clone())
+                    .chain(self.symbols.iter().map(|entry| entry.key().clone()))
+                    .filter(is_under)
+                    .collect();
+                paths.push(path);
+                paths
+            })
+            .filter(|path| !path.as_path().exists())
+            .collect();
+
+        // The settings are locked before the indexes, like when a file is parsed
+        #[cfg(feature = "css")]
+        let workspace = self.workspace();
+        #[cfg(feature = "css")]
+        let mut css_projects = self.css_projects.write().unwrap();
+        let mut js_project = self.js_project.write().unwrap();
+        let mut json_schemas = self.json_schemas.write().unwrap();
+        #[cfg(feature = "graphql")]
+        let mut graphql_project = self.graphql_project.write().unwrap();
+        let mut angular_project = self.angular_project.write().unwrap();
+        for path in paths {
+            // The documents opened by the client keep their content
+            if self.documents.contains_key(&path) {
+                continue;
+            }
+            #[cfg(feature = "css")]
+            {
+                let project_key = workspace
+                    .as_ref()
+                    .get_project_key_for(&path)
+                    .unwrap_or_default();
+                if let Some(css_project) = css_projects.get_mut(&project_key) {
+                    if css_project.get_file(&path).is_some() {
+                        Arc::make_mut(css_project).remove_file(&path);
+                    }
+                }
+            }
+            if js_project.get_file(&path).is_some() {
+                Arc::make_mut(&mut *js_project).remove_file(&path);
+            }
+            if json_schemas.get_schema(&path).is_some() {
+                Arc::make_mut(&mut *json_schemas).remove_schema(&path);
+            }
+            #[cfg(feature = "graphql")]
+            if graphql_project.get_file(&path).is_some() {
+                Arc::make_mut(&mut *graphql_project).remove_file(&path);
+            }
+            if angular_project.get_file(&path).is_some() {
+                Arc::make_mut(&mut *angular_project).remove_file(&path);
+            }
+            self.symbols.remove(&path);
+            self.content_hashes.remove(&path);
+            #[cfg(feature = "graphql")]
+            self.invalidate_graphql_schema(&path);
+        }
+
+        Ok(())
+    }
+
+    fn get_document_symbols(
+        &self,
+        params: GetDocumentSymbolsParams,
+    ) -> Result<GetDocumentSymbolsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let document_symbols = capabilities
+            .symbols
+            .document_symbols
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path)?;
+        Ok(GetDocumentSymbolsResult {
+            symbols: document_symbols(parse),
+        })
+    }
+
+    fn get_workspace_symbols(
+        &self,
+        params: GetWorkspaceSymbolsParams,
+    ) -> Result<GetWorkspaceSymbolsResult, WorkspaceError> {
+        let query = params.query.to_lowercase();
+        let mut symbols = Vec::new();
+        for entry in self.symbols.iter() {
+            collect_workspace_symbols(entry.key(), entry.value(), None, &query, &mut symbols);
+        }
+        symbols.sort_unstable_by(|a, b| {
+            a.path
+                .cmp(&b.path)
+                .then_with(|| a.range.start().cmp(&b.range.start()))
+        });
+        symbols.truncate(MAX_WORKSPACE_SYMBOLS);
+
+        Ok(GetWorkspaceSymbolsResult { symbols })
+    }
+
+    fn get_definition(
+        &self,
+        params: GetDefinitionParams,
+    ) -> Result<GetDefinitionResult, WorkspaceError> {
+        let file_source = self.get_file_source(&params.path);
+        let parse = self.get_parse(params.path.clone())?;
+        let definitions = match file_source {
+            DocumentFileSource::Js(_) => import_specifier_at(&parse, params.definition_of)
+                .and_then(|specifier| self.resolve_import(&params.path, &specifier))
+                .map(|path| Definition {
+                    path: BiomePath::new(path),
+                    range: TextRange::default(),
+                })
+                .into_iter()
+                .collect(),
+            #[cfg(feature = "css")]
+            DocumentFileSource::Css(_) => {
+                let Some(name) = custom_property_at(&parse, params.definition_of) else {
+                    return Ok(GetDefinitionResult::default());
+                };
+                let mut definitions: Vec<_> = self
+                    .get_css_project(&params.path)
+                    .custom_property_definitions(&name)
+                    .map(|(path, definition)| Definition {
+                        path: BiomePath::new(path),
+                        range: definition.range,
+                    })
+                    .collect();
+                definitions.sort_unstable_by(|a, b| {
+                    a.path
+                        .cmp(&b.path)
+                        .then_with(|| a.range.start().cmp(&b.range.start()))
+                });
+                definitions
+            }
+            _ => Vec::new(),
+        };
+
+        Ok(GetDefinitionResult { definitions })
+    }
+
+    fn get_semantic_tokens(
+        &self,
+        params: GetSemanticTokensParams,
+    ) -> Result<GetSemanticTokensResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let semantic_tokens = capabilities
+            .symbols
+            .semantic_tokens
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path)?;
+        Ok(GetSemanticTokensResult {
+            tokens: semantic_tokens(parse),
+        })
+    }
+
+    fn get_inlay_hints(
+        &self,
+        params: GetInlayHintsParams,
+    ) -> Result<GetInlayHintsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let inlay_hints = capabilities
+            .symbols
+            .inlay_hints
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path)?;
+        Ok(GetInlayHintsResult {
+            hints: inlay_hints(parse, params.range),
+        })
+    }
+
+    fn get_signature_help(
+        &self,
+        params: GetSignatureHelpParams,
+    ) -> Result<GetSignatureHelpResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let signature_help = capabilities
+            .symbols
+            .signature_help
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path)?;
+        Ok(GetSignatureHelpResult {
+            signature: signature_help(parse, params.position),
+        })
+    }
+
+    fn get_folding_ranges(
+        &self,
+        params: GetFoldingRangesParams,
+    ) -> Result<GetFoldingRangesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let folding_ranges = capabilities
+            .symbols
+            .folding_ranges
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path)?;
+        Ok(GetFoldingRangesResult {
+            ranges: folding_ranges(parse),
+        })
+    }
+
+    fn get_selection_ranges(
+        &self,
+        params: GetSelectionRangesParams,
+    ) -> Result<GetSelectionRangesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let selection_ranges = capabilities
+            .symbols
+            .selection_ranges
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path)?;
+        Ok(GetSelectionRangesResult {
+            ranges: selection_ranges(parse, params.positions),
+        })
+    }
+
+    fn get_rule_configuration(
+        &self,
+        params: GetRuleConfigurationParams,
+    ) -> Result<GetRuleConfigurationResult, WorkspaceError> {
+        let Some((group, rule)) = params
+            .category
+            .strip_prefix("lint/")
+            .and_then(|rule| rule.split_once('/'))
+        else {
+            return Ok(GetRuleConfigurationResult::default());
+        };
+
+        self.set_current_project_for(&params.path);
+        let workspace = self.workspace();
+        let configuration = workspace
+            .settings()
+            .and_then(|settings| settings.as_linter_rules(params.path.as_path()))
+            .and_then(|rules| serde_json::to_value(rules.as_ref()).ok())
+            .and_then(|rules| rules.get(group)?.get(rule).cloned())
+            .filter(|configuration| !configuration.is_null())
+            .and_then(|configuration| serde_json::to_string_pretty(&configuration).ok());
+
+        Ok(GetRuleConfigurationResult { configuration })
+    }
+
+    fn resolve_configuration(
+        &self,
+        params: ResolveConfigurationParams,
+    ) -> Result<ResolveConfigurationResult, WorkspaceError> {
+        self.set_current_project_for(&params.path);
+        let workspace = self.workspace();
+        let configuration = workspace
+            .settings()
+            .map(|settings| settings.resolved_configuration(params.path.as_path()))
+            .and_then(|configuration| serde_json::to_string_pretty(&configuration).ok())
+            .unwrap_or_default();
+
+        Ok(ResolveConfigurationResult { configuration })
+    }
+
     fn organize_imports(
         &self,
         params: OrganizeImportsParams,
     ) -> Result<OrganizeImportsResult, WorkspaceError> {
+        self.set_current_project_for(&params.path);
         let capabilities = self.get_file_capabilities(&params.path);
         let organize_imports = capabilities
             .analyzer
@@ -886,6 +1784,44 @@ impl Workspace for WorkspaceServer {
     }
 }
 
+/// Collects the symbols of `document_symbols` and their children whose name matches `query`,
+/// which is in lowercase
+fn collect_workspace_symbols(
+    path: &BiomePath,
+    document_symbols: &[DocumentSymbol],
+    container_name: Option<&str>,
+    query: &str,
+    symbols: &mut Vec<WorkspaceSymbol>,
+) {
+    for symbol in document_symbols {
+        if matches_query(&symbol.name, query) {
+            symbols.push(WorkspaceSymbol {
+                name: symbol.name.clone(),
+                kind: symbol.kind,
+                path: path.clone(),
+                range: symbol.selection_range,
+                container_name: container_name.map(str::to_string),
+            });
+        }
+        collect_workspace_symbols(path, &symbol.children, Some(&symbol.name), query, symbols);
+    }
+}
+
+/// Runs a phase of the processing of the file at `path`, such as its parsing, in a span so
+/// that its latency is traced, and measures it when profiling is enabled
+fn measure_phase<T>(phase: &'static str, path: &BiomePath, func: impl FnOnce() -> T) -> T {
+    debug_span!("phase", otel.name = phase, path = %path.as_path().display())
+        .in_scope(|| profiling::measure(phase, func))
+}
+
+/// Whether `name` contains the characters of `query`, in the same order and ignoring the case
+fn matches_query(name: &str, query: &str) -> bool {
+    let mut name = name.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .all(|expected| name.any(|character| character == expected))
+}
+
 /// Returns `true` if `path` is a directory or
 /// if it is a symlink that resolves to a directory.
 fn is_dir(path: &Path) -> bool {
@@ -894,6 +1830,7 @@ fn is_dir(path: &Path) -> bool {
 
 /// Generates a pattern ID that we can use as "handle" for referencing
 /// previously parsed search queries.
+#[cfg(feature = "grit")]
 fn make_search_pattern_id() -> PatternId {
     static COUNTER: AtomicUsize = AtomicUsize::new(1);
     let counter = COUNTER.fetch_add(1, Ordering::AcqRel);
@@ -451,7 +451,7 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 19] {
+pub fn methods() -> [WorkspaceMethod; 34] {
     [
         workspace_method!(file_features),
         workspace_method!(update_settings),
@@ -471,6 +471,21 @@ pub fn methods() -> [WorkspaceMethod; 19] {
         workspace_method!(format_range),
         workspace_method!(format_on_type),
         workspace_method!(fix_file),
+        workspace_method!(prepare_rename),
         workspace_method!(rename),
+        workspace_method!(get_document_symbols),
+        workspace_method!(get_workspace_symbols),
+        workspace_method!(get_definition),
+        workspace_method!(get_semantic_tokens),
+        workspace_method!(get_inlay_hints),
+        workspace_method!(get_signature_help),
+        workspace_method!(get_folding_ranges),
+        workspace_method!(get_selection_ranges),
+        workspace_method!(get_rule_configuration),
+        workspace_method!(resolve_configuration),
+        workspace_method!(get_module_graph),
+        workspace_method!(export_project_index),
+        workspace_method!(import_project_index),
+        workspace_method!(forget_files),
     ]
 }
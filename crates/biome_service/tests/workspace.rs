@@ -1,14 +1,27 @@
 #[cfg(test)]
 mod test {
-    use biome_analyze::RuleCategories;
+    use biome_analyze::{RuleCategories, RuleFilter};
     use biome_configuration::analyzer::{RuleGroup, RuleSelector};
-    use biome_fs::BiomePath;
-    use biome_js_syntax::{JsFileSource, TextSize};
+    use biome_configuration::ConfigurationPathHint;
+    use biome_formatter::IndentStyle;
+    use biome_fs::{BiomePath, MemoryFileSystem};
+    use biome_js_syntax::{JsFileSource, TextRange, TextSize};
+    use biome_service::configuration::{load_configuration, load_editorconfig};
     use biome_service::file_handlers::DocumentFileSource;
+    use biome_service::project_index::content_hash;
+    use biome_service::settings::Settings;
     use biome_service::workspace::{
-        server, FileGuard, OpenFileParams, RegisterProjectFolderParams,
+        server, CloseFileParams, ExportProjectIndexParams, FeaturesBuilder, FileGuard,
+        FoldingRangeKind, ForgetFilesParams, GetDefinitionParams, GetDocumentSymbolsParams,
+        GetFoldingRangesParams, GetInlayHintsParams, GetModuleGraphParams,
+        GetSelectionRangesParams, GetSemanticTokensParams, GetSignatureHelpParams,
+        GetSyntaxTreeParams, GetWorkspaceSymbolsParams, ImportProjectIndexParams, InlayHintKind,
+        OpenFileParams, PrepareRenameParams, RegisterProjectFolderParams, RenameParams,
+        SemanticTokenKind, SemanticTokenModifier, SupportsFeatureParams, SymbolKind,
+        UpdateSettingsParams,
     };
-    use biome_service::Workspace;
+    use biome_service::{DynRef, Workspace};
+    use std::path::{Path, PathBuf};
     fn create_server() -> Box<dyn Workspace> {
         let workspace = server();
         workspace
@@ -249,6 +262,465 @@ type User {
         assert_eq!(diagnostics.len(), 1)
     }
 
+    #[test]
+    fn renames_exported_symbol_in_importers() {
+        const UTILS: &str = "export function sum(a, b) { return a + b; }";
+        const MAIN: &str = r#"import { sum } from "./utils.js"; sum(1, 2);"#;
+
+        let workspace = create_server();
+        let _utils = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("utils.js"),
+                content: UTILS.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let main = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("main.js"),
+                content: MAIN.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        // The imports of a script are known once it's parsed
+        main.get_syntax_tree().unwrap();
+
+        let prepared = workspace
+            .prepare_rename(PrepareRenameParams {
+                path: BiomePath::new("utils.js"),
+                symbol_at: TextSize::from(17),
+            })
+            .unwrap();
+        assert_eq!(prepared.name, "sum");
+
+        let result = workspace
+            .rename(RenameParams {
+                path: BiomePath::new("utils.js"),
+                symbol_at: TextSize::from(17),
+                new_name: "add".into(),
+            })
+            .unwrap();
+        assert_eq!(
+            result.indels.new_string(UTILS),
+            "export function add(a, b) { return a + b; }"
+        );
+        assert_eq!(result.other_files.len(), 1);
+        assert_eq!(
+            result.other_files[0].indels.new_string(MAIN),
+            r#"import { add } from "./utils.js"; add(1, 2);"#
+        );
+    }
+
+    #[test]
+    fn lists_document_and_workspace_symbols() {
+        const SHAPES: &str =
+            "export class Shape { area() { return 0; } }\nconst ORIGIN = 0;\nexport default function () {}";
+
+        let workspace = create_server();
+        let shapes = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("shapes.ts"),
+                content: SHAPES.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        shapes.get_syntax_tree().unwrap();
+
+        let result = workspace
+            .get_document_symbols(GetDocumentSymbolsParams {
+                path: BiomePath::new("shapes.ts"),
+            })
+            .unwrap();
+        let symbols: Vec<_> = result
+            .symbols
+            .iter()
+            .map(|symbol| (symbol.name.as_str(), symbol.kind))
+            .collect();
+        assert_eq!(
+            symbols,
+            [
+                ("Shape", SymbolKind::Class),
+                ("ORIGIN", SymbolKind::Constant),
+                ("default", SymbolKind::Function),
+            ]
+        );
+        assert_eq!(result.symbols[0].children[0].name, "area");
+        assert_eq!(result.symbols[0].children[0].kind, SymbolKind::Method);
+
+        // The symbols of the closed files can still be searched
+        drop(shapes);
+        let result = workspace
+            .get_workspace_symbols(GetWorkspaceSymbolsParams { query: "ar".into() })
+            .unwrap();
+        assert_eq!(result.symbols.len(), 1);
+        assert_eq!(result.symbols[0].name, "area");
+        assert_eq!(result.symbols[0].container_name.as_deref(), Some("Shape"));
+    }
+
+    #[test]
+    fn resolves_definitions_of_imports_and_custom_properties() {
+        let workspace = create_server();
+        let open = |path: &str, content: &str| {
+            FileGuard::open(
+                workspace.as_ref(),
+                OpenFileParams {
+                    path: BiomePath::new(path),
+                    content: content.into(),
+                    version: 0,
+                    document_file_source: None,
+                },
+            )
+            .unwrap()
+        };
+        let utils = open("src/utils.ts", "export const one = 1;");
+        let _main = open("src/main.js", r#"import { one } from "./utils.js";"#);
+        let theme = open("theme.css", ":root { --primary: red; }");
+        let _button = open("button.css", ".button { color: var(--primary); }");
+        utils.get_syntax_tree().unwrap();
+        theme.get_syntax_tree().unwrap();
+
+        let result = workspace
+            .get_definition(GetDefinitionParams {
+                path: BiomePath::new("src/main.js"),
+                definition_of: TextSize::from(24),
+            })
+            .unwrap();
+        assert_eq!(result.definitions.len(), 1);
+        assert_eq!(result.definitions[0].path, BiomePath::new("src/utils.ts"));
+
+        let result = workspace
+            .get_definition(GetDefinitionParams {
+                path: BiomePath::new("button.css"),
+                definition_of: TextSize::from(25),
+            })
+            .unwrap();
+        assert_eq!(result.definitions.len(), 1);
+        assert_eq!(result.definitions[0].path, BiomePath::new("theme.css"));
+        assert_eq!(
+            result.definitions[0].range,
+            TextRange::new(TextSize::from(8), TextSize::from(17))
+        );
+    }
+
+    #[test]
+    fn imports_the_project_index_of_the_files_that_did_not_change() {
+        let main = r#"import { one } from "./utils.js";"#;
+        let utils = "export const one = 1;";
+
+        let workspace = create_server();
+        for (path, content) in [("src/main.js", main), ("src/utils.ts", utils)] {
+            workspace
+                .open_file(OpenFileParams {
+                    path: BiomePath::new(path),
+                    content: content.into(),
+                    version: 0,
+                    document_file_source: None,
+                })
+                .unwrap();
+            workspace
+                .get_syntax_tree(GetSyntaxTreeParams {
+                    path: BiomePath::new(path),
+                })
+                .unwrap();
+        }
+        let index = workspace
+            .export_project_index(ExportProjectIndexParams {})
+            .unwrap()
+            .index;
+
+        let workspace = create_server();
+        let result = workspace
+            .import_project_index(ImportProjectIndexParams {
+                index,
+                content_hashes: vec![
+                    (BiomePath::new("src/main.js"), content_hash(main)),
+                    (
+                        BiomePath::new("src/utils.ts"),
+                        content_hash("export const two = 2;"),
+                    ),
+                ],
+            })
+            .unwrap();
+        assert_eq!(result.imported, [BiomePath::new("src/main.js")]);
+
+        let graph = workspace.get_module_graph(GetModuleGraphParams {}).unwrap();
+        assert_eq!(graph.modules.len(), 1);
+        assert_eq!(graph.modules[0].unresolved, ["./utils.js"]);
+    }
+
+    #[test]
+    fn forgets_the_files_removed_from_the_disk() {
+        let workspace = create_server();
+        for (path, content) in [
+            ("src/main.js", r#"import { one } from "./utils.js";"#),
+            ("src/utils.ts", "export const one = 1;"),
+        ] {
+            workspace
+                .open_file(OpenFileParams {
+                    path: BiomePath::new(path),
+                    content: content.into(),
+                    version: 0,
+                    document_file_source: None,
+                })
+                .unwrap();
+            workspace
+                .get_syntax_tree(GetSyntaxTreeParams {
+                    path: BiomePath::new(path),
+                })
+                .unwrap();
+        }
+        workspace
+            .close_file(CloseFileParams {
+                path: BiomePath::new("src/utils.ts"),
+            })
+            .unwrap();
+
+        workspace
+            .forget_files(ForgetFilesParams {
+                paths: vec![
+                    BiomePath::new("src/main.js"),
+                    BiomePath::new("src/utils.ts"),
+                ],
+            })
+            .unwrap();
+
+        // The opened document is kept
+        let graph = workspace.get_module_graph(GetModuleGraphParams {}).unwrap();
+        assert_eq!(graph.modules.len(), 1);
+        assert_eq!(graph.modules[0].unresolved, ["./utils.js"]);
+    }
+
+    #[test]
+    fn forgets_the_files_of_the_directories_removed_from_the_disk() {
+        let workspace = create_server();
+        for (path, content) in [
+            ("src/main.js", r#"import { one } from "./utils/one.js";"#),
+            ("src/utils/one.ts", "export const one = 1;"),
+        ] {
+            let _file = FileGuard::open(
+                workspace.as_ref(),
+                OpenFileParams {
+                    path: BiomePath::new(path),
+                    content: content.into(),
+                    version: 0,
+                    document_file_source: None,
+                },
+            )
+            .unwrap();
+            workspace
+                .get_syntax_tree(GetSyntaxTreeParams {
+                    path: BiomePath::new(path),
+                })
+                .unwrap();
+        }
+
+        workspace
+            .forget_files(ForgetFilesParams {
+                paths: vec![BiomePath::new("src/utils")],
+            })
+            .unwrap();
+
+        let graph = workspace.get_module_graph(GetModuleGraphParams {}).unwrap();
+        assert_eq!(graph.modules.len(), 1);
+        assert_eq!(graph.modules[0].path, "src/main.js");
+        assert_eq!(graph.modules[0].unresolved, ["./utils/one.js"]);
+    }
+
+    #[test]
+    fn classifies_the_semantic_tokens_of_a_script() {
+        let workspace = create_server();
+        let _file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("main.js"),
+                content: "import { a } from \"a\";\nconst b = a;\nlet c = (d) => b + d;".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let result = workspace
+            .get_semantic_tokens(GetSemanticTokensParams {
+                path: BiomePath::new("main.js"),
+            })
+            .unwrap();
+        let tokens: Vec<_> = result
+            .tokens
+            .into_iter()
+            .map(|token| (u32::from(token.range.start()), token.kind, token.modifiers))
+            .collect();
+
+        use SemanticTokenKind::*;
+        use SemanticTokenModifier::*;
+        assert_eq!(
+            tokens,
+            vec![
+                (9, Variable, vec![Imported, Declaration]),
+                (29, Variable, vec![Readonly, Declaration]),
+                (33, Variable, vec![Imported]),
+                (40, Variable, vec![Declaration]),
+                (45, Parameter, vec![Declaration]),
+                (51, Variable, vec![Readonly]),
+                (55, Parameter, vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn returns_the_inlay_hints_of_a_script() {
+        let workspace = create_server();
+        let _file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("main.ts"),
+                content: "function add(first, second) {}\nconst second = 1;\nadd(2, second);\nconst values = [1, 2];\nconst names = new Set<string>();".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let result = workspace
+            .get_inlay_hints(GetInlayHintsParams {
+                path: BiomePath::new("main.ts"),
+                range: TextRange::new(TextSize::from(0), TextSize::from(120)),
+            })
+            .unwrap();
+        let hints: Vec<_> = result
+            .hints
+            .into_iter()
+            .map(|hint| (u32::from(hint.position), hint.label, hint.kind))
+            .collect();
+
+        assert_eq!(
+            hints,
+            vec![
+                (53, "first".to_string(), InlayHintKind::Parameter),
+                (77, "number[]".to_string(), InlayHintKind::Type),
+                (99, "Set<string>".to_string(), InlayHintKind::Type),
+            ]
+        );
+    }
+
+    #[test]
+    fn returns_the_signature_of_a_called_function() {
+        const SOURCE: &str = "/**
+ * Adds two numbers.
+ * @param {number} first - The first number
+ * @param second The second number
+ */
+function add(first, second) {}
+add(1, 2);";
+
+        let workspace = create_server();
+        let _file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("main.js"),
+                content: SOURCE.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let signature = workspace
+            .get_signature_help(GetSignatureHelpParams {
+                path: BiomePath::new("main.js"),
+                position: TextSize::from(SOURCE.find("2)").unwrap() as u32),
+            })
+            .unwrap()
+            .signature
+            .unwrap();
+
+        assert_eq!(signature.label, "add(first, second)");
+        assert_eq!(
+            signature.documentation.as_deref(),
+            Some("Adds two numbers.")
+        );
+        assert_eq!(signature.active_parameter, Some(1));
+        let parameters: Vec<_> = signature
+            .parameters
+            .into_iter()
+            .map(|parameter| (parameter.label, parameter.documentation))
+            .collect();
+        assert_eq!(
+            parameters,
+            vec![
+                (
+                    "first".to_string(),
+                    Some("`number` The first number".to_string())
+                ),
+                ("second".to_string(), Some("The second number".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn returns_the_folding_and_selection_ranges_of_a_script() {
+        let workspace = create_server();
+        let _file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("main.js"),
+                content: "import a from \"a\";\nimport b from \"b\";\n\n// #region helpers\nfunction f() {\n  return [a, b];\n}\n// #endregion\n".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let result = workspace
+            .get_folding_ranges(GetFoldingRangesParams {
+                path: BiomePath::new("main.js"),
+            })
+            .unwrap();
+        let ranges: Vec<_> = result
+            .ranges
+            .into_iter()
+            .map(|folding_range| {
+                (
+                    u32::from(folding_range.range.start()),
+                    u32::from(folding_range.range.end()),
+                    folding_range.kind,
+                )
+            })
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (0, 37, FoldingRangeKind::Imports),
+                (39, 105, FoldingRangeKind::Region),
+                (71, 89, FoldingRangeKind::Block),
+            ]
+        );
+
+        let result = workspace
+            .get_selection_ranges(GetSelectionRangesParams {
+                path: BiomePath::new("main.js"),
+                positions: vec![TextSize::from(83)],
+            })
+            .unwrap();
+        assert_eq!(
+            result.ranges[0][..3],
+            [
+                TextRange::new(TextSize::from(83), TextSize::from(84)),
+                TextRange::new(TextSize::from(83), TextSize::from(87)),
+                TextRange::new(TextSize::from(82), TextSize::from(88)),
+            ]
+        );
+    }
+
     #[test]
     fn pull_grit_debug_info() {
         let workspace = create_server();
@@ -272,4 +744,283 @@ type User {
 
         assert!(syntax.starts_with("GritRoot"))
     }
+
+    #[test]
+    fn overrides_merge_the_rule_options_and_exclude_the_negated_patterns() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("biome.json"),
+            r#"{
+    "linter": {
+        "rules": {
+            "style": {
+                "useNamingConvention": {
+                    "level": "error",
+                    "options": { "strictCase": false, "requireAscii": true }
+                }
+            }
+        }
+    },
+    "overrides": [
+        {
+            "include": ["packages/**", "!packages/legacy/**"],
+            "linter": {
+                "rules": {
+                    "style": {
+                        "useNamingConvention": {
+                            "level": "warn",
+                            "options": { "requireAscii": false }
+                        }
+                    }
+                }
+            }
+        }
+    ]
+}"#,
+        );
+        let fs = DynRef::Owned(Box::new(fs));
+        let loaded = load_configuration(
+            &fs,
+            ConfigurationPathHint::FromUser(PathBuf::from("biome.json")),
+        )
+        .unwrap();
+
+        let overrides = serde_json::to_value(&loaded.configuration.overrides).unwrap();
+        assert_eq!(
+            overrides[0]["linter"]["rules"]["style"]["useNamingConvention"]["options"],
+            serde_json::json!({ "strictCase": false })
+        );
+
+        let mut settings = Settings::default();
+        settings
+            .merge_with_configuration(loaded.configuration, None, None, &[])
+            .unwrap();
+        let pattern = &settings.override_settings.patterns[0];
+        let is_included = |path: &str| {
+            let path = Path::new(path);
+            pattern.include.matches_path(path) && !pattern.exclude.matches_path(path)
+        };
+        assert!(is_included("packages/app/index.js"));
+        assert!(!is_included("packages/legacy/index.js"));
+        assert!(!is_included("scripts/build.js"));
+    }
+
+    #[test]
+    fn overrides_enable_the_domains_of_rules() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("biome.json"),
+            r#"{
+    "linter": {
+        "domains": { "test": "none" }
+    },
+    "overrides": [
+        {
+            "include": ["**/*.test.js"],
+            "linter": {
+                "domains": { "test": "all" }
+            }
+        }
+    ]
+}"#,
+        );
+        let fs = DynRef::Owned(Box::new(fs));
+        let loaded = load_configuration(
+            &fs,
+            ConfigurationPathHint::FromUser(PathBuf::from("biome.json")),
+        )
+        .unwrap();
+        let mut settings = Settings::default();
+        settings
+            .merge_with_configuration(loaded.configuration, None, None, &[])
+            .unwrap();
+
+        let enabled_rules = |path: &str| {
+            settings
+                .as_linter_rules(Path::new(path))
+                .unwrap()
+                .as_enabled_rules()
+        };
+        let no_focused_tests = RuleFilter::Rule("suspicious", "noFocusedTests");
+        let no_misplaced_assertion = RuleFilter::Rule("suspicious", "noMisplacedAssertion");
+        let source_rules = enabled_rules("src/sum.js");
+        assert!(!source_rules.contains(&no_focused_tests));
+        assert!(!source_rules.contains(&no_misplaced_assertion));
+        let test_rules = enabled_rules("src/sum.test.js");
+        assert!(test_rules.contains(&no_focused_tests));
+        assert!(test_rules.contains(&no_misplaced_assertion));
+    }
+
+    #[test]
+    fn suppressions_file_turns_off_the_rules_of_the_patterns() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("biome.json"),
+            r#"{
+    "linter": {
+        "rules": {
+            "suspicious": { "noDebugger": "error" }
+        }
+    }
+}"#,
+        );
+        fs.insert(
+            PathBuf::from(".biome-suppressions"),
+            r#"# The legacy code is removed in the next major
+legacy/** suspicious/noDebugger style
+"#,
+        );
+        let fs = DynRef::Owned(Box::new(fs));
+        let loaded = load_configuration(
+            &fs,
+            ConfigurationPathHint::FromUser(PathBuf::from("biome.json")),
+        )
+        .unwrap();
+        assert!(loaded.diagnostics.is_empty());
+        let manifest = loaded.suppression_manifest.as_ref().unwrap();
+        assert_eq!(
+            manifest.entries[0].comment.as_deref(),
+            Some("The legacy code is removed in the next major")
+        );
+        assert_eq!(
+            manifest.rule_statistics().get("suspicious/noDebugger"),
+            Some(&1)
+        );
+
+        let mut settings = Settings::default();
+        settings
+            .merge_with_configuration(loaded.configuration, None, None, &[])
+            .unwrap();
+        let enabled_rules = |path: &str| {
+            settings
+                .as_linter_rules(Path::new(path))
+                .unwrap()
+                .as_enabled_rules()
+        };
+        let no_debugger = RuleFilter::Rule("suspicious", "noDebugger");
+        let use_const = RuleFilter::Rule("style", "useConst");
+        assert!(enabled_rules("src/index.js").contains(&no_debugger));
+        let legacy_rules = enabled_rules("legacy/index.js");
+        assert!(!legacy_rules.contains(&no_debugger));
+        assert!(!legacy_rules.contains(&use_const));
+    }
+
+    #[test]
+    fn files_associations_set_the_language_of_the_files() {
+        let workspace = create_server();
+        let configuration = serde_json::from_str(
+            r#"{
+    "files": {
+        "associations": { "*.pcss": "css", "**/*.mts.ejs": "ts" }
+    }
+}"#,
+        )
+        .unwrap();
+        workspace
+            .update_settings(UpdateSettingsParams {
+                configuration,
+                vcs_base_path: None,
+                gitignore_matches: Vec::new(),
+                workspace_directory: None,
+            })
+            .unwrap();
+
+        let features = workspace
+            .file_features(SupportsFeatureParams {
+                path: BiomePath::new("styles.pcss"),
+                features: FeaturesBuilder::new().with_formatter().build(),
+            })
+            .unwrap();
+        assert!(features.supports_format());
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("templates/module.mts.ejs"),
+                content: "const answer: number = 42;".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            file.format_file().unwrap().as_code(),
+            "const answer: number = 42;\n"
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "css"))]
+    fn stylesheets_are_unknown_without_the_css_feature() {
+        let workspace = create_server();
+
+        assert_eq!(
+            DocumentFileSource::from_path(Path::new("styles.css")),
+            DocumentFileSource::Unknown
+        );
+
+        let features = workspace
+            .file_features(SupportsFeatureParams {
+                path: BiomePath::new("styles.css"),
+                features: FeaturesBuilder::new()
+                    .with_formatter()
+                    .with_linter()
+                    .build(),
+            })
+            .unwrap();
+        assert!(!features.supports_format());
+        assert!(!features.supports_lint());
+    }
+
+    #[test]
+    fn editorconfig_files_are_merged_up_to_the_root_one() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from(".editorconfig"),
+            r#"root = true
+
+[*]
+indent_style = tab
+indent_size = 2
+
+[packages/app/src/*.js]
+max_line_length = 100
+
+[packages/lib/*.js]
+max_line_length = 120
+"#,
+        );
+        fs.insert(
+            PathBuf::from("packages/app/.editorconfig"),
+            r#"[*]
+indent_size = 4
+
+[*.md]
+insert_final_newline = false
+"#,
+        );
+        let fs = DynRef::Owned(Box::new(fs));
+        let (configuration, diagnostics) =
+            load_editorconfig(&fs, PathBuf::from("packages/app")).unwrap();
+        assert!(diagnostics.is_empty());
+        let configuration = configuration.unwrap();
+
+        let formatter = configuration.formatter.unwrap();
+        assert_eq!(formatter.indent_style, Some(IndentStyle::Tab));
+        assert_eq!(formatter.indent_width.map(|width| width.value()), Some(4));
+
+        let overrides = configuration.overrides.unwrap().0;
+        let includes: Vec<Vec<_>> = overrides
+            .iter()
+            .map(|pattern| {
+                let include = pattern.include.as_ref().unwrap();
+                include.iter().map(String::as_str).collect()
+            })
+            .collect();
+        assert_eq!(includes, [vec!["src/*.js"], vec!["*.md"]]);
+        assert_eq!(
+            overrides[1].formatter.as_ref().unwrap().enabled,
+            Some(false)
+        );
+    }
 }
@@ -3,7 +3,7 @@ use biome_rowan::{TextRange, TextSize};
 
 /// Single instance of a suppression comment, with the following syntax:
 ///
-/// `// biome-ignore { <category> { (<value>) }? }+: <reason>`
+/// `// biome-ignore { <category> { (<value>) }? }+ { until: <date> }?: <reason>`
 ///
 /// The category broadly describes what feature is being suppressed (formatting,
 /// linting, ...) with the value being and optional, category-specific name of
@@ -14,6 +14,10 @@ use biome_rowan::{TextRange, TextSize};
 /// A suppression must specify a reason: this part has no semantic meaning but
 /// is required to document why a particular feature is being disable for this
 /// line (lint false-positive, specific formatting requirements, ...)
+///
+/// A suppression may specify the date until which it applies, with the format
+/// `YYYY-MM-DD`, for instance when it works around a bug that should be fixed
+/// by a given release
 #[derive(Debug, PartialEq, Eq)]
 pub struct Suppression<'a> {
     /// List of categories for this suppression
@@ -23,6 +27,8 @@ pub struct Suppression<'a> {
     pub categories: Vec<(&'a Category, Option<&'a str>)>,
     /// Reason for this suppression comment to exist
     pub reason: &'a str,
+    /// Date until which the suppression applies, with the format `YYYY-MM-DD`
+    pub until: Option<&'a str>,
     /// If the comment is `// biome-ignore`
     pub is_legacy: bool,
 }
@@ -140,6 +146,7 @@ enum SuppressionDiagnosticKind {
     ParseCategory(String),
     MissingCategory,
     MissingParen,
+    InvalidDate(String),
 }
 
 impl std::fmt::Display for SuppressionDiagnosticKind {
@@ -156,6 +163,9 @@ impl std::fmt::Display for SuppressionDiagnosticKind {
                 write!(f, "unexpected token, expected one of ':' or whitespace")
             }
             SuppressionDiagnosticKind::MissingParen => write!(f, "unexpected token, expected ')'"),
+            SuppressionDiagnosticKind::InvalidDate(date) => {
+                write!(f, "invalid date {date:?}, expected the format YYYY-MM-DD")
+            }
         }
     }
 }
@@ -176,17 +186,22 @@ impl biome_console::fmt::Display for SuppressionDiagnosticKind {
             SuppressionDiagnosticKind::MissingParen => {
                 write!(fmt, "unexpected token, expected ')'")
             }
+            SuppressionDiagnosticKind::InvalidDate(date) => {
+                write!(fmt, "invalid date {date:?}, expected the format YYYY-MM-DD")
+            }
         }
     }
 }
 
-/// Parse the `{ <category> { (<value>) }? }+: <reason>` section of a suppression line
+/// Parse the `{ <category> { (<value>) }? }+ { until: <date> }?: <reason>` section of a
+/// suppression line
 fn parse_suppression_line(
     base: &str,
     is_legacy: bool,
 ) -> Result<Suppression, SuppressionDiagnostic> {
     let mut line = base;
     let mut categories = Vec::new();
+    let mut until = None;
 
     loop {
         // Find either a colon opening parenthesis or space
@@ -199,6 +214,26 @@ fn parse_suppression_line(
 
         let (category, rest) = line.split_at(separator);
         let category = category.trim_end();
+
+        // `until` isn't a category, it's followed by the date until which the suppression applies
+        if category == "until" && rest.starts_with(':') {
+            let rest = rest[1..].trim_start();
+            let end = rest
+                .find(|c: char| c == ':' || c.is_whitespace())
+                .unwrap_or(rest.len());
+            let (date, rest) = rest.split_at(end);
+            if !is_valid_date(date) {
+                return Err(SuppressionDiagnostic {
+                    message: SuppressionDiagnosticKind::InvalidDate(date.into()),
+                    span: TextRange::at(offset_from(base, date), TextSize::of(date)),
+                });
+            }
+
+            until = Some(date);
+            line = rest.trim_start();
+            continue;
+        }
+
         let category: Option<&'static Category> = if !category.is_empty() {
             let category = category.parse().map_err(|()| SuppressionDiagnostic {
                 message: SuppressionDiagnosticKind::ParseCategory(category.into()),
@@ -258,10 +293,31 @@ fn parse_suppression_line(
     Ok(Suppression {
         categories,
         reason,
+        until,
         is_legacy,
     })
 }
 
+/// Returns `true` if `date` has the format `YYYY-MM-DD`, with a valid month and day
+fn is_valid_date(date: &str) -> bool {
+    let mut parts = date.split('-');
+    let (Some(year), Some(month), Some(day), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return false;
+    };
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return false;
+    }
+    if ![year, month, day]
+        .iter()
+        .all(|part| part.bytes().all(|byte| byte.is_ascii_digit()))
+    {
+        return false;
+    }
+    matches!(month.parse::<u8>(), Ok(1..=12)) && matches!(day.parse::<u8>(), Ok(1..=31))
+}
+
 /// Returns the byte offset of `substr` within `base`
 ///
 /// # Safety
@@ -303,6 +359,7 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation1",
+                until: None,
                 is_legacy: true
             })],
         );
@@ -312,6 +369,7 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation2",
+                until: None,
                 is_legacy: true
             })],
         );
@@ -326,6 +384,7 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation3",
+                until: None,
                 is_legacy: true
             })],
         );
@@ -341,6 +400,7 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation4",
+                until: None,
                 is_legacy: true
             })],
         );
@@ -352,6 +412,7 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
+                until: None,
                 is_legacy: true
             })],
         );
@@ -361,6 +422,7 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
+                until: None,
                 is_legacy: true
             })],
         );
@@ -370,6 +432,7 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
+                until: None,
                 is_legacy: true
             })],
         );
@@ -386,6 +449,7 @@ mod tests {
                     (category!("parse"), Some("dog"))
                 ],
                 reason: "explanation",
+                until: None,
                 is_legacy: true
             })],
         );
@@ -399,6 +463,7 @@ mod tests {
                     (category!("parse"), Some("cat"))
                 ],
                 reason: "explanation",
+                until: None,
                 is_legacy: true
             })],
         );
@@ -416,6 +481,7 @@ mod tests {
                     (category!("parse"), Some("frog"))
                 ],
                 reason: "explanation",
+                until: None,
                 is_legacy: true
             })],
         );
@@ -434,6 +500,7 @@ mod tests {
                     (category!("parse"), Some("fish"))
                 ],
                 reason: "explanation",
+                until: None,
                 is_legacy: true
             })],
         );
@@ -447,6 +514,7 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None), (category!("lint"), None)],
                 reason: "explanation",
+                until: None,
                 is_legacy: true
             })],
         );
@@ -526,6 +594,7 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation1",
+                until: None,
                 is_legacy: false
             })],
         );
@@ -536,6 +605,7 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation2",
+                until: None,
                 is_legacy: false
             })],
         );
@@ -550,6 +620,7 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation3",
+                until: None,
                 is_legacy: false
             })],
         );
@@ -565,6 +636,7 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation4",
+                until: None,
                 is_legacy: false
             })],
         );
@@ -576,6 +648,7 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
+                until: None,
                 is_legacy: false
             })],
         );
@@ -585,6 +658,7 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
+                until: None,
                 is_legacy: false
             })],
         );
@@ -594,6 +668,7 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
+                until: None,
                 is_legacy: false
             })],
         );
@@ -610,6 +685,7 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("dog"))
                 ],
                 reason: "explanation",
+                until: None,
                 is_legacy: false
             })],
         );
@@ -623,6 +699,7 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("cat"))
                 ],
                 reason: "explanation",
+                until: None,
                 is_legacy: false
             })],
         );
@@ -640,6 +717,7 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("frog"))
                 ],
                 reason: "explanation",
+                until: None,
                 is_legacy: false
             })],
         );
@@ -658,6 +736,7 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("fish"))
                 ],
                 reason: "explanation",
+                until: None,
                 is_legacy: false
             })],
         );
@@ -671,6 +750,7 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None), (category!("lint"), None)],
                 reason: "explanation",
+                until: None,
                 is_legacy: false
             })],
         );
@@ -732,4 +812,32 @@ mod tests_biome_ignore {
             })],
         );
     }
+
+    #[test]
+    fn parse_suppression_until() {
+        assert_eq!(
+            parse_suppression_comment(
+                "// biome-ignore lint/style/useWhile until: 2025-06-30: explanation"
+            )
+            .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint/style/useWhile"), None)],
+                reason: "explanation",
+                until: Some("2025-06-30"),
+                is_legacy: false
+            })],
+        );
+    }
+
+    #[test]
+    fn diagnostic_invalid_date() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore lint until: 2025-13-01: explanation")
+                .collect::<Vec<_>>(),
+            vec![Err(SuppressionDiagnostic {
+                message: SuppressionDiagnosticKind::InvalidDate(String::from("2025-13-01")),
+                span: TextRange::new(TextSize::from(28), TextSize::from(38))
+            })],
+        );
+    }
 }
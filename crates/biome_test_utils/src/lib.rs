@@ -43,6 +43,7 @@ pub fn create_analyzer_options(
         globals: vec![],
         preferred_quote: PreferredQuote::Double,
         jsx_runtime: Some(JsxRuntime::Transparent),
+        require_suppression_reason: false,
     };
     let options_file = input_file.with_extension("options.json");
     if let Ok(json) = std::fs::read_to_string(options_file.clone()) {
@@ -107,6 +108,8 @@ pub fn create_analyzer_options(
                 .merge_with_configuration(configuration, None, None, &[])
                 .unwrap();
             analyzer_configuration.rules = to_analyzer_rules(&settings, input_file);
+            analyzer_configuration.require_suppression_reason =
+                settings.linter.suppression_reason.is_required();
         }
     }
 
@@ -0,0 +1,218 @@
+//! The types that pass the content of the documents, and their diagnostics, in pieces.
+//!
+//! Building a JavaScript string out of a file of several megabytes, or an array out of all its
+//! diagnostics, takes a lot of memory on both sides of the boundary. These types exchange the
+//! content as chunks of UTF-8 bytes instead, which can be read from a stream and decoded with
+//! `TextDecoder`, and convert the diagnostics to JavaScript values only when they're read.
+
+use js_sys::Error;
+use wasm_bindgen::prelude::*;
+
+use biome_diagnostics::serde::Diagnostic;
+use biome_service::workspace::PullDiagnosticsResult;
+
+use crate::utils::into_error;
+use crate::{to_value, IDiagnostic};
+
+/// The content of a document, written in chunks of UTF-8 bytes.
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct ContentBuffer {
+    bytes: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl ContentBuffer {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a chunk of UTF-8 bytes, such as the ones read from a stream. The chunk may end
+    /// in the middle of a character, as long as the next chunk completes it.
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.bytes.extend_from_slice(chunk);
+    }
+
+    /// Appends a chunk of text
+    #[wasm_bindgen(js_name = pushString)]
+    pub fn push_string(&mut self, chunk: &str) {
+        self.bytes.extend_from_slice(chunk.as_bytes());
+    }
+
+    /// The number of bytes written so far
+    #[wasm_bindgen(getter, js_name = byteLength)]
+    pub fn byte_length(&self) -> usize {
+        self.bytes.len()
+    }
+}
+
+impl ContentBuffer {
+    /// Returns the content, or an error if the bytes aren't valid UTF-8
+    pub(crate) fn into_string(self) -> Result<String, Error> {
+        String::from_utf8(self.bytes).map_err(into_error)
+    }
+}
+
+/// A text returned by the workspace, such as the content of a document or its formatted code,
+/// read in chunks of UTF-8 bytes.
+#[wasm_bindgen]
+pub struct ContentChunks {
+    text: String,
+    offset: usize,
+}
+
+#[wasm_bindgen]
+impl ContentChunks {
+    /// The length of the whole text, in bytes
+    #[wasm_bindgen(getter, js_name = byteLength)]
+    pub fn byte_length(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Returns the next chunk of at most `max_length` bytes, or `undefined` once the whole text
+    /// was read. A chunk may end in the middle of a character, so the chunks should be decoded
+    /// with the `stream` option of `TextDecoder`.
+    #[wasm_bindgen(js_name = nextChunk)]
+    pub fn next_chunk(&mut self, max_length: usize) -> Option<Vec<u8>> {
+        let bytes = &self.text.as_bytes()[self.offset..];
+        if bytes.is_empty() {
+            return None;
+        }
+        let chunk = &bytes[..bytes.len().min(max_length.max(1))];
+        self.offset += chunk.len();
+        Some(chunk.to_vec())
+    }
+}
+
+impl From<String> for ContentChunks {
+    fn from(text: String) -> Self {
+        Self { text, offset: 0 }
+    }
+}
+
+/// The diagnostics of a document, converted to JavaScript values when they're read.
+#[wasm_bindgen]
+pub struct DiagnosticList {
+    diagnostics: Vec<Diagnostic>,
+    errors: usize,
+    skipped_diagnostics: u64,
+}
+
+#[wasm_bindgen]
+impl DiagnosticList {
+    /// The number of diagnostics in the list
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.diagnostics.len()
+    }
+
+    /// The number of diagnostics with the error severity
+    #[wasm_bindgen(getter)]
+    pub fn errors(&self) -> usize {
+        self.errors
+    }
+
+    /// The number of diagnostics that weren't returned, because of the maximum requested
+    #[wasm_bindgen(getter, js_name = skippedDiagnostics)]
+    pub fn skipped_diagnostics(&self) -> u64 {
+        self.skipped_diagnostics
+    }
+
+    /// Returns the diagnostic at `index`, or `undefined` if the index is out of bounds
+    pub fn get(&self, index: usize) -> Result<Option<IDiagnostic>, Error> {
+        self.diagnostics
+            .get(index)
+            .map(|diagnostic| {
+                to_value(diagnostic)
+                    .map(IDiagnostic::from)
+                    .map_err(into_error)
+            })
+            .transpose()
+    }
+
+    /// Returns the diagnostics in the range `start..end` as the UTF-8 bytes of a JSON array.
+    ///
+    /// The buffer of the returned array can be transferred to another worker with
+    /// `postMessage`, without copying it, and read there with `JSON.parse`.
+    pub fn encode(&self, start: usize, end: usize) -> Result<Vec<u8>, Error> {
+        let end = end.min(self.diagnostics.len());
+        serde_json::to_vec(&self.diagnostics[start.min(end)..end]).map_err(into_error)
+    }
+}
+
+impl From<PullDiagnosticsResult> for DiagnosticList {
+    fn from(result: PullDiagnosticsResult) -> Self {
+        Self {
+            diagnostics: result.diagnostics,
+            errors: result.errors,
+            skipped_diagnostics: result.skipped_diagnostics,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_service::WorkspaceError;
+
+    fn read_chunks(chunks: &mut ContentChunks, max_length: usize) -> Vec<Vec<u8>> {
+        std::iter::from_fn(|| chunks.next_chunk(max_length)).collect()
+    }
+
+    #[test]
+    fn splits_the_text_in_the_middle_of_a_character() {
+        let mut chunks = ContentChunks::from("aé".to_string());
+
+        let read = read_chunks(&mut chunks, 2);
+        assert_eq!(read, [vec![b'a', 0xC3], vec![0xA9]]);
+        assert_eq!(String::from_utf8(read.concat()).unwrap(), "aé");
+    }
+
+    #[test]
+    fn reads_a_byte_at_a_time_when_the_maximum_length_is_zero() {
+        let mut chunks = ContentChunks::from("ab".to_string());
+
+        assert_eq!(read_chunks(&mut chunks, 0), [b"a", b"b"]);
+    }
+
+    #[test]
+    fn returns_no_chunk_for_an_empty_text() {
+        let mut chunks = ContentChunks::from(String::new());
+
+        assert_eq!(chunks.byte_length(), 0);
+        assert_eq!(chunks.next_chunk(16), None);
+    }
+
+    #[test]
+    fn joins_the_chunks_that_split_a_character() {
+        let mut buffer = ContentBuffer::new();
+        buffer.push_string("a");
+        buffer.push(&[0xC3]);
+        buffer.push(&[0xA9]);
+
+        assert_eq!(buffer.byte_length(), 3);
+        assert_eq!(buffer.into_string().unwrap(), "aé");
+        assert_eq!(ContentBuffer::new().into_string().unwrap(), "");
+    }
+
+    #[test]
+    fn encodes_the_diagnostics_of_a_range() {
+        let list = DiagnosticList::from(PullDiagnosticsResult {
+            diagnostics: vec![
+                Diagnostic::new(WorkspaceError::not_found()),
+                Diagnostic::new(WorkspaceError::vcs_disabled()),
+            ],
+            errors: 2,
+            skipped_diagnostics: 0,
+        });
+        let encode = |start, end| -> Vec<serde_json::Value> {
+            serde_json::from_slice(&list.encode(start, end).unwrap()).unwrap()
+        };
+
+        assert_eq!(encode(0, 10).len(), 2);
+        assert_eq!(encode(1, 2).len(), 1);
+        assert!(encode(2, 1).is_empty());
+        assert!(encode(5, 10).is_empty());
+    }
+}
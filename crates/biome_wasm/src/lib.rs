@@ -1,16 +1,24 @@
 use js_sys::Error;
 use wasm_bindgen::prelude::*;
 
+use biome_service::project_index;
 use biome_service::workspace::{
-    self, ChangeFileParams, CloseFileParams, FixFileParams, FormatFileParams, FormatOnTypeParams,
-    FormatRangeParams, GetControlFlowGraphParams, GetFileContentParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, OrganizeImportsParams, PullActionsParams, PullDiagnosticsParams,
-    RegisterProjectFolderParams, RenameParams, UpdateSettingsParams,
+    self, ChangeFileParams, CloseFileParams, ExportProjectIndexParams, FixFileParams,
+    ForgetFilesParams, FormatFileParams, FormatOnTypeParams, FormatRangeParams,
+    GetControlFlowGraphParams, GetDefinitionParams, GetDocumentSymbolsParams, GetFileContentParams,
+    GetFoldingRangesParams, GetFormatterIRParams, GetInlayHintsParams, GetModuleGraphParams,
+    GetRuleConfigurationParams, GetSelectionRangesParams, GetSemanticTokensParams,
+    GetSignatureHelpParams, GetSyntaxTreeParams, GetWorkspaceSymbolsParams,
+    ImportProjectIndexParams, OrganizeImportsParams, PrepareRenameParams, PullActionsParams,
+    PullDiagnosticsParams, RegisterProjectFolderParams, RenameParams, ResolveConfigurationParams,
+    UpdateSettingsParams,
 };
 use biome_service::workspace::{OpenFileParams, SupportsFeatureParams};
 
+mod chunks;
 mod utils;
 
+pub use crate::chunks::{ContentBuffer, ContentChunks, DiagnosticList};
 pub use crate::utils::DiagnosticPrinter;
 use crate::utils::{into_error, set_panic_hook};
 
@@ -21,6 +29,13 @@ pub fn main() {
 
 include!(concat!(env!("OUT_DIR"), "/ts_types.rs"));
 
+/// Returns the hash of `content`, which [Workspace::import_project_index] compares with the
+/// hash recorded by the snapshot to know if a file changed since it was exported
+#[wasm_bindgen(js_name = contentHash)]
+pub fn content_hash(content: &str) -> String {
+    project_index::content_hash(content).to_string()
+}
+
 #[wasm_bindgen]
 pub struct Workspace {
     inner: Box<dyn workspace::Workspace>,
@@ -78,6 +93,19 @@ impl Workspace {
         self.inner.open_file(params).map_err(into_error)
     }
 
+    /// Opens a file with the content written in `content`. The content of `params` is ignored.
+    #[wasm_bindgen(js_name = openFileFromBuffer)]
+    pub fn open_file_from_buffer(
+        &self,
+        params: IOpenFileParams,
+        content: ContentBuffer,
+    ) -> Result<(), Error> {
+        let mut params: OpenFileParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        params.content = content.into_string()?;
+        self.inner.open_file(params).map_err(into_error)
+    }
+
     #[wasm_bindgen(js_name = getFileContent)]
     pub fn get_file_content(&self, params: IGetFileContentParams) -> Result<String, Error> {
         let params: GetFileContentParams =
@@ -85,6 +113,18 @@ impl Workspace {
         self.inner.get_file_content(params).map_err(into_error)
     }
 
+    /// Returns the content of a file, to be read in chunks
+    #[wasm_bindgen(js_name = getFileContentChunks)]
+    pub fn get_file_content_chunks(
+        &self,
+        params: IGetFileContentParams,
+    ) -> Result<ContentChunks, Error> {
+        let params: GetFileContentParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let content = self.inner.get_file_content(params).map_err(into_error)?;
+        Ok(ContentChunks::from(content))
+    }
+
     #[wasm_bindgen(js_name = getSyntaxTree)]
     pub fn get_syntax_tree(
         &self,
@@ -124,6 +164,19 @@ impl Workspace {
         self.inner.change_file(params).map_err(into_error)
     }
 
+    /// Changes a file with the content written in `content`. The content of `params` is ignored.
+    #[wasm_bindgen(js_name = changeFileFromBuffer)]
+    pub fn change_file_from_buffer(
+        &self,
+        params: IChangeFileParams,
+        content: ContentBuffer,
+    ) -> Result<(), Error> {
+        let mut params: ChangeFileParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        params.content = content.into_string()?;
+        self.inner.change_file(params).map_err(into_error)
+    }
+
     #[wasm_bindgen(js_name = closeFile)]
     pub fn close_file(&self, params: ICloseFileParams) -> Result<(), Error> {
         let params: CloseFileParams =
@@ -144,6 +197,19 @@ impl Workspace {
             .map_err(into_error)
     }
 
+    /// Returns the diagnostics of a file as a list, whose diagnostics are converted to
+    /// JavaScript values one at a time
+    #[wasm_bindgen(js_name = pullDiagnosticsList)]
+    pub fn pull_diagnostics_list(
+        &self,
+        params: IPullDiagnosticsParams,
+    ) -> Result<DiagnosticList, Error> {
+        let params: PullDiagnosticsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.pull_diagnostics(params).map_err(into_error)?;
+        Ok(DiagnosticList::from(result))
+    }
+
     #[wasm_bindgen(js_name = pullActions)]
     pub fn pull_actions(&self, params: IPullActionsParams) -> Result<IPullActionsResult, Error> {
         let params: PullActionsParams =
@@ -162,6 +228,15 @@ impl Workspace {
         to_value(&result).map_err(into_error)
     }
 
+    /// Returns the formatted code of a file, to be read in chunks
+    #[wasm_bindgen(js_name = formatFileChunks)]
+    pub fn format_file_chunks(&self, params: IFormatFileParams) -> Result<ContentChunks, Error> {
+        let params: FormatFileParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.format_file(params).map_err(into_error)?;
+        Ok(ContentChunks::from(result.into_code()))
+    }
+
     #[wasm_bindgen(js_name = formatRange)]
     pub fn format_range(&self, params: IFormatRangeParams) -> Result<JsValue, Error> {
         let params: FormatRangeParams =
@@ -201,6 +276,19 @@ impl Workspace {
             .map_err(into_error)
     }
 
+    #[wasm_bindgen(js_name = prepareRename)]
+    pub fn prepare_rename(
+        &self,
+        params: IPrepareRenameParams,
+    ) -> Result<IPrepareRenameResult, Error> {
+        let params: PrepareRenameParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.prepare_rename(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IPrepareRenameResult::from)
+            .map_err(into_error)
+    }
+
     pub fn rename(&self, params: IRenameParams) -> Result<IRenameResult, Error> {
         let params: RenameParams =
             serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
@@ -209,6 +297,200 @@ impl Workspace {
             .map(IRenameResult::from)
             .map_err(into_error)
     }
+
+    #[wasm_bindgen(js_name = getDocumentSymbols)]
+    pub fn get_document_symbols(
+        &self,
+        params: IGetDocumentSymbolsParams,
+    ) -> Result<IGetDocumentSymbolsResult, Error> {
+        let params: GetDocumentSymbolsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .get_document_symbols(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IGetDocumentSymbolsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getWorkspaceSymbols)]
+    pub fn get_workspace_symbols(
+        &self,
+        params: IGetWorkspaceSymbolsParams,
+    ) -> Result<IGetWorkspaceSymbolsResult, Error> {
+        let params: GetWorkspaceSymbolsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .get_workspace_symbols(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IGetWorkspaceSymbolsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getDefinition)]
+    pub fn get_definition(
+        &self,
+        params: IGetDefinitionParams,
+    ) -> Result<IGetDefinitionResult, Error> {
+        let params: GetDefinitionParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_definition(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetDefinitionResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getSemanticTokens)]
+    pub fn get_semantic_tokens(
+        &self,
+        params: IGetSemanticTokensParams,
+    ) -> Result<IGetSemanticTokensResult, Error> {
+        let params: GetSemanticTokensParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_semantic_tokens(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetSemanticTokensResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getInlayHints)]
+    pub fn get_inlay_hints(
+        &self,
+        params: IGetInlayHintsParams,
+    ) -> Result<IGetInlayHintsResult, Error> {
+        let params: GetInlayHintsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_inlay_hints(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetInlayHintsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getSignatureHelp)]
+    pub fn get_signature_help(
+        &self,
+        params: IGetSignatureHelpParams,
+    ) -> Result<IGetSignatureHelpResult, Error> {
+        let params: GetSignatureHelpParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_signature_help(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetSignatureHelpResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getFoldingRanges)]
+    pub fn get_folding_ranges(
+        &self,
+        params: IGetFoldingRangesParams,
+    ) -> Result<IGetFoldingRangesResult, Error> {
+        let params: GetFoldingRangesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_folding_ranges(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetFoldingRangesResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getSelectionRanges)]
+    pub fn get_selection_ranges(
+        &self,
+        params: IGetSelectionRangesParams,
+    ) -> Result<IGetSelectionRangesResult, Error> {
+        let params: GetSelectionRangesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .get_selection_ranges(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IGetSelectionRangesResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getRuleConfiguration)]
+    pub fn get_rule_configuration(
+        &self,
+        params: IGetRuleConfigurationParams,
+    ) -> Result<IGetRuleConfigurationResult, Error> {
+        let params: GetRuleConfigurationParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .get_rule_configuration(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IGetRuleConfigurationResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = resolveConfiguration)]
+    pub fn resolve_configuration(
+        &self,
+        params: IResolveConfigurationParams,
+    ) -> Result<IResolveConfigurationResult, Error> {
+        let params: ResolveConfigurationParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .resolve_configuration(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IResolveConfigurationResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getModuleGraph)]
+    pub fn get_module_graph(&self, params: IGetModuleGraphParams) -> Result<IModuleGraph, Error> {
+        let params: GetModuleGraphParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_module_graph(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IModuleGraph::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = exportProjectIndex)]
+    pub fn export_project_index(
+        &self,
+        params: IExportProjectIndexParams,
+    ) -> Result<IExportProjectIndexResult, Error> {
+        let params: ExportProjectIndexParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .export_project_index(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IExportProjectIndexResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = importProjectIndex)]
+    pub fn import_project_index(
+        &self,
+        params: IImportProjectIndexParams,
+    ) -> Result<IImportProjectIndexResult, Error> {
+        let params: ImportProjectIndexParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .import_project_index(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IImportProjectIndexResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = forgetFiles)]
+    pub fn forget_files(&self, params: IForgetFilesParams) -> Result<(), Error> {
+        let params: ForgetFilesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        self.inner.forget_files(params).map_err(into_error)
+    }
 }
 
 fn to_value<T: serde::ser::Serialize + ?Sized>(
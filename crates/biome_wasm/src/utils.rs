@@ -8,7 +8,7 @@ use biome_console::{fmt::Formatter, markup};
 use biome_diagnostics::serde::Diagnostic;
 use biome_diagnostics::{DiagnosticExt, LineIndexBuf, PrintDiagnostic, SourceCode};
 
-use super::IDiagnostic;
+use super::{ContentBuffer, IDiagnostic};
 
 pub(crate) fn set_panic_hook() {
     // When the `console_error_panic_hook` feature is enabled, we can call the
@@ -43,6 +43,12 @@ impl DiagnosticPrinter {
         }
     }
 
+    /// Creates a printer for a file whose content was written in `file_source`
+    #[wasm_bindgen(js_name = fromBuffer)]
+    pub fn from_buffer(file_name: String, file_source: ContentBuffer) -> Result<Self, Error> {
+        Ok(Self::new(file_name, file_source.into_string()?))
+    }
+
     pub fn print_simple(&mut self, diagnostic: IDiagnostic) -> Result<(), Error> {
         self.print(diagnostic, |err| PrintDiagnostic::simple(err))
     }
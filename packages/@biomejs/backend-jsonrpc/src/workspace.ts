@@ -77,6 +77,10 @@ export interface PartialConfiguration {
 	 * Specific configuration for the GraphQL language
 	 */
 	graphql?: PartialGraphqlConfiguration;
+	/**
+	 * Specific configuration for the HTML language
+	 */
+	html?: PartialHtmlConfiguration;
 	/**
 	 * Specific configuration for the JavaScript language
 	 */
@@ -146,10 +150,26 @@ export type StringSet = string[];
  * The configuration of the filesystem
  */
 export interface PartialFilesConfiguration {
+	/**
+	 * Associates Unix shell style patterns to the language of the files that match them, for the files with an extension that Biome doesn't know, or that must be handled as another language. The first pattern that matches a file applies.
+	 */
+	associations?: FileAssociations;
+	/**
+	 * A list of Unix shell style patterns. Biome will skip the files that match these patterns as binary files, in addition to the files whose content isn't text.
+	 */
+	binaryFiles?: StringSet;
+	/**
+	 * Whether Biome follows the symbolic links when it traverses the directories. The symbolic links are skipped otherwise. Defaults to `true`.
+	 */
+	followSymlinks?: boolean;
 	/**
 	 * A list of Unix shell style patterns. Biome will ignore files/folders that will match these patterns.
 	 */
 	ignore?: StringSet;
+	/**
+	 * A list of files with the syntax of `.gitignore`, such as `.eslintignore`, relative to the configuration file. Biome will ignore the files/folders that they ignore, even when the integration with the VCS is disabled.
+	 */
+	ignoreFiles?: StringSet;
 	/**
 	 * Tells Biome to not emit diagnostics when handling files that doesn't know
 	 */
@@ -158,10 +178,22 @@ export interface PartialFilesConfiguration {
 	 * A list of Unix shell style patterns. Biome will handle only those files/folders that will match these patterns.
 	 */
 	include?: StringSet;
+	/**
+	 * The maximum number of files that are processed at the same time, for the systems that limit the number of open files. Unlimited by default.
+	 */
+	maxOpenFiles?: number;
 	/**
 	 * The maximum allowed size for source code files in bytes. Files above this limit will be ignored for performance reasons. Defaults to 1 MiB
 	 */
 	maxSize?: number;
+	/**
+	 * The maximum allowed size in bytes for the files of a language, which takes precedence over `maxSize` for these files. For example, the generated JSON files are often larger than the scripts.
+	 */
+	maxSizePerLanguage?: FileSizeLimits;
+	/**
+	 * The number of threads that process the files. Defaults to the number of CPUs.
+	 */
+	threads?: number;
 }
 /**
  * Generic options applied to all files
@@ -223,6 +255,19 @@ export interface PartialGraphqlConfiguration {
 	formatter?: PartialGraphqlFormatter;
 	linter?: PartialGraphqlLinter;
 }
+/**
+ * Options applied to HTML files
+ */
+export interface PartialHtmlConfiguration {
+	/**
+	 * HTML formatter options
+	 */
+	formatter?: PartialHtmlFormatter;
+	/**
+	 * HTML parsing options
+	 */
+	parser?: PartialHtmlParser;
+}
 /**
  * A set of options applied to the JavaScript files
  */
@@ -275,12 +320,24 @@ export interface PartialJsonConfiguration {
 	 * Parsing options
 	 */
 	parser?: PartialJsonParser;
+	/**
+	 * A list of JSON schemas, associated with the files that must satisfy them
+	 */
+	schemas?: JsonSchemas;
 }
 export interface PartialLinterConfiguration {
+	/**
+	 * The domains of rules to enable, such as `react` or `test`, and which of their rules are enabled: `recommended`, `all` or `none`. The rules configured in `rules` take precedence over the domains.
+	 */
+	domains?: RuleDomains;
 	/**
 	 * if `false`, it disables the feature and the linter won't be executed. `true` by default
 	 */
 	enabled?: boolean;
+	/**
+	 * Whether the commands exit with an error code when the diagnostics emit warnings. `false` by default. The argument `--error-on-warnings` takes precedence over this option.
+	 */
+	errorOnWarnings?: boolean;
 	/**
 	 * A list of Unix shell style patterns. The formatter will ignore files/folders that will match these patterns.
 	 */
@@ -289,10 +346,18 @@ export interface PartialLinterConfiguration {
 	 * A list of Unix shell style patterns. The formatter will include files/folders that will match these patterns.
 	 */
 	include?: StringSet;
+	/**
+	 * The number of warnings above which the commands exit with an error code. Unlimited by default. The argument `--max-warnings` takes precedence over this option.
+	 */
+	maxWarnings?: number;
 	/**
 	 * List of rules
 	 */
 	rules?: Rules;
+	/**
+	 * Whether the suppression comments must explain why the code is suppressed, after the colon. `optional` by default.
+	 */
+	suppressionReason?: SuppressionReason;
 }
 export interface PartialOrganizeImports {
 	/**
@@ -356,6 +421,10 @@ export interface PartialCssFormatter {
 	 * Control the formatter for CSS (and its super languages) files.
 	 */
 	enabled?: boolean;
+	/**
+	 * The letter case of hexadecimal colors in CSS code. Defaults to lower.
+	 */
+	hexColorCase?: HexColorCase;
 	/**
 	 * The indent style applied to CSS (and its super languages) files.
 	 */
@@ -364,6 +433,10 @@ export interface PartialCssFormatter {
 	 * The size of the indentation applied to CSS (and its super languages) files. Default to 2.
 	 */
 	indentWidth?: IndentWidth;
+	/**
+	 * The letter case of property names, function names, unknown at-rule names and keywords in CSS code. Defaults to preserve.
+	 */
+	keywordCase?: KeywordCase;
 	/**
 	 * The type of line ending applied to CSS (and its super languages) files.
 	 */
@@ -376,6 +449,10 @@ export interface PartialCssFormatter {
 	 * The type of quotes used in CSS code. Defaults to double.
 	 */
 	quoteStyle?: QuoteStyle;
+	/**
+	 * Whether the argument of `url()` functions should be quoted in CSS code. Defaults to preserve.
+	 */
+	urlQuotes?: UrlQuotes;
 }
 /**
  * Options that changes how the CSS linter behaves
@@ -399,6 +476,14 @@ export interface PartialCssParser {
 	 */
 	cssModules?: boolean;
 }
+/**
+ * The languages associated with the files that match a pattern
+ */
+export interface FileAssociations {}
+/**
+ * The maximum sizes of the files of a language, in bytes
+ */
+export interface FileSizeLimits {}
 export type AttributePosition = "auto" | "multiline";
 export type BracketSpacing = boolean;
 export type IndentWidth = number;
@@ -418,6 +503,14 @@ export interface PartialGraphqlFormatter {
 	 * Whether to insert spaces around brackets in object literals. Defaults to true.
 	 */
 	bracketSpacing?: BracketSpacing;
+	/**
+	 * The order of the directives in GraphQL code. Defaults to preserve.
+	 */
+	directiveOrder?: DirectiveOrder;
+	/**
+	 * Whether the top-level definitions of GraphQL documents are always separated by an empty line. Defaults to preserve.
+	 */
+	emptyLineBetweenDefinitions?: EmptyLineBetweenDefinitions;
 	/**
 	 * Control the formatter for GraphQL files.
 	 */
@@ -442,6 +535,10 @@ export interface PartialGraphqlFormatter {
 	 * The type of quotes used in GraphQL code. Defaults to double.
 	 */
 	quoteStyle?: QuoteStyle;
+	/**
+	 * Whether the selection sets that contain a single field without selection set are printed on one line when they fit. Defaults to expand.
+	 */
+	singleFieldSelectionSets?: SingleFieldSelectionSets;
 }
 /**
  * Options that changes how the GraphQL linter behaves
@@ -451,6 +548,56 @@ export interface PartialGraphqlLinter {
 	 * Control the formatter for GraphQL files.
 	 */
 	enabled?: boolean;
+	/**
+	 * The path of the GraphQL schema that the operations are validated against, relative to the root of the project. It's either a schema definition file, such as `schema.graphql`, or a `.json` file that contains the result of an introspection query.
+	 */
+	schema?: string;
+}
+/**
+ * Options that changes how the HTML formatter behaves
+ */
+export interface PartialHtmlFormatter {
+	/**
+	 * The attribute position style in HTML elements. Defaults to auto.
+	 */
+	attributePosition?: AttributePosition;
+	/**
+	 * Whether to hug the closing bracket of multiline HTML tags to the end of the last line, rather than being alone on the following line. Defaults to false.
+	 */
+	bracketSameLine?: BracketSameLine;
+	/**
+	 * Control the formatter for HTML files.
+	 */
+	enabled?: boolean;
+	/**
+	 * The indent style applied to HTML files.
+	 */
+	indentStyle?: IndentStyle;
+	/**
+	 * The size of the indentation applied to HTML files. Default to 2.
+	 */
+	indentWidth?: IndentWidth;
+	/**
+	 * The type of line ending applied to HTML files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to HTML files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+	/**
+	 * Whether the whitespace around and inside the HTML elements is significant. Defaults to css.
+	 */
+	whitespaceSensitivity?: WhitespaceSensitivity;
+}
+/**
+ * Options that changes how the HTML parser behaves
+ */
+export interface PartialHtmlParser {
+	/**
+	 * The delimiters of the regions of the templates that are kept as they are written, such as `{ "start": "{%", "end": "%}" }` for the tags of the Jinja templates. These regions are formatted as they are, instead of being parsed as markup.
+	 */
+	ignoreDelimiters?: HtmlIgnoreDelimiters;
 }
 /**
  * Linter options specific to the JavaScript linter
@@ -619,7 +766,17 @@ export interface PartialJsonParser {
 	 * Allow parsing trailing commas in `.json` files
 	 */
 	allowTrailingCommas?: boolean;
+	/**
+	 * Parse the files as JSON5, which allows unquoted keys, single quoted strings, hexadecimal numbers and multi-line strings, in addition to comments and trailing commas
+	 */
+	json5?: boolean;
 }
+export type HtmlIgnoreDelimiters = HtmlIgnoreDelimiter[];
+export type JsonSchemas = JsonSchemaAssociation[];
+/**
+ * The domains of rules that are enabled, and which of their rules are enabled
+ */
+export interface RuleDomains {}
 export interface Rules {
 	a11y?: A11y;
 	/**
@@ -638,7 +795,35 @@ export interface Rules {
 	style?: Style;
 	suspicious?: Suspicious;
 }
+/**
+ * Whether the suppression comments must have an explanation
+ */
+export type SuppressionReason = "optional" | "required";
+export interface JsonSchemaAssociation {
+	/**
+	 * A list of Unix shell style patterns. The files that match these patterns must satisfy the schema.
+	 */
+	include?: StringSet;
+	/**
+	 * The path of the JSON schema, relative to the root of the project.
+	 */
+	schema?: string;
+}
+export interface HtmlIgnoreDelimiter {
+	/**
+	 * The delimiter that ends a region, such as `%}`
+	 */
+	end?: string;
+	/**
+	 * The delimiter that starts a region, such as `{%`
+	 */
+	start?: string;
+}
 export interface OverridePattern {
+	/**
+	 * Specific configuration for the assists
+	 */
+	assists?: OverrideAssistsConfiguration;
 	/**
 	 * Specific configuration for the Css language
 	 */
@@ -651,6 +836,10 @@ export interface OverridePattern {
 	 * Specific configuration for the Graphql language
 	 */
 	graphql?: PartialGraphqlConfiguration;
+	/**
+	 * Specific configuration for the HTML language
+	 */
+	html?: PartialHtmlConfiguration;
 	/**
 	 * A list of Unix shell style patterns. The formatter will ignore files/folders that will match these patterns.
 	 */
@@ -685,16 +874,75 @@ export interface Source {
 	 * Provides a whole-source code action to sort the imports in the file using import groups and natural ordering.
 	 */
 	organizeImports?: RuleAssistConfiguration_for_Options;
+	/**
+	 * Converts media features between the range syntax and the `min-`/`max-` prefixes.
+	 */
+	useMediaFeatureRangeNotation?: RuleAssistConfiguration_for_UseMediaFeatureRangeNotationOptions;
+	/**
+	 * Normalizes colors to a single notation.
+	 */
+	useNormalizedColors?: RuleAssistConfiguration_for_UseNormalizedColorsOptions;
 	/**
 	 * Enforce attribute sorting in JSX elements.
 	 */
 	useSortedAttributes?: RuleAssistConfiguration_for_Null;
+	/**
+	 * Sorts the fields of the selection sets, the arguments and the fields of the input objects in natural order.
+	 */
+	useSortedFields?: RuleAssistConfiguration_for_UseSortedFieldsOptions;
 	/**
 	 * Sorts the keys of a JSON object in natural order
 	 */
-	useSortedKeys?: RuleAssistConfiguration_for_Null;
+	useSortedKeys?: RuleAssistConfiguration_for_UseSortedKeysOptions;
+	/**
+	 * Sorts the compiler options, the `paths` and the `references` of TypeScript configurations
+	 */
+	useSortedTsconfig?: RuleAssistConfiguration_for_Null;
 }
+/**
+ * The language of the files associated to a pattern
+ */
+export type FileLanguage =
+	| "css"
+	| "graphql"
+	| "js"
+	| "json"
+	| "jsonc"
+	| "jsx"
+	| "ts"
+	| "tsx";
+/**
+ * The letter case of hexadecimal colors, e.g. `#fff` or `#FFF`.
+ */
+export type HexColorCase = "lower" | "upper" | "preserve";
+/**
+ * The letter case of case-insensitive keywords: property names, function names, names of unknown at-rules and keywords in property values and media queries. Known at-rule names and keywords such as `!important` are always printed in lowercase.
+ */
+export type KeywordCase = "preserve" | "lower";
 export type QuoteStyle = "double" | "single";
+/**
+ * Whether the argument of `url()` functions should be quoted.
+ */
+export type UrlQuotes = "preserve" | "always" | "never";
+/**
+ * The order of the directives applied to a definition, a field or a fragment.
+ */
+export type DirectiveOrder = "preserve" | "sorted";
+/**
+ * Whether the top-level definitions of a document are separated by an empty line.
+ */
+export type EmptyLineBetweenDefinitions = "preserve" | "always";
+/**
+ * How the selection sets that contain a single field without selection set are printed.
+ */
+export type SingleFieldSelectionSets = "expand" | "collapse";
+export type BracketSameLine = boolean;
+/**
+	* Whether the whitespace around and inside the HTML elements is significant.
+
+Browsers render the whitespace between the inline elements, such as `<span>` or `<a>`, so adding or removing it can change how the document looks. 
+	 */
+export type WhitespaceSensitivity = "css" | "strict" | "ignore";
 export type ArrowParentheses = "always" | "asNeeded";
 export type QuoteProperties = "asNeeded" | "preserve";
 export type Semicolons = "always" | "asNeeded";
@@ -1226,10 +1474,26 @@ export interface Nursery {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * It enables the recommended rules for this group
+	 */
+	recommended?: boolean;
 	/**
 	 * Disallow use of CommonJs module system in favor of ESM style imports.
 	 */
 	noCommonJs?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow `@layer` statements that conflict with the layer order established earlier in the stylesheet.
+	 */
+	noConflictingLayerOrder?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the compiler options that are deprecated in TypeScript configurations.
+	 */
+	noDeprecatedCompilerOption?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow selecting deprecated fields and using deprecated enum values.
+	 */
+	noDeprecatedFields?: RuleConfiguration_for_NoDeprecatedFieldsOptions;
 	/**
 	 * Disallow a lower specificity selector from coming after a higher specificity selector.
 	 */
@@ -1242,6 +1506,10 @@ export interface Nursery {
 	 * Prevents importing next/document outside of pages/_document.jsx in Next.js projects.
 	 */
 	noDocumentImportInPage?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow defining several custom elements with the same name.
+	 */
+	noDuplicateCustomElementNames?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow duplicate custom properties within declaration blocks.
 	 */
@@ -1250,6 +1518,14 @@ export interface Nursery {
 	 * Disallow duplicate conditions in if-else-if chains
 	 */
 	noDuplicateElseIf?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow duplicate `id` attributes.
+	 */
+	noDuplicateIds?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the keys of an override that don't change the base configuration, and the overrides that never apply.
+	 */
+	noDuplicateKeysAcrossMerge?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow duplicate properties within declaration blocks.
 	 */
@@ -1266,6 +1542,10 @@ export interface Nursery {
 	 * Disallow TypeScript enum.
 	 */
 	noEnum?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow operations that select fields too deeply, or whose estimated cost is too high.
+	 */
+	noExcessiveQueryDepth?: RuleConfiguration_for_NoExcessiveQueryDepthOptions;
 	/**
 	 * Disallow exporting an imported variable.
 	 */
@@ -1282,14 +1562,26 @@ export interface Nursery {
 	 * Prevent using the next/head module in pages/_document.js on Next.js projects.
 	 */
 	noHeadImportInDocument?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce that a `label` element has a text label and an associated control.
+	 */
+	noHtmlLabelWithoutControl?: RuleConfiguration_for_Null;
 	/**
 	 * Prevent usage of \<img> element in a Next.js project.
 	 */
 	noImgElement?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow keyframe selectors outside of the `0%` to `100%` range.
+	 */
+	noInvalidKeyframeSelector?: RuleConfiguration_for_Null;
 	/**
 	 * Disallows the use of irregular whitespace characters.
 	 */
 	noIrregularWhitespace?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow using variables whose type doesn't match the argument they're passed to.
+	 */
+	noMismatchedVariableTypes?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow missing var function for css variables.
 	 */
@@ -1298,6 +1590,10 @@ export interface Nursery {
 	 * Disallow nested ternary expressions.
 	 */
 	noNestedTernary?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow animation names that do not match any `@keyframes` of the project.
+	 */
+	noNonexistentAnimationName?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow octal escape sequences in string literals
 	 */
@@ -1330,6 +1626,30 @@ export interface Nursery {
 	 * Disallow template literal placeholder syntax in regular strings.
 	 */
 	noTemplateCurlyInString?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow references to custom properties that are not defined anywhere in the project.
+	 */
+	noUndefinedCustomProperties?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow spreading fragments that aren't defined anywhere in the project.
+	 */
+	noUndefinedFragmentSpreads?: RuleConfiguration_for_Null;
+	/**
+	 * Require `attributeChangedCallback` to handle the attributes listed by `observedAttributes`.
+	 */
+	noUnhandledObservedAttributes?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow passing arguments that aren't defined by the schema.
+	 */
+	noUnknownArguments?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the compiler options that are unknown to TypeScript.
+	 */
+	noUnknownCompilerOption?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow selecting fields that aren't defined by the schema.
+	 */
+	noUnknownFields?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unknown pseudo-class selectors.
 	 */
@@ -1342,6 +1662,14 @@ export interface Nursery {
 	 * Disallow unknown type selectors.
 	 */
 	noUnknownTypeSelector?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the paths of TypeScript configurations that don't resolve to any file.
+	 */
+	noUnresolvedTsconfigPaths?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow fragments that aren't spread anywhere in the project.
+	 */
+	noUnusedFragments?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unnecessary escape sequence in regular expression literals.
 	 */
@@ -1358,14 +1686,14 @@ export interface Nursery {
 	 * Disallow use of @value rule in css modules.
 	 */
 	noValueAtRule?: RuleConfiguration_for_Null;
-	/**
-	 * It enables the recommended rules for this group
-	 */
-	recommended?: boolean;
 	/**
 	 * Disallow the use of overload signatures that are not next to each other.
 	 */
 	useAdjacentOverloadSignatures?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce the units allowed in the values of properties and media features.
+	 */
+	useAllowedUnits?: RuleFixConfiguration_for_UseAllowedUnitsOptions;
 	/**
 	 * Enforce that ARIA properties are valid for the roles that are supported by the element.
 	 */
@@ -1410,6 +1738,14 @@ export interface Nursery {
 	 * Require for-in loops to include an if statement.
 	 */
 	useGuardForIn?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce that the heading levels increase one by one.
+	 */
+	useHeadingOrder?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce that all elements that require alternative text have meaningful information to relay back to the end user.
+	 */
+	useHtmlAltText?: RuleConfiguration_for_Null;
 	/**
 	 * Disallows package private imports.
 	 */
@@ -1418,10 +1754,26 @@ export interface Nursery {
 	 * Enforce specifying the name of GraphQL operations.
 	 */
 	useNamedOperation?: RuleFixConfiguration_for_Null;
+	/**
+	 * Enforce a naming convention for the operations.
+	 */
+	useOperationNameConvention?: RuleConfiguration_for_UseOperationNameConventionOptions;
+	/**
+	 * Require the arguments that the schema marks as required.
+	 */
+	useRequiredArguments?: RuleConfiguration_for_Null;
+	/**
+	 * Require selecting the `id` field of the objects that have one.
+	 */
+	useRequiredIdSelection?: RuleConfiguration_for_UseRequiredIdSelectionOptions;
 	/**
 	 * Enforce the sorting of CSS utility classes.
 	 */
 	useSortedClasses?: RuleFixConfiguration_for_UtilityClassSortingOptions;
+	/**
+	 * Enforce the order of the elements of the arrays at the configured paths.
+	 */
+	useStableArrayOrder?: RuleFixConfiguration_for_UseStableArrayOrderOptions;
 	/**
 	 * Enforce the use of the directive "use strict" in script files.
 	 */
@@ -1434,6 +1786,14 @@ export interface Nursery {
 	 * Use valid values for the autocomplete attribute on input elements.
 	 */
 	useValidAutocomplete?: RuleConfiguration_for_UseValidAutocompleteOptions;
+	/**
+	 * Enforce valid names for the custom elements.
+	 */
+	useValidCustomElementName?: RuleConfiguration_for_UseValidCustomElementNameOptions;
+	/**
+	 * Enforce the valid usage of the landmark roles.
+	 */
+	useValidLandmarkRoles?: RuleConfiguration_for_Null;
 }
 /**
  * A list of rules that belong to this group
@@ -1987,6 +2347,16 @@ export interface Suspicious {
 	 */
 	useValidTypeof?: RuleFixConfiguration_for_Null;
 }
+export interface OverrideAssistsConfiguration {
+	/**
+	 * List of actions
+	 */
+	actions?: Actions;
+	/**
+	 * if `false`, it disables the feature and the assists won't be executed. `true` by default
+	 */
+	enabled?: boolean;
+}
 export interface OverrideFormatterConfiguration {
 	/**
 	 * The attribute position style.
@@ -2023,6 +2393,10 @@ export interface OverrideFormatterConfiguration {
 	lineWidth?: LineWidth;
 }
 export interface OverrideLinterConfiguration {
+	/**
+	 * The domains of rules to enable in the files of the override
+	 */
+	domains?: RuleDomains;
 	/**
 	 * if `false`, it disables the feature and the linter won't be executed. `true` by default
 	 */
@@ -2041,6 +2415,18 @@ export interface OverrideOrganizeImportsConfiguration {
 export type RuleAssistConfiguration_for_Options =
 	| RuleAssistPlainConfiguration
 	| RuleAssistWithOptions_for_Options;
+export type RuleAssistConfiguration_for_UseMediaFeatureRangeNotationOptions =
+	| RuleAssistPlainConfiguration
+	| RuleAssistWithOptions_for_UseMediaFeatureRangeNotationOptions;
+export type RuleAssistConfiguration_for_UseNormalizedColorsOptions =
+	| RuleAssistPlainConfiguration
+	| RuleAssistWithOptions_for_UseNormalizedColorsOptions;
+export type RuleAssistConfiguration_for_UseSortedFieldsOptions =
+	| RuleAssistPlainConfiguration
+	| RuleAssistWithOptions_for_UseSortedFieldsOptions;
+export type RuleAssistConfiguration_for_UseSortedKeysOptions =
+	| RuleAssistPlainConfiguration
+	| RuleAssistWithOptions_for_UseSortedKeysOptions;
 export type RuleAssistConfiguration_for_Null =
 	| RuleAssistPlainConfiguration
 	| RuleAssistWithOptions_for_Null;
@@ -2077,6 +2463,12 @@ export type RuleConfiguration_for_DeprecatedHooksOptions =
 export type RuleFixConfiguration_for_UseImportExtensionsOptions =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_UseImportExtensionsOptions;
+export type RuleConfiguration_for_NoDeprecatedFieldsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoDeprecatedFieldsOptions;
+export type RuleConfiguration_for_NoExcessiveQueryDepthOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoExcessiveQueryDepthOptions;
 export type RuleConfiguration_for_RestrictedImportsOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_RestrictedImportsOptions;
@@ -2086,18 +2478,33 @@ export type RuleFixConfiguration_for_NoRestrictedTypesOptions =
 export type RuleConfiguration_for_NoSecretsOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_NoSecretsOptions;
+export type RuleFixConfiguration_for_UseAllowedUnitsOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UseAllowedUnitsOptions;
 export type RuleConfiguration_for_UseComponentExportOnlyModulesOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_UseComponentExportOnlyModulesOptions;
 export type RuleConfiguration_for_ConsistentMemberAccessibilityOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_ConsistentMemberAccessibilityOptions;
+export type RuleConfiguration_for_UseOperationNameConventionOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseOperationNameConventionOptions;
+export type RuleConfiguration_for_UseRequiredIdSelectionOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseRequiredIdSelectionOptions;
 export type RuleFixConfiguration_for_UtilityClassSortingOptions =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_UtilityClassSortingOptions;
+export type RuleFixConfiguration_for_UseStableArrayOrderOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UseStableArrayOrderOptions;
 export type RuleConfiguration_for_UseValidAutocompleteOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_UseValidAutocompleteOptions;
+export type RuleConfiguration_for_UseValidCustomElementNameOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseValidCustomElementNameOptions;
 export type RuleConfiguration_for_RestrictedGlobalsOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_RestrictedGlobalsOptions;
@@ -2130,6 +2537,46 @@ export interface RuleAssistWithOptions_for_Options {
 	 */
 	options: Options;
 }
+export interface RuleAssistWithOptions_for_UseMediaFeatureRangeNotationOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RuleAssistPlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseMediaFeatureRangeNotationOptions;
+}
+export interface RuleAssistWithOptions_for_UseNormalizedColorsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RuleAssistPlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseNormalizedColorsOptions;
+}
+export interface RuleAssistWithOptions_for_UseSortedFieldsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RuleAssistPlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseSortedFieldsOptions;
+}
+export interface RuleAssistWithOptions_for_UseSortedKeysOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RuleAssistPlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseSortedKeysOptions;
+}
 export interface RuleAssistWithOptions_for_Null {
 	/**
 	 * The severity of the emitted diagnostics by the rule
@@ -2143,7 +2590,7 @@ export interface RuleAssistWithOptions_for_Null {
 export type RulePlainConfiguration = "warn" | "error" | "info" | "off";
 export interface RuleWithFixOptions_for_Null {
 	/**
-	 * The kind of the code actions emitted by the rule
+	 * The kind of the code actions emitted by the rule: `none` disables the fix of the rule, and `unsafe` only applies it with `--unsafe`
 	 */
 	fix?: FixKind;
 	/**
@@ -2157,7 +2604,7 @@ export interface RuleWithFixOptions_for_Null {
 }
 export interface RuleWithFixOptions_for_AllowDomainOptions {
 	/**
-	 * The kind of the code actions emitted by the rule
+	 * The kind of the code actions emitted by the rule: `none` disables the fix of the rule, and `unsafe` only applies it with `--unsafe`
 	 */
 	fix?: FixKind;
 	/**
@@ -2191,7 +2638,7 @@ export interface RuleWithOptions_for_Null {
 }
 export interface RuleWithFixOptions_for_ValidAriaRoleOptions {
 	/**
-	 * The kind of the code actions emitted by the rule
+	 * The kind of the code actions emitted by the rule: `none` disables the fix of the rule, and `unsafe` only applies it with `--unsafe`
 	 */
 	fix?: FixKind;
 	/**
@@ -2255,7 +2702,7 @@ export interface RuleWithOptions_for_DeprecatedHooksOptions {
 }
 export interface RuleWithFixOptions_for_UseImportExtensionsOptions {
 	/**
-	 * The kind of the code actions emitted by the rule
+	 * The kind of the code actions emitted by the rule: `none` disables the fix of the rule, and `unsafe` only applies it with `--unsafe`
 	 */
 	fix?: FixKind;
 	/**
@@ -2267,7 +2714,7 @@ export interface RuleWithFixOptions_for_UseImportExtensionsOptions {
 	 */
 	options: UseImportExtensionsOptions;
 }
-export interface RuleWithOptions_for_RestrictedImportsOptions {
+export interface RuleWithOptions_for_NoDeprecatedFieldsOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2275,13 +2722,9 @@ export interface RuleWithOptions_for_RestrictedImportsOptions {
 	/**
 	 * Rule's options
 	 */
-	options: RestrictedImportsOptions;
+	options: NoDeprecatedFieldsOptions;
 }
-export interface RuleWithFixOptions_for_NoRestrictedTypesOptions {
-	/**
-	 * The kind of the code actions emitted by the rule
-	 */
-	fix?: FixKind;
+export interface RuleWithOptions_for_NoExcessiveQueryDepthOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2289,9 +2732,9 @@ export interface RuleWithFixOptions_for_NoRestrictedTypesOptions {
 	/**
 	 * Rule's options
 	 */
-	options: NoRestrictedTypesOptions;
+	options: NoExcessiveQueryDepthOptions;
 }
-export interface RuleWithOptions_for_NoSecretsOptions {
+export interface RuleWithOptions_for_RestrictedImportsOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2299,9 +2742,47 @@ export interface RuleWithOptions_for_NoSecretsOptions {
 	/**
 	 * Rule's options
 	 */
-	options: NoSecretsOptions;
+	options: RestrictedImportsOptions;
 }
-export interface RuleWithOptions_for_UseComponentExportOnlyModulesOptions {
+export interface RuleWithFixOptions_for_NoRestrictedTypesOptions {
+	/**
+	 * The kind of the code actions emitted by the rule: `none` disables the fix of the rule, and `unsafe` only applies it with `--unsafe`
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoRestrictedTypesOptions;
+}
+export interface RuleWithOptions_for_NoSecretsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoSecretsOptions;
+}
+export interface RuleWithFixOptions_for_UseAllowedUnitsOptions {
+	/**
+	 * The kind of the code actions emitted by the rule: `none` disables the fix of the rule, and `unsafe` only applies it with `--unsafe`
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseAllowedUnitsOptions;
+}
+export interface RuleWithOptions_for_UseComponentExportOnlyModulesOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2321,9 +2802,29 @@ export interface RuleWithOptions_for_ConsistentMemberAccessibilityOptions {
 	 */
 	options: ConsistentMemberAccessibilityOptions;
 }
+export interface RuleWithOptions_for_UseOperationNameConventionOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseOperationNameConventionOptions;
+}
+export interface RuleWithOptions_for_UseRequiredIdSelectionOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseRequiredIdSelectionOptions;
+}
 export interface RuleWithFixOptions_for_UtilityClassSortingOptions {
 	/**
-	 * The kind of the code actions emitted by the rule
+	 * The kind of the code actions emitted by the rule: `none` disables the fix of the rule, and `unsafe` only applies it with `--unsafe`
 	 */
 	fix?: FixKind;
 	/**
@@ -2335,6 +2836,20 @@ export interface RuleWithFixOptions_for_UtilityClassSortingOptions {
 	 */
 	options: UtilityClassSortingOptions;
 }
+export interface RuleWithFixOptions_for_UseStableArrayOrderOptions {
+	/**
+	 * The kind of the code actions emitted by the rule: `none` disables the fix of the rule, and `unsafe` only applies it with `--unsafe`
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseStableArrayOrderOptions;
+}
 export interface RuleWithOptions_for_UseValidAutocompleteOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
@@ -2345,6 +2860,16 @@ export interface RuleWithOptions_for_UseValidAutocompleteOptions {
 	 */
 	options: UseValidAutocompleteOptions;
 }
+export interface RuleWithOptions_for_UseValidCustomElementNameOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseValidCustomElementNameOptions;
+}
 export interface RuleWithOptions_for_RestrictedGlobalsOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
@@ -2357,7 +2882,7 @@ export interface RuleWithOptions_for_RestrictedGlobalsOptions {
 }
 export interface RuleWithFixOptions_for_ConsistentArrayTypeOptions {
 	/**
-	 * The kind of the code actions emitted by the rule
+	 * The kind of the code actions emitted by the rule: `none` disables the fix of the rule, and `unsafe` only applies it with `--unsafe`
 	 */
 	fix?: FixKind;
 	/**
@@ -2381,7 +2906,7 @@ export interface RuleWithOptions_for_FilenamingConventionOptions {
 }
 export interface RuleWithFixOptions_for_NamingConventionOptions {
 	/**
-	 * The kind of the code actions emitted by the rule
+	 * The kind of the code actions emitted by the rule: `none` disables the fix of the rule, and `unsafe` only applies it with `--unsafe`
 	 */
 	fix?: FixKind;
 	/**
@@ -2395,7 +2920,7 @@ export interface RuleWithFixOptions_for_NamingConventionOptions {
 }
 export interface RuleWithFixOptions_for_UseSelfClosingElementsOptions {
 	/**
-	 * The kind of the code actions emitted by the rule
+	 * The kind of the code actions emitted by the rule: `none` disables the fix of the rule, and `unsafe` only applies it with `--unsafe`
 	 */
 	fix?: FixKind;
 	/**
@@ -2409,7 +2934,7 @@ export interface RuleWithFixOptions_for_UseSelfClosingElementsOptions {
 }
 export interface RuleWithFixOptions_for_NoConsoleOptions {
 	/**
-	 * The kind of the code actions emitted by the rule
+	 * The kind of the code actions emitted by the rule: `none` disables the fix of the rule, and `unsafe` only applies it with `--unsafe`
 	 */
 	fix?: FixKind;
 	/**
@@ -2423,7 +2948,7 @@ export interface RuleWithFixOptions_for_NoConsoleOptions {
 }
 export interface RuleWithFixOptions_for_NoDoubleEqualsOptions {
 	/**
-	 * The kind of the code actions emitted by the rule
+	 * The kind of the code actions emitted by the rule: `none` disables the fix of the rule, and `unsafe` only applies it with `--unsafe`
 	 */
 	fix?: FixKind;
 	/**
@@ -2439,6 +2964,29 @@ export interface Options {
 	importGroups?: ImportGroup[];
 	legacy?: boolean;
 }
+export interface UseMediaFeatureRangeNotationOptions {
+	notation?: MediaFeatureRangeNotation;
+}
+export interface UseNormalizedColorsOptions {
+	format?: ColorFormat;
+}
+export interface UseSortedFieldsOptions {
+	/**
+	 * Sort the arguments passed to the fields and to the directives
+	 */
+	arguments?: boolean;
+	/**
+	 * Sort the fields of the input object types and of the input object values
+	 */
+	inputFields?: boolean;
+	/**
+	 * Sort the fields of the selection sets
+	 */
+	selections?: boolean;
+}
+export interface UseSortedKeysOptions {
+	sortOrder?: SortOrder;
+}
 /**
  * Used to identify the kind of code action emitted by a rule
  */
@@ -2529,6 +3077,26 @@ export interface UseImportExtensionsOptions {
 /**
  * Options for the rule `noRestrictedImports`.
  */
+export interface NoDeprecatedFieldsOptions {
+	/**
+	 * The coordinates of the fields and the enum values that can be used even though they're deprecated, such as `User.login` or `Role.MEMBER`
+	 */
+	ignore?: string[];
+}
+export interface NoExcessiveQueryDepthOptions {
+	/**
+	 * How many times more the fields selected under a list field cost than the other fields
+	 */
+	listFactor?: number;
+	/**
+	 * The maximum estimated cost of an operation. The cost isn't checked if it's not set
+	 */
+	maxCost?: number;
+	/**
+	 * The maximum depth of the fields selected by an operation
+	 */
+	maxDepth?: number;
+}
 export interface RestrictedImportsOptions {
 	/**
 	 * A list of import paths that should trigger the rule.
@@ -2544,6 +3112,20 @@ export interface NoSecretsOptions {
 	 */
 	entropyThreshold?: number;
 }
+export interface UseAllowedUnitsOptions {
+	/**
+	 * A map where the key is a media feature name and the value the units allowed in its values
+	 */
+	mediaFeatures?: {};
+	/**
+	 * A map where the key is a property name and the value the units allowed in its values
+	 */
+	properties?: {};
+	/**
+	 * The font size of the root element, in pixels
+	 */
+	rootFontSize?: number;
+}
 export interface UseComponentExportOnlyModulesOptions {
 	/**
 	 * Allows the export of constants. This option is for environments that support it, such as [Vite](https://vitejs.dev/)
@@ -2557,6 +3139,26 @@ export interface UseComponentExportOnlyModulesOptions {
 export interface ConsistentMemberAccessibilityOptions {
 	accessibility?: Accessibility;
 }
+export interface UseOperationNameConventionOptions {
+	/**
+	 * The globs of the files where the operations can be anonymous
+	 */
+	allowAnonymousIn?: Regex[];
+	/**
+	 * The case of the names of the operations
+	 */
+	format?: OperationNameFormat;
+	/**
+	 * If `true`, the names of the operations must end with their type, such as `Query`
+	 */
+	requireTypeSuffix?: boolean;
+}
+export interface UseRequiredIdSelectionOptions {
+	/**
+	 * The types whose objects can be selected without their `id`, such as `PageInfo`
+	 */
+	ignore?: string[];
+}
 export interface UtilityClassSortingOptions {
 	/**
 	 * Additional attributes that will be sorted.
@@ -2567,12 +3169,24 @@ export interface UtilityClassSortingOptions {
 	 */
 	functions?: string[];
 }
+export interface UseStableArrayOrderOptions {
+	/**
+	 * The paths of the arrays to sort, such as `keywords` or `compilerOptions.lib`
+	 */
+	paths?: string[];
+}
 export interface UseValidAutocompleteOptions {
 	/**
 	 * `input` like custom components that should be checked.
 	 */
 	inputComponents?: string[];
 }
+export interface UseValidCustomElementNameOptions {
+	/**
+	 * The prefix that the names of the custom elements must start with, such as `acme-`
+	 */
+	prefix?: string;
+}
 /**
  * Options for the rule `noRestrictedGlobals`.
  */
@@ -2651,6 +3265,12 @@ If `false`, no such exception will be made.
 	ignoreNull: boolean;
 }
 export type ImportGroup = PredefinedImportGroup | Regex;
+export type MediaFeatureRangeNotation = "range" | "prefix";
+export type ColorFormat = "shortHex" | "longHex" | "rgb" | "oklch";
+/**
+ * The order of the keys
+ */
+export type SortOrder = "natural" | "lexicographic";
 export type DependencyAvailability = boolean | string[];
 export interface Hook {
 	/**
@@ -2679,6 +3299,10 @@ For example, for React's `useRef()` hook the value would be `true`, while for `u
 	stableResult?: StableHookResult;
 }
 export type Accessibility = "noPublic" | "explicit" | "none";
+/**
+ * Supported cases for the names of the operations.
+ */
+export type OperationNameFormat = "camelCase" | "PascalCase";
 export type ConsistentArrayType = "shorthand" | "generic";
 export type FilenameCases = FilenameCase[];
 export type Regex = string;
@@ -2818,6 +3442,8 @@ export interface JsFileSource {
 export interface JsonFileSource {
 	allow_comments: boolean;
 	allow_trailing_commas: boolean;
+	json5: boolean;
+	json_lines: boolean;
 }
 export interface CssFileSource {
 	variant: CssVariant;
@@ -2831,7 +3457,7 @@ export interface HtmlFileSource {
 export interface GritFileSource {
 	variant: GritVariant;
 }
-export type EmbeddingKind = "Astro" | "Vue" | "Svelte" | "None";
+export type EmbeddingKind = "Astro" | "Vue" | "Svelte" | "Mdx" | "None";
 export type Language =
 	| "JavaScript"
 	| { TypeScript: { definition_file: boolean } };
@@ -3048,31 +3674,42 @@ export type Category =
 	| "lint/nursery/colorNoInvalidHex"
 	| "lint/nursery/noColorInvalidHex"
 	| "lint/nursery/noCommonJs"
+	| "lint/nursery/noConflictingLayerOrder"
 	| "lint/nursery/noConsole"
+	| "lint/nursery/noDeprecatedCompilerOption"
+	| "lint/nursery/noDeprecatedFields"
 	| "lint/nursery/noDescendingSpecificity"
 	| "lint/nursery/noDocumentCookie"
 	| "lint/nursery/noDocumentImportInPage"
 	| "lint/nursery/noDoneCallback"
 	| "lint/nursery/noDuplicateAtImportRules"
+	| "lint/nursery/noDuplicateCustomElementNames"
 	| "lint/nursery/noDuplicateCustomProperties"
 	| "lint/nursery/noDuplicateElseIf"
+	| "lint/nursery/noDuplicateIds"
+	| "lint/nursery/noDuplicateKeysAcrossMerge"
 	| "lint/nursery/noDuplicateProperties"
 	| "lint/nursery/noDuplicatedFields"
 	| "lint/nursery/noDynamicNamespaceImportAccess"
 	| "lint/nursery/noEnum"
+	| "lint/nursery/noExcessiveQueryDepth"
 	| "lint/nursery/noExportedImports"
 	| "lint/nursery/noGlobalDirnameFilename"
 	| "lint/nursery/noHeadElement"
 	| "lint/nursery/noHeadImportInDocument"
+	| "lint/nursery/noHtmlLabelWithoutControl"
 	| "lint/nursery/noImgElement"
 	| "lint/nursery/noImportantInKeyframe"
 	| "lint/nursery/noInvalidDirectionInLinearGradient"
 	| "lint/nursery/noInvalidGridAreas"
+	| "lint/nursery/noInvalidKeyframeSelector"
 	| "lint/nursery/noInvalidPositionAtImportRule"
 	| "lint/nursery/noIrregularWhitespace"
+	| "lint/nursery/noMismatchedVariableTypes"
 	| "lint/nursery/noMissingGenericFamilyKeyword"
 	| "lint/nursery/noMissingVarFunction"
 	| "lint/nursery/noNestedTernary"
+	| "lint/nursery/noNonexistentAnimationName"
 	| "lint/nursery/noOctalEscape"
 	| "lint/nursery/noProcessEnv"
 	| "lint/nursery/noReactSpecificProps"
@@ -3084,6 +3721,12 @@ export type Category =
 	| "lint/nursery/noSubstr"
 	| "lint/nursery/noTemplateCurlyInString"
 	| "lint/nursery/noUndeclaredDependencies"
+	| "lint/nursery/noUndefinedCustomProperties"
+	| "lint/nursery/noUndefinedFragmentSpreads"
+	| "lint/nursery/noUnhandledObservedAttributes"
+	| "lint/nursery/noUnknownArguments"
+	| "lint/nursery/noUnknownCompilerOption"
+	| "lint/nursery/noUnknownFields"
 	| "lint/nursery/noUnknownFunction"
 	| "lint/nursery/noUnknownMediaFeatureName"
 	| "lint/nursery/noUnknownProperty"
@@ -3094,12 +3737,15 @@ export type Category =
 	| "lint/nursery/noUnknownTypeSelector"
 	| "lint/nursery/noUnknownUnit"
 	| "lint/nursery/noUnmatchableAnbSelector"
+	| "lint/nursery/noUnresolvedTsconfigPaths"
+	| "lint/nursery/noUnusedFragments"
 	| "lint/nursery/noUnusedFunctionParameters"
 	| "lint/nursery/noUselessEscapeInRegex"
 	| "lint/nursery/noUselessStringRaw"
 	| "lint/nursery/noUselessUndefined"
 	| "lint/nursery/noValueAtRule"
 	| "lint/nursery/useAdjacentOverloadSignatures"
+	| "lint/nursery/useAllowedUnits"
 	| "lint/nursery/useAriaPropsSupportedByRole"
 	| "lint/nursery/useAtIndex"
 	| "lint/nursery/useBiomeSuppressionComment"
@@ -3113,13 +3759,21 @@ export type Category =
 	| "lint/nursery/useGoogleFontDisplay"
 	| "lint/nursery/useGoogleFontPreconnect"
 	| "lint/nursery/useGuardForIn"
+	| "lint/nursery/useHeadingOrder"
+	| "lint/nursery/useHtmlAltText"
 	| "lint/nursery/useImportRestrictions"
 	| "lint/nursery/useJsxCurlyBraceConvention"
 	| "lint/nursery/useNamedOperation"
+	| "lint/nursery/useOperationNameConvention"
+	| "lint/nursery/useRequiredArguments"
+	| "lint/nursery/useRequiredIdSelection"
 	| "lint/nursery/useSortedClasses"
+	| "lint/nursery/useStableArrayOrder"
 	| "lint/nursery/useStrictMode"
 	| "lint/nursery/useTrimStartEnd"
 	| "lint/nursery/useValidAutocomplete"
+	| "lint/nursery/useValidCustomElementName"
+	| "lint/nursery/useValidLandmarkRoles"
 	| "lint/performance/noAccumulatingSpread"
 	| "lint/performance/noBarrelFile"
 	| "lint/performance/noDelete"
@@ -3245,7 +3899,11 @@ export type Category =
 	| "lint/suspicious/useNamespaceKeyword"
 	| "lint/suspicious/useNumberToFixedDigitsArgument"
 	| "lint/suspicious/useValidTypeof"
+	| "assists/source/useMediaFeatureRangeNotation"
+	| "assists/source/useNormalizedColors"
+	| "assists/source/useSortedFields"
 	| "assists/source/useSortedKeys"
+	| "assists/source/useSortedTsconfig"
 	| "syntax/correctness/noTypeOnlyImportAttributes"
 	| "syntax/correctness/noSuperWithoutExtends"
 	| "syntax/correctness/noInitializerWithDefinite"
@@ -3284,6 +3942,8 @@ export type Category =
 	| "suppressions/unknownRule"
 	| "suppressions/unused"
 	| "suppressions/deprecatedSuppressionComment"
+	| "suppressions/missingReason"
+	| "suppressions/expired"
 	| "args/fileNotFound"
 	| "flags/invalid"
 	| "semanticTests";
@@ -3430,6 +4090,7 @@ export type SourceActionKind =
 	| "FixAll"
 	| "None"
 	| "OrganizeImports"
+	| { Action: string }
 	| { Other: string };
 /**
  * Indicates how a tool should manage this suggestion.
@@ -3509,6 +4170,20 @@ export interface FixAction {
 	 */
 	rule_name?: [string, string];
 }
+export interface PrepareRenameParams {
+	path: BiomePath;
+	symbol_at: TextSize;
+}
+export interface PrepareRenameResult {
+	/**
+	 * The current name of the symbol
+	 */
+	name: string;
+	/**
+	 * Range of the name of the symbol that can be renamed
+	 */
+	range: TextRange;
+}
 export interface RenameParams {
 	new_name: string;
 	path: BiomePath;
@@ -3519,11 +4194,326 @@ export interface RenameResult {
 	 * List of text edit operations to apply on the source code
 	 */
 	indels: TextEdit;
+	/**
+	 * The edits of the other scripts of the workspace that import the symbol, when it's exported under its own name
+	 */
+	other_files?: RenamedFile[];
 	/**
 	 * Range of source code modified by this rename operation
 	 */
 	range: TextRange;
 }
+export interface RenamedFile {
+	/**
+	 * List of text edit operations to apply on the source code of the file
+	 */
+	indels: TextEdit;
+	path: BiomePath;
+}
+export interface GetDocumentSymbolsParams {
+	path: BiomePath;
+}
+export interface GetDocumentSymbolsResult {
+	/**
+	 * The symbols defined at the top level of the file, in the order they appear
+	 */
+	symbols: DocumentSymbol[];
+}
+/**
+ * A symbol defined by a file, as shown in the outline of the file
+ */
+export interface DocumentSymbol {
+	/**
+	 * The symbols defined inside the symbol, such as the methods of a class or the nested rules of a CSS rule
+	 */
+	children: DocumentSymbol[];
+	kind: SymbolKind;
+	name: string;
+	/**
+	 * The range of the whole definition of the symbol
+	 */
+	range: TextRange;
+	/**
+	 * The range of the name of the symbol
+	 */
+	selection_range: TextRange;
+}
+export type SymbolKind =
+	| "Class"
+	| "Constant"
+	| "Enum"
+	| "Function"
+	| "Interface"
+	| "Method"
+	| "Namespace"
+	| "Property"
+	| "TypeAlias"
+	| "Variable"
+	| "Rule"
+	| "AtRule"
+	| "Key";
+export interface GetWorkspaceSymbolsParams {
+	/**
+	 * The symbols whose name contains the characters of the query, in the same order, are returned. All the symbols are returned when the query is empty.
+	 */
+	query: string;
+}
+export interface GetWorkspaceSymbolsResult {
+	/**
+	 * The matching symbols, sorted by path and position
+	 */
+	symbols: WorkspaceSymbol[];
+}
+/**
+ * A symbol defined by one of the files processed by the workspace
+ */
+export interface WorkspaceSymbol {
+	/**
+	 * The name of the symbol that contains the symbol, such as the class of a method
+	 */
+	container_name?: string;
+	kind: SymbolKind;
+	name: string;
+	/**
+	 * The file that defines the symbol
+	 */
+	path: BiomePath;
+	/**
+	 * The range of the name of the symbol
+	 */
+	range: TextRange;
+}
+export interface GetDefinitionParams {
+	/**
+	 * The position of the import specifier or of the custom property whose definition is returned
+	 */
+	definition_of: TextSize;
+	path: BiomePath;
+}
+export interface GetDefinitionResult {
+	/**
+	 * The definitions of the symbol, empty if it can't be resolved. A custom property can be defined more than once.
+	 */
+	definitions: Definition[];
+}
+export interface Definition {
+	path: BiomePath;
+	/**
+	 * The range of the definition in the file, which is empty at the start of the file when the definition is the file itself, as for an import specifier
+	 */
+	range: TextRange;
+}
+export interface GetSemanticTokensParams {
+	path: BiomePath;
+}
+export interface GetSemanticTokensResult {
+	/**
+	 * The tokens of the file, sorted by position and without overlaps
+	 */
+	tokens: SemanticToken[];
+}
+/**
+ * An identifier whose meaning is known from the semantic model of the file
+ */
+export interface SemanticToken {
+	kind: SemanticTokenKind;
+	modifiers: SemanticTokenModifier[];
+	range: TextRange;
+}
+export type SemanticTokenKind =
+	| "Namespace"
+	| "Type"
+	| "Class"
+	| "Enum"
+	| "Interface"
+	| "TypeParameter"
+	| "Parameter"
+	| "Variable"
+	| "EnumMember"
+	| "Function";
+export type SemanticTokenModifier = "Declaration" | "Readonly" | "Imported";
+export interface GetInlayHintsParams {
+	path: BiomePath;
+	/**
+	 * Only the hints inside this range are returned, usually the part of the file visible in the editor
+	 */
+	range: TextRange;
+}
+export interface GetInlayHintsResult {
+	/**
+	 * The hints, sorted by position
+	 */
+	hints: InlayHint[];
+}
+/**
+ * A piece of information shown by the editors inside the code
+ */
+export interface InlayHint {
+	kind: InlayHintKind;
+	/**
+	 * The name of the parameter or the inferred type
+	 */
+	label: string;
+	/**
+	 * The position where the hint is shown
+	 */
+	position: TextSize;
+}
+export type InlayHintKind = "Parameter" | "Type";
+export interface GetSignatureHelpParams {
+	path: BiomePath;
+	/**
+	 * The position of the cursor, inside the arguments of a call
+	 */
+	position: TextSize;
+}
+export interface GetSignatureHelpResult {
+	/**
+	 * The signature of the called function, when it's declared in the file
+	 */
+	signature?: Signature;
+}
+/**
+ * The signature of a function, shown by the editors while the arguments of a call are written
+ */
+export interface Signature {
+	/**
+	 * The index of the parameter that receives the argument under the cursor
+	 */
+	active_parameter?: number;
+	/**
+	 * The description of the function in its JSDoc comment
+	 */
+	documentation?: string;
+	/**
+	 * The name of the function followed by its parameters, for example `sum(a, b)`
+	 */
+	label: string;
+	parameters: SignatureParameter[];
+}
+export interface SignatureParameter {
+	/**
+	 * The type and the description of the `@param` tag of the parameter
+	 */
+	documentation?: string;
+	/**
+	 * The text of the parameter, as written in the label of the signature
+	 */
+	label: string;
+}
+export interface GetFoldingRangesParams {
+	path: BiomePath;
+}
+export interface GetFoldingRangesResult {
+	/**
+	 * The ranges, sorted by start
+	 */
+	ranges: FoldingRange[];
+}
+/**
+ * A part of a file that the editors can collapse
+ */
+export interface FoldingRange {
+	kind: FoldingRangeKind;
+	/**
+	 * The range of the text hidden by the folding, which ends before the closing bracket of the blocks
+	 */
+	range: TextRange;
+}
+export type FoldingRangeKind = "Block" | "Comment" | "Imports" | "Region";
+export interface GetSelectionRangesParams {
+	path: BiomePath;
+	/**
+	 * The positions of the cursors
+	 */
+	positions: TextSize[];
+}
+export interface GetSelectionRangesResult {
+	/**
+	 * For each position, the ranges of the token and of the nodes that contain it, from the smallest to the largest
+	 */
+	ranges: TextRange[][];
+}
+export interface GetRuleConfigurationParams {
+	/**
+	 * The category of the diagnostics of the rule, for example `lint/suspicious/noDebugger`
+	 */
+	category: string;
+	path: BiomePath;
+}
+export interface GetRuleConfigurationResult {
+	/**
+	 * The configuration of the rule that applies to the file, overrides included, written in JSON. It's `None` when the configuration doesn't mention the rule, which then runs with its default options.
+	 */
+	configuration?: string;
+}
+export interface ResolveConfigurationParams {
+	path: BiomePath;
+}
+export interface ResolveConfigurationResult {
+	/**
+	 * The configuration that applies to the file, written in JSON: the configuration file with the configurations that it extends, the `.editorconfig` file when it's enabled, and the overrides that match the file
+	 */
+	configuration: string;
+}
+export interface GetModuleGraphParams {}
+/**
+ * The imports between the scripts known by the workspace
+ */
+export interface ModuleGraph {
+	/**
+	 * The scripts, sorted by path
+	 */
+	modules: ModuleGraphNode[];
+}
+export interface ModuleGraphNode {
+	/**
+	 * The packages imported by the script
+	 */
+	external: string[];
+	/**
+	 * The paths of the scripts imported by the script
+	 */
+	imports: string[];
+	/**
+	 * The path of the script
+	 */
+	path: string;
+	/**
+	 * The relative imports that don't match any script of the workspace
+	 */
+	unresolved: string[];
+}
+export interface ExportProjectIndexParams {}
+export interface ExportProjectIndexResult {
+	/**
+	 * The snapshot of the project index, written in JSON
+	 */
+	index: string;
+}
+export interface ImportProjectIndexParams {
+	/**
+	 * The files to import, with the hash of their current content. The files of the snapshot that aren't listed, or whose hash is different, aren't imported.
+	 */
+	content_hashes: [BiomePath, ContentHash][];
+	/**
+	 * A snapshot returned by [Workspace::export_project_index]
+	 */
+	index: string;
+}
+export type ContentHash = string;
+export interface ImportProjectIndexResult {
+	/**
+	 * The files whose indexes were imported, which don't need to be scanned again
+	 */
+	imported: BiomePath[];
+}
+export interface ForgetFilesParams {
+	/**
+	 * The files, or the directories, that were removed from the disk
+	 */
+	paths: BiomePath[];
+}
 export type Configuration = PartialConfiguration;
 export interface Workspace {
 	fileFeatures(params: SupportsFeatureParams): Promise<FileFeaturesResult>;
@@ -3550,7 +4540,42 @@ export interface Workspace {
 	formatRange(params: FormatRangeParams): Promise<Printed>;
 	formatOnType(params: FormatOnTypeParams): Promise<Printed>;
 	fixFile(params: FixFileParams): Promise<FixFileResult>;
+	prepareRename(params: PrepareRenameParams): Promise<PrepareRenameResult>;
 	rename(params: RenameParams): Promise<RenameResult>;
+	getDocumentSymbols(
+		params: GetDocumentSymbolsParams,
+	): Promise<GetDocumentSymbolsResult>;
+	getWorkspaceSymbols(
+		params: GetWorkspaceSymbolsParams,
+	): Promise<GetWorkspaceSymbolsResult>;
+	getDefinition(params: GetDefinitionParams): Promise<GetDefinitionResult>;
+	getSemanticTokens(
+		params: GetSemanticTokensParams,
+	): Promise<GetSemanticTokensResult>;
+	getInlayHints(params: GetInlayHintsParams): Promise<GetInlayHintsResult>;
+	getSignatureHelp(
+		params: GetSignatureHelpParams,
+	): Promise<GetSignatureHelpResult>;
+	getFoldingRanges(
+		params: GetFoldingRangesParams,
+	): Promise<GetFoldingRangesResult>;
+	getSelectionRanges(
+		params: GetSelectionRangesParams,
+	): Promise<GetSelectionRangesResult>;
+	getRuleConfiguration(
+		params: GetRuleConfigurationParams,
+	): Promise<GetRuleConfigurationResult>;
+	resolveConfiguration(
+		params: ResolveConfigurationParams,
+	): Promise<ResolveConfigurationResult>;
+	getModuleGraph(params: GetModuleGraphParams): Promise<ModuleGraph>;
+	exportProjectIndex(
+		params: ExportProjectIndexParams,
+	): Promise<ExportProjectIndexResult>;
+	importProjectIndex(
+		params: ImportProjectIndexParams,
+	): Promise<ImportProjectIndexResult>;
+	forgetFiles(params: ForgetFilesParams): Promise<void>;
 	destroy(): void;
 }
 export function createWorkspace(transport: Transport): Workspace {
@@ -3609,9 +4634,54 @@ export function createWorkspace(transport: Transport): Workspace {
 		fixFile(params) {
 			return transport.request("biome/fix_file", params);
 		},
+		prepareRename(params) {
+			return transport.request("biome/prepare_rename", params);
+		},
 		rename(params) {
 			return transport.request("biome/rename", params);
 		},
+		getDocumentSymbols(params) {
+			return transport.request("biome/get_document_symbols", params);
+		},
+		getWorkspaceSymbols(params) {
+			return transport.request("biome/get_workspace_symbols", params);
+		},
+		getDefinition(params) {
+			return transport.request("biome/get_definition", params);
+		},
+		getSemanticTokens(params) {
+			return transport.request("biome/get_semantic_tokens", params);
+		},
+		getInlayHints(params) {
+			return transport.request("biome/get_inlay_hints", params);
+		},
+		getSignatureHelp(params) {
+			return transport.request("biome/get_signature_help", params);
+		},
+		getFoldingRanges(params) {
+			return transport.request("biome/get_folding_ranges", params);
+		},
+		getSelectionRanges(params) {
+			return transport.request("biome/get_selection_ranges", params);
+		},
+		getRuleConfiguration(params) {
+			return transport.request("biome/get_rule_configuration", params);
+		},
+		resolveConfiguration(params) {
+			return transport.request("biome/resolve_configuration", params);
+		},
+		getModuleGraph(params) {
+			return transport.request("biome/get_module_graph", params);
+		},
+		exportProjectIndex(params) {
+			return transport.request("biome/export_project_index", params);
+		},
+		importProjectIndex(params) {
+			return transport.request("biome/import_project_index", params);
+		},
+		forgetFiles(params) {
+			return transport.request("biome/forget_files", params);
+		},
 		destroy() {
 			transport.destroy();
 		},
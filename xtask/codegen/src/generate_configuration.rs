@@ -3,6 +3,7 @@ use biome_analyze::{
 };
 use biome_css_syntax::CssLanguage;
 use biome_graphql_syntax::GraphqlLanguage;
+use biome_html_syntax::HtmlLanguage;
 use biome_js_syntax::JsLanguage;
 use biome_json_syntax::JsonLanguage;
 use biome_string_case::Case;
@@ -95,6 +96,25 @@ impl RegistryVisitor<GraphqlLanguage> for LintRulesVisitor {
     }
 }
 
+impl RegistryVisitor<HtmlLanguage> for LintRulesVisitor {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.groups
+            .entry(<R::Group as RuleGroup>::NAME)
+            .or_default()
+            .insert(R::METADATA.name, R::METADATA);
+    }
+}
+
 // ======= ASSISTS ======
 #[derive(Default)]
 struct AssistsRulesVisitor {
@@ -176,6 +196,25 @@ impl RegistryVisitor<GraphqlLanguage> for AssistsRulesVisitor {
     }
 }
 
+impl RegistryVisitor<HtmlLanguage> for AssistsRulesVisitor {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Action) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.groups
+            .entry(<R::Group as RuleGroup>::NAME)
+            .or_default()
+            .insert(R::METADATA.name, R::METADATA);
+    }
+}
+
 pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
     let linter_config_root = project_root().join("crates/biome_configuration/src/analyzer/linter");
     let assists_config_root =
@@ -192,6 +231,8 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
     biome_css_analyze::visit_registry(&mut assists_visitor);
     biome_graphql_analyze::visit_registry(&mut lint_visitor);
     biome_graphql_analyze::visit_registry(&mut assists_visitor);
+    biome_html_analyze::visit_registry(&mut lint_visitor);
+    biome_html_analyze::visit_registry(&mut assists_visitor);
 
     // let LintRulesVisitor { groups } = lint_visitor;
 
@@ -453,11 +494,21 @@ fn generate_for_groups(
                 #( #group_pascal_idents ),*
             }
             impl RuleGroup {
+                /// All the groups of rules
+                pub const ALL: &'static [Self] = &[ #( Self::#group_pascal_idents ),* ];
+
                 pub const fn as_str(self) -> &'static str {
                     match self {
                         #( Self::#group_pascal_idents => #group_pascal_idents::GROUP_NAME, )*
                     }
                 }
+
+                /// The names of the rules of the group, sorted alphabetically
+                pub const fn rule_names(self) -> &'static [&'static str] {
+                    match self {
+                        #( Self::#group_pascal_idents => #group_pascal_idents::GROUP_RULES, )*
+                    }
+                }
             }
             impl std::str::FromStr for RuleGroup {
                 type Err = &'static str;
@@ -739,6 +790,9 @@ fn generate_group_struct(
             "graphql" => quote! {
                 biome_graphql_analyze::options::#rule_name
             },
+            "html" => quote! {
+                biome_html_analyze::options::#rule_name
+            },
             "json" => quote! {
                 biome_json_analyze::options::#rule_name
             },
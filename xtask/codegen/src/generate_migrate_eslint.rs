@@ -61,6 +61,9 @@ pub(crate) fn generate_migrate_eslint(mode: Mode) -> Result<()> {
             match eslint_name {
                 #( #lines )*
                 _ => {
+                    if !matches!(rule_severity, eslint_eslint::Severity::Off) {
+                        results.unsupported_rules.insert(eslint_name.to_string());
+                    }
                     return false;
                 }
             }
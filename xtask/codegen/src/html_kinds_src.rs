@@ -36,6 +36,7 @@ pub const HTML_KINDS_SRC: KindsSrc = KindsSrc {
         "HTML_ATTRIBUTE_LIST",
         "HTML_CONTENT",
         "HTML_COMMENT",
+        "HTML_PASSTHROUGH",
         // Bogus nodes
         "HTML_BOGUS",
         "HTML_BOGUS_ELEMENT",
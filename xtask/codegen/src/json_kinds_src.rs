@@ -34,6 +34,8 @@ pub const JSON_KINDS_SRC: KindsSrc = KindsSrc {
         "JSON_MEMBER",
         "JSON_MEMBER_NAME",
         "JSON_ARRAY_ELEMENT_LIST",
+        "JSON_LINES_VALUE",
+        "JSON_RECORD_LIST",
         // Bogus nodes
         "JSON_BOGUS",
         "JSON_BOGUS_MEMBER_NAME",
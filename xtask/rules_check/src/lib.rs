@@ -14,7 +14,9 @@ use biome_css_syntax::CssLanguage;
 use biome_deserialize::json::deserialize_from_json_ast;
 use biome_diagnostics::{Diagnostic, DiagnosticExt, PrintDiagnostic};
 use biome_fs::BiomePath;
+use biome_graphql_schema::GraphqlSchema;
 use biome_graphql_syntax::GraphqlLanguage;
+use biome_html_syntax::HtmlLanguage;
 use biome_js_parser::JsParserOptions;
 use biome_js_syntax::{EmbeddingKind, JsFileSource, JsLanguage, TextSize};
 use biome_json_factory::make;
@@ -29,6 +31,7 @@ use std::fmt::Write;
 use std::path::PathBuf;
 use std::slice;
 use std::str::FromStr;
+use std::sync::Arc;
 
 pub fn check_rules() -> anyhow::Result<()> {
     #[derive(Default)]
@@ -112,11 +115,28 @@ pub fn check_rules() -> anyhow::Result<()> {
         }
     }
 
+    impl RegistryVisitor<HtmlLanguage> for LintRulesVisitor {
+        fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+            if matches!(C::CATEGORY, RuleCategory::Lint) {
+                C::record_groups(self);
+            }
+        }
+
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+                + 'static,
+        {
+            self.push_rule::<R, <R::Query as Queryable>::Language>()
+        }
+    }
+
     let mut visitor = LintRulesVisitor::default();
     biome_js_analyze::visit_registry(&mut visitor);
     biome_json_analyze::visit_registry(&mut visitor);
     biome_css_analyze::visit_registry(&mut visitor);
     biome_graphql_analyze::visit_registry(&mut visitor);
+    biome_html_analyze::visit_registry(&mut visitor);
 
     let LintRulesVisitor { groups } = visitor;
 
@@ -147,6 +167,10 @@ struct CodeBlockTest {
     /// Whether to use the last code block that was marked with
     /// `options` as the configuration settings for this code block.
     use_options: bool,
+
+    /// The name of the file that contains the code block, set with `file=<name>`.
+    /// Useful for the rules that only apply to specific files, such as `tsconfig.json`.
+    file_name: Option<String>,
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -166,6 +190,12 @@ impl CodeBlockTest {
     fn document_file_source(&self) -> DocumentFileSource {
         DocumentFileSource::from_extension(&self.tag)
     }
+
+    fn file_path(&self) -> String {
+        self.file_name
+            .clone()
+            .unwrap_or_else(|| format!("code-block.{}", self.tag))
+    }
 }
 
 impl FromStr for CodeBlockTest {
@@ -185,6 +215,7 @@ impl FromStr for CodeBlockTest {
             ignore: false,
             options: OptionsParsingMode::NoOptions,
             use_options: false,
+            file_name: None,
         };
 
         for token in tokens {
@@ -195,6 +226,9 @@ impl FromStr for CodeBlockTest {
                 "options" => test.options = OptionsParsingMode::RuleOptionsOnly,
                 "full_options" => test.options = OptionsParsingMode::FullConfiguration,
                 "use_options" => test.use_options = true,
+                _ if token.starts_with("file=") => {
+                    test.file_name = Some(token["file=".len()..].to_string());
+                }
                 // Regard as language tags, last one wins
                 _ => test.tag = token.to_string(),
             }
@@ -328,7 +362,7 @@ fn assert_lint(
     code: &str,
     config: &Option<PartialConfiguration>,
 ) -> anyhow::Result<()> {
-    let file_path = format!("code-block.{}", test.tag);
+    let file_path = test.file_path();
 
     if test.ignore {
         return Ok(());
@@ -395,7 +429,7 @@ fn assert_lint(
                     o
                 };
 
-                biome_js_analyze::analyze(&root, filter, &options, file_source, None, |signal| {
+                biome_js_analyze::analyze(&root, filter, &options, file_source, None, Default::default(), |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         let category = diag.category().expect("linter diagnostic has no code");
                         let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
@@ -444,7 +478,7 @@ fn assert_lint(
 
                 let options = create_analyzer_options::<JsonLanguage>(&settings, &file_path, test);
 
-                biome_json_analyze::analyze(&root, filter, &options, file_source, |signal| {
+                biome_json_analyze::analyze(&root, filter, &options, file_source, None, |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         let category = diag.category().expect("linter diagnostic has no code");
                         let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
@@ -493,7 +527,7 @@ fn assert_lint(
 
                 let options = create_analyzer_options::<JsonLanguage>(&settings, &file_path, test);
 
-                biome_css_analyze::analyze(&root, filter, &options, |signal| {
+                biome_css_analyze::analyze(&root, filter, &options, Default::default(), |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         let category = diag.category().expect("linter diagnostic has no code");
                         let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
@@ -541,8 +575,71 @@ fn assert_lint(
                 };
 
                 let options = create_analyzer_options::<JsonLanguage>(&settings, &file_path, test);
+                // The examples define the types of the schema next to the operations they validate
+                let schema = Arc::new(GraphqlSchema::from_sdl(&root));
+
+                let project = Arc::default();
+
+                biome_graphql_analyze::analyze(
+                    &root,
+                    filter,
+                    &options,
+                    Some(schema),
+                    project,
+                    |signal| {
+                        if let Some(mut diag) = signal.diagnostic() {
+                            let category = diag.category().expect("linter diagnostic has no code");
+                            let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
+                            "If you see this error, it means you need to run cargo codegen-configuration",
+                        );
+
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diag = diag.add_code_suggestion(action.into());
+                                }
+                            }
+
+                            let error = diag
+                                .with_severity(severity)
+                                .with_file_path(&file_path)
+                                .with_file_source_code(code);
+                            let res = diagnostics.write_diagnostic(error);
+
+                            // Abort the analysis on error
+                            if let Err(err) = res {
+                                eprintln!("Error: {err}");
+                                return ControlFlow::Break(err);
+                            }
+                        }
+
+                        ControlFlow::Continue(())
+                    },
+                );
+            }
+        }
+        DocumentFileSource::Html(..) => {
+            let parse = biome_html_parser::parse_html(
+                code,
+                biome_html_parser::HtmlParserOptions::default(),
+            );
+
+            if parse.has_errors() {
+                for diag in parse.into_diagnostics() {
+                    let error = diag.with_file_path(&file_path).with_file_source_code(code);
+                    diagnostics.write_diagnostic(error)?;
+                }
+            } else {
+                let root = parse.tree();
+
+                let rule_filter = RuleFilter::Rule(group, rule);
+                let filter = AnalysisFilter {
+                    enabled_rules: Some(slice::from_ref(&rule_filter)),
+                    ..AnalysisFilter::default()
+                };
+
+                let options = create_analyzer_options::<HtmlLanguage>(&settings, &file_path, test);
 
-                biome_graphql_analyze::analyze(&root, filter, &options, |signal| {
+                biome_html_analyze::analyze(&root, filter, &options, |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         let category = diag.category().expect("linter diagnostic has no code");
                         let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
@@ -572,7 +669,6 @@ fn assert_lint(
                 });
             }
         }
-        DocumentFileSource::Html(..) => todo!("HTML analysis is not yet supported"),
         DocumentFileSource::Grit(..) => todo!("Grit analysis is not yet supported"),
 
         // Unknown code blocks should be ignored by tests
@@ -637,7 +733,7 @@ fn parse_rule_options(
     test: &CodeBlockTest,
     code: &str,
 ) -> anyhow::Result<Option<PartialConfiguration>> {
-    let file_path = format!("code-block.{}", test.tag);
+    let file_path = test.file_path();
 
     // Record the diagnostics emitted during configuration parsing to later check
     // if what was emitted matches the expectations set for this code block.